@@ -0,0 +1,684 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use zip::ZipArchive;
+
+use crate::{intern_static, sanitize_variant};
+
+/// CLDR calendar ids in the order of the per-locale calendar table.
+const CALENDARS: [&str; 6] = [
+    "buddhist", "japanese", "persian", "islamic", "hebrew", "ethiopic",
+];
+/// CLDR era keys of each calendar, in the order of `CalendarDate::era`.
+/// Japanese dates before Meiji use the Gregorian eras.
+const ERAS: [&[&str]; 6] = [
+    &["0"],
+    &[
+        "gregorian:0",
+        "gregorian:1",
+        "232",
+        "233",
+        "234",
+        "235",
+        "236",
+    ],
+    &["0"],
+    &["0"],
+    &["0"],
+    &["0", "1"],
+];
+/// CLDR month keys; Hebrew Adar II is the extra `7-yeartype-leap` name.
+const MONTHS: [&str; 14] = [
+    "1",
+    "2",
+    "3",
+    "4",
+    "5",
+    "6",
+    "7",
+    "8",
+    "9",
+    "10",
+    "11",
+    "12",
+    "13",
+    "7-yeartype-leap",
+];
+
+pub fn run(
+    zip_buffer: Vec<u8>,
+    _asset_name: &str,
+    output_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
+
+    // 1. Japanese era start dates
+    let mut japanese_eras = Vec::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/calendarData.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        for key in &ERAS[1][2..] {
+            let start = json["supplemental"]["calendarData"]["japanese"]["eras"][key]["_start"]
+                .as_str()
+                .ok_or_else(|| format!("Missing start of Japanese era {}", key))?;
+            let parts: Vec<i64> = start
+                .split('-')
+                .map(|p| p.parse())
+                .collect::<Result<_, _>>()?;
+            japanese_eras.push(format!(
+                "({}, {})",
+                days_from_civil(parts[0], parts[1], parts[2]),
+                parts[0]
+            ));
+        }
+    }
+
+    // 2. Process Locales
+    let mut locales = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.name().contains("/main/") && file.is_dir() {
+            let parts: Vec<&str> = file.name().split('/').collect();
+            if let Some(idx) = parts.iter().position(|&r| r == "main")
+                && let Some(name) = parts.get(idx + 1)
+                && !name.is_empty()
+                && !locales.contains(&(*name).to_string())
+            {
+                locales.push((*name).to_string());
+            }
+        }
+    }
+    locales.sort();
+
+    let mut statics = String::new();
+    let mut ids: HashMap<String, usize> = HashMap::new();
+    let mut calendar_arms = String::new();
+    for name in &locales {
+        let var = sanitize_variant(name);
+        let gregorian = read_calendar(&mut archive, name, "gregorian")?;
+        let mut entries = Vec::new();
+        for (index, calendar) in CALENDARS.iter().enumerate() {
+            let data = read_calendar(&mut archive, name, calendar)?;
+            let body = calendar_body(&data, &gregorian, ERAS[index]);
+            let id = intern_static(&mut ids, &mut statics, "_CALENDAR", "_CalendarData", &body);
+            entries.push(format!("&{}", id));
+        }
+        let table = intern_static(
+            &mut ids,
+            &mut statics,
+            "_CALENDARS",
+            "[&_CalendarData; 6]",
+            &format!("[{}]", entries.join(", ")),
+        );
+        calendar_arms.push_str(&format!("            Locale::{} => &{},\n", var, table));
+    }
+
+    let code = format!(
+        r#"// Auto-generated. DO NOT EDIT.
+use crate::datetime_formats::{{_days_from_civil, _unicode_keyword, _without_zone_fields, DateStyle, DateTime}};
+use crate::error::LocaleError;
+use crate::locale::Locale;
+use crate::time_zones::ZonedDateTime;
+use std::fmt;
+use std::str::FromStr;
+
+/// Calendar system used to display a date, named after its BCP 47 `-u-ca-` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Calendar {{
+    /// `gregory`, the proleptic Gregorian calendar of [`DateTime`]
+    #[default]
+    Gregorian,
+    /// `buddhist`, the Thai solar calendar counting from 543 BC
+    Buddhist,
+    /// `japanese`, Gregorian months with Japanese eras from Meiji on
+    Japanese,
+    /// `persian`, the Solar Hijri calendar of Iran and Afghanistan
+    Persian,
+    /// `islamic-umalqura`, the Hijri calendar of Saudi Arabia
+    IslamicUmalqura,
+    /// `islamic-civil`, the tabular Hijri calendar with a Friday epoch
+    IslamicCivil,
+    /// `hebrew`, the lunisolar Hebrew calendar
+    Hebrew,
+    /// `ethiopic`, the Ethiopian calendar counting from the Incarnation
+    Ethiopic,
+}}
+
+/// A date in one of the supported calendar systems, see [`DateTime::to_calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDate {{
+    pub calendar: Calendar,
+    /// Index of the era in the calendar's era names, e.g. `1` for AD, `0` for
+    /// the Buddhist era or `6` for the Japanese Reiwa era.
+    pub era: usize,
+    /// Year within the era.
+    pub year: i32,
+    /// Month of the year from 1. Hebrew leap years and the Ethiopic calendar
+    /// have 13 months; in a Hebrew leap year month 6 is Adar I and 7 Adar II.
+    pub month: u32,
+    pub day: u32,
+}}
+
+/// Month and era names and date patterns of one calendar.
+struct _CalendarData {{
+    /// Format abbreviated, wide and narrow month names, then stand-alone ones.
+    months: [&'static [&'static str]; 6],
+    /// Abbreviated, wide and narrow era names.
+    eras: [&'static [&'static str]; 3],
+    /// Short, medium, long and full date patterns.
+    date_patterns: [&'static str; 4],
+}}
+
+impl Calendar {{
+    /// Returns the BCP 47 calendar identifier, e.g. `islamic-umalqura`.
+    pub fn bcp47_id(&self) -> &'static str {{
+        match self {{
+            Calendar::Gregorian => "gregory",
+            Calendar::Buddhist => "buddhist",
+            Calendar::Japanese => "japanese",
+            Calendar::Persian => "persian",
+            Calendar::IslamicUmalqura => "islamic-umalqura",
+            Calendar::IslamicCivil => "islamic-civil",
+            Calendar::Hebrew => "hebrew",
+            Calendar::Ethiopic => "ethiopic",
+        }}
+    }}
+
+    /// Reads the `-u-ca-` keyword of a BCP 47 language tag.
+    ///
+    /// Returns `Ok(None)` if the tag has no calendar keyword.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::calendars::Calendar;
+    ///
+    /// assert_eq!(Calendar::from_locale_tag("th-TH-u-ca-buddhist"), Ok(Some(Calendar::Buddhist)));
+    /// assert_eq!(Calendar::from_locale_tag("ar-SA-u-nu-latn-ca-islamic-umalqura"), Ok(Some(Calendar::IslamicUmalqura)));
+    /// assert_eq!(Calendar::from_locale_tag("fa-IR"), Ok(None));
+    /// ```
+    pub fn from_locale_tag(tag: &str) -> Result<Option<Calendar>, LocaleError> {{
+        _unicode_keyword(tag, "ca").map(|value| value.parse()).transpose()
+    }}
+}}
+
+impl fmt::Display for Calendar {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        f.write_str(self.bcp47_id())
+    }}
+}}
+
+impl FromStr for Calendar {{
+    type Err = LocaleError;
+
+    /// Parses a BCP 47 calendar identifier, e.g. `persian`. The aliases
+    /// `gregorian`, `ethiopian` and `islamicc` are accepted as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s.to_ascii_lowercase().as_str() {{
+            "gregory" | "gregorian" => Ok(Calendar::Gregorian),
+            "buddhist" => Ok(Calendar::Buddhist),
+            "japanese" => Ok(Calendar::Japanese),
+            "persian" => Ok(Calendar::Persian),
+            "islamic-umalqura" => Ok(Calendar::IslamicUmalqura),
+            "islamic-civil" | "islamicc" => Ok(Calendar::IslamicCivil),
+            "hebrew" => Ok(Calendar::Hebrew),
+            "ethiopic" | "ethiopian" => Ok(Calendar::Ethiopic),
+            _ => Err(LocaleError::UnknownCalendar(s.to_string())),
+        }}
+    }}
+}}
+
+impl DateTime {{
+    /// Converts the date into `calendar`. The time of day is the same in
+    /// every calendar, and days start at midnight.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::calendars::{{Calendar, CalendarDate}};
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let nowruz = DateTime::from_ymd(2026, 3, 21)?.to_calendar(Calendar::Persian);
+    /// assert_eq!((nowruz.year, nowruz.month, nowruz.day), (1405, 1, 1));
+    /// let reiwa = DateTime::from_ymd(2026, 1, 3)?.to_calendar(Calendar::Japanese);
+    /// assert_eq!((reiwa.era, reiwa.year), (6, 8));
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn to_calendar(&self, calendar: Calendar) -> CalendarDate {{
+        let days = _days_from_civil(self.year as i64, self.month, self.day);
+        let (era, year, month, day) = match calendar {{
+            Calendar::Gregorian => (usize::from(self.year > 0), _era_year(self.year), self.month, self.day),
+            Calendar::Buddhist => (0, self.year as i64 + 543, self.month, self.day),
+            Calendar::Japanese => {{
+                let era = _JAPANESE_ERAS
+                    .iter()
+                    .rposition(|&(start, _)| start <= days);
+                match era {{
+                    Some(i) => (i + 2, self.year as i64 - _JAPANESE_ERAS[i].1 as i64 + 1, self.month, self.day),
+                    None => (usize::from(self.year > 0), _era_year(self.year), self.month, self.day),
+                }}
+            }}
+            Calendar::Persian => {{
+                let (year, month, day) = _persian_from_days(days);
+                (0, year, month, day)
+            }}
+            Calendar::IslamicUmalqura => {{
+                let (year, month, day) = _umalqura_from_days(days).unwrap_or_else(|| _islamic_civil_from_days(days));
+                (0, year, month, day)
+            }}
+            Calendar::IslamicCivil => {{
+                let (year, month, day) = _islamic_civil_from_days(days);
+                (0, year, month, day)
+            }}
+            Calendar::Hebrew => {{
+                let (year, month, day) = _hebrew_from_days(days);
+                (0, year, month, day)
+            }}
+            Calendar::Ethiopic => {{
+                let (year, month, day) = _ethiopic_from_days(days);
+                // Years before the Incarnation count from the creation, 5500 years earlier.
+                if year > 0 {{ (1, year, month, day) }} else {{ (0, year + 5500, month, day) }}
+            }}
+        }};
+        CalendarDate {{
+            calendar,
+            era,
+            year: year.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            month,
+            day,
+        }}
+    }}
+}}
+
+impl CalendarDate {{
+    /// Position of the month in the calendar's month names. Hebrew names
+    /// follow CLDR: Adar I is sixth, Adar seventh, and Adar II is last.
+    fn _month_name_index(&self) -> usize {{
+        let month = self.month as usize - 1;
+        if self.calendar != Calendar::Hebrew {{
+            return month;
+        }}
+        match (_hebrew_is_leap_year(self.year as i64), month) {{
+            (true, 6) => 13,
+            (false, 5..) => month + 1,
+            _ => month,
+        }}
+    }}
+}}
+
+impl Locale {{
+    /// Parses a BCP 47 language tag that may carry a `-u-ca-` calendar
+    /// keyword, e.g. `th-TH-u-ca-buddhist`, into the locale and the calendar.
+    /// Without the keyword the calendar is Gregorian. Trailing subtags without
+    /// a locale of their own are dropped, so `th-TH` resolves to `th`.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::calendars::Calendar;
+    ///
+    /// assert_eq!(Locale::from_tag_with_calendar("th-TH-u-ca-buddhist"), Ok((Locale::th, Calendar::Buddhist)));
+    /// assert_eq!(Locale::from_tag_with_calendar("fa-u-ca-persian"), Ok((Locale::fa, Calendar::Persian)));
+    /// assert_eq!(Locale::from_tag_with_calendar("de-AT"), Ok((Locale::de_AT, Calendar::Gregorian)));
+    /// ```
+    pub fn from_tag_with_calendar(tag: &str) -> Result<(Locale, Calendar), LocaleError> {{
+        let base = tag.replace('_', "-");
+        let base = match base.to_ascii_lowercase().find("-u-") {{
+            Some(i) => &base[..i],
+            None => &base,
+        }};
+        let calendar = Calendar::from_locale_tag(tag)?.unwrap_or_default();
+        let mut candidate = base;
+        let locale = loop {{
+            if let Ok(locale) = Locale::from_flexible(candidate) {{
+                break locale;
+            }}
+            match candidate.rsplit_once('-') {{
+                Some((shorter, _)) => candidate = shorter,
+                None => return Err(LocaleError::UnknownLocale(base.to_ascii_lowercase())),
+            }}
+        }};
+        Ok((locale, calendar))
+    }}
+
+    /// Returns the date pattern of the given length in `calendar`, which for
+    /// calendars with eras usually includes the era, e.g. `MMM d, y G`.
+    pub fn date_pattern_in(&self, style: DateStyle, calendar: Calendar) -> &'static str {{
+        match self._calendar_data(calendar) {{
+            Some(data) => data.date_patterns[style as usize],
+            None => self.date_pattern(style),
+        }}
+    }}
+
+    /// Formats the date part of `dt` in `calendar` with the pattern of the
+    /// given length.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::calendars::Calendar;
+    /// use locale_rs::datetime_formats::{{DateStyle, DateTime}};
+    ///
+    /// let dt = DateTime::from_ymd(2026, 1, 3)?;
+    /// assert_eq!(Locale::en.format_date_in(&dt, DateStyle::Long, Calendar::Buddhist), "January 3, 2569 BE");
+    /// assert_eq!(Locale::ja.format_date_in(&dt, DateStyle::Long, Calendar::Japanese), "令和8年1月3日");
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_date_in(&self, dt: &DateTime, style: DateStyle, calendar: Calendar) -> String {{
+        self.format_with_pattern_in(dt, self.date_pattern_in(style, calendar), calendar)
+    }}
+
+    /// Formats `dt` with an explicit CLDR pattern in `calendar`. The era
+    /// (`G`), year (`y`), month (`M`, `L`) and day (`d`) fields follow the
+    /// calendar; other fields such as the weekday are the same in every
+    /// calendar, and week, quarter and day-of-year fields stay Gregorian.
+    pub fn format_with_pattern_in(&self, dt: &DateTime, pattern: &str, calendar: Calendar) -> String {{
+        let date = dt.to_calendar(calendar);
+        self._parse_calendar_pattern(
+            &_without_zone_fields(pattern),
+            &ZonedDateTime::with_offset(*dt, 0),
+            Some(&date),
+        )
+    }}
+
+    /// Appends a `G`, `y`, `M`, `L` or `d` field of a non-Gregorian date and
+    /// returns `true`, or returns `false` for other fields.
+    pub(crate) fn _format_calendar_field(&self, out: &mut String, c: char, count: usize, date: &CalendarDate) -> bool {{
+        let Some(data) = self._calendar_data(date.calendar) else {{
+            return false;
+        }};
+        let name = |names: &'static [&'static str], index: usize| names.get(index).copied().unwrap_or("");
+        match (c, count) {{
+            ('G', 4) => out.push_str(name(data.eras[1], date.era)),
+            ('G', 5) => out.push_str(name(data.eras[2], date.era)),
+            ('G', _) => out.push_str(name(data.eras[0], date.era)),
+            ('y' | 'U', 2) => out.push_str(&format!("{{:02}}", date.year.rem_euclid(100))),
+            ('y' | 'U', _) => out.push_str(&format!("{{:0width$}}", date.year, width = count)),
+            ('M' | 'L', 1 | 2) => out.push_str(&format!("{{:0width$}}", date.month, width = count)),
+            ('M' | 'L', _) => {{
+                let width = match count {{
+                    3 => 0,
+                    4 => 1,
+                    _ => 2,
+                }};
+                let context = if c == 'L' {{ 3 }} else {{ 0 }};
+                out.push_str(name(data.months[context + width], date._month_name_index()));
+            }}
+            ('d', _) => out.push_str(&format!("{{:0width$}}", date.day, width = count)),
+            _ => return false,
+        }}
+        true
+    }}
+
+    fn _calendar_data(&self, calendar: Calendar) -> Option<&'static _CalendarData> {{
+        let index = match calendar {{
+            Calendar::Gregorian => return None,
+            Calendar::Buddhist => 0,
+            Calendar::Japanese => 1,
+            Calendar::Persian => 2,
+            Calendar::IslamicUmalqura | Calendar::IslamicCivil => 3,
+            Calendar::Hebrew => 4,
+            Calendar::Ethiopic => 5,
+        }};
+        Some(self._calendars()[index])
+    }}
+
+    fn _calendars(&self) -> &'static [&'static _CalendarData; 6] {{
+        match self {{
+{calendar_arms}        }}
+    }}
+}}
+
+/// Year within the Gregorian eras: 1 BC is year 0 of the proleptic calendar.
+fn _era_year(year: i32) -> i64 {{
+    if year > 0 {{ year as i64 }} else {{ 1 - year as i64 }}
+}}
+
+/// Japanese eras from Meiji on as (first day since 1970-01-01, Gregorian year).
+/// Dates before Meiji use the Gregorian eras.
+const _JAPANESE_ERAS: [(i64, i32); {japanese_era_count}] = [{japanese_eras}];
+
+/// Solar Hijri date of a day, using the 33-year arithmetic leap cycle.
+fn _persian_from_days(days: i64) -> (i64, u32, u32) {{
+    // 1 Farvardin 1 AP is 622-03-22 in the proleptic Gregorian calendar.
+    let since_epoch = days + 492_268;
+    let year = 1 + (33 * since_epoch + 3).div_euclid(12_053);
+    let day_of_year = since_epoch - (365 * (year - 1) + (8 * year + 21).div_euclid(33));
+    let month = if day_of_year < 186 {{ day_of_year / 31 }} else {{ (day_of_year - 6) / 30 }};
+    let month_start = if month < 6 {{ 31 * month }} else {{ 30 * month + 6 }};
+    (year, month as u32 + 1, (day_of_year - month_start) as u32 + 1)
+}}
+
+/// Tabular Hijri date of a day, counting from Friday 622-07-19 with 11 leap
+/// years in each 30-year cycle.
+fn _islamic_civil_from_days(days: i64) -> (i64, u32, u32) {{
+    let since_epoch = days + 492_148;
+    let year = (30 * since_epoch + 10_646).div_euclid(10_631);
+    let year_start = (year - 1) * 354 + (3 + 11 * year).div_euclid(30);
+    let month = ((2 * (since_epoch - 29 - year_start) + 58).div_euclid(59)).clamp(0, 11);
+    let month_start = year_start + (59 * month + 1).div_euclid(2);
+    (year, month as u32 + 1, (since_epoch - month_start) as u32 + 1)
+}}
+
+/// First Umm al-Qura year covered by [`_UMALQURA_MONTHS`], and the day of its
+/// 1 Muharram since 1970-01-01.
+const _UMALQURA_FIRST_YEAR: i64 = 1300;
+const _UMALQURA_START: i64 = -31_826;
+
+/// Umm al-Qura month lengths of the years 1300 to 1600 AH: bit 11 is
+/// Muharram and bit 0 Dhuʻl-Hijjah, set for months of 30 days.
+#[rustfmt::skip]
+const _UMALQURA_MONTHS: [u16; 301] = [
+    0xAAA, 0xD54, 0xEC9, 0x6D4, 0x6EA, 0x36C, 0xAAD, 0x555, 0x6A9, 0x792, 0xBA9, 0x5D4,
+    0xADA, 0x55C, 0xD2D, 0x695, 0x74A, 0xB54, 0xB6A, 0x5AD, 0x4AE, 0xA4F, 0x517, 0x68B,
+    0x6A5, 0xAD5, 0x2D6, 0x95B, 0x49D, 0xA4D, 0xD26, 0xD95, 0x5AC, 0x9B6, 0x2BA, 0xA5B,
+    0x52B, 0xA95, 0x6CA, 0xAE9, 0x2F4, 0x976, 0x2B6, 0x956, 0xACA, 0xBA4, 0xBD2, 0x5D9,
+    0x2DC, 0x96D, 0x54D, 0xAA5, 0xB52, 0xBA5, 0x5B4, 0x9B6, 0x557, 0x297, 0x54B, 0x6A3,
+    0x752, 0xB65, 0x56A, 0xAAB, 0x52B, 0xC95, 0xD4A, 0xDA5, 0x5CA, 0xAD6, 0x957, 0x4AB,
+    0x94B, 0xAA5, 0xB52, 0xB6A, 0x575, 0x276, 0x8B7, 0x45B, 0x555, 0x5A9, 0x5B4, 0x9DA,
+    0x4DD, 0x26E, 0x936, 0xAAA, 0xD54, 0xDB2, 0x5D5, 0x2DA, 0x95B, 0x4AB, 0xA55, 0xB49,
+    0xB64, 0xB71, 0x5B4, 0xAB5, 0xA55, 0xD25, 0xE92, 0xEC9, 0x6D4, 0xAE9, 0x96B, 0x4AB,
+    0xA93, 0xD49, 0xDA4, 0xDB2, 0xAB9, 0x4BA, 0xA5B, 0x52B, 0xA95, 0xB2A, 0xB55, 0x55C,
+    0x4BD, 0x23D, 0x91D, 0xA95, 0xB4A, 0xB5A, 0x56D, 0x2B6, 0x93B, 0x49B, 0x655, 0x6A9,
+    0x754, 0xB6A, 0x56C, 0xAAD, 0x555, 0xB29, 0xB92, 0xBA9, 0x5D4, 0xADA, 0x55A, 0xAAB,
+    0x595, 0x749, 0x764, 0xBAA, 0x5B5, 0x2B6, 0xA56, 0xE4D, 0xB25, 0xB52, 0xB6A, 0x5AD,
+    0x2AE, 0x92F, 0x497, 0x64B, 0x6A5, 0x6AC, 0xAD6, 0x55D, 0x49D, 0xA4D, 0xD16, 0xD95,
+    0x5AA, 0x5B5, 0x2DA, 0x95B, 0x4AD, 0x595, 0x6CA, 0x6E4, 0xAEA, 0x4F5, 0x2B6, 0x956,
+    0xAAA, 0xB54, 0xBD2, 0x5D9, 0x2EA, 0x96D, 0x4AD, 0xA95, 0xB4A, 0xBA5, 0x5B2, 0x9B5,
+    0x4D6, 0xA97, 0x547, 0x693, 0x749, 0xB55, 0x56A, 0xA6B, 0x52B, 0xA8B, 0xD46, 0xDA3,
+    0x5CA, 0xAD6, 0x4DB, 0x26B, 0x94B, 0xAA5, 0xB52, 0xB69, 0x575, 0x176, 0x8B7, 0x25B,
+    0x52B, 0x565, 0x5B4, 0x9DA, 0x4ED, 0x16D, 0x8B6, 0xAA6, 0xD52, 0xDA9, 0x5D4, 0xADA,
+    0x95B, 0x4AB, 0x653, 0x729, 0x762, 0xBA9, 0x5B2, 0xAB5, 0x555, 0xB25, 0xD92, 0xEC9,
+    0x6D2, 0xAE9, 0x56B, 0x4AB, 0xA55, 0xD29, 0xD54, 0xDAA, 0x9B5, 0x4BA, 0xA3B, 0x49B,
+    0xA4D, 0xAAA, 0xAD5, 0x2DA, 0x95D, 0x45E, 0xA2E, 0xC9A, 0xD55, 0x6B2, 0x6B9, 0x4BA,
+    0xA5D, 0x52D, 0xA95, 0xB52, 0xBA8, 0xBB4, 0x5B9, 0x2DA, 0x95A, 0xB4A, 0xDA4, 0xED1,
+    0x6E8, 0xB6A, 0x56D, 0x535, 0x695, 0xD4A, 0xDA8, 0xDD4, 0x6DA, 0x55B, 0x29D, 0x62B,
+    0xB15, 0xB4A, 0xB95, 0x5AA, 0xAAE, 0x92E, 0xC8F, 0x527, 0x695, 0x6AA, 0xAD6, 0x55D,
+    0x29D,
+];
+
+/// Umm al-Qura date of a day, or `None` outside the years of [`_UMALQURA_MONTHS`].
+fn _umalqura_from_days(days: i64) -> Option<(i64, u32, u32)> {{
+    let mut start = _UMALQURA_START;
+    if days < start {{
+        return None;
+    }}
+    for (i, &months) in _UMALQURA_MONTHS.iter().enumerate() {{
+        let year_length = 348 + months.count_ones() as i64;
+        if days >= start + year_length {{
+            start += year_length;
+            continue;
+        }}
+        for month in 0..12 {{
+            let length = 29 + i64::from(months >> (11 - month) & 1);
+            if days < start + length {{
+                return Some((_UMALQURA_FIRST_YEAR + i as i64, month + 1, (days - start) as u32 + 1));
+            }}
+            start += length;
+        }}
+    }}
+    None
+}}
+
+fn _hebrew_is_leap_year(year: i64) -> bool {{
+    (7 * year + 1).rem_euclid(19) < 7
+}}
+
+/// Days from the Hebrew epoch to the molad-based start of `year`, before
+/// the postponements that keep years within their allowed lengths.
+fn _hebrew_elapsed_days(year: i64) -> i64 {{
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {{ days + 1 }} else {{ days }}
+}}
+
+/// Day of 1 Tishri of `year` since 1970-01-01.
+fn _hebrew_new_year(year: i64) -> i64 {{
+    let (previous, current, next) = (
+        _hebrew_elapsed_days(year - 1),
+        _hebrew_elapsed_days(year),
+        _hebrew_elapsed_days(year + 1),
+    );
+    let delay = if next - current == 356 {{
+        2
+    }} else if current - previous == 382 {{
+        1
+    }} else {{
+        0
+    }};
+    // 1 Tishri 1 AM is 3761-10-07 BC in the proleptic Julian calendar.
+    current + delay - 2_092_590
+}}
+
+/// Hebrew date of a day, with months counted from Tishri.
+fn _hebrew_from_days(days: i64) -> (i64, u32, u32) {{
+    // The estimate is low after the epoch and can be high before it.
+    let mut year = (days + 2_092_590).div_euclid(366) + 1;
+    while _hebrew_new_year(year) > days {{
+        year -= 1;
+    }}
+    while _hebrew_new_year(year + 1) <= days {{
+        year += 1;
+    }}
+    let year_length = _hebrew_new_year(year + 1) - _hebrew_new_year(year);
+    let leap = _hebrew_is_leap_year(year);
+    let lengths = [
+        30,
+        if year_length % 10 == 5 {{ 30 }} else {{ 29 }},
+        if year_length % 10 == 3 {{ 29 }} else {{ 30 }},
+        29,
+        30,
+        30,
+        29,
+        30,
+        29,
+        30,
+        29,
+        30,
+        29,
+    ];
+    let mut day = days - _hebrew_new_year(year);
+    let mut month = 0;
+    for (i, &length) in lengths.iter().enumerate() {{
+        // Adar I only exists in leap years.
+        if i == 5 && !leap {{
+            continue;
+        }}
+        month += 1;
+        if day < length {{
+            break;
+        }}
+        day -= length;
+    }}
+    (year, month, day as u32 + 1)
+}}
+
+/// Ethiopian date of a day, counting from the Incarnation on 8-08-27 of the
+/// proleptic Gregorian calendar.
+fn _ethiopic_from_days(days: i64) -> (i64, u32, u32) {{
+    let since_epoch = days + 716_367;
+    let year = (4 * since_epoch + 1463).div_euclid(1461);
+    let year_start = 365 * (year - 1) + year.div_euclid(4);
+    let day_of_year = since_epoch - year_start;
+    (year, (day_of_year / 30) as u32 + 1, (day_of_year % 30) as u32 + 1)
+}}
+
+{statics}"#,
+        calendar_arms = calendar_arms,
+        japanese_era_count = japanese_eras.len(),
+        japanese_eras = japanese_eras.join(", "),
+        statics = statics
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+fn read_calendar(
+    archive: &mut ZipArchive<Cursor<Vec<u8>>>,
+    name: &str,
+    calendar: &str,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let path = format!("cldr-dates-full/main/{}/ca-{}.json", name, calendar);
+    let json: Value = match archive.by_name(&path) {
+        Ok(mut file) => serde_json::from_reader(&mut file)?,
+        Err(_) => Value::Null,
+    };
+    Ok(json["main"][name]["dates"]["calendars"][calendar].clone())
+}
+
+/// Emits a `_CalendarData` literal. Era keys prefixed with `gregorian:` are
+/// looked up in the Gregorian calendar.
+fn calendar_body(data: &Value, gregorian: &Value, eras: &[&str]) -> String {
+    let mut months = Vec::new();
+    for context in ["format", "stand-alone"] {
+        for width in ["abbreviated", "wide", "narrow"] {
+            let names = &data["months"][context][width];
+            let count = if names["13"].is_string() {
+                MONTHS.len()
+            } else {
+                12
+            };
+            let names: Vec<&str> = MONTHS[..count]
+                .iter()
+                .map(|key| names[key].as_str().unwrap_or(""))
+                .collect();
+            months.push(format!("&{:?}", names));
+        }
+    }
+    let eras: Vec<String> = ["eraAbbr", "eraNames", "eraNarrow"]
+        .iter()
+        .map(|width| {
+            let names: Vec<&str> = eras
+                .iter()
+                .map(|key| match key.strip_prefix("gregorian:") {
+                    Some(key) => gregorian["eras"][width][key].as_str().unwrap_or(""),
+                    None => data["eras"][width][key].as_str().unwrap_or(""),
+                })
+                .collect();
+            format!("&{:?}", names)
+        })
+        .collect();
+    let patterns = ["short", "medium", "long", "full"].map(|length| {
+        data["dateFormats"][length]
+            .as_str()
+            .unwrap_or("")
+            .to_string()
+    });
+    format!(
+        "_CalendarData {{ months: [{}], eras: [{}], date_patterns: {:?} }}",
+        months.join(", "),
+        eras.join(", "),
+        patterns
+    )
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
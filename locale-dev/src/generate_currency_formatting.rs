@@ -1,5 +1,5 @@
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::io::Cursor;
 use zip::ZipArchive;
@@ -13,23 +13,61 @@ pub fn run(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
 
-    // 1. Load Territory -> Currency mapping from supplemental data
+    // 1. Load Territory -> Currency mapping and fraction digits from supplemental data
     let mut region_to_currency = HashMap::new();
+    let mut currency_codes = BTreeSet::new();
+    let mut fraction_digits = HashMap::new();
+    let mut default_digits = 2;
+    let mut history_arms = String::new();
     if let Ok(mut file) = archive.by_name("cldr-core/supplemental/currencyData.json") {
         let json: Value = serde_json::from_reader(&mut file)?;
         if let Some(regions) = json["supplemental"]["currencyData"]["region"].as_object() {
             for (region_code, currencies) in regions {
+                let mut periods = String::new();
                 if let Some(cur_list) = currencies.as_array() {
+                    // CLDR lists the currencies of a region from newest to oldest
                     for entry in cur_list {
                         let cur_code = entry.as_object().and_then(|m| m.keys().next());
                         if let Some(code) = cur_code
-                            && entry[code]["_to"].is_null()
+                            && is_currency_code(code)
                         {
-                            region_to_currency.insert(region_code.clone(), code.clone());
-                            break;
+                            let data = &entry[code];
+                            let tender = data["_tender"].as_str() != Some("false");
+                            currency_codes.insert(code.clone());
+                            if data["_to"].is_null() {
+                                region_to_currency
+                                    .entry(region_code.clone())
+                                    .or_insert(code.clone());
+                            }
+                            periods.push_str(&format!(
+                                "                CurrencyPeriod {{ currency: Currency::{}, from: {}, to: {}, tender: {} }},\n",
+                                code,
+                                currency_date_literal(&data["_from"]),
+                                currency_date_literal(&data["_to"]),
+                                tender
+                            ));
                         }
                     }
                 }
+                if !periods.is_empty() {
+                    history_arms.push_str(&format!(
+                        "            {:?} => &[\n{}            ],\n",
+                        region_code, periods
+                    ));
+                }
+            }
+        }
+        if let Some(fractions) = json["supplemental"]["currencyData"]["fractions"].as_object() {
+            for (code, data) in fractions {
+                let digits = data["_digits"].as_str().and_then(|d| d.parse::<u32>().ok());
+                match (code.as_str(), digits) {
+                    ("DEFAULT", Some(d)) => default_digits = d,
+                    (_, Some(d)) if is_currency_code(code) => {
+                        currency_codes.insert(code.clone());
+                        fraction_digits.insert(code.clone(), d);
+                    }
+                    _ => {}
+                }
             }
         }
     }
@@ -69,6 +107,9 @@ pub fn run(
 
     let mut pattern_arms = String::new();
     let mut symbol_arms = String::new();
+    let mut default_currency_arms = String::new();
+    let mut currency_symbol_arms = String::new();
+    let mut currency_region_arms = String::new();
 
     for name in &locales {
         let var = sanitize_variant(name);
@@ -88,12 +129,28 @@ pub fn run(
         let cur_path = format!("cldr-numbers-full/main/{}/currencies.json", name);
         if let Ok(mut file) = archive.by_name(&cur_path) {
             let json: Value = serde_json::from_reader(&mut file)?;
-            if let Some(s) =
-                json["main"][name]["numbers"]["currencies"][&currency_code]["symbol"].as_str()
-            {
+            let currencies = &json["main"][name]["numbers"]["currencies"];
+            if let Some(s) = currencies[&currency_code]["symbol"].as_str() {
                 symbol = s.to_string();
             }
+
+            // Only symbols that differ from the ISO code need an arm
+            if let Some(map) = currencies.as_object() {
+                for (code, data) in map {
+                    if let Some(s) = data["symbol"].as_str()
+                        && is_currency_code(code)
+                        && s != code
+                    {
+                        currency_codes.insert(code.clone());
+                        currency_symbol_arms.push_str(&format!(
+                            "            (Locale::{}, Currency::{}) => {:?},\n",
+                            var, code, s
+                        ));
+                    }
+                }
+            }
         }
+        currency_codes.insert(currency_code.clone());
 
         // Resolve Pattern
         let mut pattern = "¤#,##0.00".to_string();
@@ -110,12 +167,164 @@ pub fn run(
 
         pattern_arms.push_str(&format!("            Locale::{} => {:?},\n", var, pattern));
         symbol_arms.push_str(&format!("            Locale::{} => {:?},\n", var, symbol));
+        default_currency_arms.push_str(&format!(
+            "            Locale::{} => Currency::{},\n",
+            var, currency_code
+        ));
+
+        // Resolve Currency Region: an explicit region subtag wins over likely subtags
+        let language = name.split('-').next().unwrap_or(name);
+        let currency_region = name
+            .split('-')
+            .skip(1)
+            .find(|part| region_to_currency.contains_key(*part))
+            .map(str::to_string)
+            .or_else(|| lang_to_region.get(name).cloned())
+            .or_else(|| lang_to_region.get(language).cloned())
+            .unwrap_or_else(|| "US".to_string());
+        currency_region_arms.push_str(&format!(
+            "            Locale::{} => {:?},\n",
+            var, currency_region
+        ));
+    }
+
+    let mut currency_variants = String::new();
+    let mut currency_as_str_arms = String::new();
+    let mut currency_from_str_arms = String::new();
+    let mut fraction_arms = String::new();
+    for code in &currency_codes {
+        currency_variants.push_str(&format!("    {},\n", code));
+        currency_as_str_arms.push_str(&format!(
+            "            Currency::{} => \"{}\",\n",
+            code, code
+        ));
+        currency_from_str_arms.push_str(&format!(
+            "            \"{}\" => Ok(Currency::{}),\n",
+            code, code
+        ));
+        if let Some(digits) = fraction_digits.get(code)
+            && *digits != default_digits
+        {
+            fraction_arms.push_str(&format!("            Currency::{} => {},\n", code, digits));
+        }
     }
 
     let code = format!(
         r#"// Auto-generated. DO NOT EDIT.
+use crate::error::LocaleError;
 use crate::locale::Locale;
-use crate::num_formats::ToFormattedString;
+use crate::num_formats::{{NumberingSystem, ToFormattedString, _replace_digits}};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// ISO 4217 currency codes known to CLDR, including historical currencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Currency {{
+{currency_variants}}}
+
+impl Currency {{
+    /// Returns the ISO 4217 code of the currency.
+    pub fn as_str(&self) -> &'static str {{
+        match self {{
+{currency_as_str_arms}        }}
+    }}
+
+    /// Returns the number of fraction digits CLDR uses for the currency,
+    /// i.e. how many minor units make up one major unit (2 for EUR, 0 for JPY).
+    pub fn fraction_digits(&self) -> u32 {{
+        match self {{
+{fraction_arms}            _ => {default_digits},
+        }}
+    }}
+}}
+
+impl fmt::Display for Currency {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        write!(f, "{{}}", self.as_str())
+    }}
+}}
+
+impl FromStr for Currency {{
+    type Err = LocaleError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s.to_ascii_uppercase().as_str() {{
+{currency_from_str_arms}            _ => Err(LocaleError::UnknownCurrency(s.to_string())),
+        }}
+    }}
+}}
+
+/// A Gregorian calendar date bounding the use of a currency in a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CurrencyDate {{
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}}
+
+impl CurrencyDate {{
+    pub const fn new(year: i32, month: u32, day: u32) -> Self {{
+        CurrencyDate {{ year, month, day }}
+    }}
+}}
+
+#[cfg(feature = "datetime")]
+impl From<crate::datetime_formats::DateTime> for CurrencyDate {{
+    fn from(dt: crate::datetime_formats::DateTime) -> Self {{
+        CurrencyDate::new(dt.year, dt.month, dt.day)
+    }}
+}}
+
+/// A period during which a currency was used in a region, as recorded by CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurrencyPeriod {{
+    pub currency: Currency,
+    /// First day of use, if known.
+    pub from: Option<CurrencyDate>,
+    /// Last day of use, or `None` if the currency is still in use.
+    pub to: Option<CurrencyDate>,
+    /// Whether the currency is legal tender. Funds codes such as `USN` are not.
+    pub tender: bool,
+}}
+
+impl CurrencyPeriod {{
+    /// Returns true if the currency was in use on `date` (both bounds inclusive).
+    pub fn contains(&self, date: CurrencyDate) -> bool {{
+        self.from.is_none_or(|from| from <= date) && self.to.is_none_or(|to| date <= to)
+    }}
+}}
+
+/// Returns every currency a region (ISO 3166-1 code) has used, newest first.
+pub fn region_currency_history(region: &str) -> &'static [CurrencyPeriod] {{
+    match region.to_ascii_uppercase().as_str() {{
+{history_arms}        _ => &[],
+    }}
+}}
+
+/// Returns the currencies in use in `region` on `date`, legal tender first.
+///
+/// # Examples
+/// ```
+/// use locale_rs::currency_formats::{{Currency, CurrencyDate, currencies_for_region}};
+///
+/// let in_1999: Vec<Currency> = currencies_for_region("DE", CurrencyDate::new(1999, 6, 1))
+///     .iter()
+///     .map(|p| p.currency)
+///     .collect();
+/// assert_eq!(in_1999, vec![Currency::EUR, Currency::DEM]);
+/// ```
+pub fn currencies_for_region(region: &str, date: CurrencyDate) -> Vec<CurrencyPeriod> {{
+    let mut periods: Vec<CurrencyPeriod> = region_currency_history(region)
+        .iter()
+        .filter(|p| p.contains(date))
+        .copied()
+        .collect();
+    periods.sort_by_key(|p| !p.tender);
+    periods
+}}
 
 impl Locale {{
     pub fn currency_standard_pattern(&self) -> &'static str {{
@@ -128,6 +337,54 @@ impl Locale {{
 {symbol_arms}        }}
     }}
 
+    /// Returns the currency whose symbol is [`Locale::default_currency_symbol`].
+    pub fn default_currency(&self) -> Currency {{
+        match self {{
+{default_currency_arms}        }}
+    }}
+
+    /// Returns the region used to pick the locale's currency: the region
+    /// subtag if present, otherwise the likely region of the language.
+    pub fn currency_region(&self) -> &'static str {{
+        match self {{
+{currency_region_arms}        }}
+    }}
+
+    /// Returns the legal tender of the locale's currency region on `date`.
+    /// When several currencies were valid at once, the newest one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::currency_formats::{{Currency, CurrencyDate}};
+    ///
+    /// assert_eq!(Locale::de.currency_on(CurrencyDate::new(1998, 5, 1)), Some(Currency::DEM));
+    /// assert_eq!(Locale::de.currency_on(CurrencyDate::new(2026, 5, 1)), Some(Currency::EUR));
+    /// ```
+    pub fn currency_on(&self, date: CurrencyDate) -> Option<Currency> {{
+        currencies_for_region(self.currency_region(), date)
+            .first()
+            .filter(|p| p.tender)
+            .map(|p| p.currency)
+    }}
+
+    /// Returns the localized symbol of `currency`, or its ISO code if the
+    /// locale has no dedicated symbol.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::currency_formats::Currency;
+    ///
+    /// assert_eq!(Locale::de.currency_symbol(Currency::EUR), "€");
+    /// assert_eq!(Locale::de.currency_symbol(Currency::CHF), "CHF");
+    /// ```
+    pub fn currency_symbol(&self, currency: Currency) -> &'static str {{
+        match (self, currency) {{
+{currency_symbol_arms}            _ => currency.as_str(),
+        }}
+    }}
+
     pub fn format_currency<T: Into<f64>>(&self, value: T) -> String {{
         let val: f64 = value.into();
         let symbol = self.default_currency_symbol();
@@ -183,11 +440,534 @@ macro_rules! impl_currency {{
 }}
 
 impl_currency!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64, isize, usize);
+
+/// An exact monetary amount, stored as an integer number of the currency's
+/// minor units (e.g. cents) according to the CLDR fraction digits.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::currency_formats::{{Currency, Money}};
+///
+/// let price = Money::from_minor(123456, Currency::EUR);
+/// assert_eq!(price.format(&Locale::en), "€1,234.56");
+///
+/// let shares = price.split(3).unwrap();
+/// assert_eq!(shares[0].minor_units(), 41152);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {{
+    minor_units: i64,
+    currency: Currency,
+}}
+
+impl Money {{
+    /// Creates an amount from a number of minor units, e.g. cents for EUR.
+    pub fn from_minor(minor_units: i64, currency: Currency) -> Self {{
+        Money {{
+            minor_units,
+            currency,
+        }}
+    }}
+
+    /// Creates an amount from a value in major units, rounding half away
+    /// from zero to the fraction digits of the currency.
+    ///
+    /// The shortest decimal form of `amount` is rounded rather than its binary
+    /// value, so `1.005` EUR is 1.01 even though the nearest `f64` lies just
+    /// below it. Amounts that must be exact should use [`Money::from_minor`].
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::currency_formats::{{Currency, Money}};
+    ///
+    /// assert_eq!(Money::from_major(1.005, Currency::EUR)?.minor_units(), 101);
+    /// assert_eq!(Money::from_major(-2.5, Currency::JPY)?.minor_units(), -3);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn from_major(amount: f64, currency: Currency) -> Result<Self, LocaleError> {{
+        if !amount.is_finite() {{
+            return Err(LocaleError::Overflow);
+        }}
+        let digits = currency.fraction_digits() as usize;
+        let text = amount.abs().to_string();
+        let (int_part, frac_part) = text.split_once('.').unwrap_or((&text, ""));
+        let kept: String = frac_part.chars().chain(std::iter::repeat('0')).take(digits).collect();
+        let round_up = frac_part.as_bytes().get(digits).is_some_and(|d| *d >= b'5');
+        let minor = format!("{{}}{{}}", int_part, kept)
+            .parse::<i64>()
+            .ok()
+            .and_then(|m| m.checked_add(i64::from(round_up)))
+            .ok_or(LocaleError::Overflow)?;
+        Ok(Money::from_minor(if amount < 0.0 {{ -minor }} else {{ minor }}, currency))
+    }}
+
+    /// Creates a zero amount of `currency`.
+    pub fn zero(currency: Currency) -> Self {{
+        Money::from_minor(0, currency)
+    }}
+
+    /// Returns the amount in minor units.
+    pub fn minor_units(&self) -> i64 {{
+        self.minor_units
+    }}
+
+    /// Returns the currency of the amount.
+    pub fn currency(&self) -> Currency {{
+        self.currency
+    }}
+
+    /// Returns the amount in major units. This is lossy for large amounts.
+    pub fn to_major(&self) -> f64 {{
+        self.minor_units as f64 / 10f64.powi(self.currency.fraction_digits() as i32)
+    }}
+
+    pub fn is_zero(&self) -> bool {{
+        self.minor_units == 0
+    }}
+
+    pub fn is_negative(&self) -> bool {{
+        self.minor_units < 0
+    }}
+
+    /// Adds two amounts of the same currency.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, LocaleError> {{
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_add(other.minor_units)
+            .map(|m| Money::from_minor(m, self.currency))
+            .ok_or(LocaleError::Overflow)
+    }}
+
+    /// Subtracts an amount of the same currency.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, LocaleError> {{
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_sub(other.minor_units)
+            .map(|m| Money::from_minor(m, self.currency))
+            .ok_or(LocaleError::Overflow)
+    }}
+
+    /// Splits the amount into `parts` shares that differ by at most one minor unit.
+    /// Leftover minor units go to the first shares, so nothing is lost.
+    pub fn split(&self, parts: usize) -> Result<Vec<Money>, LocaleError> {{
+        self.allocate(&vec![1; parts])
+    }}
+
+    /// Allocates the amount proportionally to `ratios`.
+    ///
+    /// Every share is rounded towards zero and the remaining minor units are
+    /// handed out one at a time from the first share with a non-zero ratio,
+    /// so the shares always add up to the original amount.
+    pub fn allocate(&self, ratios: &[u32]) -> Result<Vec<Money>, LocaleError> {{
+        let total: i128 = ratios.iter().map(|&r| r as i128).sum();
+        if total == 0 {{
+            return Err(LocaleError::InvalidAllocation(
+                "ratios must contain at least one non-zero value".to_string(),
+            ));
+        }}
+
+        let amount = self.minor_units as i128;
+        let mut shares: Vec<i64> = ratios
+            .iter()
+            .map(|&r| (amount * r as i128 / total) as i64)
+            .collect();
+
+        let mut remainder = amount - shares.iter().map(|&s| s as i128).sum::<i128>();
+        let step = remainder.signum() as i64;
+        for (share, &ratio) in shares.iter_mut().zip(ratios) {{
+            if remainder == 0 {{
+                break;
+            }}
+            if ratio > 0 {{
+                *share += step;
+                remainder -= step as i128;
+            }}
+        }}
+
+        Ok(shares
+            .into_iter()
+            .map(|m| Money::from_minor(m, self.currency))
+            .collect())
+    }}
+
+    /// Converts the amount into `to` with a rate from `provider`, rounding
+    /// half away from zero to the fraction digits of the target currency.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::currency_formats::{{Currency, Money, StaticRateProvider}};
+    ///
+    /// let rates = StaticRateProvider::new().with_rate(Currency::EUR, Currency::JPY, 162.345)?;
+    /// let price = Money::from_minor(1000, Currency::EUR);
+    /// assert_eq!(
+    ///     price.convert(Currency::JPY, &rates),
+    ///     Ok(Money::from_minor(1623, Currency::JPY))
+    /// );
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn convert<P>(&self, to: Currency, provider: &P) -> Result<Money, LocaleError>
+    where
+        P: ExchangeRateProvider + ?Sized,
+    {{
+        if self.currency == to {{
+            return Ok(*self);
+        }}
+        let rate = provider
+            .rate(self.currency, to)
+            .filter(|r| r.is_finite() && *r > 0.0)
+            .ok_or_else(|| LocaleError::ExchangeRateUnavailable {{
+                from: self.currency.to_string(),
+                to: to.to_string(),
+            }})?;
+        Money::from_major(self.to_major() * rate, to)
+    }}
+
+    /// Formats the amount with the locale's currency pattern, symbol and digits.
+    pub fn format(&self, locale: &Locale) -> String {{
+        self.format_in(locale, locale.default_numbering_system())
+    }}
+
+    /// Like [`Money::format`] with the locale's symbols and the digits of a
+    /// numbering system. Algorithmic systems use the locale's default one.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::currency_formats::{{Currency, Money}};
+    /// use locale_rs::num_formats::NumberingSystem;
+    ///
+    /// let price = Money::from_minor(123456, Currency::INR);
+    /// assert_eq!(price.format_in(&Locale::hi, NumberingSystem::Deva), "₹१,२३४.५६");
+    /// ```
+    pub fn format_in(&self, locale: &Locale, system: NumberingSystem) -> String {{
+        let system = if system.is_algorithmic() {{ locale.default_numbering_system() }} else {{ system }};
+        let symbols = locale.number_symbols(system);
+        let digits = self.currency.fraction_digits();
+        let scale = 10u64.pow(digits);
+        let abs = self.minor_units.unsigned_abs();
+
+        let mut num_str = (abs / scale).to_formatted_string_in(locale, system);
+        if digits > 0 {{
+            let fraction = format!("{{:0width$}}", abs % scale, width = digits as usize);
+            num_str.push_str(symbols.decimal);
+            num_str.push_str(&match system.digits() {{
+                Some(d) => _replace_digits(fraction, &d),
+                None => fraction,
+            }});
+        }}
+
+        let result = _apply_currency_pattern(
+            locale.currency_standard_pattern(),
+            locale.currency_symbol(self.currency),
+            &num_str,
+        );
+
+        if self.minor_units < 0 {{
+            format!("{{}}{{}}", symbols.minus_sign, result)
+        }} else {{
+            result
+        }}
+    }}
+
+    /// Returns a wrapper implementing [`fmt::Display`] for the given locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::currency_formats::{{Currency, Money}};
+    ///
+    /// let price = Money::from_minor(995, Currency::EUR);
+    /// assert_eq!(format!("{{}}", price.display(&Locale::de)), "9,95\u{{a0}}€");
+    /// ```
+    pub fn display<'a>(&'a self, locale: &'a Locale) -> MoneyDisplay<'a> {{
+        MoneyDisplay {{
+            money: self,
+            locale,
+        }}
+    }}
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<(), LocaleError> {{
+        if self.currency == other.currency {{
+            Ok(())
+        }} else {{
+            Err(LocaleError::CurrencyMismatch {{
+                expected: self.currency.to_string(),
+                found: other.currency.to_string(),
+            }})
+        }}
+    }}
+}}
+
+/// Displays a [`Money`] amount for a specific locale, see [`Money::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct MoneyDisplay<'a> {{
+    money: &'a Money,
+    locale: &'a Locale,
+}}
+
+impl fmt::Display for MoneyDisplay<'_> {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        f.write_str(&self.money.format(self.locale))
+    }}
+}}
+
+impl ToCurrencyString for Money {{
+    fn to_currency(&self, locale: &Locale) -> String {{
+        self.format(locale)
+    }}
+}}
+
+/// A source of exchange rates used by [`Money::convert`].
+pub trait ExchangeRateProvider {{
+    /// Returns how many units of `to` one unit of `from` is worth,
+    /// or `None` if the rate is unknown.
+    fn rate(&self, from: Currency, to: Currency) -> Option<f64>;
+}}
+
+impl<P: ExchangeRateProvider + ?Sized> ExchangeRateProvider for &P {{
+    fn rate(&self, from: Currency, to: Currency) -> Option<f64> {{
+        (**self).rate(from, to)
+    }}
+}}
+
+/// An in-memory table of exchange rates.
+///
+/// Besides the rates that were inserted, the table answers inverse pairs and
+/// pairs that can be crossed through a single common currency.
+///
+/// # Examples
+/// ```
+/// use locale_rs::currency_formats::{{Currency, ExchangeRateProvider, StaticRateProvider}};
+///
+/// let rates = StaticRateProvider::new()
+///     .with_rate(Currency::EUR, Currency::USD, 1.25)?
+///     .with_rate(Currency::EUR, Currency::JPY, 160.0)?;
+///
+/// assert_eq!(rates.rate(Currency::USD, Currency::EUR), Some(0.8));
+/// assert_eq!(rates.rate(Currency::USD, Currency::JPY), Some(128.0));
+/// # Ok::<(), locale_rs::error::LocaleError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaticRateProvider {{
+    rates: BTreeMap<(Currency, Currency), f64>,
+}}
+
+impl StaticRateProvider {{
+    pub fn new() -> Self {{
+        StaticRateProvider::default()
+    }}
+
+    /// Adds a rate and returns the table, for chaining. See [`StaticRateProvider::insert`].
+    pub fn with_rate(mut self, from: Currency, to: Currency, rate: f64) -> Result<Self, LocaleError> {{
+        self.insert(from, to, rate)?;
+        Ok(self)
+    }}
+
+    /// Sets the rate for `from` to `to`, replacing any previous value.
+    ///
+    /// Returns [`LocaleError::InvalidRateData`] if the rate is not a finite
+    /// positive number.
+    pub fn insert(&mut self, from: Currency, to: Currency, rate: f64) -> Result<(), LocaleError> {{
+        if !(rate.is_finite() && rate > 0.0) {{
+            return Err(LocaleError::InvalidRateData(format!(
+                "invalid rate {{}} for {{}} to {{}}",
+                rate, from, to
+            )));
+        }}
+        self.rates.insert((from, to), rate);
+        Ok(())
+    }}
+
+    pub fn len(&self) -> usize {{
+        self.rates.len()
+    }}
+
+    pub fn is_empty(&self) -> bool {{
+        self.rates.is_empty()
+    }}
+
+    fn direct(&self, from: Currency, to: Currency) -> Option<f64> {{
+        if from == to {{
+            return Some(1.0);
+        }}
+        self.rates
+            .get(&(from, to))
+            .copied()
+            .or_else(|| self.rates.get(&(to, from)).map(|r| 1.0 / r))
+    }}
+}}
+
+impl ExchangeRateProvider for StaticRateProvider {{
+    fn rate(&self, from: Currency, to: Currency) -> Option<f64> {{
+        self.direct(from, to).or_else(|| {{
+            self.rates
+                .keys()
+                .flat_map(|&(a, b)| [a, b])
+                .find_map(|via| Some(self.direct(from, via)? * self.direct(via, to)?))
+        }})
+    }}
+}}
+
+/// Exchange rates loaded from a CSV or JSON file for offline use.
+///
+/// CSV files contain one `FROM,TO,RATE` line per rate; empty lines, lines
+/// starting with `#` and a header in place of the first rate are skipped:
+///
+/// ```text
+/// from,to,rate
+/// EUR,USD,1.0812
+/// EUR,JPY,162.30
+/// ```
+///
+/// With the `json` feature, JSON files list the rates of a base currency;
+/// other fields, as in the responses of most rate APIs, are ignored:
+///
+/// ```text
+/// {{ "success": true, "base": "EUR", "rates": {{ "USD": 1.0812, "JPY": 162.30 }} }}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileRateProvider {{
+    table: StaticRateProvider,
+}}
+
+impl FileRateProvider {{
+    /// Loads rates from `path`, choosing the format by the `csv` or `json` extension.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, LocaleError> {{
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| LocaleError::InvalidRateData(format!("{{}}: {{}}", path.display(), e)))?;
+        match path.extension().and_then(|e| e.to_str()) {{
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Self::from_csv_str(&content),
+            #[cfg(feature = "json")]
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json_str(&content),
+            #[cfg(not(feature = "json"))]
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Err(LocaleError::InvalidRateData(format!(
+                "{{}}: reading JSON rates requires the `json` feature",
+                path.display()
+            ))),
+            _ => Err(LocaleError::InvalidRateData(format!(
+                "{{}}: expected a .csv or .json file",
+                path.display()
+            ))),
+        }}
+    }}
+
+    /// Parses `FROM,TO,RATE` lines.
+    pub fn from_csv_str(content: &str) -> Result<Self, LocaleError> {{
+        let mut table = StaticRateProvider::new();
+        let mut first = true;
+        for (index, line) in content.lines().enumerate() {{
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('\x23') {{
+                continue;
+            }}
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let error = |msg: &str| LocaleError::InvalidRateData(format!("line {{}}: {{}}", index + 1, msg));
+            if fields.len() != 3 {{
+                return Err(error("expected FROM,TO,RATE"));
+            }}
+            // A header names the columns, so none of its fields parse.
+            let is_header = fields[0].parse::<Currency>().is_err()
+                && fields[1].parse::<Currency>().is_err()
+                && fields[2].parse::<f64>().is_err();
+            if std::mem::take(&mut first) && is_header {{
+                continue;
+            }}
+            let from = fields[0].parse().map_err(|_| error("unknown currency code"))?;
+            let to = fields[1].parse().map_err(|_| error("unknown currency code"))?;
+            let rate = fields[2].parse().map_err(|_| error("invalid rate"))?;
+            table.insert(from, to, rate).map_err(|_| error("invalid rate"))?;
+        }}
+        Ok(FileRateProvider {{ table }})
+    }}
+
+    /// Parses a `{{ "base": "EUR", "rates": {{ "USD": 1.08 }} }}` document,
+    /// ignoring any other fields.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(content: &str) -> Result<Self, LocaleError> {{
+        let error = |msg: String| LocaleError::InvalidRateData(msg);
+        let root: serde_json::Value = serde_json::from_str(content).map_err(|e| error(e.to_string()))?;
+
+        let base = match root.get("base").and_then(|b| b.as_str()) {{
+            Some(code) => code.parse::<Currency>().map_err(|_| error(format!("unknown base currency '{{}}'", code)))?,
+            None => return Err(error("missing \"base\" currency".to_string())),
+        }};
+        let rates = match root.get("rates").and_then(|r| r.as_object()) {{
+            Some(rates) => rates,
+            None => return Err(error("missing \"rates\" object".to_string())),
+        }};
+
+        let mut table = StaticRateProvider::new();
+        for (code, value) in rates {{
+            let to = code.parse::<Currency>().map_err(|_| error(format!("unknown currency code '{{}}'", code)))?;
+            let invalid = || error(format!("invalid rate for '{{}}'", code));
+            let rate = value.as_f64().ok_or_else(invalid)?;
+            table.insert(base, to, rate).map_err(|_| invalid())?;
+        }}
+        Ok(FileRateProvider {{ table }})
+    }}
+
+    /// Returns the loaded rates.
+    pub fn table(&self) -> &StaticRateProvider {{
+        &self.table
+    }}
+}}
+
+impl ExchangeRateProvider for FileRateProvider {{
+    fn rate(&self, from: Currency, to: Currency) -> Option<f64> {{
+        self.table.rate(from, to)
+    }}
+}}
+
+/// Replaces the number placeholder and the currency sign of a CLDR currency
+/// pattern. Only the positive subpattern is used; the caller adds the sign.
+fn _apply_currency_pattern(pattern: &str, symbol: &str, num_str: &str) -> String {{
+    let positive = pattern.split(';').next().unwrap_or(pattern);
+    let is_number_char = |c: char| matches!(c, '\x23' | '0' | ',' | '.');
+
+    match (positive.find(is_number_char), positive.rfind(is_number_char)) {{
+        (Some(start), Some(end)) => format!(
+            "{{}}{{}}{{}}",
+            positive[..start].replace('\u{{00a4}}', symbol),
+            num_str,
+            positive[end + 1..].replace('\u{{00a4}}', symbol)
+        ),
+        _ => positive.replace('\u{{00a4}}', symbol),
+    }}
+}}
 "#,
         pattern_arms = pattern_arms,
-        symbol_arms = symbol_arms
+        symbol_arms = symbol_arms,
+        default_currency_arms = default_currency_arms,
+        currency_region_arms = currency_region_arms,
+        history_arms = history_arms,
+        currency_symbol_arms = currency_symbol_arms,
+        currency_variants = currency_variants,
+        currency_as_str_arms = currency_as_str_arms,
+        currency_from_str_arms = currency_from_str_arms,
+        fraction_arms = fraction_arms,
+        default_digits = default_digits
     );
 
     fs::write(output_path, code)?;
     Ok(())
 }
+
+fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase())
+}
+
+fn currency_date_literal(value: &Value) -> String {
+    let parts: Vec<u32> = value
+        .as_str()
+        .map(|d| d.split('-').filter_map(|p| p.parse().ok()).collect())
+        .unwrap_or_default();
+    match parts.as_slice() {
+        [year, month, day] => format!(
+            "Some(CurrencyDate {{ year: {}, month: {}, day: {} }})",
+            year, month, day
+        ),
+        _ => "None".to_string(),
+    }
+}
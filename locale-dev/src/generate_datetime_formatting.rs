@@ -1,9 +1,165 @@
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::io::Cursor;
 use zip::ZipArchive;
 
-use crate::sanitize_variant;
+use crate::{intern_static, intern_table, sanitize_variant};
+
+/// CLDR `weekData` keys in `WeekData` field order.
+const WEEK_DATA_KEYS: [&str; 4] = ["firstDay", "minDays", "weekendStart", "weekendEnd"];
+/// CLDR day keys, numbered from 0 for Sunday like `DateTime::weekday`.
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// CLDR day period keys in `DayPeriod` order.
+const DAY_PERIODS: [(&str, &str); 12] = [
+    ("midnight", "Midnight"),
+    ("am", "Am"),
+    ("noon", "Noon"),
+    ("pm", "Pm"),
+    ("morning1", "Morning1"),
+    ("morning2", "Morning2"),
+    ("afternoon1", "Afternoon1"),
+    ("afternoon2", "Afternoon2"),
+    ("evening1", "Evening1"),
+    ("evening2", "Evening2"),
+    ("night1", "Night1"),
+    ("night2", "Night2"),
+];
+
+/// How the entries of a CLDR name table are keyed.
+#[derive(Clone, Copy)]
+enum NameKeys {
+    Months,
+    Days,
+    Quarters,
+    Eras,
+}
+
+/// Name tables generated as `Locale::<function>() -> &'static [&'static str]`:
+/// (function, doc comment, path below the calendar, keys).
+const NAME_TABLES: &[(&str, &str, &[&str], NameKeys)] = &[
+    (
+        "months_narrow",
+        "Narrow month names, e.g. `J`.",
+        &["months", "format", "narrow"],
+        NameKeys::Months,
+    ),
+    (
+        "months_standalone_abbreviated",
+        "Abbreviated month names used on their own (`LLL`).",
+        &["months", "stand-alone", "abbreviated"],
+        NameKeys::Months,
+    ),
+    (
+        "months_standalone_wide",
+        "Month names used on their own (`LLLL`), e.g. nominative forms.",
+        &["months", "stand-alone", "wide"],
+        NameKeys::Months,
+    ),
+    (
+        "months_standalone_narrow",
+        "Narrow month names used on their own (`LLLLL`).",
+        &["months", "stand-alone", "narrow"],
+        NameKeys::Months,
+    ),
+    (
+        "days_abbreviated",
+        "Abbreviated weekday names starting on Sunday, e.g. `Sun`.",
+        &["days", "format", "abbreviated"],
+        NameKeys::Days,
+    ),
+    (
+        "days_short",
+        "Short weekday names starting on Sunday, e.g. `Su`.",
+        &["days", "format", "short"],
+        NameKeys::Days,
+    ),
+    (
+        "days_narrow",
+        "Narrow weekday names starting on Sunday, e.g. `S`.",
+        &["days", "format", "narrow"],
+        NameKeys::Days,
+    ),
+    (
+        "days_standalone_abbreviated",
+        "Abbreviated weekday names used on their own (`ccc`).",
+        &["days", "stand-alone", "abbreviated"],
+        NameKeys::Days,
+    ),
+    (
+        "days_standalone_wide",
+        "Weekday names used on their own (`cccc`).",
+        &["days", "stand-alone", "wide"],
+        NameKeys::Days,
+    ),
+    (
+        "days_standalone_short",
+        "Short weekday names used on their own (`cccccc`).",
+        &["days", "stand-alone", "short"],
+        NameKeys::Days,
+    ),
+    (
+        "days_standalone_narrow",
+        "Narrow weekday names used on their own (`ccccc`).",
+        &["days", "stand-alone", "narrow"],
+        NameKeys::Days,
+    ),
+    (
+        "quarters_abbreviated",
+        "Abbreviated quarter names, e.g. `Q1`.",
+        &["quarters", "format", "abbreviated"],
+        NameKeys::Quarters,
+    ),
+    (
+        "quarters_wide",
+        "Quarter names, e.g. `1st quarter`.",
+        &["quarters", "format", "wide"],
+        NameKeys::Quarters,
+    ),
+    (
+        "quarters_narrow",
+        "Narrow quarter names, e.g. `1`.",
+        &["quarters", "format", "narrow"],
+        NameKeys::Quarters,
+    ),
+    (
+        "quarters_standalone_abbreviated",
+        "Abbreviated quarter names used on their own (`qqq`).",
+        &["quarters", "stand-alone", "abbreviated"],
+        NameKeys::Quarters,
+    ),
+    (
+        "quarters_standalone_wide",
+        "Quarter names used on their own (`qqqq`).",
+        &["quarters", "stand-alone", "wide"],
+        NameKeys::Quarters,
+    ),
+    (
+        "quarters_standalone_narrow",
+        "Narrow quarter names used on their own (`qqqqq`).",
+        &["quarters", "stand-alone", "narrow"],
+        NameKeys::Quarters,
+    ),
+    (
+        "eras_abbreviated",
+        "Abbreviated era names, BC first, e.g. `BC`, `AD`.",
+        &["eras", "eraAbbr"],
+        NameKeys::Eras,
+    ),
+    (
+        "eras_wide",
+        "Era names, BC first, e.g. `Before Christ`.",
+        &["eras", "eraNames"],
+        NameKeys::Eras,
+    ),
+    (
+        "eras_narrow",
+        "Narrow era names, BC first, e.g. `B`, `A`.",
+        &["eras", "eraNarrow"],
+        NameKeys::Eras,
+    ),
+];
 
 pub fn run(
     zip_buffer: Vec<u8>,
@@ -12,6 +168,95 @@ pub fn run(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
 
+    // Week data by region as [firstDay, minDays, weekendStart, weekendEnd];
+    // each key only lists the regions that differ from the world (`001`).
+    let mut week_keys: Vec<HashMap<String, u32>> = vec![HashMap::new(); WEEK_DATA_KEYS.len()];
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/weekData.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        let data = &json["supplemental"]["weekData"];
+        for (key, values) in WEEK_DATA_KEYS.iter().zip(&mut week_keys) {
+            for (region, value) in data[key].as_object().into_iter().flatten() {
+                let value = value.as_str().unwrap_or("");
+                let value = match WEEKDAYS.iter().position(|day| *day == value) {
+                    Some(day) => day as u32,
+                    None => value.parse()?,
+                };
+                values.insert(region.clone(), value);
+            }
+        }
+    }
+    let week_data_of = |region: &str| -> [u32; 4] {
+        let world = [1, 1, 6, 0];
+        std::array::from_fn(|i| {
+            let values = &week_keys[i];
+            values
+                .get(region)
+                .or_else(|| values.get("001"))
+                .copied()
+                .unwrap_or(world[i])
+        })
+    };
+    let mut week_regions: Vec<&String> =
+        week_keys.iter().flat_map(|values| values.keys()).collect();
+    week_regions.sort();
+    week_regions.dedup();
+    let mut week_data_arms = String::new();
+    for region in week_regions {
+        let data = week_data_of(region);
+        if region != "001" && data != week_data_of("001") {
+            week_data_arms.push_str(&format!(
+                "            {:?} => {},\n",
+                region,
+                week_data_literal(data)
+            ));
+        }
+    }
+    let world_week_data = week_data_literal(week_data_of("001"));
+
+    // Language -> likely region, to resolve the week data of `en` or `ar`
+    let mut lang_to_region = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/likelySubtags.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        if let Some(subtags) = json["supplemental"]["likelySubtags"].as_object() {
+            for (lang_key, full_locale) in subtags {
+                if let Some(full_str) = full_locale.as_str() {
+                    let parts: Vec<&str> = full_str.split('-').collect();
+                    if parts.len() >= 3 {
+                        lang_to_region.insert(lang_key.clone(), parts[2].to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    // Hour cycles by region or `language_region`: (preferred, allowed)
+    let mut time_data: HashMap<String, (String, String)> = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/timeData.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        for (key, data) in json["supplemental"]["timeData"]
+            .as_object()
+            .into_iter()
+            .flatten()
+        {
+            let preferred = data["_preferred"].as_str().unwrap_or("H").to_string();
+            let allowed = data["_allowed"].as_str().unwrap_or("H").to_string();
+            time_data.insert(key.clone(), (preferred, allowed));
+        }
+    }
+
+    // Day period rule sets by locale id
+    let mut day_period_rules: HashMap<String, Value> = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/dayPeriods.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        for (id, rules) in json["supplemental"]["dayPeriodRuleSet"]
+            .as_object()
+            .into_iter()
+            .flatten()
+        {
+            day_period_rules.insert(id.clone(), rules.clone());
+        }
+    }
+
     let mut locales = Vec::new();
     for i in 0..archive.len() {
         let file = archive.by_index(i)?;
@@ -34,9 +279,87 @@ pub fn run(
     let mut date_format_arms = String::new();
     let mut time_format_arms = String::new();
     let mut am_pm_arms = String::new();
+    let mut available_formats_arms = String::new();
+    let mut datetime_glue_arms = String::new();
+    let mut datetime_at_time_arms = String::new();
+    let mut am_pm_abbr_arms = String::new();
+    let mut am_pm_narrow_arms = String::new();
+    let mut name_table_arms = vec![String::new(); NAME_TABLES.len()];
+    let mut interval_formats_arms = String::new();
+    let mut interval_fallback_arms = String::new();
+    let mut statics = String::new();
+    let mut static_ids: HashMap<String, usize> = HashMap::new();
+    let mut week_region_arms = String::new();
+    let mut time_data_arms = String::new();
+    let mut day_period_rule_arms = String::new();
+    let mut day_period_name_arms = String::new();
 
     for name in &locales {
         let var = sanitize_variant(name);
+
+        // An explicit region subtag wins over the likely region of the language
+        let language = name.split('-').next().unwrap_or(name);
+        let region = name
+            .split('-')
+            .skip(1)
+            .find(|part| {
+                (part.len() == 2 && part.chars().all(|c| c.is_ascii_uppercase()))
+                    || (part.len() == 3 && part.chars().all(|c| c.is_ascii_digit()))
+            })
+            .map(str::to_string)
+            .or_else(|| lang_to_region.get(name).cloned())
+            .or_else(|| lang_to_region.get(language).cloned())
+            .unwrap_or_else(|| "001".to_string());
+        week_region_arms.push_str(&format!("            Locale::{} => {:?},\n", var, region));
+
+        // Day period rules: the locale, then with subtags removed, then root
+        let parts: Vec<&str> = name.split('-').collect();
+        let rules = (1..=parts.len())
+            .rev()
+            .map(|n| parts[..n].join("-"))
+            .chain(["root".to_string()])
+            .find_map(|id| day_period_rules.get(&id))
+            .unwrap_or(&Value::Null);
+        let rules_table = intern_table(
+            &mut static_ids,
+            &mut statics,
+            "_DAY_PERIOD_RULES",
+            "(DayPeriod, u16, u16)",
+            &day_period_rule_entries(rules)?,
+        );
+        day_period_rule_arms.push_str(&format!(
+            "            Locale::{} => &{},\n",
+            var, rules_table
+        ));
+
+        // Hour cycles: the locale, then language and region, then the region alone
+        let (preferred, allowed) = [
+            name.replace('-', "_"),
+            format!("{}_{}", language, region),
+            region.clone(),
+            "001".to_string(),
+        ]
+        .iter()
+        .find_map(|key| time_data.get(key))
+        .cloned()
+        .unwrap_or_else(|| ("H".to_string(), "H h".to_string()));
+        let mut allowed_cycles: Vec<&str> = Vec::new();
+        for symbol in allowed.split_whitespace() {
+            let cycle = hour_cycle_variant(symbol);
+            if !allowed_cycles.contains(&cycle) {
+                allowed_cycles.push(cycle);
+            }
+        }
+        let allowed_cycles: Vec<String> = allowed_cycles
+            .iter()
+            .map(|cycle| format!("HourCycle::{}", cycle))
+            .collect();
+        time_data_arms.push_str(&format!(
+            "            Locale::{} => (HourCycle::{}, &[{}]),\n",
+            var,
+            hour_cycle_variant(&preferred),
+            allowed_cycles.join(", ")
+        ));
         let json_path = format!("cldr-dates-full/main/{}/ca-gregorian.json", name);
 
         if let Ok(mut file) = archive.by_name(&json_path) {
@@ -55,8 +378,17 @@ pub fn run(
                 .as_str()
                 .unwrap_or("PM");
 
-            let d_medium = greg["dateFormats"]["medium"].as_str().unwrap_or("y-MM-dd");
-            let t_medium = greg["timeFormats"]["medium"].as_str().unwrap_or("HH:mm:ss");
+            let names = intern_static(
+                &mut static_ids,
+                &mut statics,
+                "_DAY_PERIOD_NAMES",
+                "[[&str; 12]; 3]",
+                &day_period_names(&greg["dayPeriods"]["format"]),
+            );
+            day_period_name_arms.push_str(&format!("            Locale::{} => &{},\n", var, names));
+
+            let date_patterns = extract_lengths(&greg["dateFormats"], &["y-MM-dd"; 4]);
+            let time_patterns = extract_lengths(&greg["timeFormats"], &["HH:mm:ss"; 4]);
 
             months_wide_arms.push_str(&format!("            Locale::{} => &{:?},\n", var, m_wide));
             months_abbr_arms.push_str(&format!("            Locale::{} => &{:?},\n", var, m_abbr));
@@ -65,8 +397,75 @@ pub fn run(
                 "            Locale::{} => ({:?}, {:?}),\n",
                 var, am, pm
             ));
-            date_format_arms.push_str(&format!("            Locale::{} => {:?},\n", var, d_medium));
-            time_format_arms.push_str(&format!("            Locale::{} => {:?},\n", var, t_medium));
+            for (arms, width) in [
+                (&mut am_pm_abbr_arms, "abbreviated"),
+                (&mut am_pm_narrow_arms, "narrow"),
+            ] {
+                let periods = &greg["dayPeriods"]["format"][width];
+                arms.push_str(&format!(
+                    "            Locale::{} => ({:?}, {:?}),\n",
+                    var,
+                    periods["am"].as_str().unwrap_or(am),
+                    periods["pm"].as_str().unwrap_or(pm)
+                ));
+            }
+
+            for ((_, _, path, keys), arms) in NAME_TABLES.iter().zip(&mut name_table_arms) {
+                let table = path.iter().fold(greg, |value, key| &value[*key]);
+                let names = match keys {
+                    NameKeys::Months => extract_indexed_months(table),
+                    NameKeys::Days => extract_days(table),
+                    NameKeys::Quarters => extract_indexed(table, 1..=4),
+                    NameKeys::Eras => extract_indexed(table, 0..=1),
+                };
+                arms.push_str(&format!("            Locale::{} => &{:?},\n", var, names));
+            }
+            date_format_arms.push_str(&format!(
+                "            Locale::{} => &{:?},\n",
+                var, date_patterns
+            ));
+            time_format_arms.push_str(&format!(
+                "            Locale::{} => &{:?},\n",
+                var, time_patterns
+            ));
+
+            // The atTime variant joins a date with a specific time ("{1} 'at' {0}")
+            // and falls back to the standard glue for each missing length.
+            let date_time_formats = &greg["dateTimeFormats"];
+            let glue = extract_lengths(date_time_formats, &["{1} {0}"; 4]);
+            let at_time_glue = extract_lengths(
+                &greg["dateTimeFormats-atTime"]["standard"],
+                &glue.each_ref().map(String::as_str),
+            );
+            let skeletons = extract_available_formats(&date_time_formats["availableFormats"]);
+            available_formats_arms.push_str(&format!(
+                "            Locale::{} => &{:?},\n",
+                var, skeletons
+            ));
+            datetime_glue_arms.push_str(&format!("            Locale::{} => &{:?},\n", var, glue));
+            let intervals = &date_time_formats["intervalFormats"];
+            let interval_table = intern_table(
+                &mut static_ids,
+                &mut statics,
+                "_INTERVAL_FORMATS",
+                "(&str, &[(char, &str)])",
+                &extract_interval_formats(intervals),
+            );
+            interval_formats_arms.push_str(&format!(
+                "            Locale::{} => &{},\n",
+                var, interval_table
+            ));
+            interval_fallback_arms.push_str(&format!(
+                "            Locale::{} => {:?},\n",
+                var,
+                intervals["intervalFormatFallback"]
+                    .as_str()
+                    .unwrap_or("{0} – {1}")
+            ));
+            datetime_at_time_arms.push_str(&format!(
+                "            Locale::{} => &{:?},\n",
+                var, at_time_glue
+            ));
         } else {
             months_wide_arms.push_str(&format!("            Locale::{} => &[\"1\",\"2\",\"3\",\"4\",\"5\",\"6\",\"7\",\"8\",\"9\",\"10\",\"11\",\"12\"],\n", var));
             months_abbr_arms.push_str(&format!("            Locale::{} => &[\"1\",\"2\",\"3\",\"4\",\"5\",\"6\",\"7\",\"8\",\"9\",\"10\",\"11\",\"12\"],\n", var));
@@ -75,176 +474,2492 @@ pub fn run(
                 "            Locale::{} => (\"AM\", \"PM\"),\n",
                 var
             ));
-            date_format_arms.push_str(&format!("            Locale::{} => \"y-MM-dd\",\n", var));
-            time_format_arms.push_str(&format!("            Locale::{} => \"HH:mm:ss\",\n", var));
+            for arms in [&mut am_pm_abbr_arms, &mut am_pm_narrow_arms] {
+                arms.push_str(&format!(
+                    "            Locale::{} => (\"AM\", \"PM\"),\n",
+                    var
+                ));
+            }
+            for ((_, _, _, keys), arms) in NAME_TABLES.iter().zip(&mut name_table_arms) {
+                let names: &[&str] = match keys {
+                    NameKeys::Months => &[
+                        "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+                    ],
+                    NameKeys::Days => &["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+                    NameKeys::Quarters => &["Q1", "Q2", "Q3", "Q4"],
+                    NameKeys::Eras => &["BC", "AD"],
+                };
+                arms.push_str(&format!("            Locale::{} => &{:?},\n", var, names));
+            }
+            date_format_arms.push_str(&format!(
+                "            Locale::{} => &[\"y-MM-dd\"; 4],\n",
+                var
+            ));
+            time_format_arms.push_str(&format!(
+                "            Locale::{} => &[\"HH:mm:ss\"; 4],\n",
+                var
+            ));
+            available_formats_arms.push_str(&format!("            Locale::{} => &[],\n", var));
+            let names = intern_static(
+                &mut static_ids,
+                &mut statics,
+                "_DAY_PERIOD_NAMES",
+                "[[&str; 12]; 3]",
+                &day_period_names(&Value::Null),
+            );
+            day_period_name_arms.push_str(&format!("            Locale::{} => &{},\n", var, names));
+            interval_formats_arms.push_str(&format!("            Locale::{} => &[],\n", var));
+            interval_fallback_arms.push_str(&format!(
+                "            Locale::{} => \"{{0}} – {{1}}\",\n",
+                var
+            ));
+            datetime_glue_arms.push_str(&format!(
+                "            Locale::{} => &[\"{{1}} {{0}}\"; 4],\n",
+                var
+            ));
+            datetime_at_time_arms.push_str(&format!(
+                "            Locale::{} => &[\"{{1}} {{0}}\"; 4],\n",
+                var
+            ));
         }
     }
 
+    let mut name_table_fns = String::new();
+    for ((function, doc, _, _), arms) in NAME_TABLES.iter().zip(&name_table_arms) {
+        name_table_fns.push_str(&format!(
+            "    /// {}\n    pub fn {}(&self) -> &'static [&'static str] {{\n        match self {{ {} }}\n    }}\n\n",
+            doc, function, arms
+        ));
+    }
+
     let code = format!(
         r#"// Auto-generated. DO NOT EDIT.
+use crate::calendars::CalendarDate;
+use crate::error::LocaleError;
 use crate::locale::Locale;
+use crate::time_zones::ZonedDateTime;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{{Duration, SystemTime, UNIX_EPOCH}};
+
+/// Length of a localized date pattern, from CLDR `dateFormats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateStyle {{
+    /// e.g. `1/3/26`
+    Short,
+    /// e.g. `Jan 3, 2026`
+    Medium,
+    /// e.g. `January 3, 2026`
+    Long,
+    /// e.g. `Saturday, January 3, 2026`
+    Full,
+}}
+
+/// Length of a localized time pattern, from CLDR `timeFormats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeStyle {{
+    /// e.g. `2:05 PM`
+    Short,
+    /// e.g. `2:05:09 PM`
+    Medium,
+    /// Adds the short zone name, e.g. `2:05:09 PM PST`
+    Long,
+    /// Adds the long zone name, e.g. `2:05:09 PM Pacific Standard Time`
+    Full,
+}}
+
+/// Width of a localized name, as selected by three to six pattern letters,
+/// e.g. `MMM`, `MMMM`, `MMMMM` and `EEEEEE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameWidth {{
+    /// e.g. `Jan`, `Sat`
+    Abbreviated,
+    /// e.g. `January`, `Saturday`
+    Wide,
+    /// e.g. `J`, `S`
+    Narrow,
+    /// e.g. `Sa`; only weekdays have short names, others use abbreviated ones
+    Short,
+}}
+
+/// Grammatical context of a localized name. Some languages inflect month
+/// names inside dates, e.g. Polish `października` in a date but
+/// `październik` in a calendar header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameContext {{
+    /// Used inside a date, as by `MMMM` and `EEEE`
+    Format,
+    /// Used on its own, as by `LLLL` and `cccc`
+    StandAlone,
+}}
+
+/// Period of the day, from CLDR `dayPeriods`. Which flexible periods exist
+/// and when they start depends on the locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPeriod {{
+    Midnight,
+    Am,
+    Noon,
+    Pm,
+    Morning1,
+    Morning2,
+    Afternoon1,
+    Afternoon2,
+    Evening1,
+    Evening2,
+    Night1,
+    Night2,
+}}
+
+/// Week conventions of a region, from CLDR `weekData`. Days are numbered as
+/// by [`DateTime::weekday`], from 0 for Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekData {{
+    /// First day of the week, e.g. 1 (Monday) in Germany and 0 (Sunday) in the US.
+    pub first_day: u32,
+    /// Minimal number of days of week 1 in the new year: 1 makes the week of
+    /// January 1st week 1, and 4 with Monday as first day gives ISO 8601 weeks.
+    pub min_days: u32,
+    /// First day of the weekend, e.g. 5 (Friday) in Egypt.
+    pub weekend_start: u32,
+    /// Last day of the weekend, e.g. 6 (Saturday) in Egypt.
+    pub weekend_end: u32,
+}}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl WeekData {{
+    /// ISO 8601 weeks: starting on Monday, with week 1 holding the first Thursday.
+    pub const ISO: WeekData = WeekData {{
+        first_day: 1,
+        min_days: 4,
+        weekend_start: 6,
+        weekend_end: 0,
+    }};
+
+    /// Returns the week data of a region code such as `DE` or `EG`. Other
+    /// regions use the world default.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::datetime_formats::WeekData;
+    ///
+    /// assert_eq!(WeekData::for_region("DE"), WeekData::ISO);
+    /// assert_eq!(WeekData::for_region("US").first_day, 0);
+    /// assert_eq!(WeekData::for_region("EG").weekend_start, 5);
+    /// ```
+    pub fn for_region(region: &str) -> WeekData {{
+        match region.to_ascii_uppercase().as_str() {{
+{week_data_arms}            _ => {world_week_data},
+        }}
+    }}
+
+    /// Returns whether `weekday`, 0 for Sunday, is part of the weekend.
+    pub fn is_weekend(&self, weekday: u32) -> bool {{
+        (weekday + 7 - self.weekend_start) % 7 <= (self.weekend_end + 7 - self.weekend_start) % 7
+    }}
+}}
+
+/// Clock used for hours, named after its BCP 47 `-u-hc-` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HourCycle {{
+    /// `h11`, 0 to 11 with AM/PM (pattern symbol `K`)
+    H11,
+    /// `h12`, 1 to 12 with AM/PM (pattern symbol `h`)
+    H12,
+    /// `h23`, 0 to 23 (pattern symbol `H`)
+    H23,
+    /// `h24`, 1 to 24 (pattern symbol `k`)
+    H24,
+}}
+
+impl HourCycle {{
+    /// Returns the BCP 47 identifier, e.g. `h23`.
+    pub fn bcp47_id(&self) -> &'static str {{
+        match self {{
+            HourCycle::H11 => "h11",
+            HourCycle::H12 => "h12",
+            HourCycle::H23 => "h23",
+            HourCycle::H24 => "h24",
+        }}
+    }}
+
+    /// Returns the pattern symbol of the hour field, e.g. `H` for `h23`.
+    pub fn symbol(&self) -> char {{
+        match self {{
+            HourCycle::H11 => 'K',
+            HourCycle::H12 => 'h',
+            HourCycle::H23 => 'H',
+            HourCycle::H24 => 'k',
+        }}
+    }}
+
+    /// Returns whether the clock shows AM/PM.
+    pub fn is_12_hour(&self) -> bool {{
+        matches!(self, HourCycle::H11 | HourCycle::H12)
+    }}
+
+    /// Reads the `-u-hc-` keyword of a BCP 47 language tag.
+    ///
+    /// Returns `Ok(None)` if the tag has no hour cycle keyword.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::datetime_formats::HourCycle;
+    ///
+    /// assert_eq!(HourCycle::from_locale_tag("en-US-u-hc-h23"), Ok(Some(HourCycle::H23)));
+    /// assert_eq!(HourCycle::from_locale_tag("de"), Ok(None));
+    /// ```
+    pub fn from_locale_tag(tag: &str) -> Result<Option<HourCycle>, LocaleError> {{
+        _unicode_keyword(tag, "hc").map(|value| value.parse()).transpose()
+    }}
+}}
+
+impl fmt::Display for HourCycle {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        f.write_str(self.bcp47_id())
+    }}
+}}
+
+impl FromStr for HourCycle {{
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s.to_ascii_lowercase().as_str() {{
+            "h11" => Ok(HourCycle::H11),
+            "h12" => Ok(HourCycle::H12),
+            "h23" => Ok(HourCycle::H23),
+            "h24" => Ok(HourCycle::H24),
+            _ => Err(LocaleError::UnknownHourCycle(s.to_string())),
+        }}
+    }}
+}}
+
+/// A date and time of day in the proleptic Gregorian calendar, without a
+/// time zone. Values order chronologically.
+///
+/// Values are created with [`DateTime::new`] or [`DateTime::from_ymd`], which
+/// check every field, so a `DateTime` always holds an existing date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DateTime {{
-    pub year: i32,
-    pub month: u32,  // 1-12
-    pub day: u32,    // 1-31
-    pub hour: u32,   // 0-23
-    pub minute: u32, // 0-59
-    pub second: u32, // 0-59
+    pub(crate) year: i32,
+    pub(crate) month: u32,  // 1-12
+    pub(crate) day: u32,    // 1-31
+    pub(crate) hour: u32,   // 0-23
+    pub(crate) minute: u32, // 0-59
+    pub(crate) second: u32, // 0-59
 }}
 
-impl Locale {{
-    pub fn months_wide(&self) -> &'static [&'static str] {{
-        match self {{ {months_wide_arms} }}
+impl DateTime {{
+    /// Creates a date and time, checking every field against the proleptic
+    /// Gregorian calendar.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// assert!(DateTime::new(2024, 2, 29, 23, 59, 59).is_ok());
+    /// assert!(DateTime::new(2025, 2, 29, 0, 0, 0).is_err());
+    /// assert!(DateTime::new(2026, 13, 1, 0, 0, 0).is_err());
+    /// ```
+    pub fn new(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, LocaleError> {{
+        let dt = DateTime {{
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }};
+        dt.validate()?;
+        Ok(dt)
     }}
 
-    pub fn months_abbreviated(&self) -> &'static [&'static str] {{
-        match self {{ {months_abbr_arms} }}
+    /// Creates a date at midnight.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, LocaleError> {{
+        DateTime::new(year, month, day, 0, 0, 0)
     }}
 
-    pub fn days_wide(&self) -> &'static [&'static str] {{
-        match self {{ {days_wide_arms} }}
+    /// The year, counting 1 BC as 0 and 2 BC as -1.
+    pub fn year(&self) -> i32 {{
+        self.year
     }}
 
-    fn am_pm(&self) -> (&'static str, &'static str) {{
-        match self {{ {am_pm_arms} }}
+    /// The month, 1 for January through 12.
+    pub fn month(&self) -> u32 {{
+        self.month
     }}
 
-    pub fn date_format_pattern(&self) -> &'static str {{
-        match self {{ {date_format_arms} }}
+    /// The day of the month, starting at 1.
+    pub fn day(&self) -> u32 {{
+        self.day
     }}
 
-    pub fn time_format_pattern(&self) -> &'static str {{
-        match self {{ {time_format_arms} }}
+    /// The hour, 0 through 23.
+    pub fn hour(&self) -> u32 {{
+        self.hour
     }}
 
-    pub fn format_date(&self, dt: &DateTime) -> String {{
-        self._parse_runtime_pattern(self.date_format_pattern(), dt)
+    /// The minute, 0 through 59.
+    pub fn minute(&self) -> u32 {{
+        self.minute
     }}
 
-    pub fn format_time(&self, dt: &DateTime) -> String {{
-        self._parse_runtime_pattern(self.time_format_pattern(), dt)
+    /// The second, 0 through 59.
+    pub fn second(&self) -> u32 {{
+        self.second
     }}
 
-    fn _parse_runtime_pattern(&self, pattern: &str, dt: &DateTime) -> String {{
-        let mut result = String::new();
-        let mut chars = pattern.chars().peekable();
-        let mut is_quoted = false;
+    /// Checks that every field is in range, e.g. no February 30th or hour 24.
+    fn validate(&self) -> Result<(), LocaleError> {{
+        let invalid = |field: &str, value: u32| {{
+            Err(LocaleError::InvalidDateTime(format!("{{}} {{}} is out of range", field, value)))
+        }};
+        if !(1..=12).contains(&self.month) {{
+            return invalid("month", self.month);
+        }}
+        if !(1..=_days_in_month(self.year, self.month)).contains(&self.day) {{
+            return invalid("day", self.day);
+        }}
+        if self.hour > 23 {{
+            return invalid("hour", self.hour);
+        }}
+        if self.minute > 59 {{
+            return invalid("minute", self.minute);
+        }}
+        if self.second > 59 {{
+            return invalid("second", self.second);
+        }}
+        Ok(())
+    }}
 
-        while let Some(c) = chars.next() {{
-            if c == '\'' {{
-                if let Some(&'\'') = chars.peek() {{
-                    result.push('\'');
-                    chars.next();
-                }} else {{
-                    is_quoted = !is_quoted;
-                }}
-                continue;
-            }}
+    pub fn is_leap_year(&self) -> bool {{
+        _is_leap_year(self.year)
+    }}
 
-            if is_quoted {{
-                result.push(c);
-                continue;
+    /// Returns the number of days in the month.
+    pub fn days_in_month(&self) -> u32 {{
+        _days_in_month(self.year, self.month)
+    }}
+
+    /// Returns the day of the week, 0 for Sunday through 6 for Saturday, as
+    /// used to index [`Locale::days_wide`].
+    pub fn weekday(&self) -> u32 {{
+        _weekday(self.year, self.month, self.day)
+    }}
+
+    /// Returns the day of the year, starting at 1 for January 1st.
+    pub fn day_of_year(&self) -> u32 {{
+        _day_of_year(self.year, self.month, self.day)
+    }}
+
+    /// Returns the week-based year and the week of the year, counting weeks
+    /// with the rules of `week_data`. Days around New Year can belong to a
+    /// week of the neighbouring year.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{DateTime, WeekData}};
+    ///
+    /// let dt = DateTime::from_ymd(2026, 12, 31)?;
+    /// assert_eq!(dt.week_of_year(&WeekData::ISO), (2026, 53));
+    /// assert_eq!(dt.week_of_year(&Locale::en.week_data()), (2027, 1));
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn week_of_year(&self, week_data: &WeekData) -> (i32, u32) {{
+        _week_of_year(self.year, self.day_of_year(), self.weekday(), week_data.first_day, week_data.min_days)
+    }}
+
+    /// Returns the ISO 8601 week-based year and week number.
+    pub fn iso_week(&self) -> (i32, u32) {{
+        self.week_of_year(&WeekData::ISO)
+    }}
+
+    /// Converts seconds since the Unix epoch to a date and time in UTC.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let dt = DateTime::from_unix_timestamp(1_767_449_109)?;
+    /// assert_eq!(dt, DateTime::new(2026, 1, 3, 14, 5, 9)?);
+    /// assert_eq!(dt.unix_timestamp(), 1_767_449_109);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn from_unix_timestamp(seconds: i64) -> Result<Self, LocaleError> {{
+        let (year, _, _) = _civil_from_days(seconds.div_euclid(86_400));
+        if i32::try_from(year).is_err() {{
+            return Err(LocaleError::Overflow);
+        }}
+        Ok(_datetime_from_timestamp(seconds))
+    }}
+
+    /// Returns the seconds since the Unix epoch, reading the value as UTC.
+    pub fn unix_timestamp(&self) -> i64 {{
+        _timestamp(self)
+    }}
+
+    /// Adds a number of days, which may be negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let dt = DateTime::from_ymd(2024, 2, 28)?;
+    /// assert_eq!(dt.checked_add_days(2)?, DateTime::from_ymd(2024, 3, 1)?);
+    /// assert_eq!(dt.checked_sub_days(59)?, DateTime::from_ymd(2023, 12, 31)?);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn checked_add_days(&self, days: i64) -> Result<Self, LocaleError> {{
+        let seconds = days.checked_mul(86_400).ok_or(LocaleError::Overflow)?;
+        self._checked_add_seconds(seconds)
+    }}
+
+    /// Subtracts a number of days, which may be negative.
+    pub fn checked_sub_days(&self, days: i64) -> Result<Self, LocaleError> {{
+        self.checked_add_days(days.checked_neg().ok_or(LocaleError::Overflow)?)
+    }}
+
+    /// Adds a number of months, which may be negative. Days past the end of
+    /// the resulting month are clamped to its last day.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let dt = DateTime::from_ymd(2026, 1, 31)?;
+    /// assert_eq!(dt.checked_add_months(1)?, DateTime::from_ymd(2026, 2, 28)?);
+    /// assert_eq!(dt.checked_sub_months(2)?, DateTime::from_ymd(2025, 11, 30)?);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn checked_add_months(&self, months: i64) -> Result<Self, LocaleError> {{
+        let total = (i64::from(self.year) * 12 + i64::from(self.month) - 1)
+            .checked_add(months)
+            .ok_or(LocaleError::Overflow)?;
+        let year = i32::try_from(total.div_euclid(12)).map_err(|_| LocaleError::Overflow)?;
+        let month = total.rem_euclid(12) as u32 + 1;
+        Ok(DateTime {{
+            year,
+            month,
+            day: self.day.min(_days_in_month(year, month)),
+            ..*self
+        }})
+    }}
+
+    /// Subtracts a number of months, see [`DateTime::checked_add_months`].
+    pub fn checked_sub_months(&self, months: i64) -> Result<Self, LocaleError> {{
+        self.checked_add_months(months.checked_neg().ok_or(LocaleError::Overflow)?)
+    }}
+
+    /// Adds a duration, dropping fractions of a second.
+    pub fn checked_add(&self, duration: Duration) -> Result<Self, LocaleError> {{
+        let seconds = i64::try_from(duration.as_secs()).map_err(|_| LocaleError::Overflow)?;
+        self._checked_add_seconds(seconds)
+    }}
+
+    /// Subtracts a duration, dropping fractions of a second.
+    pub fn checked_sub(&self, duration: Duration) -> Result<Self, LocaleError> {{
+        let seconds = i64::try_from(duration.as_secs()).map_err(|_| LocaleError::Overflow)?;
+        self._checked_add_seconds(-seconds)
+    }}
+
+    fn _checked_add_seconds(&self, seconds: i64) -> Result<Self, LocaleError> {{
+        let timestamp = _timestamp(self).checked_add(seconds).ok_or(LocaleError::Overflow)?;
+        DateTime::from_unix_timestamp(timestamp)
+    }}
+}}
+
+/// Converts to UTC, dropping fractions of a second.
+///
+/// # Panics
+/// Panics if the year does not fit an `i32`, which only happens for times
+/// billions of years away.
+impl From<SystemTime> for DateTime {{
+    fn from(time: SystemTime) -> Self {{
+        let seconds = match time.duration_since(UNIX_EPOCH) {{
+            Ok(since) => since.as_secs() as i64,
+            Err(err) => {{
+                let before = err.duration();
+                -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
             }}
+        }};
+        DateTime::from_unix_timestamp(seconds).expect("SystemTime out of DateTime range")
+    }}
+}}
 
-            let mut count = 1;
-            while let Some(&next_c) = chars.peek() {{
-                if next_c == c {{
-                    count += 1;
-                    chars.next();
-                }} else {{
-                    break;
-                }}
+/// Reads the value as UTC.
+impl TryFrom<DateTime> for SystemTime {{
+    type Error = LocaleError;
+
+    fn try_from(dt: DateTime) -> Result<Self, LocaleError> {{
+        let seconds = dt.unix_timestamp();
+        let offset = Duration::from_secs(seconds.unsigned_abs());
+        if seconds >= 0 {{
+            UNIX_EPOCH.checked_add(offset)
+        }} else {{
+            UNIX_EPOCH.checked_sub(offset)
+        }}
+        .ok_or(LocaleError::Overflow)
+    }}
+}}
+
+#[cfg(feature = "chrono")]
+mod _chrono {{
+    use super::DateTime;
+    use crate::error::LocaleError;
+    use crate::time_zones::ZonedDateTime;
+    use chrono::{{Datelike, Offset, Timelike}};
+
+    /// Drops fractions of a second; a leap second reads as second 59.
+    impl From<chrono::NaiveDateTime> for DateTime {{
+        fn from(dt: chrono::NaiveDateTime) -> Self {{
+            DateTime {{
+                year: dt.year(),
+                month: dt.month(),
+                day: dt.day(),
+                hour: dt.hour(),
+                minute: dt.minute(),
+                second: dt.second(),
             }}
+        }}
+    }}
 
-            match c {{
-                'y' => {{
-                    let year_str = dt.year.to_string();
-                    if count == 2 && year_str.len() > 2 {{
-                        result.push_str(&year_str[year_str.len()-2..]);
-                    }} else {{
-                        result.push_str(&format!("{{:0width$}}", dt.year, width = count));
-                    }}
-                }},
-                'M' => {{
-                    match count {{
-                        1 | 2 => result.push_str(&format!("{{:0width$}}", dt.month, width = count)),
-                        3 => result.push_str(self.months_abbreviated().get((dt.month - 1) as usize).unwrap_or(&"")),
-                        _ => result.push_str(self.months_wide().get((dt.month - 1) as usize).unwrap_or(&"")),
-                    }}
-                }},
-                'd' => result.push_str(&format!("{{:0width$}}", dt.day, width = count)),
-                'H' => result.push_str(&format!("{{:0width$}}", dt.hour, width = count)),
-                'h' => {{
-                    let h12 = if dt.hour % 12 == 0 {{ 12 }} else {{ dt.hour % 12 }};
-                    result.push_str(&format!("{{:0width$}}", h12, width = count));
-                }},
-                'm' => result.push_str(&format!("{{:0width$}}", dt.minute, width = count)),
-                's' => result.push_str(&format!("{{:0width$}}", dt.second, width = count)),
-                'a' => {{
-                    let (am, pm) = self.am_pm();
-                    result.push_str(if dt.hour < 12 {{ am }} else {{ pm }});
-                }},
-                'E' => {{
-                    let dow = self._calculate_weekday(dt.year, dt.month, dt.day);
-                    result.push_str(self.days_wide().get(dow as usize).unwrap_or(&""));
-                }},
-                _ => {{
-                    for _ in 0..count {{ result.push(c); }}
-                }}
+    impl From<chrono::NaiveDate> for DateTime {{
+        fn from(date: chrono::NaiveDate) -> Self {{
+            DateTime::from(date.and_time(chrono::NaiveTime::MIN))
+        }}
+    }}
+
+    /// Takes the wall-clock time in the value's own time zone.
+    impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateTime {{
+        fn from(dt: chrono::DateTime<Tz>) -> Self {{
+            DateTime::from(dt.naive_local())
+        }}
+    }}
+
+    /// Keeps the wall-clock time and UTC offset; the zone itself is unnamed.
+    impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for ZonedDateTime {{
+        fn from(dt: chrono::DateTime<Tz>) -> Self {{
+            let offset = dt.offset().fix().local_minus_utc();
+            ZonedDateTime::with_offset(DateTime::from(dt.naive_local()), offset)
+        }}
+    }}
+
+    impl TryFrom<DateTime> for chrono::NaiveDateTime {{
+        type Error = LocaleError;
+
+        fn try_from(dt: DateTime) -> Result<Self, LocaleError> {{
+            chrono::NaiveDate::from_ymd_opt(dt.year, dt.month, dt.day)
+                .and_then(|date| date.and_hms_opt(dt.hour, dt.minute, dt.second))
+                .ok_or(LocaleError::Overflow)
+        }}
+    }}
+}}
+
+#[cfg(feature = "time")]
+mod _time {{
+    use super::DateTime;
+    use crate::error::LocaleError;
+    use crate::time_zones::ZonedDateTime;
+
+    /// Drops fractions of a second.
+    impl From<time::PrimitiveDateTime> for DateTime {{
+        fn from(dt: time::PrimitiveDateTime) -> Self {{
+            DateTime {{
+                year: dt.year(),
+                month: u8::from(dt.month()) as u32,
+                day: dt.day() as u32,
+                hour: dt.hour() as u32,
+                minute: dt.minute() as u32,
+                second: dt.second() as u32,
             }}
         }}
+    }}
 
-        #[cfg(feature = "nums")]
-        {{ result = crate::num_formats::translate_digits(result, self); }}
+    impl From<time::Date> for DateTime {{
+        fn from(date: time::Date) -> Self {{
+            DateTime::from(time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT))
+        }}
+    }}
 
-        result
+    /// Takes the wall-clock time at the value's own offset.
+    impl From<time::OffsetDateTime> for DateTime {{
+        fn from(dt: time::OffsetDateTime) -> Self {{
+            DateTime::from(time::PrimitiveDateTime::new(dt.date(), dt.time()))
+        }}
+    }}
+
+    /// Keeps the wall-clock time and UTC offset; the zone itself is unnamed.
+    impl From<time::OffsetDateTime> for ZonedDateTime {{
+        fn from(dt: time::OffsetDateTime) -> Self {{
+            ZonedDateTime::with_offset(DateTime::from(dt), dt.offset().whole_seconds())
+        }}
     }}
 
-    fn _calculate_weekday(&self, y: i32, m: u32, d: u32) -> u32 {{
-        // Sakamoto's algorithm (0=Sun, 1=Mon...)
-        static T: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
-        let mut y = y;
-        if m < 3 {{ y -= 1; }}
-        ( (y + y/4 - y/100 + y/400 + T[(m-1) as usize] as i32 + d as i32) % 7 ) as u32
+    impl TryFrom<DateTime> for time::PrimitiveDateTime {{
+        type Error = LocaleError;
+
+        fn try_from(dt: DateTime) -> Result<Self, LocaleError> {{
+            let month = time::Month::try_from(dt.month as u8).map_err(|_| LocaleError::Overflow)?;
+            let date = time::Date::from_calendar_date(dt.year, month, dt.day as u8)
+                .map_err(|_| LocaleError::Overflow)?;
+            let time = time::Time::from_hms(dt.hour as u8, dt.minute as u8, dt.second as u8)
+                .map_err(|_| LocaleError::Overflow)?;
+            Ok(time::PrimitiveDateTime::new(date, time))
+        }}
     }}
 }}
-"#,
-        months_wide_arms = months_wide_arms,
-        months_abbr_arms = months_abbr_arms,
-        days_wide_arms = days_wide_arms,
-        am_pm_arms = am_pm_arms,
-        date_format_arms = date_format_arms,
-        time_format_arms = time_format_arms
-    );
 
-    fs::write(output_path, code)?;
-    Ok(())
-}
+impl Locale {{
+    pub fn months_wide(&self) -> &'static [&'static str] {{
+        match self {{ {months_wide_arms} }}
+    }}
 
-fn extract_indexed_months(obj: &Value) -> Vec<String> {
-    let mut result = Vec::new();
-    if let Some(map) = obj.as_object() {
-        for i in 1..=12 {
-            let val = map
-                .get(&i.to_string())
-                .and_then(|v| v.as_str())
-                .unwrap_or("");
-            result.push(val.to_string());
-        }
-    }
-    result
-}
+    pub fn months_abbreviated(&self) -> &'static [&'static str] {{
+        match self {{ {months_abbr_arms} }}
+    }}
 
-fn extract_days(obj: &Value) -> Vec<String> {
-    let keys = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
-    let mut result = Vec::new();
-    if let Some(map) = obj.as_object() {
-        for key in keys {
-            let val = map.get(key).and_then(|v| v.as_str()).unwrap_or("");
-            result.push(val.to_string());
-        }
-    }
-    result
+    pub fn days_wide(&self) -> &'static [&'static str] {{
+        match self {{ {days_wide_arms} }}
+    }}
+
+    /// Returns the name of a month from 1 to 12 in the given context and
+    /// width, or `None` for other months. Months have no short names, so
+    /// `Short` gives the abbreviated ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{NameContext, NameWidth}};
+    ///
+    /// assert_eq!(Locale::pl.month_name(10, NameContext::Format, NameWidth::Wide), Some("października"));
+    /// assert_eq!(Locale::pl.month_name(10, NameContext::StandAlone, NameWidth::Wide), Some("październik"));
+    /// assert_eq!(Locale::en.month_name(13, NameContext::Format, NameWidth::Wide), None);
+    /// ```
+    pub fn month_name(&self, month: u32, context: NameContext, width: NameWidth) -> Option<&'static str> {{
+        let names = match (context, width) {{
+            (NameContext::Format, NameWidth::Wide) => self.months_wide(),
+            (NameContext::Format, NameWidth::Narrow) => self.months_narrow(),
+            (NameContext::Format, _) => self.months_abbreviated(),
+            (NameContext::StandAlone, NameWidth::Wide) => self.months_standalone_wide(),
+            (NameContext::StandAlone, NameWidth::Narrow) => self.months_standalone_narrow(),
+            (NameContext::StandAlone, _) => self.months_standalone_abbreviated(),
+        }};
+        names.get((month as usize).wrapping_sub(1)).copied()
+    }}
+
+    /// Returns the name of a weekday, 0 for Sunday through 6 for Saturday as
+    /// returned by [`DateTime::weekday`], in the given context and width, or
+    /// `None` for other values.
+    pub fn weekday_name(&self, weekday: u32, context: NameContext, width: NameWidth) -> Option<&'static str> {{
+        let names = match (context, width) {{
+            (NameContext::Format, NameWidth::Abbreviated) => self.days_abbreviated(),
+            (NameContext::Format, NameWidth::Wide) => self.days_wide(),
+            (NameContext::Format, NameWidth::Narrow) => self.days_narrow(),
+            (NameContext::Format, NameWidth::Short) => self.days_short(),
+            (NameContext::StandAlone, NameWidth::Abbreviated) => self.days_standalone_abbreviated(),
+            (NameContext::StandAlone, NameWidth::Wide) => self.days_standalone_wide(),
+            (NameContext::StandAlone, NameWidth::Narrow) => self.days_standalone_narrow(),
+            (NameContext::StandAlone, NameWidth::Short) => self.days_standalone_short(),
+        }};
+        names.get(weekday as usize).copied()
+    }}
+
+    /// Returns the AM and PM markers of the given width, e.g. `("AM", "PM")`,
+    /// or `("a", "p")` narrow in English.
+    pub fn am_pm(&self, width: NameWidth) -> (&'static str, &'static str) {{
+        match width {{
+            NameWidth::Wide => self._am_pm_wide(),
+            NameWidth::Narrow => self._am_pm_narrow(),
+            NameWidth::Abbreviated | NameWidth::Short => self._am_pm_abbreviated(),
+        }}
+    }}
+
+    /// Returns the period of the day of `dt` by the locale's CLDR day period
+    /// rules, as used by the `B` pattern field, e.g. `Morning1` at 9:00 and
+    /// `Noon` at exactly 12:00 in English. Locales without flexible day
+    /// periods return `Am` or `Pm`.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{DateTime, DayPeriod, NameWidth}};
+    ///
+    /// let evening = DateTime::new(2026, 1, 3, 19, 30, 0)?;
+    /// assert_eq!(Locale::en.day_period(&evening), DayPeriod::Evening1);
+    /// assert_eq!(Locale::en.day_period_name(DayPeriod::Evening1, NameWidth::Wide), "in the evening");
+    /// assert_eq!(Locale::en.format_with_pattern(&evening, "h:mm B"), "7:30 in the evening");
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn day_period(&self, dt: &DateTime) -> DayPeriod {{
+        self._day_period(dt, true)
+    }}
+
+    /// Returns the format name of a day period, e.g. `at night`. Periods the
+    /// locale has no name for use the AM or PM marker of their half of the day.
+    pub fn day_period_name(&self, period: DayPeriod, width: NameWidth) -> &'static str {{
+        let names = &self._day_period_names()[match width {{
+            NameWidth::Wide => 0,
+            NameWidth::Narrow => 2,
+            NameWidth::Abbreviated | NameWidth::Short => 1,
+        }}];
+        match names[period as usize] {{
+            "" => {{
+                let (am, pm) = self.am_pm(width);
+                match period {{
+                    DayPeriod::Midnight | DayPeriod::Am | DayPeriod::Morning1 | DayPeriod::Morning2 => am,
+                    _ => pm,
+                }}
+            }}
+            name => name,
+        }}
+    }}
+
+    /// Finds the day period of `dt`: exact times such as noon first, then,
+    /// if `flexible`, the locale's ranges, then AM or PM.
+    fn _day_period(&self, dt: &DateTime, flexible: bool) -> DayPeriod {{
+        let minute = (dt.hour * 60 + dt.minute) as u16;
+        let rules = self._day_period_rules();
+        let exact = rules
+            .iter()
+            .find(|&&(_, from, before)| from == before && from == minute && dt.second == 0);
+        let range = rules
+            .iter()
+            .filter(|_| flexible)
+            .find(|&&(_, from, before)| _in_day_period(minute, from, before));
+        match exact.or(range) {{
+            Some(&(period, _, _)) => period,
+            None if dt.hour < 12 => DayPeriod::Am,
+            None => DayPeriod::Pm,
+        }}
+    }}
+
+    fn _am_pm_wide(&self) -> (&'static str, &'static str) {{
+        match self {{ {am_pm_arms} }}
+    }}
+
+    fn _am_pm_abbreviated(&self) -> (&'static str, &'static str) {{
+        match self {{ {am_pm_abbr_arms} }}
+    }}
+
+    fn _am_pm_narrow(&self) -> (&'static str, &'static str) {{
+        match self {{ {am_pm_narrow_arms} }}
+    }}
+
+    /// Day period rules as `(period, from, before)` in minutes of the day.
+    fn _day_period_rules(&self) -> &'static [(DayPeriod, u16, u16)] {{
+        match self {{ {day_period_rule_arms} }}
+    }}
+
+    /// Wide, abbreviated and narrow day period names in `DayPeriod` order.
+    fn _day_period_names(&self) -> &'static [[&'static str; 12]; 3] {{
+        match self {{ {day_period_name_arms} }}
+    }}
+
+{name_table_fns}
+    /// Date patterns ordered short, medium, long, full.
+    fn _date_patterns(&self) -> &'static [&'static str; 4] {{
+        match self {{ {date_format_arms} }}
+    }}
+
+    /// Time patterns ordered short, medium, long, full.
+    fn _time_patterns(&self) -> &'static [&'static str; 4] {{
+        match self {{ {time_format_arms} }}
+    }}
+
+    fn _datetime_patterns(&self) -> &'static [&'static str; 4] {{
+        match self {{ {datetime_glue_arms} }}
+    }}
+
+    fn _datetime_at_time_patterns(&self) -> &'static [&'static str; 4] {{
+        match self {{ {datetime_at_time_arms} }}
+    }}
+
+    pub fn date_format_pattern(&self) -> &'static str {{
+        self.date_pattern(DateStyle::Medium)
+    }}
+
+    pub fn time_format_pattern(&self) -> &'static str {{
+        self.time_pattern(TimeStyle::Medium)
+    }}
+
+    /// Returns the week conventions of the locale's region, or of the likely
+    /// region of its language: `en` counts weeks from Sunday like the US,
+    /// `de` uses ISO 8601 weeks. Date patterns count the week fields `w`,
+    /// `W` and `Y` and the numeric weekday `e` with them.
+    pub fn week_data(&self) -> WeekData {{
+        WeekData::for_region(self._week_region())
+    }}
+
+    /// Returns the first day of the week, 0 for Sunday.
+    pub fn first_day_of_week(&self) -> u32 {{
+        self.week_data().first_day
+    }}
+
+    /// Returns whether `dt` falls on a weekend day in the locale's region.
+    pub fn is_weekend(&self, dt: &DateTime) -> bool {{
+        self.week_data().is_weekend(dt.weekday())
+    }}
+
+    /// Returns the preferred hour cycle of the locale's region from CLDR
+    /// `timeData`, e.g. `H12` for `en` and `H23` for `de`.
+    pub fn hour_cycle(&self) -> HourCycle {{
+        self._time_data().0
+    }}
+
+    /// Returns the hour cycles in use in the locale's region, preferred first.
+    pub fn allowed_hour_cycles(&self) -> &'static [HourCycle] {{
+        self._time_data().1
+    }}
+
+    /// Returns the time pattern of the given length on the given clock, e.g.
+    /// `HH:mm` for the short `en` pattern on `h23`. Switching between 12 and
+    /// 24 hours picks the locale's matching pattern, which adds or drops the
+    /// AM/PM marker.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{HourCycle, TimeStyle}};
+    ///
+    /// assert_eq!(Locale::en.time_pattern_with_hour_cycle(TimeStyle::Short, HourCycle::H23), "HH:mm");
+    /// assert_eq!(Locale::de.time_pattern_with_hour_cycle(TimeStyle::Short, HourCycle::H12), "h:mm\u{{202f}}a");
+    /// ```
+    pub fn time_pattern_with_hour_cycle(&self, style: TimeStyle, hour_cycle: HourCycle) -> String {{
+        let pattern = self.time_pattern(style);
+        let Some(current) = pattern.chars().find(|c| matches!(c, 'h' | 'H' | 'k' | 'K')) else {{
+            return pattern.to_string();
+        }};
+        if matches!(current, 'h' | 'K') == hour_cycle.is_12_hour() {{
+            return _with_hour_symbol(pattern, hour_cycle.symbol());
+        }}
+        let hour = if hour_cycle.is_12_hour() {{ 'h' }} else {{ 'H' }};
+        let skeleton: String = _pattern_items(pattern)
+            .into_iter()
+            .filter(|&(c, n)| n > 0 && !matches!(c, 'a' | 'b' | 'B'))
+            .flat_map(|(c, n)| {{
+                let c = if matches!(c, 'h' | 'H' | 'k' | 'K') {{ hour }} else {{ c }};
+                std::iter::repeat_n(c, n)
+            }})
+            .collect();
+        _with_hour_symbol(&self.pattern_for_skeleton(&skeleton), hour_cycle.symbol())
+    }}
+
+    /// Formats the time part of `dt` with the pattern of the given length on
+    /// the given clock, see [`Locale::time_pattern_with_hour_cycle`].
+    pub fn format_time_with_hour_cycle(&self, dt: &DateTime, style: TimeStyle, hour_cycle: HourCycle) -> String {{
+        self._parse_runtime_pattern(&self.time_pattern_with_hour_cycle(style, hour_cycle), dt)
+    }}
+
+    fn _time_data(&self) -> (HourCycle, &'static [HourCycle]) {{
+        match self {{ {time_data_arms} }}
+    }}
+
+    /// Region whose week data applies to the locale.
+    fn _week_region(&self) -> &'static str {{
+        match self {{ {week_region_arms} }}
+    }}
+
+    /// Returns the date pattern of the given length.
+    pub fn date_pattern(&self, style: DateStyle) -> &'static str {{
+        self._date_patterns()[style as usize]
+    }}
+
+    /// Returns the time pattern of the given length.
+    pub fn time_pattern(&self, style: TimeStyle) -> &'static str {{
+        self._time_patterns()[style as usize]
+    }}
+
+    /// Returns the pattern that glues a date of the given length and a time
+    /// together, e.g. `{{1}}, {{0}}` where `{{1}}` is the date and `{{0}}` the time.
+    pub fn datetime_format_pattern(&self, style: DateStyle) -> &'static str {{
+        self._datetime_patterns()[style as usize]
+    }}
+
+    /// Like [`Locale::datetime_format_pattern`], but for joining a date with a
+    /// specific point in time, e.g. `{{1}} 'at' {{0}}`.
+    pub fn datetime_at_time_pattern(&self, style: DateStyle) -> &'static str {{
+        self._datetime_at_time_patterns()[style as usize]
+    }}
+
+    pub fn format_date(&self, dt: &DateTime) -> String {{
+        self._parse_runtime_pattern(self.date_format_pattern(), dt)
+    }}
+
+    pub fn format_time(&self, dt: &DateTime) -> String {{
+        self._parse_runtime_pattern(self.time_format_pattern(), dt)
+    }}
+
+    /// Formats the date part of `dt` with the pattern of the given length.
+    pub fn format_date_with_style(&self, dt: &DateTime, style: DateStyle) -> String {{
+        self._parse_runtime_pattern(self.date_pattern(style), dt)
+    }}
+
+    /// Formats the time part of `dt` with the pattern of the given length.
+    /// A `DateTime` has no zone, so the zone that the long and full patterns
+    /// name is left out; see [`Locale::format_zoned`].
+    pub fn format_time_with_style(&self, dt: &DateTime, style: TimeStyle) -> String {{
+        self._parse_runtime_pattern(self.time_pattern(style), dt)
+    }}
+
+    /// Formats `dt` as a date and a time of the given lengths, joined with the
+    /// locale's [`Locale::datetime_at_time_pattern`] for the date length.
+    /// Zone fields are left out as in [`Locale::format_time_with_style`].
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{DateStyle, DateTime, TimeStyle}};
+    ///
+    /// let dt = DateTime::new(2026, 1, 3, 14, 5, 9)?;
+    /// assert_eq!(
+    ///     Locale::en.format_datetime(&dt, DateStyle::Long, TimeStyle::Short),
+    ///     "January 3, 2026 at 2:05\u{{202f}}PM"
+    /// );
+    /// assert_eq!(
+    ///     Locale::de.format_datetime(&dt, DateStyle::Medium, TimeStyle::Short),
+    ///     "03.01.2026, 14:05"
+    /// );
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_datetime(&self, dt: &DateTime, date_style: DateStyle, time_style: TimeStyle) -> String {{
+        self._parse_runtime_pattern(&self._datetime_pattern(date_style, time_style), dt)
+    }}
+
+    /// Like [`Locale::format_datetime`] with the digits of a numbering system
+    /// instead of the locale's default ones. Algorithmic systems such as
+    /// `roman` keep the default digits.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{DateStyle, DateTime, TimeStyle}};
+    /// use locale_rs::num_formats::{{NumberingSystem, OtherNumberingSystem}};
+    ///
+    /// let dt = DateTime::new(2026, 1, 3, 14, 5, 9)?;
+    /// let native = Locale::hi.other_numbering_system(OtherNumberingSystem::Native);
+    /// assert_eq!(
+    ///     Locale::hi.format_datetime_in_numbering_system(&dt, DateStyle::Short, TimeStyle::Short, native),
+    ///     "३/१/२६, २:०५ pm"
+    /// );
+    /// assert_eq!(
+    ///     Locale::ar_EG.format_datetime_in_numbering_system(&dt, DateStyle::Short, TimeStyle::Short, NumberingSystem::Latn),
+    ///     "3\u{{200f}}/1\u{{200f}}/2026، 2:05 م"
+    /// );
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    #[cfg(feature = "nums")]
+    pub fn format_datetime_in_numbering_system(
+        &self,
+        dt: &DateTime,
+        date_style: DateStyle,
+        time_style: TimeStyle,
+        system: crate::num_formats::NumberingSystem,
+    ) -> String {{
+        self.format_with_pattern_in_numbering_system(dt, &self._datetime_pattern(date_style, time_style), system)
+    }}
+
+    /// Like [`Locale::format_datetime`] for a zoned date and time; the long and
+    /// full time styles name the zone.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::{{DateStyle, DateTime, TimeStyle}};
+    /// use locale_rs::time_zones::{{TimeZone, ZonedDateTime}};
+    ///
+    /// let dt = DateTime::new(2026, 1, 3, 14, 5, 9)?;
+    /// let zdt = ZonedDateTime::new(dt, TimeZone::America_Los_Angeles);
+    /// assert_eq!(
+    ///     Locale::en.format_zoned(&zdt, DateStyle::Medium, TimeStyle::Full),
+    ///     "Jan 3, 2026, 2:05:09\u{{202f}}PM Pacific Standard Time"
+    /// );
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_zoned(&self, zdt: &ZonedDateTime, date_style: DateStyle, time_style: TimeStyle) -> String {{
+        self._parse_zoned_pattern(&self._datetime_pattern(date_style, time_style), zdt)
+    }}
+
+    fn _datetime_pattern(&self, date_style: DateStyle, time_style: TimeStyle) -> String {{
+        self.datetime_at_time_pattern(date_style)
+            .replace("{{1}}", self.date_pattern(date_style))
+            .replace("{{0}}", self.time_pattern(time_style))
+    }}
+
+    /// Returns the CLDR `availableFormats` of the locale as `(skeleton, pattern)` pairs.
+    pub fn available_formats(&self) -> &'static [(&'static str, &'static str)] {{
+        match self {{ {available_formats_arms} }}
+    }}
+
+    /// Returns the locale's best pattern for a CLDR skeleton such as `"MMMd"` or `"yMMMEd"`.
+    ///
+    /// The closest entry of [`Locale::available_formats`] is chosen and its field
+    /// widths are adjusted to the request. Skeletons mixing date and time fields
+    /// without a direct match are split and joined with [`Locale::datetime_format_pattern`]
+    /// of the length implied by the date fields.
+    /// The hour symbols `j`, `J` and `C` stand for the locale's preferred hour format.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    ///
+    /// assert_eq!(Locale::en.pattern_for_skeleton("MMMd"), "MMM d");
+    /// assert_eq!(Locale::de.pattern_for_skeleton("MMMMd"), "d. MMMM");
+    /// assert_eq!(Locale::en.pattern_for_skeleton("jm"), "h:mm\u{{202f}}a");
+    /// ```
+    pub fn pattern_for_skeleton(&self, skeleton: &str) -> String {{
+        let requested = self._requested_skeleton_fields(skeleton);
+        if let Some(pattern) = self._pattern_for_fields(&requested, false) {{
+            return pattern;
+        }}
+
+        let (date, time): (Vec<_>, Vec<_>) = requested
+            .iter()
+            .partition(|(c, _)| _skeleton_field_kind(*c).is_some_and(|k| k < _FIRST_TIME_KIND));
+        if !date.is_empty() && !time.is_empty() {{
+            let date_pattern = self._pattern_for_fields(&date, true);
+            let time_pattern = self._pattern_for_fields(&time, true);
+            if let (Some(d), Some(t)) = (date_pattern, time_pattern) {{
+                return self
+                    .datetime_format_pattern(_skeleton_date_style(&date))
+                    .replace("{{1}}", &d)
+                    .replace("{{0}}", &t);
+            }}
+        }}
+
+        self._pattern_for_fields(&requested, true)
+            .unwrap_or_else(|| skeleton.to_string())
+    }}
+
+    /// Formats `dt` with the locale's best pattern for `skeleton`,
+    /// see [`Locale::pattern_for_skeleton`].
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let dt = DateTime::new(2026, 1, 3, 14, 5, 9)?;
+    /// assert_eq!(Locale::en.format_skeleton(&dt, "MMMd"), "Jan 3");
+    /// assert_eq!(Locale::de.format_skeleton(&dt, "MMMMd"), "3. Januar");
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_skeleton(&self, dt: &DateTime, skeleton: &str) -> String {{
+        self._parse_runtime_pattern(&self.pattern_for_skeleton(skeleton), dt)
+    }}
+
+    /// Returns the CLDR `intervalFormats` of the locale as `(skeleton, patterns)`
+    /// pairs, with one pattern per greatest different field, e.g. `'d'` for
+    /// `MMM d – d` in `MMMd`.
+    pub fn interval_formats(&self) -> &'static [(&'static str, &'static [(char, &'static str)])] {{
+        match self {{ {interval_formats_arms} }}
+    }}
+
+    /// Returns the pattern joining two formatted dates when no interval format
+    /// applies, e.g. `{{0}} – {{1}}`.
+    pub fn interval_format_fallback(&self) -> &'static str {{
+        match self {{ {interval_fallback_arms} }}
+    }}
+
+    /// Formats the range from `start` to `end` with the locale's interval
+    /// format for `skeleton`, showing the fields both ends share only once,
+    /// e.g. `Jan 3 – 7, 2026` for `yMMMd` in English.
+    ///
+    /// The pattern is picked by the greatest calendar field that differs
+    /// between the two ends. If nothing the skeleton shows differs, `start` is
+    /// formatted on its own. Without a matching interval format, both ends are
+    /// formatted with the skeleton and joined with
+    /// [`Locale::interval_format_fallback`]; the year is then added if the
+    /// years differ, and a date is added to time skeletons if the days differ.
+    /// A skeleton with a date and a time on a single day shows the date once
+    /// and the times as an interval, joined with
+    /// [`Locale::datetime_format_pattern`].
+    ///
+    /// If `end` is before `start`, the two are swapped, so the range always
+    /// reads from the earlier to the later value.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let start = DateTime::from_ymd(2026, 1, 3)?;
+    /// let end = DateTime::from_ymd(2026, 1, 7)?;
+    /// assert_eq!(Locale::en.format_interval(&start, &end, "yMMMd"), "Jan 3\u{{2009}}–\u{{2009}}7, 2026");
+    /// assert_eq!(Locale::de.format_interval(&start, &end, "yMMMd"), "3.–7. Jan. 2026");
+    ///
+    /// let start = DateTime::new(2026, 1, 3, 10, 0, 0)?;
+    /// let end = DateTime::new(2026, 1, 3, 14, 30, 0)?;
+    /// assert_eq!(
+    ///     Locale::en.format_interval(&start, &end, "yMMMdhm"),
+    ///     "Jan 3, 2026, 10:00\u{{202f}}AM\u{{2009}}–\u{{2009}}2:30\u{{202f}}PM"
+    /// );
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_interval(&self, start: &DateTime, end: &DateTime, skeleton: &str) -> String {{
+        if end < start {{
+            return self.format_interval(end, start, skeleton);
+        }}
+        let Some(difference) = _greatest_difference(start, end) else {{
+            return self.format_skeleton(start, skeleton);
+        }};
+        let requested = self._requested_skeleton_fields(skeleton);
+        let rank = _interval_rank(difference);
+        if requested.iter().all(|(c, _)| _interval_field_rank(*c).is_none_or(|r| r < rank)) {{
+            return self.format_skeleton(start, skeleton);
+        }}
+
+        if let Some(pattern) = self._interval_pattern(&requested, difference) {{
+            let (first, second) = _split_interval_pattern(&pattern);
+            return self._parse_runtime_pattern(first, start) + &self._parse_runtime_pattern(second, end);
+        }}
+
+        let (date, time): (Vec<_>, Vec<_>) = requested
+            .iter()
+            .partition(|(c, _)| _skeleton_field_kind(*c).is_some_and(|k| k < _FIRST_TIME_KIND));
+        if rank >= _interval_rank('a') && !date.is_empty() && !time.is_empty() {{
+            let skeleton_of = |fields: &[(char, usize)]| -> String {{
+                fields.iter().map(|&(c, n)| c.to_string().repeat(n)).collect()
+            }};
+            let quote = |text: String| format!("'{{}}'", text.replace('\'', "''"));
+            let pattern = self
+                .datetime_format_pattern(_skeleton_date_style(&date))
+                .replace("{{1}}", &quote(self.format_skeleton(start, &skeleton_of(&date))))
+                .replace("{{0}}", &quote(self.format_interval(start, end, &skeleton_of(&time))));
+            return self._parse_runtime_pattern(&pattern, start);
+        }}
+
+        // Show the differing fields the skeleton leaves out.
+        let has = |kinds: &str| requested.iter().any(|(c, _)| kinds.contains(*c));
+        let skeleton = if !has("GyYurQqMLwWEcedDF") && rank < _interval_rank('a') {{
+            format!("yMd{{}}", skeleton)
+        }} else if difference == 'y' && !has("yYur") {{
+            format!("y{{}}", skeleton)
+        }} else {{
+            skeleton.to_string()
+        }};
+        self.interval_format_fallback()
+            .replace("{{0}}", &self.format_skeleton(start, &skeleton))
+            .replace("{{1}}", &self.format_skeleton(end, &skeleton))
+    }}
+
+    /// Formats `dt` with an explicit CLDR/LDML pattern, e.g. `"EEEE, LLLL d"`.
+    ///
+    /// Letters are interpreted as LDML date fields using this locale's names;
+    /// text in single quotes is copied verbatim.
+    ///
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let dt = DateTime::new(2026, 1, 3, 14, 5, 9)?;
+    /// assert_eq!(Locale::en.format_with_pattern(&dt, "QQQ y G"), "Q1 2026 AD");
+    /// assert_eq!(Locale::pl.format_with_pattern(&dt, "LLLL"), "styczeń");
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_with_pattern(&self, dt: &DateTime, pattern: &str) -> String {{
+        self._parse_runtime_pattern(pattern, dt)
+    }}
+
+    /// Like [`Locale::format_with_pattern`] with the digits of a numbering
+    /// system; see [`Locale::format_datetime_in_numbering_system`].
+    #[cfg(feature = "nums")]
+    pub fn format_with_pattern_in_numbering_system(
+        &self,
+        dt: &DateTime,
+        pattern: &str,
+        system: crate::num_formats::NumberingSystem,
+    ) -> String {{
+        let result = self._format_pattern_fields(
+            &_without_zone_fields(pattern),
+            &ZonedDateTime::with_offset(*dt, 0),
+            None,
+        );
+        match system.digits() {{
+            Some(digits) => crate::num_formats::_replace_digits(result, &digits),
+            None => crate::num_formats::translate_digits(result, self),
+        }}
+    }}
+
+    /// Formats a zoned date and time with an explicit CLDR pattern. Zone fields
+    /// use the locale's zone names, e.g. `zzzz` for `Central European Summer Time`,
+    /// `v` for the generic name, `O` for `GMT+2` and `VVVV` for `Paris Time`.
+    ///
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::datetime_formats::DateTime;
+    /// use locale_rs::time_zones::{{TimeZone, ZonedDateTime}};
+    ///
+    /// let dt = DateTime::new(2026, 7, 3, 14, 5, 9)?;
+    /// let zdt = ZonedDateTime::new(dt, TimeZone::Europe_Berlin);
+    /// assert_eq!(Locale::de.format_zoned_with_pattern(&zdt, "HH:mm z"), "14:05 MESZ");
+    /// assert_eq!(Locale::en.format_zoned_with_pattern(&zdt, "HH:mm O"), "14:05 GMT+2");
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_zoned_with_pattern(&self, zdt: &ZonedDateTime, pattern: &str) -> String {{
+        self._parse_zoned_pattern(pattern, zdt)
+    }}
+
+    /// Formats a zoned date and time with a CLDR skeleton, e.g. `"jmz"`.
+    pub fn format_zoned_skeleton(&self, zdt: &ZonedDateTime, skeleton: &str) -> String {{
+        self._parse_zoned_pattern(&self.pattern_for_skeleton(skeleton), zdt)
+    }}
+
+    /// Parses a date written in one of the locale's date patterns, trying the
+    /// short, medium, long and full styles in turn. The time is midnight.
+    ///
+    /// See [`Locale::parse_with_pattern`] for what the patterns accept.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    ///
+    /// let dt = Locale::de.parse_date("18.10.2026")?;
+    /// assert_eq!((dt.year(), dt.month(), dt.day()), (2026, 10, 18));
+    /// assert_eq!(Locale::en.parse_date("10/18/26")?.year(), 2026);
+    /// assert_eq!(Locale::fr.parse_date("18 oct. 2026")?.month(), 10);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn parse_date(&self, input: &str) -> Result<DateTime, LocaleError> {{
+        self._parse_any_pattern(input, self._date_patterns())
+    }}
+
+    /// Parses a time written in one of the locale's time patterns, trying the
+    /// short, medium, long and full styles in turn. The date is 1970-01-01.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    ///
+    /// let dt = Locale::en.parse_time("2:05 pm")?;
+    /// assert_eq!((dt.hour(), dt.minute()), (14, 5));
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn parse_time(&self, input: &str) -> Result<DateTime, LocaleError> {{
+        self._parse_any_pattern(input, self._time_patterns())
+    }}
+
+    /// Parses `input` against an explicit CLDR/LDML pattern.
+    ///
+    /// Month, weekday, quarter and era names and AM/PM markers match the
+    /// locale's names of any width case-insensitively, and a trailing period
+    /// of an abbreviation may be left out. Digits may be ASCII or, with the
+    /// `nums` feature, the locale's native digits. Whitespace and bidi marks
+    /// in the pattern match any run of them, including none. Two-digit years
+    /// fall between 1950 and 2049, see [`Locale::parse_with_pattern_and_century`].
+    /// Fields missing from the pattern are taken from 1970-01-01 00:00:00;
+    /// weekdays and quarters are read but not checked against the date.
+    ///
+    /// # Errors
+    /// Returns [`LocaleError::DateTimeParse`] with the byte offset in `input`
+    /// where it stopped matching the pattern, or where an out of range field
+    /// starts. Time zone fields are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::error::LocaleError;
+    ///
+    /// let dt = Locale::en.parse_with_pattern("Saturday 3 JANUARY 2026", "EEEE d MMMM y")?;
+    /// assert_eq!((dt.year(), dt.month(), dt.day()), (2026, 1, 3));
+    /// assert_eq!(
+    ///     Locale::en.parse_with_pattern("2026-02-30", "y-MM-dd"),
+    ///     Err(LocaleError::DateTimeParse {{ position: 8, reason: "day out of range".to_string() }})
+    /// );
+    /// # Ok::<(), LocaleError>(())
+    /// ```
+    pub fn parse_with_pattern(&self, input: &str, pattern: &str) -> Result<DateTime, LocaleError> {{
+        self.parse_with_pattern_and_century(input, pattern, _DEFAULT_CENTURY_START)
+    }}
+
+    /// Parses like [`Locale::parse_with_pattern`], reading two-digit years as
+    /// the hundred years from `century_start` to `century_start + 99`.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    ///
+    /// assert_eq!(Locale::en.parse_with_pattern("3/1/49", "M/d/yy")?.year(), 2049);
+    /// assert_eq!(Locale::en.parse_with_pattern_and_century("3/1/49", "M/d/yy", 1900)?.year(), 1949);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn parse_with_pattern_and_century(
+        &self,
+        input: &str,
+        pattern: &str,
+        century_start: i32,
+    ) -> Result<DateTime, LocaleError> {{
+        self._match_pattern(input, pattern)
+            .and_then(|fields| fields.resolve(century_start))
+            .map_err(|(position, reason)| LocaleError::DateTimeParse {{ position, reason }})
+    }}
+
+    /// Parses a requested skeleton, resolving the preferred hour symbols and
+    /// dropping `a`, which the locale patterns add on their own.
+    fn _requested_skeleton_fields(&self, skeleton: &str) -> Vec<(char, usize)> {{
+        let preferred_hour = self.hour_cycle().symbol();
+
+        _skeleton_fields(skeleton)
+            .into_iter()
+            .filter(|(c, _)| *c != 'a')
+            .map(|(c, n)| match c {{
+                'j' | 'J' | 'C' => (preferred_hour, n),
+                _ => (c, n),
+            }})
+            .collect()
+    }}
+
+    /// Picks the closest available format for `requested`. Unless `allow_missing`
+    /// is set, only formats that cover every requested field are accepted.
+    fn _pattern_for_fields(&self, requested: &[(char, usize)], allow_missing: bool) -> Option<String> {{
+        let mut best: Option<(u32, &'static str, &'static str)> = None;
+        for &(skeleton, pattern) in self.available_formats() {{
+            let available = _skeleton_fields(skeleton);
+            let Some((missing, distance)) = _skeleton_distance(requested, &available) else {{
+                continue;
+            }};
+            if missing > 0 && !allow_missing {{
+                continue;
+            }}
+            let score = missing * 0x1000 + distance;
+            if best.is_none_or(|(s, _, _)| score < s) {{
+                best = Some((score, skeleton, pattern));
+            }}
+        }}
+        best.map(|(_, skeleton, pattern)| {{
+            _adjust_field_widths(pattern, requested, &_skeleton_fields(skeleton))
+        }})
+    }}
+
+    /// Picks the interval format covering every requested field and returns
+    /// its pattern for the greatest different field, stretched to the
+    /// requested widths.
+    fn _interval_pattern(&self, requested: &[(char, usize)], difference: char) -> Option<String> {{
+        let formats = self.interval_formats();
+        let mut best: Option<(u32, usize)> = None;
+        for (i, &(skeleton, _)) in formats.iter().enumerate() {{
+            let Some((0, distance)) = _skeleton_distance(requested, &_skeleton_fields(skeleton)) else {{
+                continue;
+            }};
+            if best.is_none_or(|(d, _)| distance < d) {{
+                best = Some((distance, i));
+            }}
+        }}
+        let (skeleton, patterns) = formats[best?.1];
+        let keys: &[char] = match difference {{
+            'a' => &['a', 'B', 'h', 'H', 'k', 'K'],
+            'h' => &['h', 'H', 'k', 'K'],
+            'G' => &['G', 'y'],
+            _ => std::slice::from_ref(&difference),
+        }};
+        let pattern = keys
+            .iter()
+            .find_map(|key| patterns.iter().find(|(c, _)| c == key))
+            .map(|&(_, pattern)| pattern)?;
+        Some(_adjust_field_widths(pattern, requested, &_skeleton_fields(skeleton)))
+    }}
+
+    /// Formats a value without a zone, leaving out the zone fields.
+    fn _parse_runtime_pattern(&self, pattern: &str, dt: &DateTime) -> String {{
+        self._parse_zoned_pattern(&_without_zone_fields(pattern), &ZonedDateTime::with_offset(*dt, 0))
+    }}
+
+    fn _parse_zoned_pattern(&self, pattern: &str, zdt: &ZonedDateTime) -> String {{
+        self._parse_calendar_pattern(pattern, zdt, None)
+    }}
+
+    /// Formats `pattern` with the era, year, month and day of `date` when given.
+    pub(crate) fn _parse_calendar_pattern(
+        &self,
+        pattern: &str,
+        zdt: &ZonedDateTime,
+        date: Option<&CalendarDate>,
+    ) -> String {{
+        let result = self._format_pattern_fields(pattern, zdt, date);
+
+        #[cfg(feature = "nums")]
+        let result = crate::num_formats::translate_digits(result, self);
+
+        result
+    }}
+
+    /// Formats the fields of `pattern` with ASCII digits.
+    fn _format_pattern_fields(
+        &self,
+        pattern: &str,
+        zdt: &ZonedDateTime,
+        date: Option<&CalendarDate>,
+    ) -> String {{
+        let mut result = String::new();
+        let mut chars = pattern.chars().peekable();
+        let mut is_quoted = false;
+
+        while let Some(c) = chars.next() {{
+            if c == '\'' {{
+                if let Some(&'\'') = chars.peek() {{
+                    result.push('\'');
+                    chars.next();
+                }} else {{
+                    is_quoted = !is_quoted;
+                }}
+                continue;
+            }}
+
+            if is_quoted {{
+                result.push(c);
+                continue;
+            }}
+
+            let mut count = 1;
+            while let Some(&next_c) = chars.peek() {{
+                if next_c == c {{
+                    count += 1;
+                    chars.next();
+                }} else {{
+                    break;
+                }}
+            }}
+
+            self._format_field(&mut result, c, count, zdt, date);
+        }}
+
+        result
+    }}
+
+    /// Appends a single pattern field following the LDML date field symbol table.
+    /// Unknown letters and other characters are copied unchanged.
+    fn _format_field(
+        &self,
+        out: &mut String,
+        c: char,
+        count: usize,
+        zdt: &ZonedDateTime,
+        date: Option<&CalendarDate>,
+    ) {{
+        if let Some(date) = date
+            && self._format_calendar_field(out, c, count, date)
+        {{
+            return;
+        }}
+        let dt = &zdt.datetime;
+        let pad = |value: i64| format!("{{:0width$}}", value, width = count);
+        let name = |names: &'static [&'static str], index: usize| names.get(index).copied().unwrap_or("");
+        let month = (dt.month as usize).wrapping_sub(1);
+        let weekday = || dt.weekday();
+        let year_field = |year: i32| {{
+            if count == 2 {{ format!("{{:02}}", year.rem_euclid(100)) }} else {{ pad(year as i64) }}
+        }};
+
+        let text = match c {{
+            'G' => {{
+                let era = usize::from(dt.year > 0);
+                let names = match count {{
+                    4 => self.eras_wide(),
+                    5 => self.eras_narrow(),
+                    _ => self.eras_abbreviated(),
+                }};
+                name(names, era).to_string()
+            }}
+            // Era years: 1 BC is year 0 of the proleptic Gregorian calendar.
+            'y' => year_field(if dt.year > 0 {{ dt.year }} else {{ 1 - dt.year }}),
+            'Y' => year_field(dt.week_of_year(&self.week_data()).0),
+            'u' => pad(dt.year as i64),
+            // Gregorian years have no cyclic names; use the numeric year.
+            'U' => year_field(if dt.year > 0 {{ dt.year }} else {{ 1 - dt.year }}),
+            'r' => pad(dt.year as i64),
+            'Q' | 'q' => {{
+                let quarter = month / 3;
+                let names = match (c, count) {{
+                    (_, 1 | 2) => return out.push_str(&pad(quarter as i64 + 1)),
+                    ('Q', 3) => self.quarters_abbreviated(),
+                    ('Q', 4) => self.quarters_wide(),
+                    ('Q', _) => self.quarters_narrow(),
+                    (_, 3) => self.quarters_standalone_abbreviated(),
+                    (_, 4) => self.quarters_standalone_wide(),
+                    _ => self.quarters_standalone_narrow(),
+                }};
+                name(names, quarter).to_string()
+            }}
+            'M' | 'L' => {{
+                let context = if c == 'M' {{ NameContext::Format }} else {{ NameContext::StandAlone }};
+                let width = match count {{
+                    1 | 2 => return out.push_str(&pad(dt.month as i64)),
+                    3 => NameWidth::Abbreviated,
+                    4 => NameWidth::Wide,
+                    _ => NameWidth::Narrow,
+                }};
+                self.month_name(dt.month, context, width).unwrap_or("").to_string()
+            }}
+            'w' => pad(dt.week_of_year(&self.week_data()).1 as i64),
+            'W' => {{
+                let week_data = self.week_data();
+                pad(_week_of_month(dt.day, weekday(), week_data.first_day, week_data.min_days) as i64)
+            }}
+            'd' => pad(dt.day as i64),
+            'D' => pad(_day_of_year(dt.year, dt.month, dt.day) as i64),
+            'F' => pad((dt.day as i64 - 1) / 7 + 1),
+            // Modified Julian day: days since 1858-11-17.
+            'g' => pad(_days_from_civil(dt.year as i64, dt.month, dt.day) + 40_587),
+            'E' | 'e' | 'c' => {{
+                let weekday = weekday();
+                let context = if c == 'c' {{ NameContext::StandAlone }} else {{ NameContext::Format }};
+                let width = match (c, count) {{
+                    // Local day of week, counted from the first day of the week.
+                    ('e' | 'c', 1 | 2) => {{
+                        let first_day = self.first_day_of_week();
+                        return out.push_str(&pad(((weekday + 7 - first_day) % 7 + 1) as i64));
+                    }}
+                    (_, 4) => NameWidth::Wide,
+                    (_, 5) => NameWidth::Narrow,
+                    (_, 6) => NameWidth::Short,
+                    _ => NameWidth::Abbreviated,
+                }};
+                self.weekday_name(weekday, context, width).unwrap_or("").to_string()
+            }}
+            'a' | 'b' | 'B' => {{
+                let width = match count {{
+                    4 => NameWidth::Wide,
+                    5 => NameWidth::Narrow,
+                    _ => NameWidth::Abbreviated,
+                }};
+                if c == 'a' {{
+                    let (am, pm) = self.am_pm(width);
+                    (if dt.hour < 12 {{ am }} else {{ pm }}).to_string()
+                }} else {{
+                    // `b` only adds noon and midnight to AM/PM
+                    let period = self._day_period(dt, c == 'B');
+                    self.day_period_name(period, width).to_string()
+                }}
+            }}
+            'H' => pad(dt.hour as i64),
+            'h' => pad(if dt.hour.is_multiple_of(12) {{ 12 }} else {{ dt.hour % 12 }} as i64),
+            'K' => pad((dt.hour % 12) as i64),
+            'k' => pad(if dt.hour == 0 {{ 24 }} else {{ dt.hour }} as i64),
+            'm' => pad(dt.minute as i64),
+            's' => pad(dt.second as i64),
+            // DateTime has whole-second precision.
+            'S' => "0".repeat(count),
+            'A' => pad(((dt.hour * 60 + dt.minute) * 60 + dt.second) as i64 * 1000),
+            // Only zoned values get here with zone fields, see `_without_zone_fields`.
+            'z' | 'Z' | 'O' | 'v' | 'V' | 'X' | 'x' => self._format_time_zone(c, count, zdt),
+            _ => c.to_string().repeat(count),
+        }};
+        out.push_str(&text);
+    }}
+
+    /// Tries each pattern in turn. An out of range field in input that matched
+    /// a pattern is reported first, otherwise the error that got furthest.
+    fn _parse_any_pattern(&self, input: &str, patterns: &[&str]) -> Result<DateTime, LocaleError> {{
+        let mut furthest = (0, String::new());
+        let mut out_of_range = None;
+        for pattern in patterns {{
+            match self._match_pattern(input, pattern) {{
+                Ok(fields) => match fields.resolve(_DEFAULT_CENTURY_START) {{
+                    Ok(dt) => return Ok(dt),
+                    Err(err) => {{
+                        out_of_range.get_or_insert(err);
+                    }}
+                }},
+                Err(err) if furthest.1.is_empty() || err.0 > furthest.0 => furthest = err,
+                Err(_) => {{}}
+            }}
+        }}
+        let (position, reason) = out_of_range.unwrap_or(furthest);
+        Err(LocaleError::DateTimeParse {{ position, reason }})
+    }}
+
+    /// Reads the fields of `input` against `pattern` without checking their ranges.
+    fn _match_pattern(&self, input: &str, pattern: &str) -> Result<_ParsedFields, (usize, String)> {{
+        let items = _pattern_items(pattern);
+        let mut fields = _ParsedFields::default();
+        let mut pos = _skip_spacing(input);
+
+        for (i, &(c, count)) in items.iter().enumerate() {{
+            let rest = &input[pos..];
+            if count == 0 {{
+                if _is_spacing(c) {{
+                    pos += _skip_spacing(rest);
+                    continue;
+                }}
+                match _match_prefix(rest, &c.to_string(), |c| c) {{
+                    Some(len) => pos += len,
+                    None => return Err((pos, format!("expected '{{}}'", c))),
+                }}
+                continue;
+            }}
+
+            if !_is_text_field(c, count) {{
+                // Adjacent numeric fields such as `yyyyMMdd` use their exact width.
+                let width = match items.get(i + 1) {{
+                    Some(&(next, next_count)) if next_count > 0 && !_is_text_field(next, next_count) =>
+                    {{
+                        count
+                    }}
+                    _ => 9,
+                }};
+                let (value, len) = self._parse_number(rest, width).ok_or_else(|| {{
+                    (pos, format!("expected a number for '{{}}'", c.to_string().repeat(count)))
+                }})?;
+                let field = Some((value, pos));
+                match c {{
+                    'y' | 'u' | 'Y' => {{
+                        fields.year = field;
+                        fields.two_digit_year = count == 2 && rest[..len].chars().count() == 2;
+                    }}
+                    'M' | 'L' => fields.month = field,
+                    'd' => fields.day = field,
+                    'H' => fields.hour = field,
+                    'k' => fields.hour = Some((value % 24, pos)),
+                    'h' | 'K' => {{
+                        fields.hour = field;
+                        fields.twelve_hour = true;
+                    }}
+                    'm' => fields.minute = field,
+                    's' => fields.second = field,
+                    'D' | 'w' | 'W' | 'F' | 'e' | 'c' | 'Q' | 'q' | 'S' | 'A' => {{}}
+                    _ => return Err((pos, format!("unsupported field '{{}}'", c))),
+                }}
+                pos += len;
+                continue;
+            }}
+
+            let lists: Vec<&'static [&'static str]> = match c {{
+                'M' | 'L' => vec![
+                    self.months_wide(),
+                    self.months_abbreviated(),
+                    self.months_standalone_wide(),
+                    self.months_standalone_abbreviated(),
+                ],
+                'E' | 'c' | 'e' => vec![
+                    self.days_wide(),
+                    self.days_abbreviated(),
+                    self.days_short(),
+                    self.days_standalone_wide(),
+                    self.days_standalone_abbreviated(),
+                    self.days_standalone_short(),
+                ],
+                'Q' | 'q' => vec![
+                    self.quarters_wide(),
+                    self.quarters_abbreviated(),
+                    self.quarters_standalone_wide(),
+                    self.quarters_standalone_abbreviated(),
+                ],
+                'G' => vec![self.eras_wide(), self.eras_abbreviated(), self.eras_narrow()],
+                'b' | 'B' => {{
+                    let names = self._day_period_names();
+                    let Some((index, len)) = _match_name(
+                        rest,
+                        &[&names[0][..], &names[1][..], &names[2][..]],
+                        |c| self._ascii_digit(c),
+                    ) else {{
+                        return Err((pos, "expected a day period".to_string()));
+                    }};
+                    fields.day_period = match index {{
+                        1 => Some((0, 720)),
+                        3 => Some((720, 1440)),
+                        _ => self
+                            ._day_period_rules()
+                            .iter()
+                            .find(|(period, _, _)| *period as usize == index)
+                            .map(|&(_, from, before)| (from, before)),
+                    }};
+                    pos += len;
+                    continue;
+                }}
+                'a' => {{
+                    let markers = |(am, pm): (&'static str, &'static str)| [am, pm];
+                    let (wide, abbreviated, narrow) = (
+                        markers(self.am_pm(NameWidth::Wide)),
+                        markers(self.am_pm(NameWidth::Abbreviated)),
+                        markers(self.am_pm(NameWidth::Narrow)),
+                    );
+                    let Some((index, len)) = _match_name(
+                        rest,
+                        &[&wide[..], &abbreviated[..], &narrow[..]],
+                        |c| self._ascii_digit(c),
+                    ) else {{
+                        return Err((pos, "expected AM or PM".to_string()));
+                    }};
+                    fields.pm = Some(index == 1);
+                    pos += len;
+                    continue;
+                }}
+                _ => return Err((pos, format!("unsupported field '{{}}'", c))),
+            }};
+            let Some((index, len)) = _match_name(rest, &lists, |c| self._ascii_digit(c)) else {{
+                return Err((pos, format!("expected a name for '{{}}'", c.to_string().repeat(count))));
+            }};
+            match c {{
+                'M' | 'L' => fields.month = Some((index as u32 + 1, pos)),
+                'G' => fields.before_common_era = index == 0,
+                _ => {{}}
+            }}
+            pos += len;
+        }}
+
+        pos += _skip_spacing(&input[pos..]);
+        if pos < input.len() {{
+            return Err((pos, "unexpected trailing text".to_string()));
+        }}
+        Ok(fields)
+    }}
+
+    /// Maps a native digit of the locale to its ASCII digit.
+    fn _ascii_digit(&self, c: char) -> char {{
+        #[cfg(feature = "nums")]
+        if let Some(digits) = self.digits()
+            && let Some(position) = digits.iter().position(|&d| d == c)
+        {{
+            return (b'0' + position as u8) as char;
+        }}
+        c
+    }}
+
+    /// Reads up to `width` ASCII or native digits, returning the value and its byte length.
+    fn _parse_number(&self, input: &str, width: usize) -> Option<(u32, usize)> {{
+        let mut value: u32 = 0;
+        let mut len = 0;
+        for c in input.chars().take(width) {{
+            let Some(d) = self._ascii_digit(c).to_digit(10) else {{ break }};
+            value = value.checked_mul(10)?.checked_add(d)?;
+            len += c.len_utf8();
+        }}
+        (len > 0).then_some((value, len))
+    }}
+
+}}
+
+/// Day of the week, 0 for Sunday; 1970-01-01 was a Thursday.
+fn _weekday(year: i32, month: u32, day: u32) -> u32 {{
+    (_days_from_civil(i64::from(year), month, day) + 4).rem_euclid(7) as u32
+}}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar.
+pub(crate) fn _days_from_civil(year: i64, month: u32, day: u32) -> i64 {{
+    let year = if month <= 2 {{ year - 1 }} else {{ year }};
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (if month > 2 {{ month - 3 }} else {{ month + 9 }}) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}}
+
+/// Inverse of [`_days_from_civil`].
+pub(crate) fn _civil_from_days(days: i64) -> (i64, u32, u32) {{
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 {{ mp + 3 }} else {{ mp - 9 }} as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}}
+
+/// Seconds since the Unix epoch of a date and time read as UTC.
+pub(crate) fn _timestamp(dt: &DateTime) -> i64 {{
+    _days_from_civil(i64::from(dt.year), dt.month, dt.day) * 86_400
+        + i64::from(dt.hour) * 3600
+        + i64::from(dt.minute) * 60
+        + i64::from(dt.second)
+}}
+
+pub(crate) fn _datetime_from_timestamp(timestamp: i64) -> DateTime {{
+    let (year, month, day) = _civil_from_days(timestamp.div_euclid(86_400));
+    let seconds = timestamp.rem_euclid(86_400) as u32;
+    DateTime {{
+        year: year as i32,
+        month,
+        day,
+        hour: seconds / 3600,
+        minute: seconds / 60 % 60,
+        second: seconds % 60,
+    }}
+}}
+
+fn _is_leap_year(year: i32) -> bool {{
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}}
+
+fn _day_of_year(year: i32, month: u32, day: u32) -> u32 {{
+    const DAYS_BEFORE_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let leap_day = u32::from(month > 2 && _is_leap_year(year));
+    DAYS_BEFORE_MONTH.get((month as usize).wrapping_sub(1)).copied().unwrap_or(0) + leap_day + day
+}}
+
+/// Returns the day of year (possibly before Jan 1) on which week 1 starts, given
+/// the position of Jan 1 within its week.
+fn _week_one_start(jan1_offset: i64, min_days: u32) -> i64 {{
+    1 - jan1_offset + if 7 - jan1_offset >= min_days as i64 {{ 0 }} else {{ 7 }}
+}}
+
+/// Returns `(week-based year, week of year)` for a day given by its day of year
+/// and weekday (0 = Sunday). Weeks start on `first_day`, and week 1 is the first
+/// week with at least `min_days` days in the new year; ISO 8601 uses `(1, 4)`.
+fn _week_of_year(year: i32, day_of_year: u32, weekday: u32, first_day: u32, min_days: u32) -> (i32, u32) {{
+    let days_in_year = |y: i32| -> i64 {{ if _is_leap_year(y) {{ 366 }} else {{ 365 }} }};
+    let doy = day_of_year as i64;
+    let offset = (weekday as i64 - first_day as i64).rem_euclid(7);
+    let jan1_offset = (offset - (doy - 1)).rem_euclid(7);
+    let start = _week_one_start(jan1_offset, min_days);
+
+    if doy < start {{
+        let previous_days = days_in_year(year - 1);
+        let previous_start = _week_one_start((jan1_offset - previous_days).rem_euclid(7), min_days);
+        return (year - 1, ((doy + previous_days - previous_start) / 7 + 1) as u32);
+    }}
+    let next_start = _week_one_start((jan1_offset + days_in_year(year)).rem_euclid(7), min_days);
+    if doy >= days_in_year(year) + next_start {{
+        return (year + 1, 1);
+    }}
+    (year, ((doy - start) / 7 + 1) as u32)
+}}
+
+/// Returns the week of month with the same rules as [`_week_of_year`];
+/// days before the first full week belong to week 0.
+fn _week_of_month(day: u32, weekday: u32, first_day: u32, min_days: u32) -> u32 {{
+    let offset = (weekday as i64 - first_day as i64).rem_euclid(7);
+    let first_offset = (offset - (day as i64 - 1)).rem_euclid(7);
+    let start = _week_one_start(first_offset, min_days);
+    ((day as i64 - start).div_euclid(7) + 1) as u32
+}}
+
+/// Groups of interchangeable pattern symbols used for skeleton matching.
+/// Groups before [`_FIRST_TIME_KIND`] are date fields.
+const _SKELETON_FIELD_KINDS: [&str; 16] = [
+    "G", "yYur", "Qq", "ML", "w", "W", "Ece", "d", "D", "F", "abB", "hHkK", "m", "s", "S", "zZOvVXx",
+];
+const _FIRST_TIME_KIND: usize = 10;
+
+/// Picks the glue length for a skeleton's date fields: wide month and weekday
+/// select full, a wide month long, an abbreviated month medium, anything else short.
+fn _skeleton_date_style(date: &[(char, usize)]) -> DateStyle {{
+    let width = |kinds: &str| {{
+        date.iter()
+            .filter(|(c, _)| kinds.contains(*c))
+            .map(|(_, n)| *n)
+            .max()
+            .unwrap_or(0)
+    }};
+    match (width("ML"), width("Ec")) {{
+        (4.., 4..) => DateStyle::Full,
+        (4.., _) => DateStyle::Long,
+        (3, _) => DateStyle::Medium,
+        _ => DateStyle::Short,
+    }}
+}}
+
+fn _skeleton_field_kind(c: char) -> Option<usize> {{
+    _SKELETON_FIELD_KINDS.iter().position(|kind| kind.contains(c))
+}}
+
+fn _is_text_field(c: char, count: usize) -> bool {{
+    match c {{
+        'M' | 'L' | 'Q' | 'q' | 'c' | 'e' => count >= 3,
+        'G' | 'E' | 'a' | 'b' | 'B' | 'z' | 'O' | 'v' | 'V' => true,
+        _ => false,
+    }}
+}}
+
+/// Splits a skeleton into `(symbol, count)` runs.
+fn _skeleton_fields(skeleton: &str) -> Vec<(char, usize)> {{
+    let mut fields: Vec<(char, usize)> = Vec::new();
+    for c in skeleton.chars().filter(|c| c.is_ascii_alphabetic()) {{
+        match fields.last_mut() {{
+            Some((last, count)) if *last == c => *count += 1,
+            _ => fields.push((c, 1)),
+        }}
+    }}
+    fields
+}}
+
+/// Returns the number of requested fields missing from `available` and the
+/// distance of the matched ones, or `None` if `available` has extra fields.
+fn _skeleton_distance(requested: &[(char, usize)], available: &[(char, usize)]) -> Option<(u32, u32)> {{
+    let find = |fields: &[(char, usize)], kind: Option<usize>| {{
+        fields.iter().find(|(c, _)| _skeleton_field_kind(*c) == kind).copied()
+    }};
+
+    if available.iter().any(|(c, _)| find(requested, _skeleton_field_kind(*c)).is_none()) {{
+        return None;
+    }}
+
+    let mut missing = 0;
+    let mut distance = 0;
+    for &(c, n) in requested {{
+        match find(available, _skeleton_field_kind(c)) {{
+            None => missing += 1,
+            Some((ac, an)) => {{
+                if _is_text_field(c, n) != _is_text_field(ac, an) {{
+                    distance += 0x100;
+                }} else if c != ac {{
+                    distance += 0x10;
+                }}
+                distance += n.abs_diff(an) as u32;
+            }}
+        }}
+    }}
+    Some((missing, distance))
+}}
+
+/// Stretches the fields of a matched pattern to the requested widths, e.g.
+/// `MMM d` becomes `MMMM d` for `MMMMd`. Hours, minutes and seconds keep the
+/// pattern's width, and numeric fields never turn into text or vice versa.
+/// Zone fields take the requested symbol, so `Hmz` turns `HH:mm v` into `HH:mm z`.
+fn _adjust_field_widths(pattern: &str, requested: &[(char, usize)], matched: &[(char, usize)]) -> String {{
+    let mut result = String::new();
+    let mut chars = pattern.chars().peekable();
+    let mut is_quoted = false;
+
+    while let Some(c) = chars.next() {{
+        if c == '\'' {{
+            is_quoted = !is_quoted;
+            result.push(c);
+            continue;
+        }}
+        if is_quoted || !c.is_ascii_alphabetic() {{
+            result.push(c);
+            continue;
+        }}
+
+        let mut count = 1;
+        while chars.peek() == Some(&c) {{
+            count += 1;
+            chars.next();
+        }}
+
+        let kind = _skeleton_field_kind(c);
+        if kind == Some(_SKELETON_FIELD_KINDS.len() - 1)
+            && let Some(&(rc, rn)) = requested.iter().find(|(r, _)| _skeleton_field_kind(*r) == kind)
+        {{
+            result.extend(std::iter::repeat_n(rc, rn));
+            continue;
+        }}
+        let width = match (
+            requested.iter().find(|(r, _)| _skeleton_field_kind(*r) == kind),
+            matched.iter().find(|(m, _)| _skeleton_field_kind(*m) == kind),
+        ) {{
+            (Some(&(rc, rn)), Some(&(_, mn)))
+                if !matches!(c, 'h' | 'H' | 'k' | 'K' | 'm' | 's')
+                    && rn != mn
+                    && _is_text_field(rc, rn) == _is_text_field(c, count) =>
+            {{
+                rn
+            }}
+            _ => count,
+        }};
+        result.extend(std::iter::repeat_n(c, width));
+    }}
+
+    result
+}}
+
+/// Replaces the symbol of every hour field outside quotes with `symbol`.
+fn _with_hour_symbol(pattern: &str, symbol: char) -> String {{
+    let mut is_quoted = false;
+    pattern
+        .chars()
+        .map(|c| {{
+            if c == '\'' {{
+                is_quoted = !is_quoted;
+            }}
+            if !is_quoted && matches!(c, 'h' | 'H' | 'k' | 'K') {{ symbol }} else {{ c }}
+        }})
+        .collect()
+}}
+
+/// Returns the value of a `-u-` extension keyword of a BCP 47 language tag,
+/// e.g. `buddhist` for `ca` in `th-TH-u-ca-buddhist`, lowercased.
+pub(crate) fn _unicode_keyword(tag: &str, key: &str) -> Option<String> {{
+    let tag = tag.replace('_', "-").to_ascii_lowercase();
+    let subtags: Vec<&str> = tag.split('-').collect();
+    let start = subtags.iter().position(|s| *s == "u")?;
+    let mut keyword = None;
+    let mut value: Vec<&str> = Vec::new();
+    for &subtag in &subtags[start + 1..] {{
+        if subtag.len() == 1 {{
+            break;
+        }}
+        if subtag.len() == 2 {{
+            if keyword == Some(key) {{
+                break;
+            }}
+            keyword = Some(subtag);
+            value.clear();
+        }} else if keyword == Some(key) {{
+            value.push(subtag);
+        }}
+    }}
+    (keyword == Some(key)).then(|| value.join("-"))
+}}
+
+/// Removes the zone fields of `pattern` for a value without a zone, with the
+/// spaces and brackets that set them off, e.g. `h:mm:ss a zzzz` becomes
+/// `h:mm:ss a` and `H:mm:ss (zzzz)` becomes `H:mm:ss`.
+pub(crate) fn _without_zone_fields(pattern: &str) -> String {{
+    let is_separator = |c: char| c.is_whitespace() || matches!(c, ',' | '،' | '(' | ')' | '（' | '）');
+    let mut result = String::new();
+    let mut chars = pattern.chars().peekable();
+    let mut is_quoted = false;
+    // Whitespace that preceded a removed zone, restored if more text follows.
+    let mut gap: Option<String> = None;
+
+    while let Some(c) = chars.next() {{
+        if !is_quoted && matches!(c, 'z' | 'Z' | 'O' | 'v' | 'V' | 'X' | 'x') {{
+            while chars.next_if_eq(&c).is_some() {{}}
+            let kept = result.trim_end_matches(is_separator).len();
+            let removed: String = result[kept..].chars().filter(|c| c.is_whitespace()).collect();
+            result.truncate(kept);
+            gap = Some(gap.unwrap_or_default() + &removed);
+            continue;
+        }}
+        if gap.is_some() && !is_quoted && is_separator(c) {{
+            continue;
+        }}
+        if let Some(gap) = gap.take()
+            && !result.is_empty()
+        {{
+            result.push_str(&gap);
+        }}
+        if c == '\'' {{
+            is_quoted = !is_quoted;
+        }}
+        result.push(c);
+    }}
+
+    result
+}}
+
+/// Splits a pattern into `(symbol, count)` fields; quoted text and other
+/// literals become single characters with a count of zero.
+fn _pattern_items(pattern: &str) -> Vec<(char, usize)> {{
+    let mut items: Vec<(char, usize)> = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    let mut is_quoted = false;
+
+    while let Some(c) = chars.next() {{
+        if c == '\'' {{
+            if let Some(&'\'') = chars.peek() {{
+                items.push(('\'', 0));
+                chars.next();
+            }} else {{
+                is_quoted = !is_quoted;
+            }}
+            continue;
+        }}
+        if is_quoted || !c.is_ascii_alphabetic() {{
+            items.push((c, 0));
+            continue;
+        }}
+        match items.last_mut() {{
+            Some((last, count)) if *last == c && *count > 0 => *count += 1,
+            _ => items.push((c, 1)),
+        }}
+    }}
+    items
+}}
+
+/// Whitespace and bidi marks, which match any run of themselves when parsing.
+fn _is_spacing(c: char) -> bool {{
+    c.is_whitespace() || matches!(c, '\u{{200e}}' | '\u{{200f}}' | '\u{{061c}}')
+}}
+
+fn _skip_spacing(input: &str) -> usize {{
+    input.len() - input.trim_start_matches(_is_spacing).len()
+}}
+
+/// Returns the byte length of `name` at the start of `input`, ignoring case
+/// and treating all whitespace alike. Input characters also match after
+/// mapping them with `fold`.
+fn _match_prefix(input: &str, name: &str, fold: impl Fn(char) -> char) -> Option<usize> {{
+    let mut chars = input.char_indices();
+    for n in name.chars() {{
+        let (_, c) = chars.next()?;
+        let same = |c: char| c.to_lowercase().eq(n.to_lowercase());
+        if !(same(c) || same(fold(c)) || c.is_whitespace() && n.is_whitespace()) {{
+            return None;
+        }}
+    }}
+    Some(chars.next().map_or(input.len(), |(i, _)| i))
+}}
+
+/// Finds the longest name of any list at the start of `input`, returning its
+/// index within the list and the matched byte length. Abbreviations also
+/// match without their trailing period.
+fn _match_name(
+    input: &str,
+    lists: &[&[&str]],
+    fold: impl Fn(char) -> char + Copy,
+) -> Option<(usize, usize)> {{
+    lists
+        .iter()
+        .flat_map(|names| names.iter().enumerate())
+        .filter(|(_, name)| !name.is_empty())
+        .flat_map(|(index, name)| {{
+            let short = name.strip_suffix('.').filter(|s| !s.is_empty());
+            [Some(*name), short]
+                .into_iter()
+                .flatten()
+                .filter_map(move |name| _match_prefix(input, name, fold).map(|len| (index, len)))
+        }})
+        .max_by_key(|&(_, len)| len)
+}}
+
+/// First year two-digit years are read into when no century is given.
+const _DEFAULT_CENTURY_START: i32 = 1950;
+
+/// Field values read by [`Locale::parse_with_pattern`], each with its byte offset.
+#[derive(Default)]
+struct _ParsedFields {{
+    year: Option<(u32, usize)>,
+    two_digit_year: bool,
+    before_common_era: bool,
+    month: Option<(u32, usize)>,
+    day: Option<(u32, usize)>,
+    hour: Option<(u32, usize)>,
+    twelve_hour: bool,
+    pm: Option<bool>,
+    /// Range of a parsed day period in minutes of the day, see `_day_period_rules`.
+    day_period: Option<(u16, u16)>,
+    minute: Option<(u32, usize)>,
+    second: Option<(u32, usize)>,
+}}
+
+impl _ParsedFields {{
+    fn resolve(&self, century_start: i32) -> Result<DateTime, (usize, String)> {{
+        let check = |field: Option<(u32, usize)>, default: u32, range: std::ops::RangeInclusive<u32>, name: &str| {{
+            match field {{
+                None => Ok(default),
+                Some((value, _)) if range.contains(&value) => Ok(value),
+                Some((_, pos)) => Err((pos, format!("{{}} out of range", name))),
+            }}
+        }};
+
+        let mut year = match self.year {{
+            None => 1970,
+            Some((value, pos)) => {{
+                let mut year = i64::from(value);
+                if self.two_digit_year {{
+                    let start = i64::from(century_start);
+                    year += start.div_euclid(100) * 100;
+                    if year < start {{
+                        year += 100;
+                    }}
+                }}
+                i32::try_from(year).map_err(|_| (pos, "year out of range".to_string()))?
+            }}
+        }};
+        if self.before_common_era {{
+            year = 1 - year;
+        }}
+
+        let month = check(self.month, 1, 1..=12, "month")?;
+        let day = check(self.day, 1, 1..=_days_in_month(year, month), "day")?;
+        let mut hour = if self.twelve_hour {{
+            check(self.hour, 0, 0..=12, "hour")? % 12
+        }} else {{
+            check(self.hour, 0, 0..=23, "hour")?
+        }};
+        if self.pm == Some(true) && hour < 12 {{
+            hour += 12;
+        }}
+        let minute = check(self.minute, 0, 0..=59, "minute")?;
+        // A day period picks the half of the day it covers, e.g. 7 in the evening is 19:00
+        if let (true, None, Some((from, before))) = (self.twelve_hour, self.pm, self.day_period) {{
+            let in_period = |hour: u32| {{
+                let time = (hour * 60 + minute) as u16;
+                if from == before {{ time == from }} else {{ _in_day_period(time, from, before) }}
+            }};
+            if !in_period(hour) && in_period(hour + 12) {{
+                hour += 12;
+            }}
+        }}
+        Ok(DateTime {{
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second: check(self.second, 0, 0..=59, "second")?,
+        }})
+    }}
+}}
+
+/// Returns whether a minute of the day falls in `from..before`, which wraps
+/// past midnight when `before` is earlier, e.g. 21:00 to 6:00.
+fn _in_day_period(minute: u16, from: u16, before: u16) -> bool {{
+    if from < before {{ (from..before).contains(&minute) }} else {{ from != before && (minute >= from || minute < before) }}
+}}
+
+fn _days_in_month(year: i32, month: u32) -> u32 {{
+    match month {{
+        2 if _is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }}
+}}
+
+/// Returns the greatest calendar field that differs between two date-times
+/// as an interval format key: `G`, `y`, `M`, `d`, `a` (AM/PM), `h`, `m` or `s`.
+fn _greatest_difference(start: &DateTime, end: &DateTime) -> Option<char> {{
+    if (start.year > 0) != (end.year > 0) {{
+        Some('G')
+    }} else if start.year != end.year {{
+        Some('y')
+    }} else if start.month != end.month {{
+        Some('M')
+    }} else if start.day != end.day {{
+        Some('d')
+    }} else if (start.hour < 12) != (end.hour < 12) {{
+        Some('a')
+    }} else if start.hour != end.hour {{
+        Some('h')
+    }} else if start.minute != end.minute {{
+        Some('m')
+    }} else if start.second != end.second {{
+        Some('s')
+    }} else {{
+        None
+    }}
+}}
+
+/// Orders interval format keys from the greatest field down.
+fn _interval_rank(key: char) -> u32 {{
+    _interval_field_rank(key).unwrap_or(u32::MAX)
+}}
+
+/// Rank of a skeleton symbol on the [`_interval_rank`] scale, `None` for
+/// fields that do not tell two instants apart, such as zones.
+fn _interval_field_rank(c: char) -> Option<u32> {{
+    match c {{
+        'G' => Some(0),
+        'y' | 'Y' | 'u' | 'r' => Some(1),
+        'Q' | 'q' | 'M' | 'L' => Some(2),
+        'w' | 'W' | 'E' | 'c' | 'e' | 'd' | 'D' | 'F' => Some(3),
+        'a' | 'b' | 'B' => Some(4),
+        'h' | 'H' | 'k' | 'K' => Some(5),
+        'm' => Some(6),
+        's' | 'S' => Some(7),
+        _ => None,
+    }}
+}}
+
+/// Splits an interval pattern where its first field repeats, e.g.
+/// `MMM d – d` into `MMM d – ` for the start and `d` for the end.
+fn _split_interval_pattern(pattern: &str) -> (&str, &str) {{
+    let mut seen: Vec<Option<usize>> = Vec::new();
+    let mut is_quoted = false;
+    let mut previous = None;
+    for (i, c) in pattern.char_indices() {{
+        if c == '\'' {{
+            is_quoted = !is_quoted;
+            previous = None;
+            continue;
+        }}
+        if is_quoted || !c.is_ascii_alphabetic() {{
+            previous = None;
+            continue;
+        }}
+        if previous == Some(c) {{
+            continue;
+        }}
+        previous = Some(c);
+        let kind = _skeleton_field_kind(c);
+        if seen.contains(&kind) {{
+            return pattern.split_at(i);
+        }}
+        seen.push(kind);
+    }}
+    (pattern, "")
+}}
+
+{statics}"#,
+        months_wide_arms = months_wide_arms,
+        months_abbr_arms = months_abbr_arms,
+        days_wide_arms = days_wide_arms,
+        am_pm_arms = am_pm_arms,
+        date_format_arms = date_format_arms,
+        time_format_arms = time_format_arms,
+        available_formats_arms = available_formats_arms,
+        datetime_glue_arms = datetime_glue_arms,
+        datetime_at_time_arms = datetime_at_time_arms,
+        am_pm_abbr_arms = am_pm_abbr_arms,
+        am_pm_narrow_arms = am_pm_narrow_arms,
+        name_table_fns = name_table_fns,
+        interval_formats_arms = interval_formats_arms,
+        interval_fallback_arms = interval_fallback_arms,
+        statics = statics,
+        week_data_arms = week_data_arms,
+        world_week_data = world_week_data,
+        week_region_arms = week_region_arms,
+        day_period_rule_arms = day_period_rule_arms,
+        day_period_name_arms = day_period_name_arms,
+        time_data_arms = time_data_arms
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+fn extract_indexed_months(obj: &Value) -> Vec<String> {
+    extract_indexed(obj, 1..=12)
+}
+
+fn extract_indexed(obj: &Value, keys: std::ops::RangeInclusive<u32>) -> Vec<String> {
+    let mut result = Vec::new();
+    if let Some(map) = obj.as_object() {
+        for i in keys {
+            let val = map
+                .get(&i.to_string())
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            result.push(val.to_string());
+        }
+    }
+    result
+}
+
+fn extract_days(obj: &Value) -> Vec<String> {
+    let mut result = Vec::new();
+    if let Some(map) = obj.as_object() {
+        for key in WEEKDAYS {
+            let val = map.get(key).and_then(|v| v.as_str()).unwrap_or("");
+            result.push(val.to_string());
+        }
+    }
+    result
+}
+
+/// Collects `(skeleton, pattern)` pairs, skipping `-alt-` variants and keeping
+/// only the `other` form of plural-dependent skeletons.
+fn extract_available_formats(obj: &Value) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    if let Some(map) = obj.as_object() {
+        for (key, val) in map {
+            let skeleton = match key.split_once("-count-") {
+                Some((skeleton, "other")) => skeleton,
+                Some(_) => continue,
+                None if key.contains("-alt-") => continue,
+                None => key.as_str(),
+            };
+            if let Some(pattern) = val.as_str() {
+                result.push((skeleton.to_string(), pattern.to_string()));
+            }
+        }
+    }
+    result
+}
+
+/// Collects `(skeleton, [(greatest difference, pattern)])` entries as Rust
+/// literals, leaving out the fallback pattern.
+fn extract_interval_formats(obj: &Value) -> Vec<String> {
+    let mut result = Vec::new();
+    if let Some(map) = obj.as_object() {
+        for (skeleton, patterns) in map {
+            let Some(patterns) = patterns.as_object() else {
+                continue;
+            };
+            let patterns: Vec<String> = patterns
+                .iter()
+                .filter_map(|(field, pattern)| {
+                    Some(format!(
+                        "({:?}, {:?})",
+                        field.chars().next()?,
+                        pattern.as_str()?
+                    ))
+                })
+                .collect();
+            result.push(format!("({:?}, &[{}])", skeleton, patterns.join(", ")));
+        }
+    }
+    result
+}
+
+/// Emits `(DayPeriod, from, before)` entries in minutes of the day for a CLDR
+/// rule set; `at` rules such as noon have `from` equal to `before`.
+fn day_period_rule_entries(rules: &Value) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let minutes = |time: &str| -> Result<u16, Box<dyn std::error::Error>> {
+        let (hour, minute) = time.split_once(':').ok_or("Invalid day period time")?;
+        Ok(hour.parse::<u16>()? * 60 + minute.parse::<u16>()?)
+    };
+    let mut entries = Vec::new();
+    for (key, variant) in DAY_PERIODS {
+        let rule = &rules[key];
+        if let Some(at) = rule["_at"].as_str() {
+            let at = minutes(at)?;
+            entries.push(format!("(DayPeriod::{}, {}, {})", variant, at, at));
+        } else if let (Some(from), Some(before)) =
+            (rule["_from"].as_str(), rule["_before"].as_str())
+        {
+            entries.push(format!(
+                "(DayPeriod::{}, {}, {})",
+                variant,
+                minutes(from)?,
+                minutes(before)?
+            ));
+        }
+    }
+    if entries.is_empty() {
+        entries.push("(DayPeriod::Am, 0, 720)".to_string());
+        entries.push("(DayPeriod::Pm, 720, 1440)".to_string());
+    }
+    Ok(entries)
+}
+
+/// Emits the wide, abbreviated and narrow format names in `DayPeriod` order.
+/// Narrow names fall back to abbreviated ones, and abbreviated to wide.
+fn day_period_names(periods: &Value) -> String {
+    let widths = ["wide", "abbreviated", "narrow"];
+    let tables: Vec<String> = (0..widths.len())
+        .map(|width| {
+            let names = DAY_PERIODS.map(|(key, _)| {
+                widths[..=width]
+                    .iter()
+                    .rev()
+                    .find_map(|w| periods[w][key].as_str())
+                    .unwrap_or(match key {
+                        "am" => "AM",
+                        "pm" => "PM",
+                        _ => "",
+                    })
+            });
+            format!("{:?}", names)
+        })
+        .collect();
+    format!("[{}]", tables.join(", "))
+}
+
+/// Maps a CLDR `timeData` hour symbol to its `HourCycle` variant. The `hb` and
+/// `hB` forms use a 12-hour clock with day periods instead of AM/PM.
+fn hour_cycle_variant(symbol: &str) -> &'static str {
+    match symbol.chars().next() {
+        Some('K') => "H11",
+        Some('h') => "H12",
+        Some('k') => "H24",
+        _ => "H23",
+    }
+}
+
+/// Emits a `WeekData` literal from `[firstDay, minDays, weekendStart, weekendEnd]`.
+fn week_data_literal(data: [u32; 4]) -> String {
+    format!(
+        "WeekData {{ first_day: {}, min_days: {}, weekend_start: {}, weekend_end: {} }}",
+        data[0], data[1], data[2], data[3]
+    )
+}
+
+/// Reads the `short`, `medium`, `long` and `full` entries of a CLDR length map.
+fn extract_lengths(obj: &Value, defaults: &[&str; 4]) -> [String; 4] {
+    let lengths = ["short", "medium", "long", "full"];
+    std::array::from_fn(|i| obj[lengths[i]].as_str().unwrap_or(defaults[i]).to_string())
 }
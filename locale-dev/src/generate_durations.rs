@@ -0,0 +1,318 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use zip::ZipArchive;
+
+use crate::{intern_static, sanitize_variant};
+
+/// CLDR unit keys in `_DurationPatterns::units` order.
+const UNITS: [&str; 5] = [
+    "duration-week",
+    "duration-day",
+    "duration-hour",
+    "duration-minute",
+    "duration-second",
+];
+/// CLDR unit lengths and list pattern types in `DurationStyle` order.
+const WIDTHS: [(&str, &str); 3] = [
+    ("long", "listPattern-type-unit"),
+    ("short", "listPattern-type-unit-short"),
+    ("narrow", "listPattern-type-unit-narrow"),
+];
+/// Plural categories in `PluralCategory` order.
+const COUNTS: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+/// List pattern parts in `_DurationPatterns::lists` order.
+const LIST_PARTS: [&str; 4] = ["2", "start", "middle", "end"];
+
+pub fn run(
+    zip_buffer: Vec<u8>,
+    _asset_name: &str,
+    output_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
+
+    // 1. Process Locales
+    let mut locales = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.name().contains("/main/") && file.is_dir() {
+            let parts: Vec<&str> = file.name().split('/').collect();
+            if let Some(idx) = parts.iter().position(|&r| r == "main")
+                && let Some(name) = parts.get(idx + 1)
+                && !name.is_empty()
+                && !locales.contains(&(*name).to_string())
+            {
+                locales.push((*name).to_string());
+            }
+        }
+    }
+    locales.sort();
+
+    let mut statics = String::new();
+    let mut static_ids: HashMap<String, usize> = HashMap::new();
+    let mut locale_arms = String::new();
+    for name in &locales {
+        let var = sanitize_variant(name);
+        let read = |archive: &mut ZipArchive<Cursor<Vec<u8>>>, path: String| -> Value {
+            match archive.by_name(&path) {
+                Ok(mut file) => serde_json::from_reader(&mut file).unwrap_or(Value::Null),
+                Err(_) => Value::Null,
+            }
+        };
+        let units = read(
+            &mut archive,
+            format!("cldr-units-full/main/{}/units.json", name),
+        );
+        let units = &units["main"][name]["units"];
+        let lists = read(
+            &mut archive,
+            format!("cldr-misc-full/main/{}/listPatterns.json", name),
+        );
+        let lists = &lists["main"][name]["listPatterns"];
+
+        let id = intern_static(
+            &mut static_ids,
+            &mut statics,
+            "_DURATION_PATTERNS",
+            "_DurationPatterns",
+            &patterns_body(units, lists),
+        );
+        locale_arms.push_str(&format!("            Locale::{} => &{},\n", var, id));
+    }
+
+    let code = format!(
+        r#"// Auto-generated. DO NOT EDIT.
+use std::time::Duration;
+
+use crate::locale::Locale;
+#[cfg(feature = "nums")]
+use crate::num_formats::ToFormattedString;
+use crate::plurals::PluralOperands;
+
+/// Style of a formatted duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationStyle {{
+    /// e.g. `1 hour, 5 minutes`
+    Long,
+    /// e.g. `1 hr, 5 min`
+    Short,
+    /// e.g. `1h 5m`. Some locales use the short unit names for some units,
+    /// e.g. Russian hours and minutes, as CLDR has no shorter ones.
+    Narrow,
+    /// e.g. `1:05:00`, from CLDR `durationUnit` patterns
+    Digital,
+}}
+
+/// Formats a duration in weeks, days, hours, minutes and seconds for a
+/// locale; the digital style counts hours only.
+///
+/// Units that are zero are left out, e.g. "1 hr, 5 min" rather than
+/// "1 hr, 5 min, 0 sec", and fractions of a second are dropped.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::durations::{{DurationStyle, ToDurationString}};
+/// use std::time::Duration;
+///
+/// let runtime = Duration::from_secs(3900);
+/// assert_eq!(runtime.to_duration_string(&Locale::en, DurationStyle::Short), "1 hr, 5 min");
+/// assert_eq!(runtime.to_duration_string(&Locale::de, DurationStyle::Long), "1 Stunde, 5 Minuten");
+/// assert_eq!(runtime.to_duration_string(&Locale::en, DurationStyle::Digital), "1:05:00");
+///
+/// let trip = Duration::from_secs(9 * 86_400 + 3 * 3600);
+/// assert_eq!(trip.to_duration_string(&Locale::en, DurationStyle::Long), "1 week, 2 days, 3 hours");
+/// ```
+pub trait ToDurationString {{
+    fn to_duration_string(&self, locale: &Locale, style: DurationStyle) -> String;
+}}
+
+impl ToDurationString for Duration {{
+    fn to_duration_string(&self, locale: &Locale, style: DurationStyle) -> String {{
+        locale._format_duration(self.as_secs(), style)
+    }}
+}}
+
+/// Duration patterns of a locale.
+struct _DurationPatterns {{
+    /// Week, day, hour, minute and second patterns by width and plural
+    /// category; empty ones use the `other` pattern.
+    units: [[[&'static str; 6]; 5]; 3],
+    /// Unit list patterns by width: for two items, then the start, middle
+    /// and end of longer lists.
+    lists: [[&'static str; 4]; 3],
+    /// Digital patterns with and without hours, e.g. `h:mm:ss` and `m:ss`.
+    digital: [&'static str; 2],
+}}
+
+impl Locale {{
+    fn _format_duration(&self, seconds: u64, style: DurationStyle) -> String {{
+        let patterns = self._duration_patterns();
+        let fields = [
+            seconds / 604_800,
+            seconds / 86_400 % 7,
+            seconds / 3600 % 24,
+            seconds / 60 % 60,
+            seconds % 60,
+        ];
+        let width = match style {{
+            DurationStyle::Digital => {{
+                let pattern = patterns.digital[if seconds >= 3600 {{ 0 }} else {{ 1 }}];
+                return self._format_digital_duration(pattern, seconds);
+            }}
+            style => style as usize,
+        }};
+        let mut items: Vec<String> = fields
+            .iter()
+            .zip(&patterns.units[width])
+            .filter(|(value, _)| **value > 0)
+            .map(|(value, unit)| self._format_duration_unit(*value, unit))
+            .collect();
+        if items.is_empty() {{
+            items.push(self._format_duration_unit(0, &patterns.units[width][4]));
+        }}
+        _join_list(&patterns.lists[width], &items)
+    }}
+
+    fn _format_duration_unit(&self, value: u64, patterns: &[&'static str; 6]) -> String {{
+        let category = self.plural_category(&PluralOperands::from_integer(value as i64));
+        let pattern = match patterns[category as usize] {{
+            "" => patterns[5],
+            pattern => pattern,
+        }};
+        #[cfg(feature = "nums")]
+        let number = value.to_formatted_string(self);
+        #[cfg(not(feature = "nums"))]
+        let number = value.to_string();
+        pattern.replace("{{0}}", &number)
+    }}
+
+    /// Fills a `durationUnit` pattern such as `h:mm:ss`. The largest field
+    /// is not capped, e.g. 26 hours stay `26:00:00`.
+    fn _format_digital_duration(&self, pattern: &str, seconds: u64) -> String {{
+        let has_hours = pattern.contains('h');
+        let mut result = String::new();
+        let mut chars = pattern.chars().peekable();
+        let mut is_quoted = false;
+        while let Some(c) = chars.next() {{
+            if c == '\'' {{
+                if let Some(&'\'') = chars.peek() {{
+                    result.push('\'');
+                    chars.next();
+                }} else {{
+                    is_quoted = !is_quoted;
+                }}
+                continue;
+            }}
+            let value = match c {{
+                'h' if !is_quoted => seconds / 3600,
+                'm' if !is_quoted && has_hours => seconds / 60 % 60,
+                'm' if !is_quoted => seconds / 60,
+                's' if !is_quoted => seconds % 60,
+                _ => {{
+                    result.push(c);
+                    continue;
+                }}
+            }};
+            let mut count = 1;
+            while chars.next_if_eq(&c).is_some() {{
+                count += 1;
+            }}
+            result.push_str(&format!("{{:0width$}}", value, width = count));
+        }}
+        #[cfg(feature = "nums")]
+        {{ result = crate::num_formats::translate_digits(result, self); }}
+        result
+    }}
+
+    fn _duration_patterns(&self) -> &'static _DurationPatterns {{
+        match self {{
+{locale_arms}        }}
+    }}
+}}
+
+/// Joins items with a CLDR list pattern: for two items, then the start,
+/// middle and end of longer lists.
+fn _join_list(patterns: &[&str; 4], items: &[String]) -> String {{
+    match items {{
+        [] => String::new(),
+        [item] => item.clone(),
+        [first, second] => _fill_list_pattern(patterns[0], first, second),
+        [first, middle @ .., second_last, last] => {{
+            let mut result = _fill_list_pattern(patterns[3], second_last, last);
+            for item in middle.iter().rev() {{
+                result = _fill_list_pattern(patterns[2], item, &result);
+            }}
+            _fill_list_pattern(patterns[1], first, &result)
+        }}
+    }}
+}}
+
+/// Replaces `{{0}}` and `{{1}}` in one pass, so items may contain either.
+fn _fill_list_pattern(pattern: &str, first: &str, second: &str) -> String {{
+    let mut result = String::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{{') {{
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(tail) = rest.strip_prefix("{{0}}") {{
+            result.push_str(first);
+            rest = tail;
+        }} else if let Some(tail) = rest.strip_prefix("{{1}}") {{
+            result.push_str(second);
+            rest = tail;
+        }} else {{
+            result.push('{{');
+            rest = &rest[1..];
+        }}
+    }}
+    result.push_str(rest);
+    result
+}}
+
+{statics}"#,
+        locale_arms = locale_arms,
+        statics = statics
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+fn patterns_body(units: &Value, lists: &Value) -> String {
+    let unit_patterns: Vec<[[String; 6]; 5]> = WIDTHS
+        .iter()
+        .map(|(width, _)| {
+            UNITS.map(|unit| {
+                COUNTS.map(|count| {
+                    units[*width][unit][format!("unitPattern-count-{}", count)]
+                        .as_str()
+                        .unwrap_or("")
+                        .to_string()
+                })
+            })
+        })
+        .collect();
+    let list_patterns: Vec<[String; 4]> = WIDTHS
+        .iter()
+        .map(|(_, kind)| {
+            LIST_PARTS.map(|part| {
+                lists[*kind][part]
+                    .as_str()
+                    .unwrap_or("{0}, {1}")
+                    .to_string()
+            })
+        })
+        .collect();
+    let digital = ["hms", "ms"].map(|key| {
+        units[format!("durationUnit-type-{}", key)]["durationUnitPattern"]
+            .as_str()
+            .unwrap_or(if key == "hms" { "h:mm:ss" } else { "m:ss" })
+            .to_string()
+    });
+    format!(
+        "_DurationPatterns {{ units: {:?}, lists: {:?}, digital: {:?} }}",
+        unit_patterns, list_patterns, digital
+    )
+}
@@ -4,7 +4,10 @@ use std::fs;
 use std::io::Cursor;
 use zip::ZipArchive;
 
-use crate::sanitize_variant;
+use crate::{intern_table, sanitize_variant};
+
+/// CLDR `otherNumberingSystems` keys in `OtherNumberingSystem` order.
+const OTHER_SYSTEMS: [&str; 3] = ["native", "traditional", "finance"];
 
 fn detect_all_groupings(pattern: &str) -> Vec<usize> {
     let integer_part = pattern.split('.').next().unwrap_or(pattern);
@@ -35,6 +38,15 @@ fn detect_all_groupings(pattern: &str) -> Vec<usize> {
     sizes
 }
 
+/// `NumberingSystem` variant of a CLDR numbering system name, e.g. `Arabext`.
+fn system_variant(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
 pub fn run(
     zip_buffer: Vec<u8>,
     _asset_name: &str,
@@ -43,6 +55,8 @@ pub fn run(
     let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
 
     let mut system_digit_map: HashMap<String, [char; 10]> = HashMap::new();
+    // Rule set references of algorithmic systems, e.g. `ja/SpelloutRules/%spellout-cardinal`
+    let mut system_rule_map: HashMap<String, String> = HashMap::new();
     if let Ok(mut file) = archive.by_name("cldr-core/supplemental/numberingSystems.json") {
         let json: Value = serde_json::from_reader(&mut file)?;
         if let Some(systems) = json["supplemental"]["numberingSystems"].as_object() {
@@ -56,6 +70,10 @@ pub fn run(
                         arr.copy_from_slice(&chars[..10]);
                         system_digit_map.insert(name.to_string(), arr);
                     }
+                } else if data["_type"].as_str() == Some("algorithmic")
+                    && let Some(rules) = data["_rules"].as_str()
+                {
+                    system_rule_map.insert(name.to_string(), rules.to_string());
                 }
             }
         }
@@ -82,6 +100,12 @@ pub fn run(
     let mut grp_size_arms = String::new();
     let mut digit_arms = String::new();
     let mut minus_arms = String::new();
+    let mut min_grouping_arms = String::new();
+    let mut default_system_arms = String::new();
+    let mut other_system_arms = String::new();
+    let mut symbol_arms = String::new();
+    let mut statics = String::new();
+    let mut static_ids: HashMap<String, usize> = HashMap::new();
 
     for name in &locales {
         let var = sanitize_variant(name);
@@ -89,7 +113,11 @@ pub fn run(
         let mut group = ",".to_string();
         let mut minus = "-".to_string();
         let mut grouping_sizes = vec![3];
+        let mut min_grouping = 1;
         let mut digit_set_str = "None".to_string();
+        let mut default_system = "latn".to_string();
+        let mut other_systems = ["None".to_string(), "None".to_string(), "None".to_string()];
+        let mut symbol_entries = Vec::new();
 
         let json_path = format!("cldr-numbers-full/main/{}/numbers.json", name);
         if let Ok(mut file) = archive.by_name(&json_path) {
@@ -116,10 +144,51 @@ pub fn run(
                 digit_set_str = format!("Some({:?})", digits);
             }
 
+            if let Some(min) = numbers["minimumGroupingDigits"]
+                .as_str()
+                .and_then(|m| m.parse().ok())
+            {
+                min_grouping = min;
+            }
+
             let format_key = format!("decimalFormats-numberSystem-{}", system);
             if let Some(pattern) = numbers[format_key]["standard"].as_str() {
                 grouping_sizes = detect_all_groupings(pattern);
             }
+
+            let known =
+                |s: &str| system_digit_map.contains_key(s) || system_rule_map.contains_key(s);
+            if known(system) {
+                default_system = system.to_string();
+            }
+            for (slot, kind) in other_systems.iter_mut().zip(OTHER_SYSTEMS) {
+                if let Some(other) = numbers["otherNumberingSystems"][kind].as_str()
+                    && known(other)
+                {
+                    *slot = format!("Some(NumberingSystem::{})", system_variant(other));
+                }
+            }
+            // Symbols of the systems other than the default one
+            if let Some(entries) = numbers.as_object() {
+                for (key, symbols) in entries {
+                    let Some(other) = key.strip_prefix("symbols-numberSystem-") else {
+                        continue;
+                    };
+                    if other == system || !known(other) {
+                        continue;
+                    }
+                    let symbol = |field: &str, fallback: &str| {
+                        symbols[field].as_str().unwrap_or(fallback).to_string()
+                    };
+                    symbol_entries.push(format!(
+                        "(NumberingSystem::{}, NumberSymbols {{ decimal: {:?}, group: {:?}, minus_sign: {:?} }})",
+                        system_variant(other),
+                        symbol("decimal", &decimal),
+                        symbol("group", &group),
+                        symbol("minusSign", &minus)
+                    ));
+                }
+            }
         }
 
         dec_sep_arms.push_str(&format!(
@@ -128,6 +197,10 @@ pub fn run(
         ));
         grp_sep_arms.push_str(&format!("            Locale::{} => \"{}\",\n", var, group));
         minus_arms.push_str(&format!("            Locale::{} => \"{}\",\n", var, minus));
+        min_grouping_arms.push_str(&format!(
+            "            Locale::{} => {},\n",
+            var, min_grouping
+        ));
         grp_size_arms.push_str(&format!(
             "            Locale::{} => &{:?},\n",
             var, grouping_sizes
@@ -136,11 +209,88 @@ pub fn run(
             "            Locale::{} => {},\n",
             var, digit_set_str
         ));
+        default_system_arms.push_str(&format!(
+            "            Locale::{} => NumberingSystem::{},\n",
+            var,
+            system_variant(&default_system)
+        ));
+        other_system_arms.push_str(&format!(
+            "            Locale::{} => [{}],\n",
+            var,
+            other_systems.join(", ")
+        ));
+        symbol_entries.sort();
+        let table = intern_table(
+            &mut static_ids,
+            &mut statics,
+            "_NUMBER_SYMBOLS",
+            "(NumberingSystem, NumberSymbols)",
+            &symbol_entries,
+        );
+        symbol_arms.push_str(&format!("            Locale::{} => &{},\n", var, table));
+    }
+
+    let mut system_names: Vec<&String> = system_digit_map
+        .keys()
+        .chain(system_rule_map.keys())
+        .collect();
+    system_names.sort();
+    let mut system_variants = String::new();
+    let mut system_as_str_arms = String::new();
+    let mut system_from_str_arms = String::new();
+    let mut system_digit_arms = String::new();
+    let mut system_rule_arms = String::new();
+    for name in system_names {
+        let variant = system_variant(name);
+        if let Some(digits) = system_digit_map.get(name) {
+            let digits: String = digits.iter().collect();
+            system_variants.push_str(&format!(
+                "    /// `{}`: {}\n    {},\n",
+                name, digits, variant
+            ));
+            system_digit_arms.push_str(&format!(
+                "            NumberingSystem::{} => Some({:?}),\n",
+                variant,
+                digits.chars().collect::<Vec<_>>()
+            ));
+        } else if let Some(rules) = system_rule_map.get(name) {
+            system_variants.push_str(&format!(
+                "    /// `{}`: rules `{}`\n    {},\n",
+                name, rules, variant
+            ));
+            // `ja/SpelloutRules/%spellout-cardinal` uses the rules of `ja`
+            let (locale, rule_set) = match rules.split('/').collect::<Vec<_>>().as_slice() {
+                [locale, _, rule_set] if locales.contains(&locale.replace('_', "-")) => (
+                    format!(
+                        "Some(Locale::{})",
+                        sanitize_variant(&locale.replace('_', "-"))
+                    ),
+                    rule_set.to_string(),
+                ),
+                _ => ("None".to_string(), rules.clone()),
+            };
+            system_rule_arms.push_str(&format!(
+                "            NumberingSystem::{} => Some(({}, {:?})),\n",
+                variant, locale, rule_set
+            ));
+        }
+        system_as_str_arms.push_str(&format!(
+            "            NumberingSystem::{} => {:?},\n",
+            variant, name
+        ));
+        system_from_str_arms.push_str(&format!(
+            "            {:?} => Ok(NumberingSystem::{}),\n",
+            name, variant
+        ));
     }
 
     let code = format!(
         r#"// Auto-generated. DO NOT EDIT.
+use crate::error::LocaleError;
 use crate::locale::Locale;
+use crate::rbnf::{{RbnfRule, _RuleGroup}};
+use std::fmt;
+use std::str::FromStr;
 
 impl Locale {{
     pub fn decimal_separator(&self) -> &'static str {{
@@ -159,61 +309,314 @@ impl Locale {{
         match self {{ {minus_arms} }}
     }}
 
+    /// Returns how many digits the first group must have before grouping
+    /// applies, e.g. 2 for Spanish, which writes `1234` but `12.345`.
+    pub fn minimum_grouping_digits(&self) -> usize {{
+        match self {{ {min_grouping_arms} }}
+    }}
+
     pub fn digits(&self) -> Option<[char; 10]> {{
         match self {{ {digit_arms} }}
     }}
 }}
 
+/// A CLDR numbering system, named after its BCP 47 `-u-nu-` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberingSystem {{
+{system_variants}}}
+
+impl NumberingSystem {{
+    /// Returns the CLDR name of the numbering system, e.g. `"arab"`.
+    pub fn as_str(&self) -> &'static str {{
+        match self {{
+{system_as_str_arms}        }}
+    }}
+
+    /// Returns the digits 0-9 of a numeric system, or `None` for an
+    /// algorithmic one such as `roman`.
+    pub fn digits(&self) -> Option<[char; 10]> {{
+        match self {{
+{system_digit_arms}            _ => None,
+        }}
+    }}
+
+    /// Returns whether numbers are written by rules, e.g. Roman numerals,
+    /// rather than with ten digits.
+    pub fn is_algorithmic(&self) -> bool {{
+        self.digits().is_none()
+    }}
+
+    /// The RBNF rule set of an algorithmic system, with the locale whose
+    /// rules it uses if it names one, e.g. `ja` for `jpan`.
+    fn _rules(&self) -> Option<(Option<Locale>, &'static str)> {{
+        match self {{
+{system_rule_arms}            _ => None,
+        }}
+    }}
+}}
+
+impl fmt::Display for NumberingSystem {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        write!(f, "{{}}", self.as_str())
+    }}
+}}
+
+impl FromStr for NumberingSystem {{
+    type Err = LocaleError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s.to_ascii_lowercase().as_str() {{
+{system_from_str_arms}            _ => Err(LocaleError::UnknownNumberingSystem(s.to_string())),
+        }}
+    }}
+}}
+
+/// Whether to insert grouping separators, e.g. `1,234`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GroupingStrategy {{
+    /// Group as the locale does, following its minimum grouping digits.
+    #[default]
+    Auto,
+    /// Group every number longer than the primary group size.
+    Always,
+    /// Never insert grouping separators.
+    Never,
+}}
+
+/// The alternative numbering systems a locale may name besides its default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OtherNumberingSystem {{
+    /// Native digits, e.g. `arab` for Arabic or `deva` for Hindi.
+    Native,
+    /// Traditional numerals, e.g. `hans` for Chinese.
+    Traditional,
+    /// Financial numerals, e.g. `hansfin` for Chinese.
+    Finance,
+}}
+
+/// The number symbols a locale uses with a numbering system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberSymbols {{
+    pub decimal: &'static str,
+    pub group: &'static str,
+    pub minus_sign: &'static str,
+}}
+
+{statics}impl Locale {{
+    /// Returns the numbering system the locale formats numbers in by default.
+    pub fn default_numbering_system(&self) -> NumberingSystem {{
+        match self {{ {default_system_arms} }}
+    }}
+
+    fn _other_numbering_systems(&self) -> [Option<NumberingSystem>; 3] {{
+        match self {{ {other_system_arms} }}
+    }}
+
+    /// Returns the locale's native, traditional or finance numbering system.
+    /// Traditional falls back to native and the others to the default system,
+    /// as in CLDR.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::{{NumberingSystem, OtherNumberingSystem}};
+    ///
+    /// assert_eq!(Locale::hi.other_numbering_system(OtherNumberingSystem::Native), NumberingSystem::Deva);
+    /// assert_eq!(Locale::zh.other_numbering_system(OtherNumberingSystem::Finance), NumberingSystem::Hansfin);
+    /// assert_eq!(Locale::en.other_numbering_system(OtherNumberingSystem::Traditional), NumberingSystem::Latn);
+    /// ```
+    pub fn other_numbering_system(&self, kind: OtherNumberingSystem) -> NumberingSystem {{
+        let [native, traditional, finance] = self._other_numbering_systems();
+        match kind {{
+            OtherNumberingSystem::Native => native,
+            OtherNumberingSystem::Traditional => traditional.or(native),
+            OtherNumberingSystem::Finance => finance,
+        }}
+        .unwrap_or_else(|| self.default_numbering_system())
+    }}
+
+    fn _number_symbols(&self) -> &'static [(NumberingSystem, NumberSymbols)] {{
+        match self {{ {symbol_arms} }}
+    }}
+
+    /// Returns the separators and minus sign the locale uses with a numbering
+    /// system, falling back to its `latn` symbols for systems CLDR has none for.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::NumberingSystem;
+    ///
+    /// assert_eq!(Locale::ar.number_symbols(NumberingSystem::Arab).decimal, "٫");
+    /// assert_eq!(Locale::ar.number_symbols(NumberingSystem::Latn).decimal, ".");
+    /// ```
+    pub fn number_symbols(&self, system: NumberingSystem) -> NumberSymbols {{
+        if system != self.default_numbering_system() {{
+            let table = self._number_symbols();
+            let found = table
+                .iter()
+                .find(|(s, _)| *s == system)
+                .or_else(|| table.iter().find(|(s, _)| *s == NumberingSystem::Latn));
+            if let Some((_, symbols)) = found {{
+                return *symbols;
+            }}
+        }}
+        NumberSymbols {{
+            decimal: self.decimal_separator(),
+            group: self.grouping_separator(),
+            minus_sign: self.minus_sign(),
+        }}
+    }}
+}}
+
+impl Locale {{
+    /// Formats an integer in a numbering system. Numeric systems use the
+    /// locale's symbols for the system with its digits; algorithmic ones apply
+    /// their CLDR rules, e.g. Roman numerals or Hebrew letters.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::NumberingSystem;
+    ///
+    /// let english = Locale::en;
+    /// assert_eq!(english.format_in_numbering_system(1984, NumberingSystem::Roman), "MCMLXXXIV");
+    /// assert_eq!(english.format_in_numbering_system(1234, NumberingSystem::Deva), "१,२३४");
+    /// assert_eq!(english.format_in_numbering_system(5784, "hebr".parse().unwrap()), "ה׳תשפ״ד");
+    /// ```
+    pub fn format_in_numbering_system(&self, n: i64, system: NumberingSystem) -> String {{
+        if let Some((locale, rule_set)) = system._rules() {{
+            let locale = locale.unwrap_or(*self);
+            if let Some(result) = locale.spell_out(n, RbnfRule::Named(rule_set)) {{
+                return result;
+            }}
+        }}
+        let mut buf = [0u8; 128];
+        let pos = format_int_to_buf(&mut buf, n.unsigned_abs() as u128);
+        let abs_str = unsafe {{ std::str::from_utf8_unchecked(&buf[pos..]) }};
+        _format_with_system(abs_str, None, n < 0, self, system, GroupingStrategy::Auto)
+    }}
+}}
+
 pub trait ToFormattedString {{
-    fn to_formatted_string(&self, locale: &Locale) -> String;
+    fn to_formatted_string(&self, locale: &Locale) -> String {{
+        self.to_formatted_string_in(locale, locale.default_numbering_system())
+    }}
+
+    /// Formats the number with the locale's symbols for a numbering system.
+    /// Integers in an algorithmic system are written by its rules; fractions
+    /// fall back to the locale's default system.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::{{NumberingSystem, ToFormattedString}};
+    ///
+    /// assert_eq!((-1234.5).to_formatted_string_in(&Locale::ar, NumberingSystem::Arab), "؜-١٬٢٣٤٫٥");
+    /// assert_eq!(1234.to_formatted_string_in(&Locale::ar, NumberingSystem::Latn), "1,234");
+    /// assert_eq!(12.to_formatted_string_in(&Locale::en, NumberingSystem::Roman), "XII");
+    /// ```
+    fn to_formatted_string_in(&self, locale: &Locale, system: NumberingSystem) -> String {{
+        self.to_formatted_string_with(locale, system, GroupingStrategy::Auto)
+    }}
+
+    /// Formats the number with grouping forced on or off, or left to the
+    /// locale's minimum grouping digits.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::{{GroupingStrategy, ToFormattedString}};
+    ///
+    /// assert_eq!(1234.to_formatted_string(&Locale::es), "1234");
+    /// assert_eq!(1234.to_formatted_string_with_grouping(&Locale::es, GroupingStrategy::Always), "1.234");
+    /// assert_eq!(12345.to_formatted_string_with_grouping(&Locale::en, GroupingStrategy::Never), "12345");
+    /// ```
+    fn to_formatted_string_with_grouping(&self, locale: &Locale, grouping: GroupingStrategy) -> String {{
+        self.to_formatted_string_with(locale, locale.default_numbering_system(), grouping)
+    }}
+
+    /// Formats the number in a numbering system with a grouping strategy.
+    fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String;
 }}
 
 /// Translates ASCII digits 0-9 into the locale's native numbering system.
 pub fn translate_digits(input: String, locale: &Locale) -> String {{
     match locale.digits() {{
-        Some(d) => {{
-            let bytes = input.as_bytes();
-            let mut result = String::with_capacity(input.len() * 2);  // May grow due to multi-byte digits
-            let mut i = 0;
-            
-            while i < bytes.len() {{
-                let b = bytes[i];
-                
-                if b >= b'0' && b <= b'9' {{
-                    // ASCII digit - replace with locale digit
-                    let idx = (b - b'0') as usize;
-                    result.push(d[idx]);
-                    i += 1;
-                }} else if b < 128 {{
-                    // ASCII character (not digit) - keep as-is
-                    result.push(b as char);
-                    i += 1;
-                }} else {{
-                    // Multi-byte UTF-8 sequence - copy as-is
-                    let start = i;
-                    loop {{
-                        i += 1;
-                        if i >= bytes.len() || bytes[i] & 0b11000000 != 0b10000000 {{
-                            break;
-                        }}
-                    }}
-                    let s = unsafe {{ std::str::from_utf8_unchecked(&bytes[start..i]) }};
-                    result.push_str(s);
+        Some(d) => _replace_digits(input, &d),
+        None => input,
+    }}
+}}
+
+/// Replaces ASCII digits 0-9 with the given digits.
+pub(crate) fn _replace_digits(input: String, d: &[char; 10]) -> String {{
+    let bytes = input.as_bytes();
+    let mut result = String::with_capacity(input.len() * 2);  // May grow due to multi-byte digits
+    let mut i = 0;
+    
+    while i < bytes.len() {{
+        let b = bytes[i];
+        
+        if b >= b'0' && b <= b'9' {{
+            // ASCII digit - replace with locale digit
+            let idx = (b - b'0') as usize;
+            result.push(d[idx]);
+            i += 1;
+        }} else if b < 128 {{
+            // ASCII character (not digit) - keep as-is
+            result.push(b as char);
+            i += 1;
+        }} else {{
+            // Multi-byte UTF-8 sequence - copy as-is
+            let start = i;
+            loop {{
+                i += 1;
+                if i >= bytes.len() || bytes[i] & 0b11000000 != 0b10000000 {{
+                    break;
                 }}
             }}
-            result
+            let s = unsafe {{ std::str::from_utf8_unchecked(&bytes[start..i]) }};
+            result.push_str(s);
         }}
-        None => input,
     }}
+    result
 }}
 
-/// Formats the integer portion of a number with grouping separators.
-/// Uses stack-allocated buffer to minimize heap allocations.
-fn _format_int_str(numeric_part: &str, locale: &Locale) -> String {{
-    let sizes = locale.grouping_sizes();
-    let separator = locale.grouping_separator();
+/// Formats the ASCII digits of a number with the locale's symbols and the
+/// digits of a numeric system.
+fn _format_with_system(
+    int_part: &str,
+    frac_part: Option<&str>,
+    is_neg: bool,
+    locale: &Locale,
+    system: NumberingSystem,
+    grouping: GroupingStrategy,
+) -> String {{
+    let symbols = locale.number_symbols(system);
+    let mut result = String::with_capacity(int_part.len() * 2);
+    if is_neg {{
+        result.push_str(symbols.minus_sign);
+    }}
+    let min_grouping = match grouping {{
+        GroupingStrategy::Auto => locale.minimum_grouping_digits(),
+        GroupingStrategy::Always => 1,
+        GroupingStrategy::Never => usize::MAX,
+    }};
+    result.push_str(&_format_int_str(int_part, locale.grouping_sizes(), symbols.group, min_grouping));
+    if let Some(frac_part) = frac_part {{
+        result.push_str(symbols.decimal);
+        result.push_str(frac_part);
+    }}
+    match system.digits() {{
+        Some(digits) => _replace_digits(result, &digits),
+        None => result,
+    }}
+}}
 
-    if sizes.is_empty() || sizes[0] == 0 || numeric_part.len() <= sizes[0] {{
+/// Formats the integer portion of a number with grouping separators, once it
+/// is at least `min_grouping` digits longer than the primary group.
+/// Uses stack-allocated buffer to minimize heap allocations.
+fn _format_int_str(numeric_part: &str, sizes: &[usize], separator: &str, min_grouping: usize) -> String {{
+    if sizes.is_empty() || sizes[0] == 0 || numeric_part.len() < sizes[0].saturating_add(min_grouping) {{
         return numeric_part.to_string();
     }}
 
@@ -250,12 +653,17 @@ macro_rules! impl_int {{
     ($($t:ty),*) => {{
         $(
             impl ToFormattedString for $t {{
-                fn to_formatted_string(&self, locale: &Locale) -> String {{
+                fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String {{
+                    if system.is_algorithmic()
+                        && let Ok(n) = i64::try_from(*self)
+                    {{
+                        return locale.format_in_numbering_system(n, system);
+                    }}
                     // Use itoa-like approach: write ASCII directly to stack buffer
                     let mut buf = [0u8; 128];
                     let (is_neg, pos) = if *self < 0 {{
                         // Signed type - handle negation
-                        let n = (-(*self as i128)) as u128;
+                        let n = (*self as i128).unsigned_abs();
                         (true, format_int_to_buf(&mut buf, n))
                     }} else {{
                         // Unsigned type - cast directly
@@ -263,15 +671,7 @@ macro_rules! impl_int {{
                     }};
 
                     let abs_str = unsafe {{ std::str::from_utf8_unchecked(&buf[pos..]) }};
-                    let formatted = _format_int_str(abs_str, locale);
-
-                    let res = if is_neg {{
-                        format!("{{}}{{}}", locale.minus_sign(), formatted)
-                    }} else {{
-                        formatted
-                    }};
-
-                    translate_digits(res, locale)
+                    _format_with_system(abs_str, None, is_neg, locale, system, grouping)
                 }}
             }}
         )*
@@ -282,14 +682,18 @@ macro_rules! impl_uint {{
     ($($t:ty),*) => {{
         $(
             impl ToFormattedString for $t {{
-                fn to_formatted_string(&self, locale: &Locale) -> String {{
+                fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String {{
+                    if system.is_algorithmic()
+                        && let Ok(n) = i64::try_from(*self)
+                    {{
+                        return locale.format_in_numbering_system(n, system);
+                    }}
                     // Unsigned type - no need to handle negation
                     let mut buf = [0u8; 128];
                     let pos = format_int_to_buf(&mut buf, *self as u128);
 
                     let abs_str = unsafe {{ std::str::from_utf8_unchecked(&buf[pos..]) }};
-                    let formatted = _format_int_str(abs_str, locale);
-                    translate_digits(formatted, locale)
+                    _format_with_system(abs_str, None, false, locale, system, grouping)
                 }}
             }}
         )*
@@ -320,32 +724,20 @@ macro_rules! impl_float {{
     ($($t:ty),*) => {{
         $(
             impl ToFormattedString for $t {{
-                fn to_formatted_string(&self, locale: &Locale) -> String {{
+                fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String {{
+                    let system = if system.is_algorithmic() {{ locale.default_numbering_system() }} else {{ system }};
                     if self.is_nan() {{ return "NaN".to_string(); }}
                     if self.is_infinite() {{
-                        return if self.is_sign_positive() {{ "inf".to_string() }} else {{ format!("{{}}inf", locale.minus_sign()) }};
+                        return if self.is_sign_positive() {{ "inf".to_string() }} else {{ format!("{{}}inf", locale.number_symbols(system).minus_sign) }};
                     }}
 
                     let s = format!("{{}}", self);
                     let (is_neg, s_abs) = if s.starts_with('-') {{ (true, &s[1..]) }} else {{ (false, &s[..]) }};
 
-                    // Single allocation: format the result directly
-                    let res = if let Some(dot_pos) = s_abs.find('.') {{
-                        let (int_part, frac_part) = s_abs.split_at(dot_pos);
-                        let formatted_int = _format_int_str(int_part, locale);
-                        // Combine without intermediate allocations
-                        format!("{{}}{{}}{{}}", formatted_int, locale.decimal_separator(), &frac_part[1..])
-                    }} else {{
-                        _format_int_str(s_abs, locale)
-                    }};
-
-                    // Apply minus sign and digit translation if needed
-                    let final_str = if is_neg {{
-                        format!("{{}}{{}}", locale.minus_sign(), res)
-                    }} else {{
-                        res
-                    }};
-                    translate_digits(final_str, locale)
+                    match s_abs.split_once('.') {{
+                        Some((int_part, frac_part)) => _format_with_system(int_part, Some(frac_part), is_neg, locale, system, grouping),
+                        None => _format_with_system(s_abs, None, is_neg, locale, system, grouping),
+                    }}
                 }}
             }}
         )*
@@ -353,12 +745,54 @@ macro_rules! impl_float {{
 }}
 
 impl_float!(f32, f64);
+
+/// Formats an integer as an ordinal in digits, using the locale's CLDR
+/// `%digits-ordinal` rules.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::num_formats::ToOrdinalString;
+///
+/// assert_eq!(22.to_ordinal_string(&Locale::en), "22nd");
+/// assert_eq!(1.to_ordinal_string(&Locale::fr), "1er");
+/// assert_eq!(3.to_ordinal_string(&Locale::de), "3.");
+/// ```
+pub trait ToOrdinalString {{
+    fn to_ordinal_string(&self, locale: &Locale) -> String;
+}}
+
+macro_rules! impl_ordinal {{
+    ($($t:ty),*) => {{
+        $(
+            impl ToOrdinalString for $t {{
+                fn to_ordinal_string(&self, locale: &Locale) -> String {{
+                    locale
+                        ._format_rbnf(*self as i128, _RuleGroup::Ordinal, "%digits-ordinal")
+                        .unwrap_or_else(|| self.to_formatted_string(locale))
+                }}
+            }}
+        )*
+    }};
+}}
+
+impl_ordinal!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
 "#,
         dec_sep_arms = dec_sep_arms,
         grp_sep_arms = grp_sep_arms,
         grp_size_arms = grp_size_arms,
         minus_arms = minus_arms,
-        digit_arms = digit_arms
+        min_grouping_arms = min_grouping_arms,
+        digit_arms = digit_arms,
+        system_variants = system_variants,
+        system_as_str_arms = system_as_str_arms,
+        system_from_str_arms = system_from_str_arms,
+        system_digit_arms = system_digit_arms,
+        system_rule_arms = system_rule_arms,
+        default_system_arms = default_system_arms,
+        other_system_arms = other_system_arms,
+        symbol_arms = symbol_arms,
+        statics = statics
     );
 
     fs::write(output_path, code)?;
@@ -0,0 +1,564 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use zip::ZipArchive;
+
+use crate::{intern_table, sanitize_variant};
+
+/// Plural categories in the order CLDR evaluates their rules.
+const CATEGORIES: [(&str, &str); 5] = [
+    ("zero", "Zero"),
+    ("one", "One"),
+    ("two", "Two"),
+    ("few", "Few"),
+    ("many", "Many"),
+];
+
+/// Plural category keys and variants including `other`, for ranges.
+const RANGE_CATEGORIES: [(&str, &str); 6] = [
+    ("zero", "Zero"),
+    ("one", "One"),
+    ("two", "Two"),
+    ("few", "Few"),
+    ("many", "Many"),
+    ("other", "Other"),
+];
+
+pub fn run(
+    zip_buffer: Vec<u8>,
+    _asset_name: &str,
+    output_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
+
+    // 1. Cardinal rules by locale id
+    let mut cardinal: HashMap<String, Value> = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/plurals.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        if let Some(rules) = json["supplemental"]["plurals-type-cardinal"].as_object() {
+            for (id, data) in rules {
+                cardinal.insert(id.clone(), data.clone());
+            }
+        }
+    }
+
+    // 2. Ordinal rules by locale id
+    let mut ordinal: HashMap<String, Value> = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/ordinals.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        if let Some(rules) = json["supplemental"]["plurals-type-ordinal"].as_object() {
+            for (id, data) in rules {
+                ordinal.insert(id.clone(), data.clone());
+            }
+        }
+    }
+
+    // 3. Plural range rules by locale id
+    let mut ranges: HashMap<String, Value> = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/pluralRanges.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        if let Some(rules) = json["supplemental"]["plurals-type-pluralRanges"].as_object() {
+            for (id, data) in rules {
+                ranges.insert(id.clone(), data.clone());
+            }
+        }
+    }
+
+    // 4. Process Locales
+    let mut locales = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.name().contains("/main/") && file.is_dir() {
+            let parts: Vec<&str> = file.name().split('/').collect();
+            if let Some(idx) = parts.iter().position(|&r| r == "main")
+                && let Some(name) = parts.get(idx + 1)
+                && !name.is_empty()
+                && !locales.contains(&(*name).to_string())
+            {
+                locales.push((*name).to_string());
+            }
+        }
+    }
+    locales.sort();
+
+    let mut rule_fns = String::new();
+    let mut cardinal_ids: HashMap<String, String> = HashMap::new();
+    let mut ordinal_ids: HashMap<String, String> = HashMap::new();
+    let mut cardinal_arms = String::new();
+    let mut ordinal_arms = String::new();
+    let mut range_statics = String::new();
+    let mut range_ids: HashMap<String, usize> = HashMap::new();
+    let mut range_arms = String::new();
+    for name in &locales {
+        let var = sanitize_variant(name);
+        for (rule_sets, ids, prefix, arms) in [
+            (
+                &cardinal,
+                &mut cardinal_ids,
+                "_cardinal",
+                &mut cardinal_arms,
+            ),
+            (&ordinal, &mut ordinal_ids, "_ordinal", &mut ordinal_arms),
+        ] {
+            let rules = fallback_chain(name)
+                .iter()
+                .find_map(|id| rule_sets.get(id))
+                .cloned()
+                .unwrap_or(Value::Null);
+            let fn_name = rule_fn(&mut rule_fns, ids, prefix, &rule_body(&rules)?);
+            arms.push_str(&format!(
+                "            Locale::{} => {}(operands),\n",
+                var, fn_name
+            ));
+        }
+
+        let rules = fallback_chain(name).iter().find_map(|id| ranges.get(id));
+        let table = intern_table(
+            &mut range_ids,
+            &mut range_statics,
+            "_PLURAL_RANGES",
+            "(PluralCategory, PluralCategory, PluralCategory)",
+            &range_entries(rules),
+        );
+        range_arms.push_str(&format!("            Locale::{} => &{},\n", var, table));
+    }
+
+    let code = format!(
+        r#"// Auto-generated. DO NOT EDIT.
+// The rule functions mirror the CLDR rule text rather than a minimal form.
+#![allow(clippy::nonminimal_bool)]
+use std::str::FromStr;
+
+use crate::error::LocaleError;
+use crate::locale::Locale;
+
+/// CLDR plural category of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {{
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}}
+
+/// Plural operands of a number as defined by UTS #35: the absolute value `n`,
+/// its integer digits `i`, the number of visible fraction digits `v` and
+/// without trailing zeros `w`, the visible fraction digits `f` and without
+/// trailing zeros `t`, and the compact decimal exponent `e`.
+///
+/// Visible fraction digits matter, so operands are best taken from the
+/// number as it is displayed, e.g. `"1.50".parse()` rather than `1.5`.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::plurals::{{PluralCategory, PluralOperands}};
+///
+/// assert_eq!(Locale::en.plural_category(&1.into()), PluralCategory::One);
+/// assert_eq!(Locale::en.plural_category(&"1.0".parse()?), PluralCategory::Other);
+/// assert_eq!(Locale::ru.plural_category(&21.into()), PluralCategory::One);
+/// assert_eq!(Locale::en.ordinal_category(&23.into()), PluralCategory::Few);
+/// # Ok::<(), locale_rs::error::LocaleError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralOperands {{
+    n: f64,
+    i: u64,
+    v: u32,
+    w: u32,
+    f: u64,
+    t: u64,
+    e: u32,
+}}
+
+impl PluralOperands {{
+    pub fn from_integer(value: i64) -> Self {{
+        PluralOperands {{
+            n: value.unsigned_abs() as f64,
+            i: value.unsigned_abs(),
+            v: 0,
+            w: 0,
+            f: 0,
+            t: 0,
+            e: 0,
+        }}
+    }}
+
+    /// Takes the operands of the shortest decimal that reads back as `value`,
+    /// so `1.5` has one fraction digit and `2.0` none.
+    pub fn from_f64(value: f64) -> Self {{
+        if !value.is_finite() {{
+            return PluralOperands {{ n: value.abs(), ..PluralOperands::from_integer(0) }};
+        }}
+        // Display never uses an exponent and always reads back.
+        format!("{{}}", value).parse().unwrap_or_else(|_| PluralOperands::from_integer(0))
+    }}
+}}
+
+impl From<i64> for PluralOperands {{
+    fn from(value: i64) -> Self {{
+        PluralOperands::from_integer(value)
+    }}
+}}
+
+impl From<i32> for PluralOperands {{
+    fn from(value: i32) -> Self {{
+        PluralOperands::from_integer(value as i64)
+    }}
+}}
+
+impl From<u32> for PluralOperands {{
+    fn from(value: u32) -> Self {{
+        PluralOperands::from_integer(value as i64)
+    }}
+}}
+
+impl From<u64> for PluralOperands {{
+    fn from(value: u64) -> Self {{
+        PluralOperands {{ n: value as f64, i: value, ..PluralOperands::from_integer(0) }}
+    }}
+}}
+
+impl From<usize> for PluralOperands {{
+    fn from(value: usize) -> Self {{
+        PluralOperands::from(value as u64)
+    }}
+}}
+
+impl From<f64> for PluralOperands {{
+    fn from(value: f64) -> Self {{
+        PluralOperands::from_f64(value)
+    }}
+}}
+
+/// Parses a formatted decimal such as `-1.50`, keeping its visible fraction
+/// digits, or a compact one such as `1.2c3` (1200 with exponent 3).
+impl FromStr for PluralOperands {{
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        let error = || LocaleError::InvalidNumber(s.to_string());
+        let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+        let (mantissa, exponent) = match unsigned.split_once(['c', 'e']) {{
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<u32>().map_err(|_| error())?),
+            None => (unsigned, 0),
+        }};
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) || exponent > 18 {{
+            return Err(error());
+        }}
+        // The exponent moves fraction digits into the integer part.
+        let shift = (exponent as usize).min(fraction.len());
+        let integer = format!("{{}}{{}}{{}}", integer, &fraction[..shift], "0".repeat(exponent as usize - shift));
+        let fraction = &fraction[shift..];
+        let n: f64 = format!("{{}}.{{}}0", integer, fraction).parse().map_err(|_| error())?;
+        let trimmed = fraction.trim_end_matches('0');
+        Ok(PluralOperands {{
+            n,
+            i: _saturating_digits(&integer),
+            v: fraction.len() as u32,
+            w: trimmed.len() as u32,
+            f: _saturating_digits(fraction),
+            t: _saturating_digits(trimmed),
+            e: exponent,
+        }})
+    }}
+}}
+
+/// Reads a run of decimal digits. Values above 10^18 keep their last 18
+/// digits plus 10^18, which stays large and leaves every `% 10^k` unchanged.
+fn _saturating_digits(digits: &str) -> u64 {{
+    const LIMIT: u64 = 1_000_000_000_000_000_000;
+    let digits = digits.trim_start_matches('0');
+    match digits.parse::<u64>() {{
+        Ok(value) => value,
+        Err(_) if digits.is_empty() => 0,
+        Err(_) => LIMIT + digits[digits.len().saturating_sub(18)..].parse::<u64>().unwrap_or(0),
+    }}
+}}
+
+impl Locale {{
+    /// Returns the cardinal plural category of a number, e.g. `One` for
+    /// "1 day" and `Other` for "2 days" in English.
+    pub fn plural_category(&self, operands: &PluralOperands) -> PluralCategory {{
+        match self {{
+{cardinal_arms}        }}
+    }}
+
+    /// Returns the plural category of a range of numbers, e.g. `Other` for
+    /// "1–2 days" in English but `One` for "0–1 jour" in French. Locales
+    /// without range rules use the category of the end.
+    pub fn plural_range_category(&self, start: &PluralOperands, end: &PluralOperands) -> PluralCategory {{
+        let (start, end) = (self.plural_category(start), self.plural_category(end));
+        self._plural_ranges()
+            .iter()
+            .find(|(s, e, _)| *s == start && *e == end)
+            .map_or(end, |&(_, _, category)| category)
+    }}
+
+    /// Range rules as `(start, end, range)` categories.
+    fn _plural_ranges(&self) -> &'static [(PluralCategory, PluralCategory, PluralCategory)] {{
+        match self {{
+{range_arms}        }}
+    }}
+
+    /// Returns the ordinal plural category of a number, e.g. `Two` for
+    /// "2nd" and `Few` for "23rd" in English.
+    pub fn ordinal_category(&self, operands: &PluralOperands) -> PluralCategory {{
+        match self {{
+{ordinal_arms}        }}
+    }}
+}}
+
+{rule_fns}{range_statics}"#,
+        cardinal_arms = cardinal_arms,
+        ordinal_arms = ordinal_arms,
+        range_arms = range_arms,
+        rule_fns = rule_fns,
+        range_statics = range_statics
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+/// Emits `(start, end, range)` entries from CLDR `pluralRange-start-*-end-*` keys.
+fn range_entries(rules: Option<&Value>) -> Vec<String> {
+    let variant = |key: &str| {
+        RANGE_CATEGORIES
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| *v)
+    };
+    let mut entries = Vec::new();
+    for (key, result) in rules
+        .and_then(|rules| rules.as_object())
+        .into_iter()
+        .flatten()
+    {
+        let Some((start, end)) = key
+            .strip_prefix("pluralRange-start-")
+            .and_then(|rest| rest.split_once("-end-"))
+        else {
+            continue;
+        };
+        if let (Some(start), Some(end), Some(result)) = (
+            variant(start),
+            variant(end),
+            result.as_str().and_then(variant),
+        ) {
+            entries.push(format!(
+                "(PluralCategory::{}, PluralCategory::{}, PluralCategory::{})",
+                start, end, result
+            ));
+        }
+    }
+    entries.sort();
+    entries
+}
+
+/// Emits a rule function for `body` unless an identical one with the same
+/// prefix exists, and returns its name.
+fn rule_fn(
+    rule_fns: &mut String,
+    ids: &mut HashMap<String, String>,
+    prefix: &str,
+    body: &str,
+) -> String {
+    let next_id = format!("{}_{}", prefix, ids.len());
+    ids.entry(body.to_string())
+        .or_insert_with(|| {
+            let param = if body.contains("o.") { "o" } else { "_" };
+            rule_fns.push_str(&format!(
+                "fn {}({}: &PluralOperands) -> PluralCategory {{\n{}}}\n\n",
+                next_id, param, body
+            ));
+            next_id
+        })
+        .clone()
+}
+
+/// Locale ids to look up in order: the locale itself, then with its last
+/// subtag removed, down to the language.
+fn fallback_chain(name: &str) -> Vec<String> {
+    let parts: Vec<&str> = name.split('-').collect();
+    (1..=parts.len())
+        .rev()
+        .map(|n| parts[..n].join("-"))
+        .collect()
+}
+
+/// Emits the body of a rule function: one `if` per category with a rule,
+/// falling through to `Other`.
+fn rule_body(rules: &Value) -> Result<String, String> {
+    let mut body = String::new();
+    for (key, variant) in CATEGORIES {
+        let Some(rule) = rules[format!("pluralRule-count-{}", key)].as_str() else {
+            continue;
+        };
+        // Samples after `@` only document the rule.
+        let condition = rule.split('@').next().unwrap_or("").trim();
+        if condition.is_empty() {
+            continue;
+        }
+        body.push_str(&format!(
+            "    if {} {{\n        return PluralCategory::{};\n    }}\n",
+            rule_expression(condition)?,
+            variant
+        ));
+    }
+    body.push_str("    PluralCategory::Other\n");
+    Ok(body)
+}
+
+/// Translates a CLDR plural rule condition such as `i = 1 and v = 0` into a
+/// Rust boolean expression over a `PluralOperands` named `o`.
+fn rule_expression(condition: &str) -> Result<String, String> {
+    let tokens = tokenize(condition);
+    let mut or_terms = Vec::new();
+    for and_group in tokens.split(|t| t == "or") {
+        let relations: Vec<&[String]> = and_group.split(|t| t == "and").collect();
+        let mut and_terms = Vec::new();
+        for relation in &relations {
+            let (term, is_disjunction) = relation_expression(relation, condition)?;
+            if relations.len() > 1 && is_disjunction {
+                and_terms.push(format!("({})", term));
+            } else {
+                and_terms.push(term);
+            }
+        }
+        or_terms.push(and_terms.join(" && "));
+    }
+    Ok(or_terms.join(" || "))
+}
+
+/// Translates `operand [% modulus] (= | !=) range_list`, returning whether
+/// the expression is an `||` that needs parentheses next to other relations.
+fn relation_expression(tokens: &[String], condition: &str) -> Result<(String, bool), String> {
+    let error = || format!("Unsupported plural rule: {}", condition);
+    let (operand, rest) = tokens.split_first().ok_or_else(error)?;
+    let is_float = operand == "n";
+    let field = match operand.as_str() {
+        "n" | "i" | "v" | "w" | "f" | "t" | "e" => format!("o.{}", operand),
+        "c" => "o.e".to_string(),
+        _ => return Err(error()),
+    };
+    let (value, rest) = match rest {
+        [percent, modulus, rest @ ..] if percent == "%" => {
+            let modulus = if is_float {
+                format!("{}.0", modulus)
+            } else {
+                modulus.clone()
+            };
+            (format!("{} % {}", field, modulus), rest)
+        }
+        _ => (field, rest),
+    };
+    let (operator, ranges) = rest.split_first().ok_or_else(error)?;
+    let negated = match operator.as_str() {
+        "=" => false,
+        "!=" => true,
+        _ => return Err(error()),
+    };
+
+    let mut terms = Vec::new();
+    for range in ranges.split(|t| t == ",") {
+        let literal = |s: &String| {
+            if is_float {
+                format!("{}.0", s)
+            } else {
+                s.clone()
+            }
+        };
+        let term = match range {
+            [single] if single == "0" && !is_float && value.contains(" % ") => {
+                let multiple = value.replacen(" % ", ".is_multiple_of(", 1) + ")";
+                if negated {
+                    format!("!{}", multiple)
+                } else {
+                    multiple
+                }
+            }
+            [single] => {
+                let op = if negated { "!=" } else { "==" };
+                format!("{} {} {}", value, op, literal(single))
+            }
+            [low, dots, high] if dots == ".." => {
+                let operand = if value.contains(' ') {
+                    format!("({})", value)
+                } else {
+                    value.clone()
+                };
+                let contains = format!(
+                    "({}..={}).contains(&{})",
+                    literal(low),
+                    literal(high),
+                    operand
+                );
+                match (is_float, negated) {
+                    (false, false) => contains,
+                    (false, true) => format!("!{}", contains),
+                    (true, false) => format!("{}.fract() == 0.0 && {}", operand, contains),
+                    (true, true) => format!("{}.fract() != 0.0 || !{}", operand, contains),
+                }
+            }
+            _ => return Err(error()),
+        };
+        terms.push(term);
+    }
+    Ok(if negated && terms.len() > 1 {
+        let terms: Vec<String> = terms
+            .iter()
+            .map(|term| {
+                if term.contains(" || ") {
+                    format!("({})", term)
+                } else {
+                    term.clone()
+                }
+            })
+            .collect();
+        (terms.join(" && "), false)
+    } else if negated {
+        let term = terms.remove(0);
+        let is_disjunction = term.contains(" || ");
+        (term, is_disjunction)
+    } else {
+        let is_disjunction = terms.len() > 1;
+        (terms.join(" || "), is_disjunction)
+    })
+}
+
+fn tokenize(condition: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = condition.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '.' if chars.peek() == Some(&'.') => {
+                chars.next();
+                tokens.push("..".to_string());
+            }
+            '!' if chars.peek() == Some(&'=') => {
+                chars.next();
+                tokens.push("!=".to_string());
+            }
+            c if c.is_ascii_alphanumeric() => {
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !next.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(word);
+            }
+            _ => tokens.push(c.to_string()),
+        }
+    }
+    tokens
+}
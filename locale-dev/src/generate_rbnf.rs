@@ -0,0 +1,484 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+use crate::{intern_static, intern_table, sanitize_variant};
+
+/// CLDR rule groups and their `_RuleGroup` variants.
+const GROUPS: [(&str, &str); 3] = [
+    ("SpelloutRules", "Spellout"),
+    ("OrdinalRules", "Ordinal"),
+    ("NumberingSystemRules", "NumberingSystem"),
+];
+
+pub fn run(
+    zip_buffer: Vec<u8>,
+    _asset_name: &str,
+    output_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
+
+    // 1. Parent locales that differ from truncation, e.g. es-AR -> es-419
+    let mut parents: HashMap<String, String> = HashMap::new();
+    if let Ok(file) = archive.by_name("cldr-core/supplemental/parentLocales.json") {
+        let json: Value = serde_json::from_reader(file)?;
+        if let Some(map) = json["supplemental"]["parentLocales"]["parentLocale"].as_object() {
+            for (child, parent) in map {
+                if let Some(parent) = parent.as_str() {
+                    parents.insert(child.clone(), parent.to_string());
+                }
+            }
+        }
+    }
+
+    // 2. Rule groups by RBNF locale id
+    let mut rbnf: HashMap<String, Value> = HashMap::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        let Some(id) = file
+            .name()
+            .strip_prefix("cldr-rbnf/rbnf/")
+            .and_then(|name| name.strip_suffix(".json"))
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        let json: Value = serde_json::from_str(&content)?;
+        rbnf.insert(id, json["rbnf"]["rbnf"].clone());
+    }
+
+    // 3. Process Locales
+    let mut locales = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.name().contains("/main/") && file.is_dir() {
+            let parts: Vec<&str> = file.name().split('/').collect();
+            if let Some(idx) = parts.iter().position(|&r| r == "main")
+                && let Some(name) = parts.get(idx + 1)
+                && !name.is_empty()
+                && !locales.contains(&(*name).to_string())
+            {
+                locales.push((*name).to_string());
+            }
+        }
+    }
+    locales.sort();
+
+    let mut statics = String::new();
+    let mut static_ids: HashMap<String, usize> = HashMap::new();
+    let mut group_arms = String::new();
+    for (group, variant) in GROUPS {
+        let mut locale_arms = String::new();
+        for name in &locales {
+            let var = sanitize_variant(name);
+            // Each group comes whole from the closest locale having it.
+            let sets = locale_chain(name, &parents)
+                .iter()
+                .find_map(|id| rbnf.get(id).and_then(|groups| groups[group].as_object()));
+
+            let mut entries = Vec::new();
+            for (set_name, rules) in sets.into_iter().flatten() {
+                let rules = rule_literals(rules.as_array().map(Vec::as_slice).unwrap_or(&[]))?;
+                let id = intern_static(
+                    &mut static_ids,
+                    &mut statics,
+                    "_RULE_SET",
+                    "_RuleSet",
+                    &format!(
+                        "_RuleSet {{ name: {:?}, rules: &[\n    {}\n] }}",
+                        set_name,
+                        rules.join(",\n    ")
+                    ),
+                );
+                entries.push(format!("&{}", id));
+            }
+            let table = intern_table(
+                &mut static_ids,
+                &mut statics,
+                "_RULE_SETS",
+                "&_RuleSet",
+                &entries,
+            );
+            locale_arms.push_str(&format!("                Locale::{} => &{},\n", var, table));
+        }
+        group_arms.push_str(&format!(
+            "            _RuleGroup::{} => match self {{\n{}            }},\n",
+            variant, locale_arms
+        ));
+    }
+    let group_variants: Vec<&str> = GROUPS.iter().map(|(_, variant)| *variant).collect();
+
+    let code = format!(
+        r#"// Auto-generated. DO NOT EDIT.
+use crate::locale::Locale;
+use crate::num_formats::{{ToFormattedString, translate_digits}};
+use crate::plurals::PluralOperands;
+
+/// Base value marking the rule for negative numbers (`-x`).
+const _NEGATIVE: i64 = -1;
+/// Rule sets refer to each other; deeper nesting means a cycle in the data.
+const _MAX_DEPTH: usize = 32;
+
+/// Grammatical gender of spelled-out numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {{
+    Masculine,
+    Feminine,
+    Neuter,
+}}
+
+/// A CLDR rule-based number format (RBNF) rule set for [`Locale::spell_out`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RbnfRule {{
+    /// `%spellout-numbering`, e.g. "one thousand two hundred thirty-four"
+    SpelloutNumbering,
+    /// `%spellout-numbering-year`, e.g. "nineteen eighty-four"
+    SpelloutYear,
+    /// `%spellout-cardinal`, or its gendered variant such as
+    /// `%spellout-cardinal-feminine`
+    SpelloutCardinal(Option<Gender>),
+    /// `%spellout-ordinal`, or its gendered variant such as
+    /// `%spellout-ordinal-masculine`, e.g. "twenty-first"
+    SpelloutOrdinal(Option<Gender>),
+    /// `%roman-upper`, e.g. "MCMLXXXIV"
+    RomanUpper,
+    /// `%roman-lower`, e.g. "mcmlxxxiv"
+    RomanLower,
+    /// Any public rule set by its CLDR name, e.g.
+    /// `%spellout-cardinal-feminine-genitive`
+    Named(&'static str),
+}}
+
+impl RbnfRule {{
+    /// CLDR rule set names to try in order. Gendered rules fall back to the
+    /// plain rule set, and plain ones to the masculine rule set for
+    /// languages that only have gendered ones.
+    fn _candidates(&self) -> Vec<String> {{
+        let gendered = |kind: &str, gender: &Option<Gender>| match gender {{
+            Some(gender) => vec![
+                format!("%spellout-{{}}-{{}}", kind, format!("{{:?}}", gender).to_lowercase()),
+                format!("%spellout-{{}}", kind),
+            ],
+            None => vec![format!("%spellout-{{}}", kind), format!("%spellout-{{}}-masculine", kind)],
+        }};
+        match self {{
+            RbnfRule::SpelloutNumbering => vec!["%spellout-numbering".to_string()],
+            RbnfRule::SpelloutYear => vec!["%spellout-numbering-year".to_string()],
+            RbnfRule::SpelloutCardinal(gender) => gendered("cardinal", gender),
+            RbnfRule::SpelloutOrdinal(gender) => gendered("ordinal", gender),
+            RbnfRule::RomanUpper => vec!["%roman-upper".to_string()],
+            RbnfRule::RomanLower => vec!["%roman-lower".to_string()],
+            RbnfRule::Named(name) => vec![name.to_string()],
+        }}
+    }}
+}}
+
+/// A rule of a CLDR rule-based number format (RBNF) rule set.
+struct _Rule {{
+    /// Smallest number the rule applies to, or `_NEGATIVE`.
+    base: i64,
+    /// Power of the radix that `<<` and `>>` divide by.
+    divisor: i64,
+    /// Rule text with substitutions, e.g. `<< hundred[ >>];`.
+    body: &'static str,
+}}
+
+/// A named list of rules sorted by base value, e.g. `%digits-ordinal`.
+struct _RuleSet {{
+    name: &'static str,
+    rules: &'static [_Rule],
+}}
+
+/// Groups of rule sets in CLDR RBNF data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum _RuleGroup {{
+    {group_variants},
+}}
+
+impl Locale {{
+    /// Spells out an integer with a CLDR rule-based number format rule set,
+    /// or returns `None` if the locale has no such rule set.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::rbnf::{{Gender, RbnfRule}};
+    ///
+    /// assert_eq!(
+    ///     Locale::en.spell_out(1234, RbnfRule::SpelloutNumbering).as_deref(),
+    ///     Some("one thousand two hundred thirty-four")
+    /// );
+    /// assert_eq!(
+    ///     Locale::es.spell_out(21, RbnfRule::SpelloutCardinal(Some(Gender::Feminine))).as_deref(),
+    ///     Some("veintiuna")
+    /// );
+    /// assert_eq!(Locale::en.spell_out(1984, RbnfRule::RomanUpper).as_deref(), Some("MCMLXXXIV"));
+    /// ```
+    pub fn spell_out(&self, n: i64, rule: RbnfRule) -> Option<String> {{
+        let candidates = rule._candidates();
+        candidates
+            .iter()
+            .filter(|name| !name.starts_with("%%"))
+            .find_map(|name| {{
+                [_RuleGroup::Spellout, _RuleGroup::Ordinal, _RuleGroup::NumberingSystem]
+                    .into_iter()
+                    .find_map(|group| self._format_rbnf(n as i128, group, name))
+            }})
+    }}
+
+    /// Formats `value` with the rule set `name` of a group, or returns `None`
+    /// if the locale has no such rule set. `i128::MIN`, whose absolute value
+    /// the rules cannot work on, is formatted as a plain number.
+    pub(crate) fn _format_rbnf(&self, value: i128, group: _RuleGroup, name: &str) -> Option<String> {{
+        let sets = self._rbnf_rule_sets(group);
+        let set = sets.iter().find(|set| set.name == name)?;
+        if value.checked_abs().is_none() {{
+            return Some(value.to_formatted_string(self));
+        }}
+        Some(self._apply_rule_set(sets, set, value, 0))
+    }}
+
+    fn _apply_rule_set(&self, sets: &[&_RuleSet], set: &_RuleSet, value: i128, depth: usize) -> String {{
+        match _find_rule(set.rules, value) {{
+            Some(index) if depth < _MAX_DEPTH => self._apply_rule(sets, set, index, value, depth),
+            _ => value.to_formatted_string(self),
+        }}
+    }}
+
+    fn _apply_rule(&self, sets: &[&_RuleSet], set: &_RuleSet, index: usize, value: i128, depth: usize) -> String {{
+        let rule = &set.rules[index];
+        // The negative number rule applies its substitutions to the absolute value.
+        let (value, quotient, remainder) = if rule.base == _NEGATIVE {{
+            (value.abs(), value.abs(), value.abs())
+        }} else {{
+            (value, value / rule.divisor as i128, value % rule.divisor as i128)
+        }};
+        // Bracketed text is left out for exact multiples of the divisor, but
+        // only in rules whose base is itself a positive multiple of it.
+        let omit_optional = rule.base > 0 && rule.base % rule.divisor == 0 && remainder == 0;
+        let body = rule.body.strip_suffix(';').unwrap_or(rule.body);
+        // A leading apostrophe protects leading spaces.
+        let body = body.strip_prefix('\'').unwrap_or(body);
+
+        let mut result = String::new();
+        let mut rest = body;
+        while let Some(c) = rest.chars().next() {{
+            match c {{
+                '[' if omit_optional => {{
+                    rest = rest.find(']').map_or("", |end| &rest[end + 1..]);
+                }}
+                '[' | ']' => rest = &rest[1..],
+                '$' if rest.starts_with("$(") => {{
+                    let end = rest.find(")$").unwrap_or(rest.len() - 1);
+                    result.push_str(self._select_plural_text(&rest[2..end], quotient));
+                    rest = rest.get(end + 2..).unwrap_or("");
+                }}
+                '<' | '>' | '=' => {{
+                    // `>>>` formats the remainder with the rule before this one
+                    if rest.starts_with(">>>") {{
+                        let formatted = match index.checked_sub(1) {{
+                            Some(previous) if depth < _MAX_DEPTH => {{
+                                self._apply_rule(sets, set, previous, remainder, depth + 1)
+                            }}
+                            _ => remainder.to_formatted_string(self),
+                        }};
+                        result.push_str(&formatted);
+                        rest = &rest[3..];
+                        continue;
+                    }}
+                    let end = rest[1..].find(c).map_or(rest.len() - 1, |end| end + 1);
+                    let token = &rest[1..end];
+                    let operand = match c {{
+                        '<' => quotient,
+                        '>' => remainder,
+                        _ => value,
+                    }};
+                    result.push_str(&self._format_substitution(sets, set, token, operand, depth));
+                    rest = &rest[end + 1..];
+                }}
+                _ => {{
+                    result.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }}
+            }}
+        }}
+        result
+    }}
+
+    /// Formats a substitution: with the same rule set when `token` is empty,
+    /// another rule set for `%name`, or a decimal pattern such as `#,##0`.
+    fn _format_substitution(&self, sets: &[&_RuleSet], set: &_RuleSet, token: &str, value: i128, depth: usize) -> String {{
+        if token.is_empty() {{
+            return self._apply_rule_set(sets, set, value, depth + 1);
+        }}
+        if token.starts_with('%') {{
+            return match sets.iter().find(|set| set.name == token) {{
+                Some(named) => self._apply_rule_set(sets, named, value, depth + 1),
+                None => value.to_formatted_string(self),
+            }};
+        }}
+        self._format_decimal_pattern(token, value)
+    }}
+
+    /// Formats `value` with a decimal pattern such as `#,##0` or `00`, taking
+    /// grouping sizes from the pattern and symbols from the locale.
+    fn _format_decimal_pattern(&self, pattern: &str, value: i128) -> String {{
+        let integer = pattern.split('.').next().unwrap_or("");
+        let width = integer.matches('0').count();
+        let digits = format!("{{:0width$}}", value.unsigned_abs(), width = width);
+        // Group lengths from the right, e.g. [3, 2, 1] for `#,##,##0`.
+        let groups: Vec<usize> = integer.rsplit(',').map(str::len).collect();
+        let mut result = String::new();
+        if let [primary, rest @ ..] = groups.as_slice()
+            && !rest.is_empty()
+        {{
+            let secondary = if rest.len() > 1 {{ rest[0] }} else {{ *primary }};
+            let mut size = *primary;
+            let mut end = digits.len();
+            while end > size {{
+                result.insert_str(0, &digits[end - size..end]);
+                result.insert_str(0, self.grouping_separator());
+                end -= size;
+                size = secondary;
+            }}
+            result.insert_str(0, &digits[..end]);
+        }} else {{
+            result = digits;
+        }}
+        if value < 0 {{
+            result.insert_str(0, self.minus_sign());
+        }}
+        translate_digits(result, self)
+    }}
+
+    /// Picks the text for `value` from `ordinal,one{{st}}two{{nd}}other{{th}}`.
+    fn _select_plural_text<'a>(&self, selector: &'a str, value: i128) -> &'a str {{
+        let Some((kind, cases)) = selector.split_once(',') else {{
+            return "";
+        }};
+        let operands = PluralOperands::from(value.unsigned_abs().min(u64::MAX as u128) as u64);
+        let category = if kind == "ordinal" {{
+            self.ordinal_category(&operands)
+        }} else {{
+            self.plural_category(&operands)
+        }};
+        let key = format!("{{:?}}", category).to_lowercase();
+        let mut other = "";
+        let mut rest = cases;
+        while let Some((name, tail)) = rest.split_once('{{') {{
+            let Some((text, tail)) = tail.split_once('}}') else {{
+                break;
+            }};
+            if name == key {{
+                return text;
+            }}
+            if name == "other" {{
+                other = text;
+            }}
+            rest = tail;
+        }}
+        other
+    }}
+
+    fn _rbnf_rule_sets(&self, group: _RuleGroup) -> &'static [&'static _RuleSet] {{
+        match group {{
+{group_arms}        }}
+    }}
+}}
+
+/// Finds the rule for `value`: the last one whose base is not above it. A
+/// rule whose base is not a multiple of its divisor leaves exact multiples
+/// to the rule before it, e.g. 1100 in `1010/100: << >>` rules.
+fn _find_rule(rules: &[_Rule], value: i128) -> Option<usize> {{
+    if value < 0
+        && let Some(index) = rules.iter().position(|rule| rule.base == _NEGATIVE)
+    {{
+        return Some(index);
+    }}
+    // Without a negative number rule, the rule for the absolute value gets the
+    // negative value, e.g. to pass it on to another rule set.
+    let value = value.abs();
+    let index = rules
+        .iter()
+        .rposition(|rule| rule.base != _NEGATIVE && rule.base as i128 <= value)?;
+    let rule = &rules[index];
+    let divisor = rule.divisor as i128;
+    let has_modulus = rule.body.contains(">>") || rule.body.contains(">%") || rule.body.contains(">#") || rule.body.contains(">0");
+    if has_modulus && index > 0 && value % divisor == 0 && rule.base as i128 % divisor != 0 {{
+        return Some(index - 1);
+    }}
+    Some(index)
+}}
+
+{statics}"#,
+        group_variants = group_variants.join(",\n    "),
+        group_arms = group_arms,
+        statics = statics
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+/// The locale and its ancestors up to root, following CLDR parent locales
+/// and otherwise dropping the last subtag.
+fn locale_chain(name: &str, parents: &HashMap<String, String>) -> Vec<String> {
+    let mut chain = vec![name.to_string()];
+    let mut current = name.to_string();
+    while current != "root" {
+        current = match parents.get(&current) {
+            Some(parent) => parent.clone(),
+            None => match current.rsplit_once('-') {
+                Some((parent, _)) => parent.to_string(),
+                None => "root".to_string(),
+            },
+        };
+        chain.push(current.clone());
+    }
+    chain
+}
+
+/// Emits `_Rule` literals for the integer rules of a set; fraction, infinity
+/// and NaN rules are left out.
+fn rule_literals(rules: &[Value]) -> Result<Vec<String>, String> {
+    let mut literals = Vec::new();
+    for rule in rules {
+        let (Some(descriptor), Some(body)) = (rule[0].as_str(), rule[1].as_str()) else {
+            return Err(format!("Malformed RBNF rule: {}", rule));
+        };
+        let (base, divisor) = if descriptor == "-x" {
+            (-1, 1)
+        } else if let Some(base) = descriptor_base(descriptor) {
+            base
+        } else {
+            continue;
+        };
+        literals.push(format!(
+            "_Rule {{ base: {}, divisor: {}, body: {:?} }}",
+            base, divisor, body
+        ));
+    }
+    Ok(literals)
+}
+
+/// Reads `base[/radix][>...]` into the base value and its divisor, the
+/// highest power of the radix not above the base, one lower per `>`.
+fn descriptor_base(descriptor: &str) -> Option<(i64, i64)> {
+    let shifts = descriptor.len() - descriptor.trim_end_matches('>').len();
+    let descriptor = descriptor.trim_end_matches('>');
+    let (base, radix) = descriptor.split_once('/').unwrap_or((descriptor, "10"));
+    let number = |s: &str| s.replace(',', "").parse::<i64>().ok();
+    let (base, radix) = (number(base)?, number(radix)?);
+    let mut exponent = 0;
+    let mut power: i64 = 1;
+    while let Some(next) = power.checked_mul(radix).filter(|next| *next <= base) {
+        power = next;
+        exponent += 1;
+    }
+    let divisor = radix.pow((exponent as u32).saturating_sub(shifts as u32));
+    Some((base, divisor))
+}
@@ -0,0 +1,264 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use zip::ZipArchive;
+
+use crate::{intern_static, intern_table, sanitize_variant};
+
+/// CLDR field keys in `RelativeUnit` order.
+const UNITS: [&str; 8] = [
+    "second", "minute", "hour", "day", "week", "month", "quarter", "year",
+];
+/// Field key suffixes in `RelativeStyle` order.
+const STYLES: [&str; 3] = ["", "-short", "-narrow"];
+/// Plural categories in `PluralCategory` order.
+const COUNTS: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+pub fn run(
+    zip_buffer: Vec<u8>,
+    _asset_name: &str,
+    output_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
+
+    // 1. Process Locales
+    let mut locales = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.name().contains("/main/") && file.is_dir() {
+            let parts: Vec<&str> = file.name().split('/').collect();
+            if let Some(idx) = parts.iter().position(|&r| r == "main")
+                && let Some(name) = parts.get(idx + 1)
+                && !name.is_empty()
+                && !locales.contains(&(*name).to_string())
+            {
+                locales.push((*name).to_string());
+            }
+        }
+    }
+    locales.sort();
+
+    let mut statics = String::new();
+    let mut table_ids: HashMap<String, usize> = HashMap::new();
+    let mut locale_arms = String::new();
+    for name in &locales {
+        let var = sanitize_variant(name);
+        let path = format!("cldr-dates-full/main/{}/dateFields.json", name);
+        let json: Value = match archive.by_name(&path) {
+            Ok(mut file) => serde_json::from_reader(&mut file)?,
+            Err(_) => Value::Null,
+        };
+        let fields = &json["main"][name]["dates"]["fields"];
+
+        let mut entries = Vec::new();
+        for unit in UNITS {
+            for (style, _) in STYLES.iter().enumerate() {
+                // Narrow falls back to short, short to long.
+                let field = STYLES[..=style]
+                    .iter()
+                    .rev()
+                    .map(|suffix| &fields[format!("{}{}", unit, suffix)])
+                    .find(|field| field.is_object())
+                    .unwrap_or(&Value::Null);
+                let id = intern_static(
+                    &mut table_ids,
+                    &mut statics,
+                    "_FIELD",
+                    "_RelativeField",
+                    &field_body(field),
+                );
+                entries.push(format!("&{}", id));
+            }
+        }
+        let table = intern_table(
+            &mut table_ids,
+            &mut statics,
+            "_RELATIVE_FIELDS",
+            "&_RelativeField",
+            &entries,
+        );
+        locale_arms.push_str(&format!("            Locale::{} => &{},\n", var, table));
+    }
+
+    let code = format!(
+        r#"// Auto-generated. DO NOT EDIT.
+use crate::datetime_formats::{{_days_from_civil, DateTime}};
+use crate::locale::Locale;
+#[cfg(feature = "nums")]
+use crate::num_formats::ToFormattedString;
+use crate::plurals::PluralOperands;
+
+/// Unit of a relative time, from CLDR `dateFields`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeUnit {{
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}}
+
+/// Length of a relative time phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeStyle {{
+    /// e.g. `in 3 days`
+    Long,
+    /// e.g. `in 3 days`, `in 3 mo.`
+    Short,
+    /// e.g. `in 3d`
+    Narrow,
+}}
+
+/// Relative time patterns of one unit and style.
+struct _RelativeField {{
+    /// Special phrases by offset, e.g. `(-1, "yesterday")`.
+    relatives: &'static [(i8, &'static str)],
+    /// Patterns by plural category; empty ones use the `other` pattern.
+    future: [&'static str; 6],
+    past: [&'static str; 6],
+}}
+
+impl RelativeUnit {{
+    /// Picks the unit that reads most naturally for the distance from `now`
+    /// to `datetime`, and returns the value to format in it, negative for
+    /// the past. Units up to hours count elapsed time, larger ones count
+    /// calendar days and months.
+    pub fn best_fit(datetime: &DateTime, now: &DateTime) -> (i64, RelativeUnit) {{
+        let seconds = datetime.unix_timestamp() - now.unix_timestamp();
+        if seconds.abs() < 60 {{
+            return (seconds, RelativeUnit::Second);
+        }}
+        if seconds.abs() < 3600 {{
+            return (seconds / 60, RelativeUnit::Minute);
+        }}
+        if seconds.abs() < 86400 {{
+            return (seconds / 3600, RelativeUnit::Hour);
+        }}
+        let days = _days_from_civil(datetime.year as i64, datetime.month, datetime.day)
+            - _days_from_civil(now.year as i64, now.month, now.day);
+        if days.abs() < 7 {{
+            return (days, RelativeUnit::Day);
+        }}
+        let mut months = (datetime.year as i64 - now.year as i64) * 12 + datetime.month as i64
+            - now.month as i64;
+        // A month only counts once its day of the month is reached.
+        if months > 0 && datetime.day < now.day {{
+            months -= 1;
+        }} else if months < 0 && datetime.day > now.day {{
+            months += 1;
+        }}
+        if months == 0 {{
+            (days / 7, RelativeUnit::Week)
+        }} else if months.abs() < 12 {{
+            (months, RelativeUnit::Month)
+        }} else {{
+            (months / 12, RelativeUnit::Year)
+        }}
+    }}
+}}
+
+impl Locale {{
+    /// Formats `value` units from now, e.g. `-3` days as "3 days ago".
+    /// Offsets with a phrase of their own use it instead, e.g. "yesterday"
+    /// for `-1` days or "this month" for `0` months.
+    pub fn format_relative(&self, value: i64, unit: RelativeUnit, style: RelativeStyle) -> String {{
+        let field = self._relative_field(unit, style);
+        if let Ok(offset) = i8::try_from(value)
+            && let Some((_, phrase)) = field.relatives.iter().find(|(o, _)| *o == offset)
+        {{
+            return phrase.to_string();
+        }}
+        self._format_relative_pattern(field, value)
+    }}
+
+    /// Formats `value` units from now, always with a number, e.g. "in 1 day"
+    /// where [`Locale::format_relative`] gives "tomorrow".
+    pub fn format_relative_numeric(
+        &self,
+        value: i64,
+        unit: RelativeUnit,
+        style: RelativeStyle,
+    ) -> String {{
+        self._format_relative_pattern(self._relative_field(unit, style), value)
+    }}
+
+    /// Formats `datetime` relative to `now` in the unit picked by
+    /// [`RelativeUnit::best_fit`], e.g. "2 hours ago" or "next month".
+    pub fn format_relative_datetime(
+        &self,
+        datetime: &DateTime,
+        now: &DateTime,
+        style: RelativeStyle,
+    ) -> String {{
+        let (value, unit) = RelativeUnit::best_fit(datetime, now);
+        self.format_relative(value, unit, style)
+    }}
+
+    fn _relative_field(&self, unit: RelativeUnit, style: RelativeStyle) -> &'static _RelativeField {{
+        self._relative_fields()[unit as usize * 3 + style as usize]
+    }}
+
+    fn _format_relative_pattern(&self, field: &_RelativeField, value: i64) -> String {{
+        let patterns = if value < 0 {{ &field.past }} else {{ &field.future }};
+        let category = self.plural_category(&PluralOperands::from_integer(value));
+        let pattern = match patterns[category as usize] {{
+            "" => patterns[5],
+            pattern => pattern,
+        }};
+        #[cfg(feature = "nums")]
+        let number = value.unsigned_abs().to_formatted_string(self);
+        #[cfg(not(feature = "nums"))]
+        let number = value.unsigned_abs().to_string();
+        pattern.replace("{{0}}", &number)
+    }}
+
+    fn _relative_fields(&self) -> &'static [&'static _RelativeField; 24] {{
+        match self {{
+{locale_arms}        }}
+    }}
+}}
+
+{statics}"#,
+        locale_arms = locale_arms,
+        statics = statics
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+fn field_body(field: &Value) -> String {
+    let mut relatives: Vec<(i8, String)> = field
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, phrase)| {
+            let offset = key.strip_prefix("relative-type-")?.parse().ok()?;
+            Some((offset, phrase.as_str()?.to_string()))
+        })
+        .collect();
+    relatives.sort();
+    let relatives: Vec<String> = relatives
+        .iter()
+        .map(|(offset, phrase)| format!("({}, {:?})", offset, phrase))
+        .collect();
+    let patterns = |tense: &str| {
+        COUNTS.map(|count| {
+            field[format!("relativeTime-type-{}", tense)]
+                [format!("relativeTimePattern-count-{}", count)]
+            .as_str()
+            .unwrap_or("")
+            .to_string()
+        })
+    };
+    format!(
+        "_RelativeField {{ relatives: &[{}], future: {:?}, past: {:?} }}",
+        relatives.join(", "),
+        patterns("future"),
+        patterns("past")
+    )
+}
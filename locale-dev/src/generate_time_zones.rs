@@ -0,0 +1,1136 @@
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::{intern_static, intern_table, sanitize_variant};
+
+/// Compiled tz database read for the zone offset rules, overridable via `ZONEINFO`.
+const DEFAULT_ZONEINFO: &str = "/usr/share/zoneinfo";
+
+/// tz database release the generated offsets are pinned to. Regenerating
+/// against another release requires updating this on purpose.
+const TZDATA_VERSION: &str = "2025b";
+
+/// A zone from the CLDR bcp47 time zone list.
+struct Zone {
+    iana: String,
+    bcp47: String,
+    aliases: Vec<String>,
+    variant: String,
+}
+
+/// Daylight saving rule of a POSIX TZ string: offset and the (month, week,
+/// weekday, local seconds) at which it starts and ends.
+#[derive(Clone, Copy, PartialEq)]
+struct DstRule {
+    offset: i32,
+    start: (u32, u32, u32, i64),
+    end: (u32, u32, u32, i64),
+}
+
+/// Offset history of a zone read from its TZif file.
+struct ZoneRules {
+    transitions: Vec<(i64, i32, bool)>,
+    initial: (i32, bool),
+    std_offset: i32,
+    dst: Option<DstRule>,
+}
+
+/// Display names of one locale; the name arrays hold long generic, standard,
+/// daylight, then short generic, standard, daylight.
+#[derive(Default)]
+struct LocaleZoneNames {
+    cities: BTreeMap<String, String>,
+    zones: BTreeMap<String, [String; 6]>,
+    metazones: BTreeMap<String, [String; 6]>,
+}
+
+pub fn run(
+    zip_buffer: Vec<u8>,
+    _asset_name: &str,
+    output_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(zip_buffer))?;
+    let zoneinfo = std::env::var("ZONEINFO").unwrap_or_else(|_| DEFAULT_ZONEINFO.to_string());
+    let version = tzdata_version(Path::new(&zoneinfo))
+        .ok_or_else(|| format!("cannot read the tz database version in {}", zoneinfo))?;
+    if version != TZDATA_VERSION {
+        return Err(format!(
+            "{} holds tz database {}, but the generator is pinned to {}",
+            zoneinfo, version, TZDATA_VERSION
+        )
+        .into());
+    }
+
+    // 1. Zone identifiers from the bcp47 data, keeping zones the tz database knows
+    let mut zones = Vec::new();
+    if let Ok(mut file) = archive.by_name("cldr-bcp47/bcp47/timezone.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        if let Some(ids) = json["keyword"]["u"]["tz"].as_object() {
+            for (bcp47, data) in ids {
+                if data["_deprecated"].as_str() == Some("true") {
+                    continue;
+                }
+                let aliases: Vec<String> = data["_alias"]
+                    .as_str()
+                    .unwrap_or("")
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect();
+                let Some(iana) = data["_iana"]
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| aliases.first().cloned())
+                else {
+                    continue;
+                };
+                if !Path::new(&zoneinfo).join(&iana).is_file() {
+                    tracing::warn!("No tz database entry for {}, skipping", iana);
+                    continue;
+                }
+                zones.push(Zone {
+                    variant: zone_variant(&iana),
+                    iana,
+                    bcp47: bcp47.clone(),
+                    aliases,
+                });
+            }
+        }
+    }
+    zones.sort_by(|a, b| a.iana.cmp(&b.iana));
+    let mut by_alias = HashMap::new();
+    for zone in &zones {
+        for alias in zone.aliases.iter().chain([&zone.iana]) {
+            by_alias.insert(alias.to_ascii_lowercase(), zone.iana.clone());
+        }
+    }
+
+    // 2. Metazone periods
+    let mut metazone_periods: HashMap<String, Vec<(i64, String)>> = HashMap::new();
+    if let Ok(mut file) = archive.by_name("cldr-core/supplemental/metaZones.json") {
+        let json: Value = serde_json::from_reader(&mut file)?;
+        let mut uses = Vec::new();
+        collect_zone_tree(
+            &json["supplemental"]["metaZones"]["metazoneInfo"]["timezone"],
+            "",
+            &mut uses,
+        );
+        for (id, entries) in uses {
+            let Some(iana) = by_alias.get(&id.to_ascii_lowercase()) else {
+                continue;
+            };
+            let mut periods: Vec<(i64, String)> = Vec::new();
+            for entry in entries.as_array().into_iter().flatten() {
+                let data = &entry["usesMetazone"];
+                let Some(metazone) = data["_mzone"].as_str() else {
+                    continue;
+                };
+                let from = data["_from"].as_str().map_or(i64::MIN, metazone_timestamp);
+                if periods.last().is_some_and(|(at, _)| *at == from) {
+                    periods.pop();
+                }
+                periods.push((from, metazone.to_string()));
+                if let Some(to) = data["_to"].as_str() {
+                    periods.push((metazone_timestamp(to), String::new()));
+                }
+            }
+            metazone_periods.insert(iana.clone(), periods);
+        }
+    }
+
+    // 3. Process Locales
+    let mut locales = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.name().contains("/main/") && file.is_dir() {
+            let parts: Vec<&str> = file.name().split('/').collect();
+            if let Some(idx) = parts.iter().position(|&r| r == "main")
+                && let Some(name) = parts.get(idx + 1)
+                && !name.is_empty()
+                && !locales.contains(&(*name).to_string())
+            {
+                locales.push((*name).to_string());
+            }
+        }
+    }
+    locales.sort();
+
+    let variant_of: HashMap<&str, &str> = zones
+        .iter()
+        .map(|zone| (zone.iana.as_str(), zone.variant.as_str()))
+        .collect();
+    let mut name_statics = String::new();
+    let mut table_ids: HashMap<String, usize> = HashMap::new();
+    let mut locale_arms = String::new();
+    // Language locales by name: their static and metazone names, which
+    // regional locales inherit instead of repeating.
+    let mut language_tables: HashMap<String, (String, BTreeMap<String, [String; 6]>)> =
+        HashMap::new();
+
+    for name in &locales {
+        let var = sanitize_variant(name);
+        let path = format!("cldr-dates-full/main/{}/timeZoneNames.json", name);
+        let json: Value = match archive.by_name(&path) {
+            Ok(mut file) => serde_json::from_reader(&mut file)?,
+            Err(_) => Value::Null,
+        };
+        let tzn = &json["main"][name]["dates"]["timeZoneNames"];
+        let text = |key: &str, default: &str| tzn[key].as_str().unwrap_or(default).to_string();
+
+        let mut names = LocaleZoneNames::default();
+        let mut entries = Vec::new();
+        collect_zone_tree(&tzn["zone"], "", &mut entries);
+        for (id, data) in entries {
+            let Some(iana) = by_alias.get(&id.to_ascii_lowercase()) else {
+                continue;
+            };
+            if let Some(city) = data["exemplarCity"].as_str()
+                && city != default_city(iana)
+            {
+                names.cities.insert(iana.clone(), city.to_string());
+            }
+            let set = name_set(&data);
+            if set.iter().any(|n| !n.is_empty()) {
+                names.zones.insert(iana.clone(), set);
+            }
+        }
+        for (metazone, data) in tzn["metazone"].as_object().into_iter().flatten() {
+            let set = name_set(data);
+            if set.iter().any(|n| !n.is_empty()) {
+                names.metazones.insert(metazone.clone(), set);
+            }
+        }
+
+        let cities: Vec<String> = zones
+            .iter()
+            .map(|zone| {
+                format!(
+                    "{:?}",
+                    names.cities.get(&zone.iana).map_or("", String::as_str)
+                )
+            })
+            .collect();
+        let cities = intern_static(
+            &mut table_ids,
+            &mut name_statics,
+            "_CITIES",
+            &format!("[&str; {}]", cities.len()),
+            &format!("[{}]", cities.join(", ")),
+        );
+        let language = name.split('-').next().unwrap_or(name);
+        let parent = language_tables.get(language).filter(|_| language != name);
+        let mut own_metazones = names.metazones.clone();
+        if let Some((_, inherited)) = parent {
+            own_metazones.retain(|metazone, set| inherited.get(metazone) != Some(set));
+            for metazone in inherited.keys() {
+                // An empty entry hides a name the language locale has.
+                if !names.metazones.contains_key(metazone) {
+                    own_metazones.insert(metazone.clone(), Default::default());
+                }
+            }
+        }
+        let metazones: Vec<String> = own_metazones
+            .iter()
+            .map(|(metazone, set)| format!("({:?}, {:?})", metazone, set))
+            .collect();
+        let metazones = intern_table(
+            &mut table_ids,
+            &mut name_statics,
+            "_METAZONE_NAMES",
+            "(&str, [&str; 6])",
+            &metazones,
+        );
+        let zone_names: Vec<String> = names
+            .zones
+            .iter()
+            .map(|(iana, set)| format!("(TimeZone::{}, {:?})", variant_of[iana.as_str()], set))
+            .collect();
+
+        let hour_format = text("hourFormat", "+HH:mm;-HH:mm");
+        let (positive, negative) = hour_format.split_once(';').unwrap_or(("+HH:mm", "-HH:mm"));
+        let region = text("regionFormat", "{0}");
+        let body = format!(
+            "_ZoneNames {{\n    hour_format: ({:?}, {:?}),\n    gmt_format: {:?},\n    gmt_zero_format: {:?},\n    region_formats: [{:?}, {:?}, {:?}],\n    cities: &{},\n    zones: &[{}],\n    metazones: &{},\n    parent: {},\n}}",
+            positive,
+            negative,
+            text("gmtFormat", "GMT{0}"),
+            text("gmtZeroFormat", "GMT"),
+            region,
+            text("regionFormat-type-standard", &region),
+            text("regionFormat-type-daylight", &region),
+            cities,
+            zone_names.join(", "),
+            metazones,
+            parent.map_or("None".to_string(), |(id, _)| format!("Some(&{})", id)),
+        );
+        let id = intern_static(
+            &mut table_ids,
+            &mut name_statics,
+            "_ZONE_NAMES",
+            "_ZoneNames",
+            &body,
+        );
+        locale_arms.push_str(&format!("            Locale::{} => &{},\n", var, id));
+        if language == name {
+            language_tables.insert(name.clone(), (id, names.metazones));
+        }
+    }
+
+    // 4. Zone data
+    let mut variants = String::new();
+    let mut available = Vec::new();
+    let mut iana_arms = String::new();
+    let mut bcp47_arms = String::new();
+    let mut metazone_arms = String::new();
+    let mut rules_arms = String::new();
+    let mut from_str_arms = String::new();
+    for zone in &zones {
+        let rules = read_zone_rules(&Path::new(&zoneinfo).join(&zone.iana))?;
+        let var = &zone.variant;
+        variants.push_str(&format!("    {},\n", var));
+        available.push(format!("{:?}", zone.iana));
+        iana_arms.push_str(&format!(
+            "            TimeZone::{} => {:?},\n",
+            var, zone.iana
+        ));
+        bcp47_arms.push_str(&format!(
+            "            TimeZone::{} => {:?},\n",
+            var, zone.bcp47
+        ));
+        let periods: Vec<String> = metazone_periods
+            .get(&zone.iana)
+            .into_iter()
+            .flatten()
+            .map(|(from, metazone)| format!("({}, {:?})", i64_literal(*from), metazone))
+            .collect();
+        metazone_arms.push_str(&format!(
+            "            TimeZone::{} => &[{}],\n",
+            var,
+            periods.join(", ")
+        ));
+        let transitions: Vec<String> = rules
+            .transitions
+            .iter()
+            .map(|(at, offset, dst)| format!("({}, {}, {})", at, offset, dst))
+            .collect();
+        let dst = match rules.dst {
+            Some(rule) => format!(
+                "Some(_DstRule {{ offset: {}, start: {:?}, end: {:?} }})",
+                rule.offset, rule.start, rule.end
+            ),
+            None => "None".to_string(),
+        };
+        rules_arms.push_str(&format!(
+            "            TimeZone::{} => _ZoneRules {{ transitions: &[{}], initial: {:?}, std_offset: {}, dst: {} }},\n",
+            var,
+            transitions.join(", "),
+            rules.initial,
+            rules.std_offset,
+            dst
+        ));
+        let mut keys: Vec<String> = zone
+            .aliases
+            .iter()
+            .chain([&zone.iana])
+            .map(|alias| alias.to_ascii_lowercase())
+            .collect();
+        keys.sort();
+        keys.dedup();
+        let keys: Vec<String> = keys.iter().map(|key| format!("{:?}", key)).collect();
+        from_str_arms.push_str(&format!(
+            "            {} => Ok(TimeZone::{}),\n",
+            keys.join(" | "),
+            var
+        ));
+    }
+
+    let code = format!(
+        r#"// Auto-generated. DO NOT EDIT.
+// Zone offsets from tz database {tzdata_version}.
+use crate::datetime_formats::{{
+    _civil_from_days, _datetime_from_timestamp, _days_from_civil, _timestamp, DateTime,
+}};
+use crate::error::LocaleError;
+use crate::locale::Locale;
+use std::fmt;
+use std::str::FromStr;
+
+/// The IANA tz database release the zone offsets were compiled from.
+pub const TZDATA_VERSION: &str = "{tzdata_version}";
+
+/// Canonical IANA identifiers of every [`TimeZone`].
+pub const AVAILABLE_TIME_ZONES: &[&str] = &[{available}];
+
+/// An IANA time zone known to CLDR, e.g. `TimeZone::Europe_Paris`.
+///
+/// Offsets are resolved offline from the tz database compiled into the crate:
+/// the recorded transitions first, then the zone's current daylight saving rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(non_camel_case_types)]
+pub enum TimeZone {{
+{variants}
+}}
+
+/// The UTC offset in effect in a zone at some instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneOffset {{
+    /// Total offset from UTC in seconds, including daylight saving time.
+    pub seconds: i32,
+    /// Whether daylight saving time is in effect.
+    pub is_dst: bool,
+}}
+
+/// A wall-clock [`DateTime`] together with its UTC offset and, when known, its zone.
+///
+/// ```
+/// use locale_rs::datetime_formats::DateTime;
+/// use locale_rs::time_zones::{{TimeZone, ZonedDateTime}};
+///
+/// let dt = DateTime::new(2026, 7, 3, 14, 5, 9)?;
+/// let paris = ZonedDateTime::new(dt, TimeZone::Europe_Paris);
+/// assert_eq!(paris.offset.seconds, 7200);
+/// assert!(paris.offset.is_dst);
+/// assert_eq!(paris.to_utc().hour(), 12);
+/// # Ok::<(), locale_rs::error::LocaleError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZonedDateTime {{
+    pub datetime: DateTime,
+    pub offset: ZoneOffset,
+    pub zone: Option<TimeZone>,
+}}
+
+/// Daylight saving rule from a tzdb POSIX TZ string. `start` and `end` are
+/// (month, week, weekday, seconds after local midnight); week 5 means the last.
+#[derive(Clone, Copy)]
+struct _DstRule {{
+    offset: i32,
+    start: (u32, u32, u32, i64),
+    end: (u32, u32, u32, i64),
+}}
+
+/// Offset history of a zone: transitions as (UTC timestamp, offset, is_dst), the
+/// offset before the first of them and the rule in force after the last one.
+struct _ZoneRules {{
+    transitions: &'static [(i64, i32, bool)],
+    initial: (i32, bool),
+    std_offset: i32,
+    dst: Option<_DstRule>,
+}}
+
+/// Localized time zone display data of a locale.
+struct _ZoneNames {{
+    hour_format: (&'static str, &'static str),
+    gmt_format: &'static str,
+    gmt_zero_format: &'static str,
+    /// Generic, standard and daylight region formats, e.g. `{{0}} Time`.
+    region_formats: [&'static str; 3],
+    /// Exemplar cities indexed by zone; empty when the city is the last
+    /// segment of the zone id.
+    cities: &'static [&'static str],
+    /// Names given to single zones, laid out like `metazones`.
+    zones: &'static [(TimeZone, [&'static str; 6])],
+    /// Long generic, standard and daylight names, then the short ones.
+    /// Empty strings mark names CLDR does not define.
+    metazones: &'static [(&'static str, [&'static str; 6])],
+    /// Language locale whose metazone names apply where `metazones` has none.
+    parent: Option<&'static _ZoneNames>,
+}}
+
+impl TimeZone {{
+    /// Canonical IANA identifier, e.g. `Europe/Paris`.
+    pub fn iana_id(&self) -> &'static str {{
+        match self {{
+{iana_arms}
+        }}
+    }}
+
+    /// BCP 47 identifier as used by the `-u-tz-` extension, e.g. `frpar`.
+    pub fn bcp47_id(&self) -> &'static str {{
+        match self {{
+{bcp47_arms}
+        }}
+    }}
+
+    /// UTC offset in effect at the given UTC date and time.
+    ///
+    /// ```
+    /// use locale_rs::datetime_formats::DateTime;
+    /// use locale_rs::time_zones::TimeZone;
+    ///
+    /// let utc = DateTime::new(2026, 1, 3, 14, 5, 9)?;
+    /// assert_eq!(TimeZone::America_New_York.offset_at(&utc).seconds, -5 * 3600);
+    /// assert_eq!(TimeZone::Asia_Kolkata.offset_at(&utc).seconds, 19800);
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn offset_at(&self, utc: &DateTime) -> ZoneOffset {{
+        self._offset_at_timestamp(_timestamp(utc))
+    }}
+
+    /// UTC offset of a wall-clock time in this zone.
+    ///
+    /// Times repeated when clocks go back resolve to the earlier offset; times
+    /// skipped when clocks go forward use the offset from before the gap.
+    pub fn offset_for_local(&self, local: &DateTime) -> ZoneOffset {{
+        let local = _timestamp(local);
+        let before = self._offset_at_timestamp(local - 86_400);
+        let after = self._offset_at_timestamp(local + 86_400);
+        for candidate in [before, after] {{
+            if self._offset_at_timestamp(local - i64::from(candidate.seconds)) == candidate {{
+                return candidate;
+            }}
+        }}
+        before
+    }}
+
+    fn _offset_at_timestamp(&self, timestamp: i64) -> ZoneOffset {{
+        let rules = self._rules();
+        let (seconds, is_dst) = match rules.transitions.last() {{
+            Some(&(last, _, _)) if timestamp < last => {{
+                let index = rules
+                    .transitions
+                    .partition_point(|&(at, _, _)| at <= timestamp);
+                match index.checked_sub(1) {{
+                    Some(i) => (rules.transitions[i].1, rules.transitions[i].2),
+                    None => rules.initial,
+                }}
+            }}
+            _ => _rule_offset(rules.std_offset, rules.dst, timestamp),
+        }};
+        ZoneOffset {{ seconds, is_dst }}
+    }}
+
+    /// CLDR metazone the zone belongs to at `timestamp`, if any.
+    fn _metazone_at(&self, timestamp: i64) -> Option<&'static str> {{
+        let periods = self._metazone_periods();
+        let index = periods.partition_point(|&(from, _)| from <= timestamp);
+        index
+            .checked_sub(1)
+            .map(|i| periods[i].1)
+            .filter(|metazone| !metazone.is_empty())
+    }}
+
+    fn _metazone_periods(&self) -> &'static [(i64, &'static str)] {{
+        match self {{
+{metazone_arms}
+        }}
+    }}
+
+    fn _rules(&self) -> _ZoneRules {{
+        match self {{
+{rules_arms}
+        }}
+    }}
+}}
+
+impl fmt::Display for TimeZone {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        f.write_str(self.iana_id())
+    }}
+}}
+
+impl FromStr for TimeZone {{
+    type Err = LocaleError;
+
+    /// Parses an IANA identifier or one of its aliases, ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s.to_ascii_lowercase().as_str() {{
+{from_str_arms}
+            _ => Err(LocaleError::UnknownTimeZone(s.to_string())),
+        }}
+    }}
+}}
+
+impl ZonedDateTime {{
+    /// Places a wall-clock time in `zone`. Times skipped when clocks go forward
+    /// are moved forward by the length of the gap.
+    pub fn new(datetime: DateTime, zone: TimeZone) -> Self {{
+        let offset = zone.offset_for_local(&datetime);
+        Self::_from_timestamp(_timestamp(&datetime) - i64::from(offset.seconds), zone)
+    }}
+
+    /// Converts a UTC date and time to the wall-clock time in `zone`.
+    pub fn from_utc(utc: DateTime, zone: TimeZone) -> Self {{
+        Self::_from_timestamp(_timestamp(&utc), zone)
+    }}
+
+    /// A wall-clock time at a fixed UTC offset, without a named zone.
+    pub fn with_offset(datetime: DateTime, offset_seconds: i32) -> Self {{
+        ZonedDateTime {{
+            datetime,
+            offset: ZoneOffset {{
+                seconds: offset_seconds,
+                is_dst: false,
+            }},
+            zone: None,
+        }}
+    }}
+
+    /// The same instant in UTC.
+    pub fn to_utc(&self) -> DateTime {{
+        _datetime_from_timestamp(self._timestamp())
+    }}
+
+    fn _from_timestamp(utc: i64, zone: TimeZone) -> Self {{
+        let offset = zone._offset_at_timestamp(utc);
+        ZonedDateTime {{
+            datetime: _datetime_from_timestamp(utc + i64::from(offset.seconds)),
+            offset,
+            zone: Some(zone),
+        }}
+    }}
+
+    fn _timestamp(&self) -> i64 {{
+        _timestamp(&self.datetime) - i64::from(self.offset.seconds)
+    }}
+}}
+
+impl Locale {{
+    /// Localized GMT format of a UTC offset: short `GMT+1`, long `GMT+01:00`,
+    /// and the locale's own word for GMT itself.
+    ///
+    /// ```
+    /// use locale_rs::Locale;
+    ///
+    /// assert_eq!(Locale::en.format_gmt_offset(3600, false), "GMT+1");
+    /// assert_eq!(Locale::en.format_gmt_offset(-34200, true), "GMT-09:30");
+    /// assert_eq!(Locale::fr.format_gmt_offset(0, true), "UTC");
+    /// ```
+    pub fn format_gmt_offset(&self, offset_seconds: i32, long: bool) -> String {{
+        let names = self._zone_names();
+        let minutes = offset_seconds.unsigned_abs() / 60;
+        if minutes == 0 {{
+            return names.gmt_zero_format.to_string();
+        }}
+        let hour_format = if offset_seconds < 0 {{
+            names.hour_format.1
+        }} else {{
+            names.hour_format.0
+        }};
+        let offset = _apply_hour_format(hour_format, minutes / 60, minutes % 60, long);
+        names.gmt_format.replace("{{0}}", &offset)
+    }}
+
+    /// City that identifies `zone` in this locale, e.g. `Ho-Chi-Minh-Stadt` in German.
+    pub fn exemplar_city(&self, zone: TimeZone) -> String {{
+        match self._zone_names().cities.get(zone as usize) {{
+            Some(city) if !city.is_empty() => city.to_string(),
+            _ => zone
+                .iana_id()
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .replace('_', " "),
+        }}
+    }}
+
+    /// Formats one time zone pattern field: `z`, `Z`, `O`, `v`, `V`, `X` or `x`.
+    pub(crate) fn _format_time_zone(&self, c: char, count: usize, zdt: &ZonedDateTime) -> String {{
+        let offset = zdt.offset.seconds;
+        let specific = if zdt.offset.is_dst {{ 2 }} else {{ 1 }};
+        let name_or = |index: usize, fallback: &dyn Fn() -> String| {{
+            self._zone_name(zdt, index)
+                .map(str::to_string)
+                .unwrap_or_else(fallback)
+        }};
+        match (c, count) {{
+            ('z', 1..=3) => name_or(3 + specific, &|| self.format_gmt_offset(offset, false)),
+            ('z', _) => name_or(specific, &|| self.format_gmt_offset(offset, true)),
+            ('O', 1) => self.format_gmt_offset(offset, false),
+            ('O', _) | ('Z', 4) => self.format_gmt_offset(offset, true),
+            ('v', 1) => name_or(3, &|| self._generic_location(zdt, false)),
+            ('v', _) => name_or(0, &|| self._generic_location(zdt, true)),
+            ('V', 1) => zdt.zone.map_or("unk", |zone| zone.bcp47_id()).to_string(),
+            ('V', 2) => zdt.zone.map_or_else(
+                || self.format_gmt_offset(offset, true),
+                |zone| zone.iana_id().to_string(),
+            ),
+            ('V', 3) => zdt.zone.map_or_else(
+                || self.format_gmt_offset(offset, true),
+                |zone| self.exemplar_city(zone),
+            ),
+            ('V', _) => self._generic_location(zdt, true),
+            _ => _format_iso_offset(c, count, offset),
+        }}
+    }}
+
+    /// Zone name at `index` of the [`_ZoneNames`] layout: the zone's own name
+    /// first, then the name of its metazone at that instant, looked up through
+    /// the parent locales.
+    fn _zone_name(&self, zdt: &ZonedDateTime, index: usize) -> Option<&'static str> {{
+        let zone = zdt.zone?;
+        let names = self._zone_names();
+        let own = names
+            .zones
+            .binary_search_by_key(&zone, |&(z, _)| z)
+            .ok()
+            .map(|i| names.zones[i].1[index])
+            .filter(|name| !name.is_empty());
+        own.or_else(|| {{
+            let metazone = zone._metazone_at(zdt._timestamp())?;
+            let mut names = names;
+            loop {{
+                if let Ok(i) = names.metazones.binary_search_by_key(&metazone, |&(m, _)| m) {{
+                    return Some(names.metazones[i].1[index]).filter(|name| !name.is_empty());
+                }}
+                names = names.parent?;
+            }}
+        }})
+    }}
+
+    /// Generic location format, e.g. `Paris Time`, or the GMT format without a zone.
+    fn _generic_location(&self, zdt: &ZonedDateTime, long: bool) -> String {{
+        match zdt.zone {{
+            Some(zone) => self._zone_names().region_formats[0].replace("{{0}}", &self.exemplar_city(zone)),
+            None => self.format_gmt_offset(zdt.offset.seconds, long),
+        }}
+    }}
+
+    fn _zone_names(&self) -> &'static _ZoneNames {{
+        match self {{
+{locale_arms}
+        }}
+    }}
+}}
+
+/// ISO 8601 offsets for `X`, `x` and `Z`; `X` writes `Z` for UTC.
+fn _format_iso_offset(c: char, count: usize, offset_seconds: i32) -> String {{
+    let sign = if offset_seconds < 0 {{ '-' }} else {{ '+' }};
+    let hours = offset_seconds.unsigned_abs() / 3600;
+    let minutes = offset_seconds.unsigned_abs() / 60 % 60;
+
+    match (c, count) {{
+        ('X', _) | ('Z', 5) if offset_seconds == 0 => "Z".to_string(),
+        ('X' | 'x', 1) if minutes == 0 => format!("{{}}{{:02}}", sign, hours),
+        ('X' | 'x', 1 | 2 | 4) | ('Z', 1..=3) => format!("{{}}{{:02}}{{:02}}", sign, hours, minutes),
+        _ => format!("{{}}{{:02}}:{{:02}}", sign, hours, minutes),
+    }}
+}}
+
+/// Fills a CLDR hour format such as `+HH:mm`. The short form drops the
+/// padding and, for whole hours, the minutes with their separator.
+fn _apply_hour_format(pattern: &str, hours: u32, minutes: u32, long: bool) -> String {{
+    let mut out = String::new();
+    let mut after_hours = 0;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {{
+        let mut count = 1;
+        while chars.peek() == Some(&c) {{
+            chars.next();
+            count += 1;
+        }}
+        match c {{
+            'H' => {{
+                if long && count > 1 {{
+                    out.push_str(&format!("{{:02}}", hours));
+                }} else {{
+                    out.push_str(&hours.to_string());
+                }}
+                after_hours = out.len();
+            }}
+            'm' if !long && minutes == 0 => out.truncate(after_hours),
+            'm' => out.push_str(&format!("{{:02}}", minutes)),
+            _ => (0..count).for_each(|_| out.push(c)),
+        }}
+    }}
+    out
+}}
+
+/// Offset given by a zone's standard offset and daylight saving rule.
+fn _rule_offset(std_offset: i32, dst: Option<_DstRule>, timestamp: i64) -> (i32, bool) {{
+    let Some(rule) = dst else {{
+        return (std_offset, false);
+    }};
+    let year = _civil_from_days((timestamp + i64::from(std_offset)).div_euclid(86_400)).0;
+    let start = _rule_transition(year, rule.start) - i64::from(std_offset);
+    let end = _rule_transition(year, rule.end) - i64::from(rule.offset);
+    let in_dst = if start < end {{
+        start <= timestamp && timestamp < end
+    }} else {{
+        timestamp < end || start <= timestamp
+    }};
+    if in_dst {{
+        (rule.offset, true)
+    }} else {{
+        (std_offset, false)
+    }}
+}}
+
+/// Local time, in seconds since the epoch, at which a POSIX `Mm.w.d/time`
+/// rule fires in `year`.
+fn _rule_transition(year: i64, (month, week, weekday, time): (u32, u32, u32, i64)) -> i64 {{
+    let first = _days_from_civil(year, month, 1);
+    let next_month = if month == 12 {{
+        _days_from_civil(year + 1, 1, 1)
+    }} else {{
+        _days_from_civil(year, month + 1, 1)
+    }};
+    // 1970-01-01 was a Thursday.
+    let first_weekday = (first + 4).rem_euclid(7);
+    let mut day = first + (i64::from(weekday) - first_weekday).rem_euclid(7) + i64::from(week - 1) * 7;
+    while day >= next_month {{
+        day -= 7;
+    }}
+    day * 86_400 + time
+}}
+
+{name_statics}
+"#,
+        available = available.join(", "),
+        variants = variants,
+        iana_arms = iana_arms,
+        bcp47_arms = bcp47_arms,
+        metazone_arms = metazone_arms,
+        rules_arms = rules_arms,
+        from_str_arms = from_str_arms,
+        locale_arms = locale_arms,
+        name_statics = name_statics,
+        tzdata_version = TZDATA_VERSION,
+    );
+
+    fs::write(output_path, code)?;
+    Ok(())
+}
+
+/// Enum variant for an IANA id: `Europe/Paris` becomes `Europe_Paris`,
+/// `Etc/GMT-5` becomes `Etc_GMT_minus_5`.
+/// Reads the release of a compiled tz database from `tzdata.zi`
+/// (`# version 2025b`) or, failing that, from `+VERSION`.
+fn tzdata_version(zoneinfo: &Path) -> Option<String> {
+    if let Ok(text) = fs::read_to_string(zoneinfo.join("tzdata.zi"))
+        && let Some(version) = text
+            .lines()
+            .next()
+            .and_then(|l| l.strip_prefix("# version "))
+    {
+        return Some(version.trim().to_string());
+    }
+    fs::read_to_string(zoneinfo.join("+VERSION"))
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn zone_variant(iana: &str) -> String {
+    let mut variant = String::new();
+    let mut chars = iana.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '+' => variant.push_str("_plus_"),
+            '-' if chars.peek().is_some_and(char::is_ascii_digit) => variant.push_str("_minus_"),
+            '/' | '-' => variant.push('_'),
+            _ => variant.push(c),
+        }
+    }
+    sanitize_variant(&variant)
+}
+
+/// Exemplar city CLDR derives when a locale has none: the last id segment.
+fn default_city(iana: &str) -> String {
+    iana.rsplit('/').next().unwrap_or(iana).replace('_', " ")
+}
+
+/// Flattens CLDR's nested zone objects (`{"Europe": {"Paris": ...}}`) into
+/// `("Europe/Paris", data)` pairs.
+fn collect_zone_tree(node: &Value, prefix: &str, out: &mut Vec<(String, Value)>) {
+    let Some(map) = node.as_object() else {
+        return;
+    };
+    for (key, child) in map {
+        let id = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}/{}", prefix, key)
+        };
+        let is_leaf = child.is_array()
+            || ["exemplarCity", "long", "short"]
+                .iter()
+                .any(|k| child.get(k).is_some());
+        if is_leaf {
+            out.push((id, child.clone()));
+        } else {
+            collect_zone_tree(child, &id, out);
+        }
+    }
+}
+
+fn name_set(data: &Value) -> [String; 6] {
+    let mut set: [String; 6] = Default::default();
+    for (i, width) in ["long", "short"].iter().enumerate() {
+        for (j, kind) in ["generic", "standard", "daylight"].iter().enumerate() {
+            set[i * 3 + j] = data[*width][*kind].as_str().unwrap_or("").to_string();
+        }
+    }
+    set
+}
+
+/// Emits `entries` as a static array once and returns its name.
+fn i64_literal(value: i64) -> String {
+    if value == i64::MIN {
+        "i64::MIN".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parses a metaZones.json `"1991-03-30 23:00"` stamp as UTC.
+fn metazone_timestamp(stamp: &str) -> i64 {
+    let number = |range: std::ops::Range<usize>| {
+        stamp
+            .get(range)
+            .and_then(|s| s.parse::<i64>().ok())
+            .unwrap_or(0)
+    };
+    let days = days_from_civil(number(0..4), number(5..7) as u32, number(8..10) as u32);
+    days * 86_400 + number(11..13) * 3600 + number(14..16) * 60
+}
+
+/// Reads a TZif (version 2+) file: transitions whose offset or daylight flag
+/// changes, trimmed to those the trailing POSIX rule does not reproduce.
+fn read_zone_rules(path: &Path) -> Result<ZoneRules, Box<dyn std::error::Error>> {
+    let data = fs::read(path)?;
+    if data.len() < 44 || &data[..4] != b"TZif" || data[4] == 0 {
+        return Err(format!("{}: not a TZif v2+ file", path.display()).into());
+    }
+    let read_u32 = |at: usize| {
+        u32::from_be_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]]) as usize
+    };
+    // isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt
+    let counts = |at: usize| [20, 24, 28, 32, 36, 40].map(|offset| read_u32(at + offset));
+    let [isut, isstd, leap, time, types, chars] = counts(0);
+    let header = 44 + time * 5 + types * 6 + chars + leap * 8 + isstd + isut;
+    let [isut, isstd, leap, time, types, chars] = counts(header);
+    let mut pos = header + 44;
+
+    let mut times = Vec::with_capacity(time);
+    for i in 0..time {
+        let at = pos + i * 8;
+        times.push(i64::from_be_bytes(data[at..at + 8].try_into()?));
+    }
+    pos += time * 8;
+    let indices = &data[pos..pos + time];
+    pos += time;
+    let mut infos = Vec::with_capacity(types);
+    for i in 0..types {
+        let at = pos + i * 6;
+        let offset = i32::from_be_bytes(data[at..at + 4].try_into()?);
+        infos.push((offset, data[at + 4] != 0));
+    }
+    pos += types * 6 + chars + leap * 12 + isstd + isut;
+    let footer = data
+        .get(pos + 1..)
+        .and_then(|rest| rest.split(|&b| b == b'\n').next())
+        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+        .unwrap_or_default();
+
+    let initial = infos.first().copied().unwrap_or((0, false));
+    let mut transitions: Vec<(i64, i32, bool)> = Vec::new();
+    let mut current = initial;
+    for (at, index) in times.iter().zip(indices) {
+        let info = infos[*index as usize];
+        if info != current {
+            transitions.push((*at, info.0, info.1));
+            current = info;
+        }
+    }
+
+    let (mut std_offset, mut dst) = parse_posix_tz(&footer).unwrap_or((current.0, None));
+    // Zones like Europe/Dublin use negative DST (winter time is "daylight");
+    // CLDR names summer time the daylight time, so swap the two.
+    if let Some(rule) = dst
+        && rule.offset < std_offset
+    {
+        let (summer, winter) = (std_offset, rule.offset);
+        dst = Some(DstRule {
+            offset: summer,
+            start: rule.end,
+            end: rule.start,
+        });
+        std_offset = winter;
+        for transition in &mut transitions {
+            if transition.1 == summer {
+                transition.2 = true;
+            } else if transition.1 == winter {
+                transition.2 = false;
+            }
+        }
+    }
+
+    // Drop the tail of transitions the POSIX rule reproduces.
+    let mut cut = transitions.len();
+    if let Some(&(at, offset, is_dst)) = transitions.last()
+        && rule_offset(std_offset, dst, at) == (offset, is_dst)
+    {
+        cut -= 1;
+        while cut > 0 {
+            let (at, offset, is_dst) = transitions[cut - 1];
+            if rule_offset(std_offset, dst, at) != (offset, is_dst)
+                || rule_changes_between(std_offset, dst, at, transitions[cut].0)
+            {
+                break;
+            }
+            cut -= 1;
+        }
+        cut += 1;
+    }
+    transitions.truncate(cut);
+
+    Ok(ZoneRules {
+        transitions,
+        initial,
+        std_offset,
+        dst,
+    })
+}
+
+/// Parses a POSIX TZ string such as `CET-1CEST,M3.5.0,M10.5.0/3` into the
+/// standard UTC offset and the daylight saving rule.
+fn parse_posix_tz(tz: &str) -> Option<(i32, Option<DstRule>)> {
+    let mut rest = tz;
+    rest = skip_zone_abbreviation(rest)?;
+    let (std, after) = parse_posix_time(rest)?;
+    let std_offset = -(std as i32);
+    rest = after;
+    if rest.is_empty() {
+        return Some((std_offset, None));
+    }
+    rest = skip_zone_abbreviation(rest)?;
+    let mut dst_offset = std_offset + 3600;
+    if !rest.starts_with(',') {
+        let (dst, after) = parse_posix_time(rest)?;
+        dst_offset = -(dst as i32);
+        rest = after;
+    }
+    let mut rules = rest.strip_prefix(',')?.split(',');
+    let start = parse_posix_rule(rules.next()?)?;
+    let end = parse_posix_rule(rules.next()?)?;
+    Some((
+        std_offset,
+        Some(DstRule {
+            offset: dst_offset,
+            start,
+            end,
+        }),
+    ))
+}
+
+fn skip_zone_abbreviation(tz: &str) -> Option<&str> {
+    if let Some(quoted) = tz.strip_prefix('<') {
+        return quoted.split_once('>').map(|(_, rest)| rest);
+    }
+    let end = tz
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(tz.len());
+    (end >= 3).then(|| &tz[end..])
+}
+
+/// Parses `[+-]hh[:mm[:ss]]` into seconds and the remaining input.
+fn parse_posix_time(tz: &str) -> Option<(i64, &str)> {
+    let (sign, digits) = match tz.as_bytes().first()? {
+        b'-' => (-1, &tz[1..]),
+        b'+' => (1, &tz[1..]),
+        _ => (1, tz),
+    };
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit() && c != ':')
+        .unwrap_or(digits.len());
+    let mut seconds = 0;
+    for (i, part) in digits[..end].split(':').enumerate() {
+        seconds += part.parse::<i64>().ok()? * [3600, 60, 1].get(i)?;
+    }
+    Some((sign * seconds, &digits[end..]))
+}
+
+/// Parses `Mm.w.d[/time]`; the change happens at 02:00 local time by default.
+fn parse_posix_rule(rule: &str) -> Option<(u32, u32, u32, i64)> {
+    let (date, time) = match rule.split_once('/') {
+        Some((date, time)) => (date, parse_posix_time(time)?.0),
+        None => (rule, 7200),
+    };
+    let mut fields = date
+        .strip_prefix('M')?
+        .split('.')
+        .map(|f| f.parse::<u32>().ok());
+    Some((fields.next()??, fields.next()??, fields.next()??, time))
+}
+
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Calendar year of a timestamp, mirroring `_civil_from_days` in the generated code.
+fn year_of_timestamp(timestamp: i64) -> i64 {
+    let days = timestamp.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    year_of_era + era * 400 + i64::from(mp >= 10)
+}
+
+/// UTC timestamps of a rule's two changes in `year`.
+fn rule_changes(std_offset: i32, rule: DstRule, year: i64) -> [i64; 2] {
+    [
+        rule_transition(year, rule.start) - i64::from(std_offset),
+        rule_transition(year, rule.end) - i64::from(rule.offset),
+    ]
+}
+
+fn rule_transition(year: i64, (month, week, weekday, time): (u32, u32, u32, i64)) -> i64 {
+    let first = days_from_civil(year, month, 1);
+    let next_month = if month == 12 {
+        days_from_civil(year + 1, 1, 1)
+    } else {
+        days_from_civil(year, month + 1, 1)
+    };
+    let first_weekday = (first + 4).rem_euclid(7);
+    let mut day =
+        first + (i64::from(weekday) - first_weekday).rem_euclid(7) + i64::from(week - 1) * 7;
+    while day >= next_month {
+        day -= 7;
+    }
+    day * 86_400 + time
+}
+
+/// Mirrors `_rule_offset` in the generated code.
+fn rule_offset(std_offset: i32, dst: Option<DstRule>, timestamp: i64) -> (i32, bool) {
+    let Some(rule) = dst else {
+        return (std_offset, false);
+    };
+    let year = year_of_timestamp(timestamp + i64::from(std_offset));
+    let [start, end] = rule_changes(std_offset, rule, year);
+    let in_dst = if start < end {
+        start <= timestamp && timestamp < end
+    } else {
+        timestamp < end || start <= timestamp
+    };
+    if in_dst {
+        (rule.offset, true)
+    } else {
+        (std_offset, false)
+    }
+}
+
+/// Whether the rule changes the offset strictly between `from` and `to`.
+fn rule_changes_between(std_offset: i32, dst: Option<DstRule>, from: i64, to: i64) -> bool {
+    let Some(rule) = dst else {
+        return false;
+    };
+    (year_of_timestamp(from) - 1..=year_of_timestamp(to) + 1).any(|year| {
+        rule_changes(std_offset, rule, year)
+            .iter()
+            .any(|&at| from < at && at < to)
+    })
+}
@@ -1,10 +1,18 @@
+use std::collections::HashMap;
+
 pub mod download_latest;
 pub mod error;
 pub mod format;
+pub mod generate_calendars;
 pub mod generate_currency_formatting;
 pub mod generate_datetime_formatting;
+pub mod generate_durations;
 pub mod generate_locales;
 pub mod generate_num_formats;
+pub mod generate_plurals;
+pub mod generate_rbnf;
+pub mod generate_relative_time;
+pub mod generate_time_zones;
 
 #[cfg(test)]
 mod test;
@@ -25,3 +33,40 @@ pub fn sanitize_variant(name: &str) -> String {
         variant
     }
 }
+
+/// Interns a fixed-size array of `entries`, see [`intern_static`].
+pub fn intern_table(
+    ids: &mut HashMap<String, usize>,
+    statics: &mut String,
+    prefix: &str,
+    element: &str,
+    entries: &[String],
+) -> String {
+    let body = format!("[\n    {}\n]", entries.join(",\n    "));
+    let ty = format!("[{}; {}]", element, entries.len());
+    intern_static(ids, statics, prefix, &ty, &body)
+}
+
+/// Emits `static <prefix>_<n>: <ty> = <body>;` unless an identical one exists.
+pub fn intern_static(
+    ids: &mut HashMap<String, usize>,
+    statics: &mut String,
+    prefix: &str,
+    ty: &str,
+    body: &str,
+) -> String {
+    let key = format!("{}:{}", prefix, body);
+    if let Some(id) = ids.get(&key) {
+        return format!("{}_{}", prefix, id);
+    }
+    let id = ids
+        .keys()
+        .filter(|k| k.starts_with(&format!("{}:", prefix)))
+        .count();
+    ids.insert(key, id);
+    statics.push_str(&format!(
+        "#[rustfmt::skip]\nstatic {}_{}: {} = {};\n\n",
+        prefix, id, ty, body
+    ));
+    format!("{}_{}", prefix, id)
+}
@@ -29,6 +29,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 &asset.name,
                 locale_rs_src.join("currency_formats.rs").to_str().unwrap(),
             )?;
+            generate_time_zones::run(
+                asset.buffer.clone(),
+                &asset.name,
+                locale_rs_src.join("time_zones.rs").to_str().unwrap(),
+            )?;
+            generate_plurals::run(
+                asset.buffer.clone(),
+                &asset.name,
+                locale_rs_src.join("plurals.rs").to_str().unwrap(),
+            )?;
+            generate_relative_time::run(
+                asset.buffer.clone(),
+                &asset.name,
+                locale_rs_src.join("relative_time.rs").to_str().unwrap(),
+            )?;
+            generate_calendars::run(
+                asset.buffer.clone(),
+                &asset.name,
+                locale_rs_src.join("calendars.rs").to_str().unwrap(),
+            )?;
+            generate_durations::run(
+                asset.buffer.clone(),
+                &asset.name,
+                locale_rs_src.join("durations.rs").to_str().unwrap(),
+            )?;
+            generate_rbnf::run(
+                asset.buffer.clone(),
+                &asset.name,
+                locale_rs_src.join("rbnf.rs").to_str().unwrap(),
+            )?;
             format::format_generated_code();
         }
         None => {
@@ -22,14 +22,7 @@ fn bench_datetime_formatting(c: &mut Criterion) {
     group.warm_up_time(std::time::Duration::from_millis(500));
 
     let locale = Locale::en;
-    let dt = DateTime {
-        year: 2023,
-        month: 12,
-        day: 25,
-        hour: 14,
-        minute: 30,
-        second: 05,
-    };
+    let dt = DateTime::new(2023, 12, 25, 14, 30, 5).unwrap();
 
     group.bench_function("format_date", |b| {
         b.iter(|| locale.format_date(black_box(&dt)))
@@ -2,14 +2,7 @@
 fn main() {
     use locale_rs::{datetime_formats::DateTime, locale::Locale};
     // Create a base DateTime object
-    let dt = DateTime {
-        year: 2026,
-        month: 1,
-        day: 3,
-        hour: 14,
-        minute: 5,
-        second: 9,
-    };
+    let dt = DateTime::new(2026, 1, 3, 14, 5, 9).unwrap();
 
     // 1. Standard English (US) formatting
     let locale = Locale::en;
@@ -0,0 +1,4931 @@
+// Auto-generated. DO NOT EDIT.
+use crate::datetime_formats::{
+    _days_from_civil, _unicode_keyword, _without_zone_fields, DateStyle, DateTime,
+};
+use crate::error::LocaleError;
+use crate::locale::Locale;
+use crate::time_zones::ZonedDateTime;
+use std::fmt;
+use std::str::FromStr;
+
+/// Calendar system used to display a date, named after its BCP 47 `-u-ca-` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Calendar {
+    /// `gregory`, the proleptic Gregorian calendar of [`DateTime`]
+    #[default]
+    Gregorian,
+    /// `buddhist`, the Thai solar calendar counting from 543 BC
+    Buddhist,
+    /// `japanese`, Gregorian months with Japanese eras from Meiji on
+    Japanese,
+    /// `persian`, the Solar Hijri calendar of Iran and Afghanistan
+    Persian,
+    /// `islamic-umalqura`, the Hijri calendar of Saudi Arabia
+    IslamicUmalqura,
+    /// `islamic-civil`, the tabular Hijri calendar with a Friday epoch
+    IslamicCivil,
+    /// `hebrew`, the lunisolar Hebrew calendar
+    Hebrew,
+    /// `ethiopic`, the Ethiopian calendar counting from the Incarnation
+    Ethiopic,
+}
+
+/// A date in one of the supported calendar systems, see [`DateTime::to_calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDate {
+    pub calendar: Calendar,
+    /// Index of the era in the calendar's era names, e.g. `1` for AD, `0` for
+    /// the Buddhist era or `6` for the Japanese Reiwa era.
+    pub era: usize,
+    /// Year within the era.
+    pub year: i32,
+    /// Month of the year from 1. Hebrew leap years and the Ethiopic calendar
+    /// have 13 months; in a Hebrew leap year month 6 is Adar I and 7 Adar II.
+    pub month: u32,
+    pub day: u32,
+}
+
+/// Month and era names and date patterns of one calendar.
+struct _CalendarData {
+    /// Format abbreviated, wide and narrow month names, then stand-alone ones.
+    months: [&'static [&'static str]; 6],
+    /// Abbreviated, wide and narrow era names.
+    eras: [&'static [&'static str]; 3],
+    /// Short, medium, long and full date patterns.
+    date_patterns: [&'static str; 4],
+}
+
+impl Calendar {
+    /// Returns the BCP 47 calendar identifier, e.g. `islamic-umalqura`.
+    pub fn bcp47_id(&self) -> &'static str {
+        match self {
+            Calendar::Gregorian => "gregory",
+            Calendar::Buddhist => "buddhist",
+            Calendar::Japanese => "japanese",
+            Calendar::Persian => "persian",
+            Calendar::IslamicUmalqura => "islamic-umalqura",
+            Calendar::IslamicCivil => "islamic-civil",
+            Calendar::Hebrew => "hebrew",
+            Calendar::Ethiopic => "ethiopic",
+        }
+    }
+
+    /// Reads the `-u-ca-` keyword of a BCP 47 language tag.
+    ///
+    /// Returns `Ok(None)` if the tag has no calendar keyword.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::calendars::Calendar;
+    ///
+    /// assert_eq!(Calendar::from_locale_tag("th-TH-u-ca-buddhist"), Ok(Some(Calendar::Buddhist)));
+    /// assert_eq!(Calendar::from_locale_tag("ar-SA-u-nu-latn-ca-islamic-umalqura"), Ok(Some(Calendar::IslamicUmalqura)));
+    /// assert_eq!(Calendar::from_locale_tag("fa-IR"), Ok(None));
+    /// ```
+    pub fn from_locale_tag(tag: &str) -> Result<Option<Calendar>, LocaleError> {
+        _unicode_keyword(tag, "ca")
+            .map(|value| value.parse())
+            .transpose()
+    }
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.bcp47_id())
+    }
+}
+
+impl FromStr for Calendar {
+    type Err = LocaleError;
+
+    /// Parses a BCP 47 calendar identifier, e.g. `persian`. The aliases
+    /// `gregorian`, `ethiopian` and `islamicc` are accepted as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "gregory" | "gregorian" => Ok(Calendar::Gregorian),
+            "buddhist" => Ok(Calendar::Buddhist),
+            "japanese" => Ok(Calendar::Japanese),
+            "persian" => Ok(Calendar::Persian),
+            "islamic-umalqura" => Ok(Calendar::IslamicUmalqura),
+            "islamic-civil" | "islamicc" => Ok(Calendar::IslamicCivil),
+            "hebrew" => Ok(Calendar::Hebrew),
+            "ethiopic" | "ethiopian" => Ok(Calendar::Ethiopic),
+            _ => Err(LocaleError::UnknownCalendar(s.to_string())),
+        }
+    }
+}
+
+impl DateTime {
+    /// Converts the date into `calendar`. The time of day is the same in
+    /// every calendar, and days start at midnight.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::calendars::{Calendar, CalendarDate};
+    /// use locale_rs::datetime_formats::DateTime;
+    ///
+    /// let nowruz = DateTime::from_ymd(2026, 3, 21)?.to_calendar(Calendar::Persian);
+    /// assert_eq!((nowruz.year, nowruz.month, nowruz.day), (1405, 1, 1));
+    /// let reiwa = DateTime::from_ymd(2026, 1, 3)?.to_calendar(Calendar::Japanese);
+    /// assert_eq!((reiwa.era, reiwa.year), (6, 8));
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn to_calendar(&self, calendar: Calendar) -> CalendarDate {
+        let days = _days_from_civil(self.year as i64, self.month, self.day);
+        let (era, year, month, day) = match calendar {
+            Calendar::Gregorian => (
+                usize::from(self.year > 0),
+                _era_year(self.year),
+                self.month,
+                self.day,
+            ),
+            Calendar::Buddhist => (0, self.year as i64 + 543, self.month, self.day),
+            Calendar::Japanese => {
+                let era = _JAPANESE_ERAS.iter().rposition(|&(start, _)| start <= days);
+                match era {
+                    Some(i) => (
+                        i + 2,
+                        self.year as i64 - _JAPANESE_ERAS[i].1 as i64 + 1,
+                        self.month,
+                        self.day,
+                    ),
+                    None => (
+                        usize::from(self.year > 0),
+                        _era_year(self.year),
+                        self.month,
+                        self.day,
+                    ),
+                }
+            }
+            Calendar::Persian => {
+                let (year, month, day) = _persian_from_days(days);
+                (0, year, month, day)
+            }
+            Calendar::IslamicUmalqura => {
+                let (year, month, day) =
+                    _umalqura_from_days(days).unwrap_or_else(|| _islamic_civil_from_days(days));
+                (0, year, month, day)
+            }
+            Calendar::IslamicCivil => {
+                let (year, month, day) = _islamic_civil_from_days(days);
+                (0, year, month, day)
+            }
+            Calendar::Hebrew => {
+                let (year, month, day) = _hebrew_from_days(days);
+                (0, year, month, day)
+            }
+            Calendar::Ethiopic => {
+                let (year, month, day) = _ethiopic_from_days(days);
+                // Years before the Incarnation count from the creation, 5500 years earlier.
+                if year > 0 {
+                    (1, year, month, day)
+                } else {
+                    (0, year + 5500, month, day)
+                }
+            }
+        };
+        CalendarDate {
+            calendar,
+            era,
+            year: year.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            month,
+            day,
+        }
+    }
+}
+
+impl CalendarDate {
+    /// Position of the month in the calendar's month names. Hebrew names
+    /// follow CLDR: Adar I is sixth, Adar seventh, and Adar II is last.
+    fn _month_name_index(&self) -> usize {
+        let month = self.month as usize - 1;
+        if self.calendar != Calendar::Hebrew {
+            return month;
+        }
+        match (_hebrew_is_leap_year(self.year as i64), month) {
+            (true, 6) => 13,
+            (false, 5..) => month + 1,
+            _ => month,
+        }
+    }
+}
+
+impl Locale {
+    /// Parses a BCP 47 language tag that may carry a `-u-ca-` calendar
+    /// keyword, e.g. `th-TH-u-ca-buddhist`, into the locale and the calendar.
+    /// Without the keyword the calendar is Gregorian. Trailing subtags without
+    /// a locale of their own are dropped, so `th-TH` resolves to `th`.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::calendars::Calendar;
+    ///
+    /// assert_eq!(Locale::from_tag_with_calendar("th-TH-u-ca-buddhist"), Ok((Locale::th, Calendar::Buddhist)));
+    /// assert_eq!(Locale::from_tag_with_calendar("fa-u-ca-persian"), Ok((Locale::fa, Calendar::Persian)));
+    /// assert_eq!(Locale::from_tag_with_calendar("de-AT"), Ok((Locale::de_AT, Calendar::Gregorian)));
+    /// ```
+    pub fn from_tag_with_calendar(tag: &str) -> Result<(Locale, Calendar), LocaleError> {
+        let base = tag.replace('_', "-");
+        let base = match base.to_ascii_lowercase().find("-u-") {
+            Some(i) => &base[..i],
+            None => &base,
+        };
+        let calendar = Calendar::from_locale_tag(tag)?.unwrap_or_default();
+        let mut candidate = base;
+        let locale = loop {
+            if let Ok(locale) = Locale::from_flexible(candidate) {
+                break locale;
+            }
+            match candidate.rsplit_once('-') {
+                Some((shorter, _)) => candidate = shorter,
+                None => return Err(LocaleError::UnknownLocale(base.to_ascii_lowercase())),
+            }
+        };
+        Ok((locale, calendar))
+    }
+
+    /// Returns the date pattern of the given length in `calendar`, which for
+    /// calendars with eras usually includes the era, e.g. `MMM d, y G`.
+    pub fn date_pattern_in(&self, style: DateStyle, calendar: Calendar) -> &'static str {
+        match self._calendar_data(calendar) {
+            Some(data) => data.date_patterns[style as usize],
+            None => self.date_pattern(style),
+        }
+    }
+
+    /// Formats the date part of `dt` in `calendar` with the pattern of the
+    /// given length.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::calendars::Calendar;
+    /// use locale_rs::datetime_formats::{DateStyle, DateTime};
+    ///
+    /// let dt = DateTime::from_ymd(2026, 1, 3)?;
+    /// assert_eq!(Locale::en.format_date_in(&dt, DateStyle::Long, Calendar::Buddhist), "January 3, 2569 BE");
+    /// assert_eq!(Locale::ja.format_date_in(&dt, DateStyle::Long, Calendar::Japanese), "令和8年1月3日");
+    /// # Ok::<(), locale_rs::error::LocaleError>(())
+    /// ```
+    pub fn format_date_in(&self, dt: &DateTime, style: DateStyle, calendar: Calendar) -> String {
+        self.format_with_pattern_in(dt, self.date_pattern_in(style, calendar), calendar)
+    }
+
+    /// Formats `dt` with an explicit CLDR pattern in `calendar`. The era
+    /// (`G`), year (`y`), month (`M`, `L`) and day (`d`) fields follow the
+    /// calendar; other fields such as the weekday are the same in every
+    /// calendar, and week, quarter and day-of-year fields stay Gregorian.
+    pub fn format_with_pattern_in(
+        &self,
+        dt: &DateTime,
+        pattern: &str,
+        calendar: Calendar,
+    ) -> String {
+        let date = dt.to_calendar(calendar);
+        self._parse_calendar_pattern(
+            &_without_zone_fields(pattern),
+            &ZonedDateTime::with_offset(*dt, 0),
+            Some(&date),
+        )
+    }
+
+    /// Appends a `G`, `y`, `M`, `L` or `d` field of a non-Gregorian date and
+    /// returns `true`, or returns `false` for other fields.
+    pub(crate) fn _format_calendar_field(
+        &self,
+        out: &mut String,
+        c: char,
+        count: usize,
+        date: &CalendarDate,
+    ) -> bool {
+        let Some(data) = self._calendar_data(date.calendar) else {
+            return false;
+        };
+        let name =
+            |names: &'static [&'static str], index: usize| names.get(index).copied().unwrap_or("");
+        match (c, count) {
+            ('G', 4) => out.push_str(name(data.eras[1], date.era)),
+            ('G', 5) => out.push_str(name(data.eras[2], date.era)),
+            ('G', _) => out.push_str(name(data.eras[0], date.era)),
+            ('y' | 'U', 2) => out.push_str(&format!("{:02}", date.year.rem_euclid(100))),
+            ('y' | 'U', _) => out.push_str(&format!("{:0width$}", date.year, width = count)),
+            ('M' | 'L', 1 | 2) => out.push_str(&format!("{:0width$}", date.month, width = count)),
+            ('M' | 'L', _) => {
+                let width = match count {
+                    3 => 0,
+                    4 => 1,
+                    _ => 2,
+                };
+                let context = if c == 'L' { 3 } else { 0 };
+                out.push_str(name(data.months[context + width], date._month_name_index()));
+            }
+            ('d', _) => out.push_str(&format!("{:0width$}", date.day, width = count)),
+            _ => return false,
+        }
+        true
+    }
+
+    fn _calendar_data(&self, calendar: Calendar) -> Option<&'static _CalendarData> {
+        let index = match calendar {
+            Calendar::Gregorian => return None,
+            Calendar::Buddhist => 0,
+            Calendar::Japanese => 1,
+            Calendar::Persian => 2,
+            Calendar::IslamicUmalqura | Calendar::IslamicCivil => 3,
+            Calendar::Hebrew => 4,
+            Calendar::Ethiopic => 5,
+        };
+        Some(self._calendars()[index])
+    }
+
+    fn _calendars(&self) -> &'static [&'static _CalendarData; 6] {
+        match self {
+            Locale::aa => &_CALENDARS_0,
+            Locale::aa_DJ => &_CALENDARS_0,
+            Locale::aa_ER => &_CALENDARS_0,
+            Locale::ab => &_CALENDARS_0,
+            Locale::af => &_CALENDARS_1,
+            Locale::af_NA => &_CALENDARS_1,
+            Locale::agq => &_CALENDARS_0,
+            Locale::ak => &_CALENDARS_2,
+            Locale::am => &_CALENDARS_3,
+            Locale::an => &_CALENDARS_0,
+            Locale::ann => &_CALENDARS_0,
+            Locale::apc => &_CALENDARS_0,
+            Locale::ar => &_CALENDARS_4,
+            Locale::ar_AE => &_CALENDARS_4,
+            Locale::ar_BH => &_CALENDARS_4,
+            Locale::ar_DJ => &_CALENDARS_4,
+            Locale::ar_DZ => &_CALENDARS_5,
+            Locale::ar_EG => &_CALENDARS_4,
+            Locale::ar_EH => &_CALENDARS_4,
+            Locale::ar_ER => &_CALENDARS_4,
+            Locale::ar_IL => &_CALENDARS_4,
+            Locale::ar_IQ => &_CALENDARS_6,
+            Locale::ar_JO => &_CALENDARS_7,
+            Locale::ar_KM => &_CALENDARS_4,
+            Locale::ar_KW => &_CALENDARS_4,
+            Locale::ar_LB => &_CALENDARS_7,
+            Locale::ar_LY => &_CALENDARS_4,
+            Locale::ar_MA => &_CALENDARS_8,
+            Locale::ar_MR => &_CALENDARS_9,
+            Locale::ar_OM => &_CALENDARS_4,
+            Locale::ar_PS => &_CALENDARS_7,
+            Locale::ar_QA => &_CALENDARS_4,
+            Locale::ar_SA => &_CALENDARS_4,
+            Locale::ar_SD => &_CALENDARS_4,
+            Locale::ar_SO => &_CALENDARS_4,
+            Locale::ar_SS => &_CALENDARS_4,
+            Locale::ar_SY => &_CALENDARS_7,
+            Locale::ar_TD => &_CALENDARS_4,
+            Locale::ar_TN => &_CALENDARS_5,
+            Locale::ar_YE => &_CALENDARS_4,
+            Locale::arn => &_CALENDARS_0,
+            Locale::as_ => &_CALENDARS_10,
+            Locale::asa => &_CALENDARS_0,
+            Locale::ast => &_CALENDARS_11,
+            Locale::az => &_CALENDARS_12,
+            Locale::az_Arab => &_CALENDARS_0,
+            Locale::az_Arab_IQ => &_CALENDARS_0,
+            Locale::az_Arab_TR => &_CALENDARS_0,
+            Locale::az_Cyrl => &_CALENDARS_0,
+            Locale::az_Latn => &_CALENDARS_12,
+            Locale::ba => &_CALENDARS_0,
+            Locale::bal => &_CALENDARS_0,
+            Locale::bal_Arab => &_CALENDARS_0,
+            Locale::bal_Latn => &_CALENDARS_13,
+            Locale::bas => &_CALENDARS_0,
+            Locale::be => &_CALENDARS_14,
+            Locale::be_tarask => &_CALENDARS_14,
+            Locale::bem => &_CALENDARS_0,
+            Locale::bew => &_CALENDARS_0,
+            Locale::bez => &_CALENDARS_0,
+            Locale::bg => &_CALENDARS_15,
+            Locale::bgc => &_CALENDARS_16,
+            Locale::bgn => &_CALENDARS_0,
+            Locale::bgn_AE => &_CALENDARS_0,
+            Locale::bgn_AF => &_CALENDARS_0,
+            Locale::bgn_IR => &_CALENDARS_0,
+            Locale::bgn_OM => &_CALENDARS_0,
+            Locale::bho => &_CALENDARS_17,
+            Locale::blo => &_CALENDARS_18,
+            Locale::blt => &_CALENDARS_0,
+            Locale::bm => &_CALENDARS_0,
+            Locale::bm_Nkoo => &_CALENDARS_0,
+            Locale::bn => &_CALENDARS_19,
+            Locale::bn_IN => &_CALENDARS_20,
+            Locale::bo => &_CALENDARS_0,
+            Locale::bo_IN => &_CALENDARS_0,
+            Locale::bqi => &_CALENDARS_0,
+            Locale::br => &_CALENDARS_21,
+            Locale::brx => &_CALENDARS_22,
+            Locale::bs => &_CALENDARS_23,
+            Locale::bs_Cyrl => &_CALENDARS_24,
+            Locale::bs_Latn => &_CALENDARS_23,
+            Locale::bss => &_CALENDARS_0,
+            Locale::bua => &_CALENDARS_0,
+            Locale::byn => &_CALENDARS_0,
+            Locale::ca => &_CALENDARS_25,
+            Locale::ca_AD => &_CALENDARS_25,
+            Locale::ca_ES_valencia => &_CALENDARS_25,
+            Locale::ca_FR => &_CALENDARS_25,
+            Locale::ca_IT => &_CALENDARS_25,
+            Locale::cad => &_CALENDARS_0,
+            Locale::cch => &_CALENDARS_0,
+            Locale::ccp => &_CALENDARS_0,
+            Locale::ccp_IN => &_CALENDARS_0,
+            Locale::ce => &_CALENDARS_0,
+            Locale::ceb => &_CALENDARS_26,
+            Locale::cgg => &_CALENDARS_0,
+            Locale::cho => &_CALENDARS_0,
+            Locale::chr => &_CALENDARS_27,
+            Locale::cic => &_CALENDARS_0,
+            Locale::ckb => &_CALENDARS_0,
+            Locale::ckb_IR => &_CALENDARS_0,
+            Locale::co => &_CALENDARS_0,
+            Locale::cop => &_CALENDARS_0,
+            Locale::cs => &_CALENDARS_28,
+            Locale::csw => &_CALENDARS_29,
+            Locale::cu => &_CALENDARS_0,
+            Locale::cv => &_CALENDARS_30,
+            Locale::cy => &_CALENDARS_31,
+            Locale::da => &_CALENDARS_32,
+            Locale::da_GL => &_CALENDARS_32,
+            Locale::dav => &_CALENDARS_0,
+            Locale::de => &_CALENDARS_33,
+            Locale::de_AT => &_CALENDARS_34,
+            Locale::de_BE => &_CALENDARS_33,
+            Locale::de_CH => &_CALENDARS_33,
+            Locale::de_IT => &_CALENDARS_34,
+            Locale::de_LI => &_CALENDARS_33,
+            Locale::de_LU => &_CALENDARS_33,
+            Locale::dje => &_CALENDARS_0,
+            Locale::doi => &_CALENDARS_35,
+            Locale::dsb => &_CALENDARS_36,
+            Locale::dua => &_CALENDARS_0,
+            Locale::dv => &_CALENDARS_0,
+            Locale::dyo => &_CALENDARS_0,
+            Locale::dz => &_CALENDARS_0,
+            Locale::ebu => &_CALENDARS_0,
+            Locale::ee => &_CALENDARS_37,
+            Locale::ee_TG => &_CALENDARS_37,
+            Locale::el => &_CALENDARS_38,
+            Locale::el_CY => &_CALENDARS_38,
+            Locale::el_polyton => &_CALENDARS_39,
+            Locale::en => &_CALENDARS_40,
+            Locale::en_001 => &_CALENDARS_41,
+            Locale::en_150 => &_CALENDARS_41,
+            Locale::en_AE => &_CALENDARS_42,
+            Locale::en_AG => &_CALENDARS_41,
+            Locale::en_AI => &_CALENDARS_41,
+            Locale::en_AS => &_CALENDARS_40,
+            Locale::en_AT => &_CALENDARS_41,
+            Locale::en_AU => &_CALENDARS_43,
+            Locale::en_BB => &_CALENDARS_41,
+            Locale::en_BE => &_CALENDARS_44,
+            Locale::en_BI => &_CALENDARS_40,
+            Locale::en_BM => &_CALENDARS_41,
+            Locale::en_BS => &_CALENDARS_41,
+            Locale::en_BW => &_CALENDARS_45,
+            Locale::en_BZ => &_CALENDARS_46,
+            Locale::en_CA => &_CALENDARS_40,
+            Locale::en_CC => &_CALENDARS_41,
+            Locale::en_CH => &_CALENDARS_47,
+            Locale::en_CK => &_CALENDARS_41,
+            Locale::en_CM => &_CALENDARS_41,
+            Locale::en_CX => &_CALENDARS_41,
+            Locale::en_CY => &_CALENDARS_41,
+            Locale::en_CZ => &_CALENDARS_40,
+            Locale::en_DE => &_CALENDARS_41,
+            Locale::en_DG => &_CALENDARS_41,
+            Locale::en_DK => &_CALENDARS_41,
+            Locale::en_DM => &_CALENDARS_41,
+            Locale::en_Dsrt => &_CALENDARS_0,
+            Locale::en_EE => &_CALENDARS_40,
+            Locale::en_ER => &_CALENDARS_41,
+            Locale::en_ES => &_CALENDARS_40,
+            Locale::en_FI => &_CALENDARS_41,
+            Locale::en_FJ => &_CALENDARS_41,
+            Locale::en_FK => &_CALENDARS_41,
+            Locale::en_FM => &_CALENDARS_41,
+            Locale::en_FR => &_CALENDARS_40,
+            Locale::en_GB => &_CALENDARS_41,
+            Locale::en_GD => &_CALENDARS_41,
+            Locale::en_GE => &_CALENDARS_40,
+            Locale::en_GG => &_CALENDARS_41,
+            Locale::en_GH => &_CALENDARS_41,
+            Locale::en_GI => &_CALENDARS_41,
+            Locale::en_GM => &_CALENDARS_41,
+            Locale::en_GS => &_CALENDARS_40,
+            Locale::en_GU => &_CALENDARS_40,
+            Locale::en_GY => &_CALENDARS_41,
+            Locale::en_HK => &_CALENDARS_48,
+            Locale::en_HU => &_CALENDARS_40,
+            Locale::en_ID => &_CALENDARS_41,
+            Locale::en_IE => &_CALENDARS_49,
+            Locale::en_IL => &_CALENDARS_41,
+            Locale::en_IM => &_CALENDARS_41,
+            Locale::en_IN => &_CALENDARS_50,
+            Locale::en_IO => &_CALENDARS_41,
+            Locale::en_IT => &_CALENDARS_40,
+            Locale::en_JE => &_CALENDARS_41,
+            Locale::en_JM => &_CALENDARS_41,
+            Locale::en_JP => &_CALENDARS_40,
+            Locale::en_KE => &_CALENDARS_41,
+            Locale::en_KI => &_CALENDARS_41,
+            Locale::en_KN => &_CALENDARS_41,
+            Locale::en_KY => &_CALENDARS_41,
+            Locale::en_LC => &_CALENDARS_41,
+            Locale::en_LR => &_CALENDARS_41,
+            Locale::en_LS => &_CALENDARS_41,
+            Locale::en_LT => &_CALENDARS_40,
+            Locale::en_LV => &_CALENDARS_40,
+            Locale::en_MG => &_CALENDARS_41,
+            Locale::en_MH => &_CALENDARS_40,
+            Locale::en_MO => &_CALENDARS_41,
+            Locale::en_MP => &_CALENDARS_40,
+            Locale::en_MS => &_CALENDARS_41,
+            Locale::en_MT => &_CALENDARS_51,
+            Locale::en_MU => &_CALENDARS_41,
+            Locale::en_MV => &_CALENDARS_41,
+            Locale::en_MW => &_CALENDARS_41,
+            Locale::en_MY => &_CALENDARS_41,
+            Locale::en_NA => &_CALENDARS_41,
+            Locale::en_NF => &_CALENDARS_41,
+            Locale::en_NG => &_CALENDARS_41,
+            Locale::en_NL => &_CALENDARS_41,
+            Locale::en_NO => &_CALENDARS_40,
+            Locale::en_NR => &_CALENDARS_41,
+            Locale::en_NU => &_CALENDARS_41,
+            Locale::en_NZ => &_CALENDARS_52,
+            Locale::en_PG => &_CALENDARS_41,
+            Locale::en_PH => &_CALENDARS_40,
+            Locale::en_PK => &_CALENDARS_53,
+            Locale::en_PL => &_CALENDARS_40,
+            Locale::en_PN => &_CALENDARS_41,
+            Locale::en_PR => &_CALENDARS_40,
+            Locale::en_PT => &_CALENDARS_40,
+            Locale::en_PW => &_CALENDARS_41,
+            Locale::en_RO => &_CALENDARS_40,
+            Locale::en_RW => &_CALENDARS_41,
+            Locale::en_SB => &_CALENDARS_41,
+            Locale::en_SC => &_CALENDARS_41,
+            Locale::en_SD => &_CALENDARS_41,
+            Locale::en_SE => &_CALENDARS_54,
+            Locale::en_SG => &_CALENDARS_55,
+            Locale::en_SH => &_CALENDARS_41,
+            Locale::en_SI => &_CALENDARS_41,
+            Locale::en_SK => &_CALENDARS_40,
+            Locale::en_SL => &_CALENDARS_41,
+            Locale::en_SS => &_CALENDARS_41,
+            Locale::en_SX => &_CALENDARS_41,
+            Locale::en_SZ => &_CALENDARS_41,
+            Locale::en_Shaw => &_CALENDARS_0,
+            Locale::en_TC => &_CALENDARS_41,
+            Locale::en_TK => &_CALENDARS_41,
+            Locale::en_TO => &_CALENDARS_41,
+            Locale::en_TT => &_CALENDARS_41,
+            Locale::en_TV => &_CALENDARS_41,
+            Locale::en_TZ => &_CALENDARS_41,
+            Locale::en_UA => &_CALENDARS_40,
+            Locale::en_UG => &_CALENDARS_41,
+            Locale::en_UM => &_CALENDARS_40,
+            Locale::en_VC => &_CALENDARS_41,
+            Locale::en_VG => &_CALENDARS_41,
+            Locale::en_VI => &_CALENDARS_40,
+            Locale::en_VU => &_CALENDARS_41,
+            Locale::en_WS => &_CALENDARS_41,
+            Locale::en_ZA => &_CALENDARS_56,
+            Locale::en_ZM => &_CALENDARS_41,
+            Locale::en_ZW => &_CALENDARS_57,
+            Locale::eo => &_CALENDARS_58,
+            Locale::es => &_CALENDARS_59,
+            Locale::es_419 => &_CALENDARS_60,
+            Locale::es_AR => &_CALENDARS_60,
+            Locale::es_BO => &_CALENDARS_60,
+            Locale::es_BR => &_CALENDARS_60,
+            Locale::es_BZ => &_CALENDARS_60,
+            Locale::es_CL => &_CALENDARS_61,
+            Locale::es_CO => &_CALENDARS_62,
+            Locale::es_CR => &_CALENDARS_60,
+            Locale::es_CU => &_CALENDARS_60,
+            Locale::es_DO => &_CALENDARS_63,
+            Locale::es_EA => &_CALENDARS_59,
+            Locale::es_EC => &_CALENDARS_60,
+            Locale::es_GQ => &_CALENDARS_59,
+            Locale::es_GT => &_CALENDARS_64,
+            Locale::es_HN => &_CALENDARS_65,
+            Locale::es_IC => &_CALENDARS_59,
+            Locale::es_MX => &_CALENDARS_66,
+            Locale::es_NI => &_CALENDARS_60,
+            Locale::es_PA => &_CALENDARS_67,
+            Locale::es_PE => &_CALENDARS_68,
+            Locale::es_PH => &_CALENDARS_59,
+            Locale::es_PR => &_CALENDARS_67,
+            Locale::es_PY => &_CALENDARS_69,
+            Locale::es_SV => &_CALENDARS_60,
+            Locale::es_US => &_CALENDARS_60,
+            Locale::es_UY => &_CALENDARS_70,
+            Locale::es_VE => &_CALENDARS_69,
+            Locale::et => &_CALENDARS_71,
+            Locale::eu => &_CALENDARS_72,
+            Locale::ewo => &_CALENDARS_0,
+            Locale::fa => &_CALENDARS_73,
+            Locale::fa_AF => &_CALENDARS_74,
+            Locale::ff => &_CALENDARS_0,
+            Locale::ff_Adlm => &_CALENDARS_75,
+            Locale::ff_Adlm_BF => &_CALENDARS_75,
+            Locale::ff_Adlm_CM => &_CALENDARS_75,
+            Locale::ff_Adlm_GH => &_CALENDARS_75,
+            Locale::ff_Adlm_GM => &_CALENDARS_75,
+            Locale::ff_Adlm_GW => &_CALENDARS_75,
+            Locale::ff_Adlm_LR => &_CALENDARS_75,
+            Locale::ff_Adlm_MR => &_CALENDARS_75,
+            Locale::ff_Adlm_NE => &_CALENDARS_75,
+            Locale::ff_Adlm_NG => &_CALENDARS_75,
+            Locale::ff_Adlm_SL => &_CALENDARS_75,
+            Locale::ff_Adlm_SN => &_CALENDARS_75,
+            Locale::ff_Latn => &_CALENDARS_0,
+            Locale::ff_Latn_BF => &_CALENDARS_0,
+            Locale::ff_Latn_CM => &_CALENDARS_0,
+            Locale::ff_Latn_GH => &_CALENDARS_0,
+            Locale::ff_Latn_GM => &_CALENDARS_0,
+            Locale::ff_Latn_GN => &_CALENDARS_0,
+            Locale::ff_Latn_GW => &_CALENDARS_0,
+            Locale::ff_Latn_LR => &_CALENDARS_0,
+            Locale::ff_Latn_MR => &_CALENDARS_0,
+            Locale::ff_Latn_NE => &_CALENDARS_0,
+            Locale::ff_Latn_NG => &_CALENDARS_0,
+            Locale::ff_Latn_SL => &_CALENDARS_0,
+            Locale::fi => &_CALENDARS_76,
+            Locale::fil => &_CALENDARS_77,
+            Locale::fo => &_CALENDARS_78,
+            Locale::fo_DK => &_CALENDARS_78,
+            Locale::fr => &_CALENDARS_79,
+            Locale::fr_BE => &_CALENDARS_80,
+            Locale::fr_BF => &_CALENDARS_79,
+            Locale::fr_BI => &_CALENDARS_79,
+            Locale::fr_BJ => &_CALENDARS_79,
+            Locale::fr_BL => &_CALENDARS_79,
+            Locale::fr_CA => &_CALENDARS_81,
+            Locale::fr_CD => &_CALENDARS_79,
+            Locale::fr_CF => &_CALENDARS_79,
+            Locale::fr_CG => &_CALENDARS_79,
+            Locale::fr_CH => &_CALENDARS_82,
+            Locale::fr_CI => &_CALENDARS_79,
+            Locale::fr_CM => &_CALENDARS_79,
+            Locale::fr_DJ => &_CALENDARS_79,
+            Locale::fr_DZ => &_CALENDARS_79,
+            Locale::fr_GA => &_CALENDARS_79,
+            Locale::fr_GF => &_CALENDARS_79,
+            Locale::fr_GN => &_CALENDARS_79,
+            Locale::fr_GP => &_CALENDARS_79,
+            Locale::fr_GQ => &_CALENDARS_79,
+            Locale::fr_HT => &_CALENDARS_79,
+            Locale::fr_KM => &_CALENDARS_79,
+            Locale::fr_LU => &_CALENDARS_79,
+            Locale::fr_MA => &_CALENDARS_83,
+            Locale::fr_MC => &_CALENDARS_79,
+            Locale::fr_MF => &_CALENDARS_79,
+            Locale::fr_MG => &_CALENDARS_79,
+            Locale::fr_ML => &_CALENDARS_79,
+            Locale::fr_MQ => &_CALENDARS_79,
+            Locale::fr_MR => &_CALENDARS_79,
+            Locale::fr_MU => &_CALENDARS_79,
+            Locale::fr_NC => &_CALENDARS_79,
+            Locale::fr_NE => &_CALENDARS_79,
+            Locale::fr_PF => &_CALENDARS_79,
+            Locale::fr_PM => &_CALENDARS_79,
+            Locale::fr_RE => &_CALENDARS_79,
+            Locale::fr_RW => &_CALENDARS_79,
+            Locale::fr_SC => &_CALENDARS_79,
+            Locale::fr_SN => &_CALENDARS_79,
+            Locale::fr_SY => &_CALENDARS_79,
+            Locale::fr_TD => &_CALENDARS_79,
+            Locale::fr_TG => &_CALENDARS_79,
+            Locale::fr_TN => &_CALENDARS_79,
+            Locale::fr_VU => &_CALENDARS_79,
+            Locale::fr_WF => &_CALENDARS_79,
+            Locale::fr_YT => &_CALENDARS_79,
+            Locale::frr => &_CALENDARS_0,
+            Locale::fur => &_CALENDARS_0,
+            Locale::fy => &_CALENDARS_84,
+            Locale::ga => &_CALENDARS_85,
+            Locale::ga_GB => &_CALENDARS_85,
+            Locale::gaa => &_CALENDARS_86,
+            Locale::gd => &_CALENDARS_87,
+            Locale::gez => &_CALENDARS_0,
+            Locale::gez_ER => &_CALENDARS_0,
+            Locale::gl => &_CALENDARS_88,
+            Locale::gn => &_CALENDARS_0,
+            Locale::gsw => &_CALENDARS_0,
+            Locale::gsw_FR => &_CALENDARS_0,
+            Locale::gsw_LI => &_CALENDARS_0,
+            Locale::gu => &_CALENDARS_89,
+            Locale::guz => &_CALENDARS_0,
+            Locale::gv => &_CALENDARS_0,
+            Locale::ha => &_CALENDARS_90,
+            Locale::ha_Arab => &_CALENDARS_0,
+            Locale::ha_Arab_SD => &_CALENDARS_0,
+            Locale::ha_GH => &_CALENDARS_90,
+            Locale::ha_NE => &_CALENDARS_90,
+            Locale::haw => &_CALENDARS_0,
+            Locale::he => &_CALENDARS_91,
+            Locale::hi => &_CALENDARS_92,
+            Locale::hi_Latn => &_CALENDARS_93,
+            Locale::hnj => &_CALENDARS_0,
+            Locale::hnj_Hmnp => &_CALENDARS_0,
+            Locale::hr => &_CALENDARS_94,
+            Locale::hr_BA => &_CALENDARS_94,
+            Locale::hsb => &_CALENDARS_95,
+            Locale::ht => &_CALENDARS_79,
+            Locale::hu => &_CALENDARS_96,
+            Locale::hy => &_CALENDARS_97,
+            Locale::ia => &_CALENDARS_98,
+            Locale::id => &_CALENDARS_99,
+            Locale::ie => &_CALENDARS_100,
+            Locale::ig => &_CALENDARS_101,
+            Locale::ii => &_CALENDARS_102,
+            Locale::io => &_CALENDARS_0,
+            Locale::is => &_CALENDARS_103,
+            Locale::it => &_CALENDARS_104,
+            Locale::it_CH => &_CALENDARS_105,
+            Locale::it_SM => &_CALENDARS_104,
+            Locale::it_VA => &_CALENDARS_104,
+            Locale::iu => &_CALENDARS_0,
+            Locale::iu_Latn => &_CALENDARS_0,
+            Locale::ja => &_CALENDARS_106,
+            Locale::jbo => &_CALENDARS_0,
+            Locale::jgo => &_CALENDARS_0,
+            Locale::jmc => &_CALENDARS_0,
+            Locale::jv => &_CALENDARS_107,
+            Locale::ka => &_CALENDARS_108,
+            Locale::kaa => &_CALENDARS_0,
+            Locale::kaa_Cyrl => &_CALENDARS_0,
+            Locale::kaa_Latn => &_CALENDARS_0,
+            Locale::kab => &_CALENDARS_0,
+            Locale::kaj => &_CALENDARS_0,
+            Locale::kam => &_CALENDARS_0,
+            Locale::kcg => &_CALENDARS_0,
+            Locale::kde => &_CALENDARS_0,
+            Locale::kea => &_CALENDARS_109,
+            Locale::kek => &_CALENDARS_0,
+            Locale::ken => &_CALENDARS_0,
+            Locale::kgp => &_CALENDARS_110,
+            Locale::khq => &_CALENDARS_0,
+            Locale::ki => &_CALENDARS_0,
+            Locale::kk => &_CALENDARS_111,
+            Locale::kk_Arab => &_CALENDARS_0,
+            Locale::kk_Cyrl => &_CALENDARS_111,
+            Locale::kk_KZ => &_CALENDARS_111,
+            Locale::kkj => &_CALENDARS_0,
+            Locale::kl => &_CALENDARS_0,
+            Locale::kln => &_CALENDARS_0,
+            Locale::km => &_CALENDARS_112,
+            Locale::kn => &_CALENDARS_113,
+            Locale::ko => &_CALENDARS_114,
+            Locale::ko_CN => &_CALENDARS_114,
+            Locale::ko_KP => &_CALENDARS_114,
+            Locale::kok => &_CALENDARS_115,
+            Locale::kok_Deva => &_CALENDARS_115,
+            Locale::kok_Latn => &_CALENDARS_116,
+            Locale::kpe => &_CALENDARS_0,
+            Locale::kpe_GN => &_CALENDARS_0,
+            Locale::ks => &_CALENDARS_117,
+            Locale::ks_Arab => &_CALENDARS_117,
+            Locale::ks_Deva => &_CALENDARS_118,
+            Locale::ksb => &_CALENDARS_0,
+            Locale::ksf => &_CALENDARS_0,
+            Locale::ksh => &_CALENDARS_0,
+            Locale::ku => &_CALENDARS_119,
+            Locale::ku_Arab => &_CALENDARS_0,
+            Locale::ku_Arab_IR => &_CALENDARS_0,
+            Locale::ku_Latn => &_CALENDARS_119,
+            Locale::ku_Latn_IQ => &_CALENDARS_119,
+            Locale::ku_Latn_SY => &_CALENDARS_119,
+            Locale::ku_TR => &_CALENDARS_119,
+            Locale::kw => &_CALENDARS_0,
+            Locale::kxv => &_CALENDARS_120,
+            Locale::kxv_Deva => &_CALENDARS_121,
+            Locale::kxv_Latn => &_CALENDARS_120,
+            Locale::kxv_Orya => &_CALENDARS_122,
+            Locale::kxv_Telu => &_CALENDARS_123,
+            Locale::ky => &_CALENDARS_124,
+            Locale::la => &_CALENDARS_0,
+            Locale::lag => &_CALENDARS_0,
+            Locale::lb => &_CALENDARS_125,
+            Locale::lg => &_CALENDARS_0,
+            Locale::lij => &_CALENDARS_126,
+            Locale::lkt => &_CALENDARS_0,
+            Locale::lld => &_CALENDARS_0,
+            Locale::lmo => &_CALENDARS_127,
+            Locale::ln => &_CALENDARS_0,
+            Locale::ln_AO => &_CALENDARS_0,
+            Locale::ln_CF => &_CALENDARS_0,
+            Locale::ln_CG => &_CALENDARS_0,
+            Locale::lo => &_CALENDARS_128,
+            Locale::lrc => &_CALENDARS_0,
+            Locale::lrc_IQ => &_CALENDARS_0,
+            Locale::lt => &_CALENDARS_129,
+            Locale::ltg => &_CALENDARS_0,
+            Locale::lu => &_CALENDARS_0,
+            Locale::luo => &_CALENDARS_0,
+            Locale::luy => &_CALENDARS_0,
+            Locale::lv => &_CALENDARS_130,
+            Locale::lzz => &_CALENDARS_0,
+            Locale::mai => &_CALENDARS_131,
+            Locale::mas => &_CALENDARS_0,
+            Locale::mas_TZ => &_CALENDARS_0,
+            Locale::mdf => &_CALENDARS_0,
+            Locale::mer => &_CALENDARS_0,
+            Locale::mfe => &_CALENDARS_0,
+            Locale::mg => &_CALENDARS_0,
+            Locale::mgh => &_CALENDARS_0,
+            Locale::mgo => &_CALENDARS_0,
+            Locale::mhn => &_CALENDARS_0,
+            Locale::mi => &_CALENDARS_132,
+            Locale::mic => &_CALENDARS_0,
+            Locale::mk => &_CALENDARS_133,
+            Locale::ml => &_CALENDARS_134,
+            Locale::mn => &_CALENDARS_135,
+            Locale::mn_Mong => &_CALENDARS_0,
+            Locale::mn_Mong_MN => &_CALENDARS_0,
+            Locale::mni => &_CALENDARS_136,
+            Locale::mni_Beng => &_CALENDARS_136,
+            Locale::mni_Mtei => &_CALENDARS_0,
+            Locale::moh => &_CALENDARS_0,
+            Locale::mr => &_CALENDARS_137,
+            Locale::ms => &_CALENDARS_138,
+            Locale::ms_Arab => &_CALENDARS_0,
+            Locale::ms_Arab_BN => &_CALENDARS_0,
+            Locale::ms_BN => &_CALENDARS_139,
+            Locale::ms_ID => &_CALENDARS_140,
+            Locale::ms_SG => &_CALENDARS_138,
+            Locale::mt => &_CALENDARS_141,
+            Locale::mua => &_CALENDARS_0,
+            Locale::mus => &_CALENDARS_0,
+            Locale::mww => &_CALENDARS_0,
+            Locale::mww_Hmnp => &_CALENDARS_0,
+            Locale::my => &_CALENDARS_142,
+            Locale::myv => &_CALENDARS_0,
+            Locale::mzn => &_CALENDARS_0,
+            Locale::naq => &_CALENDARS_0,
+            Locale::nb => &_CALENDARS_143,
+            Locale::nb_SJ => &_CALENDARS_143,
+            Locale::nd => &_CALENDARS_0,
+            Locale::nds => &_CALENDARS_144,
+            Locale::nds_NL => &_CALENDARS_144,
+            Locale::ne => &_CALENDARS_145,
+            Locale::ne_IN => &_CALENDARS_145,
+            Locale::nl => &_CALENDARS_146,
+            Locale::nl_AW => &_CALENDARS_146,
+            Locale::nl_BE => &_CALENDARS_146,
+            Locale::nl_BQ => &_CALENDARS_146,
+            Locale::nl_CW => &_CALENDARS_146,
+            Locale::nl_SR => &_CALENDARS_146,
+            Locale::nl_SX => &_CALENDARS_146,
+            Locale::nmg => &_CALENDARS_0,
+            Locale::nn => &_CALENDARS_143,
+            Locale::nnh => &_CALENDARS_0,
+            Locale::no => &_CALENDARS_143,
+            Locale::nqo => &_CALENDARS_147,
+            Locale::nr => &_CALENDARS_0,
+            Locale::nso => &_CALENDARS_148,
+            Locale::nus => &_CALENDARS_0,
+            Locale::nv => &_CALENDARS_0,
+            Locale::ny => &_CALENDARS_0,
+            Locale::nyn => &_CALENDARS_0,
+            Locale::oc => &_CALENDARS_149,
+            Locale::oc_ES => &_CALENDARS_150,
+            Locale::oka => &_CALENDARS_0,
+            Locale::oka_US => &_CALENDARS_0,
+            Locale::om => &_CALENDARS_151,
+            Locale::om_KE => &_CALENDARS_152,
+            Locale::or => &_CALENDARS_153,
+            Locale::os => &_CALENDARS_0,
+            Locale::os_RU => &_CALENDARS_0,
+            Locale::osa => &_CALENDARS_0,
+            Locale::pa => &_CALENDARS_154,
+            Locale::pa_Arab => &_CALENDARS_0,
+            Locale::pa_Guru => &_CALENDARS_154,
+            Locale::pap => &_CALENDARS_0,
+            Locale::pap_AW => &_CALENDARS_0,
+            Locale::pcm => &_CALENDARS_155,
+            Locale::pi => &_CALENDARS_0,
+            Locale::pi_Latn => &_CALENDARS_0,
+            Locale::pis => &_CALENDARS_0,
+            Locale::pl => &_CALENDARS_156,
+            Locale::pms => &_CALENDARS_0,
+            Locale::prg => &_CALENDARS_157,
+            Locale::ps => &_CALENDARS_158,
+            Locale::ps_PK => &_CALENDARS_159,
+            Locale::pt => &_CALENDARS_160,
+            Locale::pt_AO => &_CALENDARS_161,
+            Locale::pt_CH => &_CALENDARS_161,
+            Locale::pt_CV => &_CALENDARS_161,
+            Locale::pt_GQ => &_CALENDARS_161,
+            Locale::pt_GW => &_CALENDARS_161,
+            Locale::pt_LU => &_CALENDARS_161,
+            Locale::pt_MO => &_CALENDARS_161,
+            Locale::pt_MZ => &_CALENDARS_161,
+            Locale::pt_PT => &_CALENDARS_161,
+            Locale::pt_ST => &_CALENDARS_161,
+            Locale::pt_TL => &_CALENDARS_161,
+            Locale::qu => &_CALENDARS_162,
+            Locale::qu_BO => &_CALENDARS_162,
+            Locale::qu_EC => &_CALENDARS_162,
+            Locale::quc => &_CALENDARS_0,
+            Locale::raj => &_CALENDARS_17,
+            Locale::rhg => &_CALENDARS_0,
+            Locale::rhg_Rohg => &_CALENDARS_0,
+            Locale::rhg_Rohg_BD => &_CALENDARS_0,
+            Locale::rif => &_CALENDARS_0,
+            Locale::rm => &_CALENDARS_163,
+            Locale::rn => &_CALENDARS_0,
+            Locale::ro => &_CALENDARS_164,
+            Locale::ro_MD => &_CALENDARS_164,
+            Locale::rof => &_CALENDARS_0,
+            Locale::ru => &_CALENDARS_165,
+            Locale::ru_BY => &_CALENDARS_165,
+            Locale::ru_KG => &_CALENDARS_165,
+            Locale::ru_KZ => &_CALENDARS_165,
+            Locale::ru_MD => &_CALENDARS_165,
+            Locale::ru_UA => &_CALENDARS_165,
+            Locale::rw => &_CALENDARS_166,
+            Locale::rwk => &_CALENDARS_0,
+            Locale::sa => &_CALENDARS_167,
+            Locale::sah => &_CALENDARS_168,
+            Locale::saq => &_CALENDARS_0,
+            Locale::sat => &_CALENDARS_169,
+            Locale::sat_Deva => &_CALENDARS_0,
+            Locale::sat_Olck => &_CALENDARS_169,
+            Locale::sbp => &_CALENDARS_0,
+            Locale::sc => &_CALENDARS_170,
+            Locale::scn => &_CALENDARS_0,
+            Locale::sd => &_CALENDARS_171,
+            Locale::sd_Arab => &_CALENDARS_171,
+            Locale::sd_Deva => &_CALENDARS_172,
+            Locale::sdh => &_CALENDARS_0,
+            Locale::sdh_IQ => &_CALENDARS_0,
+            Locale::se => &_CALENDARS_0,
+            Locale::se_FI => &_CALENDARS_0,
+            Locale::se_SE => &_CALENDARS_0,
+            Locale::seh => &_CALENDARS_0,
+            Locale::ses => &_CALENDARS_0,
+            Locale::sg => &_CALENDARS_0,
+            Locale::sgs => &_CALENDARS_0,
+            Locale::shi => &_CALENDARS_0,
+            Locale::shi_Latn => &_CALENDARS_0,
+            Locale::shi_Tfng => &_CALENDARS_0,
+            Locale::shn => &_CALENDARS_0,
+            Locale::shn_TH => &_CALENDARS_0,
+            Locale::si => &_CALENDARS_173,
+            Locale::sid => &_CALENDARS_0,
+            Locale::sk => &_CALENDARS_174,
+            Locale::skr => &_CALENDARS_0,
+            Locale::sl => &_CALENDARS_175,
+            Locale::sma => &_CALENDARS_0,
+            Locale::sma_NO => &_CALENDARS_0,
+            Locale::smj => &_CALENDARS_0,
+            Locale::smj_NO => &_CALENDARS_0,
+            Locale::smn => &_CALENDARS_0,
+            Locale::sms => &_CALENDARS_0,
+            Locale::sn => &_CALENDARS_0,
+            Locale::so => &_CALENDARS_176,
+            Locale::so_DJ => &_CALENDARS_176,
+            Locale::so_ET => &_CALENDARS_176,
+            Locale::so_KE => &_CALENDARS_176,
+            Locale::sq => &_CALENDARS_177,
+            Locale::sq_MK => &_CALENDARS_177,
+            Locale::sq_XK => &_CALENDARS_177,
+            Locale::sr => &_CALENDARS_178,
+            Locale::sr_Cyrl => &_CALENDARS_178,
+            Locale::sr_Cyrl_BA => &_CALENDARS_179,
+            Locale::sr_Cyrl_ME => &_CALENDARS_0,
+            Locale::sr_Cyrl_XK => &_CALENDARS_180,
+            Locale::sr_Latn => &_CALENDARS_181,
+            Locale::sr_Latn_BA => &_CALENDARS_182,
+            Locale::sr_Latn_ME => &_CALENDARS_183,
+            Locale::sr_Latn_XK => &_CALENDARS_184,
+            Locale::ss => &_CALENDARS_0,
+            Locale::ss_SZ => &_CALENDARS_0,
+            Locale::ssy => &_CALENDARS_0,
+            Locale::st => &_CALENDARS_185,
+            Locale::st_LS => &_CALENDARS_185,
+            Locale::su => &_CALENDARS_186,
+            Locale::su_Latn => &_CALENDARS_186,
+            Locale::suz => &_CALENDARS_0,
+            Locale::suz_Deva => &_CALENDARS_0,
+            Locale::suz_Sunu => &_CALENDARS_0,
+            Locale::sv => &_CALENDARS_187,
+            Locale::sv_AX => &_CALENDARS_187,
+            Locale::sv_FI => &_CALENDARS_187,
+            Locale::sw => &_CALENDARS_188,
+            Locale::sw_CD => &_CALENDARS_188,
+            Locale::sw_KE => &_CALENDARS_188,
+            Locale::sw_UG => &_CALENDARS_188,
+            Locale::syr => &_CALENDARS_189,
+            Locale::syr_SY => &_CALENDARS_189,
+            Locale::szl => &_CALENDARS_190,
+            Locale::ta => &_CALENDARS_191,
+            Locale::ta_LK => &_CALENDARS_191,
+            Locale::ta_MY => &_CALENDARS_191,
+            Locale::ta_SG => &_CALENDARS_191,
+            Locale::te => &_CALENDARS_192,
+            Locale::teo => &_CALENDARS_0,
+            Locale::teo_KE => &_CALENDARS_0,
+            Locale::tg => &_CALENDARS_193,
+            Locale::th => &_CALENDARS_194,
+            Locale::ti => &_CALENDARS_195,
+            Locale::ti_ER => &_CALENDARS_196,
+            Locale::tig => &_CALENDARS_0,
+            Locale::tk => &_CALENDARS_197,
+            Locale::tn => &_CALENDARS_198,
+            Locale::tn_BW => &_CALENDARS_198,
+            Locale::to => &_CALENDARS_199,
+            Locale::tok => &_CALENDARS_0,
+            Locale::tpi => &_CALENDARS_0,
+            Locale::tr => &_CALENDARS_200,
+            Locale::tr_CY => &_CALENDARS_200,
+            Locale::trv => &_CALENDARS_0,
+            Locale::trw => &_CALENDARS_0,
+            Locale::ts => &_CALENDARS_0,
+            Locale::tt => &_CALENDARS_201,
+            Locale::twq => &_CALENDARS_0,
+            Locale::tyv => &_CALENDARS_0,
+            Locale::tzm => &_CALENDARS_0,
+            Locale::ug => &_CALENDARS_202,
+            Locale::uk => &_CALENDARS_203,
+            Locale::und => &_CALENDARS_0,
+            Locale::ur => &_CALENDARS_204,
+            Locale::ur_IN => &_CALENDARS_204,
+            Locale::uz => &_CALENDARS_205,
+            Locale::uz_Arab => &_CALENDARS_0,
+            Locale::uz_Cyrl => &_CALENDARS_206,
+            Locale::uz_Latn => &_CALENDARS_205,
+            Locale::vai => &_CALENDARS_0,
+            Locale::vai_Latn => &_CALENDARS_0,
+            Locale::vai_Vaii => &_CALENDARS_0,
+            Locale::ve => &_CALENDARS_0,
+            Locale::vec => &_CALENDARS_207,
+            Locale::vi => &_CALENDARS_208,
+            Locale::vmw => &_CALENDARS_209,
+            Locale::vo => &_CALENDARS_0,
+            Locale::vun => &_CALENDARS_0,
+            Locale::wa => &_CALENDARS_0,
+            Locale::wae => &_CALENDARS_0,
+            Locale::wal => &_CALENDARS_0,
+            Locale::wbp => &_CALENDARS_0,
+            Locale::wo => &_CALENDARS_210,
+            Locale::xh => &_CALENDARS_211,
+            Locale::xnr => &_CALENDARS_212,
+            Locale::xog => &_CALENDARS_0,
+            Locale::yav => &_CALENDARS_0,
+            Locale::yi => &_CALENDARS_0,
+            Locale::yo => &_CALENDARS_213,
+            Locale::yo_BJ => &_CALENDARS_214,
+            Locale::yrl => &_CALENDARS_215,
+            Locale::yrl_CO => &_CALENDARS_215,
+            Locale::yrl_VE => &_CALENDARS_215,
+            Locale::yue => &_CALENDARS_216,
+            Locale::yue_Hans => &_CALENDARS_217,
+            Locale::yue_Hant => &_CALENDARS_216,
+            Locale::yue_Hant_CN => &_CALENDARS_0,
+            Locale::yue_Hant_MO => &_CALENDARS_216,
+            Locale::za => &_CALENDARS_218,
+            Locale::zgh => &_CALENDARS_0,
+            Locale::zh => &_CALENDARS_219,
+            Locale::zh_Hans => &_CALENDARS_219,
+            Locale::zh_Hans_HK => &_CALENDARS_0,
+            Locale::zh_Hans_MO => &_CALENDARS_0,
+            Locale::zh_Hans_MY => &_CALENDARS_219,
+            Locale::zh_Hans_SG => &_CALENDARS_220,
+            Locale::zh_Hant => &_CALENDARS_221,
+            Locale::zh_Hant_HK => &_CALENDARS_222,
+            Locale::zh_Hant_MO => &_CALENDARS_222,
+            Locale::zh_Hant_MY => &_CALENDARS_221,
+            Locale::zh_Latn => &_CALENDARS_0,
+            Locale::zu => &_CALENDARS_223,
+        }
+    }
+}
+
+/// Year within the Gregorian eras: 1 BC is year 0 of the proleptic calendar.
+fn _era_year(year: i32) -> i64 {
+    if year > 0 {
+        year as i64
+    } else {
+        1 - year as i64
+    }
+}
+
+/// Japanese eras from Meiji on as (first day since 1970-01-01, Gregorian year).
+/// Dates before Meiji use the Gregorian eras.
+const _JAPANESE_ERAS: [(i64, i32); 5] = [
+    (-37004, 1868),
+    (-20974, 1912),
+    (-15713, 1926),
+    (6947, 1989),
+    (18017, 2019),
+];
+
+/// Solar Hijri date of a day, using the 33-year arithmetic leap cycle.
+fn _persian_from_days(days: i64) -> (i64, u32, u32) {
+    // 1 Farvardin 1 AP is 622-03-22 in the proleptic Gregorian calendar.
+    let since_epoch = days + 492_268;
+    let year = 1 + (33 * since_epoch + 3).div_euclid(12_053);
+    let day_of_year = since_epoch - (365 * (year - 1) + (8 * year + 21).div_euclid(33));
+    let month = if day_of_year < 186 {
+        day_of_year / 31
+    } else {
+        (day_of_year - 6) / 30
+    };
+    let month_start = if month < 6 {
+        31 * month
+    } else {
+        30 * month + 6
+    };
+    (
+        year,
+        month as u32 + 1,
+        (day_of_year - month_start) as u32 + 1,
+    )
+}
+
+/// Tabular Hijri date of a day, counting from Friday 622-07-19 with 11 leap
+/// years in each 30-year cycle.
+fn _islamic_civil_from_days(days: i64) -> (i64, u32, u32) {
+    let since_epoch = days + 492_148;
+    let year = (30 * since_epoch + 10_646).div_euclid(10_631);
+    let year_start = (year - 1) * 354 + (3 + 11 * year).div_euclid(30);
+    let month = ((2 * (since_epoch - 29 - year_start) + 58).div_euclid(59)).clamp(0, 11);
+    let month_start = year_start + (59 * month + 1).div_euclid(2);
+    (
+        year,
+        month as u32 + 1,
+        (since_epoch - month_start) as u32 + 1,
+    )
+}
+
+/// First Umm al-Qura year covered by [`_UMALQURA_MONTHS`], and the day of its
+/// 1 Muharram since 1970-01-01.
+const _UMALQURA_FIRST_YEAR: i64 = 1300;
+const _UMALQURA_START: i64 = -31_826;
+
+/// Umm al-Qura month lengths of the years 1300 to 1600 AH: bit 11 is
+/// Muharram and bit 0 Dhuʻl-Hijjah, set for months of 30 days.
+#[rustfmt::skip]
+const _UMALQURA_MONTHS: [u16; 301] = [
+    0xAAA, 0xD54, 0xEC9, 0x6D4, 0x6EA, 0x36C, 0xAAD, 0x555, 0x6A9, 0x792, 0xBA9, 0x5D4,
+    0xADA, 0x55C, 0xD2D, 0x695, 0x74A, 0xB54, 0xB6A, 0x5AD, 0x4AE, 0xA4F, 0x517, 0x68B,
+    0x6A5, 0xAD5, 0x2D6, 0x95B, 0x49D, 0xA4D, 0xD26, 0xD95, 0x5AC, 0x9B6, 0x2BA, 0xA5B,
+    0x52B, 0xA95, 0x6CA, 0xAE9, 0x2F4, 0x976, 0x2B6, 0x956, 0xACA, 0xBA4, 0xBD2, 0x5D9,
+    0x2DC, 0x96D, 0x54D, 0xAA5, 0xB52, 0xBA5, 0x5B4, 0x9B6, 0x557, 0x297, 0x54B, 0x6A3,
+    0x752, 0xB65, 0x56A, 0xAAB, 0x52B, 0xC95, 0xD4A, 0xDA5, 0x5CA, 0xAD6, 0x957, 0x4AB,
+    0x94B, 0xAA5, 0xB52, 0xB6A, 0x575, 0x276, 0x8B7, 0x45B, 0x555, 0x5A9, 0x5B4, 0x9DA,
+    0x4DD, 0x26E, 0x936, 0xAAA, 0xD54, 0xDB2, 0x5D5, 0x2DA, 0x95B, 0x4AB, 0xA55, 0xB49,
+    0xB64, 0xB71, 0x5B4, 0xAB5, 0xA55, 0xD25, 0xE92, 0xEC9, 0x6D4, 0xAE9, 0x96B, 0x4AB,
+    0xA93, 0xD49, 0xDA4, 0xDB2, 0xAB9, 0x4BA, 0xA5B, 0x52B, 0xA95, 0xB2A, 0xB55, 0x55C,
+    0x4BD, 0x23D, 0x91D, 0xA95, 0xB4A, 0xB5A, 0x56D, 0x2B6, 0x93B, 0x49B, 0x655, 0x6A9,
+    0x754, 0xB6A, 0x56C, 0xAAD, 0x555, 0xB29, 0xB92, 0xBA9, 0x5D4, 0xADA, 0x55A, 0xAAB,
+    0x595, 0x749, 0x764, 0xBAA, 0x5B5, 0x2B6, 0xA56, 0xE4D, 0xB25, 0xB52, 0xB6A, 0x5AD,
+    0x2AE, 0x92F, 0x497, 0x64B, 0x6A5, 0x6AC, 0xAD6, 0x55D, 0x49D, 0xA4D, 0xD16, 0xD95,
+    0x5AA, 0x5B5, 0x2DA, 0x95B, 0x4AD, 0x595, 0x6CA, 0x6E4, 0xAEA, 0x4F5, 0x2B6, 0x956,
+    0xAAA, 0xB54, 0xBD2, 0x5D9, 0x2EA, 0x96D, 0x4AD, 0xA95, 0xB4A, 0xBA5, 0x5B2, 0x9B5,
+    0x4D6, 0xA97, 0x547, 0x693, 0x749, 0xB55, 0x56A, 0xA6B, 0x52B, 0xA8B, 0xD46, 0xDA3,
+    0x5CA, 0xAD6, 0x4DB, 0x26B, 0x94B, 0xAA5, 0xB52, 0xB69, 0x575, 0x176, 0x8B7, 0x25B,
+    0x52B, 0x565, 0x5B4, 0x9DA, 0x4ED, 0x16D, 0x8B6, 0xAA6, 0xD52, 0xDA9, 0x5D4, 0xADA,
+    0x95B, 0x4AB, 0x653, 0x729, 0x762, 0xBA9, 0x5B2, 0xAB5, 0x555, 0xB25, 0xD92, 0xEC9,
+    0x6D2, 0xAE9, 0x56B, 0x4AB, 0xA55, 0xD29, 0xD54, 0xDAA, 0x9B5, 0x4BA, 0xA3B, 0x49B,
+    0xA4D, 0xAAA, 0xAD5, 0x2DA, 0x95D, 0x45E, 0xA2E, 0xC9A, 0xD55, 0x6B2, 0x6B9, 0x4BA,
+    0xA5D, 0x52D, 0xA95, 0xB52, 0xBA8, 0xBB4, 0x5B9, 0x2DA, 0x95A, 0xB4A, 0xDA4, 0xED1,
+    0x6E8, 0xB6A, 0x56D, 0x535, 0x695, 0xD4A, 0xDA8, 0xDD4, 0x6DA, 0x55B, 0x29D, 0x62B,
+    0xB15, 0xB4A, 0xB95, 0x5AA, 0xAAE, 0x92E, 0xC8F, 0x527, 0x695, 0x6AA, 0xAD6, 0x55D,
+    0x29D,
+];
+
+/// Umm al-Qura date of a day, or `None` outside the years of [`_UMALQURA_MONTHS`].
+fn _umalqura_from_days(days: i64) -> Option<(i64, u32, u32)> {
+    let mut start = _UMALQURA_START;
+    if days < start {
+        return None;
+    }
+    for (i, &months) in _UMALQURA_MONTHS.iter().enumerate() {
+        let year_length = 348 + months.count_ones() as i64;
+        if days >= start + year_length {
+            start += year_length;
+            continue;
+        }
+        for month in 0..12 {
+            let length = 29 + i64::from(months >> (11 - month) & 1);
+            if days < start + length {
+                return Some((
+                    _UMALQURA_FIRST_YEAR + i as i64,
+                    month + 1,
+                    (days - start) as u32 + 1,
+                ));
+            }
+            start += length;
+        }
+    }
+    None
+}
+
+fn _hebrew_is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// Days from the Hebrew epoch to the molad-based start of `year`, before
+/// the postponements that keep years within their allowed lengths.
+fn _hebrew_elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Day of 1 Tishri of `year` since 1970-01-01.
+fn _hebrew_new_year(year: i64) -> i64 {
+    let (previous, current, next) = (
+        _hebrew_elapsed_days(year - 1),
+        _hebrew_elapsed_days(year),
+        _hebrew_elapsed_days(year + 1),
+    );
+    let delay = if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    };
+    // 1 Tishri 1 AM is 3761-10-07 BC in the proleptic Julian calendar.
+    current + delay - 2_092_590
+}
+
+/// Hebrew date of a day, with months counted from Tishri.
+fn _hebrew_from_days(days: i64) -> (i64, u32, u32) {
+    // The estimate is low after the epoch and can be high before it.
+    let mut year = (days + 2_092_590).div_euclid(366) + 1;
+    while _hebrew_new_year(year) > days {
+        year -= 1;
+    }
+    while _hebrew_new_year(year + 1) <= days {
+        year += 1;
+    }
+    let year_length = _hebrew_new_year(year + 1) - _hebrew_new_year(year);
+    let leap = _hebrew_is_leap_year(year);
+    let lengths = [
+        30,
+        if year_length % 10 == 5 { 30 } else { 29 },
+        if year_length % 10 == 3 { 29 } else { 30 },
+        29,
+        30,
+        30,
+        29,
+        30,
+        29,
+        30,
+        29,
+        30,
+        29,
+    ];
+    let mut day = days - _hebrew_new_year(year);
+    let mut month = 0;
+    for (i, &length) in lengths.iter().enumerate() {
+        // Adar I only exists in leap years.
+        if i == 5 && !leap {
+            continue;
+        }
+        month += 1;
+        if day < length {
+            break;
+        }
+        day -= length;
+    }
+    (year, month, day as u32 + 1)
+}
+
+/// Ethiopian date of a day, counting from the Incarnation on 8-08-27 of the
+/// proleptic Gregorian calendar.
+fn _ethiopic_from_days(days: i64) -> (i64, u32, u32) {
+    let since_epoch = days + 716_367;
+    let year = (4 * since_epoch + 1463).div_euclid(1461);
+    let year_start = 365 * (year - 1) + year.div_euclid(4);
+    let day_of_year = since_epoch - year_start;
+    (
+        year,
+        (day_of_year / 30) as u32 + 1,
+        (day_of_year % 30) as u32 + 1,
+    )
+}
+
+#[rustfmt::skip]
+static _CALENDAR_0: _CalendarData = _CalendarData { months: [&["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_1: _CalendarData = _CalendarData { months: [&["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["M01", "M02", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11", "M12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_2: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_3: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_4: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_5: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_0: [&_CalendarData; 6] = [&_CALENDAR_0, &_CALENDAR_1, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_6: _CalendarData = _CalendarData { months: [&["Jan.", "Feb.", "Mrt.", "Apr.", "Mei", "Jun.", "Jul.", "Aug.", "Sep.", "Okt.", "Nov.", "Des."], &["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan.", "Feb.", "Mrt.", "Apr.", "Mei", "Jun.", "Jul.", "Aug.", "Sep.", "Okt.", "Nov.", "Des."], &["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["y-MM-dd GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_7: _CalendarData = _CalendarData { months: [&["Jan.", "Feb.", "Mrt.", "Apr.", "Mei", "Jun.", "Jul.", "Aug.", "Sep.", "Okt.", "Nov.", "Des."], &["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan.", "Feb.", "Mrt.", "Apr.", "Mei", "Jun.", "Jul.", "Aug.", "Sep.", "Okt.", "Nov.", "Des."], &["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["v.C.", "n.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["voor Christus", "ná Christus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v.C.", "n.C.", "M", "T", "S", "H", "R"]], date_patterns: ["y-MM-dd GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_8: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["y-MM-dd GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_9: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["y-MM-dd GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_10: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["y-MM-dd GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_11: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["y-MM-dd GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_1: [&_CalendarData; 6] = [&_CALENDAR_6, &_CALENDAR_7, &_CALENDAR_8, &_CALENDAR_9, &_CALENDAR_10, &_CALENDAR_11];
+
+#[rustfmt::skip]
+static _CALENDAR_12: _CalendarData = _CalendarData { months: [&["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔ", "Ɔ", "Ɔ", "O", "K", "A", "K", "Ɔ", "Ɛ", "A", "O", "Ɔ"], &["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔ", "Ɔ", "Ɔ", "O", "K", "A", "K", "Ɔ", "Ɛ", "A", "O", "Ɔ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_13: _CalendarData = _CalendarData { months: [&["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔ", "Ɔ", "Ɔ", "O", "K", "A", "K", "Ɔ", "Ɛ", "A", "O", "Ɔ"], &["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔpɛpɔn", "Ɔgyefoɔ", "Ɔbɛnem", "Oforisuo", "Kɔtɔnimma", "Ayɛwohomumu", "Kutawonsa", "Ɔsanaa", "Ɛbɔ", "Ahinime", "Obubuo", "Ɔpɛnimma"], &["Ɔ", "Ɔ", "Ɔ", "O", "K", "A", "K", "Ɔ", "Ɛ", "A", "O", "Ɔ"]], eras: [&["AK", "KE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Ansa Kristo", "Kristo Akyi", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["AK", "KE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_14: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_15: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_16: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_17: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDARS_2: [&_CalendarData; 6] = [&_CALENDAR_12, &_CALENDAR_13, &_CALENDAR_14, &_CALENDAR_15, &_CALENDAR_16, &_CALENDAR_17];
+
+#[rustfmt::skip]
+static _CALENDAR_18: _CalendarData = _CalendarData { months: [&["ጃን", "ፌብ", "ማርች", "ኤፕሪ", "ሜይ", "ጁን", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክቶ", "ኖቬም", "ዲሴም"], &["ጃንዋሪ", "ፌብሩዋሪ", "ማርች", "ኤፕሪል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክቶበር", "ኖቬምበር", "ዲሴምበር"], &["ጃ", "ፌ", "ማ", "ኤ", "ሜ", "ጁ", "ጁ", "ኦ", "ሴ", "ኦ", "ኖ", "ዲ"], &["ጃን", "ፌብ", "ማርች", "ኤፕሪ", "ሜይ", "ጁን", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክቶ", "ኖቬም", "ዲሴም"], &["ጃንዋሪ", "ፌብሩዋሪ", "ማርች", "ኤፕሪል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክቶበር", "ኖቬምበር", "ዲሴምበር"], &["ጃ", "ፌ", "ማ", "ኤ", "ሜ", "ጁ", "ጁ", "ኦ", "ሴ", "ኦ", "ኖ", "ዲ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE፣ d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_19: _CalendarData = _CalendarData { months: [&["ጃን", "ፌብ", "ማርች", "ኤፕሪ", "ሜይ", "ጁን", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክቶ", "ኖቬም", "ዲሴም"], &["ጃንዋሪ", "ፌብሩዋሪ", "ማርች", "ኤፕሪል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክቶበር", "ኖቬምበር", "ዲሴምበር"], &["ጃ", "ፌ", "ማ", "ኤ", "ሜ", "ጁ", "ጁ", "ኦ", "ሴ", "ኦ", "ኖ", "ዲ"], &["ጃን", "ፌብ", "ማርች", "ኤፕሪ", "ሜይ", "ጁን", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክቶ", "ኖቬም", "ዲሴም"], &["ጃንዋሪ", "ፌብሩዋሪ", "ማርች", "ኤፕሪል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክቶበር", "ኖቬምበር", "ዲሴምበር"], &["ጃ", "ፌ", "ማ", "ኤ", "ሜ", "ጁ", "ጁ", "ኦ", "ሴ", "ኦ", "ኖ", "ዲ"]], eras: [&["ዓ/ዓ", "ዓ/ም", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ዓመተ ዓለም", "ዓመተ ምሕረት", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ዓ/ዓ", "ዓ/ም", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE፣ d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_20: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE፣ d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_21: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["ሙሀረም", "ሳፈር", "ረቢዑል አወል", "ረቢዑል አኺር", "ጀማደል አወል", "ጀማደል አኺር", "ረጀብ", "ሻእባን", "ረመዳን", "ሸዋል", "ዙልቂዳህ", "ዙልሂጃህ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["ሙሀረም", "ሳፈር", "ረቢዑል አወል", "ረቢዑል አኺር", "ጀማደል አወል", "ጀማደል አኺር", "ረጀብ", "ሻእባን", "ረመዳን", "ሸዋል", "ዙልቂዳህ", "ዙልሂጃህ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE፣ d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_22: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE፣ d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_23: _CalendarData = _CalendarData { months: [&["መስከረም", "ጥቅምት", "ኅዳር", "ታኅሣሥ", "ጥር", "የካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰኔ", "ሐምሌ", "ነሐሴ", "ጳጉሜን", ""], &["መስከረም", "ጥቅምት", "ኅዳር", "ታኅሣሥ", "ጥር", "የካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰኔ", "ሐምሌ", "ነሐሴ", "ጳጉሜን", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["መስከረም", "ጥቅምት", "ኅዳር", "ታኅሣሥ", "ጥር", "የካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰኔ", "ሐምሌ", "ነሐሴ", "ጳጉሜን", ""], &["መስከረም", "ጥቅምት", "ኅዳር", "ታኅሣሥ", "ጥር", "የካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰኔ", "ሐምሌ", "ነሐሴ", "ጳጉሜን", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE፣ d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_3: [&_CalendarData; 6] = [&_CALENDAR_18, &_CALENDAR_19, &_CALENDAR_20, &_CALENDAR_21, &_CALENDAR_22, &_CALENDAR_23];
+
+#[rustfmt::skip]
+static _CALENDAR_24: _CalendarData = _CalendarData { months: [&["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"], &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"]], eras: [&["BE"], &["التقويم البوذي"], &["BE"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_25: _CalendarData = _CalendarData { months: [&["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"], &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"]], eras: [&["ق.م", "م", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["قبل الميلاد", "ميلادي", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["ق.م", "م", "M", "T", "S", "H", "R"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_26: _CalendarData = _CalendarData { months: [&["فرفردن", "أذربيهشت", "خرداد", "تار", "مرداد", "شهرفار", "مهر", "آيان", "آذر", "دي", "بهمن", "اسفندار"], &["فرفردن", "أذربيهشت", "خرداد", "تار", "مرداد", "شهرفار", "مهر", "آيان", "آذر", "دي", "بهمن", "اسفندار"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["فرفردن", "أذربيهشت", "خرداد", "تار", "مرداد", "شهرفار", "مهر", "آيان", "آذر", "دي", "بهمن", "اسفندار"], &["فرفردن", "أذربيهشت", "خرداد", "تار", "مرداد", "شهرفار", "مهر", "آيان", "آذر", "دي", "بهمن", "اسفندار"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ه\u{200d}.ش"], &["ه\u{200d}.ش"], &["ه\u{200d}.ش"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_27: _CalendarData = _CalendarData { months: [&["محرم", "صفر", "ربيع الأول", "ربيع الآخر", "جمادى الأولى", "جمادى الآخرة", "رجب", "شعبان", "رمضان", "شوال", "ذو القعدة", "ذو الحجة"], &["محرم", "صفر", "ربيع الأول", "ربيع الآخر", "جمادى الأولى", "جمادى الآخرة", "رجب", "شعبان", "رمضان", "شوال", "ذو القعدة", "ذو الحجة"], &["١", "٢", "٣", "٤", "٥", "٦", "٧", "٨", "٩", "١٠", "١١", "١٢"], &["محرم", "صفر", "ربيع الأول", "ربيع الآخر", "جمادى الأولى", "جمادى الآخرة", "رجب", "شعبان", "رمضان", "شوال", "ذو القعدة", "ذو الحجة"], &["محرم", "صفر", "ربيع الأول", "ربيع الآخر", "جمادى الأولى", "جمادى الآخرة", "رجب", "شعبان", "رمضان", "شوال", "ذو القعدة", "ذو الحجة"], &["١", "٢", "٣", "٤", "٥", "٦", "٧", "٨", "٩", "١٠", "١١", "١٢"]], eras: [&["هـ"], &["هـ"], &["هـ"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_28: _CalendarData = _CalendarData { months: [&["تشري", "مرحشوان", "كيسلو", "طيفت", "شباط", "آذار الأول", "آذار", "نيسان", "أيار", "سيفان", "تموز", "آب", "أيلول", "آذار الثاني"], &["تشري", "مرحشوان", "كيسلو", "طيفت", "شباط", "آذار الأول", "آذار", "نيسان", "أيار", "سيفان", "تموز", "آب", "أيلول", "آذار الثاني"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["تشري", "مرحشوان", "كيسلو", "طيفت", "شباط", "آذار الأول", "آذار", "نيسان", "أيار", "سيفان", "تموز", "آب", "أيلول", "آذار الثاني"], &["تشري", "مرحشوان", "كيسلو", "طيفت", "شباط", "آذار الأول", "آذار", "نيسان", "أيار", "سيفان", "تموز", "آب", "أيلول", "آذار الثاني"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["ص"], &["ص"], &["ص"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_29: _CalendarData = _CalendarData { months: [&["مسكريم", "تكمت", "هدار", "تهساس", "تر", "يكتت", "مجابيت", "ميازيا", "جنبت", "سين", "هامل", "نهاس", "باجمن", ""], &["مسكريم", "تكمت", "هدار", "تهساس", "تر", "يكتت", "مجابيت", "ميازيا", "جنبت", "سين", "هامل", "نهاس", "باجمن", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["مسكريم", "تكمت", "هدار", "تهساس", "تر", "يكتت", "مجابيت", "ميازيا", "جنبت", "سين", "هامل", "نهاس", "باجمن", ""], &["مسكريم", "تكمت", "هدار", "تهساس", "تر", "يكتت", "مجابيت", "ميازيا", "جنبت", "سين", "هامل", "نهاس", "باجمن", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_4: [&_CalendarData; 6] = [&_CALENDAR_24, &_CALENDAR_25, &_CALENDAR_26, &_CALENDAR_27, &_CALENDAR_28, &_CALENDAR_29];
+
+#[rustfmt::skip]
+static _CALENDAR_30: _CalendarData = _CalendarData { months: [&["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ج", "ف", "م", "أ", "م", "ج", "ج", "أ", "س", "أ", "ن", "د"], &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ج", "ف", "م", "أ", "م", "ج", "ج", "أ", "س", "أ", "ن", "د"]], eras: [&["BE"], &["التقويم البوذي"], &["BE"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_31: _CalendarData = _CalendarData { months: [&["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ج", "ف", "م", "أ", "م", "ج", "ج", "أ", "س", "أ", "ن", "د"], &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"], &["ج", "ف", "م", "أ", "م", "ج", "ج", "أ", "س", "أ", "ن", "د"]], eras: [&["ق.م", "م", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["قبل الميلاد", "ميلادي", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["ق.م", "م", "M", "T", "S", "H", "R"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_5: [&_CalendarData; 6] = [&_CALENDAR_30, &_CALENDAR_31, &_CALENDAR_26, &_CALENDAR_27, &_CALENDAR_28, &_CALENDAR_29];
+
+#[rustfmt::skip]
+static _CALENDAR_32: _CalendarData = _CalendarData { months: [&["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين\u{a0}الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"]], eras: [&["BE"], &["التقويم البوذي"], &["BE"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_33: _CalendarData = _CalendarData { months: [&["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين\u{a0}الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"]], eras: [&["ق.م", "م", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["قبل الميلاد", "ميلادي", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["ق.م", "م", "M", "T", "S", "H", "R"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_6: [&_CalendarData; 6] = [&_CALENDAR_32, &_CALENDAR_33, &_CALENDAR_26, &_CALENDAR_27, &_CALENDAR_28, &_CALENDAR_29];
+
+#[rustfmt::skip]
+static _CALENDAR_34: _CalendarData = _CalendarData { months: [&["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"]], eras: [&["BE"], &["التقويم البوذي"], &["BE"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_35: _CalendarData = _CalendarData { months: [&["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"], &["ك", "ش", "آ", "ن", "أ", "ح", "ت", "آ", "أ", "ت", "ت", "ك"]], eras: [&["ق.م", "م", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["قبل الميلاد", "ميلادي", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["ق.م", "م", "M", "T", "S", "H", "R"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_7: [&_CalendarData; 6] = [&_CALENDAR_34, &_CALENDAR_35, &_CALENDAR_26, &_CALENDAR_27, &_CALENDAR_28, &_CALENDAR_29];
+
+#[rustfmt::skip]
+static _CALENDAR_36: _CalendarData = _CalendarData { months: [&["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["ي", "ف", "م", "أ", "م", "ن", "ل", "غ", "ش", "ك", "ب", "د"], &["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["ي", "ف", "م", "أ", "م", "ن", "ل", "غ", "ش", "ك", "ب", "د"]], eras: [&["BE"], &["التقويم البوذي"], &["BE"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_37: _CalendarData = _CalendarData { months: [&["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["ي", "ف", "م", "أ", "م", "ن", "ل", "غ", "ش", "ك", "ب", "د"], &["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["يناير", "فبراير", "مارس", "أبريل", "ماي", "يونيو", "يوليوز", "غشت", "شتنبر", "أكتوبر", "نونبر", "دجنبر"], &["ي", "ف", "م", "أ", "م", "ن", "ل", "غ", "ش", "ك", "ب", "د"]], eras: [&["ق.م", "م", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["قبل الميلاد", "ميلادي", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["ق.م", "م", "M", "T", "S", "H", "R"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_8: [&_CalendarData; 6] = [&_CALENDAR_36, &_CALENDAR_37, &_CALENDAR_26, &_CALENDAR_27, &_CALENDAR_28, &_CALENDAR_29];
+
+#[rustfmt::skip]
+static _CALENDAR_38: _CalendarData = _CalendarData { months: [&["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["ي", "ف", "م", "إ", "و", "ن", "ل", "غ", "ش", "ك", "ب", "د"], &["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["ي", "ف", "م", "إ", "و", "ن", "ل", "غ", "ش", "ك", "ب", "د"]], eras: [&["BE"], &["التقويم البوذي"], &["BE"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_39: _CalendarData = _CalendarData { months: [&["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["ي", "ف", "م", "إ", "و", "ن", "ل", "غ", "ش", "ك", "ب", "د"], &["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["يناير", "فبراير", "مارس", "إبريل", "مايو", "يونيو", "يوليو", "أغشت", "شتمبر", "أكتوبر", "نوفمبر", "دجمبر"], &["ي", "ف", "م", "إ", "و", "ن", "ل", "غ", "ش", "ك", "ب", "د"]], eras: [&["ق.م", "م", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["قبل الميلاد", "ميلادي", "ميجي", "تيشو", "شووا", "هيسي", "ريوا"], &["ق.م", "م", "M", "T", "S", "H", "R"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "dd\u{200f}/MM\u{200f}/y G", "d MMMM y G", "EEEE، d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_9: [&_CalendarData; 6] = [&_CALENDAR_38, &_CALENDAR_39, &_CALENDAR_26, &_CALENDAR_27, &_CALENDAR_28, &_CALENDAR_29];
+
+#[rustfmt::skip]
+static _CALENDAR_40: _CalendarData = _CalendarData { months: [&["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}ৰ\u{9c1}", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগ", "ছেপ\u{9cd}তে", "অক\u{9cd}টো", "নৱে", "ডিচে"], &["জ\u{9be}ন\u{9c1}ৱ\u{9be}ৰী", "ফেব\u{9cd}ৰ\u{9c1}ৱ\u{9be}ৰী", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "ছেপ\u{9cd}তেম\u{9cd}বৰ", "অক\u{9cd}টোবৰ", "নৱেম\u{9cd}বৰ", "ডিচেম\u{9cd}বৰ"], &["জ", "ফ", "ম", "এ", "ম", "জ", "জ", "আ", "ছ", "অ", "ন", "ড"], &["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}ৰ\u{9c1}", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগ", "ছেপ\u{9cd}তে", "অক\u{9cd}টো", "নৱে", "ডিচে"], &["জ\u{9be}ন\u{9c1}ৱ\u{9be}ৰী", "ফেব\u{9cd}ৰ\u{9c1}ৱ\u{9be}ৰী", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "ছেপ\u{9cd}তেম\u{9cd}বৰ", "অক\u{9cd}টোবৰ", "নৱেম\u{9cd}বৰ", "ডিচেম\u{9cd}বৰ"], &["জ", "ফ", "ম", "এ", "ম", "জ", "জ", "আ", "ছ", "অ", "ন", "ড"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_41: _CalendarData = _CalendarData { months: [&["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}ৰ\u{9c1}", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগ", "ছেপ\u{9cd}তে", "অক\u{9cd}টো", "নৱে", "ডিচে"], &["জ\u{9be}ন\u{9c1}ৱ\u{9be}ৰী", "ফেব\u{9cd}ৰ\u{9c1}ৱ\u{9be}ৰী", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "ছেপ\u{9cd}তেম\u{9cd}বৰ", "অক\u{9cd}টোবৰ", "নৱেম\u{9cd}বৰ", "ডিচেম\u{9cd}বৰ"], &["জ", "ফ", "ম", "এ", "ম", "জ", "জ", "আ", "ছ", "অ", "ন", "ড"], &["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}ৰ\u{9c1}", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগ", "ছেপ\u{9cd}তে", "অক\u{9cd}টো", "নৱে", "ডিচে"], &["জ\u{9be}ন\u{9c1}ৱ\u{9be}ৰী", "ফেব\u{9cd}ৰ\u{9c1}ৱ\u{9be}ৰী", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে’", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "ছেপ\u{9cd}তেম\u{9cd}বৰ", "অক\u{9cd}টোবৰ", "নৱেম\u{9cd}বৰ", "ডিচেম\u{9cd}বৰ"], &["জ", "ফ", "ম", "এ", "ম", "জ", "জ", "আ", "ছ", "অ", "ন", "ড"]], eras: [&["খ\u{9cd}ৰীঃ প\u{9c2}ঃ", "খ\u{9cd}ৰীঃ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9cd}ৰীষ\u{9cd}টপ\u{9c2}ৰ\u{9cd}ব", "খ\u{9cd}ৰীষ\u{9cd}ট\u{9be}ব\u{9cd}দ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9cd}ৰীঃ প\u{9c2}ঃ", "খ\u{9cd}ৰীঃ", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_10: [&_CalendarData; 6] = [&_CALENDAR_40, &_CALENDAR_41, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_42: _CalendarData = _CalendarData { months: [&["xin", "feb", "mar", "abr", "may", "xun", "xnt", "ago", "set", "och", "pay", "avi"], &["de xineru", "de febreru", "de marzu", "d’abril", "de mayu", "de xunu", "de xunetu", "d’agostu", "de setiembre", "d’ochobre", "de payares", "d’avientu"], &["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "P", "A"], &["Xin", "Feb", "Mar", "Abr", "May", "Xun", "Xnt", "Ago", "Set", "Och", "Pay", "Avi"], &["xineru", "febreru", "marzu", "abril", "mayu", "xunu", "xunetu", "agostu", "setiembre", "ochobre", "payares", "avientu"], &["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "P", "A"]], eras: [&["EB"], &["era budista"], &["EB"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM 'de' y G", "EEEE, dd MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_43: _CalendarData = _CalendarData { months: [&["xin", "feb", "mar", "abr", "may", "xun", "xnt", "ago", "set", "och", "pay", "avi"], &["de xineru", "de febreru", "de marzu", "d’abril", "de mayu", "de xunu", "de xunetu", "d’agostu", "de setiembre", "d’ochobre", "de payares", "d’avientu"], &["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "P", "A"], &["Xin", "Feb", "Mar", "Abr", "May", "Xun", "Xnt", "Ago", "Set", "Och", "Pay", "Avi"], &["xineru", "febreru", "marzu", "abril", "mayu", "xunu", "xunetu", "agostu", "setiembre", "ochobre", "payares", "avientu"], &["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "P", "A"]], eras: [&["e.C.", "d.C.", "Meiji", "Taishō", "e. Shōwa", "Heisei", "Reiwa"], &["enantes de Cristu", "después de Cristu", "Meiji", "Taishō", "era Shōwa", "Heisei", "Reiwa"], &["e.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM 'de' y G", "EEEE, dd MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_44: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM 'de' y G", "EEEE, dd MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_45: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["de Muharram", "de Safar", "de Rabiʻ I", "de Rabiʻ II", "de Jumada I", "de Jumada II", "de Rajab", "de Shaʻban", "de Ramadan", "de Shawwal", "de Dhuʻl-Qiʻdah", "de Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM 'de' y G", "EEEE, dd MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_46: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7b"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7bis"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM 'de' y G", "EEEE, dd MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_47: _CalendarData = _CalendarData { months: [&["mes", "tek", "hed", "tah", "ter", "yek", "meg", "mia", "gen", "sen", "ham", "neh", "pag", ""], &["de meskerem", "de tekemt", "d’hedar", "de tahsas", "de ter", "de yekatit", "de megabit", "de miazia", "de genbot", "de sene", "d’hamle", "de nehasse", "de pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["mes", "tek", "hed", "tah", "ter", "yek", "meg", "mia", "gen", "sen", "ham", "neh", "pag", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "d. E."], &["ERA0", "después de la Encarnación"], &["ERA0", "dE"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM 'de' y G", "EEEE, dd MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_11: [&_CalendarData; 6] = [&_CALENDAR_42, &_CALENDAR_43, &_CALENDAR_44, &_CALENDAR_45, &_CALENDAR_46, &_CALENDAR_47];
+
+#[rustfmt::skip]
+static _CALENDAR_48: _CalendarData = _CalendarData { months: [&["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avq", "sen", "okt", "noy", "dek"], &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avq", "sen", "okt", "noy", "dek"], &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG dd.MM.y", "G d MMM y", "G d MMMM, y", "G d MMMM y, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_49: _CalendarData = _CalendarData { months: [&["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avq", "sen", "okt", "noy", "dek"], &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avq", "sen", "okt", "noy", "dek"], &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["e.ə.", "y.e.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["eramızdan əvvəl", "yeni era", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["e.ə.", "y.e.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG dd.MM.y", "G d MMM y", "G d MMMM, y", "G d MMMM y, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_50: _CalendarData = _CalendarData { months: [&["fərvərdin", "ordibeheşt", "xordəd", "tir", "mordəd", "şəhrivar", "mehr", "abən", "azər", "dey", "bəhmən", "isfənd"], &["fərvərdin", "ordibeheşt", "xordəd", "tir", "mordəd", "şəhrivar", "mehr", "abən", "azər", "dey", "bəhmən", "isfənd"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["fərvərdin", "ordibeheşt", "xordəd", "tir", "mordəd", "şəhrivar", "mehr", "abən", "azər", "dey", "bəhmən", "isfənd"], &["fərvərdin", "ordibeheşt", "xordəd", "tir", "mordəd", "şəhrivar", "mehr", "abən", "azər", "dey", "bəhmən", "isfənd"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG dd.MM.y", "G d MMM y", "G d MMMM, y", "G d MMMM y, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_51: _CalendarData = _CalendarData { months: [&["Məh.", "Səf.", "Rəb. I", "Rəb. II", "Cəm. I", "Cəm. II", "Rəc.", "Şab.", "Ram.", "Şəv.", "Zilq.", "Zilh."], &["Məhərrəm", "Səfər", "Rəbiüləvvəl", "Rəbiülaxır", "Cəmadiyələvvəl", "Cəmadiyəlaxır", "Rəcəb", "Şaban", "Ramazan", "Şəvval", "Zilqədə", "Zilhiccə"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Məh.", "Səf.", "Rəb. I", "Rəb. II", "Cəm. I", "Cəm. II", "Rəc.", "Şab.", "Ram.", "Şəv.", "Zilq.", "Zilh."], &["Məhərrəm", "Səfər", "Rəbiüləvvəl", "Rəbiülaxır", "Cəmadiyələvvəl", "Cəmadiyəlaxır", "Rəcəb", "Şaban", "Ramazan", "Şəvval", "Zilqədə", "Zilhiccə"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG dd.MM.y", "G d MMM y", "G d MMMM, y", "G d MMMM y, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_52: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG dd.MM.y", "G d MMM y", "G d MMMM, y", "G d MMMM y, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_53: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG dd.MM.y", "G d MMM y", "G d MMMM, y", "G d MMMM y, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_12: [&_CalendarData; 6] = [&_CALENDAR_48, &_CALENDAR_49, &_CALENDAR_50, &_CALENDAR_51, &_CALENDAR_52, &_CALENDAR_53];
+
+#[rustfmt::skip]
+static _CALENDAR_54: _CalendarData = _CalendarData { months: [&["Jan", "Par", "Már", "Apr", "Mai", "Jun", "Jól", "Aga", "Sat", "Akt", "Naw", "Das"], &["Janwari", "Parwari", "Márch", "Aprél", "Mai", "Jun", "Jólái", "Agast", "Satambar", "Aktubar", "Nawambar", "Dasambar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Jan", "Par", "Már", "Apr", "Mai", "Jun", "Jól", "Aga", "Sat", "Akt", "Naw", "Das"], &["Janwari", "Parwari", "Márch", "Aprél", "Mai", "Jun", "Jólái", "Agast", "Satambar", "Aktubar", "Nawambar", "Dasambar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_55: _CalendarData = _CalendarData { months: [&["Jan", "Par", "Már", "Apr", "Mai", "Jun", "Jól", "Aga", "Sat", "Akt", "Naw", "Das"], &["Janwari", "Parwari", "Márch", "Aprél", "Mai", "Jun", "Jólái", "Agast", "Satambar", "Aktubar", "Nawambar", "Dasambar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Jan", "Par", "Már", "Apr", "Mai", "Jun", "Jól", "Aga", "Sat", "Akt", "Naw", "Das"], &["Janwari", "Parwari", "Márch", "Aprél", "Mai", "Jun", "Jólái", "Agast", "Satambar", "Aktubar", "Nawambar", "Dasambar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["PM", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Péshmilád", "Annó Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["PM", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_56: _CalendarData = _CalendarData { months: [&["Moh.", "Sap.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Zol-K.", "Zol-H."], &["Moharram", "Sapar", "Rabiʻ I", "Rabiʻ II", "Jamádi I", "Jamádi II", "Rajab", "Shábán", "Ramezán", "Shauwál", "Zolkáda", "Zolhajj"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Moh.", "Sap.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Zol-K.", "Zol-H."], &["Moharram", "Sapar", "Rabiʻ I", "Rabiʻ II", "Jamádi I", "Jamádi II", "Rajab", "Shábán", "Ramezán", "Shauwál", "Zolkáda", "Zolhajj"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_13: [&_CalendarData; 6] = [&_CALENDAR_54, &_CALENDAR_55, &_CALENDAR_2, &_CALENDAR_56, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_57: _CalendarData = _CalendarData { months: [&["сту", "лют", "сак", "кра", "мая", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"], &["студзеня", "лютага", "сакавіка", "красавіка", "мая", "чэрвеня", "ліпеня", "жніўня", "верасня", "кастрычніка", "лістапада", "снежня"], &["с", "л", "с", "к", "м", "ч", "л", "ж", "в", "к", "л", "с"], &["сту", "лют", "сак", "кра", "май", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"], &["студзень", "люты", "сакавік", "красавік", "май", "чэрвень", "ліпень", "жнівень", "верасень", "кастрычнік", "лістапад", "снежань"], &["с", "л", "с", "к", "м", "ч", "л", "ж", "в", "к", "л", "с"]], eras: [&["б.э."], &["б.э."], &["б.э."]], date_patterns: ["d.M.yy", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_58: _CalendarData = _CalendarData { months: [&["сту", "лют", "сак", "кра", "мая", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"], &["студзеня", "лютага", "сакавіка", "красавіка", "мая", "чэрвеня", "ліпеня", "жніўня", "верасня", "кастрычніка", "лістапада", "снежня"], &["с", "л", "с", "к", "м", "ч", "л", "ж", "в", "к", "л", "с"], &["сту", "лют", "сак", "кра", "май", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"], &["студзень", "люты", "сакавік", "красавік", "май", "чэрвень", "ліпень", "жнівень", "верасень", "кастрычнік", "лістапад", "снежань"], &["с", "л", "с", "к", "м", "ч", "л", "ж", "в", "к", "л", "с"]], eras: [&["да н.э.", "н.э.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["да нараджэння Хрыстова", "ад нараджэння Хрыстова", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["да н.э.", "н.э.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_59: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_60: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_61: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_62: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_14: [&_CalendarData; 6] = [&_CALENDAR_57, &_CALENDAR_58, &_CALENDAR_59, &_CALENDAR_60, &_CALENDAR_61, &_CALENDAR_62];
+
+#[rustfmt::skip]
+static _CALENDAR_63: _CalendarData = _CalendarData { months: [&["яну", "фев", "март", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"], &["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"], &["я", "ф", "м", "а", "м", "ю", "ю", "а", "с", "о", "н", "д"], &["яну", "фев", "март", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"], &["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"], &["я", "ф", "м", "а", "м", "ю", "ю", "а", "с", "о", "н", "д"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.MM.yy G", "d.MM.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_64: _CalendarData = _CalendarData { months: [&["яну", "фев", "март", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"], &["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"], &["я", "ф", "м", "а", "м", "ю", "ю", "а", "с", "о", "н", "д"], &["яну", "фев", "март", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"], &["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"], &["я", "ф", "м", "а", "м", "ю", "ю", "а", "с", "о", "н", "д"]], eras: [&["пр.Хр.", "сл.Хр.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["преди Христа", "след Христа", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["пр.Хр.", "сл.Хр.", "M", "T", "S", "H", "R"]], date_patterns: ["d.MM.yy G", "d.MM.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_65: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.MM.yy G", "d.MM.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_66: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["мухарам", "сафар", "раби-1", "раби-2", "джумада-1", "джумада-2", "раджаб", "шабан", "рамазан", "Шавал", "Дхул-Каада", "Дхул-хиджа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["мухарам", "сафар", "раби-1", "раби-2", "джумада-1", "джумада-2", "раджаб", "шабан", "рамазан", "Шавал", "Дхул-Каада", "Дхул-хиджа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.MM.yy G", "d.MM.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_67: _CalendarData = _CalendarData { months: [&["тишри", "хешван", "кислев", "тебет", "шебат", "адар I", "адар", "нисан", "иар", "сиван", "тамуз", "ав", "елул", "адар II"], &["тишри", "хешван", "кислев", "тебет", "шебат", "адар I", "адар", "нисан", "иар", "сиван", "тамуз", "ав", "елул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["тишри", "хешван", "кислев", "тебет", "шебат", "адар I", "адар", "нисан", "иар", "сиван", "тамуз", "ав", "елул", "адар II"], &["тишри", "хешван", "кислев", "тебет", "шебат", "адар I", "адар", "нисан", "иар", "сиван", "тамуз", "ав", "елул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.MM.yy G", "d.MM.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_68: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.MM.yy G", "d.MM.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_15: [&_CalendarData; 6] = [&_CALENDAR_63, &_CALENDAR_64, &_CALENDAR_65, &_CALENDAR_66, &_CALENDAR_67, &_CALENDAR_68];
+
+#[rustfmt::skip]
+static _CALENDAR_69: _CalendarData = _CalendarData { months: [&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_70: _CalendarData = _CalendarData { months: [&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_16: [&_CalendarData; 6] = [&_CALENDAR_69, &_CALENDAR_70, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_71: _CalendarData = _CalendarData { months: [&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_72: _CalendarData = _CalendarData { months: [&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_17: [&_CalendarData; 6] = [&_CALENDAR_71, &_CALENDAR_72, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_73: _CalendarData = _CalendarData { months: [&["kaw", "kpa", "ci", "ɖʊ", "ɖu5", "ɖu6", "la", "kǝu", "fʊm", "cim", "pom", "bʊn"], &["ɩjikawǝrka kaŋɔrɔ", "ɩjikpaka kaŋɔrɔ", "arɛ\u{301}cika kaŋɔrɔ", "njɩbɔ nɖʊka kaŋɔrɔ", "acafʊnɖuka kaŋɔrɔ", "anɔɔɖuka kaŋɔrɔ", "alàlaka kaŋɔrɔ", "ɩjikǝuka kaŋɔrɔ", "abofʊmka kaŋɔrɔ", "ɩjicimka kaŋɔrɔ", "acapomka kaŋɔrɔ", "anɔɔbʊnka kaŋɔrɔ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["kaw", "kpa", "ci", "ɖʊ", "ɖu5", "ɖu6", "la", "kǝu", "fʊm", "cim", "pom", "bʊn"], &["ɩjikawǝrka kaŋɔrɔ", "ɩjikpaka kaŋɔrɔ", "arɛ\u{301}cika kaŋɔrɔ", "njɩbɔ nɖʊka kaŋɔrɔ", "acafʊnɖuka kaŋɔrɔ", "anɔɔɖuka kaŋɔrɔ", "alàlaka kaŋɔrɔ", "ɩjikǝuka kaŋɔrɔ", "abofʊmka kaŋɔrɔ", "ɩjicimka kaŋɔrɔ", "acapomka kaŋɔrɔ", "anɔɔbʊnka kaŋɔrɔ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d y G", "MMMM d y G", "EEEE, MMMM d y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_74: _CalendarData = _CalendarData { months: [&["kaw", "kpa", "ci", "ɖʊ", "ɖu5", "ɖu6", "la", "kǝu", "fʊm", "cim", "pom", "bʊn"], &["ɩjikawǝrka kaŋɔrɔ", "ɩjikpaka kaŋɔrɔ", "arɛ\u{301}cika kaŋɔrɔ", "njɩbɔ nɖʊka kaŋɔrɔ", "acafʊnɖuka kaŋɔrɔ", "anɔɔɖuka kaŋɔrɔ", "alàlaka kaŋɔrɔ", "ɩjikǝuka kaŋɔrɔ", "abofʊmka kaŋɔrɔ", "ɩjicimka kaŋɔrɔ", "acapomka kaŋɔrɔ", "anɔɔbʊnka kaŋɔrɔ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["kaw", "kpa", "ci", "ɖʊ", "ɖu5", "ɖu6", "la", "kǝu", "fʊm", "cim", "pom", "bʊn"], &["ɩjikawǝrka kaŋɔrɔ", "ɩjikpaka kaŋɔrɔ", "arɛ\u{301}cika kaŋɔrɔ", "njɩbɔ nɖʊka kaŋɔrɔ", "acafʊnɖuka kaŋɔrɔ", "anɔɔɖuka kaŋɔrɔ", "alàlaka kaŋɔrɔ", "ɩjikǝuka kaŋɔrɔ", "abofʊmka kaŋɔrɔ", "ɩjicimka kaŋɔrɔ", "acapomka kaŋɔrɔ", "anɔɔbʊnka kaŋɔrɔ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["naaBYŊAƖ", "AƖAK", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["naa Ba Ye Ŋʊm Annabi Ɩsa", "Annabi Ɩsa Abʊŋʊma Kaŋkǝm", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["naaBYŊAƖ", "AƖAK", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d y G", "MMMM d y G", "EEEE, MMMM d y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_75: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d y G", "MMMM d y G", "EEEE, MMMM d y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_76: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["M/d/y GGGGG", "MMM d y G", "MMMM d y G", "EEEE, MMMM d y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_77: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["M/d/y GGGGG", "MMM d y G", "MMMM d y G", "EEEE, MMMM d y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_78: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d y G", "MMMM d y G", "EEEE, MMMM d y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_18: [&_CalendarData; 6] = [&_CALENDAR_73, &_CALENDAR_74, &_CALENDAR_75, &_CALENDAR_76, &_CALENDAR_77, &_CALENDAR_78];
+
+#[rustfmt::skip]
+static _CALENDAR_79: _CalendarData = _CalendarData { months: [&["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আগ", "সেপ", "অক\u{9cd}টো", "নভে", "ডিসে"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}", "আ", "সে", "অ", "ন", "ডি"], &["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}", "আ", "সে", "অ", "ন", "ডি"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_80: _CalendarData = _CalendarData { months: [&["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আগ", "সেপ", "অক\u{9cd}টো", "নভে", "ডিসে"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}", "আ", "সে", "অ", "ন", "ডি"], &["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}", "আ", "সে", "অ", "ন", "ডি"]], eras: [&["খ\u{9cd}রিস\u{9cd}টপ\u{9c2}র\u{9cd}ব", "খ\u{9c3}ষ\u{9cd}ট\u{9be}ব\u{9cd}দ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9cd}রিস\u{9cd}টপ\u{9c2}র\u{9cd}ব", "খ\u{9cd}রীষ\u{9cd}ট\u{9be}ব\u{9cd}দ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9cd}রিস\u{9cd}টপ\u{9c2}র\u{9cd}ব", "খ\u{9c3}ষ\u{9cd}ট\u{9be}ব\u{9cd}দ", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_81: _CalendarData = _CalendarData { months: [&["ফ\u{9cd}য\u{9be}ভ\u{9be}র\u{9cd}ডিন", "অরডিবেহেশ\u{9cd}ত", "খোর\u{9cd}দ\u{9cd}দ", "তীর", "মর\u{9cd}য\u{9be}দ", "শ\u{9be}হরিব\u{9be}র", "মেহের", "আব\u{9be}ন", "সেপ\u{9cd}ট", "দে", "ব\u{9be}হম\u{9be}ন", "এসফ\u{9cd}য\u{9be}ন\u{9cd}ড"], &["ফ\u{9cd}য\u{9be}ভ\u{9be}র\u{9cd}ডিন", "অরডিবেহেশ\u{9cd}ত", "খোর\u{9cd}দ\u{9cd}দ", "তীর", "মর\u{9cd}য\u{9be}দ", "শ\u{9be}হরিব\u{9be}র", "মেহের", "আব\u{9be}ন", "ব\u{9be}জ\u{9be}র", "দে", "ব\u{9be}হম\u{9be}ন", "এসফ\u{9cd}য\u{9be}ন\u{9cd}ড"], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২"], &["ফ\u{9cd}য\u{9be}ভ\u{9be}র\u{9cd}ডিন", "অরডিবেহেশ\u{9cd}ত", "খোর\u{9cd}দ\u{9cd}দ", "তীর", "মর\u{9cd}য\u{9be}দ", "শ\u{9be}হরিব\u{9be}র", "মেহের", "আব\u{9be}ন", "সেপ\u{9cd}ট", "দে", "ব\u{9be}হম\u{9be}ন", "এসফ\u{9cd}য\u{9be}ন\u{9cd}ড"], &["ফ\u{9cd}য\u{9be}ভ\u{9be}র\u{9cd}ডিন", "অরডিবেহেশ\u{9cd}ত", "খোর\u{9cd}দ\u{9cd}দ", "তীর", "মর\u{9cd}য\u{9be}দ", "শ\u{9be}হরিব\u{9be}র", "মেহের", "আব\u{9be}ন", "ব\u{9be}জ\u{9be}র", "দে", "ব\u{9be}হম\u{9be}ন", "এসফ\u{9cd}য\u{9be}ন\u{9cd}ড"], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_82: _CalendarData = _CalendarData { months: [&["মহররম", "সফর", "রবিউল আউয\u{9bc}\u{9be}ল", "রবিউস স\u{9be}নি", "জম\u{9be}দিউল আউয\u{9bc}\u{9be}ল", "জম\u{9be}দিউস স\u{9be}নি", "রজব", "শ\u{9be}‘ব\u{9be}ন", "রমজ\u{9be}ন", "শ\u{9be}ওয\u{9bc}\u{9be}ল", "জ\u{9cd}বিলকদ", "জ\u{9cd}বিলহজ\u{9cd}জ"], &["মহররম", "সফর", "রবিউল আউয\u{9bc}\u{9be}ল", "রবিউস স\u{9be}নি", "জম\u{9be}দিউল আউয\u{9bc}\u{9be}ল", "জম\u{9be}দিউস স\u{9be}নি", "রজব", "শ\u{9be}‘ব\u{9be}ন", "রমজ\u{9be}ন", "শ\u{9be}ওয\u{9bc}\u{9be}ল", "জ\u{9cd}বিলকদ", "জ\u{9cd}বিলহজ\u{9cd}জ"], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২"], &["মহররম", "সফর", "রবিউল আউয\u{9bc}\u{9be}ল", "রবিউস স\u{9be}নি", "জম\u{9be}দিউল আউয\u{9bc}\u{9be}ল", "জম\u{9be}দিউস স\u{9be}নি", "রজব", "শ\u{9be}‘ব\u{9be}ন", "রমজ\u{9be}ন", "শ\u{9be}ওয\u{9bc}\u{9be}ল", "জ\u{9cd}বিলকদ", "জ\u{9cd}বিলহজ\u{9cd}জ"], &["মহররম", "সফর", "রবিউল আউয\u{9bc}\u{9be}ল", "রবিউস স\u{9be}নি", "জম\u{9be}দিউল আউয\u{9bc}\u{9be}ল", "জম\u{9be}দিউস স\u{9be}নি", "রজব", "শ\u{9be}‘ব\u{9be}ন", "রমজ\u{9be}ন", "শ\u{9be}ওয\u{9bc}\u{9be}ল", "জ\u{9cd}বিলকদ", "জ\u{9cd}বিলহজ\u{9cd}জ"], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২"]], eras: [&["য\u{9c1}গ"], &["য\u{9c1}গ"], &["য\u{9c1}গ"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_83: _CalendarData = _CalendarData { months: [&["তিশরি", "হেশভ\u{9be}ন", "কিসলেভ", "তেভেত", "শেভ\u{9be}ত", "আড\u{9be}র I", "আড\u{9be}র", "নিশ\u{9be}ন", "আয\u{9bc}\u{9be}র", "সিভ\u{9be}ন", "ত\u{9be}ম\u{9c1}জ", "অভ", "এল\u{9c1}ল", "আড\u{9be}র II"], &["তিশরি", "হেশভ\u{9be}ন", "কিসলেভ", "তেভেত", "শেভ\u{9be}ত", "আড\u{9be}র I", "আড\u{9be}র", "নিশ\u{9be}ন", "আয\u{9bc}\u{9be}র", "সিভ\u{9be}ন", "ত\u{9be}ম\u{9c1}জ", "অভ", "এল\u{9c1}ল", "আড\u{9be}র II"], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২", "১৩", "৭"], &["তিশরি", "হেশভ\u{9be}ন", "কিসলেভ", "তেভেত", "শেভ\u{9be}ত", "আড\u{9be}র I", "আড\u{9be}র", "নিশ\u{9be}ন", "আয\u{9bc}\u{9be}র", "সিভ\u{9be}ন", "ত\u{9be}ম\u{9c1}জ", "অভ", "এল\u{9c1}ল", "আড\u{9be}র II"], &["তিশরি", "হেশভ\u{9be}ন", "কিসলেভ", "তেভেত", "শেভ\u{9be}ত", "আড\u{9be}র I", "আড\u{9be}র", "নিশ\u{9be}ন", "আয\u{9bc}\u{9be}র", "সিভ\u{9be}ন", "ত\u{9be}ম\u{9c1}জ", "অভ", "এল\u{9c1}ল", "আড\u{9be}র II"], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২", "১৩", "৭"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_84: _CalendarData = _CalendarData { months: [&["ম\u{9be}স\u{9cd}কেরেম", "টেকেমট", "হিড\u{9be}র", "ত\u{9be}হস\u{9be}স", "টের", "ইয\u{9bc}েক\u{9be}টিট", "মেগ\u{9be}বিট", "মিয\u{9bc}\u{9be}জিয\u{9bc}\u{9be}", "গেনবট", "সিনি", "হ\u{9cd}য\u{9be}মলি", "নেহ\u{9be}সে", "প\u{9be}গ\u{9c1}মেন", ""], &["ম\u{9be}স\u{9cd}কেরেম", "টেকেমট", "হিড\u{9be}র", "ত\u{9be}হস\u{9be}স", "টের", "ইয\u{9bc}েক\u{9be}টিট", "মেগ\u{9be}বিট", "মিয\u{9bc}\u{9be}জিয\u{9bc}\u{9be}", "গেনবট", "সিনি", "হ\u{9cd}য\u{9be}মলি", "নেহ\u{9be}সে", "প\u{9be}গ\u{9c1}মেন", ""], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২", "১৩", ""], &["ম\u{9be}স\u{9cd}কেরেম", "টেকেমট", "হিড\u{9be}র", "ত\u{9be}হস\u{9be}স", "টের", "ইয\u{9bc}েক\u{9be}টিট", "মেগ\u{9be}বিট", "মিয\u{9bc}\u{9be}জিয\u{9bc}\u{9be}", "গেনবট", "সিনি", "হ\u{9cd}য\u{9be}মলি", "নেহ\u{9be}সে", "প\u{9be}গ\u{9c1}মেন", ""], &["ম\u{9be}স\u{9cd}কেরেম", "টেকেমট", "হিড\u{9be}র", "ত\u{9be}হস\u{9be}স", "টের", "ইয\u{9bc}েক\u{9be}টিট", "মেগ\u{9be}বিট", "মিয\u{9bc}\u{9be}জিয\u{9bc}\u{9be}", "গেনবট", "সিনি", "হ\u{9cd}য\u{9be}মলি", "নেহ\u{9be}সে", "প\u{9be}গ\u{9c1}মেন", ""], &["১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯", "১০", "১১", "১২", "১৩", ""]], eras: [&["ERA0", "য\u{9c1}গ ১"], &["ERA0", "য\u{9c1}গ ১"], &["ERA0", "য\u{9c1}গ ১"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_19: [&_CalendarData; 6] = [&_CALENDAR_79, &_CALENDAR_80, &_CALENDAR_81, &_CALENDAR_82, &_CALENDAR_83, &_CALENDAR_84];
+
+#[rustfmt::skip]
+static _CALENDAR_85: _CalendarData = _CalendarData { months: [&["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আগ", "সেপ\u{9cd}টেঃ", "অক\u{9cd}টোঃ", "নভেঃ", "ডিসেঃ"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "অ", "ন", "ডি"], &["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেঃ", "অক\u{9cd}টোঃ", "নভেঃ", "ডিসেঃ"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "অ", "ন", "ডি"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_86: _CalendarData = _CalendarData { months: [&["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আগ", "সেপ\u{9cd}টেঃ", "অক\u{9cd}টোঃ", "নভেঃ", "ডিসেঃ"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "অ", "ন", "ডি"], &["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেঃ", "অক\u{9cd}টোঃ", "নভেঃ", "ডিসেঃ"], &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}", "এ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "অ", "ন", "ডি"]], eras: [&["খ\u{9cd}রিঃপ\u{9c2}ঃ", "খ\u{9cd}রিঃ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9cd}রিঃপ\u{9c2}ঃ", "খ\u{9cd}রিষ\u{9cd}ট\u{9be}ব\u{9cd}দ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9cd}রিঃপ\u{9c2}ঃ", "খ\u{9cd}রিঃ", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_20: [&_CalendarData; 6] = [&_CALENDAR_85, &_CALENDAR_86, &_CALENDAR_81, &_CALENDAR_82, &_CALENDAR_83, &_CALENDAR_84];
+
+#[rustfmt::skip]
+static _CALENDAR_87: _CalendarData = _CalendarData { months: [&["Gen.", "Cʼhwe.", "Meur.", "Ebr.", "Mae", "Mezh.", "Goue.", "Eost", "Gwen.", "Here", "Du", "Kzu."], &["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"], &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12"], &["Gen.", "Cʼhwe.", "Meur.", "Ebr.", "Mae", "Mezh.", "Goue.", "Eost", "Gwen.", "Here", "Du", "Kzu."], &["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"], &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12"]], eras: [&["A.B."], &["amzervezh voudaek"], &["AB"]], date_patterns: ["dd/MM/y GGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_88: _CalendarData = _CalendarData { months: [&["Gen.", "Cʼhwe.", "Meur.", "Ebr.", "Mae", "Mezh.", "Goue.", "Eost", "Gwen.", "Here", "Du", "Kzu."], &["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"], &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12"], &["Gen.", "Cʼhwe.", "Meur.", "Ebr.", "Mae", "Mezh.", "Goue.", "Eost", "Gwen.", "Here", "Du", "Kzu."], &["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"], &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12"]], eras: [&["a-raok J.K.", "goude J.K.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a-raok Jezuz-Krist", "goude Jezuz-Krist", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a-raok J.K.", "goude J.K.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_89: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_90: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_91: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_92: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_21: [&_CalendarData; 6] = [&_CALENDAR_87, &_CALENDAR_88, &_CALENDAR_89, &_CALENDAR_90, &_CALENDAR_91, &_CALENDAR_92];
+
+#[rustfmt::skip]
+static _CALENDAR_93: _CalendarData = _CalendarData { months: [&["जान", "फ\u{947}ब", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "आग", "स\u{947}प", "अक\u{94d}ट’", "नव\u{947}", "डिस\u{947}"], &["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{942}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "आगष\u{94d}ट", "स\u{947}प\u{94d}थ\u{947}म\u{94d}बर", "अक\u{94d}ट’बर", "नव\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["ज", "फ", "म", "ए", "म", "ज", "ज", "आ", "स", "अ", "न", "ड"], &["जान", "फ\u{947}ब", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "आग", "स\u{947}प", "अक\u{94d}ट’", "नव\u{947}", "डिस\u{947}"], &["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{942}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "आगष\u{94d}ट", "स\u{947}प\u{94d}थ\u{947}म\u{94d}बर", "अक\u{94d}ट’बर", "नव\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["ज", "फ", "म", "ए", "म", "ज", "ज", "आ", "स", "अ", "न", "ड"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/yy GGGGG", "d MMM y, G", "d MMMM y, G", "d MMMM y, EEEE, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_94: _CalendarData = _CalendarData { months: [&["जान", "फ\u{947}ब", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "आग", "स\u{947}प", "अक\u{94d}ट’", "नव\u{947}", "डिस\u{947}"], &["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{942}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "आगष\u{94d}ट", "स\u{947}प\u{94d}थ\u{947}म\u{94d}बर", "अक\u{94d}ट’बर", "नव\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["ज", "फ", "म", "ए", "म", "ज", "ज", "आ", "स", "अ", "न", "ड"], &["जान", "फ\u{947}ब", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "आग", "स\u{947}प", "अक\u{94d}ट’", "नव\u{947}", "डिस\u{947}"], &["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{942}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "आगष\u{94d}ट", "स\u{947}प\u{94d}थ\u{947}म\u{94d}बर", "अक\u{94d}ट’बर", "नव\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["ज", "फ", "म", "ए", "म", "ज", "ज", "आ", "स", "अ", "न", "ड"]], eras: [&["बि.सि.", "ए.दि", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ख\u{94d}राइष\u{94d}तनि सिगा\u{902}", "आन\u{94d}न’ दामिनि", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["बि.सि.", "ए.दि", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy GGGGG", "d MMM y, G", "d MMMM y, G", "d MMMM y, EEEE, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_95: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/yy GGGGG", "d MMM y, G", "d MMMM y, G", "d MMMM y, EEEE, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_96: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/yy GGGGG", "d MMM y, G", "d MMMM y, G", "d MMMM y, EEEE, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_97: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/yy GGGGG", "d MMM y, G", "d MMMM y, G", "d MMMM y, EEEE, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_98: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/yy GGGGG", "d MMM y, G", "d MMMM y, G", "d MMMM y, EEEE, G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_22: [&_CalendarData; 6] = [&_CALENDAR_93, &_CALENDAR_94, &_CALENDAR_95, &_CALENDAR_96, &_CALENDAR_97, &_CALENDAR_98];
+
+#[rustfmt::skip]
+static _CALENDAR_99: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "juni", "juli", "august", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "juni", "juli", "august", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_100: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "juni", "juli", "august", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "juni", "juli", "august", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["p. n. e.", "n. e.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["prije nove ere", "nove ere", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["p.n.e.", "n. e.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_101: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_102: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "Rab. I", "rab. ii", "džum. i", "džum. ii", "redž.", "ša.", "ram.", "še.", "zul-k.", "zul-h."], &["muharem", "safer", "rabiʻ i", "rabiʻ ii", "džumade i", "džumade ii", "redžeb", "Shaʻban", "ramazan", "ševal", "zul-kade", "zul-hidže"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "Rab. I", "rab. ii", "džum. i", "džum. ii", "redž.", "ša.", "ram.", "še.", "zul-k.", "zul-h."], &["muharem", "safer", "rabiʻ i", "rabiʻ ii", "džumade i", "džumade ii", "redžeb", "Shaʻban", "ramazan", "ševal", "zul-kade", "zul-hidže"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y. G", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_103: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_104: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_23: [&_CalendarData; 6] = [&_CALENDAR_99, &_CALENDAR_100, &_CALENDAR_101, &_CALENDAR_102, &_CALENDAR_103, &_CALENDAR_104];
+
+#[rustfmt::skip]
+static _CALENDAR_105: _CalendarData = _CalendarData { months: [&["јан", "феб", "мар", "апр", "мај", "јун", "јул", "ауг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јуни", "јули", "аугуст", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "мар", "апр", "мај", "јун", "јул", "ауг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јуни", "јули", "аугуст", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["БЕ"], &["БЕ"], &["БЕ"]], date_patterns: ["d.M.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_106: _CalendarData = _CalendarData { months: [&["јан", "феб", "мар", "апр", "мај", "јун", "јул", "ауг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јуни", "јули", "аугуст", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "мар", "апр", "мај", "јун", "јул", "ауг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јуни", "јули", "аугуст", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["п. н. е.", "н. е.", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["прије нове ере", "нове ере", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["п.н.е.", "н.е.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_107: _CalendarData = _CalendarData { months: [&["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_108: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Мурахам", "Сафар", "Рабиʻ I", "Рабиʻ II", "Јумада I", "Јумада II", "Рађаб", "Шаʻбан", "Рамадан", "Шавал", "Дуʻл-Киʻда", "Дуʻл-хиђа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Мурахам", "Сафар", "Рабиʻ I", "Рабиʻ II", "Јумада I", "Јумада II", "Рађаб", "Шаʻбан", "Рамадан", "Шавал", "Дуʻл-Киʻда", "Дуʻл-хиђа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["АХ"], &["АХ"], &["АХ"]], date_patterns: ["dd.MM.y. G", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_109: _CalendarData = _CalendarData { months: [&["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_110: _CalendarData = _CalendarData { months: [&["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y. GGGGG", "dd.MM.y. G", "dd. MMMM y. G", "EEEE, dd. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_24: [&_CalendarData; 6] = [&_CALENDAR_105, &_CALENDAR_106, &_CALENDAR_107, &_CALENDAR_108, &_CALENDAR_109, &_CALENDAR_110];
+
+#[rustfmt::skip]
+static _CALENDAR_111: _CalendarData = _CalendarData { months: [&["de gen.", "de febr.", "de març", "d’abr.", "de maig", "de juny", "de jul.", "d’ag.", "de set.", "d’oct.", "de nov.", "de des."], &["de gener", "de febrer", "de març", "d’abril", "de maig", "de juny", "de juliol", "d’agost", "de setembre", "d’octubre", "de novembre", "de desembre"], &["GN", "FB", "MÇ", "AB", "MG", "JN", "JL", "AG", "ST", "OC", "NV", "DS"], &["gen.", "febr.", "març", "abr.", "maig", "juny", "jul.", "ag.", "set.", "oct.", "nov.", "des."], &["gener", "febrer", "març", "abril", "maig", "juny", "juliol", "agost", "setembre", "octubre", "novembre", "desembre"], &["GN", "FB", "MÇ", "AB", "MG", "JN", "JL", "AG", "ST", "OC", "NV", "DS"]], eras: [&["eB"], &["eB"], &["eB"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_112: _CalendarData = _CalendarData { months: [&["de gen.", "de febr.", "de març", "d’abr.", "de maig", "de juny", "de jul.", "d’ag.", "de set.", "d’oct.", "de nov.", "de des."], &["de gener", "de febrer", "de març", "d’abril", "de maig", "de juny", "de juliol", "d’agost", "de setembre", "d’octubre", "de novembre", "de desembre"], &["GN", "FB", "MÇ", "AB", "MG", "JN", "JL", "AG", "ST", "OC", "NV", "DS"], &["gen.", "febr.", "març", "abr.", "maig", "juny", "jul.", "ag.", "set.", "oct.", "nov.", "des."], &["gener", "febrer", "març", "abril", "maig", "juny", "juliol", "agost", "setembre", "octubre", "novembre", "desembre"], &["GN", "FB", "MÇ", "AB", "MG", "JN", "JL", "AG", "ST", "OC", "NV", "DS"]], eras: [&["aC", "dC", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["abans de Crist", "després de Crist", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["aC", "dC", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy GGGGG", "d/M/y G", "d MMMM 'del' y G", "EEEE, d MMMM 'del' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_113: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/yy GGGGG", "d/M/y G", "d MMMM 'del' y G", "EEEE, d MMMM 'del' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_114: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/yy GGGGG", "d/M/y G", "d MMMM 'del' y G", "EEEE, d MMMM 'del' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_115: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/yy GGGGG", "d/M/y G", "d MMMM 'del' y G", "EEEE, d MMMM 'del' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_116: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/yy GGGGG", "d/M/y G", "d MMMM 'del' y G", "EEEE, d MMMM 'del' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_25: [&_CalendarData; 6] = [&_CALENDAR_111, &_CALENDAR_112, &_CALENDAR_113, &_CALENDAR_114, &_CALENDAR_115, &_CALENDAR_116];
+
+#[rustfmt::skip]
+static _CALENDAR_117: _CalendarData = _CalendarData { months: [&["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Sep", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Septyembre", "Oktubre", "Nobyembre", "Disyembre"], &["E", "P", "M", "A", "M", "H", "H", "A", "S", "O", "N", "D"], &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Sep", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Septyembre", "Oktubre", "Nobyembre", "Disyembre"], &["E", "P", "M", "A", "M", "H", "H", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_118: _CalendarData = _CalendarData { months: [&["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Sep", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Septyembre", "Oktubre", "Nobyembre", "Disyembre"], &["E", "P", "M", "A", "M", "H", "H", "A", "S", "O", "N", "D"], &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Sep", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Septyembre", "Oktubre", "Nobyembre", "Disyembre"], &["E", "P", "M", "A", "M", "H", "H", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Sa Wala Pa Si Kristo", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_119: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_120: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_121: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_122: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_26: [&_CalendarData; 6] = [&_CALENDAR_117, &_CALENDAR_118, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_123: _CalendarData = _CalendarData { months: [&["ᎤᏃ", "ᎧᎦ", "ᎠᏅ", "ᎧᏬ", "ᎠᏂ", "ᏕᎭ", "ᎫᏰ", "ᎦᎶ", "ᏚᎵ", "ᏚᏂ", "ᏅᏓ", "ᎥᏍ"], &["ᎤᏃᎸᏔᏅ", "ᎧᎦᎵ", "ᎠᏅᏱ", "ᎧᏬᏂ", "ᎠᏂᏍᎬᏘ", "ᏕᎭᎷᏱ", "ᎫᏰᏉᏂ", "ᎦᎶᏂ", "ᏚᎵᏍᏗ", "ᏚᏂᏅᏗ", "ᏅᏓᏕᏆ", "ᎥᏍᎩᏱ"], &["Ꭴ", "Ꭷ", "Ꭰ", "Ꭷ", "Ꭰ", "Ꮥ", "Ꭻ", "Ꭶ", "Ꮪ", "Ꮪ", "Ꮕ", "Ꭵ"], &["ᎤᏃ", "ᎧᎦ", "ᎠᏅ", "ᎧᏬ", "ᎠᏂ", "ᏕᎭ", "ᎫᏰ", "ᎦᎶ", "ᏚᎵ", "ᏚᏂ", "ᏅᏓ", "ᎥᏍ"], &["ᎤᏃᎸᏔᏅ", "ᎧᎦᎵ", "ᎠᏅᏱ", "ᎧᏬᏂ", "ᎠᏂᏍᎬᏘ", "ᏕᎭᎷᏱ", "ᎫᏰᏉᏂ", "ᎦᎶᏂ", "ᏚᎵᏍᏗ", "ᏚᏂᏅᏗ", "ᏅᏓᏕᏆ", "ᎥᏍᎩᏱ"], &["Ꭴ", "Ꭷ", "Ꭰ", "Ꭷ", "Ꭰ", "Ꮥ", "Ꭻ", "Ꭶ", "Ꮪ", "Ꮪ", "Ꮕ", "Ꭵ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_124: _CalendarData = _CalendarData { months: [&["ᎤᏃ", "ᎧᎦ", "ᎠᏅ", "ᎧᏬ", "ᎠᏂ", "ᏕᎭ", "ᎫᏰ", "ᎦᎶ", "ᏚᎵ", "ᏚᏂ", "ᏅᏓ", "ᎥᏍ"], &["ᎤᏃᎸᏔᏅ", "ᎧᎦᎵ", "ᎠᏅᏱ", "ᎧᏬᏂ", "ᎠᏂᏍᎬᏘ", "ᏕᎭᎷᏱ", "ᎫᏰᏉᏂ", "ᎦᎶᏂ", "ᏚᎵᏍᏗ", "ᏚᏂᏅᏗ", "ᏅᏓᏕᏆ", "ᎥᏍᎩᏱ"], &["Ꭴ", "Ꭷ", "Ꭰ", "Ꭷ", "Ꭰ", "Ꮥ", "Ꭻ", "Ꭶ", "Ꮪ", "Ꮪ", "Ꮕ", "Ꭵ"], &["ᎤᏃ", "ᎧᎦ", "ᎠᏅ", "ᎧᏬ", "ᎠᏂ", "ᏕᎭ", "ᎫᏰ", "ᎦᎶ", "ᏚᎵ", "ᏚᏂ", "ᏅᏓ", "ᎥᏍ"], &["ᎤᏃᎸᏔᏅ", "ᎧᎦᎵ", "ᎠᏅᏱ", "ᎧᏬᏂ", "ᎠᏂᏍᎬᏘ", "ᏕᎭᎷᏱ", "ᎫᏰᏉᏂ", "ᎦᎶᏂ", "ᏚᎵᏍᏗ", "ᏚᏂᏅᏗ", "ᏅᏓᏕᏆ", "ᎥᏍᎩᏱ"], &["Ꭴ", "Ꭷ", "Ꭰ", "Ꭷ", "Ꭰ", "Ꮥ", "Ꭻ", "Ꭶ", "Ꮪ", "Ꮪ", "Ꮕ", "Ꭵ"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ᏧᏓᎷᎸ ᎤᎷᎯᏍᏗ ᎦᎶᏁᏛ", "ᎠᏃ ᏙᎻᏂ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_27: [&_CalendarData; 6] = [&_CALENDAR_123, &_CALENDAR_124, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_125: _CalendarData = _CalendarData { months: [&["led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro"], &["ledna", "února", "března", "dubna", "května", "června", "července", "srpna", "září", "října", "listopadu", "prosince"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro"], &["leden", "únor", "březen", "duben", "květen", "červen", "červenec", "srpen", "září", "říjen", "listopad", "prosinec"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.yy GGGGG", "d. M. y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_126: _CalendarData = _CalendarData { months: [&["led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro"], &["ledna", "února", "března", "dubna", "května", "června", "července", "srpna", "září", "října", "listopadu", "prosince"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro"], &["leden", "únor", "březen", "duben", "květen", "červen", "červenec", "srpen", "září", "říjen", "listopad", "prosinec"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["př. n. l.", "n. l.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["před naším letopočtem", "našeho letopočtu", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["př.n.l.", "n.l.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.yy GGGGG", "d. M. y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_127: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.yy GGGGG", "d. M. y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_128: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "reb. I", "reb. II", "džum. I", "džum. II", "red.", "ša.", "ram.", "šaw.", "zú l-k.", "zú l-h."], &["muharrem", "safar", "rebí’u l-awwal", "rebí’u s-sání", "džumádá al-úlá", "džumádá al-áchira", "redžeb", "ša’bán", "ramadán", "šawwal", "zú l-ka’da", "zú l-hidždža"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "reb. I", "reb. II", "džum. I", "džum. II", "red.", "ša.", "ram.", "šaw.", "zú l-k.", "zú l-h."], &["muharrem", "safar", "rebí’u l-awwal", "rebí’u s-sání", "džumádá al-úlá", "džumádá al-áchira", "redžeb", "ša’bán", "ramadán", "šawwal", "zú l-ka’da", "zú l-hidždža"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.yy GGGGG", "d. M. y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_129: _CalendarData = _CalendarData { months: [&["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.yy GGGGG", "d. M. y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_130: _CalendarData = _CalendarData { months: [&["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.yy GGGGG", "d. M. y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_28: [&_CalendarData; 6] = [&_CALENDAR_125, &_CALENDAR_126, &_CALENDAR_127, &_CALENDAR_128, &_CALENDAR_129, &_CALENDAR_130];
+
+#[rustfmt::skip]
+static _CALENDAR_131: _CalendarData = _CalendarData { months: [&["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_132: _CalendarData = _CalendarData { months: [&["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["ᐅᒉᒥᑮᓯᑳᐏᐲᓯᒼ", "ᐸᐚᐦᒐᑭᓇᓰᐢ", "ᒥᑭᓯᐏᐲᓯᒼ", "ᓂᐢᑭᐲᓯᒼ", "ᐊᓃᑭᐲᓯᒼ", "ᐚᐏᐲᓯᒼ", "ᐹᐢᑲᐦᐋᐏᐲᓯᒼ", "ᐅᐸᐦᐅᐏᐲᓯᒼ", "ᓄᒌᑐᐏᐲᓯᒼ", "ᐱᓈᐢᑯᐏᐲᓯᒼ", "ᐋᕽᐘᑎᓄᐏᐲᓯᒼ", "ᒪᑯᓭᑮᓭᑳᐏᐲᓯᒼ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_29: [&_CalendarData; 6] = [&_CALENDAR_131, &_CALENDAR_132, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_133: _CalendarData = _CalendarData { months: [&["кӑр.", "нар.", "пуш", "ака", "ҫу", "ҫӗр.", "утӑ", "ҫур.", "авӑн", "юпа", "чӳк", "раш."], &["кӑрлач", "нарӑс", "пуш", "ака", "ҫу", "ҫӗртме", "утӑ", "ҫурла", "авӑн", "юпа", "чӳк", "раштав"], &["К", "Н", "П", "А", "Ҫ", "Ҫ", "У", "Ҫ", "А", "Ю", "Ч", "Р"], &["кӑр.", "нар.", "пуш", "ака", "ҫу", "ҫӗр.", "утӑ", "ҫур.", "авӑн", "юпа", "чӳк", "раш."], &["кӑрлач", "нарӑс", "пуш", "ака", "ҫу", "ҫӗртме", "утӑ", "ҫурла", "авӑн", "юпа", "чӳк", "раштав"], &["К", "Н", "П", "А", "Ҫ", "Ҫ", "У", "Ҫ", "А", "Ю", "Ч", "Р"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}ҫ. G", "d MMMM y\u{202f}ҫ. G", "EEEE, d MMMM y\u{202f}ҫ. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_134: _CalendarData = _CalendarData { months: [&["кӑр.", "нар.", "пуш", "ака", "ҫу", "ҫӗр.", "утӑ", "ҫур.", "авӑн", "юпа", "чӳк", "раш."], &["кӑрлач", "нарӑс", "пуш", "ака", "ҫу", "ҫӗртме", "утӑ", "ҫурла", "авӑн", "юпа", "чӳк", "раштав"], &["К", "Н", "П", "А", "Ҫ", "Ҫ", "У", "Ҫ", "А", "Ю", "Ч", "Р"], &["кӑр.", "нар.", "пуш", "ака", "ҫу", "ҫӗр.", "утӑ", "ҫур.", "авӑн", "юпа", "чӳк", "раш."], &["кӑрлач", "нарӑс", "пуш", "ака", "ҫу", "ҫӗртме", "утӑ", "ҫурла", "авӑн", "юпа", "чӳк", "раштав"], &["К", "Н", "П", "А", "Ҫ", "Ҫ", "У", "Ҫ", "А", "Ю", "Ч", "Р"]], eras: [&["п. э.", "х. э.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Христос ҫуралнӑ кунччен", "Христос ҫуралнӑ кунран", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["п. э.", "х. э.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}ҫ. G", "d MMMM y\u{202f}ҫ. G", "EEEE, d MMMM y\u{202f}ҫ. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_135: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}ҫ. G", "d MMMM y\u{202f}ҫ. G", "EEEE, d MMMM y\u{202f}ҫ. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_136: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}ҫ. G", "d MMMM y\u{202f}ҫ. G", "EEEE, d MMMM y\u{202f}ҫ. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_137: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}ҫ. G", "d MMMM y\u{202f}ҫ. G", "EEEE, d MMMM y\u{202f}ҫ. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_138: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}ҫ. G", "d MMMM y\u{202f}ҫ. G", "EEEE, d MMMM y\u{202f}ҫ. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_30: [&_CalendarData; 6] = [&_CALENDAR_133, &_CALENDAR_134, &_CALENDAR_135, &_CALENDAR_136, &_CALENDAR_137, &_CALENDAR_138];
+
+#[rustfmt::skip]
+static _CALENDAR_139: _CalendarData = _CalendarData { months: [&["Ion", "Chwef", "Maw", "Ebr", "Mai", "Meh", "Gorff", "Awst", "Medi", "Hyd", "Tach", "Rhag"], &["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"], &["I", "Ch", "M", "E", "M", "M", "G", "A", "M", "H", "T", "Rh"], &["Ion", "Chw", "Maw", "Ebr", "Mai", "Meh", "Gor", "Awst", "Medi", "Hyd", "Tach", "Rhag"], &["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"], &["I", "Ch", "M", "E", "M", "M", "G", "A", "M", "H", "T", "Rh"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_140: _CalendarData = _CalendarData { months: [&["Ion", "Chwef", "Maw", "Ebr", "Mai", "Meh", "Gorff", "Awst", "Medi", "Hyd", "Tach", "Rhag"], &["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"], &["I", "Ch", "M", "E", "M", "M", "G", "A", "M", "H", "T", "Rh"], &["Ion", "Chw", "Maw", "Ebr", "Mai", "Meh", "Gor", "Awst", "Medi", "Hyd", "Tach", "Rhag"], &["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"], &["I", "Ch", "M", "E", "M", "M", "G", "A", "M", "H", "T", "Rh"]], eras: [&["CC", "OC", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Cyn Crist", "Oed Crist", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["C", "O", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_141: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_142: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_143: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_144: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_31: [&_CalendarData; 6] = [&_CALENDAR_139, &_CALENDAR_140, &_CALENDAR_141, &_CALENDAR_142, &_CALENDAR_143, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_145: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marts", "april", "maj", "juni", "juli", "august", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marts", "april", "maj", "juni", "juli", "august", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.y", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_146: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marts", "april", "maj", "juni", "juli", "august", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marts", "april", "maj", "juni", "juli", "august", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["f.Kr.", "e.Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["før Kristus", "efter Kristus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["fKr", "eKr", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_147: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_148: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadan", "shawwal", "dhuʻl-Qiʻdah", "dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadan", "shawwal", "dhuʻl-Qiʻdah", "dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.M.y", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_149: _CalendarData = _CalendarData { months: [&["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_150: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "1. tidsr."], &["ERA0", "1. tidsregning"], &["ERA0", "1. t."]], date_patterns: ["d.M.y", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_32: [&_CalendarData; 6] = [&_CALENDAR_145, &_CALENDAR_146, &_CALENDAR_147, &_CALENDAR_148, &_CALENDAR_149, &_CALENDAR_150];
+
+#[rustfmt::skip]
+static _CALENDAR_151: _CalendarData = _CalendarData { months: [&["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."], &["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], &["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["B.E."], &["BE"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_152: _CalendarData = _CalendarData { months: [&["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."], &["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], &["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["v. Chr.", "n. Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v. Chr.", "n. Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v. Chr.", "n. Chr.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_153: _CalendarData = _CalendarData { months: [&["Farwardin", "Ordibehescht", "Chordād", "Tir", "Mordād", "Schahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Essfand"], &["Farwardin", "Ordibehescht", "Chordād", "Tir", "Mordād", "Schahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Essfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farwardin", "Ordibehescht", "Chordād", "Tir", "Mordād", "Schahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Essfand"], &["Farwardin", "Ordibehescht", "Chordād", "Tir", "Mordād", "Schahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Essfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_154: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Dschumada I", "Dschumada II", "Radschab", "Shaʻban", "Ramadan", "Shawwal", "Dhu l-qaʿda", "Dhu l-Hiddscha"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Dschumada I", "Dschumada II", "Radschab", "Shaʻban", "Ramadan", "Shawwal", "Dhu l-qaʿda", "Dhu l-Hiddscha"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_155: _CalendarData = _CalendarData { months: [&["Tischri", "Cheschwan", "Kislew", "Tevet", "Schevat", "Adar I", "Adar", "Nisan", "Ijjar", "Siwan", "Tammus", "Aw", "Elul", "Adar II"], &["Tischri", "Cheschwan", "Kislew", "Tevet", "Schevat", "Adar I", "Adar", "Nisan", "Ijjar", "Siwan", "Tammus", "Aw", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tischri", "Cheschwan", "Kislew", "Tevet", "Schevat", "Adar I", "Adar", "Nisan", "Ijjar", "Siwan", "Tammus", "Aw", "Elul", "Adar II"], &["Tischri", "Cheschwan", "Kislew", "Tevet", "Schevat", "Adar I", "Adar", "Nisan", "Ijjar", "Siwan", "Tammus", "Aw", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_156: _CalendarData = _CalendarData { months: [&["Mäskäräm", "Ṭəqəmt", "Ḫədar", "Taḫśaś", "Ṭərr", "Yäkatit", "Mägabit", "Miyazya", "Gənbot", "Säne", "Ḥamle", "Nähase", "Ṗagumen", ""], &["Mäskäräm", "Ṭəqəmt", "Ḫədar", "Taḫśaś", "Ṭərr", "Yäkatit", "Mägabit", "Miyazya", "Gənbot", "Säne", "Ḥamle", "Nähase", "Ṗagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Mäskäräm", "Ṭəqəmt", "Ḫədar", "Taḫśaś", "Ṭərr", "Yäkatit", "Mägabit", "Miyazya", "Gənbot", "Säne", "Ḥamle", "Nähase", "Ṗagumen", ""], &["Mäskäräm", "Ṭəqəmt", "Ḫədar", "Taḫśaś", "Ṭərr", "Yäkatit", "Mägabit", "Miyazya", "Gənbot", "Säne", "Ḥamle", "Nähase", "Ṗagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_33: [&_CalendarData; 6] = [&_CALENDAR_151, &_CALENDAR_152, &_CALENDAR_153, &_CALENDAR_154, &_CALENDAR_155, &_CALENDAR_156];
+
+#[rustfmt::skip]
+static _CALENDAR_157: _CalendarData = _CalendarData { months: [&["Jän.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sep.", "Okt.", "Nov.", "Dez."], &["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jän", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], &["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["B.E."], &["BE"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_158: _CalendarData = _CalendarData { months: [&["Jän.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sep.", "Okt.", "Nov.", "Dez."], &["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jän", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], &["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["v. Chr.", "n. Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v. Chr.", "n. Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v. Chr.", "n. Chr.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_34: [&_CalendarData; 6] = [&_CALENDAR_157, &_CALENDAR_158, &_CALENDAR_153, &_CALENDAR_154, &_CALENDAR_155, &_CALENDAR_156];
+
+#[rustfmt::skip]
+static _CALENDAR_159: _CalendarData = _CalendarData { months: [&["जन.", "फर.", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अग.", "सित.", "अक\u{94d}त\u{942}.", "नव.", "दिस."], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म\u{947}", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन.", "फर.", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अग.", "सित.", "अक\u{94d}त\u{942}.", "नव.", "दिस."], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म\u{947}", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_160: _CalendarData = _CalendarData { months: [&["जन.", "फर.", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अग.", "सित.", "अक\u{94d}त\u{942}.", "नव.", "दिस."], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म\u{947}", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन.", "फर.", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अग.", "सित.", "अक\u{94d}त\u{942}.", "नव.", "दिस."], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म\u{947}", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["ई.प\u{942}.", "ईसवी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ई.प\u{942}.", "ई. सन\u{94d}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ई.प\u{942}.", "ईसवी", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_35: [&_CalendarData; 6] = [&_CALENDAR_159, &_CALENDAR_160, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_161: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "měr.", "apr.", "maj.", "jun.", "jul.", "awg.", "sep.", "okt.", "now.", "dec."], &["januara", "februara", "měrca", "apryla", "maja", "junija", "julija", "awgusta", "septembra", "oktobra", "nowembra", "decembra"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "měr", "apr", "maj", "jun", "jul", "awg", "sep", "okt", "now", "dec"], &["januar", "februar", "měrc", "apryl", "maj", "junij", "julij", "awgust", "september", "oktober", "nowember", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_162: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "měr.", "apr.", "maj.", "jun.", "jul.", "awg.", "sep.", "okt.", "now.", "dec."], &["januara", "februara", "měrca", "apryla", "maja", "junija", "julija", "awgusta", "septembra", "oktobra", "nowembra", "decembra"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "měr", "apr", "maj", "jun", "jul", "awg", "sep", "okt", "now", "dec"], &["januar", "februar", "měrc", "apryl", "maj", "junij", "julij", "awgust", "september", "oktober", "nowember", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["pś.Chr.n.", "pó Chr.n.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pśed Kristusowym naroźenim", "pó Kristusowem naroźenju", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pś.Chr.n.", "pó Chr.n.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_163: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_164: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_165: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_166: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_36: [&_CalendarData; 6] = [&_CALENDAR_161, &_CALENDAR_162, &_CALENDAR_163, &_CALENDAR_164, &_CALENDAR_165, &_CALENDAR_166];
+
+#[rustfmt::skip]
+static _CALENDAR_167: _CalendarData = _CalendarData { months: [&["dzv", "dzd", "ted", "afɔ", "dam", "mas", "sia", "dea", "any", "kel", "ade", "dzm"], &["dzove", "dzodze", "tedoxe", "afɔfĩe", "dame", "masa", "siamlɔm", "deasiamime", "anyɔnyɔ", "kele", "adeɛmekpɔxe", "dzome"], &["d", "d", "t", "a", "d", "m", "s", "d", "a", "k", "a", "d"], &["dzv", "dzd", "ted", "afɔ", "dam", "mas", "sia", "dea", "any", "kel", "ade", "dzm"], &["dzove", "dzodze", "tedoxe", "afɔfĩe", "dame", "masa", "siamlɔm", "deasiamime", "anyɔnyɔ", "kele", "adeɛmekpɔxe", "dzome"], &["d", "d", "t", "a", "d", "m", "s", "d", "a", "k", "a", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d 'lia,' y G", "MMMM d 'lia' y G", "EEEE, MMMM d 'lia' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_168: _CalendarData = _CalendarData { months: [&["dzv", "dzd", "ted", "afɔ", "dam", "mas", "sia", "dea", "any", "kel", "ade", "dzm"], &["dzove", "dzodze", "tedoxe", "afɔfĩe", "dame", "masa", "siamlɔm", "deasiamime", "anyɔnyɔ", "kele", "adeɛmekpɔxe", "dzome"], &["d", "d", "t", "a", "d", "m", "s", "d", "a", "k", "a", "d"], &["dzv", "dzd", "ted", "afɔ", "dam", "mas", "sia", "dea", "any", "kel", "ade", "dzm"], &["dzove", "dzodze", "tedoxe", "afɔfĩe", "dame", "masa", "siamlɔm", "deasiamime", "anyɔnyɔ", "kele", "adeɛmekpɔxe", "dzome"], &["d", "d", "t", "a", "d", "m", "s", "d", "a", "k", "a", "d"]], eras: [&["HYV", "Yŋ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Hafi Yesu Va", "Yesu ŋɔli", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["HYV", "Yŋ", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d 'lia,' y G", "MMMM d 'lia' y G", "EEEE, MMMM d 'lia' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_169: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d 'lia,' y G", "MMMM d 'lia' y G", "EEEE, MMMM d 'lia' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_170: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["M/d/y GGGGG", "MMM d 'lia,' y G", "MMMM d 'lia' y G", "EEEE, MMMM d 'lia' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_171: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["M/d/y GGGGG", "MMM d 'lia,' y G", "MMMM d 'lia' y G", "EEEE, MMMM d 'lia' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_172: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d 'lia,' y G", "MMMM d 'lia' y G", "EEEE, MMMM d 'lia' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_37: [&_CalendarData; 6] = [&_CALENDAR_167, &_CALENDAR_168, &_CALENDAR_169, &_CALENDAR_170, &_CALENDAR_171, &_CALENDAR_172];
+
+#[rustfmt::skip]
+static _CALENDAR_173: _CalendarData = _CalendarData { months: [&["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αυγ", "Σεπ", "Οκτ", "Νοε", "Δεκ"], &["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αυγούστου", "Σεπτεμβρίου", "Οκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"], &["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"], &["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αύγουστος", "Σεπτέμβριος", "Οκτώβριος", "Νοέμβριος", "Δεκέμβριος"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"]], eras: [&["BE"], &["Β.Ε."], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_174: _CalendarData = _CalendarData { months: [&["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αυγ", "Σεπ", "Οκτ", "Νοε", "Δεκ"], &["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αυγούστου", "Σεπτεμβρίου", "Οκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"], &["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"], &["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αύγουστος", "Σεπτέμβριος", "Οκτώβριος", "Νοέμβριος", "Δεκέμβριος"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"]], eras: [&["π.Χ.", "μ.Χ.", "Meiji", "Taishō", "Shōwa", "Χεϊσέι", "Ρέιβα"], &["προ Χριστού", "μετά Χριστόν", "Meiji", "Taishō", "Shōwa", "Χεϊσέι", "Ρέιβα"], &["π.Χ.", "μ.Χ.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_175: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_176: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["Ε.Ε."], &["Ε.Ε."], &["Ε.Ε."]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_177: _CalendarData = _CalendarData { months: [&["Τισρί", "Χεσβάν", "Κισλέφ", "Τέβετ", "Σεβάτ", "Αντάρ I", "Αντάρ", "Νισάν", "Ιγιάρ", "Σιβάν", "Ταμούζ", "Αβ", "Έλουλ", "Αντάρ II"], &["Τισρί", "Χεσβάν", "Κισλέφ", "Τέβετ", "Σεβάτ", "Αντάρ I", "Αντάρ", "Νισάν", "Ιγιάρ", "Σιβάν", "Ταμούζ", "Αβ", "Έλουλ", "Αντάρ II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Τισρί", "Χεσβάν", "Κισλέφ", "Τέβετ", "Σεβάτ", "Αντάρ I", "Αντάρ", "Νισάν", "Ιγιάρ", "Σιβάν", "Ταμούζ", "Αβ", "Έλουλ", "Αντάρ II"], &["Τισρί", "Χεσβάν", "Κισλέφ", "Τέβετ", "Σεβάτ", "Αντάρ I", "Αντάρ", "Νισάν", "Ιγιάρ", "Σιβάν", "Ταμούζ", "Αβ", "Έλουλ", "Αντάρ II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_178: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_38: [&_CalendarData; 6] = [&_CALENDAR_173, &_CALENDAR_174, &_CALENDAR_175, &_CALENDAR_176, &_CALENDAR_177, &_CALENDAR_178];
+
+#[rustfmt::skip]
+static _CALENDAR_179: _CalendarData = _CalendarData { months: [&["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αὐγ", "Σεπ", "Ὀκτ", "Νοε", "Δεκ"], &["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αὐγούστου", "Σεπτεμβρίου", "Ὀκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"], &["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"], &["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αὔγουστος", "Σεπτέμβριος", "Ὀκτώβριος", "Νοέμβριος", "Δεκέμβριος"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"]], eras: [&["BE"], &["Β.Ε."], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_180: _CalendarData = _CalendarData { months: [&["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αὐγ", "Σεπ", "Ὀκτ", "Νοε", "Δεκ"], &["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αὐγούστου", "Σεπτεμβρίου", "Ὀκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"], &["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"], &["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αὔγουστος", "Σεπτέμβριος", "Ὀκτώβριος", "Νοέμβριος", "Δεκέμβριος"], &["Ι", "Φ", "Μ", "Α", "Μ", "Ι", "Ι", "Α", "Σ", "Ο", "Ν", "Δ"]], eras: [&["π.Χ.", "μ.Χ.", "Meiji", "Taishō", "Shōwa", "Χεϊσέι", "Ρέιβα"], &["προ Χριστού", "μετά Χριστόν", "Meiji", "Taishō", "Shōwa", "Χεϊσέι", "Ρέιβα"], &["π.Χ.", "μ.Χ.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_39: [&_CalendarData; 6] = [&_CALENDAR_179, &_CALENDAR_180, &_CALENDAR_175, &_CALENDAR_176, &_CALENDAR_177, &_CALENDAR_178];
+
+#[rustfmt::skip]
+static _CALENDAR_181: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_182: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B", "A", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_183: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d MMM y", "d MMM y", "d MMMM y", "EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_40: [&_CalendarData; 6] = [&_CALENDAR_181, &_CALENDAR_182, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_183, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_184: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_185: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B", "A", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_41: [&_CalendarData; 6] = [&_CALENDAR_184, &_CALENDAR_185, &_CALENDAR_141, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_186: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_42: [&_CalendarData; 6] = [&_CALENDAR_186, &_CALENDAR_182, &_CALENDAR_141, &_CALENDAR_120, &_CALENDAR_183, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_187: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "June", "July", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "June", "July", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_188: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "June", "July", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "June", "July", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B", "A", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_189: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_190: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_43: [&_CalendarData; 6] = [&_CALENDAR_187, &_CALENDAR_188, &_CALENDAR_189, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_190];
+
+#[rustfmt::skip]
+static _CALENDAR_191: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_192: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_193: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_44: [&_CalendarData; 6] = [&_CALENDAR_191, &_CALENDAR_185, &_CALENDAR_192, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_193];
+
+#[rustfmt::skip]
+static _CALENDAR_194: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_195: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_196: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_45: [&_CalendarData; 6] = [&_CALENDAR_194, &_CALENDAR_185, &_CALENDAR_195, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_196];
+
+#[rustfmt::skip]
+static _CALENDAR_197: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "dd-MMM-y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_198: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "dd-MMM-y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_199: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "dd-MMM-y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_46: [&_CalendarData; 6] = [&_CALENDAR_197, &_CALENDAR_185, &_CALENDAR_198, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_199];
+
+#[rustfmt::skip]
+static _CALENDAR_200: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_201: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B", "A", "M", "T", "S", "H", "R"]], date_patterns: ["M.d.y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_202: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_203: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_204: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_47: [&_CalendarData; 6] = [&_CALENDAR_200, &_CALENDAR_201, &_CALENDAR_202, &_CALENDAR_203, &_CALENDAR_183, &_CALENDAR_204];
+
+#[rustfmt::skip]
+static _CALENDAR_205: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_206: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_207: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_48: [&_CalendarData; 6] = [&_CALENDAR_205, &_CALENDAR_185, &_CALENDAR_206, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_207];
+
+#[rustfmt::skip]
+static _CALENDAR_208: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_49: [&_CalendarData; 6] = [&_CALENDAR_208, &_CALENDAR_185, &_CALENDAR_189, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_190];
+
+#[rustfmt::skip]
+static _CALENDAR_209: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_210: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_211: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y/ GGGGG", "dd-MMM-y G", "d MMMM, y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_212: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_50: [&_CalendarData; 6] = [&_CALENDAR_209, &_CALENDAR_185, &_CALENDAR_210, &_CALENDAR_211, &_CALENDAR_183, &_CALENDAR_212];
+
+#[rustfmt::skip]
+static _CALENDAR_213: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_214: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_215: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_51: [&_CalendarData; 6] = [&_CALENDAR_213, &_CALENDAR_185, &_CALENDAR_214, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_215];
+
+#[rustfmt::skip]
+static _CALENDAR_216: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/MM/y GGGGG", "d/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_217: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/MM/y GGGGG", "d/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_218: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/MM/y GGGGG", "d/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_52: [&_CalendarData; 6] = [&_CALENDAR_216, &_CALENDAR_185, &_CALENDAR_217, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_218];
+
+#[rustfmt::skip]
+static _CALENDAR_219: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "dd-MMM-y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_220: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "dd-MMM-y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_221: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "dd-MMM-y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_53: [&_CalendarData; 6] = [&_CALENDAR_219, &_CALENDAR_185, &_CALENDAR_220, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_221];
+
+#[rustfmt::skip]
+static _CALENDAR_222: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_223: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_224: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_54: [&_CalendarData; 6] = [&_CALENDAR_222, &_CALENDAR_185, &_CALENDAR_223, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_224];
+
+#[rustfmt::skip]
+static _CALENDAR_225: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_226: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_227: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_55: [&_CalendarData; 6] = [&_CALENDAR_225, &_CALENDAR_185, &_CALENDAR_226, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_227];
+
+#[rustfmt::skip]
+static _CALENDAR_228: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y/MM/dd", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_229: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y/MM/dd", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_230: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y/MM/dd", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_56: [&_CalendarData; 6] = [&_CALENDAR_228, &_CALENDAR_185, &_CALENDAR_229, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_230];
+
+#[rustfmt::skip]
+static _CALENDAR_231: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "dd MMM,y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_232: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "dd MMM,y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_233: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "dd MMM,y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_57: [&_CalendarData; 6] = [&_CALENDAR_231, &_CALENDAR_185, &_CALENDAR_232, &_CALENDAR_142, &_CALENDAR_183, &_CALENDAR_233];
+
+#[rustfmt::skip]
+static _CALENDAR_234: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aŭg", "Sep", "Okt", "Nov", "Dec"], &["Januaro", "Februaro", "Marto", "Aprilo", "Majo", "Junio", "Julio", "Aŭgusto", "Septembro", "Oktobro", "Novembro", "Decembro"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aŭg", "Sep", "Okt", "Nov", "Dec"], &["Januaro", "Februaro", "Marto", "Aprilo", "Majo", "Junio", "Julio", "Aŭgusto", "Septembro", "Oktobro", "Novembro", "Decembro"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y-MMM-dd", "G y-MMMM-dd", "EEEE', la' d'-a de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_235: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aŭg", "Sep", "Okt", "Nov", "Dec"], &["Januaro", "Februaro", "Marto", "Aprilo", "Majo", "Junio", "Julio", "Aŭgusto", "Septembro", "Oktobro", "Novembro", "Decembro"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aŭg", "Sep", "Okt", "Nov", "Dec"], &["Januaro", "Februaro", "Marto", "Aprilo", "Majo", "Junio", "Julio", "Aŭgusto", "Septembro", "Oktobro", "Novembro", "Decembro"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["a.n.e.", "n.e.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antaŭ nia erao", "de nia erao", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.n.e.", "n.e.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y-MMM-dd", "G y-MMMM-dd", "EEEE', la' d'-a de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_236: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "G y-MMM-dd", "G y-MMMM-dd", "EEEE', la' d'-a de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_237: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G y-MMM-dd", "G y-MMMM-dd", "EEEE', la' d'-a de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_238: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "G y-MMM-dd", "G y-MMMM-dd", "EEEE', la' d'-a de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_239: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y-MM-dd", "G y-MMM-dd", "G y-MMMM-dd", "EEEE', la' d'-a de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_58: [&_CalendarData; 6] = [&_CALENDAR_234, &_CALENDAR_235, &_CALENDAR_236, &_CALENDAR_237, &_CALENDAR_238, &_CALENDAR_239];
+
+#[rustfmt::skip]
+static _CALENDAR_240: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_241: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a. C.", "d. C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "después de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a. C.", "d. C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y GGGGG", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_242: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_243: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-q.", "dhuʻl-h."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadán", "shawwal", "dhuʻl-qiʻdah", "dhuʻl-hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-q.", "dhuʻl-h."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadán", "shawwal", "dhuʻl-qiʻdah", "dhuʻl-hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_244: _CalendarData = _CalendarData { months: [&["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_245: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_59: [&_CalendarData; 6] = [&_CALENDAR_240, &_CALENDAR_241, &_CALENDAR_242, &_CALENDAR_243, &_CALENDAR_244, &_CALENDAR_245];
+
+#[rustfmt::skip]
+static _CALENDAR_246: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "después de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y GGGGG", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_247: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_248: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_60: [&_CalendarData; 6] = [&_CALENDAR_240, &_CALENDAR_246, &_CALENDAR_242, &_CALENDAR_247, &_CALENDAR_248, &_CALENDAR_245];
+
+#[rustfmt::skip]
+static _CALENDAR_249: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_250: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "después de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y GGGGG", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_251: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_252: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_253: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_254: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_61: [&_CalendarData; 6] = [&_CALENDAR_249, &_CALENDAR_250, &_CALENDAR_251, &_CALENDAR_252, &_CALENDAR_253, &_CALENDAR_254];
+
+#[rustfmt::skip]
+static _CALENDAR_255: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/MM/yy GGGGG", "d/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_256: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/MM/yy GGGGG", "d/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_257: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/MM/yy GGGGG", "d/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_258: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/MM/yy GGGGG", "d/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_259: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/MM/yy GGGGG", "d/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_62: [&_CalendarData; 6] = [&_CALENDAR_255, &_CALENDAR_250, &_CALENDAR_256, &_CALENDAR_257, &_CALENDAR_258, &_CALENDAR_259];
+
+#[rustfmt::skip]
+static _CALENDAR_260: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_261: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_262: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_263: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_264: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_63: [&_CalendarData; 6] = [&_CALENDAR_260, &_CALENDAR_246, &_CALENDAR_261, &_CALENDAR_262, &_CALENDAR_263, &_CALENDAR_264];
+
+#[rustfmt::skip]
+static _CALENDAR_265: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/MM/yy GGGGG", "d/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_64: [&_CalendarData; 6] = [&_CALENDAR_265, &_CALENDAR_246, &_CALENDAR_256, &_CALENDAR_257, &_CALENDAR_258, &_CALENDAR_259];
+
+#[rustfmt::skip]
+static _CALENDAR_266: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "dd 'de' MMMM 'de' y G", "EEEE dd 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_267: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "dd 'de' MMMM 'de' y G", "EEEE dd 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_268: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "dd 'de' MMMM 'de' y G", "EEEE dd 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_269: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "dd 'de' MMMM 'de' y G", "EEEE dd 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_270: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "dd 'de' MMMM 'de' y G", "EEEE dd 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_65: [&_CalendarData; 6] = [&_CALENDAR_266, &_CALENDAR_246, &_CALENDAR_267, &_CALENDAR_268, &_CALENDAR_269, &_CALENDAR_270];
+
+#[rustfmt::skip]
+static _CALENDAR_271: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_272: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "después de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y GGGGG", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_66: [&_CalendarData; 6] = [&_CALENDAR_271, &_CALENDAR_272, &_CALENDAR_242, &_CALENDAR_247, &_CALENDAR_248, &_CALENDAR_245];
+
+#[rustfmt::skip]
+static _CALENDAR_273: _CalendarData = _CalendarData { months: [&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["MM/dd/yy GGGGG", "MM/dd/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_274: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["MM/dd/yy GGGGG", "MM/dd/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_275: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["MM/dd/yy GGGGG", "MM/dd/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_276: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["MM/dd/yy GGGGG", "MM/dd/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_277: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["MM/dd/yy GGGGG", "MM/dd/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_67: [&_CalendarData; 6] = [&_CALENDAR_273, &_CALENDAR_246, &_CALENDAR_274, &_CALENDAR_275, &_CALENDAR_276, &_CALENDAR_277];
+
+#[rustfmt::skip]
+static _CALENDAR_278: _CalendarData = _CalendarData { months: [&["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "set.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "setiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Ene.", "Feb.", "Mar.", "Abr.", "May.", "Jun.", "Jul.", "Ago.", "Set.", "Oct.", "Nov.", "Dic."], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/MM/yy GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_279: _CalendarData = _CalendarData { months: [&["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "set.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "setiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Ene.", "Feb.", "Mar.", "Abr.", "May.", "Jun.", "Jul.", "Ago.", "Set.", "Oct.", "Nov.", "Dic."], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "después de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y GGGGG", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_280: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/MM/yy GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_281: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/MM/yy GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_282: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/MM/yy GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_283: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/MM/yy GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_68: [&_CalendarData; 6] = [&_CALENDAR_278, &_CALENDAR_279, &_CALENDAR_280, &_CALENDAR_281, &_CALENDAR_282, &_CALENDAR_283];
+
+#[rustfmt::skip]
+static _CALENDAR_284: _CalendarData = _CalendarData { months: [&["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_285: _CalendarData = _CalendarData { months: [&["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "después de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y GGGGG", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_69: [&_CalendarData; 6] = [&_CALENDAR_284, &_CALENDAR_285, &_CALENDAR_242, &_CALENDAR_247, &_CALENDAR_248, &_CALENDAR_245];
+
+#[rustfmt::skip]
+static _CALENDAR_286: _CalendarData = _CalendarData { months: [&["ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "set.", "oct.", "nov.", "dic."], &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "setiembre", "octubre", "noviembre", "diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Ene.", "Feb.", "Mar.", "Abr.", "May.", "Jun.", "Jul.", "Ago.", "Set.", "Oct.", "Nov.", "Dic."], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_70: [&_CalendarData; 6] = [&_CALENDAR_286, &_CALENDAR_279, &_CALENDAR_242, &_CALENDAR_247, &_CALENDAR_248, &_CALENDAR_245];
+
+#[rustfmt::skip]
+static _CALENDAR_287: _CalendarData = _CalendarData { months: [&["jaan", "veebr", "märts", "apr", "mai", "juuni", "juuli", "aug", "sept", "okt", "nov", "dets"], &["jaanuar", "veebruar", "märts", "aprill", "mai", "juuni", "juuli", "august", "september", "oktoober", "november", "detsember"], &["J", "V", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jaan", "veebr", "märts", "apr", "mai", "juuni", "juuli", "aug", "sept", "okt", "nov", "dets"], &["jaanuar", "veebruar", "märts", "aprill", "mai", "juuni", "juuli", "august", "september", "oktoober", "november", "detsember"], &["J", "V", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BK"], &["BK"], &["BK"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_288: _CalendarData = _CalendarData { months: [&["jaan", "veebr", "märts", "apr", "mai", "juuni", "juuli", "aug", "sept", "okt", "nov", "dets"], &["jaanuar", "veebruar", "märts", "aprill", "mai", "juuni", "juuli", "august", "september", "oktoober", "november", "detsember"], &["J", "V", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jaan", "veebr", "märts", "apr", "mai", "juuni", "juuli", "aug", "sept", "okt", "nov", "dets"], &["jaanuar", "veebruar", "märts", "aprill", "mai", "juuni", "juuli", "august", "september", "oktoober", "november", "detsember"], &["J", "V", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["eKr", "pKr", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["enne Kristust", "pärast Kristust", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["eKr", "pKr", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_289: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_290: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_291: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_292: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_71: [&_CalendarData; 6] = [&_CALENDAR_287, &_CALENDAR_288, &_CALENDAR_289, &_CALENDAR_290, &_CALENDAR_291, &_CALENDAR_292];
+
+#[rustfmt::skip]
+static _CALENDAR_293: _CalendarData = _CalendarData { months: [&["urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.", "abe."], &["urtarrila", "otsaila", "martxoa", "apirila", "maiatza", "ekaina", "uztaila", "abuztua", "iraila", "urria", "azaroa", "abendua"], &["U", "O", "M", "A", "M", "E", "U", "A", "I", "U", "A", "A"], &["urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.", "abe."], &["urtarrila", "otsaila", "martxoa", "apirila", "maiatza", "ekaina", "uztaila", "abuztua", "iraila", "urria", "azaroa", "abendua"], &["U", "O", "M", "A", "M", "E", "U", "A", "I", "U", "A", "A"]], eras: [&["BG"], &["BG"], &["BG"]], date_patterns: ["GGGGG y-MM-dd", "G'. aroko' y'(e)ko' MMM d", "G'. aroko' y'. urteko' MMMM d", "G'. aroko' y'. urteko' MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_294: _CalendarData = _CalendarData { months: [&["urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.", "abe."], &["urtarrila", "otsaila", "martxoa", "apirila", "maiatza", "ekaina", "uztaila", "abuztua", "iraila", "urria", "azaroa", "abendua"], &["U", "O", "M", "A", "M", "E", "U", "A", "I", "U", "A", "A"], &["urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.", "abe."], &["urtarrila", "otsaila", "martxoa", "apirila", "maiatza", "ekaina", "uztaila", "abuztua", "iraila", "urria", "azaroa", "abendua"], &["U", "O", "M", "A", "M", "E", "U", "A", "I", "U", "A", "A"]], eras: [&["K.a.", "K.o.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Kristo aurretik", "Kristo ondoren", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a", "o", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G'. aroko' y'(e)ko' MMM d", "G'. aroko' y'. urteko' MMMM d", "G'. aroko' y'. urteko' MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_295: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "G'. aroko' y'(e)ko' MMM d", "G'. aroko' y'. urteko' MMMM d", "G'. aroko' y'. urteko' MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_296: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G'. aroko' y'(e)ko' MMM d", "G'. aroko' y'. urteko' MMMM d", "G'. aroko' y'. urteko' MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_297: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "G'. aroko' y'(e)ko' MMM d", "G'. aroko' y'. urteko' MMMM d", "G'. aroko' y'. urteko' MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_298: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y-MM-dd", "G'. aroko' y'(e)ko' MMM d", "G'. aroko' y'. urteko' MMMM d", "G'. aroko' y'. urteko' MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_72: [&_CalendarData; 6] = [&_CALENDAR_293, &_CALENDAR_294, &_CALENDAR_295, &_CALENDAR_296, &_CALENDAR_297, &_CALENDAR_298];
+
+#[rustfmt::skip]
+static _CALENDAR_299: _CalendarData = _CalendarData { months: [&["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژانویه\u{654}", "فوریه\u{654}", "مارس", "آوریل", "مه\u{654}", "ژوئن", "ژوئیه\u{654}", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"], &["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"]], eras: [&["تقویم بودایی"], &["تقویم بودایی"], &["تقویم بودایی"]], date_patterns: ["y/M/d GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_300: _CalendarData = _CalendarData { months: [&["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژانویه\u{654}", "فوریه\u{654}", "مارس", "آوریل", "مه\u{654}", "ژوئن", "ژوئیه\u{654}", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"], &["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اکتبر", "نوامبر", "دسامبر"], &["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"]], eras: [&["ق.م.", "م.", "Meiji", "Taishō", "Shōwa", "هیسی", "ریوا"], &["قبل از میلاد", "میلادی", "Meiji", "Taishō", "Shōwa", "هیسی", "ریوا"], &["ق", "م", "M", "T", "S", "ه\u{200d}", "ر"]], date_patterns: ["y/M/d GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_301: _CalendarData = _CalendarData { months: [&["فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور", "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند"], &["فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور", "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند"], &["ف", "ا", "خ", "ت", "م", "ش", "م", "آ", "آ", "د", "ب", "ا"], &["فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور", "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند"], &["فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور", "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند"], &["ف", "ا", "خ", "ت", "م", "ش", "م", "آ", "آ", "د", "ب", "ا"]], eras: [&["ه\u{200d}.ش."], &["هجری شمسی"], &["ه\u{200d}.ش."]], date_patterns: ["y/M/d", "d MMM y", "d MMMM y", "y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_302: _CalendarData = _CalendarData { months: [&["محرم", "صفر", "ربیع\u{200c}الاول", "ربیع\u{200c}الثانی", "جمادی\u{200c}الاول", "جمادی\u{200c}الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذیقعده", "ذیحجه"], &["محرم", "صفر", "ربیع\u{200c}الاول", "ربیع\u{200c}الثانی", "جمادی\u{200c}الاول", "جمادی\u{200c}الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذیقعده", "ذیحجه"], &["م", "ص", "ر", "ر", "ج", "ج", "ر", "ش", "ر", "ش", "ذ", "ذ"], &["محرم", "صفر", "ربیع\u{200c}الاول", "ربیع\u{200c}الثانی", "جمادی\u{200c}الاول", "جمادی\u{200c}الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذیقعده", "ذیحجه"], &["محرم", "صفر", "ربیع\u{200c}الاول", "ربیع\u{200c}الثانی", "جمادی\u{200c}الاول", "جمادی\u{200c}الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذیقعده", "ذیحجه"], &["م", "ص", "ر", "ر", "ج", "ج", "ر", "ش", "ر", "ش", "ذ", "ذ"]], eras: [&["ه\u{200d}.ق."], &["هجری قمری"], &["ه\u{200d}.ق."]], date_patterns: ["y/M/d G", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_303: _CalendarData = _CalendarData { months: [&["تشری", "حشوان", "کسلو", "طوت", "شباط", "آذار", "واذار", "نیسان", "ایار", "سیوان", "تموز", "آب", "ایلول", "واذار الثانی"], &["تشری", "حشوان", "کسلو", "طوت", "شباط", "آذار", "واذار", "نیسان", "ایار", "سیوان", "تموز", "آب", "ایلول", "واذار الثانی"], &["ت", "ح", "ک", "ط", "ش", "آ", "و", "ن", "ا", "س", "ت", "آ", "ا", "و"], &["تشری", "حشوان", "کسلو", "طوت", "شباط", "آذار", "واذار", "نیسان", "ایار", "سیوان", "تموز", "آب", "ایلول", "واذار الثانی"], &["تشری", "حشوان", "کسلو", "طوت", "شباط", "آذار", "واذار", "نیسان", "ایار", "سیوان", "تموز", "آب", "ایلول", "واذار الثانی"], &["ت", "ح", "ک", "ط", "ش", "آ", "و", "ن", "ا", "س", "ت", "آ", "ا", "و"]], eras: [&["تقویم عبری"], &["تقویم عبری"], &["تقویم عبری"]], date_patterns: ["y/M/d GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_304: _CalendarData = _CalendarData { months: [&["مسکرم", "تکیمت", "هیدار", "طه\u{200c}ساز", "تر", "یکوتیت", "مگابیت", "میازیا", "گین\u{200c}بوت", "سنه", "حمله", "نحسه", "پاگومه", ""], &["مسکرم", "تکیمت", "هیدار", "طه\u{200c}ساز", "تر", "یکوتیت", "مگابیت", "میازیا", "گین\u{200c}بوت", "سنه", "حمله", "نحسه", "پاگومه", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["مسکرم", "تکیمت", "هیدار", "طه\u{200c}ساز", "تر", "یکوتیت", "مگابیت", "میازیا", "گین\u{200c}بوت", "سنه", "حمله", "نحسه", "پاگومه", ""], &["مسکرم", "تکیمت", "هیدار", "طه\u{200c}ساز", "تر", "یکوتیت", "مگابیت", "میازیا", "گین\u{200c}بوت", "سنه", "حمله", "نحسه", "پاگومه", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["y/M/d GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_73: [&_CalendarData; 6] = [&_CALENDAR_299, &_CALENDAR_300, &_CALENDAR_301, &_CALENDAR_302, &_CALENDAR_303, &_CALENDAR_304];
+
+#[rustfmt::skip]
+static _CALENDAR_305: _CalendarData = _CalendarData { months: [&["جنو", "فبروری", "مارچ", "اپریل", "می", "جون", "جول", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسم"], &["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"], &["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"]], eras: [&["تقویم بودایی"], &["تقویم بودایی"], &["تقویم بودایی"]], date_patterns: ["y/M/d GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_306: _CalendarData = _CalendarData { months: [&["جنو", "فبروری", "مارچ", "اپریل", "می", "جون", "جول", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسم"], &["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"], &["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوری", "فبروری", "مارچ", "اپریل", "می", "جون", "جولای", "اگست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"]], eras: [&["ق.م.", "م.", "Meiji", "Taishō", "Shōwa", "هیسی", "ریوا"], &["قبل از میلاد", "میلادی", "Meiji", "Taishō", "Shōwa", "هیسی", "ریوا"], &["ق", "م", "M", "T", "S", "ه\u{200d}", "ر"]], date_patterns: ["y/M/d GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_307: _CalendarData = _CalendarData { months: [&["حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله\u{654}", "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت"], &["حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله\u{654}", "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت"], &["ح", "ث", "ج", "س", "ا", "س", "م", "ع", "ق", "ج", "د", "ح"], &["حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله\u{654}", "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت"], &["حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله\u{654}", "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت"], &["ح", "ث", "ج", "س", "ا", "س", "م", "ع", "ق", "ج", "د", "ح"]], eras: [&["ه\u{200d}.ش."], &["هجری شمسی"], &["ه\u{200d}.ش."]], date_patterns: ["y/M/d", "d MMM y", "d MMMM y", "y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_74: [&_CalendarData; 6] = [&_CALENDAR_305, &_CALENDAR_306, &_CALENDAR_307, &_CALENDAR_302, &_CALENDAR_303, &_CALENDAR_304];
+
+#[rustfmt::skip]
+static _CALENDAR_308: _CalendarData = _CalendarData { months: [&["𞤅𞤭\u{1e945}𞤤𞤮", "𞤕𞤮𞤤𞤼𞤮", "𞤐𞤦𞤮\u{1e945}𞤴𞤮", "𞤅𞤫\u{1e945}𞤼𞤮", "𞤁𞤵\u{1e945}𞤶𞤮", "𞤑𞤮𞤪𞤧𞤮", "𞤃𞤮𞤪𞤧𞤮", "𞤔𞤵𞤳𞤮", "𞤅𞤭𞤤𞤼𞤮", "𞤒𞤢𞤪𞤳𞤮", "𞤔𞤮𞤤𞤮", "𞤄𞤮𞤱𞤼𞤮"], &["𞤅𞤭\u{1e945}𞤤𞤮", "𞤕𞤮𞤤𞤼𞤮", "𞤐𞤦𞤮\u{1e945}𞤴𞤮", "𞤅𞤫\u{1e945}𞤼𞤮", "𞤁𞤵\u{1e945}𞤶𞤮", "𞤑𞤮𞤪𞤧𞤮", "𞤃𞤮𞤪𞤧𞤮", "𞤔𞤵𞤳𞤮", "𞤅𞤭𞤤𞤼𞤮", "𞤒𞤢𞤪𞤳𞤮", "𞤔𞤮𞤤𞤮", "𞤄𞤮𞤱𞤼𞤮"], &["𞤅", "𞤕", "𞤄", "𞤅", "𞤁", "𞤑", "𞤃", "𞤔", "𞤅", "𞤒", "𞤔", "𞤄"], &["𞤅𞤭\u{1e945}𞤤", "𞤕𞤮𞤤", "𞤐𞤦𞤮\u{1e945}𞤴", "𞤅𞤫\u{1e945}𞤼", "𞤁𞤵\u{1e945}𞤶", "𞤑𞤮𞤪", "𞤃𞤮𞤪", "𞤔𞤵𞤳", "𞤅𞤭𞤤", "𞤒𞤢𞤪", "𞤔𞤮𞤤", "𞤄𞤮𞤱"], &["𞤅𞤭\u{1e945}𞤤𞤮", "𞤕𞤮𞤤𞤼𞤮", "𞤐𞤦𞤮\u{1e945}𞤴𞤮", "𞤅𞤫\u{1e945}𞤼𞤮", "𞤁𞤵\u{1e945}𞤶𞤮", "𞤑𞤮𞤪𞤧𞤮", "𞤃𞤮𞤪𞤧𞤮", "𞤔𞤵𞤳𞤮", "𞤅𞤭𞤤𞤼𞤮", "𞤒𞤢𞤪𞤳𞤮", "𞤔𞤮𞤤𞤮", "𞤄𞤮𞤱𞤼𞤮"], &["𞤅", "𞤕", "𞤄", "𞤅", "𞤁", "𞤑", "𞤃", "𞤔", "𞤅", "𞤒", "𞤔", "𞤄"]], eras: [&["𞤘𞤄"], &["𞤘𞤭𞤪𞤢𞤤 𞤄𞤵\u{1e945}𞤣𞤢𞤲𞤳𞤮𞤱𞤢𞤤"], &["𞤘𞤄"]], date_patterns: ["d-M-y GGGGG", "d MMM⹁ y G", "d MMMM⹁ y G", "EEEE d MMMM⹁ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_309: _CalendarData = _CalendarData { months: [&["𞤅𞤭\u{1e945}𞤤𞤮", "𞤕𞤮𞤤𞤼𞤮", "𞤐𞤦𞤮\u{1e945}𞤴𞤮", "𞤅𞤫\u{1e945}𞤼𞤮", "𞤁𞤵\u{1e945}𞤶𞤮", "𞤑𞤮𞤪𞤧𞤮", "𞤃𞤮𞤪𞤧𞤮", "𞤔𞤵𞤳𞤮", "𞤅𞤭𞤤𞤼𞤮", "𞤒𞤢𞤪𞤳𞤮", "𞤔𞤮𞤤𞤮", "𞤄𞤮𞤱𞤼𞤮"], &["𞤅𞤭\u{1e945}𞤤𞤮", "𞤕𞤮𞤤𞤼𞤮", "𞤐𞤦𞤮\u{1e945}𞤴𞤮", "𞤅𞤫\u{1e945}𞤼𞤮", "𞤁𞤵\u{1e945}𞤶𞤮", "𞤑𞤮𞤪𞤧𞤮", "𞤃𞤮𞤪𞤧𞤮", "𞤔𞤵𞤳𞤮", "𞤅𞤭𞤤𞤼𞤮", "𞤒𞤢𞤪𞤳𞤮", "𞤔𞤮𞤤𞤮", "𞤄𞤮𞤱𞤼𞤮"], &["𞤅", "𞤕", "𞤄", "𞤅", "𞤁", "𞤑", "𞤃", "𞤔", "𞤅", "𞤒", "𞤔", "𞤄"], &["𞤅𞤭\u{1e945}𞤤", "𞤕𞤮𞤤", "𞤐𞤦𞤮\u{1e945}𞤴", "𞤅𞤫\u{1e945}𞤼", "𞤁𞤵\u{1e945}𞤶", "𞤑𞤮𞤪", "𞤃𞤮𞤪", "𞤔𞤵𞤳", "𞤅𞤭𞤤", "𞤒𞤢𞤪", "𞤔𞤮𞤤", "𞤄𞤮𞤱"], &["𞤅𞤭\u{1e945}𞤤𞤮", "𞤕𞤮𞤤𞤼𞤮", "𞤐𞤦𞤮\u{1e945}𞤴𞤮", "𞤅𞤫\u{1e945}𞤼𞤮", "𞤁𞤵\u{1e945}𞤶𞤮", "𞤑𞤮𞤪𞤧𞤮", "𞤃𞤮𞤪𞤧𞤮", "𞤔𞤵𞤳𞤮", "𞤅𞤭𞤤𞤼𞤮", "𞤒𞤢𞤪𞤳𞤮", "𞤔𞤮𞤤𞤮", "𞤄𞤮𞤱𞤼𞤮"], &["𞤅", "𞤕", "𞤄", "𞤅", "𞤁", "𞤑", "𞤃", "𞤔", "𞤅", "𞤒", "𞤔", "𞤄"]], eras: [&["𞤀𞤀𞤋", "𞤇𞤀𞤋", "𞤃𞤫𞤴𞤶𞤭", "𞤚𞤢𞤴𞥃𞤮\u{1e945}", "𞤡𞤮\u{1e945}𞤱𞤢", "𞤖𞤫𞤴𞤧𞤫𞤴", "𞤈𞤫𞤴𞤱𞤢"], &["𞤀𞤣𞤮 𞤀𞤲\u{1e946}𞤢𞤦𞤭 𞤋\u{1e945}𞤧𞤢\u{1e944}", "𞤇𞤢\u{1e944}𞤱𞤮 𞤀𞤲\u{1e946}𞤢𞤦𞤭 𞤋\u{1e945}𞤧𞤢\u{1e944}", "𞤃𞤫𞤴𞤶𞤭", "𞤚𞤢𞤴𞥃𞤮\u{1e945}", "𞤡𞤮\u{1e945}𞤱𞤢", "𞤖𞤫𞤴𞤧𞤫𞤴", "𞤈𞤫𞤴𞤱𞤢"], &["𞤀𞤀𞤋", "𞤇𞤀𞤋", "𞤃", "𞤚", "𞤅", "𞤖", "𞤈"]], date_patterns: ["d-M-y GGGGG", "d MMM⹁ y G", "d MMMM⹁ y G", "EEEE d MMMM⹁ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_310: _CalendarData = _CalendarData { months: [&["𞤊𞤢𞤪𞤾𞤢𞤪𞤣𞤭𞤲", "𞤌𞤪𞤣𞤭𞤦𞤫𞤸𞤫𞥃𞤼", "𞤝𞤮𞤪𞤣𞤢𞤣", "𞤚𞤭𞤪", "𞤃𞤮𞤪𞤣𞤢𞤣", "𞤡𞤢𞤸𞤪𞤭𞤾𞤢𞤪", "𞤃𞤫𞤸𞤫𞤪", "𞤀𞤦𞤢𞤲", "𞤀𞥁𞤢𞤪", "𞤁𞤫𞤴", "𞤄𞤢𞤸𞤥𞤢𞤲", "𞤉𞤧𞤬𞤢𞤲𞤣"], &["𞤊𞤢𞤪𞤾𞤢𞤪𞤣𞤭𞤲", "𞤌𞤪𞤣𞤭𞤦𞤫𞤸𞤫𞥃𞤼", "𞤝𞤮𞤪𞤣𞤢𞤣", "𞤚𞤭𞤪", "𞤃𞤮𞤪𞤣𞤢𞤣", "𞤡𞤢𞤸𞤪𞤭𞤾𞤢𞤪", "𞤃𞤫𞤸𞤫𞤪", "𞤀𞤦𞤢𞤲", "𞤀𞥁𞤢𞤪", "𞤁𞤫𞤴", "𞤄𞤢𞤸𞤥𞤢𞤲", "𞤉𞤧𞤬𞤢𞤲𞤣"], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒"], &["𞤊𞤢𞤪𞤾𞤢𞤪𞤣𞤭𞤲", "𞤌𞤪𞤣𞤭𞤦𞤫𞤸𞤫𞥃𞤼", "𞤝𞤮𞤪𞤣𞤢𞤣", "𞤚𞤭𞤪", "𞤃𞤮𞤪𞤣𞤢𞤣", "𞤡𞤢𞤸𞤪𞤭𞤾𞤢𞤪", "𞤃𞤫𞤸𞤫𞤪", "𞤀𞤦𞤢𞤲", "𞤀𞥁𞤢𞤪", "𞤁𞤫𞤴", "𞤄𞤢𞤸𞤥𞤢𞤲", "𞤉𞤧𞤬𞤢𞤲𞤣"], &["𞤊𞤢𞤪𞤾𞤢𞤪𞤣𞤭𞤲", "𞤌𞤪𞤣𞤭𞤦𞤫𞤸𞤫𞥃𞤼", "𞤝𞤮𞤪𞤣𞤢𞤣", "𞤚𞤭𞤪", "𞤃𞤮𞤪𞤣𞤢𞤣", "𞤡𞤢𞤸𞤪𞤭𞤾𞤢𞤪", "𞤃𞤫𞤸𞤫𞤪", "𞤀𞤦𞤢𞤲", "𞤀𞥁𞤢𞤪", "𞤁𞤫𞤴", "𞤄𞤢𞤸𞤥𞤢𞤲", "𞤉𞤧𞤬𞤢𞤲𞤣"], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒"]], eras: [&["𞤀𞤆"], &["𞤀𞤆"], &["𞤀𞤆"]], date_patterns: ["d-M-y GGGGG", "d MMM⹁ y G", "d MMMM⹁ y G", "EEEE d MMMM⹁ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_311: _CalendarData = _CalendarData { months: [&["𞤔𞤮𞤦.", "𞤅𞤢𞤨.", "𞤆𞤢𞤪.", "𞤃𞤭𞤨.", "𞤄𞤢𞤨.", "𞤅𞤢𞤪.", "𞤈𞤢𞤶.", "𞤅𞤢𞤧.", "𞤅𞤵𞤥.", "𞤔𞤵𞤤.", "𞤅𞤢𞤣.", "𞤁𞤮𞤲."], &["𞤔𞤮𞤥𞤦𞤫𞤲𞤼𞤫", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤆𞤢𞤪𞤢𞤲", "𞤆𞤢𞤪𞤢𞤲", "𞤃𞤭𞤥𞤨𞤢𞤪𞤢𞤲", "𞤄𞤢𞤨\u{1e946}𞤢𞤪𞤢𞤲", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤈𞤢\u{1e944}𞤶𞤭𞤦𞤭", "𞤈𞤢\u{1e944}𞤶𞤭𞤦𞤭", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤅𞤵\u{1e945}𞤥𞤢𞤴𞤫\u{1e945}", "𞤅𞤵\u{1e945}𞤥𞤢𞤴𞤫\u{1e945}", "𞤔𞤵\u{1e945}𞤤𞤣𞤢\u{1e944}𞤲𞥋𞤣𞤵", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤁𞤮𞤲𞤳𞤭𞤲", "𞤁𞤵𞤲𞤳𞤭𞤲"], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒"], &["𞤔𞤮𞤦.", "𞤅𞤢𞤨.", "𞤆𞤢𞤪.", "𞤃𞤭𞤨.", "𞤄𞤢𞤨.", "𞤅𞤢𞤪.", "𞤈𞤢𞤶.", "𞤅𞤢𞤧.", "𞤅𞤵𞤥.", "𞤔𞤵𞤤.", "𞤅𞤢𞤣.", "𞤁𞤮𞤲."], &["𞤔𞤮𞤥𞤦𞤫𞤲𞤼𞤫", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤆𞤢𞤪𞤢𞤲", "𞤆𞤢𞤪𞤢𞤲", "𞤃𞤭𞤥𞤨𞤢𞤪𞤢𞤲", "𞤄𞤢𞤨\u{1e946}𞤢𞤪𞤢𞤲", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤈𞤢\u{1e944}𞤶𞤭𞤦𞤭", "𞤈𞤢\u{1e944}𞤶𞤭𞤦𞤭", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤅𞤵\u{1e945}𞤥𞤢𞤴𞤫\u{1e945}", "𞤅𞤵\u{1e945}𞤥𞤢𞤴𞤫\u{1e945}", "𞤔𞤵\u{1e945}𞤤𞤣𞤢\u{1e944}𞤲𞥋𞤣𞤵", "𞤅𞤢𞤦\u{1e946}𞤮𞤪𞤣𞤵-𞤁𞤮𞤲𞤳𞤭𞤲", "𞤁𞤵𞤲𞤳𞤭𞤲"], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒"]], eras: [&["𞤇𞤊"], &["𞤇𞤊"], &["𞤇𞤊"]], date_patterns: ["d-M-y GGGGG", "d MMM⹁ y G", "d MMMM⹁ y G", "EEEE d MMMM⹁ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_312: _CalendarData = _CalendarData { months: [&["𞤚𞤭𞥃𞤪𞤭", "𞤖𞤫𞥃𞤾𞤢𞤲", "𞤑𞤭𞤧𞤤𞤫𞤾", "𞤚𞤫𞤾𞤫𞤼", "𞤡𞤫𞤾𞤢𞤼", "𞤀𞤣𞤢𞤪 𞥑", "𞤀𞤣𞤢𞤪", "𞤐𞤭𞤧𞤢𞤲", "𞤋𞤴𞤢𞤪", "𞤅𞤭𞤾𞤢𞤲", "𞤚𞤢𞤥𞤵𞥁", "𞤀𞤾", "𞤉𞤤𞤵𞤤", "𞤀𞤣𞤢𞤪 𞥒"], &["𞤚𞤭𞥃𞤪𞤭", "𞤖𞤫𞥃𞤾𞤢𞤲", "𞤑𞤭𞤧𞤤𞤫𞤾", "𞤚𞤫𞤾𞤫𞤼", "𞤡𞤫𞤾𞤢𞤼", "𞤀𞤣𞤢𞤪 𞥑", "𞤀𞤣𞤢𞤪", "𞤐𞤭𞤧𞤢𞤲", "𞤋𞤴𞤢𞤪", "𞤅𞤭𞤾𞤢𞤲", "𞤚𞤢𞤥𞤵𞥁", "𞤀𞤾", "𞤉𞤤𞤵𞤤", "𞤀𞤣𞤢𞤪 𞥒"], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒", "𞥑𞥓", "𞥗"], &["𞤚𞤭𞥃𞤪𞤭", "𞤖𞤫𞥃𞤾𞤢𞤲", "𞤑𞤭𞤧𞤤𞤫𞤾", "𞤚𞤫𞤾𞤫𞤼", "𞤡𞤫𞤾𞤢𞤼", "𞤀𞤣𞤢𞤪 𞥑", "𞤀𞤣𞤢𞤪", "𞤐𞤭𞤧𞤢𞤲", "𞤋𞤴𞤢𞤪", "𞤅𞤭𞤾𞤢𞤲", "𞤚𞤢𞤥𞤵𞥁", "𞤀𞤾", "𞤉𞤤𞤵𞤤", "𞤀𞤣𞤢𞤪 𞥒"], &["𞤚𞤭𞥃𞤪𞤭", "𞤖𞤫𞥃𞤾𞤢𞤲", "𞤑𞤭𞤧𞤤𞤫𞤾", "𞤚𞤫𞤾𞤫𞤼", "𞤡𞤫𞤾𞤢𞤼", "𞤀𞤣𞤢𞤪 𞥑", "𞤀𞤣𞤢𞤪", "𞤐𞤭𞤧𞤢𞤲", "𞤋𞤴𞤢𞤪", "𞤅𞤭𞤾𞤢𞤲", "𞤚𞤢𞤥𞤵𞥁", "𞤀𞤾", "𞤉𞤤𞤵𞤤", "𞤀𞤣𞤢𞤪 𞥒"], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒", "𞥑𞥓", "𞥗"]], eras: [&["𞤀𞤎"], &["𞤀𞤎"], &["𞤀𞤎"]], date_patterns: ["d-M-y GGGGG", "d MMM⹁ y G", "d MMMM⹁ y G", "EEEE d MMMM⹁ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_313: _CalendarData = _CalendarData { months: [&["𞤃𞤫𞤧𞤳𞤫𞤪𞤫𞤥", "𞤚𞤫𞤳𞤥𞤫𞤼", "𞤖𞤫𞤣𞤢\u{1e944}𞤪", "𞤚𞤢𞤸𞤢𞤧𞤢\u{1e944}𞤧", "𞤚𞤫𞤪", "𞤒𞤫𞤳𞤢𞤼𞤫𞤳", "𞤃𞤫𞤺𞤢𞤦𞤭𞤼", "𞤃𞤭𞤴𞤢\u{1e944}𞥁𞤴𞤢", "𞤘𞤫𞤲𞤦𞤮𞤼", "𞤅𞤫\u{1e945}𞤲𞤫", "𞤖𞤢𞤥𞤤𞤫", "𞤐𞤫𞤸𞤢\u{1e944}𞤧𞤫", "𞤆𞤢𞤺𞤵𞤥𞤫\u{1e945}𞤲", ""], &["𞤃𞤫𞤧𞤳𞤫𞤪𞤫𞤥", "𞤚𞤫𞤳𞤥𞤫𞤼", "𞤖𞤫𞤣𞤢\u{1e944}𞤪", "𞤚𞤢𞤸𞤢𞤧𞤢\u{1e944}𞤧", "𞤚𞤫𞤪", "𞤒𞤫𞤳𞤢𞤼𞤫𞤳", "𞤃𞤫𞤺𞤢𞤦𞤭𞤼", "𞤃𞤭𞤴𞤢\u{1e944}𞥁𞤴𞤢", "𞤘𞤫𞤲𞤦𞤮𞤼", "𞤅𞤫\u{1e945}𞤲𞤫", "𞤖𞤢𞤥𞤤𞤫", "𞤐𞤫𞤸𞤢\u{1e944}𞤧𞤫", "𞤆𞤢𞤺𞤵𞤥𞤫\u{1e945}𞤲", ""], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒", "𞥑𞥓", ""], &["𞤃𞤫𞤧𞤳𞤫𞤪𞤫𞤥", "𞤚𞤫𞤳𞤥𞤫𞤼", "𞤖𞤫𞤣𞤢\u{1e944}𞤪", "𞤚𞤢𞤸𞤢𞤧𞤢\u{1e944}𞤧", "𞤚𞤫𞤪", "𞤒𞤫𞤳𞤢𞤼𞤫𞤳", "𞤃𞤫𞤺𞤢𞤦𞤭𞤼", "𞤃𞤭𞤴𞤢\u{1e944}𞥁𞤴𞤢", "𞤘𞤫𞤲𞤦𞤮𞤼", "𞤅𞤫\u{1e945}𞤲𞤫", "𞤖𞤢𞤥𞤤𞤫", "𞤐𞤫𞤸𞤢\u{1e944}𞤧𞤫", "𞤆𞤢𞤺𞤵𞤥𞤫\u{1e945}𞤲", ""], &["𞤃𞤫𞤧𞤳𞤫𞤪𞤫𞤥", "𞤚𞤫𞤳𞤥𞤫𞤼", "𞤖𞤫𞤣𞤢\u{1e944}𞤪", "𞤚𞤢𞤸𞤢𞤧𞤢\u{1e944}𞤧", "𞤚𞤫𞤪", "𞤒𞤫𞤳𞤢𞤼𞤫𞤳", "𞤃𞤫𞤺𞤢𞤦𞤭𞤼", "𞤃𞤭𞤴𞤢\u{1e944}𞥁𞤴𞤢", "𞤘𞤫𞤲𞤦𞤮𞤼", "𞤅𞤫\u{1e945}𞤲𞤫", "𞤖𞤢𞤥𞤤𞤫", "𞤐𞤫𞤸𞤢\u{1e944}𞤧𞤫", "𞤆𞤢𞤺𞤵𞤥𞤫\u{1e945}𞤲", ""], &["𞥑", "𞥒", "𞥓", "𞥔", "𞥕", "𞥖", "𞥗", "𞥘", "𞥙", "𞥑𞥐", "𞥑𞥑", "𞥑𞥒", "𞥑𞥓", ""]], eras: [&["𞤘𞤋𞤈𞥐", "𞤇𞤁"], &["𞤘𞤋𞤈𞥐", "𞤩𞤢\u{1e944}𞤱𞤮 𞤣𞤭\u{1e945}𞤤𞤢\u{1e944}𞤳𞤵"], &["𞤘𞤋𞤈𞥐", "𞤇𞤁"]], date_patterns: ["d-M-y GGGGG", "d MMM⹁ y G", "d MMMM⹁ y G", "EEEE d MMMM⹁ y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_75: [&_CalendarData; 6] = [&_CALENDAR_308, &_CALENDAR_309, &_CALENDAR_310, &_CALENDAR_311, &_CALENDAR_312, &_CALENDAR_313];
+
+#[rustfmt::skip]
+static _CALENDAR_314: _CalendarData = _CalendarData { months: [&["tammi", "helmi", "maalis", "huhti", "touko", "kesä", "heinä", "elo", "syys", "loka", "marras", "joulu"], &["tammikuuta", "helmikuuta", "maaliskuuta", "huhtikuuta", "toukokuuta", "kesäkuuta", "heinäkuuta", "elokuuta", "syyskuuta", "lokakuuta", "marraskuuta", "joulukuuta"], &["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"], &["tammi", "helmi", "maalis", "huhti", "touko", "kesä", "heinä", "elo", "syys", "loka", "marras", "joulu"], &["tammikuu", "helmikuu", "maaliskuu", "huhtikuu", "toukokuu", "kesäkuu", "heinäkuu", "elokuu", "syyskuu", "lokakuu", "marraskuu", "joulukuu"], &["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"]], eras: [&["BE"], &["buddhalainen aika"], &["BE"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "cccc d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_315: _CalendarData = _CalendarData { months: [&["tammi", "helmi", "maalis", "huhti", "touko", "kesä", "heinä", "elo", "syys", "loka", "marras", "joulu"], &["tammikuuta", "helmikuuta", "maaliskuuta", "huhtikuuta", "toukokuuta", "kesäkuuta", "heinäkuuta", "elokuuta", "syyskuuta", "lokakuuta", "marraskuuta", "joulukuuta"], &["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"], &["tammi", "helmi", "maalis", "huhti", "touko", "kesä", "heinä", "elo", "syys", "loka", "marras", "joulu"], &["tammikuu", "helmikuu", "maaliskuu", "huhtikuu", "toukokuu", "kesäkuu", "heinäkuu", "elokuu", "syyskuu", "lokakuu", "marraskuu", "joulukuu"], &["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"]], eras: [&["eKr.", "jKr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ennen Kristuksen syntymää", "jälkeen Kristuksen syntymän", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["eKr", "jKr", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "cccc d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_316: _CalendarData = _CalendarData { months: [&["farvardinkuuta", "ordibeheštkuuta", "khordadkuuta", "tirkuuta", "mordadkuuta", "šahrivarkuuta", "mehrkuuta", "abankuuta", "azarkuuta", "deykuuta", "bahmankuuta", "esfandkuuta"], &["farvardinkuuta", "ordibeheštkuuta", "khordadkuuta", "tirkuuta", "mordadkuuta", "šahrivarkuuta", "mehrkuuta", "abankuuta", "azarkuuta", "deykuuta", "bahmankuuta", "esfandkuuta"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehešt", "khordad", "tir", "mordad", "šahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardinkuu", "ordibeheštkuu", "khordadkuu", "tirkuu", "mordadkuu", "šahrivarkuu", "mehrkuu", "abankuu", "azarkuu", "deykuu", "bahmankuu", "esfandkuu"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["Anno Persico"], &["AP"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "cccc d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_317: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharram", "safar", "rabi’ al-awwal", "rabi’ al-akhir", "džumada-l-ula", "džumada-l-akhira", "radžab", "ša’ban", "ramadan", "šawwal", "dhu-l-qa’da", "dhu-l-hiddža"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharram", "safar", "rabi’ al-awwal", "rabi’ al-akhir", "džumada-l-ula", "džumada-l-akhira", "radžab", "ša’ban", "ramadan", "šawwal", "dhu-l-qa’da", "dhu-l-hiddža"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["hidžran jälkeen"], &["AH"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "cccc d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_318: _CalendarData = _CalendarData { months: [&["tišrí", "hešván", "kislév", "tevét", "ševát", "adár I", "adár", "nisán", "ijjár", "siván", "tammúz", "ab", "elúl", "adár II"], &["tišríkuuta", "hešvánkuuta", "kislévkuuta", "tevétkuuta", "ševátkuuta", "adárkuuta I", "adárkuuta", "nisánkuuta", "ijjárkuuta", "sivánkuuta", "tammúzkuuta", "abkuuta", "elúlkuuta", "adárkuuta II"], &["T", "H", "K", "T", "S", "A", "A", "N", "I", "S", "T", "A", "E", "A"], &["tišrí", "hešván", "kislév", "tevét", "ševát", "adár I", "adár", "nisán", "ijjár", "siván", "tammúz", "ab", "elúl", "adár II"], &["tišríkuu", "hešvánkuu", "kislévkuu", "tevétkuu", "ševátkuu", "adárkuu I", "adárkuu", "nisánkuu", "ijjárkuu", "sivánkuu", "tammúzkuu", "abkuu", "elúlkuu", "adárkuu II"], &["T", "H", "K", "T", "S", "A", "A", "N", "I", "S", "T", "A", "E", "A"]], eras: [&["AM"], &["Anno Mundi"], &["AM"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "cccc d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_319: _CalendarData = _CalendarData { months: [&["mäskärämkuuta", "ṭəqəmtkuuta", "ḫədarkuuta", "taḫśaśkuuta", "ṭərrkuuta", "yäkatitkuuta", "mägabitkuuta", "miyazyakuuta", "gənbotkuuta", "sänekuuta", "ḥamlekuuta", "nähasekuuta", "ṗagumenkuuta", ""], &["mäskärämkuuta", "ṭəqəmtkuuta", "ḫədarkuuta", "taḫśaśkuuta", "ṭərrkuuta", "yäkatitkuuta", "mägabitkuuta", "miyazyakuuta", "gənbotkuuta", "sänekuuta", "ḥamlekuuta", "nähasekuuta", "ṗagumenkuuta", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["mäskärämkuu", "ṭəqəmtkuu", "ḫədarkuu", "taḫśaśkuu", "ṭərrkuu", "yäkatitkuu", "mägabitkuu", "miyazyakuu", "gənbotkuu", "sänekuu", "ḥamlekuu", "nähasekuu", "ṗagumenkuu", ""], &["mäskärämkuu", "ṭəqəmtkuu", "ḫədarkuu", "taḫśaśkuu", "ṭərrkuu", "yäkatitkuu", "mägabitkuu", "miyazyakuu", "gənbotkuu", "sänekuu", "ḥamlekuu", "nähasekuu", "ṗagumenkuu", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "cccc d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_76: [&_CalendarData; 6] = [&_CALENDAR_314, &_CALENDAR_315, &_CALENDAR_316, &_CALENDAR_317, &_CALENDAR_318, &_CALENDAR_319];
+
+#[rustfmt::skip]
+static _CALENDAR_320: _CalendarData = _CalendarData { months: [&["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"], &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"], &["E", "P", "M", "A", "M", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_321: _CalendarData = _CalendarData { months: [&["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"], &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"], &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"], &["E", "P", "M", "A", "M", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_322: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["MMM d y", "MMM d y", "MMMM d y", "EEEE, MMMM d y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_77: [&_CalendarData; 6] = [&_CALENDAR_320, &_CALENDAR_321, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_322, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_323: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "mai", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "des."], &["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], &["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y GGGGG", "d. MMM y G", "d. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_324: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "mai", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.", "des."], &["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], &["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["f.Kr.", "e.Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["fyri Krist", "eftir Krist", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["fKr", "eKr", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "d. MMM y G", "d. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_325: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "d. MMM y G", "d. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_326: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "d. MMM y G", "d. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_327: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y GGGGG", "d. MMM y G", "d. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_328: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "d. MMM y G", "d. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_78: [&_CalendarData; 6] = [&_CALENDAR_323, &_CALENDAR_324, &_CALENDAR_325, &_CALENDAR_326, &_CALENDAR_327, &_CALENDAR_328];
+
+#[rustfmt::skip]
+static _CALENDAR_329: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["E. B."], &["ère bouddhique"], &["EB"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_330: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["av. J.-C.", "ap. J.-C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avant Jésus-Christ", "après Jésus-Christ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. J.-C.", "ap. J.-C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_331: _CalendarData = _CalendarData { months: [&["far.", "ord.", "kho.", "tir", "mor.", "šah.", "mehr", "âbân", "âzar", "dey", "bah.", "esf."], &["farvardin", "ordibehešt", "khordâd", "tir", "mordâd", "šahrivar", "mehr", "âbân", "âzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["far.", "ord.", "kho.", "tir", "mor.", "šah.", "mehr", "âbân", "âzar", "dey", "bah.", "esf."], &["farvardin", "ordibehešt", "khordâd", "tir", "mordâd", "šahrivar", "mehr", "âbân", "âzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["A. P."], &["Anno Persico"], &["A. P."]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_332: _CalendarData = _CalendarData { months: [&["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. oul.", "joum. tha.", "raj.", "chaa.", "ram.", "chaw.", "dhou. q.", "dhou. h."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. ou.", "joum. th.", "raj.", "chaa.", "ram.", "chaw.", "dhou. qi.", "dhou. hi."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["ère de l’Hégire"], &["H"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_333: _CalendarData = _CalendarData { months: [&["tich.", "hèch.", "kis.", "tév.", "chev.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "él.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tich.", "hèch.", "kis.", "tév.", "chev.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "él.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["A. M."], &["Anno Mundi"], &["A. M."]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_334: _CalendarData = _CalendarData { months: [&["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ap. Inc."], &["ERA0", "après l’Incarnation"], &["ERA0", "ap. Inc."]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_79: [&_CalendarData; 6] = [&_CALENDAR_329, &_CALENDAR_330, &_CALENDAR_331, &_CALENDAR_332, &_CALENDAR_333, &_CALENDAR_334];
+
+#[rustfmt::skip]
+static _CALENDAR_335: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["E. B."], &["ère bouddhique"], &["EB"]], date_patterns: ["d/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_336: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["av. J.-C.", "ap. J.-C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avant Jésus-Christ", "après Jésus-Christ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. J.-C.", "ap. J.-C.", "M", "T", "S", "H", "R"]], date_patterns: ["d/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_337: _CalendarData = _CalendarData { months: [&["far.", "ord.", "kho.", "tir", "mor.", "šah.", "mehr", "âbân", "âzar", "dey", "bah.", "esf."], &["farvardin", "ordibehešt", "khordâd", "tir", "mordâd", "šahrivar", "mehr", "âbân", "âzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["far.", "ord.", "kho.", "tir", "mor.", "šah.", "mehr", "âbân", "âzar", "dey", "bah.", "esf."], &["farvardin", "ordibehešt", "khordâd", "tir", "mordâd", "šahrivar", "mehr", "âbân", "âzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["A. P."], &["Anno Persico"], &["A. P."]], date_patterns: ["d/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_338: _CalendarData = _CalendarData { months: [&["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. oul.", "joum. tha.", "raj.", "chaa.", "ram.", "chaw.", "dhou. q.", "dhou. h."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. ou.", "joum. th.", "raj.", "chaa.", "ram.", "chaw.", "dhou. qi.", "dhou. hi."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["ère de l’Hégire"], &["H"]], date_patterns: ["d/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_339: _CalendarData = _CalendarData { months: [&["tich.", "hèch.", "kis.", "tév.", "chev.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "él.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tich.", "hèch.", "kis.", "tév.", "chev.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "él.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["A. M."], &["Anno Mundi"], &["A. M."]], date_patterns: ["d/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_340: _CalendarData = _CalendarData { months: [&["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ap. Inc."], &["ERA0", "après l’Incarnation"], &["ERA0", "ap. Inc."]], date_patterns: ["d/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_80: [&_CalendarData; 6] = [&_CALENDAR_335, &_CALENDAR_336, &_CALENDAR_337, &_CALENDAR_338, &_CALENDAR_339, &_CALENDAR_340];
+
+#[rustfmt::skip]
+static _CALENDAR_341: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juill.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juill.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["E. B."], &["ère bouddhique"], &["EB"]], date_patterns: ["yy-MM-dd GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_342: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juill.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juill.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["av. J.-C.", "ap. J.-C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avant Jésus-Christ", "après Jésus-Christ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. J.-C.", "ap. J.-C.", "M", "T", "S", "H", "R"]], date_patterns: ["yy-MM-dd GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_343: _CalendarData = _CalendarData { months: [&["Far.", "Ord.", "Kho.", "Tir", "Mor.", "Šah.", "Mehr", "Âbâ.", "Âzar", "Dey", "Bah.", "Esf."], &["Farvardin", "Ordibehešt", "Khordâd", "Tir", "Mordâd", "Šahrivar", "Mehr", "Âbân", "Âzar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Far.", "Ord.", "Kho.", "Tir", "Mor.", "Šah.", "Mehr", "Âbâ.", "Âzar", "Dey", "Bah.", "Esf."], &["Farvardin", "Ordibehešt", "Khordâd", "Tir", "Mordâd", "Šahrivar", "Mehr", "Âbân", "Âzar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["yy-MM-dd GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_344: _CalendarData = _CalendarData { months: [&["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. oul.", "joum. tha.", "raj.", "chaa.", "ram.", "chaw.", "dhou. q.", "dhou. h."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. ou.", "joum. th.", "raj.", "chaa.", "ram.", "chaw.", "dhou. qi.", "dhou. hi."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["Anno Hegirae"], &["AH"]], date_patterns: ["y-MM-dd GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_345: _CalendarData = _CalendarData { months: [&["tis.", "hes.", "kis.", "téb.", "sché.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "ell.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["T", "H", "K", "T", "S", "A", "A", "N", "I", "S", "T", "A", "E", "A"], &["tis.", "hes.", "kis.", "téb.", "sché.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "ell.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["T", "H", "K", "T", "S", "A", "A", "N", "I", "S", "T", "A", "E", "A"]], eras: [&["AM"], &["Anno Mundi"], &["AM"]], date_patterns: ["yy-MM-dd GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_346: _CalendarData = _CalendarData { months: [&["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["M", "T", "H", "T", "T", "Y", "M", "M", "G", "S", "H", "N", "P", ""], &["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["M", "T", "H", "T", "T", "Y", "M", "M", "G", "S", "H", "N", "P", ""]], eras: [&["ERA0", "ap. Inc."], &["ERA0", "après l’Incarnation"], &["ERA0", "ap. Inc."]], date_patterns: ["yy-MM-dd GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_81: [&_CalendarData; 6] = [&_CALENDAR_341, &_CALENDAR_342, &_CALENDAR_343, &_CALENDAR_344, &_CALENDAR_345, &_CALENDAR_346];
+
+#[rustfmt::skip]
+static _CALENDAR_347: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["E. B."], &["ère bouddhique"], &["EB"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_348: _CalendarData = _CalendarData { months: [&["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["av. J.-C.", "ap. J.-C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avant Jésus-Christ", "après Jésus-Christ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. J.-C.", "ap. J.-C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_349: _CalendarData = _CalendarData { months: [&["far.", "ord.", "kho.", "tir", "mor.", "šah.", "mehr", "âbân", "âzar", "dey", "bah.", "esf."], &["farvardin", "ordibehešt", "khordâd", "tir", "mordâd", "šahrivar", "mehr", "âbân", "âzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["far.", "ord.", "kho.", "tir", "mor.", "šah.", "mehr", "âbân", "âzar", "dey", "bah.", "esf."], &["farvardin", "ordibehešt", "khordâd", "tir", "mordâd", "šahrivar", "mehr", "âbân", "âzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["A. P."], &["Anno Persico"], &["A. P."]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_350: _CalendarData = _CalendarData { months: [&["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. oul.", "joum. tha.", "raj.", "chaa.", "ram.", "chaw.", "dhou. q.", "dhou. h."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["mouh.", "saf.", "rab. aw.", "rab. th.", "joum. ou.", "joum. th.", "raj.", "chaa.", "ram.", "chaw.", "dhou. qi.", "dhou. hi."], &["mouharram", "safar", "rabia al awal", "rabia ath-thani", "joumada al oula", "joumada ath-thania", "rajab", "chaabane", "ramadan", "chawwal", "dhou al qi`da", "dhou al-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["ère de l’Hégire"], &["H"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_351: _CalendarData = _CalendarData { months: [&["tich.", "hèch.", "kis.", "tév.", "chev.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "él.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tich.", "hèch.", "kis.", "tév.", "chev.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "él.", "ad.II"], &["tichri", "hèchvan", "kislev", "téveth", "chevat", "adar I", "adar", "nissan", "iyar", "sivan", "tamouz", "av", "éloul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["A. M."], &["Anno Mundi"], &["A. M."]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_352: _CalendarData = _CalendarData { months: [&["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["mäs.", "teq.", "hed.", "tah.", "ter", "yäk.", "mäg.", "miy.", "gue.", "sän.", "ham.", "näh.", "pag.", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ap. Inc."], &["ERA0", "après l’Incarnation"], &["ERA0", "ap. Inc."]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_82: [&_CalendarData; 6] = [&_CALENDAR_347, &_CALENDAR_348, &_CALENDAR_349, &_CALENDAR_350, &_CALENDAR_351, &_CALENDAR_352];
+
+#[rustfmt::skip]
+static _CALENDAR_353: _CalendarData = _CalendarData { months: [&["jan.", "fév.", "mar.", "avr.", "mai", "jui.", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "fév.", "mar.", "avr.", "mai", "jui.", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["E. B."], &["ère bouddhique"], &["EB"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_354: _CalendarData = _CalendarData { months: [&["jan.", "fév.", "mar.", "avr.", "mai", "jui.", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "fév.", "mar.", "avr.", "mai", "jui.", "juil.", "août", "sept.", "oct.", "nov.", "déc."], &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["av. J.-C.", "ap. J.-C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avant Jésus-Christ", "après Jésus-Christ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. J.-C.", "ap. J.-C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_83: [&_CalendarData; 6] = [&_CALENDAR_353, &_CALENDAR_354, &_CALENDAR_331, &_CALENDAR_332, &_CALENDAR_333, &_CALENDAR_334];
+
+#[rustfmt::skip]
+static _CALENDAR_355: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"], &["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"], &["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_356: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"], &["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"], &["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["f.Kr.", "n.Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Foar Kristus", "nei Kristus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["f.K.", "n.K.", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_357: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_358: _CalendarData = _CalendarData { months: [&["Moeh.", "Saf.", "Rab. I", "Rab. II", "Joem. I", "Joem. II", "Raj.", "Sja.", "Ram.", "Sjaw.", "Doe al k.", "Doe al h."], &["Moeharram", "Safar", "Rabiʻa al awal", "Rabiʻa al thani", "Joemadʻal awal", "Joemadʻal thani", "Rajab", "Sjaʻaban", "Ramadan", "Sjawal", "Doe al kaʻaba", "Doe al hizja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Moeh.", "Saf.", "Rab. I", "Rab. II", "Joem. I", "Joem. II", "Raj.", "Sja.", "Ram.", "Sjaw.", "Doe al k.", "Doe al h."], &["Moeharram", "Safar", "Rabiʻa al awal", "Rabiʻa al thani", "Joemadʻal awal", "Joemadʻal thani", "Rajab", "Sjaʻaban", "Ramadan", "Sjawal", "Doe al kaʻaba", "Doe al hizja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["Saʻna Hizjria"], &["Saʻna Hizjria"], &["Saʻna Hizjria"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_359: _CalendarData = _CalendarData { months: [&["Tisjrie", "Chesjwan", "Kislev", "Tevet", "Sjevat", "Adar A", "Adar", "Nisan", "Ijar", "Sivan", "Tammoez", "Av", "Elloel", "Adar B"], &["Tisjrie", "Chesjwan", "Kislev", "Tevet", "Sjevat", "Adar A", "Adar", "Nisan", "Ijar", "Sivan", "Tammoez", "Av", "Elloel", "Adar B"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tisjrie", "Chesjwan", "Kislev", "Tevet", "Sjevat", "Adar A", "Adar", "Nisan", "Ijar", "Sivan", "Tammoez", "Av", "Elloel", "Adar B"], &["Tisjrie", "Chesjwan", "Kislev", "Tevet", "Sjevat", "Adar A", "Adar", "Nisan", "Ijar", "Sivan", "Tammoez", "Av", "Elloel", "Adar B"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_360: _CalendarData = _CalendarData { months: [&["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_84: [&_CalendarData; 6] = [&_CALENDAR_355, &_CALENDAR_356, &_CALENDAR_357, &_CALENDAR_358, &_CALENDAR_359, &_CALENDAR_360];
+
+#[rustfmt::skip]
+static _CALENDAR_361: _CalendarData = _CalendarData { months: [&["Ean", "Feabh", "Márta", "Aib", "Beal", "Meith", "Iúil", "Lún", "MFómh", "DFómh", "Samh", "Noll"], &["Eanáir", "Feabhra", "Márta", "Aibreán", "Bealtaine", "Meitheamh", "Iúil", "Lúnasa", "Meán Fómhair", "Deireadh Fómhair", "Samhain", "Nollaig"], &["E", "F", "M", "A", "B", "M", "I", "L", "M", "D", "S", "N"], &["Ean", "Feabh", "Márta", "Aib", "Beal", "Meith", "Iúil", "Lún", "MFómh", "DFómh", "Samh", "Noll"], &["Eanáir", "Feabhra", "Márta", "Aibreán", "Bealtaine", "Meitheamh", "Iúil", "Lúnasa", "Meán Fómhair", "Deireadh Fómhair", "Samhain", "Nollaig"], &["E", "F", "M", "A", "B", "M", "I", "L", "M", "D", "S", "N"]], eras: [&["RB"], &["RB"], &["RB"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_362: _CalendarData = _CalendarData { months: [&["Ean", "Feabh", "Márta", "Aib", "Beal", "Meith", "Iúil", "Lún", "MFómh", "DFómh", "Samh", "Noll"], &["Eanáir", "Feabhra", "Márta", "Aibreán", "Bealtaine", "Meitheamh", "Iúil", "Lúnasa", "Meán Fómhair", "Deireadh Fómhair", "Samhain", "Nollaig"], &["E", "F", "M", "A", "B", "M", "I", "L", "M", "D", "S", "N"], &["Ean", "Feabh", "Márta", "Aib", "Beal", "Meith", "Iúil", "Lún", "MFómh", "DFómh", "Samh", "Noll"], &["Eanáir", "Feabhra", "Márta", "Aibreán", "Bealtaine", "Meitheamh", "Iúil", "Lúnasa", "Meán Fómhair", "Deireadh Fómhair", "Samhain", "Nollaig"], &["E", "F", "M", "A", "B", "M", "I", "L", "M", "D", "S", "N"]], eras: [&["RC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Roimh Chríost", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["RC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_363: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_364: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_85: [&_CalendarData; 6] = [&_CALENDAR_361, &_CALENDAR_362, &_CALENDAR_189, &_CALENDAR_363, &_CALENDAR_364, &_CALENDAR_190];
+
+#[rustfmt::skip]
+static _CALENDAR_365: _CalendarData = _CalendarData { months: [&["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_366: _CalendarData = _CalendarData { months: [&["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["Aharabata", "Oflɔ", "Otsokrikri", "Abɛibe", "Agbiɛnaa", "Otukwajaŋ", "Maawɛ", "Manyawale", "Gbo", "Antɔŋ", "Alemle", "Afuabe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_86: [&_CalendarData; 6] = [&_CALENDAR_365, &_CALENDAR_366, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_367: _CalendarData = _CalendarData { months: [&["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"], &["dhen Fhaoilleach", "dhen Ghearran", "dhen Mhàrt", "dhen Ghiblean", "dhen Chèitean", "dhen Ògmhios", "dhen Iuchar", "dhen Lùnastal", "dhen t-Sultain", "dhen Dàmhair", "dhen t-Samhain", "dhen Dùbhlachd"], &["F", "G", "M", "G", "C", "Ò", "I", "L", "S", "D", "S", "D"], &["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"], &["Am Faoilleach", "An Gearran", "Am Màrt", "An Giblean", "An Cèitean", "An t-Ògmhios", "An t-Iuchar", "An Lùnastal", "An t-Sultain", "An Dàmhair", "An t-Samhain", "An Dùbhlachd"], &["F", "G", "M", "G", "C", "Ò", "I", "L", "S", "D", "S", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d'mh' MMMM y G", "EEEE, d'mh' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_368: _CalendarData = _CalendarData { months: [&["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"], &["dhen Fhaoilleach", "dhen Ghearran", "dhen Mhàrt", "dhen Ghiblean", "dhen Chèitean", "dhen Ògmhios", "dhen Iuchar", "dhen Lùnastal", "dhen t-Sultain", "dhen Dàmhair", "dhen t-Samhain", "dhen Dùbhlachd"], &["F", "G", "M", "G", "C", "Ò", "I", "L", "S", "D", "S", "D"], &["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"], &["Am Faoilleach", "An Gearran", "Am Màrt", "An Giblean", "An Cèitean", "An t-Ògmhios", "An t-Iuchar", "An Lùnastal", "An t-Sultain", "An Dàmhair", "An t-Samhain", "An Dùbhlachd"], &["F", "G", "M", "G", "C", "Ò", "I", "L", "S", "D", "S", "D"]], eras: [&["RC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Ro Chrìosta", "An dèidh Chrìosta", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["R", "A", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d'mh' MMMM y G", "EEEE, d'mh' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_369: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d'mh' MMMM y G", "EEEE, d'mh' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_370: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d'mh' MMMM y G", "EEEE, d'mh' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_371: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d'mh' MMMM y G", "EEEE, d'mh' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_372: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d'mh' MMMM y G", "EEEE, d'mh' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_87: [&_CalendarData; 6] = [&_CALENDAR_367, &_CALENDAR_368, &_CALENDAR_369, &_CALENDAR_370, &_CALENDAR_371, &_CALENDAR_372];
+
+#[rustfmt::skip]
+static _CALENDAR_373: _CalendarData = _CalendarData { months: [&["xan.", "feb.", "mar.", "abr.", "maio", "xuño", "xul.", "ago.", "set.", "out.", "nov.", "dec."], &["xaneiro", "febreiro", "marzo", "abril", "maio", "xuño", "xullo", "agosto", "setembro", "outubro", "novembro", "decembro"], &["x.", "f.", "m.", "a.", "m.", "x.", "x.", "a.", "s.", "o.", "n.", "d."], &["xan.", "feb.", "mar.", "abr.", "maio", "xuño", "xul.", "ago.", "set.", "out.", "nov.", "dec."], &["xaneiro", "febreiro", "marzo", "abril", "maio", "xuño", "xullo", "agosto", "setembro", "outubro", "novembro", "decembro"], &["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' Y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_374: _CalendarData = _CalendarData { months: [&["xan.", "feb.", "mar.", "abr.", "maio", "xuño", "xul.", "ago.", "set.", "out.", "nov.", "dec."], &["xaneiro", "febreiro", "marzo", "abril", "maio", "xuño", "xullo", "agosto", "setembro", "outubro", "novembro", "decembro"], &["x.", "f.", "m.", "a.", "m.", "x.", "x.", "a.", "s.", "o.", "n.", "d."], &["xan.", "feb.", "mar.", "abr.", "maio", "xuño", "xul.", "ago.", "set.", "out.", "nov.", "dec."], &["xaneiro", "febreiro", "marzo", "abril", "maio", "xuño", "xullo", "agosto", "setembro", "outubro", "novembro", "decembro"], &["X", "F", "M", "A", "M", "X", "X", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "despois de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' Y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_375: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' Y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_376: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' Y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_377: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' Y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_378: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' Y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_88: [&_CalendarData; 6] = [&_CALENDAR_373, &_CALENDAR_374, &_CALENDAR_375, &_CALENDAR_376, &_CALENDAR_377, &_CALENDAR_378];
+
+#[rustfmt::skip]
+static _CALENDAR_379: _CalendarData = _CalendarData { months: [&["જાન\u{acd}ય\u{ac1}", "ફ\u{ac7}બ\u{acd}ર\u{ac1}", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}", "ઑક\u{acd}ટો", "નવ\u{ac7}", "ડિસ\u{ac7}"], &["જાન\u{acd}ય\u{ac1}આરી", "ફ\u{ac7}બ\u{acd}ર\u{ac1}આરી", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}મ\u{acd}બર", "ઑક\u{acd}ટોબર", "નવ\u{ac7}મ\u{acd}બર", "ડિસ\u{ac7}મ\u{acd}બર"], &["જા", "ફ\u{ac7}", "મા", "એ", "મ\u{ac7}", "જ\u{ac2}", "જ\u{ac1}", "ઑ", "સ", "ઑ", "ન", "ડિ"], &["જાન\u{acd}ય\u{ac1}", "ફ\u{ac7}બ\u{acd}ર\u{ac1}", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}", "ઑક\u{acd}ટો", "નવ\u{ac7}", "ડિસ\u{ac7}"], &["જાન\u{acd}ય\u{ac1}આરી", "ફ\u{ac7}બ\u{acd}ર\u{ac1}આરી", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}મ\u{acd}બર", "ઑક\u{acd}ટોબર", "નવ\u{ac7}મ\u{acd}બર", "ડિસ\u{ac7}મ\u{acd}બર"], &["જા", "ફ\u{ac7}", "મા", "એ", "મ\u{ac7}", "જ\u{ac2}", "જ\u{ac1}", "ઑ", "સ", "ઑ", "ન", "ડિ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d-MM- GGGGG y", "d MMM, G y", "d MMMM, G y", "EEEE, d MMMM, G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_380: _CalendarData = _CalendarData { months: [&["જાન\u{acd}ય\u{ac1}", "ફ\u{ac7}બ\u{acd}ર\u{ac1}", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}", "ઑક\u{acd}ટો", "નવ\u{ac7}", "ડિસ\u{ac7}"], &["જાન\u{acd}ય\u{ac1}આરી", "ફ\u{ac7}બ\u{acd}ર\u{ac1}આરી", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}મ\u{acd}બર", "ઑક\u{acd}ટોબર", "નવ\u{ac7}મ\u{acd}બર", "ડિસ\u{ac7}મ\u{acd}બર"], &["જા", "ફ\u{ac7}", "મા", "એ", "મ\u{ac7}", "જ\u{ac2}", "જ\u{ac1}", "ઑ", "સ", "ઑ", "ન", "ડિ"], &["જાન\u{acd}ય\u{ac1}", "ફ\u{ac7}બ\u{acd}ર\u{ac1}", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}", "ઑક\u{acd}ટો", "નવ\u{ac7}", "ડિસ\u{ac7}"], &["જાન\u{acd}ય\u{ac1}આરી", "ફ\u{ac7}બ\u{acd}ર\u{ac1}આરી", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}મ\u{acd}બર", "ઑક\u{acd}ટોબર", "નવ\u{ac7}મ\u{acd}બર", "ડિસ\u{ac7}મ\u{acd}બર"], &["જા", "ફ\u{ac7}", "મા", "એ", "મ\u{ac7}", "જ\u{ac2}", "જ\u{ac1}", "ઑ", "સ", "ઑ", "ન", "ડિ"]], eras: [&["ઈ.સ.પ\u{ac2}ર\u{acd}વ\u{ac7}", "ઈ.સ.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ઈસવીસન પ\u{ac2}ર\u{acd}વ\u{ac7}", "ઇસવીસન", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ઇ સ પ\u{ac1}", "ઇસ", "M", "T", "S", "H", "R"]], date_patterns: ["d-MM- GGGGG y", "d MMM, G y", "d MMMM, G y", "EEEE, d MMMM, G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_381: _CalendarData = _CalendarData { months: [&["ફાર\u{acd}વાર\u{acd}દિન", "ઓરડીબ\u{ac7}હ\u{ac7}શ\u{acd}ટ", "ખોરદાદ", "તિર", "મોર\u{acd}દાદ", "શાહરિવર", "મ\u{ac7}હર", "અબાન", "અઝાર", "ડ\u{ac7}ય", "બાહમ\u{ac7}ન", "એસ\u{acd}ફાન\u{acd}ડ"], &["ફાર\u{acd}વાર\u{acd}દિન", "ઓરડીબ\u{ac7}હ\u{ac7}શ\u{acd}ટ", "ખોરદાદ", "તિર", "મોર\u{acd}દાદ", "શાહરિવર", "મ\u{ac7}હર", "અબાન", "અઝાર", "ડ\u{ac7}ય", "બાહમ\u{ac7}ન", "એસ\u{acd}ફાન\u{acd}ડ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ફાર\u{acd}વાર\u{acd}દિન", "ઓરડીબ\u{ac7}હ\u{ac7}શ\u{acd}ટ", "ખોરદાદ", "તિર", "મોર\u{acd}દાદ", "શાહરિવર", "મ\u{ac7}હર", "અબાન", "અઝાર", "ડ\u{ac7}ય", "બાહમ\u{ac7}ન", "એસ\u{acd}ફાન\u{acd}ડ"], &["ફાર\u{acd}વાર\u{acd}દિન", "ઓરડીબ\u{ac7}હ\u{ac7}શ\u{acd}ટ", "ખોરદાદ", "તિર", "મોર\u{acd}દાદ", "શાહરિવર", "મ\u{ac7}હર", "અબાન", "અઝાર", "ડ\u{ac7}ય", "બાહમ\u{ac7}ન", "એસ\u{acd}ફાન\u{acd}ડ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d-MM- GGGGG y", "d MMM, G y", "d MMMM, G y", "EEEE, d MMMM, G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_382: _CalendarData = _CalendarData { months: [&["મ\u{ac1}હ.", "સફ.", "રબ.I", "રબ. II", "જ\u{ac1}મ. I", "જ\u{ac1}મ. II", "રાજ.", "શા.", "રામ.", "શાવ.", "ધ\u{ac1}ʻલ-ક\u{acd}ય\u{ac1}.", "ધ\u{ac1}ʻલ-એચ."], &["મ\u{ac1}હર\u{acd}રમ", "સફર", "રાબીʻ I", "રાબીʻ II", "જ\u{ac1}માદા I", "જ\u{ac1}માદા II", "રજબ", "શાʻબાન", "રમદાન", "શાવ\u{acd}વલ", "ધ\u{ac1}ʻલ-ક\u{acd}વીʻડાહ", "ધ\u{ac1}ʻલ-હિજ\u{acd}જાહ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["મ\u{ac1}હ.", "સફ.", "રબ.I", "રબ. II", "જ\u{ac1}મ. I", "જ\u{ac1}મ. II", "રાજ.", "શા.", "રામ.", "શાવ.", "ધ\u{ac1}ʻલ-ક\u{acd}ય\u{ac1}.", "ધ\u{ac1}ʻલ-એચ."], &["મ\u{ac1}હર\u{acd}રમ", "સફર", "રાબીʻ I", "રાબીʻ II", "જ\u{ac1}માદા I", "જ\u{ac1}માદા II", "રજબ", "શાʻબાન", "રમદાન", "શાવ\u{acd}વલ", "ધ\u{ac1}ʻલ-ક\u{acd}વીʻડાહ", "ધ\u{ac1}ʻલ-હિજ\u{acd}જાહ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d-MM- GGGGG y", "d MMM, G y", "d MMMM, G y", "EEEE, d MMMM, G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_383: _CalendarData = _CalendarData { months: [&["તીશ\u{acd}રી", "હ\u{ac7}શવાન", "કિસ\u{acd}લ\u{ac7}વ", "ત\u{ac7}વ\u{ac7}ટ", "શ\u{ac7}વાત", "અદાર I", "અદાર", "નિસાન", "ઈયાર", "સિવાન", "તામ\u{ac1}ઝ", "આવ", "ઈલ\u{ac1}લ", "અદાર II"], &["તીશ\u{acd}રી", "હ\u{ac7}શવાન", "કિસ\u{acd}લ\u{ac7}વ", "ત\u{ac7}વ\u{ac7}ટ", "શ\u{ac7}વાત", "અદાર I", "અદાર", "નિસાન", "ઈયાર", "સિવાન", "તામ\u{ac1}ઝ", "આવ", "ઈલ\u{ac1}લ", "અદાર II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["તીશ\u{acd}રી", "હ\u{ac7}શવાન", "કિસ\u{acd}લ\u{ac7}વ", "ત\u{ac7}વ\u{ac7}ટ", "શ\u{ac7}વાત", "અદાર I", "અદાર", "નિસાન", "ઈયાર", "સિવાન", "તામ\u{ac1}ઝ", "આવ", "ઈલ\u{ac1}લ", "અદાર II"], &["તીશ\u{acd}રી", "હ\u{ac7}શવાન", "કિસ\u{acd}લ\u{ac7}વ", "ત\u{ac7}વ\u{ac7}ટ", "શ\u{ac7}વાત", "અદાર I", "અદાર", "નિસાન", "ઈયાર", "સિવાન", "તામ\u{ac1}ઝ", "આવ", "ઈલ\u{ac1}લ", "અદાર II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d-MM- GGGGG y", "d MMM, G y", "d MMMM, G y", "EEEE, d MMMM, G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_384: _CalendarData = _CalendarData { months: [&["મ\u{ac7}સ\u{acd}ક\u{ac7}ર\u{ac7}મ", "ટ\u{ac7}ક\u{ac7}મ\u{acd}ટ", "હ\u{ac7}ડાર", "તાહસાસ", "ત\u{ac7}ર", "ય\u{ac7}કાતીત", "મ\u{ac7}ગાબીટ", "મિયાઝિયા", "ગ\u{ac7}નબોટ", "સ\u{ac7}ન\u{ac7}", "હ\u{ac7}મલ\u{ac7}", "ન\u{ac7}હાસ\u{acd}સ\u{ac7}", "પ\u{ac7}ગ\u{ac1}મ\u{ac7}ન", ""], &["મ\u{ac7}સ\u{acd}ક\u{ac7}ર\u{ac7}મ", "ટ\u{ac7}ક\u{ac7}મ\u{acd}ટ", "હ\u{ac7}ડાર", "તાહસાસ", "ત\u{ac7}ર", "ય\u{ac7}કાતીત", "મ\u{ac7}ગાબીટ", "મિયાઝિયા", "ગ\u{ac7}નબોટ", "સ\u{ac7}ન\u{ac7}", "હ\u{ac7}મલ\u{ac7}", "ન\u{ac7}હાસ\u{acd}સ\u{ac7}", "પ\u{ac7}ગ\u{ac1}મ\u{ac7}ન", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["મ\u{ac7}સ\u{acd}ક\u{ac7}ર\u{ac7}મ", "ટ\u{ac7}ક\u{ac7}મ\u{acd}ટ", "હ\u{ac7}ડાર", "તાહસાસ", "ત\u{ac7}ર", "ય\u{ac7}કાતીત", "મ\u{ac7}ગાબીટ", "મિયાઝિયા", "ગ\u{ac7}નબોટ", "સ\u{ac7}ન\u{ac7}", "હ\u{ac7}મલ\u{ac7}", "ન\u{ac7}હાસ\u{acd}સ\u{ac7}", "પ\u{ac7}ગ\u{ac1}મ\u{ac7}ન", ""], &["મ\u{ac7}સ\u{acd}ક\u{ac7}ર\u{ac7}મ", "ટ\u{ac7}ક\u{ac7}મ\u{acd}ટ", "હ\u{ac7}ડાર", "તાહસાસ", "ત\u{ac7}ર", "ય\u{ac7}કાતીત", "મ\u{ac7}ગાબીટ", "મિયાઝિયા", "ગ\u{ac7}નબોટ", "સ\u{ac7}ન\u{ac7}", "હ\u{ac7}મલ\u{ac7}", "ન\u{ac7}હાસ\u{acd}સ\u{ac7}", "પ\u{ac7}ગ\u{ac1}મ\u{ac7}ન", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "એરા1"], &["ERA0", "એરા1"], &["ERA0", "એરા1"]], date_patterns: ["d-MM- GGGGG y", "d MMM, G y", "d MMMM, G y", "EEEE, d MMMM, G y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_89: [&_CalendarData; 6] = [&_CALENDAR_379, &_CALENDAR_380, &_CALENDAR_381, &_CALENDAR_382, &_CALENDAR_383, &_CALENDAR_384];
+
+#[rustfmt::skip]
+static _CALENDAR_385: _CalendarData = _CalendarData { months: [&["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"], &["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"], &["J", "F", "M", "A", "M", "Y", "Y", "A", "S", "O", "N", "D"], &["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"], &["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"], &["J", "F", "M", "A", "M", "Y", "Y", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_386: _CalendarData = _CalendarData { months: [&["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"], &["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"], &["J", "F", "M", "A", "M", "Y", "Y", "A", "S", "O", "N", "D"], &["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"], &["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"], &["J", "F", "M", "A", "M", "Y", "Y", "A", "S", "O", "N", "D"]], eras: [&["K.H", "BHAI", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Kafin haihuwar annab", "Bayan haihuwar annab", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["K.H", "BHAI", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_387: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʼaban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʼaban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_388: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/yy GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_90: [&_CalendarData; 6] = [&_CALENDAR_385, &_CALENDAR_386, &_CALENDAR_206, &_CALENDAR_387, &_CALENDAR_388, &_CALENDAR_207];
+
+#[rustfmt::skip]
+static _CALENDAR_389: _CalendarData = _CalendarData { months: [&["ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳", "דצמ׳"], &["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳", "דצמ׳"], &["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["הספירה הבודהיסטית"], &["BE"]], date_patterns: ["d.M.y GGGGG", "d בMMM y G", "d בMMMM y G", "EEEE, d בMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_390: _CalendarData = _CalendarData { months: [&["ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳", "דצמ׳"], &["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳", "דצמ׳"], &["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["לפנה״ס", "לספירה", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["לפני הספירה", "לספירה", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["לפני", "אחריי", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y GGGGG", "d בMMM y G", "d בMMMM y G", "EEEE, d בMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_391: _CalendarData = _CalendarData { months: [&["פרורדין", "ארדיבהשת", "ח׳רדאד", "תיר", "מרדאד", "שהריור", "מהר", "אבאן", "אד׳ר", "די", "בהמן", "אספנד"], &["פרורדין", "ארדיבהשת", "ח׳רדאד", "תיר", "מרדאד", "שהריור", "מהר", "אבאן", "אד׳ר", "די", "בהמן", "אספנד"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["פרורדין", "ארדיבהשת", "ח׳רדאד", "תיר", "מרדאד", "שהריור", "מהר", "אבאן", "אד׳ר", "די", "בהמן", "אספנד"], &["פרורדין", "ארדיבהשת", "ח׳רדאד", "תיר", "מרדאד", "שהריור", "מהר", "אבאן", "אד׳ר", "די", "בהמן", "אספנד"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["הספירה הפרסית"], &["AP"]], date_patterns: ["d.M.y GGGGG", "d בMMM y G", "d בMMMM y G", "EEEE, d בMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_392: _CalendarData = _CalendarData { months: [&["מוחרם", "צפר", "רביע א׳", "רביע ב׳", "ג׳ומאדא א׳", "ג׳ומאדא ב׳", "רג׳ב", "שעבאן", "רמדאן", "שוואל", "ד׳ו אל־קעדה", "ד׳ו אל־חיג׳ה"], &["מוחרם", "צפר", "רביע אל-אוול", "רביע א-ת׳אני", "ג׳ומאדא אל-אולא", "ג׳ומאדא א-ת׳אניה", "רג׳ב", "שעבאן", "רמדאן", "שוואל", "ד׳ו אל־קעדה", "ד׳ו אל־חיג׳ה"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["מוחרם", "צפר", "רביע א׳", "רביע ב׳", "ג׳ומאדא א׳", "ג׳ומאדא ב׳", "רג׳ב", "שעבאן", "רמדאן", "שוואל", "ד׳ו אל־קעדה", "ד׳ו אל־חיג׳ה"], &["מוחרם", "צפר", "רביע אל־אוול", "רביע א־ת׳אני", "ג׳ומאדא אל־אולא", "ג׳ומאדא א־ת׳אניה", "רג׳ב", "שעבאן", "רמדאן", "שוואל", "ד׳ו אל־קעדה", "ד׳ו אל־חיג׳ה"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["הג׳רה"], &["שנת היג׳רה"], &["הג׳רה"]], date_patterns: ["dd/MM/yy GGGGG", "d בMMM y G", "d בMMMM y G", "EEEE, d בMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_393: _CalendarData = _CalendarData { months: [&["תשרי", "חשון", "כסלו", "טבת", "שבט", "אדר א׳", "אדר", "ניסן", "אייר", "סיון", "תמוז", "אב", "אלול", "אדר ב׳"], &["תשרי", "חשוון", "כסלו", "טבת", "שבט", "אדר א׳", "אדר", "ניסן", "אייר", "סיוון", "תמוז", "אב", "אלול", "אדר ב׳"], &["תש׳", "חש׳", "כס׳", "טב׳", "שב׳", "א״א", "אד׳", "ני׳", "אי׳", "סי׳", "תמ׳", "אב", "אל׳", "א״ב"], &["תשרי", "חשון", "כסלו", "טבת", "שבט", "אדר א׳", "אדר", "ניסן", "אייר", "סיון", "תמוז", "אב", "אלול", "אדר ב׳"], &["תשרי", "חשוון", "כסלו", "טבת", "שבט", "אדר א׳", "אדר", "ניסן", "אייר", "סיוון", "תמוז", "אב", "אלול", "אדר ב׳"], &["תש׳", "חש׳", "כס׳", "טב׳", "שב׳", "א״א", "אד׳", "ני׳", "אי׳", "סי׳", "תמ׳", "אב", "אל׳", "א״ב"]], eras: [&["AM"], &["לבריאת העולם"], &["AM"]], date_patterns: ["d בMMMM y", "d בMMMM y", "d בMMMM y", "EEEE, d בMMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_394: _CalendarData = _CalendarData { months: [&["מסקרם", "טקמת", "הדר", "תהסס", "טר", "יכתית", "מגבית", "מיאזיה", "גנבות", "סאנה", "המלה", "נהסה", "פגומן", ""], &["מסקרם", "טקמת", "הדר", "תהסס", "טר", "יכתית", "מגבית", "מיאזיה", "גנבות", "סאנה", "המלה", "נהסה", "פגומן", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["מסקרם", "טקמת", "הדר", "תהסס", "טר", "יכתית", "מגבית", "מיאזיה", "גנבות", "סאנה", "המלה", "נהסה", "פגומן", ""], &["מסקרם", "טקמת", "הדר", "תהסס", "טר", "יכתית", "מגבית", "מיאזיה", "גנבות", "סאנה", "המלה", "נהסה", "פגומן", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "עידן 1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y GGGGG", "d בMMM y G", "d בMMMM y G", "EEEE, d בMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_91: [&_CalendarData; 6] = [&_CALENDAR_389, &_CALENDAR_390, &_CALENDAR_391, &_CALENDAR_392, &_CALENDAR_393, &_CALENDAR_394];
+
+#[rustfmt::skip]
+static _CALENDAR_395: _CalendarData = _CalendarData { months: [&["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["बौद\u{94d}ध स\u{902}वत"], &["बौद\u{94d}ध स\u{902}वत"], &["बौद\u{94d}ध स\u{902}वत"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_396: _CalendarData = _CalendarData { months: [&["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["ईसा-प\u{942}र\u{94d}व", "ईस\u{94d}वी", "म\u{947}जी", "ताईशो", "शोवा", "ह\u{947}ईस\u{947}ई", "र\u{947}इवा"], &["ईसा-प\u{942}र\u{94d}व", "ईसवी सन", "म\u{947}जी", "ताईशो", "शोवा", "ह\u{947}ईस\u{947}ई", "र\u{947}इवा"], &["ईसा-प\u{942}र\u{94d}व", "ईस\u{94d}वी", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_397: _CalendarData = _CalendarData { months: [&["फर\u{94d}वादिन", "ओर\u{94d}दिव\u{947}ह\u{947}स\u{94d}ट", "खोरर\u{94d}दाद", "टिर", "मोरदाद", "शाहरीवर\u{94d}", "म\u{947}हर", "अवन", "अज\u{93c}र", "ड\u{947}", "बहमन", "ईस\u{94d}फन\u{94d}द\u{94d}"], &["फर\u{94d}वादिन", "ओर\u{94d}दिव\u{947}ह\u{947}स\u{94d}ट", "खोरर\u{94d}दाद", "टिर", "मोरदाद", "शाहरीवर\u{94d}", "म\u{947}हर", "अवन", "अज\u{93c}र", "ड\u{947}", "बहमन", "ईस\u{94d}फन\u{94d}द\u{94d}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["फर\u{94d}वादिन", "ओर\u{94d}दिव\u{947}ह\u{947}स\u{94d}ट", "खोरर\u{94d}दाद", "टिर", "मोरदाद", "शाहरीवर\u{94d}", "म\u{947}हर", "अवन", "अज\u{93c}र", "ड\u{947}", "बहमन", "ईस\u{94d}फन\u{94d}द\u{94d}"], &["फर\u{94d}वादिन", "ओर\u{94d}दिव\u{947}ह\u{947}स\u{94d}ट", "खोरर\u{94d}दाद", "टिर", "मोरदाद", "शाहरीवर\u{94d}", "म\u{947}हर", "अवन", "अज\u{93c}र", "ड\u{947}", "बहमन", "ईस\u{94d}फन\u{94d}द\u{94d}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_398: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["म\u{941}हर\u{94d}रम", "सफर", "राबी प\u{94d}रथम", "राबी द\u{94d}वितीय", "ज\u{941}म\u{94d}डा प\u{94d}रथम", "ज\u{941}म\u{94d}डा द\u{94d}वितीय", "रजब", "शावन", "रमजान", "शव\u{94d}व\u{94d}ल", "जिल-क\u{94d}दाह", "जिल\u{94d}-हिज\u{94d}जाह"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["म\u{941}हर\u{94d}रम", "सफर", "राबी प\u{94d}रथम", "राबी द\u{94d}वितीय", "ज\u{941}म\u{94d}डा प\u{94d}रथम", "ज\u{941}म\u{94d}डा द\u{94d}वितीय", "रजब", "शावन", "रमजान", "शव\u{94d}व\u{94d}ल", "जिल-क\u{94d}दाह", "जिल\u{94d}-हिज\u{94d}जाह"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_399: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_400: _CalendarData = _CalendarData { months: [&["मस\u{94d}क\u{947}र\u{947}म", "ट\u{947}क\u{947}म\u{94d}ट", "ह\u{947}दर", "तहसास", "टर", "य\u{947}काटिट", "म\u{947}गाबिट", "मियाज\u{93c}िया", "गनबोट", "स\u{947}न", "हम\u{94d}ल\u{947}", "न\u{947}हास\u{947}", "पाग\u{942}मन", ""], &["मस\u{94d}क\u{947}र\u{947}म", "ट\u{947}क\u{947}म\u{94d}ट", "ह\u{947}दर", "तहसास", "टर", "य\u{947}काटिट", "म\u{947}गाबिट", "मियाज\u{93c}िया", "गनबोट", "स\u{947}न", "हम\u{94d}ल\u{947}", "न\u{947}हास\u{947}", "पाग\u{942}मन", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["मस\u{94d}क\u{947}र\u{947}म", "ट\u{947}क\u{947}म\u{94d}ट", "ह\u{947}दर", "तहसास", "टर", "य\u{947}काटिट", "म\u{947}गाबिट", "मियाज\u{93c}िया", "गनबोट", "स\u{947}न", "हम\u{94d}ल\u{947}", "न\u{947}हास\u{947}", "पाग\u{942}मन", ""], &["मस\u{94d}क\u{947}र\u{947}म", "ट\u{947}क\u{947}म\u{94d}ट", "ह\u{947}दर", "तहसास", "टर", "य\u{947}काटिट", "म\u{947}गाबिट", "मियाज\u{93c}िया", "गनबोट", "स\u{947}न", "हम\u{94d}ल\u{947}", "न\u{947}हास\u{947}", "पाग\u{942}मन", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_92: [&_CalendarData; 6] = [&_CALENDAR_395, &_CALENDAR_396, &_CALENDAR_397, &_CALENDAR_398, &_CALENDAR_399, &_CALENDAR_400];
+
+#[rustfmt::skip]
+static _CALENDAR_401: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_402: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"], &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B", "A", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_403: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_404: _CalendarData = _CalendarData { months: [&["Muh", "Saf", "Rabi 1", "Rabi 2", "Jum 1", "Jum 2", "Rajab", "Shab", "Ram", "Shaw", "Zu Q", "Zu H"], &["Muharram", "Safar", "Rabi al-Awwal", "Rabi as-Saani", "Jumaada al-Awwal", "Jumaada as-Saani", "Rajab", "Shaabaan", "Ramzaan", "Shawwaal", "Zu’l-Qaada", "Zu’l-Hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["Hijri"], &["Hijri"], &["Hijri"]], date_patterns: ["d/M/y/ GGGGG", "dd-MMM-y G", "d MMMM, y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_405: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_93: [&_CalendarData; 6] = [&_CALENDAR_401, &_CALENDAR_402, &_CALENDAR_403, &_CALENDAR_404, &_CALENDAR_183, &_CALENDAR_405];
+
+#[rustfmt::skip]
+static _CALENDAR_406: _CalendarData = _CalendarData { months: [&["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"], &["siječnja", "veljače", "ožujka", "travnja", "svibnja", "lipnja", "srpnja", "kolovoza", "rujna", "listopada", "studenoga", "prosinca"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."], &["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"], &["siječanj", "veljača", "ožujak", "travanj", "svibanj", "lipanj", "srpanj", "kolovoz", "rujan", "listopad", "studeni", "prosinac"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd. MM. y. GGGGG", "d. MMM y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_407: _CalendarData = _CalendarData { months: [&["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"], &["siječnja", "veljače", "ožujka", "travnja", "svibnja", "lipnja", "srpnja", "kolovoza", "rujna", "listopada", "studenoga", "prosinca"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."], &["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"], &["siječanj", "veljača", "ožujak", "travanj", "svibanj", "lipanj", "srpanj", "kolovoz", "rujan", "listopad", "studeni", "prosinac"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."]], eras: [&["pr. Kr.", "po. Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["prije Krista", "poslije Krista", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pr.n.e.", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["d. M. y. GGGGG", "d. M. y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_408: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd. MM. y. GGGGG", "d. MMM y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_409: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12."]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d. M. y. GGGGG", "d. M. y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_410: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd. MM. y. GGGGG", "d. MMM y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_411: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12.", "13.", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1.", "2.", "3.", "4.", "5.", "6.", "7.", "8.", "9.", "10.", "11.", "12.", "13.", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd. MM. y. GGGGG", "d. MMM y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_94: [&_CalendarData; 6] = [&_CALENDAR_406, &_CALENDAR_407, &_CALENDAR_408, &_CALENDAR_409, &_CALENDAR_410, &_CALENDAR_411];
+
+#[rustfmt::skip]
+static _CALENDAR_412: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "měr.", "apr.", "mej.", "jun.", "jul.", "awg.", "sep.", "okt.", "now.", "dec."], &["januara", "februara", "měrca", "apryla", "meje", "junija", "julija", "awgusta", "septembra", "oktobra", "nowembra", "decembra"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "měr", "apr", "mej", "jun", "jul", "awg", "sep", "okt", "now", "dec"], &["januar", "februar", "měrc", "apryl", "meja", "junij", "julij", "awgust", "september", "oktober", "nowember", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_413: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "měr.", "apr.", "mej.", "jun.", "jul.", "awg.", "sep.", "okt.", "now.", "dec."], &["januara", "februara", "měrca", "apryla", "meje", "junija", "julija", "awgusta", "septembra", "oktobra", "nowembra", "decembra"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "měr", "apr", "mej", "jun", "jul", "awg", "sep", "okt", "now", "dec"], &["januar", "februar", "měrc", "apryl", "meja", "junij", "julij", "awgust", "september", "oktober", "nowember", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["př.Chr.n.", "po Chr.n.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["před Chrystowym narodźenjom", "po Chrystowym narodźenju", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["př.Chr.n.", "po Chr.n.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.yy GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_95: [&_CalendarData; 6] = [&_CALENDAR_412, &_CALENDAR_413, &_CALENDAR_163, &_CALENDAR_164, &_CALENDAR_165, &_CALENDAR_166];
+
+#[rustfmt::skip]
+static _CALENDAR_414: _CalendarData = _CalendarData { months: [&["jan.", "febr.", "márc.", "ápr.", "máj.", "jún.", "júl.", "aug.", "szept.", "okt.", "nov.", "dec."], &["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"], &["J", "F", "M", "Á", "M", "J", "J", "A", "Sz", "O", "N", "D"], &["jan.", "febr.", "márc.", "ápr.", "máj.", "jún.", "júl.", "aug.", "szept.", "okt.", "nov.", "dec."], &["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"], &["J", "F", "M", "Á", "M", "J", "J", "A", "Sz", "O", "N", "D"]], eras: [&["BK"], &["BK"], &["BK"]], date_patterns: ["GGGGG y. M. d.", "G y. MMM d.", "G y. MMMM d.", "G y. MMMM d., EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_415: _CalendarData = _CalendarData { months: [&["jan.", "febr.", "márc.", "ápr.", "máj.", "jún.", "júl.", "aug.", "szept.", "okt.", "nov.", "dec."], &["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"], &["J", "F", "M", "Á", "M", "J", "J", "A", "Sz", "O", "N", "D"], &["jan.", "febr.", "márc.", "ápr.", "máj.", "jún.", "júl.", "aug.", "szept.", "okt.", "nov.", "dec."], &["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"], &["J", "F", "M", "Á", "M", "J", "J", "A", "Sz", "O", "N", "D"]], eras: [&["i. e.", "i. sz.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Krisztus előtt", "időszámításunk szerint", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ie.", "isz.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y.MM.dd.", "G y.MM.dd.", "G y. MMMM d.", "G y. MMMM d., EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_416: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y. M. d.", "G y. MMM d.", "G y. MMMM d.", "G y. MMMM d., EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_417: _CalendarData = _CalendarData { months: [&["Moh.", "Saf.", "Réb. 1", "Réb. 2", "Dsem. I", "Dsem. II", "Red.", "Sab.", "Ram.", "Sev.", "Dsül k.", "Dsül h."], &["Moharrem", "Safar", "Rébi el avvel", "Rébi el accher", "Dsemádi el avvel", "Dsemádi el accher", "Redseb", "Sabán", "Ramadán", "Sevvál", "Dsül kade", "Dsül hedse"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Moh.", "Saf.", "Réb. 1", "Réb. 2", "Dsem. I", "Dsem. II", "Red.", "Sab.", "Ram.", "Sev.", "Dsül k.", "Dsül h."], &["Moharrem", "Safar", "Rébi I", "Rébi II", "Dsemádi I", "Dsemádi II", "Redseb", "Sabán", "Ramadán", "Sevvál", "Dsül kade", "Dsül hedse"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["MF"], &["MF"], &["MF"]], date_patterns: ["GGGGG y. M. d.", "G y. MMM d.", "G y. MMMM d.", "G y. MMMM d., EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_418: _CalendarData = _CalendarData { months: [&["Tisri", "Hesván", "Kiszlév", "Tévész", "Svát", "Ádár I", "Ádár", "Niszán", "Ijár", "Sziván", "Tamuz", "Áv", "Elul", "Ádár II"], &["Tisri", "Hesván", "Kiszlév", "Tévész", "Svát", "Ádár I", "Ádár", "Niszán", "Ijár", "Sziván", "Tamuz", "Áv", "Elul", "Ádár II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tisri", "Hesván", "Kiszlév", "Tévész", "Svát", "Ádár I", "Ádár", "Niszán", "Ijár", "Sziván", "Tamuz", "Áv", "Elul", "Ádár II"], &["Tisri", "Hesván", "Kiszlév", "Tévész", "Svát", "Ádár I", "Ádár", "Niszán", "Ijár", "Sziván", "Tamuz", "Áv", "Elul", "Ádár II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["TÉ"], &["TÉ"], &["TÉ"]], date_patterns: ["GGGGG y. M. d.", "G y. MMM d.", "G y. MMMM d.", "G y. MMMM d., EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_419: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y. M. d.", "G y. MMM d.", "G y. MMMM d.", "G y. MMMM d., EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_96: [&_CalendarData; 6] = [&_CALENDAR_414, &_CALENDAR_415, &_CALENDAR_416, &_CALENDAR_417, &_CALENDAR_418, &_CALENDAR_419];
+
+#[rustfmt::skip]
+static _CALENDAR_420: _CalendarData = _CalendarData { months: [&["հնվ", "փտվ", "մրտ", "ապր", "մյս", "հնս", "հլս", "օգս", "սեպ", "հոկ", "նոյ", "դեկ"], &["հունվարի", "փետրվարի", "մարտի", "ապրիլի", "մայիսի", "հունիսի", "հուլիսի", "օգոստոսի", "սեպտեմբերի", "հոկտեմբերի", "նոյեմբերի", "դեկտեմբերի"], &["Հ", "Փ", "Մ", "Ա", "Մ", "Հ", "Հ", "Օ", "Ս", "Հ", "Ն", "Դ"], &["հնվ", "փտվ", "մրտ", "ապր", "մյս", "հնս", "հլս", "օգս", "սեպ", "հոկ", "նոյ", "դեկ"], &["հունվար", "փետրվար", "մարտ", "ապրիլ", "մայիս", "հունիս", "հուլիս", "օգոստոս", "սեպտեմբեր", "հոկտեմբեր", "նոյեմբեր", "դեկտեմբեր"], &["Հ", "Փ", "Մ", "Ա", "Մ", "Հ", "Հ", "Օ", "Ս", "Հ", "Ն", "Դ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y GGGGG", "dd MMM, y թ. G", "dd MMMM, y թ. G", "d MMMM, y թ. G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_421: _CalendarData = _CalendarData { months: [&["հնվ", "փտվ", "մրտ", "ապր", "մյս", "հնս", "հլս", "օգս", "սեպ", "հոկ", "նոյ", "դեկ"], &["հունվարի", "փետրվարի", "մարտի", "ապրիլի", "մայիսի", "հունիսի", "հուլիսի", "օգոստոսի", "սեպտեմբերի", "հոկտեմբերի", "նոյեմբերի", "դեկտեմբերի"], &["Հ", "Փ", "Մ", "Ա", "Մ", "Հ", "Հ", "Օ", "Ս", "Հ", "Ն", "Դ"], &["հնվ", "փտվ", "մրտ", "ապր", "մյս", "հնս", "հլս", "օգս", "սեպ", "հոկ", "նոյ", "դեկ"], &["հունվար", "փետրվար", "մարտ", "ապրիլ", "մայիս", "հունիս", "հուլիս", "օգոստոս", "սեպտեմբեր", "հոկտեմբեր", "նոյեմբեր", "դեկտեմբեր"], &["Հ", "Փ", "Մ", "Ա", "Մ", "Հ", "Հ", "Օ", "Ս", "Հ", "Ն", "Դ"]], eras: [&["մ.թ.ա.", "մ.թ.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Քրիստոսից առաջ", "Քրիստոսից հետո", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["մ.թ.ա.", "մ.թ.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "dd MMM, y թ. G", "dd MMMM, y թ. G", "d MMMM, y թ. G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_422: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "dd MMM, y թ. G", "dd MMMM, y թ. G", "d MMMM, y թ. G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_423: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "dd MMM, y թ. G", "dd MMMM, y թ. G", "d MMMM, y թ. G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_424: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y GGGGG", "dd MMM, y թ. G", "dd MMMM, y թ. G", "d MMMM, y թ. G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_425: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "dd MMM, y թ. G", "dd MMMM, y թ. G", "d MMMM, y թ. G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_97: [&_CalendarData; 6] = [&_CALENDAR_420, &_CALENDAR_421, &_CALENDAR_422, &_CALENDAR_423, &_CALENDAR_424, &_CALENDAR_425];
+
+#[rustfmt::skip]
+static _CALENDAR_426: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"], &["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"], &["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d 'de' MMMM y G", "EEEE 'le' d 'de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_427: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"], &["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"], &["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["a.Chr.", "p.Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ante Christo", "post Christo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.Chr.", "p.Chr.", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d 'de' MMMM y G", "EEEE 'le' d 'de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_428: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d 'de' MMMM y G", "EEEE 'le' d 'de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_429: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d 'de' MMMM y G", "EEEE 'le' d 'de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_430: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d 'de' MMMM y G", "EEEE 'le' d 'de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_431: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d 'de' MMMM y G", "EEEE 'le' d 'de' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_98: [&_CalendarData; 6] = [&_CALENDAR_426, &_CALENDAR_427, &_CALENDAR_428, &_CALENDAR_429, &_CALENDAR_430, &_CALENDAR_431];
+
+#[rustfmt::skip]
+static _CALENDAR_432: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agu", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agu", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["EB"], &["Era Buddhis"], &["EB"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_433: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agu", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agu", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["SM", "M", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Sebelum Masehi", "Masehi", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["SM", "M", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_434: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_435: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. Awal", "Rab. Akhir", "Jum. Awal", "Jum. Akhir", "Raj.", "Sya.", "Ram.", "Syaw.", "Zulka.", "Zulhi."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jumadilawal", "Jumadilakhir", "Rajab", "Syakban", "Ramadan", "Syawal", "Zulkaidah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. Awal", "Rab. Akhir", "Jum. Awal", "Jum. Akhir", "Raj.", "Sya.", "Ram.", "Syaw.", "Zulka.", "Zulhi."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jumadilawal", "Jumadilakhir", "Rajab", "Syakban", "Ramadan", "Syawal", "Zulkaidah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["H"], &["H"], &["H"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_436: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_437: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_99: [&_CalendarData; 6] = [&_CALENDAR_432, &_CALENDAR_433, &_CALENDAR_434, &_CALENDAR_435, &_CALENDAR_436, &_CALENDAR_437];
+
+#[rustfmt::skip]
+static _CALENDAR_438: _CalendarData = _CalendarData { months: [&["jan.", "febr.", "mar.", "apr.", "may", "jun.", "julí", "aug.", "sept.", "oct.", "nov.", "dec."], &["januar", "februar", "marte", "april", "may", "junio", "julí", "august", "septembre", "octobre", "novembre", "decembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "febr.", "mar.", "apr.", "may", "jun.", "julí", "aug.", "sept.", "oct.", "nov.", "dec."], &["januar", "februar", "marte", "april", "may", "junio", "julí", "august", "septembre", "octobre", "novembre", "decembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_439: _CalendarData = _CalendarData { months: [&["jan.", "febr.", "mar.", "apr.", "may", "jun.", "julí", "aug.", "sept.", "oct.", "nov.", "dec."], &["januar", "februar", "marte", "april", "may", "junio", "julí", "august", "septembre", "octobre", "novembre", "decembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "febr.", "mar.", "apr.", "may", "jun.", "julí", "aug.", "sept.", "oct.", "nov.", "dec."], &["januar", "februar", "marte", "april", "may", "junio", "julí", "august", "septembre", "octobre", "novembre", "decembre"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "e.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ante Crist", "era Cristan", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "e.C.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_100: [&_CalendarData; 6] = [&_CALENDAR_438, &_CALENDAR_439, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_440: _CalendarData = _CalendarData { months: [&["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"], &["Jenụwarị", "Febrụwarị", "Maachị", "Epreel", "Mee", "Jun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"], &["J", "F", "M", "E", "M", "J", "J", "Ọ", "S", "Ọ", "N", "D"], &["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"], &["Jenụwarị", "Febrụwarị", "Maachị", "Epreel", "Mee", "Jun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"], &["J", "F", "M", "E", "M", "J", "J", "Ọ", "S", "Ọ", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_441: _CalendarData = _CalendarData { months: [&["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"], &["Jenụwarị", "Febrụwarị", "Maachị", "Epreel", "Mee", "Jun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"], &["J", "F", "M", "E", "M", "J", "J", "Ọ", "S", "Ọ", "N", "D"], &["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"], &["Jenụwarị", "Febrụwarị", "Maachị", "Epreel", "Mee", "Jun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"], &["J", "F", "M", "E", "M", "J", "J", "Ọ", "S", "Ọ", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Tupu Kraist", "Afọ Kraịst", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_101: [&_CalendarData; 6] = [&_CALENDAR_440, &_CALENDAR_441, &_CALENDAR_141, &_CALENDAR_142, &_CALENDAR_143, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_442: _CalendarData = _CalendarData { months: [&["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM dꑍ", "G y MMMM dꑍ", "G y MMMM dꑍ,EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_443: _CalendarData = _CalendarData { months: [&["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["ꋍꆪ", "ꑍꆪ", "ꌕꆪ", "ꇖꆪ", "ꉬꆪ", "ꃘꆪ", "ꏃꆪ", "ꉆꆪ", "ꈬꆪ", "ꊰꆪ", "ꊯꊪꆪ", "ꊰꑋꆪ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ꃅꋊꂿ", "ꃅꋊꊂ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ꃅꋊꂿ", "ꃅꋊꊂ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ꃅꋊꂿ", "ꃅꋊꊂ", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM dꑍ", "G y MMMM dꑍ", "G y MMMM dꑍ,EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_444: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM dꑍ", "G y MMMM dꑍ", "G y MMMM dꑍ,EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_445: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM dꑍ", "G y MMMM dꑍ", "G y MMMM dꑍ,EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_446: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM dꑍ", "G y MMMM dꑍ", "G y MMMM dꑍ,EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_447: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM dꑍ", "G y MMMM dꑍ", "G y MMMM dꑍ,EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_102: [&_CalendarData; 6] = [&_CALENDAR_442, &_CALENDAR_443, &_CALENDAR_444, &_CALENDAR_445, &_CALENDAR_446, &_CALENDAR_447];
+
+#[rustfmt::skip]
+static _CALENDAR_448: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "maí", "jún.", "júl.", "ágú.", "sep.", "okt.", "nóv.", "des."], &["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"], &["J", "F", "M", "A", "M", "J", "J", "Á", "S", "O", "N", "D"], &["jan.", "feb.", "mar.", "apr.", "maí", "jún.", "júl.", "ágú.", "sep.", "okt.", "nóv.", "des."], &["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"], &["J", "F", "M", "A", "M", "J", "J", "Á", "S", "O", "N", "D"]], eras: [&["BD"], &["búddhadagatal"], &["BD"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_449: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "maí", "jún.", "júl.", "ágú.", "sep.", "okt.", "nóv.", "des."], &["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"], &["J", "F", "M", "A", "M", "J", "J", "Á", "S", "O", "N", "D"], &["jan.", "feb.", "mar.", "apr.", "maí", "jún.", "júl.", "ágú.", "sep.", "okt.", "nóv.", "des."], &["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"], &["J", "F", "M", "A", "M", "J", "J", "Á", "S", "O", "N", "D"]], eras: [&["f.Kr.", "e.Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["fyrir Krist", "eftir Krist", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["f.k.", "e.k.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_450: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_451: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-Q.", "dhuʻl-H."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadan", "shawwal", "dhuʻl-Qiʻdah", "dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-Q.", "dhuʻl-H."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadan", "shawwal", "dhuʻl-Qiʻdah", "dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["EH"], &["eftir Hijra"], &["EH"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_452: _CalendarData = _CalendarData { months: [&["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "Nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["Anno Mundi"], &["AM"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_453: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "Tímabil1"], &["ERA0", "Tímabil1"], &["ERA0", "Tímabil1"]], date_patterns: ["d.M.y GGGGG", "d.M.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_103: [&_CalendarData; 6] = [&_CALENDAR_448, &_CALENDAR_449, &_CALENDAR_450, &_CALENDAR_451, &_CALENDAR_452, &_CALENDAR_453];
+
+#[rustfmt::skip]
+static _CALENDAR_454: _CalendarData = _CalendarData { months: [&["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"], &["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"]], eras: [&["EB"], &["EB"], &["EB"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_455: _CalendarData = _CalendarData { months: [&["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"], &["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avanti Cristo", "dopo Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["aC", "dC", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_456: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_457: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_458: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_459: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_104: [&_CalendarData; 6] = [&_CALENDAR_454, &_CALENDAR_455, &_CALENDAR_456, &_CALENDAR_457, &_CALENDAR_458, &_CALENDAR_459];
+
+#[rustfmt::skip]
+static _CALENDAR_460: _CalendarData = _CalendarData { months: [&["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"], &["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"]], eras: [&["EB"], &["EB"], &["EB"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_461: _CalendarData = _CalendarData { months: [&["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"], &["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"], &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"], &["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avanti Cristo", "dopo Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["aC", "dC", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_462: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_463: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_464: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_465: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_105: [&_CalendarData; 6] = [&_CALENDAR_460, &_CALENDAR_461, &_CALENDAR_462, &_CALENDAR_463, &_CALENDAR_464, &_CALENDAR_465];
+
+#[rustfmt::skip]
+static _CALENDAR_466: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["仏暦"], &["BE"]], date_patterns: ["Gy/MM/dd", "Gy/MM/dd", "GGGGy年M月d日", "GGGGy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_467: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["紀元前", "西暦", "明治", "大正", "昭和", "平成", "令和"], &["紀元前", "西暦", "明治", "大正", "昭和", "平成", "令和"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGGy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_468: _CalendarData = _CalendarData { months: [&["ファルヴァルディーン", "オルディーベヘシュト", "ホルダード", "ティール", "モルダード", "シャハリーヴァル", "メフル", "アーバーン", "アーザル", "デイ", "バフマン", "エスファンド"], &["ファルヴァルディーン", "オルディーベヘシュト", "ホルダード", "ティール", "モルダード", "シャハリーヴァル", "メフル", "アーバーン", "アーザル", "デイ", "バフマン", "エスファンド"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ファルヴァルディーン", "オルディーベヘシュト", "ホルダード", "ティール", "モルダード", "シャハリーヴァル", "メフル", "アーバーン", "アーザル", "デイ", "バフマン", "エスファンド"], &["ファルヴァルディーン", "オルディーベヘシュト", "ホルダード", "ティール", "モルダード", "シャハリーヴァル", "メフル", "アーバーン", "アーザル", "デイ", "バフマン", "エスファンド"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGGy/M/d", "GGGGGy/MM/dd", "Gy年M月d日", "Gy年M月d日(EEEE)"] };
+
+#[rustfmt::skip]
+static _CALENDAR_469: _CalendarData = _CalendarData { months: [&["ムハッラム", "サフアル", "ラビー・ウル・アウワル", "ラビー・ウッ・サーニー", "ジュマーダル・アウワル", "ジュマーダッサーニー", "ラジャブ", "シャアバーン", "ラマダーン", "シャウワール", "ズル・カイダ", "ズル・ヒッジャ"], &["ムハッラム", "サフアル", "ラビー・ウル・アウワル", "ラビー・ウッ・サーニー", "ジュマーダル・アウワル", "ジュマーダッサーニー", "ラジャブ", "シャアバーン", "ラマダーン", "シャウワール", "ズル・カイダ", "ズル・ヒッジャ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ムハッラム", "サフアル", "ラビー・ウル・アウワル", "ラビー・ウッ・サーニー", "ジュマーダル・アウワル", "ジュマーダッサーニー", "ラジャブ", "シャアバーン", "ラマダーン", "シャウワール", "ズル・カイダ", "ズル・ヒッジャ"], &["ムハッラム", "サフアル", "ラビー・ウル・アウワル", "ラビー・ウッ・サーニー", "ジュマーダル・アウワル", "ジュマーダッサーニー", "ラジャブ", "シャアバーン", "ラマダーン", "シャウワール", "ズル・カイダ", "ズル・ヒッジャ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["Gy/MM/dd", "Gy/MM/dd", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_470: _CalendarData = _CalendarData { months: [&["ティスレ", "へシボン", "キスレブ", "テベット", "シバット", "アダル I", "アダル", "ニサン", "イヤル", "シバン", "タムズ", "アヴ", "エルル", "アダル II"], &["ティスレ", "へシボン", "キスレブ", "テベット", "シバット", "アダル I", "アダル", "ニサン", "イヤル", "シバン", "タムズ", "アヴ", "エルル", "アダル II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ティスレ", "へシボン", "キスレブ", "テベット", "シバット", "アダル I", "アダル", "ニサン", "イヤル", "シバン", "タムズ", "アヴ", "エルル", "アダル II"], &["ティスレ", "へシボン", "キスレブ", "テベット", "シバット", "アダル I", "アダル", "ニサン", "イヤル", "シバン", "タムズ", "アヴ", "エルル", "アダル II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["Gy/MM/dd", "Gy/MM/dd", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_471: _CalendarData = _CalendarData { months: [&["メスケレム", "テケムト", "ヘダル", "ターサス", "テル", "イェカティト", "メガビト", "ミアジア", "ゲンボト", "セネ", "ハムレ", "ネハッセ", "パグメン", ""], &["メスケレム", "テケムト", "ヘダル", "ターサス", "テル", "イェカティト", "メガビト", "ミアジア", "ゲンボト", "セネ", "ハムレ", "ネハッセ", "パグメン", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["メスケレム", "テケムト", "ヘダル", "ターサス", "テル", "イェカティト", "メガビト", "ミアジア", "ゲンボト", "セネ", "ハムレ", "ネハッセ", "パグメン", ""], &["メスケレム", "テケムト", "ヘダル", "ターサス", "テル", "イェカティト", "メガビト", "ミアジア", "ゲンボト", "セネ", "ハムレ", "ネハッセ", "パグメン", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGGy/M/d", "GGGGGy/MM/dd", "Gy年M月d日", "Gy年M月d日(EEEE)"] };
+
+#[rustfmt::skip]
+static _CALENDARS_106: [&_CalendarData; 6] = [&_CALENDAR_466, &_CALENDAR_467, &_CALENDAR_468, &_CALENDAR_469, &_CALENDAR_470, &_CALENDAR_471];
+
+#[rustfmt::skip]
+static _CALENDAR_472: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agt", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agt", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_473: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agt", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Apr", "Mei", "Jun", "Jul", "Agt", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli", "Agustus", "September", "Oktober", "November", "Desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["SM", "M", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Sakdurunge Masehi", "Masehi", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["SM", "M", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_474: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_475: _CalendarData = _CalendarData { months: [&["Sur.", "Sap.", "Mul.", "B. Mul.", "Jum. Aw.", "Jum. Ak.", "Rej.", "Ruw.", "Pso.", "Shaw.", "Slo.", "Bsar."], &["Sura", "Sapar", "Mulud", "Bakda Mulud", "Jumadilawal", "Jumadilakir", "Rejeb", "Ruwah", "Pasa", "Sawal", "Selo", "Besar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Sur.", "Sap.", "Mul.", "B. Mul.", "Jum. Aw.", "Jum. Ak.", "Rej.", "Ruw.", "Pso.", "Shaw.", "Slo.", "Bsar."], &["Sura", "Sapar", "Mulud", "Bakda Mulud", "Jumadilawal", "Jumadilakir", "Rejeb", "Ruwah", "Pasa", "Sawal", "Selo", "Besar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_476: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_477: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_107: [&_CalendarData; 6] = [&_CALENDAR_472, &_CALENDAR_473, &_CALENDAR_474, &_CALENDAR_475, &_CALENDAR_476, &_CALENDAR_477];
+
+#[rustfmt::skip]
+static _CALENDAR_478: _CalendarData = _CalendarData { months: [&["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"], &["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"], &["ი", "თ", "მ", "ა", "მ", "ი", "ი", "ა", "ს", "ო", "ნ", "დ"], &["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"], &["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"], &["ი", "თ", "მ", "ა", "მ", "ი", "ი", "ა", "ს", "ო", "ნ", "დ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, dd MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_479: _CalendarData = _CalendarData { months: [&["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"], &["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"], &["ი", "თ", "მ", "ა", "მ", "ი", "ი", "ა", "ს", "ო", "ნ", "დ"], &["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"], &["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"], &["ი", "თ", "მ", "ა", "მ", "ი", "ი", "ა", "ს", "ო", "ნ", "დ"]], eras: [&["ძვ. წ.", "ახ. წ.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ძველი წელთაღრიცხვით", "ახალი წელთაღრიცხვით", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ძვ. წ.", "ახ. წ.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, dd MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_480: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, dd MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_481: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, dd MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_482: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, dd MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_483: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, dd MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_108: [&_CalendarData; 6] = [&_CALENDAR_478, &_CALENDAR_479, &_CALENDAR_480, &_CALENDAR_481, &_CALENDAR_482, &_CALENDAR_483];
+
+#[rustfmt::skip]
+static _CALENDAR_484: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nuv", "Diz"], &["Janeru", "Febreru", "Marsu", "Abril", "Maiu", "Junhu", "Julhu", "Agostu", "Setenbru", "Otubru", "Nuvenbru", "Dizenbru"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nuv", "Diz"], &["Janeru", "Febreru", "Marsu", "Abril", "Maiu", "Junhu", "Julhu", "Agostu", "Setenbru", "Otubru", "Nuvenbru", "Dizenbru"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d 'di' MMMM 'di' y G", "EEEE, d 'di' MMMM 'di' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_485: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nuv", "Diz"], &["Janeru", "Febreru", "Marsu", "Abril", "Maiu", "Junhu", "Julhu", "Agostu", "Setenbru", "Otubru", "Nuvenbru", "Dizenbru"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nuv", "Diz"], &["Janeru", "Febreru", "Marsu", "Abril", "Maiu", "Junhu", "Julhu", "Agostu", "Setenbru", "Otubru", "Nuvenbru", "Dizenbru"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["AK", "DK", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antis di Kristu", "dispos di Kristu", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["AK", "DK", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d 'di' MMMM 'di' y G", "EEEE, d 'di' MMMM 'di' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_486: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d 'di' MMMM 'di' y G", "EEEE, d 'di' MMMM 'di' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_487: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d 'di' MMMM 'di' y G", "EEEE, d 'di' MMMM 'di' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_488: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d 'di' MMMM 'di' y G", "EEEE, d 'di' MMMM 'di' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_489: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d 'di' MMMM 'di' y G", "EEEE, d 'di' MMMM 'di' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_109: [&_CalendarData; 6] = [&_CALENDAR_484, &_CALENDAR_485, &_CALENDAR_486, &_CALENDAR_487, &_CALENDAR_488, &_CALENDAR_489];
+
+#[rustfmt::skip]
+static _CALENDAR_490: _CalendarData = _CalendarData { months: [&["1Ky.", "2Ky.", "3Ky.", "4Ky.", "5Ky.", "6Ky.", "7Ky.", "8Ky.", "9Ky.", "10Ky.", "11Ky.", "12Ky."], &["1-Kysã", "2-Kysã", "3-Kysã", "4-Kysã", "5-Kysã", "6-Kysã", "7-Kysã", "8-Kysã", "9-Kysã", "10-Kysã", "11-Kysã", "12-Kysã"], &["1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K", "10K", "11K", "12K"], &["1Ky.", "2Ky.", "3Ky.", "4Ky.", "5Ky.", "6Ky.", "7Ky.", "8Ky.", "9Ky.", "10Ky.", "11Ky.", "12Ky."], &["1-Kysã", "2-Kysã", "3-Kysã", "4-Kysã", "5-Kysã", "6-Kysã", "7-Kysã", "8-Kysã", "9-Kysã", "10-Kysã", "11-Kysã", "12-Kysã"], &["1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K", "10K", "11K", "12K"]], eras: [&["BE"], &["EB"], &["EB"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d 'ne' MMMM, y G", "EEEE, d 'ne' MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_491: _CalendarData = _CalendarData { months: [&["1Ky.", "2Ky.", "3Ky.", "4Ky.", "5Ky.", "6Ky.", "7Ky.", "8Ky.", "9Ky.", "10Ky.", "11Ky.", "12Ky."], &["1-Kysã", "2-Kysã", "3-Kysã", "4-Kysã", "5-Kysã", "6-Kysã", "7-Kysã", "8-Kysã", "9-Kysã", "10-Kysã", "11-Kysã", "12-Kysã"], &["1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K", "10K", "11K", "12K"], &["1Ky.", "2Ky.", "3Ky.", "4Ky.", "5Ky.", "6Ky.", "7Ky.", "8Ky.", "9Ky.", "10Ky.", "11Ky.", "12Ky."], &["1-Kysã", "2-Kysã", "3-Kysã", "4-Kysã", "5-Kysã", "6-Kysã", "7-Kysã", "8-Kysã", "9-Kysã", "10-Kysã", "11-Kysã", "12-Kysã"], &["1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K", "10K", "11K", "12K"]], eras: [&["C.j.", "C.kk.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Cristo jo", "Cristo kar kỹ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["C.j.", "C.kk.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y G", "d 'ne' MMMM, y G", "EEEE, d 'ne' MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_492: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d 'ne' MMMM, y G", "EEEE, d 'ne' MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_493: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d 'ne' MMMM, y G", "EEEE, d 'ne' MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_494: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d 'ne' MMMM, y G", "EEEE, d 'ne' MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_495: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM, y G", "d 'ne' MMMM, y G", "EEEE, d 'ne' MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_110: [&_CalendarData; 6] = [&_CALENDAR_490, &_CALENDAR_491, &_CALENDAR_492, &_CALENDAR_493, &_CALENDAR_494, &_CALENDAR_495];
+
+#[rustfmt::skip]
+static _CALENDAR_496: _CalendarData = _CalendarData { months: [&["қаң.", "ақп.", "нау.", "сәу.", "мам.", "мау.", "шіл.", "там.", "қыр.", "қаз.", "қар.", "жел."], &["қаңтар", "ақпан", "наурыз", "сәуір", "мамыр", "маусым", "шілде", "тамыз", "қыркүйек", "қазан", "қараша", "желтоқсан"], &["Қ", "А", "Н", "С", "М", "М", "Ш", "Т", "Қ", "Қ", "Қ", "Ж"], &["қаң.", "ақп.", "нау.", "сәу.", "мам.", "мау.", "шіл.", "там.", "қыр.", "қаз.", "қар.", "жел."], &["Қаңтар", "Ақпан", "Наурыз", "Сәуір", "Мамыр", "Маусым", "Шілде", "Тамыз", "Қыркүйек", "Қазан", "Қараша", "Желтоқсан"], &["Қ", "А", "Н", "С", "М", "М", "Ш", "Т", "Қ", "Қ", "Қ", "Ж"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG dd.MM.y", "G dd.MM.y", "G y\u{202f}ж. d MMMM", "G y\u{202f}ж. d MMMM, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_497: _CalendarData = _CalendarData { months: [&["қаң.", "ақп.", "нау.", "сәу.", "мам.", "мау.", "шіл.", "там.", "қыр.", "қаз.", "қар.", "жел."], &["қаңтар", "ақпан", "наурыз", "сәуір", "мамыр", "маусым", "шілде", "тамыз", "қыркүйек", "қазан", "қараша", "желтоқсан"], &["Қ", "А", "Н", "С", "М", "М", "Ш", "Т", "Қ", "Қ", "Қ", "Ж"], &["қаң.", "ақп.", "нау.", "сәу.", "мам.", "мау.", "шіл.", "там.", "қыр.", "қаз.", "қар.", "жел."], &["Қаңтар", "Ақпан", "Наурыз", "Сәуір", "Мамыр", "Маусым", "Шілде", "Тамыз", "Қыркүйек", "Қазан", "Қараша", "Желтоқсан"], &["Қ", "А", "Н", "С", "М", "М", "Ш", "Т", "Қ", "Қ", "Қ", "Ж"]], eras: [&["б.з.д.", "б.з.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Біздің заманымызға дейін", "біздің заманымыз", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["б.з.д.", "б.з.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG dd.MM.y", "G dd.MM.y", "G y\u{202f}ж. d MMMM", "G y\u{202f}ж. d MMMM, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_498: _CalendarData = _CalendarData { months: [&["Фарвардин", "Ордибехешт", "Хордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Дей", "Бахман", "Эсфанд"], &["Фарвардин", "Ордибехешт", "Хордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Дей", "Бахман", "Эсфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Фарвардин", "Ордибехешт", "Хордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Дей", "Бахман", "Эсфанд"], &["Фарвардин", "Ордибехешт", "Хордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Дей", "Бахман", "Эсфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG dd.MM.y", "G dd.MM.y", "G y\u{202f}ж. d MMMM", "G y\u{202f}ж. d MMMM, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_499: _CalendarData = _CalendarData { months: [&["Мұх.", "Саф.", "Раб. I", "Раб. II", "Жұм. I", "Жұм. II", "Ереж.", "Шағ.", "Рам.", "Шәу.", "Зұлқ.", "Зұлх."], &["Мұхаррам", "Сафар", "Рабиғул әууәл", "Рабиғул ахир", "Жұмадәл аууәл", "Жұмадәл ахир", "Ережеп", "Шағбан", "Рамазан", "Шәууал", "Зұлқағда", "Зұлхижжа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Мұх.", "Саф.", "Раб. І", "Раб. ІІ", "Жұм. I", "Жұм. II", "Ереж.", "Шағ.", "Рам.", "Шәу.", "Зұлқ.", "Зұлх."], &["Мұхаррам", "Сафар", "Рабиғул әууәл", "Рабиғул ахир", "Жұмадәл аууәл", "Жұмадәл ахир", "Ережеп", "Шағбан", "Рамазан", "Шәууал", "Зұлқағда", "Зұлхижжа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ХЖ"], &["ХЖ"], &["ХЖ"]], date_patterns: ["GGGGG dd.MM.y", "G dd.MM.y", "G y\u{202f}ж. d MMMM", "G y\u{202f}ж. d MMMM, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_500: _CalendarData = _CalendarData { months: [&["Тишрей", "Хешван", "Кислев", "Тевет", "Шват", "Адар I", "Адар", "Нисан", "Ияр", "Сиван", "Тамуз", "Ав", "Элул", "Адар II"], &["Тишрей", "Хешван", "Кислев", "Тевет", "Шват", "Адар I", "Адар", "Нисан", "Ияр", "Сиван", "Тамуз", "Ав", "Элул", "Адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Тишрей", "Хешван", "Кислев", "Тевет", "Шват", "Адар I", "Адар", "Нисан", "Ияр", "Сиван", "Тамуз", "Ав", "Элул", "Адар II"], &["Тишрей", "Хешван", "Кислев", "Тевет", "Шват", "Адар I", "Адар", "Нисан", "Ияр", "Сиван", "Тамуз", "Ав", "Элул", "Адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG dd.MM.y", "G dd.MM.y", "G y\u{202f}ж. d MMMM", "G y\u{202f}ж. d MMMM, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_501: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG dd.MM.y", "G dd.MM.y", "G y\u{202f}ж. d MMMM", "G y\u{202f}ж. d MMMM, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_111: [&_CalendarData; 6] = [&_CALENDAR_496, &_CALENDAR_497, &_CALENDAR_498, &_CALENDAR_499, &_CALENDAR_500, &_CALENDAR_501];
+
+#[rustfmt::skip]
+static _CALENDAR_502: _CalendarData = _CalendarData { months: [&["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["ម", "ក", "ម", "ម", "ឧ", "ម", "ក", "ស", "ក", "ត", "វ", "ធ"], &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["ម", "ក", "ម", "ម", "ឧ", "ម", "ក", "ស", "ក", "ត", "វ", "ធ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_503: _CalendarData = _CalendarData { months: [&["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["ម", "ក", "ម", "ម", "ឧ", "ម", "ក", "ស", "ក", "ត", "វ", "ធ"], &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"], &["ម", "ក", "ម", "ម", "ឧ", "ម", "ក", "ស", "ក", "ត", "វ", "ធ"]], eras: [&["ម\u{17bb}ន គ.ស.", "គ.ស.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ម\u{17bb}ន\u{200b}គ\u{17d2}រ\u{17b7}ស\u{17d2}តសករាជ", "គ\u{17d2}រ\u{17b7}ស\u{17d2}តសករាជ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ម\u{17bb}ន គ.ស.", "គ.ស.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_504: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_505: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_506: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_507: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_112: [&_CalendarData; 6] = [&_CALENDAR_502, &_CALENDAR_503, &_CALENDAR_504, &_CALENDAR_505, &_CALENDAR_506, &_CALENDAR_507];
+
+#[rustfmt::skip]
+static _CALENDAR_508: _CalendarData = _CalendarData { months: [&["ಜನ", "ಫ\u{cc6}ಬ\u{ccd}ರ", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗ", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂ", "ಅಕ\u{ccd}ಟ\u{ccb}", "ನವ\u{cc6}ಂ", "ಡ\u{cbf}ಸ\u{cc6}ಂ"], &["ಜನವರ\u{cbf}", "ಫ\u{cc6}ಬ\u{ccd}ರವರ\u{cbf}", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}ಲ\u{ccd}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗಸ\u{ccd}ಟ\u{ccd}", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂಬರ\u{ccd}", "ಅಕ\u{ccd}ಟ\u{ccb}ಬರ\u{ccd}", "ನವ\u{cc6}ಂಬರ\u{ccd}", "ಡ\u{cbf}ಸ\u{cc6}ಂಬರ\u{ccd}"], &["ಜ", "ಫ\u{cc6}", "ಮಾ", "ಏ", "ಮ\u{cc7}", "ಜ\u{cc2}", "ಜು", "ಆ", "ಸ\u{cc6}", "ಅ", "ನ", "ಡ\u{cbf}"], &["ಜನ", "ಫ\u{cc6}ಬ\u{ccd}ರ", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗ", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂ", "ಅಕ\u{ccd}ಟ\u{ccb}", "ನವ\u{cc6}ಂ", "ಡ\u{cbf}ಸ\u{cc6}ಂ"], &["ಜನವರ\u{cbf}", "ಫ\u{cc6}ಬ\u{ccd}ರವರ\u{cbf}", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}ಲ\u{ccd}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗಸ\u{ccd}ಟ\u{ccd}", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂಬರ\u{ccd}", "ಅಕ\u{ccd}ಟ\u{ccb}ಬರ\u{ccd}", "ನವ\u{cc6}ಂಬರ\u{ccd}", "ಡ\u{cbf}ಸ\u{cc6}ಂಬರ\u{ccd}"], &["ಜ", "ಫ\u{cc6}", "ಮಾ", "ಏ", "ಮ\u{cc7}", "ಜ\u{cc2}", "ಜು", "ಆ", "ಸ\u{cc6}", "ಅ", "ನ", "ಡ\u{cbf}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_509: _CalendarData = _CalendarData { months: [&["ಜನ", "ಫ\u{cc6}ಬ\u{ccd}ರ", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗ", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂ", "ಅಕ\u{ccd}ಟ\u{ccb}", "ನವ\u{cc6}ಂ", "ಡ\u{cbf}ಸ\u{cc6}ಂ"], &["ಜನವರ\u{cbf}", "ಫ\u{cc6}ಬ\u{ccd}ರವರ\u{cbf}", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}ಲ\u{ccd}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗಸ\u{ccd}ಟ\u{ccd}", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂಬರ\u{ccd}", "ಅಕ\u{ccd}ಟ\u{ccb}ಬರ\u{ccd}", "ನವ\u{cc6}ಂಬರ\u{ccd}", "ಡ\u{cbf}ಸ\u{cc6}ಂಬರ\u{ccd}"], &["ಜ", "ಫ\u{cc6}", "ಮಾ", "ಏ", "ಮ\u{cc7}", "ಜ\u{cc2}", "ಜು", "ಆ", "ಸ\u{cc6}", "ಅ", "ನ", "ಡ\u{cbf}"], &["ಜನ", "ಫ\u{cc6}ಬ\u{ccd}ರ", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗ", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂ", "ಅಕ\u{ccd}ಟ\u{ccb}", "ನವ\u{cc6}ಂ", "ಡ\u{cbf}ಸ\u{cc6}ಂ"], &["ಜನವರ\u{cbf}", "ಫ\u{cc6}ಬ\u{ccd}ರವರ\u{cbf}", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}ಲ\u{ccd}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗಸ\u{ccd}ಟ\u{ccd}", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂಬರ\u{ccd}", "ಅಕ\u{ccd}ಟ\u{ccb}ಬರ\u{ccd}", "ನವ\u{cc6}ಂಬರ\u{ccd}", "ಡ\u{cbf}ಸ\u{cc6}ಂಬರ\u{ccd}"], &["ಜ", "ಫ\u{cc6}", "ಮಾ", "ಏ", "ಮ\u{cc7}", "ಜ\u{cc2}", "ಜು", "ಆ", "ಸ\u{cc6}", "ಅ", "ನ", "ಡ\u{cbf}"]], eras: [&["ಕ\u{ccd}ರ\u{cbf}.ಪ\u{cc2}", "ಕ\u{ccd}ರ\u{cbf}.ಶ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ಕ\u{ccd}ರ\u{cbf}ಸ\u{ccd}ತ ಪ\u{cc2}ರ\u{ccd}ವ", "ಕ\u{ccd}ರ\u{cbf}ಸ\u{ccd}ತ ಶಕ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ಕ\u{ccd}ರ\u{cbf}.ಪ\u{cc2}", "ಕ\u{ccd}ರ\u{cbf}.ಶ", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_510: _CalendarData = _CalendarData { months: [&["ಫರ\u{ccd}ವರ\u{ccd}ದ\u{cbf}ನ\u{ccd}", "ಓರ\u{ccd}ದ\u{cbf}ಬ\u{cc6}ಹ\u{cc6}ಶ\u{ccd}ಟ\u{ccd}", "ಖ\u{ccb}ರ\u{ccd}ಡಾದ\u{ccd}", "ಟ\u{cbf}ರ\u{ccd}", "ಮ\u{cca}ರ\u{ccd}ದಾದ\u{ccd}", "ಶಹರ\u{cbf}ವಾರ\u{ccd}", "ಮ\u{cc6}ಹ\u{ccd}ರ\u{ccd}", "ಅಬನ\u{ccd}", "ಅಝರ\u{ccd}", "ಡ\u{cc7}", "ಬಹ\u{ccd}ಮನ\u{ccd}", "ಎಸ\u{ccd}ಫಾಂಡ\u{ccd}"], &["ಫರ\u{ccd}ವರ\u{ccd}ದ\u{cbf}ನ\u{ccd}", "ಓರ\u{ccd}ದ\u{cbf}ಬ\u{cc6}ಹ\u{cc6}ಶ\u{ccd}ಟ\u{ccd}", "ಖ\u{ccb}ರ\u{ccd}ಡಾದ\u{ccd}", "ಟ\u{cbf}ರ\u{ccd}", "ಮ\u{cca}ರ\u{ccd}ದಾದ\u{ccd}", "ಶಹರ\u{cbf}ವಾರ\u{ccd}", "ಮ\u{cc6}ಹ\u{ccd}ರ\u{ccd}", "ಅಬನ\u{ccd}", "ಅಝರ\u{ccd}", "ಡ\u{cc7}", "ಬಹ\u{ccd}ಮನ\u{ccd}", "ಎಸ\u{ccd}ಫಾಂಡ\u{ccd}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ಫರ\u{ccd}ವರ\u{ccd}ದ\u{cbf}ನ\u{ccd}", "ಓರ\u{ccd}ದ\u{cbf}ಬ\u{cc6}ಹ\u{cc6}ಶ\u{ccd}ಟ\u{ccd}", "ಖ\u{ccb}ರ\u{ccd}ಡಾದ\u{ccd}", "ಟ\u{cbf}ರ\u{ccd}", "ಮ\u{cca}ರ\u{ccd}ದಾದ\u{ccd}", "ಶಹರ\u{cbf}ವಾರ\u{ccd}", "ಮ\u{cc6}ಹ\u{ccd}ರ\u{ccd}", "ಅಬನ\u{ccd}", "ಅಝರ\u{ccd}", "ಡ\u{cc7}", "ಬಹ\u{ccd}ಮನ\u{ccd}", "ಎಸ\u{ccd}ಫಾಂಡ\u{ccd}"], &["ಫರ\u{ccd}ವರ\u{ccd}ದ\u{cbf}ನ\u{ccd}", "ಓರ\u{ccd}ದ\u{cbf}ಬ\u{cc6}ಹ\u{cc6}ಶ\u{ccd}ಟ\u{ccd}", "ಖ\u{ccb}ರ\u{ccd}ಡಾದ\u{ccd}", "ಟ\u{cbf}ರ\u{ccd}", "ಮ\u{cca}ರ\u{ccd}ದಾದ\u{ccd}", "ಶಹರ\u{cbf}ವಾರ\u{ccd}", "ಮ\u{cc6}ಹ\u{ccd}ರ\u{ccd}", "ಅಬನ\u{ccd}", "ಅಝರ\u{ccd}", "ಡ\u{cc7}", "ಬಹ\u{ccd}ಮನ\u{ccd}", "ಎಸ\u{ccd}ಫಾಂಡ\u{ccd}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_511: _CalendarData = _CalendarData { months: [&["ಮುಹ\u{ccd}.", "ಸಫಾ.", "ರಬ\u{cbf}‘ I", "ರಬ\u{cbf}‘ II", "ಜುಮ\u{ccd}. I", "ಜುಮ\u{ccd}. II", "ರಜ\u{ccd}.", "ಶ.", "ರಮ\u{ccd}.", "ಶವ\u{ccd}.", "ಧು‘ಲ\u{ccd}-ಕ\u{cbf}.", "ಧು‘ಲ\u{ccd}-ಹ."], &["ಮುಹರಮ\u{ccd}", "ಸಫಾರ\u{ccd}", "ರಬ\u{cbf}‘ I", "ರಬ\u{cbf}‘ II", "ಜುಮಾದಾ I", "ಜುಮಾದಾ II", "ರಜಬ\u{ccd}", "ಶ’ಬಾನ\u{ccd}", "ರಮದಾನ\u{ccd}", "ಶವ\u{ccd}ವಾಲ\u{ccd}", "ಧು‘ಲ\u{ccd}-ಕ\u{cbf}‘ಡಾಹ\u{ccd}", "ಧು‘ಲ\u{ccd}-ಹ\u{cbf}ಜಾಹ\u{ccd}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ಮುಹ\u{ccd}.", "ಸಫಾ.", "ರಬ\u{cbf}‘ I", "ರಬ\u{cbf}‘ II", "ಜುಮ\u{ccd}. I", "ಜುಮ\u{ccd}. II", "ರಜ\u{ccd}.", "ಶ.", "ರಮ\u{ccd}.", "ಶವ\u{ccd}.", "ಧು‘ಲ\u{ccd}-ಕ\u{cbf}.", "ಧು‘ಲ\u{ccd}-ಹ."], &["ಮುಹರಮ\u{ccd}", "ಸಫಾರ\u{ccd}", "ರಬ\u{cbf}‘ I", "ರಬ\u{cbf}‘ II", "ಜುಮಾದಾ I", "ಜುಮಾದಾ II", "ರಜಬ\u{ccd}", "ಶ’ಬಾನ\u{ccd}", "ರಮದಾನ\u{ccd}", "ಶವ\u{ccd}ವಾಲ\u{ccd}", "ಧು‘ಲ\u{ccd}-ಕ\u{cbf}‘ಡಾಹ\u{ccd}", "ಧು‘ಲ\u{ccd}-ಹ\u{cbf}ಜಾಹ\u{ccd}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_512: _CalendarData = _CalendarData { months: [&["ಟ\u{cbf}ಶ\u{ccd}ರ\u{cbf}", "ಹ\u{cc6}ಶ\u{ccd}\u{200c}ವಾನ\u{ccd}", "ಕ\u{cbf}ಸ\u{ccd}ಲ\u{cc6}ವ\u{ccd}", "ಟ\u{cc6}ವ\u{cc6}ಟ\u{ccd}", "ಶ\u{cc6}ವತ\u{ccd}", "ಅದಾರ\u{ccd} I", "ಅದಾರ\u{ccd}", "ನ\u{cbf}ಸಾನ\u{ccd}", "ಇಯರ\u{ccd}", "ಸ\u{cbf}ವನ\u{ccd}", "ತಮುಜ\u{ccd}", "ಎವ\u{cbf}", "ಎಲುಲ\u{ccd}", "ಅದಾರ\u{ccd} II"], &["ಟ\u{cbf}ಶ\u{ccd}ರ\u{cbf}", "ಹ\u{cc6}ಶ\u{ccd}\u{200c}ವಾನ\u{ccd}", "ಕ\u{cbf}ಸ\u{ccd}ಲ\u{cc6}ವ\u{ccd}", "ಟ\u{cc6}ವ\u{cc6}ಟ\u{ccd}", "ಶ\u{cc6}ವತ\u{ccd}", "ಅದಾರ\u{ccd} I", "ಅದಾರ\u{ccd}", "ನ\u{cbf}ಸಾನ\u{ccd}", "ಇಯರ\u{ccd}", "ಸ\u{cbf}ವನ\u{ccd}", "ತಮುಜ\u{ccd}", "ಎವ\u{cbf}", "ಎಲುಲ\u{ccd}", "ಅದಾರ\u{ccd} II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ಟ\u{cbf}ಶ\u{ccd}ರ\u{cbf}", "ಹ\u{cc6}ಶ\u{ccd}\u{200c}ವಾನ\u{ccd}", "ಕ\u{cbf}ಸ\u{ccd}ಲ\u{cc6}ವ\u{ccd}", "ಟ\u{cc6}ವ\u{cc6}ಟ\u{ccd}", "ಶ\u{cc6}ವತ\u{ccd}", "ಅದಾರ\u{ccd} I", "ಅದಾರ\u{ccd}", "ನ\u{cbf}ಸಾನ\u{ccd}", "ಇಯರ\u{ccd}", "ಸ\u{cbf}ವನ\u{ccd}", "ತಮುಜ\u{ccd}", "ಎವ\u{cbf}", "ಎಲುಲ\u{ccd}", "ಅದಾರ\u{ccd} II"], &["ಟ\u{cbf}ಶ\u{ccd}ರ\u{cbf}", "ಹ\u{cc6}ಶ\u{ccd}\u{200c}ವಾನ\u{ccd}", "ಕ\u{cbf}ಸ\u{ccd}ಲ\u{cc6}ವ\u{ccd}", "ಟ\u{cc6}ವ\u{cc6}ಟ\u{ccd}", "ಶ\u{cc6}ವತ\u{ccd}", "ಅದಾರ\u{ccd} I", "ಅದಾರ\u{ccd}", "ನ\u{cbf}ಸಾನ\u{ccd}", "ಇಯರ\u{ccd}", "ಸ\u{cbf}ವನ\u{ccd}", "ತಮುಜ\u{ccd}", "ಎವ\u{cbf}", "ಎಲುಲ\u{ccd}", "ಅದಾರ\u{ccd} II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_513: _CalendarData = _CalendarData { months: [&["ಮ\u{cc6}ಸ\u{ccd}ಕರ\u{cc6}ಮ\u{ccd}", "ಟ\u{cc6}ಕ\u{cc6}ಮ\u{ccd}ಟ\u{ccd}", "ಹ\u{cc6}ದರ\u{ccd}", "ತ\u{cc6}ಹ\u{ccd}\u{200c}ಸಾಸ\u{ccd}", "ಟ\u{cc6}ರ\u{ccd}", "ಯ\u{cc6}ಕಟ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc6}ಗಾಬ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc8}ಝ\u{cbf}ಯಾ", "ಜ\u{cc6}ನ\u{ccd}\u{200c}ಬಾಟ\u{ccd}", "ಸ\u{cc6}ನ\u{cc6}", "ಹ\u{ccd}ಯಾಮ\u{ccd}ಲ\u{cc6}", "ನ\u{cbf}ಹಾಸ\u{ccd}ಸ\u{cc6}", "ಪ\u{cc6}ಗ\u{ccd}ಯುಮ\u{cc6}ನ\u{ccd}", ""], &["ಮ\u{cc6}ಸ\u{ccd}ಕರ\u{cc6}ಮ\u{ccd}", "ಟ\u{cc6}ಕ\u{cc6}ಮ\u{ccd}ಟ\u{ccd}", "ಹ\u{cc6}ದರ\u{ccd}", "ತ\u{cc6}ಹ\u{ccd}\u{200c}ಸಾಸ\u{ccd}", "ಟ\u{cc6}ರ\u{ccd}", "ಯ\u{cc6}ಕಟ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc6}ಗಾಬ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc8}ಝ\u{cbf}ಯಾ", "ಜ\u{cc6}ನ\u{ccd}\u{200c}ಬಾಟ\u{ccd}", "ಸ\u{cc6}ನ\u{cc6}", "ಹ\u{ccd}ಯಾಮ\u{ccd}ಲ\u{cc6}", "ನ\u{cbf}ಹಾಸ\u{ccd}ಸ\u{cc6}", "ಪ\u{cc6}ಗ\u{ccd}ಯುಮ\u{cc6}ನ\u{ccd}", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["ಮ\u{cc6}ಸ\u{ccd}ಕರ\u{cc6}ಮ\u{ccd}", "ಟ\u{cc6}ಕ\u{cc6}ಮ\u{ccd}ಟ\u{ccd}", "ಹ\u{cc6}ದರ\u{ccd}", "ತ\u{cc6}ಹ\u{ccd}\u{200c}ಸಾಸ\u{ccd}", "ಟ\u{cc6}ರ\u{ccd}", "ಯ\u{cc6}ಕಟ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc6}ಗಾಬ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc8}ಝ\u{cbf}ಯಾ", "ಜ\u{cc6}ನ\u{ccd}\u{200c}ಬಾಟ\u{ccd}", "ಸ\u{cc6}ನ\u{cc6}", "ಹ\u{ccd}ಯಾಮ\u{ccd}ಲ\u{cc6}", "ನ\u{cbf}ಹಾಸ\u{ccd}ಸ\u{cc6}", "ಪ\u{cc6}ಗ\u{ccd}ಯುಮ\u{cc6}ನ\u{ccd}", ""], &["ಮ\u{cc6}ಸ\u{ccd}ಕರ\u{cc6}ಮ\u{ccd}", "ಟ\u{cc6}ಕ\u{cc6}ಮ\u{ccd}ಟ\u{ccd}", "ಹ\u{cc6}ದರ\u{ccd}", "ತ\u{cc6}ಹ\u{ccd}\u{200c}ಸಾಸ\u{ccd}", "ಟ\u{cc6}ರ\u{ccd}", "ಯ\u{cc6}ಕಟ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc6}ಗಾಬ\u{cbf}ಟ\u{ccd}", "ಮ\u{cc8}ಝ\u{cbf}ಯಾ", "ಜ\u{cc6}ನ\u{ccd}\u{200c}ಬಾಟ\u{ccd}", "ಸ\u{cc6}ನ\u{cc6}", "ಹ\u{ccd}ಯಾಮ\u{ccd}ಲ\u{cc6}", "ನ\u{cbf}ಹಾಸ\u{ccd}ಸ\u{cc6}", "ಪ\u{cc6}ಗ\u{ccd}ಯುಮ\u{cc6}ನ\u{ccd}", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_113: [&_CalendarData; 6] = [&_CALENDAR_508, &_CALENDAR_509, &_CALENDAR_510, &_CALENDAR_511, &_CALENDAR_512, &_CALENDAR_513];
+
+#[rustfmt::skip]
+static _CALENDAR_514: _CalendarData = _CalendarData { months: [&["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"]], eras: [&["불기"], &["불기"], &["불기"]], date_patterns: ["G y. M. d.", "G y. M. d.", "G y년 M월 d일", "G y년 M월 d일 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_515: _CalendarData = _CalendarData { months: [&["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"], &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"]], eras: [&["BC", "AD", "메이지", "다이쇼", "쇼와", "헤이세이", "레이와"], &["기원전", "서기", "메이지", "다이쇼", "쇼와", "헤이세이", "레이와"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["G y. M. d.", "G y. M. d.", "G y년 M월 d일", "G y년 M월 d일 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_516: _CalendarData = _CalendarData { months: [&["화르바딘", "오르디베헤쉬트", "호르다드", "티르", "모르다드", "샤흐리바르", "메흐르", "아반", "아자르", "다이", "바흐만", "에스판드"], &["화르바딘", "오르디베헤쉬트", "호르다드", "티르", "모르다드", "샤흐리바르", "메흐르", "아반", "아자르", "다이", "바흐만", "에스판드"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["화르바딘", "오르디베헤쉬트", "호르다드", "티르", "모르다드", "샤흐리바르", "메흐르", "아반", "아자르", "다이", "바흐만", "에스판드"], &["화르바딘", "오르디베헤쉬트", "호르다드", "티르", "모르다드", "샤흐리바르", "메흐르", "아반", "아자르", "다이", "바흐만", "에스판드"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["G y. M. d.", "G y. M. d.", "G y년 M월 d일", "G y년 M월 d일 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_517: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["무하람", "사파르", "라비 알 아왈", "라비 알 쎄니", "주마다 알 아왈", "주마다 알 쎄니", "라잡", "쉐아반", "라마단", "쉐왈", "듀 알 까다", "듀 알 히자"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["무하람", "사파르", "라비 알 아왈", "라비 알 쎄니", "주마다 알 아왈", "주마다 알 쎄니", "라잡", "쉐아반", "라마단", "쉐왈", "듀 알 까다", "듀 알 히자"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["히즈라력"], &["AH"]], date_patterns: ["G y. M. d.", "G y. M. d.", "G y년 M월 d일", "G y년 M월 d일 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_518: _CalendarData = _CalendarData { months: [&["디스리", "말케스", "기슬르", "데벳", "스밧", "아달 1", "아달", "닛산", "이야르", "시완", "담무르", "압", "엘룰", "아달 2"], &["디스리", "말케스", "기슬르", "데벳", "스밧", "아달 1", "아달", "닛산", "이야르", "시완", "담무르", "압", "엘룰", "아달 2"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["디스리월", "말케스월", "기슬르월", "데벳월", "스밧월", "아달월 1", "아달월", "닛산월", "이야르월", "시완월", "담무르월", "압월", "엘룰월", "아달월 2"], &["디스리월", "말케스월", "기슬르월", "데벳월", "스밧월", "아달월 1", "아달월", "닛산월", "이야르월", "시완월", "담무르월", "압월", "엘룰월", "아달월 2"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["유대력"], &["AM"]], date_patterns: ["G y. M. d.", "G y. M. d.", "G y년 M월 d일", "G y년 M월 d일 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_519: _CalendarData = _CalendarData { months: [&["매스캐램", "테켐트", "헤다르", "타흐사스", "테르", "얘카티트", "매가비트", "미야지야", "겐보트", "새네", "함레", "내하세", "파구맨", ""], &["매스캐램", "테켐트", "헤다르", "타흐사스", "테르", "얘카티트", "매가비트", "미야지야", "겐보트", "새네", "함레", "내하세", "파구맨", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["매스캐램", "테켐트", "헤다르", "타흐사스", "테르", "얘카티트", "매가비트", "미야지야", "겐보트", "새네", "함레", "내하세", "파구맨", ""], &["매스캐램", "테켐트", "헤다르", "타흐사스", "테르", "얘카티트", "매가비트", "미야지야", "겐보트", "새네", "함레", "내하세", "파구맨", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G y. M. d.", "G y. M. d.", "G y년 M월 d일", "G y년 M월 d일 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_114: [&_CalendarData; 6] = [&_CALENDAR_514, &_CALENDAR_515, &_CALENDAR_516, &_CALENDAR_517, &_CALENDAR_518, &_CALENDAR_519];
+
+#[rustfmt::skip]
+static _CALENDAR_520: _CalendarData = _CalendarData { months: [&["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रील", "म\u{947}", "ज\u{942}न", "ज\u{941}लय", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रील", "म\u{947}", "ज\u{942}न", "ज\u{941}लय", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}री", "म\u{947}", "ज\u{942}न", "ज\u{941}ल", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नो", "डिस\u{947}"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रील", "म\u{947}", "ज\u{942}न", "ज\u{941}लय", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d-M-y GGGGG", "d-M-y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_521: _CalendarData = _CalendarData { months: [&["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रील", "म\u{947}", "ज\u{942}न", "ज\u{941}लय", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रील", "म\u{947}", "ज\u{942}न", "ज\u{941}लय", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}री", "म\u{947}", "ज\u{942}न", "ज\u{941}ल", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नो", "डिस\u{947}"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रील", "म\u{947}", "ज\u{942}न", "ज\u{941}लय", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["क\u{94d}रिस\u{94d}तप\u{942}र\u{94d}व", "क\u{94d}रि.श.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["क\u{94d}रिस\u{94d}तप\u{942}र\u{94d}व", "क\u{94d}रिस\u{94d}तशक", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["क\u{94d}रि.आ.", "क\u{94d}रि.श.", "M", "T", "S", "H", "R"]], date_patterns: ["d-M-y GGGGG", "d-M-y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_522: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d-M-y GGGGG", "d-M-y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_523: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d-M-y GGGGG", "d-M-y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_524: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d-M-y GGGGG", "d-M-y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_525: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d-M-y GGGGG", "d-M-y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_115: [&_CalendarData; 6] = [&_CALENDAR_520, &_CALENDAR_521, &_CALENDAR_522, &_CALENDAR_523, &_CALENDAR_524, &_CALENDAR_525];
+
+#[rustfmt::skip]
+static _CALENDAR_526: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nov", "Dez"], &["Janer", "Febrer", "Mars", "Abril", "Mai", "Jun", "Julai", "Agost", "Setembr", "Otubr", "Novembr", "Dezembr"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nov", "Dez"], &["Janer", "Febrer", "Mars", "Abril", "Mai", "Jun", "Julai", "Agost", "Setembr", "Otubr", "Novembr", "Dezembr"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d-M-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_527: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nov", "Dez"], &["Janer", "Febrer", "Mars", "Abril", "Mai", "Jun", "Julai", "Agost", "Setembr", "Otubr", "Novembr", "Dezembr"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Otu", "Nov", "Dez"], &["Janer", "Febrer", "Mars", "Abril", "Mai", "Jun", "Julai", "Agost", "Setembr", "Otubr", "Novembr", "Dezembr"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["KA", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Krista Adim", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["K", "A", "M", "T", "S", "H", "R"]], date_patterns: ["d-M-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_528: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d-M-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_529: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d-M-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_530: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d-M-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_531: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d-M-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_116: [&_CalendarData; 6] = [&_CALENDAR_526, &_CALENDAR_527, &_CALENDAR_528, &_CALENDAR_529, &_CALENDAR_530, &_CALENDAR_531];
+
+#[rustfmt::skip]
+static _CALENDAR_532: _CalendarData = _CalendarData { months: [&["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "ج\u{64f}ل\u{64e}ے", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"], &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "ج\u{64f}ل\u{64e}ے", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "د\u{64e}س\u{64e}مب\u{64e}ر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "س", "ا", "ن"], &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"], &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "س", "ا", "ن"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_533: _CalendarData = _CalendarData { months: [&["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "ج\u{64f}ل\u{64e}ے", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"], &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "ج\u{64f}ل\u{64e}ے", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "د\u{64e}س\u{64e}مب\u{64e}ر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "س", "ا", "ن"], &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"], &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "مئی", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "س", "ا", "ن"]], eras: [&["بی سی", "اے ڈی", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["قب\u{655}ل مسی\u{656}ح", "اینو ڈومنی", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["بی سی", "اے ڈی", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_117: [&_CalendarData; 6] = [&_CALENDAR_532, &_CALENDAR_533, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_534: _CalendarData = _CalendarData { months: [&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सत\u{941}\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{942}मबर", "दसमबर"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सतमबर", "अक\u{94d}त\u{942}बर", "नवमबर", "दसमबर"], &["ज", "फ\u{93c}", "म", "अ", "म", "ज", "ज", "अ", "स", "ओ", "न", "द"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सत\u{941}\u{902}बर", "अकत\u{941}म\u{94d}बर", "नव\u{942}मबर", "दसमबर"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सत\u{941}\u{902}बर", "अकत\u{941}म\u{94d}बर", "नव\u{942}मबर", "दस\u{942}मबर"], &["ज", "फ\u{93c}", "म", "अ", "म", "ज", "ज", "अ", "स", "ओ", "न", "द"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_535: _CalendarData = _CalendarData { months: [&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सत\u{941}\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{942}मबर", "दसमबर"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सतमबर", "अक\u{94d}त\u{942}बर", "नवमबर", "दसमबर"], &["ज", "फ\u{93c}", "म", "अ", "म", "ज", "ज", "अ", "स", "ओ", "न", "द"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सत\u{941}\u{902}बर", "अकत\u{941}म\u{94d}बर", "नव\u{942}मबर", "दसमबर"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सत\u{941}\u{902}बर", "अकत\u{941}म\u{94d}बर", "नव\u{942}मबर", "दस\u{942}मबर"], &["ज", "फ\u{93c}", "म", "अ", "म", "ज", "ज", "अ", "स", "ओ", "न", "द"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा ब\u{94d}रो\u{902}ठ", "ईस\u{94d}वी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_118: [&_CalendarData; 6] = [&_CALENDAR_534, &_CALENDAR_535, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_536: _CalendarData = _CalendarData { months: [&["rbn", "sbt", "adr", "nsn", "gln", "hzr", "trm", "tbx", "îln", "cot", "mjd", "brf"], &["rêbendan", "sibat", "adar", "nîsan", "gulan", "hezîran", "tîrmeh", "tebax", "îlon", "cotmeh", "mijdar", "berfanbar"], &["R", "S", "A", "N", "G", "H", "T", "T", "Î", "C", "M", "B"], &["rbn", "sbt", "adr", "nsn", "gln", "hzr", "trm", "tbx", "îln", "cot", "mjd", "brf"], &["rêbendan", "sibat", "adar", "nîsan", "gulan", "hezîran", "tîrmeh", "tebax", "îlon", "cotmeh", "mijdar", "berfanbar"], &["R", "S", "A", "N", "G", "H", "T", "T", "Î", "C", "M", "B"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG d.MM.y", "G dê MMM'a' y'an'", "G dê MMMM'a' y'an'", "G dê MMMM'a' y'an' EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_537: _CalendarData = _CalendarData { months: [&["rbn", "sbt", "adr", "nsn", "gln", "hzr", "trm", "tbx", "îln", "cot", "mjd", "brf"], &["rêbendan", "sibat", "adar", "nîsan", "gulan", "hezîran", "tîrmeh", "tebax", "îlon", "cotmeh", "mijdar", "berfanbar"], &["R", "S", "A", "N", "G", "H", "T", "T", "Î", "C", "M", "B"], &["rbn", "sbt", "adr", "nsn", "gln", "hzr", "trm", "tbx", "îln", "cot", "mjd", "brf"], &["rêbendan", "sibat", "adar", "nîsan", "gulan", "hezîran", "tîrmeh", "tebax", "îlon", "cotmeh", "mijdar", "berfanbar"], &["R", "S", "A", "N", "G", "H", "T", "T", "Î", "C", "M", "B"]], eras: [&["BZ", "PZ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["berî zayînê", "piştî zayînê", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BZ", "PZ", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG d.MM.y", "G dê MMM'a' y'an'", "G dê MMMM'a' y'an'", "G dê MMMM'a' y'an' EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_538: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG d.MM.y", "G dê MMM'a' y'an'", "G dê MMMM'a' y'an'", "G dê MMMM'a' y'an' EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_539: _CalendarData = _CalendarData { months: [&["muh.", "sef.", "reb. Iem", "reb. IIyem", "cmz. Iem", "cmz. IIyem", "rcb.", "şbn.", "rmz.", "şwl.", "zqd.", "zhc."], &["muherem", "sefer", "rebîʿulewel", "rebîʿulaxer", "cemazîyelewel", "cemazîyelaxer", "receb", "şeʿban", "remezan", "şewal", "zîlqeʿde", "zilhece"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "sef.", "reb. Iem", "reb. IIyem", "cmz. Iem", "cmz. IIyem", "rcb.", "şbn.", "rmz.", "şwl.", "zqd.", "zhc."], &["muherem", "sefer", "rebîʿulewel", "rebîʿulaxer", "cemazîyelewel", "cemazîyelaxer", "receb", "şeʿban", "remezan", "şewal", "zîlqeʿde", "zilhece"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["Hicrî"], &["Hicrî"], &["Hicrî"]], date_patterns: ["GGGGG d.MM.y", "G dê MMM'a' y'an'", "G dê MMMM'a' y'an'", "G dê MMMM'a' y'an' EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_540: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG d.MM.y", "G dê MMM'a' y'an'", "G dê MMMM'a' y'an'", "G dê MMMM'a' y'an' EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_541: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG d.MM.y", "G dê MMM'a' y'an'", "G dê MMMM'a' y'an'", "G dê MMMM'a' y'an' EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_119: [&_CalendarData; 6] = [&_CALENDAR_536, &_CALENDAR_537, &_CALENDAR_538, &_CALENDAR_539, &_CALENDAR_540, &_CALENDAR_541];
+
+#[rustfmt::skip]
+static _CALENDAR_542: _CalendarData = _CalendarData { months: [&["pusu", "maha", "pagu", "hire", "bese", "jaṭṭa", "aasaḍi", "srabĩ", "bado", "dasara", "divi", "pande"], &["pusu lenju", "maha lenju", "pagu lenju", "hire lenju", "bese lenju", "jaṭṭa lenju", "aasaḍi lenju", "srabĩ lenju", "bado lenju", "dasara lenju", "divi lenju", "pande lenju"], &["pu", "ma", "pa", "hi", "be", "ja", "aa", "sra", "b", "da", "di", "pa"], &["pusu", "maha", "pagu", "hire", "bese", "jaṭṭa", "aasaḍi", "srabĩ", "bado", "dasara", "divi", "pande"], &["pusu lenju", "maha lenju", "pagu lenju", "hire lenju", "bese lenju", "jaṭṭa lenju", "aasaḍi lenju", "srabĩ lenju", "bado lenju", "dasara lenju", "divi lenju", "pande lenju"], &["pu", "ma", "pa", "hi", "be", "ja", "aa", "sra", "b", "da", "di", "pa"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_543: _CalendarData = _CalendarData { months: [&["pusu", "maha", "pagu", "hire", "bese", "jaṭṭa", "aasaḍi", "srabĩ", "bado", "dasara", "divi", "pande"], &["pusu lenju", "maha lenju", "pagu lenju", "hire lenju", "bese lenju", "jaṭṭa lenju", "aasaḍi lenju", "srabĩ lenju", "bado lenju", "dasara lenju", "divi lenju", "pande lenju"], &["pu", "ma", "pa", "hi", "be", "ja", "aa", "sra", "b", "da", "di", "pa"], &["pusu", "maha", "pagu", "hire", "bese", "jaṭṭa", "aasaḍi", "srabĩ", "bado", "dasara", "divi", "pande"], &["pusu lenju", "maha lenju", "pagu lenju", "hire lenju", "bese lenju", "jaṭṭa lenju", "aasaḍi lenju", "srabĩ lenju", "bado lenju", "dasara lenju", "divi lenju", "pande lenju"], &["pu", "ma", "pa", "hi", "be", "ja", "aa", "sra", "b", "da", "di", "pa"]], eras: [&["bc", "ad", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["krisṭ purb nki", "krisṭabd", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["bc", "ad", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_544: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_545: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_546: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_547: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_120: [&_CalendarData; 6] = [&_CALENDAR_542, &_CALENDAR_543, &_CALENDAR_544, &_CALENDAR_545, &_CALENDAR_546, &_CALENDAR_547];
+
+#[rustfmt::skip]
+static _CALENDAR_548: _CalendarData = _CalendarData { months: [&["प\u{941}स\u{941}", "माहा", "पाग\u{941}", "हिर\u{94d}र\u{947}", "ब\u{947}स\u{947}", "जाट\u{94d}टा", "आसाड\u{93c}ी", "स\u{94d}राबा\u{901}", "बा\u{945}दो", "दासारा", "दिवी", "पान\u{94d}ड\u{947}"], &["प\u{941}स\u{941} ल\u{947}ञ\u{94d}ज\u{941}", "माहाका ल\u{947}ञ\u{94d}ज\u{941}", "पाग\u{941}णी ल\u{947}ञ\u{94d}ज\u{941}", "हिर\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "ब\u{947}स\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "जाटा ल\u{947}ञ\u{94d}ज\u{941}", "आसाड\u{93c}ी ल\u{947}ञ\u{94d}ज\u{941}", "स\u{94d}राबा\u{901} ल\u{947}ञ\u{94d}ज\u{941}", "बोदो ल\u{947}ञ\u{94d}ज\u{941}", "दसारा ल\u{947}ञ\u{94d}ज\u{941}", "दिवी ल\u{947}ञ\u{94d}ज\u{941}", "पान\u{94d}ड\u{947} ल\u{947}ञ\u{94d}ज\u{941}"], &["प\u{941}", "मा", "पा", "हि", "ब\u{947}", "जा", "आ", "स\u{94d}रा", "बा\u{945}", "दा", "दि", "पा"], &["प\u{941}स\u{941}", "माहा", "पाग\u{941}", "हिर\u{94d}र\u{947}", "ब\u{947}स\u{947}", "जाट\u{94d}टा", "आसाड\u{93c}ी", "स\u{94d}राबा\u{901}", "बा\u{945}दो", "दासारा", "दिवी", "पान\u{94d}ड\u{947}"], &["प\u{941}स\u{941} ल\u{947}ञ\u{94d}ज\u{941}", "माहाका ल\u{947}ञ\u{94d}ज\u{941}", "पाग\u{941}णी ल\u{947}ञ\u{94d}ज\u{941}", "हिर\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "ब\u{947}स\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "जाटा ल\u{947}ञ\u{94d}ज\u{941}", "आसाड\u{93c}ी ल\u{947}ञ\u{94d}ज\u{941}", "स\u{94d}राबा\u{901} ल\u{947}ञ\u{94d}ज\u{941}", "बोदो ल\u{947}ञ\u{94d}ज\u{941}", "दसारा ल\u{947}ञ\u{94d}ज\u{941}", "दिवी ल\u{947}ञ\u{94d}ज\u{941}", "पान\u{94d}ड\u{947} ल\u{947}ञ\u{94d}ज\u{941}"], &["प\u{941}", "मा", "पा", "हि", "ब\u{947}", "जा", "आ", "स\u{94d}रा", "बा\u{945}", "दा", "दि", "पा"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_549: _CalendarData = _CalendarData { months: [&["प\u{941}स\u{941}", "माहा", "पाग\u{941}", "हिर\u{94d}र\u{947}", "ब\u{947}स\u{947}", "जाट\u{94d}टा", "आसाड\u{93c}ी", "स\u{94d}राबा\u{901}", "बा\u{945}दो", "दासारा", "दिवी", "पान\u{94d}ड\u{947}"], &["प\u{941}स\u{941} ल\u{947}ञ\u{94d}ज\u{941}", "माहाका ल\u{947}ञ\u{94d}ज\u{941}", "पाग\u{941}णी ल\u{947}ञ\u{94d}ज\u{941}", "हिर\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "ब\u{947}स\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "जाटा ल\u{947}ञ\u{94d}ज\u{941}", "आसाड\u{93c}ी ल\u{947}ञ\u{94d}ज\u{941}", "स\u{94d}राबा\u{901} ल\u{947}ञ\u{94d}ज\u{941}", "बोदो ल\u{947}ञ\u{94d}ज\u{941}", "दसारा ल\u{947}ञ\u{94d}ज\u{941}", "दिवी ल\u{947}ञ\u{94d}ज\u{941}", "पान\u{94d}ड\u{947} ल\u{947}ञ\u{94d}ज\u{941}"], &["प\u{941}", "मा", "पा", "हि", "ब\u{947}", "जा", "आ", "स\u{94d}रा", "बा\u{945}", "दा", "दि", "पा"], &["प\u{941}स\u{941}", "माहा", "पाग\u{941}", "हिर\u{94d}र\u{947}", "ब\u{947}स\u{947}", "जाट\u{94d}टा", "आसाड\u{93c}ी", "स\u{94d}राबा\u{901}", "बा\u{945}दो", "दासारा", "दिवी", "पान\u{94d}ड\u{947}"], &["प\u{941}स\u{941} ल\u{947}ञ\u{94d}ज\u{941}", "माहाका ल\u{947}ञ\u{94d}ज\u{941}", "पाग\u{941}णी ल\u{947}ञ\u{94d}ज\u{941}", "हिर\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "ब\u{947}स\u{947} ल\u{947}ञ\u{94d}ज\u{941}", "जाटा ल\u{947}ञ\u{94d}ज\u{941}", "आसाड\u{93c}ी ल\u{947}ञ\u{94d}ज\u{941}", "स\u{94d}राबा\u{901} ल\u{947}ञ\u{94d}ज\u{941}", "बोदो ल\u{947}ञ\u{94d}ज\u{941}", "दसारा ल\u{947}ञ\u{94d}ज\u{941}", "दिवी ल\u{947}ञ\u{94d}ज\u{941}", "पान\u{94d}ड\u{947} ल\u{947}ञ\u{94d}ज\u{941}"], &["प\u{941}", "मा", "पा", "हि", "ब\u{947}", "जा", "आ", "स\u{94d}रा", "बा\u{945}", "दा", "दि", "पा"]], eras: [&["बिसि", "ए-डि", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["बिफोर क\u{94d}राइस\u{94d}ट", "अन\u{94d}नो डोमिनी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["बिसि", "ए-डि", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_121: [&_CalendarData; 6] = [&_CALENDAR_548, &_CALENDAR_549, &_CALENDAR_544, &_CALENDAR_545, &_CALENDAR_546, &_CALENDAR_547];
+
+#[rustfmt::skip]
+static _CALENDAR_550: _CalendarData = _CalendarData { months: [&["ପ\u{b41}ସ\u{b41}", "ମ\u{b3e}ହ\u{b3e}", "ପ\u{b3e}ଗ\u{b41}", "ହ\u{b3f}ରେ", "ବେସେ", "ଜ\u{b3e}ଟ\u{b3e}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01}", "ବଦ", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e}", "ଦ\u{b3f}ୱ\u{b3f}", "ପ\u{b3e}ଣ\u{b4d}ଡେ"], &["ପ\u{b41}ସ\u{b41} ଲେଞ\u{b4d}ଜ\u{b41}", "ମ\u{b3e}ହ\u{b3e}କ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଗ\u{b41}ଣ\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ହ\u{b3f}ରେ ଲେଞ\u{b4d}ଜ\u{b41}", "ବେସେ ଲେଞ\u{b4d}ଜ\u{b41}", "ଜ\u{b3e}ଟ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01} ଲେଞ\u{b4d}ଜ\u{b41}", "ବଦ ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3f}ୱ\u{b3f}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଣ\u{b4d}ଡେ ଲେଞ\u{b4d}ଜ\u{b41}"], &["ପ\u{b41}", "ମ\u{b3e}", "ପ\u{b3e}", "ହ\u{b3f}", "ବେ", "ଜ\u{b3e}", "ଆ", "ସ\u{b4d}ର\u{b3e}", "ବ", "ଦ\u{b3e}", "ଦ\u{b3f}", "ପ\u{b3e}"], &["ପ\u{b41}ସ\u{b41}", "ମ\u{b3e}ହ\u{b3e}", "ପ\u{b3e}ଗ\u{b41}", "ହ\u{b3f}ରେ", "ବେସେ", "ଜ\u{b3e}ଟ\u{b3e}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01}", "ବଦ", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e}", "ଦ\u{b3f}ୱ\u{b3f}", "ପ\u{b3e}ଣ\u{b4d}ଡେ"], &["ପ\u{b41}ସ\u{b41} ଲେଞ\u{b4d}ଜ\u{b41}", "ମ\u{b3e}ହ\u{b3e}କ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଗ\u{b41}ଣ\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ହ\u{b3f}ରେ ଲେଞ\u{b4d}ଜ\u{b41}", "ବେସେ ଲେଞ\u{b4d}ଜ\u{b41}", "ଜ\u{b3e}ଟ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01} ଲେଞ\u{b4d}ଜ\u{b41}", "ବଦ ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3f}ୱ\u{b3f}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଣ\u{b4d}ଡେ ଲେଞ\u{b4d}ଜ\u{b41}"], &["ପ\u{b41}", "ମ\u{b3e}", "ପ\u{b3e}", "ହ\u{b3f}", "ବେ", "ଜ\u{b3e}", "ଆ", "ସ\u{b4d}ର\u{b3e}", "ବ", "ଦ\u{b3e}", "ଦ\u{b3f}", "ପ\u{b3e}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_551: _CalendarData = _CalendarData { months: [&["ପ\u{b41}ସ\u{b41}", "ମ\u{b3e}ହ\u{b3e}", "ପ\u{b3e}ଗ\u{b41}", "ହ\u{b3f}ରେ", "ବେସେ", "ଜ\u{b3e}ଟ\u{b3e}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01}", "ବଦ", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e}", "ଦ\u{b3f}ୱ\u{b3f}", "ପ\u{b3e}ଣ\u{b4d}ଡେ"], &["ପ\u{b41}ସ\u{b41} ଲେଞ\u{b4d}ଜ\u{b41}", "ମ\u{b3e}ହ\u{b3e}କ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଗ\u{b41}ଣ\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ହ\u{b3f}ରେ ଲେଞ\u{b4d}ଜ\u{b41}", "ବେସେ ଲେଞ\u{b4d}ଜ\u{b41}", "ଜ\u{b3e}ଟ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01} ଲେଞ\u{b4d}ଜ\u{b41}", "ବଦ ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3f}ୱ\u{b3f}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଣ\u{b4d}ଡେ ଲେଞ\u{b4d}ଜ\u{b41}"], &["ପ\u{b41}", "ମ\u{b3e}", "ପ\u{b3e}", "ହ\u{b3f}", "ବେ", "ଜ\u{b3e}", "ଆ", "ସ\u{b4d}ର\u{b3e}", "ବ", "ଦ\u{b3e}", "ଦ\u{b3f}", "ପ\u{b3e}"], &["ପ\u{b41}ସ\u{b41}", "ମ\u{b3e}ହ\u{b3e}", "ପ\u{b3e}ଗ\u{b41}", "ହ\u{b3f}ରେ", "ବେସେ", "ଜ\u{b3e}ଟ\u{b3e}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01}", "ବଦ", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e}", "ଦ\u{b3f}ୱ\u{b3f}", "ପ\u{b3e}ଣ\u{b4d}ଡେ"], &["ପ\u{b41}ସ\u{b41} ଲେଞ\u{b4d}ଜ\u{b41}", "ମ\u{b3e}ହ\u{b3e}କ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଗ\u{b41}ଣ\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ହ\u{b3f}ରେ ଲେଞ\u{b4d}ଜ\u{b41}", "ବେସେ ଲେଞ\u{b4d}ଜ\u{b41}", "ଜ\u{b3e}ଟ\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଆସ\u{b3e}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ସ\u{b4d}ର\u{b3e}ବ\u{b3e}\u{b01} ଲେଞ\u{b4d}ଜ\u{b41}", "ବଦ ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3e}ସ\u{b3e}ର\u{b3e} ଲେଞ\u{b4d}ଜ\u{b41}", "ଦ\u{b3f}ୱ\u{b3f}ଡ\u{b3c}\u{b3f} ଲେଞ\u{b4d}ଜ\u{b41}", "ପ\u{b3e}ଣ\u{b4d}ଡେ ଲେଞ\u{b4d}ଜ\u{b41}"], &["ପ\u{b41}", "ମ\u{b3e}", "ପ\u{b3e}", "ହ\u{b3f}", "ବେ", "ଜ\u{b3e}", "ଆ", "ସ\u{b4d}ର\u{b3e}", "ବ", "ଦ\u{b3e}", "ଦ\u{b3f}", "ପ\u{b3e}"]], eras: [&["ବ\u{b3f}ସ\u{b3f}", "ଏଡ\u{b3f}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ବ\u{b3f}ଫୋର କ\u{b4d}ର\u{b3e}ଇଷ\u{b4d}ଟ", "ଆନ\u{b4d}ନ\u{b3e} ଡୋମ\u{b3f}ନ\u{b3f}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ବ\u{b3f}ସ\u{b3f}", "ଏଡ\u{b3f}", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_122: [&_CalendarData; 6] = [&_CALENDAR_550, &_CALENDAR_551, &_CALENDAR_544, &_CALENDAR_545, &_CALENDAR_546, &_CALENDAR_547];
+
+#[rustfmt::skip]
+static _CALENDAR_552: _CalendarData = _CalendarData { months: [&["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}", "గు", "హ\u{c3f}", "బ\u{c46}", "ల", "ర\u{c3e}", "బ\u{c3e}", "బ\u{c3e}", "అ", "ద\u{c3f}", "ప\u{c3e}", "పు"], &["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}", "గు", "హ\u{c3f}", "బ\u{c46}", "ల", "ర\u{c3e}", "బ\u{c3e}", "బ\u{c3e}", "అ", "ద\u{c3f}", "ప\u{c3e}", "పు"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_553: _CalendarData = _CalendarData { months: [&["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}", "గు", "హ\u{c3f}", "బ\u{c46}", "ల", "ర\u{c3e}", "బ\u{c3e}", "బ\u{c3e}", "అ", "ద\u{c3f}", "ప\u{c3e}", "పు"], &["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}గ", "గుండు", "హ\u{c3f}ర\u{c46}ఇ", "బ\u{c46}స\u{c46}క\u{c3f}", "లండ\u{c3f}", "ర\u{c3e}త", "బ\u{c3e}న\u{c4d}దప\u{c3e}ణ\u{c3e}", "బ\u{c3e}ర\u{c4d}స\u{c3f}", "అస\u{c4d}ర", "ద\u{c3f}వ\u{c46}డ\u{c3f}", "ప\u{c3e}ండు", "పుసు"], &["మ\u{c3e}", "గు", "హ\u{c3f}", "బ\u{c46}", "ల", "ర\u{c3e}", "బ\u{c3e}", "బ\u{c3e}", "అ", "ద\u{c3f}", "ప\u{c3e}", "పు"]], eras: [&["క\u{c4d}ర\u{c40}పూ", "క\u{c4d}ర\u{c40}శ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["క\u{c4d}ర\u{c40}న\u{c4d}తు వూర\u{c4d}వం", "క\u{c4d}ర\u{c40}స\u{c4d}తు సకం", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["క\u{c4d}ర\u{c40}పూ", "క\u{c4d}ర\u{c40}శ", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_123: [&_CalendarData; 6] = [&_CALENDAR_552, &_CALENDAR_553, &_CALENDAR_544, &_CALENDAR_545, &_CALENDAR_546, &_CALENDAR_547];
+
+#[rustfmt::skip]
+static _CALENDAR_554: _CalendarData = _CalendarData { months: [&["янв.", "фев.", "мар.", "апр.", "май", "июн.", "июл.", "авг.", "сен.", "окт.", "ноя.", "дек."], &["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], &["Январь", "Февраль", "Март", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d-MMMM G y-ж.", "EEEE, G d-MMMM y-ж."] };
+
+#[rustfmt::skip]
+static _CALENDAR_555: _CalendarData = _CalendarData { months: [&["янв.", "фев.", "мар.", "апр.", "май", "июн.", "июл.", "авг.", "сен.", "окт.", "ноя.", "дек."], &["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], &["Январь", "Февраль", "Март", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["б.з.ч.", "б.з.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["биздин заманга чейин", "биздин заман", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["б.з.ч.", "б.з.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d-MMMM G y-ж.", "EEEE, G d-MMMM y-ж."] };
+
+#[rustfmt::skip]
+static _CALENDAR_556: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d-MMMM G y-ж.", "EEEE, G d-MMMM y-ж."] };
+
+#[rustfmt::skip]
+static _CALENDAR_557: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d-MMMM G y-ж.", "EEEE, G d-MMMM y-ж."] };
+
+#[rustfmt::skip]
+static _CALENDAR_558: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d-MMMM G y-ж.", "EEEE, G d-MMMM y-ж."] };
+
+#[rustfmt::skip]
+static _CALENDAR_559: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d-MMMM G y-ж.", "EEEE, G d-MMMM y-ж."] };
+
+#[rustfmt::skip]
+static _CALENDARS_124: [&_CalendarData; 6] = [&_CALENDAR_554, &_CALENDAR_555, &_CALENDAR_556, &_CALENDAR_557, &_CALENDAR_558, &_CALENDAR_559];
+
+#[rustfmt::skip]
+static _CALENDAR_560: _CalendarData = _CalendarData { months: [&["Jan.", "Feb.", "Mäe.", "Abr.", "Mee", "Juni", "Juli", "Aug.", "Sep.", "Okt.", "Nov.", "Dez."], &["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mäe", "Abr", "Mee", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], &["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_561: _CalendarData = _CalendarData { months: [&["Jan.", "Feb.", "Mäe.", "Abr.", "Mee", "Juni", "Juli", "Aug.", "Sep.", "Okt.", "Nov.", "Dez."], &["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mäe", "Abr", "Mee", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"], &["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["v. Chr.", "n. Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v. Chr.", "n. Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v. Chr.", "n. Chr.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_562: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_563: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_564: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_565: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.yy GGGGG", "dd.MM.y G", "d. MMMM y G", "EEEE, d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_125: [&_CalendarData; 6] = [&_CALENDAR_560, &_CALENDAR_561, &_CALENDAR_562, &_CALENDAR_563, &_CALENDAR_564, &_CALENDAR_565];
+
+#[rustfmt::skip]
+static _CALENDAR_566: _CalendarData = _CalendarData { months: [&["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_567: _CalendarData = _CalendarData { months: [&["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["de zenâ", "de frevâ", "de marso", "d’arvî", "de mazzo", "de zugno", "de luggio", "d’agosto", "de settembre", "d’ottobre", "de novembre", "de dexembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_126: [&_CalendarData; 6] = [&_CALENDAR_566, &_CALENDAR_567, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_568: _CalendarData = _CalendarData { months: [&["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_569: _CalendarData = _CalendarData { months: [&["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["sginer", "fevrer", "marz", "avril", "masg", "sgiugn", "luj", "avost", "setember", "otover", "november", "dicember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_127: [&_CalendarData; 6] = [&_CALENDAR_568, &_CALENDAR_569, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_570: _CalendarData = _CalendarData { months: [&["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມ\u{eb4}.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."], &["ມ\u{eb1}ງກອນ", "ກ\u{eb8}ມພາ", "ມ\u{eb5}ນາ", "ເມສາ", "ພ\u{eb6}ດສະພາ", "ມ\u{eb4}ຖ\u{eb8}ນາ", "ກ\u{ecd}ລະກ\u{ebb}ດ", "ສ\u{eb4}ງຫາ", "ກ\u{eb1}ນຍາ", "ຕ\u{eb8}ລາ", "ພະຈ\u{eb4}ກ", "ທ\u{eb1}ນວາ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມ\u{eb4}.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."], &["ມ\u{eb1}ງກອນ", "ກ\u{eb8}ມພາ", "ມ\u{eb5}ນາ", "ເມສາ", "ພ\u{eb6}ດສະພາ", "ມ\u{eb4}ຖ\u{eb8}ນາ", "ກ\u{ecd}ລະກ\u{ebb}ດ", "ສ\u{eb4}ງຫາ", "ກ\u{eb1}ນຍາ", "ຕ\u{eb8}ລາ", "ພະຈ\u{eb4}ກ", "ທ\u{eb1}ນວາ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ພ.ສ."], &["ພ.ສ."], &["ພ.ສ."]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEEທ\u{eb5} d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_571: _CalendarData = _CalendarData { months: [&["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມ\u{eb4}.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."], &["ມ\u{eb1}ງກອນ", "ກ\u{eb8}ມພາ", "ມ\u{eb5}ນາ", "ເມສາ", "ພ\u{eb6}ດສະພາ", "ມ\u{eb4}ຖ\u{eb8}ນາ", "ກ\u{ecd}ລະກ\u{ebb}ດ", "ສ\u{eb4}ງຫາ", "ກ\u{eb1}ນຍາ", "ຕ\u{eb8}ລາ", "ພະຈ\u{eb4}ກ", "ທ\u{eb1}ນວາ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມ\u{eb4}.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."], &["ມ\u{eb1}ງກອນ", "ກ\u{eb8}ມພາ", "ມ\u{eb5}ນາ", "ເມສາ", "ພ\u{eb6}ດສະພາ", "ມ\u{eb4}ຖ\u{eb8}ນາ", "ກ\u{ecd}ລະກ\u{ebb}ດ", "ສ\u{eb4}ງຫາ", "ກ\u{eb1}ນຍາ", "ຕ\u{eb8}ລາ", "ພະຈ\u{eb4}ກ", "ທ\u{eb1}ນວາ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ກ\u{ec8}ອນ ຄ.ສ.", "ຄ.ສ.", "ມ\u{eb5}ຈ\u{eb5}", "ໄຕໂຊ", "ໂຊວາ", "ຮ\u{eb5}ຊ\u{eb5}", "Reiwa"], &["ກ\u{ec8}ອນຄຣ\u{eb4}ດສ\u{eb1}ກກະລາດ", "ຄຣ\u{eb4}ດສ\u{eb1}ກກະລາດ", "ມ\u{eb5}ຈ\u{eb5}", "ໄຕໂຊ", "ໂຊວາ", "ຮ\u{eb5}ຊ\u{eb5}", "Reiwa"], &["ກ\u{ec8}ອນ ຄ.ສ.", "ຄ.ສ.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEEທ\u{eb5} d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_572: _CalendarData = _CalendarData { months: [&["ຟາຣວາດ\u{eb4}ນ", "ອ\u{ecd}ຣດ\u{eb5}ບ\u{eb5}ເຫຣດ", "ຄ\u{ecd}ຣເດດ", "ແຕຣ", "ມ\u{ecd}ຣເດດ", "ຊາຣຫ\u{eb4}ວາ", "ເມ\u{eb5}", "ອາບານ", "ອາຊາ", "ດ\u{eb5}ຣ", "ບຣາມານ", "ເອສຟານ"], &["ຟຣາວາດ\u{eb4}ນ", "ອ\u{ecd}ຣດ\u{eb5}ບ\u{eb5}ເຫຣດ", "ຄ\u{ecd}ຣເດດ", "ແຕຣ", "ມ\u{ecd}ຣເດດ", "ຊາຣຫ\u{eb4}ວາ", "ເມ\u{eb5}", "ອາບານ", "ອາຊາຣ", "ດ\u{eb5}ຣ", "ບຣາແມນ", "ເອສຟານ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ຟຣາວາດ\u{eb4}ນ", "ອ\u{ecd}ຣດ\u{eb5}ບ\u{eb5}ເຫຮດ", "ຄ\u{ecd}ຣເດດ", "ແຕຣ", "ມ\u{ecd}ຣເດດ", "ຊາຣລ\u{eb4}ວາ", "ເມ\u{eb5}", "ອາບານ", "ອາຊາ", "ດ\u{eb5}ຣ", "ບຣາມານ", "ເອສຟານ"], &["ຟຣາວາດ\u{eb4}ນ", "ອ\u{ecd}ຣດ\u{eb5}ບ\u{eb5}ເຫຣດ", "ຄ\u{ecd}ຣເດດ", "ແຕຣ", "ມ\u{ecd}ຣເດດ", "ຊາຣຫ\u{eb4}ວາ", "ເມ\u{eb5}", "ອາບານ", "ອາຊາຣ", "ດ\u{eb5}ຣ", "ບຣາແມນ", "ເອສຟານ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ປ\u{eb5}ເປ\u{eb5}ເຊຍ"], &["ປ\u{eb5}ເປ\u{eb5}ເຊຍ"], &["ປ\u{eb5}ເປ\u{eb5}ເຊຍ"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEEທ\u{eb5} d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_573: _CalendarData = _CalendarData { months: [&["ມ\u{eb8}ຮ\u{eb1}ດ", "ເຄາະ", "ຮອດບ\u{eb5} 1", "ຮອກບ\u{eb5} 2", "ນ\u{eb8}ມາ 1", "ນ\u{eb8}ມາ 2", "ເຮາະ", "ຊະອ\u{ecc}", "ເຮາະມະ", "ເຊ\u{ebb}າ", "ຊ\u{eb8}ລກ\u{eb4}ອ\u{eb8}", "ຊ\u{eb8}ລຫ\u{eb4}ຈ"], &["ມ\u{eb8}ຣະຮອມ", "ຊາຟາຣ", "ຮອດບ\u{eb5} 1", "ຮອດບ\u{eb5} 2", "ຈ\u{eb8}ມາດາ 1", "ຈ\u{eb8}ມາດາ 2", "ຮາຈ\u{eb1}ບ", "ຊະບານ", "ຮາມາດອນ", "ເຊ\u{ebb}າວ\u{eb1}ດ", "ດ\u{eb8}ອ\u{eb1}ດກ\u{eb4}ດະ", "ດ\u{eb8}ອ\u{eb1}ດກ\u{eb4}ຈະ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ມ\u{eb8}ຮ\u{eb1}ດ", "ເຄາະ", "ຮອກບ\u{eb5} 1", "ຮອກບ\u{eb5} 2", "ນ\u{eb8}ມາ 1", "ນ\u{eb8}ມາ 2", "ເຮາະ", "ຊາ", "ເຮາະມະ", "ເຊ\u{ebb}າ", "ຊ\u{eb8}ລກ\u{eb4}ອ\u{eb8}", "ຊ\u{eb8}ລຫ\u{eb4}ຈ"], &["ມ\u{eb8}ຣະຮອມ", "ຊາຟາຣ", "ຮອດບ\u{eb5} 1", "ຮອດບ\u{eb5} 2", "ຈ\u{eb8}ມາດາ 1", "ຈ\u{eb8}ມາດາ 2", "ຮາຈ\u{eb1}ບ", "ຊະບານ", "ຮາມາດອນ", "ເຊ\u{ebb}າວ\u{eb1}ດ", "ດ\u{eb8}ອ\u{eb1}ດກ\u{eb4}ດະ", "ດ\u{eb8}ອ\u{eb1}ດກ\u{eb4}ຈະ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["y-MM-dd", "y MMM d", "y MMMM d", "EEEE, y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_574: _CalendarData = _CalendarData { months: [&["ທຣ\u{eb4}ດຣ\u{eb5}", "ເຮວານ", "ກ\u{eb4}ດເລບ", "ເຕເວດ", "ຊ\u{eb5}ວ\u{eb1}ດ", "ອາດາ I", "ອາດາ", "ນ\u{eb4}ດຊານ", "ອ\u{eb5}ຍາຣ", "ສ\u{eb5}ວານ", "ຕາມ\u{eb9}", "ເອບ", "ອ\u{eb5}ລ\u{eb9}", "ອາດາ II"], &["ທຣ\u{eb4}ດຣ\u{eb5}", "ເຮວານ", "ກ\u{eb4}ດເລບ", "ເຕເວດ", "ຊ\u{eb5}ວ\u{eb1}ດ", "ອາດາ I", "ອາດາ", "ນ\u{eb4}ດຊານ", "ອ\u{eb5}ຍາຣ", "ສ\u{eb5}ວານ", "ຕາມ\u{eb9}", "ເອບ", "ອ\u{eb5}ລ\u{eb9}", "ອາດາ II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ທຣ\u{eb4}ດຣ\u{eb5}", "ເຮວານ", "ກ\u{eb4}ດເລບ", "ເຕເວດ", "ຊ\u{eb5}ວ\u{eb1}ດ", "ອາດາ I", "ອາດາ", "ນ\u{eb4}ດຊານ", "ອ\u{eb5}ຍາຣ", "ສ\u{eb5}ວານ", "ຕາມ\u{eb9}", "ເອບ", "ອ\u{eb5}ລ\u{eb9}", "ອາດາ II"], &["ທຣ\u{eb4}ດຣ\u{eb5}", "ເຮວານ", "ກ\u{eb4}ດເລບ", "ເຕເວດ", "ຊ\u{eb5}ວ\u{eb1}ດ", "ອາດາ I", "ອາດາ", "ນ\u{eb4}ດຊານ", "ອ\u{eb5}ຍາຣ", "ສ\u{eb5}ວານ", "ຕາມ\u{eb9}", "ເອບ", "ອ\u{eb5}ລ\u{eb9}", "ອາດາ II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEEທ\u{eb5} d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_575: _CalendarData = _CalendarData { months: [&["ແມສເຄ\u{eb5}ແຣມ", "ເຕເກມ", "ເຮດາ", "ທາຊ\u{eb1}ສ", "ເທ\u{eb5}", "ເຍຄາທ\u{eb4}ດ", "ເມກາບ\u{eb4}ດ", "ເມຍເຊຍ", "ເຈນບອດ", "ເຊເນ", "ຮຳເລ", "ເນແຮສ\u{ecc}", "ພາກ\u{eb9}ເມນ", ""], &["ແມສເຄ\u{eb5}ແຣມ", "ເຕເກມ", "ເຮດາ", "ທາຊ\u{eb1}ສ", "ເທ\u{eb5}", "ເຍຄາທ\u{eb4}ດ", "ເມກາບ\u{eb4}ດ", "ເມຍເຊຍ", "ເຈນບອດ", "ເຊເນ", "ຮຳເລ", "ເນແຮສ\u{ecc}", "ພາກ\u{eb9}ເມນ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["ແມສເຄ\u{eb5}ແຣມ", "ເຕເກມ", "ເຮດາ", "ທາຊ\u{eb1}ສ", "ເທ\u{eb5}", "ເຍຄາທ\u{eb4}ດ", "ເມກາບ\u{eb4}ດ", "ເມຍເຊຍ", "ເຈນບອດ", "ເຊເນ", "ຮຳເລ", "ເນແຮສ\u{ecc}", "ພາກ\u{eb9}ເມນ", ""], &["ແມສເຄ\u{eb5}ແຣມ", "ເຕເກມ", "ເຮດາ", "ທາຊ\u{eb1}ສ", "ເທ\u{eb5}", "ເຍຄາທ\u{eb4}ດ", "ເມກາບ\u{eb4}ດ", "ເມຍເຊຍ", "ເຈນບອດ", "ເຊເນ", "ຮຳເລ", "ເນແຮສ\u{ecc}", "ພາກ\u{eb9}ເມນ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEEທ\u{eb5} d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_128: [&_CalendarData; 6] = [&_CALENDAR_570, &_CALENDAR_571, &_CALENDAR_572, &_CALENDAR_573, &_CALENDAR_574, &_CALENDAR_575];
+
+#[rustfmt::skip]
+static _CALENDAR_576: _CalendarData = _CalendarData { months: [&["saus.", "vas.", "kov.", "bal.", "geg.", "birž.", "liep.", "rugp.", "rugs.", "spal.", "lapkr.", "gruod."], &["sausio", "vasario", "kovo", "balandžio", "gegužės", "birželio", "liepos", "rugpjūčio", "rugsėjo", "spalio", "lapkričio", "gruodžio"], &["S", "V", "K", "B", "G", "B", "L", "R", "R", "S", "L", "G"], &["saus.", "vas.", "kov.", "bal.", "geg.", "birž.", "liep.", "rugp.", "rugs.", "spal.", "lapkr.", "gruod."], &["sausis", "vasaris", "kovas", "balandis", "gegužė", "birželis", "liepa", "rugpjūtis", "rugsėjis", "spalis", "lapkritis", "gruodis"], &["S", "V", "K", "B", "G", "B", "L", "R", "R", "S", "L", "G"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["y-MM-dd G", "y MMM d G", "y MMMM d G", "y MMMM d G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_577: _CalendarData = _CalendarData { months: [&["saus.", "vas.", "kov.", "bal.", "geg.", "birž.", "liep.", "rugp.", "rugs.", "spal.", "lapkr.", "gruod."], &["sausio", "vasario", "kovo", "balandžio", "gegužės", "birželio", "liepos", "rugpjūčio", "rugsėjo", "spalio", "lapkričio", "gruodžio"], &["S", "V", "K", "B", "G", "B", "L", "R", "R", "S", "L", "G"], &["saus.", "vas.", "kov.", "bal.", "geg.", "birž.", "liep.", "rugp.", "rugs.", "spal.", "lapkr.", "gruod."], &["sausis", "vasaris", "kovas", "balandis", "gegužė", "birželis", "liepa", "rugpjūtis", "rugsėjis", "spalis", "lapkritis", "gruodis"], &["S", "V", "K", "B", "G", "B", "L", "R", "R", "S", "L", "G"]], eras: [&["pr. Kr.", "po Kr.", "Meidži", "Taišo", "Šova", "Heisei", "Reiwa"], &["prieš Kristų", "po Kristaus", "Meidži", "Taišo", "Šova", "Heisei", "Reiwa"], &["pr. Kr.", "po Kr.", "M", "T", "S", "H", "R"]], date_patterns: ["y-MM-dd G", "y MMM d G", "y MMMM d G", "y MMMM d G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_578: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["y-MM-dd G", "y MMM d G", "y MMMM d G", "y MMMM d G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_579: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["y-MM-dd G", "y MMM d G", "y MMMM d G", "y MMMM d G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_580: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["y-MM-dd G", "y MMM d G", "y MMMM d G", "y MMMM d G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_581: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["y-MM-dd G", "y MMM d G", "y MMMM d G", "y MMMM d G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_129: [&_CalendarData; 6] = [&_CALENDAR_576, &_CALENDAR_577, &_CALENDAR_578, &_CALENDAR_579, &_CALENDAR_580, &_CALENDAR_581];
+
+#[rustfmt::skip]
+static _CALENDAR_582: _CalendarData = _CalendarData { months: [&["janv.", "febr.", "marts", "apr.", "maijs", "jūn.", "jūl.", "aug.", "sept.", "okt.", "nov.", "dec."], &["janvāris", "februāris", "marts", "aprīlis", "maijs", "jūnijs", "jūlijs", "augusts", "septembris", "oktobris", "novembris", "decembris"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "febr.", "marts", "apr.", "maijs", "jūn.", "jūl.", "aug.", "sept.", "okt.", "nov.", "dec."], &["janvāris", "februāris", "marts", "aprīlis", "maijs", "jūnijs", "jūlijs", "augusts", "septembris", "oktobris", "novembris", "decembris"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["B.E."], &["budistu ēra"], &["B.E."]], date_patterns: ["dd.MM.y. GGGGG", "y'. gada' d. MMM G", "y'. gada' d. MMMM G", "EEEE, y'. gada' d. MMMM G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_583: _CalendarData = _CalendarData { months: [&["janv.", "febr.", "marts", "apr.", "maijs", "jūn.", "jūl.", "aug.", "sept.", "okt.", "nov.", "dec."], &["janvāris", "februāris", "marts", "aprīlis", "maijs", "jūnijs", "jūlijs", "augusts", "septembris", "oktobris", "novembris", "decembris"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["janv.", "febr.", "marts", "apr.", "maijs", "jūn.", "jūl.", "aug.", "sept.", "okt.", "nov.", "dec."], &["janvāris", "februāris", "marts", "aprīlis", "maijs", "jūnijs", "jūlijs", "augusts", "septembris", "oktobris", "novembris", "decembris"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["p.m.ē.", "m.ē.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pirms mūsu ēras", "mūsu ērā", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["p.m.ē.", "m.ē.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y. GGGGG", "y'. gada' d. MMM G", "y'. gada' d. MMMM G", "EEEE, y'. gada' d. MMMM G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_584: _CalendarData = _CalendarData { months: [&["farvardīns", "ordibehešts", "hordāds", "tīrs", "mordāds", "šahrivērs", "mehrs", "abans", "azers", "dejs", "bahmans", "esfands"], &["farvardīns", "ordibehešts", "hordāds", "tīrs", "mordāds", "šahrivērs", "mehrs", "abans", "azers", "dejs", "bahmans", "esfands"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardīns", "ordibehešts", "hordāds", "tīrs", "mordāds", "šahrivērs", "mehrs", "abans", "azers", "dejs", "bahmans", "esfands"], &["farvardīns", "ordibehešts", "hordāds", "tīrs", "mordāds", "šahrivērs", "mehrs", "abans", "azers", "dejs", "bahmans", "esfands"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["pers. gads"], &["persiešu gads"], &["pers. gads"]], date_patterns: ["dd.MM.y. GGGGG", "y'. gada' d. MMM G", "y'. gada' d. MMMM G", "EEEE, y'. gada' d. MMMM G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_585: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharams", "safars", "1. rabī", "2. rabī", "1. džumādā", "2. džumādā", "radžabs", "šabans", "ramadāns", "šauvals", "du al-kidā", "du al-hidžā"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharams", "safars", "1. rabī", "2. rabī", "1. džumādā", "2. džumādā", "radžabs", "šabans", "ramadāns", "šauvals", "du al-kidā", "du al-hidžā"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["pēc hidžras"], &["AH"]], date_patterns: ["dd.MM.y. GGGGG", "y'. gada' d. MMM G", "y'. gada' d. MMMM G", "EEEE, y'. gada' d. MMMM G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_586: _CalendarData = _CalendarData { months: [&["tišri", "hešvans", "kisļevs", "tevets", "ševats", "1. adars", "adars", "nisans", "ijars", "sivans", "tamuzs", "avs", "eluls", "2. adars"], &["tišri", "hešvans", "kisļevs", "tevets", "ševats", "1. adars", "adars", "nisans", "ijars", "sivans", "tamuzs", "avs", "eluls", "2. adars"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tišri", "hešvans", "kisļevs", "tevets", "ševats", "1. adars", "adars", "nisans", "ijars", "sivans", "tamuzs", "avs", "eluls", "2. adars"], &["tišri", "hešvans", "kisļevs", "tevets", "ševats", "1. adars", "adars", "nisans", "ijars", "sivans", "tamuzs", "avs", "eluls", "2. adars"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["kopš pasaules radīšanas"], &["AM"]], date_patterns: ["dd.MM.y. GGGGG", "y'. gada' d. MMM G", "y'. gada' d. MMMM G", "EEEE, y'. gada' d. MMMM G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_587: _CalendarData = _CalendarData { months: [&["meskerems", "tekemts", "hedars", "tahsass", "ters", "jakatīts", "magabits", "miāzija", "genbots", "senē", "hamlē", "nahasē", "epagomens", ""], &["meskerems", "tekemts", "hedars", "tahsass", "ters", "jakatīts", "magabits", "miāzija", "genbots", "senē", "hamlē", "nahasē", "epagomens", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerems", "tekemts", "hedars", "tahsass", "ters", "jakatīts", "magabits", "miāzija", "genbots", "senē", "hamlē", "nahasē", "epagomens", ""], &["meskerems", "tekemts", "hedars", "tahsass", "ters", "jakatīts", "magabits", "miāzija", "genbots", "senē", "hamlē", "nahasē", "epagomens", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "pēc Kristus"], &["ERA0", "pēc Kristus iemiesošanās"], &["ERA0", "pēc Kristus"]], date_patterns: ["dd.MM.y. GGGGG", "y'. gada' d. MMM G", "y'. gada' d. MMMM G", "EEEE, y'. gada' d. MMMM G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_130: [&_CalendarData; 6] = [&_CALENDAR_582, &_CALENDAR_583, &_CALENDAR_584, &_CALENDAR_585, &_CALENDAR_586, &_CALENDAR_587];
+
+#[rustfmt::skip]
+static _CALENDAR_588: _CalendarData = _CalendarData { months: [&["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन॰", "फर॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_589: _CalendarData = _CalendarData { months: [&["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन॰", "फर॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["ईसा-प\u{942}र\u{94d}व", "ईसवी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा-प\u{942}र\u{94d}व", "ईसवी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा-प\u{942}र\u{94d}व", "ईसवी", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_590: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_591: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_592: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_131: [&_CalendarData; 6] = [&_CALENDAR_588, &_CALENDAR_589, &_CALENDAR_590, &_CALENDAR_591, &_CALENDAR_399, &_CALENDAR_592];
+
+#[rustfmt::skip]
+static _CALENDAR_593: _CalendarData = _CalendarData { months: [&["Hān", "Pēp", "Māe", "Āpe", "Mei", "Hun", "Hūr", "Āku", "Hep", "Oke", "Noe", "Tīh"], &["Hānuere", "Pēpuere", "Māehe", "Āpereira", "Mei", "Hune", "Hūrae", "Ākuhata", "Hepetema", "Oketopa", "Noema", "Tīhema"], &["H", "P", "M", "Ā", "M", "H", "H", "Ā", "H", "O", "N", "T"], &["Hān", "Pēp", "Māe", "Āpe", "Mei", "Hun", "Hūr", "Āku", "Hep", "Oke", "Noe", "Tīh"], &["Hānuere", "Pēpuere", "Māehe", "Āperira", "Mei", "Hune", "Hūrae", "Ākuhata", "Hepetema", "Oketopa", "Noema", "Tīhema"], &["H", "P", "M", "Ā", "M", "H", "H", "Ā", "H", "O", "N", "T"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_594: _CalendarData = _CalendarData { months: [&["Hān", "Pēp", "Māe", "Āpe", "Mei", "Hun", "Hūr", "Āku", "Hep", "Oke", "Noe", "Tīh"], &["Hānuere", "Pēpuere", "Māehe", "Āpereira", "Mei", "Hune", "Hūrae", "Ākuhata", "Hepetema", "Oketopa", "Noema", "Tīhema"], &["H", "P", "M", "Ā", "M", "H", "H", "Ā", "H", "O", "N", "T"], &["Hān", "Pēp", "Māe", "Āpe", "Mei", "Hun", "Hūr", "Āku", "Hep", "Oke", "Noe", "Tīh"], &["Hānuere", "Pēpuere", "Māehe", "Āperira", "Mei", "Hune", "Hūrae", "Ākuhata", "Hepetema", "Oketopa", "Noema", "Tīhema"], &["H", "P", "M", "Ā", "M", "H", "H", "Ā", "H", "O", "N", "T"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_595: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_132: [&_CalendarData; 6] = [&_CALENDAR_593, &_CALENDAR_594, &_CALENDAR_474, &_CALENDAR_595, &_CALENDAR_476, &_CALENDAR_477];
+
+#[rustfmt::skip]
+static _CALENDAR_596: _CalendarData = _CalendarData { months: [&["јан.", "фев.", "мар.", "апр.", "мај", "јун.", "јул.", "авг.", "сеп.", "окт.", "ное.", "дек."], &["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан.", "фев.", "мар.", "апр.", "мај", "јун.", "јул.", "авг.", "сеп.", "окт.", "ное.", "дек."], &["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.M.y GGGGG", "dd.M.y G", "dd MMMM y\u{202f}г. G", "EEEE, dd MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_597: _CalendarData = _CalendarData { months: [&["јан.", "фев.", "мар.", "апр.", "мај", "јун.", "јул.", "авг.", "сеп.", "окт.", "ное.", "дек."], &["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан.", "фев.", "мар.", "апр.", "мај", "јун.", "јул.", "авг.", "сеп.", "окт.", "ное.", "дек."], &["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["пр. н. е.", "н. е.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["пред нашата ера", "од нашата ера", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["пр. н. е.", "н. е.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y\u{202f}г. GGGGG", "d.M.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_598: _CalendarData = _CalendarData { months: [&["фарвардин", "ордибехешт", "кордад", "тир", "мордад", "шахривар", "мер", "абан", "азар", "деј", "бахман", "есфанд"], &["фарвардин", "ордибехешт", "кордад", "тир", "мордад", "шахривар", "мер", "абан", "азар", "деј", "бахман", "есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["фарвардин", "ордибехешт", "кордад", "тир", "мордад", "шахривар", "мер", "абан", "азар", "деј", "бахман", "есфанд"], &["фарвардин", "ордибехешт", "кордад", "тир", "мордад", "шахривар", "мер", "абан", "азар", "деј", "бахман", "есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["АП"], &["АП"], &["АП"]], date_patterns: ["d.M.y\u{202f}г. GGGGG", "d.M.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_599: _CalendarData = _CalendarData { months: [&["мух.", "саф.", "раб. I", "раб. II", "џум. I", "џум. II", "раџ.", "шаб.", "рам.", "шав.", "дулк.", "дулх."], &["мухарем", "сафар", "раби I", "раби II", "џумада I", "џумада II", "раџаб", "шабан", "рамадан", "шавал", "дулкида", "дулхиџа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["мух.", "саф.", "раб. I", "раб. II", "џум. I", "џум. II", "раџ.", "шаб.", "рам.", "шав.", "дулк.", "дулх."], &["мухарем", "сафар", "раби I", "раби II", "џумада I", "џумада II", "раџаб", "шабан", "рамадан", "шавал", "дулкида", "дулхиџа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.M.y GGGGG", "dd.M.y G", "dd MMMM y\u{202f}г. G", "EEEE, dd MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_600: _CalendarData = _CalendarData { months: [&["тишри", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ијар", "сиван", "тамуз", "ав", "елул", "адар II"], &["тишри", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ијар", "сиван", "тамуз", "ав", "елул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["тишри", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ијар", "сиван", "тамуз", "ав", "елул", "адар II"], &["тишри", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ијар", "сиван", "тамуз", "ав", "елул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.M.y GGGGG", "dd.M.y G", "dd MMMM y\u{202f}г. G", "EEEE, dd MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_601: _CalendarData = _CalendarData { months: [&["мескерем", "текемт", "хедар", "тахсас", "тер", "јекатит", "мегабит", "миазиа", "генбот", "сене", "хамле", "нехасе", "пагумен", ""], &["мескерем", "текемт", "хедар", "тахсас", "тер", "јекатит", "мегабит", "миазиа", "генбот", "сене", "хамле", "нехасе", "пагумен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["мескерем", "текемт", "хедар", "тахсас", "тер", "јекатит", "мегабит", "миазиа", "генбот", "сене", "хамле", "нехасе", "пагумен", ""], &["мескерем", "текемт", "хедар", "тахсас", "тер", "јекатит", "мегабит", "миазиа", "генбот", "сене", "хамле", "нехасе", "пагумен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ЕРА1"], &["ERA0", "ЕРА1"], &["ERA0", "ЕРА1"]], date_patterns: ["d.M.y\u{202f}г. GGGGG", "d.M.y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_133: [&_CalendarData; 6] = [&_CALENDAR_596, &_CALENDAR_597, &_CALENDAR_598, &_CALENDAR_599, &_CALENDAR_600, &_CALENDAR_601];
+
+#[rustfmt::skip]
+static _CALENDAR_602: _CalendarData = _CalendarData { months: [&["ജന\u{d41}", "ഫെബ\u{d4d}ര\u{d41}", "മ\u{d3e}ർ", "ഏപ\u{d4d}രി", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗ", "സെപ\u{d4d}റ\u{d4d}റം", "ഒക\u{d4d}ടോ", "നവം", "ഡിസം"], &["ജന\u{d41}വരി", "ഫെബ\u{d4d}ര\u{d41}വരി", "മ\u{d3e}ർച\u{d4d}ച\u{d4d}", "ഏപ\u{d4d}രിൽ", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗസ\u{d4d}റ\u{d4d}റ\u{d4d}", "സെപ\u{d4d}റ\u{d4d}റംബർ", "ഒക\u{d4d}\u{200c}ടോബർ", "നവംബർ", "ഡിസംബർ"], &["ജ", "ഫെ", "മ\u{d3e}", "ഏ", "മെ", "ജ\u{d42}ൺ", "ജ\u{d42}", "ഓ", "സെ", "ഒ", "ന", "ഡി"], &["ജന\u{d41}", "ഫെബ\u{d4d}ര\u{d41}", "മ\u{d3e}ർ", "ഏപ\u{d4d}രി", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗ", "സെപ\u{d4d}റ\u{d4d}റം", "ഒക\u{d4d}ടോ", "നവം", "ഡിസം"], &["ജന\u{d41}വരി", "ഫെബ\u{d4d}ര\u{d41}വരി", "മ\u{d3e}ർച\u{d4d}ച\u{d4d}", "ഏപ\u{d4d}രിൽ", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗസ\u{d4d}റ\u{d4d}റ\u{d4d}", "സെപ\u{d4d}റ\u{d4d}റംബർ", "ഒക\u{d4d}\u{200c}ടോബർ", "നവംബർ", "ഡിസംബർ"], &["ജ", "ഫെ", "മ\u{d3e}", "ഏ", "മെ", "ജ\u{d42}ൺ", "ജ\u{d42}", "ഓ", "സെ", "ഒ", "ന", "ഡി"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y, MMM d", "G y, MMMM d", "G y, MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_603: _CalendarData = _CalendarData { months: [&["ജന\u{d41}", "ഫെബ\u{d4d}ര\u{d41}", "മ\u{d3e}ർ", "ഏപ\u{d4d}രി", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗ", "സെപ\u{d4d}റ\u{d4d}റം", "ഒക\u{d4d}ടോ", "നവം", "ഡിസം"], &["ജന\u{d41}വരി", "ഫെബ\u{d4d}ര\u{d41}വരി", "മ\u{d3e}ർച\u{d4d}ച\u{d4d}", "ഏപ\u{d4d}രിൽ", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗസ\u{d4d}റ\u{d4d}റ\u{d4d}", "സെപ\u{d4d}റ\u{d4d}റംബർ", "ഒക\u{d4d}\u{200c}ടോബർ", "നവംബർ", "ഡിസംബർ"], &["ജ", "ഫെ", "മ\u{d3e}", "ഏ", "മെ", "ജ\u{d42}ൺ", "ജ\u{d42}", "ഓ", "സെ", "ഒ", "ന", "ഡി"], &["ജന\u{d41}", "ഫെബ\u{d4d}ര\u{d41}", "മ\u{d3e}ർ", "ഏപ\u{d4d}രി", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗ", "സെപ\u{d4d}റ\u{d4d}റം", "ഒക\u{d4d}ടോ", "നവം", "ഡിസം"], &["ജന\u{d41}വരി", "ഫെബ\u{d4d}ര\u{d41}വരി", "മ\u{d3e}ർച\u{d4d}ച\u{d4d}", "ഏപ\u{d4d}രിൽ", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗസ\u{d4d}റ\u{d4d}റ\u{d4d}", "സെപ\u{d4d}റ\u{d4d}റംബർ", "ഒക\u{d4d}\u{200c}ടോബർ", "നവംബർ", "ഡിസംബർ"], &["ജ", "ഫെ", "മ\u{d3e}", "ഏ", "മെ", "ജ\u{d42}ൺ", "ജ\u{d42}", "ഓ", "സെ", "ഒ", "ന", "ഡി"]], eras: [&["ബി.സി.", "എഡി", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ക\u{d4d}രിസ\u{d4d}\u{200c}ത\u{d41}വിന\u{d4d} മ\u{d41}മ\u{d4d}പ\u{d4d}", "ആന\u{d4d}നോ ഡൊമിനി", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ബി.സി.", "എഡി", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y, MMM d", "G y, MMMM d", "G y, MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_604: _CalendarData = _CalendarData { months: [&["ഫർവ\u{d3e}ർദിൻ", "ഓർഡിബെഹെഷ\u{d4d}\u{200c}റ\u{d4d}റ\u{d4d}", "ഖോർദ\u{d3e}ദ\u{d4d}", "ടിർ", "മോർദ\u{d3e}ദ\u{d4d}", "ഷഹ\u{d4d}\u{200c}രിവ\u{d3e}ർ", "മെഹർ", "അബ\u{d3e}ൻ", "അസർ", "ഡെയ\u{d4d}", "ബഹ\u{d4d}\u{200c}മ\u{d3e}ൻ", "എസ\u{d4d}\u{200c}ഫ\u{d3e}ൻഡ\u{d4d}"], &["ഫർവ\u{d3e}ർദിൻ", "ഓർഡിബെഹെഷ\u{d4d}\u{200c}റ\u{d4d}റ\u{d4d}", "ഖോർദ\u{d3e}ദ\u{d4d}", "ടിർ", "മോർദ\u{d3e}ദ\u{d4d}", "ഷഹ\u{d4d}\u{200c}രിവ\u{d3e}ർ", "മെഹർ", "അബ\u{d3e}ൻ", "അസർ", "ഡെയ\u{d4d}", "ബഹ\u{d4d}\u{200c}മ\u{d3e}ൻ", "എസ\u{d4d}\u{200c}ഫ\u{d3e}ൻഡ\u{d4d}"], &["ഫ.", "ഓ.", "ഖോ", "ടി.", "മോ.", "ഷ.", "മെ.", "അ.", "അ.", "ഡെ.", "ബ.", "എ."], &["ഫർവ\u{d3e}ർദിൻ", "ഓർഡിബെഹെഷ\u{d4d}\u{200c}റ\u{d4d}റ\u{d4d}", "ഖോർദ\u{d3e}ദ\u{d4d}", "ടിർ", "മോർദ\u{d3e}ദ\u{d4d}", "ഷഹ\u{d4d}\u{200c}രിവ\u{d3e}ർ", "മെഹർ", "അബ\u{d3e}ൻ", "അസർ", "ഡെയ\u{d4d}", "ബഹ\u{d4d}\u{200c}മ\u{d3e}ൻ", "എസ\u{d4d}\u{200c}ഫ\u{d3e}ൻഡ\u{d4d}"], &["ഫർവ\u{d3e}ർദിൻ", "ഓർഡിബെഹെഷ\u{d4d}\u{200c}റ\u{d4d}റ\u{d4d}", "ഖോർദ\u{d3e}ദ\u{d4d}", "ടിർ", "മോർദ\u{d3e}ദ\u{d4d}", "ഷഹ\u{d4d}\u{200c}രിവ\u{d3e}ർ", "മെഹർ", "അബ\u{d3e}ൻ", "അസർ", "ഡെയ\u{d4d}", "ബഹ\u{d4d}\u{200c}മ\u{d3e}ൻ", "എസ\u{d4d}\u{200c}ഫ\u{d3e}ൻഡ\u{d4d}"], &["ഫ.", "ഓ.", "ഖോ", "ടി.", "മോ.", "ഷ.", "മെ.", "അ.", "അ.", "ഡെ.", "ബ.", "എ."]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "G y, MMM d", "G y, MMMM d", "G y, MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_605: _CalendarData = _CalendarData { months: [&["മ\u{d41}ഹ.", "സഫ.", "റബീഹ\u{d41}ൽ അവ\u{d4d}വ.", "റബീഹ\u{d41}ൽ ആഖി.", "ജമ\u{d3e}ദ\u{d41}ൽ അവ\u{d4d}വ.", "ജമ\u{d3e}ദ\u{d41}ൽ ആഖി.", "റജ.", "ശഹബ\u{d3e}.", "റമദ\u{d3e}.", "ശവ\u{d4d}വ\u{d3e}.", "ദ\u{d41}ൽ ഖഹ.", "ദ\u{d41}ൽ ഹി."], &["മ\u{d41}ഹറം", "സഫർ", "റബീഹ\u{d41}ൽ അവ\u{d4d}വൽ", "റബീഹ\u{d41}ൽ ആഖിർ", "ജമ\u{d3e}ദ\u{d41}ൽ അവ\u{d4d}വൽ", "ജമ\u{d3e}ദ\u{d41}ൽ ആഖിർ", "റജബ\u{d4d}", "ശഹബ\u{d3e}ൻ", "റമള\u{d3e}ൻ", "ശവ\u{d4d}വ\u{d3e}ൽ", "ദ\u{d41}ൽ ഖഹദ\u{d4d}", "ദ\u{d41}ൽ ഹിജ\u{d4d}ജ"], &["മ\u{d41}", "സ", "റ", "റ", "ജ", "ജ", "റ", "ശ", "റ", "ശ", "ദ\u{d41}", "ദ\u{d41}"], &["മ\u{d41}ഹ.", "സഫ.", "റബീഹ\u{d41}ൽ അവ\u{d4d}വ.", "റബീഹ\u{d41}ൽ ആഖി.", "ജമ\u{d3e}ദ\u{d41}ൽ അവ\u{d4d}വ.", "ജമ\u{d3e}ദ\u{d41}ൽ ആഖി.", "റജ.", "ശഹബ\u{d3e}.", "റമദ\u{d3e}.", "ശവ\u{d4d}വ\u{d3e}.", "ദ\u{d41}ൽ ഖഹ.", "ദ\u{d41}ൽ ഹി."], &["മ\u{d41}ഹറം", "സഫർ", "റബീഹ\u{d41}ൽ അവ\u{d4d}വൽ", "റബീഹ\u{d41}ൽ ആഖിർ", "ജമ\u{d3e}ദ\u{d41}ൽ അവ\u{d4d}വൽ", "ജമ\u{d3e}ദ\u{d41}ൽ ആഖിർ", "റജബ\u{d4d}", "ശഹബ\u{d3e}ൻ", "റമദ\u{d3e}ൻ", "ശവ\u{d4d}വ\u{d3e}ൽ", "ദ\u{d41}ൽ ഖഹദ\u{d4d}", "ദ\u{d41}ൽ ഹിജ\u{d4d}ജ"], &["മ\u{d41}", "സ", "റ", "റ", "ജ", "ജ", "റ", "ശ", "റ", "ശ", "ദ\u{d41}", "ദ\u{d41}"]], eras: [&["ഹിജറ"], &["ഹിജറ"], &["ഹിജറ"]], date_patterns: ["GGGGG y-MM-dd", "G y, MMM d", "G y, MMMM d", "G y, MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_606: _CalendarData = _CalendarData { months: [&["തിഷ\u{d4d}റി", "ഹെഷ\u{d4d}\u{200c}വൻ", "കിസ\u{d4d}\u{200c}ലെവ\u{d4d}", "ടിവെറ\u{d4d}റ\u{d4d}", "സീബ\u{d3e}റ\u{d4d}റ\u{d4d}", "അദ\u{d3e}ർ I", "അദ\u{d3e}ർ", "നിസ\u{d3e}ൻ", "ഇയ\u{d3e}ർ", "സിവ\u{d3e}ൻ", "ത\u{d3e}മ\u{d42}സ\u{d4d}", "അബ\u{d4d}", "ഏല\u{d41}ൾ", "അദ\u{d3e}ർ II"], &["തിഷ\u{d4d}റി", "ഹെഷ\u{d4d}\u{200c}വൻ", "കിസ\u{d4d}\u{200c}ലെവ\u{d4d}", "ടിവെറ\u{d4d}റ\u{d4d}", "സീബ\u{d3e}റ\u{d4d}റ\u{d4d}", "അദ\u{d3e}ർ I", "അദ\u{d3e}ർ", "നിസ\u{d3e}ൻ", "ഇയ\u{d3e}ർ", "സിവ\u{d3e}ൻ", "ത\u{d3e}മ\u{d42}സ\u{d4d}", "അബ\u{d4d}", "ഏല\u{d41}ൾ", "അദ\u{d3e}ർ II"], &["തി.", "ഹെ.", "കി.", "ടി.", "സീ.", "അ I", "അ.", "നി.", "ഇ.", "സി.", "ത\u{d3e}.", "അ.", "ഏ.", "അ II"], &["തിഷ\u{d4d}റി", "ഹെഷ\u{d4d}\u{200c}വൻ", "കിസ\u{d4d}\u{200c}ലെവ\u{d4d}", "ടിവെറ\u{d4d}റ\u{d4d}", "സീബ\u{d3e}റ\u{d4d}റ\u{d4d}", "അദ\u{d3e}ർ I", "അദ\u{d3e}ർ", "നിസ\u{d3e}ൻ", "ഇയ\u{d3e}ർ", "സിവ\u{d3e}ൻ", "ത\u{d3e}മ\u{d42}സ\u{d4d}", "അബ\u{d4d}", "ഏല\u{d41}ൾ", "അദ\u{d3e}ർ II"], &["തിഷ\u{d4d}റി", "ഹെഷ\u{d4d}\u{200c}വൻ", "കിസ\u{d4d}\u{200c}ലെവ\u{d4d}", "ടിവെറ\u{d4d}റ\u{d4d}", "സീബ\u{d3e}റ\u{d4d}റ\u{d4d}", "അദ\u{d3e}ർ I", "അദ\u{d3e}ർ", "നിസ\u{d3e}ൻ", "ഇയ\u{d3e}ർ", "സിവ\u{d3e}ൻ", "ത\u{d3e}മ\u{d42}സ\u{d4d}", "അബ\u{d4d}", "ഏല\u{d41}ൾ", "അദ\u{d3e}ർ II"], &["തി.", "ഹെ.", "കി.", "ടി.", "സീ.", "അ I", "അ.", "നി.", "ഇ.", "സി.", "ത\u{d3e}.", "അ.", "ഏ.", "അ II"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "G y, MMM d", "G y, MMMM d", "G y, MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_607: _CalendarData = _CalendarData { months: [&["മെസ\u{d4d}\u{200c}കെരെം", "ടെക\u{d4d}കെംറ\u{d4d}റ\u{d4d}", "ഹേദർ", "തഹ\u{d4d}\u{200c}സ\u{d3e}സ\u{d4d}", "ടെർ", "യെക\u{d4d}ക\u{d3e}റ\u{d4d}റിറ\u{d4d}റ\u{d4d}", "മെഗ\u{d3e}ബിറ\u{d4d}റ\u{d4d}", "മിയ\u{d3e}സിയ", "ഗെൻബോട\u{d4d}ട\u{d4d}", "സെനെ", "ഹ\u{d3e}ംലെ", "നെഹ\u{d3e}സെ", "പ\u{d3e}ഗ\u{d41}മെൻ", ""], &["മെസ\u{d4d}\u{200c}കെരെം", "ടെക\u{d4d}കെംറ\u{d4d}റ\u{d4d}", "ഹേദർ", "തഹ\u{d4d}\u{200c}സ\u{d3e}സ\u{d4d}", "ടെർ", "യെക\u{d4d}ക\u{d3e}റ\u{d4d}റിറ\u{d4d}റ\u{d4d}", "മെഗ\u{d3e}ബിറ\u{d4d}റ\u{d4d}", "മിയ\u{d3e}സിയ", "ഗെൻബോട\u{d4d}ട\u{d4d}", "സെനെ", "ഹ\u{d3e}ംലെ", "നെഹ\u{d3e}സെ", "പ\u{d3e}ഗ\u{d41}മെൻ", ""], &["മെ.", "ടെ.", "ഹേ.", "ത.", "ടെ.", "യെ.", "മെ.", "മി.", "ഗെ.", "സെ.", "ഹ\u{d3e}ം", "നെ.", "പ\u{d3e}.", ""], &["മെസ\u{d4d}\u{200c}കെരെം", "ടെക\u{d4d}കെംറ\u{d4d}റ\u{d4d}", "ഹേദർ", "തഹ\u{d4d}\u{200c}സ\u{d3e}സ\u{d4d}", "ടെർ", "യെക\u{d4d}ക\u{d3e}റ\u{d4d}റിറ\u{d4d}റ\u{d4d}", "മെഗ\u{d3e}ബിറ\u{d4d}റ\u{d4d}", "മിയ\u{d3e}സിയ", "ഗെൻബോട\u{d4d}ട\u{d4d}", "സെനെ", "ഹ\u{d3e}ംലെ", "നെഹ\u{d3e}സെ", "പ\u{d3e}ഗ\u{d41}മെൻ", ""], &["മെസ\u{d4d}\u{200c}കെരെം", "ടെക\u{d4d}കെംറ\u{d4d}റ\u{d4d}", "ഹേദർ", "തഹ\u{d4d}\u{200c}സ\u{d3e}സ\u{d4d}", "ടെർ", "യെക\u{d4d}ക\u{d3e}റ\u{d4d}റിറ\u{d4d}റ\u{d4d}", "മെഗ\u{d3e}ബിറ\u{d4d}റ\u{d4d}", "മിയ\u{d3e}സിയ", "ഗെൻബോട\u{d4d}ട\u{d4d}", "സെനെ", "ഹ\u{d3e}ംലെ", "നെഹ\u{d3e}സെ", "പ\u{d3e}ഗ\u{d41}മെൻ", ""], &["മെ.", "ടെ.", "ഹേ.", "ത.", "ടെ.", "യെ.", "മെ.", "മി.", "ഗെ.", "സെ.", "ഹ\u{d3e}ം", "നെ.", "പ\u{d3e}.", ""]], eras: [&["ERA0", "ക\u{d3e}ലഘട\u{d4d}ടം1"], &["ERA0", "ക\u{d3e}ലഘട\u{d4d}ടം1"], &["ERA0", "ക\u{d3e}ലഘട\u{d4d}ടം1"]], date_patterns: ["GGGGG y-MM-dd", "G y, MMM d", "G y, MMMM d", "G y, MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_134: [&_CalendarData; 6] = [&_CALENDAR_602, &_CALENDAR_603, &_CALENDAR_604, &_CALENDAR_605, &_CALENDAR_606, &_CALENDAR_607];
+
+#[rustfmt::skip]
+static _CALENDAR_608: _CalendarData = _CalendarData { months: [&["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"], &["нэгдүгээр сар", "хоёрдугаар сар", "гуравдугаар сар", "дөрөвдүгээр сар", "тавдугаар сар", "зургаадугаар сар", "долоодугаар сар", "наймдугаар сар", "есдүгээр сар", "аравдугаар сар", "арван нэгдүгээр сар", "арван хоёрдугаар сар"], &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"], &["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"], &["Нэгдүгээр сар", "Хоёрдугаар сар", "Гуравдугаар сар", "Дөрөвдүгээр сар", "Тавдугаар сар", "Зургаадугаар сар", "Долоодугаар сар", "Наймдугаар сар", "Есдүгээр сар", "Аравдугаар сар", "Арван нэгдүгээр сар", "Арван хоёрдугаар сар"], &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y.MM.dd", "G y\u{202f}оны MMMын d", "G y\u{202f}оны MM сарын dd", "G y\u{202f}оны MMMMын d. cccc гараг"] };
+
+#[rustfmt::skip]
+static _CALENDAR_609: _CalendarData = _CalendarData { months: [&["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"], &["нэгдүгээр сар", "хоёрдугаар сар", "гуравдугаар сар", "дөрөвдүгээр сар", "тавдугаар сар", "зургаадугаар сар", "долоодугаар сар", "наймдугаар сар", "есдүгээр сар", "аравдугаар сар", "арван нэгдүгээр сар", "арван хоёрдугаар сар"], &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"], &["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"], &["Нэгдүгээр сар", "Хоёрдугаар сар", "Гуравдугаар сар", "Дөрөвдүгээр сар", "Тавдугаар сар", "Зургаадугаар сар", "Долоодугаар сар", "Наймдугаар сар", "Есдүгээр сар", "Аравдугаар сар", "Арван нэгдүгээр сар", "Арван хоёрдугаар сар"], &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"]], eras: [&["МЭӨ", "МЭ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["манай эриний өмнөх", "манай эриний", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["МЭӨ", "МЭ", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y.MM.dd", "G y\u{202f}оны MMMын d", "G y\u{202f}оны MM сарын dd", "G y\u{202f}оны MMMMын d. cccc гараг"] };
+
+#[rustfmt::skip]
+static _CALENDAR_610: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y.MM.dd", "G y\u{202f}оны MMMын d", "G y\u{202f}оны MM сарын dd", "G y\u{202f}оны MMMMын d. cccc гараг"] };
+
+#[rustfmt::skip]
+static _CALENDAR_611: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y.MM.dd", "G y\u{202f}оны MMMын d", "G y\u{202f}оны MM сарын dd", "G y\u{202f}оны MMMMын d. cccc гараг"] };
+
+#[rustfmt::skip]
+static _CALENDAR_612: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y.MM.dd", "G y\u{202f}оны MMMын d", "G y\u{202f}оны MM сарын dd", "G y\u{202f}оны MMMMын d. cccc гараг"] };
+
+#[rustfmt::skip]
+static _CALENDAR_613: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y.MM.dd", "G y\u{202f}оны MMMын d", "G y\u{202f}оны MM сарын dd", "G y\u{202f}оны MMMMын d. cccc гараг"] };
+
+#[rustfmt::skip]
+static _CALENDARS_135: [&_CalendarData; 6] = [&_CALENDAR_608, &_CALENDAR_609, &_CALENDAR_610, &_CALENDAR_611, &_CALENDAR_612, &_CALENDAR_613];
+
+#[rustfmt::skip]
+static _CALENDAR_614: _CalendarData = _CalendarData { months: [&["জন", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "ওগ", "সেপ\u{9cd}টেম\u{9cd}বর", "ওক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জন\u{9c1}ৱ\u{9be}রী", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "\u{200c}ওগষ\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "ওক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}র", "এপ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "ওক", "নব", "ডি"], &["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}র\u{9c1}", "ম\u{9be}র", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}", "আগ", "সেপ\u{9cd}ট", "ওক\u{9cd}টো", "নভে", "ডিসে"], &["জ\u{9be}ন\u{9c1}ৱ\u{9be}রি", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "ওগষ\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "ওক\u{9cd}টোবর", "নবেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}র", "এপ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "ও", "নব", "ডি"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "MMMM d, y G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_615: _CalendarData = _CalendarData { months: [&["জন", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "ওগ", "সেপ\u{9cd}টেম\u{9cd}বর", "ওক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জন\u{9c1}ৱ\u{9be}রী", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "\u{200c}ওগষ\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "ওক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}র", "এপ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "ওক", "নব", "ডি"], &["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}র\u{9c1}", "ম\u{9be}র", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}", "আগ", "সেপ\u{9cd}ট", "ওক\u{9cd}টো", "নভে", "ডিসে"], &["জ\u{9be}ন\u{9c1}ৱ\u{9be}রি", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "ওগষ\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "ওক\u{9cd}টোবর", "নবেম\u{9cd}বর", "ডিসেম\u{9cd}বর"], &["জ\u{9be}", "ফে", "ম\u{9be}র", "এপ", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আ", "সে", "ও", "নব", "ডি"]], eras: [&["খ\u{9c3}: মম\u{9be}ং", "খ\u{9c3}: মত\u{9c1}ং", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9c3}: মম\u{9be}ং", "খ\u{9c3}: মত\u{9c1}ং", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["খ\u{9c3}: মম\u{9be}ং", "খ\u{9c3}: মত\u{9c1}ং", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "MMMM d, y G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_616: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "MMMM d, y G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_617: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "MMMM d, y G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_618: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "MMMM d, y G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_619: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "MMMM d, y G, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_136: [&_CalendarData; 6] = [&_CALENDAR_614, &_CALENDAR_615, &_CALENDAR_616, &_CALENDAR_617, &_CALENDAR_618, &_CALENDAR_619];
+
+#[rustfmt::skip]
+static _CALENDAR_620: _CalendarData = _CalendarData { months: [&["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नोव\u{94d}ह\u{947}\u{902}", "डिस\u{947}\u{902}"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["जा", "फ\u{947}", "मा", "ए", "म\u{947}", "ज\u{942}", "ज\u{941}", "ऑ", "स", "ऑ", "नो", "डि"], &["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नोव\u{94d}ह\u{947}\u{902}", "डिस\u{947}\u{902}"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["जा", "फ\u{947}", "मा", "ए", "म\u{947}", "ज\u{942}", "ज\u{941}", "ऑ", "स", "ऑ", "नो", "डि"]], eras: [&["इसप\u{942}."], &["इसवीसन प\u{942}र\u{94d}व"], &["इसप\u{942}."]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_621: _CalendarData = _CalendarData { months: [&["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नोव\u{94d}ह\u{947}\u{902}", "डिस\u{947}\u{902}"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["जा", "फ\u{947}", "मा", "ए", "म\u{947}", "ज\u{942}", "ज\u{941}", "ऑ", "स", "ऑ", "नो", "डि"], &["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नोव\u{94d}ह\u{947}\u{902}", "डिस\u{947}\u{902}"], &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"], &["जा", "फ\u{947}", "मा", "ए", "म\u{947}", "ज\u{942}", "ज\u{941}", "ऑ", "स", "ऑ", "नो", "डि"]], eras: [&["ई. स. प\u{942}.", "इ. स.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसवीसनप\u{942}र\u{94d}व", "ईसवीसन", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ई. स. प\u{942}.", "इ. स.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_622: _CalendarData = _CalendarData { months: [&["फरवरदिन", "ओर\u{94d}दिब\u{947}ह\u{947}श\u{94d}त", "खोरदाद", "तिर", "मोरदाद", "शाहरीवार", "म\u{947}ह\u{947}र", "अबान", "अझार", "द\u{947}", "बाहमान", "एसफा\u{902}द"], &["फरवरदिन", "ओर\u{94d}दिब\u{947}ह\u{947}श\u{94d}त", "खोरदाद", "तिर", "मोरदाद", "शाहरीवार", "म\u{947}ह\u{947}र", "अबान", "अझार", "द\u{947}", "बाहमान", "एसफा\u{902}द"], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२"], &["फरवरदिन", "ओर\u{94d}दिब\u{947}ह\u{947}श\u{94d}त", "खोरदाद", "तिर", "मोरदाद", "शाहरीवार", "म\u{947}ह\u{947}र", "अबान", "अझार", "द\u{947}", "बाहमान", "एसफा\u{902}द"], &["फरवरदिन", "ओर\u{94d}दिब\u{947}ह\u{947}श\u{94d}त", "खोरदाद", "तिर", "मोरदाद", "शाहरीवार", "म\u{947}ह\u{947}र", "अबान", "अझार", "द\u{947}", "बाहमान", "एसफा\u{902}द"], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_623: _CalendarData = _CalendarData { months: [&["मोह.", "सफ.", "राबी I", "राबी II", "ज\u{941}मा. I", "ज\u{941}मा. II", "रझा.", "शाबा.", "रम.", "शव\u{94d}वा.", "ध\u{941}ल-की.", "ध\u{941}ल-हि."], &["मोहरम", "सफर", "राबी I", "राबी II", "ज\u{941}मादा I", "ज\u{941}मादा II", "रझाब", "शाबान", "रमजान", "शव\u{94d}वाल", "ध\u{941}ल-कीदाह", "ध\u{941}ल-हिजाह"], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२"], &["मोह.", "सफ.", "राबी I", "राबी II", "ज\u{941}मा. I", "ज\u{941}मा. II", "रझा.", "शाबा.", "रम.", "शव\u{94d}वा.", "ध\u{941}ल-की.", "ध\u{941}ल-हि."], &["मोहरम", "सफर", "राबी I", "राबी II", "ज\u{941}मादा I", "ज\u{941}मादा II", "रझाब", "शाबान", "रमजान", "शव\u{94d}वाल", "ध\u{941}ल-कीदाह", "ध\u{941}ल-हिजाह"], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२"]], eras: [&["हि.व."], &["हिजरी वर\u{94d}ष"], &["हि.व."]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_624: _CalendarData = _CalendarData { months: [&["तिशरी", "ह\u{947}शवान", "किस\u{94d}ल\u{947}व", "त\u{947}व\u{947}त", "श\u{947}वात", "अदार I", "अदार", "निसान", "इयार", "सिवान", "ताम\u{941}झ", "अव", "इल\u{941}ल", "अदार II"], &["तिशरी", "ह\u{947}शवान", "किस\u{94d}ल\u{947}व", "त\u{947}व\u{947}त", "श\u{947}वात", "अदार I", "अदार", "निसान", "इयार", "सिवान", "ताम\u{941}झ", "अव", "इल\u{941}ल", "अदार II"], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२", "१३", "७"], &["तिशरी", "ह\u{947}शवान", "किस\u{94d}ल\u{947}व", "त\u{947}व\u{947}त", "श\u{947}वात", "अदार I", "अदार", "निसान", "इयार", "सिवान", "ताम\u{941}झ", "अव", "इल\u{941}ल", "अदार II"], &["तिशरी", "ह\u{947}शवान", "किस\u{94d}ल\u{947}व", "त\u{947}व\u{947}त", "श\u{947}वात", "अदार I", "अदार", "निसान", "इयार", "सिवान", "ताम\u{941}झ", "अव", "इल\u{941}ल", "अदार II"], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२", "१३", "७"]], eras: [&["AM"], &["ऍन\u{94d}नो म\u{941}\u{902}डी"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_625: _CalendarData = _CalendarData { months: [&["म\u{947}सक\u{947}र\u{947}म", "त\u{947}क\u{947}म\u{94d}त", "ह\u{947}दार", "ताहसास", "त\u{947}र", "य\u{947}कातित", "म\u{947}गाबित", "मियाझिया", "ग\u{947}नबोत", "स\u{947}न\u{947}", "हाम\u{94d}ल\u{947}", "न\u{947}हास\u{94d}स\u{947}", "पाग\u{941}म\u{947}न", ""], &["म\u{947}सक\u{947}र\u{947}म", "त\u{947}क\u{947}म\u{94d}त", "ह\u{947}दार", "ताहसास", "त\u{947}र", "य\u{947}कातित", "म\u{947}गाबित", "मियाझिया", "ग\u{947}नबोत", "स\u{947}न\u{947}", "हाम\u{94d}ल\u{947}", "न\u{947}हास\u{94d}स\u{947}", "पाग\u{941}म\u{947}न", ""], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२", "१३", ""], &["म\u{947}सक\u{947}र\u{947}म", "त\u{947}क\u{947}म\u{94d}त", "ह\u{947}दार", "ताहसास", "त\u{947}र", "य\u{947}कातित", "म\u{947}गाबित", "मियाझिया", "ग\u{947}नबोत", "स\u{947}न\u{947}", "हाम\u{94d}ल\u{947}", "न\u{947}हास\u{94d}स\u{947}", "पाग\u{941}म\u{947}न", ""], &["म\u{947}सक\u{947}र\u{947}म", "त\u{947}क\u{947}म\u{94d}त", "ह\u{947}दार", "ताहसास", "त\u{947}र", "य\u{947}कातित", "म\u{947}गाबित", "मियाझिया", "ग\u{947}नबोत", "स\u{947}न\u{947}", "हाम\u{94d}ल\u{947}", "न\u{947}हास\u{94d}स\u{947}", "पाग\u{941}म\u{947}न", ""], &["१", "२", "३", "४", "५", "६", "७", "८", "९", "१०", "११", "१२", "१३", ""]], eras: [&["ERA0", "य\u{941}ग1"], &["ERA0", "य\u{941}ग1"], &["ERA0", "य\u{941}ग1"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_137: [&_CalendarData; 6] = [&_CALENDAR_620, &_CALENDAR_621, &_CALENDAR_622, &_CALENDAR_623, &_CALENDAR_624, &_CALENDAR_625];
+
+#[rustfmt::skip]
+static _CALENDAR_626: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_627: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]], eras: [&["S.M.", "TM", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["S.M.", "TM", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["S.M.", "TM", "M", "T", "S", "H", "R"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_628: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_629: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Rej.", "Syaa.", "Ram.", "Syaw.", "Zulk.", "Zulh."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jamadilawal", "Jamadilakhir", "Rejab", "Syaaban", "Ramadan", "Syawal", "Zulkaedah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Rej.", "Syaa.", "Ram.", "Syaw.", "Zulk.", "Zulh."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jamadilawal", "Jamadilakhir", "Rejab", "Syaaban", "Ramadan", "Syawal", "Zulkaedah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["H"], &["AH"], &["H"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_630: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_631: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_138: [&_CalendarData; 6] = [&_CALENDAR_626, &_CALENDAR_627, &_CALENDAR_628, &_CALENDAR_629, &_CALENDAR_630, &_CALENDAR_631];
+
+#[rustfmt::skip]
+static _CALENDAR_632: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_633: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]], eras: [&["S.M.", "TM", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["S.M.", "TM", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["S.M.", "TM", "M", "T", "S", "H", "R"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_634: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_635: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Rej.", "Syaa.", "Ram.", "Syaw.", "Zulk.", "Zulh."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jamadilawal", "Jamadilakhir", "Rejab", "Syaaban", "Ramadan", "Syawal", "Zulkaedah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Rej.", "Syaa.", "Ram.", "Syaw.", "Zulk.", "Zulh."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jamadilawal", "Jamadilakhir", "Rejab", "Syaaban", "Ramadan", "Syawal", "Zulkaedah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["H"], &["AH"], &["H"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_636: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/MM/y GGGGG", "dd/MM/y G", "d MMMM y G", "dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_139: [&_CalendarData; 6] = [&_CALENDAR_632, &_CALENDAR_633, &_CALENDAR_634, &_CALENDAR_635, &_CALENDAR_630, &_CALENDAR_636];
+
+#[rustfmt::skip]
+static _CALENDAR_637: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_638: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ogo", "Sep", "Okt", "Nov", "Dis"], &["Januari", "Februari", "Mac", "April", "Mei", "Jun", "Julai", "Ogos", "September", "Oktober", "November", "Disember"], &["J", "F", "M", "A", "M", "J", "J", "O", "S", "O", "N", "D"]], eras: [&["S.M.", "TM", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["S.M.", "TM", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["S.M.", "TM", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_639: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Rej.", "Syaa.", "Ram.", "Syaw.", "Zulk.", "Zulh."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jamadilawal", "Jamadilakhir", "Rejab", "Syaaban", "Ramadan", "Syawal", "Zulkaedah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jam. I", "Jam. II", "Rej.", "Syaa.", "Ram.", "Syaw.", "Zulk.", "Zulh."], &["Muharam", "Safar", "Rabiulawal", "Rabiulakhir", "Jamadilawal", "Jamadilakhir", "Rejab", "Syaaban", "Ramadan", "Syawal", "Zulkaedah", "Zulhijah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["H"], &["AH"], &["H"]], date_patterns: ["dd/MM/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_140: [&_CalendarData; 6] = [&_CALENDAR_637, &_CALENDAR_638, &_CALENDAR_434, &_CALENDAR_639, &_CALENDAR_630, &_CALENDAR_437];
+
+#[rustfmt::skip]
+static _CALENDAR_640: _CalendarData = _CalendarData { months: [&["Jan", "Fra", "Mar", "Apr", "Mej", "Ġun", "Lul", "Aww", "Set", "Ott", "Nov", "Diċ"], &["Jannar", "Frar", "Marzu", "April", "Mejju", "Ġunju", "Lulju", "Awwissu", "Settembru", "Ottubru", "Novembru", "Diċembru"], &["J", "F", "M", "A", "M", "Ġ", "L", "A", "S", "O", "N", "D"], &["Jan", "Fra", "Mar", "Apr", "Mej", "Ġun", "Lul", "Aww", "Set", "Ott", "Nov", "Diċ"], &["Jannar", "Frar", "Marzu", "April", "Mejju", "Ġunju", "Lulju", "Awwissu", "Settembru", "Ottubru", "Novembru", "Diċembru"], &["Jn", "Fr", "Mz", "Ap", "Mj", "Ġn", "Lj", "Aw", "St", "Ob", "Nv", "Dċ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'ta’' MMMM y G", "EEEE, d 'ta’' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_641: _CalendarData = _CalendarData { months: [&["Jan", "Fra", "Mar", "Apr", "Mej", "Ġun", "Lul", "Aww", "Set", "Ott", "Nov", "Diċ"], &["Jannar", "Frar", "Marzu", "April", "Mejju", "Ġunju", "Lulju", "Awwissu", "Settembru", "Ottubru", "Novembru", "Diċembru"], &["J", "F", "M", "A", "M", "Ġ", "L", "A", "S", "O", "N", "D"], &["Jan", "Fra", "Mar", "Apr", "Mej", "Ġun", "Lul", "Aww", "Set", "Ott", "Nov", "Diċ"], &["Jannar", "Frar", "Marzu", "April", "Mejju", "Ġunju", "Lulju", "Awwissu", "Settembru", "Ottubru", "Novembru", "Diċembru"], &["Jn", "Fr", "Mz", "Ap", "Mj", "Ġn", "Lj", "Aw", "St", "Ob", "Nv", "Dċ"]], eras: [&["QK", "WK", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Qabel Kristu", "Wara Kristu", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["QK", "WK", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'ta’' MMMM y G", "EEEE, d 'ta’' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_642: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'ta’' MMMM y G", "EEEE, d 'ta’' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_643: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'ta’' MMMM y G", "EEEE, d 'ta’' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_644: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'ta’' MMMM y G", "EEEE, d 'ta’' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_645: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'ta’' MMMM y G", "EEEE, d 'ta’' MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_141: [&_CalendarData; 6] = [&_CALENDAR_640, &_CALENDAR_641, &_CALENDAR_642, &_CALENDAR_643, &_CALENDAR_644, &_CALENDAR_645];
+
+#[rustfmt::skip]
+static _CALENDAR_646: _CalendarData = _CalendarData { months: [&["ဇန\u{103a}", "ဖေ", "မတ\u{103a}", "ဧ", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}", "ဩ", "စက\u{103a}", "အောက\u{103a}", "န\u{102d}\u{102f}", "ဒ\u{102e}"], &["ဇန\u{103a}နဝါရ\u{102e}", "ဖေဖော\u{103a}ဝါရ\u{102e}", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}လ\u{102d}\u{102f}င\u{103a}", "ဩဂ\u{102f}တ\u{103a}", "စက\u{103a}တင\u{103a}ဘာ", "အောက\u{103a}တ\u{102d}\u{102f}ဘာ", "န\u{102d}\u{102f}ဝင\u{103a}ဘာ", "ဒ\u{102e}ဇင\u{103a}ဘာ"], &["ဇ", "ဖ", "မ", "ဧ", "မ", "ဇ", "ဇ", "ဩ", "စ", "အ", "န", "ဒ"], &["ဇန\u{103a}", "ဖေ", "မတ\u{103a}", "ဧ", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}", "ဩ", "စက\u{103a}", "အောက\u{103a}", "န\u{102d}\u{102f}", "ဒ\u{102e}"], &["ဇန\u{103a}နဝါရ\u{102e}", "ဖေဖော\u{103a}ဝါရ\u{102e}", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}လ\u{102d}\u{102f}င\u{103a}", "ဩဂ\u{102f}တ\u{103a}", "စက\u{103a}တင\u{103a}ဘာ", "အောက\u{103a}တ\u{102d}\u{102f}ဘာ", "န\u{102d}\u{102f}ဝင\u{103a}ဘာ", "ဒ\u{102e}ဇင\u{103a}ဘာ"], &["ဇ", "ဖ", "မ", "ဧ", "မ", "ဇ", "ဇ", "ဩ", "စ", "အ", "န", "ဒ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG d/M/y", "G d MMM y", "G dd MMMM y", "EEEE G dd MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_647: _CalendarData = _CalendarData { months: [&["ဇန\u{103a}", "ဖေ", "မတ\u{103a}", "ဧ", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}", "ဩ", "စက\u{103a}", "အောက\u{103a}", "န\u{102d}\u{102f}", "ဒ\u{102e}"], &["ဇန\u{103a}နဝါရ\u{102e}", "ဖေဖော\u{103a}ဝါရ\u{102e}", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}လ\u{102d}\u{102f}င\u{103a}", "ဩဂ\u{102f}တ\u{103a}", "စက\u{103a}တင\u{103a}ဘာ", "အောက\u{103a}တ\u{102d}\u{102f}ဘာ", "န\u{102d}\u{102f}ဝင\u{103a}ဘာ", "ဒ\u{102e}ဇင\u{103a}ဘာ"], &["ဇ", "ဖ", "မ", "ဧ", "မ", "ဇ", "ဇ", "ဩ", "စ", "အ", "န", "ဒ"], &["ဇန\u{103a}", "ဖေ", "မတ\u{103a}", "ဧ", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}", "ဩ", "စက\u{103a}", "အောက\u{103a}", "န\u{102d}\u{102f}", "ဒ\u{102e}"], &["ဇန\u{103a}နဝါရ\u{102e}", "ဖေဖော\u{103a}ဝါရ\u{102e}", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}လ\u{102d}\u{102f}င\u{103a}", "ဩဂ\u{102f}တ\u{103a}", "စက\u{103a}တင\u{103a}ဘာ", "အောက\u{103a}တ\u{102d}\u{102f}ဘာ", "န\u{102d}\u{102f}ဝင\u{103a}ဘာ", "ဒ\u{102e}ဇင\u{103a}ဘာ"], &["ဇ", "ဖ", "မ", "ဧ", "မ", "ဇ", "ဇ", "ဩ", "စ", "အ", "န", "ဒ"]], eras: [&["ဘ\u{102e}စ\u{102e}", "အဒေ\u{102e}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ခရစ\u{103a}တော\u{103a} မပေါ\u{103a}မ\u{102e}န\u{103e}စ\u{103a}", "ခရစ\u{103a}န\u{103e}စ\u{103a}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ဘ\u{102e}စ\u{102e}", "အဒေ\u{102e}", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG d/M/y", "G d MMM y", "G dd MMMM y", "EEEE G dd MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_648: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG d/M/y", "G d MMM y", "G dd MMMM y", "EEEE G dd MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_649: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG d/M/y", "G d MMM y", "G dd MMMM y", "EEEE G dd MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_650: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG d/M/y", "G d MMM y", "G dd MMMM y", "EEEE G dd MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_651: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG d/M/y", "G d MMM y", "G dd MMMM y", "EEEE G dd MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_142: [&_CalendarData; 6] = [&_CALENDAR_646, &_CALENDAR_647, &_CALENDAR_648, &_CALENDAR_649, &_CALENDAR_650, &_CALENDAR_651];
+
+#[rustfmt::skip]
+static _CALENDAR_652: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mars", "apr.", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."], &["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], &["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.y G", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_653: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mars", "apr.", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."], &["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"], &["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["f.Kr.", "e.Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["før Kristus", "etter Kristus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["f.Kr.", "e.Kr.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M y G", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_654: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y G", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_655: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-q.", "dhuʻl-h."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadan", "shawwal", "dhuʻl-qiʻdah", "dhuʻl-hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-q.", "Dhuʻl-H."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaʻban", "ramadan", "shawwal", "dhuʻl-qiʻdah", "dhuʻl-hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.M.y G", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_656: _CalendarData = _CalendarData { months: [&["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y G", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_657: _CalendarData = _CalendarData { months: [&["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["0. t.a.", "1. t.a."], &["0. tidsalder", "1. tidsalder"], &["TA0", "TA1"]], date_patterns: ["d.M.y G", "d. MMM y G", "d. MMMM y G", "EEEE d. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_143: [&_CalendarData; 6] = [&_CALENDAR_652, &_CALENDAR_653, &_CALENDAR_654, &_CALENDAR_655, &_CALENDAR_656, &_CALENDAR_657];
+
+#[rustfmt::skip]
+static _CALENDAR_658: _CalendarData = _CalendarData { months: [&["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_659: _CalendarData = _CalendarData { months: [&["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["Januaar", "Februaar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktover", "November", "Dezember"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_144: [&_CalendarData; 6] = [&_CALENDAR_658, &_CALENDAR_659, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_660: _CalendarData = _CalendarData { months: [&["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जन", "फ\u{947}ब", "मार\u{94d}च", "अप\u{94d}र", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "अग", "स\u{947}प", "अक\u{94d}टो", "नोभ\u{947}", "डिस\u{947}"], &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जन", "फ\u{947}\u{947}ब", "मार\u{94d}च", "अप\u{94d}र", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "अग", "स\u{947}प", "अक\u{94d}टो", "नोभ\u{947}", "डिस\u{947}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_661: _CalendarData = _CalendarData { months: [&["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जन", "फ\u{947}ब", "मार\u{94d}च", "अप\u{94d}र", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "अग", "स\u{947}प", "अक\u{94d}टो", "नोभ\u{947}", "डिस\u{947}"], &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"], &["जन", "फ\u{947}\u{947}ब", "मार\u{94d}च", "अप\u{94d}र", "म\u{947}", "ज\u{941}न", "ज\u{941}ल", "अग", "स\u{947}प", "अक\u{94d}टो", "नोभ\u{947}", "डिस\u{947}"]], eras: [&["ईसा प\u{942}र\u{94d}व", "सन\u{94d}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा प\u{942}र\u{94d}व", "सन\u{94d}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा प\u{942}र\u{94d}व", "सन\u{94d}", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_145: [&_CalendarData; 6] = [&_CALENDAR_660, &_CALENDAR_661, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_662: _CalendarData = _CalendarData { months: [&["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_663: _CalendarData = _CalendarData { months: [&["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"], &["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["v.Chr.", "n.Chr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["voor Christus", "na Christus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v.C.", "n.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_664: _CalendarData = _CalendarData { months: [&["Moeh.", "Saf.", "Rab. I", "Rab. II", "Joem. I", "Joem. II", "Raj.", "Sja.", "Ram.", "Sjaw.", "Doe al k.", "Doe al h."], &["Moeharram", "Safar", "Rabiʻa al awal", "Rabiʻa al thani", "Joemadʻal awal", "Joemadʻal thani", "Rajab", "Sjaʻaban", "Ramadan", "Sjawal", "Doe al kaʻaba", "Doe al hizja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Moeh.", "Saf.", "Rab. I", "Rab. II", "Joem. I", "Joem. II", "Raj.", "Sja.", "Ram.", "Sjaw.", "Doe al k.", "Doe al h."], &["Moeharram", "Safar", "Rabiʻa al awal", "Rabiʻa al thani", "Joemadʻal awal", "Joemadʻal thani", "Rajab", "Sjaʻaban", "Ramadan", "Sjawal", "Doe al kaʻaba", "Doe al hizja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["Saʻna Hizjria"], &["AH"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_665: _CalendarData = _CalendarData { months: [&["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahsas", "T’er", "Yäkatit", "Mägabit", "Miyazya", "Genbot", "Säne", "Hamle", "Nähase", "Pagumän", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["era 0", "era 1"], &["tijdperk 0", "tijdperk 1"], &["era 0", "era 1"]], date_patterns: ["dd-MM-yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_146: [&_CalendarData; 6] = [&_CALENDAR_662, &_CALENDAR_663, &_CALENDAR_357, &_CALENDAR_664, &_CALENDAR_359, &_CALENDAR_665];
+
+#[rustfmt::skip]
+static _CALENDAR_666: _CalendarData = _CalendarData { months: [&["ߓߌ\u{7f2}ߠ", "ߞߏ\u{7f2}ߞ", "ߕߙߊ", "ߞߏ\u{7f2}ߘ", "ߘߓߊ\u{7ec}ߕ", "ߥߊ\u{7ec}ߛ", "ߞߊ\u{7ec}ߙ", "ߘߓߊ\u{7ec}ߓ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓ", "ߣߍߣ", "ߞߏߟ"], &["ߓߌ\u{7f2}ߠߊߥߎߟߋ\u{7f2}", "ߞߏ\u{7f2}ߞߏߜߍ", "ߕߙߊߓߊ", "ߞߏ\u{7f2}ߞߏߘߌ\u{7ec}ߓߌ", "ߘߓߊ\u{7ec}ߕߊ", "ߥߊ\u{7ec}ߛߌ\u{7ec}ߥߙߊ", "ߞߊ\u{7ec}ߙߌߝߐ\u{7ed}", "ߘߓߊ\u{7ec}ߓߌߟߊ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓߌߕߌ\u{7ee}", "ߣߍߣߍߓߊ", "ߞߏߟߌ\u{7f2}ߞߏߟߌ\u{7f2}"], &["ߓ", "ߞ", "ߕ", "ߞ", "ߘ", "ߥ", "ߞ", "ߘ", "ߕ", "ߞ", "ߣ", "ߞ"], &["ߓߌ\u{7f2}ߠ", "ߞߏ\u{7f2}ߞ", "ߕߙߊ", "ߞߏ\u{7f2}ߘ", "ߘߓߊ\u{7ec}ߕ", "ߥߊ\u{7ec}ߛ", "ߞߊ\u{7ec}ߙ", "ߘߓߊ\u{7ec}ߓ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓ", "ߣߍߣ", "ߞߏߟ"], &["ߓߌ\u{7f2}ߠߊߥߎߟߋ\u{7f2}", "ߞߏ\u{7f2}ߞߏߜߍ", "ߕߙߊߓߊ", "ߞߏ\u{7f2}ߞߏߘߌ\u{7ec}ߓߌ", "ߘߓߊ\u{7ec}ߕߊ", "ߥߊ\u{7ec}ߛߌ\u{7ec}ߥߙߊ", "ߞߊ\u{7ec}ߙߌߝߐ\u{7ed}", "ߘߓߊ\u{7ec}ߓߌߟߊ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓߌߕߌ\u{7ee}", "ߣߍߣߍߓߊ", "ߞߏߟߌ\u{7f2}ߞߏߟߌ\u{7f2}"], &["ߓ", "ߞ", "ߕ", "ߞ", "ߘ", "ߥ", "ߞ", "ߘ", "ߕ", "ߞ", "ߣ", "ߞ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_667: _CalendarData = _CalendarData { months: [&["ߓߌ\u{7f2}ߠ", "ߞߏ\u{7f2}ߞ", "ߕߙߊ", "ߞߏ\u{7f2}ߘ", "ߘߓߊ\u{7ec}ߕ", "ߥߊ\u{7ec}ߛ", "ߞߊ\u{7ec}ߙ", "ߘߓߊ\u{7ec}ߓ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓ", "ߣߍߣ", "ߞߏߟ"], &["ߓߌ\u{7f2}ߠߊߥߎߟߋ\u{7f2}", "ߞߏ\u{7f2}ߞߏߜߍ", "ߕߙߊߓߊ", "ߞߏ\u{7f2}ߞߏߘߌ\u{7ec}ߓߌ", "ߘߓߊ\u{7ec}ߕߊ", "ߥߊ\u{7ec}ߛߌ\u{7ec}ߥߙߊ", "ߞߊ\u{7ec}ߙߌߝߐ\u{7ed}", "ߘߓߊ\u{7ec}ߓߌߟߊ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓߌߕߌ\u{7ee}", "ߣߍߣߍߓߊ", "ߞߏߟߌ\u{7f2}ߞߏߟߌ\u{7f2}"], &["ߓ", "ߞ", "ߕ", "ߞ", "ߘ", "ߥ", "ߞ", "ߘ", "ߕ", "ߞ", "ߣ", "ߞ"], &["ߓߌ\u{7f2}ߠ", "ߞߏ\u{7f2}ߞ", "ߕߙߊ", "ߞߏ\u{7f2}ߘ", "ߘߓߊ\u{7ec}ߕ", "ߥߊ\u{7ec}ߛ", "ߞߊ\u{7ec}ߙ", "ߘߓߊ\u{7ec}ߓ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓ", "ߣߍߣ", "ߞߏߟ"], &["ߓߌ\u{7f2}ߠߊߥߎߟߋ\u{7f2}", "ߞߏ\u{7f2}ߞߏߜߍ", "ߕߙߊߓߊ", "ߞߏ\u{7f2}ߞߏߘߌ\u{7ec}ߓߌ", "ߘߓߊ\u{7ec}ߕߊ", "ߥߊ\u{7ec}ߛߌ\u{7ec}ߥߙߊ", "ߞߊ\u{7ec}ߙߌߝߐ\u{7ed}", "ߘߓߊ\u{7ec}ߓߌߟߊ", "ߕߎߟߊߝߌ\u{7f2}", "ߞߏ\u{7f2}ߓߌߕߌ\u{7ee}", "ߣߍߣߍߓߊ", "ߞߏߟߌ\u{7f2}ߞߏߟߌ\u{7f2}"], &["ߓ", "ߞ", "ߕ", "ߞ", "ߘ", "ߥ", "ߞ", "ߘ", "ߕ", "ߞ", "ߣ", "ߞ"]], eras: [&["ߌߛ. ߡ. ߢߍ\u{7eb}", "ߌߛ. ߡ. ߞߐ\u{7eb}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ߌߛߊ\u{7eb} ߡߏߦߌ ߢߍ\u{7eb}", "ߌߛߊ\u{7eb} ߡߏߦߌ ߞߐ\u{7eb}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ߌߛ. ߢߍ\u{7eb}", "ߌߛ. ߞߐ\u{7eb}", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_147: [&_CalendarData; 6] = [&_CALENDAR_666, &_CALENDAR_667, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_668: _CalendarData = _CalendarData { months: [&["Phere", "Dibo", "Hlak", "Mora", "Mei", "June", "Mose", "Agosetose", "Lewe", "Dipha", "Diba", "Manth"], &["Janeware", "Febereware", "Matšhe", "Aporele", "Mei", "June", "Julae", "Agosetose", "Setemere", "Oktobore", "Nofemere", "Disemere"], &["P", "D", "H", "M", "M", "P", "M", "P", "L", "D", "D", "M"], &["Phere", "Dibo", "Hlak", "Mora", "Mei", "June", "Mose", "Agosetose", "Lewe", "Dipha", "Diba", "Manth"], &["Janeware", "Febereware", "Matšhe", "Aporele", "Mei", "June", "Julae", "Agosetose", "Setemere", "Oktobore", "Nofemere", "Disemere"], &["P", "D", "H", "M", "M", "P", "M", "P", "L", "D", "D", "M"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_669: _CalendarData = _CalendarData { months: [&["Phere", "Dibo", "Hlak", "Mora", "Mei", "June", "Mose", "Agosetose", "Lewe", "Dipha", "Diba", "Manth"], &["Janeware", "Febereware", "Matšhe", "Aporele", "Mei", "June", "Julae", "Agosetose", "Setemere", "Oktobore", "Nofemere", "Disemere"], &["P", "D", "H", "M", "M", "P", "M", "P", "L", "D", "D", "M"], &["Phere", "Dibo", "Hlak", "Mora", "Mei", "June", "Mose", "Agosetose", "Lewe", "Dipha", "Diba", "Manth"], &["Janeware", "Febereware", "Matšhe", "Aporele", "Mei", "June", "Julae", "Agosetose", "Setemere", "Oktobore", "Nofemere", "Disemere"], &["P", "D", "H", "M", "M", "P", "M", "P", "L", "D", "D", "M"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Before Christ", "Anno Domini", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_148: [&_CalendarData; 6] = [&_CALENDAR_668, &_CALENDAR_669, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_670: _CalendarData = _CalendarData { months: [&["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_671: _CalendarData = _CalendarData { months: [&["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_149: [&_CalendarData; 6] = [&_CALENDAR_670, &_CALENDAR_671, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_672: _CalendarData = _CalendarData { months: [&["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["de genièr", "de febrièr", "de març", "d’abril", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a. C.", "d. C.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_150: [&_CalendarData; 6] = [&_CALENDAR_670, &_CALENDAR_672, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_673: _CalendarData = _CalendarData { months: [&["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], &["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], &["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"], &["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], &["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], &["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_674: _CalendarData = _CalendarData { months: [&["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], &["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], &["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"], &["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], &["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], &["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"]], eras: [&["DhKD", "BA", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Dhaloota Kiristoos Dura", "Bara Araaraa", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Dh", "B", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_675: _CalendarData = _CalendarData { months: [&["Ful", "Onk", "Sad", "Mud", "Ama", "Gur", "Bit", "Ebi", "Cam", "Wax", "Ado", "Hag", "Qam", ""], &["Fulbaana", "Guraandhala", "Sadaasa", "Mudde", "Amajji", "Waxabajjii", "Bitootessa", "Eebila", "Caamsaa", "Onkoloolessa", "Adoolessa", "Hagayya", "Qaam’ee", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["F", "O", "S", "M", "Am", "G", "B", "E", "C", "W", "Ad", "H", "Q", ""], &["Fulbaana", "Guraandhala", "Sadaasa", "Mudde", "Amajji", "Waxabajjii", "Bitootessa", "Eebila", "Caamsaa", "Onkoloolessa", "Adoolessa", "Hagayya", "Qaam’ee", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_151: [&_CalendarData; 6] = [&_CALENDAR_673, &_CALENDAR_674, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_675];
+
+#[rustfmt::skip]
+static _CALENDAR_676: _CalendarData = _CalendarData { months: [&["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], &["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], &["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"], &["Ama", "Gur", "Bitootessa", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sadaasa", "Mud"], &["Amajjii", "Guraandhala", "Bitootessa", "Eebila", "Caamsaa", "Waxabajjii", "Adoolessa", "Hagayya", "Fulbaana", "Onkoloolessa", "Sadaasa", "Mudde"], &["A", "G", "B", "E", "C", "W", "A", "H", "F", "O", "S", "M"]], eras: [&["KD", "BA", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Dhaloota Kiristoos Dura", "Bara Araaraa", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Dh", "B", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_152: [&_CalendarData; 6] = [&_CALENDAR_673, &_CALENDAR_676, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_675];
+
+#[rustfmt::skip]
+static _CALENDAR_677: _CalendarData = _CalendarData { months: [&["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}", "ଫେ", "ମ\u{b3e}", "ଅ", "ମଇ", "ଜ\u{b41}", "ଜ\u{b41}", "ଅ", "ସେ", "ଅ", "ନ", "ଡ\u{b3f}"], &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}", "ଫେ", "ମ\u{b3e}", "ଅ", "ମଇ", "ଜ\u{b41}", "ଜ\u{b41}", "ଅ", "ସେ", "ଅ", "ନ", "ଡ\u{b3f}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_678: _CalendarData = _CalendarData { months: [&["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}", "ଫେ", "ମ\u{b3e}", "ଅ", "ମଇ", "ଜ\u{b41}", "ଜ\u{b41}", "ଅ", "ସେ", "ଅ", "ନ", "ଡ\u{b3f}"], &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"], &["ଜ\u{b3e}", "ଫେ", "ମ\u{b3e}", "ଅ", "ମଇ", "ଜ\u{b41}", "ଜ\u{b41}", "ଅ", "ସେ", "ଅ", "ନ", "ଡ\u{b3f}"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ଖ\u{b4d}ରୀଷ\u{b4d}ଟପ\u{b42}ର\u{b4d}ବ", "ଖ\u{b4d}ରୀଷ\u{b4d}ଟ\u{b3e}ବ\u{b4d}ଦ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_153: [&_CalendarData; 6] = [&_CALENDAR_677, &_CALENDAR_678, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_679: _CalendarData = _CalendarData { months: [&["ਜਨ", "ਫ\u{a3c}ਰ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾ", "ਅਗ", "ਸਤ\u{a70}", "ਅਕਤ\u{a42}", "ਨਵ\u{a70}", "ਦਸ\u{a70}"], &["ਜਨਵਰੀ", "ਫ\u{a3c}ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}ਲ", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾਈ", "ਅਗਸਤ", "ਸਤ\u{a70}ਬਰ", "ਅਕਤ\u{a42}ਬਰ", "ਨਵ\u{a70}ਬਰ", "ਦਸ\u{a70}ਬਰ"], &["ਜ", "ਫ\u{a3c}", "ਮਾ", "ਅ", "ਮ", "ਜ\u{a42}", "ਜ\u{a41}", "ਅ", "ਸ", "ਅ", "ਨ", "ਦ"], &["ਜਨ", "ਫ\u{a3c}ਰ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾ", "ਅਗ", "ਸਤ\u{a70}", "ਅਕਤ\u{a42}", "ਨਵ\u{a70}", "ਦਸ\u{a70}"], &["ਜਨਵਰੀ", "ਫ\u{a3c}ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}ਲ", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾਈ", "ਅਗਸਤ", "ਸਤ\u{a70}ਬਰ", "ਅਕਤ\u{a42}ਬਰ", "ਨਵ\u{a70}ਬਰ", "ਦਸ\u{a70}ਬਰ"], &["ਜ", "ਫ\u{a3c}", "ਮਾ", "ਅ", "ਮ", "ਜ\u{a42}", "ਜ\u{a41}", "ਅ", "ਸ", "ਅ", "ਨ", "ਦ"]], eras: [&["ਈਸਵੀ ਪ\u{a42}ਰਵ"], &["ਈਸਵੀ ਪ\u{a42}ਰਵ"], &["ਈ. ਪ\u{a42}."]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_680: _CalendarData = _CalendarData { months: [&["ਜਨ", "ਫ\u{a3c}ਰ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾ", "ਅਗ", "ਸਤ\u{a70}", "ਅਕਤ\u{a42}", "ਨਵ\u{a70}", "ਦਸ\u{a70}"], &["ਜਨਵਰੀ", "ਫ\u{a3c}ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}ਲ", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾਈ", "ਅਗਸਤ", "ਸਤ\u{a70}ਬਰ", "ਅਕਤ\u{a42}ਬਰ", "ਨਵ\u{a70}ਬਰ", "ਦਸ\u{a70}ਬਰ"], &["ਜ", "ਫ\u{a3c}", "ਮਾ", "ਅ", "ਮ", "ਜ\u{a42}", "ਜ\u{a41}", "ਅ", "ਸ", "ਅ", "ਨ", "ਦ"], &["ਜਨ", "ਫ\u{a3c}ਰ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾ", "ਅਗ", "ਸਤ\u{a70}", "ਅਕਤ\u{a42}", "ਨਵ\u{a70}", "ਦਸ\u{a70}"], &["ਜਨਵਰੀ", "ਫ\u{a3c}ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}ਲ", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾਈ", "ਅਗਸਤ", "ਸਤ\u{a70}ਬਰ", "ਅਕਤ\u{a42}ਬਰ", "ਨਵ\u{a70}ਬਰ", "ਦਸ\u{a70}ਬਰ"], &["ਜ", "ਫ\u{a3c}", "ਮਾ", "ਅ", "ਮ", "ਜ\u{a42}", "ਜ\u{a41}", "ਅ", "ਸ", "ਅ", "ਨ", "ਦ"]], eras: [&["ਈ. ਪ\u{a42}.", "ਸ\u{a70}ਨ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ਈਸਵੀ ਪ\u{a42}ਰਵ", "ਈਸਵੀ ਸ\u{a70}ਨ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ਈ.ਪ\u{a42}.", "ਸ\u{a70}ਨ", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_681: _CalendarData = _CalendarData { months: [&["ਫਾਰਵਰਡੀਨ", "ਔਰਡਾਈਬਹ\u{a48}ਸ\u{a3c}ਟ", "ਖ\u{a4b}ਡਰਡ", "ਟਿਰ", "ਮ\u{a4b}ਰਡਾਦ", "ਸ\u{a3c}ਰਾਇਵਰ", "ਮ\u{a47}ਹਰ", "ਅਬਾਨ", "ਅਜ\u{a3c}ਾਰ", "ਡ\u{a47}ਅ", "ਬਾਹਮਨ", "ਐਸਫ\u{a70}ਡ"], &["ਫਾਰਵਰਡੀਨ", "ਔਰਡਾਈਬਹ\u{a48}ਸ\u{a3c}ਟ", "ਖ\u{a4b}ਡਰਡ", "ਟਿਰ", "ਮ\u{a4b}ਰਡਾਦ", "ਸ\u{a3c}ਰਾਇਵਰ", "ਮ\u{a47}ਹਰ", "ਅਬਾਨ", "ਅਜ\u{a3c}ਾਰ", "ਡ\u{a47}ਅ", "ਬਾਹਮਨ", "ਐਸਫ\u{a70}ਡ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ਫਾਰਵਰਡੀਨ", "ਔਰਡਾਈਬਹ\u{a48}ਸ\u{a3c}ਟ", "ਖ\u{a4b}ਡਰਡ", "ਟਿਰ", "ਮ\u{a4b}ਰਡਾਦ", "ਸ\u{a3c}ਰਾਇਵਰ", "ਮ\u{a47}ਹਰ", "ਅਬਾਨ", "ਅਜ\u{a3c}ਾਰ", "ਡ\u{a47}ਅ", "ਬਾਹਮਨ", "ਐਸਫ\u{a70}ਡ"], &["ਫਾਰਵਰਡੀਨ", "ਔਰਡਾਈਬਹ\u{a48}ਸ\u{a3c}ਟ", "ਖ\u{a4b}ਡਰਡ", "ਟਿਰ", "ਮ\u{a4b}ਰਡਾਦ", "ਸ\u{a3c}ਰਾਇਵਰ", "ਮ\u{a47}ਹਰ", "ਅਬਾਨ", "ਅਜ\u{a3c}ਾਰ", "ਡ\u{a47}ਅ", "ਬਾਹਮਨ", "ਐਸਫ\u{a70}ਡ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_682: _CalendarData = _CalendarData { months: [&["ਮ\u{a41}ਹ\u{a71}.", "ਸਫ.", "ਰਬ. I", "ਰਬ. II", "ਜ\u{a41}ਮ. I", "ਜ\u{a41}ਮ. II", "ਰਾਜ.", "ਸ\u{a3c}ਾ.", "ਰਾਮ.", "ਸ\u{a3c}ਅ.", "ਦ\u{a42}-ਅਲ-ਕੀ.", "ਦ\u{a42}-ਅਲ-ਹਿ."], &["ਮ\u{a41}ਹ\u{a71}ਰਮ", "ਸਫਰ", "ਰਬੀ ʻ I", "ਰਬੀ ʻ II", "ਜ\u{a41}ਮਾਦਾ I", "ਜ\u{a41}ਮਾਦਾ II", "ਰਜਬ", "ਸ\u{a3c}ਬਾਨ", "ਰਮਜ\u{a3c}ਾਨ", "ਸ\u{a3c}ਵਾਲ", "ਦ\u{a42}-ਅਲ-ਕੀਦਾਹ", "ਦ\u{a42}-ਅਲ-ਹਿਜ\u{a4d}ਹਾ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ਮ\u{a41}ਹ\u{a71}.", "ਸਫ.", "ਰਬ. I", "ਰਬ. II", "ਜ\u{a41}ਮ. I", "ਜ\u{a41}ਮ. II", "ਰਾਜ.", "ਸ\u{a3c}ਾ.", "ਰਾਮ.", "ਸ\u{a3c}ਅ.", "ਦ\u{a42}-ਅਲ-ਕੀ.", "ਦ\u{a42}-ਅਲ-ਹਿ."], &["ਮ\u{a41}ਹ\u{a71}ਰਮ", "ਸਫਰ", "ਰਬੀʻ I", "ਰਬੀʻ II", "ਜ\u{a41}ਮਾਦਾ I", "ਜ\u{a41}ਮਾਦਾ II", "ਰਜਬ", "ਸ\u{a3c}ਬਾਨ", "ਰਮਜ\u{a3c}ਾਨ", "ਸ\u{a3c}ਵਾਲ", "ਦ\u{a42}-ਅਲ-ਕੀਦਾਹ", "ਦ\u{a42}-ਅਲ-ਹਿਜ\u{a4d}ਹਾ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_683: _CalendarData = _CalendarData { months: [&["ਤਿਸ\u{a3c}ਰੀ", "ਹ\u{a47}ਸ\u{a3c}ਵਨ", "ਕਿਸਲ\u{a47}ਵ", "ਟ\u{a47}ਵਟ", "ਸ\u{a3c}\u{a47}ਵਟ", "ਅਦਰ I", "ਅਦਰ", "ਨਿਸਾਨ", "ਅਇਯਰ", "ਸਿਵਾਨ", "ਤਾਮ\u{a41}ਜ\u{a3c}", "ਅਵ", "ਏਲ\u{a41}ਲ", "ਅਦਰ II"], &["ਤਿਸ\u{a3c}ਰੀ", "ਹ\u{a47}ਸ\u{a3c}ਵਨ", "ਕਿਸਲ\u{a47}ਵ", "ਟ\u{a47}ਵਟ", "ਸ\u{a3c}\u{a47}ਵਟ", "ਅਦਰ I", "ਅਦਰ", "ਨਿਸਾਨ", "ਅਇਯਰ", "ਸਿਵਾਨ", "ਤਾਮ\u{a41}ਜ\u{a3c}", "ਅਵ", "ਏਲ\u{a41}ਲ", "ਅਦਰ II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ਤਿਸ\u{a3c}ਰੀ", "ਹ\u{a47}ਸ\u{a3c}ਵਨ", "ਕਿਸਲ\u{a47}ਵ", "ਟ\u{a47}ਵਟ", "ਸ\u{a3c}\u{a47}ਵਟ", "ਅਦਰ I", "ਅਦਰ", "ਨਿਸਾਨ", "ਅਇਯਰ", "ਸਿਵਾਨ", "ਤਾਮ\u{a41}ਜ\u{a3c}", "ਅਵ", "ਏਲ\u{a41}ਲ", "ਅਦਰ II"], &["ਤਿਸ\u{a3c}ਰੀ", "ਹ\u{a47}ਸ\u{a3c}ਵਨ", "ਕਿਸਲ\u{a47}ਵ", "ਟ\u{a47}ਵਟ", "ਸ\u{a3c}\u{a47}ਵਟ", "ਅਦਰ I", "ਅਦਰ", "ਨਿਸਾਨ", "ਅਇਯਰ", "ਸਿਵਾਨ", "ਤਾਮ\u{a41}ਜ\u{a3c}", "ਅਵ", "ਏਲ\u{a41}ਲ", "ਅਦਰ II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_684: _CalendarData = _CalendarData { months: [&["ਮ\u{a47}ਸਕ\u{a47}ਰ\u{a47}ਮ", "ਟ\u{a47}ਕ\u{a47}ਮਟ", "ਹ\u{a48}ਡਰ", "ਤਾਹਸਸ", "ਟਰ", "ਯਕ\u{a47}ਟਿਤ", "ਮ\u{a47}ਗਾਬਿਟ", "ਮਿਆਜਿਆ", "ਜ\u{a47}ਨਬ\u{a4b}ਟ", "ਸੀਨ", "ਹਮਲ\u{a47}", "ਨ\u{a47}ਹਾਸ\u{a47}", "ਪਾਗ\u{a42}ਮ\u{a47}ਨ", ""], &["ਮ\u{a47}ਸਕ\u{a47}ਰ\u{a47}ਮ", "ਟ\u{a47}ਕ\u{a47}ਮਟ", "ਹ\u{a48}ਡਰ", "ਤਾਹਸਸ", "ਟਰ", "ਯਕ\u{a47}ਟਿਤ", "ਮ\u{a47}ਗਾਬਿਟ", "ਮਿਆਜਿਆ", "ਜ\u{a47}ਨਬ\u{a4b}ਟ", "ਸੀਨ", "ਹਮਲ\u{a47}", "ਨ\u{a47}ਹਾਸ\u{a47}", "ਪਾਗ\u{a42}ਮ\u{a47}ਨ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["ਮ\u{a47}ਸਕ\u{a47}ਰ\u{a47}ਮ", "ਟ\u{a47}ਕ\u{a47}ਮਟ", "ਹ\u{a48}ਡਰ", "ਤਾਹਸਸ", "ਟਰ", "ਯਕ\u{a47}ਟਿਤ", "ਮ\u{a47}ਗਾਬਿਟ", "ਮਿਆਜਿਆ", "ਜ\u{a47}ਨਬ\u{a4b}ਟ", "ਸੀਨ", "ਹਮਲ\u{a47}", "ਨ\u{a47}ਹਾਸ\u{a47}", "ਪਾਗ\u{a42}ਮ\u{a47}ਨ", ""], &["ਮ\u{a47}ਸਕ\u{a47}ਰ\u{a47}ਮ", "ਟ\u{a47}ਕ\u{a47}ਮਟ", "ਹ\u{a48}ਡਰ", "ਤਾਹਸਸ", "ਟਰ", "ਯਕ\u{a47}ਟਿਤ", "ਮ\u{a47}ਗਾਬਿਟ", "ਮਿਆਜਿਆ", "ਜ\u{a47}ਨਬ\u{a4b}ਟ", "ਸੀਨ", "ਹਮਲ\u{a47}", "ਨ\u{a47}ਹਾਸ\u{a47}", "ਪਾਗ\u{a42}ਮ\u{a47}ਨ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ਕਾਲ1"], &["ERA0", "ਕਾਲ1"], &["ERA0", "ਕਾਲ1"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_154: [&_CalendarData; 6] = [&_CALENDAR_679, &_CALENDAR_680, &_CALENDAR_681, &_CALENDAR_682, &_CALENDAR_683, &_CALENDAR_684];
+
+#[rustfmt::skip]
+static _CALENDAR_685: _CalendarData = _CalendarData { months: [&["Jén", "Fẹ\u{301}b", "Mach", "Épr", "Mee", "Jun", "Jul", "Ọgọ", "Sẹp", "Ọkt", "Nọv", "Dis"], &["Jénúári", "Fẹ\u{301}búári", "Mach", "Éprel", "Mee", "Jun", "Julai", "Ọgọst", "Sẹptẹ\u{301}mba", "Ọktóba", "Nọvẹ\u{301}mba", "Disẹ\u{301}mba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jén", "Fẹ\u{301}b", "Mach", "Épr", "Mee", "Jun", "Jul", "Ọ\u{301}gọ", "Sẹp", "Ọkt", "Nọv", "Dis"], &["Jénúári", "Fẹ\u{301}búári", "Mach", "Éprel", "Mee", "Jun", "Julai", "Ọgọst", "Sẹptẹ\u{301}mba", "Ọktóba", "Nọvẹ\u{301}mba", "Disẹ\u{301}mba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_686: _CalendarData = _CalendarData { months: [&["Jén", "Fẹ\u{301}b", "Mach", "Épr", "Mee", "Jun", "Jul", "Ọgọ", "Sẹp", "Ọkt", "Nọv", "Dis"], &["Jénúári", "Fẹ\u{301}búári", "Mach", "Éprel", "Mee", "Jun", "Julai", "Ọgọst", "Sẹptẹ\u{301}mba", "Ọktóba", "Nọvẹ\u{301}mba", "Disẹ\u{301}mba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jén", "Fẹ\u{301}b", "Mach", "Épr", "Mee", "Jun", "Jul", "Ọ\u{301}gọ", "Sẹp", "Ọkt", "Nọv", "Dis"], &["Jénúári", "Fẹ\u{301}búári", "Mach", "Éprel", "Mee", "Jun", "Julai", "Ọgọst", "Sẹptẹ\u{301}mba", "Ọktóba", "Nọvẹ\u{301}mba", "Disẹ\u{301}mba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BK", "KIY", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Bifọ\u{301} Kraist", "Kraist Im Yiẹ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BK", "KIY", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_155: [&_CalendarData; 6] = [&_CALENDAR_685, &_CALENDAR_686, &_CALENDAR_141, &_CALENDAR_142, &_CALENDAR_143, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_687: _CalendarData = _CalendarData { months: [&["sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru"], &["stycznia", "lutego", "marca", "kwietnia", "maja", "czerwca", "lipca", "sierpnia", "września", "października", "listopada", "grudnia"], &["s", "l", "m", "k", "m", "c", "l", "s", "w", "p", "l", "g"], &["sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru"], &["styczeń", "luty", "marzec", "kwiecień", "maj", "czerwiec", "lipiec", "sierpień", "wrzesień", "październik", "listopad", "grudzień"], &["S", "L", "M", "K", "M", "C", "L", "S", "W", "P", "L", "G"]], eras: [&["e.b."], &["e.b."], &["e.b."]], date_patterns: ["dd.MM.y G", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_688: _CalendarData = _CalendarData { months: [&["sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru"], &["stycznia", "lutego", "marca", "kwietnia", "maja", "czerwca", "lipca", "sierpnia", "września", "października", "listopada", "grudnia"], &["s", "l", "m", "k", "m", "c", "l", "s", "w", "p", "l", "g"], &["sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru"], &["styczeń", "luty", "marzec", "kwiecień", "maj", "czerwiec", "lipiec", "sierpień", "wrzesień", "październik", "listopad", "grudzień"], &["S", "L", "M", "K", "M", "C", "L", "S", "W", "P", "L", "G"]], eras: [&["p.n.e.", "n.e.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["przed naszą erą", "naszej ery", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["p.n.e.", "n.e.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y G", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_689: _CalendarData = _CalendarData { months: [&["Farwardin", "Ordibeheszt", "Chordād", "Tir", "Mordād", "Szahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Esfand"], &["Farwardin", "Ordibeheszt", "Chordād", "Tir", "Mordād", "Szahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farwardin", "Ordibeheszt", "Chordād", "Tir", "Mordād", "Szahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Esfand"], &["Farwardin", "Ordibeheszt", "Chordād", "Tir", "Mordād", "Szahriwar", "Mehr", "Ābān", "Āsar", "Déi", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y G", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_690: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Dżu. I", "Dżu. II", "Ra.", "Sza.", "Ram.", "Szaw.", "Zu al-k.", "Zu al-h."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Dżumada I", "Dżumada II", "Radżab", "Szaban", "Ramadan", "Szawwal", "Zu al-kada", "Zu al-hidżdża"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Dżu. I", "Dżu. II", "Ra.", "Sza.", "Ram.", "Szaw.", "Zu al-k.", "Zu al-h."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Dżumada I", "Dżumada II", "Radżab", "Szaban", "Ramadan", "Szawwal", "Zu al-kada", "Zu al-hidżdża"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y G", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_691: _CalendarData = _CalendarData { months: [&["Tiszri", "Cheszwan", "Kislew", "Tewet", "Szwat", "Adar I", "Adar", "Nisan", "Ijar", "Siwan", "Tamuz", "Aw", "Elul", "Adar II"], &["Tiszri", "Cheszwan", "Kislew", "Tewet", "Szwat", "Adar I", "Adar", "Nisan", "Ijar", "Siwan", "Tamuz", "Aw", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tiszri", "Cheszwan", "Kislew", "Tewet", "Szwat", "Adar I", "Adar", "Nisan", "Ijar", "Siwan", "Tamuz", "Aw", "Elul", "Adar II"], &["Tiszri", "Cheszwan", "Kislew", "Tewet", "Szwat", "Adar I", "Adar", "Nisan", "Ijar", "Siwan", "Tamuz", "Aw", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y G", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_692: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y G", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_156: [&_CalendarData; 6] = [&_CALENDAR_687, &_CALENDAR_688, &_CALENDAR_689, &_CALENDAR_690, &_CALENDAR_691, &_CALENDAR_692];
+
+#[rustfmt::skip]
+static _CALENDAR_693: _CalendarData = _CalendarData { months: [&["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_694: _CalendarData = _CalendarData { months: [&["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["rags", "wassarins", "pūlis", "sakkis", "zallaws", "sīmenis", "līpa", "daggis", "sillins", "spallins", "lapkrūtis", "sallaws"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_157: [&_CalendarData; 6] = [&_CALENDAR_693, &_CALENDAR_694, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_695: _CalendarData = _CalendarData { months: [&["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"], &["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوري", "فېبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDAR_696: _CalendarData = _CalendarData { months: [&["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سېپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["ج", "ف", "م", "ا", "م", "ج", "ج", "ا", "س", "ا", "ن", "د"], &["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوري", "فېبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["له میلاد وړاندې", "م.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["له میلاد څخه وړاندې", "له میلاد څخه وروسته", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["له میلاد وړاندې", "م.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDAR_697: _CalendarData = _CalendarData { months: [&["وری", "غویی", "غبرگولی", "چنگاښ", "زمری", "وږی", "تله", "لړم", "لیندۍ", "مرغومی", "سلواغه", "کب"], &["وری", "غویی", "غبرگولی", "چنگاښ", "زمری", "وږی", "تله", "لړم", "لیندۍ", "مرغومی", "سلواغه", "کب"], &["۱", "۲", "۳", "۴", "۵", "۶", "۷", "۸", "۹", "۱۰", "۱۱", "۱۲"], &["وری", "غویی", "غبرگولی", "چنگاښ", "زمری", "وږی", "تله", "لړم", "لیندۍ", "مرغومی", "سلواغه", "کب"], &["وری", "غویی", "غبرگولی", "چنگاښ", "زمری", "وږی", "تله", "لړم", "لیندۍ", "مرغومی", "سلواغه", "کب"], &["۱", "۲", "۳", "۴", "۵", "۶", "۷", "۸", "۹", "۱۰", "۱۱", "۱۲"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDAR_698: _CalendarData = _CalendarData { months: [&["محرم", "صفر", "ربيع", "ربيع II", "جماد", "جماد ۲", "رجب", "شعبان", "رمضان", "شوال", "دالقاعده", "ذي الحج"], &["محرم", "صفر", "ربيع", "ربيع II", "جماعه", "جماعه II", "رجب", "شعبان", "رمضان", "شوال", "ذي القعده", "ذي الحج"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["محرم", "صفر", "ربيع", "ربيع II", "جماد", "جماد ۲", "رجب", "شعبان", "رمضان", "شوال", "دالقاعده", "ذي الحج"], &["محرم", "صفر", "ربيع", "ربيع II", "جماعه", "جماعه II", "رجب", "شعبان", "رمضان", "شوال", "ذي القعده", "ذي الحج"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDAR_699: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDAR_700: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDARS_158: [&_CalendarData; 6] = [&_CALENDAR_695, &_CALENDAR_696, &_CALENDAR_697, &_CALENDAR_698, &_CALENDAR_699, &_CALENDAR_700];
+
+#[rustfmt::skip]
+static _CALENDAR_701: _CalendarData = _CalendarData { months: [&["محرم", "صفر", "ربيع", "ربيع II", "جماد", "جماد ۲", "رجب", "شعبان", "رمضان", "شوال", "دالقاعده", "ذي الحج"], &["محرم", "د صفرے د", "ربيع", "ربيع II", "جماعه", "جماعه II", "رجب", "شعبان", "رمضان", "شوال", "ذي القعده", "ذي الحج"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["محرم", "صفر", "ربيع", "ربيع II", "جماد", "جماد ۲", "رجب", "شعبان", "رمضان", "شوال", "دالقاعده", "ذي الحج"], &["محرم", "د صفرے د", "ربيع", "ربيع II", "جماعه", "جماعه II", "رجب", "شعبان", "رمضان", "شوال", "ذي القعده", "ذي الحج"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y/M/d", "G y MMM d", "د G y د MMMM d", "EEEE د G y د MMMM d"] };
+
+#[rustfmt::skip]
+static _CALENDARS_159: [&_CalendarData; 6] = [&_CALENDAR_695, &_CALENDAR_696, &_CALENDAR_697, &_CALENDAR_701, &_CALENDAR_699, &_CALENDAR_700];
+
+#[rustfmt::skip]
+static _CALENDAR_702: _CalendarData = _CalendarData { months: [&["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["EB"], &["EB"]], date_patterns: ["dd/MM/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_703: _CalendarData = _CalendarData { months: [&["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "depois de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_704: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_705: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_706: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_707: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y GGGGG", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_160: [&_CalendarData; 6] = [&_CALENDAR_702, &_CALENDAR_703, &_CALENDAR_704, &_CALENDAR_705, &_CALENDAR_706, &_CALENDAR_707];
+
+#[rustfmt::skip]
+static _CALENDAR_708: _CalendarData = _CalendarData { months: [&["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y G", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_709: _CalendarData = _CalendarData { months: [&["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."], &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["a.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["antes de Cristo", "depois de Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y G", "dd/MM/y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_710: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y G", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_711: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y G", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_712: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y G", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_713: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y G", "d 'de' MMM 'de' y G", "d 'de' MMMM 'de' y G", "EEEE, d 'de' MMMM 'de' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_161: [&_CalendarData; 6] = [&_CALENDAR_708, &_CALENDAR_709, &_CALENDAR_710, &_CALENDAR_711, &_CALENDAR_712, &_CALENDAR_713];
+
+#[rustfmt::skip]
+static _CALENDAR_714: _CalendarData = _CalendarData { months: [&["Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Set", "Oct", "Nov", "Dic"], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Set", "Oct", "Nov", "Dic"], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM y, G", "d MMMM y, G", "EEEE, d MMMM y, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_715: _CalendarData = _CalendarData { months: [&["Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Set", "Oct", "Nov", "Dic"], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Set", "Oct", "Nov", "Dic"], &["Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Setiembre", "Octubre", "Noviembre", "Diciembre"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["a.d.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ñawpa cristu", "chanta cristu", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.d.", "dC", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM y, G", "d MMMM y, G", "EEEE, d MMMM y, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_716: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM y, G", "d MMMM y, G", "EEEE, d MMMM y, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_717: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d MMM y, G", "d MMMM y, G", "EEEE, d MMMM y, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_718: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM y, G", "d MMMM y, G", "EEEE, d MMMM y, G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_719: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM y, G", "d MMMM y, G", "EEEE, d MMMM y, G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_162: [&_CalendarData; 6] = [&_CALENDAR_714, &_CALENDAR_715, &_CALENDAR_716, &_CALENDAR_717, &_CALENDAR_718, &_CALENDAR_719];
+
+#[rustfmt::skip]
+static _CALENDAR_720: _CalendarData = _CalendarData { months: [&["schan.", "favr.", "mars", "avr.", "matg", "zercl.", "fan.", "avust", "sett.", "oct.", "nov.", "dec."], &["da schaner", "da favrer", "da mars", "d’avrigl", "da matg", "da zercladur", "da fanadur", "d’avust", "da settember", "d’october", "da november", "da december"], &["S", "F", "M", "A", "M", "Z", "F", "A", "S", "O", "N", "D"], &["schan.", "favr.", "mars", "avr.", "matg", "zercl.", "fan.", "avust", "sett.", "oct.", "nov.", "dec."], &["schaner", "favrer", "mars", "avrigl", "matg", "zercladur", "fanadur", "avust", "settember", "october", "november", "december"], &["S", "F", "M", "A", "M", "Z", "F", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d MMMM y G", "EEEE', ils' d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_721: _CalendarData = _CalendarData { months: [&["schan.", "favr.", "mars", "avr.", "matg", "zercl.", "fan.", "avust", "sett.", "oct.", "nov.", "dec."], &["da schaner", "da favrer", "da mars", "d’avrigl", "da matg", "da zercladur", "da fanadur", "d’avust", "da settember", "d’october", "da november", "da december"], &["S", "F", "M", "A", "M", "Z", "F", "A", "S", "O", "N", "D"], &["schan.", "favr.", "mars", "avr.", "matg", "zercl.", "fan.", "avust", "sett.", "oct.", "nov.", "dec."], &["schaner", "favrer", "mars", "avrigl", "matg", "zercladur", "fanadur", "avust", "settember", "october", "november", "december"], &["S", "F", "M", "A", "M", "Z", "F", "A", "S", "O", "N", "D"]], eras: [&["av. Cr.", "s. Cr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["avant Cristus", "suenter Cristus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. Cr.", "s. Cr.", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d MMMM y G", "EEEE', ils' d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_722: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d MMMM y G", "EEEE', ils' d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_723: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d MMMM y G", "EEEE', ils' d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_724: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d MMMM y G", "EEEE', ils' d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_725: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-y GGGGG", "dd-MM-y G", "d MMMM y G", "EEEE', ils' d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_163: [&_CalendarData; 6] = [&_CALENDAR_720, &_CALENDAR_721, &_CALENDAR_722, &_CALENDAR_723, &_CALENDAR_724, &_CALENDAR_725];
+
+#[rustfmt::skip]
+static _CALENDAR_726: _CalendarData = _CalendarData { months: [&["ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.", "dec."], &["ianuarie", "februarie", "martie", "aprilie", "mai", "iunie", "iulie", "august", "septembrie", "octombrie", "noiembrie", "decembrie"], &["I", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"], &["ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.", "dec."], &["ianuarie", "februarie", "martie", "aprilie", "mai", "iunie", "iulie", "august", "septembrie", "octombrie", "noiembrie", "decembrie"], &["I", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]], eras: [&["e.b."], &["era budistă"], &["e.b."]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_727: _CalendarData = _CalendarData { months: [&["ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.", "dec."], &["ianuarie", "februarie", "martie", "aprilie", "mai", "iunie", "iulie", "august", "septembrie", "octombrie", "noiembrie", "decembrie"], &["I", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"], &["ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.", "dec."], &["ianuarie", "februarie", "martie", "aprilie", "mai", "iunie", "iulie", "august", "septembrie", "octombrie", "noiembrie", "decembrie"], &["I", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]], eras: [&["î.Hr.", "d.Hr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["înainte de Hristos", "după Hristos", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["î.Hr.", "d.Hr.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_728: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "A-Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "A-Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "A-Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "A-Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["A.P."], &["Anno Persico"], &["A.P."]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_729: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["A.H."], &["AH"]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_730: _CalendarData = _CalendarData { months: [&["Tișrei", "Heșvan", "Kislev", "Tevet", "Șevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul", "Adar II"], &["Tișrei", "Heșvan", "Kislev", "Tevet", "Șevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tișrei", "Heșvan", "Kislev", "Tevet", "Șevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul", "Adar II"], &["Tișrei", "Heșvan", "Kislev", "Tevet", "Șevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["A.M."], &["A.M."], &["A.M."]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_731: _CalendarData = _CalendarData { months: [&["meskerem", "taqemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehase", "pagumen", ""], &["meskerem", "taqemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehase", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "taqemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehase", "pagumen", ""], &["meskerem", "taqemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehase", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "d.Într."], &["ERA0", "după Întrupare"], &["ERA0", "d.Într."]], date_patterns: ["dd.MM.y GGGGG", "dd.MM.y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_164: [&_CalendarData; 6] = [&_CALENDAR_726, &_CALENDAR_727, &_CALENDAR_728, &_CALENDAR_729, &_CALENDAR_730, &_CALENDAR_731];
+
+#[rustfmt::skip]
+static _CALENDAR_732: _CalendarData = _CalendarData { months: [&["янв.", "февр.", "мар.", "апр.", "мая", "июн.", "июл.", "авг.", "сент.", "окт.", "нояб.", "дек."], &["января", "февраля", "марта", "апреля", "мая", "июня", "июля", "августа", "сентября", "октября", "ноября", "декабря"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["янв.", "февр.", "март", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], &["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["BE"], &["буддийская эра"], &["бэ"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_733: _CalendarData = _CalendarData { months: [&["янв.", "февр.", "мар.", "апр.", "мая", "июн.", "июл.", "авг.", "сент.", "окт.", "нояб.", "дек."], &["января", "февраля", "марта", "апреля", "мая", "июня", "июля", "августа", "сентября", "октября", "ноября", "декабря"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["янв.", "февр.", "март", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], &["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["до н. э.", "н. э.", "Эпоха Мэйдзи", "Эпоха Тайсьо", "Сьова", "Эпоха Хэйсэй", "Рэйва"], &["до Рождества Христова", "от Рождества Христова", "Эпоха Мэйдзи", "Эпоха Тайсьо", "Сьова", "Эпоха Хэйсэй", "Рэйва"], &["до н.э.", "н.э.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_734: _CalendarData = _CalendarData { months: [&["фарвардин", "ордибехешт", "хордад", "тир", "мордад", "шахривер", "мехр", "абан", "азер", "дей", "бахман", "эсфанд"], &["фарвардин", "ордибехешт", "хордад", "тир", "мордад", "шахривер", "мехр", "абан", "азер", "дей", "бахман", "эсфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["фарвардин", "ордибехешт", "хордад", "тир", "мордад", "шахривер", "мехр", "абан", "азер", "дей", "бахман", "эсфанд"], &["фарвардин", "ордибехешт", "хордад", "тир", "мордад", "шахривер", "мехр", "абан", "азер", "дей", "бахман", "эсфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["перс. год"], &["персидский год"], &["перс. год"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_735: _CalendarData = _CalendarData { months: [&["мух.", "саф.", "раб. I", "раб. II", "джум. I", "джум. II", "радж.", "шааб.", "рам.", "шав.", "зуль-к.", "зуль-х."], &["мухаррам", "сафар", "раби-уль-авваль", "раби-уль-ахир", "джумад-уль-авваль", "джумад-уль-ахир", "раджаб", "шаабан", "рамадан", "шавваль", "зуль-каада", "зуль-хиджжа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["мух.", "саф.", "раб. I", "раб. II", "джум. I", "джум. II", "радж.", "шааб.", "рам.", "шав.", "зуль-к.", "зуль-х."], &["мухаррам", "сафар", "раби-уль-авваль", "раби-уль-ахир", "джумад-уль-авваль", "джумад-уль-ахир", "раджаб", "шаабан", "рамадан", "шавваль", "зуль-каада", "зуль-хиджжа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["после хиджры"], &["AH"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_736: _CalendarData = _CalendarData { months: [&["тишрей", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ияр", "сиван", "таммуз", "ав", "элул", "адар II"], &["тишрей", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ияр", "сиван", "таммуз", "ав", "элул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["тишрей", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ияр", "сиван", "таммуз", "ав", "элул", "адар II"], &["тишрей", "хешван", "кислев", "тевет", "шеват", "адар I", "адар", "нисан", "ияр", "сиван", "таммуз", "ав", "элул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["от сотворения мира"], &["AM"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_737: _CalendarData = _CalendarData { months: [&["мескерем", "текемт", "хедар", "тахсас", "тер", "якатит", "магабит", "миазия", "генбот", "сэнэ", "хамлэ", "нахасэ", "эпагомен", ""], &["мескерем", "текемт", "хедар", "тахсас", "тер", "якатит", "магабит", "миазия", "генбот", "сэнэ", "хамлэ", "нахасэ", "эпагомен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["мескерем", "текемт", "хедар", "тахсас", "тер", "якатит", "магабит", "миазия", "генбот", "сэнэ", "хамлэ", "нахасэ", "эпагомен", ""], &["мескерем", "текемт", "хедар", "тахсас", "тер", "якатит", "магабит", "миазия", "генбот", "сэнэ", "хамлэ", "нахасэ", "эпагомен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "от Христа"], &["ERA0", "от воплощения Христа"], &["ERA0", "от Христа"]], date_patterns: ["dd.MM.y G", "d MMM y\u{202f}г. G", "d MMMM y\u{202f}г. G", "EEEE, d MMMM y\u{202f}г. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_165: [&_CalendarData; 6] = [&_CALENDAR_732, &_CALENDAR_733, &_CALENDAR_734, &_CALENDAR_735, &_CALENDAR_736, &_CALENDAR_737];
+
+#[rustfmt::skip]
+static _CALENDAR_738: _CalendarData = _CalendarData { months: [&["mut.", "gas.", "wer.", "mat.", "gic.", "kam.", "nya.", "kan.", "nze.", "ukw.", "ugu.", "uku."], &["Mutarama", "Gashyantare", "Werurwe", "Mata", "Gicurasi", "Kamena", "Nyakanga", "Kanama", "Nzeri", "Ukwakira", "Ugushyingo", "Ukuboza"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["mut.", "gas.", "wer.", "mat.", "gic.", "kam.", "nya.", "kan.", "nze.", "ukw.", "ugu.", "uku."], &["Mutarama", "Gashyantare", "Werurwe", "Mata", "Gicurasi", "Kamena", "Nyakanga", "Kanama", "Nzeri", "Ukwakira", "Ugushyingo", "Ukuboza"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_739: _CalendarData = _CalendarData { months: [&["mut.", "gas.", "wer.", "mat.", "gic.", "kam.", "nya.", "kan.", "nze.", "ukw.", "ugu.", "uku."], &["Mutarama", "Gashyantare", "Werurwe", "Mata", "Gicurasi", "Kamena", "Nyakanga", "Kanama", "Nzeri", "Ukwakira", "Ugushyingo", "Ukuboza"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["mut.", "gas.", "wer.", "mat.", "gic.", "kam.", "nya.", "kan.", "nze.", "ukw.", "ugu.", "uku."], &["Mutarama", "Gashyantare", "Werurwe", "Mata", "Gicurasi", "Kamena", "Nyakanga", "Kanama", "Nzeri", "Ukwakira", "Ugushyingo", "Ukuboza"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG yy/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDARS_166: [&_CalendarData; 6] = [&_CALENDAR_738, &_CALENDAR_739, &_CALENDAR_14, &_CALENDAR_15, &_CALENDAR_16, &_CALENDAR_17];
+
+#[rustfmt::skip]
+static _CALENDAR_740: _CalendarData = _CalendarData { months: [&["जनवरी:", "फरवरी:", "मार\u{94d}च:", "अप\u{94d}र\u{948}ल:", "मई", "ज\u{942}न:", "ज\u{941}लाई:", "अगस\u{94d}त:", "सित\u{902}बर:", "अक\u{94d}त\u{942}बर:", "नव\u{902}बर:", "दिस\u{902}बर:"], &["जनवरीमासः", "फरवरीमासः", "मार\u{94d}चमासः", "अप\u{94d}र\u{948}लमासः", "मईमासः", "ज\u{942}नमासः", "ज\u{941}लाईमासः", "अगस\u{94d}तमासः", "सित\u{902}बरमासः", "अक\u{94d}त\u{942}बरमासः", "नव\u{902}बरमासः", "दिस\u{902}बरमासः"], &["ज", "फ", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जनवरी:", "फरवरी:", "मार\u{94d}च:", "अप\u{94d}र\u{948}ल:", "मई", "ज\u{942}न:", "ज\u{941}लाई:", "अगस\u{94d}त:", "सित\u{902}बर:", "अक\u{94d}त\u{942}बर:", "नव\u{902}बर:", "दिस\u{902}बर:"], &["जनवरीमासः", "फरवरीमासः", "मार\u{94d}चमासः", "अप\u{94d}र\u{948}लमासः", "मईमासः", "ज\u{942}नमासः", "ज\u{941}लाईमासः", "अगस\u{94d}तमासः", "सित\u{902}बरमासः", "अक\u{94d}त\u{942}बरमासः", "नव\u{902}बरमासः", "दिस\u{902}बरमासः"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_741: _CalendarData = _CalendarData { months: [&["जनवरी:", "फरवरी:", "मार\u{94d}च:", "अप\u{94d}र\u{948}ल:", "मई", "ज\u{942}न:", "ज\u{941}लाई:", "अगस\u{94d}त:", "सित\u{902}बर:", "अक\u{94d}त\u{942}बर:", "नव\u{902}बर:", "दिस\u{902}बर:"], &["जनवरीमासः", "फरवरीमासः", "मार\u{94d}चमासः", "अप\u{94d}र\u{948}लमासः", "मईमासः", "ज\u{942}नमासः", "ज\u{941}लाईमासः", "अगस\u{94d}तमासः", "सित\u{902}बरमासः", "अक\u{94d}त\u{942}बरमासः", "नव\u{902}बरमासः", "दिस\u{902}बरमासः"], &["ज", "फ", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जनवरी:", "फरवरी:", "मार\u{94d}च:", "अप\u{94d}र\u{948}ल:", "मई", "ज\u{942}न:", "ज\u{941}लाई:", "अगस\u{94d}त:", "सित\u{902}बर:", "अक\u{94d}त\u{942}बर:", "नव\u{902}बर:", "दिस\u{902}बर:"], &["जनवरीमासः", "फरवरीमासः", "मार\u{94d}चमासः", "अप\u{94d}र\u{948}लमासः", "मईमासः", "ज\u{942}नमासः", "ज\u{941}लाईमासः", "अगस\u{94d}तमासः", "सित\u{902}बरमासः", "अक\u{94d}त\u{942}बरमासः", "नव\u{902}बरमासः", "दिस\u{902}बरमासः"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_167: [&_CalendarData; 6] = [&_CALENDAR_740, &_CALENDAR_741, &_CALENDAR_590, &_CALENDAR_591, &_CALENDAR_399, &_CALENDAR_592];
+
+#[rustfmt::skip]
+static _CALENDAR_742: _CalendarData = _CalendarData { months: [&["Тохс", "Олун", "Клн", "Мсу", "Ыам", "Бэс", "Отй", "Атр", "Блҕ", "Алт", "Сэт", "Ахс"], &["Тохсунньу", "Олунньу", "Кулун тутар", "Муус устар", "Ыам ыйын", "Бэс ыйын", "От ыйын", "Атырдьых ыйын", "Балаҕан ыйын", "Алтынньы", "Сэтинньи", "ахсынньы"], &["Т", "О", "К", "М", "Ы", "Б", "О", "А", "Б", "А", "С", "А"], &["Тохс", "Олун", "Клн", "Мсу", "Ыам", "Бэс", "Отй", "Атр", "Блҕ", "Алт", "Сэт", "Ахс"], &["тохсунньу", "олунньу", "кулун тутар", "муус устар", "ыам ыйа", "бэс ыйа", "от ыйа", "атырдьых ыйа", "балаҕан ыйа", "алтынньы", "сэтинньи", "ахсынньы"], &["Т", "О", "К", "М", "Ы", "Б", "О", "А", "Б", "А", "С", "А"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG yy/M/d", "G y, MMM d", "G y, MMMM d", "G y\u{202f}сыл MMMM d күнэ, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_743: _CalendarData = _CalendarData { months: [&["Тохс", "Олун", "Клн", "Мсу", "Ыам", "Бэс", "Отй", "Атр", "Блҕ", "Алт", "Сэт", "Ахс"], &["Тохсунньу", "Олунньу", "Кулун тутар", "Муус устар", "Ыам ыйын", "Бэс ыйын", "От ыйын", "Атырдьых ыйын", "Балаҕан ыйын", "Алтынньы", "Сэтинньи", "ахсынньы"], &["Т", "О", "К", "М", "Ы", "Б", "О", "А", "Б", "А", "С", "А"], &["Тохс", "Олун", "Клн", "Мсу", "Ыам", "Бэс", "Отй", "Атр", "Блҕ", "Алт", "Сэт", "Ахс"], &["тохсунньу", "олунньу", "кулун тутар", "муус устар", "ыам ыйа", "бэс ыйа", "от ыйа", "атырдьых ыйа", "балаҕан ыйа", "алтынньы", "сэтинньи", "ахсынньы"], &["Т", "О", "К", "М", "Ы", "Б", "О", "А", "Б", "А", "С", "А"]], eras: [&["б. э. и.", "б. э", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["б. э. и.", "б. э", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["б. э. и.", "б. э", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG yy/M/d", "G y, MMM d", "G y, MMMM d", "G y\u{202f}сыл MMMM d күнэ, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_744: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG yy/M/d", "G y, MMM d", "G y, MMMM d", "G y\u{202f}сыл MMMM d күнэ, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_745: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG yy/M/d", "G y, MMM d", "G y, MMMM d", "G y\u{202f}сыл MMMM d күнэ, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_746: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG yy/M/d", "G y, MMM d", "G y, MMMM d", "G y\u{202f}сыл MMMM d күнэ, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_747: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG yy/M/d", "G y, MMM d", "G y, MMMM d", "G y\u{202f}сыл MMMM d күнэ, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_168: [&_CalendarData; 6] = [&_CALENDAR_742, &_CALENDAR_743, &_CALENDAR_744, &_CALENDAR_745, &_CALENDAR_746, &_CALENDAR_747];
+
+#[rustfmt::skip]
+static _CALENDAR_748: _CalendarData = _CalendarData { months: [&["ᱡᱟᱱ", "ᱯᱷᱟ", "ᱢᱟᱨ", "ᱟᱯᱨ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞ", "ᱟᱜᱟ", "ᱥᱮᱯ", "ᱚᱠᱴ", "ᱱᱟᱣ", "ᱫᱤᱥ"], &["ᱡᱟᱱᱣᱟᱨᱤ", "ᱯᱷᱟᱨᱣᱟᱨᱤ", "ᱢᱟᱨᱪ", "ᱟᱯᱨᱮᱞ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞᱟᱭ", "ᱟᱜᱟᱥᱛ", "ᱥᱮᱯᱴᱮᱢᱵᱟᱨ", "ᱚᱠᱴᱚᱵᱟᱨ", "ᱱᱟᱣᱟᱢᱵᱟᱨ", "ᱫᱤᱥᱟᱢᱵᱟᱨ"], &["ᱡ", "ᱯ", "ᱢ", "ᱟ", "ᱢ", "ᱡ", "ᱡ", "ᱟ", "ᱥ", "ᱚ", "ᱱ", "ᱫ"], &["ᱡᱟᱱ", "ᱯᱷᱟ", "ᱢᱟᱨ", "ᱟᱯᱨ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞ", "ᱟᱜᱟ", "ᱥᱮᱯ", "ᱚᱠᱴ", "ᱱᱟᱣ", "ᱫᱤᱥ"], &["ᱡᱟᱱᱣᱟᱨᱤ", "ᱯᱷᱟᱨᱣᱟᱨᱤ", "ᱢᱟᱨᱪ", "ᱟᱯᱨᱮᱞ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞᱟᱭ", "ᱟᱜᱟᱥᱛ", "ᱥᱮᱯᱴᱮᱢᱵᱟᱨ", "ᱚᱠᱴᱚᱵᱟᱨ", "ᱱᱟᱣᱟᱢᱵᱟᱨ", "ᱫᱤᱥᱟᱢᱵᱟᱨ"], &["ᱡ", "ᱯ", "ᱢ", "ᱟ", "ᱢ", "ᱡ", "ᱡ", "ᱟ", "ᱥ", "ᱚ", "ᱱ", "ᱫ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_749: _CalendarData = _CalendarData { months: [&["ᱡᱟᱱ", "ᱯᱷᱟ", "ᱢᱟᱨ", "ᱟᱯᱨ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞ", "ᱟᱜᱟ", "ᱥᱮᱯ", "ᱚᱠᱴ", "ᱱᱟᱣ", "ᱫᱤᱥ"], &["ᱡᱟᱱᱣᱟᱨᱤ", "ᱯᱷᱟᱨᱣᱟᱨᱤ", "ᱢᱟᱨᱪ", "ᱟᱯᱨᱮᱞ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞᱟᱭ", "ᱟᱜᱟᱥᱛ", "ᱥᱮᱯᱴᱮᱢᱵᱟᱨ", "ᱚᱠᱴᱚᱵᱟᱨ", "ᱱᱟᱣᱟᱢᱵᱟᱨ", "ᱫᱤᱥᱟᱢᱵᱟᱨ"], &["ᱡ", "ᱯ", "ᱢ", "ᱟ", "ᱢ", "ᱡ", "ᱡ", "ᱟ", "ᱥ", "ᱚ", "ᱱ", "ᱫ"], &["ᱡᱟᱱ", "ᱯᱷᱟ", "ᱢᱟᱨ", "ᱟᱯᱨ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞ", "ᱟᱜᱟ", "ᱥᱮᱯ", "ᱚᱠᱴ", "ᱱᱟᱣ", "ᱫᱤᱥ"], &["ᱡᱟᱱᱣᱟᱨᱤ", "ᱯᱷᱟᱨᱣᱟᱨᱤ", "ᱢᱟᱨᱪ", "ᱟᱯᱨᱮᱞ", "ᱢᱮ", "ᱡᱩᱱ", "ᱡᱩᱞᱟᱭ", "ᱟᱜᱟᱥᱛ", "ᱥᱮᱯᱴᱮᱢᱵᱟᱨ", "ᱚᱠᱴᱚᱵᱟᱨ", "ᱱᱟᱣᱟᱢᱵᱟᱨ", "ᱫᱤᱥᱟᱢᱵᱟᱨ"], &["ᱡ", "ᱯ", "ᱢ", "ᱟ", "ᱢ", "ᱡ", "ᱡ", "ᱟ", "ᱥ", "ᱚ", "ᱱ", "ᱫ"]], eras: [&["ᱵᱤ ᱥᱤ", "ᱤᱥᱣᱤ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ᱵᱤ ᱥᱤ", "ᱤᱥᱣᱤ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ᱵᱤ ᱥᱤ", "ᱤᱥᱣᱤ", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_169: [&_CalendarData; 6] = [&_CALENDAR_748, &_CALENDAR_749, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_750: _CalendarData = _CalendarData { months: [&["ghe", "fre", "mar", "abr", "maj", "làm", "trì", "aus", "cab", "stG", "stA", "nad"], &["ghennàrgiu", "freàrgiu", "martzu", "abrile", "maju", "làmpadas", "trìulas", "austu", "cabudanni", "santugaine", "santandria", "nadale"], &["G", "F", "M", "A", "M", "L", "T", "A", "C", "S", "S", "N"], &["ghe", "fre", "mar", "abr", "maj", "làm", "trì", "aus", "cab", "stG", "stA", "nad"], &["ghennàrgiu", "freàrgiu", "martzu", "abrile", "maju", "làmpadas", "trìulas", "austu", "cabudanni", "santugaine", "santandria", "nadale"], &["G", "F", "M", "A", "M", "L", "T", "A", "C", "S", "S", "N"]], eras: [&["E.B."], &["era buddhista"], &["EB"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'de' MMMM 'de su' y G", "EEEE d 'de' MMMM 'de su' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_751: _CalendarData = _CalendarData { months: [&["ghe", "fre", "mar", "abr", "maj", "làm", "trì", "aus", "cab", "stG", "stA", "nad"], &["ghennàrgiu", "freàrgiu", "martzu", "abrile", "maju", "làmpadas", "trìulas", "austu", "cabudanni", "santugaine", "santandria", "nadale"], &["G", "F", "M", "A", "M", "L", "T", "A", "C", "S", "S", "N"], &["ghe", "fre", "mar", "abr", "maj", "làm", "trì", "aus", "cab", "stG", "stA", "nad"], &["ghennàrgiu", "freàrgiu", "martzu", "abrile", "maju", "làmpadas", "trìulas", "austu", "cabudanni", "santugaine", "santandria", "nadale"], &["G", "F", "M", "A", "M", "L", "T", "A", "C", "S", "S", "N"]], eras: [&["a.C.", "p.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["in antis de Cristu", "a pustis de Cristu", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["a.C.", "p.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'de' MMMM 'de su' y G", "EEEE d 'de' MMMM 'de su' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_752: _CalendarData = _CalendarData { months: [&["far.", "ord.", "kho.", "tir", "mor.", "sha.", "mehr", "aban", "azar", "dey", "bah.", "esf."], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["far.", "ord.", "kho.", "tir", "mor.", "sha.", "mehr", "aban", "azar", "dey", "bah.", "esf."], &["farvardin", "ordibehesht", "khordad", "tir", "mordad", "shahrivar", "mehr", "aban", "azar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["a.p."], &["annu persianu"], &["a.p."]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'de' MMMM 'de su' y G", "EEEE d 'de' MMMM 'de su' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_753: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-q.", "dhuʻl-h."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaban", "ramadan", "shawwal", "dhuʻl-qiʻdah", "dhuʻl-hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "rab. I", "rab. II", "jum. I", "jum. II", "raj.", "sha.", "ram.", "shaw.", "dhuʻl-q.", "dhuʻl-h."], &["muharram", "safar", "rabiʻ I", "rabiʻ II", "jumada I", "jumada II", "rajab", "shaban", "ramadan", "shawwal", "dhuʻl-qiʻdah", "dhuʻl-hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["e.E."], &["era de s’Egira"], &["E"]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'de' MMMM 'de su' y G", "EEEE d 'de' MMMM 'de su' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_754: _CalendarData = _CalendarData { months: [&["tis.", "hes.", "kis.", "tev.", "she.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "elul", "ad.II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tis.", "hes.", "kis.", "tev.", "she.", "ad.I", "adar", "nis.", "iyar", "siv.", "tam.", "av", "elul", "ad.II"], &["tishri", "heshvan", "kislev", "tevet", "shevat", "adar I", "adar", "nisan", "iyar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["a.m."], &["annu de su mundu"], &["a.m."]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'de' MMMM 'de su' y G", "EEEE d 'de' MMMM 'de su' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_755: _CalendarData = _CalendarData { months: [&["mes.", "tek.", "hed.", "tah.", "ter", "yek.", "meg.", "mia.", "gen.", "sene", "ham.", "neh.", "pagu.", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["mes.", "tek.", "hed.", "tah.", "ter", "yek.", "meg.", "mia.", "gen.", "sene", "ham.", "neh.", "pagu.", ""], &["meskerem", "tekemt", "hedar", "tahsas", "ter", "yekatit", "megabit", "miazia", "genbot", "sene", "hamle", "nehasse", "pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["a.m.", "p.Inc."], &["annu de su mundu", "a pustis de s’Incarnatzione"], &["a.m.", "p.Inc."]], date_patterns: ["dd/MM/y GGGGG", "dd MMM y G", "d 'de' MMMM 'de su' y G", "EEEE d 'de' MMMM 'de su' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_170: [&_CalendarData; 6] = [&_CALENDAR_750, &_CALENDAR_751, &_CALENDAR_752, &_CALENDAR_753, &_CALENDAR_754, &_CALENDAR_755];
+
+#[rustfmt::skip]
+static _CALENDAR_756: _CalendarData = _CalendarData { months: [&["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_757: _CalendarData = _CalendarData { months: [&["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["جنوري", "فيبروري", "مارچ", "اپريل", "مئي", "جون", "جولاء\u{650}", "آگسٽ", "سيپٽمبر", "آڪٽوبر", "نومبر", "ڊسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "CD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["قبل مسيح", "عيسوي کان پهرين", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "CD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_758: _CalendarData = _CalendarData { months: [&["محرم", "صفر", "ربيع الاول", "ربیع الاخر", "جمادی الاول", "جمادي الاخر", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعد", "ذوالحجہ"], &["محرم", "صفر", "ربيع الاول", "ربیع الاخر", "جمادی الاول", "جمادي الاخر", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعد", "ذوالحجہ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["محرم", "صفر", "ربيع الاول", "ربیع الاخر", "جمادی الاول", "جمادي الاخر", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعد", "ذوالحجہ"], &["محرم", "صفر", "ربيع الاول", "ربیع الاخر", "جمادی الاول", "جمادي الاخر", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعد", "ذوالحجہ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_171: [&_CalendarData; 6] = [&_CALENDAR_756, &_CALENDAR_757, &_CALENDAR_2, &_CALENDAR_758, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_759: _CalendarData = _CalendarData { months: [&["जन", "फर", "मार\u{94d}च", "अप\u{94d}र\u{948}", "मई", "ज\u{942}न", "ज\u{941}", "अग", "सप\u{94d}ट\u{947}", "ऑक\u{94d}टो", "नव\u{902}", "डिस\u{902}"], &["जनवरी", "फ\u{947}बरवरी", "मार\u{94d}च\u{941}", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "आगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "आक\u{94d}टोबर", "नव\u{902}बर", "डिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "मा", "ज\u{942}", "ज\u{941}", "अग", "स", "ऑ", "न", "डि"], &["जन", "फर", "मार\u{94d}च", "अप\u{94d}र\u{948}", "मई", "ज\u{942}न", "ज\u{941}ला", "अग", "सप\u{94d}ट\u{947}", "ऑक\u{94d}टो", "नव\u{902}", "डिस\u{902}"], &["जनवरी", "फ\u{947}बरवरी", "मार\u{94d}च\u{941}", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "आगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नव\u{902}बर", "डिस\u{902}बर"], &["ज", "फ\u{93c}", "म", "अ", "मा", "ज\u{942}", "ज\u{941}", "अग", "स", "ऑ", "न", "डि"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_760: _CalendarData = _CalendarData { months: [&["जन", "फर", "मार\u{94d}च", "अप\u{94d}र\u{948}", "मई", "ज\u{942}न", "ज\u{941}", "अग", "सप\u{94d}ट\u{947}", "ऑक\u{94d}टो", "नव\u{902}", "डिस\u{902}"], &["जनवरी", "फ\u{947}बरवरी", "मार\u{94d}च\u{941}", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "आगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "आक\u{94d}टोबर", "नव\u{902}बर", "डिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "मा", "ज\u{942}", "ज\u{941}", "अग", "स", "ऑ", "न", "डि"], &["जन", "फर", "मार\u{94d}च", "अप\u{94d}र\u{948}", "मई", "ज\u{942}न", "ज\u{941}ला", "अग", "सप\u{94d}ट\u{947}", "ऑक\u{94d}टो", "नव\u{902}", "डिस\u{902}"], &["जनवरी", "फ\u{947}बरवरी", "मार\u{94d}च\u{941}", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "आगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नव\u{902}बर", "डिस\u{902}बर"], &["ज", "फ\u{93c}", "म", "अ", "मा", "ज\u{942}", "ज\u{941}", "अग", "स", "ऑ", "न", "डि"]], eras: [&["बीसी", "एडी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["बीसी", "एडी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["बीसी", "एडी", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_172: [&_CalendarData; 6] = [&_CALENDAR_759, &_CALENDAR_760, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_761: _CalendarData = _CalendarData { months: [&["ජන", "පෙබ", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝ", "සැප\u{dca}", "ඔක\u{dca}", "නොවැ", "දෙසැ"], &["ජනව\u{dcf}ර\u{dd2}", "පෙබරව\u{dcf}ර\u{dd2}", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝස\u{dca}ත\u{dd4}", "සැප\u{dca}තැම\u{dca}බර\u{dca}", "ඔක\u{dca}තෝබර\u{dca}", "නොවැම\u{dca}බර\u{dca}", "දෙසැම\u{dca}බර\u{dca}"], &["ජ", "පෙ", "ම\u{dcf}", "අ", "මැ", "ජ\u{dd6}", "ජ\u{dd6}", "අ", "සැ", "ඔ", "නෙ", "දෙ"], &["ජන", "පෙබ", "ම\u{dcf}ර\u{dca}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝ", "සැප\u{dca}", "ඔක\u{dca}", "නොවැ", "දෙසැ"], &["ජනව\u{dcf}ර\u{dd2}", "පෙබරව\u{dcf}ර\u{dd2}", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝස\u{dca}ත\u{dd4}", "සැප\u{dca}තැම\u{dca}බර\u{dca}", "ඔක\u{dca}තෝබර\u{dca}", "නොවැම\u{dca}බර\u{dca}", "දෙසැම\u{dca}බර\u{dca}"], &["ජ", "පෙ", "ම\u{dcf}", "අ", "මැ", "ජ\u{dd6}", "ජ\u{dd6}", "අ", "සැ", "ඔ", "නෙ", "දෙ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_762: _CalendarData = _CalendarData { months: [&["ජන", "පෙබ", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝ", "සැප\u{dca}", "ඔක\u{dca}", "නොවැ", "දෙසැ"], &["ජනව\u{dcf}ර\u{dd2}", "පෙබරව\u{dcf}ර\u{dd2}", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝස\u{dca}ත\u{dd4}", "සැප\u{dca}තැම\u{dca}බර\u{dca}", "ඔක\u{dca}තෝබර\u{dca}", "නොවැම\u{dca}බර\u{dca}", "දෙසැම\u{dca}බර\u{dca}"], &["ජ", "පෙ", "ම\u{dcf}", "අ", "මැ", "ජ\u{dd6}", "ජ\u{dd6}", "අ", "සැ", "ඔ", "නෙ", "දෙ"], &["ජන", "පෙබ", "ම\u{dcf}ර\u{dca}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝ", "සැප\u{dca}", "ඔක\u{dca}", "නොවැ", "දෙසැ"], &["ජනව\u{dcf}ර\u{dd2}", "පෙබරව\u{dcf}ර\u{dd2}", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝස\u{dca}ත\u{dd4}", "සැප\u{dca}තැම\u{dca}බර\u{dca}", "ඔක\u{dca}තෝබර\u{dca}", "නොවැම\u{dca}බර\u{dca}", "දෙසැම\u{dca}බර\u{dca}"], &["ජ", "පෙ", "ම\u{dcf}", "අ", "මැ", "ජ\u{dd6}", "ජ\u{dd6}", "අ", "සැ", "ඔ", "නෙ", "දෙ"]], eras: [&["ක\u{dca}\u{200d}ර\u{dd2}.ප\u{dd6}.", "ක\u{dca}\u{200d}ර\u{dd2}.ව.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ක\u{dca}\u{200d}ර\u{dd2}ස\u{dca}ත\u{dd4} ප\u{dd6}ර\u{dca}ව", "ක\u{dca}\u{200d}ර\u{dd2}ස\u{dca}ත\u{dd4} වර\u{dca}ෂ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ක\u{dca}\u{200d}ර\u{dd2}.ප\u{dd6}.", "ක\u{dca}\u{200d}ර\u{dd2}.ව.", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_173: [&_CalendarData; 6] = [&_CALENDAR_761, &_CALENDAR_762, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_763: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "máj", "jún", "júl", "aug", "sep", "okt", "nov", "dec"], &["januára", "februára", "marca", "apríla", "mája", "júna", "júla", "augusta", "septembra", "októbra", "novembra", "decembra"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "máj", "jún", "júl", "aug", "sep", "okt", "nov", "dec"], &["január", "február", "marec", "apríl", "máj", "jún", "júl", "august", "september", "október", "november", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.y GGGGG", "d. M. y G", "d. M. y G", "EEEE, d. M. y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_764: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "máj", "jún", "júl", "aug", "sep", "okt", "nov", "dec"], &["januára", "februára", "marca", "apríla", "mája", "júna", "júla", "augusta", "septembra", "októbra", "novembra", "decembra"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "máj", "jún", "júl", "aug", "sep", "okt", "nov", "dec"], &["január", "február", "marec", "apríl", "máj", "jún", "júl", "august", "september", "október", "november", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["pred Kr.", "po Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pred Kristom", "po Kristovi", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pred Kr.", "po Kr.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y GGGGG", "d. M. y G", "d. M. y G", "EEEE, d. M. y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_765: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["farvardin", "ordibehešt", "chordád", "tír", "mordád", "šahrívar", "mehr", "ábán", "ázar", "dei", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y GGGGG", "d. M. y G", "d. M. y G", "EEEE, d. M. y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_766: _CalendarData = _CalendarData { months: [&["muh.", "saf.", "rab. I", "rab. II", "džum. I", "džum. II", "rad.", "ša.", "ram.", "šau.", "dhú l-k.", "dhú l-h."], &["al-muharram", "safar", "rabí´ al-avval", "rabí´ath-thání", "džumádá l-úlá", "džumádá l-áchira", "radžab", "ša´ bán", "ramadán", "šauvál", "dhú l-ka´ da", "dhú l-hidždža"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["muh.", "saf.", "rab. I", "rab. II", "džum. I", "džum. II", "rad.", "ša.", "ram.", "šau.", "dhú l-k.", "dhú l-h."], &["al-muharram", "safar", "rabí´ al-avval", "rabí´ath-thání", "džumádá l-úlá", "džumádá l-áchira", "radžab", "ša´ bán", "ramadán", "šauvál", "dhú l-ka´ da", "dhú l-hidždža"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.M.y GGGGG", "d. M. y G", "d. M. y G", "EEEE, d. M. y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_767: _CalendarData = _CalendarData { months: [&["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["tišri", "chešvan", "kislev", "tevet", "ševat", "adar I", "adar", "nisan", "ijar", "sivan", "tamuz", "av", "elul", "adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y GGGGG", "d. M. y G", "d. M. y G", "EEEE, d. M. y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_768: _CalendarData = _CalendarData { months: [&["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["meskerem", "tikemet", "hidar", "tahesas", "tir", "yekatit", "megabit", "miyaza", "ginbot", "sene", "hamle", "nehase", "pagume", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y GGGGG", "d. M. y G", "d. M. y G", "EEEE, d. M. y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_174: [&_CalendarData; 6] = [&_CALENDAR_763, &_CALENDAR_764, &_CALENDAR_765, &_CALENDAR_766, &_CALENDAR_767, &_CALENDAR_768];
+
+#[rustfmt::skip]
+static _CALENDAR_769: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "avg.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marec", "april", "maj", "junij", "julij", "avgust", "september", "oktober", "november", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "avg.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marec", "april", "maj", "junij", "julij", "avgust", "september", "oktober", "november", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["bud. kol."], &["budistični koledar"], &["BK"]], date_patterns: ["d. MM. yy GGGGG", "d. MMM y G", "dd. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_770: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "avg.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marec", "april", "maj", "junij", "julij", "avgust", "september", "oktober", "november", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "avg.", "sep.", "okt.", "nov.", "dec."], &["januar", "februar", "marec", "april", "maj", "junij", "julij", "avgust", "september", "oktober", "november", "december"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["pr. Kr.", "po Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pred Kristusom", "po Kristusu", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["pr. Kr.", "po Kr.", "M", "T", "S", "H", "R"]], date_patterns: ["d. MM. yy GGGGG", "d. MMM y G", "dd. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_771: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d. MM. yy GGGGG", "d. MMM y G", "dd. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_772: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d. MM. yy GGGGG", "d. MMM y G", "dd. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_773: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d. MM. yy GGGGG", "d. MMM y G", "dd. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_774: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d. MM. yy GGGGG", "d. MMM y G", "dd. MMMM y G", "EEEE, dd. MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_175: [&_CalendarData; 6] = [&_CALENDAR_769, &_CALENDAR_770, &_CALENDAR_771, &_CALENDAR_772, &_CALENDAR_773, &_CALENDAR_774];
+
+#[rustfmt::skip]
+static _CALENDAR_775: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Abr", "May", "Jun", "Lul", "Ogs", "Seb", "Okt", "Nof", "Dis"], &["Janaayo", "Febraayo", "Maarso", "Abriil", "Maayo", "Juun", "Luulyo", "Agosto", "Sebtembar", "Oktoobar", "Noofeembar", "Diseembar"], &["J", "F", "M", "A", "M", "J", "L", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Abr", "May", "Jun", "Lul", "Ogs", "Seb", "Okt", "Nof", "Dis"], &["Jannaayo", "Febraayo", "Maarso", "Abriil", "Maayo", "Juun", "Luulyo", "Ogosto", "Sebteembar", "Oktoobar", "Noofeembar", "Diseembar"], &["J", "F", "M", "A", "M", "J", "L", "O", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_776: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mar", "Abr", "May", "Jun", "Lul", "Ogs", "Seb", "Okt", "Nof", "Dis"], &["Janaayo", "Febraayo", "Maarso", "Abriil", "Maayo", "Juun", "Luulyo", "Agosto", "Sebtembar", "Oktoobar", "Noofeembar", "Diseembar"], &["J", "F", "M", "A", "M", "J", "L", "O", "S", "O", "N", "D"], &["Jan", "Feb", "Mar", "Abr", "May", "Jun", "Lul", "Ogs", "Seb", "Okt", "Nof", "Dis"], &["Jannaayo", "Febraayo", "Maarso", "Abriil", "Maayo", "Juun", "Luulyo", "Ogosto", "Sebteembar", "Oktoobar", "Noofeembar", "Diseembar"], &["J", "F", "M", "A", "M", "J", "L", "O", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Ciise Hortii", "Ciise Dabadii", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B", "A", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_777: _CalendarData = _CalendarData { months: [&["Janaayo", "Feebraayo", "Maarso", "Abril", "Maayo", "Juun", "Luuliyo", "Agoosto", "Sabteembar", "Oktoobar", "Noofeembar", "Diiseembar"], &["Janaayo", "Feebraayo", "Maarso", "Abril", "Maayo", "Juun", "Luuliyo", "Agoosto", "Sabteembar", "Oktoobar", "Noofeembar", "Diiseembar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Janaayo", "Feebraayo", "Maarso", "Abril", "Maayo", "Juun", "Luuliyo", "Agoosto", "Sabteembar", "Oktoobar", "Noofeembar", "Diiseembar"], &["Janaayo", "Feebraayo", "Maarso", "Abril", "Maayo", "Juun", "Luuliyo", "Agoosto", "Sabteembar", "Oktoobar", "Noofeembar", "Diiseembar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_778: _CalendarData = _CalendarData { months: [&["Mux.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dul’-Qicda.", "Dhuʻl-H."], &["Muxarram", "Safar", "Rabic al-awwal", "Rabic al-thani", "Jumada al-awwal", "jumada al-thani", "Rajab", "Shacban", "Ramadan", "Shawwal", "Dul al-qacda", "Dul xijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Mux.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dul-Q.", "Dul-X."], &["Muxarram", "Safar", "Rabic al-awwal", "Rabic al-thani", "Jumada al-awwal", "jumada al-thani", "Rajab", "Shacban", "Ramadan", "Shawwal", "Dul al-qacdah", "Dul xijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_176: [&_CalendarData; 6] = [&_CALENDAR_775, &_CALENDAR_776, &_CALENDAR_777, &_CALENDAR_778, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_779: _CalendarData = _CalendarData { months: [&["jan", "shk", "mar", "pri", "maj", "qer", "korr", "gush", "sht", "tet", "nën", "dhj"], &["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"], &["j", "sh", "m", "p", "m", "q", "k", "g", "sh", "t", "n", "dh"], &["jan", "shk", "mar", "pri", "maj", "qer", "korr", "gush", "sht", "tet", "nën", "dhj"], &["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"], &["j", "sh", "m", "p", "m", "q", "k", "g", "sh", "t", "n", "dh"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.y GGGGG", "d MMM y G", "d MMM y G", "EEEE, d MMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_780: _CalendarData = _CalendarData { months: [&["jan", "shk", "mar", "pri", "maj", "qer", "korr", "gush", "sht", "tet", "nën", "dhj"], &["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"], &["j", "sh", "m", "p", "m", "q", "k", "g", "sh", "t", "n", "dh"], &["jan", "shk", "mar", "pri", "maj", "qer", "korr", "gush", "sht", "tet", "nën", "dhj"], &["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"], &["j", "sh", "m", "p", "m", "q", "k", "g", "sh", "t", "n", "dh"]], eras: [&["p.K.", "mb.K.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["para Krishtit", "mbas Krishtit", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["p.K.", "mb.K.", "M", "T", "S", "H", "R"]], date_patterns: ["d.M.y GGGGG", "d MMM y G", "d MMM y G", "EEEE, d MMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_781: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y GGGGG", "d MMM y G", "d MMM y G", "EEEE, d MMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_782: _CalendarData = _CalendarData { months: [&["muh.", "sef.", "reb. I", "reb. II", "xhum. I", "xhum. II", "rexh.", "sha.", "ram.", "shev.", "dhul-k.", "dhul-h."], &["muharrem", "sefer", "rebiul-evel", "rebiu-theni", "xhumadel-ula", "xhumade-theni", "rexheb", "shaban", "ramazan", "sheval", "dhul-kade", "dhul-hixhe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Sef.", "Reb. I", "Reb. II", "Xhum. I", "Xhum. II", "Rexh.", "Sha.", "Ram.", "Shev.", "Dhul-k.", "Dhul-h."], &["Muharrem", "Sefer", "Rebiul-evel", "Rebiu-theni", "Xhumadel-ula", "Xhumade-theni", "Rexheb", "Shaban", "Ramazan", "Sheval", "Dhul-kade", "Dhul-hixhe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["H."], &["H."], &["H."]], date_patterns: ["d.M.y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_783: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y GGGGG", "d MMM y G", "d MMM y G", "EEEE, d MMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_784: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y GGGGG", "d MMM y G", "d MMM y G", "EEEE, d MMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_177: [&_CalendarData; 6] = [&_CALENDAR_779, &_CALENDAR_780, &_CALENDAR_781, &_CALENDAR_782, &_CALENDAR_783, &_CALENDAR_784];
+
+#[rustfmt::skip]
+static _CALENDAR_785: _CalendarData = _CalendarData { months: [&["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["БЕ"], &["БЕ"], &["БЕ"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_786: _CalendarData = _CalendarData { months: [&["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["п. н. е.", "н. е.", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["пре нове ере", "нове ере", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["п.н.е.", "н.е.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_787: _CalendarData = _CalendarData { months: [&["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["Фаравадин", "Ордибехешт", "Кордад", "Тир", "Мордад", "Шахривар", "Мехр", "Абан", "Азар", "Деј", "Бахман", "Есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_788: _CalendarData = _CalendarData { months: [&["Мух.", "Саф.", "Реб. 1", "Реб. 2", "Џум. 1", "Џум. 2", "Реџ.", "Ша.", "Рам.", "Ше.", "Зул-к.", "Зул-х."], &["Мухарем", "Сафар", "Рабиʻ I", "Рабиʻ II", "Јумада I", "Јумада II", "Рађаб", "Шаʻбан", "Рамадан", "Шавал", "Дуʻл-Киʻда", "Дуʻл-хиђа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Мух.", "Саф.", "Реб. 1", "Реб. 2", "Џум. 1", "Џум. 2", "Реџ.", "Ша.", "Рам.", "Ше.", "Зул-к.", "Зул-х."], &["Мухарем", "Сафер", "Реби 1", "Реби 2", "Џумаде 1", "Џумаде 2", "Реџеб", "Шаʻбан", "Рамазан", "Шевал", "Зул-каде", "Зул-хиџе"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["АХ"], &["АХ"], &["АХ"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_789: _CalendarData = _CalendarData { months: [&["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["Тишри", "Хешван", "Кислев", "Тевет", "Шеват", "Адар I", "Адар", "Нисан", "Ијар", "Сиван", "Тамуз", "Ав", "Елул", "Адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_790: _CalendarData = _CalendarData { months: [&["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["Мескерем", "Текемт", "Хедар", "Тахсас", "Тер", "Јекатит", "Мегабит", "Миазиа", "Генбот", "Сене", "Хамле", "Нехасе", "Пагумен", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_178: [&_CalendarData; 6] = [&_CALENDAR_785, &_CALENDAR_786, &_CALENDAR_787, &_CALENDAR_788, &_CALENDAR_789, &_CALENDAR_790];
+
+#[rustfmt::skip]
+static _CALENDAR_791: _CalendarData = _CalendarData { months: [&["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["п. н. е.", "н. е.", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["прије нове ере", "нове ере", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["п.н.е.", "н.е.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_179: [&_CalendarData; 6] = [&_CALENDAR_785, &_CALENDAR_791, &_CALENDAR_787, &_CALENDAR_788, &_CALENDAR_789, &_CALENDAR_790];
+
+#[rustfmt::skip]
+static _CALENDAR_792: _CalendarData = _CalendarData { months: [&["јан", "феб", "март", "апр", "мај", "јун", "јул", "авг", "септ", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "март", "апр", "мај", "јун", "јул", "авг", "септ", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["БЕ"], &["БЕ"], &["БЕ"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_793: _CalendarData = _CalendarData { months: [&["јан", "феб", "март", "апр", "мај", "јун", "јул", "авг", "септ", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"], &["јан", "феб", "март", "апр", "мај", "јун", "јул", "авг", "септ", "окт", "нов", "дец"], &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"], &["ј", "ф", "м", "а", "м", "ј", "ј", "а", "с", "о", "н", "д"]], eras: [&["п. н. е.", "н. е.", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["пре нове ере", "нове ере", "Меиђи", "Таишо", "Шова", "Хаисеи", "Реива"], &["п.н.е.", "н.е.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_180: [&_CalendarData; 6] = [&_CALENDAR_792, &_CALENDAR_793, &_CALENDAR_787, &_CALENDAR_788, &_CALENDAR_789, &_CALENDAR_790];
+
+#[rustfmt::skip]
+static _CALENDAR_794: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_795: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["p. n. e.", "n. e.", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["pre nove ere", "nove ere", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["p.n.e.", "n.e.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_796: _CalendarData = _CalendarData { months: [&["Faravadin", "Ordibehešt", "Kordad", "Tir", "Mordad", "Šahrivar", "Mehr", "Aban", "Azar", "Dej", "Bahman", "Esfand"], &["Faravadin", "Ordibehešt", "Kordad", "Tir", "Mordad", "Šahrivar", "Mehr", "Aban", "Azar", "Dej", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Faravadin", "Ordibehešt", "Kordad", "Tir", "Mordad", "Šahrivar", "Mehr", "Aban", "Azar", "Dej", "Bahman", "Esfand"], &["Faravadin", "Ordibehešt", "Kordad", "Tir", "Mordad", "Šahrivar", "Mehr", "Aban", "Azar", "Dej", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_797: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Reb. 1", "Reb. 2", "Džum. 1", "Džum. 2", "Redž.", "Ša.", "Ram.", "Še.", "Zul-k.", "Zul-h."], &["Muharem", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rađab", "Šaʻban", "Ramadan", "Šaval", "Duʻl-Kiʻda", "Duʻl-hiđa"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Reb. 1", "Reb. 2", "Džum. 1", "Džum. 2", "Redž.", "Ša.", "Ram.", "Še.", "Zul-k.", "Zul-h."], &["Muharem", "Safer", "Rebi 1", "Rebi 2", "Džumade 1", "Džumade 2", "Redžeb", "Šaʻban", "Ramazan", "Ševal", "Zul-kade", "Zul-hidže"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_798: _CalendarData = _CalendarData { months: [&["Tišri", "Hešvan", "Kislev", "Tevet", "Ševat", "Adar I", "Adar", "Nisan", "Ijar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tišri", "Hešvan", "Kislev", "Tevet", "Ševat", "Adar I", "Adar", "Nisan", "Ijar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tišri", "Hešvan", "Kislev", "Tevet", "Ševat", "Adar I", "Adar", "Nisan", "Ijar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tišri", "Hešvan", "Kislev", "Tevet", "Ševat", "Adar I", "Adar", "Nisan", "Ijar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_799: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Jekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehase", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Jekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehase", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Jekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehase", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Jekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehase", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_181: [&_CalendarData; 6] = [&_CALENDAR_794, &_CALENDAR_795, &_CALENDAR_796, &_CALENDAR_797, &_CALENDAR_798, &_CALENDAR_799];
+
+#[rustfmt::skip]
+static _CALENDAR_800: _CalendarData = _CalendarData { months: [&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["p. n. e.", "n. e.", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["prije nove ere", "nove ere", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["p.n.e.", "n.e.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_182: [&_CalendarData; 6] = [&_CALENDAR_794, &_CALENDAR_800, &_CALENDAR_796, &_CALENDAR_797, &_CALENDAR_798, &_CALENDAR_799];
+
+#[rustfmt::skip]
+static _CALENDAR_801: _CalendarData = _CalendarData { months: [&["jan", "feb", "mart", "apr", "maj", "jun", "jul", "avg", "sept", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mart", "apr", "maj", "jun", "jul", "avg", "sept", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d.M.y. GGGGG", "d.MM.y. G", "d. MMMM y. G", "EEEE, d. MMMM y. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_802: _CalendarData = _CalendarData { months: [&["jan", "feb", "mart", "apr", "maj", "jun", "jul", "avg", "sept", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mart", "apr", "maj", "jun", "jul", "avg", "sept", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["p. n. e.", "n. e.", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["prije nove ere", "nove ere", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["p.n.e.", "n.e.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_183: [&_CalendarData; 6] = [&_CALENDAR_801, &_CALENDAR_802, &_CALENDAR_796, &_CALENDAR_797, &_CALENDAR_798, &_CALENDAR_799];
+
+#[rustfmt::skip]
+static _CALENDAR_803: _CalendarData = _CalendarData { months: [&["jan", "feb", "mart", "apr", "maj", "jun", "jul", "avg", "sept", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"], &["jan", "feb", "mart", "apr", "maj", "jun", "jul", "avg", "sept", "okt", "nov", "dec"], &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"], &["j", "f", "m", "a", "m", "j", "j", "a", "s", "o", "n", "d"]], eras: [&["p. n. e.", "n. e.", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["pre nove ere", "nove ere", "Meiđi", "Taišo", "Šova", "Haisei", "Reiva"], &["p.n.e.", "n.e.", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/yy G", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_184: [&_CalendarData; 6] = [&_CALENDAR_801, &_CALENDAR_803, &_CALENDAR_796, &_CALENDAR_797, &_CALENDAR_798, &_CALENDAR_799];
+
+#[rustfmt::skip]
+static _CALENDAR_804: _CalendarData = _CalendarData { months: [&["Phe", "Kol", "Ube", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"], &["Pherekgong", "Hlakola", "Hlakubele", "Mmesa", "Motsheanong", "Phupjane", "Phupu", "Phato", "Lwetse", "Mphalane", "Pudungwana", "Tshitwe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Phe", "Kol", "Ube", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"], &["Pherekgong", "Hlakola", "Hlakubele", "Mmesa", "Motsheanong", "Phupjane", "Phupu", "Phato", "Lwetse", "Mphalane", "Pudungwana", "Tshitwe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_805: _CalendarData = _CalendarData { months: [&["Phe", "Kol", "Ube", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"], &["Pherekgong", "Hlakola", "Hlakubele", "Mmesa", "Motsheanong", "Phupjane", "Phupu", "Phato", "Lwetse", "Mphalane", "Pudungwana", "Tshitwe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Phe", "Kol", "Ube", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"], &["Pherekgong", "Hlakola", "Hlakubele", "Mmesa", "Motsheanong", "Phupjane", "Phupu", "Phato", "Lwetse", "Mphalane", "Pudungwana", "Tshitwe"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_185: [&_CalendarData; 6] = [&_CALENDAR_804, &_CALENDAR_805, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_806: _CalendarData = _CalendarData { months: [&["Jan", "Péb", "Mar", "Apr", "Méi", "Jun", "Jul", "Ags", "Sép", "Okt", "Nop", "Dés"], &["Januari", "Pébruari", "Maret", "April", "Méi", "Juni", "Juli", "Agustus", "Séptémber", "Oktober", "Nopémber", "Désémber"], &["J", "P", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Péb", "Mar", "Apr", "Méi", "Jun", "Jul", "Ags", "Sép", "Okt", "Nop", "Dés"], &["Januari", "Pébruari", "Maret", "April", "Méi", "Juni", "Juli", "Agustus", "Séptémber", "Oktober", "Nopémber", "Désémber"], &["J", "P", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_807: _CalendarData = _CalendarData { months: [&["Jan", "Péb", "Mar", "Apr", "Méi", "Jun", "Jul", "Ags", "Sép", "Okt", "Nop", "Dés"], &["Januari", "Pébruari", "Maret", "April", "Méi", "Juni", "Juli", "Agustus", "Séptémber", "Oktober", "Nopémber", "Désémber"], &["J", "P", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Péb", "Mar", "Apr", "Méi", "Jun", "Jul", "Ags", "Sép", "Okt", "Nop", "Dés"], &["Januari", "Pébruari", "Maret", "April", "Méi", "Juni", "Juli", "Agustus", "Séptémber", "Oktober", "Nopémber", "Désémber"], &["J", "P", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["SM", "M", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["SM", "M", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["SM", "M", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_808: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_809: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_186: [&_CalendarData; 6] = [&_CALENDAR_806, &_CALENDAR_807, &_CALENDAR_175, &_CALENDAR_808, &_CALENDAR_809, &_CALENDAR_178];
+
+#[rustfmt::skip]
+static _CALENDAR_810: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "dec."], &["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "dec."], &["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["Buddhistisk era"], &["BE"]], date_patterns: ["y-MM-dd G", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_811: _CalendarData = _CalendarData { months: [&["jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "dec."], &["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "dec."], &["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["f.Kr.", "e.Kr.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["före Kristus", "efter Kristus", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["f.Kr.", "e.Kr.", "M", "T", "S", "H", "R"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_812: _CalendarData = _CalendarData { months: [&["farvardin", "ordibehesht", "khordād", "tir", "mordād", "shahrivar", "mehr", "ābān", "āzar", "dey", "bahman", "esfand"], &["farvardin", "ordibehesht", "khordād", "tir", "mordād", "shahrivar", "mehr", "ābān", "āzar", "dey", "bahman", "esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordād", "Tir", "Mordād", "Shahrivar", "Mehr", "Ābān", "Āzar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordād", "Tir", "Mordād", "Shahrivar", "Mehr", "Ābān", "Āzar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["Anno Persarum"], &["AP"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_813: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["muharram", "safar", "rabi’ al-awwal", "rabi’ al-akhir", "jumada-l-ula", "jumada-l-akhira", "rajab", "sha’ban", "ramadan", "shawwal", "dhu-l-ga’da", "dhu-l-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabi’ al-awwal", "Rabi’ al-akhir", "Jumada-l-ula", "Jumada-l-akhira", "Rajab", "Sha’ban", "Ramadan", "Shawwal", "Dhu-l-ga’da", "Dhu-l-hijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["efter Hirja"], &["AH"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_814: _CalendarData = _CalendarData { months: [&["tishrí", "heshván", "kislév", "tevét", "shevát", "adár I", "adár", "nisán", "ijjár", "siván", "tammúz", "ab", "elúl", "adár II"], &["tishrí", "heshván", "kislév", "tevét", "shevát", "adár I", "adár", "nisán", "ijjár", "siván", "tammúz", "ab", "elúl", "adár II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishrí", "Heshván", "Kislév", "Tevét", "Shevát", "Adár I", "Adár", "Nisán", "Ijjár", "Siván", "Tammúz", "Ab", "Elúl", "Adár II"], &["Tishrí", "Heshván", "Kislév", "Tevét", "Shevát", "Adár I", "Adár", "Nisán", "Ijjár", "Siván", "Tammúz", "Ab", "Elúl", "Adár II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["Anno Mundi"], &["AM"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_815: _CalendarData = _CalendarData { months: [&["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["mäskäräm", "teqemt", "hedar", "tahesas", "ter", "yäkatit", "mägabit", "miyazya", "guenbot", "säné", "hamlé", "nähasé", "pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahesas", "Ter", "Yäkatit", "Mägabit", "Miyazya", "Guenbot", "Säné", "Hamlé", "Nähasé", "Pagumén", ""], &["Mäskäräm", "Teqemt", "Hedar", "Tahesas", "Ter", "Yäkatit", "Mägabit", "Miyazya", "Guenbot", "Säné", "Hamlé", "Nähasé", "Pagumén", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_187: [&_CalendarData; 6] = [&_CALENDAR_810, &_CALENDAR_811, &_CALENDAR_812, &_CALENDAR_813, &_CALENDAR_814, &_CALENDAR_815];
+
+#[rustfmt::skip]
+static _CALENDAR_816: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_817: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"], &["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["KK", "BK", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Kabla ya Kristo", "Baada ya Kristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["KK", "BK", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_188: [&_CalendarData; 6] = [&_CALENDAR_816, &_CALENDAR_817, &_CALENDAR_141, &_CALENDAR_142, &_CALENDAR_143, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_818: _CalendarData = _CalendarData { months: [&["ܟܢܘܢ ܒ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܐ", "ܬܫܪܝܢ ܒ", "ܟܢܘܢ ܐ"], &["ܟܢܘܢ ܐܚܪܝܐ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܩܕܡܝܐ", "ܬܫܪܝܢ ܐܚܪܝܐ", "ܟܢܘܢ ܩܕܡܝܐ"], &["ܟ", "ܫ", "ܐ", "ܢ", "ܐ", "ܚ", "ܬ", "ܐ", "ܐ", "ܬ", "ܬ", "ܟ"], &["ܟܢܘܢ ܒ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܐ", "ܬܫܪܝܢ ܒ", "ܟܢܘܢ ܐ"], &["ܟܢܘܢ ܐܚܪܝܐ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܩܕܡܝܐ", "ܬܫܪܝܢ ܐܚܪܝܐ", "ܟܢܘܢ ܩܕܡܝܐ"], &["ܟ", "ܫ", "ܐ", "ܢ", "ܐ", "ܬ", "ܚ", "ܐ", "ܐ", "ܬ", "ܬ", "ܟ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d ܒMMM y G", "d ܒMMMM y G", "EEEE، d ܒMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_819: _CalendarData = _CalendarData { months: [&["ܟܢܘܢ ܒ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܐ", "ܬܫܪܝܢ ܒ", "ܟܢܘܢ ܐ"], &["ܟܢܘܢ ܐܚܪܝܐ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܩܕܡܝܐ", "ܬܫܪܝܢ ܐܚܪܝܐ", "ܟܢܘܢ ܩܕܡܝܐ"], &["ܟ", "ܫ", "ܐ", "ܢ", "ܐ", "ܚ", "ܬ", "ܐ", "ܐ", "ܬ", "ܬ", "ܟ"], &["ܟܢܘܢ ܒ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܐ", "ܬܫܪܝܢ ܒ", "ܟܢܘܢ ܐ"], &["ܟܢܘܢ ܐܚܪܝܐ", "ܫܒܛ", "ܐܕܪ", "ܢܝܣܢ", "ܐܝܪ", "ܚܙܝܪܢ", "ܬܡܘܙ", "ܐܒ", "ܐܝܠܘܠ", "ܬܫܪܝܢ ܩܕܡܝܐ", "ܬܫܪܝܢ ܐܚܪܝܐ", "ܟܢܘܢ ܩܕܡܝܐ"], &["ܟ", "ܫ", "ܐ", "ܢ", "ܐ", "ܬ", "ܚ", "ܐ", "ܐ", "ܬ", "ܬ", "ܟ"]], eras: [&["\u{70f}ܩܡ\u{200c}", "\u{70f}ܫܡ\u{200c}", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ܩܕܡ ܡܫܝܚܐ", "ܫܢܬܐ ܡܪܢܝܬܐ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["\u{70f}ܩܡ\u{200c}", "\u{70f}ܫܡ\u{200c}", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d ܒMMM y G", "d ܒMMMM y G", "EEEE، d ܒMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_820: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d ܒMMM y G", "d ܒMMMM y G", "EEEE، d ܒMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_821: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d ܒMMM y G", "d ܒMMMM y G", "EEEE، d ܒMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_822: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d ܒMMM y G", "d ܒMMMM y G", "EEEE، d ܒMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_823: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d ܒMMM y G", "d ܒMMMM y G", "EEEE، d ܒMMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_189: [&_CalendarData; 6] = [&_CALENDAR_818, &_CALENDAR_819, &_CALENDAR_820, &_CALENDAR_821, &_CALENDAR_822, &_CALENDAR_823];
+
+#[rustfmt::skip]
+static _CALENDAR_824: _CalendarData = _CalendarData { months: [&["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_825: _CalendarData = _CalendarData { months: [&["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "siyrpnia", "września", "października", "listopada", "grudnia"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_190: [&_CalendarData; 6] = [&_CALENDAR_824, &_CALENDAR_825, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_826: _CalendarData = _CalendarData { months: [&["ஜன.", "பிப\u{bcd}.", "ம\u{bbe}ர\u{bcd}.", "ஏப\u{bcd}.", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக.", "செப\u{bcd}.", "அக\u{bcd}.", "நவ.", "டிச."], &["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"], &["ஜ", "பி", "ம\u{bbe}", "ஏ", "மே", "ஜூ", "ஜூ", "ஆ", "செ", "அ", "ந", "டி"], &["ஜன.", "பிப\u{bcd}.", "ம\u{bbe}ர\u{bcd}.", "ஏப\u{bcd}.", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக.", "செப\u{bcd}.", "அக\u{bcd}.", "நவ.", "டிச."], &["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"], &["ஜ", "பி", "ம\u{bbe}", "ஏ", "மே", "ஜூ", "ஜூ", "ஆ", "செ", "அ", "ந", "டி"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_827: _CalendarData = _CalendarData { months: [&["ஜன.", "பிப\u{bcd}.", "ம\u{bbe}ர\u{bcd}.", "ஏப\u{bcd}.", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக.", "செப\u{bcd}.", "அக\u{bcd}.", "நவ.", "டிச."], &["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"], &["ஜ", "பி", "ம\u{bbe}", "ஏ", "மே", "ஜூ", "ஜூ", "ஆ", "செ", "அ", "ந", "டி"], &["ஜன.", "பிப\u{bcd}.", "ம\u{bbe}ர\u{bcd}.", "ஏப\u{bcd}.", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக.", "செப\u{bcd}.", "அக\u{bcd}.", "நவ.", "டிச."], &["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"], &["ஜ", "பி", "ம\u{bbe}", "ஏ", "மே", "ஜூ", "ஜூ", "ஆ", "செ", "அ", "ந", "டி"]], eras: [&["கி.மு.", "கி.பி.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["கிறிஸ\u{bcd}துவுக\u{bcd}கு முன\u{bcd}", "அன\u{bcd}னோ டோமினி", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["கி.மு.", "கி.பி.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_828: _CalendarData = _CalendarData { months: [&["ஃபர\u{bcd}.", "ஆர\u{bcd}டி.", "கொர\u{bcd}.", "திர\u{bcd}", "மொர\u{bcd}.", "ஷ\u{bbe}ரி.", "மெஹ\u{bcd}.", "அப\u{bbe}.", "அச\u{bbe}.", "தே", "பஹ\u{bcd}.", "எஃ."], &["ஃபர\u{bcd}வ\u{bbe}தின\u{bcd}", "ஆர\u{bcd}டிபெஹெஷ\u{bcd}த\u{bcd}", "கொர\u{bcd}த\u{bbe}த\u{bcd}", "திர\u{bcd}", "மொர\u{bcd}த\u{bbe}த\u{bcd}", "ஷ\u{bbe}ரிவ\u{bbe}ர\u{bcd}", "மெஹ\u{bcd}ர\u{bcd}", "அப\u{bbe}ன\u{bcd}", "அச\u{bbe}ர\u{bcd}", "தே", "பஹ\u{bcd}ம\u{bbe}ன\u{bcd}", "எஃப\u{bbe}ன\u{bcd}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ஃபர\u{bcd}.", "ஆர\u{bcd}டி.", "கொர\u{bcd}.", "திர\u{bcd}", "மொர\u{bcd}.", "ஷ\u{bbe}ரி.", "மெஹ\u{bcd}.", "அப\u{bbe}.", "அச\u{bbe}.", "தே", "பஹ\u{bcd}.", "எஃ."], &["ஃபர\u{bcd}வ\u{bbe}தின\u{bcd}", "ஆர\u{bcd}டிபெஹெஷ\u{bcd}த\u{bcd}", "கொர\u{bcd}த\u{bbe}த\u{bcd}", "திர\u{bcd}", "மொர\u{bcd}த\u{bbe}த\u{bcd}", "ஷ\u{bbe}ரிவ\u{bbe}ர\u{bcd}", "மெஹ\u{bcd}ர\u{bcd}", "அப\u{bbe}ன\u{bcd}", "அச\u{bbe}ர\u{bcd}", "தே", "பஹ\u{bcd}ம\u{bbe}ன\u{bcd}", "எஃப\u{bbe}ன\u{bcd}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_829: _CalendarData = _CalendarData { months: [&["முஹ.", "சஃப.", "ரபி 1", "ரபி 2", "ஜும. 1", "ஜும. 2", "ரஜ.", "ஷஃ.", "ரம.", "ஷவ\u{bcd}.", "துல\u{bcd} கஃ.", "துல\u{bcd} ஹிஜ\u{bcd}."], &["முஹர\u{bcd}ரம\u{bcd}", "சஃபர\u{bcd}", "ரபி 1", "ரபி 2", "ஜுமத\u{bbe} 1", "ஜுமத\u{bbe} 2", "ரஜப\u{bcd}", "ஷஃப\u{bbe}ன\u{bcd}", "ரமல\u{bbe}ன\u{bcd}", "ஷவ\u{bcd}வ\u{bbe}ல\u{bcd}", "துல\u{bcd} கஃத\u{bbe}", "துல\u{bcd} ஹிஜ\u{bcd}ஜ\u{bbe}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["முஹ.", "சஃப.", "ரபி 1", "ரபி 2", "ஜும. 1", "ஜும. 2", "ரஜ.", "ஷஃ.", "ரம.", "ஷவ\u{bcd}.", "துல\u{bcd} கஃ.", "துல\u{bcd} ஹிஜ\u{bcd}."], &["முஹர\u{bcd}ரம\u{bcd}", "சஃபர\u{bcd}", "ரபி 1", "ரபி 2", "ஜுமத\u{bbe} 1", "ஜுமத\u{bbe} 2", "ரஜப\u{bcd}", "ஷஃப\u{bbe}ன\u{bcd}", "ரமல\u{bbe}ன\u{bcd}", "ஷவ\u{bcd}வ\u{bbe}ல\u{bcd}", "துல\u{bcd} கஃத\u{bbe}", "துல\u{bcd} ஹிஜ\u{bcd}ஜ\u{bbe}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_830: _CalendarData = _CalendarData { months: [&["டிஷ\u{bcd}.", "ஹெஷ\u{bcd}.", "கிஸ\u{bcd}.", "டெவெ.", "ஷெவ\u{bbe}.", "அட\u{bbe}. 1", "அட\u{bbe}.", "நிச\u{bbe}.", "ஐய\u{bbe}.", "சிவ\u{bbe}.", "தமு.", "அவ\u{bcd}", "எலு.", "அட\u{bbe}. 2"], &["டிஷ\u{bcd}ரி", "ஹெஷ\u{bcd}வ\u{bbe}ன\u{bcd}", "கிஸ\u{bcd}லெவ\u{bcd}", "டெவெட\u{bcd}", "ஷெவ\u{bbe}ட\u{bcd}", "அட\u{bbe}ர\u{bcd} 1", "அட\u{bbe}ர\u{bcd}", "நிச\u{bbe}ன\u{bcd}", "ஐய\u{bbe}ர\u{bcd}", "சிவ\u{bbe}ன\u{bcd}", "தமுஸ\u{bcd}", "அவ\u{bcd}", "எலுல\u{bcd}", "அட\u{bbe}ர\u{bcd} 2"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["டிஷ\u{bcd}.", "ஹெஷ\u{bcd}.", "கிஸ\u{bcd}.", "டெவெ.", "ஷெவ\u{bbe}.", "அட\u{bbe}. 1", "அட\u{bbe}.", "நிச\u{bbe}.", "ஐய\u{bbe}.", "சிவ\u{bbe}.", "தமு.", "அவ\u{bcd}", "எலு.", "அட\u{bbe}. 2"], &["டிஷ\u{bcd}ரி", "ஹெஷ\u{bcd}வ\u{bbe}ன\u{bcd}", "கிஸ\u{bcd}லெவ\u{bcd}", "டெவெட\u{bcd}", "ஷெவ\u{bbe}ட\u{bcd}", "அட\u{bbe}ர\u{bcd} 1", "அட\u{bbe}ர\u{bcd}", "நிச\u{bbe}ன\u{bcd}", "ஐய\u{bbe}ர\u{bcd}", "சிவ\u{bbe}ன\u{bcd}", "தமுஸ\u{bcd}", "அவ\u{bcd}", "எலுல\u{bcd}", "அட\u{bbe}ர\u{bcd} 2"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_831: _CalendarData = _CalendarData { months: [&["மஸ\u{bcd}.", "தெகெ.", "ஹெத.", "தஹ\u{bcd}.", "தெர\u{bcd}", "ய\u{bbe}க\u{bbe}.", "மக\u{bbe}.", "மிய\u{bbe}.", "கென\u{bcd}.", "சனே", "ஹமே.", "நஹ\u{bbe}.", "ப\u{bbe}கு.", ""], &["மஸ\u{bcd}கரம\u{bcd}", "தெகெம\u{bcd}ப\u{bcd}த\u{bcd}", "ஹெதர\u{bcd}", "தஹ\u{bcd}ச\u{bbe}ஸ\u{bcd}", "தெர\u{bcd}", "ய\u{bbe}க\u{bbe}டிட\u{bcd}", "மக\u{bbe}பிட\u{bcd}", "மிய\u{bbe}ஸ\u{bcd}ய\u{bbe}", "கென\u{bcd}போ", "சனே", "ஹமேல\u{bcd}", "நஹ\u{bbe}சே", "ப\u{bbe}குமே", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["மஸ\u{bcd}.", "தெகெ.", "ஹெத.", "தஹ\u{bcd}.", "தெர\u{bcd}", "ய\u{bbe}க\u{bbe}.", "மக\u{bbe}.", "மிய\u{bbe}.", "கென\u{bcd}.", "சனே", "ஹமே.", "நஹ\u{bbe}.", "ப\u{bbe}கு.", ""], &["மஸ\u{bcd}கரம\u{bcd}", "தெகெம\u{bcd}ப\u{bcd}த\u{bcd}", "ஹெதர\u{bcd}", "தஹ\u{bcd}ச\u{bbe}ஸ\u{bcd}", "தெர\u{bcd}", "ய\u{bbe}க\u{bbe}டிட\u{bcd}", "மக\u{bbe}பிட\u{bcd}", "மிய\u{bbe}ஸ\u{bcd}ய\u{bbe}", "கென\u{bcd}போ", "சனே", "ஹமேல\u{bcd}", "நஹ\u{bbe}சே", "ப\u{bbe}குமே", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_191: [&_CalendarData; 6] = [&_CALENDAR_826, &_CALENDAR_827, &_CALENDAR_828, &_CALENDAR_829, &_CALENDAR_830, &_CALENDAR_831];
+
+#[rustfmt::skip]
+static _CALENDAR_832: _CalendarData = _CalendarData { months: [&["జన", "ఫ\u{c3f}బ\u{c4d}ర", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగ", "స\u{c46}ప\u{c4d}ట\u{c46}ం", "అక\u{c4d}ట\u{c4b}", "నవం", "డ\u{c3f}స\u{c46}ం"], &["జనవర\u{c3f}", "ఫ\u{c3f}బ\u{c4d}రవర\u{c3f}", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}ల\u{c4d}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగస\u{c4d}టు", "స\u{c46}ప\u{c4d}ట\u{c46}ంబర\u{c4d}", "అక\u{c4d}ట\u{c4b}బర\u{c4d}", "నవంబర\u{c4d}", "డ\u{c3f}స\u{c46}ంబర\u{c4d}"], &["జ", "ఫ\u{c3f}", "మ\u{c3e}", "ఏ", "మ\u{c47}", "జూ", "జు", "ఆ", "స\u{c46}", "అ", "న", "డ\u{c3f}"], &["జన", "ఫ\u{c3f}బ\u{c4d}ర", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగ", "స\u{c46}ప\u{c4d}ట\u{c46}ం", "అక\u{c4d}ట\u{c4b}", "నవం", "డ\u{c3f}స\u{c46}ం"], &["జనవర\u{c3f}", "ఫ\u{c3f}బ\u{c4d}రవర\u{c3f}", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}ల\u{c4d}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగస\u{c4d}టు", "స\u{c46}ప\u{c4d}ట\u{c46}ంబర\u{c4d}", "అక\u{c4d}ట\u{c4b}బర\u{c4d}", "నవంబర\u{c4d}", "డ\u{c3f}స\u{c46}ంబర\u{c4d}"], &["జ", "ఫ\u{c3f}", "మ\u{c3e}", "ఏ", "మ\u{c47}", "జూ", "జు", "ఆ", "స\u{c46}", "అ", "న", "డ\u{c3f}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_833: _CalendarData = _CalendarData { months: [&["జన", "ఫ\u{c3f}బ\u{c4d}ర", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగ", "స\u{c46}ప\u{c4d}ట\u{c46}ం", "అక\u{c4d}ట\u{c4b}", "నవం", "డ\u{c3f}స\u{c46}ం"], &["జనవర\u{c3f}", "ఫ\u{c3f}బ\u{c4d}రవర\u{c3f}", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}ల\u{c4d}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగస\u{c4d}టు", "స\u{c46}ప\u{c4d}ట\u{c46}ంబర\u{c4d}", "అక\u{c4d}ట\u{c4b}బర\u{c4d}", "నవంబర\u{c4d}", "డ\u{c3f}స\u{c46}ంబర\u{c4d}"], &["జ", "ఫ\u{c3f}", "మ\u{c3e}", "ఏ", "మ\u{c47}", "జూ", "జు", "ఆ", "స\u{c46}", "అ", "న", "డ\u{c3f}"], &["జన", "ఫ\u{c3f}బ\u{c4d}ర", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగ", "స\u{c46}ప\u{c4d}ట\u{c46}ం", "అక\u{c4d}ట\u{c4b}", "నవం", "డ\u{c3f}స\u{c46}ం"], &["జనవర\u{c3f}", "ఫ\u{c3f}బ\u{c4d}రవర\u{c3f}", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}ల\u{c4d}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగస\u{c4d}టు", "స\u{c46}ప\u{c4d}ట\u{c46}ంబర\u{c4d}", "అక\u{c4d}ట\u{c4b}బర\u{c4d}", "నవంబర\u{c4d}", "డ\u{c3f}స\u{c46}ంబర\u{c4d}"], &["జ", "ఫ\u{c3f}", "మ\u{c3e}", "ఏ", "మ\u{c47}", "జూ", "జు", "ఆ", "స\u{c46}", "అ", "న", "డ\u{c3f}"]], eras: [&["క\u{c4d}ర\u{c40}పూ", "క\u{c4d}ర\u{c40}శ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["క\u{c4d}ర\u{c40}స\u{c4d}తు పూర\u{c4d}వం", "క\u{c4d}ర\u{c40}స\u{c4d}తు శకం", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["క\u{c4d}ర\u{c40}పూ", "క\u{c4d}ర\u{c40}శ", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_834: _CalendarData = _CalendarData { months: [&["ఫ\u{c3e}వర\u{c4d}డ\u{c3f}న\u{c4d}", "ఊడ\u{c3e}బహష\u{c4d}ట\u{c4d}", "ఖ\u{c4b}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "ట\u{c3f}ర\u{c4d}", "మ\u{c46}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "శశ\u{c3f}వర\u{c4d}", "మ\u{c46}హర\u{c4d}", "అబన\u{c4d}", "అజర\u{c4d}", "డ\u{c47}", "బ\u{c3e}హ\u{c4d}\u{200c}మ\u{c3e}న\u{c4d}", "ఎస\u{c4d}\u{200c}ఫ\u{c3e}ండ\u{c4d}"], &["ఫ\u{c3e}వర\u{c4d}డ\u{c3f}న\u{c4d}", "ఊడ\u{c3e}బహష\u{c4d}ట\u{c4d}", "ఖ\u{c4b}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "ట\u{c3f}ర\u{c4d}", "మ\u{c46}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "శశ\u{c3f}వర\u{c4d}", "మ\u{c46}హర\u{c4d}", "అబన\u{c4d}", "అజర\u{c4d}", "డ\u{c47}", "బ\u{c3e}హ\u{c4d}\u{200c}మ\u{c3e}న\u{c4d}", "ఎస\u{c4d}\u{200c}ఫ\u{c3e}ండ\u{c4d}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ఫ\u{c3e}వర\u{c4d}డ\u{c3f}న\u{c4d}", "ఊడ\u{c3e}బహష\u{c4d}ట\u{c4d}", "ఖ\u{c4b}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "ట\u{c3f}ర\u{c4d}", "మ\u{c46}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "శశ\u{c3f}వర\u{c4d}", "మ\u{c46}హర\u{c4d}", "అబన\u{c4d}", "అజర\u{c4d}", "డ\u{c47}", "బ\u{c3e}హ\u{c4d}\u{200c}మ\u{c3e}న\u{c4d}", "ఎస\u{c4d}\u{200c}ఫ\u{c3e}ండ\u{c4d}"], &["ఫ\u{c3e}వర\u{c4d}డ\u{c3f}న\u{c4d}", "ఊడ\u{c3e}బహష\u{c4d}ట\u{c4d}", "ఖ\u{c4b}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "ట\u{c3f}ర\u{c4d}", "మ\u{c46}ర\u{c4d}డ\u{c3e}డ\u{c4d}", "శశ\u{c3f}వర\u{c4d}", "మ\u{c46}హర\u{c4d}", "అబన\u{c4d}", "అజర\u{c4d}", "డ\u{c47}", "బ\u{c3e}హ\u{c4d}\u{200c}మ\u{c3e}న\u{c4d}", "ఎస\u{c4d}\u{200c}ఫ\u{c3e}ండ\u{c4d}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_835: _CalendarData = _CalendarData { months: [&["ముహ.", "సఫ.", "ర. I", "ర. II", "జుమ. I", "జుమ. II", "రజ.", "షబ\u{c3e}.", "రంజ\u{c3e}.", "షవ\u{c4d}వ\u{c3e}.", "ధుల\u{c4d}-క\u{c3f}.", "ధుల\u{c4d}-హ\u{c3f}."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ముహ.", "సఫ.", "ర. I", "ర. II", "జుమ. I", "జుమ. II", "రజ.", "షబ\u{c3e}.", "రంజ\u{c3e}.", "షవ\u{c4d}వ\u{c3e}.", "ధుల\u{c4d}-క\u{c3f}.", "ధుల\u{c4d}-హ\u{c3f}."], &["ముహర\u{c4d}రం", "సఫర\u{c4d}", "రబ\u{c40} I", "రబ\u{c40} II", "జుమద\u{c3e} I", "జుమద\u{c3e} II", "రజబ\u{c4d}", "షబ\u{c3e}న\u{c4d}", "రంజ\u{c3e}న\u{c4d}", "షవ\u{c4d}వ\u{c3e}ల\u{c4d}", "ధుల\u{c4d}-క\u{c3f} ద\u{c3e}హ\u{c4d}", "ధుల\u{c4d}-హ\u{c3f}జ\u{c4d}జ\u{c3e}హ\u{c4d}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_836: _CalendarData = _CalendarData { months: [&["ట\u{c3f}శ\u{c4d}ర\u{c40}", "హ\u{c47}ష\u{c4d}\u{200c}వ\u{c3e}న\u{c4d}", "క\u{c3f}స\u{c4d}ల\u{c46}వ\u{c4d}", "ట\u{c46}వ\u{c46}ట\u{c4d}", "ష\u{c46}వ\u{c3e}ట\u{c4d}", "అదర\u{c4d} I", "అదర\u{c4d}", "న\u{c3f}స\u{c3e}న\u{c4d}", "ఐయర\u{c4d}", "స\u{c3f}వ\u{c3e}న\u{c4d}", "తముజ\u{c4d}", "అవ", "ఇలుల\u{c4d}", "అదర\u{c4d} II"], &["ట\u{c3f}శ\u{c4d}ర\u{c40}", "హ\u{c47}ష\u{c4d}\u{200c}వ\u{c3e}న\u{c4d}", "క\u{c3f}స\u{c4d}ల\u{c46}వ\u{c4d}", "ట\u{c46}వ\u{c46}ట\u{c4d}", "ష\u{c46}వ\u{c3e}ట\u{c4d}", "అదర\u{c4d} I", "అదర\u{c4d}", "న\u{c3f}స\u{c3e}న\u{c4d}", "ఐయర\u{c4d}", "స\u{c3f}వ\u{c3e}న\u{c4d}", "తముజ\u{c4d}", "అవ", "ఇలుల\u{c4d}", "అదర\u{c4d} II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ట\u{c3f}శ\u{c4d}ర\u{c40}", "హ\u{c47}ష\u{c4d}\u{200c}వ\u{c3e}న\u{c4d}", "క\u{c3f}స\u{c4d}ల\u{c46}వ\u{c4d}", "ట\u{c46}వ\u{c46}ట\u{c4d}", "ష\u{c46}వ\u{c3e}ట\u{c4d}", "అదర\u{c4d} I", "అదర\u{c4d}", "న\u{c3f}స\u{c3e}న\u{c4d}", "ఐయర\u{c4d}", "స\u{c3f}వ\u{c3e}న\u{c4d}", "తముజ\u{c4d}", "అవ", "ఇలుల\u{c4d}", "అదర\u{c4d} II"], &["ట\u{c3f}శ\u{c4d}ర\u{c40}", "హ\u{c47}ష\u{c4d}\u{200c}వ\u{c3e}న\u{c4d}", "క\u{c3f}స\u{c4d}ల\u{c46}వ\u{c4d}", "ట\u{c46}వ\u{c46}ట\u{c4d}", "ష\u{c46}వ\u{c3e}ట\u{c4d}", "అదర\u{c4d} I", "అదర\u{c4d}", "న\u{c3f}స\u{c3e}న\u{c4d}", "ఐయర\u{c4d}", "స\u{c3f}వ\u{c3e}న\u{c4d}", "తముజ\u{c4d}", "అవ", "ఇలుల\u{c4d}", "అదర\u{c4d} II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_837: _CalendarData = _CalendarData { months: [&["మ\u{c46}స\u{c4d}క\u{c4d}\u{200c}రమ\u{c4d}", "ట\u{c46}క\u{c46}మట\u{c4d}", "హ\u{c46}దర\u{c4d}", "తహస\u{c3e}స\u{c4d}", "టర\u{c4d}", "య\u{c46}క\u{c3e}ట\u{c3f}ట\u{c4d}", "మ\u{c46}గ\u{c3e}బ\u{c3f}ట\u{c4d}", "మ\u{c3f}యజ\u{c3f}య", "గ\u{c46}న\u{c4d}\u{200c}బ\u{c4b}ట\u{c4d}", "స\u{c46}న\u{c46}", "హమ\u{c4d}ల\u{c46}", "న\u{c46}హస\u{c4d}స\u{c46}", "పగుమ\u{c46}న\u{c4d}", ""], &["మ\u{c46}స\u{c4d}క\u{c4d}\u{200c}రమ\u{c4d}", "ట\u{c46}క\u{c46}మట\u{c4d}", "హ\u{c46}దర\u{c4d}", "తహస\u{c3e}స\u{c4d}", "టర\u{c4d}", "య\u{c46}క\u{c3e}ట\u{c3f}ట\u{c4d}", "మ\u{c46}గ\u{c3e}బ\u{c3f}ట\u{c4d}", "మ\u{c3f}యజ\u{c3f}య", "గ\u{c46}న\u{c4d}\u{200c}బ\u{c4b}ట\u{c4d}", "స\u{c46}న\u{c46}", "హమ\u{c4d}ల\u{c46}", "న\u{c46}హస\u{c4d}స\u{c46}", "పగుమ\u{c46}న\u{c4d}", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["మ\u{c46}స\u{c4d}క\u{c4d}\u{200c}రమ\u{c4d}", "ట\u{c46}క\u{c46}మట\u{c4d}", "హ\u{c46}దర\u{c4d}", "తహస\u{c3e}స\u{c4d}", "టర\u{c4d}", "య\u{c46}క\u{c3e}ట\u{c3f}ట\u{c4d}", "మ\u{c46}గ\u{c3e}బ\u{c3f}ట\u{c4d}", "మ\u{c3f}యజ\u{c3f}య", "గ\u{c46}న\u{c4d}\u{200c}బ\u{c4b}ట\u{c4d}", "స\u{c46}న\u{c46}", "హమ\u{c4d}ల\u{c46}", "న\u{c46}హస\u{c4d}స\u{c46}", "పగుమ\u{c46}న\u{c4d}", ""], &["మ\u{c46}స\u{c4d}క\u{c4d}\u{200c}రమ\u{c4d}", "ట\u{c46}క\u{c46}మట\u{c4d}", "హ\u{c46}దర\u{c4d}", "తహస\u{c3e}స\u{c4d}", "టర\u{c4d}", "య\u{c46}క\u{c3e}ట\u{c3f}ట\u{c4d}", "మ\u{c46}గ\u{c3e}బ\u{c3f}ట\u{c4d}", "మ\u{c3f}యజ\u{c3f}య", "గ\u{c46}న\u{c4d}\u{200c}బ\u{c4b}ట\u{c4d}", "స\u{c46}న\u{c46}", "హమ\u{c4d}ల\u{c46}", "న\u{c46}హస\u{c4d}స\u{c46}", "పగుమ\u{c46}న\u{c4d}", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_192: [&_CalendarData; 6] = [&_CALENDAR_832, &_CALENDAR_833, &_CALENDAR_834, &_CALENDAR_835, &_CALENDAR_836, &_CALENDAR_837];
+
+#[rustfmt::skip]
+static _CALENDAR_838: _CalendarData = _CalendarData { months: [&["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], &["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], &["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_839: _CalendarData = _CalendarData { months: [&["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], &["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"], &["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["ПеМ", "ПаМ", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Пеш аз милод", "Пас аз милод", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ПеМ", "ПаМ", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_840: _CalendarData = _CalendarData { months: [&["фарвардин", "урдибиҳишт", "хурдод", "тир", "мурдод", "шаҳривар", "меҳр", "обон", "озар", "дей", "баҳман", "исфанд"], &["фарвардин", "урдибиҳишт", "хурдод", "тир", "мурдод", "шаҳривар", "меҳр", "обон", "озар", "дей", "баҳман", "исфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["фарвардин", "урдибиҳишт", "хурдод", "тир", "мурдод", "шаҳривар", "меҳр", "обон", "озар", "дей", "баҳман", "исфанд"], &["фарвардин", "урдибиҳишт", "хурдод", "тир", "мурдод", "шаҳривар", "меҳр", "обон", "озар", "дей", "баҳман", "исфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "d MMMMи y G", "EEEE, d MMMMи y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_841: _CalendarData = _CalendarData { months: [&["Муҳ.", "Саф.", "Раб. I", "Раб. II", "Ҷум. I", "Ҷум. II", "Раҷ.", "Ша.", "Рам.", "Шав.", "Дхул-Қ.", "Дхул-Ҳ."], &["Муҳаррам", "Сафар", "Рабеъ I", "Рабеъ II", "ҷимоди-ул-уло", "ҷимоди-ул-сони", "раҷаб", "Шабан", "Рамадан", "Шаввал", "Дхут-Қидаҳ", "Дхут-Ҳиҷҷаҳ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Муҳ.", "Саф.", "Раб. I", "Раб. II", "Ҷум. I", "Ҷум. II", "Раҷ.", "Ша.", "Рам.", "Шав.", "Дхул-Қ.", "Дхул-Ҳ."], &["Муҳаррам", "Сафар", "Рабеъ I", "Рабеъ II", "Ҷимоди-ул-уло", "Ҷимоди-ул-сони", "Раҷаб", "Шаъбон", "Рамазон", "Шаавол", "Дхул-Қидаҳ", "Дхул-Ҳиҷҷаҳ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["САНА"], &["САНА"], &["САНА"]], date_patterns: ["M/d/y GGGGG", "d MMM y G", "d MMMMи y G", "EEEE, d MMMMи y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_842: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_843: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/yy GGGGG", "dd MMM y G", "dd MMMM y G", "EEEE, dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_193: [&_CalendarData; 6] = [&_CALENDAR_838, &_CALENDAR_839, &_CALENDAR_840, &_CALENDAR_841, &_CALENDAR_842, &_CALENDAR_843];
+
+#[rustfmt::skip]
+static _CALENDAR_844: _CalendarData = _CalendarData { months: [&["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], &["มกราคม", "ก\u{e38}มภาพ\u{e31}นธ\u{e4c}", "ม\u{e35}นาคม", "เมษายน", "พฤษภาคม", "ม\u{e34}ถ\u{e38}นายน", "กรกฎาคม", "ส\u{e34}งหาคม", "ก\u{e31}นยายน", "ต\u{e38}ลาคม", "พฤศจ\u{e34}กายน", "ธ\u{e31}นวาคม"], &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], &["มกราคม", "ก\u{e38}มภาพ\u{e31}นธ\u{e4c}", "ม\u{e35}นาคม", "เมษายน", "พฤษภาคม", "ม\u{e34}ถ\u{e38}นายน", "กรกฎาคม", "ส\u{e34}งหาคม", "ก\u{e31}นยายน", "ต\u{e38}ลาคม", "พฤศจ\u{e34}กายน", "ธ\u{e31}นวาคม"], &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."]], eras: [&["พ.ศ."], &["พ\u{e38}ทธศ\u{e31}กราช"], &["พ.ศ."]], date_patterns: ["d/M/yy", "d MMM y", "d MMMM y", "EEEEท\u{e35}\u{e48} d MMMM G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_845: _CalendarData = _CalendarData { months: [&["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], &["มกราคม", "ก\u{e38}มภาพ\u{e31}นธ\u{e4c}", "ม\u{e35}นาคม", "เมษายน", "พฤษภาคม", "ม\u{e34}ถ\u{e38}นายน", "กรกฎาคม", "ส\u{e34}งหาคม", "ก\u{e31}นยายน", "ต\u{e38}ลาคม", "พฤศจ\u{e34}กายน", "ธ\u{e31}นวาคม"], &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."], &["มกราคม", "ก\u{e38}มภาพ\u{e31}นธ\u{e4c}", "ม\u{e35}นาคม", "เมษายน", "พฤษภาคม", "ม\u{e34}ถ\u{e38}นายน", "กรกฎาคม", "ส\u{e34}งหาคม", "ก\u{e31}นยายน", "ต\u{e38}ลาคม", "พฤศจ\u{e34}กายน", "ธ\u{e31}นวาคม"], &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."]], eras: [&["ก\u{e48}อน ค.ศ.", "ค.ศ.", "เมจ\u{e34}", "ทะอ\u{e34}โช", "โชวะ", "เฮเซ", "เรวะ"], &["ป\u{e35}ก\u{e48}อนคร\u{e34}สตกาล", "คร\u{e34}สต\u{e4c}ศ\u{e31}กราช", "เมจ\u{e34}", "ทะอ\u{e34}โช", "โชวะ", "เฮเซ", "เรวะ"], &["ก\u{e48}อน ค.ศ.", "ค.ศ.", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy G", "d MMM G y", "d MMMM ป\u{e35}G y", "EEEEท\u{e35}\u{e48} d MMMM ป\u{e35}Gท\u{e35}\u{e48} y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_846: _CalendarData = _CalendarData { months: [&["ฟาร\u{e4c}วาร\u{e4c}ด\u{e34}น", "ออร\u{e4c}ด\u{e34}เบเฮชต\u{e4c}", "คอร\u{e4c}แดด", "เตอร\u{e4c}", "มอร\u{e4c}แดด", "ชาหร\u{e34}วาร\u{e4c}", "เมฮร\u{e4c}", "อะบาน", "อะซาร\u{e4c}", "เดย\u{e4c}", "บาฮ\u{e4c}มาน", "เอสฟานด\u{e4c}"], &["ฟาร\u{e4c}วาร\u{e4c}ด\u{e34}น", "ออร\u{e4c}ด\u{e34}เบเฮชต\u{e4c}", "คอร\u{e4c}แดด", "เตอร\u{e4c}", "มอร\u{e4c}แดด", "ชาหร\u{e34}วาร\u{e4c}", "เมฮร\u{e4c}", "อะบาน", "อะซาร\u{e4c}", "เดย\u{e4c}", "บาฮ\u{e4c}มาน", "เอสฟานด\u{e4c}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ฟาร\u{e4c}วาร\u{e4c}ด\u{e34}น", "ออร\u{e4c}ด\u{e34}เบเฮชต\u{e4c}", "คอร\u{e4c}แดด", "เตอร\u{e4c}", "มอร\u{e4c}แดด", "ชาหร\u{e34}วาร\u{e4c}", "เมฮร\u{e4c}", "อะบาน", "อะซาร\u{e4c}", "เดย\u{e4c}", "บาฮ\u{e4c}มาน", "เอสฟานด\u{e4c}"], &["ฟาร\u{e4c}วาร\u{e4c}ด\u{e34}น", "ออร\u{e4c}ด\u{e34}เบเฮชต\u{e4c}", "คอร\u{e4c}แดด", "เตอร\u{e4c}", "มอร\u{e4c}แดด", "ชาหร\u{e34}วาร\u{e4c}", "เมฮร\u{e4c}", "อะบาน", "อะซาร\u{e4c}", "เดย\u{e4c}", "บาฮ\u{e4c}มาน", "เอสฟานด\u{e4c}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ป\u{e35}เปอร\u{e4c}เซ\u{e35}ย"], &["ป\u{e35}เปอร\u{e4c}เซ\u{e35}ย"], &["ป\u{e35}เปอร\u{e4c}เซ\u{e35}ย"]], date_patterns: ["d/M/y G", "d MMM G y", "d MMMM G y", "EEEEท\u{e35}\u{e48} d MMMM G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_847: _CalendarData = _CalendarData { months: [&["ม\u{e38}ฮ\u{e31}ร.", "เศาะ.", "รอบ\u{e35} I", "รอบ\u{e35} II", "จ\u{e38}มาดา I", "จ\u{e38}มาดา II", "เราะ.", "ชะอ\u{e4c}.", "เราะมะ.", "เชาว.", "ซ\u{e38}ลก\u{e34}อ\u{e3a}.", "ซ\u{e38}ลห\u{e34}จ."], &["ม\u{e38}ฮะร\u{e4c}รอม", "ซอฟาร\u{e4c}", "รอบ\u{e35} I", "รอบ\u{e35} II", "จ\u{e38}มาดา I", "จ\u{e38}มาดา II", "รอจ\u{e31}บ", "ชะอะบาน", "รอมะดอน", "เชาว\u{e31}ล", "ซ\u{e38}ลก\u{e34}อ\u{e3a}ดะฮ\u{e3a}", "ซ\u{e38}ลห\u{e34}จญะฮ\u{e3a}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ม\u{e38}ฮ\u{e31}ร.", "เศาะ.", "รอบ\u{e35} I", "รอบ\u{e35} II", "จ\u{e38}มาดา I", "จ\u{e38}มาดา II", "เราะ.", "ชะอ\u{e4c}.", "เราะมะ.", "เชาว.", "ซ\u{e38}ลก\u{e34}อ\u{e3a}.", "ซ\u{e38}ลห\u{e34}จ."], &["ม\u{e38}ฮะร\u{e4c}รอม", "ซอฟาร\u{e4c}", "รอบ\u{e35} I", "รอบ\u{e35} II", "จ\u{e38}มาดา I", "จ\u{e38}มาดา II", "รอจ\u{e31}บ", "ชะอะบาน", "รอมะดอน", "เชาว\u{e31}ล", "ซ\u{e38}ลก\u{e34}อ\u{e3a}ดะฮ\u{e3a}", "ซ\u{e38}ลห\u{e34}จญะฮ\u{e3a}"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ฮ.ศ."], &["ฮ\u{e34}จเราะห\u{e4c}ศ\u{e31}กราช"], &["ฮ.ศ."]], date_patterns: ["d/M/y G", "d MMM G y", "d MMMM G y", "EEEEท\u{e35}\u{e48} d MMMM G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_848: _CalendarData = _CalendarData { months: [&["ท\u{e34}ชร\u{e35}", "เฮวาน", "ก\u{e35}สเลฟ", "เตเวต", "เชว\u{e31}ต", "อาดาร\u{e4c} I", "อาดาร\u{e4c}", "น\u{e34}สซาน", "อ\u{e34}ยาร\u{e4c}", "ส\u{e35}ว\u{e31}น", "ตาม\u{e39}ซ", "อ\u{e31}ฟ", "เอลอ\u{e38}ล", "อาดาร\u{e4c} II"], &["ท\u{e34}ชร\u{e35}", "เฮวาน", "ก\u{e35}สเลฟ", "เตเวต", "เชว\u{e31}ต", "อาดาร\u{e4c} I", "อาดาร\u{e4c}", "น\u{e34}สซาน", "อ\u{e34}ยาร\u{e4c}", "ส\u{e35}ว\u{e31}น", "ตาม\u{e39}ซ", "อ\u{e31}ฟ", "เอลอ\u{e38}ล", "อาดาร\u{e4c} II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ท\u{e34}ชร\u{e35}", "เฮวาน", "ก\u{e35}สเลฟ", "เตเวต", "เชว\u{e31}ต", "อาดาร\u{e4c} I", "อาดาร\u{e4c}", "น\u{e34}สซาน", "อ\u{e34}ยาร\u{e4c}", "ส\u{e35}ว\u{e31}น", "ตาม\u{e39}ซ", "อ\u{e31}ฟ", "เอลอ\u{e38}ล", "อาดาร\u{e4c} II"], &["ท\u{e34}ชร\u{e35}", "เฮวาน", "ก\u{e35}สเลฟ", "เตเวต", "เชว\u{e31}ต", "อาดาร\u{e4c} I", "อาดาร\u{e4c}", "น\u{e34}สซาน", "อ\u{e34}ยาร\u{e4c}", "ส\u{e35}ว\u{e31}น", "ตาม\u{e39}ซ", "อ\u{e31}ฟ", "เอลอ\u{e38}ล", "อาดาร\u{e4c} II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["ย.ศ."], &["ย.ศ."], &["ย.ศ."]], date_patterns: ["d/M/y G", "d MMM G y", "d MMMM G y", "EEEEท\u{e35}\u{e48} d MMMM G y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_849: _CalendarData = _CalendarData { months: [&["เมสเคอเรม", "เตเกมท", "เฮดาร\u{e4c}", "ทาฮ\u{e4c}ซ\u{e31}ส", "เทอร\u{e4c}", "เยคาท\u{e34}ท", "เมกาบ\u{e34}ต", "เม\u{e35}ยเซ\u{e35}ย", "เจนบอต", "เซเน", "ฮ\u{e31}มเล", "เนแฮซ", "พาก\u{e39}เมน", ""], &["เมสเคอเรม", "เตเกมท", "เฮดาร\u{e4c}", "ทาฮ\u{e4c}ซ\u{e31}ส", "เทอร\u{e4c}", "เยคาท\u{e34}ท", "เมกาบ\u{e34}ต", "เม\u{e35}ยเซ\u{e35}ย", "เจนบอต", "เซเน", "ฮ\u{e31}มเล", "เนแฮซ", "พาก\u{e39}เมน", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["เมสเคอเรม", "เตเกมท", "เฮดาร\u{e4c}", "ทาฮ\u{e4c}ซ\u{e31}ส", "เทอร\u{e4c}", "เยคาท\u{e34}ท", "เมกาบ\u{e34}ต", "เม\u{e35}ยเซ\u{e35}ย", "เจนบอต", "เซเน", "ฮ\u{e31}มเล", "เนแฮซ", "พาก\u{e39}เมน", ""], &["เมสเคอเรม", "เตเกมท", "เฮดาร\u{e4c}", "ทาฮ\u{e4c}ซ\u{e31}ส", "เทอร\u{e4c}", "เยคาท\u{e34}ท", "เมกาบ\u{e34}ต", "เม\u{e35}ยเซ\u{e35}ย", "เจนบอต", "เซเน", "ฮ\u{e31}มเล", "เนแฮซ", "พาก\u{e39}เมน", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y G", "d MMM G y", "d MMMM G y", "EEEEท\u{e35}\u{e48} d MMMM G y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_194: [&_CalendarData; 6] = [&_CALENDAR_844, &_CALENDAR_845, &_CALENDAR_846, &_CALENDAR_847, &_CALENDAR_848, &_CALENDAR_849];
+
+#[rustfmt::skip]
+static _CALENDAR_850: _CalendarData = _CalendarData { months: [&["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"], &["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_851: _CalendarData = _CalendarData { months: [&["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"], &["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"]], eras: [&["ዓ/ዓ", "ዓ/ም", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ቅድመ ክርስቶስ", "ዓመተ ምሕረት", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ዓ/ዓ", "ዓ/ም", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_852: _CalendarData = _CalendarData { months: [&["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_195: [&_CalendarData; 6] = [&_CALENDAR_850, &_CALENDAR_851, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_852];
+
+#[rustfmt::skip]
+static _CALENDAR_853: _CalendarData = _CalendarData { months: [&["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"], &["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE፡ dd MMMM መዓልቲ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_854: _CalendarData = _CalendarData { months: [&["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"], &["ጥሪ", "ለካ", "መጋ", "ሚያ", "ግን", "ሰነ", "ሓም", "ነሓ", "መስ", "ጥቅ", "ሕዳ", "ታሕ"], &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"], &["ጥ", "ለ", "መ", "ሚ", "ግ", "ሰ", "ሓ", "ነ", "መ", "ጥ", "ሕ", "ታ"]], eras: [&["ዓ/ዓ", "ዓ/ም", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ዓመተ ዓለም", "ዓመተ ምሕረት", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ዓ/ዓ", "ዓ/ም", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE፡ dd MMMM መዓልቲ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_855: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE፡ dd MMMM መዓልቲ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_856: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE፡ dd MMMM መዓልቲ y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_857: _CalendarData = _CalendarData { months: [&["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ", "ጥሪ", "ለካቲት", "መጋቢት", "ሚያዚያ", "ጉንበት", "ሰነ", "ሓምለ", "ነሓሰ", "ጷጉሜ", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE፡ dd MMMM መዓልቲ y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_196: [&_CalendarData; 6] = [&_CALENDAR_853, &_CALENDAR_854, &_CALENDAR_855, &_CALENDAR_120, &_CALENDAR_856, &_CALENDAR_857];
+
+#[rustfmt::skip]
+static _CALENDAR_858: _CalendarData = _CalendarData { months: [&["ýan", "few", "mart", "apr", "maý", "iýun", "iýul", "awg", "sen", "okt", "noý", "dek"], &["ýanwar", "fewral", "mart", "aprel", "maý", "iýun", "iýul", "awgust", "sentýabr", "oktýabr", "noýabr", "dekabr"], &["Ý", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"], &["Ýan", "Few", "Mar", "Apr", "Maý", "Iýun", "Iýul", "Awg", "Sen", "Okt", "Noý", "Dek"], &["Ýanwar", "Fewral", "Mart", "Aprel", "Maý", "Iýun", "Iýul", "Awgust", "Sentýabr", "Oktýabr", "Noýabr", "Dekabr"], &["Ý", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "d MMMM y G EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_859: _CalendarData = _CalendarData { months: [&["ýan", "few", "mart", "apr", "maý", "iýun", "iýul", "awg", "sen", "okt", "noý", "dek"], &["ýanwar", "fewral", "mart", "aprel", "maý", "iýun", "iýul", "awgust", "sentýabr", "oktýabr", "noýabr", "dekabr"], &["Ý", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"], &["Ýan", "Few", "Mar", "Apr", "Maý", "Iýun", "Iýul", "Awg", "Sen", "Okt", "Noý", "Dek"], &["Ýanwar", "Fewral", "Mart", "Aprel", "Maý", "Iýun", "Iýul", "Awgust", "Sentýabr", "Oktýabr", "Noýabr", "Dekabr"], &["Ý", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]], eras: [&["B.e.öň", "B.e.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Isadan öň", "Isadan soň", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["B.e.öň", "B.e.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "d MMMM y G EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_860: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "d MMMM y G EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_861: _CalendarData = _CalendarData { months: [&["Aşy", "Sap", "Tir I", "Tir II", "Tir III", "Tir IV", "Rej", "Mer", "Ora", "Baý", "Boş", "Gur"], &["Aşyr", "Sapar", "Dört tirkeşik 1", "Dört tirkeşik 2", "Dört tirkeşik 3", "Dört tirkeşik 4", "Rejep", "Meret", "Oraza", "Baýram", "Boş aý", "Gurban"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Aşy", "Sap", "Tir I", "Tir II", "Tir III", "Tir IV", "Rej", "Mer", "Ora", "Baý", "Boş", "Gur"], &["Aşyr", "Sapar", "Dört tirkeşik 1", "Dört tirkeşik 2", "Dört tirkeşik 3", "Dört tirkeşik 4", "Rejep", "Meret", "Oraza", "Baýram", "Boş aý", "Gurban"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["HS"], &["HS"], &["HS"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_862: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "d MMMM y G EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_863: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y GGGGG", "d MMM y G", "d MMMM y G", "d MMMM y G EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_197: [&_CalendarData; 6] = [&_CALENDAR_858, &_CALENDAR_859, &_CALENDAR_860, &_CALENDAR_861, &_CALENDAR_862, &_CALENDAR_863];
+
+#[rustfmt::skip]
+static _CALENDAR_864: _CalendarData = _CalendarData { months: [&["Fer", "Tlh", "Mop", "Mor", "Mot", "See", "Phu", "Pha", "Lwe", "Dip", "Ngw", "Sed"], &["Ferikgong", "Tlhakole", "Mopitlo", "Moranang", "Motsheganang", "Seetebosigo", "Phukwi", "Phatwe", "Lwetse", "Diphalane", "Ngwanatsele", "Sedimonthole"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Fer", "Tlh", "Mop", "Mor", "Mot", "See", "Phu", "Pha", "Lwe", "Dip", "Ngw", "Sed"], &["Ferikgong", "Tlhakole", "Mopitlo", "Moranang", "Motsheganang", "Seetebosigo", "Phukwi", "Phatwe", "Lwetse", "Diphalane", "Ngwanatsele", "Sedimonthole"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_865: _CalendarData = _CalendarData { months: [&["Fer", "Tlh", "Mop", "Mor", "Mot", "See", "Phu", "Pha", "Lwe", "Dip", "Ngw", "Sed"], &["Ferikgong", "Tlhakole", "Mopitlo", "Moranang", "Motsheganang", "Seetebosigo", "Phukwi", "Phatwe", "Lwetse", "Diphalane", "Ngwanatsele", "Sedimonthole"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Fer", "Tlh", "Mop", "Mor", "Mot", "See", "Phu", "Pha", "Lwe", "Dip", "Ngw", "Sed"], &["Ferikgong", "Tlhakole", "Mopitlo", "Moranang", "Motsheganang", "Seetebosigo", "Phukwi", "Phatwe", "Lwetse", "Diphalane", "Ngwanatsele", "Sedimonthole"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Pele ga tsalo ya Morena Jeso", "Morago ga Leso la Morena Jeso", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_198: [&_CalendarData; 6] = [&_CALENDAR_864, &_CALENDAR_865, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_866: _CalendarData = _CalendarData { months: [&["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sēp", "ʻOka", "Nōv", "Tīs"], &["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sēpitema", "ʻOkatopa", "Nōvema", "Tīsema"], &["S", "F", "M", "ʻE", "M", "S", "S", "ʻA", "S", "ʻO", "N", "T"], &["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sēp", "ʻOka", "Nōv", "Tīs"], &["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sepitema", "ʻOkatopa", "Nōvema", "Tīsema"], &["S", "F", "M", "ʻE", "M", "S", "S", "ʻA", "S", "ʻO", "N", "T"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_867: _CalendarData = _CalendarData { months: [&["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sēp", "ʻOka", "Nōv", "Tīs"], &["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sēpitema", "ʻOkatopa", "Nōvema", "Tīsema"], &["S", "F", "M", "ʻE", "M", "S", "S", "ʻA", "S", "ʻO", "N", "T"], &["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sēp", "ʻOka", "Nōv", "Tīs"], &["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sepitema", "ʻOkatopa", "Nōvema", "Tīsema"], &["S", "F", "M", "ʻE", "M", "S", "S", "ʻA", "S", "ʻO", "N", "T"]], eras: [&["KM", "TS", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ki muʻa", "taʻu ʻo Sīsū", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["KM", "TS", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_868: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_869: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_870: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_871: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/yy GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_199: [&_CalendarData; 6] = [&_CALENDAR_866, &_CALENDAR_867, &_CALENDAR_868, &_CALENDAR_869, &_CALENDAR_870, &_CALENDAR_871];
+
+#[rustfmt::skip]
+static _CALENDAR_872: _CalendarData = _CalendarData { months: [&["Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara"], &["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"], &["O", "Ş", "M", "N", "M", "H", "T", "A", "E", "E", "K", "A"], &["Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara"], &["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"], &["O", "Ş", "M", "N", "M", "H", "T", "A", "E", "E", "K", "A"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG d.MM.y", "G d MMM y", "G d MMMM y", "G d MMMM y EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_873: _CalendarData = _CalendarData { months: [&["Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara"], &["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"], &["O", "Ş", "M", "N", "M", "H", "T", "A", "E", "E", "K", "A"], &["Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara"], &["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"], &["O", "Ş", "M", "N", "M", "H", "T", "A", "E", "E", "K", "A"]], eras: [&["MÖ", "MS", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Milattan Önce", "Milattan Sonra", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["MÖ", "MS", "M", "T", "S", "H", "R"]], date_patterns: ["d.MM.y G", "d MMM y G", "d MMMM y G", "d MMMM y G EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_874: _CalendarData = _CalendarData { months: [&["Ferverdin", "Ordibeheşt", "Hordad", "Tir", "Mordad", "Şehriver", "Mehr", "Aban", "Azer", "Dey", "Behmen", "Esfend"], &["Ferverdin", "Ordibeheşt", "Hordad", "Tir", "Mordad", "Şehriver", "Mehr", "Aban", "Azer", "Dey", "Behmen", "Esfend"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Ferverdin", "Ordibeheşt", "Hordad", "Tir", "Mordad", "Şehriver", "Mehr", "Aban", "Azer", "Dey", "Behmen", "Esfend"], &["Ferverdin", "Ordibeheşt", "Hordad", "Tir", "Mordad", "Şehriver", "Mehr", "Aban", "Azer", "Dey", "Behmen", "Esfend"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG d.MM.y", "G d MMM y", "G d MMMM y", "G d MMMM y EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_875: _CalendarData = _CalendarData { months: [&["Muhar.", "Safer", "R.evvel", "R.ahir", "C.evvel", "C.ahir", "Recep", "Şaban", "Ram.", "Şevval", "Zilkade", "Zilhicce"], &["Muharrem", "Safer", "Rebiülevvel", "Rebiülahir", "Cemaziyelevvel", "Cemaziyelahir", "Recep", "Şaban", "Ramazan", "Şevval", "Zilkade", "Zilhicce"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muhar.", "Safer", "R.evvel", "R.ahir", "C.evvel", "C.ahir", "Recep", "Şaban", "Ram.", "Şevval", "Zilkade", "Zilhicce"], &["Muharrem", "Safer", "Rebiülevvel", "Rebiülahir", "Cemaziyelevvel", "Cemaziyelahir", "Recep", "Şaban", "Ramazan", "Şevval", "Zilkade", "Zilhicce"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["Hicri"], &["Hicri"], &["Hicri"]], date_patterns: ["GGGGG d.MM.y", "G d MMM y", "G d MMMM y", "G d MMMM y EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_876: _CalendarData = _CalendarData { months: [&["Tişri", "Heşvan", "Kislev", "Tevet", "Şevat", "Adar Rişon", "Adar", "Nisan", "İyar", "Sivan", "Tamuz", "Av", "Elul", "Veadar"], &["Tişri", "Heşvan", "Kislev", "Tevet", "Şevat", "Adar Rişon", "Adar", "Nisan", "İyar", "Sivan", "Tamuz", "Av", "Elul", "Veadar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tişri", "Heşvan", "Kislev", "Tevet", "Şevat", "Adar Rişon", "Adar", "Nisan", "İyar", "Sivan", "Tamuz", "Av", "Elul", "Veadar"], &["Tişri", "Heşvan", "Kislev", "Tevet", "Şevat", "Adar Rişon", "Adar", "Nisan", "İyar", "Sivan", "Tamuz", "Av", "Elul", "Veadar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG d.MM.y", "G d MMM y", "G d MMMM y", "G d MMMM y EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_877: _CalendarData = _CalendarData { months: [&["Meskerem", "Tikimt", "Hidar", "Tahsas", "Tir", "Yakatit", "Magabit", "Miyazya", "Ginbot", "Sene", "Hamle", "Nehasa", "Pagumiene", ""], &["Meskerem", "Tikimt", "Hidar", "Tahsas", "Tir", "Yakatit", "Magabit", "Miyazya", "Ginbot", "Sene", "Hamle", "Nehasa", "Pagumiene", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tikimt", "Hidar", "Tahsas", "Tir", "Yakatit", "Magabit", "Miyazya", "Ginbot", "Sene", "Hamle", "Nehasa", "Pagumiene", ""], &["Meskerem", "Tikimt", "Hidar", "Tahsas", "Tir", "Yakatit", "Magabit", "Miyazya", "Ginbot", "Sene", "Hamle", "Nehasa", "Pagumiene", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG d.MM.y", "G d MMM y", "G d MMMM y", "G d MMMM y EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_200: [&_CalendarData; 6] = [&_CALENDAR_872, &_CALENDAR_873, &_CALENDAR_874, &_CALENDAR_875, &_CALENDAR_876, &_CALENDAR_877];
+
+#[rustfmt::skip]
+static _CALENDAR_878: _CalendarData = _CalendarData { months: [&["гыйн.", "фев.", "мар.", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], &["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["гыйн.", "фев.", "мар.", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], &["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y (GGGGG)", "dd.MM.y (G)", "d MMMM, y\u{202f}ел (G)", "d MMMM, y\u{202f}ел (G), EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_879: _CalendarData = _CalendarData { months: [&["гыйн.", "фев.", "мар.", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], &["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["гыйн.", "фев.", "мар.", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."], &["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["б.э.к.", "милади", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["безнең эрага кадәр", "милади", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["б.э.к.", "милади", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y (GGGGG)", "dd.MM.y (G)", "d MMMM, y\u{202f}ел (G)", "d MMMM, y\u{202f}ел (G), EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_880: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.y (GGGGG)", "dd.MM.y (G)", "d MMMM, y\u{202f}ел (G)", "d MMMM, y\u{202f}ел (G), EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_881: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.y (GGGGG)", "dd.MM.y (G)", "d MMMM, y\u{202f}ел (G)", "d MMMM, y\u{202f}ел (G), EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_882: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y (GGGGG)", "dd.MM.y (G)", "d MMMM, y\u{202f}ел (G)", "d MMMM, y\u{202f}ел (G), EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_883: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y (GGGGG)", "dd.MM.y (G)", "d MMMM, y\u{202f}ел (G)", "d MMMM, y\u{202f}ел (G), EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_201: [&_CalendarData; 6] = [&_CALENDAR_878, &_CALENDAR_879, &_CALENDAR_880, &_CALENDAR_881, &_CALENDAR_882, &_CALENDAR_883];
+
+#[rustfmt::skip]
+static _CALENDAR_884: _CalendarData = _CalendarData { months: [&["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["بۇددا يىلنامەسى"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d، y G", "MMMM d، y G", "EEEE، MMMM d، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_885: _CalendarData = _CalendarData { months: [&["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "مىلادىيە", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["مىلادىيەدىن بۇرۇن", "مىلادىيە", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "مىلادىيە", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d، y G", "MMMM d، y G", "EEEE, MMMM d، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_886: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["M/d/y GGGGG", "MMM d، y G", "MMMM d، y G", "EEEE، MMMM d، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_887: _CalendarData = _CalendarData { months: [&["مۇھەررەم", "سەپەر", "رەبىئۇلئەۋۋەل", "رەبىئۇلئاخىر", "جەمادىيەلئەۋۋەل", "جەمادىيەلئاخىر", "رەجەب", "شەئبان", "رامىزان", "شەۋۋال", "زۇلقەئدە", "زۇلھەججە"], &["مۇھەررەم", "سەپەر", "رەبىئۇلئەۋۋەل", "رەبىئۇلئاخىر", "جەمادىيەلئەۋۋەل", "جەمادىيەلئاخىر", "رەجەب", "شەئبان", "رامىزان", "شەۋۋال", "زۇلقەئدە", "زۇلھەججە"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["مۇھەررەم", "سەپەر", "رەبىئۇلئەۋۋەل", "رەبىئۇلئاخىر", "جەمادىيەلئەۋۋەل", "جەمادىيەلئاخىر", "رەجەب", "شەئبان", "رامىزان", "شەۋۋال", "زۇلقەئدە", "زۇلھەججە"], &["مۇھەررەم", "سەپەر", "رەبىئۇلئەۋۋەل", "رەبىئۇلئاخىر", "جەمادىيەلئەۋۋەل", "جەمادىيەلئاخىر", "رەجەب", "شەئبان", "رامىزان", "شەۋۋال", "زۇلقەئدە", "زۇلھەججە"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ھىجرىيە"], &["ھىجرىيە"], &["ھىجرىيە"]], date_patterns: ["d\u{200f}/M\u{200f}/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_888: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["M/d/y GGGGG", "MMM d، y G", "MMMM d، y G", "EEEE، MMMM d، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_889: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["M/d/y GGGGG", "MMM d، y G", "MMMM d، y G", "EEEE، MMMM d، y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_202: [&_CalendarData; 6] = [&_CALENDAR_884, &_CALENDAR_885, &_CALENDAR_886, &_CALENDAR_887, &_CALENDAR_888, &_CALENDAR_889];
+
+#[rustfmt::skip]
+static _CALENDAR_890: _CalendarData = _CalendarData { months: [&["січ.", "лют.", "бер.", "квіт.", "трав.", "черв.", "лип.", "серп.", "вер.", "жовт.", "лист.", "груд."], &["січня", "лютого", "березня", "квітня", "травня", "червня", "липня", "серпня", "вересня", "жовтня", "листопада", "грудня"], &["с", "л", "б", "к", "т", "ч", "л", "с", "в", "ж", "л", "г"], &["січ.", "лют.", "бер.", "квіт.", "трав.", "черв.", "лип.", "серп.", "вер.", "жовт.", "лист.", "груд."], &["січень", "лютий", "березень", "квітень", "травень", "червень", "липень", "серпень", "вересень", "жовтень", "листопад", "грудень"], &["С", "Л", "Б", "К", "Т", "Ч", "Л", "С", "В", "Ж", "Л", "Г"]], eras: [&["б. е."], &["б. е."], &["б.е."]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y\u{202f}р. G", "d MMMM y\u{202f}р. G", "EEEE, d MMMM y\u{202f}р. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_891: _CalendarData = _CalendarData { months: [&["січ.", "лют.", "бер.", "квіт.", "трав.", "черв.", "лип.", "серп.", "вер.", "жовт.", "лист.", "груд."], &["січня", "лютого", "березня", "квітня", "травня", "червня", "липня", "серпня", "вересня", "жовтня", "листопада", "грудня"], &["с", "л", "б", "к", "т", "ч", "л", "с", "в", "ж", "л", "г"], &["січ.", "лют.", "бер.", "квіт.", "трав.", "черв.", "лип.", "серп.", "вер.", "жовт.", "лист.", "груд."], &["січень", "лютий", "березень", "квітень", "травень", "червень", "липень", "серпень", "вересень", "жовтень", "листопад", "грудень"], &["С", "Л", "Б", "К", "Т", "Ч", "Л", "С", "В", "Ж", "Л", "Г"]], eras: [&["до н. е.", "н. е.", "Meiji", "Taishō", "Shōwa", "Хейсей", "Рейва"], &["до нашої ери", "нашої ери", "Meiji", "Taishō", "Shōwa", "Хейсей", "Рейва"], &["до н.е.", "н.е.", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y\u{202f}р. G", "d MMMM y\u{202f}р. G", "EEEE, d MMMM y\u{202f}р. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_892: _CalendarData = _CalendarData { months: [&["фарвардін", "ордібехешт", "хордад", "тір", "мордад", "шахрівер", "мехр", "абан", "азер", "дей", "бахман", "есфанд"], &["фарвардін", "ордібехешт", "хордад", "тір", "мордад", "шахрівер", "мехр", "абан", "азер", "дей", "бахман", "есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["фарвардін", "ордібехешт", "хордад", "тір", "мордад", "шахрівер", "мехр", "абан", "азер", "дей", "бахман", "есфанд"], &["фарвардін", "ордібехешт", "хордад", "тір", "мордад", "шахрівер", "мехр", "абан", "азер", "дей", "бахман", "есфанд"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y\u{202f}р. G", "d MMMM y\u{202f}р. G", "EEEE, d MMMM y\u{202f}р. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_893: _CalendarData = _CalendarData { months: [&["мух.", "саф.", "рабі I", "рабі II", "джум. I", "джум. II", "радж.", "шааб.", "рам.", "дав.", "зу-ль-к.", "зу-ль-х."], &["мухаррам", "сафар", "рабі I", "рабі II", "джумада I", "джумада II", "раджаб", "шаабан", "рамадан", "даввал", "зу-ль-каада", "зу-ль-хіджа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["мух", "саф", "рабі I", "рабі II", "джум I", "джум II", "радж", "шааб", "рам", "дав", "зу-ль-к", "зу-ль-х"], &["мухаррам", "сафар", "рабі I", "рабі II", "джумада I", "джумада II", "раджаб", "шаабан", "рамадан", "даввал", "зу-ль-каада", "зу-ль-хіджа"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y\u{202f}р. G", "d MMMM y\u{202f}р. G", "EEEE, d MMMM y\u{202f}р. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_894: _CalendarData = _CalendarData { months: [&["тішри", "марчешван", "числьов", "тебет", "шеват", "адар I", "адар", "нісан", "іар", "сиван", "таммуз", "аб", "елул", "адар II"], &["тішри", "марчешван", "числьов", "тебет", "шеват", "адар I", "адар", "нісан", "іар", "сиван", "таммуз", "аб", "елул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["тішри", "марчешван", "числьов", "тебет", "шеват", "адар I", "адар", "нісан", "іар", "сиван", "таммуз", "аб", "елул", "адар II"], &["тішри", "марчешван", "числьов", "тебет", "шеват", "адар I", "адар", "нісан", "іар", "сиван", "таммуз", "аб", "елул", "адар II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y\u{202f}р. G", "d MMMM y\u{202f}р. G", "EEEE, d MMMM y\u{202f}р. G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_895: _CalendarData = _CalendarData { months: [&["мес.", "тек.", "хед.", "тах.", "тер.", "єкат.", "мег.", "міяз.", "ген.", "сен.", "хам.", "нех.", "паг.", ""], &["мескерема", "текемта", "хедара", "тахсаса", "тера", "єкатіта", "мегабіта", "міязія", "генбота", "сене", "хамле", "нехасе", "пагумена", ""], &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12", "13", ""], &["мес.", "тек.", "хед.", "тах.", "тер.", "єкат.", "мег.", "міяз.", "ген.", "сен.", "хам.", "нех.", "паг.", ""], &["мескерема", "текемт", "хедара", "тахсаса", "тера", "єкатіта", "мегабіта", "міязія", "генбота", "сене", "хамле", "нехасе", "пагумена", ""], &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.yy GGGGG", "d MMM y\u{202f}р. G", "d MMMM y\u{202f}р. G", "EEEE, d MMMM y\u{202f}р. G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_203: [&_CalendarData; 6] = [&_CALENDAR_890, &_CALENDAR_891, &_CALENDAR_892, &_CALENDAR_893, &_CALENDAR_894, &_CALENDAR_895];
+
+#[rustfmt::skip]
+static _CALENDAR_896: _CalendarData = _CalendarData { months: [&["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_897: _CalendarData = _CalendarData { months: [&["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"], &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["قبل مسیح", "عیسوی", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["قبل مسیح", "عیسوی", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["قبل مسیح", "عیسوی", "M", "T", "S", "H", "R"]], date_patterns: ["d/M/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_898: _CalendarData = _CalendarData { months: [&["فروردن", "آرڈبائش", "خداداد", "تیر", "مرداد", "شہریوار", "مہر", "ابان", "آزر", "ڈے", "بہمن", "اسفند"], &["فروردن", "آرڈبائش", "خداداد", "تیر", "مرداد", "شہریوار", "مہر", "ابان", "آزر", "ڈے", "بہمن", "اسفند"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["فروردن", "آرڈبائش", "خداداد", "تیر", "مرداد", "شہریوار", "مہر", "ابان", "آزر", "ڈے", "بہمن", "اسفند"], &["فروردن", "آرڈبائش", "خداداد", "تیر", "مرداد", "شہریوار", "مہر", "ابان", "آزر", "ڈے", "بہمن", "اسفند"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_899: _CalendarData = _CalendarData { months: [&["محرم", "صفر", "ربیع الاو\u{651}ل", "ربیع الثانی", "جمادی الاو\u{651}ل", "جمادی الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعدۃ", "ذوالحجۃ"], &["محرم", "صفر", "ر بیع الاول", "ر بیع الثانی", "جمادی الاول", "جمادی الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعدۃ", "ذوالحجۃ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["محرم", "صفر", "ربیع الاو\u{651}ل", "ربیع الث\u{651}انی", "جمادی الاو\u{651}ل", "جمادی الث\u{651}انی", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعدۃ", "ذوالحجۃ"], &["محرم", "صفر", "ر بیع الاول", "ر بیع الثانی", "جمادی الاول", "جمادی الثانی", "رجب", "شعبان", "رمضان", "شوال", "ذوالقعدۃ", "ذوالحجۃ"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["ہجری"], &["ہجری"], &["ہجری"]], date_patterns: ["d/M/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_900: _CalendarData = _CalendarData { months: [&["ٹشری", "هےشوان", "کسلیو", "تیویت", "شیوت", "آدر او\u{651}ل", "آدر", "نسان", "ایئر", "سیون", "تموز", "او", "ای لول", "آدر دو\u{651}م"], &["ٹشری", "هےشوان", "کسلیو", "تیویت", "شیوت", "آدر او\u{651}ل", "آدر", "نسان", "ایئر", "سیون", "تموز", "او", "ای لول", "آدر دو\u{651}م"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["ٹشری", "هےشوان", "کسلیو", "تیویت", "شیوت", "آدر او\u{651}ل", "آدر", "نسان", "ایئر", "سیون", "تموز", "او", "ای لول", "آدر دو\u{651}م"], &["ٹشری", "هےشوان", "کسلیو", "تیویت", "شیوت", "آدر او\u{651}ل", "آدر", "نسان", "ایئر", "سیون", "تموز", "او", "ای لول", "آدر دو\u{651}م"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_901: _CalendarData = _CalendarData { months: [&["مسکرم", "تیکیمت", "ہیدر", "تہساس", "تیر", "یکاتیت", "میگابت", "میازیا", "گیمبوٹ", "سینے", "ہیملے", "نیہاسے", "پیگیومین", ""], &["مسکرم", "تیکیمت", "ہیدر", "تہساس", "تیر", "یکاتیت", "میگابت", "میازیا", "گیمبوٹ", "سینے", "ہیملے", "نیہاسے", "پیگیومین", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["مسکرم", "تیکیمت", "ہیدر", "تہساس", "تیر", "یکاتیت", "میگابت", "میازیا", "گیمبوٹ", "سینے", "ہیملے", "نیہاسے", "پیگیومین", ""], &["مسکرم", "تیکیمت", "ہیدر", "تہساس", "تیر", "یکاتیت", "میگابت", "میازیا", "گیمبوٹ", "سینے", "ہیملے", "نیہاسے", "پیگیومین", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "دور1"], &["ERA0", "دور1"], &["ERA0", "دور1"]], date_patterns: ["d/M/y GGGGG", "d MMM، y G", "d MMMM، y G", "EEEE، d MMMM، y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_204: [&_CalendarData; 6] = [&_CALENDAR_896, &_CALENDAR_897, &_CALENDAR_898, &_CALENDAR_899, &_CALENDAR_900, &_CALENDAR_901];
+
+#[rustfmt::skip]
+static _CALENDAR_902: _CalendarData = _CalendarData { months: [&["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avg", "sen", "okt", "noy", "dek"], &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avgust", "sentabr", "oktabr", "noyabr", "dekabr"], &["Y", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"], &["Yan", "Fev", "Mar", "Apr", "May", "Iyn", "Iyl", "Avg", "Sen", "Okt", "Noy", "Dek"], &["Yanvar", "Fevral", "Mart", "Aprel", "May", "Iyun", "Iyul", "Avgust", "Sentabr", "Oktabr", "Noyabr", "Dekabr"], &["Y", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd.MM.y (GGGGG)", "d-MMM, y (G)", "d-MMMM, y (G)", "EEEE, d-MMMM, y (G)"] };
+
+#[rustfmt::skip]
+static _CALENDAR_903: _CalendarData = _CalendarData { months: [&["yan", "fev", "mar", "apr", "may", "iyn", "iyl", "avg", "sen", "okt", "noy", "dek"], &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avgust", "sentabr", "oktabr", "noyabr", "dekabr"], &["Y", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"], &["Yan", "Fev", "Mar", "Apr", "May", "Iyn", "Iyl", "Avg", "Sen", "Okt", "Noy", "Dek"], &["Yanvar", "Fevral", "Mart", "Aprel", "May", "Iyun", "Iyul", "Avgust", "Sentabr", "Oktabr", "Noyabr", "Dekabr"], &["Y", "F", "M", "A", "M", "I", "I", "A", "S", "O", "N", "D"]], eras: [&["m.a.", "milodiy", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["miloddan avvalgi", "milodiy", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["m.a.", "milodiy", "M", "T", "S", "H", "R"]], date_patterns: ["dd.MM.y (GGGGG)", "d-MMM, y (G)", "d-MMMM, y (G)", "EEEE, d-MMMM, y (G)"] };
+
+#[rustfmt::skip]
+static _CALENDAR_904: _CalendarData = _CalendarData { months: [&["farvardin", "oʻrdibehisht", "xurdod", "tir", "murdod", "shahrivar", "mehr", "obon", "ozar", "dey", "bahman", "isfan"], &["farvardin", "oʻrdibehisht", "xurdod", "tir", "murdod", "shahrivar", "mehr", "obon", "ozar", "dey", "bahman", "isfan"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["farvardin", "oʻrdibehisht", "xurdod", "tir", "murdod", "shahrivar", "mehr", "obon", "ozar", "dey", "bahman", "isfand"], &["farvardin", "oʻrdibehisht", "xurdod", "tur", "murdod", "shahrivar", "mehr", "obon", "ozar", "dey", "bahman", "isfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["forsiy"], &["forsiy"], &["forsiy"]], date_patterns: ["dd.MM.y (GGGGG)", "d-MMM, y (G)", "d-MMMM, y (G)", "EEEE, d-MMMM, y (G)"] };
+
+#[rustfmt::skip]
+static _CALENDAR_905: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. avv.", "Rab. son.", "Jum. avv.", "Jum. son.", "Raj.", "Sha.", "Ram.", "Shav.", "Zulq.", "Zulh."], &["Muharram", "Safar", "Rabiʼul avval", "Rabiʼus soniy", "Jumodul avval", "Jumodus soniy", "Rajab", "Sha’bon", "Ramazon", "Shavvol", "Zulqaʼda", "Zulhijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. avv.", "Rab. son.", "Jum. avv.", "Jum. son.", "Raj.", "Sha.", "Ram.", "Shav.", "Zulq.", "Zulh."], &["Muharram", "Safar", "Rabiʼul avval", "Rabiʼus soniy", "Jumodul avval", "Jumodus soniy", "Rajab", "Sha’bon", "Ramazon", "Shavvol", "Zulqaʼda", "Zulhijja"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["hijriy"], &["hijriy"], &["hijriy"]], date_patterns: ["dd.MM.y (GGGGG)", "d-MMM, y (G)", "d-MMMM, y (G)", "EEEE, d-MMMM, y (G)"] };
+
+#[rustfmt::skip]
+static _CALENDAR_906: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd.MM.y (GGGGG)", "d-MMM, y (G)", "d-MMMM, y (G)", "EEEE, d-MMMM, y (G)"] };
+
+#[rustfmt::skip]
+static _CALENDAR_907: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd.MM.y (GGGGG)", "d-MMM, y (G)", "d-MMMM, y (G)", "EEEE, d-MMMM, y (G)"] };
+
+#[rustfmt::skip]
+static _CALENDARS_205: [&_CalendarData; 6] = [&_CALENDAR_902, &_CALENDAR_903, &_CALENDAR_904, &_CALENDAR_905, &_CALENDAR_906, &_CALENDAR_907];
+
+#[rustfmt::skip]
+static _CALENDAR_908: _CalendarData = _CalendarData { months: [&["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"], &["январ", "феврал", "март", "апрел", "май", "июн", "июл", "август", "сентябр", "октябр", "ноябр", "декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"], &["январ", "феврал", "март", "апрел", "май", "июн", "июл", "август", "сентябр", "октябр", "ноябр", "декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_909: _CalendarData = _CalendarData { months: [&["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"], &["январ", "феврал", "март", "апрел", "май", "июн", "июл", "август", "сентябр", "октябр", "ноябр", "декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"], &["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"], &["январ", "феврал", "март", "апрел", "май", "июн", "июл", "август", "сентябр", "октябр", "ноябр", "декабр"], &["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"]], eras: [&["м.а.", "милодий", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["милоддан аввалги", "милодий", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["м.а.", "милодий", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_910: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_911: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_912: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDAR_913: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y/MM/dd", "G y MMM d", "G y MMMM d", "EEEE, G y MMMM dd"] };
+
+#[rustfmt::skip]
+static _CALENDARS_206: [&_CalendarData; 6] = [&_CALENDAR_908, &_CALENDAR_909, &_CALENDAR_910, &_CALENDAR_911, &_CALENDAR_912, &_CALENDAR_913];
+
+#[rustfmt::skip]
+static _CALENDAR_914: _CalendarData = _CalendarData { months: [&["jen", "feb", "mar", "apr", "maj", "jug", "luj", "ago", "set", "oto", "nov", "dez"], &["jenaro", "febraro", "marso", "aprile", "majo", "jugno", "lujo", "agosto", "setenbre", "otobre", "novenbre", "dezenbre"], &["J", "F", "M", "A", "M", "J", "L", "A", "S", "O", "N", "D"], &["jen", "feb", "mar", "apr", "maj", "jug", "luj", "ago", "set", "oto", "nov", "dez"], &["jenaro", "febraro", "marso", "aprile", "majo", "jugno", "lujo", "agosto", "setenbre", "otobre", "novenbre", "dezenbre"], &["J", "F", "M", "A", "M", "J", "L", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_915: _CalendarData = _CalendarData { months: [&["jen", "feb", "mar", "apr", "maj", "jug", "luj", "ago", "set", "oto", "nov", "dez"], &["jenaro", "febraro", "marso", "aprile", "majo", "jugno", "lujo", "agosto", "setenbre", "otobre", "novenbre", "dezenbre"], &["J", "F", "M", "A", "M", "J", "L", "A", "S", "O", "N", "D"], &["jen", "feb", "mar", "apr", "maj", "jug", "luj", "ago", "set", "oto", "nov", "dez"], &["jenaro", "febraro", "marso", "aprile", "majo", "jugno", "lujo", "agosto", "setenbre", "otobre", "novenbre", "dezenbre"], &["J", "F", "M", "A", "M", "J", "L", "A", "S", "O", "N", "D"]], eras: [&["v.C.", "d.C.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["vanti Cristo", "daspò Cristo", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["v.C.", "d.C.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_207: [&_CalendarData; 6] = [&_CALENDAR_914, &_CALENDAR_915, &_CALENDAR_189, &_CALENDAR_363, &_CALENDAR_364, &_CALENDAR_190];
+
+#[rustfmt::skip]
+static _CALENDAR_916: _CalendarData = _CalendarData { months: [&["thg 1", "thg 2", "thg 3", "thg 4", "thg 5", "thg 6", "thg 7", "thg 8", "thg 9", "thg 10", "thg 11", "thg 12"], &["tháng 1", "tháng 2", "tháng 3", "tháng 4", "tháng 5", "tháng 6", "tháng 7", "tháng 8", "tháng 9", "tháng 10", "tháng 11", "tháng 12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"], &["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "'ngày' d 'tháng' M 'năm' y G", "EEEE', ngày' dd MMMM 'năm' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_917: _CalendarData = _CalendarData { months: [&["thg 1", "thg 2", "thg 3", "thg 4", "thg 5", "thg 6", "thg 7", "thg 8", "thg 9", "thg 10", "thg 11", "thg 12"], &["tháng 1", "tháng 2", "tháng 3", "tháng 4", "tháng 5", "tháng 6", "tháng 7", "tháng 8", "tháng 9", "tháng 10", "tháng 11", "tháng 12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"], &["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["TCN", "SCN", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Trước Chúa Giáng Sinh", "Sau Công Nguyên", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["TCN", "CN", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y G", "dd-MM-y G", "'Ngày' dd 'tháng' M 'năm' y G", "EEEE', ngày' dd MMMM 'năm' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_918: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "'ngày' d 'tháng' M 'năm' y G", "EEEE', ngày' d 'tháng' M 'năm' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_919: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "'ngày' d 'tháng' M 'năm' y G", "EEEE', ngày' d 'tháng' M 'năm' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_920: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "'ngày' d 'tháng' M 'năm' y G", "EEEE', ngày' d 'tháng' M 'năm' y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_921: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["d/M/y GGGGG", "d MMM, y G", "'ngày' d 'tháng' M 'năm' y G", "EEEE', ngày' d 'tháng' M 'năm' y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_208: [&_CalendarData; 6] = [&_CALENDAR_916, &_CALENDAR_917, &_CALENDAR_918, &_CALENDAR_919, &_CALENDAR_920, &_CALENDAR_921];
+
+#[rustfmt::skip]
+static _CALENDAR_922: _CalendarData = _CalendarData { months: [&["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_923: _CalendarData = _CalendarData { months: [&["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["janeiru", "fevereiru", "marsu", "abril", "maiu", "junyu", "julyu", "agostu", "setembru", "outubru", "novembru", "dezembru"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_209: [&_CalendarData; 6] = [&_CALENDAR_922, &_CALENDAR_923, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_924: _CalendarData = _CalendarData { months: [&["Sam", "Few", "Mar", "Awr", "Mee", "Suw", "Sul", "Ut", "Sàt", "Okt", "Now", "Des"], &["Samwiyee", "Fewriyee", "Mars", "Awril", "Mee", "Suwe", "Sulet", "Ut", "Sàttumbar", "Oktoobar", "Nowàmbar", "Desàmbar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Sam", "Few", "Mar", "Awr", "Mee", "Suw", "Sul", "Ut", "Sàt", "Okt", "Now", "Des"], &["Samwiyee", "Fewriyee", "Mars", "Awril", "Mee", "Suwe", "Sulet", "Ut", "Sàttumbar", "Oktoobar", "Nowàmbar", "Desàmbar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd-MM-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_925: _CalendarData = _CalendarData { months: [&["Sam", "Few", "Mar", "Awr", "Mee", "Suw", "Sul", "Ut", "Sàt", "Okt", "Now", "Des"], &["Samwiyee", "Fewriyee", "Mars", "Awril", "Mee", "Suwe", "Sulet", "Ut", "Sàttumbar", "Oktoobar", "Nowàmbar", "Desàmbar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Sam", "Few", "Mar", "Awr", "Mee", "Suw", "Sul", "Ut", "Sàt", "Okt", "Now", "Des"], &["Samwiyee", "Fewriyee", "Mars", "Awril", "Mee", "Suwe", "Sulet", "Ut", "Sàttumbar", "Oktoobar", "Nowàmbar", "Desàmbar"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["JC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["av. JC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["JC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["dd-MM-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_926: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd-MM-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_927: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd-MM-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_928: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd-MM-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_929: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd-MM-y GGGGG", "d MMM, y G", "d MMMM, y G", "EEEE, d MMM, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_210: [&_CalendarData; 6] = [&_CALENDAR_924, &_CALENDAR_925, &_CALENDAR_926, &_CALENDAR_927, &_CALENDAR_928, &_CALENDAR_929];
+
+#[rustfmt::skip]
+static _CALENDAR_930: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sept", "Okt", "Nov", "Dis"], &["Janyuwari", "Februwari", "Matshi", "Epreli", "Meyi", "Juni", "Julayi", "Agasti", "Septemba", "Okthobha", "Novemba", "Disemba"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], &["Janyuwari", "Februwari", "Matshi", "Epreli", "Meyi", "Juni", "Julayi", "Agasti", "Septemba", "Okthoba", "Novemba", "Disemba"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_931: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sept", "Okt", "Nov", "Dis"], &["Janyuwari", "Februwari", "Matshi", "Epreli", "Meyi", "Juni", "Julayi", "Agasti", "Septemba", "Okthobha", "Novemba", "Disemba"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], &["Janyuwari", "Februwari", "Matshi", "Epreli", "Meyi", "Juni", "Julayi", "Agasti", "Septemba", "Okthoba", "Novemba", "Disemba"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["M/d/y GGGGG", "MMM d, y G", "MMMM d, y G", "EEEE, MMMM d, y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_211: [&_CalendarData; 6] = [&_CALENDAR_930, &_CALENDAR_931, &_CALENDAR_119, &_CALENDAR_120, &_CALENDAR_121, &_CALENDAR_122];
+
+#[rustfmt::skip]
+static _CALENDAR_932: _CalendarData = _CalendarData { months: [&["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDAR_933: _CalendarData = _CalendarData { months: [&["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"], &["जन॰", "फ\u{93c}र॰", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}ल॰", "अग॰", "सित॰", "अक\u{94d}त\u{942}॰", "नव॰", "दिस॰"], &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"], &["ज", "फ\u{93c}", "मा", "अ", "म", "ज\u{942}", "ज\u{941}", "अ", "सि", "अ", "न", "दि"]], eras: [&["ईसा-प\u{948}ल\u{947}", "ईस\u{94d}वी", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा-प\u{948}ल\u{947}", "ईसवी सन", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["ईसा-प\u{948}ल\u{947}", "ईस\u{94d}वी", "M", "T", "S", "H", "R"]], date_patterns: ["G d/M/y", "G d MMM y", "G d MMMM y", "G EEEE, d MMMM y"] };
+
+#[rustfmt::skip]
+static _CALENDARS_212: [&_CalendarData; 6] = [&_CALENDAR_932, &_CALENDAR_933, &_CALENDAR_590, &_CALENDAR_591, &_CALENDAR_399, &_CALENDAR_592];
+
+#[rustfmt::skip]
+static _CALENDAR_934: _CalendarData = _CalendarData { months: [&["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["S", "È", "Ẹ", "Ì", "Ẹ\u{300}", "Ò", "A", "Ò", "O", "Ọ\u{300}", "B", "Ọ\u{300}"], &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["S", "È", "Ẹ", "Ì", "Ẹ\u{300}", "Ò", "A", "Ò", "O", "Ọ\u{300}", "B", "Ọ\u{300}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_935: _CalendarData = _CalendarData { months: [&["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["S", "È", "Ẹ", "Ì", "Ẹ\u{300}", "Ò", "A", "Ò", "O", "Ọ\u{300}", "B", "Ọ\u{300}"], &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"], &["S", "È", "Ẹ", "Ì", "Ẹ\u{300}", "Ò", "A", "Ò", "O", "Ọ\u{300}", "B", "Ọ\u{300}"]], eras: [&["BCE", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Saju Kristi", "Lehin Kristi", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_936: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_937: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_938: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_939: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_213: [&_CalendarData; 6] = [&_CALENDAR_934, &_CALENDAR_935, &_CALENDAR_936, &_CALENDAR_937, &_CALENDAR_938, &_CALENDAR_939];
+
+#[rustfmt::skip]
+static _CALENDAR_940: _CalendarData = _CalendarData { months: [&["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["S", "È", "Ɛ", "Ì", "Ɛ\u{300}", "Ò", "A", "Ò", "O", "Ɔ\u{300}", "B", "Ɔ\u{300}"], &["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["S", "È", "Ɛ", "Ì", "Ɛ\u{300}", "Ò", "A", "Ò", "O", "Ɔ\u{300}", "B", "Ɔ\u{300}"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_941: _CalendarData = _CalendarData { months: [&["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["S", "È", "Ɛ", "Ì", "Ɛ\u{300}", "Ò", "A", "Ò", "O", "Ɔ\u{300}", "B", "Ɔ\u{300}"], &["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["Oshù Shɛ\u{301}rɛ\u{301}", "Oshù Èrèlè", "Oshù Ɛrɛ\u{300}nà", "Oshù Ìgbé", "Oshù Ɛ\u{300}bibi", "Oshù Òkúdu", "Oshù Agɛmɔ", "Oshù Ògún", "Oshù Owewe", "Oshù Ɔ\u{300}wàrà", "Oshù Bélú", "Oshù Ɔ\u{300}pɛ\u{300}"], &["S", "È", "Ɛ", "Ì", "Ɛ\u{300}", "Ò", "A", "Ò", "O", "Ɔ\u{300}", "B", "Ɔ\u{300}"]], eras: [&["BCE", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Saju Kristi", "Lehin Kristi", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/y G", "d MM y G", "d MM y G", "EEEE, d MM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_214: [&_CalendarData; 6] = [&_CALENDAR_940, &_CALENDAR_941, &_CALENDAR_936, &_CALENDAR_937, &_CALENDAR_938, &_CALENDAR_939];
+
+#[rustfmt::skip]
+static _CALENDAR_942: _CalendarData = _CalendarData { months: [&["ye", "mk", "ms", "id", "pu", "py", "pm", "ps", "pi", "yp", "yy", "ym"], &["yepé", "mukũi", "musapíri", "irũdí", "pú", "pú-yepé", "pú-mukũi", "pú-musapíri", "pú-irũdí", "yepé-putimaã", "yepé-yepé", "yepé-mukũi"], &["Y", "M", "M", "I", "P", "P", "P", "P", "P", "Y", "Y", "Y"], &["ye", "mk", "ms", "id", "pu", "py", "pm", "ps", "pi", "yp", "yy", "ym"], &["yepé", "mukũi", "musapíri", "irũdí", "pú", "pú-yepé", "pú-mukũi", "pú-musapíri", "pú-irũdí", "yepé-putimaã", "yepé-yepé", "yepé-mukũi"], &["Y", "M", "M", "I", "P", "P", "P", "P", "P", "Y", "Y", "Y"]], eras: [&["BE"], &["EB"], &["EB"]], date_patterns: ["dd/MM/y GGGGG", "d MMM y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_943: _CalendarData = _CalendarData { months: [&["ye", "mk", "ms", "id", "pu", "py", "pm", "ps", "pi", "yp", "yy", "ym"], &["yepé", "mukũi", "musapíri", "irũdí", "pú", "pú-yepé", "pú-mukũi", "pú-musapíri", "pú-irũdí", "yepé-putimaã", "yepé-yepé", "yepé-mukũi"], &["Y", "M", "M", "I", "P", "P", "P", "P", "P", "Y", "Y", "Y"], &["ye", "mk", "ms", "id", "pu", "py", "pm", "ps", "pi", "yp", "yy", "ym"], &["yepé", "mukũi", "musapíri", "irũdí", "pú", "pú-yepé", "pú-mukũi", "pú-musapíri", "pú-irũdí", "yepé-putimaã", "yepé-yepé", "yepé-mukũi"], &["Y", "M", "M", "I", "P", "P", "P", "P", "P", "Y", "Y", "Y"]], eras: [&["K.s.", "K.a.", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["Kiristu senũdé", "Kiristu ariré", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["K.s.", "K.a.", "M", "T", "S", "H", "R"]], date_patterns: ["dd/MM/yy GGGGG", "dd/MM/y G", "d MMMM y G", "EEEE, d MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_215: [&_CalendarData; 6] = [&_CALENDAR_942, &_CALENDAR_943, &_CALENDAR_141, &_CALENDAR_142, &_CALENDAR_143, &_CALENDAR_144];
+
+#[rustfmt::skip]
+static _CALENDAR_944: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["佛曆"], &["佛曆"], &["佛曆"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_945: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"], &["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"], &["西元前", "西元", "M", "T", "S", "H", "R"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_946: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["波斯曆"], &["波斯曆"], &["波斯曆"]], date_patterns: ["G y/M/d", "G y年M月d日", "G y年M月d日", "G y年M月d日 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_947: _CalendarData = _CalendarData { months: [&["穆哈蘭姆月", "色法爾月", "賴比月 I", "賴比月 II", "主馬達月 I", "主馬達月 II", "賴哲卜月", "舍爾邦月", "賴買丹月", "閃瓦魯月", "都爾喀爾德月", "都爾黑哲月"], &["穆哈蘭姆月", "色法爾月", "賴比月 I", "賴比月 II", "主馬達月 I", "主馬達月 II", "賴哲卜月", "舍爾邦月", "賴買丹月", "閃瓦魯月", "都爾喀爾德月", "都爾黑哲月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["穆哈蘭姆月", "色法爾月", "賴比月 I", "賴比月 II", "主馬達月 I", "主馬達月 II", "賴哲卜月", "舍爾邦月", "賴買丹月", "閃瓦魯月", "都爾喀爾德月", "都爾黑哲月"], &["穆哈蘭姆月", "色法爾月", "賴比月 I", "賴比月 II", "主馬達月 I", "主馬達月 II", "賴哲卜月", "舍爾邦月", "賴買丹月", "閃瓦魯月", "都爾喀爾德月", "都爾黑哲月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["伊斯蘭曆"], &["伊斯蘭曆"], &["伊斯蘭曆"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_948: _CalendarData = _CalendarData { months: [&["提斯利月", "瑪西班月", "基斯流月", "提別月", "細罷特月", "亞達月 I", "亞達月", "尼散月", "以珥月", "西彎月", "搭模斯月", "埃波月", "以祿月", "亞達月 II"], &["提斯利月", "瑪西班月", "基斯流月", "提別月", "細罷特月", "亞達月 I", "亞達月", "尼散月", "以珥月", "西彎月", "搭模斯月", "埃波月", "以祿月", "亞達月 II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["提斯利月", "瑪西班月", "基斯流月", "提別月", "細罷特月", "亞達月 I", "亞達月", "尼散月", "以珥月", "西彎月", "搭模斯月", "埃波月", "以祿月", "亞達月 II"], &["提斯利月", "瑪西班月", "基斯流月", "提別月", "細罷特月", "亞達月 I", "亞達月", "尼散月", "以珥月", "西彎月", "搭模斯月", "埃波月", "以祿月", "亞達月 II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["創世紀元"], &["創世紀元"], &["創世紀元"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_949: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["G y/M/d", "G y年M月d日", "G y年M月d日", "G y年M月d日 EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_216: [&_CalendarData; 6] = [&_CALENDAR_944, &_CALENDAR_945, &_CALENDAR_946, &_CALENDAR_947, &_CALENDAR_948, &_CALENDAR_949];
+
+#[rustfmt::skip]
+static _CALENDAR_950: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["佛历"], &["佛历"], &["佛历"]], date_patterns: ["Gy-M-d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_951: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"], &["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"], &["西元前", "西元", "M", "T", "S", "H", "R"]], date_patterns: ["Gyy-MM-dd", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_952: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["波斯历"], &["波斯历"], &["波斯历"]], date_patterns: ["Gy/M/d", "Gy年MM月d日", "Gy年MM月d日", "Gy年MM月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_953: _CalendarData = _CalendarData { months: [&["穆哈兰姆月", "色法尔月", "赖比月 I", "赖比月 II", "主马达月 I", "主马达月 II", "赖哲卜月", "舍尔邦月", "赖买丹月", "闪瓦鲁月", "都尔喀尔德月", "都尔黑哲月"], &["穆哈兰姆月", "色法尔月", "赖比月 I", "赖比月 II", "主马达月 I", "主马达月 II", "赖哲卜月", "舍尔邦月", "赖买丹月", "闪瓦鲁月", "都尔喀尔德月", "都尔黑哲月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["穆哈兰姆月", "色法尔月", "赖比月 I", "赖比月 II", "主马达月 I", "主马达月 II", "赖哲卜月", "舍尔邦月", "赖买丹月", "闪瓦鲁月", "都尔喀尔德月", "都尔黑哲月"], &["穆哈兰姆月", "色法尔月", "赖比月 I", "赖比月 II", "主马达月 I", "主马达月 II", "赖哲卜月", "舍尔邦月", "赖买丹月", "闪瓦鲁月", "都尔喀尔德月", "都尔黑哲月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["伊斯兰历"], &["伊斯兰历"], &["伊斯兰历"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_954: _CalendarData = _CalendarData { months: [&["提斯利月", "玛西班月", "基斯流月", "提别月", "细罢特月", "亚达月 I", "亚达月", "尼散月", "以珥月", "西弯月", "搭模斯月", "埃波月", "以禄月", "亚达月 II"], &["提斯利月", "玛西班月", "基斯流月", "提别月", "细罢特月", "亚达月 I", "亚达月", "尼散月", "以珥月", "西弯月", "搭模斯月", "埃波月", "以禄月", "亚达月 II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["提斯利月", "玛西班月", "基斯流月", "提别月", "细罢特月", "亚达月 I", "亚达月", "尼散月", "以珥月", "西弯月", "搭模斯月", "埃波月", "以禄月", "亚达月 II"], &["提斯利月", "玛西班月", "基斯流月", "提别月", "细罢特月", "亚达月 I", "亚达月", "尼散月", "以珥月", "西弯月", "搭模斯月", "埃波月", "以禄月", "亚达月 II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["创世纪元"], &["创世纪元"], &["创世纪元"]], date_patterns: ["Gy-M-d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_955: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["Gy/M/d", "Gy年MM月d日", "Gy年MM月d日", "Gy年MM月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_217: [&_CalendarData; 6] = [&_CALENDAR_950, &_CALENDAR_951, &_CALENDAR_952, &_CALENDAR_953, &_CALENDAR_954, &_CALENDAR_955];
+
+#[rustfmt::skip]
+static _CALENDAR_956: _CalendarData = _CalendarData { months: [&["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_957: _CalendarData = _CalendarData { months: [&["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["ndwenit", "ndwenngeih", "ndwensam", "ndwenseiq", "ndwenngux", "ndwenloeg", "ndwencaet", "ndwenbet", "ndwengouj", "ndwencib", "ndwencib’it", "ndwencibngeih"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BCE", "CE", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "G y MMM d", "G y MMMM d", "G y MMMM d, EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_218: [&_CalendarData; 6] = [&_CALENDAR_956, &_CALENDAR_957, &_CALENDAR_2, &_CALENDAR_3, &_CALENDAR_4, &_CALENDAR_5];
+
+#[rustfmt::skip]
+static _CALENDAR_958: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"], &["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"], &["公元前", "公元", "M", "T", "S", "H", "R"]], date_patterns: ["Gy-MM-dd", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_959: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["波斯历"], &["波斯历"], &["波斯历"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_960: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["伊斯兰历"], &["伊斯兰历"], &["伊斯兰历"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_961: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", "闰7月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月", "十三月", "闰七月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", "闰7月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月", "十三月", "闰七月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["希伯来历"], &["希伯来历"], &["希伯来历"]], date_patterns: ["G y/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_962: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月", "十三月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月", "十三月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "埃历"], &["埃塞俄比亚阿米特阿莱姆历", "埃塞俄比亚历"], &["ERA0", "埃历"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年MM月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_219: [&_CalendarData; 6] = [&_CALENDAR_950, &_CALENDAR_958, &_CALENDAR_959, &_CALENDAR_960, &_CALENDAR_961, &_CALENDAR_962];
+
+#[rustfmt::skip]
+static _CALENDAR_963: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["佛历"], &["佛历"], &["佛历"]], date_patterns: ["Gd/M/yy", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_964: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"], &["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"], &["公元前", "公元", "M", "T", "S", "H", "R"]], date_patterns: ["Gd/M/yy", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_965: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["波斯历"], &["波斯历"], &["波斯历"]], date_patterns: ["dd/MM/yyGGGGG", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_966: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["伊斯兰历"], &["伊斯兰历"], &["伊斯兰历"]], date_patterns: ["Gd/M/yy", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_967: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月", "十三月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月", "十三月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "埃历"], &["埃塞俄比亚阿米特阿莱姆历", "埃塞俄比亚历"], &["ERA0", "埃历"]], date_patterns: ["dd/MM/yyGGGGG", "Gy年M月d日", "Gy年M月d日", "Gy年MM月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_220: [&_CalendarData; 6] = [&_CALENDAR_963, &_CALENDAR_964, &_CALENDAR_965, &_CALENDAR_966, &_CALENDAR_961, &_CALENDAR_967];
+
+#[rustfmt::skip]
+static _CALENDAR_968: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"], &["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"], &["西元前", "西元", "明治", "大正", "昭和", "平成", "令和"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_221: [&_CalendarData; 6] = [&_CALENDAR_944, &_CALENDAR_968, &_CALENDAR_946, &_CALENDAR_947, &_CALENDAR_948, &_CALENDAR_949];
+
+#[rustfmt::skip]
+static _CALENDAR_969: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"], &["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"], &["公元前", "公元", "明治", "大正", "昭和", "平成", "令和"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_970: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["波斯曆"], &["波斯曆"], &["波斯曆"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDAR_971: _CalendarData = _CalendarData { months: [&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月", "13月", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["Gy/M/d", "Gy年M月d日", "Gy年M月d日", "Gy年M月d日EEEE"] };
+
+#[rustfmt::skip]
+static _CALENDARS_222: [&_CalendarData; 6] = [&_CALENDAR_944, &_CALENDAR_969, &_CALENDAR_970, &_CALENDAR_947, &_CALENDAR_948, &_CALENDAR_971];
+
+#[rustfmt::skip]
+static _CALENDAR_972: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mas", "Eph", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], &["Januwari", "Februwari", "Mashi", "Ephreli", "Meyi", "Juni", "Julayi", "Agasti", "Septhemba", "Okthoba", "Novemba", "Disemba"], &["J", "F", "M", "E", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mas", "Eph", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], &["Januwari", "Februwari", "Mashi", "Ephreli", "Meyi", "Juni", "Julayi", "Agasti", "Septhemba", "Okthoba", "Novemba", "Disemba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BE"], &["BE"], &["BE"]], date_patterns: ["GGGGG y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_973: _CalendarData = _CalendarData { months: [&["Jan", "Feb", "Mas", "Eph", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], &["Januwari", "Februwari", "Mashi", "Ephreli", "Meyi", "Juni", "Julayi", "Agasti", "Septhemba", "Okthoba", "Novemba", "Disemba"], &["J", "F", "M", "E", "M", "J", "J", "A", "S", "O", "N", "D"], &["Jan", "Feb", "Mas", "Eph", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"], &["Januwari", "Februwari", "Mashi", "Ephreli", "Meyi", "Juni", "Julayi", "Agasti", "Septhemba", "Okthoba", "Novemba", "Disemba"], &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]], eras: [&["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"], &["BC", "AD", "M", "T", "S", "H", "R"]], date_patterns: ["GGGGG y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_974: _CalendarData = _CalendarData { months: [&["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar", "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AP"], &["AP"], &["AP"]], date_patterns: ["GGGGG y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_975: _CalendarData = _CalendarData { months: [&["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"], &["Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.", "Dhuʻl-Q.", "Dhuʻl-H."], &["Muharram", "Safar", "Rabiʻ I", "Rabiʻ II", "Jumada I", "Jumada II", "Rajab", "Shaʻban", "Ramadan", "Shawwal", "Dhuʻl-Qiʻdah", "Dhuʻl-Hijjah"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"]], eras: [&["AH"], &["AH"], &["AH"]], date_patterns: ["GGGGG y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_976: _CalendarData = _CalendarData { months: [&["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Nisan", "Iyar", "Sivan", "Tamuz", "Av", "Elul", "Adar II"], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7"]], eras: [&["AM"], &["AM"], &["AM"]], date_patterns: ["GGGGG y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDAR_977: _CalendarData = _CalendarData { months: [&["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["Meskerem", "Tekemt", "Hedar", "Tahsas", "Ter", "Yekatit", "Megabit", "Miazia", "Genbot", "Sene", "Hamle", "Nehasse", "Pagumen", ""], &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", ""]], eras: [&["ERA0", "ERA1"], &["ERA0", "ERA1"], &["ERA0", "ERA1"]], date_patterns: ["GGGGG y-MM-dd", "d MMM y G", "d MMMM y G", "EEEE dd MMMM y G"] };
+
+#[rustfmt::skip]
+static _CALENDARS_223: [&_CalendarData; 6] = [&_CALENDAR_972, &_CALENDAR_973, &_CALENDAR_974, &_CALENDAR_975, &_CALENDAR_976, &_CALENDAR_977];
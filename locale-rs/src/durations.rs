@@ -0,0 +1,1430 @@
+// Auto-generated. DO NOT EDIT.
+use std::time::Duration;
+
+use crate::locale::Locale;
+#[cfg(feature = "nums")]
+use crate::num_formats::ToFormattedString;
+use crate::plurals::PluralOperands;
+
+/// Style of a formatted duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationStyle {
+    /// e.g. `1 hour, 5 minutes`
+    Long,
+    /// e.g. `1 hr, 5 min`
+    Short,
+    /// e.g. `1h 5m`. Some locales use the short unit names for some units,
+    /// e.g. Russian hours and minutes, as CLDR has no shorter ones.
+    Narrow,
+    /// e.g. `1:05:00`, from CLDR `durationUnit` patterns
+    Digital,
+}
+
+/// Formats a duration in weeks, days, hours, minutes and seconds for a
+/// locale; the digital style counts hours only.
+///
+/// Units that are zero are left out, e.g. "1 hr, 5 min" rather than
+/// "1 hr, 5 min, 0 sec", and fractions of a second are dropped.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::durations::{DurationStyle, ToDurationString};
+/// use std::time::Duration;
+///
+/// let runtime = Duration::from_secs(3900);
+/// assert_eq!(runtime.to_duration_string(&Locale::en, DurationStyle::Short), "1 hr, 5 min");
+/// assert_eq!(runtime.to_duration_string(&Locale::de, DurationStyle::Long), "1 Stunde, 5 Minuten");
+/// assert_eq!(runtime.to_duration_string(&Locale::en, DurationStyle::Digital), "1:05:00");
+///
+/// let trip = Duration::from_secs(9 * 86_400 + 3 * 3600);
+/// assert_eq!(trip.to_duration_string(&Locale::en, DurationStyle::Long), "1 week, 2 days, 3 hours");
+/// ```
+pub trait ToDurationString {
+    fn to_duration_string(&self, locale: &Locale, style: DurationStyle) -> String;
+}
+
+impl ToDurationString for Duration {
+    fn to_duration_string(&self, locale: &Locale, style: DurationStyle) -> String {
+        locale._format_duration(self.as_secs(), style)
+    }
+}
+
+/// Duration patterns of a locale.
+struct _DurationPatterns {
+    /// Week, day, hour, minute and second patterns by width and plural
+    /// category; empty ones use the `other` pattern.
+    units: [[[&'static str; 6]; 5]; 3],
+    /// Unit list patterns by width: for two items, then the start, middle
+    /// and end of longer lists.
+    lists: [[&'static str; 4]; 3],
+    /// Digital patterns with and without hours, e.g. `h:mm:ss` and `m:ss`.
+    digital: [&'static str; 2],
+}
+
+impl Locale {
+    fn _format_duration(&self, seconds: u64, style: DurationStyle) -> String {
+        let patterns = self._duration_patterns();
+        let fields = [
+            seconds / 604_800,
+            seconds / 86_400 % 7,
+            seconds / 3600 % 24,
+            seconds / 60 % 60,
+            seconds % 60,
+        ];
+        let width = match style {
+            DurationStyle::Digital => {
+                let pattern = patterns.digital[if seconds >= 3600 { 0 } else { 1 }];
+                return self._format_digital_duration(pattern, seconds);
+            }
+            style => style as usize,
+        };
+        let mut items: Vec<String> = fields
+            .iter()
+            .zip(&patterns.units[width])
+            .filter(|(value, _)| **value > 0)
+            .map(|(value, unit)| self._format_duration_unit(*value, unit))
+            .collect();
+        if items.is_empty() {
+            items.push(self._format_duration_unit(0, &patterns.units[width][4]));
+        }
+        _join_list(&patterns.lists[width], &items)
+    }
+
+    fn _format_duration_unit(&self, value: u64, patterns: &[&'static str; 6]) -> String {
+        let category = self.plural_category(&PluralOperands::from_integer(value as i64));
+        let pattern = match patterns[category as usize] {
+            "" => patterns[5],
+            pattern => pattern,
+        };
+        #[cfg(feature = "nums")]
+        let number = value.to_formatted_string(self);
+        #[cfg(not(feature = "nums"))]
+        let number = value.to_string();
+        pattern.replace("{0}", &number)
+    }
+
+    /// Fills a `durationUnit` pattern such as `h:mm:ss`. The largest field
+    /// is not capped, e.g. 26 hours stay `26:00:00`.
+    fn _format_digital_duration(&self, pattern: &str, seconds: u64) -> String {
+        let has_hours = pattern.contains('h');
+        let mut result = String::new();
+        let mut chars = pattern.chars().peekable();
+        let mut is_quoted = false;
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if let Some(&'\'') = chars.peek() {
+                    result.push('\'');
+                    chars.next();
+                } else {
+                    is_quoted = !is_quoted;
+                }
+                continue;
+            }
+            let value = match c {
+                'h' if !is_quoted => seconds / 3600,
+                'm' if !is_quoted && has_hours => seconds / 60 % 60,
+                'm' if !is_quoted => seconds / 60,
+                's' if !is_quoted => seconds % 60,
+                _ => {
+                    result.push(c);
+                    continue;
+                }
+            };
+            let mut count = 1;
+            while chars.next_if_eq(&c).is_some() {
+                count += 1;
+            }
+            result.push_str(&format!("{:0width$}", value, width = count));
+        }
+        #[cfg(feature = "nums")]
+        {
+            result = crate::num_formats::translate_digits(result, self);
+        }
+        result
+    }
+
+    fn _duration_patterns(&self) -> &'static _DurationPatterns {
+        match self {
+            Locale::aa => &_DURATION_PATTERNS_0,
+            Locale::aa_DJ => &_DURATION_PATTERNS_0,
+            Locale::aa_ER => &_DURATION_PATTERNS_0,
+            Locale::ab => &_DURATION_PATTERNS_0,
+            Locale::af => &_DURATION_PATTERNS_1,
+            Locale::af_NA => &_DURATION_PATTERNS_1,
+            Locale::agq => &_DURATION_PATTERNS_0,
+            Locale::ak => &_DURATION_PATTERNS_0,
+            Locale::am => &_DURATION_PATTERNS_2,
+            Locale::an => &_DURATION_PATTERNS_0,
+            Locale::ann => &_DURATION_PATTERNS_0,
+            Locale::apc => &_DURATION_PATTERNS_0,
+            Locale::ar => &_DURATION_PATTERNS_3,
+            Locale::ar_AE => &_DURATION_PATTERNS_3,
+            Locale::ar_BH => &_DURATION_PATTERNS_3,
+            Locale::ar_DJ => &_DURATION_PATTERNS_3,
+            Locale::ar_DZ => &_DURATION_PATTERNS_3,
+            Locale::ar_EG => &_DURATION_PATTERNS_3,
+            Locale::ar_EH => &_DURATION_PATTERNS_3,
+            Locale::ar_ER => &_DURATION_PATTERNS_3,
+            Locale::ar_IL => &_DURATION_PATTERNS_3,
+            Locale::ar_IQ => &_DURATION_PATTERNS_3,
+            Locale::ar_JO => &_DURATION_PATTERNS_3,
+            Locale::ar_KM => &_DURATION_PATTERNS_3,
+            Locale::ar_KW => &_DURATION_PATTERNS_3,
+            Locale::ar_LB => &_DURATION_PATTERNS_3,
+            Locale::ar_LY => &_DURATION_PATTERNS_3,
+            Locale::ar_MA => &_DURATION_PATTERNS_3,
+            Locale::ar_MR => &_DURATION_PATTERNS_3,
+            Locale::ar_OM => &_DURATION_PATTERNS_3,
+            Locale::ar_PS => &_DURATION_PATTERNS_3,
+            Locale::ar_QA => &_DURATION_PATTERNS_3,
+            Locale::ar_SA => &_DURATION_PATTERNS_4,
+            Locale::ar_SD => &_DURATION_PATTERNS_3,
+            Locale::ar_SO => &_DURATION_PATTERNS_3,
+            Locale::ar_SS => &_DURATION_PATTERNS_3,
+            Locale::ar_SY => &_DURATION_PATTERNS_3,
+            Locale::ar_TD => &_DURATION_PATTERNS_3,
+            Locale::ar_TN => &_DURATION_PATTERNS_3,
+            Locale::ar_YE => &_DURATION_PATTERNS_3,
+            Locale::arn => &_DURATION_PATTERNS_0,
+            Locale::as_ => &_DURATION_PATTERNS_5,
+            Locale::asa => &_DURATION_PATTERNS_0,
+            Locale::ast => &_DURATION_PATTERNS_6,
+            Locale::az => &_DURATION_PATTERNS_7,
+            Locale::az_Arab => &_DURATION_PATTERNS_7,
+            Locale::az_Arab_IQ => &_DURATION_PATTERNS_7,
+            Locale::az_Arab_TR => &_DURATION_PATTERNS_7,
+            Locale::az_Cyrl => &_DURATION_PATTERNS_0,
+            Locale::az_Latn => &_DURATION_PATTERNS_7,
+            Locale::ba => &_DURATION_PATTERNS_0,
+            Locale::bal => &_DURATION_PATTERNS_0,
+            Locale::bal_Arab => &_DURATION_PATTERNS_0,
+            Locale::bal_Latn => &_DURATION_PATTERNS_0,
+            Locale::bas => &_DURATION_PATTERNS_0,
+            Locale::be => &_DURATION_PATTERNS_8,
+            Locale::be_tarask => &_DURATION_PATTERNS_8,
+            Locale::bem => &_DURATION_PATTERNS_0,
+            Locale::bew => &_DURATION_PATTERNS_0,
+            Locale::bez => &_DURATION_PATTERNS_0,
+            Locale::bg => &_DURATION_PATTERNS_9,
+            Locale::bgc => &_DURATION_PATTERNS_0,
+            Locale::bgn => &_DURATION_PATTERNS_0,
+            Locale::bgn_AE => &_DURATION_PATTERNS_0,
+            Locale::bgn_AF => &_DURATION_PATTERNS_0,
+            Locale::bgn_IR => &_DURATION_PATTERNS_0,
+            Locale::bgn_OM => &_DURATION_PATTERNS_0,
+            Locale::bho => &_DURATION_PATTERNS_0,
+            Locale::blo => &_DURATION_PATTERNS_0,
+            Locale::blt => &_DURATION_PATTERNS_0,
+            Locale::bm => &_DURATION_PATTERNS_0,
+            Locale::bm_Nkoo => &_DURATION_PATTERNS_0,
+            Locale::bn => &_DURATION_PATTERNS_10,
+            Locale::bn_IN => &_DURATION_PATTERNS_10,
+            Locale::bo => &_DURATION_PATTERNS_0,
+            Locale::bo_IN => &_DURATION_PATTERNS_0,
+            Locale::bqi => &_DURATION_PATTERNS_0,
+            Locale::br => &_DURATION_PATTERNS_11,
+            Locale::brx => &_DURATION_PATTERNS_12,
+            Locale::bs => &_DURATION_PATTERNS_13,
+            Locale::bs_Cyrl => &_DURATION_PATTERNS_14,
+            Locale::bs_Latn => &_DURATION_PATTERNS_13,
+            Locale::bss => &_DURATION_PATTERNS_0,
+            Locale::bua => &_DURATION_PATTERNS_0,
+            Locale::byn => &_DURATION_PATTERNS_0,
+            Locale::ca => &_DURATION_PATTERNS_15,
+            Locale::ca_AD => &_DURATION_PATTERNS_15,
+            Locale::ca_ES_valencia => &_DURATION_PATTERNS_15,
+            Locale::ca_FR => &_DURATION_PATTERNS_15,
+            Locale::ca_IT => &_DURATION_PATTERNS_15,
+            Locale::cad => &_DURATION_PATTERNS_0,
+            Locale::cch => &_DURATION_PATTERNS_0,
+            Locale::ccp => &_DURATION_PATTERNS_16,
+            Locale::ccp_IN => &_DURATION_PATTERNS_16,
+            Locale::ce => &_DURATION_PATTERNS_17,
+            Locale::ceb => &_DURATION_PATTERNS_18,
+            Locale::cgg => &_DURATION_PATTERNS_0,
+            Locale::cho => &_DURATION_PATTERNS_0,
+            Locale::chr => &_DURATION_PATTERNS_19,
+            Locale::cic => &_DURATION_PATTERNS_0,
+            Locale::ckb => &_DURATION_PATTERNS_0,
+            Locale::ckb_IR => &_DURATION_PATTERNS_0,
+            Locale::co => &_DURATION_PATTERNS_0,
+            Locale::cop => &_DURATION_PATTERNS_0,
+            Locale::cs => &_DURATION_PATTERNS_20,
+            Locale::csw => &_DURATION_PATTERNS_0,
+            Locale::cu => &_DURATION_PATTERNS_0,
+            Locale::cv => &_DURATION_PATTERNS_21,
+            Locale::cy => &_DURATION_PATTERNS_22,
+            Locale::da => &_DURATION_PATTERNS_23,
+            Locale::da_GL => &_DURATION_PATTERNS_23,
+            Locale::dav => &_DURATION_PATTERNS_0,
+            Locale::de => &_DURATION_PATTERNS_24,
+            Locale::de_AT => &_DURATION_PATTERNS_24,
+            Locale::de_BE => &_DURATION_PATTERNS_24,
+            Locale::de_CH => &_DURATION_PATTERNS_24,
+            Locale::de_IT => &_DURATION_PATTERNS_24,
+            Locale::de_LI => &_DURATION_PATTERNS_24,
+            Locale::de_LU => &_DURATION_PATTERNS_24,
+            Locale::dje => &_DURATION_PATTERNS_0,
+            Locale::doi => &_DURATION_PATTERNS_0,
+            Locale::dsb => &_DURATION_PATTERNS_25,
+            Locale::dua => &_DURATION_PATTERNS_0,
+            Locale::dv => &_DURATION_PATTERNS_0,
+            Locale::dyo => &_DURATION_PATTERNS_0,
+            Locale::dz => &_DURATION_PATTERNS_26,
+            Locale::ebu => &_DURATION_PATTERNS_0,
+            Locale::ee => &_DURATION_PATTERNS_27,
+            Locale::ee_TG => &_DURATION_PATTERNS_27,
+            Locale::el => &_DURATION_PATTERNS_28,
+            Locale::el_CY => &_DURATION_PATTERNS_28,
+            Locale::el_polyton => &_DURATION_PATTERNS_28,
+            Locale::en => &_DURATION_PATTERNS_29,
+            Locale::en_001 => &_DURATION_PATTERNS_30,
+            Locale::en_150 => &_DURATION_PATTERNS_30,
+            Locale::en_AE => &_DURATION_PATTERNS_29,
+            Locale::en_AG => &_DURATION_PATTERNS_30,
+            Locale::en_AI => &_DURATION_PATTERNS_30,
+            Locale::en_AS => &_DURATION_PATTERNS_29,
+            Locale::en_AT => &_DURATION_PATTERNS_30,
+            Locale::en_AU => &_DURATION_PATTERNS_31,
+            Locale::en_BB => &_DURATION_PATTERNS_30,
+            Locale::en_BE => &_DURATION_PATTERNS_30,
+            Locale::en_BI => &_DURATION_PATTERNS_29,
+            Locale::en_BM => &_DURATION_PATTERNS_30,
+            Locale::en_BS => &_DURATION_PATTERNS_30,
+            Locale::en_BW => &_DURATION_PATTERNS_30,
+            Locale::en_BZ => &_DURATION_PATTERNS_30,
+            Locale::en_CA => &_DURATION_PATTERNS_32,
+            Locale::en_CC => &_DURATION_PATTERNS_30,
+            Locale::en_CH => &_DURATION_PATTERNS_30,
+            Locale::en_CK => &_DURATION_PATTERNS_30,
+            Locale::en_CM => &_DURATION_PATTERNS_30,
+            Locale::en_CX => &_DURATION_PATTERNS_30,
+            Locale::en_CY => &_DURATION_PATTERNS_30,
+            Locale::en_CZ => &_DURATION_PATTERNS_29,
+            Locale::en_DE => &_DURATION_PATTERNS_30,
+            Locale::en_DG => &_DURATION_PATTERNS_30,
+            Locale::en_DK => &_DURATION_PATTERNS_30,
+            Locale::en_DM => &_DURATION_PATTERNS_30,
+            Locale::en_Dsrt => &_DURATION_PATTERNS_29,
+            Locale::en_EE => &_DURATION_PATTERNS_29,
+            Locale::en_ER => &_DURATION_PATTERNS_30,
+            Locale::en_ES => &_DURATION_PATTERNS_29,
+            Locale::en_FI => &_DURATION_PATTERNS_30,
+            Locale::en_FJ => &_DURATION_PATTERNS_30,
+            Locale::en_FK => &_DURATION_PATTERNS_30,
+            Locale::en_FM => &_DURATION_PATTERNS_30,
+            Locale::en_FR => &_DURATION_PATTERNS_29,
+            Locale::en_GB => &_DURATION_PATTERNS_30,
+            Locale::en_GD => &_DURATION_PATTERNS_30,
+            Locale::en_GE => &_DURATION_PATTERNS_29,
+            Locale::en_GG => &_DURATION_PATTERNS_30,
+            Locale::en_GH => &_DURATION_PATTERNS_30,
+            Locale::en_GI => &_DURATION_PATTERNS_30,
+            Locale::en_GM => &_DURATION_PATTERNS_30,
+            Locale::en_GS => &_DURATION_PATTERNS_29,
+            Locale::en_GU => &_DURATION_PATTERNS_29,
+            Locale::en_GY => &_DURATION_PATTERNS_30,
+            Locale::en_HK => &_DURATION_PATTERNS_30,
+            Locale::en_HU => &_DURATION_PATTERNS_29,
+            Locale::en_ID => &_DURATION_PATTERNS_29,
+            Locale::en_IE => &_DURATION_PATTERNS_30,
+            Locale::en_IL => &_DURATION_PATTERNS_30,
+            Locale::en_IM => &_DURATION_PATTERNS_30,
+            Locale::en_IN => &_DURATION_PATTERNS_30,
+            Locale::en_IO => &_DURATION_PATTERNS_30,
+            Locale::en_IT => &_DURATION_PATTERNS_29,
+            Locale::en_JE => &_DURATION_PATTERNS_30,
+            Locale::en_JM => &_DURATION_PATTERNS_30,
+            Locale::en_JP => &_DURATION_PATTERNS_29,
+            Locale::en_KE => &_DURATION_PATTERNS_30,
+            Locale::en_KI => &_DURATION_PATTERNS_30,
+            Locale::en_KN => &_DURATION_PATTERNS_30,
+            Locale::en_KY => &_DURATION_PATTERNS_30,
+            Locale::en_LC => &_DURATION_PATTERNS_30,
+            Locale::en_LR => &_DURATION_PATTERNS_30,
+            Locale::en_LS => &_DURATION_PATTERNS_30,
+            Locale::en_LT => &_DURATION_PATTERNS_29,
+            Locale::en_LV => &_DURATION_PATTERNS_29,
+            Locale::en_MG => &_DURATION_PATTERNS_30,
+            Locale::en_MH => &_DURATION_PATTERNS_29,
+            Locale::en_MO => &_DURATION_PATTERNS_30,
+            Locale::en_MP => &_DURATION_PATTERNS_29,
+            Locale::en_MS => &_DURATION_PATTERNS_30,
+            Locale::en_MT => &_DURATION_PATTERNS_30,
+            Locale::en_MU => &_DURATION_PATTERNS_30,
+            Locale::en_MV => &_DURATION_PATTERNS_30,
+            Locale::en_MW => &_DURATION_PATTERNS_30,
+            Locale::en_MY => &_DURATION_PATTERNS_30,
+            Locale::en_NA => &_DURATION_PATTERNS_30,
+            Locale::en_NF => &_DURATION_PATTERNS_30,
+            Locale::en_NG => &_DURATION_PATTERNS_30,
+            Locale::en_NL => &_DURATION_PATTERNS_30,
+            Locale::en_NO => &_DURATION_PATTERNS_29,
+            Locale::en_NR => &_DURATION_PATTERNS_30,
+            Locale::en_NU => &_DURATION_PATTERNS_30,
+            Locale::en_NZ => &_DURATION_PATTERNS_30,
+            Locale::en_PG => &_DURATION_PATTERNS_30,
+            Locale::en_PH => &_DURATION_PATTERNS_29,
+            Locale::en_PK => &_DURATION_PATTERNS_30,
+            Locale::en_PL => &_DURATION_PATTERNS_29,
+            Locale::en_PN => &_DURATION_PATTERNS_30,
+            Locale::en_PR => &_DURATION_PATTERNS_29,
+            Locale::en_PT => &_DURATION_PATTERNS_29,
+            Locale::en_PW => &_DURATION_PATTERNS_30,
+            Locale::en_RO => &_DURATION_PATTERNS_29,
+            Locale::en_RW => &_DURATION_PATTERNS_30,
+            Locale::en_SB => &_DURATION_PATTERNS_30,
+            Locale::en_SC => &_DURATION_PATTERNS_30,
+            Locale::en_SD => &_DURATION_PATTERNS_30,
+            Locale::en_SE => &_DURATION_PATTERNS_30,
+            Locale::en_SG => &_DURATION_PATTERNS_30,
+            Locale::en_SH => &_DURATION_PATTERNS_30,
+            Locale::en_SI => &_DURATION_PATTERNS_30,
+            Locale::en_SK => &_DURATION_PATTERNS_29,
+            Locale::en_SL => &_DURATION_PATTERNS_30,
+            Locale::en_SS => &_DURATION_PATTERNS_30,
+            Locale::en_SX => &_DURATION_PATTERNS_30,
+            Locale::en_SZ => &_DURATION_PATTERNS_30,
+            Locale::en_Shaw => &_DURATION_PATTERNS_29,
+            Locale::en_TC => &_DURATION_PATTERNS_30,
+            Locale::en_TK => &_DURATION_PATTERNS_30,
+            Locale::en_TO => &_DURATION_PATTERNS_30,
+            Locale::en_TT => &_DURATION_PATTERNS_30,
+            Locale::en_TV => &_DURATION_PATTERNS_30,
+            Locale::en_TZ => &_DURATION_PATTERNS_30,
+            Locale::en_UA => &_DURATION_PATTERNS_29,
+            Locale::en_UG => &_DURATION_PATTERNS_30,
+            Locale::en_UM => &_DURATION_PATTERNS_29,
+            Locale::en_VC => &_DURATION_PATTERNS_30,
+            Locale::en_VG => &_DURATION_PATTERNS_30,
+            Locale::en_VI => &_DURATION_PATTERNS_29,
+            Locale::en_VU => &_DURATION_PATTERNS_30,
+            Locale::en_WS => &_DURATION_PATTERNS_30,
+            Locale::en_ZA => &_DURATION_PATTERNS_30,
+            Locale::en_ZM => &_DURATION_PATTERNS_30,
+            Locale::en_ZW => &_DURATION_PATTERNS_30,
+            Locale::eo => &_DURATION_PATTERNS_0,
+            Locale::es => &_DURATION_PATTERNS_33,
+            Locale::es_419 => &_DURATION_PATTERNS_34,
+            Locale::es_AR => &_DURATION_PATTERNS_35,
+            Locale::es_BO => &_DURATION_PATTERNS_34,
+            Locale::es_BR => &_DURATION_PATTERNS_34,
+            Locale::es_BZ => &_DURATION_PATTERNS_34,
+            Locale::es_CL => &_DURATION_PATTERNS_36,
+            Locale::es_CO => &_DURATION_PATTERNS_37,
+            Locale::es_CR => &_DURATION_PATTERNS_34,
+            Locale::es_CU => &_DURATION_PATTERNS_34,
+            Locale::es_DO => &_DURATION_PATTERNS_38,
+            Locale::es_EA => &_DURATION_PATTERNS_33,
+            Locale::es_EC => &_DURATION_PATTERNS_34,
+            Locale::es_GQ => &_DURATION_PATTERNS_33,
+            Locale::es_GT => &_DURATION_PATTERNS_34,
+            Locale::es_HN => &_DURATION_PATTERNS_34,
+            Locale::es_IC => &_DURATION_PATTERNS_33,
+            Locale::es_MX => &_DURATION_PATTERNS_39,
+            Locale::es_NI => &_DURATION_PATTERNS_34,
+            Locale::es_PA => &_DURATION_PATTERNS_34,
+            Locale::es_PE => &_DURATION_PATTERNS_34,
+            Locale::es_PH => &_DURATION_PATTERNS_33,
+            Locale::es_PR => &_DURATION_PATTERNS_34,
+            Locale::es_PY => &_DURATION_PATTERNS_40,
+            Locale::es_SV => &_DURATION_PATTERNS_34,
+            Locale::es_US => &_DURATION_PATTERNS_41,
+            Locale::es_UY => &_DURATION_PATTERNS_34,
+            Locale::es_VE => &_DURATION_PATTERNS_34,
+            Locale::et => &_DURATION_PATTERNS_42,
+            Locale::eu => &_DURATION_PATTERNS_43,
+            Locale::ewo => &_DURATION_PATTERNS_0,
+            Locale::fa => &_DURATION_PATTERNS_44,
+            Locale::fa_AF => &_DURATION_PATTERNS_44,
+            Locale::ff => &_DURATION_PATTERNS_0,
+            Locale::ff_Adlm => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_BF => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_CM => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_GH => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_GM => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_GW => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_LR => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_MR => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_NE => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_NG => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_SL => &_DURATION_PATTERNS_45,
+            Locale::ff_Adlm_SN => &_DURATION_PATTERNS_45,
+            Locale::ff_Latn => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_BF => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_CM => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_GH => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_GM => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_GN => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_GW => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_LR => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_MR => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_NE => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_NG => &_DURATION_PATTERNS_0,
+            Locale::ff_Latn_SL => &_DURATION_PATTERNS_0,
+            Locale::fi => &_DURATION_PATTERNS_46,
+            Locale::fil => &_DURATION_PATTERNS_47,
+            Locale::fo => &_DURATION_PATTERNS_48,
+            Locale::fo_DK => &_DURATION_PATTERNS_48,
+            Locale::fr => &_DURATION_PATTERNS_49,
+            Locale::fr_BE => &_DURATION_PATTERNS_49,
+            Locale::fr_BF => &_DURATION_PATTERNS_49,
+            Locale::fr_BI => &_DURATION_PATTERNS_49,
+            Locale::fr_BJ => &_DURATION_PATTERNS_49,
+            Locale::fr_BL => &_DURATION_PATTERNS_49,
+            Locale::fr_CA => &_DURATION_PATTERNS_50,
+            Locale::fr_CD => &_DURATION_PATTERNS_49,
+            Locale::fr_CF => &_DURATION_PATTERNS_49,
+            Locale::fr_CG => &_DURATION_PATTERNS_49,
+            Locale::fr_CH => &_DURATION_PATTERNS_49,
+            Locale::fr_CI => &_DURATION_PATTERNS_49,
+            Locale::fr_CM => &_DURATION_PATTERNS_49,
+            Locale::fr_DJ => &_DURATION_PATTERNS_49,
+            Locale::fr_DZ => &_DURATION_PATTERNS_49,
+            Locale::fr_GA => &_DURATION_PATTERNS_49,
+            Locale::fr_GF => &_DURATION_PATTERNS_49,
+            Locale::fr_GN => &_DURATION_PATTERNS_49,
+            Locale::fr_GP => &_DURATION_PATTERNS_49,
+            Locale::fr_GQ => &_DURATION_PATTERNS_49,
+            Locale::fr_HT => &_DURATION_PATTERNS_49,
+            Locale::fr_KM => &_DURATION_PATTERNS_49,
+            Locale::fr_LU => &_DURATION_PATTERNS_49,
+            Locale::fr_MA => &_DURATION_PATTERNS_49,
+            Locale::fr_MC => &_DURATION_PATTERNS_49,
+            Locale::fr_MF => &_DURATION_PATTERNS_49,
+            Locale::fr_MG => &_DURATION_PATTERNS_49,
+            Locale::fr_ML => &_DURATION_PATTERNS_49,
+            Locale::fr_MQ => &_DURATION_PATTERNS_49,
+            Locale::fr_MR => &_DURATION_PATTERNS_49,
+            Locale::fr_MU => &_DURATION_PATTERNS_49,
+            Locale::fr_NC => &_DURATION_PATTERNS_49,
+            Locale::fr_NE => &_DURATION_PATTERNS_49,
+            Locale::fr_PF => &_DURATION_PATTERNS_49,
+            Locale::fr_PM => &_DURATION_PATTERNS_49,
+            Locale::fr_RE => &_DURATION_PATTERNS_49,
+            Locale::fr_RW => &_DURATION_PATTERNS_49,
+            Locale::fr_SC => &_DURATION_PATTERNS_49,
+            Locale::fr_SN => &_DURATION_PATTERNS_49,
+            Locale::fr_SY => &_DURATION_PATTERNS_49,
+            Locale::fr_TD => &_DURATION_PATTERNS_49,
+            Locale::fr_TG => &_DURATION_PATTERNS_49,
+            Locale::fr_TN => &_DURATION_PATTERNS_49,
+            Locale::fr_VU => &_DURATION_PATTERNS_49,
+            Locale::fr_WF => &_DURATION_PATTERNS_49,
+            Locale::fr_YT => &_DURATION_PATTERNS_49,
+            Locale::frr => &_DURATION_PATTERNS_0,
+            Locale::fur => &_DURATION_PATTERNS_51,
+            Locale::fy => &_DURATION_PATTERNS_52,
+            Locale::ga => &_DURATION_PATTERNS_53,
+            Locale::ga_GB => &_DURATION_PATTERNS_53,
+            Locale::gaa => &_DURATION_PATTERNS_0,
+            Locale::gd => &_DURATION_PATTERNS_54,
+            Locale::gez => &_DURATION_PATTERNS_0,
+            Locale::gez_ER => &_DURATION_PATTERNS_0,
+            Locale::gl => &_DURATION_PATTERNS_55,
+            Locale::gn => &_DURATION_PATTERNS_0,
+            Locale::gsw => &_DURATION_PATTERNS_56,
+            Locale::gsw_FR => &_DURATION_PATTERNS_56,
+            Locale::gsw_LI => &_DURATION_PATTERNS_56,
+            Locale::gu => &_DURATION_PATTERNS_57,
+            Locale::guz => &_DURATION_PATTERNS_0,
+            Locale::gv => &_DURATION_PATTERNS_0,
+            Locale::ha => &_DURATION_PATTERNS_58,
+            Locale::ha_Arab => &_DURATION_PATTERNS_58,
+            Locale::ha_Arab_SD => &_DURATION_PATTERNS_58,
+            Locale::ha_GH => &_DURATION_PATTERNS_58,
+            Locale::ha_NE => &_DURATION_PATTERNS_58,
+            Locale::haw => &_DURATION_PATTERNS_59,
+            Locale::he => &_DURATION_PATTERNS_60,
+            Locale::hi => &_DURATION_PATTERNS_61,
+            Locale::hi_Latn => &_DURATION_PATTERNS_62,
+            Locale::hnj => &_DURATION_PATTERNS_0,
+            Locale::hnj_Hmnp => &_DURATION_PATTERNS_0,
+            Locale::hr => &_DURATION_PATTERNS_63,
+            Locale::hr_BA => &_DURATION_PATTERNS_63,
+            Locale::hsb => &_DURATION_PATTERNS_64,
+            Locale::ht => &_DURATION_PATTERNS_0,
+            Locale::hu => &_DURATION_PATTERNS_65,
+            Locale::hy => &_DURATION_PATTERNS_66,
+            Locale::ia => &_DURATION_PATTERNS_67,
+            Locale::id => &_DURATION_PATTERNS_68,
+            Locale::ie => &_DURATION_PATTERNS_0,
+            Locale::ig => &_DURATION_PATTERNS_69,
+            Locale::ii => &_DURATION_PATTERNS_0,
+            Locale::io => &_DURATION_PATTERNS_0,
+            Locale::is => &_DURATION_PATTERNS_70,
+            Locale::it => &_DURATION_PATTERNS_71,
+            Locale::it_CH => &_DURATION_PATTERNS_71,
+            Locale::it_SM => &_DURATION_PATTERNS_71,
+            Locale::it_VA => &_DURATION_PATTERNS_71,
+            Locale::iu => &_DURATION_PATTERNS_0,
+            Locale::iu_Latn => &_DURATION_PATTERNS_0,
+            Locale::ja => &_DURATION_PATTERNS_72,
+            Locale::jbo => &_DURATION_PATTERNS_0,
+            Locale::jgo => &_DURATION_PATTERNS_73,
+            Locale::jmc => &_DURATION_PATTERNS_0,
+            Locale::jv => &_DURATION_PATTERNS_74,
+            Locale::ka => &_DURATION_PATTERNS_75,
+            Locale::kaa => &_DURATION_PATTERNS_0,
+            Locale::kaa_Cyrl => &_DURATION_PATTERNS_0,
+            Locale::kaa_Latn => &_DURATION_PATTERNS_0,
+            Locale::kab => &_DURATION_PATTERNS_0,
+            Locale::kaj => &_DURATION_PATTERNS_0,
+            Locale::kam => &_DURATION_PATTERNS_0,
+            Locale::kcg => &_DURATION_PATTERNS_0,
+            Locale::kde => &_DURATION_PATTERNS_0,
+            Locale::kea => &_DURATION_PATTERNS_76,
+            Locale::kek => &_DURATION_PATTERNS_0,
+            Locale::ken => &_DURATION_PATTERNS_0,
+            Locale::kgp => &_DURATION_PATTERNS_77,
+            Locale::khq => &_DURATION_PATTERNS_0,
+            Locale::ki => &_DURATION_PATTERNS_0,
+            Locale::kk => &_DURATION_PATTERNS_78,
+            Locale::kk_Arab => &_DURATION_PATTERNS_78,
+            Locale::kk_Cyrl => &_DURATION_PATTERNS_78,
+            Locale::kk_KZ => &_DURATION_PATTERNS_78,
+            Locale::kkj => &_DURATION_PATTERNS_0,
+            Locale::kl => &_DURATION_PATTERNS_0,
+            Locale::kln => &_DURATION_PATTERNS_0,
+            Locale::km => &_DURATION_PATTERNS_79,
+            Locale::kn => &_DURATION_PATTERNS_80,
+            Locale::ko => &_DURATION_PATTERNS_81,
+            Locale::ko_CN => &_DURATION_PATTERNS_81,
+            Locale::ko_KP => &_DURATION_PATTERNS_81,
+            Locale::kok => &_DURATION_PATTERNS_82,
+            Locale::kok_Deva => &_DURATION_PATTERNS_82,
+            Locale::kok_Latn => &_DURATION_PATTERNS_82,
+            Locale::kpe => &_DURATION_PATTERNS_0,
+            Locale::kpe_GN => &_DURATION_PATTERNS_0,
+            Locale::ks => &_DURATION_PATTERNS_83,
+            Locale::ks_Arab => &_DURATION_PATTERNS_83,
+            Locale::ks_Deva => &_DURATION_PATTERNS_0,
+            Locale::ksb => &_DURATION_PATTERNS_0,
+            Locale::ksf => &_DURATION_PATTERNS_0,
+            Locale::ksh => &_DURATION_PATTERNS_84,
+            Locale::ku => &_DURATION_PATTERNS_85,
+            Locale::ku_Arab => &_DURATION_PATTERNS_85,
+            Locale::ku_Arab_IR => &_DURATION_PATTERNS_85,
+            Locale::ku_Latn => &_DURATION_PATTERNS_85,
+            Locale::ku_Latn_IQ => &_DURATION_PATTERNS_85,
+            Locale::ku_Latn_SY => &_DURATION_PATTERNS_85,
+            Locale::ku_TR => &_DURATION_PATTERNS_85,
+            Locale::kw => &_DURATION_PATTERNS_0,
+            Locale::kxv => &_DURATION_PATTERNS_0,
+            Locale::kxv_Deva => &_DURATION_PATTERNS_0,
+            Locale::kxv_Latn => &_DURATION_PATTERNS_0,
+            Locale::kxv_Orya => &_DURATION_PATTERNS_0,
+            Locale::kxv_Telu => &_DURATION_PATTERNS_0,
+            Locale::ky => &_DURATION_PATTERNS_86,
+            Locale::la => &_DURATION_PATTERNS_0,
+            Locale::lag => &_DURATION_PATTERNS_0,
+            Locale::lb => &_DURATION_PATTERNS_87,
+            Locale::lg => &_DURATION_PATTERNS_0,
+            Locale::lij => &_DURATION_PATTERNS_0,
+            Locale::lkt => &_DURATION_PATTERNS_88,
+            Locale::lld => &_DURATION_PATTERNS_0,
+            Locale::lmo => &_DURATION_PATTERNS_0,
+            Locale::ln => &_DURATION_PATTERNS_0,
+            Locale::ln_AO => &_DURATION_PATTERNS_0,
+            Locale::ln_CF => &_DURATION_PATTERNS_0,
+            Locale::ln_CG => &_DURATION_PATTERNS_0,
+            Locale::lo => &_DURATION_PATTERNS_89,
+            Locale::lrc => &_DURATION_PATTERNS_0,
+            Locale::lrc_IQ => &_DURATION_PATTERNS_0,
+            Locale::lt => &_DURATION_PATTERNS_90,
+            Locale::ltg => &_DURATION_PATTERNS_0,
+            Locale::lu => &_DURATION_PATTERNS_0,
+            Locale::luo => &_DURATION_PATTERNS_0,
+            Locale::luy => &_DURATION_PATTERNS_0,
+            Locale::lv => &_DURATION_PATTERNS_91,
+            Locale::lzz => &_DURATION_PATTERNS_0,
+            Locale::mai => &_DURATION_PATTERNS_0,
+            Locale::mas => &_DURATION_PATTERNS_0,
+            Locale::mas_TZ => &_DURATION_PATTERNS_0,
+            Locale::mdf => &_DURATION_PATTERNS_0,
+            Locale::mer => &_DURATION_PATTERNS_0,
+            Locale::mfe => &_DURATION_PATTERNS_0,
+            Locale::mg => &_DURATION_PATTERNS_0,
+            Locale::mgh => &_DURATION_PATTERNS_0,
+            Locale::mgo => &_DURATION_PATTERNS_92,
+            Locale::mhn => &_DURATION_PATTERNS_0,
+            Locale::mi => &_DURATION_PATTERNS_0,
+            Locale::mic => &_DURATION_PATTERNS_0,
+            Locale::mk => &_DURATION_PATTERNS_93,
+            Locale::ml => &_DURATION_PATTERNS_94,
+            Locale::mn => &_DURATION_PATTERNS_95,
+            Locale::mn_Mong => &_DURATION_PATTERNS_95,
+            Locale::mn_Mong_MN => &_DURATION_PATTERNS_95,
+            Locale::mni => &_DURATION_PATTERNS_0,
+            Locale::mni_Beng => &_DURATION_PATTERNS_0,
+            Locale::mni_Mtei => &_DURATION_PATTERNS_0,
+            Locale::moh => &_DURATION_PATTERNS_0,
+            Locale::mr => &_DURATION_PATTERNS_96,
+            Locale::ms => &_DURATION_PATTERNS_97,
+            Locale::ms_Arab => &_DURATION_PATTERNS_97,
+            Locale::ms_Arab_BN => &_DURATION_PATTERNS_97,
+            Locale::ms_BN => &_DURATION_PATTERNS_97,
+            Locale::ms_ID => &_DURATION_PATTERNS_97,
+            Locale::ms_SG => &_DURATION_PATTERNS_97,
+            Locale::mt => &_DURATION_PATTERNS_98,
+            Locale::mua => &_DURATION_PATTERNS_0,
+            Locale::mus => &_DURATION_PATTERNS_0,
+            Locale::mww => &_DURATION_PATTERNS_0,
+            Locale::mww_Hmnp => &_DURATION_PATTERNS_0,
+            Locale::my => &_DURATION_PATTERNS_99,
+            Locale::myv => &_DURATION_PATTERNS_0,
+            Locale::mzn => &_DURATION_PATTERNS_100,
+            Locale::naq => &_DURATION_PATTERNS_0,
+            Locale::nb => &_DURATION_PATTERNS_101,
+            Locale::nb_SJ => &_DURATION_PATTERNS_101,
+            Locale::nd => &_DURATION_PATTERNS_0,
+            Locale::nds => &_DURATION_PATTERNS_0,
+            Locale::nds_NL => &_DURATION_PATTERNS_0,
+            Locale::ne => &_DURATION_PATTERNS_102,
+            Locale::ne_IN => &_DURATION_PATTERNS_102,
+            Locale::nl => &_DURATION_PATTERNS_103,
+            Locale::nl_AW => &_DURATION_PATTERNS_103,
+            Locale::nl_BE => &_DURATION_PATTERNS_103,
+            Locale::nl_BQ => &_DURATION_PATTERNS_103,
+            Locale::nl_CW => &_DURATION_PATTERNS_103,
+            Locale::nl_SR => &_DURATION_PATTERNS_103,
+            Locale::nl_SX => &_DURATION_PATTERNS_103,
+            Locale::nmg => &_DURATION_PATTERNS_0,
+            Locale::nn => &_DURATION_PATTERNS_104,
+            Locale::nnh => &_DURATION_PATTERNS_0,
+            Locale::no => &_DURATION_PATTERNS_101,
+            Locale::nqo => &_DURATION_PATTERNS_0,
+            Locale::nr => &_DURATION_PATTERNS_0,
+            Locale::nso => &_DURATION_PATTERNS_0,
+            Locale::nus => &_DURATION_PATTERNS_0,
+            Locale::nv => &_DURATION_PATTERNS_0,
+            Locale::ny => &_DURATION_PATTERNS_0,
+            Locale::nyn => &_DURATION_PATTERNS_0,
+            Locale::oc => &_DURATION_PATTERNS_0,
+            Locale::oc_ES => &_DURATION_PATTERNS_0,
+            Locale::oka => &_DURATION_PATTERNS_0,
+            Locale::oka_US => &_DURATION_PATTERNS_0,
+            Locale::om => &_DURATION_PATTERNS_0,
+            Locale::om_KE => &_DURATION_PATTERNS_0,
+            Locale::or => &_DURATION_PATTERNS_105,
+            Locale::os => &_DURATION_PATTERNS_106,
+            Locale::os_RU => &_DURATION_PATTERNS_106,
+            Locale::osa => &_DURATION_PATTERNS_0,
+            Locale::pa => &_DURATION_PATTERNS_107,
+            Locale::pa_Arab => &_DURATION_PATTERNS_0,
+            Locale::pa_Guru => &_DURATION_PATTERNS_107,
+            Locale::pap => &_DURATION_PATTERNS_0,
+            Locale::pap_AW => &_DURATION_PATTERNS_0,
+            Locale::pcm => &_DURATION_PATTERNS_108,
+            Locale::pi => &_DURATION_PATTERNS_0,
+            Locale::pi_Latn => &_DURATION_PATTERNS_0,
+            Locale::pis => &_DURATION_PATTERNS_0,
+            Locale::pl => &_DURATION_PATTERNS_109,
+            Locale::pms => &_DURATION_PATTERNS_0,
+            Locale::prg => &_DURATION_PATTERNS_0,
+            Locale::ps => &_DURATION_PATTERNS_110,
+            Locale::ps_PK => &_DURATION_PATTERNS_111,
+            Locale::pt => &_DURATION_PATTERNS_112,
+            Locale::pt_AO => &_DURATION_PATTERNS_113,
+            Locale::pt_CH => &_DURATION_PATTERNS_113,
+            Locale::pt_CV => &_DURATION_PATTERNS_113,
+            Locale::pt_GQ => &_DURATION_PATTERNS_113,
+            Locale::pt_GW => &_DURATION_PATTERNS_113,
+            Locale::pt_LU => &_DURATION_PATTERNS_113,
+            Locale::pt_MO => &_DURATION_PATTERNS_113,
+            Locale::pt_MZ => &_DURATION_PATTERNS_113,
+            Locale::pt_PT => &_DURATION_PATTERNS_113,
+            Locale::pt_ST => &_DURATION_PATTERNS_113,
+            Locale::pt_TL => &_DURATION_PATTERNS_113,
+            Locale::qu => &_DURATION_PATTERNS_114,
+            Locale::qu_BO => &_DURATION_PATTERNS_114,
+            Locale::qu_EC => &_DURATION_PATTERNS_114,
+            Locale::quc => &_DURATION_PATTERNS_0,
+            Locale::raj => &_DURATION_PATTERNS_0,
+            Locale::rhg => &_DURATION_PATTERNS_0,
+            Locale::rhg_Rohg => &_DURATION_PATTERNS_0,
+            Locale::rhg_Rohg_BD => &_DURATION_PATTERNS_0,
+            Locale::rif => &_DURATION_PATTERNS_0,
+            Locale::rm => &_DURATION_PATTERNS_115,
+            Locale::rn => &_DURATION_PATTERNS_0,
+            Locale::ro => &_DURATION_PATTERNS_116,
+            Locale::ro_MD => &_DURATION_PATTERNS_117,
+            Locale::rof => &_DURATION_PATTERNS_0,
+            Locale::ru => &_DURATION_PATTERNS_118,
+            Locale::ru_BY => &_DURATION_PATTERNS_118,
+            Locale::ru_KG => &_DURATION_PATTERNS_118,
+            Locale::ru_KZ => &_DURATION_PATTERNS_118,
+            Locale::ru_MD => &_DURATION_PATTERNS_118,
+            Locale::ru_UA => &_DURATION_PATTERNS_118,
+            Locale::rw => &_DURATION_PATTERNS_0,
+            Locale::rwk => &_DURATION_PATTERNS_0,
+            Locale::sa => &_DURATION_PATTERNS_0,
+            Locale::sah => &_DURATION_PATTERNS_119,
+            Locale::saq => &_DURATION_PATTERNS_0,
+            Locale::sat => &_DURATION_PATTERNS_0,
+            Locale::sat_Deva => &_DURATION_PATTERNS_0,
+            Locale::sat_Olck => &_DURATION_PATTERNS_0,
+            Locale::sbp => &_DURATION_PATTERNS_0,
+            Locale::sc => &_DURATION_PATTERNS_120,
+            Locale::scn => &_DURATION_PATTERNS_0,
+            Locale::sd => &_DURATION_PATTERNS_121,
+            Locale::sd_Arab => &_DURATION_PATTERNS_121,
+            Locale::sd_Deva => &_DURATION_PATTERNS_0,
+            Locale::sdh => &_DURATION_PATTERNS_0,
+            Locale::sdh_IQ => &_DURATION_PATTERNS_0,
+            Locale::se => &_DURATION_PATTERNS_122,
+            Locale::se_FI => &_DURATION_PATTERNS_122,
+            Locale::se_SE => &_DURATION_PATTERNS_122,
+            Locale::seh => &_DURATION_PATTERNS_0,
+            Locale::ses => &_DURATION_PATTERNS_0,
+            Locale::sg => &_DURATION_PATTERNS_0,
+            Locale::sgs => &_DURATION_PATTERNS_0,
+            Locale::shi => &_DURATION_PATTERNS_0,
+            Locale::shi_Latn => &_DURATION_PATTERNS_0,
+            Locale::shi_Tfng => &_DURATION_PATTERNS_0,
+            Locale::shn => &_DURATION_PATTERNS_0,
+            Locale::shn_TH => &_DURATION_PATTERNS_0,
+            Locale::si => &_DURATION_PATTERNS_123,
+            Locale::sid => &_DURATION_PATTERNS_0,
+            Locale::sk => &_DURATION_PATTERNS_124,
+            Locale::skr => &_DURATION_PATTERNS_0,
+            Locale::sl => &_DURATION_PATTERNS_125,
+            Locale::sma => &_DURATION_PATTERNS_0,
+            Locale::sma_NO => &_DURATION_PATTERNS_0,
+            Locale::smj => &_DURATION_PATTERNS_0,
+            Locale::smj_NO => &_DURATION_PATTERNS_0,
+            Locale::smn => &_DURATION_PATTERNS_0,
+            Locale::sms => &_DURATION_PATTERNS_0,
+            Locale::sn => &_DURATION_PATTERNS_0,
+            Locale::so => &_DURATION_PATTERNS_126,
+            Locale::so_DJ => &_DURATION_PATTERNS_126,
+            Locale::so_ET => &_DURATION_PATTERNS_126,
+            Locale::so_KE => &_DURATION_PATTERNS_126,
+            Locale::sq => &_DURATION_PATTERNS_127,
+            Locale::sq_MK => &_DURATION_PATTERNS_127,
+            Locale::sq_XK => &_DURATION_PATTERNS_127,
+            Locale::sr => &_DURATION_PATTERNS_128,
+            Locale::sr_Cyrl => &_DURATION_PATTERNS_128,
+            Locale::sr_Cyrl_BA => &_DURATION_PATTERNS_129,
+            Locale::sr_Cyrl_ME => &_DURATION_PATTERNS_128,
+            Locale::sr_Cyrl_XK => &_DURATION_PATTERNS_128,
+            Locale::sr_Latn => &_DURATION_PATTERNS_130,
+            Locale::sr_Latn_BA => &_DURATION_PATTERNS_131,
+            Locale::sr_Latn_ME => &_DURATION_PATTERNS_130,
+            Locale::sr_Latn_XK => &_DURATION_PATTERNS_130,
+            Locale::ss => &_DURATION_PATTERNS_0,
+            Locale::ss_SZ => &_DURATION_PATTERNS_0,
+            Locale::ssy => &_DURATION_PATTERNS_0,
+            Locale::st => &_DURATION_PATTERNS_0,
+            Locale::st_LS => &_DURATION_PATTERNS_0,
+            Locale::su => &_DURATION_PATTERNS_132,
+            Locale::su_Latn => &_DURATION_PATTERNS_132,
+            Locale::suz => &_DURATION_PATTERNS_0,
+            Locale::suz_Deva => &_DURATION_PATTERNS_0,
+            Locale::suz_Sunu => &_DURATION_PATTERNS_0,
+            Locale::sv => &_DURATION_PATTERNS_133,
+            Locale::sv_AX => &_DURATION_PATTERNS_133,
+            Locale::sv_FI => &_DURATION_PATTERNS_133,
+            Locale::sw => &_DURATION_PATTERNS_134,
+            Locale::sw_CD => &_DURATION_PATTERNS_134,
+            Locale::sw_KE => &_DURATION_PATTERNS_134,
+            Locale::sw_UG => &_DURATION_PATTERNS_134,
+            Locale::syr => &_DURATION_PATTERNS_0,
+            Locale::syr_SY => &_DURATION_PATTERNS_0,
+            Locale::szl => &_DURATION_PATTERNS_0,
+            Locale::ta => &_DURATION_PATTERNS_135,
+            Locale::ta_LK => &_DURATION_PATTERNS_135,
+            Locale::ta_MY => &_DURATION_PATTERNS_135,
+            Locale::ta_SG => &_DURATION_PATTERNS_135,
+            Locale::te => &_DURATION_PATTERNS_136,
+            Locale::teo => &_DURATION_PATTERNS_0,
+            Locale::teo_KE => &_DURATION_PATTERNS_0,
+            Locale::tg => &_DURATION_PATTERNS_0,
+            Locale::th => &_DURATION_PATTERNS_137,
+            Locale::ti => &_DURATION_PATTERNS_138,
+            Locale::ti_ER => &_DURATION_PATTERNS_138,
+            Locale::tig => &_DURATION_PATTERNS_0,
+            Locale::tk => &_DURATION_PATTERNS_139,
+            Locale::tn => &_DURATION_PATTERNS_0,
+            Locale::tn_BW => &_DURATION_PATTERNS_0,
+            Locale::to => &_DURATION_PATTERNS_140,
+            Locale::tok => &_DURATION_PATTERNS_0,
+            Locale::tpi => &_DURATION_PATTERNS_0,
+            Locale::tr => &_DURATION_PATTERNS_141,
+            Locale::tr_CY => &_DURATION_PATTERNS_141,
+            Locale::trv => &_DURATION_PATTERNS_0,
+            Locale::trw => &_DURATION_PATTERNS_0,
+            Locale::ts => &_DURATION_PATTERNS_0,
+            Locale::tt => &_DURATION_PATTERNS_0,
+            Locale::twq => &_DURATION_PATTERNS_0,
+            Locale::tyv => &_DURATION_PATTERNS_0,
+            Locale::tzm => &_DURATION_PATTERNS_0,
+            Locale::ug => &_DURATION_PATTERNS_142,
+            Locale::uk => &_DURATION_PATTERNS_143,
+            Locale::und => &_DURATION_PATTERNS_0,
+            Locale::ur => &_DURATION_PATTERNS_144,
+            Locale::ur_IN => &_DURATION_PATTERNS_144,
+            Locale::uz => &_DURATION_PATTERNS_145,
+            Locale::uz_Arab => &_DURATION_PATTERNS_0,
+            Locale::uz_Cyrl => &_DURATION_PATTERNS_146,
+            Locale::uz_Latn => &_DURATION_PATTERNS_145,
+            Locale::vai => &_DURATION_PATTERNS_0,
+            Locale::vai_Latn => &_DURATION_PATTERNS_0,
+            Locale::vai_Vaii => &_DURATION_PATTERNS_0,
+            Locale::ve => &_DURATION_PATTERNS_0,
+            Locale::vec => &_DURATION_PATTERNS_0,
+            Locale::vi => &_DURATION_PATTERNS_147,
+            Locale::vmw => &_DURATION_PATTERNS_0,
+            Locale::vo => &_DURATION_PATTERNS_0,
+            Locale::vun => &_DURATION_PATTERNS_0,
+            Locale::wa => &_DURATION_PATTERNS_0,
+            Locale::wae => &_DURATION_PATTERNS_148,
+            Locale::wal => &_DURATION_PATTERNS_0,
+            Locale::wbp => &_DURATION_PATTERNS_0,
+            Locale::wo => &_DURATION_PATTERNS_0,
+            Locale::xh => &_DURATION_PATTERNS_0,
+            Locale::xnr => &_DURATION_PATTERNS_0,
+            Locale::xog => &_DURATION_PATTERNS_0,
+            Locale::yav => &_DURATION_PATTERNS_0,
+            Locale::yi => &_DURATION_PATTERNS_0,
+            Locale::yo => &_DURATION_PATTERNS_149,
+            Locale::yo_BJ => &_DURATION_PATTERNS_150,
+            Locale::yrl => &_DURATION_PATTERNS_151,
+            Locale::yrl_CO => &_DURATION_PATTERNS_151,
+            Locale::yrl_VE => &_DURATION_PATTERNS_151,
+            Locale::yue => &_DURATION_PATTERNS_152,
+            Locale::yue_Hans => &_DURATION_PATTERNS_153,
+            Locale::yue_Hant => &_DURATION_PATTERNS_152,
+            Locale::yue_Hant_CN => &_DURATION_PATTERNS_152,
+            Locale::yue_Hant_MO => &_DURATION_PATTERNS_152,
+            Locale::za => &_DURATION_PATTERNS_0,
+            Locale::zgh => &_DURATION_PATTERNS_0,
+            Locale::zh => &_DURATION_PATTERNS_154,
+            Locale::zh_Hans => &_DURATION_PATTERNS_154,
+            Locale::zh_Hans_HK => &_DURATION_PATTERNS_155,
+            Locale::zh_Hans_MO => &_DURATION_PATTERNS_155,
+            Locale::zh_Hans_MY => &_DURATION_PATTERNS_154,
+            Locale::zh_Hans_SG => &_DURATION_PATTERNS_155,
+            Locale::zh_Hant => &_DURATION_PATTERNS_152,
+            Locale::zh_Hant_HK => &_DURATION_PATTERNS_156,
+            Locale::zh_Hant_MO => &_DURATION_PATTERNS_156,
+            Locale::zh_Hant_MY => &_DURATION_PATTERNS_152,
+            Locale::zh_Latn => &_DURATION_PATTERNS_154,
+            Locale::zu => &_DURATION_PATTERNS_157,
+        }
+    }
+}
+
+/// Joins items with a CLDR list pattern: for two items, then the start,
+/// middle and end of longer lists.
+fn _join_list(patterns: &[&str; 4], items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [first, second] => _fill_list_pattern(patterns[0], first, second),
+        [first, middle @ .., second_last, last] => {
+            let mut result = _fill_list_pattern(patterns[3], second_last, last);
+            for item in middle.iter().rev() {
+                result = _fill_list_pattern(patterns[2], item, &result);
+            }
+            _fill_list_pattern(patterns[1], first, &result)
+        }
+    }
+}
+
+/// Replaces `{0}` and `{1}` in one pass, so items may contain either.
+fn _fill_list_pattern(pattern: &str, first: &str, second: &str) -> String {
+    let mut result = String::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(tail) = rest.strip_prefix("{0}") {
+            result.push_str(first);
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("{1}") {
+            result.push_str(second);
+            rest = tail;
+        } else {
+            result.push('{');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_0: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_1: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weke"], ["", "{0} dag", "", "", "", "{0} dae"], ["", "{0} uur", "", "", "", "{0} uur"], ["", "{0} minuut", "", "", "", "{0} minute"], ["", "{0} sekonde", "", "", "", "{0} sekondes"]], [["", "{0} w.", "", "", "", "{0} w."], ["", "{0} dag", "", "", "", "{0} dae"], ["", "{0} u.", "", "", "", "{0} u."], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} s.", "", "", "", "{0} s."]], [["", "{0} w.", "", "", "", "{0} w."], ["", "{0} d.", "", "", "", "{0} d."], ["", "{0} u.", "", "", "", "{0} u."], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} s.", "", "", "", "{0} s."]]], lists: [["{0} en {1}", "{0}, {1}", "{0}, {1}", "{0} en {1}"], ["{0} en {1}", "{0}, {1}", "{0}, {1}", "{0} en {1}"], ["{0} en {1}", "{0}, {1}", "{0}, {1}", "{0} en {1}"]], digital: ["hh:mm:ss", "mm:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_2: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ሳምንት", "", "", "", "{0} ሳምንታት"], ["", "{0} ቀናት", "", "", "", "{0} ቀናት"], ["", "{0} ሰዓት", "", "", "", "{0} ሰዓቶች"], ["", "{0} ደቂቃ", "", "", "", "{0} ደቂቃዎች"], ["", "{0} ሰከንድ", "", "", "", "{0} ሰከንዶች"]], [["", "{0} ሳምንት", "", "", "", "{0} ሳምንታት"], ["", "{0} ቀናት", "", "", "", "{0} ቀናት"], ["", "{0} ሰዓ", "", "", "", "{0} ሰዓ"], ["", "{0} ደቂ", "", "", "", "{0} ደቂቃ"], ["", "{0} ሰከ", "", "", "", "{0} ሰከ"]], [["", "{0} ሳምንት", "", "", "", "{0} ሳምንት"], ["", "{0} ቀ", "", "", "", "{0} ቀ"], ["", "{0} ሰ", "", "", "", "{0} ሰ"], ["", "{0} ደ", "", "", "", "{0} ደ"], ["", "{0} ሰ", "", "", "", "{0} ሰ"]]], lists: [["{0}፣ {1}", "{0}፣ {1}", "{0}፣ {1}", "{0}፣ {1}"], ["{0}፣ {1}", "{0}፣ {1}", "{0}፣ {1}", "{0}፣ {1}"], ["{0} {1}", "{0}፣ {1}", "{0}፣ {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_3: _DurationPatterns = _DurationPatterns { units: [[["{0} أسبوع", "أسبوع", "أسبوعان", "{0} أسابيع", "{0} أسبوع\u{64b}ا", "{0} أسبوع"], ["{0} يوم", "يوم", "يومان", "{0} أيام", "{0} يوم\u{64b}ا", "{0} يوم"], ["{0} ساعة", "ساعة", "ساعتان", "{0} ساعات", "{0} ساعة", "{0} ساعة"], ["{0} دقيقة", "دقيقة", "دقيقتان", "{0} دقائق", "{0} دقيقة", "{0} دقيقة"], ["{0} ثانية", "ثانية", "ثانيتان", "{0} ثوان", "{0} ثانية", "{0} ثانية"]], [["{0} أسبوع", "أسبوع", "أسبوعان", "{0} أسابيع", "{0} أسبوع\u{64b}ا", "{0} أسبوع"], ["{0} يوم", "يوم", "يومان", "{0} أيام", "{0} يوم\u{64b}ا", "{0} يوم"], ["{0} س", "{0} س", "{0} س", "{0} س", "{0} س", "{0} س"], ["{0} د", "{0} د", "{0} د", "{0} د", "{0} د", "{0} د"], ["{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث"]], [["{0} أ", "{0} أ", "{0} أ", "{0} أ", "{0} أ", "{0} أ"], ["{0} ي", "{0} ي", "{0} ي", "{0} ي", "{0} ي", "{0} ي"], ["{0} س", "{0} س", "{0} س", "{0} س", "{0} س", "{0} س"], ["{0} د", "{0} د", "{0} د", "{0} د", "{0} د", "{0} د"], ["{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث"]]], lists: [["{0} و{1}", "{0}، و{1}", "{0}، و{1}", "{0}، و{1}"], ["{0} و{1}", "{0}، و{1}", "{0}، و{1}", "{0}، و{1}"], ["{0} و{1}", "{0} و{1}", "{0} و{1}", "{0} و{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_4: _DurationPatterns = _DurationPatterns { units: [[["{0} أسبوع", "أسبوع", "أسبوعان", "{0} أسابيع", "{0} أسبوع\u{64b}ا", "{0} أسبوع"], ["{0} يوم", "يوم", "يومان", "{0} أيام", "{0} يوم\u{64b}ا", "{0} يوم"], ["{0} ساعة", "ساعة", "ساعتان", "{0} ساعات", "{0} ساعة", "{0} ساعة"], ["{0} دقيقة", "دقيقة", "دقيقتان", "{0} دقائق", "{0} دقيقة", "{0} دقيقة"], ["{0} ثانية", "ثانية", "ثانيتان", "{0} ثوان\u{64d}", "{0} ثانية", "{0} ثانية"]], [["{0} أسبوع", "أسبوع", "أسبوعان", "{0} أسابيع", "{0} أسبوع\u{64b}ا", "{0} أسبوع"], ["{0} يوم", "يوم", "يومان", "{0} أيام", "{0} يوم\u{64b}ا", "{0} يوم"], ["{0} س", "{0} س", "{0} س", "{0} س", "{0} س", "{0} س"], ["{0} د", "{0} د", "{0} د", "{0} د", "{0} د", "{0} د"], ["{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث"]], [["{0} أ", "{0} أ", "{0} أ", "{0} أ", "{0} أ", "{0} أ"], ["{0} ي", "{0} ي", "{0} ي", "{0} ي", "{0} ي", "{0} ي"], ["{0} س", "{0} س", "{0} س", "{0} س", "{0} س", "{0} س"], ["{0} د", "{0} د", "{0} د", "{0} د", "{0} د", "{0} د"], ["{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث", "{0} ث"]]], lists: [["{0} و{1}", "{0}، و{1}", "{0}، و{1}", "{0}، و{1}"], ["{0} و{1}", "{0}، و{1}", "{0}، و{1}", "{0}، و{1}"], ["{0} و{1}", "{0} و{1}", "{0} و{1}", "{0} و{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_5: _DurationPatterns = _DurationPatterns { units: [[["", "{0} সপ\u{9cd}ত\u{9be}হ", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ"], ["", "{0} দিন", "", "", "", "{0} দিন"], ["", "{0} ঘণ\u{9cd}ট\u{9be}", "", "", "", "{0} ঘণ\u{9cd}ট\u{9be}"], ["", "{0} মিনিট", "", "", "", "{0} মিনিট"], ["", "{0} ছেকেণ\u{9cd}ড", "", "", "", "{0} ছেকেণ\u{9cd}ড"]], [["", "{0} সপ\u{9cd}ত\u{9be}হ", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ"], ["", "{0} দিন", "", "", "", "{0} দিন"], ["", "{0} ঘণ\u{9cd}ট\u{9be}", "", "", "", "{0} ঘণ\u{9cd}ট\u{9be}"], ["", "{0} মিনিট", "", "", "", "{0} মিনিট"], ["", "{0} ছেকেণ\u{9cd}ড", "", "", "", "{0} ছেকেণ\u{9cd}ড"]], [["", "{0} সপ\u{9cd}ত\u{9be}হ", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ"], ["", "{0} দিন", "", "", "", "{0} দিন"], ["", "{0} ঘণ\u{9cd}ট\u{9be}", "", "", "", "{0} ঘণ\u{9cd}ট\u{9be}"], ["", "{0} মিনিট", "", "", "", "{0} মিনিট"], ["", "{0} ছেকেণ\u{9cd}ড", "", "", "", "{0} ছেকেণ\u{9cd}ড"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_6: _DurationPatterns = _DurationPatterns { units: [[["", "{0} selmana", "", "", "", "{0} selmanes"], ["", "{0} día", "", "", "", "{0} díes"], ["", "{0} hora", "", "", "", "{0} hores"], ["", "{0} minutu", "", "", "", "{0} minutos"], ["", "{0} segundu", "", "", "", "{0} segundos"]], [["", "{0} sel", "", "", "", "{0} sels"], ["", "{0} día", "", "", "", "{0} díes"], ["", "{0} hr", "", "", "", "{0} hrs"], ["", "{0} min", "", "", "", "{0} mins"], ["", "{0} seg", "", "", "", "{0} segs"]], [["", "{0}sel", "", "", "", "{0}sels"], ["", "{0}día", "", "", "", "{0}díes"], ["", "{0}hr", "", "", "", "{0}hrs"], ["", "{0}min", "", "", "", "{0}mins"], ["", "{0}seg", "", "", "", "{0}segs"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_7: _DurationPatterns = _DurationPatterns { units: [[["", "{0} həftə", "", "", "", "{0} həftə"], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} saat", "", "", "", "{0} saat"], ["", "{0} dəqiqə", "", "", "", "{0} dəqiqə"], ["", "{0} saniyə", "", "", "", "{0} saniyə"]], [["", "{0} hft", "", "", "", "{0} hft"], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} saat", "", "", "", "{0} saat"], ["", "{0} dəq", "", "", "", "{0} dəq"], ["", "{0} san", "", "", "", "{0} san"]], [["", "{0} hft", "", "", "", "{0} hft"], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} saat", "", "", "", "{0} saat"], ["", "{0} dəq", "", "", "", "{0} dəq"], ["", "{0} san", "", "", "", "{0} san"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_8: _DurationPatterns = _DurationPatterns { units: [[["", "{0} тыдзень", "", "{0} тыдні", "{0} тыдняў", "{0} тыдня"], ["", "{0} суткі", "", "{0} сутак", "{0} сутак", "{0} сутак"], ["", "{0} гадзіна", "", "{0} гадзіны", "{0} гадзін", "{0} гадзіны"], ["", "{0} хвіліна", "", "{0} хвіліны", "{0} хвілін", "{0} хвіліны"], ["", "{0} секунда", "", "{0} секунды", "{0} секунд", "{0} секунды"]], [["", "{0} тыдз.", "", "{0} тыдз.", "{0} тыдз.", "{0} тыдз."], ["", "{0} сут", "", "{0} сут", "{0} сут", "{0} сут"], ["", "{0} гадз", "", "{0} гадз", "{0} гадз", "{0} гадз"], ["", "{0} хв", "", "{0} хв", "{0} хв", "{0} хв"], ["", "{0} с", "", "{0} с", "{0} с", "{0} с"]], [["", "{0} тыдз.", "", "{0} тыдз.", "{0} тыдз.", "{0} тыдз."], ["", "{0} сут", "", "{0} сут", "{0} сут", "{0} сут"], ["", "{0} гадз", "", "{0} гадз", "{0} гадз", "{0} гадз"], ["", "{0} хв", "", "{0} хв", "{0} хв", "{0} хв"], ["", "{0} с", "", "{0} с", "{0} с", "{0} с"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["hh:mm:ss", "mm:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_9: _DurationPatterns = _DurationPatterns { units: [[["", "{0} седмица", "", "", "", "{0} седмици"], ["", "{0} ден", "", "", "", "{0} дни"], ["", "{0} час", "", "", "", "{0} часа"], ["", "{0} минута", "", "", "", "{0} минути"], ["", "{0} секунда", "", "", "", "{0} секунди"]], [["", "{0} седм.", "", "", "", "{0} седм."], ["", "{0} д", "", "", "", "{0} д"], ["", "{0} ч", "", "", "", "{0} ч"], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} сек", "", "", "", "{0} сек"]], [["", "{0} седм.", "", "", "", "{0} седм."], ["", "{0} д", "", "", "", "{0} д"], ["", "{0} ч", "", "", "", "{0} ч"], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} с", "", "", "", "{0} с"]]], lists: [["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_10: _DurationPatterns = _DurationPatterns { units: [[["", "{0} সপ\u{9cd}ত\u{9be}হ", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ"], ["", "{0} দিন", "", "", "", "{0} দিন"], ["", "{0} ঘন\u{9cd}ট\u{9be}", "", "", "", "{0} ঘন\u{9cd}ট\u{9be}"], ["", "{0} মিনিট", "", "", "", "{0} মিনিট"], ["", "{0} সেকেন\u{9cd}ড", "", "", "", "{0} সেকেন\u{9cd}ড"]], [["", "{0} সপ\u{9cd}ত\u{9be}হ", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ"], ["", "{0} দিন", "", "", "", "{0} দিন"], ["", "{0} ঘন\u{9cd}ট\u{9be}", "", "", "", "{0} ঘন\u{9cd}ট\u{9be}"], ["", "{0} মিনিট", "", "", "", "{0} মিনিট"], ["", "{0} সেকেন\u{9cd}ড", "", "", "", "{0} সেকেন\u{9cd}ড"]], [["", "{0} সপ\u{9cd}ত\u{9be}হ", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ"], ["", "{0} দিন", "", "", "", "{0} দিন"], ["", "{0} ঘঃ", "", "", "", "{0} ঘঃ"], ["", "{0} মিঃ", "", "", "", "{0} মিঃ"], ["", "{0} সেঃ", "", "", "", "{0} সেঃ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_11: _DurationPatterns = _DurationPatterns { units: [[["", "{0} sizhun", "{0} sizhun", "{0} sizhun", "{0} a sizhunioù", "{0} sizhun"], ["", "{0} deiz", "{0} zeiz", "{0} deiz", "{0} a zeizioù", "{0} deiz"], ["", "{0} eur", "{0} eur", "{0} eur", "{0} a eurioù", "{0} eur"], ["", "{0} munut", "{0} vunut", "{0} munut", "{0} a vunutoù", "{0} munut"], ["", "{0} eilenn", "{0} eilenn", "{0} eilenn", "{0} a eilennoù", "{0} eilenn"]], [["", "{0} sizh.", "{0} sizh.", "{0} sizh.", "{0} sizh.", "{0} sizh."], ["", "{0} d", "{0} d", "{0} d", "{0} d", "{0} d"], ["", "{0} h", "{0} h", "{0} h", "{0} h", "{0} h"], ["", "{0} min", "{0} min", "{0} min", "{0} min", "{0} min"], ["", "{0} s", "{0} s", "{0} s", "{0} s", "{0} s"]], [["", "{0}sizh.", "{0}sizh.", "{0}sizh.", "{0}sizh.", "{0}sizh."], ["", "{0}d", "{0}d", "{0}d", "{0}d", "{0}d"], ["", "{0}h", "{0}h", "{0}h", "{0}h", "{0}h"], ["", "{0}min", "{0}min", "{0}min", "{0}min", "{0}min"], ["", "{0}s", "{0}s", "{0}s", "{0}s", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_12: _DurationPatterns = _DurationPatterns { units: [[["", "{0} सप\u{94d}ताह", "", "", "", "{0} सप\u{94d}ताह"], ["", "{0} सान", "", "", "", "{0} सान"], ["", "{0} रि\u{902}गा", "", "", "", "{0} घ\u{902}ट\u{947}"], ["", "{0} मिन.", "", "", "", "{0} मिन."], ["", "{0} स\u{947}क\u{902}.", "", "", "", "{0} स\u{947}क\u{902}."]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_13: _DurationPatterns = _DurationPatterns { units: [[["", "{0} sedmica", "", "{0} sedmice", "", "{0} sedmica"], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} sat", "", "{0} sata", "", "{0} sati"], ["", "{0} minuta", "", "{0} minute", "", "{0} minuta"], ["", "{0} sekunda", "", "{0} sekunde", "", "{0} sekundi"]], [["", "{0} sedm.", "", "{0} sedm.", "", "{0} sedm."], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} h", "", "{0} h", "", "{0} h"], ["", "{0} min.", "", "{0} min.", "", "{0} min."], ["", "{0} sek.", "", "{0} sek.", "", "{0} sek."]], [["", "{0} sedm.", "", "{0} sedm.", "", "{0} sedm."], ["", "{0} d.", "", "{0} d.", "", "{0} d."], ["", "{0} h", "", "{0} h", "", "{0} h"], ["", "{0} m", "", "{0} m", "", "{0} m"], ["", "{0} s", "", "{0} s", "", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_14: _DurationPatterns = _DurationPatterns { units: [[["", "{0} седмица", "", "{0} седмице", "", "{0} седмица"], ["", "{0} дан", "", "{0} дана", "", "{0} дана"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} минут", "", "{0} минута", "", "{0} минута"], ["", "{0} секунда", "", "{0} секунде", "", "{0} секунди"]], [["", "{0} сед.", "", "{0} сед.", "", "{0} сед."], ["", "{0} дан", "", "{0} дана", "", "{0} дан"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} мин.", "", "{0} мин.", "", "{0} мин."], ["", "{0} сек.", "", "{0} сек.", "", "{0} сек."]], [["", "{0} сед.", "", "{0} сед.", "", "{0} сед."], ["", "{0} дан", "", "{0} дана", "", "{0} дан"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} мин.", "", "{0} мин.", "", "{0} мин."], ["", "{0} сек.", "", "{0} сек.", "", "{0} сек."]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_15: _DurationPatterns = _DurationPatterns { units: [[["", "{0} setmana", "", "", "", "{0} setmanes"], ["", "{0} dia", "", "", "", "{0} dies"], ["", "{0} hora", "", "", "", "{0} hores"], ["", "{0} minut", "", "", "", "{0} minuts"], ["", "{0} segon", "", "", "", "{0} segons"]], [["", "{0} setm.", "", "", "", "{0} setm."], ["", "{0} dia", "", "", "", "{0} dies"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} setm.", "", "", "", "{0} setm."], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_16: _DurationPatterns = _DurationPatterns { units: [[["", "{0} 𑄥𑄛\u{11134}𑄖", "", "", "", "{0} 𑄥𑄛\u{11134}𑄖"], ["", "{0} 𑄘\u{11128}𑄚\u{11134}", "", "", "", "{0} 𑄘\u{11128}𑄚\u{11134}"], ["", "{0} 𑄊\u{11127}𑄚\u{11134}𑄘", "", "", "", "{0} 𑄊\u{11127}𑄚\u{11134}𑄘"], ["", "{0} 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134}", "", "", "", "{0} 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134}"], ["", "{0} 𑄥𑄬𑄇𑄬𑄚\u{11133}𑄓\u{11134}", "", "", "", "{0} 𑄥𑄬𑄇𑄬𑄚\u{11133}𑄓\u{11134}"]], [["", "{0} 𑄥𑄛\u{11134}𑄖", "", "", "", "{0} 𑄥𑄛\u{11134}𑄖"], ["", "{0} 𑄘\u{11128}𑄚\u{11134}", "", "", "", "{0} 𑄘\u{11128}𑄚\u{11134}"], ["", "{0} 𑄊\u{11127}𑄚\u{11134}𑄑", "", "", "", "{0} 𑄊\u{11127}𑄚\u{11134}𑄑"], ["", "{0} 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134}", "", "", "", "{0} 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134}"], ["", "{0} 𑄥𑄬𑄇𑄬𑄚\u{11133}𑄓\u{11134}", "", "", "", "{0} 𑄥𑄬𑄇𑄬𑄚\u{11133}𑄓\u{11134}"]], [["", "{0} 𑄥𑄛\u{11134}𑄖", "", "", "", "{0} 𑄥𑄛\u{11134}𑄖"], ["", "{0} 𑄘\u{11128}𑄚\u{11134}", "", "", "", "{0} 𑄘\u{11128}𑄚\u{11134}"], ["", "{0} 𑄊\u{11102}", "", "", "", "{0} 𑄊\u{11102}"], ["", "{0} 𑄟\u{11128}\u{11102}", "", "", "", "{0} 𑄟\u{11128}\u{11102}"], ["", "{0} 𑄥𑄬\u{11102}", "", "", "", "{0} 𑄥𑄬\u{11102}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_17: _DurationPatterns = _DurationPatterns { units: [[["", "{0} кӀира", "", "", "", "{0} кӀира"], ["", "{0} де", "", "", "", "{0} де"], ["", "{0} сахьт", "", "", "", "{0} сахьт"], ["", "{0} минот", "", "", "", "{0} минот"], ["", "{0} секунд", "", "", "", "{0} секунд"]], [["", "{0} кӀир.", "", "", "", "{0} кӀир."], ["", "д.", "", "", "", "{0} д."], ["", "{0} сахь.", "", "", "", "{0} сахь."], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} сек", "", "", "", "{0} сек"]], [["", "{0} кӀ.", "", "", "", "{0} кӀ."], ["", "{0} д.", "", "", "", "{0} д."], ["", "{0} сахь.", "", "", "", "{0} сахь."], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} с", "", "", "", "{0} с"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_18: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ka semana", "", "", "", "{0} ka mga semana"], ["", "{0} ka adlaw", "", "", "", "{0} ka mga adlaw"], ["", "{0} ka oras", "", "", "", "{0} ka mga oras"], ["", "{0} ka minuto", "", "", "", "{0} ka mga minuto"], ["", "{0} ka segundo", "", "", "", "{0} ka mga segundo"]], [["", "{0} ka semana", "", "", "", "{0} ka semana"], ["", "{0} ka adlaw", "", "", "", "{0} ka adlaw"], ["", "{0} ka oras", "", "", "", "{0} ka oras"], ["", "{0} ka minuto", "", "", "", "{0} ka minuto"], ["", "{0}segundo", "", "", "", "{0}segundo"]], [["", "{0} semana", "", "", "", "{0}w"], ["", "{0} adlaw", "", "", "", "{0} adlaw"], ["", "{0} ka oras", "", "", "", "{0} ka oras"], ["", "{0} minuto", "", "", "", "{0} minuto"], ["", "{0}segundo", "", "", "", "{0}segundo"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_19: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ᏒᎾᏙᏓᏆᏍᏗ", "", "", "", "{0} ᎢᏳᎾᏙᏓᏆᏍᏗ"], ["", "{0} ᎢᎦ", "", "", "", "{0} ᎯᎸᏍᎩ ᏧᏒᎯᏓ"], ["", "{0} ᏑᏟᎶᏓ", "", "", "", "{0} ᎢᏳᏟᎶᏓ"], ["", "{0} ᎢᏯᏔᏬᏍᏔᏅ", "", "", "", "{0} ᎢᏯᏔᏬᏍᏔᏅ"], ["", "{0} ᎠᏎᏢ", "", "", "", "{0} ᏗᏎᏢ"]], [["", "{0} ᏒᎾ", "", "", "", "{0} ᎢᏳᎾ"], ["", "{0} ᎢᎦ", "", "", "", "{0} ᏧᏒᎯᏓ"], ["", "{0} ᏑᏟ", "", "", "", "{0} ᏑᏟ"], ["", "{0} ᎢᏯᏔ", "", "", "", "{0} ᎢᏯᏔ"], ["", "{0} ᎠᏎᏢ", "", "", "", "{0} ᎠᏎᏢ"]], [["", "{0}Ꮢ", "", "", "", "{0}Ꮢ"], ["", "{0}Ꭲ", "", "", "", "{0}Ꭲ"], ["", "{0}Ꮡ", "", "", "", "{0}Ꮡ"], ["", "{0}Ꭲ", "", "", "", "{0}Ꭲ"], ["", "{0}ᎠᏎ", "", "", "", "{0}ᎠᏎ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_20: _DurationPatterns = _DurationPatterns { units: [[["", "{0} týden", "", "{0} týdny", "{0} týdne", "{0} týdnů"], ["", "{0} den", "", "{0} dny", "{0} dne", "{0} dnů"], ["", "{0} hodina", "", "{0} hodiny", "{0} hodiny", "{0} hodin"], ["", "{0} minuta", "", "{0} minuty", "{0} minuty", "{0} minut"], ["", "{0} sekunda", "", "{0} sekundy", "{0} sekundy", "{0} sekund"]], [["", "{0} týd.", "", "{0} týd.", "{0} týd.", "{0} týd."], ["", "{0} den", "", "{0} dny", "{0} dne", "{0} dnů"], ["", "{0} h", "", "{0} h", "{0} h", "{0} h"], ["", "{0} min", "", "{0} min", "{0} min", "{0} min"], ["", "{0} s", "", "{0} s", "{0} s", "{0} s"]], [["", "{0} t.", "", "{0} t.", "{0} t.", "{0} t."], ["", "{0} d.", "", "{0} d.", "{0} d.", "{0} d."], ["", "{0} h", "", "{0} h", "{0} h", "{0} h"], ["", "{0} m", "", "{0} m", "{0} m", "{0} m"], ["", "{0} s", "", "{0} s", "{0} s", "{0} s"]]], lists: [["{0} a\u{a0}{1}", "{0}, {1}", "{0}, {1}", "{0} a\u{a0}{1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} a\u{a0}{1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_21: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_22: _DurationPatterns = _DurationPatterns { units: [[["{0} wythnos", "{0} wythnos", "{0} wythnos", "{0} wythnos", "{0} wythnos", "{0} wythnos"], ["{0} diwrnod", "{0} diwrnod", "{0} ddiwrnod", "{0} diwrnod", "{0} diwrnod", "{0} diwrnod"], ["{0} awr", "{0} awr", "{0} awr", "{0} awr", "{0} awr", "{0} awr"], ["{0} munud", "{0} munud", "{0} funud", "{0} munud", "{0} munud", "{0} munud"], ["{0} eiliad", "{0} eiliad", "{0} eiliad", "{0} eiliad", "{0} eiliad", "{0} eiliad"]], [["{0} ws", "{0} ws", "{0} ws", "{0} ws", "{0} ws", "{0} ws"], ["{0} diwrnod", "{0} diwrnod", "{0} ddiwrnod", "{0} diwrnod", "{0} diwrnod", "{0} diwrnod"], ["{0} awr", "{0} awr", "{0} awr", "{0} awr", "{0} awr", "{0} awr"], ["{0} mun", "{0} mun", "{0} mun", "{0} mun", "{0} mun", "{0} mun"], ["{0} eil", "{0} eil", "{0} eil", "{0} eil", "{0} eil", "{0} eil"]], [["{0} ws", "{0}w", "{0} ws", "{0} ws", "{0} ws", "{0}w"], ["{0}d", "{0}d", "{0}d", "{0}d", "{0}d", "{0}d"], ["{0} awr", "{0} awr", "{0} awr", "{0} awr", "{0} awr", "{0} awr"], ["{0}mun", "{0}mun", "{0}mun", "{0}mun", "{0}mun", "{0}mun"], ["{0} eil", "{0} eil", "{0} eil", "{0} eil", "{0} eil", "{0} eil"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_23: _DurationPatterns = _DurationPatterns { units: [[["", "{0} uge", "", "", "", "{0} uger"], ["", "{0} dag", "", "", "", "{0} dage"], ["", "{0} time", "", "", "", "{0} timer"], ["", "{0} minut", "", "", "", "{0} minutter"], ["", "{0} sekund", "", "", "", "{0} sekunder"]], [["", "{0} uge", "", "", "", "{0} uger"], ["", "{0} dag", "", "", "", "{0} dage"], ["", "{0} t.", "", "", "", "{0} t."], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} sek.", "", "", "", "{0} sek."]], [["", "{0} u", "", "", "", "{0} u"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} t", "", "", "", "{0} t"], ["", "{0} m", "", "", "", "{0} m"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} og {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"], ["{0} og {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"], ["{0} og {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_24: _DurationPatterns = _DurationPatterns { units: [[["", "{0} Woche", "", "", "", "{0} Wochen"], ["", "{0} Tag", "", "", "", "{0} Tage"], ["", "{0} Stunde", "", "", "", "{0} Stunden"], ["", "{0} Minute", "", "", "", "{0} Minuten"], ["", "{0} Sekunde", "", "", "", "{0} Sekunden"]], [["", "{0} Wo.", "", "", "", "{0} Wo."], ["", "{0} Tg.", "", "", "", "{0} Tg."], ["", "{0} Std.", "", "", "", "{0} Std."], ["", "{0} Min.", "", "", "", "{0} Min."], ["", "{0} Sek.", "", "", "", "{0} Sek."]], [["", "{0} W", "", "", "", "{0} W"], ["", "{0} T", "", "", "", "{0} T"], ["", "{0} Std.", "", "", "", "{0} Std."], ["", "{0} Min.", "", "", "", "{0} Min."], ["", "{0} Sek.", "", "", "", "{0} Sek."]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} und {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} und {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} und {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_25: _DurationPatterns = _DurationPatterns { units: [[["", "{0} tyźeń", "{0} tyźenja", "{0} tyźenje", "", "{0} tyźenjow"], ["", "{0} źeń", "{0} dnja", "{0} dny", "", "{0} dnjow"], ["", "{0} góźina", "{0} góźinje", "{0} góźiny", "", "{0} góźinow"], ["", "{0} minuta", "{0} minuśe", "{0} minuty", "", "{0} minutow"], ["", "{0} sekunda", "{0} sekunźe", "{0} sekundy", "", "{0} sekundow"]], [["", "{0} tyź.", "{0} tyź.", "{0} tyź.", "", "{0} tyź."], ["", "{0} ź.", "{0} dn.", "{0} dn.", "", "{0} dn."], ["", "{0} góź.", "{0} góź.", "{0} góź.", "", "{0} góź."], ["", "{0} min.", "{0} min.", "{0} min.", "", "{0} min."], ["", "{0} sek.", "{0} sek.", "{0} sek.", "", "{0} sek."]], [["", "{0} tyź.", "{0} tyź.", "{0} tyź.", "", "{0} tyź."], ["", "{0} ź", "{0} d", "{0} d", "", "{0} d"], ["", "{0} g", "{0} g", "{0} g", "", "{0} g"], ["", "{0} min", "{0} min", "{0} min", "", "{0} min"], ["", "{0} s", "{0} s", "{0} s", "", "{0} s"]]], lists: [["{0} a {1}", "{0}, {1}", "{0}, {1}", "{0} a {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} a {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_26: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "བང\u{f74}ན་ཕ\u{fb2}ག་ {0}"], ["", "", "", "", "", "ཉ\u{f72}ན་ཞག་ {0}"], ["", "", "", "", "", "ཆ\u{f74}་ཚ\u{f7c}ད་ {0}"], ["", "", "", "", "", "ས\u{f90}ར་མ་ {0}"], ["", "", "", "", "", "ས\u{f90}ར་ཆ་ {0}"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_27: _DurationPatterns = _DurationPatterns { units: [[["", "kɔsiɖa {0}", "", "", "", "kɔsiɖa {0}"], ["", "ŋkeke {0}", "", "", "", "ŋkeke {0}"], ["", "gaƒoƒo {0}", "", "", "", "gaƒoƒo {0}"], ["", "aɖabaƒoƒo {0}", "", "", "", "aɖabaƒoƒo {0}"], ["", "sekend {0} wo", "", "", "", "sekend {0} wo"]], [["", "kɔsiɖa {0}", "", "", "", "kɔsiɖa {0}"], ["", "ŋkeke {0}", "", "", "", "ŋkeke {0}"], ["", "gaƒoƒo {0}", "", "", "", "gaƒoƒo {0}"], ["", "a {0}", "", "", "", "a {0}"], ["", "sekend {0}", "", "", "", "sekend {0}"]], [["", "kɔsiɖa {0}", "", "", "", "kɔsiɖa {0}"], ["", "ŋkeke {0}", "", "", "", "ŋkeke {0}"], ["", "gaƒoƒo {0}", "", "", "", "gaƒoƒo {0}"], ["", "a {0}", "", "", "", "a {0}"], ["", "s {0}", "", "", "", "s {0}"]]], lists: [["{0} kple {1}", "{0}, {1}", "{0}, {1}", "{0}, kple {1}"], ["{0} kple {1}", "{0}, {1}", "{0}, {1}", "{0}, kple {1}"], ["{0} kple {1}", "{0}, {1}", "{0}, {1}", "{0}, kple {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_28: _DurationPatterns = _DurationPatterns { units: [[["", "{0} εβδομάδα", "", "", "", "{0} εβδομάδες"], ["", "{0} ημέρα", "", "", "", "{0} ημέρες"], ["", "{0} ώρα", "", "", "", "{0} ώρες"], ["", "{0} λεπτό", "", "", "", "{0} λεπτά"], ["", "{0} δευτερόλεπτο", "", "", "", "{0} δευτερόλεπτα"]], [["", "{0} εβδ.", "", "", "", "{0} εβδ."], ["", "{0} ημέρα", "", "", "", "{0} ημέρες"], ["", "{0} ώ.", "", "", "", "{0} ώ."], ["", "{0} λ.", "", "", "", "{0} λ."], ["", "{0} δευτ.", "", "", "", "{0} δευτ."]], [["", "{0} ε", "", "", "", "{0} ε"], ["", "{0} η", "", "", "", "{0} η"], ["", "{0} ώ", "", "", "", "{0} ώ"], ["", "{0} λ", "", "", "", "{0} λ"], ["", "{0} δ", "", "", "", "{0} δ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_29: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weeks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hour", "", "", "", "{0} hours"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0} second", "", "", "", "{0} seconds"]], [["", "{0} wk", "", "", "", "{0} wks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hr", "", "", "", "{0} hr"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sec", "", "", "", "{0} sec"]], [["", "{0}w", "", "", "", "{0}w"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_30: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weeks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hour", "", "", "", "{0} hours"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0} second", "", "", "", "{0} seconds"]], [["", "{0} wk", "", "", "", "{0} wks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hr", "", "", "", "{0} hrs"], ["", "{0} min", "", "", "", "{0} mins"], ["", "{0} sec", "", "", "", "{0} secs"]], [["", "{0}w", "", "", "", "{0}w"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_31: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weeks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hour", "", "", "", "{0} hours"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0} second", "", "", "", "{0} seconds"]], [["", "{0} wk", "", "", "", "{0} wks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hr", "", "", "", "{0} hrs"], ["", "{0} min.", "", "", "", "{0} mins"], ["", "{0} sec.", "", "", "", "{0} secs"]], [["", "{0}w", "", "", "", "{0}w"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}min.", "", "", "", "{0}min."], ["", "{0}s.", "", "", "", "{0}s."]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_32: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weeks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hour", "", "", "", "{0} hours"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0} second", "", "", "", "{0} seconds"]], [["", "{0} wk", "", "", "", "{0} wks"], ["", "{0} day", "", "", "", "{0} days"], ["", "{0} hr", "", "", "", "{0} hrs"], ["", "{0} min", "", "", "", "{0} mins"], ["", "{0} sec", "", "", "", "{0} secs"]], [["", "{0}w", "", "", "", "{0}w"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}min", "", "", "", "{0}min"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_33: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sem", "", "", "", "{0}sem"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}min", "", "", "", "{0}min"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_34: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} d.", "", "", "", "{0} dd."], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sem.", "", "", "", "{0}sems."], ["", "{0}d.", "", "", "", "{0}dd."], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_35: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} d.", "", "", "", "{0} dd."], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} seg.", "", "", "", "{0} seg."]], [["", "{0}sem.", "", "", "", "{0}sems."], ["", "{0}d.", "", "", "", "{0}dd."], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}seg.", "", "", "", "{0}seg."]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_36: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} d.", "", "", "", "{0} dd."], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sem.", "", "", "", "{0}sems."], ["", "{0}d.", "", "", "", "{0}dd."], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["hh:mm:ss", "mm:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_37: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_38: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} d.", "", "", "", "{0} dd."], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} seg.", "", "", "", "{0} seg."]], [["", "{0}sem.", "", "", "", "{0}sems."], ["", "{0}d.", "", "", "", "{0}d."], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} y {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_39: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem", "", "", "", "{0} sem"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sem", "", "", "", "{0}sem"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_40: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} seg.", "", "", "", "{0} seg."]], [["", "{0}sem.", "", "", "", "{0}sems."], ["", "{0}d.", "", "", "", "{0}dd."], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_41: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sems."], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sem.", "", "", "", "{0}sems."], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} y {1}", "{0}, {1}", "{0}, {1}", "{0} y {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_42: _DurationPatterns = _DurationPatterns { units: [[["", "{0} nädal", "", "", "", "{0} nädalat"], ["", "{0} ööpäev", "", "", "", "{0} ööpäeva"], ["", "{0} tund", "", "", "", "{0} tundi"], ["", "{0} minut", "", "", "", "{0} minutit"], ["", "{0} sekund", "", "", "", "{0} sekundit"]], [["", "{0} näd", "", "", "", "{0} näd"], ["", "{0} päev", "", "", "", "{0} päeva"], ["", "{0} t", "", "", "", "{0} t"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sek", "", "", "", "{0} sek"]], [["", "{0} n", "", "", "", "{0} n"], ["", "{0} p", "", "", "", "{0} p"], ["", "{0} t", "", "", "", "{0} t"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_43: _DurationPatterns = _DurationPatterns { units: [[["", "{0} aste", "", "", "", "{0} aste"], ["", "{0} egun", "", "", "", "{0} egun"], ["", "{0} ordu", "", "", "", "{0} ordu"], ["", "{0} minutu", "", "", "", "{0} minutu"], ["", "{0} segundo", "", "", "", "{0} segundo"]], [["", "{0} aste", "", "", "", "{0} aste"], ["", "{0} egun", "", "", "", "{0} egun"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} aste", "", "", "", "{0} aste"], ["", "{0} e.", "", "", "", "{0} e."], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} eta {1}", "{0}, {1}", "{0}, {1}", "{0} eta {1}"], ["{0} eta {1}", "{0}, {1}", "{0}, {1}", "{0} eta {1}"], ["{0} eta {1}", "{0}, {1}", "{0}, {1}", "{0} eta {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_44: _DurationPatterns = _DurationPatterns { units: [[["", "{0} هفته", "", "", "", "{0} هفته"], ["", "{0} روز", "", "", "", "{0} روز"], ["", "{0} ساعت", "", "", "", "{0} ساعت"], ["", "{0} دقیقه", "", "", "", "{0} دقیقه"], ["", "{0} ثانیه", "", "", "", "{0} ثانیه"]], [["", "{0} هفته", "", "", "", "{0} هفته"], ["", "{0} روز", "", "", "", "{0} روز"], ["", "{0} ساعت", "", "", "", "{0} ساعت"], ["", "{0} دقیقه", "", "", "", "{0} دقیقه"], ["", "{0} ثانیه", "", "", "", "{0} ثانیه"]], [["", "{0} هفته", "", "", "", "{0} هفته"], ["", "{0} روز", "", "", "", "{0} روز"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} و {1}", "{0}،\u{200f} {1}", "{0}،\u{200f} {1}", "{0}، و {1}"], ["{0}،\u{200f} {1}", "{0}،\u{200f} {1}", "{0}،\u{200f} {1}", "{0}، و {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_45: _DurationPatterns = _DurationPatterns { units: [[["", "{0} 𞤴𞤮𞤲𞤼𞤫𞤪𞤫", "", "", "", "{0} 𞤶𞤮𞤲𞤼𞤫"], ["", "{0} 𞤻𞤢𞤤\u{1e946}𞤢𞤤", "", "", "", "{0} 𞤻𞤢𞤤\u{1e946}𞤫"], ["", "{0} 𞤲𞤶𞤢𞤥𞤲𞤣𞤭", "", "", "", "{0} 𞤲𞤶𞤢𞤥𞤤𞤭"], ["", "{0} 𞤸𞤮𞤶𞤮𞤥𞤢\u{1e944}𞤪𞤫", "", "", "", "{0} 𞤸𞤮𞤶𞤮𞤥𞤢\u{1e944}𞤶𞤫"], ["", "{0} 𞤳𞤭𞤲𞤰𞤮", "", "", "", "{0} 𞤳𞤭𞤲𞤰𞤫"]], [["", "{0} 𞤴𞤼", "", "", "", "{0} 𞤶𞤼"], ["", "{0} 𞤻𞤢𞤤.", "", "", "", "{0} 𞤻𞤢𞤤."], ["", "{0} 𞤶𞤢", "", "", "", "{0} 𞤶𞤢"], ["", "{0} 𞤸𞤮𞤶", "", "", "", "{0} 𞤸𞤮𞤶"], ["", "{0} 𞤳𞤭𞤲", "", "", "", "{0} 𞤳𞤭𞤲"]], [["", "{0} 𞤴𞤼", "", "", "", "{0} 𞤶𞤼"], ["", "{0}𞤻", "", "", "", "{0}𞤻"], ["", "{0}𞤶", "", "", "", "{0}𞤶"], ["", "{0}𞤸𞤮𞤶", "", "", "", "{0}𞤸𞤮𞤶"], ["", "{0}𞤳𞤭𞤲", "", "", "", "{0}𞤳𞤭𞤲"]]], lists: [["{0} 𞤫 {1}", "{0}⹁ {1}", "{0}, {1}", "{0} 𞤫 {1}"], ["{0} 𞤫 {1}", "{0}⹁ {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_46: _DurationPatterns = _DurationPatterns { units: [[["", "{0} viikko", "", "", "", "{0} viikkoa"], ["", "{0} päivä", "", "", "", "{0} päivää"], ["", "{0} tunti", "", "", "", "{0} tuntia"], ["", "{0} minuutti", "", "", "", "{0} minuuttia"], ["", "{0} sekunti", "", "", "", "{0} sekuntia"]], [["", "{0} vk", "", "", "", "{0} vk"], ["", "{0} pv", "", "", "", "{0} pv"], ["", "{0} t", "", "", "", "{0} t"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}vk", "", "", "", "{0}vk"], ["", "{0}pv", "", "", "", "{0}pv"], ["", "{0}t", "", "", "", "{0}t"], ["", "{0}min", "", "", "", "{0}min"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} ja {1}", "{0}, {1}", "{0}, {1}", "{0} ja {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_47: _DurationPatterns = _DurationPatterns { units: [[["", "{0} linggo", "", "", "", "{0} na linggo"], ["", "{0} araw", "", "", "", "{0} na araw"], ["", "{0} oras", "", "", "", "{0} na oras"], ["", "{0} minuto", "", "", "", "{0} na minuto"], ["", "{0} segundo", "", "", "", "{0} na segundo"]], [["", "{0} linggo", "", "", "", "{0} na linggo"], ["", "{0} araw", "", "", "", "{0} araw"], ["", "{0} oras", "", "", "", "{0} na oras"], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} seg.", "", "", "", "{0} seg."]], [["", "{0}linggo", "", "", "", "{0}linggo"], ["", "{0} araw", "", "", "", "{0} na araw"], ["", "{0} oras", "", "", "", "{0} oras"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_48: _DurationPatterns = _DurationPatterns { units: [[["", "{0} vika", "", "", "", "{0} vikur"], ["", "{0} dagur", "", "", "", "{0} dagar"], ["", "{0} tími", "", "", "", "{0} tímar"], ["", "{0} minuttur", "", "", "", "{0} minuttir"], ["", "{0} sekund", "", "", "", "{0} sekundir"]], [["", "{0} vi.", "", "", "", "{0} vi."], ["", "{0} d.", "", "", "", "{0} d."], ["", "{0} t.", "", "", "", "{0} t."], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} sek.", "", "", "", "{0} sek."]], [["", "{0}v.", "", "", "", "{0}v."], ["", "{0}d.", "", "", "", "{0}d."], ["", "{0}t.", "", "", "", "{0}t."], ["", "{0}m.", "", "", "", "{0}m."], ["", "{0}s.", "", "", "", "{0}s."]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_49: _DurationPatterns = _DurationPatterns { units: [[["", "{0}\u{a0}semaine", "", "", "", "{0}\u{a0}semaines"], ["", "{0}\u{a0}jour", "", "", "", "{0}\u{a0}jours"], ["", "{0}\u{a0}heure", "", "", "", "{0}\u{a0}heures"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0}\u{a0}seconde", "", "", "", "{0}\u{a0}secondes"]], [["", "{0}\u{202f}sem.", "", "", "", "{0}\u{202f}sem."], ["", "{0}\u{202f}j", "", "", "", "{0}\u{202f}j"], ["", "{0}\u{202f}h", "", "", "", "{0}\u{202f}h"], ["", "{0}\u{a0}min", "", "", "", "{0}\u{a0}min"], ["", "{0}\u{202f}s", "", "", "", "{0}\u{202f}s"]], [["", "{0}sem.", "", "", "", "{0}sem."], ["", "{0}j", "", "", "", "{0}j"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}min", "", "", "", "{0}min"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} et {1}", "{0}, {1}", "{0}, {1}", "{0} et {1}"], ["{0} et {1}", "{0}, {1}", "{0}, {1}", "{0} et {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_50: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semaine", "", "", "", "{0} semaines"], ["", "{0} jour", "", "", "", "{0} jours"], ["", "{0} heure", "", "", "", "{0} heures"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0} seconde", "", "", "", "{0} secondes"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} j", "", "", "", "{0} j"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sem", "", "", "", "{0}sem"], ["", "{0}j", "", "", "", "{0}j"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} et {1}", "{0}, {1}", "{0}, {1}", "{0} et {1}"], ["{0} et {1}", "{0}, {1}", "{0}, {1}", "{0} et {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_51: _DurationPatterns = _DurationPatterns { units: [[["", "{0} setemane", "", "", "", "{0} setemanis"], ["", "{0} zornade", "", "", "", "{0} zornadis"], ["", "{0} ore", "", "", "", "{0} oris"], ["", "{0} minût", "", "", "", "{0} minûts"], ["", "{0} secont", "", "", "", "{0} seconts"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_52: _DurationPatterns = _DurationPatterns { units: [[["", "{0} wike", "", "", "", "{0} wiken"], ["", "{0} dei", "", "", "", "{0} deien"], ["", "{0} oere", "", "", "", "{0} oere"], ["", "{0} minút", "", "", "", "{0} minuten"], ["", "{0} sekonde", "", "", "", "{0} sekonden"]], [["", "{0} wk", "", "", "", "{0} wkn"], ["", "{0} dei", "", "", "", "{0} deien"], ["", "{0} oere", "", "", "", "{0} oere"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sek.", "", "", "", "{0} sek."]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} u", "", "", "", "{0} u"], ["", "{0} m", "", "", "", "{0} m"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} en {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_53: _DurationPatterns = _DurationPatterns { units: [[["", "{0} seachtain", "{0} sheachtain", "{0} seachtaine", "{0} seachtaine", "{0} seachtain"], ["", "{0} lá", "{0} lá", "{0} lá", "{0} lá", "{0} lá"], ["", "{0} uair", "{0} uair", "{0} huaire", "{0} n-uaire", "{0} uair"], ["", "{0} nóiméad", "{0} nóiméad", "{0} nóiméad", "{0} nóiméad", "{0} nóiméad"], ["", "{0} soicind", "{0} shoicind", "{0} shoicind", "{0} soicind", "{0} soicind"]], [["", "{0} scht", "{0} scht", "{0} scht", "{0} scht", "{0} scht"], ["", "{0} lá", "{0} lá", "{0} lá", "{0} lá", "{0} lá"], ["", "{0} u", "{0} u", "{0} u", "{0} u", "{0} u"], ["", "{0} nóim", "{0} nóim", "{0} nóim", "{0} nóim", "{0} nóim"], ["", "{0} soic", "{0} shoic", "{0} shoic", "{0} soic", "{0} soic"]], [["", "{0}s", "{0}s", "{0}s", "{0}s", "{0}s"], ["", "{0}l", "{0}l", "{0}l", "{0}l", "{0}l"], ["", "{0}u", "{0}u", "{0}u", "{0}u", "{0}u"], ["", "{0}n", "{0}n", "{0}n", "{0}n", "{0}n"], ["", "{0}s", "{0}s", "{0}s", "{0}s", "{0}s"]]], lists: [["{0} agus {1}", "{0}, {1}", "{0}, {1}", "{0} agus {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_54: _DurationPatterns = _DurationPatterns { units: [[["", "{0} seachdain", "{0} sheachdain", "{0} seachdainean", "", "{0} seachdain"], ["", "{0} latha", "{0} latha", "{0} làithean", "", "{0} latha"], ["", "{0} uair a thìde", "{0} uair a thìde", "{0} uairean a thìde", "", "{0} uair a thìde"], ["", "{0} mhionaid", "{0} mhionaid", "{0} mionaidean", "", "{0} mionaid"], ["", "{0} diog", "{0} dhiog", "{0} diogan", "", "{0} diog"]], [["", "{0} shn", "{0} shn", "{0} sn", "", "{0} sn"], ["", "{0} là", "{0} là", "{0} là", "", "{0} là"], ["", "{0} uair", "{0} uair", "{0} uair", "", "{0} uair"], ["", "{0} mhion", "{0} mhion", "{0} mion", "", "{0} mion"], ["", "{0} diog", "{0} dhiog", "{0} diog", "", "{0} diog"]], [["", "{0}s", "{0}s", "{0}s", "", "{0}s"], ["", "{0}là", "{0}là", "{0}là", "", "{0}là"], ["", "{0}u", "{0}u", "{0}u", "", "{0}u"], ["", "{0}m", "{0}m", "{0}m", "", "{0}m"], ["", "{0}d", "{0}d", "{0}d", "", "{0}d"]]], lists: [["{0} agus {1}", "{0}, {1}", "{0}, {1}", "{0} agus {1}"], ["{0} ’s {1}", "{0}, {1}", "{0}, {1}", "{0} ’s {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_55: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} día", "", "", "", "{0} días"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_56: _DurationPatterns = _DurationPatterns { units: [[["", "{0} Wuche", "", "", "", "{0} Wuche"], ["", "{0} Taag", "", "", "", "{0} Tääg"], ["", "{0} Schtund", "", "", "", "{0} Schtunde"], ["", "{0} Minuute", "", "", "", "{0} Minuute"], ["", "{0} Sekunde", "", "", "", "{0} Sekunde"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_57: _DurationPatterns = _DurationPatterns { units: [[["", "{0} અઠવાડિય\u{ac1}\u{a82}", "", "", "", "{0} અઠવાડિયા"], ["", "{0} દિવસ", "", "", "", "{0} દિવસ"], ["", "{0} કલાક", "", "", "", "{0} કલાક"], ["", "{0} મિનિટ", "", "", "", "{0} મિનિટ"], ["", "{0} સ\u{ac7}ક\u{a82}ડ", "", "", "", "{0} સ\u{ac7}ક\u{a82}ડ"]], [["", "{0} અઠ.", "", "", "", "{0} અઠ."], ["", "{0} દિવસ", "", "", "", "{0} દિવસ"], ["", "{0} કલાક", "", "", "", "{0} કલાક"], ["", "{0} મિનિટ", "", "", "", "{0} મિનિટ"], ["", "{0} સ\u{ac7}ક\u{a82}ડ", "", "", "", "{0} સ\u{ac7}ક\u{a82}ડ"]], [["", "{0} અઠ.", "", "", "", "{0} અઠ."], ["", "{0} દિ", "", "", "", "{0} દિ"], ["", "{0} ક", "", "", "", "{0} ક"], ["", "{0} મિ", "", "", "", "{0} મિ"], ["", "{0} સ\u{ac7}", "", "", "", "{0} સ\u{ac7}"]]], lists: [["{0} અન\u{ac7} {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_58: _DurationPatterns = _DurationPatterns { units: [[["", "mako {0}", "", "", "", "makonni {0}"], ["", "rana {0}", "", "", "", "ranaku {0}"], ["", "sa′a {0}", "", "", "", "sa′o′i {0}"], ["", "minti {0}", "", "", "", "mintoci {0}"], ["", "daƙiƙa {0}", "", "", "", "daƙiƙoƙi {0}"]], [["", "mk {0}", "", "", "", "mkn {0}"], ["", "rana {0}", "", "", "", "Rnk. {0}"], ["", "s {0}", "", "", "", "s {0}"], ["", "mnt {0}", "", "", "", "mnt {0}"], ["", "d {0}", "", "", "", "d {0}"]], [["", "m{0}", "", "", "", "m{0}"], ["", "r{0}", "", "", "", "r{0}"], ["", "s{0}", "", "", "", "s{0}"], ["", "minti{0}", "", "", "", "minti {0}"], ["", "d {0}", "", "", "", "d {0}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_59: _DurationPatterns = _DurationPatterns { units: [[["", "{0} pule", "", "", "", "{0} pule"], ["", "{0} lā", "", "", "", "{0} lā"], ["", "{0} hola", "", "", "", "{0} hola"], ["", "{0} minuke", "", "", "", "{0} minuke"], ["", "{0} kekona", "", "", "", "{0} kekona"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_60: _DurationPatterns = _DurationPatterns { units: [[["", "שבוע", "שבועיים", "", "{0} שבועות", "{0} שבועות"], ["", "יום {0}", "יומיים", "", "{0} יום", "{0} ימים"], ["", "שעה", "שעתיים", "", "{0} שעות", "{0} שעות"], ["", "דקה", "שתי דקות", "", "{0} דקות", "{0} דקות"], ["", "שניה", "שתי שניות", "", "\u{200f}{0} שניות", "{0} שניות"]], [["", "שבוע {0}", "שבועיים", "", "{0} שבועות", "{0} שבועות"], ["", "יום", "יומיים", "", "{0} ימ׳", "{0} ימ׳"], ["", "שעה", "שעתיים", "", "{0} שע׳", "{0} שע׳"], ["", "דקה", "שתי דק׳", "", "{0} דק׳", "{0} דק׳"], ["", "שנ׳", "שתי שנ׳", "", "{0} שנ׳", "{0} שנ׳"]], [["", "ש′ {0}", "{0} ש′", "", "{0} ש′", "{0} ש′"], ["", "י׳", "{0} י׳", "", "{0} י׳", "{0} י׳"], ["", "שעה {0}", "{0} שע׳", "", "{0} שע׳", "{0} שע׳"], ["", "דקה", "שתי דק׳", "", "{0} דק׳", "{0} דק׳"], ["", "שניה", "שתי שנ׳", "", "{0} שנ׳", "{0} שנ׳"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} ו-{1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_61: _DurationPatterns = _DurationPatterns { units: [[["", "{0} सप\u{94d}ताह", "", "", "", "{0} सप\u{94d}ताह"], ["", "{0} दिन", "", "", "", "{0} दिन"], ["", "{0} घ\u{902}टा", "", "", "", "{0} घ\u{902}ट\u{947}"], ["", "{0} मिनट", "", "", "", "{0} मिनट"], ["", "{0} स\u{947}क\u{902}ड", "", "", "", "{0} स\u{947}क\u{902}ड"]], [["", "{0} सप\u{94d}ताह", "", "", "", "{0} सप\u{94d}ताह"], ["", "{0} दिन", "", "", "", "{0} दिन"], ["", "{0} घ\u{902}॰", "", "", "", "{0} घ\u{902}॰"], ["", "{0} मि॰", "", "", "", "{0} मि॰"], ["", "{0} स\u{947}॰", "", "", "", "{0} स\u{947}॰"]], [["", "{0} सप\u{94d}ताह", "", "", "", "{0} सप\u{94d}ताह"], ["", "{0} दि", "", "", "", "{0} दि"], ["", "{0}घ\u{902}॰", "", "", "", "{0} घ\u{902}"], ["", "{0} मि", "", "", "", "{0} मि"], ["", "{0} स\u{947}", "", "", "", "{0} स\u{947}"]]], lists: [["{0} और {1}", "{0}, {1}", "{0}, {1}", "{0}, और {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0}, {1}", "{0}, {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_62: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weeks"], ["", "{0} din", "", "", "", "{0} din"], ["", "{0} ghanta", "", "", "", "{0} ghante"], ["", "{0} minute", "", "", "", "{0} minutes"], ["", "{0} second", "", "", "", "{0} seconds"]], [["", "{0} wk", "", "", "", "{0} wks"], ["", "{0} din", "", "", "", "{0} din"], ["", "{0} hr", "", "", "", "{0} hrs"], ["", "{0} min", "", "", "", "{0} mins"], ["", "{0} sec", "", "", "", "{0} secs"]], [["", "{0}w", "", "", "", "{0}w"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} aur {1}", "{0}, {1}", "{0}, {1}", "{0}, aur {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_63: _DurationPatterns = _DurationPatterns { units: [[["", "{0} tjedan", "", "{0} tjedna", "", "{0} tjedana"], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} sat", "", "{0} sata", "", "{0} sati"], ["", "{0} minuta", "", "{0} minute", "", "{0} minuta"], ["", "{0} sekunda", "", "{0} sekunde", "", "{0} sekundi"]], [["", "{0} tj.", "", "{0} tj.", "", "{0} tj."], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} h", "", "{0} h", "", "{0} h"], ["", "{0} min", "", "{0} min", "", "{0} min"], ["", "{0} s", "", "{0} s", "", "{0} s"]], [["", "{0} tj.", "", "{0} tj.", "", "{0} tj."], ["", "{0} d.", "", "{0} d.", "", "{0} d."], ["", "{0} h", "", "{0} h", "", "{0} h"], ["", "{0} m", "", "{0} m", "", "{0} m"], ["", "{0} s", "", "{0} s", "", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_64: _DurationPatterns = _DurationPatterns { units: [[["", "{0} tydźeń", "{0} tydźenjej", "{0} tydźenje", "", "{0} tydźenjow"], ["", "{0} dźeń", "{0} dnjej", "{0} dny", "", "{0} dnjow"], ["", "{0} hodźina", "{0} hodźinje", "{0} hodźiny", "", "{0} hodźinow"], ["", "{0} minuta", "{0} minuće", "{0} minuty", "", "{0} minutow"], ["", "{0} sekunda", "{0} sekundźe", "{0} sekundy", "", "{0} sekundow"]], [["", "{0} tydź.", "{0} tydź.", "{0} tydź.", "", "{0} tydź."], ["", "{0} dź.", "{0} dn.", "{0} dn.", "", "{0} dn."], ["", "{0} hodź.", "{0} hodź.", "{0} hodź.", "", "{0} hodź."], ["", "{0} min.", "{0} min.", "{0} min.", "", "{0} min."], ["", "{0} sek.", "{0} sek.", "{0} sek.", "", "{0} sek."]], [["", "{0} t.", "{0} t.", "{0} t.", "", "{0} t."], ["", "{0} d", "{0} d", "{0} d", "", "{0} d"], ["", "{0} h", "{0} h", "{0} h", "", "{0} h"], ["", "{0} min", "{0} min", "{0} min", "", "{0} min"], ["", "{0} s", "{0} s", "{0} s", "", "{0} s"]]], lists: [["{0} a {1}", "{0}, {1}", "{0}, {1}", "{0} a {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} a {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_65: _DurationPatterns = _DurationPatterns { units: [[["", "{0} hét", "", "", "", "{0} hét"], ["", "{0} nap", "", "", "", "{0} nap"], ["", "{0} óra", "", "", "", "{0} óra"], ["", "{0} perc", "", "", "", "{0} perc"], ["", "{0} másodperc", "", "", "", "{0} másodperc"]], [["", "{0} hét", "", "", "", "{0} hét"], ["", "{0} nap", "", "", "", "{0} nap"], ["", "{0} ó", "", "", "", "{0} ó"], ["", "{0} p", "", "", "", "{0} p"], ["", "{0} mp", "", "", "", "{0} mp"]], [["", "{0} hét", "", "", "", "{0} hét"], ["", "{0} nap", "", "", "", "{0} nap"], ["", "{0} ó", "", "", "", "{0} ó"], ["", "{0} p", "", "", "", "{0} p"], ["", "{0} mp", "", "", "", "{0} mp"]]], lists: [["{0} és {1}", "{0}, {1}", "{0}, {1}", "{0} és {1}"], ["{0} és {1}", "{0}, {1}", "{0}, {1}", "{0} és {1}"], ["{0} és {1}", "{0}, {1}", "{0}, {1}", "{0} és {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_66: _DurationPatterns = _DurationPatterns { units: [[["", "{0} շաբաթ", "", "", "", "{0} շաբաթ"], ["", "{0} օր", "", "", "", "{0} օր"], ["", "{0} ժամ", "", "", "", "{0} ժամ"], ["", "{0} րոպե", "", "", "", "{0} րոպե"], ["", "{0} վայրկյան", "", "", "", "{0} վայրկյան"]], [["", "{0} շաբ", "", "", "", "{0} շաբ"], ["", "{0} օր", "", "", "", "{0} օր"], ["", "{0} ժ", "", "", "", "{0} ժ"], ["", "{0} ր", "", "", "", "{0} ր"], ["", "{0} վրկ", "", "", "", "{0} վրկ"]], [["", "{0} շ", "", "", "", "{0} շ"], ["", "{0} օ", "", "", "", "{0} օ"], ["", "{0} ժ", "", "", "", "{0} ժ"], ["", "{0} ր", "", "", "", "{0} ր"], ["", "{0} վ", "", "", "", "{0} վ"]]], lists: [["{0} և {1}", "{0}, {1}", "{0}, {1}", "{0} և {1}"], ["{0} և {1}", "{0} {1}", "{0} {1}", "{0} և {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_67: _DurationPatterns = _DurationPatterns { units: [[["", "{0} septimana", "", "", "", "{0} septimanas"], ["", "{0} die", "", "", "", "{0} dies"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuta", "", "", "", "{0} minutas"], ["", "{0} secunda", "", "", "", "{0} secundas"]], [["", "{0} sept", "", "", "", "{0} sept"], ["", "{0} die", "", "", "", "{0} dies"], ["", "{0} hr", "", "", "", "{0} hr"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sec", "", "", "", "{0} sec"]], [["", "{0}sept", "", "", "", "{0}sept"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["hh:mm:ss", "mm:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_68: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} minggu"], ["", "", "", "", "", "{0} hari"], ["", "", "", "", "", "{0} jam"], ["", "", "", "", "", "{0} menit"], ["", "", "", "", "", "{0} detik"]], [["", "", "", "", "", "{0} mgg"], ["", "", "", "", "", "{0} hr"], ["", "", "", "", "", "{0} j"], ["", "", "", "", "", "{0} mnt"], ["", "", "", "", "", "{0} dtk"]], [["", "", "", "", "", "{0} mgg"], ["", "", "", "", "", "{0} hr"], ["", "", "", "", "", "{0} j"], ["", "", "", "", "", "{0} mnt"], ["", "", "", "", "", "{0} dtk"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_69: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0} na {1}", "{0}, {1}", "{0}, {1}", "{0}, na {1}"], ["{0} na {1}", "{0}, {1}", "{0}, {1}", "{0}, na {1}"], ["{0} na {1}", "{0}, {1}", "{0}, {1}", "{0}, na {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_70: _DurationPatterns = _DurationPatterns { units: [[["", "{0} vika", "", "", "", "{0} vikur"], ["", "{0} dagur", "", "", "", "{0} dagar"], ["", "{0} klukkustund", "", "", "", "{0} klukkustundir"], ["", "{0} mínúta", "", "", "", "{0} mínútur"], ["", "{0} sekúnda", "", "", "", "{0} sekúndur"]], [["", "{0} vika", "", "", "", "{0} vikur"], ["", "{0} dagur", "", "", "", "{0} dagar"], ["", "{0} klst.", "", "", "", "{0} klst."], ["", "{0} mín.", "", "", "", "{0} mín."], ["", "{0} sek.", "", "", "", "{0} sek."]], [["", "{0} v.", "", "", "", "{0} v."], ["", "{0} d.", "", "", "", "{0} d."], ["", "{0} klst.", "", "", "", "{0} klst."], ["", "{0} mín.", "", "", "", "{0} mín."], ["", "{0} sek.", "", "", "", "{0} sek."]]], lists: [["{0} og {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"], ["{0} og {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"], ["{0} og {1}", "{0} {1}", "{0} {1}", "{0} og {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_71: _DurationPatterns = _DurationPatterns { units: [[["", "{0} settimana", "", "", "", "{0} settimane"], ["", "{0} giorno", "", "", "", "{0} giorni"], ["", "{0} ora", "", "", "", "{0} ore"], ["", "{0} minuto", "", "", "", "{0} minuti"], ["", "{0} secondo", "", "", "", "{0} secondi"]], [["", "{0} sett.", "", "", "", "{0} sett."], ["", "{0} giorno", "", "", "", "{0} giorni"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}sett.", "", "", "", "{0}sett."], ["", "{0}g", "", "", "", "{0}gg"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}min", "", "", "", "{0}min"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_72: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} 週間"], ["", "", "", "", "", "{0} 日"], ["", "", "", "", "", "{0} 時間"], ["", "", "", "", "", "{0} 分"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0} 週間"], ["", "", "", "", "", "{0} 日"], ["", "", "", "", "", "{0} 時間"], ["", "", "", "", "", "{0} 分"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0}w"], ["", "", "", "", "", "{0}d"], ["", "", "", "", "", "{0}h"], ["", "", "", "", "", "{0}m"], ["", "", "", "", "", "{0}s"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_73: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "{0} lɛ\u{301}Ꞌ", "", "", "", "{0} lɛ\u{301}Ꞌ"], ["", "{0} háwa", "", "", "", "{0} háwa"], ["", "{0} minút", "", "", "", "{0} minút"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_74: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} peken"], ["", "", "", "", "", "{0} dina"], ["", "", "", "", "", "{0} jam"], ["", "", "", "", "", "{0} menit"], ["", "", "", "", "", "{0} detik"]], [["", "", "", "", "", "{0} peken"], ["", "", "", "", "", "{0} dina"], ["", "", "", "", "", "{0} jam"], ["", "", "", "", "", "{0} mnt"], ["", "", "", "", "", "{0} dtk"]], [["", "", "", "", "", "{0} peken"], ["", "", "", "", "", "{0}d"], ["", "", "", "", "", "{0}j"], ["", "", "", "", "", "{0} mnt"], ["", "", "", "", "", "{0} dtk"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_75: _DurationPatterns = _DurationPatterns { units: [[["", "{0} კვირა", "", "", "", "{0} კვირა"], ["", "{0} დღე", "", "", "", "{0} დღე"], ["", "{0} საათი", "", "", "", "{0} საათი"], ["", "{0} წუთი", "", "", "", "{0} წუთი"], ["", "{0} წამი", "", "", "", "{0} წამი"]], [["", "{0} კვრ", "", "", "", "{0} კვრ"], ["", "{0} დღე", "", "", "", "{0} დღე"], ["", "{0} სთ", "", "", "", "{0} სთ"], ["", "{0} წთ", "", "", "", "{0} წთ"], ["", "{0} წმ", "", "", "", "{0} წმ"]], [["", "{0} კვრ", "", "", "", "{0} კვრ"], ["", "{0} დღე", "", "", "", "{0} დღე"], ["", "{0}სთ", "", "", "", "{0}სთ"], ["", "{0}წთ", "", "", "", "{0}წთ"], ["", "{0}წმ", "", "", "", "{0}წმ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_76: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} simana"], ["", "", "", "", "", "{0} dia"], ["", "", "", "", "", "{0} ora"], ["", "", "", "", "", "{0} minutu"], ["", "", "", "", "", "{0} sigundu"]], [["", "", "", "", "", "{0} sim."], ["", "", "", "", "", "{0} dia"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min."], ["", "", "", "", "", "{0} sig."]], [["", "", "", "", "", "{0} sim."], ["", "", "", "", "", "{0} dia"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min."], ["", "", "", "", "", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_77: _DurationPatterns = _DurationPatterns { units: [[["", "{0} simỹnỹ", "", "", "", "{0} simỹnỹ ag"], ["", "kurã {0}", "", "", "", "kurã {0}"], ["", "óra {0}", "", "", "", "óra ag {0}"], ["", "mĩnũtu {0}", "", "", "", "mĩnũtu {0}"], ["", "sigũnu {0}", "", "", "", "sigũnu {0}"]], [["", "{0} sim.", "", "", "", "{0} sim."], ["", "{0} kurã", "", "", "", "{0} kurã ag"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sig", "", "", "", "{0} sig"]], [["", "{0} sim.", "", "", "", "{0} sim. Ag"], ["", "{0} kurã", "", "", "", "{0} kurã ag"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} kar {1}", "{0}, {1}", "{0}, {1}", "{0} kar {1}"], ["{0} kar {1}", "{0}, {1}", "{0}, {1}", "{0} kar {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_78: _DurationPatterns = _DurationPatterns { units: [[["", "{0} апта", "", "", "", "{0} апта"], ["", "{0} күн", "", "", "", "{0} күн"], ["", "{0} сағат", "", "", "", "{0} сағат"], ["", "{0} минут", "", "", "", "{0} минут"], ["", "{0} секунд", "", "", "", "{0} секунд"]], [["", "{0} ап.", "", "", "", "{0} ап."], ["", "{0} күн", "", "", "", "{0} күн"], ["", "{0} сағ", "", "", "", "{0} сағ"], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} с", "", "", "", "{0} с"]], [["", "{0} ап.", "", "", "", "{0} ап."], ["", "{0} к.", "", "", "", "{0} к."], ["", "{0} сағ", "", "", "", "{0} сағ"], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} с", "", "", "", "{0} с"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_79: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} សប\u{17d2}ដាហ\u{17cd}"], ["", "", "", "", "", "{0} ថ\u{17d2}ងៃ"], ["", "", "", "", "", "{0} ម\u{17c9}ោង"], ["", "", "", "", "", "{0} នាទ\u{17b8}"], ["", "", "", "", "", "{0} វ\u{17b7}នាទ\u{17b8}"]], [["", "", "", "", "", "{0} សប\u{17d2}ដាហ\u{17cd}"], ["", "", "", "", "", "{0} ថ\u{17d2}ងៃ"], ["", "", "", "", "", "{0} ម\u{17c9}ោង"], ["", "", "", "", "", "{0}\u{a0}នាទ\u{17b8}"], ["", "", "", "", "", "{0} វ\u{17b7}នាទ\u{17b8}"]], [["", "", "", "", "", "{0} សប\u{17d2}ដាហ\u{17cd}"], ["", "", "", "", "", "{0} ថ\u{17d2}ងៃ"], ["", "", "", "", "", "{0} ម\u{17c9}ោង"], ["", "", "", "", "", "{0}\u{a0}នាទ\u{17b8}"], ["", "", "", "", "", "{0} វ\u{17b7}នាទ\u{17b8}"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_80: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ವಾರವು", "", "", "", "{0} ವಾರಗಳು"], ["", "{0} ದ\u{cbf}ನವು", "", "", "", "{0} ದ\u{cbf}ನಗಳು"], ["", "{0} ಗಂಟ\u{cc6}ಯು", "", "", "", "{0} ಗಂಟ\u{cc6}ಗಳು"], ["", "{0} ನ\u{cbf}ಮ\u{cbf}ಷವು", "", "", "", "{0} ನ\u{cbf}ಮ\u{cbf}ಷಗಳು"], ["", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂಡ\u{ccd}", "", "", "", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂಡುಗಳು"]], [["", "{0} ವಾರ", "", "", "", "{0} ವಾರಗಳು"], ["", "{0} ದ\u{cbf}ನ", "", "", "", "{0} ದ\u{cbf}ನಗಳು"], ["", "{0} ಗಂ.", "", "", "", "{0} ಗಂ."], ["", "{0} ನ\u{cbf}ಮ\u{cbf}", "", "", "", "{0} ನ\u{cbf}ಮ\u{cbf}"], ["", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂ", "", "", "", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂ"]], [["", "{0}ವಾ", "", "", "", "{0}ವಾ"], ["", "{0}ದ\u{cbf}", "", "", "", "{0}ದ\u{cbf}"], ["", "{0}ಗಂ.", "", "", "", "{0}ಗಂ."], ["", "{0}ನ\u{cbf}ಮ\u{cbf}", "", "", "", "{0}ನ\u{cbf}ಮ\u{cbf}"], ["", "{0}ಸ\u{cc6}ಕ\u{cc6}ಂ", "", "", "", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_81: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0}주"], ["", "", "", "", "", "{0}일"], ["", "", "", "", "", "{0}시간"], ["", "", "", "", "", "{0}분"], ["", "", "", "", "", "{0}초"]], [["", "", "", "", "", "{0}주"], ["", "", "", "", "", "{0}일"], ["", "", "", "", "", "{0}시간"], ["", "", "", "", "", "{0}분"], ["", "", "", "", "", "{0}초"]], [["", "", "", "", "", "{0}주"], ["", "", "", "", "", "{0}일"], ["", "", "", "", "", "{0}시간"], ["", "", "", "", "", "{0}분"], ["", "", "", "", "", "{0}초"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_82: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} सप\u{94d}तक"], ["", "", "", "", "", "{0} दीस"], ["", "", "", "", "", "{0} वरा\u{902}"], ["", "", "", "", "", "{0} मिण\u{94d}टा\u{902}"], ["", "", "", "", "", "{0} स\u{947}क\u{902}दा\u{902}नी"]], [["", "", "", "", "", "{0} सप\u{94d}तक"], ["", "", "", "", "", "{0} दीस"], ["", "", "", "", "", "{0} वर"], ["", "", "", "", "", "{0} मिनीट"], ["", "", "", "", "", "{0} स\u{947}क\u{902}द"]], [["", "", "", "", "", "{0}स"], ["", "", "", "", "", "{0}दी"], ["", "", "", "", "", "{0}व"], ["", "", "", "", "", "{0} मि"], ["", "", "", "", "", "{0}स\u{947}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_83: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ہ\u{64e}فتہ\u{655}", "", "", "", "{0} ہ\u{64e}فتہ\u{655}"], ["", "{0} دۄہ", "", "", "", "{0} دۄہ"], ["", "{0} گ\u{64e}نٹہ\u{655}", "", "", "", "{0} گٲنٹہ\u{655}"], ["", "{0} م\u{650}ن\u{64e}ٹ", "", "", "", "{0} م\u{650}ن\u{64e}ٹ"], ["", "{0} سیک\u{64e}نڈ", "", "", "", "{0} سیک\u{64e}نڈ"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_84: _DurationPatterns = _DurationPatterns { units: [[["{0} Woche", "{0} Woche", "", "", "", "{0} Woche"], ["{0} Dääsch", "{0} Dääsch", "", "", "", "{0} Dääsch"], ["{0} Schtunde", "{0} Schtunde", "", "", "", "{0} Schtunde"], ["{0} Menutte", "{0} Menutte", "", "", "", "{0} Menutte"], ["{0} Sekunde", "{0} Sekunde", "", "", "", "{0} Sekunde"]], [["", "", "", "", "", "{0} w"], ["{0} d", "{0} d", "", "", "", "{0} d"], ["{0} h", "{0} h", "", "", "", "{0} h"], ["{0} min", "{0} min", "", "", "", "{0} min"], ["{0} s", "{0} s", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["{0}d", "{0}d", "", "", "", "{0}d"], ["{0}h", "{0}h", "", "", "", "{0}h"], ["{0}m", "{0}m", "", "", "", "{0}m"], ["{0}s", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_85: _DurationPatterns = _DurationPatterns { units: [[["", "{0} hefte", "", "", "", "{0} hefte"], ["", "{0} roj", "", "", "", "{0} roj"], ["", "{0} saet", "", "", "", "{0} saet"], ["", "{0} deqîqe", "", "", "", "{0} deqîqe"], ["", "{0} saniye", "", "", "", "{0} saniye"]], [["", "{0} hf", "", "", "", "{0} hf"], ["", "{0} roj", "", "", "", "{0} roj"], ["", "{0} st", "", "", "", "{0} st"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}hf", "", "", "", "{0}hf"], ["", "{0}r", "", "", "", "{0}r"], ["", "{0}st", "", "", "", "{0}st"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} û {1}", "{0}, {1}", "{0}, {1}", "{0} û {1}"], ["{0} û {1}", "{0}, {1}", "{0}, {1}", "{0} û {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_86: _DurationPatterns = _DurationPatterns { units: [[["", "{0} апта", "", "", "", "{0} апта"], ["", "{0} күн", "", "", "", "{0} күн"], ["", "{0} саат", "", "", "", "{0} саат"], ["", "{0} мүнөт", "", "", "", "{0} мүнөт"], ["", "{0} секунд", "", "", "", "{0} секунд"]], [["", "{0} апт", "", "", "", "{0} апт"], ["", "{0} күн", "", "", "", "{0} күн"], ["", "{0} ст", "", "", "", "{0} ст"], ["", "{0} мүн", "", "", "", "{0} мүн"], ["", "{0} сек", "", "", "", "{0} сек"]], [["", "{0} ап", "", "", "", "{0} ап"], ["", "{0} кн", "", "", "", "{0} кн"], ["", "{0} ст", "", "", "", "{0} ст"], ["", "{0} мүн", "", "", "", "{0} мүн"], ["", "{0} сек", "", "", "", "{0} сек"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_87: _DurationPatterns = _DurationPatterns { units: [[["", "{0} Woch", "", "", "", "{0} Wochen"], ["", "{0} Dag", "", "", "", "{0} Deeg"], ["", "{0} Stonn", "", "", "", "{0} Stonnen"], ["", "{0} Minutt", "", "", "", "{0} Minutten"], ["", "{0} Sekonn", "", "", "", "{0} Sekonnen"]], [["", "{0} W", "", "", "", "{0} W"], ["", "{0} D", "", "", "", "{0} D"], ["", "{0} St.", "", "", "", "{0} St."], ["", "{0} Min.", "", "", "", "{0} Min."], ["", "{0} Sek.", "", "", "", "{0} Sek."]], [["", "{0} W", "", "", "", "{0} W"], ["", "{0} D", "", "", "", "{0} D"], ["", "{0} st", "", "", "", "{0} st"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_88: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "okó {0}"], ["", "", "", "", "", "{0}-čháŋ"], ["", "", "", "", "", "Owápȟe {0}"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "Okpí {0}"]], [["", "", "", "", "", "okó {0}"], ["", "", "", "", "", "{0}-čháŋ"], ["", "", "", "", "", "Owápȟe {0}"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "Okpí {0}"]], [["", "", "", "", "", "okó {0}"], ["", "", "", "", "", "{0}-čháŋ"], ["", "", "", "", "", "Owápȟe {0}"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "Okpí {0}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_89: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} ອາທ\u{eb4}ດ"], ["", "", "", "", "", "{0} ມ\u{eb7}\u{ec9}"], ["", "", "", "", "", "{0} ຊ\u{ebb}\u{ec8}ວໂມງ"], ["", "", "", "", "", "{0} ນາທ\u{eb5}"], ["", "", "", "", "", "{0} ວ\u{eb4}ນາທ\u{eb5}"]], [["", "", "", "", "", "{0} ອທ."], ["", "", "", "", "", "{0} ມ\u{eb7}\u{ec9}"], ["", "", "", "", "", "{0} ຊມ"], ["", "", "", "", "", "{0} ນທ"], ["", "", "", "", "", "{0} ວ\u{eb4}"]], [["", "", "", "", "", "{0} ອທ."], ["", "", "", "", "", "{0} ມ."], ["", "", "", "", "", "{0} ຊມ"], ["", "", "", "", "", "{0} ນທ"], ["", "", "", "", "", "{0} ວ\u{eb4}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_90: _DurationPatterns = _DurationPatterns { units: [[["", "{0} savaitė", "", "{0} savaitės", "{0} savaitės", "{0} savaičių"], ["", "{0} diena", "", "{0} dienos", "{0} dienos", "{0} dienų"], ["", "{0} valanda", "", "{0} valandos", "{0} valandos", "{0} valandų"], ["", "{0} minutė", "", "{0} minutės", "{0} minutės", "{0} minučių"], ["", "{0} sekundė", "", "{0} sekundės", "{0} sekundės", "{0} sekundžių"]], [["", "{0} sav.", "", "{0} sav.", "{0} sav.", "{0} sav."], ["", "{0} d.", "", "{0} d.", "{0} d.", "{0} d."], ["", "{0} val.", "", "{0} val.", "{0} val.", "{0} val."], ["", "{0} min.", "", "{0} min.", "{0} min.", "{0} min."], ["", "{0} sek.", "", "{0} sek.", "{0} sek.", "{0} sek."]], [["", "{0} sav.", "", "{0} sav.", "{0} sav.", "{0} sav."], ["", "{0} d.", "", "{0} d.", "{0} d.", "{0} d."], ["", "{0} h", "", "{0} h", "{0} h", "{0} h"], ["", "{0} min.", "", "{0} min.", "{0} min.", "{0} min."], ["", "{0} s", "", "{0} s", "{0} s", "{0} s"]]], lists: [["{0} ir {1}", "{0} {1}", "{0} {1}", "{0} ir {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["hh:mm:ss", "mm:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_91: _DurationPatterns = _DurationPatterns { units: [[["{0} nedēļu", "{0} nedēļa", "", "", "", "{0} nedēļas"], ["{0} dienu", "{0} diena", "", "", "", "{0} dienas"], ["{0} stundu", "{0} stunda", "", "", "", "{0} stundas"], ["{0} minūšu", "{0} minūte", "", "", "", "{0} minūtes"], ["{0} sekunžu", "{0} sekunde", "", "", "", "{0} sekundes"]], [["{0} ned.", "{0} ned.", "", "", "", "{0} ned."], ["{0} d.", "{0} d.", "", "", "", "{0} d."], ["{0} st.", "{0} st.", "", "", "", "{0} st."], ["{0} min", "{0} min", "", "", "", "{0} min"], ["{0} sek.", "{0} sek.", "", "", "", "{0} sek."]], [["{0} n.", "{0} n.", "", "", "", "{0} n."], ["{0} d.", "{0} d.", "", "", "", "{0} d."], ["{0} h", "{0} h", "", "", "", "{0} h"], ["{0} min", "{0} min", "", "", "", "{0} min"], ["{0} s", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} un {1}", "{0}, {1}", "{0}, {1}", "{0} un {1}"], ["{0} un {1}", "{0}, {1}", "{0}, {1}", "{0} un {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_92: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_93: _DurationPatterns = _DurationPatterns { units: [[["", "{0} седмица", "", "", "", "{0} седмици"], ["", "{0} ден", "", "", "", "{0} дена"], ["", "{0} час", "", "", "", "{0} часа"], ["", "{0} минута", "", "", "", "{0} минути"], ["", "{0} секунда", "", "", "", "{0} секунди"]], [["", "{0} сед.", "", "", "", "{0} сед."], ["", "{0} ден", "", "", "", "{0} дена"], ["", "{0} ч.", "", "", "", "{0} ч."], ["", "{0} мин.", "", "", "", "{0} мин."], ["", "{0} сек.", "", "", "", "{0} сек."]], [["", "{0} с.", "", "", "", "{0} с."], ["", "{0} д.", "", "", "", "{0} д."], ["", "{0} ч.", "", "", "", "{0} ч."], ["", "{0} м.", "", "", "", "{0} м."], ["", "{0} с.", "", "", "", "{0} с."]]], lists: [["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_94: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ആഴ\u{d4d}ച", "", "", "", "{0} ആഴ\u{d4d}ച"], ["", "{0} ദിവസം", "", "", "", "{0} ദിവസം"], ["", "{0} മണിക\u{d4d}ക\u{d42}ർ", "", "", "", "{0} മണിക\u{d4d}ക\u{d42}ർ"], ["", "{0} മിനിറ\u{d4d}റ\u{d4d}", "", "", "", "{0} മിനിറ\u{d4d}റ\u{d4d}"], ["", "{0} സെക\u{d4d}കൻഡ\u{d4d}", "", "", "", "{0} സെക\u{d4d}കൻഡ\u{d4d}"]], [["", "{0} ആ", "", "", "", "{0} ആ"], ["", "{0} ദിവസം\u{200c}", "", "", "", "{0} ദിവസം\u{200c}"], ["", "{0} മ", "", "", "", "{0} മ"], ["", "{0} മി.", "", "", "", "{0} മി."], ["", "{0} സെ.", "", "", "", "{0} സെ."]], [["", "{0} ആ", "", "", "", "{0} ആ"], ["", "{0} ദി", "", "", "", "{0} ദി"], ["", "{0} മ", "", "", "", "{0} മ"], ["", "{0} മി.", "", "", "", "{0} മി."], ["", "{0} സെ.", "", "", "", "{0} സെ."]]], lists: [["{0} ക\u{d42}ട\u{d3e}തെ {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_95: _DurationPatterns = _DurationPatterns { units: [[["", "{0} долоо хоног", "", "", "", "{0} долоо хоног"], ["", "{0} хоног", "", "", "", "{0} хоног"], ["", "{0} цаг", "", "", "", "{0} цаг"], ["", "{0} минут", "", "", "", "{0} минут"], ["", "{0} секунд", "", "", "", "{0} секунд"]], [["", "{0} д.х", "", "", "", "{0} д.х"], ["", "{0} хоног", "", "", "", "{0} хоног"], ["", "{0} цаг", "", "", "", "{0} цаг"], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} сек", "", "", "", "{0} сек"]], [["", "{0} д.х", "", "", "", "{0} д.х"], ["", "{0} хоног", "", "", "", "{0} хоног"], ["", "{0} ц", "", "", "", "{0} ц"], ["", "{0} мин", "", "", "", "{0} мин"], ["", "{0} сек", "", "", "", "{0} сек"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["hh:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_96: _DurationPatterns = _DurationPatterns { units: [[["", "{0} आठवडा", "", "", "", "{0} आठवड\u{947}"], ["", "{0} दिवस", "", "", "", "{0} दिवस"], ["", "{0} तास", "", "", "", "{0} तास"], ["", "{0} मिनिट", "", "", "", "{0} मिनिट\u{947}"], ["", "{0} स\u{947}क\u{902}द", "", "", "", "{0} स\u{947}क\u{902}द"]], [["", "{0} आ", "", "", "", "{0} आ"], ["", "{0} दिवस", "", "", "", "{0} दिवस"], ["", "{0} ता", "", "", "", "{0} ता"], ["", "{0} मिनि", "", "", "", "{0} मिनि"], ["", "{0} स\u{947}", "", "", "", "{0} स\u{947}"]], [["", "{0}आ", "", "", "", "{0}आ"], ["", "{0}दि", "", "", "", "{0}दि"], ["", "{0}ता", "", "", "", "{0}ता"], ["", "{0}मि", "", "", "", "{0}मि"], ["", "{0}स\u{947}", "", "", "", "{0}स\u{947}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_97: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} minggu"], ["", "", "", "", "", "{0} hari"], ["", "", "", "", "", "{0} jam"], ["", "", "", "", "", "{0} minit"], ["", "", "", "", "", "{0} saat"]], [["", "", "", "", "", "{0} mgu"], ["", "", "", "", "", "{0} hari"], ["", "", "", "", "", "{0} j"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} saat"]], [["", "", "", "", "", "{0} mgu"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} j"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} dan {1}", "{0}, {1}", "{0}, {1}", "{0} dan {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_98: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0} u {1}", "{0}, {1}", "{0}, {1}", "{0}, u {1}"], ["{0} u {1}", "{0}, {1}", "{0}, {1}", "{0}, u {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_99: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} ပတ\u{103a}"], ["", "", "", "", "", "{0} ရက\u{103a}"], ["", "", "", "", "", "{0} နာရ\u{102e}"], ["", "", "", "", "", "{0} မ\u{102d}နစ\u{103a}"], ["", "", "", "", "", "{0} စက\u{1039}ကန\u{1037}\u{103a}"]], [["", "", "", "", "", "{0} ပတ\u{103a}"], ["", "", "", "", "", "{0} ရက\u{103a}"], ["", "", "", "", "", "{0} နာရ\u{102e}"], ["", "", "", "", "", "{0} မ\u{102d}နစ\u{103a}"], ["", "", "", "", "", "{0} sec"]], [["", "", "", "", "", "{0} ပတ\u{103a}"], ["", "", "", "", "", "{0} ရက\u{103a}"], ["", "", "", "", "", "{0} နာရ\u{102e}"], ["", "", "", "", "", "{0} မ\u{102d}နစ\u{103a}"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}န\u{103e}င\u{1037}\u{103a}{1}", "{0}- {1}", "{0}- {1}", "{0}န\u{103e}င\u{1037}\u{103a} {1}"], ["{0}န\u{103e}င\u{1037}\u{103a} {1}", "{0}- {1}", "{0}- {1}", "{0}န\u{103e}င\u{1037}\u{103a} {1}"], ["{0}န\u{103e}င\u{1037}\u{103a} {1}", "{0} {1}", "{0} {1}", "{0}န\u{103e}င\u{1037}\u{103a} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_100: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} هفته"], ["", "", "", "", "", "{0} روز"], ["", "", "", "", "", "{0} ساع\u{650}ت"], ["", "", "", "", "", "{0} دقیقه"], ["", "", "", "", "", "{0} ثانیه"]], [["", "", "", "", "", "{0} هفته"], ["", "", "", "", "", "{0} روز"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} د\u{64e}قه"], ["", "", "", "", "", "{0} ثانیه"]], [["", "", "", "", "", "{0} هفته"], ["", "", "", "", "", "{0} روز"], ["", "", "", "", "", "{0} ساع\u{650}ت"], ["", "", "", "", "", "{0} د\u{64e}قه"], ["", "", "", "", "", "{0} ثانیه"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_101: _DurationPatterns = _DurationPatterns { units: [[["", "{0} uke", "", "", "", "{0} uker"], ["", "{0} døgn", "", "", "", "{0} døgn"], ["", "{0} time", "", "", "", "{0} timer"], ["", "{0} minutt", "", "", "", "{0} minutter"], ["", "{0} sekund", "", "", "", "{0} sekunder"]], [["", "{0} u", "", "", "", "{0} u"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} t", "", "", "", "{0} t"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sek", "", "", "", "{0} sek"]], [["", "{0}u", "", "", "", "{0}u"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}t", "", "", "", "{0}t"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} og {1}", "{0}, {1}", "{0}, {1}", "{0} og {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_102: _DurationPatterns = _DurationPatterns { units: [[["", "{0} हप\u{94d}ता", "", "", "", "{0} हप\u{94d}ता"], ["", "{0} दिन", "", "", "", "{0} दिन"], ["", "{0} घण\u{94d}टा", "", "", "", "{0} घण\u{94d}टा"], ["", "{0} मिन\u{947}ट", "", "", "", "{0} मिन\u{947}ट"], ["", "{0} स\u{947}क\u{947}न\u{94d}ड", "", "", "", "{0} स\u{947}क\u{947}न\u{94d}ड"]], [["", "{0} हप\u{94d}ता", "", "", "", "{0} हप\u{94d}ता"], ["", "{0} दिन", "", "", "", "{0} दिन"], ["", "{0} घण\u{94d}टा", "", "", "", "{0} घण\u{94d}टा"], ["", "{0} मिन\u{947}ट", "", "", "", "{0} मिन\u{947}ट"], ["", "{0} स\u{947}क\u{947}न\u{94d}ड", "", "", "", "{0} स\u{947}क\u{947}न\u{94d}ड"]], [["", "{0} हप\u{94d}ता", "", "", "", "{0} हप\u{94d}ता"], ["", "{0} दिन", "", "", "", "{0} दिन"], ["", "{0} घण\u{94d}टा", "", "", "", "{0} घण\u{94d}टा"], ["", "{0} मिन\u{947}ट", "", "", "", "{0} मिन\u{947}ट"], ["", "{0} स\u{947}क\u{947}न\u{94d}ड", "", "", "", "{0} स\u{947}क\u{947}न\u{94d}ड"]]], lists: [["{0},{1}", "{0},{1}", "{0}, {1}", "{0},{1}"], ["{0} {1}", "{0},{1}", "{0}, {1}", "{0},{1}"], ["{0} {1}", "{0} {1}", "{0}{1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_103: _DurationPatterns = _DurationPatterns { units: [[["", "{0} week", "", "", "", "{0} weken"], ["", "{0} dag", "", "", "", "{0} dagen"], ["", "{0} uur", "", "", "", "{0} uur"], ["", "{0} minuut", "", "", "", "{0} minuten"], ["", "{0} seconde", "", "", "", "{0} seconden"]], [["", "{0} wk", "", "", "", "{0} wkn"], ["", "{0} dag", "", "", "", "{0} dagen"], ["", "{0} uur", "", "", "", "{0} uur"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sec", "", "", "", "{0} sec"]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} u", "", "", "", "{0} u"], ["", "{0} m", "", "", "", "{0} m"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} en {1}", "{0}, {1}", "{0}, {1}", "{0} en {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_104: _DurationPatterns = _DurationPatterns { units: [[["", "{0} veke", "", "", "", "{0} veker"], ["", "{0} døgn", "", "", "", "{0} døgn"], ["", "{0} time", "", "", "", "{0} timar"], ["", "{0} minutt", "", "", "", "{0} minutt"], ["", "{0} sekund", "", "", "", "{0} sekund"]], [["", "{0} v", "", "", "", "{0} v"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} t", "", "", "", "{0} t"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}v", "", "", "", "{0}v"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}t", "", "", "", "{0}t"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_105: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ସପ\u{b4d}ତ\u{b3e}ହ", "", "", "", "{0} ସପ\u{b4d}ତ\u{b3e}ହ"], ["", "{0} ଦ\u{b3f}ନ", "", "", "", "{0} ଦ\u{b3f}ନ"], ["", "{0} ଘଣ\u{b4d}ଟ\u{b3e}", "", "", "", "{0} ଘଣ\u{b4d}ଟ\u{b3e}"], ["", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}", "", "", "", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}"], ["", "{0} ସେକେଣ\u{b4d}ଡ", "", "", "", "{0} ସେକେଣ\u{b4d}ଡ"]], [["", "{0} ସପ\u{b4d}ତ\u{b3e}ହ", "", "", "", "{0} ସପ\u{b4d}ତ\u{b3e}ହ"], ["", "{0} ଦ\u{b3f}ନ", "", "", "", "{0} ଦ\u{b3f}ନ"], ["", "{0} ଘଣ\u{b4d}ଟ\u{b3e}", "", "", "", "{0} ଘଣ\u{b4d}ଟ\u{b3e}"], ["", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}", "", "", "", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}"], ["", "{0} ସେକେଣ\u{b4d}ଡ", "", "", "", "{0} ସେକେଣ\u{b4d}ଡ"]], [["", "{0}ସପ\u{b4d}", "", "", "", "{0} ସପ\u{b4d}"], ["", "{0}ଦ\u{b3f}ନ", "", "", "", "{0}ଦ\u{b3f}ନ"], ["", "{0}ଘଣ\u{b4d}ଟ\u{b3e}", "", "", "", "{0}ଘଣ\u{b4d}ଟ\u{b3e}"], ["", "{0}ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}", "", "", "", "{0}ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}"], ["", "{0}ସେକ\u{b4d}", "", "", "", "{0}ସେକ\u{b4d}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_106: _DurationPatterns = _DurationPatterns { units: [[["", "{0} къуыри", "", "", "", "{0} къуырийы"], ["", "{0} бон", "", "", "", "{0} боны"], ["", "{0} сахат", "", "", "", "{0} сахаты"], ["", "{0} минут", "", "", "", "{0} минуты"], ["", "{0} секунд", "", "", "", "{0} секунды"]], [["", "", "", "", "", "{0} w"], ["", "{0} бон", "", "", "", "{0} боны"], ["", "{0} с.", "", "", "", "{0} с."], ["", "{0} мин.", "", "", "", "{0} мин."], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "{0} бон", "", "", "", "{0} боны"], ["", "{0} с.", "", "", "", "{0} с."], ["", "{0} мин.", "", "", "", "{0} мин."], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_107: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ਹਫ\u{a3c}ਤਾ", "", "", "", "{0} ਹਫ\u{a3c}ਤ\u{a47}"], ["", "{0} ਦਿਨ", "", "", "", "{0} ਦਿਨ"], ["", "{0} ਘ\u{a70}ਟਾ", "", "", "", "{0} ਘ\u{a70}ਟ\u{a47}"], ["", "{0} ਮਿ\u{a70}ਟ", "", "", "", "{0} ਮਿ\u{a70}ਟ"], ["", "{0} ਸਕਿ\u{a70}ਟ", "", "", "", "{0} ਸਕਿ\u{a70}ਟ"]], [["", "{0} ਹਫ\u{a3c}ਤਾ", "", "", "", "{0} ਹਫ\u{a3c}ਤ\u{a47}"], ["", "{0} ਦਿਨ", "", "", "", "{0} ਦਿਨ"], ["", "{0} ਘ\u{a70}ਟਾ", "", "", "", "{0} ਘ\u{a70}ਟ\u{a47}"], ["", "{0} ਮਿ\u{a70}ਟ", "", "", "", "{0} ਮਿ\u{a70}ਟ"], ["", "{0} ਸਕਿ\u{a70}ਟ", "", "", "", "{0} ਸਕਿ\u{a70}ਟ"]], [["", "{0} ਹਫ\u{a3c}ਤਾ", "", "", "", "{0} ਹਫ\u{a3c}ਤ\u{a47}"], ["", "{0} ਦਿਨ", "", "", "", "{0} ਦਿਨ"], ["", "{0} ਘ\u{a70}ਟਾ", "", "", "", "{0} ਘ\u{a70}ਟ\u{a47}"], ["", "{0} ਮਿ\u{a70}ਟ", "", "", "", "{0} ਮਿ\u{a70}ਟ"], ["", "{0} ਸਕਿ\u{a70}ਟ", "", "", "", "{0} ਸਕਿ\u{a70}ਟ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_108: _DurationPatterns = _DurationPatterns { units: [[["", "{0} Wik", "", "", "", "{0} Wik"], ["", "{0} Dè", "", "", "", "{0} Dè"], ["", "{0} Áwa", "", "", "", "{0} Áwa"], ["", "{0} Mínit", "", "", "", "{0} Mínit"], ["", "{0} Sẹ\u{301}kọn", "", "", "", "{0} Sẹ\u{301}kọn"]], [["", "{0} Wik", "", "", "", "Wik {0}"], ["", "{0} dè", "", "", "", "{0} dez"], ["", "{0} Áwa", "", "", "", "{0} Áwa"], ["", "{0} Mínit", "", "", "", "{0} Mínit"], ["", "{0} Sẹ\u{301}kọn", "", "", "", "{0} Sẹ\u{301}kọn"]], [["", "{0}Wik", "", "", "", "{0}Wik"], ["", "{0}Dè", "", "", "", "{0}Dè"], ["", "{0}Áwa", "", "", "", "{0}Áwa"], ["", "{0}Mínit", "", "", "", "{0}Mínit"], ["", "{0}Sẹ\u{301}kọn", "", "", "", "{0}Sẹ\u{301}kọn"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_109: _DurationPatterns = _DurationPatterns { units: [[["", "{0} tydzień", "", "{0} tygodnie", "{0} tygodni", "{0} tygodnia"], ["", "{0} doba", "", "{0} doby", "{0} dób", "{0} doby"], ["", "{0} godzina", "", "{0} godziny", "{0} godzin", "{0} godziny"], ["", "{0} minuta", "", "{0} minuty", "{0} minut", "{0} minuty"], ["", "{0} sekunda", "", "{0} sekundy", "{0} sekund", "{0} sekundy"]], [["", "{0} tydz.", "", "{0} tyg.", "{0} tyg.", "{0} tyg."], ["", "{0} doba", "", "{0} doby", "{0} dób", "{0} doby"], ["", "{0} godz.", "", "{0} godz.", "{0} godz.", "{0} godz."], ["", "{0} min", "", "{0} min", "{0} min", "{0} min"], ["", "{0} sek.", "", "{0} sek.", "{0} sek.", "{0} sek."]], [["", "{0} tydz.", "", "{0} t.", "{0} tyg.", "{0} tyg."], ["", "{0} d.", "", "{0} d.", "{0} d.", "{0} d."], ["", "{0} h", "", "{0} h", "{0} h", "{0} h"], ["", "{0} min", "", "{0} min", "{0} min", "{0} min"], ["", "{0} s", "", "{0} s", "{0} s", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_110: _DurationPatterns = _DurationPatterns { units: [[["", "اونۍ", "", "", "", "{0} اونۍ"], ["", "{0} ورځ", "", "", "", "{0} ورځې"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} w", "", "", "", "{0} wks"], ["", "{0} ورځ", "", "", "", "{0} ورځې"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0} ورځ", "", "", "", "{0}d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0}m"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} او {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} و {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_111: _DurationPatterns = _DurationPatterns { units: [[["", "اونۍ", "", "", "", "{0} اونۍ"], ["", "{0} ورځ", "", "", "", "{0} ورځے"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} w", "", "", "", "{0} wks"], ["", "{0} ورځ", "", "", "", "{0} ورځے"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0} ورځ", "", "", "", "{0}d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0}m"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} او {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} و {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_112: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} dia", "", "", "", "{0} dias"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} dia", "", "", "", "{0} dias"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} dia", "", "", "", "{0} dias"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_113: _DurationPatterns = _DurationPatterns { units: [[["", "{0} semana", "", "", "", "{0} semanas"], ["", "{0} dia", "", "", "", "{0} dias"], ["", "{0} hora", "", "", "", "{0} horas"], ["", "{0} minuto", "", "", "", "{0} minutos"], ["", "{0} segundo", "", "", "", "{0} segundos"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} dia", "", "", "", "{0} dias"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} sem.", "", "", "", "{0} sem."], ["", "{0} dia", "", "", "", "{0} dias"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_114: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} semana"], ["", "", "", "", "", "{0} punchaw"], ["", "", "", "", "", "{0} hora"], ["", "", "", "", "", "{0} minuto"], ["", "", "", "", "", "{0} segundo"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} s"], ["", "", "", "", "", "{0} p"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_115: _DurationPatterns = _DurationPatterns { units: [[["", "{0} emna", "", "", "", "{0} emnas"], ["", "{0} di", "", "", "", "{0} dis"], ["", "{0} ura", "", "", "", "{0} uras"], ["", "{0} minuta", "", "", "", "{0} minutas"], ["", "{0} secunda", "", "", "", "{0} secundas"]], [["", "{0} emna", "", "", "", "{0} emnas"], ["", "{0} di", "", "", "", "{0} dis"], ["", "{0} ura", "", "", "", "{0} uras"], ["", "{0} min.", "", "", "", "{0} mins."], ["", "{0} sec.", "", "", "", "{0} secs."]], [["", "{0} emnas", "", "", "", "{0} emnas"], ["", "{0} dis", "", "", "", "{0} dis"], ["", "{0} uras", "", "", "", "{0} uras"], ["", "{0} mins.", "", "", "", "{0} mins."], ["", "{0} secs.", "", "", "", "{0} secs."]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_116: _DurationPatterns = _DurationPatterns { units: [[["", "{0} săptămână", "", "{0} săptămâni", "", "{0} de săptămâni"], ["", "{0} zi", "", "{0} zile", "", "{0} de zile"], ["", "{0} oră", "", "{0} ore", "", "{0} de ore"], ["", "{0} minut", "", "{0} minute", "", "{0} de minute"], ["", "{0} secundă", "", "{0} secunde", "", "{0} de secunde"]], [["", "{0} săpt.", "", "{0} săpt.", "", "{0} săpt."], ["", "{0} zi", "", "{0} zile", "", "{0} zile"], ["", "{0} oră", "", "{0} ore", "", "{0} ore"], ["", "{0} min.", "", "{0} min.", "", "{0} min."], ["", "{0} s", "", "{0} s", "", "{0} s"]], [["", "{0} săpt.", "", "{0} săpt.", "", "{0} săpt."], ["", "{0} z", "", "{0} z", "", "{0} z"], ["", "{0} h", "", "{0} h", "", "{0} h"], ["", "{0} m", "", "{0} m", "", "{0} m"], ["", "{0} s", "", "{0} s", "", "{0} s"]]], lists: [["{0} și {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_117: _DurationPatterns = _DurationPatterns { units: [[["", "{0} săptămână", "", "{0} săptămâni", "", "{0} de săptămâni"], ["", "{0} zi", "", "{0} zile", "", "{0} de zile"], ["", "{0} oră", "", "{0} ore", "", "{0} de ore"], ["", "{0} minut", "", "{0} minute", "", "{0} de minute"], ["", "{0} secundă", "", "{0} secunde", "", "{0} de secunde"]], [["", "{0} săpt.", "", "{0} săpt.", "", "{0} săpt."], ["", "{0} zi", "", "{0} zile", "", "{0} zile"], ["", "{0} oră", "", "{0} ore", "", "{0} ore"], ["", "{0} min.", "", "{0} min.", "", "{0} min."], ["", "{0} s", "", "{0} s", "", "{0} s"]], [["", "{0} săpt.", "", "{0} săpt.", "", "{0} săpt."], ["", "{0} zi", "", "{0} zile", "", "{0} zile"], ["", "{0} h", "", "{0} h", "", "{0} h"], ["", "{0} min.", "", "{0} min.", "", "{0} min."], ["", "{0} s", "", "{0} s", "", "{0} s"]]], lists: [["{0} și {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_118: _DurationPatterns = _DurationPatterns { units: [[["", "{0} неделя", "", "{0} недели", "{0} недель", "{0} недели"], ["", "{0} день", "", "{0} дня", "{0} дней", "{0} дня"], ["", "{0} час", "", "{0} часа", "{0} часов", "{0} часа"], ["", "{0} минута", "", "{0} минуты", "{0} минут", "{0} минуты"], ["", "{0} секунда", "", "{0} секунды", "{0} секунд", "{0} секунды"]], [["", "{0} нед.", "", "{0} нед.", "{0} нед.", "{0} нед."], ["", "{0} дн.", "", "{0} дн.", "{0} дн.", "{0} дн."], ["", "{0} ч", "", "{0} ч", "{0} ч", "{0} ч"], ["", "{0} мин", "", "{0} мин", "{0} мин", "{0} мин"], ["", "{0} с", "", "{0} с", "{0} с", "{0} с"]], [["", "{0} н.", "", "{0} н.", "{0} н.", "{0} н."], ["", "{0} д.", "", "{0} д.", "{0} д.", "{0} д."], ["", "{0} ч", "", "{0} ч", "{0} ч", "{0} ч"], ["", "{0} мин", "", "{0} мин", "{0} мин", "{0} мин"], ["", "{0} с", "", "{0} с", "{0} с", "{0} с"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_119: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} нэдиэлэ"], ["", "", "", "", "", "{0} күн"], ["", "", "", "", "", "{0} чаас"], ["", "", "", "", "", "{0} мүнүүтэ"], ["", "", "", "", "", "{0} сөкүүндэ"]], [["", "", "", "", "", "{0} нэд."], ["", "", "", "", "", "{0} күн"], ["", "", "", "", "", "{0} ч"], ["", "", "", "", "", "{0} мүн"], ["", "", "", "", "", "{0} сөк"]], [["", "", "", "", "", "{0} н."], ["", "", "", "", "", "{0} к."], ["", "", "", "", "", "{0} ч"], ["", "", "", "", "", "{0} мүн"], ["", "", "", "", "", "{0} с"]]], lists: [["{0} уонна {1}", "{0}, {1}", "{0}, {1}", "{0} уонна {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_120: _DurationPatterns = _DurationPatterns { units: [[["", "{0} chida", "", "", "", "{0} chidas"], ["", "{0} die", "", "", "", "{0} dies"], ["", "{0} ora", "", "", "", "{0} oras"], ["", "{0} minutu", "", "", "", "{0} minutos"], ["", "{0} segundu", "", "", "", "{0} segundos"]], [["", "{0} chida", "", "", "", "{0} chida"], ["", "{0} die", "", "", "", "{0} dies"], ["", "{0} ora", "", "", "", "{0} oras"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} seg", "", "", "", "{0} seg"]], [["", "{0}ch.", "", "", "", "{0}ch."], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}o", "", "", "", "{0}o"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_121: _DurationPatterns = _DurationPatterns { units: [[["", "{0} هفتا", "", "", "", "{0} هفتا"], ["", "{0} ڏينهن", "", "", "", "{0} ڏينهن"], ["", "{0} ڪلاڪ", "", "", "", "{0} ڪلاڪ"], ["", "{0} منٽ", "", "", "", "{0} منٽ"], ["", "{0} في سيڪنڊ", "", "", "", "{0} سيڪنڊ"]], [["", "{0} هفتا", "", "", "", "{0} هفتا"], ["", "{0} ڏينهن", "", "", "", "{0} ڏينهن"], ["", "{0} ڪلاڪ", "", "", "", "{0} ڪلاڪ"], ["", "{0} منٽ", "", "", "", "{0} منٽ"], ["", "{0} سيڪنڊ", "", "", "", "{0} سيڪنڊ"]], [["", "{0} هفتا", "", "", "", "{0} هفتي"], ["", "{0} ڏينهن", "", "", "", "{0} ڏينهن"], ["", "{0} ڪلاڪ", "", "", "", "{0} ڪلاڪ"], ["", "{0} منٽ", "", "", "", "{0} منٽ"], ["", "{0} s", "", "", "", "{0} سيڪنڊ"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_122: _DurationPatterns = _DurationPatterns { units: [[["", "{0} váhku", "{0} váhkku", "", "", "{0} váhkku"], ["", "{0} jándor", "{0} jándora", "", "", "{0} jándora"], ["", "{0} diibmu", "{0} diimmur", "", "", "{0} diibmur"], ["", "{0} minuhta", "{0} minuhtta", "", "", "{0} minuhtta"], ["", "{0} sekunda", "{0} sekundda", "", "", "{0} sekundda"]], [["", "{0} v", "{0} v", "", "", "{0} v"], ["", "{0} d", "{0} d", "", "", "{0} d"], ["", "{0} h", "{0} h", "", "", "{0} h"], ["", "{0} min", "{0} min", "", "", "{0} min"], ["", "{0} s", "{0} s", "", "", "{0} s"]], [["", "{0}v", "{0}v", "", "", "{0}v"], ["", "{0}d", "{0}d", "", "", "{0}d"], ["", "{0}h", "{0}h", "", "", "{0}h"], ["", "{0}m", "{0}m", "", "", "{0}m"], ["", "{0}s", "{0}s", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_123: _DurationPatterns = _DurationPatterns { units: [[["", "සත\u{dd2} {0}", "", "", "", "සත\u{dd2} {0}"], ["", "ද\u{dd2}න {0}", "", "", "", "ද\u{dd2}න {0}"], ["", "පැය {0}", "", "", "", "පැය {0}"], ["", "ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4} {0}", "", "", "", "ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4} {0}"], ["", "තත\u{dca}පර {0}", "", "", "", "තත\u{dca}පර {0}"]], [["", "සත\u{dd2} {0}", "", "", "", "සත\u{dd2} {0}"], ["", "ද\u{dd2}න {0}", "", "", "", "ද\u{dd2}න {0}"], ["", "පැය {0}", "", "", "", "පැය {0}"], ["", "ම\u{dd2}න\u{dd2} {0}", "", "", "", "ම\u{dd2}න\u{dd2} {0}"], ["", "තත\u{dca} {0}", "", "", "", "තත\u{dca} {0}"]], [["", "ස {0}", "", "", "", "ස {0}"], ["", "ද\u{dd2} {0}", "", "", "", "ද\u{dd2} {0}"], ["", "පැය {0}", "", "", "", "පැය {0}"], ["", "ම\u{dd2} {0}", "", "", "", "ම\u{dd2} {0}"], ["", "ත {0}", "", "", "", "ත {0}"]]], lists: [["{0} සහ {1}", "{0}, {1}", "{0}, {1}", "{0}, සහ {1}"], ["{0} සහ {1}", "{0}, {1}", "{0}, {1}", "{0}, සහ {1}"], ["{0} සහ {1}", "{0}, {1}", "{0}, {1}", "{0}, සහ {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_124: _DurationPatterns = _DurationPatterns { units: [[["", "{0} týždeň", "", "{0} týždne", "{0} týždňa", "{0} týždňov"], ["", "{0} deň", "", "{0} dni", "{0} dňa", "{0} dní"], ["", "{0} hodina", "", "{0} hodiny", "{0} hodiny", "{0} hodín"], ["", "{0} minúta", "", "{0} minúty", "{0} minúty", "{0} minút"], ["", "{0} sekunda", "", "{0} sekundy", "{0} sekundy", "{0} sekúnd"]], [["", "{0} týž.", "", "{0} týž.", "{0} týž.", "{0} týž."], ["", "{0} deň", "", "{0} dni", "{0} dňa", "{0} dní"], ["", "{0} h", "", "{0} h", "{0} h", "{0} h"], ["", "{0} min", "", "{0} min", "{0} min", "{0} min"], ["", "{0} s", "", "{0} s", "{0} s", "{0} s"]], [["", "{0} t.", "", "{0} t.", "{0} t.", "{0} t."], ["", "{0} d.", "", "{0} d.", "{0} d.", "{0} d."], ["", "{0} h", "", "{0} h", "{0} h", "{0} h"], ["", "{0} min", "", "{0} min", "{0} min", "{0} min"], ["", "{0} s", "", "{0} s", "{0} s", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_125: _DurationPatterns = _DurationPatterns { units: [[["", "{0} teden", "{0} tedna", "{0} tedni", "", "{0} tednov"], ["", "{0} dan", "{0} dneva", "{0} dni", "", "{0} dni"], ["", "{0} ura", "{0} uri", "{0} ure", "", "{0} ur"], ["", "{0} minuta", "{0} minuti", "{0} minute", "", "{0} minut"], ["", "{0} sekunda", "{0} sekundi", "{0} sekunde", "", "{0} sekund"]], [["", "{0} t", "{0} t", "{0} t", "", "{0} t"], ["", "{0} d", "{0} d", "{0} d", "", "{0} d"], ["", "{0} h", "{0} h", "{0} h", "", "{0} h"], ["", "{0} min", "{0} min", "{0} min", "", "{0} min"], ["", "{0} sek.", "{0} sek.", "{0} sek.", "", "{0} sek."]], [["", "{0} t", "{0} t", "{0} t", "", "{0} t"], ["", "{0} d", "{0} d", "{0} d", "", "{0} d"], ["", "{0} h", "{0} h", "{0} h", "", "{0} h"], ["", "{0} min", "{0} min", "{0} min", "", "{0} min"], ["", "{0} s", "{0} s", "{0} s", "", "{0} s"]]], lists: [["{0} in {1}", "{0}, {1}", "{0}, {1}", "{0} in {1}"], ["{0} in {1}", "{0}, {1}", "{0}, {1}", "{0} in {1}"], ["{0} in {1}", "{0}, {1}", "{0}, {1}", "{0} in {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_126: _DurationPatterns = _DurationPatterns { units: [[["", "{0} toddobaad", "", "", "", "{0} toddobaadyo"], ["", "{0} maalin", "", "", "", "{0} maalmood"], ["", "{0} saacad", "", "", "", "{0} saacadood"], ["", "{0} daqiiqad", "", "", "", "{0} daqiiqo"], ["", "{0} ilbiriqsi", "", "", "", "{0} ilbiriqsi"]], [["", "{0} tdbd", "", "", "", "{0} tdbd"], ["", "{0} mln", "", "", "", "{0} mln"], ["", "{0} scd", "", "", "", "{0} scd"], ["", "{0} dqqd", "", "", "", "{0} daqiiqo"], ["", "{0} ilbrqsi", "", "", "", "{0} ilbrqsi"]], [["", "{0}t", "", "", "", "{0}t"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0} scd", "", "", "", "{0} s"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}il", "", "", "", "{0}il"]]], lists: [["{0} iyo {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_127: _DurationPatterns = _DurationPatterns { units: [[["", "{0} javë", "", "", "", "{0} javë"], ["", "{0} ditë", "", "", "", "{0} ditë"], ["", "{0} orë", "", "", "", "{0} orë"], ["", "{0} minutë", "", "", "", "{0} minuta"], ["", "{0} sekondë", "", "", "", "{0} sekonda"]], [["", "{0} javë", "", "", "", "{0} javë"], ["", "{0} ditë", "", "", "", "{0} ditë"], ["", "{0} orë", "", "", "", "{0} orë"], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} sek.", "", "", "", "{0} sek."]], [["", "{0} javë", "", "", "", "{0} javë"], ["", "{0} ditë", "", "", "", "{0} ditë"], ["", "{0} orë", "", "", "", "{0} orë"], ["", "{0} min.", "", "", "", "{0} min."], ["", "{0} sek.", "", "", "", "{0} sek."]]], lists: [["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"], ["{0} e {1}", "{0}, {1}", "{0}, {1}", "{0} e {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_128: _DurationPatterns = _DurationPatterns { units: [[["", "{0} недеља", "", "{0} недеље", "", "{0} недеља"], ["", "{0} дан", "", "{0} дана", "", "{0} дана"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} минут", "", "{0} минута", "", "{0} минута"], ["", "{0} секунда", "", "{0} секунде", "", "{0} секунди"]], [["", "{0} нед.", "", "{0} нед.", "", "{0} нед."], ["", "{0} дан", "", "{0} дана", "", "{0} дана"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} мин", "", "{0} мин", "", "{0} мин"], ["", "{0} сек", "", "{0} сек", "", "{0} сек"]], [["", "{0} н", "", "{0} н", "", "{0} н"], ["", "{0} д", "", "{0} д", "", "{0} д"], ["", "{0} ч", "", "{0} ч", "", "{0} ч"], ["", "{0} м", "", "{0} м", "", "{0} м"], ["", "{0} с", "", "{0} с", "", "{0} с"]]], lists: [["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_129: _DurationPatterns = _DurationPatterns { units: [[["", "{0} недјеља", "", "{0} недјеље", "", "{0} недјеља"], ["", "{0} дан", "", "{0} дана", "", "{0} дана"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} минут", "", "{0} минута", "", "{0} минута"], ["", "{0} секунда", "", "{0} секунде", "", "{0} секунди"]], [["", "{0} нед.", "", "{0} нед.", "", "{0} нед."], ["", "{0} дан", "", "{0} дана", "", "{0} дана"], ["", "{0} сат", "", "{0} сата", "", "{0} сати"], ["", "{0} мин", "", "{0} мин", "", "{0} мин"], ["", "{0} сек", "", "{0} сек", "", "{0} сек"]], [["", "{0} н", "", "{0} н", "", "{0} н"], ["", "{0} д", "", "{0} д", "", "{0} д"], ["", "{0} ч", "", "{0} ч", "", "{0} ч"], ["", "{0} м", "", "{0} м", "", "{0} м"], ["", "{0} с", "", "{0} с", "", "{0} с"]]], lists: [["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"], ["{0} и {1}", "{0}, {1}", "{0}, {1}", "{0} и {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_130: _DurationPatterns = _DurationPatterns { units: [[["", "{0} nedelja", "", "{0} nedelje", "", "{0} nedelja"], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} sat", "", "{0} sata", "", "{0} sati"], ["", "{0} minut", "", "{0} minuta", "", "{0} minuta"], ["", "{0} sekunda", "", "{0} sekunde", "", "{0} sekundi"]], [["", "{0} ned.", "", "{0} ned.", "", "{0} ned."], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} sat", "", "{0} sata", "", "{0} sati"], ["", "{0} min", "", "{0} min", "", "{0} min"], ["", "{0} sek", "", "{0} sek", "", "{0} sek"]], [["", "{0} n", "", "{0} n", "", "{0} n"], ["", "{0} d", "", "{0} d", "", "{0} d"], ["", "{0} č", "", "{0} č", "", "{0} č"], ["", "{0} m", "", "{0} m", "", "{0} m"], ["", "{0} s", "", "{0} s", "", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_131: _DurationPatterns = _DurationPatterns { units: [[["", "{0} nedjelja", "", "{0} nedjelje", "", "{0} nedjelja"], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} sat", "", "{0} sata", "", "{0} sati"], ["", "{0} minut", "", "{0} minuta", "", "{0} minuta"], ["", "{0} sekunda", "", "{0} sekunde", "", "{0} sekundi"]], [["", "{0} ned.", "", "{0} ned.", "", "{0} ned."], ["", "{0} dan", "", "{0} dana", "", "{0} dana"], ["", "{0} sat", "", "{0} sata", "", "{0} sati"], ["", "{0} min", "", "{0} min", "", "{0} min"], ["", "{0} sek", "", "{0} sek", "", "{0} sek"]], [["", "{0} n", "", "{0} n", "", "{0} n"], ["", "{0} d", "", "{0} d", "", "{0} d"], ["", "{0} č", "", "{0} č", "", "{0} č"], ["", "{0} m", "", "{0} m", "", "{0} m"], ["", "{0} s", "", "{0} s", "", "{0} s"]]], lists: [["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"], ["{0} i {1}", "{0}, {1}", "{0}, {1}", "{0} i {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_132: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h.mm.ss", "m.ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_133: _DurationPatterns = _DurationPatterns { units: [[["", "{0} vecka", "", "", "", "{0} veckor"], ["", "{0} dygn", "", "", "", "{0} dygn"], ["", "{0} timme", "", "", "", "{0} timmar"], ["", "{0} minut", "", "", "", "{0} minuter"], ["", "{0} sekund", "", "", "", "{0} sekunder"]], [["", "{0} v", "", "", "", "{0} v"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} tim", "", "", "", "{0} tim"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0}v", "", "", "", "{0}v"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_134: _DurationPatterns = _DurationPatterns { units: [[["", "wiki {0}", "", "", "", "wiki {0}"], ["", "siku {0}", "", "", "", "siku {0}"], ["", "saa {0}", "", "", "", "saa {0}"], ["", "dakika {0}", "", "", "", "dakika {0}"], ["", "sekunde {0}", "", "", "", "sekunde {0}"]], [["", "wiki {0}", "", "", "", "wiki {0}"], ["", "siku {0}", "", "", "", "siku {0}"], ["", "saa {0}", "", "", "", "saa {0}"], ["", "dakika {0}", "", "", "", "dakika {0}"], ["", "sekunde {0}", "", "", "", "sekunde {0}"]], [["", "wiki {0}", "", "", "", "wiki {0}"], ["", "siku {0}", "", "", "", "siku {0}"], ["", "saa {0}", "", "", "", "saa {0}"], ["", "dak {0}", "", "", "", "dak {0}"], ["", "sek {0}", "", "", "", "sek {0}"]]], lists: [["{0} na {1}", "{0}, {1}", "{0}, {1}", "{0} na {1}"], ["{0} na {1}", "{0}, {1}", "{0}, {1}", "{0} na {1}"], ["{0} na {1}", "{0}, {1}", "{0}, {1}", "{0} na {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_135: _DurationPatterns = _DurationPatterns { units: [[["", "{0} வ\u{bbe}ரம\u{bcd}", "", "", "", "{0} வ\u{bbe}ரங\u{bcd}கள\u{bcd}"], ["", "{0} ந\u{bbe}ள\u{bcd}", "", "", "", "{0} ந\u{bbe}ட\u{bcd}கள\u{bcd}"], ["", "{0} மணிநேரம\u{bcd}", "", "", "", "{0} மணிநேரங\u{bcd}கள\u{bcd}"], ["", "{0} நிமிடம\u{bcd}", "", "", "", "{0} நிமிடங\u{bcd}கள\u{bcd}"], ["", "{0} விந\u{bbe}டி", "", "", "", "{0} விந\u{bbe}டிகள\u{bcd}"]], [["", "{0} வ\u{bbe}ரம\u{bcd}", "", "", "", "{0} வ\u{bbe}ர."], ["", "{0} ந\u{bbe}ள\u{bcd}", "", "", "", "{0} ந\u{bbe}ட\u{bcd}கள\u{bcd}"], ["", "{0} மணிநேரம\u{bcd}", "", "", "", "{0} மணிநேரம\u{bcd}"], ["", "{0} நிமிடம\u{bcd}", "", "", "", "{0} நிமிட"], ["", "{0} விந\u{bbe}டி", "", "", "", "{0} விந\u{bbe}டி"]], [["", "{0} வ\u{bbe}", "", "", "", "{0} வ\u{bbe}"], ["", "{0} ந\u{bbe}", "", "", "", "{0} ந\u{bbe}"], ["", "{0} ம.நே.", "", "", "", "{0} ம.நே."], ["", "{0} நிமி.", "", "", "", "{0} நிமி."], ["", "{0} வி.", "", "", "", "{0} வி."]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_136: _DurationPatterns = _DurationPatterns { units: [[["", "{0} వ\u{c3e}రం", "", "", "", "{0} వ\u{c3e}ర\u{c3e}లు"], ["", "{0} ర\u{c4b}జు", "", "", "", "{0} ర\u{c4b}జులు"], ["", "{0} గంట", "", "", "", "{0} గంటలు"], ["", "{0} న\u{c3f}మ\u{c3f}షం", "", "", "", "{0} న\u{c3f}మ\u{c3f}ష\u{c3e}లు"], ["", "{0} స\u{c46}కను", "", "", "", "{0} స\u{c46}కన\u{c4d}లు"]], [["", "{0} వ\u{c3e}.", "", "", "", "{0} వ\u{c3e}."], ["", "{0} ర\u{c4b}జు", "", "", "", "{0} ర\u{c4b}జులు"], ["", "{0} గం.", "", "", "", "{0} గం."], ["", "{0} న\u{c3f}మ\u{c3f}.", "", "", "", "{0} న\u{c3f}మ\u{c3f}."], ["", "{0} స\u{c46}.", "", "", "", "{0} స\u{c46}క."]], [["", "{0}వ\u{c3e}", "", "", "", "{0}వ\u{c3e}"], ["", "{0}ర\u{c4b}", "", "", "", "{0}ర\u{c4b}"], ["", "{0}గం", "", "", "", "{0}గం"], ["", "{0}న\u{c3f}", "", "", "", "{0}న\u{c3f}"], ["", "{0}స\u{c46}", "", "", "", "{0}స\u{c46}"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_137: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} ส\u{e31}ปดาห\u{e4c}"], ["", "", "", "", "", "{0} ว\u{e31}น"], ["", "", "", "", "", "{0} ช\u{e31}\u{e48}วโมง"], ["", "", "", "", "", "{0} นาท\u{e35}"], ["", "", "", "", "", "{0} ว\u{e34}นาท\u{e35}"]], [["", "", "", "", "", "{0} ส\u{e31}ปดาห\u{e4c}"], ["", "", "", "", "", "{0} ว\u{e31}น"], ["", "", "", "", "", "{0} ชม."], ["", "", "", "", "", "{0} นาท\u{e35}"], ["", "", "", "", "", "{0} ว\u{e34}"]], [["", "", "", "", "", "{0}ส\u{e31}ปดาห\u{e4c}"], ["", "", "", "", "", "{0}ว\u{e31}น"], ["", "", "", "", "", "{0}ชม."], ["", "", "", "", "", "{0}นาท\u{e35}"], ["", "", "", "", "", "{0}ว\u{e34}"]]], lists: [["{0} และ {1}", "{0} {1}", "{0} {1}", "{0} และ {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} และ {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_138: _DurationPatterns = _DurationPatterns { units: [[["", "{0} w", "", "", "", "{0} w"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0} d", "", "", "", "{0} d"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0}ን {1}ን", "{0}፣ {1}", "{0}፣ {1}", "{0}ን {1}ን"], ["{0}ን {1}ን", "{0}፣ {1}", "{0}፣ {1}", "{0}፣ {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_139: _DurationPatterns = _DurationPatterns { units: [[["", "{0} hepde", "", "", "", "{0} hepde"], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} sagat", "", "", "", "{0} sagat"], ["", "{0} minut", "", "", "", "{0} minut"], ["", "{0} sekunt", "", "", "", "{0} sekunt"]], [["", "{0} hep", "", "", "", "{0} hep"], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} sag", "", "", "", "{0} sag"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} sek", "", "", "", "{0} sek"]], [["", "{0}h", "", "", "", "{0}h"], ["", "{0}g", "", "", "", "{0}g"], ["", "{0}sg", "", "", "", "{0}sg"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}se", "", "", "", "{0}se"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["hh:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_140: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "uike ʻe {0}"], ["", "", "", "", "", "ʻaho ʻe {0}"], ["", "", "", "", "", "houa ʻe {0}"], ["", "", "", "", "", "miniti ʻe {0}"], ["", "", "", "", "", "sekoni ʻe {0}"]], [["", "", "", "", "", "u ʻe {0}"], ["", "", "", "", "", "ʻa ʻe {0}"], ["", "", "", "", "", "h ʻe {0}"], ["", "", "", "", "", "m ʻe {0}"], ["", "", "", "", "", "s ʻe {0}"]], [["", "", "", "", "", "{0} u"], ["", "", "", "", "", "{0} ʻa"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} m"], ["", "", "", "", "", "{0} s"]]], lists: [["{0} mo e {1}", "{0}, {1}", "{0}, {1}", "{0} mo e {1}"], ["{0} mo e {1}", "{0}, {1}", "{0}, {1}", "{0} mo e {1}"], ["{0} mo e {1}", "{0} {1}", "{0} {1}", "{0} mo e {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_141: _DurationPatterns = _DurationPatterns { units: [[["", "{0} hafta", "", "", "", "{0} hafta"], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} saat", "", "", "", "{0} saat"], ["", "{0} dakika", "", "", "", "{0} dakika"], ["", "{0} saniye", "", "", "", "{0} saniye"]], [["", "{0} hf.", "", "", "", "{0} hf."], ["", "{0} gün", "", "", "", "{0} gün"], ["", "{0} sa.", "", "", "", "{0} sa."], ["", "{0} dk.", "", "", "", "{0} dk."], ["", "{0} sn.", "", "", "", "{0} sn."]], [["", "{0}h", "", "", "", "{0}h"], ["", "{0}g", "", "", "", "{0}g"], ["", "{0} sa", "", "", "", "{0}s"], ["", "{0}d", "", "", "", "{0}d"], ["", "{0}sn", "", "", "", "{0}sn"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_142: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ھەپتە", "", "", "", "{0} ھەپتە"], ["", "{0} كۈن", "", "", "", "{0} كۈن"], ["", "{0} سائەت", "", "", "", "{0} سائەت"], ["", "{0} مىنۇت", "", "", "", "{0} مىنۇت"], ["", "{0} سېكۇنت", "", "", "", "{0} سېكۇنت"]], [["", "{0} ھەپتە", "", "", "", "{0} ھەپتە"], ["", "{0} كۈن", "", "", "", "{0} كۈن"], ["", "{0} سائەت", "", "", "", "{0} سائەت"], ["", "{0} مىنۇت", "", "", "", "{0} مىنۇت"], ["", "{0} سېكۇنت", "", "", "", "{0} سېكۇنت"]], [["", "{0} ھەپتە", "", "", "", "{0} ھەپتە"], ["", "{0} كۈن", "", "", "", "{0} كۈن"], ["", "{0}h", "", "", "", "{0}h"], ["", "{0}m", "", "", "", "{0}m"], ["", "{0}s", "", "", "", "{0}s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_143: _DurationPatterns = _DurationPatterns { units: [[["", "{0} тиждень", "", "{0} тижні", "{0} тижнів", "{0} тижня"], ["", "{0} день", "", "{0} дні", "{0} днів", "{0} дня"], ["", "{0} година", "", "{0} години", "{0} годин", "{0} години"], ["", "{0} хвилина", "", "{0} хвилини", "{0} хвилин", "{0} хвилини"], ["", "{0} секунда", "", "{0} секунди", "{0} секунд", "{0} секунди"]], [["", "{0} тиж.", "", "{0} тиж.", "{0} тиж.", "{0} тиж."], ["", "{0} дн.", "", "{0} дн.", "{0} дн.", "{0} дн."], ["", "{0} год", "", "{0} год", "{0} год", "{0} год"], ["", "{0} хв", "", "{0} хв", "{0} хв", "{0} хв"], ["", "{0} с", "", "{0} с", "{0} с", "{0} с"]], [["", "{0}т", "", "{0}т", "{0}т", "{0}т"], ["", "{0}д", "", "{0}д", "{0}д", "{0}д"], ["", "{0} год.", "", "{0} год.", "{0} год.", "{0} год."], ["", "{0}х", "", "{0}х", "{0}х", "{0}х"], ["", "{0}с", "", "{0}с", "{0}с", "{0}с"]]], lists: [["{0} і {1}", "{0}, {1}", "{0}, {1}", "{0} і {1}"], ["{0} і {1}", "{0}, {1}", "{0}, {1}", "{0} і {1}"], ["{0} і {1}", "{0}, {1}", "{0}, {1}", "{0} і {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_144: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ہفتہ", "", "", "", "{0} ہفتے"], ["", "{0} دن", "", "", "", "{0} دن"], ["", "{0} گھنٹہ", "", "", "", "{0} گھنٹے"], ["", "{0} منٹ", "", "", "", "{0} منٹ"], ["", "{0} سیکنڈ", "", "", "", "{0} سیکنڈ"]], [["", "{0} ہفتہ", "", "", "", "{0} ہفتے"], ["", "{0} دن", "", "", "", "{0} دن"], ["", "{0} گھنٹہ", "", "", "", "{0} گھنٹے"], ["", "{0} منٹ", "", "", "", "{0} منٹ"], ["", "{0} سیکنڈ", "", "", "", "{0} سیکنڈ"]], [["", "{0} ہفتہ", "", "", "", "{0} ہفتے"], ["", "{0} دن", "", "", "", "{0} دن"], ["", "{0} گھنٹہ", "", "", "", "{0} گھنٹے"], ["", "{0} منٹ", "", "", "", "{0} منٹ"], ["", "{0} سیکنڈ", "", "", "", "{0} سیکنڈ"]]], lists: [["{0}، {1}", "{0}, {1}", "{0}, {1}", "{0}، اور {1}"], ["{0} اور {1}", "{0}، {1}", "{0}، {1}", "{0}، اور {1}"], ["{0} اور {1}", "{0}، {1}", "{0}، {1}", "{0}، اور {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_145: _DurationPatterns = _DurationPatterns { units: [[["", "{0} hafta", "", "", "", "{0} hafta"], ["", "{0} kun", "", "", "", "{0} kun"], ["", "{0} soat", "", "", "", "{0} soat"], ["", "{0} daqiqa", "", "", "", "{0} daqiqa"], ["", "{0} soniya", "", "", "", "{0} soniya"]], [["", "{0} hafta", "", "", "", "{0} hafta"], ["", "{0} kun", "", "", "", "{0} kun"], ["", "{0} soat", "", "", "", "{0} soat"], ["", "{0} daq.", "", "", "", "{0} daq."], ["", "{0} son.", "", "", "", "{0} son."]], [["", "{0} hafta", "", "", "", "{0} hafta"], ["", "{0} kun", "", "", "", "{0} kun"], ["", "{0} soat", "", "", "", "{0} soat"], ["", "{0} daq.", "", "", "", "{0} daq."], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_146: _DurationPatterns = _DurationPatterns { units: [[["", "{0} ҳафта", "", "", "", "{0} ҳафта"], ["", "{0} кун", "", "", "", "{0} кун"], ["", "{0} соат", "", "", "", "{0} соат"], ["", "{0} дақиқа", "", "", "", "{0} дақиқа"], ["", "{0} сония", "", "", "", "{0} сония"]], [["", "{0} ҳафт", "", "", "", "{0} ҳафт"], ["", "{0} кун", "", "", "", "{0} кун"], ["", "{0} соат", "", "", "", "{0} соат"], ["", "{0} дақ", "", "", "", "{0} дақ"], ["", "{0} сония", "", "", "", "{0} сония"]], [["", "{0} ҳафт", "", "", "", "{0} ҳафт"], ["", "{0} к", "", "", "", "{0} к"], ["", "{0} с", "", "", "", "{0} с"], ["", "{0} дақ", "", "", "", "{0} дақ"], ["", "{0} сон", "", "", "", "{0} сон"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_147: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} tuần"], ["", "", "", "", "", "{0} ngày"], ["", "", "", "", "", "{0} giờ"], ["", "", "", "", "", "{0} phút"], ["", "", "", "", "", "{0} giây"]], [["", "", "", "", "", "{0} tuần"], ["", "", "", "", "", "{0} ngày"], ["", "", "", "", "", "{0} giờ"], ["", "", "", "", "", "{0} phút"], ["", "", "", "", "", "{0} giây"]], [["", "", "", "", "", "{0} tuần"], ["", "", "", "", "", "{0} ngày"], ["", "", "", "", "", "{0} giờ"], ["", "", "", "", "", "{0} phút"], ["", "", "", "", "", "{0} giây"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_148: _DurationPatterns = _DurationPatterns { units: [[["", "{0} wuča", "", "", "", "{0} wučä"], ["", "{0} täg", "", "", "", "{0} täg"], ["", "{0} stund", "", "", "", "{0} stunde"], ["", "{0} minüta", "", "", "", "{0} minüte"], ["", "{0} sekund", "", "", "", "{0} sekunde"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]], [["", "", "", "", "", "{0} w"], ["", "", "", "", "", "{0} d"], ["", "", "", "", "", "{0} h"], ["", "", "", "", "", "{0} min"], ["", "", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_149: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} ọṣ"], ["", "", "", "", "", "ọj {0}"], ["", "", "", "", "", "{0} wkt"], ["", "", "", "", "", "{0} ìṣ"], ["", "", "", "", "", "{0}ìṣ àáy"]], [["", "", "", "", "", "{0} ọṣ"], ["", "", "", "", "", "{0} ọj"], ["", "", "", "", "", "{0} wkt"], ["", "", "", "", "", "{0} ìṣ"], ["", "", "", "", "", "{0} ìṣ àáy"]], [["", "", "", "", "", "{0} ọṣ"], ["", "", "", "", "", "ọj {0}"], ["", "", "", "", "", "{0} wkt"], ["", "", "", "", "", "{0}/ìṣ"], ["", "", "", "", "", "{0} ìṣ àáy"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_150: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} ɔsh"], ["", "", "", "", "", "ɔj {0}"], ["", "", "", "", "", "{0} wkt"], ["", "", "", "", "", "{0} ìsh"], ["", "", "", "", "", "{0}ìsh àáy"]], [["", "", "", "", "", "{0} ɔsh"], ["", "", "", "", "", "{0} ɔj"], ["", "", "", "", "", "{0} wkt"], ["", "", "", "", "", "{0} ìsh"], ["", "", "", "", "", "{0} ìsh àáy"]], [["", "", "", "", "", "{0} ɔsh"], ["", "", "", "", "", "ɔj {0}"], ["", "", "", "", "", "{0} wkt"], ["", "", "", "", "", "{0}/ìsh"], ["", "", "", "", "", "{0} ìsh àáy"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_151: _DurationPatterns = _DurationPatterns { units: [[["", "{0} sẽmãna", "", "", "", "{0} sẽmãna-ita"], ["", "{0} ara", "", "", "", "{0} ara-ita"], ["", "{0} hura", "", "", "", "{0} hura-ita"], ["", "{0} minutu", "", "", "", "{0} minutu-ita"], ["", "{0} segũdu", "", "", "", "{0} segũdu-ita"]], [["", "{0} sem.", "", "", "", "{0} sem"], ["", "{0} ara", "", "", "", "{0} ara"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} seg", "", "", "", "{0} seg"]], [["", "{0} sem.", "", "", "", "{0} sem"], ["", "{0} ara", "", "", "", "{0} ara"], ["", "{0} h", "", "", "", "{0} h"], ["", "{0} min", "", "", "", "{0} min"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0} asuí {1}", "{0}, {1}", "{0}, {1}", "{0} asuí {1}"], ["{0} asuí {1}", "{0}, {1}", "{0}, {1}", "{0} asuí {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_152: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} 週"], ["", "", "", "", "", "{0} 天"], ["", "", "", "", "", "{0} 小時"], ["", "", "", "", "", "{0} 分鐘"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0} 週"], ["", "", "", "", "", "{0} 天"], ["", "", "", "", "", "{0} 小時"], ["", "", "", "", "", "{0} 分鐘"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0} 週"], ["", "", "", "", "", "{0} 天"], ["", "", "", "", "", "{0} 小時"], ["", "", "", "", "", "{0} 分鐘"], ["", "", "", "", "", "{0} 秒"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_153: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} 周"], ["", "", "", "", "", "{0} 天"], ["", "", "", "", "", "{0} 小时"], ["", "", "", "", "", "{0} 分钟"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0} 周"], ["", "", "", "", "", "{0} 天"], ["", "", "", "", "", "{0} 小时"], ["", "", "", "", "", "{0} 分钟"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0} 周"], ["", "", "", "", "", "{0} 天"], ["", "", "", "", "", "{0} 小时"], ["", "", "", "", "", "{0} 分钟"], ["", "", "", "", "", "{0} 秒"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_154: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0}周"], ["", "", "", "", "", "{0}天"], ["", "", "", "", "", "{0}小时"], ["", "", "", "", "", "{0}分钟"], ["", "", "", "", "", "{0}秒钟"]], [["", "", "", "", "", "{0}周"], ["", "", "", "", "", "{0}天"], ["", "", "", "", "", "{0}小时"], ["", "", "", "", "", "{0}分钟"], ["", "", "", "", "", "{0}秒"]], [["", "", "", "", "", "{0}周"], ["", "", "", "", "", "{0}天"], ["", "", "", "", "", "{0}小时"], ["", "", "", "", "", "{0}分钟"], ["", "", "", "", "", "{0}秒"]]], lists: [["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_155: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0}周"], ["", "", "", "", "", "{0}天"], ["", "", "", "", "", "{0}小时"], ["", "", "", "", "", "{0}分钟"], ["", "", "", "", "", "{0}秒"]], [["", "", "", "", "", "{0}周"], ["", "", "", "", "", "{0}天"], ["", "", "", "", "", "{0}小时"], ["", "", "", "", "", "{0}分钟"], ["", "", "", "", "", "{0}秒"]], [["", "", "", "", "", "{0}周"], ["", "", "", "", "", "{0}天"], ["", "", "", "", "", "{0}小时"], ["", "", "", "", "", "{0}分钟"], ["", "", "", "", "", "{0}秒"]]], lists: [["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_156: _DurationPatterns = _DurationPatterns { units: [[["", "", "", "", "", "{0} 星期"], ["", "", "", "", "", "{0} 日"], ["", "", "", "", "", "{0} 小時"], ["", "", "", "", "", "{0} 分鐘"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0} 星期"], ["", "", "", "", "", "{0} 日"], ["", "", "", "", "", "{0} 小時"], ["", "", "", "", "", "{0} 分鐘"], ["", "", "", "", "", "{0} 秒"]], [["", "", "", "", "", "{0}週"], ["", "", "", "", "", "{0}日"], ["", "", "", "", "", "{0}小時"], ["", "", "", "", "", "{0}分"], ["", "", "", "", "", "{0}秒"]]], lists: [["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0} {1}", "{0} {1}", "{0} {1}", "{0} {1}"], ["{0}{1}", "{0}{1}", "{0}{1}", "{0}{1}"]], digital: ["h:mm:ss", "m:ss"] };
+
+#[rustfmt::skip]
+static _DURATION_PATTERNS_157: _DurationPatterns = _DurationPatterns { units: [[["", "{0} iviki", "", "", "", "{0} amaviki"], ["", "{0} usuku", "", "", "", "{0} izinsuku"], ["", "{0} ihora", "", "", "", "{0} amahora"], ["", "{0} iminithi", "", "", "", "{0} amaminithi"], ["", "{0} isekhondi", "", "", "", "{0} amasekhondi"]], [["", "{0} viki", "", "", "", "{0} amaviki"], ["", "{0} usuku", "", "", "", "{0} izinsuku"], ["", "{0} hora", "", "", "", "{0} hr"], ["", "{0} iminithi", "", "", "", "{0} iminithi"], ["", "{0} sekhondi", "", "", "", "{0} sec"]], [["", "{0} w", "", "", "", "{0} w"], ["", "{0}", "", "", "", "{0} suku"], ["", "{0} hora", "", "", "", "{0} hora"], ["", "{0} umzuzu", "", "", "", "{0} umzuzu"], ["", "{0} s", "", "", "", "{0} s"]]], lists: [["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0}, {1}"], ["{0}, {1}", "{0}, {1}", "{0}, {1}", "{0} {1}"]], digital: ["h:mm:ss", "m:ss"] };
@@ -6,4 +6,30 @@ pub enum LocaleError {
     Unknown(String),
     #[error("Unknown locale identifier: '{0}'")]
     UnknownLocale(String),
+    #[error("Unknown currency code: '{0}'")]
+    UnknownCurrency(String),
+    #[error("Currency mismatch (expected {expected}, found {found})")]
+    CurrencyMismatch { expected: String, found: String },
+    #[error("Arithmetic overflow")]
+    Overflow,
+    #[error("Invalid allocation: {0}")]
+    InvalidAllocation(String),
+    #[error("No exchange rate from {from} to {to}")]
+    ExchangeRateUnavailable { from: String, to: String },
+    #[error("Invalid exchange rate data: {0}")]
+    InvalidRateData(String),
+    #[error("Unknown time zone: '{0}'")]
+    UnknownTimeZone(String),
+    #[error("Unknown calendar: '{0}'")]
+    UnknownCalendar(String),
+    #[error("Unknown hour cycle: '{0}'")]
+    UnknownHourCycle(String),
+    #[error("Unknown numbering system: '{0}'")]
+    UnknownNumberingSystem(String),
+    #[error("Cannot parse date/time at position {position}: {reason}")]
+    DateTimeParse { position: usize, reason: String },
+    #[error("Invalid date/time: {0}")]
+    InvalidDateTime(String),
+    #[error("Invalid number: '{0}'")]
+    InvalidNumber(String),
 }
@@ -3,10 +3,22 @@ pub mod locale;
 #[cfg(feature = "nums")]
 pub mod num_formats;
 pub use locale::{AVAILABLE_LOCALES, Locale};
+#[cfg(feature = "datetime")]
+pub mod calendars;
 #[cfg(feature = "currency")]
 pub mod currency_formats;
 #[cfg(feature = "datetime")]
 pub mod datetime_formats;
+#[cfg(feature = "datetime")]
+pub mod durations;
+#[cfg(feature = "plurals")]
+pub mod plurals;
+#[cfg(feature = "nums")]
+pub mod rbnf;
+#[cfg(feature = "datetime")]
+pub mod relative_time;
+#[cfg(feature = "datetime")]
+pub mod time_zones;
 
 #[cfg(test)]
 mod test;
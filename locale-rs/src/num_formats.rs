@@ -1,5 +1,9 @@
 // Auto-generated. DO NOT EDIT.
+use crate::error::LocaleError;
 use crate::locale::Locale;
+use crate::rbnf::{_RuleGroup, RbnfRule};
+use std::fmt;
+use std::str::FromStr;
 
 impl Locale {
     pub fn decimal_separator(&self) -> &'static str {
@@ -3086,6 +3090,779 @@ impl Locale {
         }
     }
 
+    /// Returns how many digits the first group must have before grouping
+    /// applies, e.g. 2 for Spanish, which writes `1234` but `12.345`.
+    pub fn minimum_grouping_digits(&self) -> usize {
+        match self {
+            Locale::aa => 1,
+            Locale::aa_DJ => 1,
+            Locale::aa_ER => 1,
+            Locale::ab => 1,
+            Locale::af => 1,
+            Locale::af_NA => 1,
+            Locale::agq => 1,
+            Locale::ak => 1,
+            Locale::am => 1,
+            Locale::an => 1,
+            Locale::ann => 1,
+            Locale::apc => 1,
+            Locale::ar => 1,
+            Locale::ar_AE => 1,
+            Locale::ar_BH => 1,
+            Locale::ar_DJ => 1,
+            Locale::ar_DZ => 1,
+            Locale::ar_EG => 1,
+            Locale::ar_EH => 1,
+            Locale::ar_ER => 1,
+            Locale::ar_IL => 1,
+            Locale::ar_IQ => 1,
+            Locale::ar_JO => 1,
+            Locale::ar_KM => 1,
+            Locale::ar_KW => 1,
+            Locale::ar_LB => 1,
+            Locale::ar_LY => 1,
+            Locale::ar_MA => 1,
+            Locale::ar_MR => 1,
+            Locale::ar_OM => 1,
+            Locale::ar_PS => 1,
+            Locale::ar_QA => 1,
+            Locale::ar_SA => 1,
+            Locale::ar_SD => 1,
+            Locale::ar_SO => 1,
+            Locale::ar_SS => 1,
+            Locale::ar_SY => 1,
+            Locale::ar_TD => 1,
+            Locale::ar_TN => 1,
+            Locale::ar_YE => 1,
+            Locale::arn => 1,
+            Locale::as_ => 1,
+            Locale::asa => 1,
+            Locale::ast => 1,
+            Locale::az => 1,
+            Locale::az_Arab => 1,
+            Locale::az_Arab_IQ => 1,
+            Locale::az_Arab_TR => 1,
+            Locale::az_Cyrl => 1,
+            Locale::az_Latn => 1,
+            Locale::ba => 1,
+            Locale::bal => 1,
+            Locale::bal_Arab => 1,
+            Locale::bal_Latn => 1,
+            Locale::bas => 1,
+            Locale::be => 2,
+            Locale::be_tarask => 2,
+            Locale::bem => 1,
+            Locale::bew => 1,
+            Locale::bez => 1,
+            Locale::bg => 2,
+            Locale::bgc => 1,
+            Locale::bgn => 1,
+            Locale::bgn_AE => 1,
+            Locale::bgn_AF => 1,
+            Locale::bgn_IR => 1,
+            Locale::bgn_OM => 1,
+            Locale::bho => 1,
+            Locale::blo => 1,
+            Locale::blt => 1,
+            Locale::bm => 1,
+            Locale::bm_Nkoo => 1,
+            Locale::bn => 1,
+            Locale::bn_IN => 1,
+            Locale::bo => 1,
+            Locale::bo_IN => 1,
+            Locale::bqi => 1,
+            Locale::br => 1,
+            Locale::brx => 1,
+            Locale::bs => 1,
+            Locale::bs_Cyrl => 1,
+            Locale::bs_Latn => 1,
+            Locale::bss => 1,
+            Locale::bua => 1,
+            Locale::byn => 1,
+            Locale::ca => 1,
+            Locale::ca_AD => 1,
+            Locale::ca_ES_valencia => 1,
+            Locale::ca_FR => 1,
+            Locale::ca_IT => 1,
+            Locale::cad => 1,
+            Locale::cch => 1,
+            Locale::ccp => 1,
+            Locale::ccp_IN => 1,
+            Locale::ce => 1,
+            Locale::ceb => 1,
+            Locale::cgg => 1,
+            Locale::cho => 1,
+            Locale::chr => 1,
+            Locale::cic => 1,
+            Locale::ckb => 1,
+            Locale::ckb_IR => 1,
+            Locale::co => 1,
+            Locale::cop => 1,
+            Locale::cs => 1,
+            Locale::csw => 1,
+            Locale::cu => 1,
+            Locale::cv => 1,
+            Locale::cy => 1,
+            Locale::da => 1,
+            Locale::da_GL => 1,
+            Locale::dav => 1,
+            Locale::de => 1,
+            Locale::de_AT => 1,
+            Locale::de_BE => 1,
+            Locale::de_CH => 1,
+            Locale::de_IT => 1,
+            Locale::de_LI => 1,
+            Locale::de_LU => 1,
+            Locale::dje => 1,
+            Locale::doi => 1,
+            Locale::dsb => 1,
+            Locale::dua => 1,
+            Locale::dv => 1,
+            Locale::dyo => 1,
+            Locale::dz => 1,
+            Locale::ebu => 1,
+            Locale::ee => 3,
+            Locale::ee_TG => 3,
+            Locale::el => 1,
+            Locale::el_CY => 1,
+            Locale::el_polyton => 1,
+            Locale::en => 1,
+            Locale::en_001 => 1,
+            Locale::en_150 => 1,
+            Locale::en_AE => 1,
+            Locale::en_AG => 1,
+            Locale::en_AI => 1,
+            Locale::en_AS => 1,
+            Locale::en_AT => 1,
+            Locale::en_AU => 1,
+            Locale::en_BB => 1,
+            Locale::en_BE => 1,
+            Locale::en_BI => 1,
+            Locale::en_BM => 1,
+            Locale::en_BS => 1,
+            Locale::en_BW => 1,
+            Locale::en_BZ => 1,
+            Locale::en_CA => 1,
+            Locale::en_CC => 1,
+            Locale::en_CH => 1,
+            Locale::en_CK => 1,
+            Locale::en_CM => 1,
+            Locale::en_CX => 1,
+            Locale::en_CY => 1,
+            Locale::en_CZ => 1,
+            Locale::en_DE => 1,
+            Locale::en_DG => 1,
+            Locale::en_DK => 1,
+            Locale::en_DM => 1,
+            Locale::en_Dsrt => 1,
+            Locale::en_EE => 1,
+            Locale::en_ER => 1,
+            Locale::en_ES => 1,
+            Locale::en_FI => 1,
+            Locale::en_FJ => 1,
+            Locale::en_FK => 1,
+            Locale::en_FM => 1,
+            Locale::en_FR => 1,
+            Locale::en_GB => 1,
+            Locale::en_GD => 1,
+            Locale::en_GE => 1,
+            Locale::en_GG => 1,
+            Locale::en_GH => 1,
+            Locale::en_GI => 1,
+            Locale::en_GM => 1,
+            Locale::en_GS => 1,
+            Locale::en_GU => 1,
+            Locale::en_GY => 1,
+            Locale::en_HK => 1,
+            Locale::en_HU => 1,
+            Locale::en_ID => 1,
+            Locale::en_IE => 1,
+            Locale::en_IL => 1,
+            Locale::en_IM => 1,
+            Locale::en_IN => 1,
+            Locale::en_IO => 1,
+            Locale::en_IT => 1,
+            Locale::en_JE => 1,
+            Locale::en_JM => 1,
+            Locale::en_JP => 1,
+            Locale::en_KE => 1,
+            Locale::en_KI => 1,
+            Locale::en_KN => 1,
+            Locale::en_KY => 1,
+            Locale::en_LC => 1,
+            Locale::en_LR => 1,
+            Locale::en_LS => 1,
+            Locale::en_LT => 1,
+            Locale::en_LV => 1,
+            Locale::en_MG => 1,
+            Locale::en_MH => 1,
+            Locale::en_MO => 1,
+            Locale::en_MP => 1,
+            Locale::en_MS => 1,
+            Locale::en_MT => 1,
+            Locale::en_MU => 1,
+            Locale::en_MV => 1,
+            Locale::en_MW => 1,
+            Locale::en_MY => 1,
+            Locale::en_NA => 1,
+            Locale::en_NF => 1,
+            Locale::en_NG => 1,
+            Locale::en_NL => 1,
+            Locale::en_NO => 1,
+            Locale::en_NR => 1,
+            Locale::en_NU => 1,
+            Locale::en_NZ => 1,
+            Locale::en_PG => 1,
+            Locale::en_PH => 1,
+            Locale::en_PK => 1,
+            Locale::en_PL => 1,
+            Locale::en_PN => 1,
+            Locale::en_PR => 1,
+            Locale::en_PT => 1,
+            Locale::en_PW => 1,
+            Locale::en_RO => 1,
+            Locale::en_RW => 1,
+            Locale::en_SB => 1,
+            Locale::en_SC => 1,
+            Locale::en_SD => 1,
+            Locale::en_SE => 1,
+            Locale::en_SG => 1,
+            Locale::en_SH => 1,
+            Locale::en_SI => 1,
+            Locale::en_SK => 1,
+            Locale::en_SL => 1,
+            Locale::en_SS => 1,
+            Locale::en_SX => 1,
+            Locale::en_SZ => 1,
+            Locale::en_Shaw => 1,
+            Locale::en_TC => 1,
+            Locale::en_TK => 1,
+            Locale::en_TO => 1,
+            Locale::en_TT => 1,
+            Locale::en_TV => 1,
+            Locale::en_TZ => 1,
+            Locale::en_UA => 1,
+            Locale::en_UG => 1,
+            Locale::en_UM => 1,
+            Locale::en_VC => 1,
+            Locale::en_VG => 1,
+            Locale::en_VI => 1,
+            Locale::en_VU => 1,
+            Locale::en_WS => 1,
+            Locale::en_ZA => 1,
+            Locale::en_ZM => 1,
+            Locale::en_ZW => 1,
+            Locale::eo => 1,
+            Locale::es => 2,
+            Locale::es_419 => 1,
+            Locale::es_AR => 1,
+            Locale::es_BO => 1,
+            Locale::es_BR => 1,
+            Locale::es_BZ => 1,
+            Locale::es_CL => 1,
+            Locale::es_CO => 1,
+            Locale::es_CR => 1,
+            Locale::es_CU => 1,
+            Locale::es_DO => 1,
+            Locale::es_EA => 2,
+            Locale::es_EC => 1,
+            Locale::es_GQ => 2,
+            Locale::es_GT => 1,
+            Locale::es_HN => 1,
+            Locale::es_IC => 2,
+            Locale::es_MX => 1,
+            Locale::es_NI => 1,
+            Locale::es_PA => 1,
+            Locale::es_PE => 1,
+            Locale::es_PH => 2,
+            Locale::es_PR => 1,
+            Locale::es_PY => 1,
+            Locale::es_SV => 1,
+            Locale::es_US => 1,
+            Locale::es_UY => 1,
+            Locale::es_VE => 1,
+            Locale::et => 2,
+            Locale::eu => 1,
+            Locale::ewo => 1,
+            Locale::fa => 1,
+            Locale::fa_AF => 1,
+            Locale::ff => 1,
+            Locale::ff_Adlm => 1,
+            Locale::ff_Adlm_BF => 1,
+            Locale::ff_Adlm_CM => 1,
+            Locale::ff_Adlm_GH => 1,
+            Locale::ff_Adlm_GM => 1,
+            Locale::ff_Adlm_GW => 1,
+            Locale::ff_Adlm_LR => 1,
+            Locale::ff_Adlm_MR => 1,
+            Locale::ff_Adlm_NE => 1,
+            Locale::ff_Adlm_NG => 1,
+            Locale::ff_Adlm_SL => 1,
+            Locale::ff_Adlm_SN => 1,
+            Locale::ff_Latn => 1,
+            Locale::ff_Latn_BF => 1,
+            Locale::ff_Latn_CM => 1,
+            Locale::ff_Latn_GH => 1,
+            Locale::ff_Latn_GM => 1,
+            Locale::ff_Latn_GN => 1,
+            Locale::ff_Latn_GW => 1,
+            Locale::ff_Latn_LR => 1,
+            Locale::ff_Latn_MR => 1,
+            Locale::ff_Latn_NE => 1,
+            Locale::ff_Latn_NG => 1,
+            Locale::ff_Latn_SL => 1,
+            Locale::fi => 1,
+            Locale::fil => 1,
+            Locale::fo => 1,
+            Locale::fo_DK => 1,
+            Locale::fr => 1,
+            Locale::fr_BE => 1,
+            Locale::fr_BF => 1,
+            Locale::fr_BI => 1,
+            Locale::fr_BJ => 1,
+            Locale::fr_BL => 1,
+            Locale::fr_CA => 1,
+            Locale::fr_CD => 1,
+            Locale::fr_CF => 1,
+            Locale::fr_CG => 1,
+            Locale::fr_CH => 1,
+            Locale::fr_CI => 1,
+            Locale::fr_CM => 1,
+            Locale::fr_DJ => 1,
+            Locale::fr_DZ => 1,
+            Locale::fr_GA => 1,
+            Locale::fr_GF => 1,
+            Locale::fr_GN => 1,
+            Locale::fr_GP => 1,
+            Locale::fr_GQ => 1,
+            Locale::fr_HT => 1,
+            Locale::fr_KM => 1,
+            Locale::fr_LU => 1,
+            Locale::fr_MA => 1,
+            Locale::fr_MC => 1,
+            Locale::fr_MF => 1,
+            Locale::fr_MG => 1,
+            Locale::fr_ML => 1,
+            Locale::fr_MQ => 1,
+            Locale::fr_MR => 1,
+            Locale::fr_MU => 1,
+            Locale::fr_NC => 1,
+            Locale::fr_NE => 1,
+            Locale::fr_PF => 1,
+            Locale::fr_PM => 1,
+            Locale::fr_RE => 1,
+            Locale::fr_RW => 1,
+            Locale::fr_SC => 1,
+            Locale::fr_SN => 1,
+            Locale::fr_SY => 1,
+            Locale::fr_TD => 1,
+            Locale::fr_TG => 1,
+            Locale::fr_TN => 1,
+            Locale::fr_VU => 1,
+            Locale::fr_WF => 1,
+            Locale::fr_YT => 1,
+            Locale::frr => 1,
+            Locale::fur => 1,
+            Locale::fy => 1,
+            Locale::ga => 1,
+            Locale::ga_GB => 1,
+            Locale::gaa => 1,
+            Locale::gd => 1,
+            Locale::gez => 1,
+            Locale::gez_ER => 1,
+            Locale::gl => 1,
+            Locale::gn => 1,
+            Locale::gsw => 1,
+            Locale::gsw_FR => 1,
+            Locale::gsw_LI => 1,
+            Locale::gu => 1,
+            Locale::guz => 1,
+            Locale::gv => 1,
+            Locale::ha => 1,
+            Locale::ha_Arab => 1,
+            Locale::ha_Arab_SD => 1,
+            Locale::ha_GH => 1,
+            Locale::ha_NE => 1,
+            Locale::haw => 1,
+            Locale::he => 1,
+            Locale::hi => 1,
+            Locale::hi_Latn => 1,
+            Locale::hnj => 1,
+            Locale::hnj_Hmnp => 1,
+            Locale::hr => 1,
+            Locale::hr_BA => 1,
+            Locale::hsb => 1,
+            Locale::ht => 1,
+            Locale::hu => 1,
+            Locale::hy => 1,
+            Locale::ia => 2,
+            Locale::id => 1,
+            Locale::ie => 1,
+            Locale::ig => 1,
+            Locale::ii => 1,
+            Locale::io => 1,
+            Locale::is => 1,
+            Locale::it => 1,
+            Locale::it_CH => 1,
+            Locale::it_SM => 1,
+            Locale::it_VA => 1,
+            Locale::iu => 1,
+            Locale::iu_Latn => 1,
+            Locale::ja => 1,
+            Locale::jbo => 1,
+            Locale::jgo => 1,
+            Locale::jmc => 1,
+            Locale::jv => 1,
+            Locale::ka => 2,
+            Locale::kaa => 1,
+            Locale::kaa_Cyrl => 1,
+            Locale::kaa_Latn => 1,
+            Locale::kab => 1,
+            Locale::kaj => 1,
+            Locale::kam => 1,
+            Locale::kcg => 1,
+            Locale::kde => 1,
+            Locale::kea => 1,
+            Locale::kek => 1,
+            Locale::ken => 1,
+            Locale::kgp => 1,
+            Locale::khq => 1,
+            Locale::ki => 1,
+            Locale::kk => 1,
+            Locale::kk_Arab => 1,
+            Locale::kk_Cyrl => 1,
+            Locale::kk_KZ => 1,
+            Locale::kkj => 1,
+            Locale::kl => 1,
+            Locale::kln => 1,
+            Locale::km => 1,
+            Locale::kn => 1,
+            Locale::ko => 1,
+            Locale::ko_CN => 1,
+            Locale::ko_KP => 1,
+            Locale::kok => 1,
+            Locale::kok_Deva => 1,
+            Locale::kok_Latn => 1,
+            Locale::kpe => 1,
+            Locale::kpe_GN => 1,
+            Locale::ks => 1,
+            Locale::ks_Arab => 1,
+            Locale::ks_Deva => 1,
+            Locale::ksb => 1,
+            Locale::ksf => 1,
+            Locale::ksh => 1,
+            Locale::ku => 1,
+            Locale::ku_Arab => 1,
+            Locale::ku_Arab_IR => 1,
+            Locale::ku_Latn => 1,
+            Locale::ku_Latn_IQ => 1,
+            Locale::ku_Latn_SY => 1,
+            Locale::ku_TR => 1,
+            Locale::kw => 1,
+            Locale::kxv => 1,
+            Locale::kxv_Deva => 1,
+            Locale::kxv_Latn => 1,
+            Locale::kxv_Orya => 1,
+            Locale::kxv_Telu => 1,
+            Locale::ky => 1,
+            Locale::la => 1,
+            Locale::lag => 1,
+            Locale::lb => 1,
+            Locale::lg => 1,
+            Locale::lij => 1,
+            Locale::lkt => 1,
+            Locale::lld => 1,
+            Locale::lmo => 1,
+            Locale::ln => 1,
+            Locale::ln_AO => 1,
+            Locale::ln_CF => 1,
+            Locale::ln_CG => 1,
+            Locale::lo => 1,
+            Locale::lrc => 1,
+            Locale::lrc_IQ => 1,
+            Locale::lt => 1,
+            Locale::ltg => 1,
+            Locale::lu => 1,
+            Locale::luo => 1,
+            Locale::luy => 1,
+            Locale::lv => 2,
+            Locale::lzz => 1,
+            Locale::mai => 1,
+            Locale::mas => 1,
+            Locale::mas_TZ => 1,
+            Locale::mdf => 1,
+            Locale::mer => 1,
+            Locale::mfe => 1,
+            Locale::mg => 1,
+            Locale::mgh => 1,
+            Locale::mgo => 1,
+            Locale::mhn => 1,
+            Locale::mi => 1,
+            Locale::mic => 1,
+            Locale::mk => 1,
+            Locale::ml => 1,
+            Locale::mn => 1,
+            Locale::mn_Mong => 1,
+            Locale::mn_Mong_MN => 1,
+            Locale::mni => 1,
+            Locale::mni_Beng => 1,
+            Locale::mni_Mtei => 1,
+            Locale::moh => 1,
+            Locale::mr => 1,
+            Locale::ms => 1,
+            Locale::ms_Arab => 1,
+            Locale::ms_Arab_BN => 1,
+            Locale::ms_BN => 1,
+            Locale::ms_ID => 1,
+            Locale::ms_SG => 1,
+            Locale::mt => 1,
+            Locale::mua => 1,
+            Locale::mus => 1,
+            Locale::mww => 1,
+            Locale::mww_Hmnp => 1,
+            Locale::my => 1,
+            Locale::myv => 1,
+            Locale::mzn => 1,
+            Locale::naq => 1,
+            Locale::nb => 1,
+            Locale::nb_SJ => 1,
+            Locale::nd => 1,
+            Locale::nds => 1,
+            Locale::nds_NL => 1,
+            Locale::ne => 1,
+            Locale::ne_IN => 1,
+            Locale::nl => 1,
+            Locale::nl_AW => 1,
+            Locale::nl_BE => 1,
+            Locale::nl_BQ => 1,
+            Locale::nl_CW => 1,
+            Locale::nl_SR => 1,
+            Locale::nl_SX => 1,
+            Locale::nmg => 1,
+            Locale::nn => 1,
+            Locale::nnh => 1,
+            Locale::no => 1,
+            Locale::nqo => 1,
+            Locale::nr => 1,
+            Locale::nso => 1,
+            Locale::nus => 1,
+            Locale::nv => 1,
+            Locale::ny => 1,
+            Locale::nyn => 1,
+            Locale::oc => 1,
+            Locale::oc_ES => 1,
+            Locale::oka => 1,
+            Locale::oka_US => 1,
+            Locale::om => 1,
+            Locale::om_KE => 1,
+            Locale::or => 1,
+            Locale::os => 1,
+            Locale::os_RU => 1,
+            Locale::osa => 1,
+            Locale::pa => 1,
+            Locale::pa_Arab => 1,
+            Locale::pa_Guru => 1,
+            Locale::pap => 1,
+            Locale::pap_AW => 1,
+            Locale::pcm => 1,
+            Locale::pi => 1,
+            Locale::pi_Latn => 1,
+            Locale::pis => 1,
+            Locale::pl => 2,
+            Locale::pms => 1,
+            Locale::prg => 1,
+            Locale::ps => 1,
+            Locale::ps_PK => 1,
+            Locale::pt => 1,
+            Locale::pt_AO => 1,
+            Locale::pt_CH => 2,
+            Locale::pt_CV => 2,
+            Locale::pt_GQ => 2,
+            Locale::pt_GW => 2,
+            Locale::pt_LU => 2,
+            Locale::pt_MO => 2,
+            Locale::pt_MZ => 2,
+            Locale::pt_PT => 2,
+            Locale::pt_ST => 2,
+            Locale::pt_TL => 2,
+            Locale::qu => 1,
+            Locale::qu_BO => 1,
+            Locale::qu_EC => 1,
+            Locale::quc => 1,
+            Locale::raj => 1,
+            Locale::rhg => 1,
+            Locale::rhg_Rohg => 1,
+            Locale::rhg_Rohg_BD => 1,
+            Locale::rif => 1,
+            Locale::rm => 1,
+            Locale::rn => 1,
+            Locale::ro => 1,
+            Locale::ro_MD => 1,
+            Locale::rof => 1,
+            Locale::ru => 1,
+            Locale::ru_BY => 1,
+            Locale::ru_KG => 1,
+            Locale::ru_KZ => 1,
+            Locale::ru_MD => 1,
+            Locale::ru_UA => 2,
+            Locale::rw => 1,
+            Locale::rwk => 1,
+            Locale::sa => 1,
+            Locale::sah => 1,
+            Locale::saq => 1,
+            Locale::sat => 1,
+            Locale::sat_Deva => 1,
+            Locale::sat_Olck => 1,
+            Locale::sbp => 1,
+            Locale::sc => 1,
+            Locale::scn => 1,
+            Locale::sd => 1,
+            Locale::sd_Arab => 1,
+            Locale::sd_Deva => 1,
+            Locale::sdh => 1,
+            Locale::sdh_IQ => 1,
+            Locale::se => 1,
+            Locale::se_FI => 1,
+            Locale::se_SE => 1,
+            Locale::seh => 1,
+            Locale::ses => 1,
+            Locale::sg => 1,
+            Locale::sgs => 1,
+            Locale::shi => 1,
+            Locale::shi_Latn => 1,
+            Locale::shi_Tfng => 1,
+            Locale::shn => 1,
+            Locale::shn_TH => 1,
+            Locale::si => 1,
+            Locale::sid => 1,
+            Locale::sk => 1,
+            Locale::skr => 1,
+            Locale::sl => 1,
+            Locale::sma => 1,
+            Locale::sma_NO => 1,
+            Locale::smj => 1,
+            Locale::smj_NO => 1,
+            Locale::smn => 1,
+            Locale::sms => 1,
+            Locale::sn => 1,
+            Locale::so => 1,
+            Locale::so_DJ => 1,
+            Locale::so_ET => 1,
+            Locale::so_KE => 1,
+            Locale::sq => 2,
+            Locale::sq_MK => 2,
+            Locale::sq_XK => 2,
+            Locale::sr => 1,
+            Locale::sr_Cyrl => 1,
+            Locale::sr_Cyrl_BA => 1,
+            Locale::sr_Cyrl_ME => 1,
+            Locale::sr_Cyrl_XK => 1,
+            Locale::sr_Latn => 1,
+            Locale::sr_Latn_BA => 1,
+            Locale::sr_Latn_ME => 1,
+            Locale::sr_Latn_XK => 1,
+            Locale::ss => 1,
+            Locale::ss_SZ => 1,
+            Locale::ssy => 1,
+            Locale::st => 1,
+            Locale::st_LS => 1,
+            Locale::su => 1,
+            Locale::su_Latn => 1,
+            Locale::suz => 1,
+            Locale::suz_Deva => 1,
+            Locale::suz_Sunu => 1,
+            Locale::sv => 1,
+            Locale::sv_AX => 1,
+            Locale::sv_FI => 1,
+            Locale::sw => 1,
+            Locale::sw_CD => 1,
+            Locale::sw_KE => 1,
+            Locale::sw_UG => 1,
+            Locale::syr => 1,
+            Locale::syr_SY => 1,
+            Locale::szl => 1,
+            Locale::ta => 1,
+            Locale::ta_LK => 1,
+            Locale::ta_MY => 1,
+            Locale::ta_SG => 1,
+            Locale::te => 1,
+            Locale::teo => 1,
+            Locale::teo_KE => 1,
+            Locale::tg => 1,
+            Locale::th => 1,
+            Locale::ti => 1,
+            Locale::ti_ER => 1,
+            Locale::tig => 1,
+            Locale::tk => 1,
+            Locale::tn => 1,
+            Locale::tn_BW => 1,
+            Locale::to => 1,
+            Locale::tok => 1,
+            Locale::tpi => 1,
+            Locale::tr => 1,
+            Locale::tr_CY => 1,
+            Locale::trv => 1,
+            Locale::trw => 1,
+            Locale::ts => 1,
+            Locale::tt => 1,
+            Locale::twq => 1,
+            Locale::tyv => 1,
+            Locale::tzm => 1,
+            Locale::ug => 1,
+            Locale::uk => 1,
+            Locale::und => 1,
+            Locale::ur => 1,
+            Locale::ur_IN => 1,
+            Locale::uz => 1,
+            Locale::uz_Arab => 1,
+            Locale::uz_Cyrl => 1,
+            Locale::uz_Latn => 1,
+            Locale::vai => 1,
+            Locale::vai_Latn => 1,
+            Locale::vai_Vaii => 1,
+            Locale::ve => 1,
+            Locale::vec => 1,
+            Locale::vi => 1,
+            Locale::vmw => 1,
+            Locale::vo => 1,
+            Locale::vun => 1,
+            Locale::wa => 1,
+            Locale::wae => 1,
+            Locale::wal => 1,
+            Locale::wbp => 1,
+            Locale::wo => 1,
+            Locale::xh => 1,
+            Locale::xnr => 1,
+            Locale::xog => 1,
+            Locale::yav => 1,
+            Locale::yi => 1,
+            Locale::yo => 1,
+            Locale::yo_BJ => 1,
+            Locale::yrl => 1,
+            Locale::yrl_CO => 1,
+            Locale::yrl_VE => 1,
+            Locale::yue => 1,
+            Locale::yue_Hans => 1,
+            Locale::yue_Hant => 1,
+            Locale::yue_Hant_CN => 1,
+            Locale::yue_Hant_MO => 1,
+            Locale::za => 1,
+            Locale::zgh => 1,
+            Locale::zh => 1,
+            Locale::zh_Hans => 1,
+            Locale::zh_Hans_HK => 1,
+            Locale::zh_Hans_MO => 1,
+            Locale::zh_Hans_MY => 1,
+            Locale::zh_Hans_SG => 1,
+            Locale::zh_Hant => 1,
+            Locale::zh_Hant_HK => 1,
+            Locale::zh_Hant_MO => 1,
+            Locale::zh_Hant_MY => 1,
+            Locale::zh_Latn => 1,
+            Locale::zu => 1,
+        }
+    }
+
     pub fn digits(&self) -> Option<[char; 10]> {
         match self {
             Locale::aa => None,
@@ -3858,56 +4635,3380 @@ impl Locale {
     }
 }
 
+/// A CLDR numbering system, named after its BCP 47 `-u-nu-` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberingSystem {
+    /// `adlm`: 𞥐𞥑𞥒𞥓𞥔𞥕𞥖𞥗𞥘𞥙
+    Adlm,
+    /// `ahom`: 𑜰𑜱𑜲𑜳𑜴𑜵𑜶𑜷𑜸𑜹
+    Ahom,
+    /// `arab`: ٠١٢٣٤٥٦٧٨٩
+    Arab,
+    /// `arabext`: ۰۱۲۳۴۵۶۷۸۹
+    Arabext,
+    /// `armn`: rules `%armenian-upper`
+    Armn,
+    /// `armnlow`: rules `%armenian-lower`
+    Armnlow,
+    /// `bali`: ᭐᭑᭒᭓᭔᭕᭖᭗᭘᭙
+    Bali,
+    /// `beng`: ০১২৩৪৫৬৭৮৯
+    Beng,
+    /// `bhks`: 𑱐𑱑𑱒𑱓𑱔𑱕𑱖𑱗𑱘𑱙
+    Bhks,
+    /// `brah`: 𑁦𑁧𑁨𑁩𑁪𑁫𑁬𑁭𑁮𑁯
+    Brah,
+    /// `cakm`: 𑄶𑄷𑄸𑄹𑄺𑄻𑄼𑄽𑄾𑄿
+    Cakm,
+    /// `cham`: ꩐꩑꩒꩓꩔꩕꩖꩗꩘꩙
+    Cham,
+    /// `cyrl`: rules `%cyrillic-lower`
+    Cyrl,
+    /// `deva`: ०१२३४५६७८९
+    Deva,
+    /// `diak`: 𑥐𑥑𑥒𑥓𑥔𑥕𑥖𑥗𑥘𑥙
+    Diak,
+    /// `ethi`: rules `%ethiopic`
+    Ethi,
+    /// `fullwide`: ０１２３４５６７８９
+    Fullwide,
+    /// `geor`: rules `%georgian`
+    Geor,
+    /// `gong`: 𑶠𑶡𑶢𑶣𑶤𑶥𑶦𑶧𑶨𑶩
+    Gong,
+    /// `gonm`: 𑵐𑵑𑵒𑵓𑵔𑵕𑵖𑵗𑵘𑵙
+    Gonm,
+    /// `grek`: rules `%greek-upper`
+    Grek,
+    /// `greklow`: rules `%greek-lower`
+    Greklow,
+    /// `gujr`: ૦૧૨૩૪૫૬૭૮૯
+    Gujr,
+    /// `guru`: ੦੧੨੩੪੫੬੭੮੯
+    Guru,
+    /// `hanidays`: rules `zh/SpelloutRules/%spellout-numbering-days`
+    Hanidays,
+    /// `hanidec`: 〇一二三四五六七八九
+    Hanidec,
+    /// `hans`: rules `zh/SpelloutRules/%spellout-cardinal`
+    Hans,
+    /// `hansfin`: rules `zh/SpelloutRules/%spellout-cardinal-financial`
+    Hansfin,
+    /// `hant`: rules `zh_Hant/SpelloutRules/%spellout-cardinal`
+    Hant,
+    /// `hantfin`: rules `zh_Hant/SpelloutRules/%spellout-cardinal-financial`
+    Hantfin,
+    /// `hebr`: rules `%hebrew`
+    Hebr,
+    /// `hmng`: 𖭐𖭑𖭒𖭓𖭔𖭕𖭖𖭗𖭘𖭙
+    Hmng,
+    /// `hmnp`: 𞅀𞅁𞅂𞅃𞅄𞅅𞅆𞅇𞅈𞅉
+    Hmnp,
+    /// `java`: ꧐꧑꧒꧓꧔꧕꧖꧗꧘꧙
+    Java,
+    /// `jpan`: rules `ja/SpelloutRules/%spellout-cardinal`
+    Jpan,
+    /// `jpanfin`: rules `ja/SpelloutRules/%spellout-cardinal-financial`
+    Jpanfin,
+    /// `jpanyear`: rules `ja/SpelloutRules/%spellout-numbering-year-latn`
+    Jpanyear,
+    /// `kali`: ꤀꤁꤂꤃꤄꤅꤆꤇꤈꤉
+    Kali,
+    /// `kawi`: 𑽐𑽑𑽒𑽓𑽔𑽕𑽖𑽗𑽘𑽙
+    Kawi,
+    /// `khmr`: ០១២៣៤៥៦៧៨៩
+    Khmr,
+    /// `knda`: ೦೧೨೩೪೫೬೭೮೯
+    Knda,
+    /// `lana`: ᪀᪁᪂᪃᪄᪅᪆᪇᪈᪉
+    Lana,
+    /// `lanatham`: ᪐᪑᪒᪓᪔᪕᪖᪗᪘᪙
+    Lanatham,
+    /// `laoo`: ໐໑໒໓໔໕໖໗໘໙
+    Laoo,
+    /// `latn`: 0123456789
+    Latn,
+    /// `lepc`: ᱀᱁᱂᱃᱄᱅᱆᱇᱈᱉
+    Lepc,
+    /// `limb`: ᥆᥇᥈᥉᥊᥋᥌᥍᥎᥏
+    Limb,
+    /// `mathbold`: 𝟎𝟏𝟐𝟑𝟒𝟓𝟔𝟕𝟖𝟗
+    Mathbold,
+    /// `mathdbl`: 𝟘𝟙𝟚𝟛𝟜𝟝𝟞𝟟𝟠𝟡
+    Mathdbl,
+    /// `mathmono`: 𝟶𝟷𝟸𝟹𝟺𝟻𝟼𝟽𝟾𝟿
+    Mathmono,
+    /// `mathsanb`: 𝟬𝟭𝟮𝟯𝟰𝟱𝟲𝟳𝟴𝟵
+    Mathsanb,
+    /// `mathsans`: 𝟢𝟣𝟤𝟥𝟦𝟧𝟨𝟩𝟪𝟫
+    Mathsans,
+    /// `mlym`: ൦൧൨൩൪൫൬൭൮൯
+    Mlym,
+    /// `modi`: 𑙐𑙑𑙒𑙓𑙔𑙕𑙖𑙗𑙘𑙙
+    Modi,
+    /// `mong`: ᠐᠑᠒᠓᠔᠕᠖᠗᠘᠙
+    Mong,
+    /// `mroo`: 𖩠𖩡𖩢𖩣𖩤𖩥𖩦𖩧𖩨𖩩
+    Mroo,
+    /// `mtei`: ꯰꯱꯲꯳꯴꯵꯶꯷꯸꯹
+    Mtei,
+    /// `mymr`: ၀၁၂၃၄၅၆၇၈၉
+    Mymr,
+    /// `mymrshan`: ႐႑႒႓႔႕႖႗႘႙
+    Mymrshan,
+    /// `mymrtlng`: ꧰꧱꧲꧳꧴꧵꧶꧷꧸꧹
+    Mymrtlng,
+    /// `nagm`: 𞓰𞓱𞓲𞓳𞓴𞓵𞓶𞓷𞓸𞓹
+    Nagm,
+    /// `newa`: 𑑐𑑑𑑒𑑓𑑔𑑕𑑖𑑗𑑘𑑙
+    Newa,
+    /// `nkoo`: ߀߁߂߃߄߅߆߇߈߉
+    Nkoo,
+    /// `olck`: ᱐᱑᱒᱓᱔᱕᱖᱗᱘᱙
+    Olck,
+    /// `orya`: ୦୧୨୩୪୫୬୭୮୯
+    Orya,
+    /// `osma`: 𐒠𐒡𐒢𐒣𐒤𐒥𐒦𐒧𐒨𐒩
+    Osma,
+    /// `rohg`: 𐴰𐴱𐴲𐴳𐴴𐴵𐴶𐴷𐴸𐴹
+    Rohg,
+    /// `roman`: rules `%roman-upper`
+    Roman,
+    /// `romanlow`: rules `%roman-lower`
+    Romanlow,
+    /// `saur`: ꣐꣑꣒꣓꣔꣕꣖꣗꣘꣙
+    Saur,
+    /// `segment`: 🯰🯱🯲🯳🯴🯵🯶🯷🯸🯹
+    Segment,
+    /// `shrd`: 𑇐𑇑𑇒𑇓𑇔𑇕𑇖𑇗𑇘𑇙
+    Shrd,
+    /// `sind`: 𑋰𑋱𑋲𑋳𑋴𑋵𑋶𑋷𑋸𑋹
+    Sind,
+    /// `sinh`: ෦෧෨෩෪෫෬෭෮෯
+    Sinh,
+    /// `sora`: 𑃰𑃱𑃲𑃳𑃴𑃵𑃶𑃷𑃸𑃹
+    Sora,
+    /// `sund`: ᮰᮱᮲᮳᮴᮵᮶᮷᮸᮹
+    Sund,
+    /// `takr`: 𑛀𑛁𑛂𑛃𑛄𑛅𑛆𑛇𑛈𑛉
+    Takr,
+    /// `talu`: ᧐᧑᧒᧓᧔᧕᧖᧗᧘᧙
+    Talu,
+    /// `taml`: rules `%tamil`
+    Taml,
+    /// `tamldec`: ௦௧௨௩௪௫௬௭௮௯
+    Tamldec,
+    /// `telu`: ౦౧౨౩౪౫౬౭౮౯
+    Telu,
+    /// `thai`: ๐๑๒๓๔๕๖๗๘๙
+    Thai,
+    /// `tibt`: ༠༡༢༣༤༥༦༧༨༩
+    Tibt,
+    /// `tirh`: 𑓐𑓑𑓒𑓓𑓔𑓕𑓖𑓗𑓘𑓙
+    Tirh,
+    /// `tnsa`: 𖫀𖫁𖫂𖫃𖫄𖫅𖫆𖫇𖫈𖫉
+    Tnsa,
+    /// `vaii`: ꘠꘡꘢꘣꘤꘥꘦꘧꘨꘩
+    Vaii,
+    /// `wara`: 𑣠𑣡𑣢𑣣𑣤𑣥𑣦𑣧𑣨𑣩
+    Wara,
+    /// `wcho`: 𞋰𞋱𞋲𞋳𞋴𞋵𞋶𞋷𞋸𞋹
+    Wcho,
+}
+
+impl NumberingSystem {
+    /// Returns the CLDR name of the numbering system, e.g. `"arab"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberingSystem::Adlm => "adlm",
+            NumberingSystem::Ahom => "ahom",
+            NumberingSystem::Arab => "arab",
+            NumberingSystem::Arabext => "arabext",
+            NumberingSystem::Armn => "armn",
+            NumberingSystem::Armnlow => "armnlow",
+            NumberingSystem::Bali => "bali",
+            NumberingSystem::Beng => "beng",
+            NumberingSystem::Bhks => "bhks",
+            NumberingSystem::Brah => "brah",
+            NumberingSystem::Cakm => "cakm",
+            NumberingSystem::Cham => "cham",
+            NumberingSystem::Cyrl => "cyrl",
+            NumberingSystem::Deva => "deva",
+            NumberingSystem::Diak => "diak",
+            NumberingSystem::Ethi => "ethi",
+            NumberingSystem::Fullwide => "fullwide",
+            NumberingSystem::Geor => "geor",
+            NumberingSystem::Gong => "gong",
+            NumberingSystem::Gonm => "gonm",
+            NumberingSystem::Grek => "grek",
+            NumberingSystem::Greklow => "greklow",
+            NumberingSystem::Gujr => "gujr",
+            NumberingSystem::Guru => "guru",
+            NumberingSystem::Hanidays => "hanidays",
+            NumberingSystem::Hanidec => "hanidec",
+            NumberingSystem::Hans => "hans",
+            NumberingSystem::Hansfin => "hansfin",
+            NumberingSystem::Hant => "hant",
+            NumberingSystem::Hantfin => "hantfin",
+            NumberingSystem::Hebr => "hebr",
+            NumberingSystem::Hmng => "hmng",
+            NumberingSystem::Hmnp => "hmnp",
+            NumberingSystem::Java => "java",
+            NumberingSystem::Jpan => "jpan",
+            NumberingSystem::Jpanfin => "jpanfin",
+            NumberingSystem::Jpanyear => "jpanyear",
+            NumberingSystem::Kali => "kali",
+            NumberingSystem::Kawi => "kawi",
+            NumberingSystem::Khmr => "khmr",
+            NumberingSystem::Knda => "knda",
+            NumberingSystem::Lana => "lana",
+            NumberingSystem::Lanatham => "lanatham",
+            NumberingSystem::Laoo => "laoo",
+            NumberingSystem::Latn => "latn",
+            NumberingSystem::Lepc => "lepc",
+            NumberingSystem::Limb => "limb",
+            NumberingSystem::Mathbold => "mathbold",
+            NumberingSystem::Mathdbl => "mathdbl",
+            NumberingSystem::Mathmono => "mathmono",
+            NumberingSystem::Mathsanb => "mathsanb",
+            NumberingSystem::Mathsans => "mathsans",
+            NumberingSystem::Mlym => "mlym",
+            NumberingSystem::Modi => "modi",
+            NumberingSystem::Mong => "mong",
+            NumberingSystem::Mroo => "mroo",
+            NumberingSystem::Mtei => "mtei",
+            NumberingSystem::Mymr => "mymr",
+            NumberingSystem::Mymrshan => "mymrshan",
+            NumberingSystem::Mymrtlng => "mymrtlng",
+            NumberingSystem::Nagm => "nagm",
+            NumberingSystem::Newa => "newa",
+            NumberingSystem::Nkoo => "nkoo",
+            NumberingSystem::Olck => "olck",
+            NumberingSystem::Orya => "orya",
+            NumberingSystem::Osma => "osma",
+            NumberingSystem::Rohg => "rohg",
+            NumberingSystem::Roman => "roman",
+            NumberingSystem::Romanlow => "romanlow",
+            NumberingSystem::Saur => "saur",
+            NumberingSystem::Segment => "segment",
+            NumberingSystem::Shrd => "shrd",
+            NumberingSystem::Sind => "sind",
+            NumberingSystem::Sinh => "sinh",
+            NumberingSystem::Sora => "sora",
+            NumberingSystem::Sund => "sund",
+            NumberingSystem::Takr => "takr",
+            NumberingSystem::Talu => "talu",
+            NumberingSystem::Taml => "taml",
+            NumberingSystem::Tamldec => "tamldec",
+            NumberingSystem::Telu => "telu",
+            NumberingSystem::Thai => "thai",
+            NumberingSystem::Tibt => "tibt",
+            NumberingSystem::Tirh => "tirh",
+            NumberingSystem::Tnsa => "tnsa",
+            NumberingSystem::Vaii => "vaii",
+            NumberingSystem::Wara => "wara",
+            NumberingSystem::Wcho => "wcho",
+        }
+    }
+
+    /// Returns the digits 0-9 of a numeric system, or `None` for an
+    /// algorithmic one such as `roman`.
+    pub fn digits(&self) -> Option<[char; 10]> {
+        match self {
+            NumberingSystem::Adlm => Some(['𞥐', '𞥑', '𞥒', '𞥓', '𞥔', '𞥕', '𞥖', '𞥗', '𞥘', '𞥙']),
+            NumberingSystem::Ahom => Some(['𑜰', '𑜱', '𑜲', '𑜳', '𑜴', '𑜵', '𑜶', '𑜷', '𑜸', '𑜹']),
+            NumberingSystem::Arab => Some(['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩']),
+            NumberingSystem::Arabext => Some(['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹']),
+            NumberingSystem::Bali => Some(['᭐', '᭑', '᭒', '᭓', '᭔', '᭕', '᭖', '᭗', '᭘', '᭙']),
+            NumberingSystem::Beng => Some(['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯']),
+            NumberingSystem::Bhks => Some(['𑱐', '𑱑', '𑱒', '𑱓', '𑱔', '𑱕', '𑱖', '𑱗', '𑱘', '𑱙']),
+            NumberingSystem::Brah => Some(['𑁦', '𑁧', '𑁨', '𑁩', '𑁪', '𑁫', '𑁬', '𑁭', '𑁮', '𑁯']),
+            NumberingSystem::Cakm => Some(['𑄶', '𑄷', '𑄸', '𑄹', '𑄺', '𑄻', '𑄼', '𑄽', '𑄾', '𑄿']),
+            NumberingSystem::Cham => Some(['꩐', '꩑', '꩒', '꩓', '꩔', '꩕', '꩖', '꩗', '꩘', '꩙']),
+            NumberingSystem::Deva => Some(['०', '१', '२', '३', '४', '५', '६', '७', '८', '९']),
+            NumberingSystem::Diak => Some(['𑥐', '𑥑', '𑥒', '𑥓', '𑥔', '𑥕', '𑥖', '𑥗', '𑥘', '𑥙']),
+            NumberingSystem::Fullwide => {
+                Some(['０', '１', '２', '３', '４', '５', '６', '７', '８', '９'])
+            }
+            NumberingSystem::Gong => Some(['𑶠', '𑶡', '𑶢', '𑶣', '𑶤', '𑶥', '𑶦', '𑶧', '𑶨', '𑶩']),
+            NumberingSystem::Gonm => Some(['𑵐', '𑵑', '𑵒', '𑵓', '𑵔', '𑵕', '𑵖', '𑵗', '𑵘', '𑵙']),
+            NumberingSystem::Gujr => Some(['૦', '૧', '૨', '૩', '૪', '૫', '૬', '૭', '૮', '૯']),
+            NumberingSystem::Guru => Some(['੦', '੧', '੨', '੩', '੪', '੫', '੬', '੭', '੮', '੯']),
+            NumberingSystem::Hanidec => {
+                Some(['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'])
+            }
+            NumberingSystem::Hmng => Some(['𖭐', '𖭑', '𖭒', '𖭓', '𖭔', '𖭕', '𖭖', '𖭗', '𖭘', '𖭙']),
+            NumberingSystem::Hmnp => Some(['𞅀', '𞅁', '𞅂', '𞅃', '𞅄', '𞅅', '𞅆', '𞅇', '𞅈', '𞅉']),
+            NumberingSystem::Java => Some(['꧐', '꧑', '꧒', '꧓', '꧔', '꧕', '꧖', '꧗', '꧘', '꧙']),
+            NumberingSystem::Kali => Some(['꤀', '꤁', '꤂', '꤃', '꤄', '꤅', '꤆', '꤇', '꤈', '꤉']),
+            NumberingSystem::Kawi => Some(['𑽐', '𑽑', '𑽒', '𑽓', '𑽔', '𑽕', '𑽖', '𑽗', '𑽘', '𑽙']),
+            NumberingSystem::Khmr => Some(['០', '១', '២', '៣', '៤', '៥', '៦', '៧', '៨', '៩']),
+            NumberingSystem::Knda => Some(['೦', '೧', '೨', '೩', '೪', '೫', '೬', '೭', '೮', '೯']),
+            NumberingSystem::Lana => Some(['᪀', '᪁', '᪂', '᪃', '᪄', '᪅', '᪆', '᪇', '᪈', '᪉']),
+            NumberingSystem::Lanatham => Some(['᪐', '᪑', '᪒', '᪓', '᪔', '᪕', '᪖', '᪗', '᪘', '᪙']),
+            NumberingSystem::Laoo => Some(['໐', '໑', '໒', '໓', '໔', '໕', '໖', '໗', '໘', '໙']),
+            NumberingSystem::Latn => Some(['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']),
+            NumberingSystem::Lepc => Some(['᱀', '᱁', '᱂', '᱃', '᱄', '᱅', '᱆', '᱇', '᱈', '᱉']),
+            NumberingSystem::Limb => Some(['᥆', '᥇', '᥈', '᥉', '᥊', '᥋', '᥌', '᥍', '᥎', '᥏']),
+            NumberingSystem::Mathbold => Some(['𝟎', '𝟏', '𝟐', '𝟑', '𝟒', '𝟓', '𝟔', '𝟕', '𝟖', '𝟗']),
+            NumberingSystem::Mathdbl => Some(['𝟘', '𝟙', '𝟚', '𝟛', '𝟜', '𝟝', '𝟞', '𝟟', '𝟠', '𝟡']),
+            NumberingSystem::Mathmono => Some(['𝟶', '𝟷', '𝟸', '𝟹', '𝟺', '𝟻', '𝟼', '𝟽', '𝟾', '𝟿']),
+            NumberingSystem::Mathsanb => Some(['𝟬', '𝟭', '𝟮', '𝟯', '𝟰', '𝟱', '𝟲', '𝟳', '𝟴', '𝟵']),
+            NumberingSystem::Mathsans => Some(['𝟢', '𝟣', '𝟤', '𝟥', '𝟦', '𝟧', '𝟨', '𝟩', '𝟪', '𝟫']),
+            NumberingSystem::Mlym => Some(['൦', '൧', '൨', '൩', '൪', '൫', '൬', '൭', '൮', '൯']),
+            NumberingSystem::Modi => Some(['𑙐', '𑙑', '𑙒', '𑙓', '𑙔', '𑙕', '𑙖', '𑙗', '𑙘', '𑙙']),
+            NumberingSystem::Mong => Some(['᠐', '᠑', '᠒', '᠓', '᠔', '᠕', '᠖', '᠗', '᠘', '᠙']),
+            NumberingSystem::Mroo => Some(['𖩠', '𖩡', '𖩢', '𖩣', '𖩤', '𖩥', '𖩦', '𖩧', '𖩨', '𖩩']),
+            NumberingSystem::Mtei => Some(['꯰', '꯱', '꯲', '꯳', '꯴', '꯵', '꯶', '꯷', '꯸', '꯹']),
+            NumberingSystem::Mymr => Some(['၀', '၁', '၂', '၃', '၄', '၅', '၆', '၇', '၈', '၉']),
+            NumberingSystem::Mymrshan => Some(['႐', '႑', '႒', '႓', '႔', '႕', '႖', '႗', '႘', '႙']),
+            NumberingSystem::Mymrtlng => Some(['꧰', '꧱', '꧲', '꧳', '꧴', '꧵', '꧶', '꧷', '꧸', '꧹']),
+            NumberingSystem::Nagm => Some(['𞓰', '𞓱', '𞓲', '𞓳', '𞓴', '𞓵', '𞓶', '𞓷', '𞓸', '𞓹']),
+            NumberingSystem::Newa => Some(['𑑐', '𑑑', '𑑒', '𑑓', '𑑔', '𑑕', '𑑖', '𑑗', '𑑘', '𑑙']),
+            NumberingSystem::Nkoo => Some(['߀', '߁', '߂', '߃', '߄', '߅', '߆', '߇', '߈', '߉']),
+            NumberingSystem::Olck => Some(['᱐', '᱑', '᱒', '᱓', '᱔', '᱕', '᱖', '᱗', '᱘', '᱙']),
+            NumberingSystem::Orya => Some(['୦', '୧', '୨', '୩', '୪', '୫', '୬', '୭', '୮', '୯']),
+            NumberingSystem::Osma => Some(['𐒠', '𐒡', '𐒢', '𐒣', '𐒤', '𐒥', '𐒦', '𐒧', '𐒨', '𐒩']),
+            NumberingSystem::Rohg => Some(['𐴰', '𐴱', '𐴲', '𐴳', '𐴴', '𐴵', '𐴶', '𐴷', '𐴸', '𐴹']),
+            NumberingSystem::Saur => Some(['꣐', '꣑', '꣒', '꣓', '꣔', '꣕', '꣖', '꣗', '꣘', '꣙']),
+            NumberingSystem::Segment => Some(['🯰', '🯱', '🯲', '🯳', '🯴', '🯵', '🯶', '🯷', '🯸', '🯹']),
+            NumberingSystem::Shrd => Some(['𑇐', '𑇑', '𑇒', '𑇓', '𑇔', '𑇕', '𑇖', '𑇗', '𑇘', '𑇙']),
+            NumberingSystem::Sind => Some(['𑋰', '𑋱', '𑋲', '𑋳', '𑋴', '𑋵', '𑋶', '𑋷', '𑋸', '𑋹']),
+            NumberingSystem::Sinh => Some(['෦', '෧', '෨', '෩', '෪', '෫', '෬', '෭', '෮', '෯']),
+            NumberingSystem::Sora => Some(['𑃰', '𑃱', '𑃲', '𑃳', '𑃴', '𑃵', '𑃶', '𑃷', '𑃸', '𑃹']),
+            NumberingSystem::Sund => Some(['᮰', '᮱', '᮲', '᮳', '᮴', '᮵', '᮶', '᮷', '᮸', '᮹']),
+            NumberingSystem::Takr => Some(['𑛀', '𑛁', '𑛂', '𑛃', '𑛄', '𑛅', '𑛆', '𑛇', '𑛈', '𑛉']),
+            NumberingSystem::Talu => Some(['᧐', '᧑', '᧒', '᧓', '᧔', '᧕', '᧖', '᧗', '᧘', '᧙']),
+            NumberingSystem::Tamldec => Some(['௦', '௧', '௨', '௩', '௪', '௫', '௬', '௭', '௮', '௯']),
+            NumberingSystem::Telu => Some(['౦', '౧', '౨', '౩', '౪', '౫', '౬', '౭', '౮', '౯']),
+            NumberingSystem::Thai => Some(['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙']),
+            NumberingSystem::Tibt => Some(['༠', '༡', '༢', '༣', '༤', '༥', '༦', '༧', '༨', '༩']),
+            NumberingSystem::Tirh => Some(['𑓐', '𑓑', '𑓒', '𑓓', '𑓔', '𑓕', '𑓖', '𑓗', '𑓘', '𑓙']),
+            NumberingSystem::Tnsa => Some(['𖫀', '𖫁', '𖫂', '𖫃', '𖫄', '𖫅', '𖫆', '𖫇', '𖫈', '𖫉']),
+            NumberingSystem::Vaii => Some(['꘠', '꘡', '꘢', '꘣', '꘤', '꘥', '꘦', '꘧', '꘨', '꘩']),
+            NumberingSystem::Wara => Some(['𑣠', '𑣡', '𑣢', '𑣣', '𑣤', '𑣥', '𑣦', '𑣧', '𑣨', '𑣩']),
+            NumberingSystem::Wcho => Some(['𞋰', '𞋱', '𞋲', '𞋳', '𞋴', '𞋵', '𞋶', '𞋷', '𞋸', '𞋹']),
+            _ => None,
+        }
+    }
+
+    /// Returns whether numbers are written by rules, e.g. Roman numerals,
+    /// rather than with ten digits.
+    pub fn is_algorithmic(&self) -> bool {
+        self.digits().is_none()
+    }
+
+    /// The RBNF rule set of an algorithmic system, with the locale whose
+    /// rules it uses if it names one, e.g. `ja` for `jpan`.
+    fn _rules(&self) -> Option<(Option<Locale>, &'static str)> {
+        match self {
+            NumberingSystem::Armn => Some((None, "%armenian-upper")),
+            NumberingSystem::Armnlow => Some((None, "%armenian-lower")),
+            NumberingSystem::Cyrl => Some((None, "%cyrillic-lower")),
+            NumberingSystem::Ethi => Some((None, "%ethiopic")),
+            NumberingSystem::Geor => Some((None, "%georgian")),
+            NumberingSystem::Grek => Some((None, "%greek-upper")),
+            NumberingSystem::Greklow => Some((None, "%greek-lower")),
+            NumberingSystem::Hanidays => Some((Some(Locale::zh), "%spellout-numbering-days")),
+            NumberingSystem::Hans => Some((Some(Locale::zh), "%spellout-cardinal")),
+            NumberingSystem::Hansfin => Some((Some(Locale::zh), "%spellout-cardinal-financial")),
+            NumberingSystem::Hant => Some((Some(Locale::zh_Hant), "%spellout-cardinal")),
+            NumberingSystem::Hantfin => {
+                Some((Some(Locale::zh_Hant), "%spellout-cardinal-financial"))
+            }
+            NumberingSystem::Hebr => Some((None, "%hebrew")),
+            NumberingSystem::Jpan => Some((Some(Locale::ja), "%spellout-cardinal")),
+            NumberingSystem::Jpanfin => Some((Some(Locale::ja), "%spellout-cardinal-financial")),
+            NumberingSystem::Jpanyear => Some((Some(Locale::ja), "%spellout-numbering-year-latn")),
+            NumberingSystem::Roman => Some((None, "%roman-upper")),
+            NumberingSystem::Romanlow => Some((None, "%roman-lower")),
+            NumberingSystem::Taml => Some((None, "%tamil")),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for NumberingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for NumberingSystem {
+    type Err = LocaleError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "adlm" => Ok(NumberingSystem::Adlm),
+            "ahom" => Ok(NumberingSystem::Ahom),
+            "arab" => Ok(NumberingSystem::Arab),
+            "arabext" => Ok(NumberingSystem::Arabext),
+            "armn" => Ok(NumberingSystem::Armn),
+            "armnlow" => Ok(NumberingSystem::Armnlow),
+            "bali" => Ok(NumberingSystem::Bali),
+            "beng" => Ok(NumberingSystem::Beng),
+            "bhks" => Ok(NumberingSystem::Bhks),
+            "brah" => Ok(NumberingSystem::Brah),
+            "cakm" => Ok(NumberingSystem::Cakm),
+            "cham" => Ok(NumberingSystem::Cham),
+            "cyrl" => Ok(NumberingSystem::Cyrl),
+            "deva" => Ok(NumberingSystem::Deva),
+            "diak" => Ok(NumberingSystem::Diak),
+            "ethi" => Ok(NumberingSystem::Ethi),
+            "fullwide" => Ok(NumberingSystem::Fullwide),
+            "geor" => Ok(NumberingSystem::Geor),
+            "gong" => Ok(NumberingSystem::Gong),
+            "gonm" => Ok(NumberingSystem::Gonm),
+            "grek" => Ok(NumberingSystem::Grek),
+            "greklow" => Ok(NumberingSystem::Greklow),
+            "gujr" => Ok(NumberingSystem::Gujr),
+            "guru" => Ok(NumberingSystem::Guru),
+            "hanidays" => Ok(NumberingSystem::Hanidays),
+            "hanidec" => Ok(NumberingSystem::Hanidec),
+            "hans" => Ok(NumberingSystem::Hans),
+            "hansfin" => Ok(NumberingSystem::Hansfin),
+            "hant" => Ok(NumberingSystem::Hant),
+            "hantfin" => Ok(NumberingSystem::Hantfin),
+            "hebr" => Ok(NumberingSystem::Hebr),
+            "hmng" => Ok(NumberingSystem::Hmng),
+            "hmnp" => Ok(NumberingSystem::Hmnp),
+            "java" => Ok(NumberingSystem::Java),
+            "jpan" => Ok(NumberingSystem::Jpan),
+            "jpanfin" => Ok(NumberingSystem::Jpanfin),
+            "jpanyear" => Ok(NumberingSystem::Jpanyear),
+            "kali" => Ok(NumberingSystem::Kali),
+            "kawi" => Ok(NumberingSystem::Kawi),
+            "khmr" => Ok(NumberingSystem::Khmr),
+            "knda" => Ok(NumberingSystem::Knda),
+            "lana" => Ok(NumberingSystem::Lana),
+            "lanatham" => Ok(NumberingSystem::Lanatham),
+            "laoo" => Ok(NumberingSystem::Laoo),
+            "latn" => Ok(NumberingSystem::Latn),
+            "lepc" => Ok(NumberingSystem::Lepc),
+            "limb" => Ok(NumberingSystem::Limb),
+            "mathbold" => Ok(NumberingSystem::Mathbold),
+            "mathdbl" => Ok(NumberingSystem::Mathdbl),
+            "mathmono" => Ok(NumberingSystem::Mathmono),
+            "mathsanb" => Ok(NumberingSystem::Mathsanb),
+            "mathsans" => Ok(NumberingSystem::Mathsans),
+            "mlym" => Ok(NumberingSystem::Mlym),
+            "modi" => Ok(NumberingSystem::Modi),
+            "mong" => Ok(NumberingSystem::Mong),
+            "mroo" => Ok(NumberingSystem::Mroo),
+            "mtei" => Ok(NumberingSystem::Mtei),
+            "mymr" => Ok(NumberingSystem::Mymr),
+            "mymrshan" => Ok(NumberingSystem::Mymrshan),
+            "mymrtlng" => Ok(NumberingSystem::Mymrtlng),
+            "nagm" => Ok(NumberingSystem::Nagm),
+            "newa" => Ok(NumberingSystem::Newa),
+            "nkoo" => Ok(NumberingSystem::Nkoo),
+            "olck" => Ok(NumberingSystem::Olck),
+            "orya" => Ok(NumberingSystem::Orya),
+            "osma" => Ok(NumberingSystem::Osma),
+            "rohg" => Ok(NumberingSystem::Rohg),
+            "roman" => Ok(NumberingSystem::Roman),
+            "romanlow" => Ok(NumberingSystem::Romanlow),
+            "saur" => Ok(NumberingSystem::Saur),
+            "segment" => Ok(NumberingSystem::Segment),
+            "shrd" => Ok(NumberingSystem::Shrd),
+            "sind" => Ok(NumberingSystem::Sind),
+            "sinh" => Ok(NumberingSystem::Sinh),
+            "sora" => Ok(NumberingSystem::Sora),
+            "sund" => Ok(NumberingSystem::Sund),
+            "takr" => Ok(NumberingSystem::Takr),
+            "talu" => Ok(NumberingSystem::Talu),
+            "taml" => Ok(NumberingSystem::Taml),
+            "tamldec" => Ok(NumberingSystem::Tamldec),
+            "telu" => Ok(NumberingSystem::Telu),
+            "thai" => Ok(NumberingSystem::Thai),
+            "tibt" => Ok(NumberingSystem::Tibt),
+            "tirh" => Ok(NumberingSystem::Tirh),
+            "tnsa" => Ok(NumberingSystem::Tnsa),
+            "vaii" => Ok(NumberingSystem::Vaii),
+            "wara" => Ok(NumberingSystem::Wara),
+            "wcho" => Ok(NumberingSystem::Wcho),
+            _ => Err(LocaleError::UnknownNumberingSystem(s.to_string())),
+        }
+    }
+}
+
+/// Whether to insert grouping separators, e.g. `1,234`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GroupingStrategy {
+    /// Group as the locale does, following its minimum grouping digits.
+    #[default]
+    Auto,
+    /// Group every number longer than the primary group size.
+    Always,
+    /// Never insert grouping separators.
+    Never,
+}
+
+/// The alternative numbering systems a locale may name besides its default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OtherNumberingSystem {
+    /// Native digits, e.g. `arab` for Arabic or `deva` for Hindi.
+    Native,
+    /// Traditional numerals, e.g. `hans` for Chinese.
+    Traditional,
+    /// Financial numerals, e.g. `hansfin` for Chinese.
+    Finance,
+}
+
+/// The number symbols a locale uses with a numbering system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberSymbols {
+    pub decimal: &'static str,
+    pub group: &'static str,
+    pub minus_sign: &'static str,
+}
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_0: [(NumberingSystem, NumberSymbols); 0] = [
+    
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_1: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Ethi, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_2: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Arab, NumberSymbols { decimal: "٫", group: "٬", minus_sign: "\u{61c}-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_3: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ".", group: ",", minus_sign: "\u{200e}-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_4: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ",", group: ".", minus_sign: "\u{200e}-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_5: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_6: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ",", group: ".", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_7: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Tibt, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_8: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Deva, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_9: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Grek, NumberSymbols { decimal: ",", group: ".", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_10: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ".", group: ",", minus_sign: "\u{200e}−" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_11: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ".", group: "⹁", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_12: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Gujr, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_13: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Hebr, NumberSymbols { decimal: ".", group: ",", minus_sign: "\u{200e}-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_14: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Armn, NumberSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_15: [(NumberingSystem, NumberSymbols); 2] = [
+    (NumberingSystem::Jpan, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" }),
+    (NumberingSystem::Jpanfin, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_16: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Java, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_17: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Geor, NumberSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_18: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Khmr, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_19: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Knda, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_20: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ".", group: "،", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_21: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Laoo, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_22: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Mlym, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_23: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Orya, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_24: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Guru, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_25: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Latn, NumberSymbols { decimal: ",", group: ".", minus_sign: "\u{200e}−" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_26: [(NumberingSystem, NumberSymbols); 2] = [
+    (NumberingSystem::Taml, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" }),
+    (NumberingSystem::Tamldec, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_27: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Telu, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_28: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Thai, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_29: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Arabext, NumberSymbols { decimal: "٫", group: "٬", minus_sign: "\u{200e}-\u{200e}" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_30: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Vaii, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_31: [(NumberingSystem, NumberSymbols); 1] = [
+    (NumberingSystem::Hebr, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_32: [(NumberingSystem, NumberSymbols); 3] = [
+    (NumberingSystem::Hanidec, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" }),
+    (NumberingSystem::Hant, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" }),
+    (NumberingSystem::Hantfin, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+#[rustfmt::skip]
+static _NUMBER_SYMBOLS_33: [(NumberingSystem, NumberSymbols); 3] = [
+    (NumberingSystem::Hanidec, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" }),
+    (NumberingSystem::Hans, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" }),
+    (NumberingSystem::Hansfin, NumberSymbols { decimal: ".", group: ",", minus_sign: "-" })
+];
+
+impl Locale {
+    /// Returns the numbering system the locale formats numbers in by default.
+    pub fn default_numbering_system(&self) -> NumberingSystem {
+        match self {
+            Locale::aa => NumberingSystem::Latn,
+            Locale::aa_DJ => NumberingSystem::Latn,
+            Locale::aa_ER => NumberingSystem::Latn,
+            Locale::ab => NumberingSystem::Latn,
+            Locale::af => NumberingSystem::Latn,
+            Locale::af_NA => NumberingSystem::Latn,
+            Locale::agq => NumberingSystem::Latn,
+            Locale::ak => NumberingSystem::Latn,
+            Locale::am => NumberingSystem::Latn,
+            Locale::an => NumberingSystem::Latn,
+            Locale::ann => NumberingSystem::Latn,
+            Locale::apc => NumberingSystem::Latn,
+            Locale::ar => NumberingSystem::Latn,
+            Locale::ar_AE => NumberingSystem::Latn,
+            Locale::ar_BH => NumberingSystem::Arab,
+            Locale::ar_DJ => NumberingSystem::Arab,
+            Locale::ar_DZ => NumberingSystem::Latn,
+            Locale::ar_EG => NumberingSystem::Arab,
+            Locale::ar_EH => NumberingSystem::Latn,
+            Locale::ar_ER => NumberingSystem::Arab,
+            Locale::ar_IL => NumberingSystem::Arab,
+            Locale::ar_IQ => NumberingSystem::Arab,
+            Locale::ar_JO => NumberingSystem::Arab,
+            Locale::ar_KM => NumberingSystem::Arab,
+            Locale::ar_KW => NumberingSystem::Arab,
+            Locale::ar_LB => NumberingSystem::Arab,
+            Locale::ar_LY => NumberingSystem::Latn,
+            Locale::ar_MA => NumberingSystem::Latn,
+            Locale::ar_MR => NumberingSystem::Arab,
+            Locale::ar_OM => NumberingSystem::Arab,
+            Locale::ar_PS => NumberingSystem::Arab,
+            Locale::ar_QA => NumberingSystem::Arab,
+            Locale::ar_SA => NumberingSystem::Arab,
+            Locale::ar_SD => NumberingSystem::Arab,
+            Locale::ar_SO => NumberingSystem::Arab,
+            Locale::ar_SS => NumberingSystem::Arab,
+            Locale::ar_SY => NumberingSystem::Arab,
+            Locale::ar_TD => NumberingSystem::Arab,
+            Locale::ar_TN => NumberingSystem::Latn,
+            Locale::ar_YE => NumberingSystem::Arab,
+            Locale::arn => NumberingSystem::Latn,
+            Locale::as_ => NumberingSystem::Beng,
+            Locale::asa => NumberingSystem::Latn,
+            Locale::ast => NumberingSystem::Latn,
+            Locale::az => NumberingSystem::Latn,
+            Locale::az_Arab => NumberingSystem::Arabext,
+            Locale::az_Arab_IQ => NumberingSystem::Arabext,
+            Locale::az_Arab_TR => NumberingSystem::Arabext,
+            Locale::az_Cyrl => NumberingSystem::Latn,
+            Locale::az_Latn => NumberingSystem::Latn,
+            Locale::ba => NumberingSystem::Latn,
+            Locale::bal => NumberingSystem::Latn,
+            Locale::bal_Arab => NumberingSystem::Latn,
+            Locale::bal_Latn => NumberingSystem::Latn,
+            Locale::bas => NumberingSystem::Latn,
+            Locale::be => NumberingSystem::Latn,
+            Locale::be_tarask => NumberingSystem::Latn,
+            Locale::bem => NumberingSystem::Latn,
+            Locale::bew => NumberingSystem::Latn,
+            Locale::bez => NumberingSystem::Latn,
+            Locale::bg => NumberingSystem::Latn,
+            Locale::bgc => NumberingSystem::Deva,
+            Locale::bgn => NumberingSystem::Arabext,
+            Locale::bgn_AE => NumberingSystem::Arabext,
+            Locale::bgn_AF => NumberingSystem::Arabext,
+            Locale::bgn_IR => NumberingSystem::Arabext,
+            Locale::bgn_OM => NumberingSystem::Arabext,
+            Locale::bho => NumberingSystem::Deva,
+            Locale::blo => NumberingSystem::Latn,
+            Locale::blt => NumberingSystem::Latn,
+            Locale::bm => NumberingSystem::Latn,
+            Locale::bm_Nkoo => NumberingSystem::Latn,
+            Locale::bn => NumberingSystem::Beng,
+            Locale::bn_IN => NumberingSystem::Beng,
+            Locale::bo => NumberingSystem::Latn,
+            Locale::bo_IN => NumberingSystem::Latn,
+            Locale::bqi => NumberingSystem::Latn,
+            Locale::br => NumberingSystem::Latn,
+            Locale::brx => NumberingSystem::Latn,
+            Locale::bs => NumberingSystem::Latn,
+            Locale::bs_Cyrl => NumberingSystem::Latn,
+            Locale::bs_Latn => NumberingSystem::Latn,
+            Locale::bss => NumberingSystem::Latn,
+            Locale::bua => NumberingSystem::Latn,
+            Locale::byn => NumberingSystem::Latn,
+            Locale::ca => NumberingSystem::Latn,
+            Locale::ca_AD => NumberingSystem::Latn,
+            Locale::ca_ES_valencia => NumberingSystem::Latn,
+            Locale::ca_FR => NumberingSystem::Latn,
+            Locale::ca_IT => NumberingSystem::Latn,
+            Locale::cad => NumberingSystem::Latn,
+            Locale::cch => NumberingSystem::Latn,
+            Locale::ccp => NumberingSystem::Cakm,
+            Locale::ccp_IN => NumberingSystem::Cakm,
+            Locale::ce => NumberingSystem::Latn,
+            Locale::ceb => NumberingSystem::Latn,
+            Locale::cgg => NumberingSystem::Latn,
+            Locale::cho => NumberingSystem::Latn,
+            Locale::chr => NumberingSystem::Latn,
+            Locale::cic => NumberingSystem::Latn,
+            Locale::ckb => NumberingSystem::Arab,
+            Locale::ckb_IR => NumberingSystem::Arab,
+            Locale::co => NumberingSystem::Latn,
+            Locale::cop => NumberingSystem::Latn,
+            Locale::cs => NumberingSystem::Latn,
+            Locale::csw => NumberingSystem::Latn,
+            Locale::cu => NumberingSystem::Latn,
+            Locale::cv => NumberingSystem::Latn,
+            Locale::cy => NumberingSystem::Latn,
+            Locale::da => NumberingSystem::Latn,
+            Locale::da_GL => NumberingSystem::Latn,
+            Locale::dav => NumberingSystem::Latn,
+            Locale::de => NumberingSystem::Latn,
+            Locale::de_AT => NumberingSystem::Latn,
+            Locale::de_BE => NumberingSystem::Latn,
+            Locale::de_CH => NumberingSystem::Latn,
+            Locale::de_IT => NumberingSystem::Latn,
+            Locale::de_LI => NumberingSystem::Latn,
+            Locale::de_LU => NumberingSystem::Latn,
+            Locale::dje => NumberingSystem::Latn,
+            Locale::doi => NumberingSystem::Latn,
+            Locale::dsb => NumberingSystem::Latn,
+            Locale::dua => NumberingSystem::Latn,
+            Locale::dv => NumberingSystem::Latn,
+            Locale::dyo => NumberingSystem::Latn,
+            Locale::dz => NumberingSystem::Tibt,
+            Locale::ebu => NumberingSystem::Latn,
+            Locale::ee => NumberingSystem::Latn,
+            Locale::ee_TG => NumberingSystem::Latn,
+            Locale::el => NumberingSystem::Latn,
+            Locale::el_CY => NumberingSystem::Latn,
+            Locale::el_polyton => NumberingSystem::Latn,
+            Locale::en => NumberingSystem::Latn,
+            Locale::en_001 => NumberingSystem::Latn,
+            Locale::en_150 => NumberingSystem::Latn,
+            Locale::en_AE => NumberingSystem::Latn,
+            Locale::en_AG => NumberingSystem::Latn,
+            Locale::en_AI => NumberingSystem::Latn,
+            Locale::en_AS => NumberingSystem::Latn,
+            Locale::en_AT => NumberingSystem::Latn,
+            Locale::en_AU => NumberingSystem::Latn,
+            Locale::en_BB => NumberingSystem::Latn,
+            Locale::en_BE => NumberingSystem::Latn,
+            Locale::en_BI => NumberingSystem::Latn,
+            Locale::en_BM => NumberingSystem::Latn,
+            Locale::en_BS => NumberingSystem::Latn,
+            Locale::en_BW => NumberingSystem::Latn,
+            Locale::en_BZ => NumberingSystem::Latn,
+            Locale::en_CA => NumberingSystem::Latn,
+            Locale::en_CC => NumberingSystem::Latn,
+            Locale::en_CH => NumberingSystem::Latn,
+            Locale::en_CK => NumberingSystem::Latn,
+            Locale::en_CM => NumberingSystem::Latn,
+            Locale::en_CX => NumberingSystem::Latn,
+            Locale::en_CY => NumberingSystem::Latn,
+            Locale::en_CZ => NumberingSystem::Latn,
+            Locale::en_DE => NumberingSystem::Latn,
+            Locale::en_DG => NumberingSystem::Latn,
+            Locale::en_DK => NumberingSystem::Latn,
+            Locale::en_DM => NumberingSystem::Latn,
+            Locale::en_Dsrt => NumberingSystem::Latn,
+            Locale::en_EE => NumberingSystem::Latn,
+            Locale::en_ER => NumberingSystem::Latn,
+            Locale::en_ES => NumberingSystem::Latn,
+            Locale::en_FI => NumberingSystem::Latn,
+            Locale::en_FJ => NumberingSystem::Latn,
+            Locale::en_FK => NumberingSystem::Latn,
+            Locale::en_FM => NumberingSystem::Latn,
+            Locale::en_FR => NumberingSystem::Latn,
+            Locale::en_GB => NumberingSystem::Latn,
+            Locale::en_GD => NumberingSystem::Latn,
+            Locale::en_GE => NumberingSystem::Latn,
+            Locale::en_GG => NumberingSystem::Latn,
+            Locale::en_GH => NumberingSystem::Latn,
+            Locale::en_GI => NumberingSystem::Latn,
+            Locale::en_GM => NumberingSystem::Latn,
+            Locale::en_GS => NumberingSystem::Latn,
+            Locale::en_GU => NumberingSystem::Latn,
+            Locale::en_GY => NumberingSystem::Latn,
+            Locale::en_HK => NumberingSystem::Latn,
+            Locale::en_HU => NumberingSystem::Latn,
+            Locale::en_ID => NumberingSystem::Latn,
+            Locale::en_IE => NumberingSystem::Latn,
+            Locale::en_IL => NumberingSystem::Latn,
+            Locale::en_IM => NumberingSystem::Latn,
+            Locale::en_IN => NumberingSystem::Latn,
+            Locale::en_IO => NumberingSystem::Latn,
+            Locale::en_IT => NumberingSystem::Latn,
+            Locale::en_JE => NumberingSystem::Latn,
+            Locale::en_JM => NumberingSystem::Latn,
+            Locale::en_JP => NumberingSystem::Latn,
+            Locale::en_KE => NumberingSystem::Latn,
+            Locale::en_KI => NumberingSystem::Latn,
+            Locale::en_KN => NumberingSystem::Latn,
+            Locale::en_KY => NumberingSystem::Latn,
+            Locale::en_LC => NumberingSystem::Latn,
+            Locale::en_LR => NumberingSystem::Latn,
+            Locale::en_LS => NumberingSystem::Latn,
+            Locale::en_LT => NumberingSystem::Latn,
+            Locale::en_LV => NumberingSystem::Latn,
+            Locale::en_MG => NumberingSystem::Latn,
+            Locale::en_MH => NumberingSystem::Latn,
+            Locale::en_MO => NumberingSystem::Latn,
+            Locale::en_MP => NumberingSystem::Latn,
+            Locale::en_MS => NumberingSystem::Latn,
+            Locale::en_MT => NumberingSystem::Latn,
+            Locale::en_MU => NumberingSystem::Latn,
+            Locale::en_MV => NumberingSystem::Latn,
+            Locale::en_MW => NumberingSystem::Latn,
+            Locale::en_MY => NumberingSystem::Latn,
+            Locale::en_NA => NumberingSystem::Latn,
+            Locale::en_NF => NumberingSystem::Latn,
+            Locale::en_NG => NumberingSystem::Latn,
+            Locale::en_NL => NumberingSystem::Latn,
+            Locale::en_NO => NumberingSystem::Latn,
+            Locale::en_NR => NumberingSystem::Latn,
+            Locale::en_NU => NumberingSystem::Latn,
+            Locale::en_NZ => NumberingSystem::Latn,
+            Locale::en_PG => NumberingSystem::Latn,
+            Locale::en_PH => NumberingSystem::Latn,
+            Locale::en_PK => NumberingSystem::Latn,
+            Locale::en_PL => NumberingSystem::Latn,
+            Locale::en_PN => NumberingSystem::Latn,
+            Locale::en_PR => NumberingSystem::Latn,
+            Locale::en_PT => NumberingSystem::Latn,
+            Locale::en_PW => NumberingSystem::Latn,
+            Locale::en_RO => NumberingSystem::Latn,
+            Locale::en_RW => NumberingSystem::Latn,
+            Locale::en_SB => NumberingSystem::Latn,
+            Locale::en_SC => NumberingSystem::Latn,
+            Locale::en_SD => NumberingSystem::Latn,
+            Locale::en_SE => NumberingSystem::Latn,
+            Locale::en_SG => NumberingSystem::Latn,
+            Locale::en_SH => NumberingSystem::Latn,
+            Locale::en_SI => NumberingSystem::Latn,
+            Locale::en_SK => NumberingSystem::Latn,
+            Locale::en_SL => NumberingSystem::Latn,
+            Locale::en_SS => NumberingSystem::Latn,
+            Locale::en_SX => NumberingSystem::Latn,
+            Locale::en_SZ => NumberingSystem::Latn,
+            Locale::en_Shaw => NumberingSystem::Latn,
+            Locale::en_TC => NumberingSystem::Latn,
+            Locale::en_TK => NumberingSystem::Latn,
+            Locale::en_TO => NumberingSystem::Latn,
+            Locale::en_TT => NumberingSystem::Latn,
+            Locale::en_TV => NumberingSystem::Latn,
+            Locale::en_TZ => NumberingSystem::Latn,
+            Locale::en_UA => NumberingSystem::Latn,
+            Locale::en_UG => NumberingSystem::Latn,
+            Locale::en_UM => NumberingSystem::Latn,
+            Locale::en_VC => NumberingSystem::Latn,
+            Locale::en_VG => NumberingSystem::Latn,
+            Locale::en_VI => NumberingSystem::Latn,
+            Locale::en_VU => NumberingSystem::Latn,
+            Locale::en_WS => NumberingSystem::Latn,
+            Locale::en_ZA => NumberingSystem::Latn,
+            Locale::en_ZM => NumberingSystem::Latn,
+            Locale::en_ZW => NumberingSystem::Latn,
+            Locale::eo => NumberingSystem::Latn,
+            Locale::es => NumberingSystem::Latn,
+            Locale::es_419 => NumberingSystem::Latn,
+            Locale::es_AR => NumberingSystem::Latn,
+            Locale::es_BO => NumberingSystem::Latn,
+            Locale::es_BR => NumberingSystem::Latn,
+            Locale::es_BZ => NumberingSystem::Latn,
+            Locale::es_CL => NumberingSystem::Latn,
+            Locale::es_CO => NumberingSystem::Latn,
+            Locale::es_CR => NumberingSystem::Latn,
+            Locale::es_CU => NumberingSystem::Latn,
+            Locale::es_DO => NumberingSystem::Latn,
+            Locale::es_EA => NumberingSystem::Latn,
+            Locale::es_EC => NumberingSystem::Latn,
+            Locale::es_GQ => NumberingSystem::Latn,
+            Locale::es_GT => NumberingSystem::Latn,
+            Locale::es_HN => NumberingSystem::Latn,
+            Locale::es_IC => NumberingSystem::Latn,
+            Locale::es_MX => NumberingSystem::Latn,
+            Locale::es_NI => NumberingSystem::Latn,
+            Locale::es_PA => NumberingSystem::Latn,
+            Locale::es_PE => NumberingSystem::Latn,
+            Locale::es_PH => NumberingSystem::Latn,
+            Locale::es_PR => NumberingSystem::Latn,
+            Locale::es_PY => NumberingSystem::Latn,
+            Locale::es_SV => NumberingSystem::Latn,
+            Locale::es_US => NumberingSystem::Latn,
+            Locale::es_UY => NumberingSystem::Latn,
+            Locale::es_VE => NumberingSystem::Latn,
+            Locale::et => NumberingSystem::Latn,
+            Locale::eu => NumberingSystem::Latn,
+            Locale::ewo => NumberingSystem::Latn,
+            Locale::fa => NumberingSystem::Arabext,
+            Locale::fa_AF => NumberingSystem::Arabext,
+            Locale::ff => NumberingSystem::Latn,
+            Locale::ff_Adlm => NumberingSystem::Adlm,
+            Locale::ff_Adlm_BF => NumberingSystem::Adlm,
+            Locale::ff_Adlm_CM => NumberingSystem::Adlm,
+            Locale::ff_Adlm_GH => NumberingSystem::Adlm,
+            Locale::ff_Adlm_GM => NumberingSystem::Adlm,
+            Locale::ff_Adlm_GW => NumberingSystem::Adlm,
+            Locale::ff_Adlm_LR => NumberingSystem::Adlm,
+            Locale::ff_Adlm_MR => NumberingSystem::Adlm,
+            Locale::ff_Adlm_NE => NumberingSystem::Adlm,
+            Locale::ff_Adlm_NG => NumberingSystem::Adlm,
+            Locale::ff_Adlm_SL => NumberingSystem::Adlm,
+            Locale::ff_Adlm_SN => NumberingSystem::Adlm,
+            Locale::ff_Latn => NumberingSystem::Latn,
+            Locale::ff_Latn_BF => NumberingSystem::Latn,
+            Locale::ff_Latn_CM => NumberingSystem::Latn,
+            Locale::ff_Latn_GH => NumberingSystem::Latn,
+            Locale::ff_Latn_GM => NumberingSystem::Latn,
+            Locale::ff_Latn_GN => NumberingSystem::Latn,
+            Locale::ff_Latn_GW => NumberingSystem::Latn,
+            Locale::ff_Latn_LR => NumberingSystem::Latn,
+            Locale::ff_Latn_MR => NumberingSystem::Latn,
+            Locale::ff_Latn_NE => NumberingSystem::Latn,
+            Locale::ff_Latn_NG => NumberingSystem::Latn,
+            Locale::ff_Latn_SL => NumberingSystem::Latn,
+            Locale::fi => NumberingSystem::Latn,
+            Locale::fil => NumberingSystem::Latn,
+            Locale::fo => NumberingSystem::Latn,
+            Locale::fo_DK => NumberingSystem::Latn,
+            Locale::fr => NumberingSystem::Latn,
+            Locale::fr_BE => NumberingSystem::Latn,
+            Locale::fr_BF => NumberingSystem::Latn,
+            Locale::fr_BI => NumberingSystem::Latn,
+            Locale::fr_BJ => NumberingSystem::Latn,
+            Locale::fr_BL => NumberingSystem::Latn,
+            Locale::fr_CA => NumberingSystem::Latn,
+            Locale::fr_CD => NumberingSystem::Latn,
+            Locale::fr_CF => NumberingSystem::Latn,
+            Locale::fr_CG => NumberingSystem::Latn,
+            Locale::fr_CH => NumberingSystem::Latn,
+            Locale::fr_CI => NumberingSystem::Latn,
+            Locale::fr_CM => NumberingSystem::Latn,
+            Locale::fr_DJ => NumberingSystem::Latn,
+            Locale::fr_DZ => NumberingSystem::Latn,
+            Locale::fr_GA => NumberingSystem::Latn,
+            Locale::fr_GF => NumberingSystem::Latn,
+            Locale::fr_GN => NumberingSystem::Latn,
+            Locale::fr_GP => NumberingSystem::Latn,
+            Locale::fr_GQ => NumberingSystem::Latn,
+            Locale::fr_HT => NumberingSystem::Latn,
+            Locale::fr_KM => NumberingSystem::Latn,
+            Locale::fr_LU => NumberingSystem::Latn,
+            Locale::fr_MA => NumberingSystem::Latn,
+            Locale::fr_MC => NumberingSystem::Latn,
+            Locale::fr_MF => NumberingSystem::Latn,
+            Locale::fr_MG => NumberingSystem::Latn,
+            Locale::fr_ML => NumberingSystem::Latn,
+            Locale::fr_MQ => NumberingSystem::Latn,
+            Locale::fr_MR => NumberingSystem::Latn,
+            Locale::fr_MU => NumberingSystem::Latn,
+            Locale::fr_NC => NumberingSystem::Latn,
+            Locale::fr_NE => NumberingSystem::Latn,
+            Locale::fr_PF => NumberingSystem::Latn,
+            Locale::fr_PM => NumberingSystem::Latn,
+            Locale::fr_RE => NumberingSystem::Latn,
+            Locale::fr_RW => NumberingSystem::Latn,
+            Locale::fr_SC => NumberingSystem::Latn,
+            Locale::fr_SN => NumberingSystem::Latn,
+            Locale::fr_SY => NumberingSystem::Latn,
+            Locale::fr_TD => NumberingSystem::Latn,
+            Locale::fr_TG => NumberingSystem::Latn,
+            Locale::fr_TN => NumberingSystem::Latn,
+            Locale::fr_VU => NumberingSystem::Latn,
+            Locale::fr_WF => NumberingSystem::Latn,
+            Locale::fr_YT => NumberingSystem::Latn,
+            Locale::frr => NumberingSystem::Latn,
+            Locale::fur => NumberingSystem::Latn,
+            Locale::fy => NumberingSystem::Latn,
+            Locale::ga => NumberingSystem::Latn,
+            Locale::ga_GB => NumberingSystem::Latn,
+            Locale::gaa => NumberingSystem::Latn,
+            Locale::gd => NumberingSystem::Latn,
+            Locale::gez => NumberingSystem::Latn,
+            Locale::gez_ER => NumberingSystem::Latn,
+            Locale::gl => NumberingSystem::Latn,
+            Locale::gn => NumberingSystem::Latn,
+            Locale::gsw => NumberingSystem::Latn,
+            Locale::gsw_FR => NumberingSystem::Latn,
+            Locale::gsw_LI => NumberingSystem::Latn,
+            Locale::gu => NumberingSystem::Latn,
+            Locale::guz => NumberingSystem::Latn,
+            Locale::gv => NumberingSystem::Latn,
+            Locale::ha => NumberingSystem::Latn,
+            Locale::ha_Arab => NumberingSystem::Latn,
+            Locale::ha_Arab_SD => NumberingSystem::Latn,
+            Locale::ha_GH => NumberingSystem::Latn,
+            Locale::ha_NE => NumberingSystem::Latn,
+            Locale::haw => NumberingSystem::Latn,
+            Locale::he => NumberingSystem::Latn,
+            Locale::hi => NumberingSystem::Latn,
+            Locale::hi_Latn => NumberingSystem::Latn,
+            Locale::hnj => NumberingSystem::Hmnp,
+            Locale::hnj_Hmnp => NumberingSystem::Hmnp,
+            Locale::hr => NumberingSystem::Latn,
+            Locale::hr_BA => NumberingSystem::Latn,
+            Locale::hsb => NumberingSystem::Latn,
+            Locale::ht => NumberingSystem::Latn,
+            Locale::hu => NumberingSystem::Latn,
+            Locale::hy => NumberingSystem::Latn,
+            Locale::ia => NumberingSystem::Latn,
+            Locale::id => NumberingSystem::Latn,
+            Locale::ie => NumberingSystem::Latn,
+            Locale::ig => NumberingSystem::Latn,
+            Locale::ii => NumberingSystem::Latn,
+            Locale::io => NumberingSystem::Latn,
+            Locale::is => NumberingSystem::Latn,
+            Locale::it => NumberingSystem::Latn,
+            Locale::it_CH => NumberingSystem::Latn,
+            Locale::it_SM => NumberingSystem::Latn,
+            Locale::it_VA => NumberingSystem::Latn,
+            Locale::iu => NumberingSystem::Latn,
+            Locale::iu_Latn => NumberingSystem::Latn,
+            Locale::ja => NumberingSystem::Latn,
+            Locale::jbo => NumberingSystem::Latn,
+            Locale::jgo => NumberingSystem::Latn,
+            Locale::jmc => NumberingSystem::Latn,
+            Locale::jv => NumberingSystem::Latn,
+            Locale::ka => NumberingSystem::Latn,
+            Locale::kaa => NumberingSystem::Latn,
+            Locale::kaa_Cyrl => NumberingSystem::Latn,
+            Locale::kaa_Latn => NumberingSystem::Latn,
+            Locale::kab => NumberingSystem::Latn,
+            Locale::kaj => NumberingSystem::Latn,
+            Locale::kam => NumberingSystem::Latn,
+            Locale::kcg => NumberingSystem::Latn,
+            Locale::kde => NumberingSystem::Latn,
+            Locale::kea => NumberingSystem::Latn,
+            Locale::kek => NumberingSystem::Latn,
+            Locale::ken => NumberingSystem::Latn,
+            Locale::kgp => NumberingSystem::Latn,
+            Locale::khq => NumberingSystem::Latn,
+            Locale::ki => NumberingSystem::Latn,
+            Locale::kk => NumberingSystem::Latn,
+            Locale::kk_Arab => NumberingSystem::Latn,
+            Locale::kk_Cyrl => NumberingSystem::Latn,
+            Locale::kk_KZ => NumberingSystem::Latn,
+            Locale::kkj => NumberingSystem::Latn,
+            Locale::kl => NumberingSystem::Latn,
+            Locale::kln => NumberingSystem::Latn,
+            Locale::km => NumberingSystem::Latn,
+            Locale::kn => NumberingSystem::Latn,
+            Locale::ko => NumberingSystem::Latn,
+            Locale::ko_CN => NumberingSystem::Latn,
+            Locale::ko_KP => NumberingSystem::Latn,
+            Locale::kok => NumberingSystem::Latn,
+            Locale::kok_Deva => NumberingSystem::Latn,
+            Locale::kok_Latn => NumberingSystem::Latn,
+            Locale::kpe => NumberingSystem::Latn,
+            Locale::kpe_GN => NumberingSystem::Latn,
+            Locale::ks => NumberingSystem::Arabext,
+            Locale::ks_Arab => NumberingSystem::Arabext,
+            Locale::ks_Deva => NumberingSystem::Latn,
+            Locale::ksb => NumberingSystem::Latn,
+            Locale::ksf => NumberingSystem::Latn,
+            Locale::ksh => NumberingSystem::Latn,
+            Locale::ku => NumberingSystem::Latn,
+            Locale::ku_Arab => NumberingSystem::Latn,
+            Locale::ku_Arab_IR => NumberingSystem::Latn,
+            Locale::ku_Latn => NumberingSystem::Latn,
+            Locale::ku_Latn_IQ => NumberingSystem::Latn,
+            Locale::ku_Latn_SY => NumberingSystem::Latn,
+            Locale::ku_TR => NumberingSystem::Latn,
+            Locale::kw => NumberingSystem::Latn,
+            Locale::kxv => NumberingSystem::Latn,
+            Locale::kxv_Deva => NumberingSystem::Latn,
+            Locale::kxv_Latn => NumberingSystem::Latn,
+            Locale::kxv_Orya => NumberingSystem::Latn,
+            Locale::kxv_Telu => NumberingSystem::Latn,
+            Locale::ky => NumberingSystem::Latn,
+            Locale::la => NumberingSystem::Latn,
+            Locale::lag => NumberingSystem::Latn,
+            Locale::lb => NumberingSystem::Latn,
+            Locale::lg => NumberingSystem::Latn,
+            Locale::lij => NumberingSystem::Latn,
+            Locale::lkt => NumberingSystem::Latn,
+            Locale::lld => NumberingSystem::Latn,
+            Locale::lmo => NumberingSystem::Latn,
+            Locale::ln => NumberingSystem::Latn,
+            Locale::ln_AO => NumberingSystem::Latn,
+            Locale::ln_CF => NumberingSystem::Latn,
+            Locale::ln_CG => NumberingSystem::Latn,
+            Locale::lo => NumberingSystem::Latn,
+            Locale::lrc => NumberingSystem::Arabext,
+            Locale::lrc_IQ => NumberingSystem::Arabext,
+            Locale::lt => NumberingSystem::Latn,
+            Locale::ltg => NumberingSystem::Latn,
+            Locale::lu => NumberingSystem::Latn,
+            Locale::luo => NumberingSystem::Latn,
+            Locale::luy => NumberingSystem::Latn,
+            Locale::lv => NumberingSystem::Latn,
+            Locale::lzz => NumberingSystem::Latn,
+            Locale::mai => NumberingSystem::Latn,
+            Locale::mas => NumberingSystem::Latn,
+            Locale::mas_TZ => NumberingSystem::Latn,
+            Locale::mdf => NumberingSystem::Latn,
+            Locale::mer => NumberingSystem::Latn,
+            Locale::mfe => NumberingSystem::Latn,
+            Locale::mg => NumberingSystem::Latn,
+            Locale::mgh => NumberingSystem::Latn,
+            Locale::mgo => NumberingSystem::Latn,
+            Locale::mhn => NumberingSystem::Latn,
+            Locale::mi => NumberingSystem::Latn,
+            Locale::mic => NumberingSystem::Latn,
+            Locale::mk => NumberingSystem::Latn,
+            Locale::ml => NumberingSystem::Latn,
+            Locale::mn => NumberingSystem::Latn,
+            Locale::mn_Mong => NumberingSystem::Latn,
+            Locale::mn_Mong_MN => NumberingSystem::Latn,
+            Locale::mni => NumberingSystem::Beng,
+            Locale::mni_Beng => NumberingSystem::Beng,
+            Locale::mni_Mtei => NumberingSystem::Mtei,
+            Locale::moh => NumberingSystem::Latn,
+            Locale::mr => NumberingSystem::Deva,
+            Locale::ms => NumberingSystem::Latn,
+            Locale::ms_Arab => NumberingSystem::Latn,
+            Locale::ms_Arab_BN => NumberingSystem::Latn,
+            Locale::ms_BN => NumberingSystem::Latn,
+            Locale::ms_ID => NumberingSystem::Latn,
+            Locale::ms_SG => NumberingSystem::Latn,
+            Locale::mt => NumberingSystem::Latn,
+            Locale::mua => NumberingSystem::Latn,
+            Locale::mus => NumberingSystem::Latn,
+            Locale::mww => NumberingSystem::Hmnp,
+            Locale::mww_Hmnp => NumberingSystem::Hmnp,
+            Locale::my => NumberingSystem::Mymr,
+            Locale::myv => NumberingSystem::Latn,
+            Locale::mzn => NumberingSystem::Arabext,
+            Locale::naq => NumberingSystem::Latn,
+            Locale::nb => NumberingSystem::Latn,
+            Locale::nb_SJ => NumberingSystem::Latn,
+            Locale::nd => NumberingSystem::Latn,
+            Locale::nds => NumberingSystem::Latn,
+            Locale::nds_NL => NumberingSystem::Latn,
+            Locale::ne => NumberingSystem::Deva,
+            Locale::ne_IN => NumberingSystem::Deva,
+            Locale::nl => NumberingSystem::Latn,
+            Locale::nl_AW => NumberingSystem::Latn,
+            Locale::nl_BE => NumberingSystem::Latn,
+            Locale::nl_BQ => NumberingSystem::Latn,
+            Locale::nl_CW => NumberingSystem::Latn,
+            Locale::nl_SR => NumberingSystem::Latn,
+            Locale::nl_SX => NumberingSystem::Latn,
+            Locale::nmg => NumberingSystem::Latn,
+            Locale::nn => NumberingSystem::Latn,
+            Locale::nnh => NumberingSystem::Latn,
+            Locale::no => NumberingSystem::Latn,
+            Locale::nqo => NumberingSystem::Nkoo,
+            Locale::nr => NumberingSystem::Latn,
+            Locale::nso => NumberingSystem::Latn,
+            Locale::nus => NumberingSystem::Latn,
+            Locale::nv => NumberingSystem::Latn,
+            Locale::ny => NumberingSystem::Latn,
+            Locale::nyn => NumberingSystem::Latn,
+            Locale::oc => NumberingSystem::Latn,
+            Locale::oc_ES => NumberingSystem::Latn,
+            Locale::oka => NumberingSystem::Latn,
+            Locale::oka_US => NumberingSystem::Latn,
+            Locale::om => NumberingSystem::Latn,
+            Locale::om_KE => NumberingSystem::Latn,
+            Locale::or => NumberingSystem::Latn,
+            Locale::os => NumberingSystem::Latn,
+            Locale::os_RU => NumberingSystem::Latn,
+            Locale::osa => NumberingSystem::Latn,
+            Locale::pa => NumberingSystem::Latn,
+            Locale::pa_Arab => NumberingSystem::Arabext,
+            Locale::pa_Guru => NumberingSystem::Latn,
+            Locale::pap => NumberingSystem::Latn,
+            Locale::pap_AW => NumberingSystem::Latn,
+            Locale::pcm => NumberingSystem::Latn,
+            Locale::pi => NumberingSystem::Latn,
+            Locale::pi_Latn => NumberingSystem::Latn,
+            Locale::pis => NumberingSystem::Latn,
+            Locale::pl => NumberingSystem::Latn,
+            Locale::pms => NumberingSystem::Latn,
+            Locale::prg => NumberingSystem::Latn,
+            Locale::ps => NumberingSystem::Arabext,
+            Locale::ps_PK => NumberingSystem::Arabext,
+            Locale::pt => NumberingSystem::Latn,
+            Locale::pt_AO => NumberingSystem::Latn,
+            Locale::pt_CH => NumberingSystem::Latn,
+            Locale::pt_CV => NumberingSystem::Latn,
+            Locale::pt_GQ => NumberingSystem::Latn,
+            Locale::pt_GW => NumberingSystem::Latn,
+            Locale::pt_LU => NumberingSystem::Latn,
+            Locale::pt_MO => NumberingSystem::Latn,
+            Locale::pt_MZ => NumberingSystem::Latn,
+            Locale::pt_PT => NumberingSystem::Latn,
+            Locale::pt_ST => NumberingSystem::Latn,
+            Locale::pt_TL => NumberingSystem::Latn,
+            Locale::qu => NumberingSystem::Latn,
+            Locale::qu_BO => NumberingSystem::Latn,
+            Locale::qu_EC => NumberingSystem::Latn,
+            Locale::quc => NumberingSystem::Latn,
+            Locale::raj => NumberingSystem::Deva,
+            Locale::rhg => NumberingSystem::Latn,
+            Locale::rhg_Rohg => NumberingSystem::Latn,
+            Locale::rhg_Rohg_BD => NumberingSystem::Latn,
+            Locale::rif => NumberingSystem::Latn,
+            Locale::rm => NumberingSystem::Latn,
+            Locale::rn => NumberingSystem::Latn,
+            Locale::ro => NumberingSystem::Latn,
+            Locale::ro_MD => NumberingSystem::Latn,
+            Locale::rof => NumberingSystem::Latn,
+            Locale::ru => NumberingSystem::Latn,
+            Locale::ru_BY => NumberingSystem::Latn,
+            Locale::ru_KG => NumberingSystem::Latn,
+            Locale::ru_KZ => NumberingSystem::Latn,
+            Locale::ru_MD => NumberingSystem::Latn,
+            Locale::ru_UA => NumberingSystem::Latn,
+            Locale::rw => NumberingSystem::Latn,
+            Locale::rwk => NumberingSystem::Latn,
+            Locale::sa => NumberingSystem::Deva,
+            Locale::sah => NumberingSystem::Latn,
+            Locale::saq => NumberingSystem::Latn,
+            Locale::sat => NumberingSystem::Olck,
+            Locale::sat_Deva => NumberingSystem::Deva,
+            Locale::sat_Olck => NumberingSystem::Olck,
+            Locale::sbp => NumberingSystem::Latn,
+            Locale::sc => NumberingSystem::Latn,
+            Locale::scn => NumberingSystem::Latn,
+            Locale::sd => NumberingSystem::Arab,
+            Locale::sd_Arab => NumberingSystem::Arab,
+            Locale::sd_Deva => NumberingSystem::Latn,
+            Locale::sdh => NumberingSystem::Arab,
+            Locale::sdh_IQ => NumberingSystem::Arab,
+            Locale::se => NumberingSystem::Latn,
+            Locale::se_FI => NumberingSystem::Latn,
+            Locale::se_SE => NumberingSystem::Latn,
+            Locale::seh => NumberingSystem::Latn,
+            Locale::ses => NumberingSystem::Latn,
+            Locale::sg => NumberingSystem::Latn,
+            Locale::sgs => NumberingSystem::Latn,
+            Locale::shi => NumberingSystem::Latn,
+            Locale::shi_Latn => NumberingSystem::Latn,
+            Locale::shi_Tfng => NumberingSystem::Latn,
+            Locale::shn => NumberingSystem::Latn,
+            Locale::shn_TH => NumberingSystem::Latn,
+            Locale::si => NumberingSystem::Latn,
+            Locale::sid => NumberingSystem::Latn,
+            Locale::sk => NumberingSystem::Latn,
+            Locale::skr => NumberingSystem::Latn,
+            Locale::sl => NumberingSystem::Latn,
+            Locale::sma => NumberingSystem::Latn,
+            Locale::sma_NO => NumberingSystem::Latn,
+            Locale::smj => NumberingSystem::Latn,
+            Locale::smj_NO => NumberingSystem::Latn,
+            Locale::smn => NumberingSystem::Latn,
+            Locale::sms => NumberingSystem::Latn,
+            Locale::sn => NumberingSystem::Latn,
+            Locale::so => NumberingSystem::Latn,
+            Locale::so_DJ => NumberingSystem::Latn,
+            Locale::so_ET => NumberingSystem::Latn,
+            Locale::so_KE => NumberingSystem::Latn,
+            Locale::sq => NumberingSystem::Latn,
+            Locale::sq_MK => NumberingSystem::Latn,
+            Locale::sq_XK => NumberingSystem::Latn,
+            Locale::sr => NumberingSystem::Latn,
+            Locale::sr_Cyrl => NumberingSystem::Latn,
+            Locale::sr_Cyrl_BA => NumberingSystem::Latn,
+            Locale::sr_Cyrl_ME => NumberingSystem::Latn,
+            Locale::sr_Cyrl_XK => NumberingSystem::Latn,
+            Locale::sr_Latn => NumberingSystem::Latn,
+            Locale::sr_Latn_BA => NumberingSystem::Latn,
+            Locale::sr_Latn_ME => NumberingSystem::Latn,
+            Locale::sr_Latn_XK => NumberingSystem::Latn,
+            Locale::ss => NumberingSystem::Latn,
+            Locale::ss_SZ => NumberingSystem::Latn,
+            Locale::ssy => NumberingSystem::Latn,
+            Locale::st => NumberingSystem::Latn,
+            Locale::st_LS => NumberingSystem::Latn,
+            Locale::su => NumberingSystem::Latn,
+            Locale::su_Latn => NumberingSystem::Latn,
+            Locale::suz => NumberingSystem::Latn,
+            Locale::suz_Deva => NumberingSystem::Latn,
+            Locale::suz_Sunu => NumberingSystem::Latn,
+            Locale::sv => NumberingSystem::Latn,
+            Locale::sv_AX => NumberingSystem::Latn,
+            Locale::sv_FI => NumberingSystem::Latn,
+            Locale::sw => NumberingSystem::Latn,
+            Locale::sw_CD => NumberingSystem::Latn,
+            Locale::sw_KE => NumberingSystem::Latn,
+            Locale::sw_UG => NumberingSystem::Latn,
+            Locale::syr => NumberingSystem::Latn,
+            Locale::syr_SY => NumberingSystem::Latn,
+            Locale::szl => NumberingSystem::Latn,
+            Locale::ta => NumberingSystem::Latn,
+            Locale::ta_LK => NumberingSystem::Latn,
+            Locale::ta_MY => NumberingSystem::Latn,
+            Locale::ta_SG => NumberingSystem::Latn,
+            Locale::te => NumberingSystem::Latn,
+            Locale::teo => NumberingSystem::Latn,
+            Locale::teo_KE => NumberingSystem::Latn,
+            Locale::tg => NumberingSystem::Latn,
+            Locale::th => NumberingSystem::Latn,
+            Locale::ti => NumberingSystem::Latn,
+            Locale::ti_ER => NumberingSystem::Latn,
+            Locale::tig => NumberingSystem::Latn,
+            Locale::tk => NumberingSystem::Latn,
+            Locale::tn => NumberingSystem::Latn,
+            Locale::tn_BW => NumberingSystem::Latn,
+            Locale::to => NumberingSystem::Latn,
+            Locale::tok => NumberingSystem::Latn,
+            Locale::tpi => NumberingSystem::Latn,
+            Locale::tr => NumberingSystem::Latn,
+            Locale::tr_CY => NumberingSystem::Latn,
+            Locale::trv => NumberingSystem::Latn,
+            Locale::trw => NumberingSystem::Latn,
+            Locale::ts => NumberingSystem::Latn,
+            Locale::tt => NumberingSystem::Latn,
+            Locale::twq => NumberingSystem::Latn,
+            Locale::tyv => NumberingSystem::Latn,
+            Locale::tzm => NumberingSystem::Latn,
+            Locale::ug => NumberingSystem::Latn,
+            Locale::uk => NumberingSystem::Latn,
+            Locale::und => NumberingSystem::Latn,
+            Locale::ur => NumberingSystem::Latn,
+            Locale::ur_IN => NumberingSystem::Arabext,
+            Locale::uz => NumberingSystem::Latn,
+            Locale::uz_Arab => NumberingSystem::Arabext,
+            Locale::uz_Cyrl => NumberingSystem::Latn,
+            Locale::uz_Latn => NumberingSystem::Latn,
+            Locale::vai => NumberingSystem::Latn,
+            Locale::vai_Latn => NumberingSystem::Latn,
+            Locale::vai_Vaii => NumberingSystem::Latn,
+            Locale::ve => NumberingSystem::Latn,
+            Locale::vec => NumberingSystem::Latn,
+            Locale::vi => NumberingSystem::Latn,
+            Locale::vmw => NumberingSystem::Latn,
+            Locale::vo => NumberingSystem::Latn,
+            Locale::vun => NumberingSystem::Latn,
+            Locale::wa => NumberingSystem::Latn,
+            Locale::wae => NumberingSystem::Latn,
+            Locale::wal => NumberingSystem::Latn,
+            Locale::wbp => NumberingSystem::Latn,
+            Locale::wo => NumberingSystem::Latn,
+            Locale::xh => NumberingSystem::Latn,
+            Locale::xnr => NumberingSystem::Latn,
+            Locale::xog => NumberingSystem::Latn,
+            Locale::yav => NumberingSystem::Latn,
+            Locale::yi => NumberingSystem::Latn,
+            Locale::yo => NumberingSystem::Latn,
+            Locale::yo_BJ => NumberingSystem::Latn,
+            Locale::yrl => NumberingSystem::Latn,
+            Locale::yrl_CO => NumberingSystem::Latn,
+            Locale::yrl_VE => NumberingSystem::Latn,
+            Locale::yue => NumberingSystem::Latn,
+            Locale::yue_Hans => NumberingSystem::Latn,
+            Locale::yue_Hant => NumberingSystem::Latn,
+            Locale::yue_Hant_CN => NumberingSystem::Latn,
+            Locale::yue_Hant_MO => NumberingSystem::Latn,
+            Locale::za => NumberingSystem::Latn,
+            Locale::zgh => NumberingSystem::Latn,
+            Locale::zh => NumberingSystem::Latn,
+            Locale::zh_Hans => NumberingSystem::Latn,
+            Locale::zh_Hans_HK => NumberingSystem::Latn,
+            Locale::zh_Hans_MO => NumberingSystem::Latn,
+            Locale::zh_Hans_MY => NumberingSystem::Latn,
+            Locale::zh_Hans_SG => NumberingSystem::Latn,
+            Locale::zh_Hant => NumberingSystem::Latn,
+            Locale::zh_Hant_HK => NumberingSystem::Latn,
+            Locale::zh_Hant_MO => NumberingSystem::Latn,
+            Locale::zh_Hant_MY => NumberingSystem::Latn,
+            Locale::zh_Latn => NumberingSystem::Latn,
+            Locale::zu => NumberingSystem::Latn,
+        }
+    }
+
+    fn _other_numbering_systems(&self) -> [Option<NumberingSystem>; 3] {
+        match self {
+            Locale::aa => [Some(NumberingSystem::Latn), None, None],
+            Locale::aa_DJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::aa_ER => [Some(NumberingSystem::Latn), None, None],
+            Locale::ab => [Some(NumberingSystem::Latn), None, None],
+            Locale::af => [Some(NumberingSystem::Latn), None, None],
+            Locale::af_NA => [Some(NumberingSystem::Latn), None, None],
+            Locale::agq => [Some(NumberingSystem::Latn), None, None],
+            Locale::ak => [Some(NumberingSystem::Latn), None, None],
+            Locale::am => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Ethi),
+                None,
+            ],
+            Locale::an => [Some(NumberingSystem::Latn), None, None],
+            Locale::ann => [Some(NumberingSystem::Latn), None, None],
+            Locale::apc => [Some(NumberingSystem::Latn), None, None],
+            Locale::ar => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_AE => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_BH => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_DJ => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_DZ => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_EG => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_EH => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_ER => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_IL => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_IQ => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_JO => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_KM => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_KW => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_LB => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_LY => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_MA => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_MR => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_OM => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_PS => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_QA => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_SA => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_SD => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_SO => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_SS => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_SY => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_TD => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_TN => [Some(NumberingSystem::Arab), None, None],
+            Locale::ar_YE => [Some(NumberingSystem::Arab), None, None],
+            Locale::arn => [Some(NumberingSystem::Latn), None, None],
+            Locale::as_ => [Some(NumberingSystem::Beng), None, None],
+            Locale::asa => [Some(NumberingSystem::Latn), None, None],
+            Locale::ast => [Some(NumberingSystem::Latn), None, None],
+            Locale::az => [Some(NumberingSystem::Latn), None, None],
+            Locale::az_Arab => [Some(NumberingSystem::Latn), None, None],
+            Locale::az_Arab_IQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::az_Arab_TR => [Some(NumberingSystem::Latn), None, None],
+            Locale::az_Cyrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::az_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::ba => [Some(NumberingSystem::Latn), None, None],
+            Locale::bal => [Some(NumberingSystem::Latn), None, None],
+            Locale::bal_Arab => [Some(NumberingSystem::Latn), None, None],
+            Locale::bal_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::bas => [Some(NumberingSystem::Latn), None, None],
+            Locale::be => [Some(NumberingSystem::Latn), None, None],
+            Locale::be_tarask => [Some(NumberingSystem::Latn), None, None],
+            Locale::bem => [Some(NumberingSystem::Latn), None, None],
+            Locale::bew => [Some(NumberingSystem::Latn), None, None],
+            Locale::bez => [Some(NumberingSystem::Latn), None, None],
+            Locale::bg => [Some(NumberingSystem::Latn), None, None],
+            Locale::bgc => [Some(NumberingSystem::Latn), None, None],
+            Locale::bgn => [Some(NumberingSystem::Latn), None, None],
+            Locale::bgn_AE => [Some(NumberingSystem::Latn), None, None],
+            Locale::bgn_AF => [Some(NumberingSystem::Latn), None, None],
+            Locale::bgn_IR => [Some(NumberingSystem::Latn), None, None],
+            Locale::bgn_OM => [Some(NumberingSystem::Latn), None, None],
+            Locale::bho => [Some(NumberingSystem::Latn), None, None],
+            Locale::blo => [Some(NumberingSystem::Latn), None, None],
+            Locale::blt => [Some(NumberingSystem::Latn), None, None],
+            Locale::bm => [Some(NumberingSystem::Latn), None, None],
+            Locale::bm_Nkoo => [Some(NumberingSystem::Latn), None, None],
+            Locale::bn => [Some(NumberingSystem::Beng), None, None],
+            Locale::bn_IN => [Some(NumberingSystem::Beng), None, None],
+            Locale::bo => [Some(NumberingSystem::Tibt), None, None],
+            Locale::bo_IN => [Some(NumberingSystem::Tibt), None, None],
+            Locale::bqi => [Some(NumberingSystem::Latn), None, None],
+            Locale::br => [Some(NumberingSystem::Latn), None, None],
+            Locale::brx => [Some(NumberingSystem::Deva), None, None],
+            Locale::bs => [Some(NumberingSystem::Latn), None, None],
+            Locale::bs_Cyrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::bs_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::bss => [Some(NumberingSystem::Latn), None, None],
+            Locale::bua => [Some(NumberingSystem::Latn), None, None],
+            Locale::byn => [Some(NumberingSystem::Latn), None, None],
+            Locale::ca => [Some(NumberingSystem::Latn), None, None],
+            Locale::ca_AD => [Some(NumberingSystem::Latn), None, None],
+            Locale::ca_ES_valencia => [Some(NumberingSystem::Latn), None, None],
+            Locale::ca_FR => [Some(NumberingSystem::Latn), None, None],
+            Locale::ca_IT => [Some(NumberingSystem::Latn), None, None],
+            Locale::cad => [Some(NumberingSystem::Latn), None, None],
+            Locale::cch => [Some(NumberingSystem::Latn), None, None],
+            Locale::ccp => [Some(NumberingSystem::Cakm), None, None],
+            Locale::ccp_IN => [Some(NumberingSystem::Cakm), None, None],
+            Locale::ce => [Some(NumberingSystem::Latn), None, None],
+            Locale::ceb => [Some(NumberingSystem::Latn), None, None],
+            Locale::cgg => [Some(NumberingSystem::Latn), None, None],
+            Locale::cho => [Some(NumberingSystem::Latn), None, None],
+            Locale::chr => [Some(NumberingSystem::Latn), None, None],
+            Locale::cic => [Some(NumberingSystem::Latn), None, None],
+            Locale::ckb => [Some(NumberingSystem::Arab), None, None],
+            Locale::ckb_IR => [Some(NumberingSystem::Arab), None, None],
+            Locale::co => [Some(NumberingSystem::Latn), None, None],
+            Locale::cop => [Some(NumberingSystem::Latn), None, None],
+            Locale::cs => [Some(NumberingSystem::Latn), None, None],
+            Locale::csw => [Some(NumberingSystem::Latn), None, None],
+            Locale::cu => [Some(NumberingSystem::Latn), None, None],
+            Locale::cv => [Some(NumberingSystem::Latn), None, None],
+            Locale::cy => [Some(NumberingSystem::Latn), None, None],
+            Locale::da => [Some(NumberingSystem::Latn), None, None],
+            Locale::da_GL => [Some(NumberingSystem::Latn), None, None],
+            Locale::dav => [Some(NumberingSystem::Latn), None, None],
+            Locale::de => [Some(NumberingSystem::Latn), None, None],
+            Locale::de_AT => [Some(NumberingSystem::Latn), None, None],
+            Locale::de_BE => [Some(NumberingSystem::Latn), None, None],
+            Locale::de_CH => [Some(NumberingSystem::Latn), None, None],
+            Locale::de_IT => [Some(NumberingSystem::Latn), None, None],
+            Locale::de_LI => [Some(NumberingSystem::Latn), None, None],
+            Locale::de_LU => [Some(NumberingSystem::Latn), None, None],
+            Locale::dje => [Some(NumberingSystem::Latn), None, None],
+            Locale::doi => [Some(NumberingSystem::Deva), None, None],
+            Locale::dsb => [Some(NumberingSystem::Latn), None, None],
+            Locale::dua => [Some(NumberingSystem::Latn), None, None],
+            Locale::dv => [Some(NumberingSystem::Latn), None, None],
+            Locale::dyo => [Some(NumberingSystem::Latn), None, None],
+            Locale::dz => [Some(NumberingSystem::Tibt), None, None],
+            Locale::ebu => [Some(NumberingSystem::Latn), None, None],
+            Locale::ee => [Some(NumberingSystem::Latn), None, None],
+            Locale::ee_TG => [Some(NumberingSystem::Latn), None, None],
+            Locale::el => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Grek),
+                None,
+            ],
+            Locale::el_CY => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Grek),
+                None,
+            ],
+            Locale::el_polyton => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Grek),
+                None,
+            ],
+            Locale::en => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_001 => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_150 => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_AE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_AG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_AI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_AS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_AT => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_AU => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BB => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BW => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_BZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CA => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CC => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CH => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CX => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CY => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_CZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_DE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_DG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_DK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_DM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_Dsrt => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_EE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_ER => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_ES => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_FI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_FJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_FK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_FM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_FR => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GB => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GD => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GH => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GU => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_GY => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_HK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_HU => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_ID => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_IE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_IL => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_IM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_IN => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_IO => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_IT => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_JE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_JM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_JP => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_KE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_KI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_KN => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_KY => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_LC => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_LR => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_LS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_LT => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_LV => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MH => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MO => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MP => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MT => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MU => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MV => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MW => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_MY => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NA => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NF => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NL => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NO => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NR => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NU => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_NZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PH => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PL => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PN => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PR => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PT => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_PW => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_RO => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_RW => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SB => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SC => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SD => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SE => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SH => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SL => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SX => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_SZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_Shaw => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_TC => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_TK => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_TO => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_TT => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_TV => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_TZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_UA => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_UG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_UM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_VC => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_VG => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_VI => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_VU => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_WS => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_ZA => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_ZM => [Some(NumberingSystem::Latn), None, None],
+            Locale::en_ZW => [Some(NumberingSystem::Latn), None, None],
+            Locale::eo => [Some(NumberingSystem::Latn), None, None],
+            Locale::es => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_419 => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_AR => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_BO => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_BR => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_BZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_CL => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_CO => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_CR => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_CU => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_DO => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_EA => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_EC => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_GQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_GT => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_HN => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_IC => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_MX => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_NI => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_PA => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_PE => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_PH => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_PR => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_PY => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_SV => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_US => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_UY => [Some(NumberingSystem::Latn), None, None],
+            Locale::es_VE => [Some(NumberingSystem::Latn), None, None],
+            Locale::et => [Some(NumberingSystem::Latn), None, None],
+            Locale::eu => [Some(NumberingSystem::Latn), None, None],
+            Locale::ewo => [Some(NumberingSystem::Latn), None, None],
+            Locale::fa => [Some(NumberingSystem::Arabext), None, None],
+            Locale::fa_AF => [Some(NumberingSystem::Arabext), None, None],
+            Locale::ff => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Adlm => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_BF => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_CM => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_GH => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_GM => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_GW => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_LR => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_MR => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_NE => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_NG => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_SL => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Adlm_SN => [Some(NumberingSystem::Adlm), None, None],
+            Locale::ff_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_BF => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_CM => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_GH => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_GM => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_GN => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_GW => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_LR => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_MR => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_NE => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_NG => [Some(NumberingSystem::Latn), None, None],
+            Locale::ff_Latn_SL => [Some(NumberingSystem::Latn), None, None],
+            Locale::fi => [Some(NumberingSystem::Latn), None, None],
+            Locale::fil => [Some(NumberingSystem::Latn), None, None],
+            Locale::fo => [Some(NumberingSystem::Latn), None, None],
+            Locale::fo_DK => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_BE => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_BF => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_BI => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_BJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_BL => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CA => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CD => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CF => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CG => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CH => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CI => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_CM => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_DJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_DZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_GA => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_GF => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_GN => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_GP => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_GQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_HT => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_KM => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_LU => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MA => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MC => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MF => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MG => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_ML => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MR => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_MU => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_NC => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_NE => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_PF => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_PM => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_RE => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_RW => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_SC => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_SN => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_SY => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_TD => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_TG => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_TN => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_VU => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_WF => [Some(NumberingSystem::Latn), None, None],
+            Locale::fr_YT => [Some(NumberingSystem::Latn), None, None],
+            Locale::frr => [Some(NumberingSystem::Latn), None, None],
+            Locale::fur => [Some(NumberingSystem::Latn), None, None],
+            Locale::fy => [Some(NumberingSystem::Latn), None, None],
+            Locale::ga => [Some(NumberingSystem::Latn), None, None],
+            Locale::ga_GB => [Some(NumberingSystem::Latn), None, None],
+            Locale::gaa => [Some(NumberingSystem::Latn), None, None],
+            Locale::gd => [Some(NumberingSystem::Latn), None, None],
+            Locale::gez => [Some(NumberingSystem::Latn), None, None],
+            Locale::gez_ER => [Some(NumberingSystem::Latn), None, None],
+            Locale::gl => [Some(NumberingSystem::Latn), None, None],
+            Locale::gn => [Some(NumberingSystem::Latn), None, None],
+            Locale::gsw => [Some(NumberingSystem::Latn), None, None],
+            Locale::gsw_FR => [Some(NumberingSystem::Latn), None, None],
+            Locale::gsw_LI => [Some(NumberingSystem::Latn), None, None],
+            Locale::gu => [Some(NumberingSystem::Gujr), None, None],
+            Locale::guz => [Some(NumberingSystem::Latn), None, None],
+            Locale::gv => [Some(NumberingSystem::Latn), None, None],
+            Locale::ha => [Some(NumberingSystem::Latn), None, None],
+            Locale::ha_Arab => [Some(NumberingSystem::Latn), None, None],
+            Locale::ha_Arab_SD => [Some(NumberingSystem::Latn), None, None],
+            Locale::ha_GH => [Some(NumberingSystem::Latn), None, None],
+            Locale::ha_NE => [Some(NumberingSystem::Latn), None, None],
+            Locale::haw => [Some(NumberingSystem::Latn), None, None],
+            Locale::he => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Hebr),
+                None,
+            ],
+            Locale::hi => [Some(NumberingSystem::Deva), None, None],
+            Locale::hi_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::hnj => [Some(NumberingSystem::Latn), None, None],
+            Locale::hnj_Hmnp => [Some(NumberingSystem::Latn), None, None],
+            Locale::hr => [Some(NumberingSystem::Latn), None, None],
+            Locale::hr_BA => [Some(NumberingSystem::Latn), None, None],
+            Locale::hsb => [Some(NumberingSystem::Latn), None, None],
+            Locale::ht => [Some(NumberingSystem::Latn), None, None],
+            Locale::hu => [Some(NumberingSystem::Latn), None, None],
+            Locale::hy => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Armn),
+                None,
+            ],
+            Locale::ia => [Some(NumberingSystem::Latn), None, None],
+            Locale::id => [Some(NumberingSystem::Latn), None, None],
+            Locale::ie => [Some(NumberingSystem::Latn), None, None],
+            Locale::ig => [Some(NumberingSystem::Latn), None, None],
+            Locale::ii => [Some(NumberingSystem::Latn), None, None],
+            Locale::io => [Some(NumberingSystem::Latn), None, None],
+            Locale::is => [Some(NumberingSystem::Latn), None, None],
+            Locale::it => [Some(NumberingSystem::Latn), None, None],
+            Locale::it_CH => [Some(NumberingSystem::Latn), None, None],
+            Locale::it_SM => [Some(NumberingSystem::Latn), None, None],
+            Locale::it_VA => [Some(NumberingSystem::Latn), None, None],
+            Locale::iu => [Some(NumberingSystem::Latn), None, None],
+            Locale::iu_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::ja => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Jpan),
+                Some(NumberingSystem::Jpanfin),
+            ],
+            Locale::jbo => [Some(NumberingSystem::Latn), None, None],
+            Locale::jgo => [Some(NumberingSystem::Latn), None, None],
+            Locale::jmc => [Some(NumberingSystem::Latn), None, None],
+            Locale::jv => [Some(NumberingSystem::Java), None, None],
+            Locale::ka => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Geor),
+                None,
+            ],
+            Locale::kaa => [Some(NumberingSystem::Latn), None, None],
+            Locale::kaa_Cyrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::kaa_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::kab => [Some(NumberingSystem::Latn), None, None],
+            Locale::kaj => [Some(NumberingSystem::Latn), None, None],
+            Locale::kam => [Some(NumberingSystem::Latn), None, None],
+            Locale::kcg => [Some(NumberingSystem::Latn), None, None],
+            Locale::kde => [Some(NumberingSystem::Latn), None, None],
+            Locale::kea => [Some(NumberingSystem::Latn), None, None],
+            Locale::kek => [Some(NumberingSystem::Latn), None, None],
+            Locale::ken => [Some(NumberingSystem::Latn), None, None],
+            Locale::kgp => [Some(NumberingSystem::Latn), None, None],
+            Locale::khq => [Some(NumberingSystem::Latn), None, None],
+            Locale::ki => [Some(NumberingSystem::Latn), None, None],
+            Locale::kk => [Some(NumberingSystem::Latn), None, None],
+            Locale::kk_Arab => [Some(NumberingSystem::Latn), None, None],
+            Locale::kk_Cyrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::kk_KZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::kkj => [Some(NumberingSystem::Latn), None, None],
+            Locale::kl => [Some(NumberingSystem::Latn), None, None],
+            Locale::kln => [Some(NumberingSystem::Latn), None, None],
+            Locale::km => [Some(NumberingSystem::Khmr), None, None],
+            Locale::kn => [Some(NumberingSystem::Knda), None, None],
+            Locale::ko => [Some(NumberingSystem::Latn), None, None],
+            Locale::ko_CN => [Some(NumberingSystem::Latn), None, None],
+            Locale::ko_KP => [Some(NumberingSystem::Latn), None, None],
+            Locale::kok => [Some(NumberingSystem::Deva), None, None],
+            Locale::kok_Deva => [Some(NumberingSystem::Deva), None, None],
+            Locale::kok_Latn => [Some(NumberingSystem::Deva), None, None],
+            Locale::kpe => [Some(NumberingSystem::Latn), None, None],
+            Locale::kpe_GN => [Some(NumberingSystem::Latn), None, None],
+            Locale::ks => [Some(NumberingSystem::Arabext), None, None],
+            Locale::ks_Arab => [Some(NumberingSystem::Arabext), None, None],
+            Locale::ks_Deva => [Some(NumberingSystem::Latn), None, None],
+            Locale::ksb => [Some(NumberingSystem::Latn), None, None],
+            Locale::ksf => [Some(NumberingSystem::Latn), None, None],
+            Locale::ksh => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku_Arab => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku_Arab_IR => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku_Latn_IQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku_Latn_SY => [Some(NumberingSystem::Latn), None, None],
+            Locale::ku_TR => [Some(NumberingSystem::Latn), None, None],
+            Locale::kw => [Some(NumberingSystem::Latn), None, None],
+            Locale::kxv => [Some(NumberingSystem::Latn), None, None],
+            Locale::kxv_Deva => [Some(NumberingSystem::Latn), None, None],
+            Locale::kxv_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::kxv_Orya => [Some(NumberingSystem::Latn), None, None],
+            Locale::kxv_Telu => [Some(NumberingSystem::Latn), None, None],
+            Locale::ky => [Some(NumberingSystem::Latn), None, None],
+            Locale::la => [Some(NumberingSystem::Latn), None, None],
+            Locale::lag => [Some(NumberingSystem::Latn), None, None],
+            Locale::lb => [Some(NumberingSystem::Latn), None, None],
+            Locale::lg => [Some(NumberingSystem::Latn), None, None],
+            Locale::lij => [Some(NumberingSystem::Latn), None, None],
+            Locale::lkt => [Some(NumberingSystem::Latn), None, None],
+            Locale::lld => [Some(NumberingSystem::Latn), None, None],
+            Locale::lmo => [Some(NumberingSystem::Latn), None, None],
+            Locale::ln => [Some(NumberingSystem::Latn), None, None],
+            Locale::ln_AO => [Some(NumberingSystem::Latn), None, None],
+            Locale::ln_CF => [Some(NumberingSystem::Latn), None, None],
+            Locale::ln_CG => [Some(NumberingSystem::Latn), None, None],
+            Locale::lo => [Some(NumberingSystem::Laoo), None, None],
+            Locale::lrc => [Some(NumberingSystem::Arabext), None, None],
+            Locale::lrc_IQ => [Some(NumberingSystem::Arabext), None, None],
+            Locale::lt => [Some(NumberingSystem::Latn), None, None],
+            Locale::ltg => [Some(NumberingSystem::Latn), None, None],
+            Locale::lu => [Some(NumberingSystem::Latn), None, None],
+            Locale::luo => [Some(NumberingSystem::Latn), None, None],
+            Locale::luy => [Some(NumberingSystem::Latn), None, None],
+            Locale::lv => [Some(NumberingSystem::Latn), None, None],
+            Locale::lzz => [Some(NumberingSystem::Latn), None, None],
+            Locale::mai => [Some(NumberingSystem::Deva), None, None],
+            Locale::mas => [Some(NumberingSystem::Latn), None, None],
+            Locale::mas_TZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::mdf => [Some(NumberingSystem::Latn), None, None],
+            Locale::mer => [Some(NumberingSystem::Latn), None, None],
+            Locale::mfe => [Some(NumberingSystem::Latn), None, None],
+            Locale::mg => [Some(NumberingSystem::Latn), None, None],
+            Locale::mgh => [Some(NumberingSystem::Latn), None, None],
+            Locale::mgo => [Some(NumberingSystem::Latn), None, None],
+            Locale::mhn => [Some(NumberingSystem::Latn), None, None],
+            Locale::mi => [Some(NumberingSystem::Latn), None, None],
+            Locale::mic => [Some(NumberingSystem::Latn), None, None],
+            Locale::mk => [Some(NumberingSystem::Latn), None, None],
+            Locale::ml => [Some(NumberingSystem::Mlym), None, None],
+            Locale::mn => [Some(NumberingSystem::Latn), None, None],
+            Locale::mn_Mong => [Some(NumberingSystem::Latn), None, None],
+            Locale::mn_Mong_MN => [Some(NumberingSystem::Latn), None, None],
+            Locale::mni => [Some(NumberingSystem::Latn), None, None],
+            Locale::mni_Beng => [Some(NumberingSystem::Latn), None, None],
+            Locale::mni_Mtei => [Some(NumberingSystem::Latn), None, None],
+            Locale::moh => [Some(NumberingSystem::Latn), None, None],
+            Locale::mr => [Some(NumberingSystem::Deva), None, None],
+            Locale::ms => [Some(NumberingSystem::Latn), None, None],
+            Locale::ms_Arab => [Some(NumberingSystem::Latn), None, None],
+            Locale::ms_Arab_BN => [Some(NumberingSystem::Latn), None, None],
+            Locale::ms_BN => [Some(NumberingSystem::Latn), None, None],
+            Locale::ms_ID => [Some(NumberingSystem::Latn), None, None],
+            Locale::ms_SG => [Some(NumberingSystem::Latn), None, None],
+            Locale::mt => [Some(NumberingSystem::Latn), None, None],
+            Locale::mua => [Some(NumberingSystem::Latn), None, None],
+            Locale::mus => [Some(NumberingSystem::Latn), None, None],
+            Locale::mww => [Some(NumberingSystem::Latn), None, None],
+            Locale::mww_Hmnp => [Some(NumberingSystem::Latn), None, None],
+            Locale::my => [Some(NumberingSystem::Mymr), None, None],
+            Locale::myv => [Some(NumberingSystem::Latn), None, None],
+            Locale::mzn => [Some(NumberingSystem::Arabext), None, None],
+            Locale::naq => [Some(NumberingSystem::Latn), None, None],
+            Locale::nb => [Some(NumberingSystem::Latn), None, None],
+            Locale::nb_SJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::nd => [Some(NumberingSystem::Latn), None, None],
+            Locale::nds => [Some(NumberingSystem::Latn), None, None],
+            Locale::nds_NL => [Some(NumberingSystem::Latn), None, None],
+            Locale::ne => [Some(NumberingSystem::Deva), None, None],
+            Locale::ne_IN => [Some(NumberingSystem::Deva), None, None],
+            Locale::nl => [Some(NumberingSystem::Latn), None, None],
+            Locale::nl_AW => [Some(NumberingSystem::Latn), None, None],
+            Locale::nl_BE => [Some(NumberingSystem::Latn), None, None],
+            Locale::nl_BQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::nl_CW => [Some(NumberingSystem::Latn), None, None],
+            Locale::nl_SR => [Some(NumberingSystem::Latn), None, None],
+            Locale::nl_SX => [Some(NumberingSystem::Latn), None, None],
+            Locale::nmg => [Some(NumberingSystem::Latn), None, None],
+            Locale::nn => [Some(NumberingSystem::Latn), None, None],
+            Locale::nnh => [Some(NumberingSystem::Latn), None, None],
+            Locale::no => [Some(NumberingSystem::Latn), None, None],
+            Locale::nqo => [Some(NumberingSystem::Latn), None, None],
+            Locale::nr => [Some(NumberingSystem::Latn), None, None],
+            Locale::nso => [Some(NumberingSystem::Latn), None, None],
+            Locale::nus => [Some(NumberingSystem::Latn), None, None],
+            Locale::nv => [Some(NumberingSystem::Latn), None, None],
+            Locale::ny => [Some(NumberingSystem::Latn), None, None],
+            Locale::nyn => [Some(NumberingSystem::Latn), None, None],
+            Locale::oc => [Some(NumberingSystem::Latn), None, None],
+            Locale::oc_ES => [Some(NumberingSystem::Latn), None, None],
+            Locale::oka => [Some(NumberingSystem::Latn), None, None],
+            Locale::oka_US => [Some(NumberingSystem::Latn), None, None],
+            Locale::om => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Ethi),
+                None,
+            ],
+            Locale::om_KE => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Ethi),
+                None,
+            ],
+            Locale::or => [Some(NumberingSystem::Orya), None, None],
+            Locale::os => [Some(NumberingSystem::Latn), None, None],
+            Locale::os_RU => [Some(NumberingSystem::Latn), None, None],
+            Locale::osa => [Some(NumberingSystem::Latn), None, None],
+            Locale::pa => [Some(NumberingSystem::Guru), None, None],
+            Locale::pa_Arab => [Some(NumberingSystem::Arabext), None, None],
+            Locale::pa_Guru => [Some(NumberingSystem::Guru), None, None],
+            Locale::pap => [Some(NumberingSystem::Latn), None, None],
+            Locale::pap_AW => [Some(NumberingSystem::Latn), None, None],
+            Locale::pcm => [Some(NumberingSystem::Latn), None, None],
+            Locale::pi => [Some(NumberingSystem::Latn), None, None],
+            Locale::pi_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::pis => [Some(NumberingSystem::Latn), None, None],
+            Locale::pl => [Some(NumberingSystem::Latn), None, None],
+            Locale::pms => [Some(NumberingSystem::Latn), None, None],
+            Locale::prg => [Some(NumberingSystem::Latn), None, None],
+            Locale::ps => [Some(NumberingSystem::Arabext), None, None],
+            Locale::ps_PK => [Some(NumberingSystem::Arabext), None, None],
+            Locale::pt => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_AO => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_CH => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_CV => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_GQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_GW => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_LU => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_MO => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_MZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_PT => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_ST => [Some(NumberingSystem::Latn), None, None],
+            Locale::pt_TL => [Some(NumberingSystem::Latn), None, None],
+            Locale::qu => [Some(NumberingSystem::Latn), None, None],
+            Locale::qu_BO => [Some(NumberingSystem::Latn), None, None],
+            Locale::qu_EC => [Some(NumberingSystem::Latn), None, None],
+            Locale::quc => [Some(NumberingSystem::Latn), None, None],
+            Locale::raj => [Some(NumberingSystem::Latn), None, None],
+            Locale::rhg => [Some(NumberingSystem::Latn), None, None],
+            Locale::rhg_Rohg => [Some(NumberingSystem::Latn), None, None],
+            Locale::rhg_Rohg_BD => [Some(NumberingSystem::Latn), None, None],
+            Locale::rif => [Some(NumberingSystem::Latn), None, None],
+            Locale::rm => [Some(NumberingSystem::Latn), None, None],
+            Locale::rn => [Some(NumberingSystem::Latn), None, None],
+            Locale::ro => [Some(NumberingSystem::Latn), None, None],
+            Locale::ro_MD => [Some(NumberingSystem::Latn), None, None],
+            Locale::rof => [Some(NumberingSystem::Latn), None, None],
+            Locale::ru => [Some(NumberingSystem::Latn), None, None],
+            Locale::ru_BY => [Some(NumberingSystem::Latn), None, None],
+            Locale::ru_KG => [Some(NumberingSystem::Latn), None, None],
+            Locale::ru_KZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::ru_MD => [Some(NumberingSystem::Latn), None, None],
+            Locale::ru_UA => [Some(NumberingSystem::Latn), None, None],
+            Locale::rw => [Some(NumberingSystem::Latn), None, None],
+            Locale::rwk => [Some(NumberingSystem::Latn), None, None],
+            Locale::sa => [Some(NumberingSystem::Deva), None, None],
+            Locale::sah => [Some(NumberingSystem::Latn), None, None],
+            Locale::saq => [Some(NumberingSystem::Latn), None, None],
+            Locale::sat => [Some(NumberingSystem::Latn), None, None],
+            Locale::sat_Deva => [Some(NumberingSystem::Latn), None, None],
+            Locale::sat_Olck => [Some(NumberingSystem::Latn), None, None],
+            Locale::sbp => [Some(NumberingSystem::Latn), None, None],
+            Locale::sc => [Some(NumberingSystem::Latn), None, None],
+            Locale::scn => [Some(NumberingSystem::Latn), None, None],
+            Locale::sd => [Some(NumberingSystem::Arab), None, None],
+            Locale::sd_Arab => [Some(NumberingSystem::Arab), None, None],
+            Locale::sd_Deva => [Some(NumberingSystem::Latn), None, None],
+            Locale::sdh => [Some(NumberingSystem::Latn), None, None],
+            Locale::sdh_IQ => [Some(NumberingSystem::Latn), None, None],
+            Locale::se => [Some(NumberingSystem::Latn), None, None],
+            Locale::se_FI => [Some(NumberingSystem::Latn), None, None],
+            Locale::se_SE => [Some(NumberingSystem::Latn), None, None],
+            Locale::seh => [Some(NumberingSystem::Latn), None, None],
+            Locale::ses => [Some(NumberingSystem::Latn), None, None],
+            Locale::sg => [Some(NumberingSystem::Latn), None, None],
+            Locale::sgs => [Some(NumberingSystem::Latn), None, None],
+            Locale::shi => [Some(NumberingSystem::Latn), None, None],
+            Locale::shi_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::shi_Tfng => [Some(NumberingSystem::Latn), None, None],
+            Locale::shn => [Some(NumberingSystem::Latn), None, None],
+            Locale::shn_TH => [Some(NumberingSystem::Latn), None, None],
+            Locale::si => [Some(NumberingSystem::Latn), None, None],
+            Locale::sid => [Some(NumberingSystem::Latn), None, None],
+            Locale::sk => [Some(NumberingSystem::Latn), None, None],
+            Locale::skr => [Some(NumberingSystem::Latn), None, None],
+            Locale::sl => [Some(NumberingSystem::Latn), None, None],
+            Locale::sma => [Some(NumberingSystem::Latn), None, None],
+            Locale::sma_NO => [Some(NumberingSystem::Latn), None, None],
+            Locale::smj => [Some(NumberingSystem::Latn), None, None],
+            Locale::smj_NO => [Some(NumberingSystem::Latn), None, None],
+            Locale::smn => [Some(NumberingSystem::Latn), None, None],
+            Locale::sms => [Some(NumberingSystem::Latn), None, None],
+            Locale::sn => [Some(NumberingSystem::Latn), None, None],
+            Locale::so => [Some(NumberingSystem::Latn), None, None],
+            Locale::so_DJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::so_ET => [Some(NumberingSystem::Latn), None, None],
+            Locale::so_KE => [Some(NumberingSystem::Latn), None, None],
+            Locale::sq => [Some(NumberingSystem::Latn), None, None],
+            Locale::sq_MK => [Some(NumberingSystem::Latn), None, None],
+            Locale::sq_XK => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Cyrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Cyrl_BA => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Cyrl_ME => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Cyrl_XK => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Latn_BA => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Latn_ME => [Some(NumberingSystem::Latn), None, None],
+            Locale::sr_Latn_XK => [Some(NumberingSystem::Latn), None, None],
+            Locale::ss => [Some(NumberingSystem::Latn), None, None],
+            Locale::ss_SZ => [Some(NumberingSystem::Latn), None, None],
+            Locale::ssy => [Some(NumberingSystem::Latn), None, None],
+            Locale::st => [Some(NumberingSystem::Latn), None, None],
+            Locale::st_LS => [Some(NumberingSystem::Latn), None, None],
+            Locale::su => [Some(NumberingSystem::Latn), None, None],
+            Locale::su_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::suz => [Some(NumberingSystem::Latn), None, None],
+            Locale::suz_Deva => [Some(NumberingSystem::Latn), None, None],
+            Locale::suz_Sunu => [Some(NumberingSystem::Latn), None, None],
+            Locale::sv => [Some(NumberingSystem::Latn), None, None],
+            Locale::sv_AX => [Some(NumberingSystem::Latn), None, None],
+            Locale::sv_FI => [Some(NumberingSystem::Latn), None, None],
+            Locale::sw => [Some(NumberingSystem::Latn), None, None],
+            Locale::sw_CD => [Some(NumberingSystem::Latn), None, None],
+            Locale::sw_KE => [Some(NumberingSystem::Latn), None, None],
+            Locale::sw_UG => [Some(NumberingSystem::Latn), None, None],
+            Locale::syr => [Some(NumberingSystem::Latn), None, None],
+            Locale::syr_SY => [Some(NumberingSystem::Latn), None, None],
+            Locale::szl => [Some(NumberingSystem::Latn), None, None],
+            Locale::ta => [
+                Some(NumberingSystem::Tamldec),
+                Some(NumberingSystem::Taml),
+                None,
+            ],
+            Locale::ta_LK => [
+                Some(NumberingSystem::Tamldec),
+                Some(NumberingSystem::Taml),
+                None,
+            ],
+            Locale::ta_MY => [
+                Some(NumberingSystem::Tamldec),
+                Some(NumberingSystem::Taml),
+                None,
+            ],
+            Locale::ta_SG => [
+                Some(NumberingSystem::Tamldec),
+                Some(NumberingSystem::Taml),
+                None,
+            ],
+            Locale::te => [Some(NumberingSystem::Telu), None, None],
+            Locale::teo => [Some(NumberingSystem::Latn), None, None],
+            Locale::teo_KE => [Some(NumberingSystem::Latn), None, None],
+            Locale::tg => [Some(NumberingSystem::Latn), None, None],
+            Locale::th => [Some(NumberingSystem::Thai), None, None],
+            Locale::ti => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Ethi),
+                None,
+            ],
+            Locale::ti_ER => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Ethi),
+                None,
+            ],
+            Locale::tig => [Some(NumberingSystem::Latn), None, None],
+            Locale::tk => [Some(NumberingSystem::Latn), None, None],
+            Locale::tn => [Some(NumberingSystem::Latn), None, None],
+            Locale::tn_BW => [Some(NumberingSystem::Latn), None, None],
+            Locale::to => [Some(NumberingSystem::Latn), None, None],
+            Locale::tok => [Some(NumberingSystem::Latn), None, None],
+            Locale::tpi => [Some(NumberingSystem::Latn), None, None],
+            Locale::tr => [Some(NumberingSystem::Latn), None, None],
+            Locale::tr_CY => [Some(NumberingSystem::Latn), None, None],
+            Locale::trv => [Some(NumberingSystem::Latn), None, None],
+            Locale::trw => [Some(NumberingSystem::Latn), None, None],
+            Locale::ts => [Some(NumberingSystem::Latn), None, None],
+            Locale::tt => [Some(NumberingSystem::Latn), None, None],
+            Locale::twq => [Some(NumberingSystem::Latn), None, None],
+            Locale::tyv => [Some(NumberingSystem::Latn), None, None],
+            Locale::tzm => [Some(NumberingSystem::Latn), None, None],
+            Locale::ug => [Some(NumberingSystem::Arabext), None, None],
+            Locale::uk => [Some(NumberingSystem::Latn), None, None],
+            Locale::und => [Some(NumberingSystem::Latn), None, None],
+            Locale::ur => [Some(NumberingSystem::Arabext), None, None],
+            Locale::ur_IN => [Some(NumberingSystem::Arabext), None, None],
+            Locale::uz => [Some(NumberingSystem::Latn), None, None],
+            Locale::uz_Arab => [Some(NumberingSystem::Arabext), None, None],
+            Locale::uz_Cyrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::uz_Latn => [Some(NumberingSystem::Latn), None, None],
+            Locale::vai => [Some(NumberingSystem::Vaii), None, None],
+            Locale::vai_Latn => [Some(NumberingSystem::Vaii), None, None],
+            Locale::vai_Vaii => [Some(NumberingSystem::Vaii), None, None],
+            Locale::ve => [Some(NumberingSystem::Latn), None, None],
+            Locale::vec => [Some(NumberingSystem::Latn), None, None],
+            Locale::vi => [Some(NumberingSystem::Latn), None, None],
+            Locale::vmw => [Some(NumberingSystem::Latn), None, None],
+            Locale::vo => [Some(NumberingSystem::Latn), None, None],
+            Locale::vun => [Some(NumberingSystem::Latn), None, None],
+            Locale::wa => [Some(NumberingSystem::Latn), None, None],
+            Locale::wae => [Some(NumberingSystem::Latn), None, None],
+            Locale::wal => [Some(NumberingSystem::Latn), None, None],
+            Locale::wbp => [Some(NumberingSystem::Latn), None, None],
+            Locale::wo => [Some(NumberingSystem::Latn), None, None],
+            Locale::xh => [Some(NumberingSystem::Latn), None, None],
+            Locale::xnr => [Some(NumberingSystem::Latn), None, None],
+            Locale::xog => [Some(NumberingSystem::Latn), None, None],
+            Locale::yav => [Some(NumberingSystem::Latn), None, None],
+            Locale::yi => [
+                Some(NumberingSystem::Latn),
+                Some(NumberingSystem::Hebr),
+                None,
+            ],
+            Locale::yo => [Some(NumberingSystem::Latn), None, None],
+            Locale::yo_BJ => [Some(NumberingSystem::Latn), None, None],
+            Locale::yrl => [Some(NumberingSystem::Latn), None, None],
+            Locale::yrl_CO => [Some(NumberingSystem::Latn), None, None],
+            Locale::yrl_VE => [Some(NumberingSystem::Latn), None, None],
+            Locale::yue => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::yue_Hans => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::yue_Hant => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::yue_Hant_CN => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::yue_Hant_MO => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::za => [Some(NumberingSystem::Latn), None, None],
+            Locale::zgh => [Some(NumberingSystem::Latn), None, None],
+            Locale::zh => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zh_Hans => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zh_Hans_HK => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zh_Hans_MO => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zh_Hans_MY => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zh_Hans_SG => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zh_Hant => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::zh_Hant_HK => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::zh_Hant_MO => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::zh_Hant_MY => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hant),
+                Some(NumberingSystem::Hantfin),
+            ],
+            Locale::zh_Latn => [
+                Some(NumberingSystem::Hanidec),
+                Some(NumberingSystem::Hans),
+                Some(NumberingSystem::Hansfin),
+            ],
+            Locale::zu => [Some(NumberingSystem::Latn), None, None],
+        }
+    }
+
+    /// Returns the locale's native, traditional or finance numbering system.
+    /// Traditional falls back to native and the others to the default system,
+    /// as in CLDR.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::{NumberingSystem, OtherNumberingSystem};
+    ///
+    /// assert_eq!(Locale::hi.other_numbering_system(OtherNumberingSystem::Native), NumberingSystem::Deva);
+    /// assert_eq!(Locale::zh.other_numbering_system(OtherNumberingSystem::Finance), NumberingSystem::Hansfin);
+    /// assert_eq!(Locale::en.other_numbering_system(OtherNumberingSystem::Traditional), NumberingSystem::Latn);
+    /// ```
+    pub fn other_numbering_system(&self, kind: OtherNumberingSystem) -> NumberingSystem {
+        let [native, traditional, finance] = self._other_numbering_systems();
+        match kind {
+            OtherNumberingSystem::Native => native,
+            OtherNumberingSystem::Traditional => traditional.or(native),
+            OtherNumberingSystem::Finance => finance,
+        }
+        .unwrap_or_else(|| self.default_numbering_system())
+    }
+
+    fn _number_symbols(&self) -> &'static [(NumberingSystem, NumberSymbols)] {
+        match self {
+            Locale::aa => &_NUMBER_SYMBOLS_0,
+            Locale::aa_DJ => &_NUMBER_SYMBOLS_0,
+            Locale::aa_ER => &_NUMBER_SYMBOLS_0,
+            Locale::ab => &_NUMBER_SYMBOLS_0,
+            Locale::af => &_NUMBER_SYMBOLS_0,
+            Locale::af_NA => &_NUMBER_SYMBOLS_0,
+            Locale::agq => &_NUMBER_SYMBOLS_0,
+            Locale::ak => &_NUMBER_SYMBOLS_0,
+            Locale::am => &_NUMBER_SYMBOLS_1,
+            Locale::an => &_NUMBER_SYMBOLS_0,
+            Locale::ann => &_NUMBER_SYMBOLS_0,
+            Locale::apc => &_NUMBER_SYMBOLS_0,
+            Locale::ar => &_NUMBER_SYMBOLS_2,
+            Locale::ar_AE => &_NUMBER_SYMBOLS_2,
+            Locale::ar_BH => &_NUMBER_SYMBOLS_3,
+            Locale::ar_DJ => &_NUMBER_SYMBOLS_3,
+            Locale::ar_DZ => &_NUMBER_SYMBOLS_2,
+            Locale::ar_EG => &_NUMBER_SYMBOLS_3,
+            Locale::ar_EH => &_NUMBER_SYMBOLS_2,
+            Locale::ar_ER => &_NUMBER_SYMBOLS_3,
+            Locale::ar_IL => &_NUMBER_SYMBOLS_3,
+            Locale::ar_IQ => &_NUMBER_SYMBOLS_3,
+            Locale::ar_JO => &_NUMBER_SYMBOLS_3,
+            Locale::ar_KM => &_NUMBER_SYMBOLS_3,
+            Locale::ar_KW => &_NUMBER_SYMBOLS_3,
+            Locale::ar_LB => &_NUMBER_SYMBOLS_4,
+            Locale::ar_LY => &_NUMBER_SYMBOLS_2,
+            Locale::ar_MA => &_NUMBER_SYMBOLS_2,
+            Locale::ar_MR => &_NUMBER_SYMBOLS_4,
+            Locale::ar_OM => &_NUMBER_SYMBOLS_3,
+            Locale::ar_PS => &_NUMBER_SYMBOLS_3,
+            Locale::ar_QA => &_NUMBER_SYMBOLS_3,
+            Locale::ar_SA => &_NUMBER_SYMBOLS_3,
+            Locale::ar_SD => &_NUMBER_SYMBOLS_3,
+            Locale::ar_SO => &_NUMBER_SYMBOLS_3,
+            Locale::ar_SS => &_NUMBER_SYMBOLS_3,
+            Locale::ar_SY => &_NUMBER_SYMBOLS_3,
+            Locale::ar_TD => &_NUMBER_SYMBOLS_3,
+            Locale::ar_TN => &_NUMBER_SYMBOLS_2,
+            Locale::ar_YE => &_NUMBER_SYMBOLS_3,
+            Locale::arn => &_NUMBER_SYMBOLS_0,
+            Locale::as_ => &_NUMBER_SYMBOLS_5,
+            Locale::asa => &_NUMBER_SYMBOLS_0,
+            Locale::ast => &_NUMBER_SYMBOLS_0,
+            Locale::az => &_NUMBER_SYMBOLS_0,
+            Locale::az_Arab => &_NUMBER_SYMBOLS_6,
+            Locale::az_Arab_IQ => &_NUMBER_SYMBOLS_6,
+            Locale::az_Arab_TR => &_NUMBER_SYMBOLS_6,
+            Locale::az_Cyrl => &_NUMBER_SYMBOLS_0,
+            Locale::az_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::ba => &_NUMBER_SYMBOLS_0,
+            Locale::bal => &_NUMBER_SYMBOLS_0,
+            Locale::bal_Arab => &_NUMBER_SYMBOLS_0,
+            Locale::bal_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::bas => &_NUMBER_SYMBOLS_0,
+            Locale::be => &_NUMBER_SYMBOLS_0,
+            Locale::be_tarask => &_NUMBER_SYMBOLS_0,
+            Locale::bem => &_NUMBER_SYMBOLS_0,
+            Locale::bew => &_NUMBER_SYMBOLS_0,
+            Locale::bez => &_NUMBER_SYMBOLS_0,
+            Locale::bg => &_NUMBER_SYMBOLS_0,
+            Locale::bgc => &_NUMBER_SYMBOLS_5,
+            Locale::bgn => &_NUMBER_SYMBOLS_5,
+            Locale::bgn_AE => &_NUMBER_SYMBOLS_5,
+            Locale::bgn_AF => &_NUMBER_SYMBOLS_5,
+            Locale::bgn_IR => &_NUMBER_SYMBOLS_5,
+            Locale::bgn_OM => &_NUMBER_SYMBOLS_5,
+            Locale::bho => &_NUMBER_SYMBOLS_5,
+            Locale::blo => &_NUMBER_SYMBOLS_0,
+            Locale::blt => &_NUMBER_SYMBOLS_0,
+            Locale::bm => &_NUMBER_SYMBOLS_0,
+            Locale::bm_Nkoo => &_NUMBER_SYMBOLS_0,
+            Locale::bn => &_NUMBER_SYMBOLS_5,
+            Locale::bn_IN => &_NUMBER_SYMBOLS_5,
+            Locale::bo => &_NUMBER_SYMBOLS_7,
+            Locale::bo_IN => &_NUMBER_SYMBOLS_7,
+            Locale::bqi => &_NUMBER_SYMBOLS_0,
+            Locale::br => &_NUMBER_SYMBOLS_0,
+            Locale::brx => &_NUMBER_SYMBOLS_8,
+            Locale::bs => &_NUMBER_SYMBOLS_0,
+            Locale::bs_Cyrl => &_NUMBER_SYMBOLS_0,
+            Locale::bs_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::bss => &_NUMBER_SYMBOLS_0,
+            Locale::bua => &_NUMBER_SYMBOLS_0,
+            Locale::byn => &_NUMBER_SYMBOLS_0,
+            Locale::ca => &_NUMBER_SYMBOLS_0,
+            Locale::ca_AD => &_NUMBER_SYMBOLS_0,
+            Locale::ca_ES_valencia => &_NUMBER_SYMBOLS_0,
+            Locale::ca_FR => &_NUMBER_SYMBOLS_0,
+            Locale::ca_IT => &_NUMBER_SYMBOLS_0,
+            Locale::cad => &_NUMBER_SYMBOLS_0,
+            Locale::cch => &_NUMBER_SYMBOLS_0,
+            Locale::ccp => &_NUMBER_SYMBOLS_5,
+            Locale::ccp_IN => &_NUMBER_SYMBOLS_5,
+            Locale::ce => &_NUMBER_SYMBOLS_0,
+            Locale::ceb => &_NUMBER_SYMBOLS_0,
+            Locale::cgg => &_NUMBER_SYMBOLS_0,
+            Locale::cho => &_NUMBER_SYMBOLS_0,
+            Locale::chr => &_NUMBER_SYMBOLS_0,
+            Locale::cic => &_NUMBER_SYMBOLS_0,
+            Locale::ckb => &_NUMBER_SYMBOLS_5,
+            Locale::ckb_IR => &_NUMBER_SYMBOLS_5,
+            Locale::co => &_NUMBER_SYMBOLS_0,
+            Locale::cop => &_NUMBER_SYMBOLS_0,
+            Locale::cs => &_NUMBER_SYMBOLS_0,
+            Locale::csw => &_NUMBER_SYMBOLS_0,
+            Locale::cu => &_NUMBER_SYMBOLS_0,
+            Locale::cv => &_NUMBER_SYMBOLS_0,
+            Locale::cy => &_NUMBER_SYMBOLS_0,
+            Locale::da => &_NUMBER_SYMBOLS_0,
+            Locale::da_GL => &_NUMBER_SYMBOLS_0,
+            Locale::dav => &_NUMBER_SYMBOLS_0,
+            Locale::de => &_NUMBER_SYMBOLS_0,
+            Locale::de_AT => &_NUMBER_SYMBOLS_0,
+            Locale::de_BE => &_NUMBER_SYMBOLS_0,
+            Locale::de_CH => &_NUMBER_SYMBOLS_0,
+            Locale::de_IT => &_NUMBER_SYMBOLS_0,
+            Locale::de_LI => &_NUMBER_SYMBOLS_0,
+            Locale::de_LU => &_NUMBER_SYMBOLS_0,
+            Locale::dje => &_NUMBER_SYMBOLS_0,
+            Locale::doi => &_NUMBER_SYMBOLS_8,
+            Locale::dsb => &_NUMBER_SYMBOLS_0,
+            Locale::dua => &_NUMBER_SYMBOLS_0,
+            Locale::dv => &_NUMBER_SYMBOLS_0,
+            Locale::dyo => &_NUMBER_SYMBOLS_0,
+            Locale::dz => &_NUMBER_SYMBOLS_5,
+            Locale::ebu => &_NUMBER_SYMBOLS_0,
+            Locale::ee => &_NUMBER_SYMBOLS_0,
+            Locale::ee_TG => &_NUMBER_SYMBOLS_0,
+            Locale::el => &_NUMBER_SYMBOLS_9,
+            Locale::el_CY => &_NUMBER_SYMBOLS_9,
+            Locale::el_polyton => &_NUMBER_SYMBOLS_9,
+            Locale::en => &_NUMBER_SYMBOLS_0,
+            Locale::en_001 => &_NUMBER_SYMBOLS_0,
+            Locale::en_150 => &_NUMBER_SYMBOLS_0,
+            Locale::en_AE => &_NUMBER_SYMBOLS_0,
+            Locale::en_AG => &_NUMBER_SYMBOLS_0,
+            Locale::en_AI => &_NUMBER_SYMBOLS_0,
+            Locale::en_AS => &_NUMBER_SYMBOLS_0,
+            Locale::en_AT => &_NUMBER_SYMBOLS_0,
+            Locale::en_AU => &_NUMBER_SYMBOLS_0,
+            Locale::en_BB => &_NUMBER_SYMBOLS_0,
+            Locale::en_BE => &_NUMBER_SYMBOLS_0,
+            Locale::en_BI => &_NUMBER_SYMBOLS_0,
+            Locale::en_BM => &_NUMBER_SYMBOLS_0,
+            Locale::en_BS => &_NUMBER_SYMBOLS_0,
+            Locale::en_BW => &_NUMBER_SYMBOLS_0,
+            Locale::en_BZ => &_NUMBER_SYMBOLS_0,
+            Locale::en_CA => &_NUMBER_SYMBOLS_0,
+            Locale::en_CC => &_NUMBER_SYMBOLS_0,
+            Locale::en_CH => &_NUMBER_SYMBOLS_0,
+            Locale::en_CK => &_NUMBER_SYMBOLS_0,
+            Locale::en_CM => &_NUMBER_SYMBOLS_0,
+            Locale::en_CX => &_NUMBER_SYMBOLS_0,
+            Locale::en_CY => &_NUMBER_SYMBOLS_0,
+            Locale::en_CZ => &_NUMBER_SYMBOLS_0,
+            Locale::en_DE => &_NUMBER_SYMBOLS_0,
+            Locale::en_DG => &_NUMBER_SYMBOLS_0,
+            Locale::en_DK => &_NUMBER_SYMBOLS_0,
+            Locale::en_DM => &_NUMBER_SYMBOLS_0,
+            Locale::en_Dsrt => &_NUMBER_SYMBOLS_0,
+            Locale::en_EE => &_NUMBER_SYMBOLS_0,
+            Locale::en_ER => &_NUMBER_SYMBOLS_0,
+            Locale::en_ES => &_NUMBER_SYMBOLS_0,
+            Locale::en_FI => &_NUMBER_SYMBOLS_0,
+            Locale::en_FJ => &_NUMBER_SYMBOLS_0,
+            Locale::en_FK => &_NUMBER_SYMBOLS_0,
+            Locale::en_FM => &_NUMBER_SYMBOLS_0,
+            Locale::en_FR => &_NUMBER_SYMBOLS_0,
+            Locale::en_GB => &_NUMBER_SYMBOLS_0,
+            Locale::en_GD => &_NUMBER_SYMBOLS_0,
+            Locale::en_GE => &_NUMBER_SYMBOLS_0,
+            Locale::en_GG => &_NUMBER_SYMBOLS_0,
+            Locale::en_GH => &_NUMBER_SYMBOLS_0,
+            Locale::en_GI => &_NUMBER_SYMBOLS_0,
+            Locale::en_GM => &_NUMBER_SYMBOLS_0,
+            Locale::en_GS => &_NUMBER_SYMBOLS_0,
+            Locale::en_GU => &_NUMBER_SYMBOLS_0,
+            Locale::en_GY => &_NUMBER_SYMBOLS_0,
+            Locale::en_HK => &_NUMBER_SYMBOLS_0,
+            Locale::en_HU => &_NUMBER_SYMBOLS_0,
+            Locale::en_ID => &_NUMBER_SYMBOLS_0,
+            Locale::en_IE => &_NUMBER_SYMBOLS_0,
+            Locale::en_IL => &_NUMBER_SYMBOLS_0,
+            Locale::en_IM => &_NUMBER_SYMBOLS_0,
+            Locale::en_IN => &_NUMBER_SYMBOLS_0,
+            Locale::en_IO => &_NUMBER_SYMBOLS_0,
+            Locale::en_IT => &_NUMBER_SYMBOLS_0,
+            Locale::en_JE => &_NUMBER_SYMBOLS_0,
+            Locale::en_JM => &_NUMBER_SYMBOLS_0,
+            Locale::en_JP => &_NUMBER_SYMBOLS_0,
+            Locale::en_KE => &_NUMBER_SYMBOLS_0,
+            Locale::en_KI => &_NUMBER_SYMBOLS_0,
+            Locale::en_KN => &_NUMBER_SYMBOLS_0,
+            Locale::en_KY => &_NUMBER_SYMBOLS_0,
+            Locale::en_LC => &_NUMBER_SYMBOLS_0,
+            Locale::en_LR => &_NUMBER_SYMBOLS_0,
+            Locale::en_LS => &_NUMBER_SYMBOLS_0,
+            Locale::en_LT => &_NUMBER_SYMBOLS_0,
+            Locale::en_LV => &_NUMBER_SYMBOLS_0,
+            Locale::en_MG => &_NUMBER_SYMBOLS_0,
+            Locale::en_MH => &_NUMBER_SYMBOLS_0,
+            Locale::en_MO => &_NUMBER_SYMBOLS_0,
+            Locale::en_MP => &_NUMBER_SYMBOLS_0,
+            Locale::en_MS => &_NUMBER_SYMBOLS_0,
+            Locale::en_MT => &_NUMBER_SYMBOLS_0,
+            Locale::en_MU => &_NUMBER_SYMBOLS_0,
+            Locale::en_MV => &_NUMBER_SYMBOLS_0,
+            Locale::en_MW => &_NUMBER_SYMBOLS_0,
+            Locale::en_MY => &_NUMBER_SYMBOLS_0,
+            Locale::en_NA => &_NUMBER_SYMBOLS_0,
+            Locale::en_NF => &_NUMBER_SYMBOLS_0,
+            Locale::en_NG => &_NUMBER_SYMBOLS_0,
+            Locale::en_NL => &_NUMBER_SYMBOLS_0,
+            Locale::en_NO => &_NUMBER_SYMBOLS_0,
+            Locale::en_NR => &_NUMBER_SYMBOLS_0,
+            Locale::en_NU => &_NUMBER_SYMBOLS_0,
+            Locale::en_NZ => &_NUMBER_SYMBOLS_0,
+            Locale::en_PG => &_NUMBER_SYMBOLS_0,
+            Locale::en_PH => &_NUMBER_SYMBOLS_0,
+            Locale::en_PK => &_NUMBER_SYMBOLS_0,
+            Locale::en_PL => &_NUMBER_SYMBOLS_0,
+            Locale::en_PN => &_NUMBER_SYMBOLS_0,
+            Locale::en_PR => &_NUMBER_SYMBOLS_0,
+            Locale::en_PT => &_NUMBER_SYMBOLS_0,
+            Locale::en_PW => &_NUMBER_SYMBOLS_0,
+            Locale::en_RO => &_NUMBER_SYMBOLS_0,
+            Locale::en_RW => &_NUMBER_SYMBOLS_0,
+            Locale::en_SB => &_NUMBER_SYMBOLS_0,
+            Locale::en_SC => &_NUMBER_SYMBOLS_0,
+            Locale::en_SD => &_NUMBER_SYMBOLS_0,
+            Locale::en_SE => &_NUMBER_SYMBOLS_0,
+            Locale::en_SG => &_NUMBER_SYMBOLS_0,
+            Locale::en_SH => &_NUMBER_SYMBOLS_0,
+            Locale::en_SI => &_NUMBER_SYMBOLS_0,
+            Locale::en_SK => &_NUMBER_SYMBOLS_0,
+            Locale::en_SL => &_NUMBER_SYMBOLS_0,
+            Locale::en_SS => &_NUMBER_SYMBOLS_0,
+            Locale::en_SX => &_NUMBER_SYMBOLS_0,
+            Locale::en_SZ => &_NUMBER_SYMBOLS_0,
+            Locale::en_Shaw => &_NUMBER_SYMBOLS_0,
+            Locale::en_TC => &_NUMBER_SYMBOLS_0,
+            Locale::en_TK => &_NUMBER_SYMBOLS_0,
+            Locale::en_TO => &_NUMBER_SYMBOLS_0,
+            Locale::en_TT => &_NUMBER_SYMBOLS_0,
+            Locale::en_TV => &_NUMBER_SYMBOLS_0,
+            Locale::en_TZ => &_NUMBER_SYMBOLS_0,
+            Locale::en_UA => &_NUMBER_SYMBOLS_0,
+            Locale::en_UG => &_NUMBER_SYMBOLS_0,
+            Locale::en_UM => &_NUMBER_SYMBOLS_0,
+            Locale::en_VC => &_NUMBER_SYMBOLS_0,
+            Locale::en_VG => &_NUMBER_SYMBOLS_0,
+            Locale::en_VI => &_NUMBER_SYMBOLS_0,
+            Locale::en_VU => &_NUMBER_SYMBOLS_0,
+            Locale::en_WS => &_NUMBER_SYMBOLS_0,
+            Locale::en_ZA => &_NUMBER_SYMBOLS_0,
+            Locale::en_ZM => &_NUMBER_SYMBOLS_0,
+            Locale::en_ZW => &_NUMBER_SYMBOLS_0,
+            Locale::eo => &_NUMBER_SYMBOLS_0,
+            Locale::es => &_NUMBER_SYMBOLS_0,
+            Locale::es_419 => &_NUMBER_SYMBOLS_0,
+            Locale::es_AR => &_NUMBER_SYMBOLS_0,
+            Locale::es_BO => &_NUMBER_SYMBOLS_0,
+            Locale::es_BR => &_NUMBER_SYMBOLS_0,
+            Locale::es_BZ => &_NUMBER_SYMBOLS_0,
+            Locale::es_CL => &_NUMBER_SYMBOLS_0,
+            Locale::es_CO => &_NUMBER_SYMBOLS_0,
+            Locale::es_CR => &_NUMBER_SYMBOLS_0,
+            Locale::es_CU => &_NUMBER_SYMBOLS_0,
+            Locale::es_DO => &_NUMBER_SYMBOLS_0,
+            Locale::es_EA => &_NUMBER_SYMBOLS_0,
+            Locale::es_EC => &_NUMBER_SYMBOLS_0,
+            Locale::es_GQ => &_NUMBER_SYMBOLS_0,
+            Locale::es_GT => &_NUMBER_SYMBOLS_0,
+            Locale::es_HN => &_NUMBER_SYMBOLS_0,
+            Locale::es_IC => &_NUMBER_SYMBOLS_0,
+            Locale::es_MX => &_NUMBER_SYMBOLS_0,
+            Locale::es_NI => &_NUMBER_SYMBOLS_0,
+            Locale::es_PA => &_NUMBER_SYMBOLS_0,
+            Locale::es_PE => &_NUMBER_SYMBOLS_0,
+            Locale::es_PH => &_NUMBER_SYMBOLS_0,
+            Locale::es_PR => &_NUMBER_SYMBOLS_0,
+            Locale::es_PY => &_NUMBER_SYMBOLS_0,
+            Locale::es_SV => &_NUMBER_SYMBOLS_0,
+            Locale::es_US => &_NUMBER_SYMBOLS_0,
+            Locale::es_UY => &_NUMBER_SYMBOLS_0,
+            Locale::es_VE => &_NUMBER_SYMBOLS_0,
+            Locale::et => &_NUMBER_SYMBOLS_0,
+            Locale::eu => &_NUMBER_SYMBOLS_0,
+            Locale::ewo => &_NUMBER_SYMBOLS_0,
+            Locale::fa => &_NUMBER_SYMBOLS_10,
+            Locale::fa_AF => &_NUMBER_SYMBOLS_10,
+            Locale::ff => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Adlm => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_BF => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_CM => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_GH => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_GM => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_GW => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_LR => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_MR => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_NE => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_NG => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_SL => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Adlm_SN => &_NUMBER_SYMBOLS_11,
+            Locale::ff_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_BF => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_CM => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_GH => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_GM => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_GN => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_GW => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_LR => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_MR => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_NE => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_NG => &_NUMBER_SYMBOLS_0,
+            Locale::ff_Latn_SL => &_NUMBER_SYMBOLS_0,
+            Locale::fi => &_NUMBER_SYMBOLS_0,
+            Locale::fil => &_NUMBER_SYMBOLS_0,
+            Locale::fo => &_NUMBER_SYMBOLS_0,
+            Locale::fo_DK => &_NUMBER_SYMBOLS_0,
+            Locale::fr => &_NUMBER_SYMBOLS_0,
+            Locale::fr_BE => &_NUMBER_SYMBOLS_0,
+            Locale::fr_BF => &_NUMBER_SYMBOLS_0,
+            Locale::fr_BI => &_NUMBER_SYMBOLS_0,
+            Locale::fr_BJ => &_NUMBER_SYMBOLS_0,
+            Locale::fr_BL => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CA => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CD => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CF => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CG => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CH => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CI => &_NUMBER_SYMBOLS_0,
+            Locale::fr_CM => &_NUMBER_SYMBOLS_0,
+            Locale::fr_DJ => &_NUMBER_SYMBOLS_0,
+            Locale::fr_DZ => &_NUMBER_SYMBOLS_0,
+            Locale::fr_GA => &_NUMBER_SYMBOLS_0,
+            Locale::fr_GF => &_NUMBER_SYMBOLS_0,
+            Locale::fr_GN => &_NUMBER_SYMBOLS_0,
+            Locale::fr_GP => &_NUMBER_SYMBOLS_0,
+            Locale::fr_GQ => &_NUMBER_SYMBOLS_0,
+            Locale::fr_HT => &_NUMBER_SYMBOLS_0,
+            Locale::fr_KM => &_NUMBER_SYMBOLS_0,
+            Locale::fr_LU => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MA => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MC => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MF => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MG => &_NUMBER_SYMBOLS_0,
+            Locale::fr_ML => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MQ => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MR => &_NUMBER_SYMBOLS_0,
+            Locale::fr_MU => &_NUMBER_SYMBOLS_0,
+            Locale::fr_NC => &_NUMBER_SYMBOLS_0,
+            Locale::fr_NE => &_NUMBER_SYMBOLS_0,
+            Locale::fr_PF => &_NUMBER_SYMBOLS_0,
+            Locale::fr_PM => &_NUMBER_SYMBOLS_0,
+            Locale::fr_RE => &_NUMBER_SYMBOLS_0,
+            Locale::fr_RW => &_NUMBER_SYMBOLS_0,
+            Locale::fr_SC => &_NUMBER_SYMBOLS_0,
+            Locale::fr_SN => &_NUMBER_SYMBOLS_0,
+            Locale::fr_SY => &_NUMBER_SYMBOLS_0,
+            Locale::fr_TD => &_NUMBER_SYMBOLS_0,
+            Locale::fr_TG => &_NUMBER_SYMBOLS_0,
+            Locale::fr_TN => &_NUMBER_SYMBOLS_0,
+            Locale::fr_VU => &_NUMBER_SYMBOLS_0,
+            Locale::fr_WF => &_NUMBER_SYMBOLS_0,
+            Locale::fr_YT => &_NUMBER_SYMBOLS_0,
+            Locale::frr => &_NUMBER_SYMBOLS_0,
+            Locale::fur => &_NUMBER_SYMBOLS_0,
+            Locale::fy => &_NUMBER_SYMBOLS_0,
+            Locale::ga => &_NUMBER_SYMBOLS_0,
+            Locale::ga_GB => &_NUMBER_SYMBOLS_0,
+            Locale::gaa => &_NUMBER_SYMBOLS_0,
+            Locale::gd => &_NUMBER_SYMBOLS_0,
+            Locale::gez => &_NUMBER_SYMBOLS_0,
+            Locale::gez_ER => &_NUMBER_SYMBOLS_0,
+            Locale::gl => &_NUMBER_SYMBOLS_0,
+            Locale::gn => &_NUMBER_SYMBOLS_0,
+            Locale::gsw => &_NUMBER_SYMBOLS_0,
+            Locale::gsw_FR => &_NUMBER_SYMBOLS_0,
+            Locale::gsw_LI => &_NUMBER_SYMBOLS_0,
+            Locale::gu => &_NUMBER_SYMBOLS_12,
+            Locale::guz => &_NUMBER_SYMBOLS_0,
+            Locale::gv => &_NUMBER_SYMBOLS_0,
+            Locale::ha => &_NUMBER_SYMBOLS_0,
+            Locale::ha_Arab => &_NUMBER_SYMBOLS_0,
+            Locale::ha_Arab_SD => &_NUMBER_SYMBOLS_0,
+            Locale::ha_GH => &_NUMBER_SYMBOLS_0,
+            Locale::ha_NE => &_NUMBER_SYMBOLS_0,
+            Locale::haw => &_NUMBER_SYMBOLS_0,
+            Locale::he => &_NUMBER_SYMBOLS_13,
+            Locale::hi => &_NUMBER_SYMBOLS_8,
+            Locale::hi_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::hnj => &_NUMBER_SYMBOLS_5,
+            Locale::hnj_Hmnp => &_NUMBER_SYMBOLS_5,
+            Locale::hr => &_NUMBER_SYMBOLS_0,
+            Locale::hr_BA => &_NUMBER_SYMBOLS_0,
+            Locale::hsb => &_NUMBER_SYMBOLS_0,
+            Locale::ht => &_NUMBER_SYMBOLS_0,
+            Locale::hu => &_NUMBER_SYMBOLS_0,
+            Locale::hy => &_NUMBER_SYMBOLS_14,
+            Locale::ia => &_NUMBER_SYMBOLS_0,
+            Locale::id => &_NUMBER_SYMBOLS_0,
+            Locale::ie => &_NUMBER_SYMBOLS_0,
+            Locale::ig => &_NUMBER_SYMBOLS_0,
+            Locale::ii => &_NUMBER_SYMBOLS_0,
+            Locale::io => &_NUMBER_SYMBOLS_0,
+            Locale::is => &_NUMBER_SYMBOLS_0,
+            Locale::it => &_NUMBER_SYMBOLS_0,
+            Locale::it_CH => &_NUMBER_SYMBOLS_0,
+            Locale::it_SM => &_NUMBER_SYMBOLS_0,
+            Locale::it_VA => &_NUMBER_SYMBOLS_0,
+            Locale::iu => &_NUMBER_SYMBOLS_0,
+            Locale::iu_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::ja => &_NUMBER_SYMBOLS_15,
+            Locale::jbo => &_NUMBER_SYMBOLS_0,
+            Locale::jgo => &_NUMBER_SYMBOLS_0,
+            Locale::jmc => &_NUMBER_SYMBOLS_0,
+            Locale::jv => &_NUMBER_SYMBOLS_16,
+            Locale::ka => &_NUMBER_SYMBOLS_17,
+            Locale::kaa => &_NUMBER_SYMBOLS_0,
+            Locale::kaa_Cyrl => &_NUMBER_SYMBOLS_0,
+            Locale::kaa_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::kab => &_NUMBER_SYMBOLS_0,
+            Locale::kaj => &_NUMBER_SYMBOLS_0,
+            Locale::kam => &_NUMBER_SYMBOLS_0,
+            Locale::kcg => &_NUMBER_SYMBOLS_0,
+            Locale::kde => &_NUMBER_SYMBOLS_0,
+            Locale::kea => &_NUMBER_SYMBOLS_0,
+            Locale::kek => &_NUMBER_SYMBOLS_0,
+            Locale::ken => &_NUMBER_SYMBOLS_0,
+            Locale::kgp => &_NUMBER_SYMBOLS_0,
+            Locale::khq => &_NUMBER_SYMBOLS_0,
+            Locale::ki => &_NUMBER_SYMBOLS_0,
+            Locale::kk => &_NUMBER_SYMBOLS_0,
+            Locale::kk_Arab => &_NUMBER_SYMBOLS_0,
+            Locale::kk_Cyrl => &_NUMBER_SYMBOLS_0,
+            Locale::kk_KZ => &_NUMBER_SYMBOLS_0,
+            Locale::kkj => &_NUMBER_SYMBOLS_0,
+            Locale::kl => &_NUMBER_SYMBOLS_0,
+            Locale::kln => &_NUMBER_SYMBOLS_0,
+            Locale::km => &_NUMBER_SYMBOLS_18,
+            Locale::kn => &_NUMBER_SYMBOLS_19,
+            Locale::ko => &_NUMBER_SYMBOLS_0,
+            Locale::ko_CN => &_NUMBER_SYMBOLS_0,
+            Locale::ko_KP => &_NUMBER_SYMBOLS_0,
+            Locale::kok => &_NUMBER_SYMBOLS_8,
+            Locale::kok_Deva => &_NUMBER_SYMBOLS_8,
+            Locale::kok_Latn => &_NUMBER_SYMBOLS_8,
+            Locale::kpe => &_NUMBER_SYMBOLS_0,
+            Locale::kpe_GN => &_NUMBER_SYMBOLS_0,
+            Locale::ks => &_NUMBER_SYMBOLS_20,
+            Locale::ks_Arab => &_NUMBER_SYMBOLS_20,
+            Locale::ks_Deva => &_NUMBER_SYMBOLS_0,
+            Locale::ksb => &_NUMBER_SYMBOLS_0,
+            Locale::ksf => &_NUMBER_SYMBOLS_0,
+            Locale::ksh => &_NUMBER_SYMBOLS_0,
+            Locale::ku => &_NUMBER_SYMBOLS_0,
+            Locale::ku_Arab => &_NUMBER_SYMBOLS_0,
+            Locale::ku_Arab_IR => &_NUMBER_SYMBOLS_0,
+            Locale::ku_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::ku_Latn_IQ => &_NUMBER_SYMBOLS_0,
+            Locale::ku_Latn_SY => &_NUMBER_SYMBOLS_0,
+            Locale::ku_TR => &_NUMBER_SYMBOLS_0,
+            Locale::kw => &_NUMBER_SYMBOLS_0,
+            Locale::kxv => &_NUMBER_SYMBOLS_0,
+            Locale::kxv_Deva => &_NUMBER_SYMBOLS_0,
+            Locale::kxv_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::kxv_Orya => &_NUMBER_SYMBOLS_0,
+            Locale::kxv_Telu => &_NUMBER_SYMBOLS_0,
+            Locale::ky => &_NUMBER_SYMBOLS_0,
+            Locale::la => &_NUMBER_SYMBOLS_0,
+            Locale::lag => &_NUMBER_SYMBOLS_0,
+            Locale::lb => &_NUMBER_SYMBOLS_0,
+            Locale::lg => &_NUMBER_SYMBOLS_0,
+            Locale::lij => &_NUMBER_SYMBOLS_0,
+            Locale::lkt => &_NUMBER_SYMBOLS_0,
+            Locale::lld => &_NUMBER_SYMBOLS_0,
+            Locale::lmo => &_NUMBER_SYMBOLS_0,
+            Locale::ln => &_NUMBER_SYMBOLS_0,
+            Locale::ln_AO => &_NUMBER_SYMBOLS_0,
+            Locale::ln_CF => &_NUMBER_SYMBOLS_0,
+            Locale::ln_CG => &_NUMBER_SYMBOLS_0,
+            Locale::lo => &_NUMBER_SYMBOLS_21,
+            Locale::lrc => &_NUMBER_SYMBOLS_5,
+            Locale::lrc_IQ => &_NUMBER_SYMBOLS_5,
+            Locale::lt => &_NUMBER_SYMBOLS_0,
+            Locale::ltg => &_NUMBER_SYMBOLS_0,
+            Locale::lu => &_NUMBER_SYMBOLS_0,
+            Locale::luo => &_NUMBER_SYMBOLS_0,
+            Locale::luy => &_NUMBER_SYMBOLS_0,
+            Locale::lv => &_NUMBER_SYMBOLS_0,
+            Locale::lzz => &_NUMBER_SYMBOLS_0,
+            Locale::mai => &_NUMBER_SYMBOLS_8,
+            Locale::mas => &_NUMBER_SYMBOLS_0,
+            Locale::mas_TZ => &_NUMBER_SYMBOLS_0,
+            Locale::mdf => &_NUMBER_SYMBOLS_0,
+            Locale::mer => &_NUMBER_SYMBOLS_0,
+            Locale::mfe => &_NUMBER_SYMBOLS_0,
+            Locale::mg => &_NUMBER_SYMBOLS_0,
+            Locale::mgh => &_NUMBER_SYMBOLS_0,
+            Locale::mgo => &_NUMBER_SYMBOLS_0,
+            Locale::mhn => &_NUMBER_SYMBOLS_0,
+            Locale::mi => &_NUMBER_SYMBOLS_0,
+            Locale::mic => &_NUMBER_SYMBOLS_0,
+            Locale::mk => &_NUMBER_SYMBOLS_0,
+            Locale::ml => &_NUMBER_SYMBOLS_22,
+            Locale::mn => &_NUMBER_SYMBOLS_0,
+            Locale::mn_Mong => &_NUMBER_SYMBOLS_0,
+            Locale::mn_Mong_MN => &_NUMBER_SYMBOLS_0,
+            Locale::mni => &_NUMBER_SYMBOLS_5,
+            Locale::mni_Beng => &_NUMBER_SYMBOLS_5,
+            Locale::mni_Mtei => &_NUMBER_SYMBOLS_5,
+            Locale::moh => &_NUMBER_SYMBOLS_0,
+            Locale::mr => &_NUMBER_SYMBOLS_5,
+            Locale::ms => &_NUMBER_SYMBOLS_0,
+            Locale::ms_Arab => &_NUMBER_SYMBOLS_0,
+            Locale::ms_Arab_BN => &_NUMBER_SYMBOLS_0,
+            Locale::ms_BN => &_NUMBER_SYMBOLS_0,
+            Locale::ms_ID => &_NUMBER_SYMBOLS_0,
+            Locale::ms_SG => &_NUMBER_SYMBOLS_0,
+            Locale::mt => &_NUMBER_SYMBOLS_0,
+            Locale::mua => &_NUMBER_SYMBOLS_0,
+            Locale::mus => &_NUMBER_SYMBOLS_0,
+            Locale::mww => &_NUMBER_SYMBOLS_5,
+            Locale::mww_Hmnp => &_NUMBER_SYMBOLS_5,
+            Locale::my => &_NUMBER_SYMBOLS_5,
+            Locale::myv => &_NUMBER_SYMBOLS_0,
+            Locale::mzn => &_NUMBER_SYMBOLS_5,
+            Locale::naq => &_NUMBER_SYMBOLS_0,
+            Locale::nb => &_NUMBER_SYMBOLS_0,
+            Locale::nb_SJ => &_NUMBER_SYMBOLS_0,
+            Locale::nd => &_NUMBER_SYMBOLS_0,
+            Locale::nds => &_NUMBER_SYMBOLS_0,
+            Locale::nds_NL => &_NUMBER_SYMBOLS_0,
+            Locale::ne => &_NUMBER_SYMBOLS_5,
+            Locale::ne_IN => &_NUMBER_SYMBOLS_5,
+            Locale::nl => &_NUMBER_SYMBOLS_0,
+            Locale::nl_AW => &_NUMBER_SYMBOLS_0,
+            Locale::nl_BE => &_NUMBER_SYMBOLS_0,
+            Locale::nl_BQ => &_NUMBER_SYMBOLS_0,
+            Locale::nl_CW => &_NUMBER_SYMBOLS_0,
+            Locale::nl_SR => &_NUMBER_SYMBOLS_0,
+            Locale::nl_SX => &_NUMBER_SYMBOLS_0,
+            Locale::nmg => &_NUMBER_SYMBOLS_0,
+            Locale::nn => &_NUMBER_SYMBOLS_0,
+            Locale::nnh => &_NUMBER_SYMBOLS_0,
+            Locale::no => &_NUMBER_SYMBOLS_0,
+            Locale::nqo => &_NUMBER_SYMBOLS_5,
+            Locale::nr => &_NUMBER_SYMBOLS_0,
+            Locale::nso => &_NUMBER_SYMBOLS_0,
+            Locale::nus => &_NUMBER_SYMBOLS_0,
+            Locale::nv => &_NUMBER_SYMBOLS_0,
+            Locale::ny => &_NUMBER_SYMBOLS_0,
+            Locale::nyn => &_NUMBER_SYMBOLS_0,
+            Locale::oc => &_NUMBER_SYMBOLS_0,
+            Locale::oc_ES => &_NUMBER_SYMBOLS_0,
+            Locale::oka => &_NUMBER_SYMBOLS_0,
+            Locale::oka_US => &_NUMBER_SYMBOLS_0,
+            Locale::om => &_NUMBER_SYMBOLS_1,
+            Locale::om_KE => &_NUMBER_SYMBOLS_1,
+            Locale::or => &_NUMBER_SYMBOLS_23,
+            Locale::os => &_NUMBER_SYMBOLS_0,
+            Locale::os_RU => &_NUMBER_SYMBOLS_0,
+            Locale::osa => &_NUMBER_SYMBOLS_0,
+            Locale::pa => &_NUMBER_SYMBOLS_24,
+            Locale::pa_Arab => &_NUMBER_SYMBOLS_3,
+            Locale::pa_Guru => &_NUMBER_SYMBOLS_24,
+            Locale::pap => &_NUMBER_SYMBOLS_0,
+            Locale::pap_AW => &_NUMBER_SYMBOLS_0,
+            Locale::pcm => &_NUMBER_SYMBOLS_0,
+            Locale::pi => &_NUMBER_SYMBOLS_0,
+            Locale::pi_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::pis => &_NUMBER_SYMBOLS_0,
+            Locale::pl => &_NUMBER_SYMBOLS_0,
+            Locale::pms => &_NUMBER_SYMBOLS_0,
+            Locale::prg => &_NUMBER_SYMBOLS_0,
+            Locale::ps => &_NUMBER_SYMBOLS_25,
+            Locale::ps_PK => &_NUMBER_SYMBOLS_25,
+            Locale::pt => &_NUMBER_SYMBOLS_0,
+            Locale::pt_AO => &_NUMBER_SYMBOLS_0,
+            Locale::pt_CH => &_NUMBER_SYMBOLS_0,
+            Locale::pt_CV => &_NUMBER_SYMBOLS_0,
+            Locale::pt_GQ => &_NUMBER_SYMBOLS_0,
+            Locale::pt_GW => &_NUMBER_SYMBOLS_0,
+            Locale::pt_LU => &_NUMBER_SYMBOLS_0,
+            Locale::pt_MO => &_NUMBER_SYMBOLS_0,
+            Locale::pt_MZ => &_NUMBER_SYMBOLS_0,
+            Locale::pt_PT => &_NUMBER_SYMBOLS_0,
+            Locale::pt_ST => &_NUMBER_SYMBOLS_0,
+            Locale::pt_TL => &_NUMBER_SYMBOLS_0,
+            Locale::qu => &_NUMBER_SYMBOLS_0,
+            Locale::qu_BO => &_NUMBER_SYMBOLS_0,
+            Locale::qu_EC => &_NUMBER_SYMBOLS_0,
+            Locale::quc => &_NUMBER_SYMBOLS_0,
+            Locale::raj => &_NUMBER_SYMBOLS_5,
+            Locale::rhg => &_NUMBER_SYMBOLS_0,
+            Locale::rhg_Rohg => &_NUMBER_SYMBOLS_0,
+            Locale::rhg_Rohg_BD => &_NUMBER_SYMBOLS_0,
+            Locale::rif => &_NUMBER_SYMBOLS_0,
+            Locale::rm => &_NUMBER_SYMBOLS_0,
+            Locale::rn => &_NUMBER_SYMBOLS_0,
+            Locale::ro => &_NUMBER_SYMBOLS_0,
+            Locale::ro_MD => &_NUMBER_SYMBOLS_0,
+            Locale::rof => &_NUMBER_SYMBOLS_0,
+            Locale::ru => &_NUMBER_SYMBOLS_0,
+            Locale::ru_BY => &_NUMBER_SYMBOLS_0,
+            Locale::ru_KG => &_NUMBER_SYMBOLS_0,
+            Locale::ru_KZ => &_NUMBER_SYMBOLS_0,
+            Locale::ru_MD => &_NUMBER_SYMBOLS_0,
+            Locale::ru_UA => &_NUMBER_SYMBOLS_0,
+            Locale::rw => &_NUMBER_SYMBOLS_0,
+            Locale::rwk => &_NUMBER_SYMBOLS_0,
+            Locale::sa => &_NUMBER_SYMBOLS_5,
+            Locale::sah => &_NUMBER_SYMBOLS_0,
+            Locale::saq => &_NUMBER_SYMBOLS_0,
+            Locale::sat => &_NUMBER_SYMBOLS_5,
+            Locale::sat_Deva => &_NUMBER_SYMBOLS_5,
+            Locale::sat_Olck => &_NUMBER_SYMBOLS_5,
+            Locale::sbp => &_NUMBER_SYMBOLS_0,
+            Locale::sc => &_NUMBER_SYMBOLS_0,
+            Locale::scn => &_NUMBER_SYMBOLS_0,
+            Locale::sd => &_NUMBER_SYMBOLS_5,
+            Locale::sd_Arab => &_NUMBER_SYMBOLS_5,
+            Locale::sd_Deva => &_NUMBER_SYMBOLS_0,
+            Locale::sdh => &_NUMBER_SYMBOLS_5,
+            Locale::sdh_IQ => &_NUMBER_SYMBOLS_5,
+            Locale::se => &_NUMBER_SYMBOLS_0,
+            Locale::se_FI => &_NUMBER_SYMBOLS_0,
+            Locale::se_SE => &_NUMBER_SYMBOLS_0,
+            Locale::seh => &_NUMBER_SYMBOLS_0,
+            Locale::ses => &_NUMBER_SYMBOLS_0,
+            Locale::sg => &_NUMBER_SYMBOLS_0,
+            Locale::sgs => &_NUMBER_SYMBOLS_0,
+            Locale::shi => &_NUMBER_SYMBOLS_0,
+            Locale::shi_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::shi_Tfng => &_NUMBER_SYMBOLS_0,
+            Locale::shn => &_NUMBER_SYMBOLS_0,
+            Locale::shn_TH => &_NUMBER_SYMBOLS_0,
+            Locale::si => &_NUMBER_SYMBOLS_0,
+            Locale::sid => &_NUMBER_SYMBOLS_0,
+            Locale::sk => &_NUMBER_SYMBOLS_0,
+            Locale::skr => &_NUMBER_SYMBOLS_0,
+            Locale::sl => &_NUMBER_SYMBOLS_0,
+            Locale::sma => &_NUMBER_SYMBOLS_0,
+            Locale::sma_NO => &_NUMBER_SYMBOLS_0,
+            Locale::smj => &_NUMBER_SYMBOLS_0,
+            Locale::smj_NO => &_NUMBER_SYMBOLS_0,
+            Locale::smn => &_NUMBER_SYMBOLS_0,
+            Locale::sms => &_NUMBER_SYMBOLS_0,
+            Locale::sn => &_NUMBER_SYMBOLS_0,
+            Locale::so => &_NUMBER_SYMBOLS_0,
+            Locale::so_DJ => &_NUMBER_SYMBOLS_0,
+            Locale::so_ET => &_NUMBER_SYMBOLS_0,
+            Locale::so_KE => &_NUMBER_SYMBOLS_0,
+            Locale::sq => &_NUMBER_SYMBOLS_0,
+            Locale::sq_MK => &_NUMBER_SYMBOLS_0,
+            Locale::sq_XK => &_NUMBER_SYMBOLS_0,
+            Locale::sr => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Cyrl => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Cyrl_BA => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Cyrl_ME => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Cyrl_XK => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Latn_BA => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Latn_ME => &_NUMBER_SYMBOLS_0,
+            Locale::sr_Latn_XK => &_NUMBER_SYMBOLS_0,
+            Locale::ss => &_NUMBER_SYMBOLS_0,
+            Locale::ss_SZ => &_NUMBER_SYMBOLS_0,
+            Locale::ssy => &_NUMBER_SYMBOLS_0,
+            Locale::st => &_NUMBER_SYMBOLS_0,
+            Locale::st_LS => &_NUMBER_SYMBOLS_0,
+            Locale::su => &_NUMBER_SYMBOLS_0,
+            Locale::su_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::suz => &_NUMBER_SYMBOLS_0,
+            Locale::suz_Deva => &_NUMBER_SYMBOLS_0,
+            Locale::suz_Sunu => &_NUMBER_SYMBOLS_0,
+            Locale::sv => &_NUMBER_SYMBOLS_0,
+            Locale::sv_AX => &_NUMBER_SYMBOLS_0,
+            Locale::sv_FI => &_NUMBER_SYMBOLS_0,
+            Locale::sw => &_NUMBER_SYMBOLS_0,
+            Locale::sw_CD => &_NUMBER_SYMBOLS_0,
+            Locale::sw_KE => &_NUMBER_SYMBOLS_0,
+            Locale::sw_UG => &_NUMBER_SYMBOLS_0,
+            Locale::syr => &_NUMBER_SYMBOLS_0,
+            Locale::syr_SY => &_NUMBER_SYMBOLS_0,
+            Locale::szl => &_NUMBER_SYMBOLS_0,
+            Locale::ta => &_NUMBER_SYMBOLS_26,
+            Locale::ta_LK => &_NUMBER_SYMBOLS_26,
+            Locale::ta_MY => &_NUMBER_SYMBOLS_26,
+            Locale::ta_SG => &_NUMBER_SYMBOLS_26,
+            Locale::te => &_NUMBER_SYMBOLS_27,
+            Locale::teo => &_NUMBER_SYMBOLS_0,
+            Locale::teo_KE => &_NUMBER_SYMBOLS_0,
+            Locale::tg => &_NUMBER_SYMBOLS_0,
+            Locale::th => &_NUMBER_SYMBOLS_28,
+            Locale::ti => &_NUMBER_SYMBOLS_1,
+            Locale::ti_ER => &_NUMBER_SYMBOLS_1,
+            Locale::tig => &_NUMBER_SYMBOLS_0,
+            Locale::tk => &_NUMBER_SYMBOLS_0,
+            Locale::tn => &_NUMBER_SYMBOLS_0,
+            Locale::tn_BW => &_NUMBER_SYMBOLS_0,
+            Locale::to => &_NUMBER_SYMBOLS_0,
+            Locale::tok => &_NUMBER_SYMBOLS_0,
+            Locale::tpi => &_NUMBER_SYMBOLS_0,
+            Locale::tr => &_NUMBER_SYMBOLS_0,
+            Locale::tr_CY => &_NUMBER_SYMBOLS_0,
+            Locale::trv => &_NUMBER_SYMBOLS_0,
+            Locale::trw => &_NUMBER_SYMBOLS_0,
+            Locale::ts => &_NUMBER_SYMBOLS_0,
+            Locale::tt => &_NUMBER_SYMBOLS_0,
+            Locale::twq => &_NUMBER_SYMBOLS_0,
+            Locale::tyv => &_NUMBER_SYMBOLS_0,
+            Locale::tzm => &_NUMBER_SYMBOLS_0,
+            Locale::ug => &_NUMBER_SYMBOLS_29,
+            Locale::uk => &_NUMBER_SYMBOLS_0,
+            Locale::und => &_NUMBER_SYMBOLS_0,
+            Locale::ur => &_NUMBER_SYMBOLS_29,
+            Locale::ur_IN => &_NUMBER_SYMBOLS_3,
+            Locale::uz => &_NUMBER_SYMBOLS_0,
+            Locale::uz_Arab => &_NUMBER_SYMBOLS_25,
+            Locale::uz_Cyrl => &_NUMBER_SYMBOLS_0,
+            Locale::uz_Latn => &_NUMBER_SYMBOLS_0,
+            Locale::vai => &_NUMBER_SYMBOLS_30,
+            Locale::vai_Latn => &_NUMBER_SYMBOLS_30,
+            Locale::vai_Vaii => &_NUMBER_SYMBOLS_30,
+            Locale::ve => &_NUMBER_SYMBOLS_0,
+            Locale::vec => &_NUMBER_SYMBOLS_0,
+            Locale::vi => &_NUMBER_SYMBOLS_0,
+            Locale::vmw => &_NUMBER_SYMBOLS_0,
+            Locale::vo => &_NUMBER_SYMBOLS_0,
+            Locale::vun => &_NUMBER_SYMBOLS_0,
+            Locale::wa => &_NUMBER_SYMBOLS_0,
+            Locale::wae => &_NUMBER_SYMBOLS_0,
+            Locale::wal => &_NUMBER_SYMBOLS_0,
+            Locale::wbp => &_NUMBER_SYMBOLS_0,
+            Locale::wo => &_NUMBER_SYMBOLS_0,
+            Locale::xh => &_NUMBER_SYMBOLS_0,
+            Locale::xnr => &_NUMBER_SYMBOLS_0,
+            Locale::xog => &_NUMBER_SYMBOLS_0,
+            Locale::yav => &_NUMBER_SYMBOLS_0,
+            Locale::yi => &_NUMBER_SYMBOLS_31,
+            Locale::yo => &_NUMBER_SYMBOLS_0,
+            Locale::yo_BJ => &_NUMBER_SYMBOLS_0,
+            Locale::yrl => &_NUMBER_SYMBOLS_0,
+            Locale::yrl_CO => &_NUMBER_SYMBOLS_0,
+            Locale::yrl_VE => &_NUMBER_SYMBOLS_0,
+            Locale::yue => &_NUMBER_SYMBOLS_32,
+            Locale::yue_Hans => &_NUMBER_SYMBOLS_33,
+            Locale::yue_Hant => &_NUMBER_SYMBOLS_32,
+            Locale::yue_Hant_CN => &_NUMBER_SYMBOLS_32,
+            Locale::yue_Hant_MO => &_NUMBER_SYMBOLS_32,
+            Locale::za => &_NUMBER_SYMBOLS_0,
+            Locale::zgh => &_NUMBER_SYMBOLS_0,
+            Locale::zh => &_NUMBER_SYMBOLS_33,
+            Locale::zh_Hans => &_NUMBER_SYMBOLS_33,
+            Locale::zh_Hans_HK => &_NUMBER_SYMBOLS_33,
+            Locale::zh_Hans_MO => &_NUMBER_SYMBOLS_33,
+            Locale::zh_Hans_MY => &_NUMBER_SYMBOLS_33,
+            Locale::zh_Hans_SG => &_NUMBER_SYMBOLS_33,
+            Locale::zh_Hant => &_NUMBER_SYMBOLS_32,
+            Locale::zh_Hant_HK => &_NUMBER_SYMBOLS_32,
+            Locale::zh_Hant_MO => &_NUMBER_SYMBOLS_32,
+            Locale::zh_Hant_MY => &_NUMBER_SYMBOLS_32,
+            Locale::zh_Latn => &_NUMBER_SYMBOLS_33,
+            Locale::zu => &_NUMBER_SYMBOLS_0,
+        }
+    }
+
+    /// Returns the separators and minus sign the locale uses with a numbering
+    /// system, falling back to its `latn` symbols for systems CLDR has none for.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::NumberingSystem;
+    ///
+    /// assert_eq!(Locale::ar.number_symbols(NumberingSystem::Arab).decimal, "٫");
+    /// assert_eq!(Locale::ar.number_symbols(NumberingSystem::Latn).decimal, ".");
+    /// ```
+    pub fn number_symbols(&self, system: NumberingSystem) -> NumberSymbols {
+        if system != self.default_numbering_system() {
+            let table = self._number_symbols();
+            let found = table
+                .iter()
+                .find(|(s, _)| *s == system)
+                .or_else(|| table.iter().find(|(s, _)| *s == NumberingSystem::Latn));
+            if let Some((_, symbols)) = found {
+                return *symbols;
+            }
+        }
+        NumberSymbols {
+            decimal: self.decimal_separator(),
+            group: self.grouping_separator(),
+            minus_sign: self.minus_sign(),
+        }
+    }
+}
+
+impl Locale {
+    /// Formats an integer in a numbering system. Numeric systems use the
+    /// locale's symbols for the system with its digits; algorithmic ones apply
+    /// their CLDR rules, e.g. Roman numerals or Hebrew letters.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::NumberingSystem;
+    ///
+    /// let english = Locale::en;
+    /// assert_eq!(english.format_in_numbering_system(1984, NumberingSystem::Roman), "MCMLXXXIV");
+    /// assert_eq!(english.format_in_numbering_system(1234, NumberingSystem::Deva), "१,२३४");
+    /// assert_eq!(english.format_in_numbering_system(5784, "hebr".parse().unwrap()), "ה׳תשפ״ד");
+    /// ```
+    pub fn format_in_numbering_system(&self, n: i64, system: NumberingSystem) -> String {
+        if let Some((locale, rule_set)) = system._rules() {
+            let locale = locale.unwrap_or(*self);
+            if let Some(result) = locale.spell_out(n, RbnfRule::Named(rule_set)) {
+                return result;
+            }
+        }
+        let mut buf = [0u8; 128];
+        let pos = format_int_to_buf(&mut buf, n.unsigned_abs() as u128);
+        let abs_str = unsafe { std::str::from_utf8_unchecked(&buf[pos..]) };
+        _format_with_system(abs_str, None, n < 0, self, system, GroupingStrategy::Auto)
+    }
+}
+
 pub trait ToFormattedString {
-    fn to_formatted_string(&self, locale: &Locale) -> String;
+    fn to_formatted_string(&self, locale: &Locale) -> String {
+        self.to_formatted_string_in(locale, locale.default_numbering_system())
+    }
+
+    /// Formats the number with the locale's symbols for a numbering system.
+    /// Integers in an algorithmic system are written by its rules; fractions
+    /// fall back to the locale's default system.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::{NumberingSystem, ToFormattedString};
+    ///
+    /// assert_eq!((-1234.5).to_formatted_string_in(&Locale::ar, NumberingSystem::Arab), "؜-١٬٢٣٤٫٥");
+    /// assert_eq!(1234.to_formatted_string_in(&Locale::ar, NumberingSystem::Latn), "1,234");
+    /// assert_eq!(12.to_formatted_string_in(&Locale::en, NumberingSystem::Roman), "XII");
+    /// ```
+    fn to_formatted_string_in(&self, locale: &Locale, system: NumberingSystem) -> String {
+        self.to_formatted_string_with(locale, system, GroupingStrategy::Auto)
+    }
+
+    /// Formats the number with grouping forced on or off, or left to the
+    /// locale's minimum grouping digits.
+    ///
+    /// # Examples
+    /// ```
+    /// use locale_rs::Locale;
+    /// use locale_rs::num_formats::{GroupingStrategy, ToFormattedString};
+    ///
+    /// assert_eq!(1234.to_formatted_string(&Locale::es), "1234");
+    /// assert_eq!(1234.to_formatted_string_with_grouping(&Locale::es, GroupingStrategy::Always), "1.234");
+    /// assert_eq!(12345.to_formatted_string_with_grouping(&Locale::en, GroupingStrategy::Never), "12345");
+    /// ```
+    fn to_formatted_string_with_grouping(
+        &self,
+        locale: &Locale,
+        grouping: GroupingStrategy,
+    ) -> String {
+        self.to_formatted_string_with(locale, locale.default_numbering_system(), grouping)
+    }
+
+    /// Formats the number in a numbering system with a grouping strategy.
+    fn to_formatted_string_with(
+        &self,
+        locale: &Locale,
+        system: NumberingSystem,
+        grouping: GroupingStrategy,
+    ) -> String;
 }
 
 /// Translates ASCII digits 0-9 into the locale's native numbering system.
 pub fn translate_digits(input: String, locale: &Locale) -> String {
     match locale.digits() {
-        Some(d) => {
-            let bytes = input.as_bytes();
-            let mut result = String::with_capacity(input.len() * 2); // May grow due to multi-byte digits
-            let mut i = 0;
-
-            while i < bytes.len() {
-                let b = bytes[i];
-
-                if b >= b'0' && b <= b'9' {
-                    // ASCII digit - replace with locale digit
-                    let idx = (b - b'0') as usize;
-                    result.push(d[idx]);
-                    i += 1;
-                } else if b < 128 {
-                    // ASCII character (not digit) - keep as-is
-                    result.push(b as char);
-                    i += 1;
-                } else {
-                    // Multi-byte UTF-8 sequence - copy as-is
-                    let start = i;
-                    loop {
-                        i += 1;
-                        if i >= bytes.len() || bytes[i] & 0b11000000 != 0b10000000 {
-                            break;
-                        }
-                    }
-                    let s = unsafe { std::str::from_utf8_unchecked(&bytes[start..i]) };
-                    result.push_str(s);
+        Some(d) => _replace_digits(input, &d),
+        None => input,
+    }
+}
+
+/// Replaces ASCII digits 0-9 with the given digits.
+pub(crate) fn _replace_digits(input: String, d: &[char; 10]) -> String {
+    let bytes = input.as_bytes();
+    let mut result = String::with_capacity(input.len() * 2); // May grow due to multi-byte digits
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+
+        if b >= b'0' && b <= b'9' {
+            // ASCII digit - replace with locale digit
+            let idx = (b - b'0') as usize;
+            result.push(d[idx]);
+            i += 1;
+        } else if b < 128 {
+            // ASCII character (not digit) - keep as-is
+            result.push(b as char);
+            i += 1;
+        } else {
+            // Multi-byte UTF-8 sequence - copy as-is
+            let start = i;
+            loop {
+                i += 1;
+                if i >= bytes.len() || bytes[i] & 0b11000000 != 0b10000000 {
+                    break;
                 }
             }
-            result
+            let s = unsafe { std::str::from_utf8_unchecked(&bytes[start..i]) };
+            result.push_str(s);
         }
-        None => input,
     }
+    result
 }
 
-/// Formats the integer portion of a number with grouping separators.
-/// Uses stack-allocated buffer to minimize heap allocations.
-fn _format_int_str(numeric_part: &str, locale: &Locale) -> String {
-    let sizes = locale.grouping_sizes();
-    let separator = locale.grouping_separator();
+/// Formats the ASCII digits of a number with the locale's symbols and the
+/// digits of a numeric system.
+fn _format_with_system(
+    int_part: &str,
+    frac_part: Option<&str>,
+    is_neg: bool,
+    locale: &Locale,
+    system: NumberingSystem,
+    grouping: GroupingStrategy,
+) -> String {
+    let symbols = locale.number_symbols(system);
+    let mut result = String::with_capacity(int_part.len() * 2);
+    if is_neg {
+        result.push_str(symbols.minus_sign);
+    }
+    let min_grouping = match grouping {
+        GroupingStrategy::Auto => locale.minimum_grouping_digits(),
+        GroupingStrategy::Always => 1,
+        GroupingStrategy::Never => usize::MAX,
+    };
+    result.push_str(&_format_int_str(
+        int_part,
+        locale.grouping_sizes(),
+        symbols.group,
+        min_grouping,
+    ));
+    if let Some(frac_part) = frac_part {
+        result.push_str(symbols.decimal);
+        result.push_str(frac_part);
+    }
+    match system.digits() {
+        Some(digits) => _replace_digits(result, &digits),
+        None => result,
+    }
+}
 
-    if sizes.is_empty() || sizes[0] == 0 || numeric_part.len() <= sizes[0] {
+/// Formats the integer portion of a number with grouping separators, once it
+/// is at least `min_grouping` digits longer than the primary group.
+/// Uses stack-allocated buffer to minimize heap allocations.
+fn _format_int_str(
+    numeric_part: &str,
+    sizes: &[usize],
+    separator: &str,
+    min_grouping: usize,
+) -> String {
+    if sizes.is_empty()
+        || sizes[0] == 0
+        || numeric_part.len() < sizes[0].saturating_add(min_grouping)
+    {
         return numeric_part.to_string();
     }
 
@@ -3944,12 +8045,17 @@ macro_rules! impl_int {
     ($($t:ty),*) => {
         $(
             impl ToFormattedString for $t {
-                fn to_formatted_string(&self, locale: &Locale) -> String {
+                fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String {
+                    if system.is_algorithmic()
+                        && let Ok(n) = i64::try_from(*self)
+                    {
+                        return locale.format_in_numbering_system(n, system);
+                    }
                     // Use itoa-like approach: write ASCII directly to stack buffer
                     let mut buf = [0u8; 128];
                     let (is_neg, pos) = if *self < 0 {
                         // Signed type - handle negation
-                        let n = (-(*self as i128)) as u128;
+                        let n = (*self as i128).unsigned_abs();
                         (true, format_int_to_buf(&mut buf, n))
                     } else {
                         // Unsigned type - cast directly
@@ -3957,15 +8063,7 @@ macro_rules! impl_int {
                     };
 
                     let abs_str = unsafe { std::str::from_utf8_unchecked(&buf[pos..]) };
-                    let formatted = _format_int_str(abs_str, locale);
-
-                    let res = if is_neg {
-                        format!("{}{}", locale.minus_sign(), formatted)
-                    } else {
-                        formatted
-                    };
-
-                    translate_digits(res, locale)
+                    _format_with_system(abs_str, None, is_neg, locale, system, grouping)
                 }
             }
         )*
@@ -3976,14 +8074,18 @@ macro_rules! impl_uint {
     ($($t:ty),*) => {
         $(
             impl ToFormattedString for $t {
-                fn to_formatted_string(&self, locale: &Locale) -> String {
+                fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String {
+                    if system.is_algorithmic()
+                        && let Ok(n) = i64::try_from(*self)
+                    {
+                        return locale.format_in_numbering_system(n, system);
+                    }
                     // Unsigned type - no need to handle negation
                     let mut buf = [0u8; 128];
                     let pos = format_int_to_buf(&mut buf, *self as u128);
 
                     let abs_str = unsafe { std::str::from_utf8_unchecked(&buf[pos..]) };
-                    let formatted = _format_int_str(abs_str, locale);
-                    translate_digits(formatted, locale)
+                    _format_with_system(abs_str, None, false, locale, system, grouping)
                 }
             }
         )*
@@ -4014,32 +8116,20 @@ macro_rules! impl_float {
     ($($t:ty),*) => {
         $(
             impl ToFormattedString for $t {
-                fn to_formatted_string(&self, locale: &Locale) -> String {
+                fn to_formatted_string_with(&self, locale: &Locale, system: NumberingSystem, grouping: GroupingStrategy) -> String {
+                    let system = if system.is_algorithmic() { locale.default_numbering_system() } else { system };
                     if self.is_nan() { return "NaN".to_string(); }
                     if self.is_infinite() {
-                        return if self.is_sign_positive() { "inf".to_string() } else { format!("{}inf", locale.minus_sign()) };
+                        return if self.is_sign_positive() { "inf".to_string() } else { format!("{}inf", locale.number_symbols(system).minus_sign) };
                     }
 
                     let s = format!("{}", self);
                     let (is_neg, s_abs) = if s.starts_with('-') { (true, &s[1..]) } else { (false, &s[..]) };
 
-                    // Single allocation: format the result directly
-                    let res = if let Some(dot_pos) = s_abs.find('.') {
-                        let (int_part, frac_part) = s_abs.split_at(dot_pos);
-                        let formatted_int = _format_int_str(int_part, locale);
-                        // Combine without intermediate allocations
-                        format!("{}{}{}", formatted_int, locale.decimal_separator(), &frac_part[1..])
-                    } else {
-                        _format_int_str(s_abs, locale)
-                    };
-
-                    // Apply minus sign and digit translation if needed
-                    let final_str = if is_neg {
-                        format!("{}{}", locale.minus_sign(), res)
-                    } else {
-                        res
-                    };
-                    translate_digits(final_str, locale)
+                    match s_abs.split_once('.') {
+                        Some((int_part, frac_part)) => _format_with_system(int_part, Some(frac_part), is_neg, locale, system, grouping),
+                        None => _format_with_system(s_abs, None, is_neg, locale, system, grouping),
+                    }
                 }
             }
         )*
@@ -4047,3 +8137,35 @@ macro_rules! impl_float {
 }
 
 impl_float!(f32, f64);
+
+/// Formats an integer as an ordinal in digits, using the locale's CLDR
+/// `%digits-ordinal` rules.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::num_formats::ToOrdinalString;
+///
+/// assert_eq!(22.to_ordinal_string(&Locale::en), "22nd");
+/// assert_eq!(1.to_ordinal_string(&Locale::fr), "1er");
+/// assert_eq!(3.to_ordinal_string(&Locale::de), "3.");
+/// ```
+pub trait ToOrdinalString {
+    fn to_ordinal_string(&self, locale: &Locale) -> String;
+}
+
+macro_rules! impl_ordinal {
+    ($($t:ty),*) => {
+        $(
+            impl ToOrdinalString for $t {
+                fn to_ordinal_string(&self, locale: &Locale) -> String {
+                    locale
+                        ._format_rbnf(*self as i128, _RuleGroup::Ordinal, "%digits-ordinal")
+                        .unwrap_or_else(|| self.to_formatted_string(locale))
+                }
+            }
+        )*
+    };
+}
+
+impl_ordinal!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
@@ -0,0 +1,3535 @@
+// Auto-generated. DO NOT EDIT.
+// The rule functions mirror the CLDR rule text rather than a minimal form.
+#![allow(clippy::nonminimal_bool)]
+use std::str::FromStr;
+
+use crate::error::LocaleError;
+use crate::locale::Locale;
+
+/// CLDR plural category of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// Plural operands of a number as defined by UTS #35: the absolute value `n`,
+/// its integer digits `i`, the number of visible fraction digits `v` and
+/// without trailing zeros `w`, the visible fraction digits `f` and without
+/// trailing zeros `t`, and the compact decimal exponent `e`.
+///
+/// Visible fraction digits matter, so operands are best taken from the
+/// number as it is displayed, e.g. `"1.50".parse()` rather than `1.5`.
+///
+/// # Examples
+/// ```
+/// use locale_rs::Locale;
+/// use locale_rs::plurals::{PluralCategory, PluralOperands};
+///
+/// assert_eq!(Locale::en.plural_category(&1.into()), PluralCategory::One);
+/// assert_eq!(Locale::en.plural_category(&"1.0".parse()?), PluralCategory::Other);
+/// assert_eq!(Locale::ru.plural_category(&21.into()), PluralCategory::One);
+/// assert_eq!(Locale::en.ordinal_category(&23.into()), PluralCategory::Few);
+/// # Ok::<(), locale_rs::error::LocaleError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralOperands {
+    n: f64,
+    i: u64,
+    v: u32,
+    w: u32,
+    f: u64,
+    t: u64,
+    e: u32,
+}
+
+impl PluralOperands {
+    pub fn from_integer(value: i64) -> Self {
+        PluralOperands {
+            n: value.unsigned_abs() as f64,
+            i: value.unsigned_abs(),
+            v: 0,
+            w: 0,
+            f: 0,
+            t: 0,
+            e: 0,
+        }
+    }
+
+    /// Takes the operands of the shortest decimal that reads back as `value`,
+    /// so `1.5` has one fraction digit and `2.0` none.
+    pub fn from_f64(value: f64) -> Self {
+        if !value.is_finite() {
+            return PluralOperands {
+                n: value.abs(),
+                ..PluralOperands::from_integer(0)
+            };
+        }
+        // Display never uses an exponent and always reads back.
+        format!("{}", value)
+            .parse()
+            .unwrap_or_else(|_| PluralOperands::from_integer(0))
+    }
+}
+
+impl From<i64> for PluralOperands {
+    fn from(value: i64) -> Self {
+        PluralOperands::from_integer(value)
+    }
+}
+
+impl From<i32> for PluralOperands {
+    fn from(value: i32) -> Self {
+        PluralOperands::from_integer(value as i64)
+    }
+}
+
+impl From<u32> for PluralOperands {
+    fn from(value: u32) -> Self {
+        PluralOperands::from_integer(value as i64)
+    }
+}
+
+impl From<u64> for PluralOperands {
+    fn from(value: u64) -> Self {
+        PluralOperands {
+            n: value as f64,
+            i: value,
+            ..PluralOperands::from_integer(0)
+        }
+    }
+}
+
+impl From<usize> for PluralOperands {
+    fn from(value: usize) -> Self {
+        PluralOperands::from(value as u64)
+    }
+}
+
+impl From<f64> for PluralOperands {
+    fn from(value: f64) -> Self {
+        PluralOperands::from_f64(value)
+    }
+}
+
+/// Parses a formatted decimal such as `-1.50`, keeping its visible fraction
+/// digits, or a compact one such as `1.2c3` (1200 with exponent 3).
+impl FromStr for PluralOperands {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || LocaleError::InvalidNumber(s.to_string());
+        let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+        let (mantissa, exponent) = match unsigned.split_once(['c', 'e']) {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<u32>().map_err(|_| error())?),
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) || exponent > 18 {
+            return Err(error());
+        }
+        // The exponent moves fraction digits into the integer part.
+        let shift = (exponent as usize).min(fraction.len());
+        let integer = format!(
+            "{}{}{}",
+            integer,
+            &fraction[..shift],
+            "0".repeat(exponent as usize - shift)
+        );
+        let fraction = &fraction[shift..];
+        let n: f64 = format!("{}.{}0", integer, fraction)
+            .parse()
+            .map_err(|_| error())?;
+        let trimmed = fraction.trim_end_matches('0');
+        Ok(PluralOperands {
+            n,
+            i: _saturating_digits(&integer),
+            v: fraction.len() as u32,
+            w: trimmed.len() as u32,
+            f: _saturating_digits(fraction),
+            t: _saturating_digits(trimmed),
+            e: exponent,
+        })
+    }
+}
+
+/// Reads a run of decimal digits. Values above 10^18 keep their last 18
+/// digits plus 10^18, which stays large and leaves every `% 10^k` unchanged.
+fn _saturating_digits(digits: &str) -> u64 {
+    const LIMIT: u64 = 1_000_000_000_000_000_000;
+    let digits = digits.trim_start_matches('0');
+    match digits.parse::<u64>() {
+        Ok(value) => value,
+        Err(_) if digits.is_empty() => 0,
+        Err(_) => {
+            LIMIT
+                + digits[digits.len().saturating_sub(18)..]
+                    .parse::<u64>()
+                    .unwrap_or(0)
+        }
+    }
+}
+
+impl Locale {
+    /// Returns the cardinal plural category of a number, e.g. `One` for
+    /// "1 day" and `Other` for "2 days" in English.
+    pub fn plural_category(&self, operands: &PluralOperands) -> PluralCategory {
+        match self {
+            Locale::aa => _cardinal_0(operands),
+            Locale::aa_DJ => _cardinal_0(operands),
+            Locale::aa_ER => _cardinal_0(operands),
+            Locale::ab => _cardinal_0(operands),
+            Locale::af => _cardinal_1(operands),
+            Locale::af_NA => _cardinal_1(operands),
+            Locale::agq => _cardinal_0(operands),
+            Locale::ak => _cardinal_2(operands),
+            Locale::am => _cardinal_3(operands),
+            Locale::an => _cardinal_1(operands),
+            Locale::ann => _cardinal_0(operands),
+            Locale::apc => _cardinal_0(operands),
+            Locale::ar => _cardinal_4(operands),
+            Locale::ar_AE => _cardinal_4(operands),
+            Locale::ar_BH => _cardinal_4(operands),
+            Locale::ar_DJ => _cardinal_4(operands),
+            Locale::ar_DZ => _cardinal_4(operands),
+            Locale::ar_EG => _cardinal_4(operands),
+            Locale::ar_EH => _cardinal_4(operands),
+            Locale::ar_ER => _cardinal_4(operands),
+            Locale::ar_IL => _cardinal_4(operands),
+            Locale::ar_IQ => _cardinal_4(operands),
+            Locale::ar_JO => _cardinal_4(operands),
+            Locale::ar_KM => _cardinal_4(operands),
+            Locale::ar_KW => _cardinal_4(operands),
+            Locale::ar_LB => _cardinal_4(operands),
+            Locale::ar_LY => _cardinal_4(operands),
+            Locale::ar_MA => _cardinal_4(operands),
+            Locale::ar_MR => _cardinal_4(operands),
+            Locale::ar_OM => _cardinal_4(operands),
+            Locale::ar_PS => _cardinal_4(operands),
+            Locale::ar_QA => _cardinal_4(operands),
+            Locale::ar_SA => _cardinal_4(operands),
+            Locale::ar_SD => _cardinal_4(operands),
+            Locale::ar_SO => _cardinal_4(operands),
+            Locale::ar_SS => _cardinal_4(operands),
+            Locale::ar_SY => _cardinal_4(operands),
+            Locale::ar_TD => _cardinal_4(operands),
+            Locale::ar_TN => _cardinal_4(operands),
+            Locale::ar_YE => _cardinal_4(operands),
+            Locale::arn => _cardinal_0(operands),
+            Locale::as_ => _cardinal_3(operands),
+            Locale::asa => _cardinal_1(operands),
+            Locale::ast => _cardinal_5(operands),
+            Locale::az => _cardinal_1(operands),
+            Locale::az_Arab => _cardinal_0(operands),
+            Locale::az_Arab_IQ => _cardinal_0(operands),
+            Locale::az_Arab_TR => _cardinal_0(operands),
+            Locale::az_Cyrl => _cardinal_0(operands),
+            Locale::az_Latn => _cardinal_1(operands),
+            Locale::ba => _cardinal_0(operands),
+            Locale::bal => _cardinal_1(operands),
+            Locale::bal_Arab => _cardinal_1(operands),
+            Locale::bal_Latn => _cardinal_1(operands),
+            Locale::bas => _cardinal_0(operands),
+            Locale::be => _cardinal_6(operands),
+            Locale::be_tarask => _cardinal_6(operands),
+            Locale::bem => _cardinal_1(operands),
+            Locale::bew => _cardinal_0(operands),
+            Locale::bez => _cardinal_1(operands),
+            Locale::bg => _cardinal_1(operands),
+            Locale::bgc => _cardinal_0(operands),
+            Locale::bgn => _cardinal_0(operands),
+            Locale::bgn_AE => _cardinal_0(operands),
+            Locale::bgn_AF => _cardinal_0(operands),
+            Locale::bgn_IR => _cardinal_0(operands),
+            Locale::bgn_OM => _cardinal_0(operands),
+            Locale::bho => _cardinal_2(operands),
+            Locale::blo => _cardinal_7(operands),
+            Locale::blt => _cardinal_0(operands),
+            Locale::bm => _cardinal_0(operands),
+            Locale::bm_Nkoo => _cardinal_0(operands),
+            Locale::bn => _cardinal_3(operands),
+            Locale::bn_IN => _cardinal_3(operands),
+            Locale::bo => _cardinal_0(operands),
+            Locale::bo_IN => _cardinal_0(operands),
+            Locale::bqi => _cardinal_0(operands),
+            Locale::br => _cardinal_8(operands),
+            Locale::brx => _cardinal_1(operands),
+            Locale::bs => _cardinal_9(operands),
+            Locale::bs_Cyrl => _cardinal_0(operands),
+            Locale::bs_Latn => _cardinal_9(operands),
+            Locale::bss => _cardinal_0(operands),
+            Locale::bua => _cardinal_0(operands),
+            Locale::byn => _cardinal_0(operands),
+            Locale::ca => _cardinal_10(operands),
+            Locale::ca_AD => _cardinal_10(operands),
+            Locale::ca_ES_valencia => _cardinal_10(operands),
+            Locale::ca_FR => _cardinal_10(operands),
+            Locale::ca_IT => _cardinal_10(operands),
+            Locale::cad => _cardinal_0(operands),
+            Locale::cch => _cardinal_0(operands),
+            Locale::ccp => _cardinal_0(operands),
+            Locale::ccp_IN => _cardinal_0(operands),
+            Locale::ce => _cardinal_1(operands),
+            Locale::ceb => _cardinal_11(operands),
+            Locale::cgg => _cardinal_1(operands),
+            Locale::cho => _cardinal_0(operands),
+            Locale::chr => _cardinal_1(operands),
+            Locale::cic => _cardinal_0(operands),
+            Locale::ckb => _cardinal_1(operands),
+            Locale::ckb_IR => _cardinal_1(operands),
+            Locale::co => _cardinal_0(operands),
+            Locale::cop => _cardinal_0(operands),
+            Locale::cs => _cardinal_12(operands),
+            Locale::csw => _cardinal_2(operands),
+            Locale::cu => _cardinal_0(operands),
+            Locale::cv => _cardinal_0(operands),
+            Locale::cy => _cardinal_13(operands),
+            Locale::da => _cardinal_14(operands),
+            Locale::da_GL => _cardinal_14(operands),
+            Locale::dav => _cardinal_0(operands),
+            Locale::de => _cardinal_5(operands),
+            Locale::de_AT => _cardinal_5(operands),
+            Locale::de_BE => _cardinal_5(operands),
+            Locale::de_CH => _cardinal_5(operands),
+            Locale::de_IT => _cardinal_5(operands),
+            Locale::de_LI => _cardinal_5(operands),
+            Locale::de_LU => _cardinal_5(operands),
+            Locale::dje => _cardinal_0(operands),
+            Locale::doi => _cardinal_3(operands),
+            Locale::dsb => _cardinal_15(operands),
+            Locale::dua => _cardinal_0(operands),
+            Locale::dv => _cardinal_1(operands),
+            Locale::dyo => _cardinal_0(operands),
+            Locale::dz => _cardinal_0(operands),
+            Locale::ebu => _cardinal_0(operands),
+            Locale::ee => _cardinal_1(operands),
+            Locale::ee_TG => _cardinal_1(operands),
+            Locale::el => _cardinal_1(operands),
+            Locale::el_CY => _cardinal_1(operands),
+            Locale::el_polyton => _cardinal_1(operands),
+            Locale::en => _cardinal_5(operands),
+            Locale::en_001 => _cardinal_5(operands),
+            Locale::en_150 => _cardinal_5(operands),
+            Locale::en_AE => _cardinal_5(operands),
+            Locale::en_AG => _cardinal_5(operands),
+            Locale::en_AI => _cardinal_5(operands),
+            Locale::en_AS => _cardinal_5(operands),
+            Locale::en_AT => _cardinal_5(operands),
+            Locale::en_AU => _cardinal_5(operands),
+            Locale::en_BB => _cardinal_5(operands),
+            Locale::en_BE => _cardinal_5(operands),
+            Locale::en_BI => _cardinal_5(operands),
+            Locale::en_BM => _cardinal_5(operands),
+            Locale::en_BS => _cardinal_5(operands),
+            Locale::en_BW => _cardinal_5(operands),
+            Locale::en_BZ => _cardinal_5(operands),
+            Locale::en_CA => _cardinal_5(operands),
+            Locale::en_CC => _cardinal_5(operands),
+            Locale::en_CH => _cardinal_5(operands),
+            Locale::en_CK => _cardinal_5(operands),
+            Locale::en_CM => _cardinal_5(operands),
+            Locale::en_CX => _cardinal_5(operands),
+            Locale::en_CY => _cardinal_5(operands),
+            Locale::en_CZ => _cardinal_5(operands),
+            Locale::en_DE => _cardinal_5(operands),
+            Locale::en_DG => _cardinal_5(operands),
+            Locale::en_DK => _cardinal_5(operands),
+            Locale::en_DM => _cardinal_5(operands),
+            Locale::en_Dsrt => _cardinal_0(operands),
+            Locale::en_EE => _cardinal_5(operands),
+            Locale::en_ER => _cardinal_5(operands),
+            Locale::en_ES => _cardinal_5(operands),
+            Locale::en_FI => _cardinal_5(operands),
+            Locale::en_FJ => _cardinal_5(operands),
+            Locale::en_FK => _cardinal_5(operands),
+            Locale::en_FM => _cardinal_5(operands),
+            Locale::en_FR => _cardinal_5(operands),
+            Locale::en_GB => _cardinal_5(operands),
+            Locale::en_GD => _cardinal_5(operands),
+            Locale::en_GE => _cardinal_5(operands),
+            Locale::en_GG => _cardinal_5(operands),
+            Locale::en_GH => _cardinal_5(operands),
+            Locale::en_GI => _cardinal_5(operands),
+            Locale::en_GM => _cardinal_5(operands),
+            Locale::en_GS => _cardinal_5(operands),
+            Locale::en_GU => _cardinal_5(operands),
+            Locale::en_GY => _cardinal_5(operands),
+            Locale::en_HK => _cardinal_5(operands),
+            Locale::en_HU => _cardinal_5(operands),
+            Locale::en_ID => _cardinal_5(operands),
+            Locale::en_IE => _cardinal_5(operands),
+            Locale::en_IL => _cardinal_5(operands),
+            Locale::en_IM => _cardinal_5(operands),
+            Locale::en_IN => _cardinal_5(operands),
+            Locale::en_IO => _cardinal_5(operands),
+            Locale::en_IT => _cardinal_5(operands),
+            Locale::en_JE => _cardinal_5(operands),
+            Locale::en_JM => _cardinal_5(operands),
+            Locale::en_JP => _cardinal_5(operands),
+            Locale::en_KE => _cardinal_5(operands),
+            Locale::en_KI => _cardinal_5(operands),
+            Locale::en_KN => _cardinal_5(operands),
+            Locale::en_KY => _cardinal_5(operands),
+            Locale::en_LC => _cardinal_5(operands),
+            Locale::en_LR => _cardinal_5(operands),
+            Locale::en_LS => _cardinal_5(operands),
+            Locale::en_LT => _cardinal_5(operands),
+            Locale::en_LV => _cardinal_5(operands),
+            Locale::en_MG => _cardinal_5(operands),
+            Locale::en_MH => _cardinal_5(operands),
+            Locale::en_MO => _cardinal_5(operands),
+            Locale::en_MP => _cardinal_5(operands),
+            Locale::en_MS => _cardinal_5(operands),
+            Locale::en_MT => _cardinal_5(operands),
+            Locale::en_MU => _cardinal_5(operands),
+            Locale::en_MV => _cardinal_5(operands),
+            Locale::en_MW => _cardinal_5(operands),
+            Locale::en_MY => _cardinal_5(operands),
+            Locale::en_NA => _cardinal_5(operands),
+            Locale::en_NF => _cardinal_5(operands),
+            Locale::en_NG => _cardinal_5(operands),
+            Locale::en_NL => _cardinal_5(operands),
+            Locale::en_NO => _cardinal_5(operands),
+            Locale::en_NR => _cardinal_5(operands),
+            Locale::en_NU => _cardinal_5(operands),
+            Locale::en_NZ => _cardinal_5(operands),
+            Locale::en_PG => _cardinal_5(operands),
+            Locale::en_PH => _cardinal_5(operands),
+            Locale::en_PK => _cardinal_5(operands),
+            Locale::en_PL => _cardinal_5(operands),
+            Locale::en_PN => _cardinal_5(operands),
+            Locale::en_PR => _cardinal_5(operands),
+            Locale::en_PT => _cardinal_5(operands),
+            Locale::en_PW => _cardinal_5(operands),
+            Locale::en_RO => _cardinal_5(operands),
+            Locale::en_RW => _cardinal_5(operands),
+            Locale::en_SB => _cardinal_5(operands),
+            Locale::en_SC => _cardinal_5(operands),
+            Locale::en_SD => _cardinal_5(operands),
+            Locale::en_SE => _cardinal_5(operands),
+            Locale::en_SG => _cardinal_5(operands),
+            Locale::en_SH => _cardinal_5(operands),
+            Locale::en_SI => _cardinal_5(operands),
+            Locale::en_SK => _cardinal_5(operands),
+            Locale::en_SL => _cardinal_5(operands),
+            Locale::en_SS => _cardinal_5(operands),
+            Locale::en_SX => _cardinal_5(operands),
+            Locale::en_SZ => _cardinal_5(operands),
+            Locale::en_Shaw => _cardinal_0(operands),
+            Locale::en_TC => _cardinal_5(operands),
+            Locale::en_TK => _cardinal_5(operands),
+            Locale::en_TO => _cardinal_5(operands),
+            Locale::en_TT => _cardinal_5(operands),
+            Locale::en_TV => _cardinal_5(operands),
+            Locale::en_TZ => _cardinal_5(operands),
+            Locale::en_UA => _cardinal_5(operands),
+            Locale::en_UG => _cardinal_5(operands),
+            Locale::en_UM => _cardinal_5(operands),
+            Locale::en_VC => _cardinal_5(operands),
+            Locale::en_VG => _cardinal_5(operands),
+            Locale::en_VI => _cardinal_5(operands),
+            Locale::en_VU => _cardinal_5(operands),
+            Locale::en_WS => _cardinal_5(operands),
+            Locale::en_ZA => _cardinal_5(operands),
+            Locale::en_ZM => _cardinal_5(operands),
+            Locale::en_ZW => _cardinal_5(operands),
+            Locale::eo => _cardinal_1(operands),
+            Locale::es => _cardinal_16(operands),
+            Locale::es_419 => _cardinal_16(operands),
+            Locale::es_AR => _cardinal_16(operands),
+            Locale::es_BO => _cardinal_16(operands),
+            Locale::es_BR => _cardinal_16(operands),
+            Locale::es_BZ => _cardinal_16(operands),
+            Locale::es_CL => _cardinal_16(operands),
+            Locale::es_CO => _cardinal_16(operands),
+            Locale::es_CR => _cardinal_16(operands),
+            Locale::es_CU => _cardinal_16(operands),
+            Locale::es_DO => _cardinal_16(operands),
+            Locale::es_EA => _cardinal_16(operands),
+            Locale::es_EC => _cardinal_16(operands),
+            Locale::es_GQ => _cardinal_16(operands),
+            Locale::es_GT => _cardinal_16(operands),
+            Locale::es_HN => _cardinal_16(operands),
+            Locale::es_IC => _cardinal_16(operands),
+            Locale::es_MX => _cardinal_16(operands),
+            Locale::es_NI => _cardinal_16(operands),
+            Locale::es_PA => _cardinal_16(operands),
+            Locale::es_PE => _cardinal_16(operands),
+            Locale::es_PH => _cardinal_16(operands),
+            Locale::es_PR => _cardinal_16(operands),
+            Locale::es_PY => _cardinal_16(operands),
+            Locale::es_SV => _cardinal_16(operands),
+            Locale::es_US => _cardinal_16(operands),
+            Locale::es_UY => _cardinal_16(operands),
+            Locale::es_VE => _cardinal_16(operands),
+            Locale::et => _cardinal_5(operands),
+            Locale::eu => _cardinal_1(operands),
+            Locale::ewo => _cardinal_0(operands),
+            Locale::fa => _cardinal_3(operands),
+            Locale::fa_AF => _cardinal_3(operands),
+            Locale::ff => _cardinal_17(operands),
+            Locale::ff_Adlm => _cardinal_17(operands),
+            Locale::ff_Adlm_BF => _cardinal_17(operands),
+            Locale::ff_Adlm_CM => _cardinal_17(operands),
+            Locale::ff_Adlm_GH => _cardinal_17(operands),
+            Locale::ff_Adlm_GM => _cardinal_17(operands),
+            Locale::ff_Adlm_GW => _cardinal_17(operands),
+            Locale::ff_Adlm_LR => _cardinal_17(operands),
+            Locale::ff_Adlm_MR => _cardinal_17(operands),
+            Locale::ff_Adlm_NE => _cardinal_17(operands),
+            Locale::ff_Adlm_NG => _cardinal_17(operands),
+            Locale::ff_Adlm_SL => _cardinal_17(operands),
+            Locale::ff_Adlm_SN => _cardinal_17(operands),
+            Locale::ff_Latn => _cardinal_17(operands),
+            Locale::ff_Latn_BF => _cardinal_17(operands),
+            Locale::ff_Latn_CM => _cardinal_17(operands),
+            Locale::ff_Latn_GH => _cardinal_17(operands),
+            Locale::ff_Latn_GM => _cardinal_17(operands),
+            Locale::ff_Latn_GN => _cardinal_17(operands),
+            Locale::ff_Latn_GW => _cardinal_17(operands),
+            Locale::ff_Latn_LR => _cardinal_17(operands),
+            Locale::ff_Latn_MR => _cardinal_17(operands),
+            Locale::ff_Latn_NE => _cardinal_17(operands),
+            Locale::ff_Latn_NG => _cardinal_17(operands),
+            Locale::ff_Latn_SL => _cardinal_17(operands),
+            Locale::fi => _cardinal_5(operands),
+            Locale::fil => _cardinal_11(operands),
+            Locale::fo => _cardinal_1(operands),
+            Locale::fo_DK => _cardinal_1(operands),
+            Locale::fr => _cardinal_18(operands),
+            Locale::fr_BE => _cardinal_18(operands),
+            Locale::fr_BF => _cardinal_18(operands),
+            Locale::fr_BI => _cardinal_18(operands),
+            Locale::fr_BJ => _cardinal_18(operands),
+            Locale::fr_BL => _cardinal_18(operands),
+            Locale::fr_CA => _cardinal_18(operands),
+            Locale::fr_CD => _cardinal_18(operands),
+            Locale::fr_CF => _cardinal_18(operands),
+            Locale::fr_CG => _cardinal_18(operands),
+            Locale::fr_CH => _cardinal_18(operands),
+            Locale::fr_CI => _cardinal_18(operands),
+            Locale::fr_CM => _cardinal_18(operands),
+            Locale::fr_DJ => _cardinal_18(operands),
+            Locale::fr_DZ => _cardinal_18(operands),
+            Locale::fr_GA => _cardinal_18(operands),
+            Locale::fr_GF => _cardinal_18(operands),
+            Locale::fr_GN => _cardinal_18(operands),
+            Locale::fr_GP => _cardinal_18(operands),
+            Locale::fr_GQ => _cardinal_18(operands),
+            Locale::fr_HT => _cardinal_18(operands),
+            Locale::fr_KM => _cardinal_18(operands),
+            Locale::fr_LU => _cardinal_18(operands),
+            Locale::fr_MA => _cardinal_18(operands),
+            Locale::fr_MC => _cardinal_18(operands),
+            Locale::fr_MF => _cardinal_18(operands),
+            Locale::fr_MG => _cardinal_18(operands),
+            Locale::fr_ML => _cardinal_18(operands),
+            Locale::fr_MQ => _cardinal_18(operands),
+            Locale::fr_MR => _cardinal_18(operands),
+            Locale::fr_MU => _cardinal_18(operands),
+            Locale::fr_NC => _cardinal_18(operands),
+            Locale::fr_NE => _cardinal_18(operands),
+            Locale::fr_PF => _cardinal_18(operands),
+            Locale::fr_PM => _cardinal_18(operands),
+            Locale::fr_RE => _cardinal_18(operands),
+            Locale::fr_RW => _cardinal_18(operands),
+            Locale::fr_SC => _cardinal_18(operands),
+            Locale::fr_SN => _cardinal_18(operands),
+            Locale::fr_SY => _cardinal_18(operands),
+            Locale::fr_TD => _cardinal_18(operands),
+            Locale::fr_TG => _cardinal_18(operands),
+            Locale::fr_TN => _cardinal_18(operands),
+            Locale::fr_VU => _cardinal_18(operands),
+            Locale::fr_WF => _cardinal_18(operands),
+            Locale::fr_YT => _cardinal_18(operands),
+            Locale::frr => _cardinal_0(operands),
+            Locale::fur => _cardinal_1(operands),
+            Locale::fy => _cardinal_5(operands),
+            Locale::ga => _cardinal_19(operands),
+            Locale::ga_GB => _cardinal_19(operands),
+            Locale::gaa => _cardinal_0(operands),
+            Locale::gd => _cardinal_20(operands),
+            Locale::gez => _cardinal_0(operands),
+            Locale::gez_ER => _cardinal_0(operands),
+            Locale::gl => _cardinal_5(operands),
+            Locale::gn => _cardinal_0(operands),
+            Locale::gsw => _cardinal_1(operands),
+            Locale::gsw_FR => _cardinal_1(operands),
+            Locale::gsw_LI => _cardinal_1(operands),
+            Locale::gu => _cardinal_3(operands),
+            Locale::guz => _cardinal_0(operands),
+            Locale::gv => _cardinal_21(operands),
+            Locale::ha => _cardinal_1(operands),
+            Locale::ha_Arab => _cardinal_0(operands),
+            Locale::ha_Arab_SD => _cardinal_0(operands),
+            Locale::ha_GH => _cardinal_1(operands),
+            Locale::ha_NE => _cardinal_1(operands),
+            Locale::haw => _cardinal_1(operands),
+            Locale::he => _cardinal_22(operands),
+            Locale::hi => _cardinal_3(operands),
+            Locale::hi_Latn => _cardinal_5(operands),
+            Locale::hnj => _cardinal_0(operands),
+            Locale::hnj_Hmnp => _cardinal_0(operands),
+            Locale::hr => _cardinal_9(operands),
+            Locale::hr_BA => _cardinal_9(operands),
+            Locale::hsb => _cardinal_15(operands),
+            Locale::ht => _cardinal_18(operands),
+            Locale::hu => _cardinal_1(operands),
+            Locale::hy => _cardinal_17(operands),
+            Locale::ia => _cardinal_5(operands),
+            Locale::id => _cardinal_0(operands),
+            Locale::ie => _cardinal_0(operands),
+            Locale::ig => _cardinal_0(operands),
+            Locale::ii => _cardinal_0(operands),
+            Locale::io => _cardinal_5(operands),
+            Locale::is => _cardinal_23(operands),
+            Locale::it => _cardinal_10(operands),
+            Locale::it_CH => _cardinal_10(operands),
+            Locale::it_SM => _cardinal_10(operands),
+            Locale::it_VA => _cardinal_10(operands),
+            Locale::iu => _cardinal_24(operands),
+            Locale::iu_Latn => _cardinal_24(operands),
+            Locale::ja => _cardinal_0(operands),
+            Locale::jbo => _cardinal_0(operands),
+            Locale::jgo => _cardinal_1(operands),
+            Locale::jmc => _cardinal_1(operands),
+            Locale::jv => _cardinal_0(operands),
+            Locale::ka => _cardinal_1(operands),
+            Locale::kaa => _cardinal_0(operands),
+            Locale::kaa_Cyrl => _cardinal_0(operands),
+            Locale::kaa_Latn => _cardinal_0(operands),
+            Locale::kab => _cardinal_17(operands),
+            Locale::kaj => _cardinal_1(operands),
+            Locale::kam => _cardinal_0(operands),
+            Locale::kcg => _cardinal_1(operands),
+            Locale::kde => _cardinal_0(operands),
+            Locale::kea => _cardinal_0(operands),
+            Locale::kek => _cardinal_0(operands),
+            Locale::ken => _cardinal_0(operands),
+            Locale::kgp => _cardinal_0(operands),
+            Locale::khq => _cardinal_0(operands),
+            Locale::ki => _cardinal_0(operands),
+            Locale::kk => _cardinal_1(operands),
+            Locale::kk_Arab => _cardinal_0(operands),
+            Locale::kk_Cyrl => _cardinal_1(operands),
+            Locale::kk_KZ => _cardinal_1(operands),
+            Locale::kkj => _cardinal_1(operands),
+            Locale::kl => _cardinal_1(operands),
+            Locale::kln => _cardinal_0(operands),
+            Locale::km => _cardinal_0(operands),
+            Locale::kn => _cardinal_3(operands),
+            Locale::ko => _cardinal_0(operands),
+            Locale::ko_CN => _cardinal_0(operands),
+            Locale::ko_KP => _cardinal_0(operands),
+            Locale::kok => _cardinal_0(operands),
+            Locale::kok_Deva => _cardinal_0(operands),
+            Locale::kok_Latn => _cardinal_0(operands),
+            Locale::kpe => _cardinal_0(operands),
+            Locale::kpe_GN => _cardinal_0(operands),
+            Locale::ks => _cardinal_1(operands),
+            Locale::ks_Arab => _cardinal_1(operands),
+            Locale::ks_Deva => _cardinal_0(operands),
+            Locale::ksb => _cardinal_1(operands),
+            Locale::ksf => _cardinal_0(operands),
+            Locale::ksh => _cardinal_7(operands),
+            Locale::ku => _cardinal_1(operands),
+            Locale::ku_Arab => _cardinal_0(operands),
+            Locale::ku_Arab_IR => _cardinal_0(operands),
+            Locale::ku_Latn => _cardinal_1(operands),
+            Locale::ku_Latn_IQ => _cardinal_1(operands),
+            Locale::ku_Latn_SY => _cardinal_1(operands),
+            Locale::ku_TR => _cardinal_1(operands),
+            Locale::kw => _cardinal_25(operands),
+            Locale::kxv => _cardinal_0(operands),
+            Locale::kxv_Deva => _cardinal_0(operands),
+            Locale::kxv_Latn => _cardinal_0(operands),
+            Locale::kxv_Orya => _cardinal_0(operands),
+            Locale::kxv_Telu => _cardinal_0(operands),
+            Locale::ky => _cardinal_1(operands),
+            Locale::la => _cardinal_0(operands),
+            Locale::lag => _cardinal_26(operands),
+            Locale::lb => _cardinal_1(operands),
+            Locale::lg => _cardinal_1(operands),
+            Locale::lij => _cardinal_5(operands),
+            Locale::lkt => _cardinal_0(operands),
+            Locale::lld => _cardinal_0(operands),
+            Locale::lmo => _cardinal_0(operands),
+            Locale::ln => _cardinal_2(operands),
+            Locale::ln_AO => _cardinal_2(operands),
+            Locale::ln_CF => _cardinal_2(operands),
+            Locale::ln_CG => _cardinal_2(operands),
+            Locale::lo => _cardinal_0(operands),
+            Locale::lrc => _cardinal_0(operands),
+            Locale::lrc_IQ => _cardinal_0(operands),
+            Locale::lt => _cardinal_27(operands),
+            Locale::ltg => _cardinal_0(operands),
+            Locale::lu => _cardinal_0(operands),
+            Locale::luo => _cardinal_0(operands),
+            Locale::luy => _cardinal_0(operands),
+            Locale::lv => _cardinal_28(operands),
+            Locale::lzz => _cardinal_0(operands),
+            Locale::mai => _cardinal_0(operands),
+            Locale::mas => _cardinal_1(operands),
+            Locale::mas_TZ => _cardinal_1(operands),
+            Locale::mdf => _cardinal_0(operands),
+            Locale::mer => _cardinal_0(operands),
+            Locale::mfe => _cardinal_0(operands),
+            Locale::mg => _cardinal_2(operands),
+            Locale::mgh => _cardinal_0(operands),
+            Locale::mgo => _cardinal_1(operands),
+            Locale::mhn => _cardinal_0(operands),
+            Locale::mi => _cardinal_0(operands),
+            Locale::mic => _cardinal_0(operands),
+            Locale::mk => _cardinal_29(operands),
+            Locale::ml => _cardinal_1(operands),
+            Locale::mn => _cardinal_1(operands),
+            Locale::mn_Mong => _cardinal_0(operands),
+            Locale::mn_Mong_MN => _cardinal_0(operands),
+            Locale::mni => _cardinal_0(operands),
+            Locale::mni_Beng => _cardinal_0(operands),
+            Locale::mni_Mtei => _cardinal_0(operands),
+            Locale::moh => _cardinal_0(operands),
+            Locale::mr => _cardinal_1(operands),
+            Locale::ms => _cardinal_0(operands),
+            Locale::ms_Arab => _cardinal_0(operands),
+            Locale::ms_Arab_BN => _cardinal_0(operands),
+            Locale::ms_BN => _cardinal_0(operands),
+            Locale::ms_ID => _cardinal_0(operands),
+            Locale::ms_SG => _cardinal_0(operands),
+            Locale::mt => _cardinal_30(operands),
+            Locale::mua => _cardinal_0(operands),
+            Locale::mus => _cardinal_0(operands),
+            Locale::mww => _cardinal_0(operands),
+            Locale::mww_Hmnp => _cardinal_0(operands),
+            Locale::my => _cardinal_0(operands),
+            Locale::myv => _cardinal_0(operands),
+            Locale::mzn => _cardinal_0(operands),
+            Locale::naq => _cardinal_24(operands),
+            Locale::nb => _cardinal_1(operands),
+            Locale::nb_SJ => _cardinal_1(operands),
+            Locale::nd => _cardinal_1(operands),
+            Locale::nds => _cardinal_0(operands),
+            Locale::nds_NL => _cardinal_0(operands),
+            Locale::ne => _cardinal_1(operands),
+            Locale::ne_IN => _cardinal_1(operands),
+            Locale::nl => _cardinal_5(operands),
+            Locale::nl_AW => _cardinal_5(operands),
+            Locale::nl_BE => _cardinal_5(operands),
+            Locale::nl_BQ => _cardinal_5(operands),
+            Locale::nl_CW => _cardinal_5(operands),
+            Locale::nl_SR => _cardinal_5(operands),
+            Locale::nl_SX => _cardinal_5(operands),
+            Locale::nmg => _cardinal_0(operands),
+            Locale::nn => _cardinal_1(operands),
+            Locale::nnh => _cardinal_1(operands),
+            Locale::no => _cardinal_1(operands),
+            Locale::nqo => _cardinal_0(operands),
+            Locale::nr => _cardinal_1(operands),
+            Locale::nso => _cardinal_2(operands),
+            Locale::nus => _cardinal_0(operands),
+            Locale::nv => _cardinal_0(operands),
+            Locale::ny => _cardinal_1(operands),
+            Locale::nyn => _cardinal_1(operands),
+            Locale::oc => _cardinal_0(operands),
+            Locale::oc_ES => _cardinal_0(operands),
+            Locale::oka => _cardinal_0(operands),
+            Locale::oka_US => _cardinal_0(operands),
+            Locale::om => _cardinal_1(operands),
+            Locale::om_KE => _cardinal_1(operands),
+            Locale::or => _cardinal_1(operands),
+            Locale::os => _cardinal_1(operands),
+            Locale::os_RU => _cardinal_1(operands),
+            Locale::osa => _cardinal_0(operands),
+            Locale::pa => _cardinal_2(operands),
+            Locale::pa_Arab => _cardinal_0(operands),
+            Locale::pa_Guru => _cardinal_2(operands),
+            Locale::pap => _cardinal_1(operands),
+            Locale::pap_AW => _cardinal_1(operands),
+            Locale::pcm => _cardinal_3(operands),
+            Locale::pi => _cardinal_0(operands),
+            Locale::pi_Latn => _cardinal_0(operands),
+            Locale::pis => _cardinal_0(operands),
+            Locale::pl => _cardinal_31(operands),
+            Locale::pms => _cardinal_0(operands),
+            Locale::prg => _cardinal_28(operands),
+            Locale::ps => _cardinal_1(operands),
+            Locale::ps_PK => _cardinal_1(operands),
+            Locale::pt => _cardinal_32(operands),
+            Locale::pt_AO => _cardinal_10(operands),
+            Locale::pt_CH => _cardinal_10(operands),
+            Locale::pt_CV => _cardinal_10(operands),
+            Locale::pt_GQ => _cardinal_10(operands),
+            Locale::pt_GW => _cardinal_10(operands),
+            Locale::pt_LU => _cardinal_10(operands),
+            Locale::pt_MO => _cardinal_10(operands),
+            Locale::pt_MZ => _cardinal_10(operands),
+            Locale::pt_PT => _cardinal_10(operands),
+            Locale::pt_ST => _cardinal_10(operands),
+            Locale::pt_TL => _cardinal_10(operands),
+            Locale::qu => _cardinal_0(operands),
+            Locale::qu_BO => _cardinal_0(operands),
+            Locale::qu_EC => _cardinal_0(operands),
+            Locale::quc => _cardinal_0(operands),
+            Locale::raj => _cardinal_0(operands),
+            Locale::rhg => _cardinal_0(operands),
+            Locale::rhg_Rohg => _cardinal_0(operands),
+            Locale::rhg_Rohg_BD => _cardinal_0(operands),
+            Locale::rif => _cardinal_0(operands),
+            Locale::rm => _cardinal_1(operands),
+            Locale::rn => _cardinal_0(operands),
+            Locale::ro => _cardinal_33(operands),
+            Locale::ro_MD => _cardinal_33(operands),
+            Locale::rof => _cardinal_1(operands),
+            Locale::ru => _cardinal_34(operands),
+            Locale::ru_BY => _cardinal_34(operands),
+            Locale::ru_KG => _cardinal_34(operands),
+            Locale::ru_KZ => _cardinal_34(operands),
+            Locale::ru_MD => _cardinal_34(operands),
+            Locale::ru_UA => _cardinal_34(operands),
+            Locale::rw => _cardinal_0(operands),
+            Locale::rwk => _cardinal_1(operands),
+            Locale::sa => _cardinal_0(operands),
+            Locale::sah => _cardinal_0(operands),
+            Locale::saq => _cardinal_1(operands),
+            Locale::sat => _cardinal_24(operands),
+            Locale::sat_Deva => _cardinal_0(operands),
+            Locale::sat_Olck => _cardinal_24(operands),
+            Locale::sbp => _cardinal_0(operands),
+            Locale::sc => _cardinal_5(operands),
+            Locale::scn => _cardinal_5(operands),
+            Locale::sd => _cardinal_1(operands),
+            Locale::sd_Arab => _cardinal_1(operands),
+            Locale::sd_Deva => _cardinal_0(operands),
+            Locale::sdh => _cardinal_1(operands),
+            Locale::sdh_IQ => _cardinal_1(operands),
+            Locale::se => _cardinal_24(operands),
+            Locale::se_FI => _cardinal_24(operands),
+            Locale::se_SE => _cardinal_24(operands),
+            Locale::seh => _cardinal_1(operands),
+            Locale::ses => _cardinal_0(operands),
+            Locale::sg => _cardinal_0(operands),
+            Locale::sgs => _cardinal_0(operands),
+            Locale::shi => _cardinal_35(operands),
+            Locale::shi_Latn => _cardinal_35(operands),
+            Locale::shi_Tfng => _cardinal_35(operands),
+            Locale::shn => _cardinal_0(operands),
+            Locale::shn_TH => _cardinal_0(operands),
+            Locale::si => _cardinal_36(operands),
+            Locale::sid => _cardinal_0(operands),
+            Locale::sk => _cardinal_12(operands),
+            Locale::skr => _cardinal_0(operands),
+            Locale::sl => _cardinal_37(operands),
+            Locale::sma => _cardinal_24(operands),
+            Locale::sma_NO => _cardinal_24(operands),
+            Locale::smj => _cardinal_24(operands),
+            Locale::smj_NO => _cardinal_24(operands),
+            Locale::smn => _cardinal_24(operands),
+            Locale::sms => _cardinal_24(operands),
+            Locale::sn => _cardinal_1(operands),
+            Locale::so => _cardinal_1(operands),
+            Locale::so_DJ => _cardinal_1(operands),
+            Locale::so_ET => _cardinal_1(operands),
+            Locale::so_KE => _cardinal_1(operands),
+            Locale::sq => _cardinal_1(operands),
+            Locale::sq_MK => _cardinal_1(operands),
+            Locale::sq_XK => _cardinal_1(operands),
+            Locale::sr => _cardinal_9(operands),
+            Locale::sr_Cyrl => _cardinal_9(operands),
+            Locale::sr_Cyrl_BA => _cardinal_9(operands),
+            Locale::sr_Cyrl_ME => _cardinal_0(operands),
+            Locale::sr_Cyrl_XK => _cardinal_9(operands),
+            Locale::sr_Latn => _cardinal_0(operands),
+            Locale::sr_Latn_BA => _cardinal_0(operands),
+            Locale::sr_Latn_ME => _cardinal_0(operands),
+            Locale::sr_Latn_XK => _cardinal_0(operands),
+            Locale::ss => _cardinal_1(operands),
+            Locale::ss_SZ => _cardinal_1(operands),
+            Locale::ssy => _cardinal_1(operands),
+            Locale::st => _cardinal_1(operands),
+            Locale::st_LS => _cardinal_1(operands),
+            Locale::su => _cardinal_0(operands),
+            Locale::su_Latn => _cardinal_0(operands),
+            Locale::suz => _cardinal_0(operands),
+            Locale::suz_Deva => _cardinal_0(operands),
+            Locale::suz_Sunu => _cardinal_0(operands),
+            Locale::sv => _cardinal_5(operands),
+            Locale::sv_AX => _cardinal_5(operands),
+            Locale::sv_FI => _cardinal_5(operands),
+            Locale::sw => _cardinal_5(operands),
+            Locale::sw_CD => _cardinal_5(operands),
+            Locale::sw_KE => _cardinal_5(operands),
+            Locale::sw_UG => _cardinal_5(operands),
+            Locale::syr => _cardinal_1(operands),
+            Locale::syr_SY => _cardinal_1(operands),
+            Locale::szl => _cardinal_0(operands),
+            Locale::ta => _cardinal_1(operands),
+            Locale::ta_LK => _cardinal_1(operands),
+            Locale::ta_MY => _cardinal_1(operands),
+            Locale::ta_SG => _cardinal_1(operands),
+            Locale::te => _cardinal_1(operands),
+            Locale::teo => _cardinal_1(operands),
+            Locale::teo_KE => _cardinal_1(operands),
+            Locale::tg => _cardinal_0(operands),
+            Locale::th => _cardinal_0(operands),
+            Locale::ti => _cardinal_2(operands),
+            Locale::ti_ER => _cardinal_2(operands),
+            Locale::tig => _cardinal_1(operands),
+            Locale::tk => _cardinal_1(operands),
+            Locale::tn => _cardinal_1(operands),
+            Locale::tn_BW => _cardinal_1(operands),
+            Locale::to => _cardinal_0(operands),
+            Locale::tok => _cardinal_0(operands),
+            Locale::tpi => _cardinal_0(operands),
+            Locale::tr => _cardinal_1(operands),
+            Locale::tr_CY => _cardinal_1(operands),
+            Locale::trv => _cardinal_0(operands),
+            Locale::trw => _cardinal_0(operands),
+            Locale::ts => _cardinal_1(operands),
+            Locale::tt => _cardinal_0(operands),
+            Locale::twq => _cardinal_0(operands),
+            Locale::tyv => _cardinal_0(operands),
+            Locale::tzm => _cardinal_38(operands),
+            Locale::ug => _cardinal_1(operands),
+            Locale::uk => _cardinal_34(operands),
+            Locale::und => _cardinal_0(operands),
+            Locale::ur => _cardinal_5(operands),
+            Locale::ur_IN => _cardinal_5(operands),
+            Locale::uz => _cardinal_1(operands),
+            Locale::uz_Arab => _cardinal_0(operands),
+            Locale::uz_Cyrl => _cardinal_0(operands),
+            Locale::uz_Latn => _cardinal_1(operands),
+            Locale::vai => _cardinal_0(operands),
+            Locale::vai_Latn => _cardinal_0(operands),
+            Locale::vai_Vaii => _cardinal_0(operands),
+            Locale::ve => _cardinal_1(operands),
+            Locale::vec => _cardinal_10(operands),
+            Locale::vi => _cardinal_0(operands),
+            Locale::vmw => _cardinal_0(operands),
+            Locale::vo => _cardinal_1(operands),
+            Locale::vun => _cardinal_1(operands),
+            Locale::wa => _cardinal_2(operands),
+            Locale::wae => _cardinal_1(operands),
+            Locale::wal => _cardinal_0(operands),
+            Locale::wbp => _cardinal_0(operands),
+            Locale::wo => _cardinal_0(operands),
+            Locale::xh => _cardinal_1(operands),
+            Locale::xnr => _cardinal_0(operands),
+            Locale::xog => _cardinal_1(operands),
+            Locale::yav => _cardinal_0(operands),
+            Locale::yi => _cardinal_5(operands),
+            Locale::yo => _cardinal_0(operands),
+            Locale::yo_BJ => _cardinal_0(operands),
+            Locale::yrl => _cardinal_0(operands),
+            Locale::yrl_CO => _cardinal_0(operands),
+            Locale::yrl_VE => _cardinal_0(operands),
+            Locale::yue => _cardinal_0(operands),
+            Locale::yue_Hans => _cardinal_0(operands),
+            Locale::yue_Hant => _cardinal_0(operands),
+            Locale::yue_Hant_CN => _cardinal_0(operands),
+            Locale::yue_Hant_MO => _cardinal_0(operands),
+            Locale::za => _cardinal_0(operands),
+            Locale::zgh => _cardinal_0(operands),
+            Locale::zh => _cardinal_0(operands),
+            Locale::zh_Hans => _cardinal_0(operands),
+            Locale::zh_Hans_HK => _cardinal_0(operands),
+            Locale::zh_Hans_MO => _cardinal_0(operands),
+            Locale::zh_Hans_MY => _cardinal_0(operands),
+            Locale::zh_Hans_SG => _cardinal_0(operands),
+            Locale::zh_Hant => _cardinal_0(operands),
+            Locale::zh_Hant_HK => _cardinal_0(operands),
+            Locale::zh_Hant_MO => _cardinal_0(operands),
+            Locale::zh_Hant_MY => _cardinal_0(operands),
+            Locale::zh_Latn => _cardinal_0(operands),
+            Locale::zu => _cardinal_3(operands),
+        }
+    }
+
+    /// Returns the plural category of a range of numbers, e.g. `Other` for
+    /// "1–2 days" in English but `One` for "0–1 jour" in French. Locales
+    /// without range rules use the category of the end.
+    pub fn plural_range_category(
+        &self,
+        start: &PluralOperands,
+        end: &PluralOperands,
+    ) -> PluralCategory {
+        let (start, end) = (self.plural_category(start), self.plural_category(end));
+        self._plural_ranges()
+            .iter()
+            .find(|(s, e, _)| *s == start && *e == end)
+            .map_or(end, |&(_, _, category)| category)
+    }
+
+    /// Range rules as `(start, end, range)` categories.
+    fn _plural_ranges(&self) -> &'static [(PluralCategory, PluralCategory, PluralCategory)] {
+        match self {
+            Locale::aa => &_PLURAL_RANGES_0,
+            Locale::aa_DJ => &_PLURAL_RANGES_0,
+            Locale::aa_ER => &_PLURAL_RANGES_0,
+            Locale::ab => &_PLURAL_RANGES_0,
+            Locale::af => &_PLURAL_RANGES_1,
+            Locale::af_NA => &_PLURAL_RANGES_1,
+            Locale::agq => &_PLURAL_RANGES_0,
+            Locale::ak => &_PLURAL_RANGES_2,
+            Locale::am => &_PLURAL_RANGES_3,
+            Locale::an => &_PLURAL_RANGES_1,
+            Locale::ann => &_PLURAL_RANGES_0,
+            Locale::apc => &_PLURAL_RANGES_0,
+            Locale::ar => &_PLURAL_RANGES_4,
+            Locale::ar_AE => &_PLURAL_RANGES_4,
+            Locale::ar_BH => &_PLURAL_RANGES_4,
+            Locale::ar_DJ => &_PLURAL_RANGES_4,
+            Locale::ar_DZ => &_PLURAL_RANGES_4,
+            Locale::ar_EG => &_PLURAL_RANGES_4,
+            Locale::ar_EH => &_PLURAL_RANGES_4,
+            Locale::ar_ER => &_PLURAL_RANGES_4,
+            Locale::ar_IL => &_PLURAL_RANGES_4,
+            Locale::ar_IQ => &_PLURAL_RANGES_4,
+            Locale::ar_JO => &_PLURAL_RANGES_4,
+            Locale::ar_KM => &_PLURAL_RANGES_4,
+            Locale::ar_KW => &_PLURAL_RANGES_4,
+            Locale::ar_LB => &_PLURAL_RANGES_4,
+            Locale::ar_LY => &_PLURAL_RANGES_4,
+            Locale::ar_MA => &_PLURAL_RANGES_4,
+            Locale::ar_MR => &_PLURAL_RANGES_4,
+            Locale::ar_OM => &_PLURAL_RANGES_4,
+            Locale::ar_PS => &_PLURAL_RANGES_4,
+            Locale::ar_QA => &_PLURAL_RANGES_4,
+            Locale::ar_SA => &_PLURAL_RANGES_4,
+            Locale::ar_SD => &_PLURAL_RANGES_4,
+            Locale::ar_SO => &_PLURAL_RANGES_4,
+            Locale::ar_SS => &_PLURAL_RANGES_4,
+            Locale::ar_SY => &_PLURAL_RANGES_4,
+            Locale::ar_TD => &_PLURAL_RANGES_4,
+            Locale::ar_TN => &_PLURAL_RANGES_4,
+            Locale::ar_YE => &_PLURAL_RANGES_4,
+            Locale::arn => &_PLURAL_RANGES_0,
+            Locale::as_ => &_PLURAL_RANGES_3,
+            Locale::asa => &_PLURAL_RANGES_0,
+            Locale::ast => &_PLURAL_RANGES_0,
+            Locale::az => &_PLURAL_RANGES_5,
+            Locale::az_Arab => &_PLURAL_RANGES_5,
+            Locale::az_Arab_IQ => &_PLURAL_RANGES_5,
+            Locale::az_Arab_TR => &_PLURAL_RANGES_5,
+            Locale::az_Cyrl => &_PLURAL_RANGES_5,
+            Locale::az_Latn => &_PLURAL_RANGES_5,
+            Locale::ba => &_PLURAL_RANGES_0,
+            Locale::bal => &_PLURAL_RANGES_0,
+            Locale::bal_Arab => &_PLURAL_RANGES_0,
+            Locale::bal_Latn => &_PLURAL_RANGES_0,
+            Locale::bas => &_PLURAL_RANGES_0,
+            Locale::be => &_PLURAL_RANGES_6,
+            Locale::be_tarask => &_PLURAL_RANGES_6,
+            Locale::bem => &_PLURAL_RANGES_0,
+            Locale::bew => &_PLURAL_RANGES_0,
+            Locale::bez => &_PLURAL_RANGES_0,
+            Locale::bg => &_PLURAL_RANGES_1,
+            Locale::bgc => &_PLURAL_RANGES_0,
+            Locale::bgn => &_PLURAL_RANGES_0,
+            Locale::bgn_AE => &_PLURAL_RANGES_0,
+            Locale::bgn_AF => &_PLURAL_RANGES_0,
+            Locale::bgn_IR => &_PLURAL_RANGES_0,
+            Locale::bgn_OM => &_PLURAL_RANGES_0,
+            Locale::bho => &_PLURAL_RANGES_0,
+            Locale::blo => &_PLURAL_RANGES_0,
+            Locale::blt => &_PLURAL_RANGES_0,
+            Locale::bm => &_PLURAL_RANGES_0,
+            Locale::bm_Nkoo => &_PLURAL_RANGES_0,
+            Locale::bn => &_PLURAL_RANGES_3,
+            Locale::bn_IN => &_PLURAL_RANGES_3,
+            Locale::bo => &_PLURAL_RANGES_0,
+            Locale::bo_IN => &_PLURAL_RANGES_0,
+            Locale::bqi => &_PLURAL_RANGES_0,
+            Locale::br => &_PLURAL_RANGES_0,
+            Locale::brx => &_PLURAL_RANGES_0,
+            Locale::bs => &_PLURAL_RANGES_7,
+            Locale::bs_Cyrl => &_PLURAL_RANGES_7,
+            Locale::bs_Latn => &_PLURAL_RANGES_7,
+            Locale::bss => &_PLURAL_RANGES_0,
+            Locale::bua => &_PLURAL_RANGES_0,
+            Locale::byn => &_PLURAL_RANGES_0,
+            Locale::ca => &_PLURAL_RANGES_1,
+            Locale::ca_AD => &_PLURAL_RANGES_1,
+            Locale::ca_ES_valencia => &_PLURAL_RANGES_1,
+            Locale::ca_FR => &_PLURAL_RANGES_1,
+            Locale::ca_IT => &_PLURAL_RANGES_1,
+            Locale::cad => &_PLURAL_RANGES_0,
+            Locale::cch => &_PLURAL_RANGES_0,
+            Locale::ccp => &_PLURAL_RANGES_0,
+            Locale::ccp_IN => &_PLURAL_RANGES_0,
+            Locale::ce => &_PLURAL_RANGES_0,
+            Locale::ceb => &_PLURAL_RANGES_0,
+            Locale::cgg => &_PLURAL_RANGES_0,
+            Locale::cho => &_PLURAL_RANGES_0,
+            Locale::chr => &_PLURAL_RANGES_0,
+            Locale::cic => &_PLURAL_RANGES_0,
+            Locale::ckb => &_PLURAL_RANGES_0,
+            Locale::ckb_IR => &_PLURAL_RANGES_0,
+            Locale::co => &_PLURAL_RANGES_0,
+            Locale::cop => &_PLURAL_RANGES_0,
+            Locale::cs => &_PLURAL_RANGES_8,
+            Locale::csw => &_PLURAL_RANGES_0,
+            Locale::cu => &_PLURAL_RANGES_0,
+            Locale::cv => &_PLURAL_RANGES_0,
+            Locale::cy => &_PLURAL_RANGES_9,
+            Locale::da => &_PLURAL_RANGES_10,
+            Locale::da_GL => &_PLURAL_RANGES_10,
+            Locale::dav => &_PLURAL_RANGES_0,
+            Locale::de => &_PLURAL_RANGES_5,
+            Locale::de_AT => &_PLURAL_RANGES_5,
+            Locale::de_BE => &_PLURAL_RANGES_5,
+            Locale::de_CH => &_PLURAL_RANGES_5,
+            Locale::de_IT => &_PLURAL_RANGES_5,
+            Locale::de_LI => &_PLURAL_RANGES_5,
+            Locale::de_LU => &_PLURAL_RANGES_5,
+            Locale::dje => &_PLURAL_RANGES_0,
+            Locale::doi => &_PLURAL_RANGES_0,
+            Locale::dsb => &_PLURAL_RANGES_0,
+            Locale::dua => &_PLURAL_RANGES_0,
+            Locale::dv => &_PLURAL_RANGES_0,
+            Locale::dyo => &_PLURAL_RANGES_0,
+            Locale::dz => &_PLURAL_RANGES_0,
+            Locale::ebu => &_PLURAL_RANGES_0,
+            Locale::ee => &_PLURAL_RANGES_0,
+            Locale::ee_TG => &_PLURAL_RANGES_0,
+            Locale::el => &_PLURAL_RANGES_5,
+            Locale::el_CY => &_PLURAL_RANGES_5,
+            Locale::el_polyton => &_PLURAL_RANGES_5,
+            Locale::en => &_PLURAL_RANGES_1,
+            Locale::en_001 => &_PLURAL_RANGES_1,
+            Locale::en_150 => &_PLURAL_RANGES_1,
+            Locale::en_AE => &_PLURAL_RANGES_1,
+            Locale::en_AG => &_PLURAL_RANGES_1,
+            Locale::en_AI => &_PLURAL_RANGES_1,
+            Locale::en_AS => &_PLURAL_RANGES_1,
+            Locale::en_AT => &_PLURAL_RANGES_1,
+            Locale::en_AU => &_PLURAL_RANGES_1,
+            Locale::en_BB => &_PLURAL_RANGES_1,
+            Locale::en_BE => &_PLURAL_RANGES_1,
+            Locale::en_BI => &_PLURAL_RANGES_1,
+            Locale::en_BM => &_PLURAL_RANGES_1,
+            Locale::en_BS => &_PLURAL_RANGES_1,
+            Locale::en_BW => &_PLURAL_RANGES_1,
+            Locale::en_BZ => &_PLURAL_RANGES_1,
+            Locale::en_CA => &_PLURAL_RANGES_1,
+            Locale::en_CC => &_PLURAL_RANGES_1,
+            Locale::en_CH => &_PLURAL_RANGES_1,
+            Locale::en_CK => &_PLURAL_RANGES_1,
+            Locale::en_CM => &_PLURAL_RANGES_1,
+            Locale::en_CX => &_PLURAL_RANGES_1,
+            Locale::en_CY => &_PLURAL_RANGES_1,
+            Locale::en_CZ => &_PLURAL_RANGES_1,
+            Locale::en_DE => &_PLURAL_RANGES_1,
+            Locale::en_DG => &_PLURAL_RANGES_1,
+            Locale::en_DK => &_PLURAL_RANGES_1,
+            Locale::en_DM => &_PLURAL_RANGES_1,
+            Locale::en_Dsrt => &_PLURAL_RANGES_1,
+            Locale::en_EE => &_PLURAL_RANGES_1,
+            Locale::en_ER => &_PLURAL_RANGES_1,
+            Locale::en_ES => &_PLURAL_RANGES_1,
+            Locale::en_FI => &_PLURAL_RANGES_1,
+            Locale::en_FJ => &_PLURAL_RANGES_1,
+            Locale::en_FK => &_PLURAL_RANGES_1,
+            Locale::en_FM => &_PLURAL_RANGES_1,
+            Locale::en_FR => &_PLURAL_RANGES_1,
+            Locale::en_GB => &_PLURAL_RANGES_1,
+            Locale::en_GD => &_PLURAL_RANGES_1,
+            Locale::en_GE => &_PLURAL_RANGES_1,
+            Locale::en_GG => &_PLURAL_RANGES_1,
+            Locale::en_GH => &_PLURAL_RANGES_1,
+            Locale::en_GI => &_PLURAL_RANGES_1,
+            Locale::en_GM => &_PLURAL_RANGES_1,
+            Locale::en_GS => &_PLURAL_RANGES_1,
+            Locale::en_GU => &_PLURAL_RANGES_1,
+            Locale::en_GY => &_PLURAL_RANGES_1,
+            Locale::en_HK => &_PLURAL_RANGES_1,
+            Locale::en_HU => &_PLURAL_RANGES_1,
+            Locale::en_ID => &_PLURAL_RANGES_1,
+            Locale::en_IE => &_PLURAL_RANGES_1,
+            Locale::en_IL => &_PLURAL_RANGES_1,
+            Locale::en_IM => &_PLURAL_RANGES_1,
+            Locale::en_IN => &_PLURAL_RANGES_1,
+            Locale::en_IO => &_PLURAL_RANGES_1,
+            Locale::en_IT => &_PLURAL_RANGES_1,
+            Locale::en_JE => &_PLURAL_RANGES_1,
+            Locale::en_JM => &_PLURAL_RANGES_1,
+            Locale::en_JP => &_PLURAL_RANGES_1,
+            Locale::en_KE => &_PLURAL_RANGES_1,
+            Locale::en_KI => &_PLURAL_RANGES_1,
+            Locale::en_KN => &_PLURAL_RANGES_1,
+            Locale::en_KY => &_PLURAL_RANGES_1,
+            Locale::en_LC => &_PLURAL_RANGES_1,
+            Locale::en_LR => &_PLURAL_RANGES_1,
+            Locale::en_LS => &_PLURAL_RANGES_1,
+            Locale::en_LT => &_PLURAL_RANGES_1,
+            Locale::en_LV => &_PLURAL_RANGES_1,
+            Locale::en_MG => &_PLURAL_RANGES_1,
+            Locale::en_MH => &_PLURAL_RANGES_1,
+            Locale::en_MO => &_PLURAL_RANGES_1,
+            Locale::en_MP => &_PLURAL_RANGES_1,
+            Locale::en_MS => &_PLURAL_RANGES_1,
+            Locale::en_MT => &_PLURAL_RANGES_1,
+            Locale::en_MU => &_PLURAL_RANGES_1,
+            Locale::en_MV => &_PLURAL_RANGES_1,
+            Locale::en_MW => &_PLURAL_RANGES_1,
+            Locale::en_MY => &_PLURAL_RANGES_1,
+            Locale::en_NA => &_PLURAL_RANGES_1,
+            Locale::en_NF => &_PLURAL_RANGES_1,
+            Locale::en_NG => &_PLURAL_RANGES_1,
+            Locale::en_NL => &_PLURAL_RANGES_1,
+            Locale::en_NO => &_PLURAL_RANGES_1,
+            Locale::en_NR => &_PLURAL_RANGES_1,
+            Locale::en_NU => &_PLURAL_RANGES_1,
+            Locale::en_NZ => &_PLURAL_RANGES_1,
+            Locale::en_PG => &_PLURAL_RANGES_1,
+            Locale::en_PH => &_PLURAL_RANGES_1,
+            Locale::en_PK => &_PLURAL_RANGES_1,
+            Locale::en_PL => &_PLURAL_RANGES_1,
+            Locale::en_PN => &_PLURAL_RANGES_1,
+            Locale::en_PR => &_PLURAL_RANGES_1,
+            Locale::en_PT => &_PLURAL_RANGES_1,
+            Locale::en_PW => &_PLURAL_RANGES_1,
+            Locale::en_RO => &_PLURAL_RANGES_1,
+            Locale::en_RW => &_PLURAL_RANGES_1,
+            Locale::en_SB => &_PLURAL_RANGES_1,
+            Locale::en_SC => &_PLURAL_RANGES_1,
+            Locale::en_SD => &_PLURAL_RANGES_1,
+            Locale::en_SE => &_PLURAL_RANGES_1,
+            Locale::en_SG => &_PLURAL_RANGES_1,
+            Locale::en_SH => &_PLURAL_RANGES_1,
+            Locale::en_SI => &_PLURAL_RANGES_1,
+            Locale::en_SK => &_PLURAL_RANGES_1,
+            Locale::en_SL => &_PLURAL_RANGES_1,
+            Locale::en_SS => &_PLURAL_RANGES_1,
+            Locale::en_SX => &_PLURAL_RANGES_1,
+            Locale::en_SZ => &_PLURAL_RANGES_1,
+            Locale::en_Shaw => &_PLURAL_RANGES_1,
+            Locale::en_TC => &_PLURAL_RANGES_1,
+            Locale::en_TK => &_PLURAL_RANGES_1,
+            Locale::en_TO => &_PLURAL_RANGES_1,
+            Locale::en_TT => &_PLURAL_RANGES_1,
+            Locale::en_TV => &_PLURAL_RANGES_1,
+            Locale::en_TZ => &_PLURAL_RANGES_1,
+            Locale::en_UA => &_PLURAL_RANGES_1,
+            Locale::en_UG => &_PLURAL_RANGES_1,
+            Locale::en_UM => &_PLURAL_RANGES_1,
+            Locale::en_VC => &_PLURAL_RANGES_1,
+            Locale::en_VG => &_PLURAL_RANGES_1,
+            Locale::en_VI => &_PLURAL_RANGES_1,
+            Locale::en_VU => &_PLURAL_RANGES_1,
+            Locale::en_WS => &_PLURAL_RANGES_1,
+            Locale::en_ZA => &_PLURAL_RANGES_1,
+            Locale::en_ZM => &_PLURAL_RANGES_1,
+            Locale::en_ZW => &_PLURAL_RANGES_1,
+            Locale::eo => &_PLURAL_RANGES_0,
+            Locale::es => &_PLURAL_RANGES_1,
+            Locale::es_419 => &_PLURAL_RANGES_1,
+            Locale::es_AR => &_PLURAL_RANGES_1,
+            Locale::es_BO => &_PLURAL_RANGES_1,
+            Locale::es_BR => &_PLURAL_RANGES_1,
+            Locale::es_BZ => &_PLURAL_RANGES_1,
+            Locale::es_CL => &_PLURAL_RANGES_1,
+            Locale::es_CO => &_PLURAL_RANGES_1,
+            Locale::es_CR => &_PLURAL_RANGES_1,
+            Locale::es_CU => &_PLURAL_RANGES_1,
+            Locale::es_DO => &_PLURAL_RANGES_1,
+            Locale::es_EA => &_PLURAL_RANGES_1,
+            Locale::es_EC => &_PLURAL_RANGES_1,
+            Locale::es_GQ => &_PLURAL_RANGES_1,
+            Locale::es_GT => &_PLURAL_RANGES_1,
+            Locale::es_HN => &_PLURAL_RANGES_1,
+            Locale::es_IC => &_PLURAL_RANGES_1,
+            Locale::es_MX => &_PLURAL_RANGES_1,
+            Locale::es_NI => &_PLURAL_RANGES_1,
+            Locale::es_PA => &_PLURAL_RANGES_1,
+            Locale::es_PE => &_PLURAL_RANGES_1,
+            Locale::es_PH => &_PLURAL_RANGES_1,
+            Locale::es_PR => &_PLURAL_RANGES_1,
+            Locale::es_PY => &_PLURAL_RANGES_1,
+            Locale::es_SV => &_PLURAL_RANGES_1,
+            Locale::es_US => &_PLURAL_RANGES_1,
+            Locale::es_UY => &_PLURAL_RANGES_1,
+            Locale::es_VE => &_PLURAL_RANGES_1,
+            Locale::et => &_PLURAL_RANGES_1,
+            Locale::eu => &_PLURAL_RANGES_1,
+            Locale::ewo => &_PLURAL_RANGES_0,
+            Locale::fa => &_PLURAL_RANGES_2,
+            Locale::fa_AF => &_PLURAL_RANGES_2,
+            Locale::ff => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_BF => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_CM => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_GH => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_GM => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_GW => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_LR => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_MR => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_NE => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_NG => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_SL => &_PLURAL_RANGES_0,
+            Locale::ff_Adlm_SN => &_PLURAL_RANGES_0,
+            Locale::ff_Latn => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_BF => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_CM => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_GH => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_GM => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_GN => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_GW => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_LR => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_MR => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_NE => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_NG => &_PLURAL_RANGES_0,
+            Locale::ff_Latn_SL => &_PLURAL_RANGES_0,
+            Locale::fi => &_PLURAL_RANGES_1,
+            Locale::fil => &_PLURAL_RANGES_10,
+            Locale::fo => &_PLURAL_RANGES_0,
+            Locale::fo_DK => &_PLURAL_RANGES_0,
+            Locale::fr => &_PLURAL_RANGES_3,
+            Locale::fr_BE => &_PLURAL_RANGES_3,
+            Locale::fr_BF => &_PLURAL_RANGES_3,
+            Locale::fr_BI => &_PLURAL_RANGES_3,
+            Locale::fr_BJ => &_PLURAL_RANGES_3,
+            Locale::fr_BL => &_PLURAL_RANGES_3,
+            Locale::fr_CA => &_PLURAL_RANGES_3,
+            Locale::fr_CD => &_PLURAL_RANGES_3,
+            Locale::fr_CF => &_PLURAL_RANGES_3,
+            Locale::fr_CG => &_PLURAL_RANGES_3,
+            Locale::fr_CH => &_PLURAL_RANGES_3,
+            Locale::fr_CI => &_PLURAL_RANGES_3,
+            Locale::fr_CM => &_PLURAL_RANGES_3,
+            Locale::fr_DJ => &_PLURAL_RANGES_3,
+            Locale::fr_DZ => &_PLURAL_RANGES_3,
+            Locale::fr_GA => &_PLURAL_RANGES_3,
+            Locale::fr_GF => &_PLURAL_RANGES_3,
+            Locale::fr_GN => &_PLURAL_RANGES_3,
+            Locale::fr_GP => &_PLURAL_RANGES_3,
+            Locale::fr_GQ => &_PLURAL_RANGES_3,
+            Locale::fr_HT => &_PLURAL_RANGES_3,
+            Locale::fr_KM => &_PLURAL_RANGES_3,
+            Locale::fr_LU => &_PLURAL_RANGES_3,
+            Locale::fr_MA => &_PLURAL_RANGES_3,
+            Locale::fr_MC => &_PLURAL_RANGES_3,
+            Locale::fr_MF => &_PLURAL_RANGES_3,
+            Locale::fr_MG => &_PLURAL_RANGES_3,
+            Locale::fr_ML => &_PLURAL_RANGES_3,
+            Locale::fr_MQ => &_PLURAL_RANGES_3,
+            Locale::fr_MR => &_PLURAL_RANGES_3,
+            Locale::fr_MU => &_PLURAL_RANGES_3,
+            Locale::fr_NC => &_PLURAL_RANGES_3,
+            Locale::fr_NE => &_PLURAL_RANGES_3,
+            Locale::fr_PF => &_PLURAL_RANGES_3,
+            Locale::fr_PM => &_PLURAL_RANGES_3,
+            Locale::fr_RE => &_PLURAL_RANGES_3,
+            Locale::fr_RW => &_PLURAL_RANGES_3,
+            Locale::fr_SC => &_PLURAL_RANGES_3,
+            Locale::fr_SN => &_PLURAL_RANGES_3,
+            Locale::fr_SY => &_PLURAL_RANGES_3,
+            Locale::fr_TD => &_PLURAL_RANGES_3,
+            Locale::fr_TG => &_PLURAL_RANGES_3,
+            Locale::fr_TN => &_PLURAL_RANGES_3,
+            Locale::fr_VU => &_PLURAL_RANGES_3,
+            Locale::fr_WF => &_PLURAL_RANGES_3,
+            Locale::fr_YT => &_PLURAL_RANGES_3,
+            Locale::frr => &_PLURAL_RANGES_0,
+            Locale::fur => &_PLURAL_RANGES_0,
+            Locale::fy => &_PLURAL_RANGES_0,
+            Locale::ga => &_PLURAL_RANGES_11,
+            Locale::ga_GB => &_PLURAL_RANGES_11,
+            Locale::gaa => &_PLURAL_RANGES_0,
+            Locale::gd => &_PLURAL_RANGES_0,
+            Locale::gez => &_PLURAL_RANGES_0,
+            Locale::gez_ER => &_PLURAL_RANGES_0,
+            Locale::gl => &_PLURAL_RANGES_5,
+            Locale::gn => &_PLURAL_RANGES_0,
+            Locale::gsw => &_PLURAL_RANGES_5,
+            Locale::gsw_FR => &_PLURAL_RANGES_5,
+            Locale::gsw_LI => &_PLURAL_RANGES_5,
+            Locale::gu => &_PLURAL_RANGES_3,
+            Locale::guz => &_PLURAL_RANGES_0,
+            Locale::gv => &_PLURAL_RANGES_0,
+            Locale::ha => &_PLURAL_RANGES_0,
+            Locale::ha_Arab => &_PLURAL_RANGES_0,
+            Locale::ha_Arab_SD => &_PLURAL_RANGES_0,
+            Locale::ha_GH => &_PLURAL_RANGES_0,
+            Locale::ha_NE => &_PLURAL_RANGES_0,
+            Locale::haw => &_PLURAL_RANGES_0,
+            Locale::he => &_PLURAL_RANGES_12,
+            Locale::hi => &_PLURAL_RANGES_3,
+            Locale::hi_Latn => &_PLURAL_RANGES_3,
+            Locale::hnj => &_PLURAL_RANGES_0,
+            Locale::hnj_Hmnp => &_PLURAL_RANGES_0,
+            Locale::hr => &_PLURAL_RANGES_7,
+            Locale::hr_BA => &_PLURAL_RANGES_7,
+            Locale::hsb => &_PLURAL_RANGES_0,
+            Locale::ht => &_PLURAL_RANGES_0,
+            Locale::hu => &_PLURAL_RANGES_5,
+            Locale::hy => &_PLURAL_RANGES_3,
+            Locale::ia => &_PLURAL_RANGES_1,
+            Locale::id => &_PLURAL_RANGES_13,
+            Locale::ie => &_PLURAL_RANGES_0,
+            Locale::ig => &_PLURAL_RANGES_0,
+            Locale::ii => &_PLURAL_RANGES_0,
+            Locale::io => &_PLURAL_RANGES_1,
+            Locale::is => &_PLURAL_RANGES_10,
+            Locale::it => &_PLURAL_RANGES_5,
+            Locale::it_CH => &_PLURAL_RANGES_5,
+            Locale::it_SM => &_PLURAL_RANGES_5,
+            Locale::it_VA => &_PLURAL_RANGES_5,
+            Locale::iu => &_PLURAL_RANGES_0,
+            Locale::iu_Latn => &_PLURAL_RANGES_0,
+            Locale::ja => &_PLURAL_RANGES_13,
+            Locale::jbo => &_PLURAL_RANGES_0,
+            Locale::jgo => &_PLURAL_RANGES_0,
+            Locale::jmc => &_PLURAL_RANGES_0,
+            Locale::jv => &_PLURAL_RANGES_0,
+            Locale::ka => &_PLURAL_RANGES_14,
+            Locale::kaa => &_PLURAL_RANGES_0,
+            Locale::kaa_Cyrl => &_PLURAL_RANGES_0,
+            Locale::kaa_Latn => &_PLURAL_RANGES_0,
+            Locale::kab => &_PLURAL_RANGES_0,
+            Locale::kaj => &_PLURAL_RANGES_0,
+            Locale::kam => &_PLURAL_RANGES_0,
+            Locale::kcg => &_PLURAL_RANGES_0,
+            Locale::kde => &_PLURAL_RANGES_0,
+            Locale::kea => &_PLURAL_RANGES_0,
+            Locale::kek => &_PLURAL_RANGES_0,
+            Locale::ken => &_PLURAL_RANGES_0,
+            Locale::kgp => &_PLURAL_RANGES_0,
+            Locale::khq => &_PLURAL_RANGES_0,
+            Locale::ki => &_PLURAL_RANGES_0,
+            Locale::kk => &_PLURAL_RANGES_5,
+            Locale::kk_Arab => &_PLURAL_RANGES_5,
+            Locale::kk_Cyrl => &_PLURAL_RANGES_5,
+            Locale::kk_KZ => &_PLURAL_RANGES_5,
+            Locale::kkj => &_PLURAL_RANGES_0,
+            Locale::kl => &_PLURAL_RANGES_0,
+            Locale::kln => &_PLURAL_RANGES_0,
+            Locale::km => &_PLURAL_RANGES_13,
+            Locale::kn => &_PLURAL_RANGES_3,
+            Locale::ko => &_PLURAL_RANGES_13,
+            Locale::ko_CN => &_PLURAL_RANGES_13,
+            Locale::ko_KP => &_PLURAL_RANGES_13,
+            Locale::kok => &_PLURAL_RANGES_0,
+            Locale::kok_Deva => &_PLURAL_RANGES_0,
+            Locale::kok_Latn => &_PLURAL_RANGES_0,
+            Locale::kpe => &_PLURAL_RANGES_0,
+            Locale::kpe_GN => &_PLURAL_RANGES_0,
+            Locale::ks => &_PLURAL_RANGES_0,
+            Locale::ks_Arab => &_PLURAL_RANGES_0,
+            Locale::ks_Deva => &_PLURAL_RANGES_0,
+            Locale::ksb => &_PLURAL_RANGES_0,
+            Locale::ksf => &_PLURAL_RANGES_0,
+            Locale::ksh => &_PLURAL_RANGES_0,
+            Locale::ku => &_PLURAL_RANGES_0,
+            Locale::ku_Arab => &_PLURAL_RANGES_0,
+            Locale::ku_Arab_IR => &_PLURAL_RANGES_0,
+            Locale::ku_Latn => &_PLURAL_RANGES_0,
+            Locale::ku_Latn_IQ => &_PLURAL_RANGES_0,
+            Locale::ku_Latn_SY => &_PLURAL_RANGES_0,
+            Locale::ku_TR => &_PLURAL_RANGES_0,
+            Locale::kw => &_PLURAL_RANGES_0,
+            Locale::kxv => &_PLURAL_RANGES_0,
+            Locale::kxv_Deva => &_PLURAL_RANGES_0,
+            Locale::kxv_Latn => &_PLURAL_RANGES_0,
+            Locale::kxv_Orya => &_PLURAL_RANGES_0,
+            Locale::kxv_Telu => &_PLURAL_RANGES_0,
+            Locale::ky => &_PLURAL_RANGES_5,
+            Locale::la => &_PLURAL_RANGES_0,
+            Locale::lag => &_PLURAL_RANGES_0,
+            Locale::lb => &_PLURAL_RANGES_0,
+            Locale::lg => &_PLURAL_RANGES_0,
+            Locale::lij => &_PLURAL_RANGES_5,
+            Locale::lkt => &_PLURAL_RANGES_0,
+            Locale::lld => &_PLURAL_RANGES_0,
+            Locale::lmo => &_PLURAL_RANGES_0,
+            Locale::ln => &_PLURAL_RANGES_0,
+            Locale::ln_AO => &_PLURAL_RANGES_0,
+            Locale::ln_CF => &_PLURAL_RANGES_0,
+            Locale::ln_CG => &_PLURAL_RANGES_0,
+            Locale::lo => &_PLURAL_RANGES_13,
+            Locale::lrc => &_PLURAL_RANGES_0,
+            Locale::lrc_IQ => &_PLURAL_RANGES_0,
+            Locale::lt => &_PLURAL_RANGES_6,
+            Locale::ltg => &_PLURAL_RANGES_0,
+            Locale::lu => &_PLURAL_RANGES_0,
+            Locale::luo => &_PLURAL_RANGES_0,
+            Locale::luy => &_PLURAL_RANGES_0,
+            Locale::lv => &_PLURAL_RANGES_15,
+            Locale::lzz => &_PLURAL_RANGES_0,
+            Locale::mai => &_PLURAL_RANGES_0,
+            Locale::mas => &_PLURAL_RANGES_0,
+            Locale::mas_TZ => &_PLURAL_RANGES_0,
+            Locale::mdf => &_PLURAL_RANGES_0,
+            Locale::mer => &_PLURAL_RANGES_0,
+            Locale::mfe => &_PLURAL_RANGES_0,
+            Locale::mg => &_PLURAL_RANGES_0,
+            Locale::mgh => &_PLURAL_RANGES_0,
+            Locale::mgo => &_PLURAL_RANGES_0,
+            Locale::mhn => &_PLURAL_RANGES_0,
+            Locale::mi => &_PLURAL_RANGES_0,
+            Locale::mic => &_PLURAL_RANGES_0,
+            Locale::mk => &_PLURAL_RANGES_16,
+            Locale::ml => &_PLURAL_RANGES_5,
+            Locale::mn => &_PLURAL_RANGES_5,
+            Locale::mn_Mong => &_PLURAL_RANGES_5,
+            Locale::mn_Mong_MN => &_PLURAL_RANGES_5,
+            Locale::mni => &_PLURAL_RANGES_0,
+            Locale::mni_Beng => &_PLURAL_RANGES_0,
+            Locale::mni_Mtei => &_PLURAL_RANGES_0,
+            Locale::moh => &_PLURAL_RANGES_0,
+            Locale::mr => &_PLURAL_RANGES_3,
+            Locale::ms => &_PLURAL_RANGES_13,
+            Locale::ms_Arab => &_PLURAL_RANGES_13,
+            Locale::ms_Arab_BN => &_PLURAL_RANGES_13,
+            Locale::ms_BN => &_PLURAL_RANGES_13,
+            Locale::ms_ID => &_PLURAL_RANGES_13,
+            Locale::ms_SG => &_PLURAL_RANGES_13,
+            Locale::mt => &_PLURAL_RANGES_0,
+            Locale::mua => &_PLURAL_RANGES_0,
+            Locale::mus => &_PLURAL_RANGES_0,
+            Locale::mww => &_PLURAL_RANGES_0,
+            Locale::mww_Hmnp => &_PLURAL_RANGES_0,
+            Locale::my => &_PLURAL_RANGES_13,
+            Locale::myv => &_PLURAL_RANGES_0,
+            Locale::mzn => &_PLURAL_RANGES_0,
+            Locale::naq => &_PLURAL_RANGES_0,
+            Locale::nb => &_PLURAL_RANGES_1,
+            Locale::nb_SJ => &_PLURAL_RANGES_1,
+            Locale::nd => &_PLURAL_RANGES_0,
+            Locale::nds => &_PLURAL_RANGES_0,
+            Locale::nds_NL => &_PLURAL_RANGES_0,
+            Locale::ne => &_PLURAL_RANGES_5,
+            Locale::ne_IN => &_PLURAL_RANGES_5,
+            Locale::nl => &_PLURAL_RANGES_5,
+            Locale::nl_AW => &_PLURAL_RANGES_5,
+            Locale::nl_BE => &_PLURAL_RANGES_5,
+            Locale::nl_BQ => &_PLURAL_RANGES_5,
+            Locale::nl_CW => &_PLURAL_RANGES_5,
+            Locale::nl_SR => &_PLURAL_RANGES_5,
+            Locale::nl_SX => &_PLURAL_RANGES_5,
+            Locale::nmg => &_PLURAL_RANGES_0,
+            Locale::nn => &_PLURAL_RANGES_0,
+            Locale::nnh => &_PLURAL_RANGES_0,
+            Locale::no => &_PLURAL_RANGES_1,
+            Locale::nqo => &_PLURAL_RANGES_0,
+            Locale::nr => &_PLURAL_RANGES_0,
+            Locale::nso => &_PLURAL_RANGES_0,
+            Locale::nus => &_PLURAL_RANGES_0,
+            Locale::nv => &_PLURAL_RANGES_0,
+            Locale::ny => &_PLURAL_RANGES_0,
+            Locale::nyn => &_PLURAL_RANGES_0,
+            Locale::oc => &_PLURAL_RANGES_0,
+            Locale::oc_ES => &_PLURAL_RANGES_0,
+            Locale::oka => &_PLURAL_RANGES_0,
+            Locale::oka_US => &_PLURAL_RANGES_0,
+            Locale::om => &_PLURAL_RANGES_0,
+            Locale::om_KE => &_PLURAL_RANGES_0,
+            Locale::or => &_PLURAL_RANGES_2,
+            Locale::os => &_PLURAL_RANGES_0,
+            Locale::os_RU => &_PLURAL_RANGES_0,
+            Locale::osa => &_PLURAL_RANGES_0,
+            Locale::pa => &_PLURAL_RANGES_10,
+            Locale::pa_Arab => &_PLURAL_RANGES_10,
+            Locale::pa_Guru => &_PLURAL_RANGES_10,
+            Locale::pap => &_PLURAL_RANGES_0,
+            Locale::pap_AW => &_PLURAL_RANGES_0,
+            Locale::pcm => &_PLURAL_RANGES_1,
+            Locale::pi => &_PLURAL_RANGES_0,
+            Locale::pi_Latn => &_PLURAL_RANGES_0,
+            Locale::pis => &_PLURAL_RANGES_0,
+            Locale::pl => &_PLURAL_RANGES_8,
+            Locale::pms => &_PLURAL_RANGES_0,
+            Locale::prg => &_PLURAL_RANGES_0,
+            Locale::ps => &_PLURAL_RANGES_3,
+            Locale::ps_PK => &_PLURAL_RANGES_3,
+            Locale::pt => &_PLURAL_RANGES_3,
+            Locale::pt_AO => &_PLURAL_RANGES_3,
+            Locale::pt_CH => &_PLURAL_RANGES_3,
+            Locale::pt_CV => &_PLURAL_RANGES_3,
+            Locale::pt_GQ => &_PLURAL_RANGES_3,
+            Locale::pt_GW => &_PLURAL_RANGES_3,
+            Locale::pt_LU => &_PLURAL_RANGES_3,
+            Locale::pt_MO => &_PLURAL_RANGES_3,
+            Locale::pt_MZ => &_PLURAL_RANGES_3,
+            Locale::pt_PT => &_PLURAL_RANGES_3,
+            Locale::pt_ST => &_PLURAL_RANGES_3,
+            Locale::pt_TL => &_PLURAL_RANGES_3,
+            Locale::qu => &_PLURAL_RANGES_0,
+            Locale::qu_BO => &_PLURAL_RANGES_0,
+            Locale::qu_EC => &_PLURAL_RANGES_0,
+            Locale::quc => &_PLURAL_RANGES_0,
+            Locale::raj => &_PLURAL_RANGES_0,
+            Locale::rhg => &_PLURAL_RANGES_0,
+            Locale::rhg_Rohg => &_PLURAL_RANGES_0,
+            Locale::rhg_Rohg_BD => &_PLURAL_RANGES_0,
+            Locale::rif => &_PLURAL_RANGES_0,
+            Locale::rm => &_PLURAL_RANGES_0,
+            Locale::rn => &_PLURAL_RANGES_0,
+            Locale::ro => &_PLURAL_RANGES_17,
+            Locale::ro_MD => &_PLURAL_RANGES_17,
+            Locale::rof => &_PLURAL_RANGES_0,
+            Locale::ru => &_PLURAL_RANGES_6,
+            Locale::ru_BY => &_PLURAL_RANGES_6,
+            Locale::ru_KG => &_PLURAL_RANGES_6,
+            Locale::ru_KZ => &_PLURAL_RANGES_6,
+            Locale::ru_MD => &_PLURAL_RANGES_6,
+            Locale::ru_UA => &_PLURAL_RANGES_6,
+            Locale::rw => &_PLURAL_RANGES_0,
+            Locale::rwk => &_PLURAL_RANGES_0,
+            Locale::sa => &_PLURAL_RANGES_0,
+            Locale::sah => &_PLURAL_RANGES_0,
+            Locale::saq => &_PLURAL_RANGES_0,
+            Locale::sat => &_PLURAL_RANGES_0,
+            Locale::sat_Deva => &_PLURAL_RANGES_0,
+            Locale::sat_Olck => &_PLURAL_RANGES_0,
+            Locale::sbp => &_PLURAL_RANGES_0,
+            Locale::sc => &_PLURAL_RANGES_5,
+            Locale::scn => &_PLURAL_RANGES_5,
+            Locale::sd => &_PLURAL_RANGES_2,
+            Locale::sd_Arab => &_PLURAL_RANGES_2,
+            Locale::sd_Deva => &_PLURAL_RANGES_2,
+            Locale::sdh => &_PLURAL_RANGES_0,
+            Locale::sdh_IQ => &_PLURAL_RANGES_0,
+            Locale::se => &_PLURAL_RANGES_0,
+            Locale::se_FI => &_PLURAL_RANGES_0,
+            Locale::se_SE => &_PLURAL_RANGES_0,
+            Locale::seh => &_PLURAL_RANGES_0,
+            Locale::ses => &_PLURAL_RANGES_0,
+            Locale::sg => &_PLURAL_RANGES_0,
+            Locale::sgs => &_PLURAL_RANGES_0,
+            Locale::shi => &_PLURAL_RANGES_0,
+            Locale::shi_Latn => &_PLURAL_RANGES_0,
+            Locale::shi_Tfng => &_PLURAL_RANGES_0,
+            Locale::shn => &_PLURAL_RANGES_0,
+            Locale::shn_TH => &_PLURAL_RANGES_0,
+            Locale::si => &_PLURAL_RANGES_18,
+            Locale::sid => &_PLURAL_RANGES_0,
+            Locale::sk => &_PLURAL_RANGES_8,
+            Locale::skr => &_PLURAL_RANGES_0,
+            Locale::sl => &_PLURAL_RANGES_19,
+            Locale::sma => &_PLURAL_RANGES_0,
+            Locale::sma_NO => &_PLURAL_RANGES_0,
+            Locale::smj => &_PLURAL_RANGES_0,
+            Locale::smj_NO => &_PLURAL_RANGES_0,
+            Locale::smn => &_PLURAL_RANGES_0,
+            Locale::sms => &_PLURAL_RANGES_0,
+            Locale::sn => &_PLURAL_RANGES_0,
+            Locale::so => &_PLURAL_RANGES_0,
+            Locale::so_DJ => &_PLURAL_RANGES_0,
+            Locale::so_ET => &_PLURAL_RANGES_0,
+            Locale::so_KE => &_PLURAL_RANGES_0,
+            Locale::sq => &_PLURAL_RANGES_5,
+            Locale::sq_MK => &_PLURAL_RANGES_5,
+            Locale::sq_XK => &_PLURAL_RANGES_5,
+            Locale::sr => &_PLURAL_RANGES_7,
+            Locale::sr_Cyrl => &_PLURAL_RANGES_7,
+            Locale::sr_Cyrl_BA => &_PLURAL_RANGES_7,
+            Locale::sr_Cyrl_ME => &_PLURAL_RANGES_7,
+            Locale::sr_Cyrl_XK => &_PLURAL_RANGES_7,
+            Locale::sr_Latn => &_PLURAL_RANGES_7,
+            Locale::sr_Latn_BA => &_PLURAL_RANGES_7,
+            Locale::sr_Latn_ME => &_PLURAL_RANGES_7,
+            Locale::sr_Latn_XK => &_PLURAL_RANGES_7,
+            Locale::ss => &_PLURAL_RANGES_0,
+            Locale::ss_SZ => &_PLURAL_RANGES_0,
+            Locale::ssy => &_PLURAL_RANGES_0,
+            Locale::st => &_PLURAL_RANGES_0,
+            Locale::st_LS => &_PLURAL_RANGES_0,
+            Locale::su => &_PLURAL_RANGES_0,
+            Locale::su_Latn => &_PLURAL_RANGES_0,
+            Locale::suz => &_PLURAL_RANGES_0,
+            Locale::suz_Deva => &_PLURAL_RANGES_0,
+            Locale::suz_Sunu => &_PLURAL_RANGES_0,
+            Locale::sv => &_PLURAL_RANGES_1,
+            Locale::sv_AX => &_PLURAL_RANGES_1,
+            Locale::sv_FI => &_PLURAL_RANGES_1,
+            Locale::sw => &_PLURAL_RANGES_5,
+            Locale::sw_CD => &_PLURAL_RANGES_5,
+            Locale::sw_KE => &_PLURAL_RANGES_5,
+            Locale::sw_UG => &_PLURAL_RANGES_5,
+            Locale::syr => &_PLURAL_RANGES_0,
+            Locale::syr_SY => &_PLURAL_RANGES_0,
+            Locale::szl => &_PLURAL_RANGES_0,
+            Locale::ta => &_PLURAL_RANGES_5,
+            Locale::ta_LK => &_PLURAL_RANGES_5,
+            Locale::ta_MY => &_PLURAL_RANGES_5,
+            Locale::ta_SG => &_PLURAL_RANGES_5,
+            Locale::te => &_PLURAL_RANGES_5,
+            Locale::teo => &_PLURAL_RANGES_0,
+            Locale::teo_KE => &_PLURAL_RANGES_0,
+            Locale::tg => &_PLURAL_RANGES_0,
+            Locale::th => &_PLURAL_RANGES_13,
+            Locale::ti => &_PLURAL_RANGES_0,
+            Locale::ti_ER => &_PLURAL_RANGES_0,
+            Locale::tig => &_PLURAL_RANGES_0,
+            Locale::tk => &_PLURAL_RANGES_5,
+            Locale::tn => &_PLURAL_RANGES_0,
+            Locale::tn_BW => &_PLURAL_RANGES_0,
+            Locale::to => &_PLURAL_RANGES_0,
+            Locale::tok => &_PLURAL_RANGES_0,
+            Locale::tpi => &_PLURAL_RANGES_0,
+            Locale::tr => &_PLURAL_RANGES_5,
+            Locale::tr_CY => &_PLURAL_RANGES_5,
+            Locale::trv => &_PLURAL_RANGES_0,
+            Locale::trw => &_PLURAL_RANGES_0,
+            Locale::ts => &_PLURAL_RANGES_0,
+            Locale::tt => &_PLURAL_RANGES_0,
+            Locale::twq => &_PLURAL_RANGES_0,
+            Locale::tyv => &_PLURAL_RANGES_0,
+            Locale::tzm => &_PLURAL_RANGES_0,
+            Locale::ug => &_PLURAL_RANGES_5,
+            Locale::uk => &_PLURAL_RANGES_6,
+            Locale::und => &_PLURAL_RANGES_0,
+            Locale::ur => &_PLURAL_RANGES_1,
+            Locale::ur_IN => &_PLURAL_RANGES_1,
+            Locale::uz => &_PLURAL_RANGES_5,
+            Locale::uz_Arab => &_PLURAL_RANGES_5,
+            Locale::uz_Cyrl => &_PLURAL_RANGES_5,
+            Locale::uz_Latn => &_PLURAL_RANGES_5,
+            Locale::vai => &_PLURAL_RANGES_0,
+            Locale::vai_Latn => &_PLURAL_RANGES_0,
+            Locale::vai_Vaii => &_PLURAL_RANGES_0,
+            Locale::ve => &_PLURAL_RANGES_0,
+            Locale::vec => &_PLURAL_RANGES_0,
+            Locale::vi => &_PLURAL_RANGES_13,
+            Locale::vmw => &_PLURAL_RANGES_0,
+            Locale::vo => &_PLURAL_RANGES_0,
+            Locale::vun => &_PLURAL_RANGES_0,
+            Locale::wa => &_PLURAL_RANGES_0,
+            Locale::wae => &_PLURAL_RANGES_0,
+            Locale::wal => &_PLURAL_RANGES_0,
+            Locale::wbp => &_PLURAL_RANGES_0,
+            Locale::wo => &_PLURAL_RANGES_0,
+            Locale::xh => &_PLURAL_RANGES_0,
+            Locale::xnr => &_PLURAL_RANGES_0,
+            Locale::xog => &_PLURAL_RANGES_0,
+            Locale::yav => &_PLURAL_RANGES_0,
+            Locale::yi => &_PLURAL_RANGES_0,
+            Locale::yo => &_PLURAL_RANGES_0,
+            Locale::yo_BJ => &_PLURAL_RANGES_0,
+            Locale::yrl => &_PLURAL_RANGES_0,
+            Locale::yrl_CO => &_PLURAL_RANGES_0,
+            Locale::yrl_VE => &_PLURAL_RANGES_0,
+            Locale::yue => &_PLURAL_RANGES_13,
+            Locale::yue_Hans => &_PLURAL_RANGES_13,
+            Locale::yue_Hant => &_PLURAL_RANGES_13,
+            Locale::yue_Hant_CN => &_PLURAL_RANGES_13,
+            Locale::yue_Hant_MO => &_PLURAL_RANGES_13,
+            Locale::za => &_PLURAL_RANGES_0,
+            Locale::zgh => &_PLURAL_RANGES_0,
+            Locale::zh => &_PLURAL_RANGES_13,
+            Locale::zh_Hans => &_PLURAL_RANGES_13,
+            Locale::zh_Hans_HK => &_PLURAL_RANGES_13,
+            Locale::zh_Hans_MO => &_PLURAL_RANGES_13,
+            Locale::zh_Hans_MY => &_PLURAL_RANGES_13,
+            Locale::zh_Hans_SG => &_PLURAL_RANGES_13,
+            Locale::zh_Hant => &_PLURAL_RANGES_13,
+            Locale::zh_Hant_HK => &_PLURAL_RANGES_13,
+            Locale::zh_Hant_MO => &_PLURAL_RANGES_13,
+            Locale::zh_Hant_MY => &_PLURAL_RANGES_13,
+            Locale::zh_Latn => &_PLURAL_RANGES_13,
+            Locale::zu => &_PLURAL_RANGES_3,
+        }
+    }
+
+    /// Returns the ordinal plural category of a number, e.g. `Two` for
+    /// "2nd" and `Few` for "23rd" in English.
+    pub fn ordinal_category(&self, operands: &PluralOperands) -> PluralCategory {
+        match self {
+            Locale::aa => _ordinal_0(operands),
+            Locale::aa_DJ => _ordinal_0(operands),
+            Locale::aa_ER => _ordinal_0(operands),
+            Locale::ab => _ordinal_0(operands),
+            Locale::af => _ordinal_0(operands),
+            Locale::af_NA => _ordinal_0(operands),
+            Locale::agq => _ordinal_0(operands),
+            Locale::ak => _ordinal_0(operands),
+            Locale::am => _ordinal_0(operands),
+            Locale::an => _ordinal_0(operands),
+            Locale::ann => _ordinal_0(operands),
+            Locale::apc => _ordinal_0(operands),
+            Locale::ar => _ordinal_0(operands),
+            Locale::ar_AE => _ordinal_0(operands),
+            Locale::ar_BH => _ordinal_0(operands),
+            Locale::ar_DJ => _ordinal_0(operands),
+            Locale::ar_DZ => _ordinal_0(operands),
+            Locale::ar_EG => _ordinal_0(operands),
+            Locale::ar_EH => _ordinal_0(operands),
+            Locale::ar_ER => _ordinal_0(operands),
+            Locale::ar_IL => _ordinal_0(operands),
+            Locale::ar_IQ => _ordinal_0(operands),
+            Locale::ar_JO => _ordinal_0(operands),
+            Locale::ar_KM => _ordinal_0(operands),
+            Locale::ar_KW => _ordinal_0(operands),
+            Locale::ar_LB => _ordinal_0(operands),
+            Locale::ar_LY => _ordinal_0(operands),
+            Locale::ar_MA => _ordinal_0(operands),
+            Locale::ar_MR => _ordinal_0(operands),
+            Locale::ar_OM => _ordinal_0(operands),
+            Locale::ar_PS => _ordinal_0(operands),
+            Locale::ar_QA => _ordinal_0(operands),
+            Locale::ar_SA => _ordinal_0(operands),
+            Locale::ar_SD => _ordinal_0(operands),
+            Locale::ar_SO => _ordinal_0(operands),
+            Locale::ar_SS => _ordinal_0(operands),
+            Locale::ar_SY => _ordinal_0(operands),
+            Locale::ar_TD => _ordinal_0(operands),
+            Locale::ar_TN => _ordinal_0(operands),
+            Locale::ar_YE => _ordinal_0(operands),
+            Locale::arn => _ordinal_0(operands),
+            Locale::as_ => _ordinal_1(operands),
+            Locale::asa => _ordinal_0(operands),
+            Locale::ast => _ordinal_0(operands),
+            Locale::az => _ordinal_2(operands),
+            Locale::az_Arab => _ordinal_2(operands),
+            Locale::az_Arab_IQ => _ordinal_2(operands),
+            Locale::az_Arab_TR => _ordinal_2(operands),
+            Locale::az_Cyrl => _ordinal_2(operands),
+            Locale::az_Latn => _ordinal_2(operands),
+            Locale::ba => _ordinal_0(operands),
+            Locale::bal => _ordinal_3(operands),
+            Locale::bal_Arab => _ordinal_3(operands),
+            Locale::bal_Latn => _ordinal_3(operands),
+            Locale::bas => _ordinal_0(operands),
+            Locale::be => _ordinal_4(operands),
+            Locale::be_tarask => _ordinal_4(operands),
+            Locale::bem => _ordinal_0(operands),
+            Locale::bew => _ordinal_0(operands),
+            Locale::bez => _ordinal_0(operands),
+            Locale::bg => _ordinal_0(operands),
+            Locale::bgc => _ordinal_0(operands),
+            Locale::bgn => _ordinal_0(operands),
+            Locale::bgn_AE => _ordinal_0(operands),
+            Locale::bgn_AF => _ordinal_0(operands),
+            Locale::bgn_IR => _ordinal_0(operands),
+            Locale::bgn_OM => _ordinal_0(operands),
+            Locale::bho => _ordinal_0(operands),
+            Locale::blo => _ordinal_0(operands),
+            Locale::blt => _ordinal_0(operands),
+            Locale::bm => _ordinal_0(operands),
+            Locale::bm_Nkoo => _ordinal_0(operands),
+            Locale::bn => _ordinal_1(operands),
+            Locale::bn_IN => _ordinal_1(operands),
+            Locale::bo => _ordinal_0(operands),
+            Locale::bo_IN => _ordinal_0(operands),
+            Locale::bqi => _ordinal_0(operands),
+            Locale::br => _ordinal_0(operands),
+            Locale::brx => _ordinal_0(operands),
+            Locale::bs => _ordinal_0(operands),
+            Locale::bs_Cyrl => _ordinal_0(operands),
+            Locale::bs_Latn => _ordinal_0(operands),
+            Locale::bss => _ordinal_0(operands),
+            Locale::bua => _ordinal_0(operands),
+            Locale::byn => _ordinal_0(operands),
+            Locale::ca => _ordinal_5(operands),
+            Locale::ca_AD => _ordinal_5(operands),
+            Locale::ca_ES_valencia => _ordinal_5(operands),
+            Locale::ca_FR => _ordinal_5(operands),
+            Locale::ca_IT => _ordinal_5(operands),
+            Locale::cad => _ordinal_0(operands),
+            Locale::cch => _ordinal_0(operands),
+            Locale::ccp => _ordinal_0(operands),
+            Locale::ccp_IN => _ordinal_0(operands),
+            Locale::ce => _ordinal_0(operands),
+            Locale::ceb => _ordinal_0(operands),
+            Locale::cgg => _ordinal_0(operands),
+            Locale::cho => _ordinal_0(operands),
+            Locale::chr => _ordinal_0(operands),
+            Locale::cic => _ordinal_0(operands),
+            Locale::ckb => _ordinal_0(operands),
+            Locale::ckb_IR => _ordinal_0(operands),
+            Locale::co => _ordinal_0(operands),
+            Locale::cop => _ordinal_0(operands),
+            Locale::cs => _ordinal_0(operands),
+            Locale::csw => _ordinal_0(operands),
+            Locale::cu => _ordinal_0(operands),
+            Locale::cv => _ordinal_0(operands),
+            Locale::cy => _ordinal_6(operands),
+            Locale::da => _ordinal_0(operands),
+            Locale::da_GL => _ordinal_0(operands),
+            Locale::dav => _ordinal_0(operands),
+            Locale::de => _ordinal_0(operands),
+            Locale::de_AT => _ordinal_0(operands),
+            Locale::de_BE => _ordinal_0(operands),
+            Locale::de_CH => _ordinal_0(operands),
+            Locale::de_IT => _ordinal_0(operands),
+            Locale::de_LI => _ordinal_0(operands),
+            Locale::de_LU => _ordinal_0(operands),
+            Locale::dje => _ordinal_0(operands),
+            Locale::doi => _ordinal_0(operands),
+            Locale::dsb => _ordinal_0(operands),
+            Locale::dua => _ordinal_0(operands),
+            Locale::dv => _ordinal_0(operands),
+            Locale::dyo => _ordinal_0(operands),
+            Locale::dz => _ordinal_0(operands),
+            Locale::ebu => _ordinal_0(operands),
+            Locale::ee => _ordinal_0(operands),
+            Locale::ee_TG => _ordinal_0(operands),
+            Locale::el => _ordinal_0(operands),
+            Locale::el_CY => _ordinal_0(operands),
+            Locale::el_polyton => _ordinal_0(operands),
+            Locale::en => _ordinal_7(operands),
+            Locale::en_001 => _ordinal_7(operands),
+            Locale::en_150 => _ordinal_7(operands),
+            Locale::en_AE => _ordinal_7(operands),
+            Locale::en_AG => _ordinal_7(operands),
+            Locale::en_AI => _ordinal_7(operands),
+            Locale::en_AS => _ordinal_7(operands),
+            Locale::en_AT => _ordinal_7(operands),
+            Locale::en_AU => _ordinal_7(operands),
+            Locale::en_BB => _ordinal_7(operands),
+            Locale::en_BE => _ordinal_7(operands),
+            Locale::en_BI => _ordinal_7(operands),
+            Locale::en_BM => _ordinal_7(operands),
+            Locale::en_BS => _ordinal_7(operands),
+            Locale::en_BW => _ordinal_7(operands),
+            Locale::en_BZ => _ordinal_7(operands),
+            Locale::en_CA => _ordinal_7(operands),
+            Locale::en_CC => _ordinal_7(operands),
+            Locale::en_CH => _ordinal_7(operands),
+            Locale::en_CK => _ordinal_7(operands),
+            Locale::en_CM => _ordinal_7(operands),
+            Locale::en_CX => _ordinal_7(operands),
+            Locale::en_CY => _ordinal_7(operands),
+            Locale::en_CZ => _ordinal_7(operands),
+            Locale::en_DE => _ordinal_7(operands),
+            Locale::en_DG => _ordinal_7(operands),
+            Locale::en_DK => _ordinal_7(operands),
+            Locale::en_DM => _ordinal_7(operands),
+            Locale::en_Dsrt => _ordinal_7(operands),
+            Locale::en_EE => _ordinal_7(operands),
+            Locale::en_ER => _ordinal_7(operands),
+            Locale::en_ES => _ordinal_7(operands),
+            Locale::en_FI => _ordinal_7(operands),
+            Locale::en_FJ => _ordinal_7(operands),
+            Locale::en_FK => _ordinal_7(operands),
+            Locale::en_FM => _ordinal_7(operands),
+            Locale::en_FR => _ordinal_7(operands),
+            Locale::en_GB => _ordinal_7(operands),
+            Locale::en_GD => _ordinal_7(operands),
+            Locale::en_GE => _ordinal_7(operands),
+            Locale::en_GG => _ordinal_7(operands),
+            Locale::en_GH => _ordinal_7(operands),
+            Locale::en_GI => _ordinal_7(operands),
+            Locale::en_GM => _ordinal_7(operands),
+            Locale::en_GS => _ordinal_7(operands),
+            Locale::en_GU => _ordinal_7(operands),
+            Locale::en_GY => _ordinal_7(operands),
+            Locale::en_HK => _ordinal_7(operands),
+            Locale::en_HU => _ordinal_7(operands),
+            Locale::en_ID => _ordinal_7(operands),
+            Locale::en_IE => _ordinal_7(operands),
+            Locale::en_IL => _ordinal_7(operands),
+            Locale::en_IM => _ordinal_7(operands),
+            Locale::en_IN => _ordinal_7(operands),
+            Locale::en_IO => _ordinal_7(operands),
+            Locale::en_IT => _ordinal_7(operands),
+            Locale::en_JE => _ordinal_7(operands),
+            Locale::en_JM => _ordinal_7(operands),
+            Locale::en_JP => _ordinal_7(operands),
+            Locale::en_KE => _ordinal_7(operands),
+            Locale::en_KI => _ordinal_7(operands),
+            Locale::en_KN => _ordinal_7(operands),
+            Locale::en_KY => _ordinal_7(operands),
+            Locale::en_LC => _ordinal_7(operands),
+            Locale::en_LR => _ordinal_7(operands),
+            Locale::en_LS => _ordinal_7(operands),
+            Locale::en_LT => _ordinal_7(operands),
+            Locale::en_LV => _ordinal_7(operands),
+            Locale::en_MG => _ordinal_7(operands),
+            Locale::en_MH => _ordinal_7(operands),
+            Locale::en_MO => _ordinal_7(operands),
+            Locale::en_MP => _ordinal_7(operands),
+            Locale::en_MS => _ordinal_7(operands),
+            Locale::en_MT => _ordinal_7(operands),
+            Locale::en_MU => _ordinal_7(operands),
+            Locale::en_MV => _ordinal_7(operands),
+            Locale::en_MW => _ordinal_7(operands),
+            Locale::en_MY => _ordinal_7(operands),
+            Locale::en_NA => _ordinal_7(operands),
+            Locale::en_NF => _ordinal_7(operands),
+            Locale::en_NG => _ordinal_7(operands),
+            Locale::en_NL => _ordinal_7(operands),
+            Locale::en_NO => _ordinal_7(operands),
+            Locale::en_NR => _ordinal_7(operands),
+            Locale::en_NU => _ordinal_7(operands),
+            Locale::en_NZ => _ordinal_7(operands),
+            Locale::en_PG => _ordinal_7(operands),
+            Locale::en_PH => _ordinal_7(operands),
+            Locale::en_PK => _ordinal_7(operands),
+            Locale::en_PL => _ordinal_7(operands),
+            Locale::en_PN => _ordinal_7(operands),
+            Locale::en_PR => _ordinal_7(operands),
+            Locale::en_PT => _ordinal_7(operands),
+            Locale::en_PW => _ordinal_7(operands),
+            Locale::en_RO => _ordinal_7(operands),
+            Locale::en_RW => _ordinal_7(operands),
+            Locale::en_SB => _ordinal_7(operands),
+            Locale::en_SC => _ordinal_7(operands),
+            Locale::en_SD => _ordinal_7(operands),
+            Locale::en_SE => _ordinal_7(operands),
+            Locale::en_SG => _ordinal_7(operands),
+            Locale::en_SH => _ordinal_7(operands),
+            Locale::en_SI => _ordinal_7(operands),
+            Locale::en_SK => _ordinal_7(operands),
+            Locale::en_SL => _ordinal_7(operands),
+            Locale::en_SS => _ordinal_7(operands),
+            Locale::en_SX => _ordinal_7(operands),
+            Locale::en_SZ => _ordinal_7(operands),
+            Locale::en_Shaw => _ordinal_7(operands),
+            Locale::en_TC => _ordinal_7(operands),
+            Locale::en_TK => _ordinal_7(operands),
+            Locale::en_TO => _ordinal_7(operands),
+            Locale::en_TT => _ordinal_7(operands),
+            Locale::en_TV => _ordinal_7(operands),
+            Locale::en_TZ => _ordinal_7(operands),
+            Locale::en_UA => _ordinal_7(operands),
+            Locale::en_UG => _ordinal_7(operands),
+            Locale::en_UM => _ordinal_7(operands),
+            Locale::en_VC => _ordinal_7(operands),
+            Locale::en_VG => _ordinal_7(operands),
+            Locale::en_VI => _ordinal_7(operands),
+            Locale::en_VU => _ordinal_7(operands),
+            Locale::en_WS => _ordinal_7(operands),
+            Locale::en_ZA => _ordinal_7(operands),
+            Locale::en_ZM => _ordinal_7(operands),
+            Locale::en_ZW => _ordinal_7(operands),
+            Locale::eo => _ordinal_0(operands),
+            Locale::es => _ordinal_0(operands),
+            Locale::es_419 => _ordinal_0(operands),
+            Locale::es_AR => _ordinal_0(operands),
+            Locale::es_BO => _ordinal_0(operands),
+            Locale::es_BR => _ordinal_0(operands),
+            Locale::es_BZ => _ordinal_0(operands),
+            Locale::es_CL => _ordinal_0(operands),
+            Locale::es_CO => _ordinal_0(operands),
+            Locale::es_CR => _ordinal_0(operands),
+            Locale::es_CU => _ordinal_0(operands),
+            Locale::es_DO => _ordinal_0(operands),
+            Locale::es_EA => _ordinal_0(operands),
+            Locale::es_EC => _ordinal_0(operands),
+            Locale::es_GQ => _ordinal_0(operands),
+            Locale::es_GT => _ordinal_0(operands),
+            Locale::es_HN => _ordinal_0(operands),
+            Locale::es_IC => _ordinal_0(operands),
+            Locale::es_MX => _ordinal_0(operands),
+            Locale::es_NI => _ordinal_0(operands),
+            Locale::es_PA => _ordinal_0(operands),
+            Locale::es_PE => _ordinal_0(operands),
+            Locale::es_PH => _ordinal_0(operands),
+            Locale::es_PR => _ordinal_0(operands),
+            Locale::es_PY => _ordinal_0(operands),
+            Locale::es_SV => _ordinal_0(operands),
+            Locale::es_US => _ordinal_0(operands),
+            Locale::es_UY => _ordinal_0(operands),
+            Locale::es_VE => _ordinal_0(operands),
+            Locale::et => _ordinal_0(operands),
+            Locale::eu => _ordinal_0(operands),
+            Locale::ewo => _ordinal_0(operands),
+            Locale::fa => _ordinal_0(operands),
+            Locale::fa_AF => _ordinal_0(operands),
+            Locale::ff => _ordinal_0(operands),
+            Locale::ff_Adlm => _ordinal_0(operands),
+            Locale::ff_Adlm_BF => _ordinal_0(operands),
+            Locale::ff_Adlm_CM => _ordinal_0(operands),
+            Locale::ff_Adlm_GH => _ordinal_0(operands),
+            Locale::ff_Adlm_GM => _ordinal_0(operands),
+            Locale::ff_Adlm_GW => _ordinal_0(operands),
+            Locale::ff_Adlm_LR => _ordinal_0(operands),
+            Locale::ff_Adlm_MR => _ordinal_0(operands),
+            Locale::ff_Adlm_NE => _ordinal_0(operands),
+            Locale::ff_Adlm_NG => _ordinal_0(operands),
+            Locale::ff_Adlm_SL => _ordinal_0(operands),
+            Locale::ff_Adlm_SN => _ordinal_0(operands),
+            Locale::ff_Latn => _ordinal_0(operands),
+            Locale::ff_Latn_BF => _ordinal_0(operands),
+            Locale::ff_Latn_CM => _ordinal_0(operands),
+            Locale::ff_Latn_GH => _ordinal_0(operands),
+            Locale::ff_Latn_GM => _ordinal_0(operands),
+            Locale::ff_Latn_GN => _ordinal_0(operands),
+            Locale::ff_Latn_GW => _ordinal_0(operands),
+            Locale::ff_Latn_LR => _ordinal_0(operands),
+            Locale::ff_Latn_MR => _ordinal_0(operands),
+            Locale::ff_Latn_NE => _ordinal_0(operands),
+            Locale::ff_Latn_NG => _ordinal_0(operands),
+            Locale::ff_Latn_SL => _ordinal_0(operands),
+            Locale::fi => _ordinal_0(operands),
+            Locale::fil => _ordinal_3(operands),
+            Locale::fo => _ordinal_0(operands),
+            Locale::fo_DK => _ordinal_0(operands),
+            Locale::fr => _ordinal_3(operands),
+            Locale::fr_BE => _ordinal_3(operands),
+            Locale::fr_BF => _ordinal_3(operands),
+            Locale::fr_BI => _ordinal_3(operands),
+            Locale::fr_BJ => _ordinal_3(operands),
+            Locale::fr_BL => _ordinal_3(operands),
+            Locale::fr_CA => _ordinal_3(operands),
+            Locale::fr_CD => _ordinal_3(operands),
+            Locale::fr_CF => _ordinal_3(operands),
+            Locale::fr_CG => _ordinal_3(operands),
+            Locale::fr_CH => _ordinal_3(operands),
+            Locale::fr_CI => _ordinal_3(operands),
+            Locale::fr_CM => _ordinal_3(operands),
+            Locale::fr_DJ => _ordinal_3(operands),
+            Locale::fr_DZ => _ordinal_3(operands),
+            Locale::fr_GA => _ordinal_3(operands),
+            Locale::fr_GF => _ordinal_3(operands),
+            Locale::fr_GN => _ordinal_3(operands),
+            Locale::fr_GP => _ordinal_3(operands),
+            Locale::fr_GQ => _ordinal_3(operands),
+            Locale::fr_HT => _ordinal_3(operands),
+            Locale::fr_KM => _ordinal_3(operands),
+            Locale::fr_LU => _ordinal_3(operands),
+            Locale::fr_MA => _ordinal_3(operands),
+            Locale::fr_MC => _ordinal_3(operands),
+            Locale::fr_MF => _ordinal_3(operands),
+            Locale::fr_MG => _ordinal_3(operands),
+            Locale::fr_ML => _ordinal_3(operands),
+            Locale::fr_MQ => _ordinal_3(operands),
+            Locale::fr_MR => _ordinal_3(operands),
+            Locale::fr_MU => _ordinal_3(operands),
+            Locale::fr_NC => _ordinal_3(operands),
+            Locale::fr_NE => _ordinal_3(operands),
+            Locale::fr_PF => _ordinal_3(operands),
+            Locale::fr_PM => _ordinal_3(operands),
+            Locale::fr_RE => _ordinal_3(operands),
+            Locale::fr_RW => _ordinal_3(operands),
+            Locale::fr_SC => _ordinal_3(operands),
+            Locale::fr_SN => _ordinal_3(operands),
+            Locale::fr_SY => _ordinal_3(operands),
+            Locale::fr_TD => _ordinal_3(operands),
+            Locale::fr_TG => _ordinal_3(operands),
+            Locale::fr_TN => _ordinal_3(operands),
+            Locale::fr_VU => _ordinal_3(operands),
+            Locale::fr_WF => _ordinal_3(operands),
+            Locale::fr_YT => _ordinal_3(operands),
+            Locale::frr => _ordinal_0(operands),
+            Locale::fur => _ordinal_0(operands),
+            Locale::fy => _ordinal_0(operands),
+            Locale::ga => _ordinal_3(operands),
+            Locale::ga_GB => _ordinal_3(operands),
+            Locale::gaa => _ordinal_0(operands),
+            Locale::gd => _ordinal_8(operands),
+            Locale::gez => _ordinal_0(operands),
+            Locale::gez_ER => _ordinal_0(operands),
+            Locale::gl => _ordinal_0(operands),
+            Locale::gn => _ordinal_0(operands),
+            Locale::gsw => _ordinal_0(operands),
+            Locale::gsw_FR => _ordinal_0(operands),
+            Locale::gsw_LI => _ordinal_0(operands),
+            Locale::gu => _ordinal_9(operands),
+            Locale::guz => _ordinal_0(operands),
+            Locale::gv => _ordinal_0(operands),
+            Locale::ha => _ordinal_0(operands),
+            Locale::ha_Arab => _ordinal_0(operands),
+            Locale::ha_Arab_SD => _ordinal_0(operands),
+            Locale::ha_GH => _ordinal_0(operands),
+            Locale::ha_NE => _ordinal_0(operands),
+            Locale::haw => _ordinal_0(operands),
+            Locale::he => _ordinal_0(operands),
+            Locale::hi => _ordinal_9(operands),
+            Locale::hi_Latn => _ordinal_9(operands),
+            Locale::hnj => _ordinal_0(operands),
+            Locale::hnj_Hmnp => _ordinal_0(operands),
+            Locale::hr => _ordinal_0(operands),
+            Locale::hr_BA => _ordinal_0(operands),
+            Locale::hsb => _ordinal_0(operands),
+            Locale::ht => _ordinal_0(operands),
+            Locale::hu => _ordinal_10(operands),
+            Locale::hy => _ordinal_3(operands),
+            Locale::ia => _ordinal_0(operands),
+            Locale::id => _ordinal_0(operands),
+            Locale::ie => _ordinal_0(operands),
+            Locale::ig => _ordinal_0(operands),
+            Locale::ii => _ordinal_0(operands),
+            Locale::io => _ordinal_0(operands),
+            Locale::is => _ordinal_0(operands),
+            Locale::it => _ordinal_11(operands),
+            Locale::it_CH => _ordinal_11(operands),
+            Locale::it_SM => _ordinal_11(operands),
+            Locale::it_VA => _ordinal_11(operands),
+            Locale::iu => _ordinal_0(operands),
+            Locale::iu_Latn => _ordinal_0(operands),
+            Locale::ja => _ordinal_0(operands),
+            Locale::jbo => _ordinal_0(operands),
+            Locale::jgo => _ordinal_0(operands),
+            Locale::jmc => _ordinal_0(operands),
+            Locale::jv => _ordinal_0(operands),
+            Locale::ka => _ordinal_12(operands),
+            Locale::kaa => _ordinal_0(operands),
+            Locale::kaa_Cyrl => _ordinal_0(operands),
+            Locale::kaa_Latn => _ordinal_0(operands),
+            Locale::kab => _ordinal_0(operands),
+            Locale::kaj => _ordinal_0(operands),
+            Locale::kam => _ordinal_0(operands),
+            Locale::kcg => _ordinal_0(operands),
+            Locale::kde => _ordinal_0(operands),
+            Locale::kea => _ordinal_0(operands),
+            Locale::kek => _ordinal_0(operands),
+            Locale::ken => _ordinal_0(operands),
+            Locale::kgp => _ordinal_0(operands),
+            Locale::khq => _ordinal_0(operands),
+            Locale::ki => _ordinal_0(operands),
+            Locale::kk => _ordinal_13(operands),
+            Locale::kk_Arab => _ordinal_13(operands),
+            Locale::kk_Cyrl => _ordinal_13(operands),
+            Locale::kk_KZ => _ordinal_13(operands),
+            Locale::kkj => _ordinal_0(operands),
+            Locale::kl => _ordinal_0(operands),
+            Locale::kln => _ordinal_0(operands),
+            Locale::km => _ordinal_0(operands),
+            Locale::kn => _ordinal_0(operands),
+            Locale::ko => _ordinal_0(operands),
+            Locale::ko_CN => _ordinal_0(operands),
+            Locale::ko_KP => _ordinal_0(operands),
+            Locale::kok => _ordinal_0(operands),
+            Locale::kok_Deva => _ordinal_0(operands),
+            Locale::kok_Latn => _ordinal_0(operands),
+            Locale::kpe => _ordinal_0(operands),
+            Locale::kpe_GN => _ordinal_0(operands),
+            Locale::ks => _ordinal_0(operands),
+            Locale::ks_Arab => _ordinal_0(operands),
+            Locale::ks_Deva => _ordinal_0(operands),
+            Locale::ksb => _ordinal_0(operands),
+            Locale::ksf => _ordinal_0(operands),
+            Locale::ksh => _ordinal_0(operands),
+            Locale::ku => _ordinal_0(operands),
+            Locale::ku_Arab => _ordinal_0(operands),
+            Locale::ku_Arab_IR => _ordinal_0(operands),
+            Locale::ku_Latn => _ordinal_0(operands),
+            Locale::ku_Latn_IQ => _ordinal_0(operands),
+            Locale::ku_Latn_SY => _ordinal_0(operands),
+            Locale::ku_TR => _ordinal_0(operands),
+            Locale::kw => _ordinal_14(operands),
+            Locale::kxv => _ordinal_0(operands),
+            Locale::kxv_Deva => _ordinal_0(operands),
+            Locale::kxv_Latn => _ordinal_0(operands),
+            Locale::kxv_Orya => _ordinal_0(operands),
+            Locale::kxv_Telu => _ordinal_0(operands),
+            Locale::ky => _ordinal_0(operands),
+            Locale::la => _ordinal_0(operands),
+            Locale::lag => _ordinal_0(operands),
+            Locale::lb => _ordinal_0(operands),
+            Locale::lg => _ordinal_0(operands),
+            Locale::lij => _ordinal_15(operands),
+            Locale::lkt => _ordinal_0(operands),
+            Locale::lld => _ordinal_0(operands),
+            Locale::lmo => _ordinal_0(operands),
+            Locale::ln => _ordinal_0(operands),
+            Locale::ln_AO => _ordinal_0(operands),
+            Locale::ln_CF => _ordinal_0(operands),
+            Locale::ln_CG => _ordinal_0(operands),
+            Locale::lo => _ordinal_3(operands),
+            Locale::lrc => _ordinal_0(operands),
+            Locale::lrc_IQ => _ordinal_0(operands),
+            Locale::lt => _ordinal_0(operands),
+            Locale::ltg => _ordinal_0(operands),
+            Locale::lu => _ordinal_0(operands),
+            Locale::luo => _ordinal_0(operands),
+            Locale::luy => _ordinal_0(operands),
+            Locale::lv => _ordinal_0(operands),
+            Locale::lzz => _ordinal_0(operands),
+            Locale::mai => _ordinal_0(operands),
+            Locale::mas => _ordinal_0(operands),
+            Locale::mas_TZ => _ordinal_0(operands),
+            Locale::mdf => _ordinal_0(operands),
+            Locale::mer => _ordinal_0(operands),
+            Locale::mfe => _ordinal_0(operands),
+            Locale::mg => _ordinal_0(operands),
+            Locale::mgh => _ordinal_0(operands),
+            Locale::mgo => _ordinal_0(operands),
+            Locale::mhn => _ordinal_0(operands),
+            Locale::mi => _ordinal_0(operands),
+            Locale::mic => _ordinal_0(operands),
+            Locale::mk => _ordinal_16(operands),
+            Locale::ml => _ordinal_0(operands),
+            Locale::mn => _ordinal_0(operands),
+            Locale::mn_Mong => _ordinal_0(operands),
+            Locale::mn_Mong_MN => _ordinal_0(operands),
+            Locale::mni => _ordinal_0(operands),
+            Locale::mni_Beng => _ordinal_0(operands),
+            Locale::mni_Mtei => _ordinal_0(operands),
+            Locale::moh => _ordinal_0(operands),
+            Locale::mr => _ordinal_17(operands),
+            Locale::ms => _ordinal_3(operands),
+            Locale::ms_Arab => _ordinal_3(operands),
+            Locale::ms_Arab_BN => _ordinal_3(operands),
+            Locale::ms_BN => _ordinal_3(operands),
+            Locale::ms_ID => _ordinal_3(operands),
+            Locale::ms_SG => _ordinal_3(operands),
+            Locale::mt => _ordinal_0(operands),
+            Locale::mua => _ordinal_0(operands),
+            Locale::mus => _ordinal_0(operands),
+            Locale::mww => _ordinal_0(operands),
+            Locale::mww_Hmnp => _ordinal_0(operands),
+            Locale::my => _ordinal_0(operands),
+            Locale::myv => _ordinal_0(operands),
+            Locale::mzn => _ordinal_0(operands),
+            Locale::naq => _ordinal_0(operands),
+            Locale::nb => _ordinal_0(operands),
+            Locale::nb_SJ => _ordinal_0(operands),
+            Locale::nd => _ordinal_0(operands),
+            Locale::nds => _ordinal_0(operands),
+            Locale::nds_NL => _ordinal_0(operands),
+            Locale::ne => _ordinal_18(operands),
+            Locale::ne_IN => _ordinal_18(operands),
+            Locale::nl => _ordinal_0(operands),
+            Locale::nl_AW => _ordinal_0(operands),
+            Locale::nl_BE => _ordinal_0(operands),
+            Locale::nl_BQ => _ordinal_0(operands),
+            Locale::nl_CW => _ordinal_0(operands),
+            Locale::nl_SR => _ordinal_0(operands),
+            Locale::nl_SX => _ordinal_0(operands),
+            Locale::nmg => _ordinal_0(operands),
+            Locale::nn => _ordinal_0(operands),
+            Locale::nnh => _ordinal_0(operands),
+            Locale::no => _ordinal_0(operands),
+            Locale::nqo => _ordinal_0(operands),
+            Locale::nr => _ordinal_0(operands),
+            Locale::nso => _ordinal_0(operands),
+            Locale::nus => _ordinal_0(operands),
+            Locale::nv => _ordinal_0(operands),
+            Locale::ny => _ordinal_0(operands),
+            Locale::nyn => _ordinal_0(operands),
+            Locale::oc => _ordinal_0(operands),
+            Locale::oc_ES => _ordinal_0(operands),
+            Locale::oka => _ordinal_0(operands),
+            Locale::oka_US => _ordinal_0(operands),
+            Locale::om => _ordinal_0(operands),
+            Locale::om_KE => _ordinal_0(operands),
+            Locale::or => _ordinal_19(operands),
+            Locale::os => _ordinal_0(operands),
+            Locale::os_RU => _ordinal_0(operands),
+            Locale::osa => _ordinal_0(operands),
+            Locale::pa => _ordinal_0(operands),
+            Locale::pa_Arab => _ordinal_0(operands),
+            Locale::pa_Guru => _ordinal_0(operands),
+            Locale::pap => _ordinal_0(operands),
+            Locale::pap_AW => _ordinal_0(operands),
+            Locale::pcm => _ordinal_0(operands),
+            Locale::pi => _ordinal_0(operands),
+            Locale::pi_Latn => _ordinal_0(operands),
+            Locale::pis => _ordinal_0(operands),
+            Locale::pl => _ordinal_0(operands),
+            Locale::pms => _ordinal_0(operands),
+            Locale::prg => _ordinal_0(operands),
+            Locale::ps => _ordinal_0(operands),
+            Locale::ps_PK => _ordinal_0(operands),
+            Locale::pt => _ordinal_0(operands),
+            Locale::pt_AO => _ordinal_0(operands),
+            Locale::pt_CH => _ordinal_0(operands),
+            Locale::pt_CV => _ordinal_0(operands),
+            Locale::pt_GQ => _ordinal_0(operands),
+            Locale::pt_GW => _ordinal_0(operands),
+            Locale::pt_LU => _ordinal_0(operands),
+            Locale::pt_MO => _ordinal_0(operands),
+            Locale::pt_MZ => _ordinal_0(operands),
+            Locale::pt_PT => _ordinal_0(operands),
+            Locale::pt_ST => _ordinal_0(operands),
+            Locale::pt_TL => _ordinal_0(operands),
+            Locale::qu => _ordinal_0(operands),
+            Locale::qu_BO => _ordinal_0(operands),
+            Locale::qu_EC => _ordinal_0(operands),
+            Locale::quc => _ordinal_0(operands),
+            Locale::raj => _ordinal_0(operands),
+            Locale::rhg => _ordinal_0(operands),
+            Locale::rhg_Rohg => _ordinal_0(operands),
+            Locale::rhg_Rohg_BD => _ordinal_0(operands),
+            Locale::rif => _ordinal_0(operands),
+            Locale::rm => _ordinal_0(operands),
+            Locale::rn => _ordinal_0(operands),
+            Locale::ro => _ordinal_3(operands),
+            Locale::ro_MD => _ordinal_3(operands),
+            Locale::rof => _ordinal_0(operands),
+            Locale::ru => _ordinal_0(operands),
+            Locale::ru_BY => _ordinal_0(operands),
+            Locale::ru_KG => _ordinal_0(operands),
+            Locale::ru_KZ => _ordinal_0(operands),
+            Locale::ru_MD => _ordinal_0(operands),
+            Locale::ru_UA => _ordinal_0(operands),
+            Locale::rw => _ordinal_0(operands),
+            Locale::rwk => _ordinal_0(operands),
+            Locale::sa => _ordinal_0(operands),
+            Locale::sah => _ordinal_0(operands),
+            Locale::saq => _ordinal_0(operands),
+            Locale::sat => _ordinal_0(operands),
+            Locale::sat_Deva => _ordinal_0(operands),
+            Locale::sat_Olck => _ordinal_0(operands),
+            Locale::sbp => _ordinal_0(operands),
+            Locale::sc => _ordinal_11(operands),
+            Locale::scn => _ordinal_11(operands),
+            Locale::sd => _ordinal_0(operands),
+            Locale::sd_Arab => _ordinal_0(operands),
+            Locale::sd_Deva => _ordinal_0(operands),
+            Locale::sdh => _ordinal_0(operands),
+            Locale::sdh_IQ => _ordinal_0(operands),
+            Locale::se => _ordinal_0(operands),
+            Locale::se_FI => _ordinal_0(operands),
+            Locale::se_SE => _ordinal_0(operands),
+            Locale::seh => _ordinal_0(operands),
+            Locale::ses => _ordinal_0(operands),
+            Locale::sg => _ordinal_0(operands),
+            Locale::sgs => _ordinal_0(operands),
+            Locale::shi => _ordinal_0(operands),
+            Locale::shi_Latn => _ordinal_0(operands),
+            Locale::shi_Tfng => _ordinal_0(operands),
+            Locale::shn => _ordinal_0(operands),
+            Locale::shn_TH => _ordinal_0(operands),
+            Locale::si => _ordinal_0(operands),
+            Locale::sid => _ordinal_0(operands),
+            Locale::sk => _ordinal_0(operands),
+            Locale::skr => _ordinal_0(operands),
+            Locale::sl => _ordinal_0(operands),
+            Locale::sma => _ordinal_0(operands),
+            Locale::sma_NO => _ordinal_0(operands),
+            Locale::smj => _ordinal_0(operands),
+            Locale::smj_NO => _ordinal_0(operands),
+            Locale::smn => _ordinal_0(operands),
+            Locale::sms => _ordinal_0(operands),
+            Locale::sn => _ordinal_0(operands),
+            Locale::so => _ordinal_0(operands),
+            Locale::so_DJ => _ordinal_0(operands),
+            Locale::so_ET => _ordinal_0(operands),
+            Locale::so_KE => _ordinal_0(operands),
+            Locale::sq => _ordinal_20(operands),
+            Locale::sq_MK => _ordinal_20(operands),
+            Locale::sq_XK => _ordinal_20(operands),
+            Locale::sr => _ordinal_0(operands),
+            Locale::sr_Cyrl => _ordinal_0(operands),
+            Locale::sr_Cyrl_BA => _ordinal_0(operands),
+            Locale::sr_Cyrl_ME => _ordinal_0(operands),
+            Locale::sr_Cyrl_XK => _ordinal_0(operands),
+            Locale::sr_Latn => _ordinal_0(operands),
+            Locale::sr_Latn_BA => _ordinal_0(operands),
+            Locale::sr_Latn_ME => _ordinal_0(operands),
+            Locale::sr_Latn_XK => _ordinal_0(operands),
+            Locale::ss => _ordinal_0(operands),
+            Locale::ss_SZ => _ordinal_0(operands),
+            Locale::ssy => _ordinal_0(operands),
+            Locale::st => _ordinal_0(operands),
+            Locale::st_LS => _ordinal_0(operands),
+            Locale::su => _ordinal_0(operands),
+            Locale::su_Latn => _ordinal_0(operands),
+            Locale::suz => _ordinal_0(operands),
+            Locale::suz_Deva => _ordinal_0(operands),
+            Locale::suz_Sunu => _ordinal_0(operands),
+            Locale::sv => _ordinal_21(operands),
+            Locale::sv_AX => _ordinal_21(operands),
+            Locale::sv_FI => _ordinal_21(operands),
+            Locale::sw => _ordinal_0(operands),
+            Locale::sw_CD => _ordinal_0(operands),
+            Locale::sw_KE => _ordinal_0(operands),
+            Locale::sw_UG => _ordinal_0(operands),
+            Locale::syr => _ordinal_0(operands),
+            Locale::syr_SY => _ordinal_0(operands),
+            Locale::szl => _ordinal_0(operands),
+            Locale::ta => _ordinal_0(operands),
+            Locale::ta_LK => _ordinal_0(operands),
+            Locale::ta_MY => _ordinal_0(operands),
+            Locale::ta_SG => _ordinal_0(operands),
+            Locale::te => _ordinal_0(operands),
+            Locale::teo => _ordinal_0(operands),
+            Locale::teo_KE => _ordinal_0(operands),
+            Locale::tg => _ordinal_0(operands),
+            Locale::th => _ordinal_0(operands),
+            Locale::ti => _ordinal_0(operands),
+            Locale::ti_ER => _ordinal_0(operands),
+            Locale::tig => _ordinal_0(operands),
+            Locale::tk => _ordinal_22(operands),
+            Locale::tn => _ordinal_0(operands),
+            Locale::tn_BW => _ordinal_0(operands),
+            Locale::to => _ordinal_0(operands),
+            Locale::tok => _ordinal_0(operands),
+            Locale::tpi => _ordinal_0(operands),
+            Locale::tr => _ordinal_0(operands),
+            Locale::tr_CY => _ordinal_0(operands),
+            Locale::trv => _ordinal_0(operands),
+            Locale::trw => _ordinal_0(operands),
+            Locale::ts => _ordinal_0(operands),
+            Locale::tt => _ordinal_0(operands),
+            Locale::twq => _ordinal_0(operands),
+            Locale::tyv => _ordinal_0(operands),
+            Locale::tzm => _ordinal_0(operands),
+            Locale::ug => _ordinal_0(operands),
+            Locale::uk => _ordinal_23(operands),
+            Locale::und => _ordinal_0(operands),
+            Locale::ur => _ordinal_0(operands),
+            Locale::ur_IN => _ordinal_0(operands),
+            Locale::uz => _ordinal_0(operands),
+            Locale::uz_Arab => _ordinal_0(operands),
+            Locale::uz_Cyrl => _ordinal_0(operands),
+            Locale::uz_Latn => _ordinal_0(operands),
+            Locale::vai => _ordinal_0(operands),
+            Locale::vai_Latn => _ordinal_0(operands),
+            Locale::vai_Vaii => _ordinal_0(operands),
+            Locale::ve => _ordinal_0(operands),
+            Locale::vec => _ordinal_11(operands),
+            Locale::vi => _ordinal_3(operands),
+            Locale::vmw => _ordinal_0(operands),
+            Locale::vo => _ordinal_0(operands),
+            Locale::vun => _ordinal_0(operands),
+            Locale::wa => _ordinal_0(operands),
+            Locale::wae => _ordinal_0(operands),
+            Locale::wal => _ordinal_0(operands),
+            Locale::wbp => _ordinal_0(operands),
+            Locale::wo => _ordinal_0(operands),
+            Locale::xh => _ordinal_0(operands),
+            Locale::xnr => _ordinal_0(operands),
+            Locale::xog => _ordinal_0(operands),
+            Locale::yav => _ordinal_0(operands),
+            Locale::yi => _ordinal_0(operands),
+            Locale::yo => _ordinal_0(operands),
+            Locale::yo_BJ => _ordinal_0(operands),
+            Locale::yrl => _ordinal_0(operands),
+            Locale::yrl_CO => _ordinal_0(operands),
+            Locale::yrl_VE => _ordinal_0(operands),
+            Locale::yue => _ordinal_0(operands),
+            Locale::yue_Hans => _ordinal_0(operands),
+            Locale::yue_Hant => _ordinal_0(operands),
+            Locale::yue_Hant_CN => _ordinal_0(operands),
+            Locale::yue_Hant_MO => _ordinal_0(operands),
+            Locale::za => _ordinal_0(operands),
+            Locale::zgh => _ordinal_0(operands),
+            Locale::zh => _ordinal_0(operands),
+            Locale::zh_Hans => _ordinal_0(operands),
+            Locale::zh_Hans_HK => _ordinal_0(operands),
+            Locale::zh_Hans_MO => _ordinal_0(operands),
+            Locale::zh_Hans_MY => _ordinal_0(operands),
+            Locale::zh_Hans_SG => _ordinal_0(operands),
+            Locale::zh_Hant => _ordinal_0(operands),
+            Locale::zh_Hant_HK => _ordinal_0(operands),
+            Locale::zh_Hant_MO => _ordinal_0(operands),
+            Locale::zh_Hant_MY => _ordinal_0(operands),
+            Locale::zh_Latn => _ordinal_0(operands),
+            Locale::zu => _ordinal_0(operands),
+        }
+    }
+}
+
+fn _cardinal_0(_: &PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+
+fn _ordinal_0(_: &PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+
+fn _cardinal_1(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_2(o: &PluralOperands) -> PluralCategory {
+    if o.n.fract() == 0.0 && (0.0..=1.0).contains(&o.n) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_3(o: &PluralOperands) -> PluralCategory {
+    if o.i == 0 || o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_4(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 {
+        return PluralCategory::Zero;
+    }
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    if (o.n % 100.0).fract() == 0.0 && (3.0..=10.0).contains(&(o.n % 100.0)) {
+        return PluralCategory::Few;
+    }
+    if (o.n % 100.0).fract() == 0.0 && (11.0..=99.0).contains(&(o.n % 100.0)) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_1(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.n == 5.0 || o.n == 7.0 || o.n == 8.0 || o.n == 9.0 || o.n == 10.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 || o.n == 3.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 4.0 {
+        return PluralCategory::Few;
+    }
+    if o.n == 6.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_5(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 && o.v == 0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_2(o: &PluralOperands) -> PluralCategory {
+    if o.i % 10 == 1
+        || o.i % 10 == 2
+        || o.i % 10 == 5
+        || o.i % 10 == 7
+        || o.i % 10 == 8
+        || o.i % 100 == 20
+        || o.i % 100 == 50
+        || o.i % 100 == 70
+        || o.i % 100 == 80
+    {
+        return PluralCategory::One;
+    }
+    if o.i % 10 == 3
+        || o.i % 10 == 4
+        || o.i % 1000 == 100
+        || o.i % 1000 == 200
+        || o.i % 1000 == 300
+        || o.i % 1000 == 400
+        || o.i % 1000 == 500
+        || o.i % 1000 == 600
+        || o.i % 1000 == 700
+        || o.i % 1000 == 800
+        || o.i % 1000 == 900
+    {
+        return PluralCategory::Few;
+    }
+    if o.i == 0 || o.i % 10 == 6 || o.i % 100 == 40 || o.i % 100 == 60 || o.i % 100 == 90 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_3(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_6(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 1.0 && o.n % 100.0 != 11.0 {
+        return PluralCategory::One;
+    }
+    if (o.n % 10.0).fract() == 0.0
+        && (2.0..=4.0).contains(&(o.n % 10.0))
+        && ((o.n % 100.0).fract() != 0.0 || !(12.0..=14.0).contains(&(o.n % 100.0)))
+    {
+        return PluralCategory::Few;
+    }
+    if o.n % 10.0 == 0.0
+        || (o.n % 10.0).fract() == 0.0 && (5.0..=9.0).contains(&(o.n % 10.0))
+        || (o.n % 100.0).fract() == 0.0 && (11.0..=14.0).contains(&(o.n % 100.0))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_4(o: &PluralOperands) -> PluralCategory {
+    if (o.n % 10.0 == 2.0 || o.n % 10.0 == 3.0) && o.n % 100.0 != 12.0 && o.n % 100.0 != 13.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_7(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 {
+        return PluralCategory::Zero;
+    }
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_8(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 1.0 && o.n % 100.0 != 11.0 && o.n % 100.0 != 71.0 && o.n % 100.0 != 91.0 {
+        return PluralCategory::One;
+    }
+    if o.n % 10.0 == 2.0 && o.n % 100.0 != 12.0 && o.n % 100.0 != 72.0 && o.n % 100.0 != 92.0 {
+        return PluralCategory::Two;
+    }
+    if ((o.n % 10.0).fract() == 0.0 && (3.0..=4.0).contains(&(o.n % 10.0)) || o.n % 10.0 == 9.0)
+        && ((o.n % 100.0).fract() != 0.0 || !(10.0..=19.0).contains(&(o.n % 100.0)))
+        && ((o.n % 100.0).fract() != 0.0 || !(70.0..=79.0).contains(&(o.n % 100.0)))
+        && ((o.n % 100.0).fract() != 0.0 || !(90.0..=99.0).contains(&(o.n % 100.0)))
+    {
+        return PluralCategory::Few;
+    }
+    if o.n != 0.0 && o.n % 1000000.0 == 0.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_9(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.f % 10 == 1 && o.f % 100 != 11 {
+        return PluralCategory::One;
+    }
+    if o.v == 0 && (2..=4).contains(&(o.i % 10)) && !(12..=14).contains(&(o.i % 100))
+        || (2..=4).contains(&(o.f % 10)) && !(12..=14).contains(&(o.f % 100))
+    {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_10(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 && o.v == 0 {
+        return PluralCategory::One;
+    }
+    if o.e == 0 && o.i != 0 && o.i.is_multiple_of(1000000) && o.v == 0 || !(0..=5).contains(&o.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_5(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.n == 3.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 4.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_11(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && (o.i == 1 || o.i == 2 || o.i == 3)
+        || o.v == 0 && o.i % 10 != 4 && o.i % 10 != 6 && o.i % 10 != 9
+        || o.v != 0 && o.f % 10 != 4 && o.f % 10 != 6 && o.f % 10 != 9
+    {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_12(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 && o.v == 0 {
+        return PluralCategory::One;
+    }
+    if (2..=4).contains(&o.i) && o.v == 0 {
+        return PluralCategory::Few;
+    }
+    if o.v != 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_13(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 {
+        return PluralCategory::Zero;
+    }
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 3.0 {
+        return PluralCategory::Few;
+    }
+    if o.n == 6.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_6(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 || o.n == 7.0 || o.n == 8.0 || o.n == 9.0 {
+        return PluralCategory::Zero;
+    }
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 3.0 || o.n == 4.0 {
+        return PluralCategory::Few;
+    }
+    if o.n == 5.0 || o.n == 6.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_14(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.t != 0 && (o.i == 0 || o.i == 1) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_15(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && o.i % 100 == 1 || o.f % 100 == 1 {
+        return PluralCategory::One;
+    }
+    if o.v == 0 && o.i % 100 == 2 || o.f % 100 == 2 {
+        return PluralCategory::Two;
+    }
+    if o.v == 0 && (3..=4).contains(&(o.i % 100)) || (3..=4).contains(&(o.f % 100)) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_7(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 1.0 && o.n % 100.0 != 11.0 {
+        return PluralCategory::One;
+    }
+    if o.n % 10.0 == 2.0 && o.n % 100.0 != 12.0 {
+        return PluralCategory::Two;
+    }
+    if o.n % 10.0 == 3.0 && o.n % 100.0 != 13.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_16(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.e == 0 && o.i != 0 && o.i.is_multiple_of(1000000) && o.v == 0 || !(0..=5).contains(&o.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_17(o: &PluralOperands) -> PluralCategory {
+    if o.i == 0 || o.i == 1 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_18(o: &PluralOperands) -> PluralCategory {
+    if o.i == 0 || o.i == 1 {
+        return PluralCategory::One;
+    }
+    if o.e == 0 && o.i != 0 && o.i.is_multiple_of(1000000) && o.v == 0 || !(0..=5).contains(&o.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_19(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    if o.n.fract() == 0.0 && (3.0..=6.0).contains(&o.n) {
+        return PluralCategory::Few;
+    }
+    if o.n.fract() == 0.0 && (7.0..=10.0).contains(&o.n) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_20(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.n == 11.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 || o.n == 12.0 {
+        return PluralCategory::Two;
+    }
+    if o.n.fract() == 0.0 && (3.0..=10.0).contains(&o.n)
+        || o.n.fract() == 0.0 && (13.0..=19.0).contains(&o.n)
+    {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_8(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.n == 11.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 || o.n == 12.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 3.0 || o.n == 13.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_9(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 || o.n == 3.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 4.0 {
+        return PluralCategory::Few;
+    }
+    if o.n == 6.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_21(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && o.i % 10 == 1 {
+        return PluralCategory::One;
+    }
+    if o.v == 0 && o.i % 10 == 2 {
+        return PluralCategory::Two;
+    }
+    if o.v == 0
+        && (o.i.is_multiple_of(100)
+            || o.i % 100 == 20
+            || o.i % 100 == 40
+            || o.i % 100 == 60
+            || o.i % 100 == 80)
+    {
+        return PluralCategory::Few;
+    }
+    if o.v != 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_22(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 && o.v == 0 || o.i == 0 && o.v != 0 {
+        return PluralCategory::One;
+    }
+    if o.i == 2 && o.v == 0 {
+        return PluralCategory::Two;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_10(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.n == 5.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_23(o: &PluralOperands) -> PluralCategory {
+    if o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.t % 10 == 1 && o.t % 100 != 11 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_11(o: &PluralOperands) -> PluralCategory {
+    if o.n == 11.0 || o.n == 8.0 || o.n == 80.0 || o.n == 800.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_24(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_12(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 {
+        return PluralCategory::One;
+    }
+    if o.i == 0
+        || (2..=20).contains(&(o.i % 100))
+        || o.i % 100 == 40
+        || o.i % 100 == 60
+        || o.i % 100 == 80
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_13(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 6.0 || o.n % 10.0 == 9.0 || o.n % 10.0 == 0.0 && o.n != 0.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_25(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 {
+        return PluralCategory::Zero;
+    }
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n % 100.0 == 2.0
+        || o.n % 100.0 == 22.0
+        || o.n % 100.0 == 42.0
+        || o.n % 100.0 == 62.0
+        || o.n % 100.0 == 82.0
+        || o.n % 1000.0 == 0.0
+            && ((o.n % 100000.0).fract() == 0.0 && (1000.0..=20000.0).contains(&(o.n % 100000.0))
+                || o.n % 100000.0 == 40000.0
+                || o.n % 100000.0 == 60000.0
+                || o.n % 100000.0 == 80000.0)
+        || o.n != 0.0 && o.n % 1000000.0 == 100000.0
+    {
+        return PluralCategory::Two;
+    }
+    if o.n % 100.0 == 3.0
+        || o.n % 100.0 == 23.0
+        || o.n % 100.0 == 43.0
+        || o.n % 100.0 == 63.0
+        || o.n % 100.0 == 83.0
+    {
+        return PluralCategory::Few;
+    }
+    if o.n != 1.0
+        && (o.n % 100.0 == 1.0
+            || o.n % 100.0 == 21.0
+            || o.n % 100.0 == 41.0
+            || o.n % 100.0 == 61.0
+            || o.n % 100.0 == 81.0)
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_14(o: &PluralOperands) -> PluralCategory {
+    if o.n.fract() == 0.0 && (1.0..=4.0).contains(&o.n)
+        || (o.n % 100.0).fract() == 0.0 && (1.0..=4.0).contains(&(o.n % 100.0))
+        || (o.n % 100.0).fract() == 0.0 && (21.0..=24.0).contains(&(o.n % 100.0))
+        || (o.n % 100.0).fract() == 0.0 && (41.0..=44.0).contains(&(o.n % 100.0))
+        || (o.n % 100.0).fract() == 0.0 && (61.0..=64.0).contains(&(o.n % 100.0))
+        || (o.n % 100.0).fract() == 0.0 && (81.0..=84.0).contains(&(o.n % 100.0))
+    {
+        return PluralCategory::One;
+    }
+    if o.n == 5.0 || o.n % 100.0 == 5.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_26(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 {
+        return PluralCategory::Zero;
+    }
+    if (o.i == 0 || o.i == 1) && o.n != 0.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_15(o: &PluralOperands) -> PluralCategory {
+    if o.n == 11.0
+        || o.n == 8.0
+        || o.n.fract() == 0.0 && (80.0..=89.0).contains(&o.n)
+        || o.n.fract() == 0.0 && (800.0..=899.0).contains(&o.n)
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_27(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 1.0
+        && ((o.n % 100.0).fract() != 0.0 || !(11.0..=19.0).contains(&(o.n % 100.0)))
+    {
+        return PluralCategory::One;
+    }
+    if (o.n % 10.0).fract() == 0.0
+        && (2.0..=9.0).contains(&(o.n % 10.0))
+        && ((o.n % 100.0).fract() != 0.0 || !(11.0..=19.0).contains(&(o.n % 100.0)))
+    {
+        return PluralCategory::Few;
+    }
+    if o.f != 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_28(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 0.0
+        || (o.n % 100.0).fract() == 0.0 && (11.0..=19.0).contains(&(o.n % 100.0))
+        || o.v == 2 && (11..=19).contains(&(o.f % 100))
+    {
+        return PluralCategory::Zero;
+    }
+    if o.n % 10.0 == 1.0 && o.n % 100.0 != 11.0
+        || o.v == 2 && o.f % 10 == 1 && o.f % 100 != 11
+        || o.v != 2 && o.f % 10 == 1
+    {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_29(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.f % 10 == 1 && o.f % 100 != 11 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_16(o: &PluralOperands) -> PluralCategory {
+    if o.i % 10 == 1 && o.i % 100 != 11 {
+        return PluralCategory::One;
+    }
+    if o.i % 10 == 2 && o.i % 100 != 12 {
+        return PluralCategory::Two;
+    }
+    if (o.i % 10 == 7 || o.i % 10 == 8) && o.i % 100 != 17 && o.i % 100 != 18 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_17(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 || o.n == 3.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 4.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_30(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 0.0 || (o.n % 100.0).fract() == 0.0 && (3.0..=10.0).contains(&(o.n % 100.0)) {
+        return PluralCategory::Few;
+    }
+    if (o.n % 100.0).fract() == 0.0 && (11.0..=19.0).contains(&(o.n % 100.0)) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_18(o: &PluralOperands) -> PluralCategory {
+    if o.n.fract() == 0.0 && (1.0..=4.0).contains(&o.n) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_19(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 || o.n == 5.0 || o.n.fract() == 0.0 && (7.0..=9.0).contains(&o.n) {
+        return PluralCategory::One;
+    }
+    if o.n == 2.0 || o.n == 3.0 {
+        return PluralCategory::Two;
+    }
+    if o.n == 4.0 {
+        return PluralCategory::Few;
+    }
+    if o.n == 6.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_31(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 && o.v == 0 {
+        return PluralCategory::One;
+    }
+    if o.v == 0 && (2..=4).contains(&(o.i % 10)) && !(12..=14).contains(&(o.i % 100)) {
+        return PluralCategory::Few;
+    }
+    if o.v == 0 && o.i != 1 && (0..=1).contains(&(o.i % 10))
+        || o.v == 0 && (5..=9).contains(&(o.i % 10))
+        || o.v == 0 && (12..=14).contains(&(o.i % 100))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_32(o: &PluralOperands) -> PluralCategory {
+    if (0..=1).contains(&o.i) {
+        return PluralCategory::One;
+    }
+    if o.e == 0 && o.i != 0 && o.i.is_multiple_of(1000000) && o.v == 0 || !(0..=5).contains(&o.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_33(o: &PluralOperands) -> PluralCategory {
+    if o.i == 1 && o.v == 0 {
+        return PluralCategory::One;
+    }
+    if o.v != 0
+        || o.n == 0.0
+        || o.n != 1.0 && (o.n % 100.0).fract() == 0.0 && (1.0..=19.0).contains(&(o.n % 100.0))
+    {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_34(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 {
+        return PluralCategory::One;
+    }
+    if o.v == 0 && (2..=4).contains(&(o.i % 10)) && !(12..=14).contains(&(o.i % 100)) {
+        return PluralCategory::Few;
+    }
+    if o.v == 0 && o.i.is_multiple_of(10)
+        || o.v == 0 && (5..=9).contains(&(o.i % 10))
+        || o.v == 0 && (11..=14).contains(&(o.i % 100))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_35(o: &PluralOperands) -> PluralCategory {
+    if o.i == 0 || o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n.fract() == 0.0 && (2.0..=10.0).contains(&o.n) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_36(o: &PluralOperands) -> PluralCategory {
+    if o.n == 0.0 || o.n == 1.0 || o.i == 0 && o.f == 1 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_37(o: &PluralOperands) -> PluralCategory {
+    if o.v == 0 && o.i % 100 == 1 {
+        return PluralCategory::One;
+    }
+    if o.v == 0 && o.i % 100 == 2 {
+        return PluralCategory::Two;
+    }
+    if o.v == 0 && (3..=4).contains(&(o.i % 100)) || o.v != 0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_20(o: &PluralOperands) -> PluralCategory {
+    if o.n == 1.0 {
+        return PluralCategory::One;
+    }
+    if o.n % 10.0 == 4.0 && o.n % 100.0 != 14.0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_21(o: &PluralOperands) -> PluralCategory {
+    if (o.n % 10.0 == 1.0 || o.n % 10.0 == 2.0) && o.n % 100.0 != 11.0 && o.n % 100.0 != 12.0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_22(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 6.0 || o.n % 10.0 == 9.0 || o.n == 10.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+fn _cardinal_38(o: &PluralOperands) -> PluralCategory {
+    if o.n.fract() == 0.0 && (0.0..=1.0).contains(&o.n)
+        || o.n.fract() == 0.0 && (11.0..=99.0).contains(&o.n)
+    {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+
+fn _ordinal_23(o: &PluralOperands) -> PluralCategory {
+    if o.n % 10.0 == 3.0 && o.n % 100.0 != 13.0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_0: [(PluralCategory, PluralCategory, PluralCategory); 0] = [
+    
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_1: [(PluralCategory, PluralCategory, PluralCategory); 3] = [
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_2: [(PluralCategory, PluralCategory, PluralCategory); 4] = [
+    (PluralCategory::One, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_3: [(PluralCategory, PluralCategory, PluralCategory); 3] = [
+    (PluralCategory::One, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_4: [(PluralCategory, PluralCategory, PluralCategory); 23] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Many, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Many, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Many, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Two, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Two, PluralCategory::Other),
+    (PluralCategory::Two, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Two, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Two, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Zero, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Zero, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Zero, PluralCategory::One, PluralCategory::Zero),
+    (PluralCategory::Zero, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Zero, PluralCategory::Two, PluralCategory::Zero)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_5: [(PluralCategory, PluralCategory, PluralCategory); 3] = [
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_6: [(PluralCategory, PluralCategory, PluralCategory); 16] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Few, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Many, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Many, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Many, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Many, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::One, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_7: [(PluralCategory, PluralCategory, PluralCategory); 9] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_8: [(PluralCategory, PluralCategory, PluralCategory); 14] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Many, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Many, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Many, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Many, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_9: [(PluralCategory, PluralCategory, PluralCategory); 20] = [
+    (PluralCategory::Few, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Many, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::Two, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Two, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Two, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Zero, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Zero, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Zero, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Zero, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Zero, PluralCategory::Two, PluralCategory::Two)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_10: [(PluralCategory, PluralCategory, PluralCategory); 4] = [
+    (PluralCategory::One, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_11: [(PluralCategory, PluralCategory, PluralCategory); 17] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Many, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Many, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::Two, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Two, PluralCategory::Many, PluralCategory::Many),
+    (PluralCategory::Two, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_12: [(PluralCategory, PluralCategory, PluralCategory); 6] = [
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Two, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Two, PluralCategory::Other),
+    (PluralCategory::Two, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_13: [(PluralCategory, PluralCategory, PluralCategory); 1] = [
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_14: [(PluralCategory, PluralCategory, PluralCategory); 3] = [
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_15: [(PluralCategory, PluralCategory, PluralCategory); 9] = [
+    (PluralCategory::One, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Zero, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Zero, PluralCategory::Other),
+    (PluralCategory::Zero, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::Zero, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Zero, PluralCategory::Zero, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_16: [(PluralCategory, PluralCategory, PluralCategory); 4] = [
+    (PluralCategory::One, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_17: [(PluralCategory, PluralCategory, PluralCategory); 7] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::One, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_18: [(PluralCategory, PluralCategory, PluralCategory); 4] = [
+    (PluralCategory::One, PluralCategory::One, PluralCategory::One),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other)
+];
+
+#[rustfmt::skip]
+static _PLURAL_RANGES_19: [(PluralCategory, PluralCategory, PluralCategory); 16] = [
+    (PluralCategory::Few, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::One, PluralCategory::Few),
+    (PluralCategory::Few, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Few, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::One, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::One, PluralCategory::Few),
+    (PluralCategory::One, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::One, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::Other, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::One, PluralCategory::Few),
+    (PluralCategory::Other, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Other, PluralCategory::Two, PluralCategory::Two),
+    (PluralCategory::Two, PluralCategory::Few, PluralCategory::Few),
+    (PluralCategory::Two, PluralCategory::One, PluralCategory::Few),
+    (PluralCategory::Two, PluralCategory::Other, PluralCategory::Other),
+    (PluralCategory::Two, PluralCategory::Two, PluralCategory::Two)
+];
@@ -0,0 +1,12377 @@
+// Auto-generated. DO NOT EDIT.
+use crate::datetime_formats::{_days_from_civil, DateTime};
+use crate::locale::Locale;
+#[cfg(feature = "nums")]
+use crate::num_formats::ToFormattedString;
+use crate::plurals::PluralOperands;
+
+/// Unit of a relative time, from CLDR `dateFields`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// Length of a relative time phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeStyle {
+    /// e.g. `in 3 days`
+    Long,
+    /// e.g. `in 3 days`, `in 3 mo.`
+    Short,
+    /// e.g. `in 3d`
+    Narrow,
+}
+
+/// Relative time patterns of one unit and style.
+struct _RelativeField {
+    /// Special phrases by offset, e.g. `(-1, "yesterday")`.
+    relatives: &'static [(i8, &'static str)],
+    /// Patterns by plural category; empty ones use the `other` pattern.
+    future: [&'static str; 6],
+    past: [&'static str; 6],
+}
+
+impl RelativeUnit {
+    /// Picks the unit that reads most naturally for the distance from `now`
+    /// to `datetime`, and returns the value to format in it, negative for
+    /// the past. Units up to hours count elapsed time, larger ones count
+    /// calendar days and months.
+    pub fn best_fit(datetime: &DateTime, now: &DateTime) -> (i64, RelativeUnit) {
+        let seconds = datetime.unix_timestamp() - now.unix_timestamp();
+        if seconds.abs() < 60 {
+            return (seconds, RelativeUnit::Second);
+        }
+        if seconds.abs() < 3600 {
+            return (seconds / 60, RelativeUnit::Minute);
+        }
+        if seconds.abs() < 86400 {
+            return (seconds / 3600, RelativeUnit::Hour);
+        }
+        let days = _days_from_civil(datetime.year as i64, datetime.month, datetime.day)
+            - _days_from_civil(now.year as i64, now.month, now.day);
+        if days.abs() < 7 {
+            return (days, RelativeUnit::Day);
+        }
+        let mut months = (datetime.year as i64 - now.year as i64) * 12 + datetime.month as i64
+            - now.month as i64;
+        // A month only counts once its day of the month is reached.
+        if months > 0 && datetime.day < now.day {
+            months -= 1;
+        } else if months < 0 && datetime.day > now.day {
+            months += 1;
+        }
+        if months == 0 {
+            (days / 7, RelativeUnit::Week)
+        } else if months.abs() < 12 {
+            (months, RelativeUnit::Month)
+        } else {
+            (months / 12, RelativeUnit::Year)
+        }
+    }
+}
+
+impl Locale {
+    /// Formats `value` units from now, e.g. `-3` days as "3 days ago".
+    /// Offsets with a phrase of their own use it instead, e.g. "yesterday"
+    /// for `-1` days or "this month" for `0` months.
+    pub fn format_relative(&self, value: i64, unit: RelativeUnit, style: RelativeStyle) -> String {
+        let field = self._relative_field(unit, style);
+        if let Ok(offset) = i8::try_from(value)
+            && let Some((_, phrase)) = field.relatives.iter().find(|(o, _)| *o == offset)
+        {
+            return phrase.to_string();
+        }
+        self._format_relative_pattern(field, value)
+    }
+
+    /// Formats `value` units from now, always with a number, e.g. "in 1 day"
+    /// where [`Locale::format_relative`] gives "tomorrow".
+    pub fn format_relative_numeric(
+        &self,
+        value: i64,
+        unit: RelativeUnit,
+        style: RelativeStyle,
+    ) -> String {
+        self._format_relative_pattern(self._relative_field(unit, style), value)
+    }
+
+    /// Formats `datetime` relative to `now` in the unit picked by
+    /// [`RelativeUnit::best_fit`], e.g. "2 hours ago" or "next month".
+    pub fn format_relative_datetime(
+        &self,
+        datetime: &DateTime,
+        now: &DateTime,
+        style: RelativeStyle,
+    ) -> String {
+        let (value, unit) = RelativeUnit::best_fit(datetime, now);
+        self.format_relative(value, unit, style)
+    }
+
+    fn _relative_field(&self, unit: RelativeUnit, style: RelativeStyle) -> &'static _RelativeField {
+        self._relative_fields()[unit as usize * 3 + style as usize]
+    }
+
+    fn _format_relative_pattern(&self, field: &_RelativeField, value: i64) -> String {
+        let patterns = if value < 0 {
+            &field.past
+        } else {
+            &field.future
+        };
+        let category = self.plural_category(&PluralOperands::from_integer(value));
+        let pattern = match patterns[category as usize] {
+            "" => patterns[5],
+            pattern => pattern,
+        };
+        #[cfg(feature = "nums")]
+        let number = value.unsigned_abs().to_formatted_string(self);
+        #[cfg(not(feature = "nums"))]
+        let number = value.unsigned_abs().to_string();
+        pattern.replace("{0}", &number)
+    }
+
+    fn _relative_fields(&self) -> &'static [&'static _RelativeField; 24] {
+        match self {
+            Locale::aa => &_RELATIVE_FIELDS_0,
+            Locale::aa_DJ => &_RELATIVE_FIELDS_0,
+            Locale::aa_ER => &_RELATIVE_FIELDS_0,
+            Locale::ab => &_RELATIVE_FIELDS_0,
+            Locale::af => &_RELATIVE_FIELDS_1,
+            Locale::af_NA => &_RELATIVE_FIELDS_1,
+            Locale::agq => &_RELATIVE_FIELDS_0,
+            Locale::ak => &_RELATIVE_FIELDS_2,
+            Locale::am => &_RELATIVE_FIELDS_3,
+            Locale::an => &_RELATIVE_FIELDS_0,
+            Locale::ann => &_RELATIVE_FIELDS_0,
+            Locale::apc => &_RELATIVE_FIELDS_0,
+            Locale::ar => &_RELATIVE_FIELDS_4,
+            Locale::ar_AE => &_RELATIVE_FIELDS_5,
+            Locale::ar_BH => &_RELATIVE_FIELDS_4,
+            Locale::ar_DJ => &_RELATIVE_FIELDS_4,
+            Locale::ar_DZ => &_RELATIVE_FIELDS_4,
+            Locale::ar_EG => &_RELATIVE_FIELDS_4,
+            Locale::ar_EH => &_RELATIVE_FIELDS_4,
+            Locale::ar_ER => &_RELATIVE_FIELDS_4,
+            Locale::ar_IL => &_RELATIVE_FIELDS_4,
+            Locale::ar_IQ => &_RELATIVE_FIELDS_4,
+            Locale::ar_JO => &_RELATIVE_FIELDS_4,
+            Locale::ar_KM => &_RELATIVE_FIELDS_4,
+            Locale::ar_KW => &_RELATIVE_FIELDS_4,
+            Locale::ar_LB => &_RELATIVE_FIELDS_4,
+            Locale::ar_LY => &_RELATIVE_FIELDS_4,
+            Locale::ar_MA => &_RELATIVE_FIELDS_4,
+            Locale::ar_MR => &_RELATIVE_FIELDS_4,
+            Locale::ar_OM => &_RELATIVE_FIELDS_4,
+            Locale::ar_PS => &_RELATIVE_FIELDS_4,
+            Locale::ar_QA => &_RELATIVE_FIELDS_4,
+            Locale::ar_SA => &_RELATIVE_FIELDS_4,
+            Locale::ar_SD => &_RELATIVE_FIELDS_4,
+            Locale::ar_SO => &_RELATIVE_FIELDS_4,
+            Locale::ar_SS => &_RELATIVE_FIELDS_4,
+            Locale::ar_SY => &_RELATIVE_FIELDS_4,
+            Locale::ar_TD => &_RELATIVE_FIELDS_4,
+            Locale::ar_TN => &_RELATIVE_FIELDS_4,
+            Locale::ar_YE => &_RELATIVE_FIELDS_4,
+            Locale::arn => &_RELATIVE_FIELDS_0,
+            Locale::as_ => &_RELATIVE_FIELDS_6,
+            Locale::asa => &_RELATIVE_FIELDS_0,
+            Locale::ast => &_RELATIVE_FIELDS_7,
+            Locale::az => &_RELATIVE_FIELDS_8,
+            Locale::az_Arab => &_RELATIVE_FIELDS_0,
+            Locale::az_Arab_IQ => &_RELATIVE_FIELDS_0,
+            Locale::az_Arab_TR => &_RELATIVE_FIELDS_0,
+            Locale::az_Cyrl => &_RELATIVE_FIELDS_0,
+            Locale::az_Latn => &_RELATIVE_FIELDS_8,
+            Locale::ba => &_RELATIVE_FIELDS_0,
+            Locale::bal => &_RELATIVE_FIELDS_0,
+            Locale::bal_Arab => &_RELATIVE_FIELDS_0,
+            Locale::bal_Latn => &_RELATIVE_FIELDS_9,
+            Locale::bas => &_RELATIVE_FIELDS_0,
+            Locale::be => &_RELATIVE_FIELDS_10,
+            Locale::be_tarask => &_RELATIVE_FIELDS_11,
+            Locale::bem => &_RELATIVE_FIELDS_0,
+            Locale::bew => &_RELATIVE_FIELDS_0,
+            Locale::bez => &_RELATIVE_FIELDS_0,
+            Locale::bg => &_RELATIVE_FIELDS_12,
+            Locale::bgc => &_RELATIVE_FIELDS_13,
+            Locale::bgn => &_RELATIVE_FIELDS_0,
+            Locale::bgn_AE => &_RELATIVE_FIELDS_0,
+            Locale::bgn_AF => &_RELATIVE_FIELDS_0,
+            Locale::bgn_IR => &_RELATIVE_FIELDS_0,
+            Locale::bgn_OM => &_RELATIVE_FIELDS_0,
+            Locale::bho => &_RELATIVE_FIELDS_0,
+            Locale::blo => &_RELATIVE_FIELDS_14,
+            Locale::blt => &_RELATIVE_FIELDS_0,
+            Locale::bm => &_RELATIVE_FIELDS_0,
+            Locale::bm_Nkoo => &_RELATIVE_FIELDS_0,
+            Locale::bn => &_RELATIVE_FIELDS_15,
+            Locale::bn_IN => &_RELATIVE_FIELDS_15,
+            Locale::bo => &_RELATIVE_FIELDS_0,
+            Locale::bo_IN => &_RELATIVE_FIELDS_0,
+            Locale::bqi => &_RELATIVE_FIELDS_0,
+            Locale::br => &_RELATIVE_FIELDS_16,
+            Locale::brx => &_RELATIVE_FIELDS_17,
+            Locale::bs => &_RELATIVE_FIELDS_18,
+            Locale::bs_Cyrl => &_RELATIVE_FIELDS_19,
+            Locale::bs_Latn => &_RELATIVE_FIELDS_18,
+            Locale::bss => &_RELATIVE_FIELDS_0,
+            Locale::bua => &_RELATIVE_FIELDS_0,
+            Locale::byn => &_RELATIVE_FIELDS_0,
+            Locale::ca => &_RELATIVE_FIELDS_20,
+            Locale::ca_AD => &_RELATIVE_FIELDS_20,
+            Locale::ca_ES_valencia => &_RELATIVE_FIELDS_20,
+            Locale::ca_FR => &_RELATIVE_FIELDS_20,
+            Locale::ca_IT => &_RELATIVE_FIELDS_20,
+            Locale::cad => &_RELATIVE_FIELDS_0,
+            Locale::cch => &_RELATIVE_FIELDS_0,
+            Locale::ccp => &_RELATIVE_FIELDS_0,
+            Locale::ccp_IN => &_RELATIVE_FIELDS_0,
+            Locale::ce => &_RELATIVE_FIELDS_0,
+            Locale::ceb => &_RELATIVE_FIELDS_21,
+            Locale::cgg => &_RELATIVE_FIELDS_0,
+            Locale::cho => &_RELATIVE_FIELDS_0,
+            Locale::chr => &_RELATIVE_FIELDS_22,
+            Locale::cic => &_RELATIVE_FIELDS_0,
+            Locale::ckb => &_RELATIVE_FIELDS_0,
+            Locale::ckb_IR => &_RELATIVE_FIELDS_0,
+            Locale::co => &_RELATIVE_FIELDS_0,
+            Locale::cop => &_RELATIVE_FIELDS_0,
+            Locale::cs => &_RELATIVE_FIELDS_23,
+            Locale::csw => &_RELATIVE_FIELDS_24,
+            Locale::cu => &_RELATIVE_FIELDS_0,
+            Locale::cv => &_RELATIVE_FIELDS_25,
+            Locale::cy => &_RELATIVE_FIELDS_26,
+            Locale::da => &_RELATIVE_FIELDS_27,
+            Locale::da_GL => &_RELATIVE_FIELDS_27,
+            Locale::dav => &_RELATIVE_FIELDS_0,
+            Locale::de => &_RELATIVE_FIELDS_28,
+            Locale::de_AT => &_RELATIVE_FIELDS_28,
+            Locale::de_BE => &_RELATIVE_FIELDS_28,
+            Locale::de_CH => &_RELATIVE_FIELDS_28,
+            Locale::de_IT => &_RELATIVE_FIELDS_28,
+            Locale::de_LI => &_RELATIVE_FIELDS_28,
+            Locale::de_LU => &_RELATIVE_FIELDS_28,
+            Locale::dje => &_RELATIVE_FIELDS_0,
+            Locale::doi => &_RELATIVE_FIELDS_29,
+            Locale::dsb => &_RELATIVE_FIELDS_30,
+            Locale::dua => &_RELATIVE_FIELDS_0,
+            Locale::dv => &_RELATIVE_FIELDS_0,
+            Locale::dyo => &_RELATIVE_FIELDS_0,
+            Locale::dz => &_RELATIVE_FIELDS_0,
+            Locale::ebu => &_RELATIVE_FIELDS_0,
+            Locale::ee => &_RELATIVE_FIELDS_31,
+            Locale::ee_TG => &_RELATIVE_FIELDS_31,
+            Locale::el => &_RELATIVE_FIELDS_32,
+            Locale::el_CY => &_RELATIVE_FIELDS_32,
+            Locale::el_polyton => &_RELATIVE_FIELDS_32,
+            Locale::en => &_RELATIVE_FIELDS_33,
+            Locale::en_001 => &_RELATIVE_FIELDS_34,
+            Locale::en_150 => &_RELATIVE_FIELDS_34,
+            Locale::en_AE => &_RELATIVE_FIELDS_33,
+            Locale::en_AG => &_RELATIVE_FIELDS_34,
+            Locale::en_AI => &_RELATIVE_FIELDS_34,
+            Locale::en_AS => &_RELATIVE_FIELDS_33,
+            Locale::en_AT => &_RELATIVE_FIELDS_34,
+            Locale::en_AU => &_RELATIVE_FIELDS_35,
+            Locale::en_BB => &_RELATIVE_FIELDS_34,
+            Locale::en_BE => &_RELATIVE_FIELDS_34,
+            Locale::en_BI => &_RELATIVE_FIELDS_33,
+            Locale::en_BM => &_RELATIVE_FIELDS_34,
+            Locale::en_BS => &_RELATIVE_FIELDS_34,
+            Locale::en_BW => &_RELATIVE_FIELDS_34,
+            Locale::en_BZ => &_RELATIVE_FIELDS_34,
+            Locale::en_CA => &_RELATIVE_FIELDS_36,
+            Locale::en_CC => &_RELATIVE_FIELDS_34,
+            Locale::en_CH => &_RELATIVE_FIELDS_34,
+            Locale::en_CK => &_RELATIVE_FIELDS_34,
+            Locale::en_CM => &_RELATIVE_FIELDS_34,
+            Locale::en_CX => &_RELATIVE_FIELDS_34,
+            Locale::en_CY => &_RELATIVE_FIELDS_34,
+            Locale::en_CZ => &_RELATIVE_FIELDS_33,
+            Locale::en_DE => &_RELATIVE_FIELDS_34,
+            Locale::en_DG => &_RELATIVE_FIELDS_34,
+            Locale::en_DK => &_RELATIVE_FIELDS_34,
+            Locale::en_DM => &_RELATIVE_FIELDS_34,
+            Locale::en_Dsrt => &_RELATIVE_FIELDS_0,
+            Locale::en_EE => &_RELATIVE_FIELDS_33,
+            Locale::en_ER => &_RELATIVE_FIELDS_34,
+            Locale::en_ES => &_RELATIVE_FIELDS_33,
+            Locale::en_FI => &_RELATIVE_FIELDS_34,
+            Locale::en_FJ => &_RELATIVE_FIELDS_34,
+            Locale::en_FK => &_RELATIVE_FIELDS_34,
+            Locale::en_FM => &_RELATIVE_FIELDS_34,
+            Locale::en_FR => &_RELATIVE_FIELDS_33,
+            Locale::en_GB => &_RELATIVE_FIELDS_34,
+            Locale::en_GD => &_RELATIVE_FIELDS_34,
+            Locale::en_GE => &_RELATIVE_FIELDS_33,
+            Locale::en_GG => &_RELATIVE_FIELDS_34,
+            Locale::en_GH => &_RELATIVE_FIELDS_34,
+            Locale::en_GI => &_RELATIVE_FIELDS_34,
+            Locale::en_GM => &_RELATIVE_FIELDS_34,
+            Locale::en_GS => &_RELATIVE_FIELDS_33,
+            Locale::en_GU => &_RELATIVE_FIELDS_33,
+            Locale::en_GY => &_RELATIVE_FIELDS_34,
+            Locale::en_HK => &_RELATIVE_FIELDS_34,
+            Locale::en_HU => &_RELATIVE_FIELDS_33,
+            Locale::en_ID => &_RELATIVE_FIELDS_34,
+            Locale::en_IE => &_RELATIVE_FIELDS_34,
+            Locale::en_IL => &_RELATIVE_FIELDS_34,
+            Locale::en_IM => &_RELATIVE_FIELDS_34,
+            Locale::en_IN => &_RELATIVE_FIELDS_34,
+            Locale::en_IO => &_RELATIVE_FIELDS_34,
+            Locale::en_IT => &_RELATIVE_FIELDS_33,
+            Locale::en_JE => &_RELATIVE_FIELDS_34,
+            Locale::en_JM => &_RELATIVE_FIELDS_34,
+            Locale::en_JP => &_RELATIVE_FIELDS_33,
+            Locale::en_KE => &_RELATIVE_FIELDS_34,
+            Locale::en_KI => &_RELATIVE_FIELDS_34,
+            Locale::en_KN => &_RELATIVE_FIELDS_34,
+            Locale::en_KY => &_RELATIVE_FIELDS_34,
+            Locale::en_LC => &_RELATIVE_FIELDS_34,
+            Locale::en_LR => &_RELATIVE_FIELDS_34,
+            Locale::en_LS => &_RELATIVE_FIELDS_34,
+            Locale::en_LT => &_RELATIVE_FIELDS_33,
+            Locale::en_LV => &_RELATIVE_FIELDS_33,
+            Locale::en_MG => &_RELATIVE_FIELDS_34,
+            Locale::en_MH => &_RELATIVE_FIELDS_33,
+            Locale::en_MO => &_RELATIVE_FIELDS_34,
+            Locale::en_MP => &_RELATIVE_FIELDS_33,
+            Locale::en_MS => &_RELATIVE_FIELDS_34,
+            Locale::en_MT => &_RELATIVE_FIELDS_34,
+            Locale::en_MU => &_RELATIVE_FIELDS_34,
+            Locale::en_MV => &_RELATIVE_FIELDS_34,
+            Locale::en_MW => &_RELATIVE_FIELDS_34,
+            Locale::en_MY => &_RELATIVE_FIELDS_34,
+            Locale::en_NA => &_RELATIVE_FIELDS_34,
+            Locale::en_NF => &_RELATIVE_FIELDS_34,
+            Locale::en_NG => &_RELATIVE_FIELDS_34,
+            Locale::en_NL => &_RELATIVE_FIELDS_34,
+            Locale::en_NO => &_RELATIVE_FIELDS_33,
+            Locale::en_NR => &_RELATIVE_FIELDS_34,
+            Locale::en_NU => &_RELATIVE_FIELDS_34,
+            Locale::en_NZ => &_RELATIVE_FIELDS_34,
+            Locale::en_PG => &_RELATIVE_FIELDS_34,
+            Locale::en_PH => &_RELATIVE_FIELDS_33,
+            Locale::en_PK => &_RELATIVE_FIELDS_34,
+            Locale::en_PL => &_RELATIVE_FIELDS_33,
+            Locale::en_PN => &_RELATIVE_FIELDS_34,
+            Locale::en_PR => &_RELATIVE_FIELDS_33,
+            Locale::en_PT => &_RELATIVE_FIELDS_33,
+            Locale::en_PW => &_RELATIVE_FIELDS_34,
+            Locale::en_RO => &_RELATIVE_FIELDS_33,
+            Locale::en_RW => &_RELATIVE_FIELDS_34,
+            Locale::en_SB => &_RELATIVE_FIELDS_34,
+            Locale::en_SC => &_RELATIVE_FIELDS_34,
+            Locale::en_SD => &_RELATIVE_FIELDS_34,
+            Locale::en_SE => &_RELATIVE_FIELDS_34,
+            Locale::en_SG => &_RELATIVE_FIELDS_37,
+            Locale::en_SH => &_RELATIVE_FIELDS_34,
+            Locale::en_SI => &_RELATIVE_FIELDS_34,
+            Locale::en_SK => &_RELATIVE_FIELDS_33,
+            Locale::en_SL => &_RELATIVE_FIELDS_34,
+            Locale::en_SS => &_RELATIVE_FIELDS_34,
+            Locale::en_SX => &_RELATIVE_FIELDS_34,
+            Locale::en_SZ => &_RELATIVE_FIELDS_34,
+            Locale::en_Shaw => &_RELATIVE_FIELDS_0,
+            Locale::en_TC => &_RELATIVE_FIELDS_34,
+            Locale::en_TK => &_RELATIVE_FIELDS_34,
+            Locale::en_TO => &_RELATIVE_FIELDS_34,
+            Locale::en_TT => &_RELATIVE_FIELDS_34,
+            Locale::en_TV => &_RELATIVE_FIELDS_34,
+            Locale::en_TZ => &_RELATIVE_FIELDS_34,
+            Locale::en_UA => &_RELATIVE_FIELDS_33,
+            Locale::en_UG => &_RELATIVE_FIELDS_34,
+            Locale::en_UM => &_RELATIVE_FIELDS_33,
+            Locale::en_VC => &_RELATIVE_FIELDS_34,
+            Locale::en_VG => &_RELATIVE_FIELDS_34,
+            Locale::en_VI => &_RELATIVE_FIELDS_33,
+            Locale::en_VU => &_RELATIVE_FIELDS_34,
+            Locale::en_WS => &_RELATIVE_FIELDS_34,
+            Locale::en_ZA => &_RELATIVE_FIELDS_34,
+            Locale::en_ZM => &_RELATIVE_FIELDS_34,
+            Locale::en_ZW => &_RELATIVE_FIELDS_34,
+            Locale::eo => &_RELATIVE_FIELDS_0,
+            Locale::es => &_RELATIVE_FIELDS_38,
+            Locale::es_419 => &_RELATIVE_FIELDS_39,
+            Locale::es_AR => &_RELATIVE_FIELDS_40,
+            Locale::es_BO => &_RELATIVE_FIELDS_39,
+            Locale::es_BR => &_RELATIVE_FIELDS_39,
+            Locale::es_BZ => &_RELATIVE_FIELDS_39,
+            Locale::es_CL => &_RELATIVE_FIELDS_39,
+            Locale::es_CO => &_RELATIVE_FIELDS_39,
+            Locale::es_CR => &_RELATIVE_FIELDS_39,
+            Locale::es_CU => &_RELATIVE_FIELDS_39,
+            Locale::es_DO => &_RELATIVE_FIELDS_39,
+            Locale::es_EA => &_RELATIVE_FIELDS_38,
+            Locale::es_EC => &_RELATIVE_FIELDS_39,
+            Locale::es_GQ => &_RELATIVE_FIELDS_38,
+            Locale::es_GT => &_RELATIVE_FIELDS_39,
+            Locale::es_HN => &_RELATIVE_FIELDS_39,
+            Locale::es_IC => &_RELATIVE_FIELDS_38,
+            Locale::es_MX => &_RELATIVE_FIELDS_41,
+            Locale::es_NI => &_RELATIVE_FIELDS_39,
+            Locale::es_PA => &_RELATIVE_FIELDS_39,
+            Locale::es_PE => &_RELATIVE_FIELDS_39,
+            Locale::es_PH => &_RELATIVE_FIELDS_38,
+            Locale::es_PR => &_RELATIVE_FIELDS_39,
+            Locale::es_PY => &_RELATIVE_FIELDS_42,
+            Locale::es_SV => &_RELATIVE_FIELDS_43,
+            Locale::es_US => &_RELATIVE_FIELDS_44,
+            Locale::es_UY => &_RELATIVE_FIELDS_39,
+            Locale::es_VE => &_RELATIVE_FIELDS_39,
+            Locale::et => &_RELATIVE_FIELDS_45,
+            Locale::eu => &_RELATIVE_FIELDS_46,
+            Locale::ewo => &_RELATIVE_FIELDS_0,
+            Locale::fa => &_RELATIVE_FIELDS_47,
+            Locale::fa_AF => &_RELATIVE_FIELDS_47,
+            Locale::ff => &_RELATIVE_FIELDS_0,
+            Locale::ff_Adlm => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_BF => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_CM => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_GH => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_GM => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_GW => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_LR => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_MR => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_NE => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_NG => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_SL => &_RELATIVE_FIELDS_48,
+            Locale::ff_Adlm_SN => &_RELATIVE_FIELDS_48,
+            Locale::ff_Latn => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_BF => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_CM => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_GH => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_GM => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_GN => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_GW => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_LR => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_MR => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_NE => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_NG => &_RELATIVE_FIELDS_0,
+            Locale::ff_Latn_SL => &_RELATIVE_FIELDS_0,
+            Locale::fi => &_RELATIVE_FIELDS_49,
+            Locale::fil => &_RELATIVE_FIELDS_50,
+            Locale::fo => &_RELATIVE_FIELDS_51,
+            Locale::fo_DK => &_RELATIVE_FIELDS_51,
+            Locale::fr => &_RELATIVE_FIELDS_52,
+            Locale::fr_BE => &_RELATIVE_FIELDS_52,
+            Locale::fr_BF => &_RELATIVE_FIELDS_52,
+            Locale::fr_BI => &_RELATIVE_FIELDS_52,
+            Locale::fr_BJ => &_RELATIVE_FIELDS_52,
+            Locale::fr_BL => &_RELATIVE_FIELDS_52,
+            Locale::fr_CA => &_RELATIVE_FIELDS_53,
+            Locale::fr_CD => &_RELATIVE_FIELDS_52,
+            Locale::fr_CF => &_RELATIVE_FIELDS_52,
+            Locale::fr_CG => &_RELATIVE_FIELDS_52,
+            Locale::fr_CH => &_RELATIVE_FIELDS_52,
+            Locale::fr_CI => &_RELATIVE_FIELDS_52,
+            Locale::fr_CM => &_RELATIVE_FIELDS_52,
+            Locale::fr_DJ => &_RELATIVE_FIELDS_52,
+            Locale::fr_DZ => &_RELATIVE_FIELDS_52,
+            Locale::fr_GA => &_RELATIVE_FIELDS_52,
+            Locale::fr_GF => &_RELATIVE_FIELDS_52,
+            Locale::fr_GN => &_RELATIVE_FIELDS_52,
+            Locale::fr_GP => &_RELATIVE_FIELDS_52,
+            Locale::fr_GQ => &_RELATIVE_FIELDS_52,
+            Locale::fr_HT => &_RELATIVE_FIELDS_52,
+            Locale::fr_KM => &_RELATIVE_FIELDS_52,
+            Locale::fr_LU => &_RELATIVE_FIELDS_52,
+            Locale::fr_MA => &_RELATIVE_FIELDS_52,
+            Locale::fr_MC => &_RELATIVE_FIELDS_52,
+            Locale::fr_MF => &_RELATIVE_FIELDS_52,
+            Locale::fr_MG => &_RELATIVE_FIELDS_52,
+            Locale::fr_ML => &_RELATIVE_FIELDS_52,
+            Locale::fr_MQ => &_RELATIVE_FIELDS_52,
+            Locale::fr_MR => &_RELATIVE_FIELDS_52,
+            Locale::fr_MU => &_RELATIVE_FIELDS_52,
+            Locale::fr_NC => &_RELATIVE_FIELDS_52,
+            Locale::fr_NE => &_RELATIVE_FIELDS_52,
+            Locale::fr_PF => &_RELATIVE_FIELDS_52,
+            Locale::fr_PM => &_RELATIVE_FIELDS_52,
+            Locale::fr_RE => &_RELATIVE_FIELDS_52,
+            Locale::fr_RW => &_RELATIVE_FIELDS_52,
+            Locale::fr_SC => &_RELATIVE_FIELDS_52,
+            Locale::fr_SN => &_RELATIVE_FIELDS_52,
+            Locale::fr_SY => &_RELATIVE_FIELDS_52,
+            Locale::fr_TD => &_RELATIVE_FIELDS_52,
+            Locale::fr_TG => &_RELATIVE_FIELDS_52,
+            Locale::fr_TN => &_RELATIVE_FIELDS_52,
+            Locale::fr_VU => &_RELATIVE_FIELDS_52,
+            Locale::fr_WF => &_RELATIVE_FIELDS_52,
+            Locale::fr_YT => &_RELATIVE_FIELDS_52,
+            Locale::frr => &_RELATIVE_FIELDS_0,
+            Locale::fur => &_RELATIVE_FIELDS_0,
+            Locale::fy => &_RELATIVE_FIELDS_54,
+            Locale::ga => &_RELATIVE_FIELDS_55,
+            Locale::ga_GB => &_RELATIVE_FIELDS_55,
+            Locale::gaa => &_RELATIVE_FIELDS_0,
+            Locale::gd => &_RELATIVE_FIELDS_56,
+            Locale::gez => &_RELATIVE_FIELDS_0,
+            Locale::gez_ER => &_RELATIVE_FIELDS_0,
+            Locale::gl => &_RELATIVE_FIELDS_57,
+            Locale::gn => &_RELATIVE_FIELDS_0,
+            Locale::gsw => &_RELATIVE_FIELDS_0,
+            Locale::gsw_FR => &_RELATIVE_FIELDS_0,
+            Locale::gsw_LI => &_RELATIVE_FIELDS_0,
+            Locale::gu => &_RELATIVE_FIELDS_58,
+            Locale::guz => &_RELATIVE_FIELDS_0,
+            Locale::gv => &_RELATIVE_FIELDS_0,
+            Locale::ha => &_RELATIVE_FIELDS_59,
+            Locale::ha_Arab => &_RELATIVE_FIELDS_0,
+            Locale::ha_Arab_SD => &_RELATIVE_FIELDS_0,
+            Locale::ha_GH => &_RELATIVE_FIELDS_59,
+            Locale::ha_NE => &_RELATIVE_FIELDS_59,
+            Locale::haw => &_RELATIVE_FIELDS_0,
+            Locale::he => &_RELATIVE_FIELDS_60,
+            Locale::hi => &_RELATIVE_FIELDS_61,
+            Locale::hi_Latn => &_RELATIVE_FIELDS_62,
+            Locale::hnj => &_RELATIVE_FIELDS_0,
+            Locale::hnj_Hmnp => &_RELATIVE_FIELDS_0,
+            Locale::hr => &_RELATIVE_FIELDS_63,
+            Locale::hr_BA => &_RELATIVE_FIELDS_63,
+            Locale::hsb => &_RELATIVE_FIELDS_64,
+            Locale::ht => &_RELATIVE_FIELDS_52,
+            Locale::hu => &_RELATIVE_FIELDS_65,
+            Locale::hy => &_RELATIVE_FIELDS_66,
+            Locale::ia => &_RELATIVE_FIELDS_67,
+            Locale::id => &_RELATIVE_FIELDS_68,
+            Locale::ie => &_RELATIVE_FIELDS_69,
+            Locale::ig => &_RELATIVE_FIELDS_70,
+            Locale::ii => &_RELATIVE_FIELDS_71,
+            Locale::io => &_RELATIVE_FIELDS_0,
+            Locale::is => &_RELATIVE_FIELDS_72,
+            Locale::it => &_RELATIVE_FIELDS_73,
+            Locale::it_CH => &_RELATIVE_FIELDS_73,
+            Locale::it_SM => &_RELATIVE_FIELDS_73,
+            Locale::it_VA => &_RELATIVE_FIELDS_73,
+            Locale::iu => &_RELATIVE_FIELDS_0,
+            Locale::iu_Latn => &_RELATIVE_FIELDS_0,
+            Locale::ja => &_RELATIVE_FIELDS_74,
+            Locale::jbo => &_RELATIVE_FIELDS_0,
+            Locale::jgo => &_RELATIVE_FIELDS_0,
+            Locale::jmc => &_RELATIVE_FIELDS_0,
+            Locale::jv => &_RELATIVE_FIELDS_75,
+            Locale::ka => &_RELATIVE_FIELDS_76,
+            Locale::kaa => &_RELATIVE_FIELDS_0,
+            Locale::kaa_Cyrl => &_RELATIVE_FIELDS_0,
+            Locale::kaa_Latn => &_RELATIVE_FIELDS_0,
+            Locale::kab => &_RELATIVE_FIELDS_0,
+            Locale::kaj => &_RELATIVE_FIELDS_0,
+            Locale::kam => &_RELATIVE_FIELDS_0,
+            Locale::kcg => &_RELATIVE_FIELDS_0,
+            Locale::kde => &_RELATIVE_FIELDS_0,
+            Locale::kea => &_RELATIVE_FIELDS_77,
+            Locale::kek => &_RELATIVE_FIELDS_0,
+            Locale::ken => &_RELATIVE_FIELDS_0,
+            Locale::kgp => &_RELATIVE_FIELDS_78,
+            Locale::khq => &_RELATIVE_FIELDS_0,
+            Locale::ki => &_RELATIVE_FIELDS_0,
+            Locale::kk => &_RELATIVE_FIELDS_79,
+            Locale::kk_Arab => &_RELATIVE_FIELDS_0,
+            Locale::kk_Cyrl => &_RELATIVE_FIELDS_79,
+            Locale::kk_KZ => &_RELATIVE_FIELDS_79,
+            Locale::kkj => &_RELATIVE_FIELDS_0,
+            Locale::kl => &_RELATIVE_FIELDS_0,
+            Locale::kln => &_RELATIVE_FIELDS_0,
+            Locale::km => &_RELATIVE_FIELDS_80,
+            Locale::kn => &_RELATIVE_FIELDS_81,
+            Locale::ko => &_RELATIVE_FIELDS_82,
+            Locale::ko_CN => &_RELATIVE_FIELDS_82,
+            Locale::ko_KP => &_RELATIVE_FIELDS_82,
+            Locale::kok => &_RELATIVE_FIELDS_83,
+            Locale::kok_Deva => &_RELATIVE_FIELDS_83,
+            Locale::kok_Latn => &_RELATIVE_FIELDS_84,
+            Locale::kpe => &_RELATIVE_FIELDS_0,
+            Locale::kpe_GN => &_RELATIVE_FIELDS_0,
+            Locale::ks => &_RELATIVE_FIELDS_85,
+            Locale::ks_Arab => &_RELATIVE_FIELDS_85,
+            Locale::ks_Deva => &_RELATIVE_FIELDS_86,
+            Locale::ksb => &_RELATIVE_FIELDS_0,
+            Locale::ksf => &_RELATIVE_FIELDS_0,
+            Locale::ksh => &_RELATIVE_FIELDS_0,
+            Locale::ku => &_RELATIVE_FIELDS_87,
+            Locale::ku_Arab => &_RELATIVE_FIELDS_0,
+            Locale::ku_Arab_IR => &_RELATIVE_FIELDS_0,
+            Locale::ku_Latn => &_RELATIVE_FIELDS_87,
+            Locale::ku_Latn_IQ => &_RELATIVE_FIELDS_87,
+            Locale::ku_Latn_SY => &_RELATIVE_FIELDS_87,
+            Locale::ku_TR => &_RELATIVE_FIELDS_87,
+            Locale::kw => &_RELATIVE_FIELDS_0,
+            Locale::kxv => &_RELATIVE_FIELDS_88,
+            Locale::kxv_Deva => &_RELATIVE_FIELDS_89,
+            Locale::kxv_Latn => &_RELATIVE_FIELDS_88,
+            Locale::kxv_Orya => &_RELATIVE_FIELDS_90,
+            Locale::kxv_Telu => &_RELATIVE_FIELDS_91,
+            Locale::ky => &_RELATIVE_FIELDS_92,
+            Locale::la => &_RELATIVE_FIELDS_0,
+            Locale::lag => &_RELATIVE_FIELDS_0,
+            Locale::lb => &_RELATIVE_FIELDS_93,
+            Locale::lg => &_RELATIVE_FIELDS_0,
+            Locale::lij => &_RELATIVE_FIELDS_0,
+            Locale::lkt => &_RELATIVE_FIELDS_0,
+            Locale::lld => &_RELATIVE_FIELDS_0,
+            Locale::lmo => &_RELATIVE_FIELDS_0,
+            Locale::ln => &_RELATIVE_FIELDS_0,
+            Locale::ln_AO => &_RELATIVE_FIELDS_0,
+            Locale::ln_CF => &_RELATIVE_FIELDS_0,
+            Locale::ln_CG => &_RELATIVE_FIELDS_0,
+            Locale::lo => &_RELATIVE_FIELDS_94,
+            Locale::lrc => &_RELATIVE_FIELDS_0,
+            Locale::lrc_IQ => &_RELATIVE_FIELDS_0,
+            Locale::lt => &_RELATIVE_FIELDS_95,
+            Locale::ltg => &_RELATIVE_FIELDS_0,
+            Locale::lu => &_RELATIVE_FIELDS_0,
+            Locale::luo => &_RELATIVE_FIELDS_0,
+            Locale::luy => &_RELATIVE_FIELDS_0,
+            Locale::lv => &_RELATIVE_FIELDS_96,
+            Locale::lzz => &_RELATIVE_FIELDS_0,
+            Locale::mai => &_RELATIVE_FIELDS_97,
+            Locale::mas => &_RELATIVE_FIELDS_0,
+            Locale::mas_TZ => &_RELATIVE_FIELDS_0,
+            Locale::mdf => &_RELATIVE_FIELDS_0,
+            Locale::mer => &_RELATIVE_FIELDS_0,
+            Locale::mfe => &_RELATIVE_FIELDS_0,
+            Locale::mg => &_RELATIVE_FIELDS_0,
+            Locale::mgh => &_RELATIVE_FIELDS_0,
+            Locale::mgo => &_RELATIVE_FIELDS_0,
+            Locale::mhn => &_RELATIVE_FIELDS_0,
+            Locale::mi => &_RELATIVE_FIELDS_98,
+            Locale::mic => &_RELATIVE_FIELDS_0,
+            Locale::mk => &_RELATIVE_FIELDS_99,
+            Locale::ml => &_RELATIVE_FIELDS_100,
+            Locale::mn => &_RELATIVE_FIELDS_101,
+            Locale::mn_Mong => &_RELATIVE_FIELDS_0,
+            Locale::mn_Mong_MN => &_RELATIVE_FIELDS_0,
+            Locale::mni => &_RELATIVE_FIELDS_102,
+            Locale::mni_Beng => &_RELATIVE_FIELDS_102,
+            Locale::mni_Mtei => &_RELATIVE_FIELDS_0,
+            Locale::moh => &_RELATIVE_FIELDS_0,
+            Locale::mr => &_RELATIVE_FIELDS_103,
+            Locale::ms => &_RELATIVE_FIELDS_104,
+            Locale::ms_Arab => &_RELATIVE_FIELDS_0,
+            Locale::ms_Arab_BN => &_RELATIVE_FIELDS_0,
+            Locale::ms_BN => &_RELATIVE_FIELDS_104,
+            Locale::ms_ID => &_RELATIVE_FIELDS_104,
+            Locale::ms_SG => &_RELATIVE_FIELDS_104,
+            Locale::mt => &_RELATIVE_FIELDS_105,
+            Locale::mua => &_RELATIVE_FIELDS_0,
+            Locale::mus => &_RELATIVE_FIELDS_0,
+            Locale::mww => &_RELATIVE_FIELDS_0,
+            Locale::mww_Hmnp => &_RELATIVE_FIELDS_0,
+            Locale::my => &_RELATIVE_FIELDS_106,
+            Locale::myv => &_RELATIVE_FIELDS_0,
+            Locale::mzn => &_RELATIVE_FIELDS_0,
+            Locale::naq => &_RELATIVE_FIELDS_0,
+            Locale::nb => &_RELATIVE_FIELDS_107,
+            Locale::nb_SJ => &_RELATIVE_FIELDS_107,
+            Locale::nd => &_RELATIVE_FIELDS_0,
+            Locale::nds => &_RELATIVE_FIELDS_0,
+            Locale::nds_NL => &_RELATIVE_FIELDS_0,
+            Locale::ne => &_RELATIVE_FIELDS_108,
+            Locale::ne_IN => &_RELATIVE_FIELDS_108,
+            Locale::nl => &_RELATIVE_FIELDS_109,
+            Locale::nl_AW => &_RELATIVE_FIELDS_109,
+            Locale::nl_BE => &_RELATIVE_FIELDS_109,
+            Locale::nl_BQ => &_RELATIVE_FIELDS_109,
+            Locale::nl_CW => &_RELATIVE_FIELDS_109,
+            Locale::nl_SR => &_RELATIVE_FIELDS_109,
+            Locale::nl_SX => &_RELATIVE_FIELDS_109,
+            Locale::nmg => &_RELATIVE_FIELDS_0,
+            Locale::nn => &_RELATIVE_FIELDS_110,
+            Locale::nnh => &_RELATIVE_FIELDS_0,
+            Locale::no => &_RELATIVE_FIELDS_107,
+            Locale::nqo => &_RELATIVE_FIELDS_111,
+            Locale::nr => &_RELATIVE_FIELDS_0,
+            Locale::nso => &_RELATIVE_FIELDS_0,
+            Locale::nus => &_RELATIVE_FIELDS_0,
+            Locale::nv => &_RELATIVE_FIELDS_0,
+            Locale::ny => &_RELATIVE_FIELDS_0,
+            Locale::nyn => &_RELATIVE_FIELDS_0,
+            Locale::oc => &_RELATIVE_FIELDS_0,
+            Locale::oc_ES => &_RELATIVE_FIELDS_0,
+            Locale::oka => &_RELATIVE_FIELDS_0,
+            Locale::oka_US => &_RELATIVE_FIELDS_0,
+            Locale::om => &_RELATIVE_FIELDS_112,
+            Locale::om_KE => &_RELATIVE_FIELDS_112,
+            Locale::or => &_RELATIVE_FIELDS_113,
+            Locale::os => &_RELATIVE_FIELDS_0,
+            Locale::os_RU => &_RELATIVE_FIELDS_0,
+            Locale::osa => &_RELATIVE_FIELDS_0,
+            Locale::pa => &_RELATIVE_FIELDS_114,
+            Locale::pa_Arab => &_RELATIVE_FIELDS_0,
+            Locale::pa_Guru => &_RELATIVE_FIELDS_114,
+            Locale::pap => &_RELATIVE_FIELDS_0,
+            Locale::pap_AW => &_RELATIVE_FIELDS_0,
+            Locale::pcm => &_RELATIVE_FIELDS_115,
+            Locale::pi => &_RELATIVE_FIELDS_0,
+            Locale::pi_Latn => &_RELATIVE_FIELDS_0,
+            Locale::pis => &_RELATIVE_FIELDS_0,
+            Locale::pl => &_RELATIVE_FIELDS_116,
+            Locale::pms => &_RELATIVE_FIELDS_0,
+            Locale::prg => &_RELATIVE_FIELDS_0,
+            Locale::ps => &_RELATIVE_FIELDS_117,
+            Locale::ps_PK => &_RELATIVE_FIELDS_118,
+            Locale::pt => &_RELATIVE_FIELDS_119,
+            Locale::pt_AO => &_RELATIVE_FIELDS_120,
+            Locale::pt_CH => &_RELATIVE_FIELDS_120,
+            Locale::pt_CV => &_RELATIVE_FIELDS_120,
+            Locale::pt_GQ => &_RELATIVE_FIELDS_120,
+            Locale::pt_GW => &_RELATIVE_FIELDS_120,
+            Locale::pt_LU => &_RELATIVE_FIELDS_120,
+            Locale::pt_MO => &_RELATIVE_FIELDS_120,
+            Locale::pt_MZ => &_RELATIVE_FIELDS_120,
+            Locale::pt_PT => &_RELATIVE_FIELDS_120,
+            Locale::pt_ST => &_RELATIVE_FIELDS_120,
+            Locale::pt_TL => &_RELATIVE_FIELDS_120,
+            Locale::qu => &_RELATIVE_FIELDS_121,
+            Locale::qu_BO => &_RELATIVE_FIELDS_121,
+            Locale::qu_EC => &_RELATIVE_FIELDS_121,
+            Locale::quc => &_RELATIVE_FIELDS_0,
+            Locale::raj => &_RELATIVE_FIELDS_122,
+            Locale::rhg => &_RELATIVE_FIELDS_0,
+            Locale::rhg_Rohg => &_RELATIVE_FIELDS_0,
+            Locale::rhg_Rohg_BD => &_RELATIVE_FIELDS_0,
+            Locale::rif => &_RELATIVE_FIELDS_0,
+            Locale::rm => &_RELATIVE_FIELDS_123,
+            Locale::rn => &_RELATIVE_FIELDS_0,
+            Locale::ro => &_RELATIVE_FIELDS_124,
+            Locale::ro_MD => &_RELATIVE_FIELDS_124,
+            Locale::rof => &_RELATIVE_FIELDS_0,
+            Locale::ru => &_RELATIVE_FIELDS_125,
+            Locale::ru_BY => &_RELATIVE_FIELDS_125,
+            Locale::ru_KG => &_RELATIVE_FIELDS_125,
+            Locale::ru_KZ => &_RELATIVE_FIELDS_125,
+            Locale::ru_MD => &_RELATIVE_FIELDS_125,
+            Locale::ru_UA => &_RELATIVE_FIELDS_125,
+            Locale::rw => &_RELATIVE_FIELDS_0,
+            Locale::rwk => &_RELATIVE_FIELDS_0,
+            Locale::sa => &_RELATIVE_FIELDS_126,
+            Locale::sah => &_RELATIVE_FIELDS_127,
+            Locale::saq => &_RELATIVE_FIELDS_0,
+            Locale::sat => &_RELATIVE_FIELDS_128,
+            Locale::sat_Deva => &_RELATIVE_FIELDS_0,
+            Locale::sat_Olck => &_RELATIVE_FIELDS_128,
+            Locale::sbp => &_RELATIVE_FIELDS_0,
+            Locale::sc => &_RELATIVE_FIELDS_129,
+            Locale::scn => &_RELATIVE_FIELDS_0,
+            Locale::sd => &_RELATIVE_FIELDS_130,
+            Locale::sd_Arab => &_RELATIVE_FIELDS_130,
+            Locale::sd_Deva => &_RELATIVE_FIELDS_131,
+            Locale::sdh => &_RELATIVE_FIELDS_0,
+            Locale::sdh_IQ => &_RELATIVE_FIELDS_0,
+            Locale::se => &_RELATIVE_FIELDS_0,
+            Locale::se_FI => &_RELATIVE_FIELDS_0,
+            Locale::se_SE => &_RELATIVE_FIELDS_0,
+            Locale::seh => &_RELATIVE_FIELDS_0,
+            Locale::ses => &_RELATIVE_FIELDS_0,
+            Locale::sg => &_RELATIVE_FIELDS_0,
+            Locale::sgs => &_RELATIVE_FIELDS_0,
+            Locale::shi => &_RELATIVE_FIELDS_0,
+            Locale::shi_Latn => &_RELATIVE_FIELDS_0,
+            Locale::shi_Tfng => &_RELATIVE_FIELDS_0,
+            Locale::shn => &_RELATIVE_FIELDS_0,
+            Locale::shn_TH => &_RELATIVE_FIELDS_0,
+            Locale::si => &_RELATIVE_FIELDS_132,
+            Locale::sid => &_RELATIVE_FIELDS_0,
+            Locale::sk => &_RELATIVE_FIELDS_133,
+            Locale::skr => &_RELATIVE_FIELDS_0,
+            Locale::sl => &_RELATIVE_FIELDS_134,
+            Locale::sma => &_RELATIVE_FIELDS_0,
+            Locale::sma_NO => &_RELATIVE_FIELDS_0,
+            Locale::smj => &_RELATIVE_FIELDS_0,
+            Locale::smj_NO => &_RELATIVE_FIELDS_0,
+            Locale::smn => &_RELATIVE_FIELDS_0,
+            Locale::sms => &_RELATIVE_FIELDS_0,
+            Locale::sn => &_RELATIVE_FIELDS_0,
+            Locale::so => &_RELATIVE_FIELDS_135,
+            Locale::so_DJ => &_RELATIVE_FIELDS_135,
+            Locale::so_ET => &_RELATIVE_FIELDS_135,
+            Locale::so_KE => &_RELATIVE_FIELDS_135,
+            Locale::sq => &_RELATIVE_FIELDS_136,
+            Locale::sq_MK => &_RELATIVE_FIELDS_136,
+            Locale::sq_XK => &_RELATIVE_FIELDS_136,
+            Locale::sr => &_RELATIVE_FIELDS_137,
+            Locale::sr_Cyrl => &_RELATIVE_FIELDS_137,
+            Locale::sr_Cyrl_BA => &_RELATIVE_FIELDS_138,
+            Locale::sr_Cyrl_ME => &_RELATIVE_FIELDS_0,
+            Locale::sr_Cyrl_XK => &_RELATIVE_FIELDS_137,
+            Locale::sr_Latn => &_RELATIVE_FIELDS_139,
+            Locale::sr_Latn_BA => &_RELATIVE_FIELDS_140,
+            Locale::sr_Latn_ME => &_RELATIVE_FIELDS_139,
+            Locale::sr_Latn_XK => &_RELATIVE_FIELDS_139,
+            Locale::ss => &_RELATIVE_FIELDS_0,
+            Locale::ss_SZ => &_RELATIVE_FIELDS_0,
+            Locale::ssy => &_RELATIVE_FIELDS_0,
+            Locale::st => &_RELATIVE_FIELDS_0,
+            Locale::st_LS => &_RELATIVE_FIELDS_0,
+            Locale::su => &_RELATIVE_FIELDS_141,
+            Locale::su_Latn => &_RELATIVE_FIELDS_141,
+            Locale::suz => &_RELATIVE_FIELDS_0,
+            Locale::suz_Deva => &_RELATIVE_FIELDS_0,
+            Locale::suz_Sunu => &_RELATIVE_FIELDS_0,
+            Locale::sv => &_RELATIVE_FIELDS_142,
+            Locale::sv_AX => &_RELATIVE_FIELDS_142,
+            Locale::sv_FI => &_RELATIVE_FIELDS_142,
+            Locale::sw => &_RELATIVE_FIELDS_143,
+            Locale::sw_CD => &_RELATIVE_FIELDS_143,
+            Locale::sw_KE => &_RELATIVE_FIELDS_143,
+            Locale::sw_UG => &_RELATIVE_FIELDS_143,
+            Locale::syr => &_RELATIVE_FIELDS_144,
+            Locale::syr_SY => &_RELATIVE_FIELDS_144,
+            Locale::szl => &_RELATIVE_FIELDS_0,
+            Locale::ta => &_RELATIVE_FIELDS_145,
+            Locale::ta_LK => &_RELATIVE_FIELDS_145,
+            Locale::ta_MY => &_RELATIVE_FIELDS_145,
+            Locale::ta_SG => &_RELATIVE_FIELDS_145,
+            Locale::te => &_RELATIVE_FIELDS_146,
+            Locale::teo => &_RELATIVE_FIELDS_0,
+            Locale::teo_KE => &_RELATIVE_FIELDS_0,
+            Locale::tg => &_RELATIVE_FIELDS_147,
+            Locale::th => &_RELATIVE_FIELDS_148,
+            Locale::ti => &_RELATIVE_FIELDS_149,
+            Locale::ti_ER => &_RELATIVE_FIELDS_149,
+            Locale::tig => &_RELATIVE_FIELDS_0,
+            Locale::tk => &_RELATIVE_FIELDS_150,
+            Locale::tn => &_RELATIVE_FIELDS_151,
+            Locale::tn_BW => &_RELATIVE_FIELDS_151,
+            Locale::to => &_RELATIVE_FIELDS_152,
+            Locale::tok => &_RELATIVE_FIELDS_0,
+            Locale::tpi => &_RELATIVE_FIELDS_0,
+            Locale::tr => &_RELATIVE_FIELDS_153,
+            Locale::tr_CY => &_RELATIVE_FIELDS_153,
+            Locale::trv => &_RELATIVE_FIELDS_0,
+            Locale::trw => &_RELATIVE_FIELDS_0,
+            Locale::ts => &_RELATIVE_FIELDS_0,
+            Locale::tt => &_RELATIVE_FIELDS_154,
+            Locale::twq => &_RELATIVE_FIELDS_0,
+            Locale::tyv => &_RELATIVE_FIELDS_0,
+            Locale::tzm => &_RELATIVE_FIELDS_0,
+            Locale::ug => &_RELATIVE_FIELDS_155,
+            Locale::uk => &_RELATIVE_FIELDS_156,
+            Locale::und => &_RELATIVE_FIELDS_0,
+            Locale::ur => &_RELATIVE_FIELDS_157,
+            Locale::ur_IN => &_RELATIVE_FIELDS_158,
+            Locale::uz => &_RELATIVE_FIELDS_159,
+            Locale::uz_Arab => &_RELATIVE_FIELDS_0,
+            Locale::uz_Cyrl => &_RELATIVE_FIELDS_160,
+            Locale::uz_Latn => &_RELATIVE_FIELDS_159,
+            Locale::vai => &_RELATIVE_FIELDS_0,
+            Locale::vai_Latn => &_RELATIVE_FIELDS_0,
+            Locale::vai_Vaii => &_RELATIVE_FIELDS_0,
+            Locale::ve => &_RELATIVE_FIELDS_0,
+            Locale::vec => &_RELATIVE_FIELDS_161,
+            Locale::vi => &_RELATIVE_FIELDS_162,
+            Locale::vmw => &_RELATIVE_FIELDS_0,
+            Locale::vo => &_RELATIVE_FIELDS_0,
+            Locale::vun => &_RELATIVE_FIELDS_0,
+            Locale::wa => &_RELATIVE_FIELDS_0,
+            Locale::wae => &_RELATIVE_FIELDS_0,
+            Locale::wal => &_RELATIVE_FIELDS_0,
+            Locale::wbp => &_RELATIVE_FIELDS_0,
+            Locale::wo => &_RELATIVE_FIELDS_163,
+            Locale::xh => &_RELATIVE_FIELDS_164,
+            Locale::xnr => &_RELATIVE_FIELDS_165,
+            Locale::xog => &_RELATIVE_FIELDS_0,
+            Locale::yav => &_RELATIVE_FIELDS_0,
+            Locale::yi => &_RELATIVE_FIELDS_0,
+            Locale::yo => &_RELATIVE_FIELDS_166,
+            Locale::yo_BJ => &_RELATIVE_FIELDS_167,
+            Locale::yrl => &_RELATIVE_FIELDS_168,
+            Locale::yrl_CO => &_RELATIVE_FIELDS_168,
+            Locale::yrl_VE => &_RELATIVE_FIELDS_168,
+            Locale::yue => &_RELATIVE_FIELDS_169,
+            Locale::yue_Hans => &_RELATIVE_FIELDS_170,
+            Locale::yue_Hant => &_RELATIVE_FIELDS_169,
+            Locale::yue_Hant_CN => &_RELATIVE_FIELDS_0,
+            Locale::yue_Hant_MO => &_RELATIVE_FIELDS_169,
+            Locale::za => &_RELATIVE_FIELDS_0,
+            Locale::zgh => &_RELATIVE_FIELDS_0,
+            Locale::zh => &_RELATIVE_FIELDS_171,
+            Locale::zh_Hans => &_RELATIVE_FIELDS_171,
+            Locale::zh_Hans_HK => &_RELATIVE_FIELDS_0,
+            Locale::zh_Hans_MO => &_RELATIVE_FIELDS_0,
+            Locale::zh_Hans_MY => &_RELATIVE_FIELDS_171,
+            Locale::zh_Hans_SG => &_RELATIVE_FIELDS_172,
+            Locale::zh_Hant => &_RELATIVE_FIELDS_173,
+            Locale::zh_Hant_HK => &_RELATIVE_FIELDS_174,
+            Locale::zh_Hant_MO => &_RELATIVE_FIELDS_174,
+            Locale::zh_Hant_MY => &_RELATIVE_FIELDS_173,
+            Locale::zh_Latn => &_RELATIVE_FIELDS_0,
+            Locale::zu => &_RELATIVE_FIELDS_175,
+        }
+    }
+}
+
+#[rustfmt::skip]
+static _FIELD_0: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "", "", "", "", "+{0} min"], past: ["", "", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_2: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_3: _RelativeField = _RelativeField { relatives: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_4: _RelativeField = _RelativeField { relatives: &[(-1, "last week"), (0, "this week"), (1, "next week")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_5: _RelativeField = _RelativeField { relatives: &[(-1, "last month"), (0, "this month"), (1, "next month")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_6: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "", "", "", "", "+{0} Q"], past: ["", "", "", "", "", "-{0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_7: _RelativeField = _RelativeField { relatives: &[(-1, "last year"), (0, "this year"), (1, "next year")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_0: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_3,
+    &_FIELD_3,
+    &_FIELD_3,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_8: _RelativeField = _RelativeField { relatives: &[(0, "nou")], future: ["", "oor {0} sekonde", "", "", "", "oor {0} sekondes"], past: ["", "{0} sekonde gelede", "", "", "", "{0} sekondes gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_9: _RelativeField = _RelativeField { relatives: &[(0, "nou")], future: ["", "oor {0} s.", "", "", "", "oor {0} s."], past: ["", "{0} s. gelede", "", "", "", "{0} s. gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_10: _RelativeField = _RelativeField { relatives: &[(0, "hierdie minuut")], future: ["", "oor {0} minuut", "", "", "", "oor {0} minute"], past: ["", "{0} minuut gelede", "", "", "", "{0} minute gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_11: _RelativeField = _RelativeField { relatives: &[(0, "hierdie minuut")], future: ["", "oor {0} min.", "", "", "", "oor {0} min."], past: ["", "{0} min. gelede", "", "", "", "{0} min. gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_12: _RelativeField = _RelativeField { relatives: &[(0, "hierdie uur")], future: ["", "oor {0} uur", "", "", "", "oor {0} uur"], past: ["", "{0} uur gelede", "", "", "", "{0} uur gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_13: _RelativeField = _RelativeField { relatives: &[(0, "hierdie uur")], future: ["", "oor {0} u.", "", "", "", "oor {0} u."], past: ["", "{0} u. gelede", "", "", "", "{0} u. gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_14: _RelativeField = _RelativeField { relatives: &[(-2, "eergister"), (-1, "gister"), (0, "vandag"), (1, "môre"), (2, "oormôre")], future: ["", "oor {0} dag", "", "", "", "oor {0} dae"], past: ["", "{0} dag gelede", "", "", "", "{0} dae gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_15: _RelativeField = _RelativeField { relatives: &[(-1, "verlede week"), (0, "hierdie week"), (1, "volgende week")], future: ["", "oor {0} week", "", "", "", "oor {0} weke"], past: ["", "{0} week gelede", "", "", "", "{0} weke gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_16: _RelativeField = _RelativeField { relatives: &[(-1, "verlede w."), (0, "hierdie w."), (1, "volgende w.")], future: ["", "oor {0} w.", "", "", "", "oor {0} w."], past: ["", "{0} w. gelede", "", "", "", "{0} w. gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_17: _RelativeField = _RelativeField { relatives: &[(-1, "verlede maand"), (0, "vandeesmaand"), (1, "volgende maand")], future: ["", "oor {0} maand", "", "", "", "oor {0} maande"], past: ["", "{0} maand gelede", "", "", "", "{0} maande gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_18: _RelativeField = _RelativeField { relatives: &[(-1, "verlede md."), (0, "hierdie md."), (1, "volgende md.")], future: ["", "oor {0} md.", "", "", "", "oor {0} md."], past: ["", "{0} md. gelede", "", "", "", "{0} md. gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_19: _RelativeField = _RelativeField { relatives: &[(-1, "verlede kwartaal"), (0, "hierdie kwartaal"), (1, "volgende kwartaal")], future: ["", "oor {0} kwartaal", "", "", "", "oor {0} kwartale"], past: ["", "{0} kwartaal gelede", "", "", "", "{0} kwartale gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_20: _RelativeField = _RelativeField { relatives: &[(-1, "verlede kwartaal"), (0, "hierdie kwartaal"), (1, "volgende kwartaal")], future: ["", "oor {0} kw.", "", "", "", "oor {0} kw."], past: ["", "{0} kw. gelede", "", "", "", "{0} kw. gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_21: _RelativeField = _RelativeField { relatives: &[(-1, "verlede jaar"), (0, "hierdie jaar"), (1, "volgende jaar")], future: ["", "oor {0} jaar", "", "", "", "oor {0} jaar"], past: ["", "{0} jaar gelede", "", "", "", "{0} jaar gelede"] };
+
+#[rustfmt::skip]
+static _FIELD_22: _RelativeField = _RelativeField { relatives: &[(-1, "verlede j."), (0, "hierdie j."), (1, "volgende j.")], future: ["", "oor {0} j.", "", "", "", "oor {0} j."], past: ["", "{0} j. gelede", "", "", "", "{0} j. gelede"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_1: [&_RelativeField; 24] = [
+    &_FIELD_8,
+    &_FIELD_9,
+    &_FIELD_9,
+    &_FIELD_10,
+    &_FIELD_11,
+    &_FIELD_11,
+    &_FIELD_12,
+    &_FIELD_13,
+    &_FIELD_13,
+    &_FIELD_14,
+    &_FIELD_14,
+    &_FIELD_14,
+    &_FIELD_15,
+    &_FIELD_16,
+    &_FIELD_16,
+    &_FIELD_17,
+    &_FIELD_18,
+    &_FIELD_18,
+    &_FIELD_19,
+    &_FIELD_20,
+    &_FIELD_20,
+    &_FIELD_21,
+    &_FIELD_22,
+    &_FIELD_22
+];
+
+#[rustfmt::skip]
+static _FIELD_23: _RelativeField = _RelativeField { relatives: &[(0, "seesei")], future: ["", "anibuo {0} mu", "", "", "", "nnibuo {0} mu"], past: ["", "anibuo {0} a atwam", "", "", "", "nnibuo {0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_24: _RelativeField = _RelativeField { relatives: &[(0, "sima yi")], future: ["", "sima {0} mu", "", "", "", "sima {0} mu"], past: ["", "sima {0} a atwam", "", "", "", "sima {0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_25: _RelativeField = _RelativeField { relatives: &[(0, "dɔnhwere yi")], future: ["", "dɔnhwere {0} mu", "", "", "", "nnɔnhwere {0} mu"], past: ["", "dɔnhwere {0} a atwam", "", "", "", "nnɔnhwere {0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_26: _RelativeField = _RelativeField { relatives: &[(-1, "nnora"), (0, "ɛnnɛ"), (1, "Ɔkyena")], future: ["", "da {0} mu", "", "", "", "nna {0} mu"], past: ["", "da{0} a atwam", "", "", "", "nna{0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_27: _RelativeField = _RelativeField { relatives: &[(-1, "Ndeda ɛnnora"), (0, "Ndɛ ɛnnɛ"), (1, "Ɔkyena")], future: ["", "da {0} mu", "", "", "", "nna {0} mu"], past: ["", "da{0} a atwam", "", "", "", "nna{0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_28: _RelativeField = _RelativeField { relatives: &[(-1, "ɛnnora"), (0, "ɛnnɛ"), (1, "Ɔkyena")], future: ["", "da {0} mu", "", "", "", "nna {0} mu"], past: ["", "da{0} a atwam", "", "", "", "nna{0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_29: _RelativeField = _RelativeField { relatives: &[(-1, "nnawɔtwe a atwam"), (0, "nnawɔtwe yi"), (1, "nnawɔtwe a ɛdi hɔ")], future: ["", "nnawɔtwe {0} mu", "", "", "", "nnawɔtwe {0} mu"], past: ["", "nnawɔtwe{0} a atwam", "", "", "", "nnawɔtwe{0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_30: _RelativeField = _RelativeField { relatives: &[(-1, "nnawɔtwe a atwam"), (0, "nnawɔtwe yi"), (1, "nnawɔtwe a ɛdi hɔ")], future: ["", "nnawɔtwe {0} mu.", "", "", "", "nnawɔtwe {0} mu"], past: ["", "nnawɔtwe{0} a atwam", "", "", "", "nnawɔtwe {0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_31: _RelativeField = _RelativeField { relatives: &[(-1, "bosome a atwam"), (0, "bosome yi"), (1, "bosome a ɛdi hɔ")], future: ["", "bosome{0} mu", "", "", "", "abosome{0} mu"], past: ["", "-{0} bosome a atwam", "", "", "", "abosome{0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_32: _RelativeField = _RelativeField { relatives: &[(-1, "bosome a atwam"), (0, "bosome yi"), (1, "bosome a ɛdi hɔ")], future: ["", "bosome {0} mu", "", "", "", "abosome{0} mu"], past: ["", "bosome {0} a atwam", "", "", "", "abosome{0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_33: _RelativeField = _RelativeField { relatives: &[(-1, "kɔta a atwam"), (0, "kɔta yi"), (1, "kɔta a ɛdi hɔ")], future: ["", "kɔta {0} mu", "", "", "", "kɔta ahodoɔ {0} mu"], past: ["", "kɔta {0} a atwam", "", "", "", "kɔta ahodoɔ {0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_34: _RelativeField = _RelativeField { relatives: &[(-1, "kɔta a atwam"), (0, "kɔta yi"), (1, "kɔta a ɛdi hɔ")], future: ["", "kɔta {0} mu", "", "", "", "kɔta {0} mu"], past: ["", "kɔta {0} a atwam", "", "", "", "kɔta {0} a atwam"] };
+
+#[rustfmt::skip]
+static _FIELD_35: _RelativeField = _RelativeField { relatives: &[(-1, "afe a atwam"), (0, "afe yi"), (1, "afe a ɛdi hɔ")], future: ["", "afe {0} mu", "", "", "", "mfeɛ {0} mu"], past: ["", "afe {0} a atwam", "", "", "", "mfeɛ {0} a atwam"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_2: [&_RelativeField; 24] = [
+    &_FIELD_23,
+    &_FIELD_23,
+    &_FIELD_23,
+    &_FIELD_24,
+    &_FIELD_24,
+    &_FIELD_24,
+    &_FIELD_25,
+    &_FIELD_25,
+    &_FIELD_25,
+    &_FIELD_26,
+    &_FIELD_27,
+    &_FIELD_28,
+    &_FIELD_29,
+    &_FIELD_29,
+    &_FIELD_30,
+    &_FIELD_31,
+    &_FIELD_32,
+    &_FIELD_32,
+    &_FIELD_33,
+    &_FIELD_33,
+    &_FIELD_34,
+    &_FIELD_35,
+    &_FIELD_35,
+    &_FIELD_35
+];
+
+#[rustfmt::skip]
+static _FIELD_36: _RelativeField = _RelativeField { relatives: &[(0, "አሁን")], future: ["", "በ{0} ሰከንድ ውስጥ", "", "", "", "በ{0} ሰከንዶች ውስጥ"], past: ["", "ከ{0} ሰከንድ በፊት", "", "", "", "ከ{0} ሰከንዶች በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_37: _RelativeField = _RelativeField { relatives: &[(0, "ይህ ደቂቃ")], future: ["", "በ{0} ደቂቃ ውስጥ", "", "", "", "በ{0} ደቂቃዎች ውስጥ"], past: ["", "ከ{0} ደቂቃ በፊት", "", "", "", "ከ{0} ደቂቃዎች በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_38: _RelativeField = _RelativeField { relatives: &[(0, "ይህ ሰዓት")], future: ["", "በ{0} ሰዓት ውስጥ", "", "", "", "በ{0} ሰዓቶች ውስጥ"], past: ["", "ከ{0} ሰዓት በፊት", "", "", "", "ከ{0} ሰዓቶች በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_39: _RelativeField = _RelativeField { relatives: &[(-2, "ከትናንት ወዲያ"), (-1, "ትናንት"), (0, "ዛሬ"), (1, "ነገ"), (2, "ከነገ ወዲያ")], future: ["", "በ{0} ቀን ውስጥ", "", "", "", "በ{0} ቀናት ውስጥ"], past: ["", "ከ{0} ቀን በፊት", "", "", "", "ከ{0} ቀናት በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_40: _RelativeField = _RelativeField { relatives: &[(-2, "ከትናንት ወዲያ"), (-1, "ትላንትና"), (0, "ዛሬ"), (1, "ነገ"), (2, "ከነገ ወዲያ")], future: ["", "በ{0} ቀን ውስጥ", "", "", "", "በ{0} ቀኖች ውስጥ"], past: ["", "ከ {0} ቀን በፊት", "", "", "", "ከ{0} ቀኖች በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_41: _RelativeField = _RelativeField { relatives: &[(-1, "ያለፈው ሳምንት"), (0, "በዚህ ሳምንት"), (1, "የሚቀጥለው ሳምንት")], future: ["", "በ{0} ሳምንት ውስጥ", "", "", "", "በ{0} ሳምንታት ውስጥ"], past: ["", "ከ{0} ሳምንት በፊት", "", "", "", "ከ{0} ሳምንታት በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_42: _RelativeField = _RelativeField { relatives: &[(-1, "ባለፈው ሳምንት"), (0, "በዚህ ሣምንት"), (1, "የሚቀጥለው ሳምንት")], future: ["", "በ{0} ሳምንታት ውስጥ", "", "", "", "በ{0} ሳምንታት ውስጥ"], past: ["", "ከ{0} ሳምንታት በፊት", "", "", "", "ከ{0} ሳምንታት በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_43: _RelativeField = _RelativeField { relatives: &[(-1, "ያለፈው ወር"), (0, "በዚህ ወር"), (1, "የሚቀጥለው ወር")], future: ["", "በ{0} ወር ውስጥ", "", "", "", "በ{0} ወራት ውስጥ"], past: ["", "ከ{0} ወር በፊት", "", "", "", "ከ{0} ወራት በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_44: _RelativeField = _RelativeField { relatives: &[(-1, "ያለፈው ወር"), (0, "በዚህ ወር"), (1, "የሚቀጥለው ወር")], future: ["", "በ{0} ወራት ውስጥ", "", "", "", "በ{0} ወራት ውስጥ"], past: ["", "ከ{0} ወራት በፊት", "", "", "", "ከ{0} ወራት በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_45: _RelativeField = _RelativeField { relatives: &[(-1, "የመጨረሻው ሩብ"), (0, "ይህ ሩብ"), (1, "የሚቀጥለው ሩብ")], future: ["", "+{0} ሩብ", "", "", "", "+{0} ሩብ"], past: ["", "{0} ሩብ በፊት", "", "", "", "{0} ሩብ በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_46: _RelativeField = _RelativeField { relatives: &[(-1, "ያለፈው ዓመት"), (0, "በዚህ ዓመት"), (1, "የሚቀጥለው ዓመት")], future: ["", "በ{0} ዓመታት ውስጥ", "", "", "", "በ{0} ዓመታት ውስጥ"], past: ["", "ከ{0} ዓመት በፊት", "", "", "", "ከ{0} ዓመታት በፊት"] };
+
+#[rustfmt::skip]
+static _FIELD_47: _RelativeField = _RelativeField { relatives: &[(-1, "ያለፈው ዓመት"), (0, "በዚህ ዓመት"), (1, "የሚቀጥለው ዓመት")], future: ["", "በ{0} ዓመታት ውስጥ", "", "", "", "በ{0} ዓመታት ውስጥ"], past: ["", "ከ{0} ዓመታት በፊት", "", "", "", "ከ{0} ዓመታት በፊት"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_3: [&_RelativeField; 24] = [
+    &_FIELD_36,
+    &_FIELD_36,
+    &_FIELD_36,
+    &_FIELD_37,
+    &_FIELD_37,
+    &_FIELD_37,
+    &_FIELD_38,
+    &_FIELD_38,
+    &_FIELD_38,
+    &_FIELD_39,
+    &_FIELD_40,
+    &_FIELD_40,
+    &_FIELD_41,
+    &_FIELD_42,
+    &_FIELD_42,
+    &_FIELD_43,
+    &_FIELD_44,
+    &_FIELD_44,
+    &_FIELD_45,
+    &_FIELD_45,
+    &_FIELD_45,
+    &_FIELD_46,
+    &_FIELD_47,
+    &_FIELD_47
+];
+
+#[rustfmt::skip]
+static _FIELD_48: _RelativeField = _RelativeField { relatives: &[(0, "الآن")], future: ["خلال {0} ثانية", "خلال ثانية واحدة", "خلال ثانيتين", "خلال {0} ثوان\u{64d}", "خلال {0} ثانية", "خلال {0} ثانية"], past: ["قبل {0} ثانية", "قبل ثانية واحدة", "قبل ثانيتين", "قبل {0} ثوان\u{650}", "قبل {0} ثانية", "قبل {0} ثانية"] };
+
+#[rustfmt::skip]
+static _FIELD_49: _RelativeField = _RelativeField { relatives: &[(0, "الآن")], future: ["خلال {0} ثانية", "خلال ثانية واحدة", "خلال ثانيتين", "خلال {0} ثوان\u{64d}", "خلال {0} ثانية", "خلال {0} ثانية"], past: ["قبل {0} ثانية", "قبل ثانية واحدة", "قبل ثانيتين", "قبل {0} ثوان\u{64d}", "قبل {0} ثانية", "قبل {0} ثانية"] };
+
+#[rustfmt::skip]
+static _FIELD_50: _RelativeField = _RelativeField { relatives: &[(0, "هذه الدقيقة")], future: ["خلال {0} دقيقة", "خلال دقيقة واحدة", "خلال دقيقتين", "خلال {0} دقائق", "خلال {0} دقيقة", "خلال {0} دقيقة"], past: ["قبل {0} دقيقة", "قبل دقيقة واحدة", "قبل دقيقتين", "قبل {0} دقائق", "قبل {0} دقيقة", "قبل {0} دقيقة"] };
+
+#[rustfmt::skip]
+static _FIELD_51: _RelativeField = _RelativeField { relatives: &[(0, "الساعة الحالية")], future: ["خلال {0} ساعة", "خلال ساعة واحدة", "خلال ساعتين", "خلال {0} ساعات", "خلال {0} ساعة", "خلال {0} ساعة"], past: ["قبل {0} ساعة", "قبل ساعة واحدة", "قبل ساعتين", "قبل {0} ساعات", "قبل {0} ساعة", "قبل {0} ساعة"] };
+
+#[rustfmt::skip]
+static _FIELD_52: _RelativeField = _RelativeField { relatives: &[(-2, "أول أمس"), (-1, "أمس"), (0, "اليوم"), (1, "غد\u{64b}ا"), (2, "بعد الغد")], future: ["خلال {0} يوم", "خلال يوم واحد", "خلال يومين", "خلال {0} أيام", "خلال {0} يوم\u{64b}ا", "خلال {0} يوم"], past: ["قبل {0} يوم", "قبل يوم واحد", "قبل يومين", "قبل {0} أيام", "قبل {0} يوم\u{64b}ا", "قبل {0} يوم"] };
+
+#[rustfmt::skip]
+static _FIELD_53: _RelativeField = _RelativeField { relatives: &[(-1, "الأسبوع الماضي"), (0, "هذا الأسبوع"), (1, "الأسبوع القادم")], future: ["خلال {0} أسبوع", "خلال أسبوع واحد", "خلال أسبوعين", "خلال {0} أسابيع", "خلال {0} أسبوع\u{64b}ا", "خلال {0} أسبوع"], past: ["قبل {0} أسبوع", "قبل أسبوع واحد", "قبل أسبوعين", "قبل {0} أسابيع", "قبل {0} أسبوع\u{64b}ا", "قبل {0} أسبوع"] };
+
+#[rustfmt::skip]
+static _FIELD_54: _RelativeField = _RelativeField { relatives: &[(-1, "الأسبوع الماضي"), (0, "هذا الأسبوع"), (1, "الأسبوع القادم")], future: ["خلال {0} أسبوع", "خلال أسبوع واحد", "خلال {0} أسبوعين", "خلال {0} أسابيع", "خلال {0} أسبوع\u{64b}ا", "خلال {0} أسبوع"], past: ["قبل {0} أسبوع", "قبل أسبوع واحد", "قبل أسبوعين", "قبل {0} أسابيع", "قبل {0} أسبوع\u{64b}ا", "قبل {0} أسبوع"] };
+
+#[rustfmt::skip]
+static _FIELD_55: _RelativeField = _RelativeField { relatives: &[(-1, "الشهر الماضي"), (0, "هذا الشهر"), (1, "الشهر القادم")], future: ["خلال {0} شهر", "خلال شهر واحد", "خلال شهرين", "خلال {0} أشهر", "خلال {0} شهر\u{64b}ا", "خلال {0} شهر"], past: ["قبل {0} شهر", "قبل شهر واحد", "قبل شهرين", "قبل {0} أشهر", "قبل {0} شهر\u{64b}ا", "قبل {0} شهر"] };
+
+#[rustfmt::skip]
+static _FIELD_56: _RelativeField = _RelativeField { relatives: &[(-1, "الشهر الماضي"), (0, "هذا الشهر"), (1, "الشهر القادم")], future: ["خلال {0} شهر", "خلال شهر واحد", "خلال شهرين", "خلال {0} أشهر", "خلال {0} شهر\u{64b}ا", "خلال {0} شهر"], past: ["قبل {0} شهر", "قبل شهر واحد", "قبل شهرين", "خلال {0} أشهر", "قبل {0} شهر\u{64b}ا", "قبل {0} شهر"] };
+
+#[rustfmt::skip]
+static _FIELD_57: _RelativeField = _RelativeField { relatives: &[(-1, "الربع الأخير"), (0, "هذا الربع"), (1, "الربع القادم")], future: ["خلال {0} ربع سنة", "خلال ربع سنة واحد", "خلال ربعي سنة", "خلال {0} أرباع سنة", "خلال {0} ربع سنة", "خلال {0} ربع سنة"], past: ["قبل {0} ربع سنة", "قبل ربع سنة واحد", "قبل ربعي سنة", "قبل {0} أرباع سنة", "قبل {0} ربع سنة", "قبل {0} ربع سنة"] };
+
+#[rustfmt::skip]
+static _FIELD_58: _RelativeField = _RelativeField { relatives: &[(-1, "السنة الماضية"), (0, "السنة الحالية"), (1, "السنة القادمة")], future: ["خلال {0} سنة", "خلال سنة واحدة", "خلال سنتين", "خلال {0} سنوات", "خلال {0} سنة", "خلال {0} سنة"], past: ["قبل {0} سنة", "قبل سنة واحدة", "قبل سنتين", "قبل {0} سنوات", "قبل {0} سنة", "قبل {0} سنة"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_4: [&_RelativeField; 24] = [
+    &_FIELD_48,
+    &_FIELD_49,
+    &_FIELD_49,
+    &_FIELD_50,
+    &_FIELD_50,
+    &_FIELD_50,
+    &_FIELD_51,
+    &_FIELD_51,
+    &_FIELD_51,
+    &_FIELD_52,
+    &_FIELD_52,
+    &_FIELD_52,
+    &_FIELD_53,
+    &_FIELD_54,
+    &_FIELD_53,
+    &_FIELD_55,
+    &_FIELD_56,
+    &_FIELD_55,
+    &_FIELD_57,
+    &_FIELD_57,
+    &_FIELD_57,
+    &_FIELD_58,
+    &_FIELD_58,
+    &_FIELD_58
+];
+
+#[rustfmt::skip]
+static _FIELD_59: _RelativeField = _RelativeField { relatives: &[(-1, "السنة الماضية"), (0, "هذه السنة"), (1, "السنة التالية")], future: ["خلال {0} سنة", "خلال سنة واحدة", "خلال سنتين", "خلال {0} سنوات", "خلال {0} سنة", "خلال {0} سنة"], past: ["قبل {0} سنة", "قبل سنة واحدة", "قبل سنتين", "قبل {0} سنوات", "قبل {0} سنة", "قبل {0} سنة"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_5: [&_RelativeField; 24] = [
+    &_FIELD_48,
+    &_FIELD_49,
+    &_FIELD_49,
+    &_FIELD_50,
+    &_FIELD_50,
+    &_FIELD_50,
+    &_FIELD_51,
+    &_FIELD_51,
+    &_FIELD_51,
+    &_FIELD_52,
+    &_FIELD_52,
+    &_FIELD_52,
+    &_FIELD_53,
+    &_FIELD_54,
+    &_FIELD_53,
+    &_FIELD_55,
+    &_FIELD_56,
+    &_FIELD_55,
+    &_FIELD_57,
+    &_FIELD_57,
+    &_FIELD_57,
+    &_FIELD_59,
+    &_FIELD_59,
+    &_FIELD_59
+];
+
+#[rustfmt::skip]
+static _FIELD_60: _RelativeField = _RelativeField { relatives: &[(0, "এতিয\u{9bc}\u{9be}")], future: ["", "{0} ছেকেণ\u{9cd}ডত", "", "", "", "{0} ছেকেণ\u{9cd}ডত"], past: ["", "{0} ছেকেণ\u{9cd}ড প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} ছেকেণ\u{9cd}ড প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_61: _RelativeField = _RelativeField { relatives: &[(0, "এইটো মিনিটত")], future: ["", "{0} মিনিটত", "", "", "", "{0} মিনিটত"], past: ["", "{0} মিনিট প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} মিনিট প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_62: _RelativeField = _RelativeField { relatives: &[(0, "এইটো ঘণ\u{9cd}ট\u{9be}ত")], future: ["", "{0} ঘণ\u{9cd}ট\u{9be}ত", "", "", "", "{0} ঘণ\u{9cd}ট\u{9be}ত"], past: ["", "{0} ঘণ\u{9cd}ট\u{9be} প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} ঘণ\u{9cd}ট\u{9be} প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_63: _RelativeField = _RelativeField { relatives: &[(-2, "পৰহি"), (-1, "ক\u{9be}লি"), (0, "আজি"), (1, "ক\u{9be}ইলৈ"), (2, "পৰহিলৈ")], future: ["", "{0} দিনত", "", "", "", "{0} দিনত"], past: ["", "{0} দিন প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} দিন প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_64: _RelativeField = _RelativeField { relatives: &[(-1, "যোৱ\u{9be} সপ\u{9cd}ত\u{9be}হ"), (0, "এই সপ\u{9cd}ত\u{9be}হ"), (1, "অহ\u{9be} সপ\u{9cd}ত\u{9be}হ")], future: ["", "{0} সপ\u{9cd}ত\u{9be}হত", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হত"], past: ["", "{0} সপ\u{9cd}ত\u{9be}হ প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_65: _RelativeField = _RelativeField { relatives: &[(-1, "যোৱ\u{9be} ম\u{9be}হ"), (0, "এই ম\u{9be}হ"), (1, "অহ\u{9be} ম\u{9be}হ")], future: ["", "{0} ম\u{9be}হত", "", "", "", "{0} ম\u{9be}হত"], past: ["", "{0} ম\u{9be}হ প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} ম\u{9be}হ প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_66: _RelativeField = _RelativeField { relatives: &[(-1, "যোৱ\u{9be} ম\u{9be}."), (0, "এই ম\u{9be}."), (1, "অহ\u{9be} ম\u{9be}হ")], future: ["", "{0} ম\u{9be}হত", "", "", "", "{0} ম\u{9be}হত"], past: ["", "{0} ম\u{9be}হ প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} ম\u{9be}হ প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_67: _RelativeField = _RelativeField { relatives: &[(-1, "যোৱ\u{9be} তিনি ম\u{9be}হ"), (0, "এই তিনি ম\u{9be}হ"), (1, "অহ\u{9be} তিনি ম\u{9be}হ")], future: ["", "{0} তিনি ম\u{9be}হত", "", "", "", "{0} তিনি ম\u{9be}হত"], past: ["", "{0} তিনি ম\u{9be}হ প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} তিনি ম\u{9be}হ প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_68: _RelativeField = _RelativeField { relatives: &[(-1, "যোৱ\u{9be} বছৰ"), (0, "এই বছৰ"), (1, "অহ\u{9be} বছৰ")], future: ["", "{0} বছৰত", "", "", "", "{0} বছৰত"], past: ["", "{0} বছৰৰ প\u{9c2}ৰ\u{9cd}বে", "", "", "", "{0} বছৰৰ প\u{9c2}ৰ\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_6: [&_RelativeField; 24] = [
+    &_FIELD_60,
+    &_FIELD_60,
+    &_FIELD_60,
+    &_FIELD_61,
+    &_FIELD_61,
+    &_FIELD_61,
+    &_FIELD_62,
+    &_FIELD_62,
+    &_FIELD_62,
+    &_FIELD_63,
+    &_FIELD_63,
+    &_FIELD_63,
+    &_FIELD_64,
+    &_FIELD_64,
+    &_FIELD_64,
+    &_FIELD_65,
+    &_FIELD_65,
+    &_FIELD_66,
+    &_FIELD_67,
+    &_FIELD_67,
+    &_FIELD_67,
+    &_FIELD_68,
+    &_FIELD_68,
+    &_FIELD_68
+];
+
+#[rustfmt::skip]
+static _FIELD_69: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "en {0} segundu", "", "", "", "en {0} segundos"], past: ["", "hai {0} segundu", "", "", "", "hai {0} segundos"] };
+
+#[rustfmt::skip]
+static _FIELD_70: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "en {0} seg.", "", "", "", "en {0} seg."], past: ["", "hai {0} seg.", "", "", "", "hai {0} seg."] };
+
+#[rustfmt::skip]
+static _FIELD_71: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "en {0} s.", "", "", "", "en {0} s."], past: ["", "hai {0} s.", "", "", "", "hai {0} s."] };
+
+#[rustfmt::skip]
+static _FIELD_72: _RelativeField = _RelativeField { relatives: &[(0, "esti minutu")], future: ["", "en {0} minutu", "", "", "", "en {0} minutos"], past: ["", "hai {0} minutu", "", "", "", "hai {0} minutos"] };
+
+#[rustfmt::skip]
+static _FIELD_73: _RelativeField = _RelativeField { relatives: &[(0, "esti min.")], future: ["", "en {0} min.", "", "", "", "en {0} min."], past: ["", "hai {0} min.", "", "", "", "hai {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_74: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "en {0} hora", "", "", "", "en {0} hores"], past: ["", "hai {0} hora", "", "", "", "hai {0} hores"] };
+
+#[rustfmt::skip]
+static _FIELD_75: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "en {0} h.", "", "", "", "en {0} h."], past: ["", "hai {0} h.", "", "", "", "hai {0} h."] };
+
+#[rustfmt::skip]
+static _FIELD_76: _RelativeField = _RelativeField { relatives: &[(0, "esta h.")], future: ["", "en {0} h.", "", "", "", "en {0} h."], past: ["", "hai {0} h.", "", "", "", "hai {0} h."] };
+
+#[rustfmt::skip]
+static _FIELD_77: _RelativeField = _RelativeField { relatives: &[(-2, "antayeri"), (-1, "ayeri"), (0, "güei"), (1, "mañana"), (2, "pasao mañana")], future: ["", "en {0} día", "", "", "", "en {0} díes"], past: ["", "hai {0} día", "", "", "", "hai {0} díes"] };
+
+#[rustfmt::skip]
+static _FIELD_78: _RelativeField = _RelativeField { relatives: &[(-2, "antay."), (-1, "ayeri"), (0, "güei"), (1, "mañ."), (2, "p. mañ.")], future: ["", "en {0} d.", "", "", "", "en {0} d."], past: ["", "hai {0} d.", "", "", "", "hai {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_79: _RelativeField = _RelativeField { relatives: &[(-1, "la selmana pasada"), (0, "esta selmana"), (1, "la selmana viniente")], future: ["", "en {0} selmana", "", "", "", "en {0} selmanes"], past: ["", "hai {0} selmana", "", "", "", "hai {0} selmanes"] };
+
+#[rustfmt::skip]
+static _FIELD_80: _RelativeField = _RelativeField { relatives: &[(-1, "selm. pasada"), (0, "esta selm."), (1, "selm. viniente")], future: ["", "en {0} selm.", "", "", "", "en {0} selm."], past: ["", "hai {0} selm.", "", "", "", "hai {0} selm."] };
+
+#[rustfmt::skip]
+static _FIELD_81: _RelativeField = _RelativeField { relatives: &[(-1, "selm. pas."), (0, "esta selm."), (1, "selm. vin.")], future: ["", "en {0} se.", "", "", "", "en {0} se."], past: ["", "hai {0} se.", "", "", "", "hai {0} se."] };
+
+#[rustfmt::skip]
+static _FIELD_82: _RelativeField = _RelativeField { relatives: &[(-1, "el mes pasáu"), (0, "esti mes"), (1, "el mes viniente")], future: ["", "en {0} mes", "", "", "", "en {0} meses"], past: ["", "hai {0} mes", "", "", "", "hai {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_83: _RelativeField = _RelativeField { relatives: &[(-1, "mes pas."), (0, "esti mes"), (1, "mes vin.")], future: ["", "en {0} mes", "", "", "", "en {0} meses"], past: ["", "hai {0} mes", "", "", "", "hai {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_84: _RelativeField = _RelativeField { relatives: &[(-1, "mes pas."), (0, "esti mes"), (1, "mes vin.")], future: ["", "en {0} m.", "", "", "", "en {0} m."], past: ["", "hai {0} m.", "", "", "", "hai {0} m."] };
+
+#[rustfmt::skip]
+static _FIELD_85: _RelativeField = _RelativeField { relatives: &[(-1, "trimestre anterior"), (0, "esti trimestre"), (1, "trimestre viniente")], future: ["", "en {0} trimestre", "", "", "", "en {0} trimestres"], past: ["", "hai {0} trimestre", "", "", "", "hai {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_86: _RelativeField = _RelativeField { relatives: &[(-1, "trim. ant."), (0, "esti trim."), (1, "trim. vin.")], future: ["", "en {0} trim.", "", "", "", "en {0} trim."], past: ["", "hai {0} trim.", "", "", "", "hai {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_87: _RelativeField = _RelativeField { relatives: &[(-1, "trim. ant."), (0, "esti trim."), (1, "trim. vin.")], future: ["", "en {0} tr.", "", "", "", "en {0} tr."], past: ["", "hai {0} tr.", "", "", "", "hai {0} tr."] };
+
+#[rustfmt::skip]
+static _FIELD_88: _RelativeField = _RelativeField { relatives: &[(-1, "l’añu pasáu"), (0, "esti añu"), (1, "l’añu viniente")], future: ["", "en {0} añu", "", "", "", "en {0} años"], past: ["", "hai {0} añu", "", "", "", "hai {0} años"] };
+
+#[rustfmt::skip]
+static _FIELD_89: _RelativeField = _RelativeField { relatives: &[(-1, "l’añu pas."), (0, "esti añu"), (1, "l’añu vin.")], future: ["", "en {0} añu", "", "", "", "en {0} años"], past: ["", "hai {0} añu", "", "", "", "hai {0} años"] };
+
+#[rustfmt::skip]
+static _FIELD_90: _RelativeField = _RelativeField { relatives: &[(-1, "añu pas."), (0, "esti añu"), (1, "añu vin.")], future: ["", "en {0} a.", "", "", "", "en {0} a."], past: ["", "hai {0} a.", "", "", "", "hai {0} a."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_7: [&_RelativeField; 24] = [
+    &_FIELD_69,
+    &_FIELD_70,
+    &_FIELD_71,
+    &_FIELD_72,
+    &_FIELD_73,
+    &_FIELD_73,
+    &_FIELD_74,
+    &_FIELD_75,
+    &_FIELD_76,
+    &_FIELD_77,
+    &_FIELD_77,
+    &_FIELD_78,
+    &_FIELD_79,
+    &_FIELD_80,
+    &_FIELD_81,
+    &_FIELD_82,
+    &_FIELD_83,
+    &_FIELD_84,
+    &_FIELD_85,
+    &_FIELD_86,
+    &_FIELD_87,
+    &_FIELD_88,
+    &_FIELD_89,
+    &_FIELD_90
+];
+
+#[rustfmt::skip]
+static _FIELD_91: _RelativeField = _RelativeField { relatives: &[(0, "indi")], future: ["", "{0} saniyə ərzində", "", "", "", "{0} saniyə ərzində"], past: ["", "{0} saniyə öncə", "", "", "", "{0} saniyə öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_92: _RelativeField = _RelativeField { relatives: &[(0, "bu dəqiqə")], future: ["", "{0} dəqiqə ərzində", "", "", "", "{0} dəqiqə ərzində"], past: ["", "{0} dəqiqə öncə", "", "", "", "{0} dəqiqə öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_93: _RelativeField = _RelativeField { relatives: &[(0, "bu saat")], future: ["", "{0} saat ərzində", "", "", "", "{0} saat ərzində"], past: ["", "{0} saat öncə", "", "", "", "{0} saat öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_94: _RelativeField = _RelativeField { relatives: &[(-1, "dünən"), (0, "bu gün"), (1, "sabah")], future: ["", "{0} gün ərzində", "", "", "", "{0} gün ərzində"], past: ["", "{0} gün öncə", "", "", "", "{0} gün öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_95: _RelativeField = _RelativeField { relatives: &[(-1, "keçən həftə"), (0, "bu həftə"), (1, "gələn həftə")], future: ["", "{0} həftə ərzində", "", "", "", "{0} həftə ərzində"], past: ["", "{0} həftə öncə", "", "", "", "{0} həftə öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_96: _RelativeField = _RelativeField { relatives: &[(-1, "keçən ay"), (0, "bu ay"), (1, "gələn ay")], future: ["", "{0} ay ərzində", "", "", "", "{0} ay ərzində"], past: ["", "{0} ay öncə", "", "", "", "{0} ay öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_97: _RelativeField = _RelativeField { relatives: &[(-1, "keçən rüb"), (0, "bu rüb"), (1, "gələn rüb")], future: ["", "{0} rüb ərzində", "", "", "", "{0} rüb ərzində"], past: ["", "{0} rüb öncə", "", "", "", "{0} rüb öncə"] };
+
+#[rustfmt::skip]
+static _FIELD_98: _RelativeField = _RelativeField { relatives: &[(-1, "keçən il"), (0, "bu il"), (1, "gələn il")], future: ["", "{0} il ərzində", "", "", "", "{0} il ərzində"], past: ["", "{0} il öncə", "", "", "", "{0} il öncə"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_8: [&_RelativeField; 24] = [
+    &_FIELD_91,
+    &_FIELD_91,
+    &_FIELD_91,
+    &_FIELD_92,
+    &_FIELD_92,
+    &_FIELD_92,
+    &_FIELD_93,
+    &_FIELD_93,
+    &_FIELD_93,
+    &_FIELD_94,
+    &_FIELD_94,
+    &_FIELD_94,
+    &_FIELD_95,
+    &_FIELD_95,
+    &_FIELD_95,
+    &_FIELD_96,
+    &_FIELD_96,
+    &_FIELD_96,
+    &_FIELD_97,
+    &_FIELD_97,
+    &_FIELD_97,
+    &_FIELD_98,
+    &_FIELD_98,
+    &_FIELD_98
+];
+
+#[rustfmt::skip]
+static _FIELD_99: _RelativeField = _RelativeField { relatives: &[(0, "annun")], future: ["", "sekendhá", "", "", "", "{0} sekendhá rand"], past: ["", "{0} sekendhá pésar", "", "", "", "{0} sekendhá pésar"] };
+
+#[rustfmt::skip]
+static _FIELD_100: _RelativeField = _RelativeField { relatives: &[(0, "é damán")], future: ["", "meletthéá", "", "", "", "{0} meletthá rand"], past: ["", "{0} meletthá pésar", "", "", "", "{0} meletthá pésar"] };
+
+#[rustfmt::skip]
+static _FIELD_101: _RelativeField = _RelativeField { relatives: &[(0, "É sáhat")], future: ["", "Ganthahéá", "", "", "", "{0} ganthahá rand"], past: ["", "{0} ganthahá pésar", "", "", "", "{0} ganthahá pésar"] };
+
+#[rustfmt::skip]
+static _FIELD_102: _RelativeField = _RelativeField { relatives: &[(-1, "zi"), (0, "maróchi"), (1, "bándá")], future: ["", "{0} róchá", "", "", "", "{0} róchá rand"], past: ["", "{0} róchá pésar", "", "", "", "{0} róchá pésar"] };
+
+#[rustfmt::skip]
+static _FIELD_103: _RelativeField = _RelativeField { relatives: &[(-1, "gwastagén haptag"), (0, "é haptag"), (1, "démay haptag")], future: ["", "démay haptag", "", "", "", "démay {0} haptag"], past: ["", "gwastagén haptag", "", "", "", "gwastagén {0} haptag"] };
+
+#[rustfmt::skip]
+static _FIELD_104: _RelativeField = _RelativeField { relatives: &[(-1, "gwastagén máh"), (0, "É máh"), (1, "Démay máh")], future: ["", "{0} máhá", "", "", "", "{0} máhá"], past: ["", "{0} máhá pésar", "", "", "", "{0} máhá pésar"] };
+
+#[rustfmt::skip]
+static _FIELD_105: _RelativeField = _RelativeField { relatives: &[(-1, "Sálay gwastagén chárek"), (0, "Sálay é chárek"), (1, "Sálay démay chárek")], future: ["", "Áókén {0} chárek", "", "", "", "Áókén {0} chárek"], past: ["", "Sálay {0} cháreká pésar", "", "", "", "Sálay {0} chárká pésar"] };
+
+#[rustfmt::skip]
+static _FIELD_106: _RelativeField = _RelativeField { relatives: &[(-1, "Pári"), (0, "Embarán"), (1, "Démay sál")], future: ["", "Áókén {0} sál", "", "", "", "Áókén {0} sálá"], past: ["", "Gwastagén {0} sál", "", "", "", "{0} sálá pésar"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_9: [&_RelativeField; 24] = [
+    &_FIELD_99,
+    &_FIELD_99,
+    &_FIELD_99,
+    &_FIELD_100,
+    &_FIELD_100,
+    &_FIELD_100,
+    &_FIELD_101,
+    &_FIELD_101,
+    &_FIELD_101,
+    &_FIELD_102,
+    &_FIELD_102,
+    &_FIELD_102,
+    &_FIELD_103,
+    &_FIELD_103,
+    &_FIELD_103,
+    &_FIELD_104,
+    &_FIELD_104,
+    &_FIELD_104,
+    &_FIELD_105,
+    &_FIELD_105,
+    &_FIELD_105,
+    &_FIELD_106,
+    &_FIELD_106,
+    &_FIELD_106
+];
+
+#[rustfmt::skip]
+static _FIELD_107: _RelativeField = _RelativeField { relatives: &[(0, "цяпер")], future: ["", "праз {0} секунду", "", "праз {0} секунды", "праз {0} секунд", "праз {0} секунды"], past: ["", "{0} секунду таму", "", "{0} секунды таму", "{0} секунд таму", "{0} секунды таму"] };
+
+#[rustfmt::skip]
+static _FIELD_108: _RelativeField = _RelativeField { relatives: &[(0, "цяпер")], future: ["", "праз {0} с", "", "праз {0} с", "праз {0} с", "праз {0} с"], past: ["", "{0} с таму", "", "{0} с таму", "{0} с таму", "{0} с таму"] };
+
+#[rustfmt::skip]
+static _FIELD_109: _RelativeField = _RelativeField { relatives: &[(0, "у гэту хвіліну")], future: ["", "праз {0} хвіліну", "", "праз {0} хвіліны", "праз {0} хвілін", "праз {0} хвіліны"], past: ["", "{0} хвіліну таму", "", "{0} хвіліны таму", "{0} хвілін таму", "{0} хвіліны таму"] };
+
+#[rustfmt::skip]
+static _FIELD_110: _RelativeField = _RelativeField { relatives: &[(0, "у гэту хвіліну")], future: ["", "праз {0} хв", "", "праз {0} хв", "праз {0} хв", "праз {0} хв"], past: ["", "{0} хв таму", "", "{0} хв таму", "{0} хв таму", "{0} хв таму"] };
+
+#[rustfmt::skip]
+static _FIELD_111: _RelativeField = _RelativeField { relatives: &[(0, "у гэту гадзіну")], future: ["", "праз {0} гадзіну", "", "праз {0} гадзіны", "праз {0} гадзін", "праз {0} гадзіны"], past: ["", "{0} гадзіну таму", "", "{0} гадзіны таму", "{0} гадзін таму", "{0} гадзіны таму"] };
+
+#[rustfmt::skip]
+static _FIELD_112: _RelativeField = _RelativeField { relatives: &[(0, "у гэту гадзіну")], future: ["", "праз {0} гадз", "", "праз {0} гадз", "праз {0} гадз", "праз {0} гадз"], past: ["", "{0} гадз таму", "", "{0} гадз таму", "{0} гадз таму", "{0} гадз таму"] };
+
+#[rustfmt::skip]
+static _FIELD_113: _RelativeField = _RelativeField { relatives: &[(-2, "пазаўчора"), (-1, "учора"), (0, "сёння"), (1, "заўтра"), (2, "паслязаўтра")], future: ["", "праз {0} дзень", "", "праз {0} дні", "праз {0} дзён", "праз {0} дня"], past: ["", "{0} дзень таму", "", "{0} дні таму", "{0} дзён таму", "{0} дня таму"] };
+
+#[rustfmt::skip]
+static _FIELD_114: _RelativeField = _RelativeField { relatives: &[(-1, "на мінулым тыдні"), (0, "на гэтым тыдні"), (1, "на наступным тыдні")], future: ["", "праз {0} тыдзень", "", "праз {0} тыдні", "праз {0} тыдняў", "праз {0} тыдня"], past: ["", "{0} тыдзень таму", "", "{0} тыдні таму", "{0} тыдняў таму", "{0} тыдня таму"] };
+
+#[rustfmt::skip]
+static _FIELD_115: _RelativeField = _RelativeField { relatives: &[(-1, "на мін. тыд."), (0, "на гэтым тыд."), (1, "на наст. тыд.")], future: ["", "праз {0} тыд", "", "праз {0} тыд", "праз {0} тыд", "праз {0} тыд"], past: ["", "{0} тыд таму", "", "{0} тыд таму", "{0} тыд таму", "{0} тыд таму"] };
+
+#[rustfmt::skip]
+static _FIELD_116: _RelativeField = _RelativeField { relatives: &[(-1, "у мінулым месяцы"), (0, "у гэтым месяцы"), (1, "у наступным месяцы")], future: ["", "праз {0} месяц", "", "праз {0} месяцы", "праз {0} месяцаў", "праз {0} месяца"], past: ["", "{0} месяц таму", "", "{0} месяцы таму", "{0} месяцаў таму", "{0} месяца таму"] };
+
+#[rustfmt::skip]
+static _FIELD_117: _RelativeField = _RelativeField { relatives: &[(-1, "у мін. мес."), (0, "у гэтым мес."), (1, "у наст. мес.")], future: ["", "праз {0} мес.", "", "праз {0} мес.", "праз {0} мес.", "праз {0} мес."], past: ["", "{0} мес. таму", "", "{0} мес. таму", "{0} мес. таму", "{0} мес. таму"] };
+
+#[rustfmt::skip]
+static _FIELD_118: _RelativeField = _RelativeField { relatives: &[(-1, "у мінулым квартале"), (0, "у гэтым квартале"), (1, "у наступным квартале")], future: ["", "праз {0} квартал", "", "праз {0} кварталы", "праз {0} кварталаў", "праз {0} квартала"], past: ["", "{0} квартал таму", "", "{0} кварталы таму", "{0} кварталаў таму", "{0} квартала таму"] };
+
+#[rustfmt::skip]
+static _FIELD_119: _RelativeField = _RelativeField { relatives: &[(-1, "у мінулым квартале"), (0, "у гэтым квартале"), (1, "у наступным квартале")], future: ["", "праз {0} кв.", "", "праз {0} кв.", "праз {0} кв.", "праз {0} кв."], past: ["", "{0} кв. таму", "", "{0} кв. таму", "{0} кв. таму", "{0} кв. таму"] };
+
+#[rustfmt::skip]
+static _FIELD_120: _RelativeField = _RelativeField { relatives: &[(-1, "у мінулым годзе"), (0, "у гэтым годзе"), (1, "у наступным годзе")], future: ["", "праз {0} год", "", "праз {0} гады", "праз {0} гадоў", "праз {0} года"], past: ["", "{0} год таму", "", "{0} гады таму", "{0} гадоў таму", "{0} года таму"] };
+
+#[rustfmt::skip]
+static _FIELD_121: _RelativeField = _RelativeField { relatives: &[(-1, "у мін. годзе"), (0, "у гэтым годзе"), (1, "у наст. годзе")], future: ["", "праз {0} г.", "", "праз {0} г.", "праз {0} г.", "праз {0} г."], past: ["", "{0} г. таму", "", "{0} г. таму", "{0} г. таму", "{0} г. таму"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_10: [&_RelativeField; 24] = [
+    &_FIELD_107,
+    &_FIELD_108,
+    &_FIELD_108,
+    &_FIELD_109,
+    &_FIELD_110,
+    &_FIELD_110,
+    &_FIELD_111,
+    &_FIELD_112,
+    &_FIELD_112,
+    &_FIELD_113,
+    &_FIELD_113,
+    &_FIELD_113,
+    &_FIELD_114,
+    &_FIELD_115,
+    &_FIELD_115,
+    &_FIELD_116,
+    &_FIELD_117,
+    &_FIELD_117,
+    &_FIELD_118,
+    &_FIELD_119,
+    &_FIELD_119,
+    &_FIELD_120,
+    &_FIELD_121,
+    &_FIELD_121
+];
+
+#[rustfmt::skip]
+static _FIELD_122: _RelativeField = _RelativeField { relatives: &[(0, "у гэтую хвіліну")], future: ["", "праз {0} хв", "", "праз {0} хв", "праз {0} хв", "праз {0} хв"], past: ["", "{0} хв таму", "", "{0} хв таму", "{0} хв таму", "{0} хв таму"] };
+
+#[rustfmt::skip]
+static _FIELD_123: _RelativeField = _RelativeField { relatives: &[(0, "у гэтую гадзіну")], future: ["", "праз {0} гадз", "", "праз {0} гадз", "праз {0} гадз", "праз {0} гадз"], past: ["", "{0} гадз таму", "", "{0} гадз таму", "{0} гадз таму", "{0} гадз таму"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_11: [&_RelativeField; 24] = [
+    &_FIELD_107,
+    &_FIELD_108,
+    &_FIELD_108,
+    &_FIELD_109,
+    &_FIELD_122,
+    &_FIELD_122,
+    &_FIELD_111,
+    &_FIELD_123,
+    &_FIELD_123,
+    &_FIELD_113,
+    &_FIELD_113,
+    &_FIELD_113,
+    &_FIELD_114,
+    &_FIELD_115,
+    &_FIELD_115,
+    &_FIELD_116,
+    &_FIELD_117,
+    &_FIELD_117,
+    &_FIELD_118,
+    &_FIELD_119,
+    &_FIELD_119,
+    &_FIELD_120,
+    &_FIELD_121,
+    &_FIELD_121
+];
+
+#[rustfmt::skip]
+static _FIELD_124: _RelativeField = _RelativeField { relatives: &[(0, "сега")], future: ["", "след {0} секунда", "", "", "", "след {0} секунди"], past: ["", "преди {0} секунда", "", "", "", "преди {0} секунди"] };
+
+#[rustfmt::skip]
+static _FIELD_125: _RelativeField = _RelativeField { relatives: &[(0, "сега")], future: ["", "след {0} сек", "", "", "", "след {0} сек"], past: ["", "преди {0} сек", "", "", "", "преди {0} сек"] };
+
+#[rustfmt::skip]
+static _FIELD_126: _RelativeField = _RelativeField { relatives: &[(0, "сега")], future: ["", "сл. {0} сек", "", "", "", "сл. {0} сек"], past: ["", "пр. {0} сек", "", "", "", "пр. {0} сек"] };
+
+#[rustfmt::skip]
+static _FIELD_127: _RelativeField = _RelativeField { relatives: &[(0, "в тази минута")], future: ["", "след {0} минута", "", "", "", "след {0} минути"], past: ["", "преди {0} минута", "", "", "", "преди {0} минути"] };
+
+#[rustfmt::skip]
+static _FIELD_128: _RelativeField = _RelativeField { relatives: &[(0, "в тази минута")], future: ["", "след {0} мин", "", "", "", "след {0} мин"], past: ["", "преди {0} мин", "", "", "", "преди {0} мин"] };
+
+#[rustfmt::skip]
+static _FIELD_129: _RelativeField = _RelativeField { relatives: &[(0, "в тази минута")], future: ["", "сл. {0} мин", "", "", "", "сл. {0} мин"], past: ["", "пр. {0} мин", "", "", "", "пр. {0} мин"] };
+
+#[rustfmt::skip]
+static _FIELD_130: _RelativeField = _RelativeField { relatives: &[(0, "в този час")], future: ["", "след {0} час", "", "", "", "след {0} часа"], past: ["", "преди {0} час", "", "", "", "преди {0} часа"] };
+
+#[rustfmt::skip]
+static _FIELD_131: _RelativeField = _RelativeField { relatives: &[(0, "в този час")], future: ["", "след {0} ч", "", "", "", "след {0} ч"], past: ["", "преди {0} ч", "", "", "", "преди {0} ч"] };
+
+#[rustfmt::skip]
+static _FIELD_132: _RelativeField = _RelativeField { relatives: &[(0, "в този час")], future: ["", "сл. {0} ч", "", "", "", "сл. {0} ч"], past: ["", "пр. {0} ч", "", "", "", "пр. {0} ч"] };
+
+#[rustfmt::skip]
+static _FIELD_133: _RelativeField = _RelativeField { relatives: &[(-2, "онзи ден"), (-1, "вчера"), (0, "днес"), (1, "утре"), (2, "вдругиден")], future: ["", "след {0} ден", "", "", "", "след {0} дни"], past: ["", "преди {0} ден", "", "", "", "преди {0} дни"] };
+
+#[rustfmt::skip]
+static _FIELD_134: _RelativeField = _RelativeField { relatives: &[(-2, "онзи ден"), (-1, "вчера"), (0, "днес"), (1, "утре"), (2, "вдругиден")], future: ["", "сл. {0} д", "", "", "", "сл. {0} д"], past: ["", "пр. {0} д", "", "", "", "пр. {0} д"] };
+
+#[rustfmt::skip]
+static _FIELD_135: _RelativeField = _RelativeField { relatives: &[(-1, "предходната седмица"), (0, "тази седмица"), (1, "следващата седмица")], future: ["", "след {0} седмица", "", "", "", "след {0} седмици"], past: ["", "преди {0} седмица", "", "", "", "преди {0} седмици"] };
+
+#[rustfmt::skip]
+static _FIELD_136: _RelativeField = _RelativeField { relatives: &[(-1, "мин. седм."), (0, "тази седм."), (1, "следв. седм.")], future: ["", "след {0} седм.", "", "", "", "след {0} седм."], past: ["", "преди {0} седм.", "", "", "", "преди {0} седм."] };
+
+#[rustfmt::skip]
+static _FIELD_137: _RelativeField = _RelativeField { relatives: &[(-1, "мин. седм."), (0, "тази седм."), (1, "сл. седм.")], future: ["", "сл. {0} седм.", "", "", "", "сл. {0} седм."], past: ["", "пр. {0} седм.", "", "", "", "пр. {0} седм."] };
+
+#[rustfmt::skip]
+static _FIELD_138: _RelativeField = _RelativeField { relatives: &[(-1, "предходен месец"), (0, "този месец"), (1, "следващ месец")], future: ["", "след {0} месец", "", "", "", "след {0} месеца"], past: ["", "преди {0} месец", "", "", "", "преди {0} месеца"] };
+
+#[rustfmt::skip]
+static _FIELD_139: _RelativeField = _RelativeField { relatives: &[(-1, "мин. мес."), (0, "този мес."), (1, "следв. мес.")], future: ["", "след {0} м.", "", "", "", "след {0} м."], past: ["", "преди {0} м.", "", "", "", "преди {0} м."] };
+
+#[rustfmt::skip]
+static _FIELD_140: _RelativeField = _RelativeField { relatives: &[(-1, "мин. м."), (0, "т. м."), (1, "сл. м.")], future: ["", "сл. {0} м.", "", "", "", "сл. {0} м."], past: ["", "пр. {0} м.", "", "", "", "пр. {0} м."] };
+
+#[rustfmt::skip]
+static _FIELD_141: _RelativeField = _RelativeField { relatives: &[(-1, "предходно тримесечие"), (0, "това тримесечие"), (1, "следващо тримесечие")], future: ["", "след {0} тримесечие", "", "", "", "след {0} тримесечия"], past: ["", "преди {0} тримесечие", "", "", "", "преди {0} тримесечия"] };
+
+#[rustfmt::skip]
+static _FIELD_142: _RelativeField = _RelativeField { relatives: &[(-1, "мин. трим."), (0, "това трим."), (1, "следв. трим.")], future: ["", "след {0} трим.", "", "", "", "след {0} трим."], past: ["", "преди {0} трим.", "", "", "", "преди {0} трим."] };
+
+#[rustfmt::skip]
+static _FIELD_143: _RelativeField = _RelativeField { relatives: &[(-1, "мин. трим."), (0, "това трим."), (1, "следв. трим.")], future: ["", "сл. {0} трим.", "", "", "", "сл. {0} трим."], past: ["", "пр. {0} трим.", "", "", "", "пр. {0} трим."] };
+
+#[rustfmt::skip]
+static _FIELD_144: _RelativeField = _RelativeField { relatives: &[(-1, "миналата година"), (0, "тази година"), (1, "следващата година")], future: ["", "след {0} година", "", "", "", "след {0} години"], past: ["", "преди {0} година", "", "", "", "преди {0} години"] };
+
+#[rustfmt::skip]
+static _FIELD_145: _RelativeField = _RelativeField { relatives: &[(-1, "мин. г."), (0, "т. г."), (1, "следв. г.")], future: ["", "след {0} г.", "", "", "", "след {0} г."], past: ["", "преди {0} г.", "", "", "", "преди {0} г."] };
+
+#[rustfmt::skip]
+static _FIELD_146: _RelativeField = _RelativeField { relatives: &[(-1, "мин. г."), (0, "т. г."), (1, "сл. г.")], future: ["", "сл. {0} г.", "", "", "", "сл. {0} г."], past: ["", "пр. {0} г.", "", "", "", "пр. {0} г."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_12: [&_RelativeField; 24] = [
+    &_FIELD_124,
+    &_FIELD_125,
+    &_FIELD_126,
+    &_FIELD_127,
+    &_FIELD_128,
+    &_FIELD_129,
+    &_FIELD_130,
+    &_FIELD_131,
+    &_FIELD_132,
+    &_FIELD_133,
+    &_FIELD_133,
+    &_FIELD_134,
+    &_FIELD_135,
+    &_FIELD_136,
+    &_FIELD_137,
+    &_FIELD_138,
+    &_FIELD_139,
+    &_FIELD_140,
+    &_FIELD_141,
+    &_FIELD_142,
+    &_FIELD_143,
+    &_FIELD_144,
+    &_FIELD_145,
+    &_FIELD_146
+];
+
+#[rustfmt::skip]
+static _FIELD_147: _RelativeField = _RelativeField { relatives: &[(-1, "काह\u{94d}ल (बित\u{94d}या होड)"), (0, "आज"), (1, "काह\u{94d}ल (आण आला)")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_148: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला हफ\u{93c}\u{94d}ता"), (0, "इस हफ\u{93c}\u{94d}त\u{947}"), (1, "अगला हफ\u{93c}\u{94d}ता")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_149: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला मिह\u{94d}ना"), (0, "इस मिह\u{94d}न\u{947}"), (1, "अगला मिह\u{94d}ना")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_150: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला साल"), (0, "इस साल"), (1, "अगला साल")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_13: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_147,
+    &_FIELD_147,
+    &_FIELD_147,
+    &_FIELD_148,
+    &_FIELD_148,
+    &_FIELD_148,
+    &_FIELD_149,
+    &_FIELD_149,
+    &_FIELD_149,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_150,
+    &_FIELD_150,
+    &_FIELD_150
+];
+
+#[rustfmt::skip]
+static _FIELD_151: _RelativeField = _RelativeField { relatives: &[(0, "nnyǝmǝ")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_152: _RelativeField = _RelativeField { relatives: &[(0, "gʊnaŋa ŋgʊɖe")], future: ["", "", "", "", "", "+{0} min"], past: ["", "", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_153: _RelativeField = _RelativeField { relatives: &[(0, "gakɔŋkɔŋɔ\u{300} ŋgaɖe")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_154: _RelativeField = _RelativeField { relatives: &[(-1, "galaɩ"), (0, "ʊrɔŋ"), (1, "gacǝŋ")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_155: _RelativeField = _RelativeField { relatives: &[(-1, "bɔkɔɩ aboŋoka"), (0, "bɔkɔɩ nnɖe"), (1, "bɔkɔɩ afɔlɩ")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_156: _RelativeField = _RelativeField { relatives: &[(-1, "bk. boŋo"), (0, "bk. ɖe"), (1, "bk. fɔlɩ")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_157: _RelativeField = _RelativeField { relatives: &[(-1, "bk boŋo"), (0, "bk ɖe"), (1, "bk fɔlɩ")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_158: _RelativeField = _RelativeField { relatives: &[(-1, "aŋɔrɔ aboŋoka"), (0, "aŋɔrɔ nnɖe"), (1, "aŋɔrɔ afɔlɩ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_159: _RelativeField = _RelativeField { relatives: &[(-1, "aŋ. boŋo"), (0, "aŋ. ɖe"), (1, "aŋ. fɔlɩ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_160: _RelativeField = _RelativeField { relatives: &[(-1, "aŋ boŋo"), (0, "aŋ ɖe"), (1, "aŋ fɔlɩ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_161: _RelativeField = _RelativeField { relatives: &[(-1, "ɩŋɔrɩriu ɩboŋoka"), (0, "ɩŋɔrɩriu nnɖe"), (1, "ɩŋɔrɩriu ɩfɔlɩ")], future: ["", "", "", "", "", "+{0} Q"], past: ["", "", "", "", "", "-{0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_162: _RelativeField = _RelativeField { relatives: &[(-1, "gaja gaboŋoka"), (0, "gaja ŋgaɖe"), (1, "gaja gafɔlɩ")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_163: _RelativeField = _RelativeField { relatives: &[(-1, "gj. boŋo"), (0, "gj. ɖe"), (1, "gj. fɔlɩ")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_164: _RelativeField = _RelativeField { relatives: &[(-1, "gj boŋo"), (0, "gj ɖe"), (1, "gj fɔlɩ")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_14: [&_RelativeField; 24] = [
+    &_FIELD_151,
+    &_FIELD_151,
+    &_FIELD_151,
+    &_FIELD_152,
+    &_FIELD_152,
+    &_FIELD_152,
+    &_FIELD_153,
+    &_FIELD_153,
+    &_FIELD_153,
+    &_FIELD_154,
+    &_FIELD_154,
+    &_FIELD_154,
+    &_FIELD_155,
+    &_FIELD_156,
+    &_FIELD_157,
+    &_FIELD_158,
+    &_FIELD_159,
+    &_FIELD_160,
+    &_FIELD_161,
+    &_FIELD_161,
+    &_FIELD_161,
+    &_FIELD_162,
+    &_FIELD_163,
+    &_FIELD_164
+];
+
+#[rustfmt::skip]
+static _FIELD_165: _RelativeField = _RelativeField { relatives: &[(0, "এখন")], future: ["", "{0} সেকেন\u{9cd}ডে", "", "", "", "{0} সেকেন\u{9cd}ডে"], past: ["", "{0} সেকেন\u{9cd}ড আগে", "", "", "", "{0} সেকেন\u{9cd}ড প\u{9c2}র\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _FIELD_166: _RelativeField = _RelativeField { relatives: &[(0, "এখন")], future: ["", "{0} সেকেন\u{9cd}ডে", "", "", "", "{0} সেকেন\u{9cd}ডে"], past: ["", "{0} সেকেন\u{9cd}ড আগে", "", "", "", "{0} সেকেন\u{9cd}ড আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_167: _RelativeField = _RelativeField { relatives: &[(0, "এই মিনিট")], future: ["", "{0} মিনিটে", "", "", "", "{0} মিনিটে"], past: ["", "{0} মিনিট আগে", "", "", "", "{0} মিনিট আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_168: _RelativeField = _RelativeField { relatives: &[(0, "এই ঘণ\u{9cd}ট\u{9be}য\u{9bc}")], future: ["", "{0} ঘন\u{9cd}ট\u{9be}য\u{9bc}", "", "", "", "{0} ঘন\u{9cd}ট\u{9be}য\u{9bc}"], past: ["", "{0} ঘন\u{9cd}ট\u{9be} আগে", "", "", "", "{0} ঘন\u{9cd}ট\u{9be} আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_169: _RelativeField = _RelativeField { relatives: &[(-2, "গত পরশ\u{9c1}"), (-1, "গতক\u{9be}ল"), (0, "আজ"), (1, "আগ\u{9be}মীক\u{9be}ল"), (2, "আগ\u{9be}মী পরশ\u{9c1}")], future: ["", "{0} দিনের মধ\u{9cd}যে", "", "", "", "{0} দিনের মধ\u{9cd}যে"], past: ["", "{0} দিন আগে", "", "", "", "{0} দিন আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_170: _RelativeField = _RelativeField { relatives: &[(-1, "গত সপ\u{9cd}ত\u{9be}হ"), (0, "এই সপ\u{9cd}ত\u{9be}হ"), (1, "পরের সপ\u{9cd}ত\u{9be}হ")], future: ["", "{0} সপ\u{9cd}ত\u{9be}হে", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হে"], past: ["", "{0} সপ\u{9cd}ত\u{9be}হ আগে", "", "", "", "{0} সপ\u{9cd}ত\u{9be}হ আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_171: _RelativeField = _RelativeField { relatives: &[(-1, "গত ম\u{9be}স"), (0, "এই ম\u{9be}স"), (1, "পরের ম\u{9be}স")], future: ["", "{0} ম\u{9be}সে", "", "", "", "{0} ম\u{9be}সে"], past: ["", "{0} ম\u{9be}স আগে", "", "", "", "{0} ম\u{9be}স আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_172: _RelativeField = _RelativeField { relatives: &[(-1, "গত ত\u{9cd}রৈম\u{9be}সিক"), (0, "এই ত\u{9cd}রৈম\u{9be}সিক"), (1, "পরের ত\u{9cd}রৈম\u{9be}সিক")], future: ["", "{0} ত\u{9cd}রৈম\u{9be}সিকে", "", "", "", "{0} ত\u{9cd}রৈম\u{9be}সিকে"], past: ["", "{0} ত\u{9cd}রৈম\u{9be}সিক আগে", "", "", "", "{0} ত\u{9cd}রৈম\u{9be}সিক আগে"] };
+
+#[rustfmt::skip]
+static _FIELD_173: _RelativeField = _RelativeField { relatives: &[(-1, "গত বছর"), (0, "এই বছর"), (1, "পরের বছর")], future: ["", "{0} বছরে", "", "", "", "{0} বছরে"], past: ["", "{0} বছর প\u{9c2}র\u{9cd}বে", "", "", "", "{0} বছর প\u{9c2}র\u{9cd}বে"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_15: [&_RelativeField; 24] = [
+    &_FIELD_165,
+    &_FIELD_166,
+    &_FIELD_166,
+    &_FIELD_167,
+    &_FIELD_167,
+    &_FIELD_167,
+    &_FIELD_168,
+    &_FIELD_168,
+    &_FIELD_168,
+    &_FIELD_169,
+    &_FIELD_169,
+    &_FIELD_169,
+    &_FIELD_170,
+    &_FIELD_170,
+    &_FIELD_170,
+    &_FIELD_171,
+    &_FIELD_171,
+    &_FIELD_171,
+    &_FIELD_172,
+    &_FIELD_172,
+    &_FIELD_172,
+    &_FIELD_173,
+    &_FIELD_173,
+    &_FIELD_173
+];
+
+#[rustfmt::skip]
+static _FIELD_174: _RelativeField = _RelativeField { relatives: &[(0, "bremañ")], future: ["", "a-benn {0} eilenn", "a-benn {0} eilenn", "a-benn {0} eilenn", "a-benn {0} a eilennoù", "a-benn {0} eilenn"], past: ["", "{0} eilenn zo", "{0} eilenn zo", "{0} eilenn zo", "{0} eilenn zo", "{0} eilenn zo"] };
+
+#[rustfmt::skip]
+static _FIELD_175: _RelativeField = _RelativeField { relatives: &[(0, "brem.")], future: ["", "a-benn {0} s", "a-benn {0} s", "a-benn {0} s", "a-benn {0} s", "a-benn {0} s"], past: ["", "{0} s zo", "{0} s zo", "{0} s zo", "{0} s zo", "{0} s zo"] };
+
+#[rustfmt::skip]
+static _FIELD_176: _RelativeField = _RelativeField { relatives: &[(0, "brem.")], future: ["", "+{0} s", "+{0} s", "+{0} s", "+{0} s", "+{0} s"], past: ["", "-{0} s", "-{0} s", "-{0} s", "-{0} s", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_177: _RelativeField = _RelativeField { relatives: &[(0, "ar munut-mañ")], future: ["", "a-benn {0} munut", "a-benn {0} vunut", "a-benn {0} munut", "a-benn {0} a vunutoù", "a-benn {0} munut"], past: ["", "{0} munut zo", "{0} vunut zo", "{0} munut zo", "{0} a vunutoù zo", "{0} munut zo"] };
+
+#[rustfmt::skip]
+static _FIELD_178: _RelativeField = _RelativeField { relatives: &[(0, "ar munut-mañ")], future: ["", "a-benn {0} min", "a-benn {0} min", "a-benn {0} min", "a-benn {0} min", "a-benn {0} min"], past: ["", "{0} min zo", "{0} min zo", "{0} min zo", "{0} min zo", "{0} min zo"] };
+
+#[rustfmt::skip]
+static _FIELD_179: _RelativeField = _RelativeField { relatives: &[(0, "ar munut-mañ")], future: ["", "+{0} min", "+{0} min", "+{0} min", "+{0} min", "+{0} min"], past: ["", "-{0} min", "-{0} min", "-{0} min", "-{0} min", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_180: _RelativeField = _RelativeField { relatives: &[(0, "dʼan eur-mañ")], future: ["", "a-benn {0} eur", "a-benn {0} eur", "a-benn {0} eur", "a-benn {0} a eurioù", "a-benn {0} eur"], past: ["", "{0} eur zo", "{0} eur zo", "{0} eur zo", "{0} a eurioù zo", "{0} eur zo"] };
+
+#[rustfmt::skip]
+static _FIELD_181: _RelativeField = _RelativeField { relatives: &[(0, "dʼan eur-mañ")], future: ["", "a-benn {0} e", "a-benn {0} e", "a-benn {0} e", "a-benn {0} e", "a-benn {0} e"], past: ["", "{0} e zo", "{0} e zo", "{0} e zo", "{0} e zo", "{0} e zo"] };
+
+#[rustfmt::skip]
+static _FIELD_182: _RelativeField = _RelativeField { relatives: &[(0, "dʼan eur-mañ")], future: ["", "+{0} h", "+{0} h", "+{0} h", "+{0} h", "+{0} h"], past: ["", "-{0} h", "-{0} h", "-{0} h", "-{0} h", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_183: _RelativeField = _RelativeField { relatives: &[(-2, "dercʼhent-decʼh"), (-1, "decʼh"), (0, "hiziv"), (1, "warcʼhoazh")], future: ["", "a-benn {0} deiz", "a-benn {0} zeiz", "a-benn {0} deiz", "a-benn {0} a zeizioù", "a-benn {0} deiz"], past: ["", "{0} deiz zo", "{0} zeiz zo", "{0} deiz zo", "{0} a zeizioù zo", "{0} deiz zo"] };
+
+#[rustfmt::skip]
+static _FIELD_184: _RelativeField = _RelativeField { relatives: &[(-2, "dercʼhent-decʼh"), (-1, "decʼh"), (0, "hiziv"), (1, "warcʼhoazh")], future: ["", "a-benn {0} d", "a-benn {0} d", "a-benn {0} d", "a-benn {0} d", "a-benn {0} d"], past: ["", "{0} d zo", "{0} d zo", "{0} d zo", "{0} d zo", "{0} d zo"] };
+
+#[rustfmt::skip]
+static _FIELD_185: _RelativeField = _RelativeField { relatives: &[(-2, "dercʼhent-decʼh"), (-1, "decʼh"), (0, "hiziv"), (1, "warcʼhoazh")], future: ["", "+{0} d", "+{0} d", "+{0} d", "+{0} d", "+{0} d"], past: ["", "-{0} d", "-{0} d", "-{0} d", "-{0} d", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_186: _RelativeField = _RelativeField { relatives: &[(-1, "ar sizhun diaraok"), (0, "ar sizhun-mañ"), (1, "ar sizhun a zeu")], future: ["", "a-benn {0} sizhun", "a-benn {0} sizhun", "a-benn {0} sizhun", "a-benn {0} a sizhunioù", "a-benn {0} sizhun"], past: ["", "{0} sizhun zo", "{0} sizhun zo", "{0} sizhun zo", "{0} a sizhunioù zo", "{0} sizhun zo"] };
+
+#[rustfmt::skip]
+static _FIELD_187: _RelativeField = _RelativeField { relatives: &[(-1, "ar sizh. diaraok"), (0, "ar sizh.-mañ"), (1, "ar sizh. a zeu")], future: ["", "a-benn {0} sizh.", "a-benn {0} sizh.", "a-benn {0} sizh.", "a-benn {0} sizh.", "a-benn {0} sizh."], past: ["", "{0} sizh. zo", "{0} sizh. zo", "{0} sizh. zo", "{0} sizh. zo", "{0} sizh. zo"] };
+
+#[rustfmt::skip]
+static _FIELD_188: _RelativeField = _RelativeField { relatives: &[(-1, "ar miz diaraok"), (0, "ar miz-mañ"), (1, "ar miz a zeu")], future: ["", "a-benn {0} miz", "a-benn {0} viz", "a-benn {0} miz", "a-benn {0} a vizioù", "a-benn {0} miz"], past: ["", "{0} miz zo", "{0} viz zo", "{0} miz zo", "{0} a vizioù zo", "{0} miz zo"] };
+
+#[rustfmt::skip]
+static _FIELD_189: _RelativeField = _RelativeField { relatives: &[(-1, "ar m. diaraok"), (0, "ar m.-mañ"), (1, "ar m. a zeu")], future: ["", "+{0} miz", "+{0} miz", "+{0} miz", "+{0} miz", "+{0} miz"], past: ["", "-{0} miz", "-{0} miz", "-{0} miz", "-{0} miz", "-{0} miz"] };
+
+#[rustfmt::skip]
+static _FIELD_190: _RelativeField = _RelativeField { relatives: &[(-1, "an trimiziad diaraok"), (0, "an trimiziad-mañ"), (1, "an trimiziad a zeu")], future: ["", "a-benn {0} trimiziad", "a-benn {0} drimiziad", "a-benn {0} zrimiziad", "a-benn {0} a drimiziadoù", "a-benn {0} trimiziad"], past: ["", "{0} trimiziad zo", "{0} drimiziad zo", "{0} zrimiziad zo", "{0} a zrimiziadoù zo", "{0} trimiziad zo"] };
+
+#[rustfmt::skip]
+static _FIELD_191: _RelativeField = _RelativeField { relatives: &[(-1, "an trim. diaraok"), (0, "an trim.-mañ"), (1, "an trim. a zeu")], future: ["", "a-benn {0} trim.", "a-benn {0} trim.", "a-benn {0} trim.", "a-benn {0} trim.", "a-benn {0} trim."], past: ["", "{0} trim. zo", "{0} trim. zo", "{0} trim. zo", "{0} trim. zo", "{0} trim. zo"] };
+
+#[rustfmt::skip]
+static _FIELD_192: _RelativeField = _RelativeField { relatives: &[(-1, "an trim. diaraok"), (0, "an trim.-mañ"), (1, "an trim. a zeu")], future: ["", "+{0} trim.", "+{0} trim.", "+{0} trim.", "+{0} trim.", "+{0} trim."], past: ["", "-{0} trim.", "-{0} trim.", "-{0} trim.", "-{0} trim.", "-{0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_193: _RelativeField = _RelativeField { relatives: &[(-1, "warlene"), (0, "hevlene"), (1, "ar bloaz a zeu")], future: ["", "a-benn {0} bloaz", "a-benn {0} vloaz", "a-benn {0} bloaz", "a-benn {0} a vloazioù", "a-benn {0} vloaz"], past: ["", "{0} bloaz zo", "{0} vloaz zo", "{0} bloaz zo", "{0} a vloazioù zo", "{0} vloaz zo"] };
+
+#[rustfmt::skip]
+static _FIELD_194: _RelativeField = _RelativeField { relatives: &[(-1, "warlene"), (0, "hevlene"), (1, "ar bl. a zeu")], future: ["", "a-benn {0} bl.", "a-benn {0} bl.", "a-benn {0} bl.", "a-benn {0} bl.", "a-benn {0} bl."], past: ["", "{0} bl. zo", "{0} bl. zo", "{0} bl. zo", "{0} bl. zo", "{0} bl. zo"] };
+
+#[rustfmt::skip]
+static _FIELD_195: _RelativeField = _RelativeField { relatives: &[(-1, "warlene"), (0, "hevlene"), (1, "ar bl. a zeu")], future: ["", "+{0} bl.", "+{0} bl.", "+{0} bl.", "+{0} bl.", "+{0} bl."], past: ["", "-{0} bl.", "-{0} bl.", "-{0} bl.", "-{0} bl.", "-{0} bl."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_16: [&_RelativeField; 24] = [
+    &_FIELD_174,
+    &_FIELD_175,
+    &_FIELD_176,
+    &_FIELD_177,
+    &_FIELD_178,
+    &_FIELD_179,
+    &_FIELD_180,
+    &_FIELD_181,
+    &_FIELD_182,
+    &_FIELD_183,
+    &_FIELD_184,
+    &_FIELD_185,
+    &_FIELD_186,
+    &_FIELD_187,
+    &_FIELD_187,
+    &_FIELD_188,
+    &_FIELD_188,
+    &_FIELD_189,
+    &_FIELD_190,
+    &_FIELD_191,
+    &_FIELD_192,
+    &_FIELD_193,
+    &_FIELD_194,
+    &_FIELD_195
+];
+
+#[rustfmt::skip]
+static _FIELD_196: _RelativeField = _RelativeField { relatives: &[(0, "दा")], future: ["", "{0} स\u{947}क\u{947}ण\u{94d}डआव", "", "", "", "{0} स\u{947}क\u{947}ण\u{94d}डआव"], past: ["", "{0} स\u{947}क\u{947}ण\u{94d}ड सिगा\u{902}", "", "", "", "{0} स\u{947}क\u{947}ण\u{94d}ड सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_197: _RelativeField = _RelativeField { relatives: &[(0, "ब\u{947} मिनिट")], future: ["", "{0} मिनिटआव", "", "", "", "{0} मिनिटआव"], past: ["", "{0} मिनिट सिगा\u{902}", "", "", "", "{0} मिनिट सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_198: _RelativeField = _RelativeField { relatives: &[(0, "ब\u{947} घन\u{94d}टा")], future: ["", "{0} घन\u{94d}टायाव", "", "", "", "{0} घन\u{94d}टायाव"], past: ["", "{0} घन\u{94d}टा सिगा\u{902}", "", "", "", "{0} घन\u{94d}टा सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_199: _RelativeField = _RelativeField { relatives: &[(-1, "म\u{948}या"), (0, "दिन\u{948}"), (1, "गाबोन")], future: ["", "{0} सानाव", "", "", "", "{0} सानाव"], past: ["", "{0} सान सिगा\u{902}", "", "", "", "{0} सान सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_200: _RelativeField = _RelativeField { relatives: &[(-1, "था\u{902}नाय सप\u{94d}थाह"), (0, "ब\u{947} सप\u{94d}थाह"), (1, "फ\u{948}गौ सप\u{94d}थाह")], future: ["", "{0} सप\u{94d}थाहयाव", "", "", "", "{0} सप\u{94d}थाहयाव"], past: ["", "{0} सप\u{94d}थाह सिगा\u{902}", "", "", "", "{0} सप\u{94d}थाह सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_201: _RelativeField = _RelativeField { relatives: &[(-1, "था\u{902}नाय दान"), (0, "ब\u{947} दान"), (1, "फ\u{948}गौ दान")], future: ["", "{0} दानाव", "", "", "", "{0} दानाव"], past: ["", "{0} दान सिगा\u{902}", "", "", "", "{0} दान सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_202: _RelativeField = _RelativeField { relatives: &[(-1, "था\u{902}नाय खोन\u{94d}दोस\u{947}"), (0, "ब\u{947} खोन\u{94d}दोस\u{947}"), (1, "फ\u{948}गौ खोन\u{94d}दोस\u{947}")], future: ["", "{0} खोन\u{94d}दोस\u{947}आव", "", "", "", "{0} खोन\u{94d}दोस\u{947}आव"], past: ["", "{0} खोन\u{94d}दोस\u{947} सिगा\u{902}", "", "", "", "{0} खोन\u{94d}दोस\u{947} सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_203: _RelativeField = _RelativeField { relatives: &[(-1, "था\u{902}नाय बोसोर"), (0, "ब\u{947} बोसोर"), (1, "फ\u{948}गौ बोसोर")], future: ["", "{0} बोसोरआव", "", "", "", "{0} बोसोरआव"], past: ["", "{0} बोसोर सिगा\u{902}", "", "", "", "{0} बोसोर सिगा\u{902}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_17: [&_RelativeField; 24] = [
+    &_FIELD_196,
+    &_FIELD_196,
+    &_FIELD_196,
+    &_FIELD_197,
+    &_FIELD_197,
+    &_FIELD_197,
+    &_FIELD_198,
+    &_FIELD_198,
+    &_FIELD_198,
+    &_FIELD_199,
+    &_FIELD_199,
+    &_FIELD_199,
+    &_FIELD_200,
+    &_FIELD_200,
+    &_FIELD_200,
+    &_FIELD_201,
+    &_FIELD_201,
+    &_FIELD_201,
+    &_FIELD_202,
+    &_FIELD_202,
+    &_FIELD_202,
+    &_FIELD_203,
+    &_FIELD_203,
+    &_FIELD_203
+];
+
+#[rustfmt::skip]
+static _FIELD_204: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} sekundu", "", "za {0} sekunde", "", "za {0} sekundi"], past: ["", "prije {0} sekundu", "", "prije {0} sekunde", "", "prije {0} sekundi"] };
+
+#[rustfmt::skip]
+static _FIELD_205: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} sek.", "", "za {0} sek.", "", "za {0} sek."], past: ["", "prije {0} sek.", "", "prije {0} sek.", "", "prije {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_206: _RelativeField = _RelativeField { relatives: &[(0, "ova minuta")], future: ["", "za {0} minutu", "", "za {0} minute", "", "za {0} minuta"], past: ["", "prije {0} minutu", "", "prije {0} minute", "", "prije {0} minuta"] };
+
+#[rustfmt::skip]
+static _FIELD_207: _RelativeField = _RelativeField { relatives: &[(0, "ova minuta")], future: ["", "za {0} min", "", "za {0} min.", "", "za {0} min."], past: ["", "prije {0} min.", "", "prije {0} min.", "", "prije {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_208: _RelativeField = _RelativeField { relatives: &[(0, "ovaj sat")], future: ["", "za {0} sat", "", "za {0} sata", "", "za {0} sati"], past: ["", "prije {0} sat", "", "prije {0} sata", "", "prije {0} sati"] };
+
+#[rustfmt::skip]
+static _FIELD_209: _RelativeField = _RelativeField { relatives: &[(-2, "prekjučer"), (-1, "jučer"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} dan", "", "za {0} dana", "", "za {0} dana"], past: ["", "prije {0} dan", "", "prije {0} dana", "", "prije {0} dana"] };
+
+#[rustfmt::skip]
+static _FIELD_210: _RelativeField = _RelativeField { relatives: &[(-2, "prekjučer"), (-1, "jučer"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} d.", "", "za {0} d.", "", "za {0} d."], past: ["", "prije {0} d.", "", "prije {0} d.", "", "prije {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_211: _RelativeField = _RelativeField { relatives: &[(-1, "prošle sedmice"), (0, "ove sedmice"), (1, "sljedeće sedmice")], future: ["", "za {0} sedmicu", "", "za {0} sedmice", "", "za {0} sedmica"], past: ["", "prije {0} sedmicu", "", "prije {0} sedmice", "", "prije {0} sedmica"] };
+
+#[rustfmt::skip]
+static _FIELD_212: _RelativeField = _RelativeField { relatives: &[(-1, "prošle sedmice"), (0, "ove sedmice"), (1, "sljedeće sedmice")], future: ["", "za {0} sed.", "", "za {0} sed.", "", "za {0} sed."], past: ["", "prije {0} sed.", "", "prije {0} sed.", "", "prije {0} sed."] };
+
+#[rustfmt::skip]
+static _FIELD_213: _RelativeField = _RelativeField { relatives: &[(-1, "prošli mjesec"), (0, "ovaj mjesec"), (1, "sljedeći mjesec")], future: ["", "za {0} mjesec", "", "za {0} mjeseca", "", "za {0} mjeseci"], past: ["", "prije {0} mjesec", "", "prije {0} mjeseca", "", "prije {0} mjeseci"] };
+
+#[rustfmt::skip]
+static _FIELD_214: _RelativeField = _RelativeField { relatives: &[(-1, "prošli mjesec"), (0, "ovaj mjesec"), (1, "sljedeći mjesec")], future: ["", "za {0} mj.", "", "za {0} mj.", "", "za {0} mj."], past: ["", "prije {0} mj.", "", "prije {0} mj.", "", "prije {0} mj."] };
+
+#[rustfmt::skip]
+static _FIELD_215: _RelativeField = _RelativeField { relatives: &[(-1, "posljednji kvartal"), (0, "ovaj kvartal"), (1, "sljedeći kvartal")], future: ["", "za {0} kvartal", "", "za {0} kvartala", "", "za {0} kvartala"], past: ["", "prije {0} kvartala", "", "prije {0} kvartala", "", "prije {0} kvartala"] };
+
+#[rustfmt::skip]
+static _FIELD_216: _RelativeField = _RelativeField { relatives: &[(-1, "posljednji kvartal"), (0, "ovaj kvartal"), (1, "sljedeći kvartal")], future: ["", "za {0} kv.", "", "za {0} kv.", "", "za {0} kv."], past: ["", "prije {0} kv.", "", "prije {0} kv.", "", "prije {0} kv."] };
+
+#[rustfmt::skip]
+static _FIELD_217: _RelativeField = _RelativeField { relatives: &[(-1, "prošle godine"), (0, "ove godine"), (1, "sljedeće godine")], future: ["", "za {0} godinu", "", "za {0} godine", "", "za {0} godina"], past: ["", "prije {0} godinu", "", "prije {0} godine", "", "prije {0} godina"] };
+
+#[rustfmt::skip]
+static _FIELD_218: _RelativeField = _RelativeField { relatives: &[(-1, "prošle godine"), (0, "ove godine"), (1, "sljedeće godine")], future: ["", "za {0} god.", "", "za {0} god.", "", "za {0} god."], past: ["", "prije {0} god.", "", "prije {0} god.", "", "prije {0} god."] };
+
+#[rustfmt::skip]
+static _FIELD_219: _RelativeField = _RelativeField { relatives: &[(-1, "prošle godine"), (0, "ove godine"), (1, "sljedeće godine")], future: ["", "za {0} g.", "", "za {0} g.", "", "za {0} g."], past: ["", "prije {0} g.", "", "prije {0} g.", "", "prije {0} g."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_18: [&_RelativeField; 24] = [
+    &_FIELD_204,
+    &_FIELD_205,
+    &_FIELD_205,
+    &_FIELD_206,
+    &_FIELD_207,
+    &_FIELD_207,
+    &_FIELD_208,
+    &_FIELD_208,
+    &_FIELD_208,
+    &_FIELD_209,
+    &_FIELD_210,
+    &_FIELD_210,
+    &_FIELD_211,
+    &_FIELD_212,
+    &_FIELD_212,
+    &_FIELD_213,
+    &_FIELD_214,
+    &_FIELD_214,
+    &_FIELD_215,
+    &_FIELD_216,
+    &_FIELD_216,
+    &_FIELD_217,
+    &_FIELD_218,
+    &_FIELD_219
+];
+
+#[rustfmt::skip]
+static _FIELD_220: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} секунд", "", "за {0} секунде", "", "за {0} секунди"], past: ["", "прије {0} секунд", "", "прије {0} секунде", "", "прије {0} секунди"] };
+
+#[rustfmt::skip]
+static _FIELD_221: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} сек.", "", "за {0} секунде", "", "за {0} секунди"], past: ["", "прије {0} сек.", "", "прије {0} сек.", "", "прије {0} сек."] };
+
+#[rustfmt::skip]
+static _FIELD_222: _RelativeField = _RelativeField { relatives: &[(0, "овог минута")], future: ["", "за {0} минут", "", "за {0} минута", "", "за {0} минута"], past: ["", "прије {0} минут", "", "прије {0} минута", "", "прије {0} минута"] };
+
+#[rustfmt::skip]
+static _FIELD_223: _RelativeField = _RelativeField { relatives: &[(0, "овог минута")], future: ["", "за {0} минут", "", "за {0} минута", "", "за {0} минута"], past: ["", "прије {0} мин.", "", "прије {0} мин.", "", "прије {0} мин."] };
+
+#[rustfmt::skip]
+static _FIELD_224: _RelativeField = _RelativeField { relatives: &[(0, "овог сата")], future: ["", "за {0} сат", "", "за {0} сата", "", "за {0} сати"], past: ["", "прије {0} сат", "", "прије {0} сата", "", "прије {0} сати"] };
+
+#[rustfmt::skip]
+static _FIELD_225: _RelativeField = _RelativeField { relatives: &[(-2, "прекјуче"), (-1, "јуче"), (0, "данас"), (1, "сутра"), (2, "прекосутра")], future: ["", "за {0} дан", "", "за {0} дана", "", "за {0} дана"], past: ["", "прије {0} дан", "", "прије {0} дана", "", "прије {0} дана"] };
+
+#[rustfmt::skip]
+static _FIELD_226: _RelativeField = _RelativeField { relatives: &[(-1, "прошле седмице"), (0, "ове седмице"), (1, "сљедеће седмице")], future: ["", "за {0} седмицу", "", "за {0} седмице", "", "за {0} седмица"], past: ["", "прије {0} седмицу", "", "прије {0} седмице", "", "прије {0} седмица"] };
+
+#[rustfmt::skip]
+static _FIELD_227: _RelativeField = _RelativeField { relatives: &[(-1, "прош. седм."), (0, "ове седм."), (1, "сљ. седм.")], future: ["", "за {0} седм.", "", "за {0} седм.", "", "за {0} седм."], past: ["", "прије {0} седм.", "", "прије {0} седм.", "", "прије {0} седм."] };
+
+#[rustfmt::skip]
+static _FIELD_228: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог мјесеца"), (0, "овог мјесеца"), (1, "сљедећег мјесеца")], future: ["", "за {0} мјесец", "", "за {0} мјесеца", "", "за {0} мјесеци"], past: ["", "прије {0} мјесец", "", "прије {0} мјесеца", "", "прије {0} мјесеци"] };
+
+#[rustfmt::skip]
+static _FIELD_229: _RelativeField = _RelativeField { relatives: &[(-1, "прош. мјесеца"), (0, "овог мјес."), (1, "сљед. мјесеца")], future: ["", "за {0} мјесец", "", "за {0} мјесеца", "", "за {0} мјесеци"], past: ["", "прије {0} мјесец", "", "прије {0} мјесеца", "", "прије {0} мјесеци"] };
+
+#[rustfmt::skip]
+static _FIELD_230: _RelativeField = _RelativeField { relatives: &[(-1, "прош. мјес."), (0, "ов. мјес."), (1, "сљ. мјес.")], future: ["", "за {0} мјес.", "", "за {0} мјес.", "", "за {0} мјес."], past: ["", "прије {0} мјес.", "", "прије {0} мјес.", "", "прије {0} мјес."] };
+
+#[rustfmt::skip]
+static _FIELD_231: _RelativeField = _RelativeField { relatives: &[(-1, "прошло тромјесечје"), (0, "ово тромјесечје"), (1, "сљедеће тромјесечје")], future: ["", "", "", "", "", "+{0} Q"], past: ["", "", "", "", "", "-{0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_232: _RelativeField = _RelativeField { relatives: &[(-1, "прошле године"), (0, "ове године"), (1, "сљедеће године")], future: ["", "за {0} годину", "", "за {0} године", "", "за {0} година"], past: ["", "прије {0} годину", "", "прије {0} године", "", "прије {0} година"] };
+
+#[rustfmt::skip]
+static _FIELD_233: _RelativeField = _RelativeField { relatives: &[(-1, "прош. године"), (0, "ове год."), (1, "сљед. године")], future: ["", "за {0} годину", "", "за {0} године", "", "за {0} година"], past: ["", "прије {0} годину", "", "прије {0} године", "", "прије {0} година"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_19: [&_RelativeField; 24] = [
+    &_FIELD_220,
+    &_FIELD_221,
+    &_FIELD_221,
+    &_FIELD_222,
+    &_FIELD_222,
+    &_FIELD_223,
+    &_FIELD_224,
+    &_FIELD_224,
+    &_FIELD_224,
+    &_FIELD_225,
+    &_FIELD_225,
+    &_FIELD_225,
+    &_FIELD_226,
+    &_FIELD_227,
+    &_FIELD_227,
+    &_FIELD_228,
+    &_FIELD_229,
+    &_FIELD_230,
+    &_FIELD_231,
+    &_FIELD_231,
+    &_FIELD_231,
+    &_FIELD_232,
+    &_FIELD_233,
+    &_FIELD_233
+];
+
+#[rustfmt::skip]
+static _FIELD_234: _RelativeField = _RelativeField { relatives: &[(0, "ara")], future: ["", "d’aquí a {0} segon", "", "", "", "d’aquí a {0} segons"], past: ["", "fa {0} segon", "", "", "", "fa {0} segons"] };
+
+#[rustfmt::skip]
+static _FIELD_235: _RelativeField = _RelativeField { relatives: &[(0, "ara")], future: ["", "d’aquí a {0} s", "", "", "", "d’aquí a {0} s"], past: ["", "fa {0} s", "", "", "", "fa {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_236: _RelativeField = _RelativeField { relatives: &[(0, "aquest minut")], future: ["", "d’aquí a {0} minut", "", "", "", "d’aquí a {0} minuts"], past: ["", "fa {0} minut", "", "", "", "fa {0} minuts"] };
+
+#[rustfmt::skip]
+static _FIELD_237: _RelativeField = _RelativeField { relatives: &[(0, "aquest minut")], future: ["", "d’aquí a {0} min", "", "", "", "d’aquí a {0} min"], past: ["", "fa {0} min", "", "", "", "fa {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_238: _RelativeField = _RelativeField { relatives: &[(0, "aquesta hora")], future: ["", "d’aquí a {0} hora", "", "", "", "d’aquí a {0} hores"], past: ["", "fa {0} hora", "", "", "", "fa {0} hores"] };
+
+#[rustfmt::skip]
+static _FIELD_239: _RelativeField = _RelativeField { relatives: &[(0, "aquesta hora")], future: ["", "d’aquí a {0} h", "", "", "", "d’aquí a {0} h"], past: ["", "fa {0} h", "", "", "", "fa {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_240: _RelativeField = _RelativeField { relatives: &[(0, "aquesta hora")], future: ["", "d‘aquí a {0} h", "", "", "", "d‘aquí a {0} h"], past: ["", "fa {0} h", "", "", "", "fa {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_241: _RelativeField = _RelativeField { relatives: &[(-2, "abans-d’ahir"), (-1, "ahir"), (0, "avui"), (1, "demà"), (2, "demà passat")], future: ["", "d’aquí a {0} dia", "", "", "", "d’aquí a {0} dies"], past: ["", "fa {0} dia", "", "", "", "fa {0} dies"] };
+
+#[rustfmt::skip]
+static _FIELD_242: _RelativeField = _RelativeField { relatives: &[(-1, "la setmana passada"), (0, "aquesta setmana"), (1, "la setmana que ve")], future: ["", "d’aquí a {0} setmana", "", "", "", "d’aquí a {0} setmanes"], past: ["", "fa {0} setmana", "", "", "", "fa {0} setmanes"] };
+
+#[rustfmt::skip]
+static _FIELD_243: _RelativeField = _RelativeField { relatives: &[(-1, "la setm. passada"), (0, "aquesta setm."), (1, "la setm. que ve")], future: ["", "d’aquí a {0} setm.", "", "", "", "d’aquí a {0} setm."], past: ["", "fa {0} setm.", "", "", "", "fa {0} setm."] };
+
+#[rustfmt::skip]
+static _FIELD_244: _RelativeField = _RelativeField { relatives: &[(-1, "el mes passat"), (0, "aquest mes"), (1, "el mes que ve")], future: ["", "d’aquí a {0} mes", "", "", "", "d’aquí a {0} mesos"], past: ["", "fa {0} mes", "", "", "", "fa {0} mesos"] };
+
+#[rustfmt::skip]
+static _FIELD_245: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre passat"), (0, "aquest trimestre"), (1, "el trimestre que ve")], future: ["", "d’aquí a {0} trimestre", "", "", "", "d’aquí a {0} trimestres"], past: ["", "fa {0} trimestre", "", "", "", "fa {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_246: _RelativeField = _RelativeField { relatives: &[(-1, "el trim. passat"), (0, "aquest trim."), (1, "el trim. que ve")], future: ["", "d’aquí a {0} trim.", "", "", "", "d’aquí a {0} trim."], past: ["", "fa {0} trim.", "", "", "", "fa {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_247: _RelativeField = _RelativeField { relatives: &[(-1, "trim. passat"), (0, "aquest trim."), (1, "trim. vinent")], future: ["", "d’aquí a {0} trim.", "", "", "", "d’aquí a {0} trim."], past: ["", "fa {0} trim.", "", "", "", "fa {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_248: _RelativeField = _RelativeField { relatives: &[(-1, "l’any passat"), (0, "enguany"), (1, "l’any que ve")], future: ["", "d’aquí a {0} any", "", "", "", "d’aquí a {0} anys"], past: ["", "fa {0} any", "", "", "", "fa {0} anys"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_20: [&_RelativeField; 24] = [
+    &_FIELD_234,
+    &_FIELD_235,
+    &_FIELD_235,
+    &_FIELD_236,
+    &_FIELD_237,
+    &_FIELD_237,
+    &_FIELD_238,
+    &_FIELD_239,
+    &_FIELD_240,
+    &_FIELD_241,
+    &_FIELD_241,
+    &_FIELD_241,
+    &_FIELD_242,
+    &_FIELD_243,
+    &_FIELD_243,
+    &_FIELD_244,
+    &_FIELD_244,
+    &_FIELD_244,
+    &_FIELD_245,
+    &_FIELD_246,
+    &_FIELD_247,
+    &_FIELD_248,
+    &_FIELD_248,
+    &_FIELD_248
+];
+
+#[rustfmt::skip]
+static _FIELD_249: _RelativeField = _RelativeField { relatives: &[(-1, "gahapon"), (0, "karong adlawa"), (1, "ugma")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_250: _RelativeField = _RelativeField { relatives: &[(-1, "miaging semana"), (0, "karong semanaha"), (1, "sunod nga semana")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_251: _RelativeField = _RelativeField { relatives: &[(-1, "miaging buwan"), (0, "karong buwana"), (1, "sunod nga buwan")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_252: _RelativeField = _RelativeField { relatives: &[(-1, "miaging tuig"), (0, "karong tuiga"), (1, "sunod nga tuig")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_21: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_249,
+    &_FIELD_249,
+    &_FIELD_249,
+    &_FIELD_250,
+    &_FIELD_250,
+    &_FIELD_250,
+    &_FIELD_251,
+    &_FIELD_251,
+    &_FIELD_251,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_252,
+    &_FIELD_252,
+    &_FIELD_252
+];
+
+#[rustfmt::skip]
+static _FIELD_253: _RelativeField = _RelativeField { relatives: &[(0, "ᏃᏊ")], future: ["", "ᎾᎿ {0} ᎠᏎᏢ", "", "", "", "ᎾᎿ {0} ᏓᏓᎾᏩᏍᎬ ᏥᎨᏒ"], past: ["", "{0} ᎠᏎᏢ ᏥᎨᏒ", "", "", "", "{0} ᏓᏓᎾᏩᏍᎬ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_254: _RelativeField = _RelativeField { relatives: &[(0, "ᏃᏊ")], future: ["", "ᎾᎿ {0} ᎠᏎ.", "", "", "", "ᎾᎿ {0} ᎠᏎ."], past: ["", "{0} ᎠᏎ. ᏥᎨᏒ", "", "", "", "{0} ᎠᏎ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_255: _RelativeField = _RelativeField { relatives: &[(0, "ᎯᎠ ᎢᏯᏔᏬᏍᏔᏅ")], future: ["", "ᎾᎿ {0} ᎢᏯᏔᏬᏍᏔᏅ", "", "", "", "ᎾᎿ {0} ᎢᏯᏔᏬᏍᏔᏅ"], past: ["", "{0} ᎢᏯᏔᏬᏍᏔᏅ ᏥᎨᏒ", "", "", "", "{0} ᎢᏯᏔᏬᏍᏔᏅ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_256: _RelativeField = _RelativeField { relatives: &[(0, "ᎯᎠ ᎢᏯᏔᏬᏍᏔᏅ")], future: ["", "ᎾᎿ {0} ᎢᏯᏔ.", "", "", "", "ᎾᎿ {0} ᎢᏯᏔ."], past: ["", "{0} ᎢᏯᏔ. ᏥᎨᏒ", "", "", "", "{0} ᎢᏯᏔ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_257: _RelativeField = _RelativeField { relatives: &[(0, "ᎯᎠ ᏑᏟᎶᏓ")], future: ["", "ᎾᎿ {0} ᏑᏟᎶᏓ", "", "", "", "ᎾᎿ {0} ᎢᏳᏟᎶᏓ"], past: ["", "{0} ᏑᏟᎶᏓ ᏥᎨᏒ", "", "", "", "{0} ᎢᏳᏟᎶᏓ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_258: _RelativeField = _RelativeField { relatives: &[(0, "ᎯᎠ ᏑᏟᎶᏓ")], future: ["", "ᎾᎿ {0} ᏑᏟ.", "", "", "", "ᎾᎿ {0} ᏑᏟ."], past: ["", "{0} ᏑᏟ. ᏥᎨᏒ", "", "", "", "{0} ᏑᏟ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_259: _RelativeField = _RelativeField { relatives: &[(-1, "ᏒᎯ"), (0, "ᎪᎯ ᎢᎦ"), (1, "ᏌᎾᎴᎢ")], future: ["", "ᎾᎿ {0} ᎢᎦ", "", "", "", "ᎾᎿ {0} ᎯᎸᏍᎩ ᏧᏒᎯᏛ"], past: ["", "{0} ᎢᎦ ᏥᎨᏒ", "", "", "", "{0} ᎯᎸᏍᎩ ᏧᏒᎯᏛ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_260: _RelativeField = _RelativeField { relatives: &[(-1, "ᏥᏛᎵᏱᎵᏒᎢ"), (0, "ᎯᎠ ᎠᎵᎵᏌ"), (1, "ᏐᏆᎴᏅᎲ")], future: ["", "ᎾᎿ {0} ᏒᎾᏙᏓᏆᏍᏗ", "", "", "", "ᎾᎿ {0} ᎢᏳᎾᏙᏓᏆᏍᏗ"], past: ["", "{0} ᏒᎾᏙᏓᏆᏍᏗ ᏥᎨᏒ", "", "", "", "{0} ᎢᏳᎾᏙᏓᏆᏍᏗ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_261: _RelativeField = _RelativeField { relatives: &[(-1, "ᏥᏛᎵᏱᎵᏒᎢ."), (0, "ᎯᎠ ᎠᎵᎵᏌ."), (1, "ᏐᏆᎴᏅᎲ.")], future: ["", "ᎾᎿ {0} ᏒᎾ.", "", "", "", "ᎾᎿ {0} ᏒᎾ."], past: ["", "{0} ᏒᎾ. ᏥᎨᏒ", "", "", "", "{0} ᏒᎾ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_262: _RelativeField = _RelativeField { relatives: &[(-1, "ᎧᎸᎢ ᏥᎨᏒ"), (0, "ᎯᎠ ᎧᎸᎢ"), (1, "ᏔᎵᏁ ᎧᎸᎢ")], future: ["", "ᎾᎿ {0} ᎧᎸᎢ", "", "", "", "ᎾᎿ {0} ᏗᎧᎸᎢ"], past: ["", "{0} ᎧᎸᎢ ᏥᎨᏒ", "", "", "", "{0} ᏗᎧᎸᎢ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_263: _RelativeField = _RelativeField { relatives: &[(-1, "ᎧᎸ. ᏥᎨᏒ"), (0, "ᎯᎠ ᎧᎸ."), (1, "ᎯᎠ ᎧᎸ.")], future: ["", "ᎾᎿ {0} ᎧᎸ.", "", "", "", "ᎾᎿ {0} ᎧᎸ."], past: ["", "{0} ᎧᎸ. ᏥᎨᏒ", "", "", "", "{0} ᎧᎸ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_264: _RelativeField = _RelativeField { relatives: &[(-1, "ᎧᎸ. ᏥᎨᏒ"), (0, "ᎯᎠ ᎧᎸ."), (1, "ᏔᎵᏁ ᎧᎸ.")], future: ["", "ᎾᎿ {0} ᎧᎸ.", "", "", "", "ᎾᎿ {0} ᎧᎸ."], past: ["", "{0} ᎧᎸ. ᏥᎨᏒ", "", "", "", "{0} ᎧᎸ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_265: _RelativeField = _RelativeField { relatives: &[(-1, "ᎩᏄᏙᏗ ᏥᎨᏒ"), (0, "ᎯᎠ ᎩᏄᏙᏗ"), (1, "ᏔᎵᏁ ᎩᏄᏙᏗ")], future: ["", "ᎾᎿ {0} ᎩᏄᏙᏗ", "", "", "", "ᎾᎿ {0} ᎩᏄᏙᏗ"], past: ["", "ᎾᎿ {0} ᎩᏄᏙᏗ ᏥᎨᏒ", "", "", "", "{0} ᎩᏄᏙᏗ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_266: _RelativeField = _RelativeField { relatives: &[(-1, "ᎩᏄᏙᏗ ᏥᎨᏒ"), (0, "ᎯᎠ ᎩᏄᏙᏗ"), (1, "ᏔᎵᏁ ᎩᏄᏙᏗ")], future: ["", "ᎾᎿ {0} ᎩᏄᏘ.", "", "", "", "ᎾᎿ {0} ᎩᏄᏘ."], past: ["", "{0} ᎩᏄᏘ. ᏥᎨᏒ", "", "", "", "{0} ᎩᏄᏘ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_267: _RelativeField = _RelativeField { relatives: &[(-1, "ᎡᏘ ᏥᎨᏒ"), (0, "ᎯᎠ ᏧᏕᏘᏴᏒᏘ"), (1, "ᎡᏘᏴᎢ")], future: ["", "ᎾᎿ {0} ᎤᏕᏘᏴᏌᏗᏒᎢ", "", "", "", "ᎾᎿ {0} ᎢᏧᏕᏘᏴᏌᏗᏒᎢ"], past: ["", "{0} ᎤᏕᏘᏴᏌᏗᏒᎢ ᏥᎨᏒ", "", "", "", "{0} ᎢᏧᏕᏘᏴᏌᏗᏒᎢ ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _FIELD_268: _RelativeField = _RelativeField { relatives: &[(-1, "ᎡᏘ. ᏥᎨᏒ"), (0, "ᎯᎠ ᏧᏕᏘᏴᏒᏘ."), (1, "ᎡᏘᏴᎢ.")], future: ["", "ᎾᎿ {0} ᎤᏕ.", "", "", "", "ᎾᎿ {0} ᎤᏕ."], past: ["", "{0} ᎤᏕ. ᏥᎨᏒ", "", "", "", "{0} ᎤᏕ. ᏥᎨᏒ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_22: [&_RelativeField; 24] = [
+    &_FIELD_253,
+    &_FIELD_254,
+    &_FIELD_254,
+    &_FIELD_255,
+    &_FIELD_256,
+    &_FIELD_256,
+    &_FIELD_257,
+    &_FIELD_258,
+    &_FIELD_258,
+    &_FIELD_259,
+    &_FIELD_259,
+    &_FIELD_259,
+    &_FIELD_260,
+    &_FIELD_261,
+    &_FIELD_261,
+    &_FIELD_262,
+    &_FIELD_263,
+    &_FIELD_264,
+    &_FIELD_265,
+    &_FIELD_266,
+    &_FIELD_266,
+    &_FIELD_267,
+    &_FIELD_268,
+    &_FIELD_268
+];
+
+#[rustfmt::skip]
+static _FIELD_269: _RelativeField = _RelativeField { relatives: &[(0, "nyní")], future: ["", "za {0} sekundu", "", "za {0} sekundy", "za {0} sekundy", "za {0} sekund"], past: ["", "před {0} sekundou", "", "před {0} sekundami", "před {0} sekundy", "před {0} sekundami"] };
+
+#[rustfmt::skip]
+static _FIELD_270: _RelativeField = _RelativeField { relatives: &[(0, "nyní")], future: ["", "za {0} s", "", "za {0} s", "za {0} s", "za {0} s"], past: ["", "před {0} s", "", "před {0} s", "před {0} s", "před {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_271: _RelativeField = _RelativeField { relatives: &[(0, "tuto minutu")], future: ["", "za {0} minutu", "", "za {0} minuty", "za {0} minuty", "za {0} minut"], past: ["", "před {0} minutou", "", "před {0} minutami", "před {0} minuty", "před {0} minutami"] };
+
+#[rustfmt::skip]
+static _FIELD_272: _RelativeField = _RelativeField { relatives: &[(0, "tuto minutu")], future: ["", "za {0} min", "", "za {0} min", "za {0} min", "za {0} min"], past: ["", "před {0} min", "", "před {0} min", "před {0} min", "před {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_273: _RelativeField = _RelativeField { relatives: &[(0, "tuto hodinu")], future: ["", "za {0} hodinu", "", "za {0} hodiny", "za {0} hodiny", "za {0} hodin"], past: ["", "před {0} hodinou", "", "před {0} hodinami", "před {0} hodiny", "před {0} hodinami"] };
+
+#[rustfmt::skip]
+static _FIELD_274: _RelativeField = _RelativeField { relatives: &[(0, "tuto hodinu")], future: ["", "za {0} h", "", "za {0} h", "za {0} h", "za {0} h"], past: ["", "před {0} h", "", "před {0} h", "před {0} h", "před {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_275: _RelativeField = _RelativeField { relatives: &[(-2, "předevčírem"), (-1, "včera"), (0, "dnes"), (1, "zítra"), (2, "pozítří")], future: ["", "za {0} den", "", "za {0} dny", "za {0} dne", "za {0} dní"], past: ["", "před {0} dnem", "", "před {0} dny", "před {0} dne", "před {0} dny"] };
+
+#[rustfmt::skip]
+static _FIELD_276: _RelativeField = _RelativeField { relatives: &[(-1, "minulý týden"), (0, "tento týden"), (1, "příští týden")], future: ["", "za {0} týden", "", "za {0} týdny", "za {0} týdne", "za {0} týdnů"], past: ["", "před {0} týdnem", "", "před {0} týdny", "před {0} týdne", "před {0} týdny"] };
+
+#[rustfmt::skip]
+static _FIELD_277: _RelativeField = _RelativeField { relatives: &[(-1, "minulý týd."), (0, "tento týd."), (1, "příští týd.")], future: ["", "za {0} týd.", "", "za {0} týd.", "za {0} týd.", "za {0} týd."], past: ["", "před {0} týd.", "", "před {0} týd.", "před {0} týd.", "před {0} týd."] };
+
+#[rustfmt::skip]
+static _FIELD_278: _RelativeField = _RelativeField { relatives: &[(-1, "minulý měsíc"), (0, "tento měsíc"), (1, "příští měsíc")], future: ["", "za {0} měsíc", "", "za {0} měsíce", "za {0} měsíce", "za {0} měsíců"], past: ["", "před {0} měsícem", "", "před {0} měsíci", "před {0} měsíce", "před {0} měsíci"] };
+
+#[rustfmt::skip]
+static _FIELD_279: _RelativeField = _RelativeField { relatives: &[(-1, "minulý měs."), (0, "tento měs."), (1, "příští měs.")], future: ["", "za {0} měs.", "", "za {0} měs.", "za {0} měs.", "za {0} měs."], past: ["", "před {0} měs.", "", "před {0} měs.", "před {0} měs.", "před {0} měs."] };
+
+#[rustfmt::skip]
+static _FIELD_280: _RelativeField = _RelativeField { relatives: &[(-1, "minulé čtvrtletí"), (0, "toto čtvrtletí"), (1, "příští čtvrtletí")], future: ["", "za {0} čtvrtletí", "", "za {0} čtvrtletí", "za {0} čtvrtletí", "za {0} čtvrtletí"], past: ["", "před {0} čtvrtletím", "", "před {0} čtvrtletími", "před {0} čtvrtletí", "před {0} čtvrtletími"] };
+
+#[rustfmt::skip]
+static _FIELD_281: _RelativeField = _RelativeField { relatives: &[(-1, "minulé čtvrtletí"), (0, "toto čtvrtletí"), (1, "příští čtvrtletí")], future: ["", "+{0} Q", "", "+{0} Q", "+{0} Q", "+{0} Q"], past: ["", "-{0} Q", "", "-{0} Q", "-{0} Q", "-{0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_282: _RelativeField = _RelativeField { relatives: &[(-1, "minulý rok"), (0, "tento rok"), (1, "příští rok")], future: ["", "za {0} rok", "", "za {0} roky", "za {0} roku", "za {0} let"], past: ["", "před {0} rokem", "", "před {0} lety", "před {0} roku", "před {0} lety"] };
+
+#[rustfmt::skip]
+static _FIELD_283: _RelativeField = _RelativeField { relatives: &[(-1, "minulý rok"), (0, "tento rok"), (1, "příští rok")], future: ["", "za {0} r.", "", "za {0} r.", "za {0} r.", "za {0} l."], past: ["", "před {0} r.", "", "před {0} r.", "před {0} r.", "před {0} l."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_23: [&_RelativeField; 24] = [
+    &_FIELD_269,
+    &_FIELD_270,
+    &_FIELD_270,
+    &_FIELD_271,
+    &_FIELD_272,
+    &_FIELD_272,
+    &_FIELD_273,
+    &_FIELD_274,
+    &_FIELD_274,
+    &_FIELD_275,
+    &_FIELD_275,
+    &_FIELD_275,
+    &_FIELD_276,
+    &_FIELD_277,
+    &_FIELD_277,
+    &_FIELD_278,
+    &_FIELD_279,
+    &_FIELD_279,
+    &_FIELD_280,
+    &_FIELD_281,
+    &_FIELD_281,
+    &_FIELD_282,
+    &_FIELD_283,
+    &_FIELD_283
+];
+
+#[rustfmt::skip]
+static _FIELD_284: _RelativeField = _RelativeField { relatives: &[(0, "ᒣᐠᐚᐨ")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_285: _RelativeField = _RelativeField { relatives: &[(0, "ᐆᒪ ᑎᐸᐦᐃᑲᓂᐢ")], future: ["", "ᑕᐦᑐ {0} ᑎᐸᐦᐃᑲᓂᓴ", "", "", "", "ᑕᐦᑐ {0} ᑎᐸᐦᐃᑲᓂᓴ"], past: ["", "{0} ᑎᐸᐦᐃᑲᓂᓯᐦᑯᕽ", "", "", "", "{0} ᑎᐸᐦᐃᑲᓂᓯᐦᑯᕽ"] };
+
+#[rustfmt::skip]
+static _FIELD_286: _RelativeField = _RelativeField { relatives: &[(0, "ᐆᒪ ᐘᐢᑳᐸᓄᐤ")], future: ["", "ᑕᐦᑐ {0} ᐘᐢᑳᐸᓂᑭ", "", "", "", "ᑕᐦᑐ {0} ᐘᐢᑳᐸᓂᑭ"], past: ["", "{0} ᐘᐢᑳᐸᓂᑯᕽ", "", "", "", "{0} ᐘᐢᑳᐸᓂᑯᕽ"] };
+
+#[rustfmt::skip]
+static _FIELD_287: _RelativeField = _RelativeField { relatives: &[(0, "ᐆᒪ ᐘᐢᑳᐸᓄᐤ")], future: ["", "ᑕᐦᑐ {0} ᐘᐢᑳᐸᓂᑭ", "", "", "", "ᑕᐦᑐ {0} ᐘᐢᑳᐸᓂᑭ"], past: ["", "{0} ᑕᐦᑐ ᐘᐢᑳᐸᓂᑭ", "", "", "", "{0} ᐘᐢᑳᐸᓂᑯᕽ"] };
+
+#[rustfmt::skip]
+static _FIELD_288: _RelativeField = _RelativeField { relatives: &[(-1, "ᐅᑖᑯᓯᕽ"), (0, "ᐊᓄᐦᐨ"), (1, "ᐚᐸᐦᑫ")], future: ["", "ᑕᐦᑐ {0} ᑮᓯᑳᑭ", "", "", "", "ᑕᐦᑐ {0} ᑮᓯᑳᑭ"], past: ["", "{0} ᑳᑮ ᑕᐦᑐ ᑮᓯᑳᐠ", "", "", "", "{0} ᑳᑮ ᑕᐦᑐ ᑮᓯᑳᐠ"] };
+
+#[rustfmt::skip]
+static _FIELD_289: _RelativeField = _RelativeField { relatives: &[(-1, "ᐅᑖᑯᓰᕽ"), (0, "ᐊᓄᐦᐨ"), (1, "ᐚᐸᐦᑫ")], future: ["", "ᑕᐦᑐ {0} ᑮᓯᑳᑭ", "", "", "", "ᑕᐦᑐ {0} ᑮᓯᑳᑭ"], past: ["", "{0} ᑳᑮ ᑕᐦᑐ ᑮᓯᑳᐠ", "", "", "", "{0} ᑳᑮ ᑕᐦᑐ ᑮᓯᑳᐠ"] };
+
+#[rustfmt::skip]
+static _FIELD_290: _RelativeField = _RelativeField { relatives: &[(-1, "ᐅᑖᑯᓰᕽ"), (0, "ᐊᓄᐦᐨ"), (1, "ᐚᐸᐦᑫ")], future: ["", "ᑕᐦᑐ {0}ᑮᓯᑳᑭ", "", "", "", "ᑕᐦᑐ {0}ᑮᓯᑳᑭ"], past: ["", "{0} ᐅᑖᑯᓰᕽ", "", "", "", "{0} ᑳᑮ ᑕᐦᑐ ᑮᓯᑳᐠ"] };
+
+#[rustfmt::skip]
+static _FIELD_291: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᐠᐚᐨ ᑳᐃᐢᐸᓂᐠ"), (0, "ᐊᓄᐦᐨ ᑳᐃᐢᐸᓂᐠ"), (1, "ᒦᓇ ᐃᐢᐸᓂᑭ")], future: ["", "ᓃᑳᐣ {0} ᐃᐢᐸᓂᑭ", "", "", "", "ᓃᑳᐣ {0} ᐃᐢᐸᓂᑭ"], past: ["", "ᑳᑮ {0} ᐃᐢᐸᓂᐠ", "", "", "", "ᑳᑮ {0} ᐃᐢᐸᓂᐠ"] };
+
+#[rustfmt::skip]
+static _FIELD_292: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᐠᐚᐨ ᑳᐃᐢᐸᓂᐠ"), (0, "ᐊᓄᐦᐨ ᑳᐃᐢᐸᓂᐠ"), (1, "ᒦᓇ ᐃᐢᐸᓂᑭ")], future: ["", "ᓃᑳᐣ {0} ᐃᐢᐸᓂᑭ", "", "", "", "ᓃᑳᐣ {0} ᐃᐢᐸᓂᑭ"], past: ["", "{0} ᐅᑖᑯᓰᕽ", "", "", "", "{0} ᑳᑮ ᐃᐢᐸᓂᐠ"] };
+
+#[rustfmt::skip]
+static _FIELD_293: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᐠᐚᐨ ᐲᓯᒼ"), (0, "ᐊᓄᐦᐨ ᐲᓯᒼ"), (1, "ᒦᓇ ᐲᓯᒼ")], future: ["", "ᓃᑳᐣ {0} ᐲᓯᒧᐤᐊᐧᐦᑭ", "", "", "", "ᓃᑳᐣ {0} ᐲᓯᒧᐤᐊᐧᐦᑭ"], past: ["", "{0} ᐃᐢᐠᐚᐨ ᐲᓯᒼ", "", "", "", "{0} ᑕᐦᑐ ᐲᓯᒼ"] };
+
+#[rustfmt::skip]
+static _FIELD_294: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᑳᐤᐨ ᐲᓯᒼ"), (0, "ᐊᓄᐦᐨ ᐲᓯᒼ"), (1, "ᒦᓇ ᐲᓯᒼ")], future: ["", "ᓃᑳᐣ {0} ᐲᓯᒧᐤᐊᐧᐦᑭ", "", "", "", "ᓃᑳᐣ {0} ᐲᓯᒧᐤᐊᐧᐦᑭ"], past: ["", "{0} ᐃᐢᐠᐚᐨ ᐲᓯᒼ", "", "", "", "{0} ᑕᐦᑐ ᐲᓯᒼ"] };
+
+#[rustfmt::skip]
+static _FIELD_295: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᐠᐚᐨ ᐊᐢᑭᕀ"), (0, "ᐆᒪ ᐊᐢᑫᕀ"), (1, "ᒦᓇ ᐊᐢᑭᕀ")], future: ["", "ᓃᑳᐣ {0} ᐊᐢᑮᐊᐧᐦᑭᕀ", "", "", "", "ᓃᑳᐣ {0} ᐊᐢᑮᐊᐧᐦᑭ"], past: ["", "{0} ᑲᔮᐢ ᐊᐢᑭᕀ", "", "", "", "{0} ᑲᔮᐢ ᐊᐢᑭᕀ"] };
+
+#[rustfmt::skip]
+static _FIELD_296: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᐠᐚᐨ ᐊᐢᑭᕀ"), (0, "ᐆᒪ ᐊᐢᑫᕀ"), (1, "ᒦᓇ ᐊᐢᑭᕀ")], future: ["", "ᓃᑳᐣ {0} ᐊᐢᑮᐊᐧᐦᑭ", "", "", "", "ᓃᑳᐣ {0} ᐊᐢᑮᐊᐧᐦᑭ"], past: ["", "{0} ᑲᔮᐢ ᐊᐢᑭᕀ", "", "", "", "{0} ᑲᔮᐢ ᐊᐢᑭᕀ"] };
+
+#[rustfmt::skip]
+static _FIELD_297: _RelativeField = _RelativeField { relatives: &[(-1, "ᐃᐢᐠᐚᐨ ᐊᐢᑭᕀ"), (0, "ᐆᒪ ᐊᐢᑫᕀ"), (1, "ᒦᓇ ᐊᐢᑭᕀ")], future: ["", "ᓃᑳᐣ {0}ᐊᐢᑮᐊᐧᐦᑭ", "", "", "", "ᓃᑳᐣ {0}ᐊᐢᑮᐊᐧᐦᑭ"], past: ["", "{0} ᑲᔮᐢ ᐊᐢᑭᕀ", "", "", "", "{0} ᑲᔮᐢ ᐊᐢᑭᕀ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_24: [&_RelativeField; 24] = [
+    &_FIELD_284,
+    &_FIELD_284,
+    &_FIELD_284,
+    &_FIELD_285,
+    &_FIELD_285,
+    &_FIELD_285,
+    &_FIELD_286,
+    &_FIELD_286,
+    &_FIELD_287,
+    &_FIELD_288,
+    &_FIELD_289,
+    &_FIELD_290,
+    &_FIELD_291,
+    &_FIELD_291,
+    &_FIELD_292,
+    &_FIELD_293,
+    &_FIELD_293,
+    &_FIELD_294,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_295,
+    &_FIELD_296,
+    &_FIELD_297
+];
+
+#[rustfmt::skip]
+static _FIELD_298: _RelativeField = _RelativeField { relatives: &[(-1, "ӗнер"), (0, "паян"), (1, "ыран")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_299: _RelativeField = _RelativeField { relatives: &[(-1, "иртнӗ эрнере"), (0, "ҫак эрнере"), (1, "ҫитес эрнере")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_300: _RelativeField = _RelativeField { relatives: &[(-1, "иртнӗ эр."), (0, "ҫак эр."), (1, "ҫитес эр.")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_301: _RelativeField = _RelativeField { relatives: &[(-1, "иртнӗ уйӑхра"), (0, "ку уйӑхра"), (1, "ҫитес уйӑхра")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_302: _RelativeField = _RelativeField { relatives: &[(-1, "иртнӗ уй."), (0, "ку уй."), (1, "ҫитес уй.")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_303: _RelativeField = _RelativeField { relatives: &[(-1, "пӗлтӗр"), (0, "кӑҫал"), (1, "ҫитес ҫул")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_304: _RelativeField = _RelativeField { relatives: &[(-1, "пӗлтӗр"), (0, "кӑҫал"), (1, "ҫитес ҫ.")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_25: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_298,
+    &_FIELD_298,
+    &_FIELD_298,
+    &_FIELD_299,
+    &_FIELD_300,
+    &_FIELD_300,
+    &_FIELD_301,
+    &_FIELD_302,
+    &_FIELD_302,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_303,
+    &_FIELD_304,
+    &_FIELD_304
+];
+
+#[rustfmt::skip]
+static _FIELD_305: _RelativeField = _RelativeField { relatives: &[(0, "nawr")], future: ["ymhen {0} eiliad", "ymhen {0} eiliad", "ymhen {0} eiliad", "ymhen {0} eiliad", "ymhen {0} eiliad", "ymhen {0} eiliad"], past: ["{0} eiliad yn ôl", "{0} eiliad yn ôl", "{0} eiliad yn ôl", "{0} eiliad yn ôl", "{0} eiliad yn ôl", "{0} eiliad yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_306: _RelativeField = _RelativeField { relatives: &[(0, "nawr")], future: ["ymhen {0} eiliad", "ymhen {0} eil.", "ymhen {0} eiliad", "ymhen {0} eiliad", "ymhen {0} eiliad", "ymhen {0} eil."], past: ["{0} eiliad yn ôl", "{0} eil. yn ôl", "{0} eiliad yn ôl", "{0} eiliad yn ôl", "{0} eiliad yn ôl", "{0} eil. yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_307: _RelativeField = _RelativeField { relatives: &[(0, "y funud hon")], future: ["ymhen {0} munud", "ymhen {0} munud", "ymhen {0} munud", "ymhen {0} munud", "ymhen {0} munud", "ymhen {0} munud"], past: ["{0} munud yn ôl", "{0} munud yn ôl", "{0} munud yn ôl", "{0} munud yn ôl", "{0} munud yn ôl", "{0} munud yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_308: _RelativeField = _RelativeField { relatives: &[(0, "y funud hon")], future: ["ymhen {0} munud", "ymhen {0} mun.", "ymhen {0} fun.", "ymhen {0} munud", "ymhen {0} munud", "ymhen {0} mun."], past: ["{0} munud yn ôl", "{0} mun. yn ôl", "{0} funud yn ôl", "{0} munud yn ôl", "{0} munud yn ôl", "{0} mun. yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_309: _RelativeField = _RelativeField { relatives: &[(0, "y funud hon")], future: ["ymhen {0} munud", "ymhen {0} mun.", "ymhen {0} mun.", "ymhen {0} munud", "ymhen {0} munud", "ymhen {0} mun."], past: ["{0} munud yn ôl", "{0} mun. yn ôl", "{0} mun. yn ôl", "{0} mun. yn ôl", "{0} munud yn ôl", "{0} mun. yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_310: _RelativeField = _RelativeField { relatives: &[(0, "yr awr hon")], future: ["ymhen {0} awr", "ymhen awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr"], past: ["{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_311: _RelativeField = _RelativeField { relatives: &[(0, "yr awr hon")], future: ["ymhen {0} awr", "ymhen awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr"], past: ["{0} awr yn ôl", "awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_312: _RelativeField = _RelativeField { relatives: &[(0, "yr awr hon")], future: ["ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr", "ymhen {0} awr"], past: ["{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl", "{0} awr yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_313: _RelativeField = _RelativeField { relatives: &[(-2, "echdoe"), (-1, "ddoe"), (0, "heddiw"), (1, "yfory"), (2, "drennydd")], future: ["ymhen {0} diwrnod", "ymhen diwrnod", "ymhen deuddydd", "ymhen {0} diwrnod", "ymhen {0} diwrnod", "ymhen {0} diwrnod"], past: ["{0} diwrnod yn ôl", "{0} diwrnod yn ôl", "{0} ddiwrnod yn ôl", "{0} diwrnod yn ôl", "{0} diwrnod yn ôl", "{0} diwrnod yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_314: _RelativeField = _RelativeField { relatives: &[(-2, "echdoe"), (-1, "ddoe"), (0, "heddiw"), (1, "yfory"), (2, "drennydd")], future: ["ymhen {0} diwrnod", "ymhen {0} diwrnod", "ymhen {0} diwrnod", "ymhen {0} diwrnod", "ymhen {0} diwrnod", "ymhen {0} diwrnod"], past: ["{0} diwrnod yn ôl", "{0} diwrnod yn ôl", "{0} ddiwrnod yn ôl", "{0} diwrnod yn ôl", "{0} diwrnod yn ôl", "{0} diwrnod yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_315: _RelativeField = _RelativeField { relatives: &[(-1, "wythnos ddiwethaf"), (0, "yr wythnos hon"), (1, "wythnos nesaf")], future: ["ymhen {0} wythnos", "ymhen wythnos", "ymhen pythefnos", "ymhen {0} wythnos", "ymhen {0} wythnos", "ymhen {0} wythnos"], past: ["{0} wythnos yn ôl", "{0} wythnos yn ôl", "{0} wythnos yn ôl", "{0} wythnos yn ôl", "{0} wythnos yn ôl", "{0} wythnos yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_316: _RelativeField = _RelativeField { relatives: &[(-1, "ws. ddiwethaf"), (0, "yr ws. hon"), (1, "ws. nesaf")], future: ["ymhen {0} wythnos", "ymhen ws.", "ymhen pythefnos", "ymhen {0} wythnos", "ymhen {0} wythnos", "ymhen {0} ws."], past: ["{0} wythnos yn ôl", "{0} ws. yn ôl", "pythefnos yn ôl", "{0} wythnos yn ôl", "{0} wythnos yn ôl", "{0} ws yn ôl."] };
+
+#[rustfmt::skip]
+static _FIELD_317: _RelativeField = _RelativeField { relatives: &[(-1, "ws. ddiwethaf"), (0, "yr ws. hon"), (1, "ws. nesaf")], future: ["ymhen {0} wythnos", "ymhen {0} ws.", "ymhen {0} wythnos", "ymhen {0} wythnos", "ymhen {0} wythnos", "ymhen {0} ws."], past: ["{0} wythnos yn ôl", "{0} ws. yn ôl", "pythefnos yn ôl", "{0} wythnos yn ôl", "{0} wythnos yn ôl", "{0} ws. yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_318: _RelativeField = _RelativeField { relatives: &[(-1, "mis diwethaf"), (0, "y mis hwn"), (1, "mis nesaf")], future: ["ymhen {0} mis", "ymhen mis", "ymhen deufis", "ymhen {0} mis", "ymhen {0} mis", "ymhen {0} mis"], past: ["{0} mis yn ôl", "{0} mis yn ôl", "{0} fis yn ôl", "{0} mis yn ôl", "{0} mis yn ôl", "{0} mis yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_319: _RelativeField = _RelativeField { relatives: &[(-1, "mis diwethaf"), (0, "y mis hwn"), (1, "mis nesaf")], future: ["ymhen {0} mis", "ymhen mis", "ymhen deufis", "ymhen {0} mis", "ymhen {0} mis", "ymhen {0} mis"], past: ["{0} mis yn ôl", "{0} mis yn ôl", "deufis yn ôl", "{0} mis yn ôl", "{0} mis yn ôl", "{0} mis yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_320: _RelativeField = _RelativeField { relatives: &[(-1, "chwarter olaf"), (0, "chwarter hwn"), (1, "chwarter nesaf")], future: ["ymhen {0} chwarter", "ymhen {0} chwarter", "ymhen {0} chwarter", "ymhen {0} chwarter", "ymhen {0} chwarter", "ymhen {0} chwarter"], past: ["{0} o chwarteri yn ôl", "{0} chwarter yn ôl", "{0} chwarter yn ôl", "{0} chwarter yn ôl", "{0} chwarter yn ôl", "{0} o chwarteri yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_321: _RelativeField = _RelativeField { relatives: &[(-1, "chwarter olaf"), (0, "chwarter hwn"), (1, "chwarter nesaf")], future: ["ymhen {0} chwarter", "ymhen {0} chw.", "ymhen {0} chwarter", "ymhen {0} chwarter", "ymhen {0} chwarter", "ymhen {0} chw."], past: ["{0} o chwarteri yn ôl", "{0} chw. yn ôl", "{0} chwarter yn ôl", "{0} chwarter yn ôl", "{0} chwarter yn ôl", "{0} chw. yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_322: _RelativeField = _RelativeField { relatives: &[(-1, "llynedd"), (0, "eleni"), (1, "blwyddyn nesaf")], future: ["ymhen {0} o flynyddoedd", "ymhen blwyddyn", "ymhen {0} flynedd", "ymhen {0} blynedd", "ymhen {0} blynedd", "ymhen {0} mlynedd"], past: ["{0} o flynyddoedd yn ôl", "blwyddyn yn ôl", "{0} flynedd yn ôl", "{0} o flynyddoedd yn ôl", "{0} o flynyddoedd yn ôl", "{0} o flynyddoedd yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_323: _RelativeField = _RelativeField { relatives: &[(-1, "llynedd"), (0, "eleni"), (1, "blwyddyn nesaf")], future: ["ymhen {0} o flynyddoedd", "ymhen blwyddyn", "ymhen {0} flynedd", "ymhen {0} blynedd", "ymhen {0} blynedd", "ymhen {0} o flynyddoedd"], past: ["{0} o flynyddoedd yn ôl", "{0} bl. yn ôl", "{0} flynedd yn ôl", "{0} o flynyddoedd yn ôl", "{0} o flynyddoedd yn ôl", "{0} o flynyddoedd yn ôl"] };
+
+#[rustfmt::skip]
+static _FIELD_324: _RelativeField = _RelativeField { relatives: &[(-1, "llynedd"), (0, "eleni"), (1, "bl. nesaf")], future: ["ymhen {0} o flynyddoedd", "ymhen bl.", "ymhen {0} flynedd", "ymhen {0} blynedd", "ymhen {0} blynedd", "ymhen {0} o flynyddoedd"], past: ["{0} o flynyddoedd yn ôl", "blwyddyn yn ôl", "{0} flynedd yn ôl", "{0} o flynyddoedd yn ôl", "{0} o flynyddoedd yn ôl", "{0} o flynyddoedd yn ôl"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_26: [&_RelativeField; 24] = [
+    &_FIELD_305,
+    &_FIELD_306,
+    &_FIELD_305,
+    &_FIELD_307,
+    &_FIELD_308,
+    &_FIELD_309,
+    &_FIELD_310,
+    &_FIELD_311,
+    &_FIELD_312,
+    &_FIELD_313,
+    &_FIELD_313,
+    &_FIELD_314,
+    &_FIELD_315,
+    &_FIELD_316,
+    &_FIELD_317,
+    &_FIELD_318,
+    &_FIELD_319,
+    &_FIELD_318,
+    &_FIELD_320,
+    &_FIELD_321,
+    &_FIELD_321,
+    &_FIELD_322,
+    &_FIELD_323,
+    &_FIELD_324
+];
+
+#[rustfmt::skip]
+static _FIELD_325: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "om {0} sekund", "", "", "", "om {0} sekunder"], past: ["", "for {0} sekund siden", "", "", "", "for {0} sekunder siden"] };
+
+#[rustfmt::skip]
+static _FIELD_326: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "om {0} sek.", "", "", "", "om {0} sek."], past: ["", "{0} sek. siden", "", "", "", "{0} sek. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_327: _RelativeField = _RelativeField { relatives: &[(0, "dette minut")], future: ["", "om {0} minut", "", "", "", "om {0} minutter"], past: ["", "for {0} minut siden", "", "", "", "for {0} minutter siden"] };
+
+#[rustfmt::skip]
+static _FIELD_328: _RelativeField = _RelativeField { relatives: &[(0, "dette minut")], future: ["", "om {0} min.", "", "", "", "om {0} min."], past: ["", "{0} min. siden", "", "", "", "{0} min. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_329: _RelativeField = _RelativeField { relatives: &[(0, "denne time")], future: ["", "om {0} time", "", "", "", "om {0} timer"], past: ["", "for {0} time siden", "", "", "", "for {0} timer siden"] };
+
+#[rustfmt::skip]
+static _FIELD_330: _RelativeField = _RelativeField { relatives: &[(0, "denne time")], future: ["", "om {0} time", "", "", "", "om {0} timer"], past: ["", "{0} time siden", "", "", "", "{0} timer siden"] };
+
+#[rustfmt::skip]
+static _FIELD_331: _RelativeField = _RelativeField { relatives: &[(-2, "i forgårs"), (-1, "i går"), (0, "i dag"), (1, "i morgen"), (2, "i overmorgen")], future: ["", "om {0} dag", "", "", "", "om {0} dage"], past: ["", "for {0} dag siden", "", "", "", "for {0} dage siden"] };
+
+#[rustfmt::skip]
+static _FIELD_332: _RelativeField = _RelativeField { relatives: &[(-2, "i forgårs"), (-1, "i går"), (0, "i dag"), (1, "i morgen"), (2, "i overmorgen")], future: ["", "om {0} dag", "", "", "", "om {0} dage"], past: ["", "{0} dag siden", "", "", "", "{0} dage siden"] };
+
+#[rustfmt::skip]
+static _FIELD_333: _RelativeField = _RelativeField { relatives: &[(-1, "sidste uge"), (0, "denne uge"), (1, "næste uge")], future: ["", "om {0} uge", "", "", "", "om {0} uger"], past: ["", "for {0} uge siden", "", "", "", "for {0} uger siden"] };
+
+#[rustfmt::skip]
+static _FIELD_334: _RelativeField = _RelativeField { relatives: &[(-1, "sidste uge"), (0, "denne uge"), (1, "næste uge")], future: ["", "om {0} uge", "", "", "", "om {0} uger"], past: ["", "{0} uge siden", "", "", "", "{0} uger siden"] };
+
+#[rustfmt::skip]
+static _FIELD_335: _RelativeField = _RelativeField { relatives: &[(-1, "sidste måned"), (0, "denne måned"), (1, "næste måned")], future: ["", "om {0} måned", "", "", "", "om {0} måneder"], past: ["", "for {0} måned siden", "", "", "", "for {0} måneder siden"] };
+
+#[rustfmt::skip]
+static _FIELD_336: _RelativeField = _RelativeField { relatives: &[(-1, "sidste md."), (0, "denne md."), (1, "næste md.")], future: ["", "om {0} md.", "", "", "", "om {0} mdr."], past: ["", "{0} md. siden", "", "", "", "{0} mdr. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_337: _RelativeField = _RelativeField { relatives: &[(-1, "sidste kvartal"), (0, "dette kvartal"), (1, "næste kvartal")], future: ["", "om {0} kvartal", "", "", "", "om {0} kvartaler"], past: ["", "for {0} kvartal siden", "", "", "", "for {0} kvartaler siden"] };
+
+#[rustfmt::skip]
+static _FIELD_338: _RelativeField = _RelativeField { relatives: &[(-1, "sidste kvt."), (0, "dette kvt."), (1, "næste kvt.")], future: ["", "om {0} kvt.", "", "", "", "om {0} kvt."], past: ["", "{0} kvt. siden", "", "", "", "{0} kvt. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_339: _RelativeField = _RelativeField { relatives: &[(-1, "sidste år"), (0, "i år"), (1, "næste år")], future: ["", "om {0} år", "", "", "", "om {0} år"], past: ["", "for {0} år siden", "", "", "", "for {0} år siden"] };
+
+#[rustfmt::skip]
+static _FIELD_340: _RelativeField = _RelativeField { relatives: &[(-1, "sidste år"), (0, "i år"), (1, "næste år")], future: ["", "om {0} år", "", "", "", "om {0} år"], past: ["", "{0} år siden", "", "", "", "{0} år siden"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_27: [&_RelativeField; 24] = [
+    &_FIELD_325,
+    &_FIELD_326,
+    &_FIELD_326,
+    &_FIELD_327,
+    &_FIELD_328,
+    &_FIELD_328,
+    &_FIELD_329,
+    &_FIELD_330,
+    &_FIELD_330,
+    &_FIELD_331,
+    &_FIELD_332,
+    &_FIELD_332,
+    &_FIELD_333,
+    &_FIELD_334,
+    &_FIELD_334,
+    &_FIELD_335,
+    &_FIELD_336,
+    &_FIELD_336,
+    &_FIELD_337,
+    &_FIELD_338,
+    &_FIELD_338,
+    &_FIELD_339,
+    &_FIELD_340,
+    &_FIELD_340
+];
+
+#[rustfmt::skip]
+static _FIELD_341: _RelativeField = _RelativeField { relatives: &[(0, "jetzt")], future: ["", "in {0} Sekunde", "", "", "", "in {0} Sekunden"], past: ["", "vor {0} Sekunde", "", "", "", "vor {0} Sekunden"] };
+
+#[rustfmt::skip]
+static _FIELD_342: _RelativeField = _RelativeField { relatives: &[(0, "jetzt")], future: ["", "in {0} Sek.", "", "", "", "in {0} Sek."], past: ["", "vor {0} Sek.", "", "", "", "vor {0} Sek."] };
+
+#[rustfmt::skip]
+static _FIELD_343: _RelativeField = _RelativeField { relatives: &[(0, "jetzt")], future: ["", "in {0} s", "", "", "", "in {0} s"], past: ["", "vor {0} s", "", "", "", "vor {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_344: _RelativeField = _RelativeField { relatives: &[(0, "in dieser Minute")], future: ["", "in {0} Minute", "", "", "", "in {0} Minuten"], past: ["", "vor {0} Minute", "", "", "", "vor {0} Minuten"] };
+
+#[rustfmt::skip]
+static _FIELD_345: _RelativeField = _RelativeField { relatives: &[(0, "in dieser Minute")], future: ["", "in {0} Min.", "", "", "", "in {0} Min."], past: ["", "vor {0} Min.", "", "", "", "vor {0} Min."] };
+
+#[rustfmt::skip]
+static _FIELD_346: _RelativeField = _RelativeField { relatives: &[(0, "in dieser Minute")], future: ["", "in {0} m", "", "", "", "in {0} m"], past: ["", "vor {0} m", "", "", "", "vor {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_347: _RelativeField = _RelativeField { relatives: &[(0, "in dieser Stunde")], future: ["", "in {0} Stunde", "", "", "", "in {0} Stunden"], past: ["", "vor {0} Stunde", "", "", "", "vor {0} Stunden"] };
+
+#[rustfmt::skip]
+static _FIELD_348: _RelativeField = _RelativeField { relatives: &[(0, "in dieser Stunde")], future: ["", "in {0} Std.", "", "", "", "in {0} Std."], past: ["", "vor {0} Std.", "", "", "", "vor {0} Std."] };
+
+#[rustfmt::skip]
+static _FIELD_349: _RelativeField = _RelativeField { relatives: &[(-2, "vorgestern"), (-1, "gestern"), (0, "heute"), (1, "morgen"), (2, "übermorgen")], future: ["", "in {0} Tag", "", "", "", "in {0} Tagen"], past: ["", "vor {0} Tag", "", "", "", "vor {0} Tagen"] };
+
+#[rustfmt::skip]
+static _FIELD_350: _RelativeField = _RelativeField { relatives: &[(-1, "letzte Woche"), (0, "diese Woche"), (1, "nächste Woche")], future: ["", "in {0} Woche", "", "", "", "in {0} Wochen"], past: ["", "vor {0} Woche", "", "", "", "vor {0} Wochen"] };
+
+#[rustfmt::skip]
+static _FIELD_351: _RelativeField = _RelativeField { relatives: &[(-1, "letzte Woche"), (0, "diese Woche"), (1, "nächste Woche")], future: ["", "in {0} Wo.", "", "", "", "in {0} Wo."], past: ["", "vor {0} Wo.", "", "", "", "vor {0} Wo."] };
+
+#[rustfmt::skip]
+static _FIELD_352: _RelativeField = _RelativeField { relatives: &[(-1, "letzten Monat"), (0, "diesen Monat"), (1, "nächsten Monat")], future: ["", "in {0} Monat", "", "", "", "in {0} Monaten"], past: ["", "vor {0} Monat", "", "", "", "vor {0} Monaten"] };
+
+#[rustfmt::skip]
+static _FIELD_353: _RelativeField = _RelativeField { relatives: &[(-1, "letzten Monat"), (0, "diesen Monat"), (1, "nächsten Monat")], future: ["", "in {0} Monat", "", "", "", "in {0} Monaten"], past: ["", "vor {0} Monat", "", "", "", "vor {0}\u{a0}Monaten"] };
+
+#[rustfmt::skip]
+static _FIELD_354: _RelativeField = _RelativeField { relatives: &[(-1, "letzten Monat"), (0, "diesen Monat"), (1, "nächsten Monat")], future: ["", "in {0} Monat", "", "", "", "in {0} Monaten"], past: ["", "vor {0}\u{a0}Monat", "", "", "", "vor {0} Monaten"] };
+
+#[rustfmt::skip]
+static _FIELD_355: _RelativeField = _RelativeField { relatives: &[(-1, "letztes Quartal"), (0, "dieses Quartal"), (1, "nächstes Quartal")], future: ["", "in {0} Quartal", "", "", "", "in {0} Quartalen"], past: ["", "vor {0} Quartal", "", "", "", "vor {0} Quartalen"] };
+
+#[rustfmt::skip]
+static _FIELD_356: _RelativeField = _RelativeField { relatives: &[(-1, "letztes Quartal"), (0, "dieses Quartal"), (1, "nächstes Quartal")], future: ["", "in {0} Quart.", "", "", "", "in {0} Quart."], past: ["", "vor {0} Quart.", "", "", "", "vor {0} Quart."] };
+
+#[rustfmt::skip]
+static _FIELD_357: _RelativeField = _RelativeField { relatives: &[(-1, "letztes Quartal"), (0, "dieses Quartal"), (1, "nächstes Quartal")], future: ["", "in {0} Q", "", "", "", "in {0} Q"], past: ["", "vor {0} Q", "", "", "", "vor {0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_358: _RelativeField = _RelativeField { relatives: &[(-1, "letztes Jahr"), (0, "dieses Jahr"), (1, "nächstes Jahr")], future: ["", "in {0} Jahr", "", "", "", "in {0} Jahren"], past: ["", "vor {0} Jahr", "", "", "", "vor {0} Jahren"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_28: [&_RelativeField; 24] = [
+    &_FIELD_341,
+    &_FIELD_342,
+    &_FIELD_343,
+    &_FIELD_344,
+    &_FIELD_345,
+    &_FIELD_346,
+    &_FIELD_347,
+    &_FIELD_348,
+    &_FIELD_348,
+    &_FIELD_349,
+    &_FIELD_349,
+    &_FIELD_349,
+    &_FIELD_350,
+    &_FIELD_350,
+    &_FIELD_351,
+    &_FIELD_352,
+    &_FIELD_353,
+    &_FIELD_354,
+    &_FIELD_355,
+    &_FIELD_356,
+    &_FIELD_357,
+    &_FIELD_358,
+    &_FIELD_358,
+    &_FIELD_358
+];
+
+#[rustfmt::skip]
+static _FIELD_359: _RelativeField = _RelativeField { relatives: &[(0, "ह\u{942}न/इसल\u{948}")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_360: _RelativeField = _RelativeField { relatives: &[(0, "एह\u{94d} मि\u{902}ट/ इस मि\u{902}ट")], future: ["", "", "", "", "", "+{0} min"], past: ["", "", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_361: _RelativeField = _RelativeField { relatives: &[(0, "एह\u{94d} घ\u{948}\u{902}टा/ इस घ\u{948}\u{902}ट\u{947}")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_362: _RelativeField = _RelativeField { relatives: &[(-1, "ज\u{902}दा कल"), (0, "अज\u{94d}ज"), (1, "औ\u{902}द\u{947} कल")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_363: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला हफ\u{94d}ता/पिछल\u{947} हफ\u{94d}त\u{948}"), (0, "एह\u{94d} हफ\u{94d}ता/इस हफ\u{94d}त\u{948}"), (1, "अगला हफ\u{94d}ता/अगल\u{947} हफ\u{94d}त\u{948}")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_364: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला म\u{94d}हीना/पिछल\u{947} म\u{94d}हीन\u{948}"), (0, "एह\u{94d} म\u{94d}हीना/इस म\u{94d}हीन\u{948}"), (1, "अगला म\u{94d}हीना/अगल\u{947} म\u{94d}हीन\u{948}")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_365: _RelativeField = _RelativeField { relatives: &[(-1, "आखरी त\u{94d}रमाही"), (0, "एह\u{94d} त\u{94d}रमाही"), (1, "अगली त\u{94d}रमाही")], future: ["", "", "", "", "", "+{0} Q"], past: ["", "", "", "", "", "-{0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_366: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला ब’रा"), (0, "एह\u{94d} ब’रा"), (1, "अगला ब’रा")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_367: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला ब’रा/पिछल\u{947} ब’र\u{948}"), (0, "एह\u{94d} ब’रा/इस ब’र\u{948}"), (1, "अगला ब’रा/अगल\u{947} ब’र\u{948}")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_29: [&_RelativeField; 24] = [
+    &_FIELD_359,
+    &_FIELD_359,
+    &_FIELD_359,
+    &_FIELD_360,
+    &_FIELD_360,
+    &_FIELD_360,
+    &_FIELD_361,
+    &_FIELD_361,
+    &_FIELD_361,
+    &_FIELD_362,
+    &_FIELD_362,
+    &_FIELD_362,
+    &_FIELD_363,
+    &_FIELD_363,
+    &_FIELD_363,
+    &_FIELD_364,
+    &_FIELD_364,
+    &_FIELD_364,
+    &_FIELD_365,
+    &_FIELD_365,
+    &_FIELD_365,
+    &_FIELD_366,
+    &_FIELD_367,
+    &_FIELD_366
+];
+
+#[rustfmt::skip]
+static _FIELD_368: _RelativeField = _RelativeField { relatives: &[(0, "něnto")], future: ["", "za {0} sekundu", "za {0} sekunźe", "za {0} sekundy", "", "za {0} sekundow"], past: ["", "pśed {0} sekundu", "pśed {0} sekundoma", "pśed {0} sekundami", "", "pśed {0} sekundami"] };
+
+#[rustfmt::skip]
+static _FIELD_369: _RelativeField = _RelativeField { relatives: &[(0, "něnto")], future: ["", "za {0} sek.", "za {0} sek.", "za {0} sek.", "", "za {0} sek."], past: ["", "pśed {0} sek.", "pśed {0} sek.", "pśed {0} sek.", "", "pśed {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_370: _RelativeField = _RelativeField { relatives: &[(0, "něnto")], future: ["", "za {0} s", "za {0} s", "za {0} s", "", "za {0} s"], past: ["", "pśed {0} s", "pśed {0} s", "pśed {0} s", "", "pśed {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_371: _RelativeField = _RelativeField { relatives: &[(0, "w toś tej minuśe")], future: ["", "za {0} minutu", "za {0} minuśe", "za {0} minuty", "", "za {0} minutow"], past: ["", "pśed {0} minutu", "pśed {0} minutoma", "pśed {0} minutami", "", "pśed {0} minutami"] };
+
+#[rustfmt::skip]
+static _FIELD_372: _RelativeField = _RelativeField { relatives: &[(0, "w toś tej minuśe")], future: ["", "za {0} min.", "za {0} min.", "za {0} min.", "", "za {0} min."], past: ["", "pśed {0} min.", "pśed {0} min.", "pśed {0} min.", "", "pśed {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_373: _RelativeField = _RelativeField { relatives: &[(0, "w toś tej minuśe")], future: ["", "za {0} m", "za {0} m", "za {0} m", "", "za {0} m"], past: ["", "pśed {0} m", "pśed {0} m", "pśed {0} m", "", "pśed {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_374: _RelativeField = _RelativeField { relatives: &[(0, "w toś tej góźinje")], future: ["", "za {0} góźinu", "za {0} góźinje", "za {0} góźiny", "", "za {0} góźin"], past: ["", "pśed {0} góźinu", "pśed {0} góźinoma", "pśed {0} góźinami", "", "pśed {0} góźinami"] };
+
+#[rustfmt::skip]
+static _FIELD_375: _RelativeField = _RelativeField { relatives: &[(0, "w toś tej góźinje")], future: ["", "za {0} góź.", "za {0} góź.", "za {0} góź.", "", "za {0} góź."], past: ["", "pśed {0} góź.", "pśed {0} góź.", "pśed {0} góź.", "", "pśed {0} góź."] };
+
+#[rustfmt::skip]
+static _FIELD_376: _RelativeField = _RelativeField { relatives: &[(0, "w toś tej góźinje")], future: ["", "za {0} g", "za {0} g", "za {0} g", "", "za {0} g"], past: ["", "pśed {0} g", "pśed {0} g", "pśed {0} g", "", "pśed {0} g"] };
+
+#[rustfmt::skip]
+static _FIELD_377: _RelativeField = _RelativeField { relatives: &[(-1, "cora"), (0, "źinsa"), (1, "witśe")], future: ["", "za {0} źeń", "za {0} dnja", "za {0} dny", "", "za {0} dnjow"], past: ["", "pśed {0} dnjom", "pśed {0} dnjoma", "pśed {0} dnjami", "", "pśed {0} dnjami"] };
+
+#[rustfmt::skip]
+static _FIELD_378: _RelativeField = _RelativeField { relatives: &[(-1, "cra."), (0, "źins."), (1, "witś.")], future: ["", "za {0} źeń", "za {0} dnj.", "za {0} dny", "", "za {0} dnj."], past: ["", "pśed {0} dnj.", "pśed {0} dnj.", "pśed {0} dnj.", "", "pśed {0} dnj."] };
+
+#[rustfmt::skip]
+static _FIELD_379: _RelativeField = _RelativeField { relatives: &[(-1, "cr."), (0, "źis."), (1, "wit.")], future: ["", "za {0} ź", "za {0} ź", "za {0} ź", "", "za {0} ź"], past: ["", "pśed {0} d", "pśed {0} d", "pśed {0} d", "", "pśed {0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_380: _RelativeField = _RelativeField { relatives: &[(-1, "zajźony tyźeń"), (0, "ten tyźeń"), (1, "pśiducy tyźeń")], future: ["", "za {0} tyźeń", "za {0} tyźenja", "za {0} tyźenje", "", "za {0} tyźenjow"], past: ["", "pśed {0} tyźenjom", "pśed {0} tyźenjoma", "pśed {0} tyźenjami", "", "pśed {0} tyźenjami"] };
+
+#[rustfmt::skip]
+static _FIELD_381: _RelativeField = _RelativeField { relatives: &[(-1, "zajź. tyźeń"), (0, "tot. tyźeń"), (1, "pśiduc. tyźeń")], future: ["", "za {0} tyź.", "za {0} tyź.", "za {0} tyź.", "", "za {0} tyź."], past: ["", "pśed {0} tyź.", "pśed {0} tyź.", "pśed {0} tyź.", "", "pśed {0} tyź."] };
+
+#[rustfmt::skip]
+static _FIELD_382: _RelativeField = _RelativeField { relatives: &[(-1, "zajź. tyźeń"), (0, "te. tyźeń"), (1, "pśid. tyźeń")], future: ["", "za {0} tyź.", "za {0} tyź.", "za {0} tyź.", "", "za {0} tyź."], past: ["", "pśed {0} tyź.", "pśed {0} tyź.", "pśed {0} tyź.", "", "pśed {0} tyź."] };
+
+#[rustfmt::skip]
+static _FIELD_383: _RelativeField = _RelativeField { relatives: &[(-1, "zajźony mjasec"), (0, "ten mjasec"), (1, "pśiducy mjasec")], future: ["", "za {0} mjasec", "za {0} mjaseca", "za {0} mjasecy", "", "za {0} mjasecow"], past: ["", "pśed {0} mjasecom", "pśed {0} mjasecoma", "pśed {0} mjasecami", "", "pśed {0} mjasecami"] };
+
+#[rustfmt::skip]
+static _FIELD_384: _RelativeField = _RelativeField { relatives: &[(-1, "zajź. mjasec"), (0, "tot. mjasec"), (1, "pśiduc. mjasec")], future: ["", "za {0} mjas.", "za {0} mjas.", "za {0} mjas.", "", "za {0} mjas."], past: ["", "pśed {0} mjas.", "pśed {0} mjas.", "pśed {0} mjas.", "", "pśed {0} mjas."] };
+
+#[rustfmt::skip]
+static _FIELD_385: _RelativeField = _RelativeField { relatives: &[(-1, "zajź. mjasec"), (0, "te. mjasec"), (1, "pśid. mjasec")], future: ["", "za {0} mjas.", "za {0} mjas.", "za {0} mjas.", "", "za {0} mjas."], past: ["", "pśed {0} mjas.", "pśed {0} mjas.", "pśed {0} mjas.", "", "pśed {0} mjas."] };
+
+#[rustfmt::skip]
+static _FIELD_386: _RelativeField = _RelativeField { relatives: &[(-1, "zajźony kwartal"), (0, "toś ten kwartal"), (1, "pśiducy kwartal")], future: ["", "za {0} kwartal", "za {0} kwartala", "za {0} kwartale", "", "za {0} kwartalow"], past: ["", "pśed {0} kwartalom", "pśed {0} kwartaloma", "pśed {0} kwartalami", "", "pśed {0} kwartalami"] };
+
+#[rustfmt::skip]
+static _FIELD_387: _RelativeField = _RelativeField { relatives: &[(-1, "zajźony kwartal"), (0, "toś ten kwartal"), (1, "pśiducy kwartal")], future: ["", "za {0} kwart.", "za {0} kwart.", "za {0} kwart.", "", "za {0} kwart."], past: ["", "pśed {0} kwart.", "pśed {0} kwart.", "pśed {0} kwart.", "", "pśed {0} kwart."] };
+
+#[rustfmt::skip]
+static _FIELD_388: _RelativeField = _RelativeField { relatives: &[(-1, "zajźony kwartal"), (0, "toś ten kwartal"), (1, "pśiducy kwartal")], future: ["", "za {0} kw.", "za {0} kw.", "za {0} kw.", "", "za {0} kw."], past: ["", "pśed {0} kw.", "pśed {0} kw.", "pśed {0} kw.", "", "pśed {0} kw."] };
+
+#[rustfmt::skip]
+static _FIELD_389: _RelativeField = _RelativeField { relatives: &[(-1, "łoni"), (0, "lětosa"), (1, "znowa")], future: ["", "za {0} lěto", "za {0} lěśe", "za {0} lěta", "", "za {0} lět"], past: ["", "pśed {0} lětom", "pśed {0} lětoma", "pśed {0} lětami", "", "pśed {0} lětami"] };
+
+#[rustfmt::skip]
+static _FIELD_390: _RelativeField = _RelativeField { relatives: &[(-1, "łon."), (0, "lěts."), (1, "znow.")], future: ["", "za {0} l.", "za {0} l.", "za {0} l.", "", "za {0} l."], past: ["", "pśed {0} l.", "pśed {0} l.", "pśed {0} l.", "", "pśed {0} l."] };
+
+#[rustfmt::skip]
+static _FIELD_391: _RelativeField = _RelativeField { relatives: &[(-1, "ło."), (0, "lět."), (1, "zno.")], future: ["", "za {0} l.", "za {0} l.", "za {0} l.", "", "za {0} l."], past: ["", "pśed {0} l.", "pśed {0} l.", "pśed {0} l.", "", "pśed {0} l."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_30: [&_RelativeField; 24] = [
+    &_FIELD_368,
+    &_FIELD_369,
+    &_FIELD_370,
+    &_FIELD_371,
+    &_FIELD_372,
+    &_FIELD_373,
+    &_FIELD_374,
+    &_FIELD_375,
+    &_FIELD_376,
+    &_FIELD_377,
+    &_FIELD_378,
+    &_FIELD_379,
+    &_FIELD_380,
+    &_FIELD_381,
+    &_FIELD_382,
+    &_FIELD_383,
+    &_FIELD_384,
+    &_FIELD_385,
+    &_FIELD_386,
+    &_FIELD_387,
+    &_FIELD_388,
+    &_FIELD_389,
+    &_FIELD_390,
+    &_FIELD_391
+];
+
+#[rustfmt::skip]
+static _FIELD_392: _RelativeField = _RelativeField { relatives: &[(0, "fifi")], future: ["", "le sekend {0} me", "", "", "", "le sekend {0} wo me"], past: ["", "sekend {0} si va yi", "", "", "", "sekend {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_393: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "le aɖabaƒoƒo {0} me", "", "", "", "le aɖabaƒoƒo {0} wo me"], past: ["", "aɖabaƒoƒo {0} si va yi", "", "", "", "aɖabaƒoƒo {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_394: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "le gaƒoƒo {0} me", "", "", "", "le gaƒoƒo {0} wo me"], past: ["", "gaƒoƒo {0} si va yi", "", "", "", "gaƒoƒo {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_395: _RelativeField = _RelativeField { relatives: &[(-2, "nyitsɔ si va yi"), (-1, "etsɔ si va yi"), (0, "egbe"), (1, "etsɔ si gbɔna"), (2, "nyitsɔ si gbɔna")], future: ["", "le ŋkeke {0} me", "", "", "", "le ŋkeke {0} wo me"], past: ["", "ŋkeke {0} si va yi", "", "", "", "ŋkeke {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_396: _RelativeField = _RelativeField { relatives: &[(-1, "kɔsiɖa si va yi"), (0, "kɔsiɖa sia"), (1, "kɔsiɖa si gbɔ na")], future: ["", "le kɔsiɖa {0} me", "", "", "", "le kɔsiɖa {0} wo me"], past: ["", "kɔsiɖa {0} si va yi", "", "", "", "kɔsiɖa {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_397: _RelativeField = _RelativeField { relatives: &[(-1, "ɣleti si va yi"), (0, "ɣleti sia"), (1, "ɣleti si gbɔ na")], future: ["", "le ɣleti {0} me", "", "", "", "le ɣleti {0} wo me"], past: ["", "ɣleti {0} si va yi", "", "", "", "ɣleti {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_398: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "le kɔta {0} si gbɔ na me", "", "", "", "le kɔta {0} si gbɔ na me"], past: ["", "kɔta {0} si va yi me", "", "", "", "kɔta {0} si va yi me"] };
+
+#[rustfmt::skip]
+static _FIELD_399: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "le kɔta {0} si gbɔna me", "", "", "", "le kɔta {0} si gbɔ na me"], past: ["", "kɔta {0} si va yi me", "", "", "", "kɔta {0} si va yi me"] };
+
+#[rustfmt::skip]
+static _FIELD_400: _RelativeField = _RelativeField { relatives: &[(-1, "ƒe si va yi"), (0, "ƒe sia"), (1, "ƒe si gbɔ na")], future: ["", "le ƒe {0} me", "", "", "", "le ƒe {0} me"], past: ["", "ƒe {0} si va yi", "", "", "", "ƒe {0} si wo va yi"] };
+
+#[rustfmt::skip]
+static _FIELD_401: _RelativeField = _RelativeField { relatives: &[(-1, "ƒe si va yi"), (0, "ƒe sia"), (1, "ƒe si gbɔ na")], future: ["", "le ƒe {0} me", "", "", "", "le ƒe {0} me"], past: ["", "le ƒe {0} si va yi me", "", "", "", "le ƒe {0} si va yi me"] };
+
+#[rustfmt::skip]
+static _FIELD_402: _RelativeField = _RelativeField { relatives: &[(-1, "ƒe si va yi"), (0, "ƒe sia"), (1, "ƒe si gbɔ na")], future: ["", "le ƒe {0} si gbɔna me", "", "", "", "le ƒe {0} si gbɔna me"], past: ["", "ƒe {0} si va yi me", "", "", "", "ƒe {0} si va yi me"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_31: [&_RelativeField; 24] = [
+    &_FIELD_392,
+    &_FIELD_392,
+    &_FIELD_392,
+    &_FIELD_393,
+    &_FIELD_393,
+    &_FIELD_393,
+    &_FIELD_394,
+    &_FIELD_394,
+    &_FIELD_394,
+    &_FIELD_395,
+    &_FIELD_395,
+    &_FIELD_395,
+    &_FIELD_396,
+    &_FIELD_396,
+    &_FIELD_396,
+    &_FIELD_397,
+    &_FIELD_397,
+    &_FIELD_397,
+    &_FIELD_398,
+    &_FIELD_398,
+    &_FIELD_399,
+    &_FIELD_400,
+    &_FIELD_401,
+    &_FIELD_402
+];
+
+#[rustfmt::skip]
+static _FIELD_403: _RelativeField = _RelativeField { relatives: &[(0, "τώρα")], future: ["", "σε {0} δευτερόλεπτο", "", "", "", "σε {0} δευτερόλεπτα"], past: ["", "πριν από {0} δευτερόλεπτο", "", "", "", "πριν από {0} δευτερόλεπτα"] };
+
+#[rustfmt::skip]
+static _FIELD_404: _RelativeField = _RelativeField { relatives: &[(0, "τώρα")], future: ["", "σε {0} δευτ.", "", "", "", "σε {0} δευτ."], past: ["", "πριν από {0} δευτ.", "", "", "", "πριν από {0} δευτ."] };
+
+#[rustfmt::skip]
+static _FIELD_405: _RelativeField = _RelativeField { relatives: &[(0, "τώρα")], future: ["", "σε {0} δ.", "", "", "", "σε {0} δ."], past: ["", "{0} δ. πριν", "", "", "", "{0} δ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_406: _RelativeField = _RelativeField { relatives: &[(0, "τρέχον λεπτό")], future: ["", "σε {0} λεπτό", "", "", "", "σε {0} λεπτά"], past: ["", "πριν από {0} λεπτό", "", "", "", "πριν από {0} λεπτά"] };
+
+#[rustfmt::skip]
+static _FIELD_407: _RelativeField = _RelativeField { relatives: &[(0, "τρέχον λεπτό")], future: ["", "σε {0} λεπ.", "", "", "", "σε {0} λεπ."], past: ["", "πριν από {0} λεπ.", "", "", "", "πριν από {0} λεπ."] };
+
+#[rustfmt::skip]
+static _FIELD_408: _RelativeField = _RelativeField { relatives: &[(0, "τρέχον λεπτό")], future: ["", "σε {0} λ.", "", "", "", "σε {0} λ."], past: ["", "{0} λ. πριν", "", "", "", "{0} λ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_409: _RelativeField = _RelativeField { relatives: &[(0, "τρέχουσα ώρα")], future: ["", "σε {0} ώρα", "", "", "", "σε {0} ώρες"], past: ["", "πριν από {0} ώρα", "", "", "", "πριν από {0} ώρες"] };
+
+#[rustfmt::skip]
+static _FIELD_410: _RelativeField = _RelativeField { relatives: &[(0, "τρέχουσα ώρα")], future: ["", "σε {0} ώ.", "", "", "", "σε {0} ώ."], past: ["", "πριν από {0} ώ.", "", "", "", "πριν από {0} ώ."] };
+
+#[rustfmt::skip]
+static _FIELD_411: _RelativeField = _RelativeField { relatives: &[(0, "τρέχουσα ώρα")], future: ["", "σε {0} ώ.", "", "", "", "σε {0} ώ."], past: ["", "{0} ώ. πριν", "", "", "", "{0} ώ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_412: _RelativeField = _RelativeField { relatives: &[(-2, "προχθές"), (-1, "χθες"), (0, "σήμερα"), (1, "αύριο"), (2, "μεθαύριο")], future: ["", "σε {0} ημέρα", "", "", "", "σε {0} ημέρες"], past: ["", "πριν από {0} ημέρα", "", "", "", "πριν από {0} ημέρες"] };
+
+#[rustfmt::skip]
+static _FIELD_413: _RelativeField = _RelativeField { relatives: &[(-2, "προχθές"), (-1, "χθες"), (0, "σήμερα"), (1, "αύριο"), (2, "μεθαύριο")], future: ["", "σε {0} ημ.", "", "", "", "σε {0} ημ."], past: ["", "πριν από {0} ημ.", "", "", "", "πριν από {0} ημ."] };
+
+#[rustfmt::skip]
+static _FIELD_414: _RelativeField = _RelativeField { relatives: &[(-2, "προχθές"), (-1, "χθες"), (0, "σήμερα"), (1, "αύριο"), (2, "μεθαύριο")], future: ["", "σε {0} ημ.", "", "", "", "σε {0} ημ."], past: ["", "{0} ημ. πριν", "", "", "", "{0} ημ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_415: _RelativeField = _RelativeField { relatives: &[(-1, "προηγούμενη εβδομάδα"), (0, "τρέχουσα εβδομάδα"), (1, "επόμενη εβδομάδα")], future: ["", "σε {0} εβδομάδα", "", "", "", "σε {0} εβδομάδες"], past: ["", "πριν από {0} εβδομάδα", "", "", "", "πριν από {0} εβδομάδες"] };
+
+#[rustfmt::skip]
+static _FIELD_416: _RelativeField = _RelativeField { relatives: &[(-1, "προηγ. εβδομάδα"), (0, "τρέχ. εβδομάδα"), (1, "επόμ. εβδομάδα")], future: ["", "σε {0} εβδ.", "", "", "", "σε {0} εβδ."], past: ["", "πριν από {0} εβδ.", "", "", "", "πριν από {0} εβδ."] };
+
+#[rustfmt::skip]
+static _FIELD_417: _RelativeField = _RelativeField { relatives: &[(-1, "προηγ. εβδ."), (0, "τρέχ. εβδ."), (1, "επόμ. εβδ.")], future: ["", "σε {0} εβδ.", "", "", "", "σε {0} εβδ."], past: ["", "{0} εβδ. πριν", "", "", "", "{0} εβδ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_418: _RelativeField = _RelativeField { relatives: &[(-1, "προηγούμενος μήνας"), (0, "τρέχων μήνας"), (1, "επόμενος μήνας")], future: ["", "σε {0} μήνα", "", "", "", "σε {0} μήνες"], past: ["", "πριν από {0} μήνα", "", "", "", "πριν από {0} μήνες"] };
+
+#[rustfmt::skip]
+static _FIELD_419: _RelativeField = _RelativeField { relatives: &[(-1, "προηγ. μήνας"), (0, "τρέχων μήνας"), (1, "επόμ. μήνας")], future: ["", "σε {0} μήνα", "", "", "", "σε {0} μήνες"], past: ["", "πριν από {0} μήνα", "", "", "", "πριν από {0} μήνες"] };
+
+#[rustfmt::skip]
+static _FIELD_420: _RelativeField = _RelativeField { relatives: &[(-1, "προηγ. μήνας"), (0, "τρέχ. μήνας"), (1, "επόμ. μήνας")], future: ["", "σε {0} μ.", "", "", "", "σε {0} μ."], past: ["", "{0} μ. πριν", "", "", "", "{0} μ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_421: _RelativeField = _RelativeField { relatives: &[(-1, "προηγούμενο τρίμηνο"), (0, "τρέχον τρίμηνο"), (1, "επόμενο τρίμηνο")], future: ["", "σε {0} τρίμηνο", "", "", "", "σε {0} τρίμηνα"], past: ["", "πριν από {0} τρίμηνο", "", "", "", "πριν από {0} τρίμηνα"] };
+
+#[rustfmt::skip]
+static _FIELD_422: _RelativeField = _RelativeField { relatives: &[(-1, "προηγ. τρίμ."), (0, "τρέχον τρίμ."), (1, "επόμ. τρίμ.")], future: ["", "σε {0} τρίμ.", "", "", "", "σε {0} τρίμ."], past: ["", "πριν από {0} τρίμ.", "", "", "", "πριν από {0} τρίμ."] };
+
+#[rustfmt::skip]
+static _FIELD_423: _RelativeField = _RelativeField { relatives: &[(-1, "προηγ. τρίμ."), (0, "τρέχον τρίμ."), (1, "επόμ. τρίμ.")], future: ["", "σε {0} τρίμ.", "", "", "", "σε {0} τρίμ."], past: ["", "{0} τρίμ. πριν", "", "", "", "{0} τρίμ. πριν"] };
+
+#[rustfmt::skip]
+static _FIELD_424: _RelativeField = _RelativeField { relatives: &[(-1, "πέρσι"), (0, "φέτος"), (1, "επόμενο έτος")], future: ["", "σε {0} έτος", "", "", "", "σε {0} έτη"], past: ["", "πριν από {0} έτος", "", "", "", "πριν από {0} έτη"] };
+
+#[rustfmt::skip]
+static _FIELD_425: _RelativeField = _RelativeField { relatives: &[(-1, "πέρσι"), (0, "φέτος"), (1, "επόμενο έτος")], future: ["", "σε {0} έτος", "", "", "", "σε {0} έτη"], past: ["", "{0} έτος πριν", "", "", "", "{0} έτη πριν"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_32: [&_RelativeField; 24] = [
+    &_FIELD_403,
+    &_FIELD_404,
+    &_FIELD_405,
+    &_FIELD_406,
+    &_FIELD_407,
+    &_FIELD_408,
+    &_FIELD_409,
+    &_FIELD_410,
+    &_FIELD_411,
+    &_FIELD_412,
+    &_FIELD_413,
+    &_FIELD_414,
+    &_FIELD_415,
+    &_FIELD_416,
+    &_FIELD_417,
+    &_FIELD_418,
+    &_FIELD_419,
+    &_FIELD_420,
+    &_FIELD_421,
+    &_FIELD_422,
+    &_FIELD_423,
+    &_FIELD_424,
+    &_FIELD_424,
+    &_FIELD_425
+];
+
+#[rustfmt::skip]
+static _FIELD_426: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "in {0} second", "", "", "", "in {0} seconds"], past: ["", "{0} second ago", "", "", "", "{0} seconds ago"] };
+
+#[rustfmt::skip]
+static _FIELD_427: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "in {0} sec.", "", "", "", "in {0} sec."], past: ["", "{0} sec. ago", "", "", "", "{0} sec. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_428: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "in {0}s", "", "", "", "in {0}s"], past: ["", "{0}s ago", "", "", "", "{0}s ago"] };
+
+#[rustfmt::skip]
+static _FIELD_429: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "in {0} minute", "", "", "", "in {0} minutes"], past: ["", "{0} minute ago", "", "", "", "{0} minutes ago"] };
+
+#[rustfmt::skip]
+static _FIELD_430: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "in {0} min.", "", "", "", "in {0} min."], past: ["", "{0} min. ago", "", "", "", "{0} min. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_431: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "in {0}m", "", "", "", "in {0}m"], past: ["", "{0}m ago", "", "", "", "{0}m ago"] };
+
+#[rustfmt::skip]
+static _FIELD_432: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "in {0} hour", "", "", "", "in {0} hours"], past: ["", "{0} hour ago", "", "", "", "{0} hours ago"] };
+
+#[rustfmt::skip]
+static _FIELD_433: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "in {0} hr.", "", "", "", "in {0} hr."], past: ["", "{0} hr. ago", "", "", "", "{0} hr. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_434: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "in {0}h", "", "", "", "in {0}h"], past: ["", "{0}h ago", "", "", "", "{0}h ago"] };
+
+#[rustfmt::skip]
+static _FIELD_435: _RelativeField = _RelativeField { relatives: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")], future: ["", "in {0} day", "", "", "", "in {0} days"], past: ["", "{0} day ago", "", "", "", "{0} days ago"] };
+
+#[rustfmt::skip]
+static _FIELD_436: _RelativeField = _RelativeField { relatives: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")], future: ["", "in {0}d", "", "", "", "in {0}d"], past: ["", "{0}d ago", "", "", "", "{0}d ago"] };
+
+#[rustfmt::skip]
+static _FIELD_437: _RelativeField = _RelativeField { relatives: &[(-1, "last week"), (0, "this week"), (1, "next week")], future: ["", "in {0} week", "", "", "", "in {0} weeks"], past: ["", "{0} week ago", "", "", "", "{0} weeks ago"] };
+
+#[rustfmt::skip]
+static _FIELD_438: _RelativeField = _RelativeField { relatives: &[(-1, "last wk."), (0, "this wk."), (1, "next wk.")], future: ["", "in {0} wk.", "", "", "", "in {0} wk."], past: ["", "{0} wk. ago", "", "", "", "{0} wk. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_439: _RelativeField = _RelativeField { relatives: &[(-1, "last wk."), (0, "this wk."), (1, "next wk.")], future: ["", "in {0}w", "", "", "", "in {0}w"], past: ["", "{0}w ago", "", "", "", "{0}w ago"] };
+
+#[rustfmt::skip]
+static _FIELD_440: _RelativeField = _RelativeField { relatives: &[(-1, "last month"), (0, "this month"), (1, "next month")], future: ["", "in {0} month", "", "", "", "in {0} months"], past: ["", "{0} month ago", "", "", "", "{0} months ago"] };
+
+#[rustfmt::skip]
+static _FIELD_441: _RelativeField = _RelativeField { relatives: &[(-1, "last mo."), (0, "this mo."), (1, "next mo.")], future: ["", "in {0} mo.", "", "", "", "in {0} mo."], past: ["", "{0} mo. ago", "", "", "", "{0} mo. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_442: _RelativeField = _RelativeField { relatives: &[(-1, "last mo."), (0, "this mo."), (1, "next mo.")], future: ["", "in {0}mo", "", "", "", "in {0}mo"], past: ["", "{0}mo ago", "", "", "", "{0}mo ago"] };
+
+#[rustfmt::skip]
+static _FIELD_443: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "in {0} quarter", "", "", "", "in {0} quarters"], past: ["", "{0} quarter ago", "", "", "", "{0} quarters ago"] };
+
+#[rustfmt::skip]
+static _FIELD_444: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")], future: ["", "in {0} qtr.", "", "", "", "in {0} qtrs."], past: ["", "{0} qtr. ago", "", "", "", "{0} qtrs. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_445: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")], future: ["", "in {0}q", "", "", "", "in {0}q"], past: ["", "{0}q ago", "", "", "", "{0}q ago"] };
+
+#[rustfmt::skip]
+static _FIELD_446: _RelativeField = _RelativeField { relatives: &[(-1, "last year"), (0, "this year"), (1, "next year")], future: ["", "in {0} year", "", "", "", "in {0} years"], past: ["", "{0} year ago", "", "", "", "{0} years ago"] };
+
+#[rustfmt::skip]
+static _FIELD_447: _RelativeField = _RelativeField { relatives: &[(-1, "last yr."), (0, "this yr."), (1, "next yr.")], future: ["", "in {0} yr.", "", "", "", "in {0} yr."], past: ["", "{0} yr. ago", "", "", "", "{0} yr. ago"] };
+
+#[rustfmt::skip]
+static _FIELD_448: _RelativeField = _RelativeField { relatives: &[(-1, "last yr."), (0, "this yr."), (1, "next yr.")], future: ["", "in {0}y", "", "", "", "in {0}y"], past: ["", "{0}y ago", "", "", "", "{0}y ago"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_33: [&_RelativeField; 24] = [
+    &_FIELD_426,
+    &_FIELD_427,
+    &_FIELD_428,
+    &_FIELD_429,
+    &_FIELD_430,
+    &_FIELD_431,
+    &_FIELD_432,
+    &_FIELD_433,
+    &_FIELD_434,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_436,
+    &_FIELD_437,
+    &_FIELD_438,
+    &_FIELD_439,
+    &_FIELD_440,
+    &_FIELD_441,
+    &_FIELD_442,
+    &_FIELD_443,
+    &_FIELD_444,
+    &_FIELD_445,
+    &_FIELD_446,
+    &_FIELD_447,
+    &_FIELD_448
+];
+
+#[rustfmt::skip]
+static _FIELD_449: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "in {0} sec", "", "", "", "in {0} sec"], past: ["", "{0} sec ago", "", "", "", "{0} sec ago"] };
+
+#[rustfmt::skip]
+static _FIELD_450: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "in {0} min", "", "", "", "in {0} min"], past: ["", "{0} min ago", "", "", "", "{0} min ago"] };
+
+#[rustfmt::skip]
+static _FIELD_451: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "in {0} hr", "", "", "", "in {0} hr"], past: ["", "{0} hr ago", "", "", "", "{0} hr ago"] };
+
+#[rustfmt::skip]
+static _FIELD_452: _RelativeField = _RelativeField { relatives: &[(-1, "last wk"), (0, "this wk"), (1, "next wk")], future: ["", "in {0} wk", "", "", "", "in {0} wk"], past: ["", "{0} wk ago", "", "", "", "{0} wk ago"] };
+
+#[rustfmt::skip]
+static _FIELD_453: _RelativeField = _RelativeField { relatives: &[(-1, "last mo"), (0, "this mo"), (1, "next mo")], future: ["", "in {0} mo", "", "", "", "in {0} mo"], past: ["", "{0} mo ago", "", "", "", "{0} mo ago"] };
+
+#[rustfmt::skip]
+static _FIELD_454: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")], future: ["", "in {0} qtr", "", "", "", "in {0} qtr"], past: ["", "{0} qtr ago", "", "", "", "{0} qtr ago"] };
+
+#[rustfmt::skip]
+static _FIELD_455: _RelativeField = _RelativeField { relatives: &[(-1, "last yr"), (0, "this yr"), (1, "next yr")], future: ["", "in {0} yr", "", "", "", "in {0} yr"], past: ["", "{0} yr ago", "", "", "", "{0} yr ago"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_34: [&_RelativeField; 24] = [
+    &_FIELD_426,
+    &_FIELD_449,
+    &_FIELD_449,
+    &_FIELD_429,
+    &_FIELD_450,
+    &_FIELD_450,
+    &_FIELD_432,
+    &_FIELD_451,
+    &_FIELD_451,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_437,
+    &_FIELD_452,
+    &_FIELD_452,
+    &_FIELD_440,
+    &_FIELD_453,
+    &_FIELD_453,
+    &_FIELD_443,
+    &_FIELD_454,
+    &_FIELD_454,
+    &_FIELD_446,
+    &_FIELD_455,
+    &_FIELD_455
+];
+
+#[rustfmt::skip]
+static _FIELD_456: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "in {0} sec", "", "", "", "in {0} secs"], past: ["", "{0} sec ago", "", "", "", "{0} secs ago"] };
+
+#[rustfmt::skip]
+static _FIELD_457: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "in {0} min", "", "", "", "in {0} mins"], past: ["", "{0} min ago", "", "", "", "{0} mins ago"] };
+
+#[rustfmt::skip]
+static _FIELD_458: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "in {0} hr", "", "", "", "in {0} hrs"], past: ["", "{0} hr ago", "", "", "", "{0} hrs ago"] };
+
+#[rustfmt::skip]
+static _FIELD_459: _RelativeField = _RelativeField { relatives: &[(-1, "last wk"), (0, "this wk"), (1, "next wk")], future: ["", "in {0} wk", "", "", "", "in {0} wks"], past: ["", "{0} wk ago", "", "", "", "{0} wks ago"] };
+
+#[rustfmt::skip]
+static _FIELD_460: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")], future: ["", "in {0} qtr", "", "", "", "in {0} qtrs"], past: ["", "{0} qtr ago", "", "", "", "{0} qtrs ago"] };
+
+#[rustfmt::skip]
+static _FIELD_461: _RelativeField = _RelativeField { relatives: &[(-1, "last yr"), (0, "this yr"), (1, "next yr")], future: ["", "in {0} yr", "", "", "", "in {0} yrs"], past: ["", "{0} yr ago", "", "", "", "{0} yrs ago"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_35: [&_RelativeField; 24] = [
+    &_FIELD_426,
+    &_FIELD_456,
+    &_FIELD_456,
+    &_FIELD_429,
+    &_FIELD_457,
+    &_FIELD_457,
+    &_FIELD_432,
+    &_FIELD_458,
+    &_FIELD_458,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_437,
+    &_FIELD_459,
+    &_FIELD_459,
+    &_FIELD_440,
+    &_FIELD_453,
+    &_FIELD_453,
+    &_FIELD_443,
+    &_FIELD_460,
+    &_FIELD_460,
+    &_FIELD_446,
+    &_FIELD_461,
+    &_FIELD_461
+];
+
+#[rustfmt::skip]
+static _FIELD_462: _RelativeField = _RelativeField { relatives: &[(-1, "last mo"), (0, "this mo"), (1, "next mo")], future: ["", "in {0} mo", "", "", "", "in {0} mos"], past: ["", "{0} mo ago", "", "", "", "{0} mos ago"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_36: [&_RelativeField; 24] = [
+    &_FIELD_426,
+    &_FIELD_456,
+    &_FIELD_456,
+    &_FIELD_429,
+    &_FIELD_457,
+    &_FIELD_457,
+    &_FIELD_432,
+    &_FIELD_458,
+    &_FIELD_458,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_436,
+    &_FIELD_437,
+    &_FIELD_459,
+    &_FIELD_459,
+    &_FIELD_440,
+    &_FIELD_462,
+    &_FIELD_462,
+    &_FIELD_443,
+    &_FIELD_460,
+    &_FIELD_460,
+    &_FIELD_446,
+    &_FIELD_461,
+    &_FIELD_461
+];
+
+#[rustfmt::skip]
+static _FIELD_463: _RelativeField = _RelativeField { relatives: &[(-1, "last mth"), (0, "this mth"), (1, "next mth")], future: ["", "in {0} mth", "", "", "", "in {0} mth"], past: ["", "{0} mth ago", "", "", "", "{0} mth ago"] };
+
+#[rustfmt::skip]
+static _FIELD_464: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr"), (0, "this qtr"), (1, "next qtr")], future: ["", "in {0} qtr", "", "", "", "in {0} qtrs"], past: ["", "{0} qtr ago", "", "", "", "{0} qtrs ago"] };
+
+#[rustfmt::skip]
+static _FIELD_465: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr"), (0, "this qtr"), (1, "next qtr")], future: ["", "in {0} qtr", "", "", "", "in {0} qtr"], past: ["", "{0} qtr ago", "", "", "", "{0} qtr ago"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_37: [&_RelativeField; 24] = [
+    &_FIELD_426,
+    &_FIELD_449,
+    &_FIELD_449,
+    &_FIELD_429,
+    &_FIELD_450,
+    &_FIELD_450,
+    &_FIELD_432,
+    &_FIELD_451,
+    &_FIELD_451,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_435,
+    &_FIELD_437,
+    &_FIELD_452,
+    &_FIELD_452,
+    &_FIELD_440,
+    &_FIELD_463,
+    &_FIELD_453,
+    &_FIELD_443,
+    &_FIELD_464,
+    &_FIELD_465,
+    &_FIELD_446,
+    &_FIELD_455,
+    &_FIELD_455
+];
+
+#[rustfmt::skip]
+static _FIELD_466: _RelativeField = _RelativeField { relatives: &[(0, "ahora")], future: ["", "dentro de {0} segundo", "", "", "", "dentro de {0} segundos"], past: ["", "hace {0} segundo", "", "", "", "hace {0} segundos"] };
+
+#[rustfmt::skip]
+static _FIELD_467: _RelativeField = _RelativeField { relatives: &[(0, "ahora")], future: ["", "dentro de {0} s", "", "", "", "dentro de {0} s"], past: ["", "hace {0} s", "", "", "", "hace {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_468: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "dentro de {0} minuto", "", "", "", "dentro de {0} minutos"], past: ["", "hace {0} minuto", "", "", "", "hace {0} minutos"] };
+
+#[rustfmt::skip]
+static _FIELD_469: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "dentro de {0} min", "", "", "", "dentro de {0} min"], past: ["", "hace {0} min", "", "", "", "hace {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_470: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "dentro de {0} hora", "", "", "", "dentro de {0} horas"], past: ["", "hace {0} hora", "", "", "", "hace {0} horas"] };
+
+#[rustfmt::skip]
+static _FIELD_471: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "dentro de {0} h", "", "", "", "dentro de {0} h"], past: ["", "hace {0} h", "", "", "", "hace {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_472: _RelativeField = _RelativeField { relatives: &[(-2, "anteayer"), (-1, "ayer"), (0, "hoy"), (1, "mañana"), (2, "pasado mañana")], future: ["", "dentro de {0} día", "", "", "", "dentro de {0} días"], past: ["", "hace {0} día", "", "", "", "hace {0} días"] };
+
+#[rustfmt::skip]
+static _FIELD_473: _RelativeField = _RelativeField { relatives: &[(-2, "anteayer"), (-1, "ayer"), (0, "hoy"), (1, "mañana"), (2, "pasado mañana")], future: ["", "dentro de {0} d", "", "", "", "dentro de {0} d"], past: ["", "hace {0} d", "", "", "", "hace {0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_474: _RelativeField = _RelativeField { relatives: &[(-1, "la semana pasada"), (0, "esta semana"), (1, "la próxima semana")], future: ["", "dentro de {0} semana", "", "", "", "dentro de {0} semanas"], past: ["", "hace {0} semana", "", "", "", "hace {0} semanas"] };
+
+#[rustfmt::skip]
+static _FIELD_475: _RelativeField = _RelativeField { relatives: &[(-1, "sem. ant."), (0, "esta sem."), (1, "próx. sem.")], future: ["", "dentro de {0} sem.", "", "", "", "dentro de {0} sem."], past: ["", "hace {0} sem.", "", "", "", "hace {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_476: _RelativeField = _RelativeField { relatives: &[(-1, "el mes pasado"), (0, "este mes"), (1, "el próximo mes")], future: ["", "dentro de {0} mes", "", "", "", "dentro de {0} meses"], past: ["", "hace {0} mes", "", "", "", "hace {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_477: _RelativeField = _RelativeField { relatives: &[(-1, "el mes pasado"), (0, "este mes"), (1, "el próximo mes")], future: ["", "dentro de {0} m", "", "", "", "dentro de {0} m"], past: ["", "hace {0} m", "", "", "", "hace {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_478: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre pasado"), (0, "este trimestre"), (1, "el próximo trimestre")], future: ["", "dentro de {0} trimestre", "", "", "", "dentro de {0} trimestres"], past: ["", "hace {0} trimestre", "", "", "", "hace {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_479: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre pasado"), (0, "este trimestre"), (1, "el próximo trimestre")], future: ["", "dentro de {0} trim.", "", "", "", "dentro de {0} trim."], past: ["", "hace {0} trim.", "", "", "", "hace {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_480: _RelativeField = _RelativeField { relatives: &[(-1, "el año pasado"), (0, "este año"), (1, "el próximo año")], future: ["", "dentro de {0} año", "", "", "", "dentro de {0} años"], past: ["", "hace {0} año", "", "", "", "hace {0} años"] };
+
+#[rustfmt::skip]
+static _FIELD_481: _RelativeField = _RelativeField { relatives: &[(-1, "el año pasado"), (0, "este año"), (1, "el próximo año")], future: ["", "dentro de {0} a", "", "", "", "dentro de {0} a"], past: ["", "hace {0} a", "", "", "", "hace {0} a"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_38: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_467,
+    &_FIELD_467,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_471,
+    &_FIELD_471,
+    &_FIELD_472,
+    &_FIELD_473,
+    &_FIELD_473,
+    &_FIELD_474,
+    &_FIELD_475,
+    &_FIELD_475,
+    &_FIELD_476,
+    &_FIELD_477,
+    &_FIELD_477,
+    &_FIELD_478,
+    &_FIELD_479,
+    &_FIELD_479,
+    &_FIELD_480,
+    &_FIELD_481,
+    &_FIELD_481
+];
+
+#[rustfmt::skip]
+static _FIELD_482: _RelativeField = _RelativeField { relatives: &[(-1, "sem. pas."), (0, "esta sem."), (1, "próx. sem.")], future: ["", "dentro de {0} sem.", "", "", "", "dentro de {0} sem."], past: ["", "hace {0} sem.", "", "", "", "hace {0} sem."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_39: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_467,
+    &_FIELD_467,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_471,
+    &_FIELD_471,
+    &_FIELD_472,
+    &_FIELD_472,
+    &_FIELD_472,
+    &_FIELD_474,
+    &_FIELD_482,
+    &_FIELD_482,
+    &_FIELD_476,
+    &_FIELD_477,
+    &_FIELD_477,
+    &_FIELD_478,
+    &_FIELD_479,
+    &_FIELD_479,
+    &_FIELD_480,
+    &_FIELD_481,
+    &_FIELD_481
+];
+
+#[rustfmt::skip]
+static _FIELD_483: _RelativeField = _RelativeField { relatives: &[(0, "ahora")], future: ["", "dentro de {0} seg.", "", "", "", "dentro de {0} seg."], past: ["", "hace {0} seg.", "", "", "", "hace {0} seg."] };
+
+#[rustfmt::skip]
+static _FIELD_484: _RelativeField = _RelativeField { relatives: &[(-2, "anteayer"), (-1, "ayer"), (0, "hoy"), (1, "mañana"), (2, "pasado mañana")], future: ["", "dentro de {0} días", "", "", "", "dentro de {0} días"], past: ["", "hace {0} días", "", "", "", "hace {0} días"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_40: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_483,
+    &_FIELD_483,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_471,
+    &_FIELD_471,
+    &_FIELD_472,
+    &_FIELD_484,
+    &_FIELD_484,
+    &_FIELD_474,
+    &_FIELD_482,
+    &_FIELD_482,
+    &_FIELD_476,
+    &_FIELD_477,
+    &_FIELD_477,
+    &_FIELD_478,
+    &_FIELD_479,
+    &_FIELD_479,
+    &_FIELD_480,
+    &_FIELD_481,
+    &_FIELD_481
+];
+
+#[rustfmt::skip]
+static _FIELD_485: _RelativeField = _RelativeField { relatives: &[(0, "ahora")], future: ["", "en {0} s", "", "", "", "en {0} s"], past: ["", "hace {0} s", "", "", "", "hace {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_486: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "en {0} h", "", "", "", "en {0} n"], past: ["", "hace {0} h", "", "", "", "hace {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_487: _RelativeField = _RelativeField { relatives: &[(-2, "anteayer"), (-1, "ayer"), (0, "hoy"), (1, "mañana"), (2, "pasado mañana")], future: ["", "en {0} día", "", "", "", "en {0} días"], past: ["", "hace {0} día", "", "", "", "hace {0} días"] };
+
+#[rustfmt::skip]
+static _FIELD_488: _RelativeField = _RelativeField { relatives: &[(-1, "la semana pasada"), (0, "esta semana"), (1, "la semana próxima")], future: ["", "dentro de {0} semana", "", "", "", "dentro de {0} semanas"], past: ["", "hace {0} semana", "", "", "", "hace {0} semanas"] };
+
+#[rustfmt::skip]
+static _FIELD_489: _RelativeField = _RelativeField { relatives: &[(-1, "sem. pas."), (0, "esta sem."), (1, "próx. sem.")], future: ["", "en {0} sem.", "", "", "", "en {0} sem."], past: ["", "hace {0} sem.", "", "", "", "hace {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_490: _RelativeField = _RelativeField { relatives: &[(-1, "el mes pasado"), (0, "este mes"), (1, "el mes próximo")], future: ["", "en {0} mes", "", "", "", "en {0} meses"], past: ["", "hace {0} mes", "", "", "", "hace {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_491: _RelativeField = _RelativeField { relatives: &[(-1, "el mes pasado"), (0, "este mes"), (1, "el mes próximo")], future: ["", "en {0} m", "", "", "", "en {0} m"], past: ["", "hace {0} m", "", "", "", "hace {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_492: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre pasado"), (0, "este trimestre"), (1, "el próximo trimestre")], future: ["", "en {0} trimestre", "", "", "", "dentro de {0} trimetres"], past: ["", "hace {0} trimestre", "", "", "", "hace {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_493: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre pasado"), (0, "este trimestre"), (1, "el próximo trimestre")], future: ["", "en {0} trim.", "", "", "", "en {0} trim"], past: ["", "hace {0} trim.", "", "", "", "hace {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_494: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre pasado"), (0, "este trimestre"), (1, "el próximo trimestre")], future: ["", "en {0} trim.", "", "", "", "en {0} trim."], past: ["", "hace {0} trim.", "", "", "", "hace {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_495: _RelativeField = _RelativeField { relatives: &[(-1, "el año pasado"), (0, "este año"), (1, "el año próximo")], future: ["", "dentro de {0} año", "", "", "", "dentro de {0} años"], past: ["", "hace {0} año", "", "", "", "hace {0} años"] };
+
+#[rustfmt::skip]
+static _FIELD_496: _RelativeField = _RelativeField { relatives: &[(-1, "el año pasado"), (0, "este año"), (1, "el año próximo")], future: ["", "en {0} a", "", "", "", "en {0} a"], past: ["", "hace {0} a", "", "", "", "hace {0} a"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_41: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_485,
+    &_FIELD_485,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_486,
+    &_FIELD_486,
+    &_FIELD_472,
+    &_FIELD_487,
+    &_FIELD_487,
+    &_FIELD_488,
+    &_FIELD_489,
+    &_FIELD_489,
+    &_FIELD_490,
+    &_FIELD_491,
+    &_FIELD_491,
+    &_FIELD_492,
+    &_FIELD_493,
+    &_FIELD_494,
+    &_FIELD_495,
+    &_FIELD_496,
+    &_FIELD_496
+];
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_42: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_483,
+    &_FIELD_483,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_471,
+    &_FIELD_471,
+    &_FIELD_472,
+    &_FIELD_472,
+    &_FIELD_472,
+    &_FIELD_474,
+    &_FIELD_482,
+    &_FIELD_482,
+    &_FIELD_476,
+    &_FIELD_477,
+    &_FIELD_477,
+    &_FIELD_478,
+    &_FIELD_479,
+    &_FIELD_479,
+    &_FIELD_480,
+    &_FIELD_481,
+    &_FIELD_481
+];
+
+#[rustfmt::skip]
+static _FIELD_497: _RelativeField = _RelativeField { relatives: &[(-2, "antier"), (-1, "ayer"), (0, "hoy"), (1, "mañana"), (2, "pasado mañana")], future: ["", "dentro de {0} día", "", "", "", "dentro de {0} días"], past: ["", "hace {0} día", "", "", "", "hace {0} días"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_43: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_467,
+    &_FIELD_467,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_471,
+    &_FIELD_471,
+    &_FIELD_497,
+    &_FIELD_497,
+    &_FIELD_497,
+    &_FIELD_474,
+    &_FIELD_482,
+    &_FIELD_482,
+    &_FIELD_476,
+    &_FIELD_477,
+    &_FIELD_477,
+    &_FIELD_478,
+    &_FIELD_479,
+    &_FIELD_479,
+    &_FIELD_480,
+    &_FIELD_481,
+    &_FIELD_481
+];
+
+#[rustfmt::skip]
+static _FIELD_498: _RelativeField = _RelativeField { relatives: &[(-1, "sem. pasada"), (0, "esta sem."), (1, "próxima sem.")], future: ["", "dentro de {0} sem.", "", "", "", "dentro de {0} sem."], past: ["", "hace {0} sem.", "", "", "", "hace {0} sem."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_44: [&_RelativeField; 24] = [
+    &_FIELD_466,
+    &_FIELD_467,
+    &_FIELD_467,
+    &_FIELD_468,
+    &_FIELD_469,
+    &_FIELD_469,
+    &_FIELD_470,
+    &_FIELD_471,
+    &_FIELD_471,
+    &_FIELD_472,
+    &_FIELD_472,
+    &_FIELD_472,
+    &_FIELD_474,
+    &_FIELD_498,
+    &_FIELD_498,
+    &_FIELD_476,
+    &_FIELD_477,
+    &_FIELD_477,
+    &_FIELD_478,
+    &_FIELD_479,
+    &_FIELD_479,
+    &_FIELD_480,
+    &_FIELD_481,
+    &_FIELD_481
+];
+
+#[rustfmt::skip]
+static _FIELD_499: _RelativeField = _RelativeField { relatives: &[(0, "nüüd")], future: ["", "{0} sekundi pärast", "", "", "", "{0} sekundi pärast"], past: ["", "{0} sekundi eest", "", "", "", "{0} sekundi eest"] };
+
+#[rustfmt::skip]
+static _FIELD_500: _RelativeField = _RelativeField { relatives: &[(0, "nüüd")], future: ["", "{0} sek pärast", "", "", "", "{0} sek pärast"], past: ["", "{0} sek eest", "", "", "", "{0} sek eest"] };
+
+#[rustfmt::skip]
+static _FIELD_501: _RelativeField = _RelativeField { relatives: &[(0, "nüüd")], future: ["", "{0} s pärast", "", "", "", "{0} s pärast"], past: ["", "{0} s eest", "", "", "", "{0} s eest"] };
+
+#[rustfmt::skip]
+static _FIELD_502: _RelativeField = _RelativeField { relatives: &[(0, "praegusel minutil")], future: ["", "{0} minuti pärast", "", "", "", "{0} minuti pärast"], past: ["", "{0} minuti eest", "", "", "", "{0} minuti eest"] };
+
+#[rustfmt::skip]
+static _FIELD_503: _RelativeField = _RelativeField { relatives: &[(0, "praegusel minutil")], future: ["", "{0} min pärast", "", "", "", "{0} min pärast"], past: ["", "{0} min eest", "", "", "", "{0} min eest"] };
+
+#[rustfmt::skip]
+static _FIELD_504: _RelativeField = _RelativeField { relatives: &[(0, "praegusel tunnil")], future: ["", "{0} tunni pärast", "", "", "", "{0} tunni pärast"], past: ["", "{0} tunni eest", "", "", "", "{0} tunni eest"] };
+
+#[rustfmt::skip]
+static _FIELD_505: _RelativeField = _RelativeField { relatives: &[(0, "praegusel tunnil")], future: ["", "{0} t pärast", "", "", "", "{0} t pärast"], past: ["", "{0} t eest", "", "", "", "{0} t eest"] };
+
+#[rustfmt::skip]
+static _FIELD_506: _RelativeField = _RelativeField { relatives: &[(-2, "üleeile"), (-1, "eile"), (0, "täna"), (1, "homme"), (2, "ülehomme")], future: ["", "{0} päeva pärast", "", "", "", "{0} päeva pärast"], past: ["", "{0} päeva eest", "", "", "", "{0} päeva eest"] };
+
+#[rustfmt::skip]
+static _FIELD_507: _RelativeField = _RelativeField { relatives: &[(-2, "üleeile"), (-1, "eile"), (0, "täna"), (1, "homme"), (2, "ülehomme")], future: ["", "{0} p pärast", "", "", "", "{0} p pärast"], past: ["", "{0} p eest", "", "", "", "{0} p eest"] };
+
+#[rustfmt::skip]
+static _FIELD_508: _RelativeField = _RelativeField { relatives: &[(-1, "eelmine nädal"), (0, "käesolev nädal"), (1, "järgmine nädal")], future: ["", "{0} nädala pärast", "", "", "", "{0} nädala pärast"], past: ["", "{0} nädala eest", "", "", "", "{0} nädala eest"] };
+
+#[rustfmt::skip]
+static _FIELD_509: _RelativeField = _RelativeField { relatives: &[(-1, "eelm. näd"), (0, "see näd"), (1, "järgm. näd")], future: ["", "{0} näd pärast", "", "", "", "{0} näd pärast"], past: ["", "{0} näd eest", "", "", "", "{0} näd eest"] };
+
+#[rustfmt::skip]
+static _FIELD_510: _RelativeField = _RelativeField { relatives: &[(-1, "eelm. n"), (0, "see n"), (1, "järgm. n")], future: ["", "{0} näd pärast", "", "", "", "{0} näd pärast"], past: ["", "{0} näd eest", "", "", "", "{0} näd eest"] };
+
+#[rustfmt::skip]
+static _FIELD_511: _RelativeField = _RelativeField { relatives: &[(-1, "eelmine kuu"), (0, "käesolev kuu"), (1, "järgmine kuu")], future: ["", "{0} kuu pärast", "", "", "", "{0} kuu pärast"], past: ["", "{0} kuu eest", "", "", "", "{0} kuu eest"] };
+
+#[rustfmt::skip]
+static _FIELD_512: _RelativeField = _RelativeField { relatives: &[(-1, "eelm. kuu"), (0, "see kuu"), (1, "järgm. kuu")], future: ["", "{0} kuu pärast", "", "", "", "{0} kuu pärast"], past: ["", "{0} kuu eest", "", "", "", "{0} kuu eest"] };
+
+#[rustfmt::skip]
+static _FIELD_513: _RelativeField = _RelativeField { relatives: &[(-1, "eelm. k"), (0, "see k"), (1, "järgm. k")], future: ["", "{0} k pärast", "", "", "", "{0} k pärast"], past: ["", "{0} k eest", "", "", "", "{0} k eest"] };
+
+#[rustfmt::skip]
+static _FIELD_514: _RelativeField = _RelativeField { relatives: &[(-1, "eelmine kvartal"), (0, "käesolev kvartal"), (1, "järgmine kvartal")], future: ["", "{0} kvartali pärast", "", "", "", "{0} kvartali pärast"], past: ["", "{0} kvartali eest", "", "", "", "{0} kvartali eest"] };
+
+#[rustfmt::skip]
+static _FIELD_515: _RelativeField = _RelativeField { relatives: &[(-1, "eelmine kv"), (0, "käesolev kv"), (1, "järgmine kv")], future: ["", "{0} kv pärast", "", "", "", "{0} kv pärast"], past: ["", "{0} kv eest", "", "", "", "{0} kv eest"] };
+
+#[rustfmt::skip]
+static _FIELD_516: _RelativeField = _RelativeField { relatives: &[(-1, "eelmine aasta"), (0, "käesolev aasta"), (1, "järgmine aasta")], future: ["", "{0} aasta pärast", "", "", "", "{0} aasta pärast"], past: ["", "{0} aasta eest", "", "", "", "{0} aasta eest"] };
+
+#[rustfmt::skip]
+static _FIELD_517: _RelativeField = _RelativeField { relatives: &[(-1, "eelmine a"), (0, "käesolev a"), (1, "järgmine a")], future: ["", "{0} a pärast", "", "", "", "{0} a pärast"], past: ["", "{0} a eest", "", "", "", "{0} a eest"] };
+
+#[rustfmt::skip]
+static _FIELD_518: _RelativeField = _RelativeField { relatives: &[(-1, "eelm. a"), (0, "see a"), (1, "järgm. a")], future: ["", "{0} a pärast", "", "", "", "{0} a pärast"], past: ["", "{0} a eest", "", "", "", "{0} a eest"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_45: [&_RelativeField; 24] = [
+    &_FIELD_499,
+    &_FIELD_500,
+    &_FIELD_501,
+    &_FIELD_502,
+    &_FIELD_503,
+    &_FIELD_503,
+    &_FIELD_504,
+    &_FIELD_505,
+    &_FIELD_505,
+    &_FIELD_506,
+    &_FIELD_507,
+    &_FIELD_507,
+    &_FIELD_508,
+    &_FIELD_509,
+    &_FIELD_510,
+    &_FIELD_511,
+    &_FIELD_512,
+    &_FIELD_513,
+    &_FIELD_514,
+    &_FIELD_515,
+    &_FIELD_515,
+    &_FIELD_516,
+    &_FIELD_517,
+    &_FIELD_518
+];
+
+#[rustfmt::skip]
+static _FIELD_519: _RelativeField = _RelativeField { relatives: &[(0, "orain")], future: ["", "{0} segundo barru", "", "", "", "{0} segundo barru"], past: ["", "duela {0} segundo", "", "", "", "duela {0} segundo"] };
+
+#[rustfmt::skip]
+static _FIELD_520: _RelativeField = _RelativeField { relatives: &[(0, "minutu honetan")], future: ["", "{0} minutu barru", "", "", "", "{0} minutu barru"], past: ["", "duela {0} minutu", "", "", "", "duela {0} minutu"] };
+
+#[rustfmt::skip]
+static _FIELD_521: _RelativeField = _RelativeField { relatives: &[(0, "ordu honetan")], future: ["", "{0} ordu barru", "", "", "", "{0} ordu barru"], past: ["", "duela {0} ordu", "", "", "", "duela {0} ordu"] };
+
+#[rustfmt::skip]
+static _FIELD_522: _RelativeField = _RelativeField { relatives: &[(-2, "herenegun"), (-1, "atzo"), (0, "gaur"), (1, "bihar"), (2, "etzi")], future: ["", "{0} egun barru", "", "", "", "{0} egun barru"], past: ["", "duela {0} egun", "", "", "", "duela {0} egun"] };
+
+#[rustfmt::skip]
+static _FIELD_523: _RelativeField = _RelativeField { relatives: &[(-1, "aurreko astean"), (0, "aste honetan"), (1, "hurrengo astean")], future: ["", "{0} aste barru", "", "", "", "{0} aste barru"], past: ["", "duela {0} aste", "", "", "", "duela {0} aste"] };
+
+#[rustfmt::skip]
+static _FIELD_524: _RelativeField = _RelativeField { relatives: &[(-1, "aurreko hilabetean"), (0, "hilabete honetan"), (1, "hurrengo hilabetean")], future: ["", "{0} hilabete barru", "", "", "", "{0} hilabete barru"], past: ["", "duela {0} hilabete", "", "", "", "duela {0} hilabete"] };
+
+#[rustfmt::skip]
+static _FIELD_525: _RelativeField = _RelativeField { relatives: &[(-1, "aurreko hiruhilekoa"), (0, "hiruhileko hau"), (1, "hurrengo hiruhilekoa")], future: ["", "{0} hiruhileko barru", "", "", "", "{0} hiruhileko barru"], past: ["", "duela {0} hiruhileko", "", "", "", "duela {0} hiruhileko"] };
+
+#[rustfmt::skip]
+static _FIELD_526: _RelativeField = _RelativeField { relatives: &[(-1, "aurreko hiruhil."), (0, "hiruhil. hau"), (1, "hurrengo hiruhil.")], future: ["", "{0} hiruhileko barru", "", "", "", "{0} hiruhileko barru"], past: ["", "duela {0} hiruhileko", "", "", "", "duela {0} hiruhileko"] };
+
+#[rustfmt::skip]
+static _FIELD_527: _RelativeField = _RelativeField { relatives: &[(-1, "iaz"), (0, "aurten"), (1, "hurrengo urtean")], future: ["", "{0} urte barru", "", "", "", "{0} urte barru"], past: ["", "duela {0} urte", "", "", "", "duela {0} urte"] };
+
+#[rustfmt::skip]
+static _FIELD_528: _RelativeField = _RelativeField { relatives: &[(-1, "aurreko urtea"), (0, "aurten"), (1, "hurrengo urtea")], future: ["", "{0} urte barru", "", "", "", "{0} urte barru"], past: ["", "duela {0} urte", "", "", "", "duela {0} urte"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_46: [&_RelativeField; 24] = [
+    &_FIELD_519,
+    &_FIELD_519,
+    &_FIELD_519,
+    &_FIELD_520,
+    &_FIELD_520,
+    &_FIELD_520,
+    &_FIELD_521,
+    &_FIELD_521,
+    &_FIELD_521,
+    &_FIELD_522,
+    &_FIELD_522,
+    &_FIELD_522,
+    &_FIELD_523,
+    &_FIELD_523,
+    &_FIELD_523,
+    &_FIELD_524,
+    &_FIELD_524,
+    &_FIELD_524,
+    &_FIELD_525,
+    &_FIELD_526,
+    &_FIELD_526,
+    &_FIELD_527,
+    &_FIELD_528,
+    &_FIELD_528
+];
+
+#[rustfmt::skip]
+static _FIELD_529: _RelativeField = _RelativeField { relatives: &[(0, "اکنون")], future: ["", "{0} ثانیه بعد", "", "", "", "{0} ثانیه بعد"], past: ["", "{0} ثانیه پیش", "", "", "", "{0} ثانیه پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_530: _RelativeField = _RelativeField { relatives: &[(0, "همین دقیقه")], future: ["", "{0} دقیقه بعد", "", "", "", "{0} دقیقه بعد"], past: ["", "{0} دقیقه پیش", "", "", "", "{0} دقیقه پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_531: _RelativeField = _RelativeField { relatives: &[(0, "همین ساعت")], future: ["", "{0} ساعت بعد", "", "", "", "{0} ساعت بعد"], past: ["", "{0} ساعت پیش", "", "", "", "{0} ساعت پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_532: _RelativeField = _RelativeField { relatives: &[(-2, "پریروز"), (-1, "دیروز"), (0, "امروز"), (1, "فردا"), (2, "پس\u{200c}فردا")], future: ["", "{0} روز دیگر", "", "", "", "{0} روز دیگر"], past: ["", "{0} روز پیش", "", "", "", "{0} روز پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_533: _RelativeField = _RelativeField { relatives: &[(-2, "پریروز"), (-1, "دیروز"), (0, "امروز"), (1, "فردا"), (2, "پس\u{200c}فردا")], future: ["", "{0} روز بعد", "", "", "", "{0} روز بعد"], past: ["", "{0} روز پیش", "", "", "", "{0} روز پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_534: _RelativeField = _RelativeField { relatives: &[(-1, "هفته\u{654} گذشته"), (0, "این هفته"), (1, "هفته\u{654} آینده")], future: ["", "{0} هفته بعد", "", "", "", "{0} هفته بعد"], past: ["", "{0} هفته پیش", "", "", "", "{0} هفته پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_535: _RelativeField = _RelativeField { relatives: &[(-1, "ماه گذشته"), (0, "این ماه"), (1, "ماه آینده")], future: ["", "{0} ماه بعد", "", "", "", "{0} ماه بعد"], past: ["", "{0} ماه پیش", "", "", "", "{0} ماه پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_536: _RelativeField = _RelativeField { relatives: &[(-1, "سه\u{200c}ماهه\u{654} گذشته"), (0, "سه\u{200c}ماهه\u{654} کنونی"), (1, "سه\u{200c}ماهه\u{654} آینده")], future: ["", "{0} سه\u{200c}ماهه\u{654} بعد", "", "", "", "{0} سه\u{200c}ماهه\u{654} بعد"], past: ["", "{0} سه\u{200c}ماهه\u{654} پیش", "", "", "", "{0} سه\u{200c}ماهه\u{654} پیش"] };
+
+#[rustfmt::skip]
+static _FIELD_537: _RelativeField = _RelativeField { relatives: &[(-1, "سال گذشته"), (0, "امسال"), (1, "سال آینده")], future: ["", "{0} سال بعد", "", "", "", "{0} سال بعد"], past: ["", "{0} سال پیش", "", "", "", "{0} سال پیش"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_47: [&_RelativeField; 24] = [
+    &_FIELD_529,
+    &_FIELD_529,
+    &_FIELD_529,
+    &_FIELD_530,
+    &_FIELD_530,
+    &_FIELD_530,
+    &_FIELD_531,
+    &_FIELD_531,
+    &_FIELD_531,
+    &_FIELD_532,
+    &_FIELD_532,
+    &_FIELD_533,
+    &_FIELD_534,
+    &_FIELD_534,
+    &_FIELD_534,
+    &_FIELD_535,
+    &_FIELD_535,
+    &_FIELD_535,
+    &_FIELD_536,
+    &_FIELD_536,
+    &_FIELD_536,
+    &_FIELD_537,
+    &_FIELD_537,
+    &_FIELD_537
+];
+
+#[rustfmt::skip]
+static _FIELD_538: _RelativeField = _RelativeField { relatives: &[(0, "𞤶𞤮\u{1e945}𞤲𞤭")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤳𞤭𞤲𞤰𞤢𞤤", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤳𞤭𞤲𞤰𞤫"], past: ["", "{0} 𞤳𞤭𞤲𞤰𞤢𞤤 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤺𞤢𞤤", "", "", "", "{0} 𞤳𞤭𞤲𞤰𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_539: _RelativeField = _RelativeField { relatives: &[(0, "𞤶𞤮\u{1e945}𞤲𞤭")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤳𞤭𞤲.", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤳𞤭𞤲."], past: ["", "{0} 𞤳𞤭𞤲. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤺𞤢𞤤", "", "", "", "{0} 𞤳𞤭𞤲. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_540: _RelativeField = _RelativeField { relatives: &[(0, "𞤲𞥋𞤣𞤫\u{1e945} 𞤯𞤮𞤮 𞤸𞤮𞤶𞤮𞤥𞤪𞤫")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤸𞤮𞤶𞤮𞤥𞤪𞤫", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤳𞤮𞤶𞤮𞤥𞤶𞤫"], past: ["", "{0} 𞤸𞤮𞤶𞤮𞤥𞤪𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤳𞤮𞤶𞤮𞤥𞤶𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_541: _RelativeField = _RelativeField { relatives: &[(0, "𞤲𞤣𞤫\u{1e945} 𞤸𞤮𞤶𞤮𞤥𞤢\u{1e944}𞤪𞤫")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤸𞤮𞤶.", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤸𞤮𞤶."], past: ["", "{0} 𞤸𞤮𞤶. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤳𞤮𞤶. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_542: _RelativeField = _RelativeField { relatives: &[(0, "𞤐𞤣𞤫\u{1e945} 𞤸𞤮𞤶𞤮𞤥𞤢\u{1e944}𞤪𞤫")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤸𞤮𞤶.", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤸𞤮𞤶."], past: ["", "{0} 𞤸𞤮𞤶. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤸𞤮𞤶. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_543: _RelativeField = _RelativeField { relatives: &[(0, "𞤲𞥋𞤣𞤭\u{1e945} 𞤯𞤮𞤮 𞤲𞥋𞤶𞤢𞤥𞤲𞥋𞤣𞤭")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤲𞥋𞤶𞤢𞤥𞤲𞥋𞤣𞤭", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤲𞥋𞤶𞤢𞤥𞤤𞤭"], past: ["", "{0} 𞤲𞥋𞤶𞤢𞤥𞤲𞥋𞤣𞤭 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤭", "", "", "", "{0} 𞤲𞥋𞤶𞤢𞤥𞤤𞤭 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤭"] };
+
+#[rustfmt::skip]
+static _FIELD_544: _RelativeField = _RelativeField { relatives: &[(0, "𞤐𞤣𞤭\u{1e945} 𞤲𞤶𞤢𞤥𞤲𞤣𞤭")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤶𞤢.", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤶𞤢."], past: ["", "{0} 𞤶𞤢. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤭", "", "", "", "{0} 𞤶𞤢. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤭"] };
+
+#[rustfmt::skip]
+static _FIELD_545: _RelativeField = _RelativeField { relatives: &[(-1, "𞤸𞤢𞤲𞤳𞤭"), (0, "𞤸𞤢𞤲𞤣𞤫"), (1, "𞤶𞤢𞤲𞤺𞤮")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤻𞤢𞤤\u{1e946}𞤢𞤤", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤻𞤢𞤤\u{1e946}𞤫"], past: ["", "{0} 𞤻𞤢𞤤\u{1e946}𞤢𞤤 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤻𞤢𞤤\u{1e946}𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_546: _RelativeField = _RelativeField { relatives: &[(-1, "𞤴𞤮𞤲𞤼𞤫𞤪𞤫 𞤬𞤫𞤰\u{1e946}𞤵𞤲𞥋𞤣𞤫"), (0, "𞤲𞤣𞤫\u{1e945} 𞤯𞤮𞤮 𞤴𞤮𞤲𞤼𞤫𞤪𞤫"), (1, "𞤴𞤮𞤲𞤼𞤫𞤪𞤫 𞤢𞤪𞤢𞤴𞤲𞤣𞤫")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤴𞤮𞤲𞤼𞤫𞤪𞤫", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤶𞤮𞤲𞤼𞤫"], past: ["", "{0} 𞤴𞤮𞤲𞤼𞤫𞤪𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤶𞤮𞤲𞤼𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_547: _RelativeField = _RelativeField { relatives: &[(-1, "𞤴𞤼. 𞤬𞤫𞤰\u{1e946}𞤵𞤲𞥋𞤣𞤫"), (0, "𞤲𞤣𞤫\u{1e945} 𞤯𞤮𞤮 𞤴𞤼."), (1, "𞤴𞤼. 𞤸𞤭𞤳\u{1e946}𞤭\u{1e945}𞤲𞤣𞤫")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤴𞤼.", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤶𞤼."], past: ["", "{0} 𞤴𞤼. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤶𞤼. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_548: _RelativeField = _RelativeField { relatives: &[(-1, "𞤤𞤫𞤱𞤪𞤵 𞤬𞤫𞤰\u{1e946}𞤵𞤲𞥋𞤣𞤵"), (0, "𞤲𞥋𞤣𞤵\u{1e945} 𞤯𞤮𞤮 𞤤𞤫𞤱𞤪𞤵"), (1, "𞤤𞤫𞤱𞤪𞤵 𞤢𞤪𞤢𞤴𞤲𞥋𞤣𞤵")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤤𞤫𞤱𞤪𞤵", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤤𞤫𞤦\u{1e946}𞤭"], past: ["", "{0} 𞤤𞤫𞤱𞤪𞤵 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤵", "", "", "", "{0} 𞤤𞤫𞤦\u{1e946}𞤭 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤭"] };
+
+#[rustfmt::skip]
+static _FIELD_549: _RelativeField = _RelativeField { relatives: &[(-1, "𞤤𞤫𞤱. 𞤬𞤫𞤰\u{1e946}𞤵𞤲𞥋𞤣𞤵"), (0, "𞤲𞤣𞤮\u{1e945} 𞤯𞤮𞤮 𞤤𞤫𞤱."), (1, "𞤤𞤫𞤱. 𞤸𞤭𞤳\u{1e946}𞤭\u{1e945}𞤲𞤣𞤵")], future: ["", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤤𞤫𞤱.", "", "", "", "𞤲𞥋𞤣𞤫𞤪 {0} 𞤤𞤫𞤦."], past: ["", "{0} 𞤤𞤫𞤱. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤵", "", "", "", "{0} 𞤤𞤫𞤦. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤭"] };
+
+#[rustfmt::skip]
+static _FIELD_550: _RelativeField = _RelativeField { relatives: &[(-1, "𞤲𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 𞤬𞤫𞤰\u{1e946}𞤵𞤲𞥋𞤣𞤫"), (0, "𞤲𞤣𞤫\u{1e945} 𞤲𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫"), (1, "𞤲𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 𞤢𞤪𞤮\u{1e945}𞤪𞤫")], future: ["", "𞤲𞤣𞤫𞤪 𞤲𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 {0}", "", "", "", "𞤲𞤣𞤫𞤪 𞤲𞤢𞤴𞤩𞤭\u{1e945}𞤶𞤫 {0}"], past: ["", "𞤱𞤢𞤯𞤭\u{1e945} 𞤲𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 {0}", "", "", "", "{0} 𞤲𞤢𞤴𞤢𞤩𞤭\u{1e945}𞤶𞤫 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_551: _RelativeField = _RelativeField { relatives: &[(-1, "𞤲𞤢𞤴. 𞤬𞤫𞤰."), (0, "𞤲𞤣𞤫\u{1e945} 𞤲𞤢𞤴."), (1, "𞤲𞤢𞤴. 𞤢𞤪𞤮\u{1e945}𞤪𞤫")], future: ["", "𞤲𞤣𞤫𞤪 𞤲𞤢𞤴. {0}", "", "", "", "𞤲𞤣𞤫𞤪 𞤲𞤢𞤴. {0}"], past: ["", "𞤱𞤢𞤯𞤭\u{1e945} 𞤲𞤢𞤴. {0}", "", "", "", "{0} 𞤲𞤢𞤴𞤶. 𞤱𞤵𞤤𞤭\u{1e945}𞤯𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_552: _RelativeField = _RelativeField { relatives: &[(-1, "𞤲𞤢𞤴. 𞤬𞤫𞤰."), (0, "𞤲𞤣𞤫\u{1e945} 𞤲𞤢𞤴."), (1, "𞤲𞤢𞤴. 𞤢𞤪𞤮\u{1e945}𞤪𞤫")], future: ["", "𞤲𞤣𞤫𞤪 𞤲𞤢𞤴. {0}", "", "", "", "𞤲𞤣𞤫𞤪 𞤲𞤢𞤴. {0}"], past: ["", "{0} 𞤲𞤢𞤴. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫", "", "", "", "{0} 𞤲𞤢𞤴𞤶. 𞤱𞤵𞤤𞤭\u{1e945}𞤲𞥋𞤣𞤫"] };
+
+#[rustfmt::skip]
+static _FIELD_553: _RelativeField = _RelativeField { relatives: &[(-1, "𞤪𞤮𞤱𞤢𞤲𞤭"), (0, "𞤸𞤭𞤳\u{1e946}𞤢"), (1, "𞤸𞤭𞤼𞤢\u{1e944}𞤲𞤣𞤫 𞤢𞤪𞤮\u{1e945}𞤪𞤫")], future: ["", "𞤲𞤣𞤫𞤪 {0} 𞤸𞤭𞤼𞤢\u{1e944}𞤲𞤣𞤫", "", "", "", "𞤲𞤣𞤫𞤪 {0} 𞤳𞤭𞤼𞤢\u{1e944}𞤤𞤫"], past: ["", "𞤱𞤢𞤯𞤭\u{1e945} 𞤸𞤭𞤼𞤢\u{1e944}𞤲𞤣𞤫 {0}", "", "", "", "{0} 𞤳𞤭𞤼𞤢\u{1e944}𞤯𞤫 𞤪𞤫𞤱𞤢𞤲𞤭"] };
+
+#[rustfmt::skip]
+static _FIELD_554: _RelativeField = _RelativeField { relatives: &[(-1, "𞤪𞤮𞤱𞤢𞤲𞤭"), (0, "𞤸𞤭𞤳\u{1e946}𞤢"), (1, "𞤸𞤭𞤼𞤢\u{1e944}𞤲𞤣𞤫 𞤢𞤪𞤮\u{1e945}𞤪𞤫")], future: ["", "𞤲𞤣𞤫𞤪 𞤸𞤭𞤼. {0}", "", "", "", "𞤲𞤣𞤫𞤪 𞤳𞤭𞤼. {0}"], past: ["", "{0} 𞤸𞤭𞤼. 𞤪𞤫𞤱𞤢𞤲𞤭", "", "", "", "{0} 𞤳𞤭𞤼. 𞤪𞤫𞤱𞤢𞤲𞤭"] };
+
+#[rustfmt::skip]
+static _FIELD_555: _RelativeField = _RelativeField { relatives: &[(-1, "𞤪𞤮𞤱𞤢𞤲𞤭"), (0, "𞤸𞤭𞤳\u{1e946}𞤢"), (1, "𞤸𞤭𞤼 𞤢𞤪𞤮\u{1e945}𞤪𞤫")], future: ["", "𞤲𞤣𞤫𞤪 𞤸𞤭𞤼. {0}", "", "", "", "𞤲𞤣𞤫𞤪 𞤳𞤭𞤼. {0}"], past: ["", "{0} 𞤸𞤭𞤼. 𞤪𞤫𞤱𞤢𞤲𞤭", "", "", "", "{0} 𞤳𞤭𞤼. 𞤪𞤫𞤱𞤢𞤲𞤭"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_48: [&_RelativeField; 24] = [
+    &_FIELD_538,
+    &_FIELD_539,
+    &_FIELD_539,
+    &_FIELD_540,
+    &_FIELD_541,
+    &_FIELD_542,
+    &_FIELD_543,
+    &_FIELD_544,
+    &_FIELD_544,
+    &_FIELD_545,
+    &_FIELD_545,
+    &_FIELD_545,
+    &_FIELD_546,
+    &_FIELD_547,
+    &_FIELD_547,
+    &_FIELD_548,
+    &_FIELD_549,
+    &_FIELD_549,
+    &_FIELD_550,
+    &_FIELD_551,
+    &_FIELD_552,
+    &_FIELD_553,
+    &_FIELD_554,
+    &_FIELD_555
+];
+
+#[rustfmt::skip]
+static _FIELD_556: _RelativeField = _RelativeField { relatives: &[(0, "nyt")], future: ["", "{0} sekunnin päästä", "", "", "", "{0} sekunnin päästä"], past: ["", "{0} sekunti sitten", "", "", "", "{0} sekuntia sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_557: _RelativeField = _RelativeField { relatives: &[(0, "nyt")], future: ["", "{0} s päästä", "", "", "", "{0} s päästä"], past: ["", "{0} s sitten", "", "", "", "{0} s sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_558: _RelativeField = _RelativeField { relatives: &[(0, "tämän minuutin aikana")], future: ["", "{0} minuutin päästä", "", "", "", "{0} minuutin päästä"], past: ["", "{0} minuutti sitten", "", "", "", "{0} minuuttia sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_559: _RelativeField = _RelativeField { relatives: &[(0, "minuutin sisällä")], future: ["", "{0} min päästä", "", "", "", "{0} min päästä"], past: ["", "{0} min sitten", "", "", "", "{0} min sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_560: _RelativeField = _RelativeField { relatives: &[(0, "tämän tunnin aikana")], future: ["", "{0} tunnin päästä", "", "", "", "{0} tunnin päästä"], past: ["", "{0} tunti sitten", "", "", "", "{0} tuntia sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_561: _RelativeField = _RelativeField { relatives: &[(0, "tunnin sisällä")], future: ["", "{0} t päästä", "", "", "", "{0} t päästä"], past: ["", "{0} t sitten", "", "", "", "{0} t sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_562: _RelativeField = _RelativeField { relatives: &[(-2, "toissa päivänä"), (-1, "eilen"), (0, "tänään"), (1, "huomenna"), (2, "ylihuomenna")], future: ["", "{0} päivän päästä", "", "", "", "{0} päivän päästä"], past: ["", "{0} päivä sitten", "", "", "", "{0} päivää sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_563: _RelativeField = _RelativeField { relatives: &[(-2, "toissap."), (-1, "eilen"), (0, "tänään"), (1, "huom."), (2, "ylihuom.")], future: ["", "{0} pv päästä", "", "", "", "{0} pv päästä"], past: ["", "{0} pv sitten", "", "", "", "{0} pv sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_564: _RelativeField = _RelativeField { relatives: &[(-1, "viime viikolla"), (0, "tällä viikolla"), (1, "ensi viikolla")], future: ["", "{0} viikon päästä", "", "", "", "{0} viikon päästä"], past: ["", "{0} viikko sitten", "", "", "", "{0} viikkoa sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_565: _RelativeField = _RelativeField { relatives: &[(-1, "viime vk"), (0, "tällä vk"), (1, "ensi vk")], future: ["", "{0} vk päästä", "", "", "", "{0} vk päästä"], past: ["", "{0} vk sitten", "", "", "", "{0} vk sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_566: _RelativeField = _RelativeField { relatives: &[(-1, "viime kuussa"), (0, "tässä kuussa"), (1, "ensi kuussa")], future: ["", "{0} kuukauden päästä", "", "", "", "{0} kuukauden päästä"], past: ["", "{0} kuukausi sitten", "", "", "", "{0} kuukautta sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_567: _RelativeField = _RelativeField { relatives: &[(-1, "viime kk"), (0, "tässä kk"), (1, "ensi kk")], future: ["", "{0} kk päästä", "", "", "", "{0} kk päästä"], past: ["", "{0} kk sitten", "", "", "", "{0} kk sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_568: _RelativeField = _RelativeField { relatives: &[(-1, "viime neljännesvuonna"), (0, "tänä neljännesvuonna"), (1, "ensi neljännesvuonna")], future: ["", "{0} neljännesvuoden päästä", "", "", "", "{0} neljännesvuoden päästä"], past: ["", "{0} neljännesvuosi sitten", "", "", "", "{0} neljännesvuotta sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_569: _RelativeField = _RelativeField { relatives: &[(-1, "viime neljänneksenä"), (0, "tänä neljänneksenä"), (1, "ensi neljänneksenä")], future: ["", "{0} neljänneksen päästä", "", "", "", "{0} neljänneksen päästä"], past: ["", "{0} neljännes sitten", "", "", "", "{0} neljännestä sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_570: _RelativeField = _RelativeField { relatives: &[(-1, "viime nelj."), (0, "tänä nelj."), (1, "ensi nelj.")], future: ["", "{0} nelj. päästä", "", "", "", "{0} nelj. päästä"], past: ["", "{0} nelj. sitten", "", "", "", "{0} nelj. sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_571: _RelativeField = _RelativeField { relatives: &[(-1, "viime vuonna"), (0, "tänä vuonna"), (1, "ensi vuonna")], future: ["", "{0} vuoden päästä", "", "", "", "{0} vuoden päästä"], past: ["", "{0} vuosi sitten", "", "", "", "{0} vuotta sitten"] };
+
+#[rustfmt::skip]
+static _FIELD_572: _RelativeField = _RelativeField { relatives: &[(-1, "viime v"), (0, "tänä v"), (1, "ensi v")], future: ["", "{0} v päästä", "", "", "", "{0} v päästä"], past: ["", "{0} v sitten", "", "", "", "{0} v sitten"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_49: [&_RelativeField; 24] = [
+    &_FIELD_556,
+    &_FIELD_557,
+    &_FIELD_557,
+    &_FIELD_558,
+    &_FIELD_559,
+    &_FIELD_559,
+    &_FIELD_560,
+    &_FIELD_561,
+    &_FIELD_561,
+    &_FIELD_562,
+    &_FIELD_563,
+    &_FIELD_563,
+    &_FIELD_564,
+    &_FIELD_565,
+    &_FIELD_565,
+    &_FIELD_566,
+    &_FIELD_567,
+    &_FIELD_567,
+    &_FIELD_568,
+    &_FIELD_569,
+    &_FIELD_570,
+    &_FIELD_571,
+    &_FIELD_572,
+    &_FIELD_572
+];
+
+#[rustfmt::skip]
+static _FIELD_573: _RelativeField = _RelativeField { relatives: &[(0, "ngayon")], future: ["", "sa {0} segundo", "", "", "", "sa {0} segundo"], past: ["", "{0} segundo ang nakalipas", "", "", "", "{0} segundo ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_574: _RelativeField = _RelativeField { relatives: &[(0, "ngayon")], future: ["", "sa {0} seg.", "", "", "", "sa {0} seg."], past: ["", "{0} seg. ang nakalipas", "", "", "", "{0} seg. ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_575: _RelativeField = _RelativeField { relatives: &[(0, "sa minutong ito")], future: ["", "sa {0} minuto", "", "", "", "sa {0} minuto"], past: ["", "{0} minuto ang nakalipas", "", "", "", "{0} minuto ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_576: _RelativeField = _RelativeField { relatives: &[(0, "sa minutong ito")], future: ["", "sa {0} min.", "", "", "", "sa {0} min."], past: ["", "{0} min. ang nakalipas", "", "", "", "{0} min. ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_577: _RelativeField = _RelativeField { relatives: &[(0, "ngayong oras")], future: ["", "sa {0} oras", "", "", "", "sa {0} oras"], past: ["", "{0} oras ang nakalipas", "", "", "", "{0} oras ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_578: _RelativeField = _RelativeField { relatives: &[(0, "ngayong oras")], future: ["", "sa {0} oras", "", "", "", "sa {0} (na) oras"], past: ["", "{0} oras ang nakalipas", "", "", "", "{0} oras ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_579: _RelativeField = _RelativeField { relatives: &[(-2, "Araw bago ang kahapon"), (-1, "kahapon"), (0, "ngayong araw"), (1, "bukas"), (2, "Samakalawa")], future: ["", "sa {0} araw", "", "", "", "sa {0} araw"], past: ["", "{0} araw ang nakalipas", "", "", "", "{0} araw ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_580: _RelativeField = _RelativeField { relatives: &[(-2, "Araw bago ang kahapon"), (-1, "kahapon"), (0, "ngayong araw"), (1, "bukas"), (2, "Samakalawa")], future: ["", "sa {0} (na) araw", "", "", "", "sa {0} araw"], past: ["", "{0} araw ang nakalipas", "", "", "", "{0} (na) araw ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_581: _RelativeField = _RelativeField { relatives: &[(-1, "nakalipas na linggo"), (0, "sa linggong ito"), (1, "susunod na linggo")], future: ["", "sa {0} linggo", "", "", "", "sa {0} linggo"], past: ["", "{0} linggo ang nakalipas", "", "", "", "{0} linggo ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_582: _RelativeField = _RelativeField { relatives: &[(-1, "nakaraang linggo"), (0, "ngayong linggo"), (1, "susunod na linggo")], future: ["", "sa {0} linggo", "", "", "", "sa {0} linggo"], past: ["", "{0} linggo ang nakalipas", "", "", "", "{0} (na) linggo ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_583: _RelativeField = _RelativeField { relatives: &[(-1, "nakaraang linggo"), (0, "ngayong linggo"), (1, "susunod na linggo")], future: ["", "sa {0} linggo", "", "", "", "sa {0} linggo"], past: ["", "{0} linggo ang nakalipas", "", "", "", "{0} linggo ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_584: _RelativeField = _RelativeField { relatives: &[(-1, "nakaraang buwan"), (0, "ngayong buwan"), (1, "susunod na buwan")], future: ["", "sa {0} buwan", "", "", "", "sa {0} buwan"], past: ["", "{0} buwan ang nakalipas", "", "", "", "{0} buwan ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_585: _RelativeField = _RelativeField { relatives: &[(-1, "nakaraang quarter"), (0, "ngayong quarter"), (1, "susunod na quarter")], future: ["", "sa {0} quarter", "", "", "", "sa {0} quarter"], past: ["", "{0} quarter ang nakalipas", "", "", "", "{0} quarter ang nakalipas"] };
+
+#[rustfmt::skip]
+static _FIELD_586: _RelativeField = _RelativeField { relatives: &[(-1, "nakaraang taon"), (0, "ngayong taon"), (1, "susunod na taon")], future: ["", "sa {0} taon", "", "", "", "sa {0} taon"], past: ["", "{0} taon ang nakalipas", "", "", "", "{0} taon ang nakalipas"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_50: [&_RelativeField; 24] = [
+    &_FIELD_573,
+    &_FIELD_574,
+    &_FIELD_574,
+    &_FIELD_575,
+    &_FIELD_576,
+    &_FIELD_576,
+    &_FIELD_577,
+    &_FIELD_578,
+    &_FIELD_577,
+    &_FIELD_579,
+    &_FIELD_580,
+    &_FIELD_579,
+    &_FIELD_581,
+    &_FIELD_582,
+    &_FIELD_583,
+    &_FIELD_584,
+    &_FIELD_584,
+    &_FIELD_584,
+    &_FIELD_585,
+    &_FIELD_585,
+    &_FIELD_585,
+    &_FIELD_586,
+    &_FIELD_586,
+    &_FIELD_586
+];
+
+#[rustfmt::skip]
+static _FIELD_587: _RelativeField = _RelativeField { relatives: &[(0, "nú")], future: ["", "um {0} sekund", "", "", "", "um {0} sekund"], past: ["", "{0} sekund síðan", "", "", "", "{0} sekund síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_588: _RelativeField = _RelativeField { relatives: &[(0, "nú")], future: ["", "um {0} sek.", "", "", "", "um {0} sek."], past: ["", "{0} sek. síðan", "", "", "", "{0} sek. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_589: _RelativeField = _RelativeField { relatives: &[(0, "nú")], future: ["", "um {0} s.", "", "", "", "um {0} s."], past: ["", "{0} s. síðan", "", "", "", "{0} s. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_590: _RelativeField = _RelativeField { relatives: &[(0, "hendan minuttin")], future: ["", "um {0} minutt", "", "", "", "um {0} minuttir"], past: ["", "{0} minutt síðan", "", "", "", "{0} minuttir síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_591: _RelativeField = _RelativeField { relatives: &[(0, "hendan minuttin")], future: ["", "um {0} min.", "", "", "", "um {0} min."], past: ["", "{0} min. síðan", "", "", "", "{0} min. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_592: _RelativeField = _RelativeField { relatives: &[(0, "hendan minuttin")], future: ["", "um {0} m.", "", "", "", "um {0} m."], past: ["", "{0} m. síðan", "", "", "", "{0} m. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_593: _RelativeField = _RelativeField { relatives: &[(0, "hendan tíman")], future: ["", "um {0} tíma", "", "", "", "um {0} tímar"], past: ["", "{0} tími síðan", "", "", "", "{0} tímar síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_594: _RelativeField = _RelativeField { relatives: &[(0, "hendan tíman")], future: ["", "um {0} t.", "", "", "", "um {0} t."], past: ["", "{0} t. síðan", "", "", "", "{0} t. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_595: _RelativeField = _RelativeField { relatives: &[(-2, "fyrradagin"), (-1, "í gjár"), (0, "í dag"), (1, "í morgin"), (2, "í ovurmorgin")], future: ["", "um {0} dag", "", "", "", "um {0} dagar"], past: ["", "{0} dagur síðan", "", "", "", "{0} dagar síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_596: _RelativeField = _RelativeField { relatives: &[(-2, "fyrradagin"), (-1, "í gjár"), (0, "í dag"), (1, "í morgin"), (2, "í ovurmorgin")], future: ["", "um {0} da.", "", "", "", "um {0} da."], past: ["", "{0} da. síðan", "", "", "", "{0} da. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_597: _RelativeField = _RelativeField { relatives: &[(-2, "fyrradagin"), (-1, "í gjár"), (0, "í dag"), (1, "í morgin"), (2, "í ovurmorgin")], future: ["", "um {0} d.", "", "", "", "um {0} d."], past: ["", "{0} d. síðan", "", "", "", "{0} d. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_598: _RelativeField = _RelativeField { relatives: &[(-1, "seinastu viku"), (0, "hesu viku"), (1, "næstu viku")], future: ["", "um {0} viku", "", "", "", "um {0} vikur"], past: ["", "{0} vika síðan", "", "", "", "{0} vikur síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_599: _RelativeField = _RelativeField { relatives: &[(-1, "seinastu vi."), (0, "hesu vi."), (1, "næstu vi.")], future: ["", "um {0} vi.", "", "", "", "um {0} vi."], past: ["", "{0} vi. síðan", "", "", "", "{0} vi. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_600: _RelativeField = _RelativeField { relatives: &[(-1, "seinastu v."), (0, "hesu v."), (1, "næstu v.")], future: ["", "um {0} v.", "", "", "", "um {0} v."], past: ["", "{0} v. síðan", "", "", "", "{0} v. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_601: _RelativeField = _RelativeField { relatives: &[(-1, "seinasta mánað"), (0, "henda mánaðin"), (1, "næsta mánað")], future: ["", "um {0} mánað", "", "", "", "um {0} mánaðir"], past: ["", "{0} mánað síðan", "", "", "", "{0} mánaðir síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_602: _RelativeField = _RelativeField { relatives: &[(-1, "seinasta mnð."), (0, "henda mnð."), (1, "næsta mnð.")], future: ["", "um {0} mnð.", "", "", "", "um {0} mnð."], past: ["", "{0} mnð. síðan", "", "", "", "{0} mnð. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_603: _RelativeField = _RelativeField { relatives: &[(-1, "seinasta ársfjórðing"), (0, "hendan ársfjórðingin"), (1, "næsta ársfjórðing")], future: ["", "um {0} ársfjórðing", "", "", "", "um {0} ársfjórðingar"], past: ["", "{0} ársfjórðing síðan", "", "", "", "{0} ársfjórðingar síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_604: _RelativeField = _RelativeField { relatives: &[(-1, "seinasta ársfjórðing"), (0, "hendan ársfjórðingin"), (1, "næsta ársfjórðing")], future: ["", "um {0} ársfj.", "", "", "", "um {0} ársfj."], past: ["", "{0} ársfj. síðan", "", "", "", "{0} ársfj. síðan"] };
+
+#[rustfmt::skip]
+static _FIELD_605: _RelativeField = _RelativeField { relatives: &[(-1, "í fjør"), (0, "í ár"), (1, "næsta ár")], future: ["", "um {0} ár", "", "", "", "um {0} ár"], past: ["", "{0} ár síðan", "", "", "", "{0} ár síðan"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_51: [&_RelativeField; 24] = [
+    &_FIELD_587,
+    &_FIELD_588,
+    &_FIELD_589,
+    &_FIELD_590,
+    &_FIELD_591,
+    &_FIELD_592,
+    &_FIELD_593,
+    &_FIELD_594,
+    &_FIELD_594,
+    &_FIELD_595,
+    &_FIELD_596,
+    &_FIELD_597,
+    &_FIELD_598,
+    &_FIELD_599,
+    &_FIELD_600,
+    &_FIELD_601,
+    &_FIELD_602,
+    &_FIELD_602,
+    &_FIELD_603,
+    &_FIELD_604,
+    &_FIELD_604,
+    &_FIELD_605,
+    &_FIELD_605,
+    &_FIELD_605
+];
+
+#[rustfmt::skip]
+static _FIELD_606: _RelativeField = _RelativeField { relatives: &[(0, "maintenant")], future: ["", "dans {0} seconde", "", "", "", "dans {0} secondes"], past: ["", "il y a {0} seconde", "", "", "", "il y a {0} secondes"] };
+
+#[rustfmt::skip]
+static _FIELD_607: _RelativeField = _RelativeField { relatives: &[(0, "maintenant")], future: ["", "dans {0}\u{a0}s", "", "", "", "dans {0}\u{a0}s"], past: ["", "il y a {0}\u{a0}s", "", "", "", "il y a {0}\u{a0}s"] };
+
+#[rustfmt::skip]
+static _FIELD_608: _RelativeField = _RelativeField { relatives: &[(0, "maintenant")], future: ["", "+{0} s", "", "", "", "+{0} s"], past: ["", "-{0} s", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_609: _RelativeField = _RelativeField { relatives: &[(0, "cette minute-ci")], future: ["", "dans {0} minute", "", "", "", "dans {0} minutes"], past: ["", "il y a {0} minute", "", "", "", "il y a {0} minutes"] };
+
+#[rustfmt::skip]
+static _FIELD_610: _RelativeField = _RelativeField { relatives: &[(0, "cette minute-ci")], future: ["", "dans {0}\u{a0}min", "", "", "", "dans {0}\u{a0}min"], past: ["", "il y a {0}\u{a0}min", "", "", "", "il y a {0}\u{a0}min"] };
+
+#[rustfmt::skip]
+static _FIELD_611: _RelativeField = _RelativeField { relatives: &[(0, "cette minute-ci")], future: ["", "+{0} min", "", "", "", "+{0} min"], past: ["", "-{0} min", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_612: _RelativeField = _RelativeField { relatives: &[(0, "cette heure-ci")], future: ["", "dans {0} heure", "", "", "", "dans {0} heures"], past: ["", "il y a {0} heure", "", "", "", "il y a {0} heures"] };
+
+#[rustfmt::skip]
+static _FIELD_613: _RelativeField = _RelativeField { relatives: &[(0, "cette heure-ci")], future: ["", "dans {0}\u{a0}h", "", "", "", "dans {0}\u{a0}h"], past: ["", "il y a {0}\u{a0}h", "", "", "", "il y a {0}\u{a0}h"] };
+
+#[rustfmt::skip]
+static _FIELD_614: _RelativeField = _RelativeField { relatives: &[(0, "cette heure-ci")], future: ["", "+{0} h", "", "", "", "+{0} h"], past: ["", "-{0} h", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_615: _RelativeField = _RelativeField { relatives: &[(-2, "avant-hier"), (-1, "hier"), (0, "aujourd’hui"), (1, "demain"), (2, "après-demain")], future: ["", "dans {0} jour", "", "", "", "dans {0} jours"], past: ["", "il y a {0} jour", "", "", "", "il y a {0} jours"] };
+
+#[rustfmt::skip]
+static _FIELD_616: _RelativeField = _RelativeField { relatives: &[(-2, "avant-hier"), (-1, "hier"), (0, "aujourd’hui"), (1, "demain"), (2, "après-demain")], future: ["", "dans {0}\u{a0}j", "", "", "", "dans {0}\u{a0}j"], past: ["", "il y a {0}\u{a0}j", "", "", "", "il y a {0}\u{a0}j"] };
+
+#[rustfmt::skip]
+static _FIELD_617: _RelativeField = _RelativeField { relatives: &[(-2, "avant-hier"), (-1, "hier"), (0, "aujourd’hui"), (1, "demain"), (2, "après-demain")], future: ["", "+{0} j", "", "", "", "+{0} j"], past: ["", "-{0} j", "", "", "", "-{0} j"] };
+
+#[rustfmt::skip]
+static _FIELD_618: _RelativeField = _RelativeField { relatives: &[(-1, "la semaine dernière"), (0, "cette semaine"), (1, "la semaine prochaine")], future: ["", "dans {0} semaine", "", "", "", "dans {0} semaines"], past: ["", "il y a {0} semaine", "", "", "", "il y a {0} semaines"] };
+
+#[rustfmt::skip]
+static _FIELD_619: _RelativeField = _RelativeField { relatives: &[(-1, "la semaine dernière"), (0, "cette semaine"), (1, "la semaine prochaine")], future: ["", "dans {0} sem.", "", "", "", "dans {0} sem."], past: ["", "il y a {0} sem.", "", "", "", "il y a {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_620: _RelativeField = _RelativeField { relatives: &[(-1, "la semaine dernière"), (0, "cette semaine"), (1, "la semaine prochaine")], future: ["", "+{0} sem.", "", "", "", "+{0} sem."], past: ["", "-{0} sem.", "", "", "", "-{0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_621: _RelativeField = _RelativeField { relatives: &[(-1, "le mois dernier"), (0, "ce mois-ci"), (1, "le mois prochain")], future: ["", "dans {0} mois", "", "", "", "dans {0} mois"], past: ["", "il y a {0} mois", "", "", "", "il y a {0} mois"] };
+
+#[rustfmt::skip]
+static _FIELD_622: _RelativeField = _RelativeField { relatives: &[(-1, "le mois dernier"), (0, "ce mois-ci"), (1, "le mois prochain")], future: ["", "dans {0} m.", "", "", "", "dans {0} m."], past: ["", "il y a {0} m.", "", "", "", "il y a {0} m."] };
+
+#[rustfmt::skip]
+static _FIELD_623: _RelativeField = _RelativeField { relatives: &[(-1, "le mois dernier"), (0, "ce mois-ci"), (1, "le mois prochain")], future: ["", "+{0} m.", "", "", "", "+{0} m."], past: ["", "-{0} m.", "", "", "", "-{0} m."] };
+
+#[rustfmt::skip]
+static _FIELD_624: _RelativeField = _RelativeField { relatives: &[(-1, "le trimestre dernier"), (0, "ce trimestre"), (1, "le trimestre prochain")], future: ["", "dans {0} trimestre", "", "", "", "dans {0} trimestres"], past: ["", "il y a {0} trimestre", "", "", "", "il y a {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_625: _RelativeField = _RelativeField { relatives: &[(-1, "le trimestre dernier"), (0, "ce trimestre"), (1, "le trimestre prochain")], future: ["", "dans {0} trim.", "", "", "", "dans {0} trim."], past: ["", "il y a {0} trim.", "", "", "", "il y a {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_626: _RelativeField = _RelativeField { relatives: &[(-1, "le trimestre dernier"), (0, "ce trimestre"), (1, "le trimestre prochain")], future: ["", "+{0} trim.", "", "", "", "+{0} trim."], past: ["", "-{0} trim.", "", "", "", "-{0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_627: _RelativeField = _RelativeField { relatives: &[(-1, "l’année dernière"), (0, "cette année"), (1, "l’année prochaine")], future: ["", "dans {0} an", "", "", "", "dans {0} ans"], past: ["", "il y a {0} an", "", "", "", "il y a {0} ans"] };
+
+#[rustfmt::skip]
+static _FIELD_628: _RelativeField = _RelativeField { relatives: &[(-1, "l’année dernière"), (0, "cette année"), (1, "l’année prochaine")], future: ["", "dans {0} a", "", "", "", "dans {0} a"], past: ["", "il y a {0} a", "", "", "", "il y a {0} a"] };
+
+#[rustfmt::skip]
+static _FIELD_629: _RelativeField = _RelativeField { relatives: &[(-1, "l’année dernière"), (0, "cette année"), (1, "l’année prochaine")], future: ["", "+{0} a", "", "", "", "+{0} a"], past: ["", "-{0} a", "", "", "", "-{0} a"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_52: [&_RelativeField; 24] = [
+    &_FIELD_606,
+    &_FIELD_607,
+    &_FIELD_608,
+    &_FIELD_609,
+    &_FIELD_610,
+    &_FIELD_611,
+    &_FIELD_612,
+    &_FIELD_613,
+    &_FIELD_614,
+    &_FIELD_615,
+    &_FIELD_616,
+    &_FIELD_617,
+    &_FIELD_618,
+    &_FIELD_619,
+    &_FIELD_620,
+    &_FIELD_621,
+    &_FIELD_622,
+    &_FIELD_623,
+    &_FIELD_624,
+    &_FIELD_625,
+    &_FIELD_626,
+    &_FIELD_627,
+    &_FIELD_628,
+    &_FIELD_629
+];
+
+#[rustfmt::skip]
+static _FIELD_630: _RelativeField = _RelativeField { relatives: &[(0, "maintenant")], future: ["", "+ {0} s", "", "", "", "+{0} s"], past: ["", "-{0} s", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_631: _RelativeField = _RelativeField { relatives: &[(-1, "le trimestre dernier"), (0, "ce trimestre-ci"), (1, "le trimestre prochain")], future: ["", "dans {0} trimestre", "", "", "", "dans {0} trimestres"], past: ["", "il y a {0} trimestre", "", "", "", "il y a {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_632: _RelativeField = _RelativeField { relatives: &[(-1, "trim. dernier"), (0, "ce trim."), (1, "trim. prochain")], future: ["", "dans {0} trim.", "", "", "", "dans {0} trim."], past: ["", "il y a {0} trim.", "", "", "", "il y a {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_633: _RelativeField = _RelativeField { relatives: &[(-1, "trim. dernier"), (0, "ce trim."), (1, "trim.prochain")], future: ["", "+{0} trim.", "", "", "", "+{0} trim."], past: ["", "-{0} trim.", "", "", "", "-{0} trim."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_53: [&_RelativeField; 24] = [
+    &_FIELD_606,
+    &_FIELD_607,
+    &_FIELD_630,
+    &_FIELD_609,
+    &_FIELD_610,
+    &_FIELD_611,
+    &_FIELD_612,
+    &_FIELD_613,
+    &_FIELD_614,
+    &_FIELD_615,
+    &_FIELD_616,
+    &_FIELD_617,
+    &_FIELD_618,
+    &_FIELD_619,
+    &_FIELD_620,
+    &_FIELD_621,
+    &_FIELD_622,
+    &_FIELD_623,
+    &_FIELD_631,
+    &_FIELD_632,
+    &_FIELD_633,
+    &_FIELD_627,
+    &_FIELD_628,
+    &_FIELD_629
+];
+
+#[rustfmt::skip]
+static _FIELD_634: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "Oer {0} sekonde", "", "", "", "Oer {0} sekonden"], past: ["", "{0} sekonde lyn", "", "", "", "{0} sekonden lyn"] };
+
+#[rustfmt::skip]
+static _FIELD_635: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "Oer {0} minút", "", "", "", "Oer {0} minuten"], past: ["", "{0} minút lyn", "", "", "", "{0} minuten lyn"] };
+
+#[rustfmt::skip]
+static _FIELD_636: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "Oer {0} oere", "", "", "", "Oer {0} oere"], past: ["", "{0} oere lyn", "", "", "", "{0} oere lyn"] };
+
+#[rustfmt::skip]
+static _FIELD_637: _RelativeField = _RelativeField { relatives: &[(-2, "eergisteren"), (-1, "gisteren"), (0, "vandaag"), (1, "morgen"), (2, "Oermorgen")], future: ["", "Oer {0} dei", "", "", "", "Oer {0} deien"], past: ["", "{0} dei lyn", "", "", "", "{0} deien lyn"] };
+
+#[rustfmt::skip]
+static _FIELD_638: _RelativeField = _RelativeField { relatives: &[(-1, "foarige wike"), (0, "dizze wike"), (1, "folgjende wike")], future: ["", "Oer {0} wike", "", "", "", "Oer {0} wiken"], past: ["", "{0} wike lyn", "", "", "", "{0} wiken lyn"] };
+
+#[rustfmt::skip]
+static _FIELD_639: _RelativeField = _RelativeField { relatives: &[(-1, "foarige moanne"), (0, "dizze moanne"), (1, "folgjende moanne")], future: ["", "Oer {0} moanne", "", "", "", "Oer {0} moannen"], past: ["", "{0} moanne lyn", "", "", "", "{0} moannen lyn"] };
+
+#[rustfmt::skip]
+static _FIELD_640: _RelativeField = _RelativeField { relatives: &[(-1, "foarich jier"), (0, "dit jier"), (1, "folgjend jier")], future: ["", "Oer {0} jier", "", "", "", "Oer {0} jier"], past: ["", "{0} jier lyn", "", "", "", "{0} jier lyn"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_54: [&_RelativeField; 24] = [
+    &_FIELD_634,
+    &_FIELD_634,
+    &_FIELD_634,
+    &_FIELD_635,
+    &_FIELD_635,
+    &_FIELD_635,
+    &_FIELD_636,
+    &_FIELD_636,
+    &_FIELD_636,
+    &_FIELD_637,
+    &_FIELD_637,
+    &_FIELD_637,
+    &_FIELD_638,
+    &_FIELD_638,
+    &_FIELD_638,
+    &_FIELD_639,
+    &_FIELD_639,
+    &_FIELD_639,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_640,
+    &_FIELD_640,
+    &_FIELD_640
+];
+
+#[rustfmt::skip]
+static _FIELD_641: _RelativeField = _RelativeField { relatives: &[(0, "anois")], future: ["", "i gceann {0} soicind", "i gceann {0} shoicind", "i gceann {0} shoicind", "i gceann {0} soicind", "i gceann {0} soicind"], past: ["", "{0} soicind ó shin", "{0} shoicind ó shin", "{0} shoicind ó shin", "{0} soicind ó shin", "{0} soicind ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_642: _RelativeField = _RelativeField { relatives: &[(0, "anois")], future: ["", "i gceann {0} soic.", "i gceann {0} shoic.", "i gceann {0} shoic.", "i gceann {0} soic.", "i gceann {0} soic."], past: ["", "{0} soic. ó shin", "{0} shoic. ó shin", "{0} shoic. ó shin", "{0} soic. ó shin", "{0} soic. ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_643: _RelativeField = _RelativeField { relatives: &[(0, "anois")], future: ["", "+{0} s", "+{0} s", "+{0} s", "+{0} s", "+{0} s"], past: ["", "-{0} s", "-{0} s", "-{0} s", "-{0} s", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_644: _RelativeField = _RelativeField { relatives: &[(0, "an nóiméad seo")], future: ["", "i gceann {0} nóiméad", "i gceann {0} nóiméad", "i gceann {0} nóiméad", "i gceann {0} nóiméad", "i gceann {0} nóiméad"], past: ["", "{0} nóiméad ó shin", "{0} nóiméad ó shin", "{0} nóiméad ó shin", "{0} nóiméad ó shin", "{0} nóiméad ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_645: _RelativeField = _RelativeField { relatives: &[(0, "an nóiméad seo")], future: ["", "i gceann {0} nóim.", "i gceann {0} nóim.", "i gceann {0} nóim.", "i gceann {0} nóim.", "i gceann {0} nóim."], past: ["", "{0} nóim. ó shin", "{0} nóim. ó shin", "{0} nóim. ó shin", "{0} nóim. ó shin", "{0} nóim. ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_646: _RelativeField = _RelativeField { relatives: &[(0, "an nóiméad seo")], future: ["", "+{0} n", "+{0} n", "+{0} n", "+{0} n", "+{0} n"], past: ["", "-{0} n", "-{0} n", "-{0} n", "-{0} n", "-{0} n"] };
+
+#[rustfmt::skip]
+static _FIELD_647: _RelativeField = _RelativeField { relatives: &[(0, "an uair seo")], future: ["", "i gceann {0} uair an chloig", "i gceann {0} uair an chloig", "i gceann {0} uair an chloig", "i gceann {0} n-uair an chloig", "i gceann {0} uair an chloig"], past: ["", "{0} uair an chloig ó shin", "{0} uair an chloig ó shin", "{0} uair an chloig ó shin", "{0} n-uair an chloig ó shin", "{0} uair an chloig ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_648: _RelativeField = _RelativeField { relatives: &[(0, "an uair seo")], future: ["", "i gceann {0} uair", "i gceann {0} uair", "i gceann {0} huaire", "i gceann {0} n-uaire", "i gceann {0} uair"], past: ["", "{0} uair ó shin", "{0} uair ó shin", "{0} huaire ó shin", "{0} n-uaire ó shin", "{0} uair ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_649: _RelativeField = _RelativeField { relatives: &[(0, "an uair seo")], future: ["", "+{0} u", "+{0} u", "+{0} u", "+{0} u", "+{0} u"], past: ["", "-{0} u", "-{0} u", "-{0} u", "-{0} u", "-{0} u"] };
+
+#[rustfmt::skip]
+static _FIELD_650: _RelativeField = _RelativeField { relatives: &[(-2, "arú inné"), (-1, "inné"), (0, "inniu"), (1, "amárach"), (2, "arú amárach")], future: ["", "i gceann {0} lá", "i gceann {0} lá", "i gceann {0} lá", "i gceann {0} lá", "i gceann {0} lá"], past: ["", "{0} lá ó shin", "{0} lá ó shin", "{0} lá ó shin", "{0} lá ó shin", "{0} lá ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_651: _RelativeField = _RelativeField { relatives: &[(-2, "arú inné"), (-1, "inné"), (0, "inniu"), (1, "amárach"), (2, "arú amárach")], future: ["", "+{0} lá", "+{0} lá", "+{0} lá", "+{0} lá", "+{0} lá"], past: ["", "-{0} lá", "-{0} lá", "-{0} lá", "-{0} lá", "-{0} lá"] };
+
+#[rustfmt::skip]
+static _FIELD_652: _RelativeField = _RelativeField { relatives: &[(-1, "an tseachtain seo caite"), (0, "an tseachtain seo"), (1, "an tseachtain seo chugainn")], future: ["", "i gceann {0} seachtaine", "i gceann {0} sheachtain", "i gceann {0} seachtaine", "i gceann {0} seachtaine", "i gceann {0} seachtain"], past: ["", "{0} seachtain ó shin", "{0} sheachtain ó shin", "{0} seachtaine ó shin", "{0} seachtaine ó shin", "{0} seachtain ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_653: _RelativeField = _RelativeField { relatives: &[(-1, "an tscht. seo caite"), (0, "an tscht. seo"), (1, "an tscht. seo chugainn")], future: ["", "i gceann {0} scht.", "i gceann {0} shcht.", "i gceann {0} shcht.", "i gceann {0} scht.", "i gceann {0} scht."], past: ["", "{0} scht. ó shin", "{0} shcht. ó shin", "{0} shcht. ó shin", "{0} scht. ó shin", "{0} scht. ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_654: _RelativeField = _RelativeField { relatives: &[(-1, "an tscht. seo caite"), (0, "an tscht. seo"), (1, "an tscht. seo chugainn")], future: ["", "+{0} scht.", "i gceann {0} shcht.", "i gceann {0} shcht.", "+{0} scht.", "+{0} scht."], past: ["", "-{0} scht.", "{0} shcht. ó shin", "{0} shcht. ó shin", "-{0} scht.", "-{0} scht."] };
+
+#[rustfmt::skip]
+static _FIELD_655: _RelativeField = _RelativeField { relatives: &[(-1, "an mhí seo caite"), (0, "an mhí seo"), (1, "an mhí seo chugainn")], future: ["", "i gceann {0} mhí", "i gceann {0} mhí", "i gceann {0} mhí", "i gceann {0} mí", "i gceann {0} mí"], past: ["", "{0} mhí ó shin", "{0} mhí ó shin", "{0} mhí ó shin", "{0} mí ó shin", "{0} mí ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_656: _RelativeField = _RelativeField { relatives: &[(-1, "an mhí seo caite"), (0, "an mhí seo"), (1, "an mhí seo chugainn")], future: ["", "i gceann {0} míosa", "i gceann {0} mhí", "i gceann {0} mhí", "i gceann {0} mí", "i gceann {0} mí"], past: ["", "{0} mhí ó shin", "{0} mhí ó shin", "{0} mhí ó shin", "{0} mí ó shin", "{0} mí ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_657: _RelativeField = _RelativeField { relatives: &[(-1, "an mhí seo caite"), (0, "an mhí seo"), (1, "an mhí seo chugainn")], future: ["", "+{0} mhí", "+{0} mhí", "+{0} mhí", "+{0} mí", "+{0} mí"], past: ["", "-{0} mhí", "-{0} mhí", "-{0} mhí", "-{0} mí", "-{0} mí"] };
+
+#[rustfmt::skip]
+static _FIELD_658: _RelativeField = _RelativeField { relatives: &[(-1, "an ráithe seo caite"), (0, "an ráithe seo"), (1, "an ráithe seo chugainn")], future: ["", "i gceann {0} ráithe", "i gceann {0} ráithe", "i gceann {0} ráithe", "i gceann {0} ráithe", "i gceann {0} ráithe"], past: ["", "{0} ráithe ó shin", "{0} ráithe ó shin", "{0} ráithe ó shin", "{0} ráithe ó shin", "{0} ráithe ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_659: _RelativeField = _RelativeField { relatives: &[(-1, "an ráithe seo caite"), (0, "an ráithe seo"), (1, "an ráithe seo chugainn")], future: ["", "+{0} R", "+{0} R", "+{0} R", "+{0} R", "+{0} R"], past: ["", "-{0} R", "-{0} R", "-{0} R", "-{0} R", "-{0} R"] };
+
+#[rustfmt::skip]
+static _FIELD_660: _RelativeField = _RelativeField { relatives: &[(-1, "anuraidh"), (0, "i mbliana"), (1, "an bhliain seo chugainn")], future: ["", "i gceann {0} bliana", "i gceann {0} bhliain", "i gceann {0} bliana", "i gceann {0} mbliana", "i gceann {0} bliain"], past: ["", "{0} bhliain ó shin", "{0} bhliain ó shin", "{0} bliana ó shin", "{0} mbliana ó shin", "{0} bliain ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_661: _RelativeField = _RelativeField { relatives: &[(-1, "anuraidh"), (0, "i mbl."), (1, "an bhl. seo chugainn")], future: ["", "i gceann {0} bl.", "i gceann {0} bhl.", "i gceann {0} bl.", "i gceann {0} mbl.", "i gceann {0} bl."], past: ["", "{0} bhl. ó shin", "{0} bhl. ó shin", "{0} bl. ó shin", "{0} mbl. ó shin", "{0} bl. ó shin"] };
+
+#[rustfmt::skip]
+static _FIELD_662: _RelativeField = _RelativeField { relatives: &[(-1, "anuraidh"), (0, "i mbl."), (1, "an bhl. seo chugainn")], future: ["", "+{0} bhl.", "+{0} bhl.", "+{0} bl.", "+{0} mbl.", "+{0} bl."], past: ["", "-{0} bhl.", "-{0} bhl.", "-{0} bl.", "-{0} mbl.", "-{0} bl."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_55: [&_RelativeField; 24] = [
+    &_FIELD_641,
+    &_FIELD_642,
+    &_FIELD_643,
+    &_FIELD_644,
+    &_FIELD_645,
+    &_FIELD_646,
+    &_FIELD_647,
+    &_FIELD_648,
+    &_FIELD_649,
+    &_FIELD_650,
+    &_FIELD_650,
+    &_FIELD_651,
+    &_FIELD_652,
+    &_FIELD_653,
+    &_FIELD_654,
+    &_FIELD_655,
+    &_FIELD_656,
+    &_FIELD_657,
+    &_FIELD_658,
+    &_FIELD_658,
+    &_FIELD_659,
+    &_FIELD_660,
+    &_FIELD_661,
+    &_FIELD_662
+];
+
+#[rustfmt::skip]
+static _FIELD_663: _RelativeField = _RelativeField { relatives: &[(0, "an-dràsta")], future: ["", "an ceann {0} diog", "an ceann {0} dhiog", "an ceann {0} diogan", "", "an ceann {0} diog"], past: ["", "{0} diog air ais", "{0} dhiog air ais", "{0} diogan air ais", "", "{0} diog air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_664: _RelativeField = _RelativeField { relatives: &[(0, "an-dràsta")], future: ["", "an {0} diog", "an {0} dhiog", "an {0} diog.", "", "an {0} diog"], past: ["", "o {0} diog", "o {0} dhiog", "o {0} diog.", "", "o {0} diog"] };
+
+#[rustfmt::skip]
+static _FIELD_665: _RelativeField = _RelativeField { relatives: &[(0, "an-dràsta")], future: ["", "+{0} d", "+{0} d", "+{0} d", "", "+{0} d"], past: ["", "-{0} d", "-{0} d", "-{0} d", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_666: _RelativeField = _RelativeField { relatives: &[(0, "am broinn mionaid")], future: ["", "an ceann {0} mhionaid", "an ceann {0} mhionaid", "an ceann {0} mionaidean", "", "an ceann {0} mionaid"], past: ["", "{0} mhionaid air ais", "{0} mhionaid air ais", "{0} mionaidean air ais", "", "{0} mionaid air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_667: _RelativeField = _RelativeField { relatives: &[(0, "am broinn mion.")], future: ["", "an {0} mhion.", "an {0} mhion.", "an {0} mion.", "", "an {0} mion."], past: ["", "o {0} mhion.", "o {0} mhion.", "o {0} mion.", "", "o {0} mion."] };
+
+#[rustfmt::skip]
+static _FIELD_668: _RelativeField = _RelativeField { relatives: &[(0, "sa mhion.")], future: ["", "+{0} m", "+{0} m", "+{0} m", "", "+{0} m"], past: ["", "-{0} m", "-{0} m", "-{0} m", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_669: _RelativeField = _RelativeField { relatives: &[(0, "am broinn uair a thìde")], future: ["", "an ceann {0} uair a thìde", "an ceann {0} uair a thìde", "an ceann {0} uairean a thìde", "", "an ceann {0} uair a thìde"], past: ["", "{0} uair a thìde air ais", "{0} uair a thìde air ais", "{0} uairean a thìde air ais", "", "{0} uair a thìde air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_670: _RelativeField = _RelativeField { relatives: &[(0, "am broinn uair")], future: ["", "an {0} uair", "an {0} uair", "an {0} uair.", "", "an {0} uair"], past: ["", "o {0} uair", "o {0} uair", "o {0} uair.", "", "o {0} uair"] };
+
+#[rustfmt::skip]
+static _FIELD_671: _RelativeField = _RelativeField { relatives: &[(0, "san uair")], future: ["", "+{0} u.", "+{0} u.", "+{0} u.", "", "+{0} u."], past: ["", "-{0} u.", "-{0} u.", "-{0} u.", "", "-{0} u."] };
+
+#[rustfmt::skip]
+static _FIELD_672: _RelativeField = _RelativeField { relatives: &[(-2, "a-bhòin-dè"), (-1, "an-dè"), (0, "an-diugh"), (1, "a-màireach"), (2, "an-earar"), (3, "an-eararais")], future: ["", "an ceann {0} latha", "an ceann {0} latha", "an ceann {0} làithean", "", "an ceann {0} latha"], past: ["", "{0} latha air ais", "{0} latha air ais", "{0} làithean air ais", "", "{0} latha air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_673: _RelativeField = _RelativeField { relatives: &[(-2, "a-bhòin-dè"), (-1, "an-dè"), (0, "an-diugh"), (1, "a-màireach"), (2, "an-earar"), (3, "an-eararais")], future: ["", "an {0} là", "an {0} là", "an {0} là.", "", "an {0} là"], past: ["", "o {0} là", "o {0} là", "o {0} là.", "", "o {0} là"] };
+
+#[rustfmt::skip]
+static _FIELD_674: _RelativeField = _RelativeField { relatives: &[(-2, "a-bhòin-dè"), (-1, "an-dè"), (0, "an-diugh"), (1, "a-màireach"), (2, "an-earar"), (3, "an-eararais")], future: ["", "+{0} là", "+{0} là", "+{0} là", "", "+{0} là"], past: ["", "-{0} là", "-{0} là", "-{0} là", "", "-{0} là"] };
+
+#[rustfmt::skip]
+static _FIELD_675: _RelativeField = _RelativeField { relatives: &[(-1, "an t-seachdain seo chaidh"), (0, "an t-seachdain seo"), (1, "an ath-sheachdain")], future: ["", "an ceann {0} seachdain", "an ceann {0} sheachdain", "an ceann {0} seachdainean", "", "an ceann {0} seachdain"], past: ["", "{0} seachdain air ais", "{0} sheachdain air ais", "{0} seachdainean air ais", "", "{0} seachdain air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_676: _RelativeField = _RelativeField { relatives: &[(-1, "seachd. sa chaidh"), (0, "an t-seachd. seo"), (1, "an ath-sheachd.")], future: ["", "an {0} sheachd.", "an {0} sheachd.", "an {0} seachd.", "", "an {0} seachd."], past: ["", "o {0} sheachd.", "o {0} sheachd.", "o {0} seachd.", "", "o {0} seachd."] };
+
+#[rustfmt::skip]
+static _FIELD_677: _RelativeField = _RelativeField { relatives: &[(-1, "sn. ch."), (0, "an t-sn. seo"), (1, "ath-shn.")], future: ["", "+{0} sn.", "+{0} sn.", "+{0} sn.", "", "+{0} sn."], past: ["", "-{0} sn.", "-{0} sn.", "-{0} sn.", "", "-{0} sn."] };
+
+#[rustfmt::skip]
+static _FIELD_678: _RelativeField = _RelativeField { relatives: &[(-1, "am mìos seo chaidh"), (0, "am mìos seo"), (1, "an ath-mhìos")], future: ["", "an ceann {0} mhìosa", "an ceann {0} mhìosa", "an ceann {0} mìosan", "", "an ceann {0} mìosa"], past: ["", "{0} mhìos air ais", "{0} mhìos air ais", "{0} mìosan air ais", "", "{0} mìos air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_679: _RelativeField = _RelativeField { relatives: &[(-1, "am mìos sa chaidh"), (0, "am mìos seo"), (1, "an ath-mhìos")], future: ["", "an {0} mhìos.", "an {0} mhìos.", "an {0} mìos.", "", "an {0} mìos."], past: ["", "o {0} mhìos.", "o {0} mhìos.", "o {0} mìos.", "", "o {0} mìos."] };
+
+#[rustfmt::skip]
+static _FIELD_680: _RelativeField = _RelativeField { relatives: &[(-1, "mì. ch."), (0, "am mì. seo"), (1, "ath-mhì.")], future: ["", "+{0} mhì.", "+{0} mhì.", "+{0} mì.", "", "+{0} mì."], past: ["", "-{0} mhì.", "-{0} mhì.", "-{0} mì.", "", "-{0} mì."] };
+
+#[rustfmt::skip]
+static _FIELD_681: _RelativeField = _RelativeField { relatives: &[(-1, "an cairteal seo chaidh"), (0, "an cairteal seo"), (1, "an ath-chairteal")], future: ["", "an ceann {0} chairteil", "an ceann {0} chairteil", "an ceann {0} cairtealan", "", "an ceann {0} cairteil"], past: ["", "{0} chairteal air ais", "{0} chairteal air ais", "{0} cairtealan air ais", "", "{0} cairteal air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_682: _RelativeField = _RelativeField { relatives: &[(-1, "an cairt. sa chaidh"), (0, "an cairt. seo"), (1, "an ath-chairt.")], future: ["", "an {0} chairt.", "an {0} chairt.", "an {0} cairt.", "", "an {0} cairt."], past: ["", "o {0} chairt.", "o {0} chairt.", "o {0} cairt.", "", "o {0} cairt."] };
+
+#[rustfmt::skip]
+static _FIELD_683: _RelativeField = _RelativeField { relatives: &[(-1, "c. ch."), (0, "an c. seo"), (1, "ath-ch.")], future: ["", "+{0} c.", "+{0} c.", "+{0} c.", "", "+{0} c."], past: ["", "-{0} c.", "-{0} c.", "-{0} c.", "", "-{0} c."] };
+
+#[rustfmt::skip]
+static _FIELD_684: _RelativeField = _RelativeField { relatives: &[(-2, "a-bhòn-uiridh"), (-1, "an-uiridh"), (0, "am bliadhna"), (1, "an ath-bhliadhna")], future: ["", "an ceann {0} bhliadhna", "an ceann {0} bhliadhna", "an ceann {0} bliadhnaichean", "", "an ceann {0} bliadhna"], past: ["", "{0} bhliadhna air ais", "{0} bhliadhna air ais", "{0} bhliadhnaichean air ais", "", "{0} bliadhna air ais"] };
+
+#[rustfmt::skip]
+static _FIELD_685: _RelativeField = _RelativeField { relatives: &[(-2, "a-bhòn-uiridh"), (-1, "an-uiridh"), (0, "am bliadhna"), (1, "an ath-bhliadhna")], future: ["", "an {0} bhlia.", "an {0} bhlia.", "an {0} blia.", "", "an {0} blia."], past: ["", "o {0} bhlia.", "o {0} bhlia.", "o {0} blia.", "", "o {0} blia."] };
+
+#[rustfmt::skip]
+static _FIELD_686: _RelativeField = _RelativeField { relatives: &[(-2, "a-bh-uir."), (-1, "an-uir."), (0, "am bl."), (1, "an ath-bhl.")], future: ["", "+{0} bhl.", "+{0} bhl.", "+{0} bl.", "", "+{0} bl."], past: ["", "-{0} bhl.", "-{0} bhl.", "-{0} bl.", "", "-{0} bl."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_56: [&_RelativeField; 24] = [
+    &_FIELD_663,
+    &_FIELD_664,
+    &_FIELD_665,
+    &_FIELD_666,
+    &_FIELD_667,
+    &_FIELD_668,
+    &_FIELD_669,
+    &_FIELD_670,
+    &_FIELD_671,
+    &_FIELD_672,
+    &_FIELD_673,
+    &_FIELD_674,
+    &_FIELD_675,
+    &_FIELD_676,
+    &_FIELD_677,
+    &_FIELD_678,
+    &_FIELD_679,
+    &_FIELD_680,
+    &_FIELD_681,
+    &_FIELD_682,
+    &_FIELD_683,
+    &_FIELD_684,
+    &_FIELD_685,
+    &_FIELD_686
+];
+
+#[rustfmt::skip]
+static _FIELD_687: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "en {0} segundo", "", "", "", "en {0} segundos"], past: ["", "hai {0} segundo", "", "", "", "hai {0} segundos"] };
+
+#[rustfmt::skip]
+static _FIELD_688: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "en {0} s", "", "", "", "en {0} s"], past: ["", "hai {0} s", "", "", "", "hai {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_689: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "en {0} minuto", "", "", "", "en {0} minutos"], past: ["", "hai {0} minuto", "", "", "", "hai {0} minutos"] };
+
+#[rustfmt::skip]
+static _FIELD_690: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "en {0} min", "", "", "", "en {0} min"], past: ["", "hai {0} min", "", "", "", "hai {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_691: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "en {0} hora", "", "", "", "en {0} horas"], past: ["", "hai {0} hora", "", "", "", "hai {0} horas"] };
+
+#[rustfmt::skip]
+static _FIELD_692: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "en {0} h", "", "", "", "en {0} h"], past: ["", "hai {0} h", "", "", "", "hai {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_693: _RelativeField = _RelativeField { relatives: &[(-2, "antonte"), (-1, "onte"), (0, "hoxe"), (1, "mañá"), (2, "pasadomañá")], future: ["", "en {0} día", "", "", "", "en {0} días"], past: ["", "hai {0} día", "", "", "", "hai {0} días"] };
+
+#[rustfmt::skip]
+static _FIELD_694: _RelativeField = _RelativeField { relatives: &[(-1, "a semana pasada"), (0, "esta semana"), (1, "a próxima semana")], future: ["", "en {0} semana", "", "", "", "en {0} semanas"], past: ["", "hai {0} semana", "", "", "", "hai {0} semanas"] };
+
+#[rustfmt::skip]
+static _FIELD_695: _RelativeField = _RelativeField { relatives: &[(-1, "a sem. pasada"), (0, "esta sem."), (1, "a próxima sem.")], future: ["", "en {0} sem.", "", "", "", "en {0} sem."], past: ["", "hai {0} sem.", "", "", "", "hai {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_696: _RelativeField = _RelativeField { relatives: &[(-1, "a sem. pas."), (0, "esta sem."), (1, "a próx. sem.")], future: ["", "en {0} sem.", "", "", "", "en {0} sem."], past: ["", "hai {0} sem.", "", "", "", "hai {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_697: _RelativeField = _RelativeField { relatives: &[(-1, "o mes pasado"), (0, "este mes"), (1, "o próximo mes")], future: ["", "en {0} mes", "", "", "", "en {0} meses"], past: ["", "hai {0} mes", "", "", "", "hai {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_698: _RelativeField = _RelativeField { relatives: &[(-1, "o mes pas."), (0, "este mes"), (1, "o próx. mes")], future: ["", "en {0} mes", "", "", "", "en {0} meses"], past: ["", "hai {0} mes", "", "", "", "hai {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_699: _RelativeField = _RelativeField { relatives: &[(-1, "o trimestre pasado"), (0, "este trimestre"), (1, "o próximo trimestre")], future: ["", "en {0} trimestre", "", "", "", "en {0} trimestres"], past: ["", "hai {0} trimestre", "", "", "", "hai {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_700: _RelativeField = _RelativeField { relatives: &[(-1, "trim. pasado"), (0, "este trim."), (1, "trim. seguinte")], future: ["", "en {0} trim.", "", "", "", "en {0} trim."], past: ["", "hai {0} trim.", "", "", "", "hai {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_701: _RelativeField = _RelativeField { relatives: &[(-1, "o ano pasado"), (0, "este ano"), (1, "o próximo ano")], future: ["", "en {0} ano", "", "", "", "en {0} anos"], past: ["", "hai {0} ano", "", "", "", "hai {0} anos"] };
+
+#[rustfmt::skip]
+static _FIELD_702: _RelativeField = _RelativeField { relatives: &[(-1, "o ano pas."), (0, "este ano"), (1, "o próx. ano")], future: ["", "en {0} ano", "", "", "", "en {0} anos"], past: ["", "hai {0} ano", "", "", "", "hai {0} anos"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_57: [&_RelativeField; 24] = [
+    &_FIELD_687,
+    &_FIELD_688,
+    &_FIELD_688,
+    &_FIELD_689,
+    &_FIELD_690,
+    &_FIELD_690,
+    &_FIELD_691,
+    &_FIELD_692,
+    &_FIELD_692,
+    &_FIELD_693,
+    &_FIELD_693,
+    &_FIELD_693,
+    &_FIELD_694,
+    &_FIELD_695,
+    &_FIELD_696,
+    &_FIELD_697,
+    &_FIELD_697,
+    &_FIELD_698,
+    &_FIELD_699,
+    &_FIELD_700,
+    &_FIELD_700,
+    &_FIELD_701,
+    &_FIELD_701,
+    &_FIELD_702
+];
+
+#[rustfmt::skip]
+static _FIELD_703: _RelativeField = _RelativeField { relatives: &[(0, "હમણા\u{a82}")], future: ["", "{0} સ\u{ac7}ક\u{a82}ડમા\u{a82}", "", "", "", "{0} સ\u{ac7}ક\u{a82}ડમા\u{a82}"], past: ["", "{0} સ\u{ac7}ક\u{a82}ડ પહ\u{ac7}લા\u{a82}", "", "", "", "{0} સ\u{ac7}ક\u{a82}ડ પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_704: _RelativeField = _RelativeField { relatives: &[(0, "આ મિનિટ")], future: ["", "{0} મિનિટમા\u{a82}", "", "", "", "{0} મિનિટમા\u{a82}"], past: ["", "{0} મિનિટ પહ\u{ac7}લા\u{a82}", "", "", "", "{0} મિનિટ પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_705: _RelativeField = _RelativeField { relatives: &[(0, "આ કલાક")], future: ["", "{0} કલાકમા\u{a82}", "", "", "", "{0} કલાકમા\u{a82}"], past: ["", "{0} કલાક પહ\u{ac7}લા\u{a82}", "", "", "", "{0} કલાક પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_706: _RelativeField = _RelativeField { relatives: &[(-2, "ગયા પરમદિવસ\u{ac7}"), (-1, "ગઈકાલ\u{ac7}"), (0, "આજ\u{ac7}"), (1, "આવતીકાલ\u{ac7}"), (2, "પરમદિવસ\u{ac7}")], future: ["", "{0} દિવસમા\u{a82}", "", "", "", "{0} દિવસમા\u{a82}"], past: ["", "{0} દિવસ પહ\u{ac7}લા\u{a82}", "", "", "", "{0} દિવસ પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_707: _RelativeField = _RelativeField { relatives: &[(-1, "ગયા અઠવાડિય\u{ac7}"), (0, "આ અઠવાડિય\u{ac7}"), (1, "આવતા અઠવાડિય\u{ac7}")], future: ["", "{0} અઠવાડિયામા\u{a82}", "", "", "", "{0} અઠવાડિયામા\u{a82}"], past: ["", "{0} અઠવાડિયા પહ\u{ac7}લા\u{a82}", "", "", "", "{0} અઠવાડિયા પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_708: _RelativeField = _RelativeField { relatives: &[(-1, "ગયા અઠવાડિય\u{ac7}"), (0, "આ અઠવાડિય\u{ac7}"), (1, "આવતા અઠવાડિય\u{ac7}")], future: ["", "{0} અઠ. મા\u{a82}", "", "", "", "{0} અઠ. મા\u{a82}"], past: ["", "{0} અઠ. પહ\u{ac7}લા\u{a82}", "", "", "", "{0} અઠ. પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_709: _RelativeField = _RelativeField { relatives: &[(-1, "ગયા મહિન\u{ac7}"), (0, "આ મહિન\u{ac7}"), (1, "આવતા મહિન\u{ac7}")], future: ["", "{0} મહિનામા\u{a82}", "", "", "", "{0} મહિનામા\u{a82}"], past: ["", "{0} મહિના પહ\u{ac7}લા\u{a82}", "", "", "", "{0} મહિના પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_710: _RelativeField = _RelativeField { relatives: &[(-1, "છ\u{ac7}લ\u{acd}લ\u{ac1}\u{a82} ત\u{acd}રિમાસિક"), (0, "આ ત\u{acd}રિમાસિક"), (1, "પછીન\u{ac1}\u{a82} ત\u{acd}રિમાસિક")], future: ["", "{0} ત\u{acd}રિમાસિકમા\u{a82}", "", "", "", "{0} ત\u{acd}રિમાસિકમા\u{a82}"], past: ["", "{0} ત\u{acd}રિમાસિક પહ\u{ac7}લા\u{a82}", "", "", "", "{0} ત\u{acd}રિમાસિક પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _FIELD_711: _RelativeField = _RelativeField { relatives: &[(-1, "ગયા વર\u{acd}ષ\u{ac7}"), (0, "આ વર\u{acd}ષ\u{ac7}"), (1, "આવતા વર\u{acd}ષ\u{ac7}")], future: ["", "{0} વર\u{acd}ષમા\u{a82}", "", "", "", "{0} વર\u{acd}ષમા\u{a82}"], past: ["", "{0} વર\u{acd}ષ પહ\u{ac7}લા\u{a82}", "", "", "", "{0} વર\u{acd}ષ પહ\u{ac7}લા\u{a82}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_58: [&_RelativeField; 24] = [
+    &_FIELD_703,
+    &_FIELD_703,
+    &_FIELD_703,
+    &_FIELD_704,
+    &_FIELD_704,
+    &_FIELD_704,
+    &_FIELD_705,
+    &_FIELD_705,
+    &_FIELD_705,
+    &_FIELD_706,
+    &_FIELD_706,
+    &_FIELD_706,
+    &_FIELD_707,
+    &_FIELD_708,
+    &_FIELD_708,
+    &_FIELD_709,
+    &_FIELD_709,
+    &_FIELD_709,
+    &_FIELD_710,
+    &_FIELD_710,
+    &_FIELD_710,
+    &_FIELD_711,
+    &_FIELD_711,
+    &_FIELD_711
+];
+
+#[rustfmt::skip]
+static _FIELD_712: _RelativeField = _RelativeField { relatives: &[(0, "yanzu")], future: ["", "cikin dakika {0}", "", "", "", "cikin dakiku {0}"], past: ["", "dakika {0} da ta gabata", "", "", "", "dakiku {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_713: _RelativeField = _RelativeField { relatives: &[(0, "wannan mintin")], future: ["", "cikin minti {0}", "", "", "", "cikin mintuna {0}"], past: ["", "minti {0} da ya gabata", "", "", "", "mintuna {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_714: _RelativeField = _RelativeField { relatives: &[(0, "wannan awa")], future: ["", "cikin awa {0}", "", "", "", "cikin awanni {0}"], past: ["", "awa {0} da ta gabata", "", "", "", "awanni {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_715: _RelativeField = _RelativeField { relatives: &[(-1, "jiya"), (0, "yau"), (1, "gobe")], future: ["", "a cikin kwanaki {0}", "", "", "", "a cikin kwanaki {0}"], past: ["", "kwana {0} da ya gabata", "", "", "", "kwanaki {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_716: _RelativeField = _RelativeField { relatives: &[(-1, "satin da ya gabata"), (0, "wannan satin"), (1, "sati na gaba")], future: ["", "a cikin mako {0}", "", "", "", "a cikin makonni {0}"], past: ["", "mako {0} da ya gabata", "", "", "", "makonni {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_717: _RelativeField = _RelativeField { relatives: &[(-1, "satin da ya gabata"), (0, "wannan satin"), (1, "sati na gaba")], future: ["", "a cikin mako {0}", "", "", "", "a cikin makonni {0}"], past: ["", "mako {0} da ya gabata", "", "", "", "mako {0} da ya gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_718: _RelativeField = _RelativeField { relatives: &[(-1, "watan da ya gabata"), (0, "wannan watan"), (1, "wata na gaba")], future: ["", "a cikin watan {0}", "", "", "", "a cikin watanni {0}"], past: ["", "wata {0} da ya gabata", "", "", "", "watanni {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_719: _RelativeField = _RelativeField { relatives: &[(-1, "watan da ya gabata"), (0, "wannan watan"), (1, "wata na gaba")], future: ["", "a cikin watan {0}", "", "", "", "a cikin watan {0}"], past: ["", "wata {0} da ya gabata", "", "", "", "watanni {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_720: _RelativeField = _RelativeField { relatives: &[(-1, "kwatan karshe"), (0, "wannan kwatan"), (1, "kwata na gaba")], future: ["", "a cikin kwata {0}", "", "", "", "a cikin kwatas {0}"], past: ["", "kwata {0} da suka gabata", "", "", "", "kwata {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _FIELD_721: _RelativeField = _RelativeField { relatives: &[(-1, "bara"), (0, "bana"), (1, "badi")], future: ["", "a shekarar {0}", "", "", "", "a shekaru {0}"], past: ["", "shekara {0} da ta gabata", "", "", "", "shekaru {0} da suka gabata"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_59: [&_RelativeField; 24] = [
+    &_FIELD_712,
+    &_FIELD_712,
+    &_FIELD_712,
+    &_FIELD_713,
+    &_FIELD_713,
+    &_FIELD_713,
+    &_FIELD_714,
+    &_FIELD_714,
+    &_FIELD_714,
+    &_FIELD_715,
+    &_FIELD_715,
+    &_FIELD_715,
+    &_FIELD_716,
+    &_FIELD_716,
+    &_FIELD_717,
+    &_FIELD_718,
+    &_FIELD_719,
+    &_FIELD_719,
+    &_FIELD_720,
+    &_FIELD_720,
+    &_FIELD_720,
+    &_FIELD_721,
+    &_FIELD_721,
+    &_FIELD_721
+];
+
+#[rustfmt::skip]
+static _FIELD_722: _RelativeField = _RelativeField { relatives: &[(0, "עכשיו")], future: ["", "בעוד שנייה", "בעוד שתי שניות", "", "", "בעוד {0} שניות"], past: ["", "לפני שנייה", "לפני שתי שניות", "", "", "לפני {0} שניות"] };
+
+#[rustfmt::skip]
+static _FIELD_723: _RelativeField = _RelativeField { relatives: &[(0, "עכשיו")], future: ["", "בעוד שנ׳", "בעוד שתי שנ׳", "", "בעוד {0} שנ׳", "בעוד {0} שנ׳"], past: ["", "לפני שנ׳", "לפני שתי שנ׳", "", "לפני {0} שנ׳", "לפני {0} שנ׳"] };
+
+#[rustfmt::skip]
+static _FIELD_724: _RelativeField = _RelativeField { relatives: &[(0, "בדקה זו")], future: ["", "בעוד דקה", "בעוד שתי דקות", "", "", "בעוד {0} דקות"], past: ["", "לפני דקה", "לפני שתי דקות", "", "", "לפני {0} דקות"] };
+
+#[rustfmt::skip]
+static _FIELD_725: _RelativeField = _RelativeField { relatives: &[(0, "דקה זו")], future: ["", "בעוד דקה", "בעוד שתי דק׳", "", "בעוד {0} דק׳", "בעוד {0} דק׳"], past: ["", "לפני דקה", "לפני {0} דק׳", "", "לפני {0} דק׳", "לפני {0} דק׳"] };
+
+#[rustfmt::skip]
+static _FIELD_726: _RelativeField = _RelativeField { relatives: &[(0, "דקה זו")], future: ["", "בעוד דקה", "בעוד שתי דק׳", "", "בעוד {0} דק׳", "בעוד {0} דק׳"], past: ["", "לפני דקה", "לפני שתי דק׳", "", "לפני {0} דק׳", "לפני {0} דק׳"] };
+
+#[rustfmt::skip]
+static _FIELD_727: _RelativeField = _RelativeField { relatives: &[(0, "בשעה זו")], future: ["", "בעוד שעה", "בעוד שעתיים", "", "", "בעוד {0} שעות"], past: ["", "לפני שעה", "לפני שעתיים", "", "", "לפני {0} שעות"] };
+
+#[rustfmt::skip]
+static _FIELD_728: _RelativeField = _RelativeField { relatives: &[(0, "בשעה זו")], future: ["", "בעוד שעה", "בעוד שעתיים", "", "בעוד {0} שע׳", "בעוד {0} שע׳"], past: ["", "לפני שעה", "לפני שעתיים", "", "לפני {0} שע׳", "לפני {0} שע׳"] };
+
+#[rustfmt::skip]
+static _FIELD_729: _RelativeField = _RelativeField { relatives: &[(-2, "שלשום"), (-1, "אתמול"), (0, "היום"), (1, "מחר"), (2, "מחרתיים")], future: ["", "בעוד יום {0}", "בעוד יומיים", "", "", "בעוד {0} ימים"], past: ["", "לפני יום {0}", "לפני יומיים", "", "", "לפני {0} ימים"] };
+
+#[rustfmt::skip]
+static _FIELD_730: _RelativeField = _RelativeField { relatives: &[(-2, "שלשום"), (-1, "אתמול"), (0, "היום"), (1, "מחר"), (2, "מחרתיים")], future: ["", "מחר", "בעוד יומיים", "", "", "בעוד {0} ימים"], past: ["", "אתמול", "לפני יומיים", "", "", "לפני {0} ימים"] };
+
+#[rustfmt::skip]
+static _FIELD_731: _RelativeField = _RelativeField { relatives: &[(-1, "השבוע שעבר"), (0, "השבוע"), (1, "השבוע הבא")], future: ["", "בעוד שבוע", "בעוד שבועיים", "", "", "בעוד {0} שבועות"], past: ["", "לפני שבוע", "לפני שבועיים", "", "", "לפני {0} שבועות"] };
+
+#[rustfmt::skip]
+static _FIELD_732: _RelativeField = _RelativeField { relatives: &[(-1, "השבוע שעבר"), (0, "השבוע"), (1, "השבוע הבא")], future: ["", "בעוד שב׳", "בעוד שבועיים", "", "בעוד {0} שב׳", "בעוד {0} שב׳"], past: ["", "לפני שב׳", "לפני שבועיים", "", "לפני {0} שב׳", "לפני {0} שב׳"] };
+
+#[rustfmt::skip]
+static _FIELD_733: _RelativeField = _RelativeField { relatives: &[(-1, "השבוע שעבר"), (0, "השבוע"), (1, "השבוע הבא")], future: ["", "בעוד שב׳", "בעוד שבועיים", "", "בעוד {0} שב׳", "בעוד {0} שב׳"], past: ["", "לפני שבוע", "לפני שבועיים", "", "לפני {0} שב׳", "לפני {0} שב׳"] };
+
+#[rustfmt::skip]
+static _FIELD_734: _RelativeField = _RelativeField { relatives: &[(-1, "החודש שעבר"), (0, "החודש"), (1, "החודש הבא")], future: ["", "בעוד חודש", "בעוד חודשיים", "", "", "בעוד {0} חודשים"], past: ["", "לפני חודש", "לפני חודשיים", "", "", "לפני {0} חודשים"] };
+
+#[rustfmt::skip]
+static _FIELD_735: _RelativeField = _RelativeField { relatives: &[(-1, "החודש שעבר"), (0, "החודש"), (1, "החודש הבא")], future: ["", "בעוד חו׳", "בעוד חודשיים", "", "בעוד {0} חו׳", "בעוד {0} חו׳"], past: ["", "לפני חו׳", "לפני חודשיים", "", "לפני {0} חו׳", "לפני {0} חו׳"] };
+
+#[rustfmt::skip]
+static _FIELD_736: _RelativeField = _RelativeField { relatives: &[(-1, "הרבעון הקודם"), (0, "רבעון זה"), (1, "הרבעון הבא")], future: ["", "ברבעון הבא", "בעוד שני רבעונים", "", "", "בעוד {0} רבעונים"], past: ["", "ברבעון הקודם", "לפני שני רבעונים", "", "", "לפני {0} רבעונים"] };
+
+#[rustfmt::skip]
+static _FIELD_737: _RelativeField = _RelativeField { relatives: &[(-1, "הרבעון הקודם"), (0, "רבעון זה"), (1, "הרבעון הבא")], future: ["", "ברבע׳ הבא", "בעוד שני רבע׳", "", "בעוד {0} רבע׳", "בעוד {0} רבע׳"], past: ["", "ברבע׳ הקודם", "לפני שני רבע׳", "", "לפני {0} רבע׳", "לפני {0} רבע׳"] };
+
+#[rustfmt::skip]
+static _FIELD_738: _RelativeField = _RelativeField { relatives: &[(-1, "השנה שעברה"), (0, "השנה"), (1, "השנה הבאה")], future: ["", "בעוד שנה", "בעוד שנתיים", "", "", "בעוד {0} שנים"], past: ["", "לפני שנה", "לפני שנתיים", "", "", "לפני {0} שנים"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_60: [&_RelativeField; 24] = [
+    &_FIELD_722,
+    &_FIELD_723,
+    &_FIELD_723,
+    &_FIELD_724,
+    &_FIELD_725,
+    &_FIELD_726,
+    &_FIELD_727,
+    &_FIELD_728,
+    &_FIELD_728,
+    &_FIELD_729,
+    &_FIELD_730,
+    &_FIELD_730,
+    &_FIELD_731,
+    &_FIELD_732,
+    &_FIELD_733,
+    &_FIELD_734,
+    &_FIELD_734,
+    &_FIELD_735,
+    &_FIELD_736,
+    &_FIELD_737,
+    &_FIELD_737,
+    &_FIELD_738,
+    &_FIELD_738,
+    &_FIELD_738
+];
+
+#[rustfmt::skip]
+static _FIELD_739: _RelativeField = _RelativeField { relatives: &[(0, "अब")], future: ["", "{0} स\u{947}क\u{902}ड म\u{947}\u{902}", "", "", "", "{0} स\u{947}क\u{902}ड म\u{947}\u{902}"], past: ["", "{0} स\u{947}क\u{902}ड पहल\u{947}", "", "", "", "{0} स\u{947}क\u{902}ड पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_740: _RelativeField = _RelativeField { relatives: &[(0, "अब")], future: ["", "{0} स\u{947}॰ म\u{947}\u{902}", "", "", "", "{0} स\u{947}॰ म\u{947}\u{902}"], past: ["", "{0} स\u{947}॰ पहल\u{947}", "", "", "", "{0} स\u{947}॰ पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_741: _RelativeField = _RelativeField { relatives: &[(0, "यह मिनट")], future: ["", "{0} मिनट म\u{947}\u{902}", "", "", "", "{0} मिनट म\u{947}\u{902}"], past: ["", "{0} मिनट पहल\u{947}", "", "", "", "{0} मिनट पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_742: _RelativeField = _RelativeField { relatives: &[(0, "यह मिनट")], future: ["", "{0} मि॰ म\u{947}\u{902}", "", "", "", "{0} मि॰ म\u{947}\u{902}"], past: ["", "{0} मि॰ पहल\u{947}", "", "", "", "{0} मि॰ पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_743: _RelativeField = _RelativeField { relatives: &[(0, "यह घ\u{902}टा")], future: ["", "{0} घ\u{902}ट\u{947} म\u{947}\u{902}", "", "", "", "{0} घ\u{902}ट\u{947} म\u{947}\u{902}"], past: ["", "{0} घ\u{902}ट\u{947} पहल\u{947}", "", "", "", "{0} घ\u{902}ट\u{947} पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_744: _RelativeField = _RelativeField { relatives: &[(0, "यह घ\u{902}टा")], future: ["", "{0} घ\u{902}॰ म\u{947}\u{902}", "", "", "", "{0} घ\u{902}॰ म\u{947}\u{902}"], past: ["", "{0} घ\u{902}॰ पहल\u{947}", "", "", "", "{0} घ\u{902}॰ पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_745: _RelativeField = _RelativeField { relatives: &[(-2, "परसो\u{902}"), (-1, "कल"), (0, "आज"), (1, "कल"), (2, "परसो\u{902}")], future: ["", "{0} दिन म\u{947}\u{902}", "", "", "", "{0} दिन म\u{947}\u{902}"], past: ["", "{0} दिन पहल\u{947}", "", "", "", "{0} दिन पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_746: _RelativeField = _RelativeField { relatives: &[(-2, "परसो\u{902}"), (-1, "बीता कल"), (0, "आज"), (1, "आन\u{947} वाला कल"), (2, "परसो\u{902}")], future: ["", "{0} दिन म\u{947}\u{902}", "", "", "", "{0} दिन म\u{947}\u{902}"], past: ["", "{0} दिन पहल\u{947}", "", "", "", "{0} दिन पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_747: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला सप\u{94d}ताह"), (0, "इस सप\u{94d}ताह"), (1, "अगला सप\u{94d}ताह")], future: ["", "{0} सप\u{94d}ताह म\u{947}\u{902}", "", "", "", "{0} सप\u{94d}ताह म\u{947}\u{902}"], past: ["", "{0} सप\u{94d}ताह पहल\u{947}", "", "", "", "{0} सप\u{94d}ताह पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_748: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला माह"), (0, "इस माह"), (1, "अगला माह")], future: ["", "{0} माह म\u{947}\u{902}", "", "", "", "{0} माह म\u{947}\u{902}"], past: ["", "{0} माह पहल\u{947}", "", "", "", "{0} माह पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_749: _RelativeField = _RelativeField { relatives: &[(-1, "अ\u{902}तिम तिमाही"), (0, "इस तिमाही"), (1, "अगली तिमाही")], future: ["", "{0} तिमाही म\u{947}\u{902}", "", "", "", "{0} तिमाहियो\u{902} म\u{947}\u{902}"], past: ["", "{0} तिमाही पहल\u{947}", "", "", "", "{0} तिमाही पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_750: _RelativeField = _RelativeField { relatives: &[(-1, "अ\u{902}तिम तिमाही"), (0, "इस तिमाही"), (1, "अगली तिमाही")], future: ["", "{0} तिमाही म\u{947}\u{902}", "", "", "", "{0} तिमाहियो\u{902} म\u{947}\u{902}"], past: ["", "{0} तिमाही पहल\u{947}", "", "", "", "{0} तिमाहियो\u{902} पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_751: _RelativeField = _RelativeField { relatives: &[(-1, "अ\u{902}तिम तिमाही"), (0, "इस तिमाही"), (1, "अगली तिमाही")], future: ["", "{0} ति॰ म\u{947}\u{902}", "", "", "", "{0} ति॰ म\u{947}\u{902}"], past: ["", "{0} ति॰ पहल\u{947}", "", "", "", "{0} ति॰ पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_752: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला वर\u{94d}ष"), (0, "इस वर\u{94d}ष"), (1, "अगला वर\u{94d}ष")], future: ["", "{0} वर\u{94d}ष म\u{947}\u{902}", "", "", "", "{0} वर\u{94d}ष म\u{947}\u{902}"], past: ["", "{0} वर\u{94d}ष पहल\u{947}", "", "", "", "{0} वर\u{94d}ष पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_753: _RelativeField = _RelativeField { relatives: &[(-1, "पिछल\u{947} साल"), (0, "इस साल"), (1, "अगल\u{947} साल")], future: ["", "{0} वर\u{94d}ष म\u{947}\u{902}", "", "", "", "{0} वर\u{94d}ष म\u{947}\u{902}"], past: ["", "{0} वर\u{94d}ष पहल\u{947}", "", "", "", "{0} वर\u{94d}ष पहल\u{947}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_61: [&_RelativeField; 24] = [
+    &_FIELD_739,
+    &_FIELD_740,
+    &_FIELD_740,
+    &_FIELD_741,
+    &_FIELD_742,
+    &_FIELD_742,
+    &_FIELD_743,
+    &_FIELD_744,
+    &_FIELD_744,
+    &_FIELD_745,
+    &_FIELD_746,
+    &_FIELD_746,
+    &_FIELD_747,
+    &_FIELD_747,
+    &_FIELD_747,
+    &_FIELD_748,
+    &_FIELD_748,
+    &_FIELD_748,
+    &_FIELD_749,
+    &_FIELD_750,
+    &_FIELD_751,
+    &_FIELD_752,
+    &_FIELD_752,
+    &_FIELD_753
+];
+
+#[rustfmt::skip]
+static _FIELD_754: _RelativeField = _RelativeField { relatives: &[(0, "abhi")], future: ["", "{0} second mein", "", "", "", "{0} second mein"], past: ["", "{0} second pahle", "", "", "", "{0} second pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_755: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "{0} sec. mein", "", "", "", "{0} sec. mein"], past: ["", "{0} sec. pahle", "", "", "", "{0} sec. pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_756: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "{0}s mein", "", "", "", "{0}s mein"], past: ["", "{0}s pahle", "", "", "", "{0}s pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_757: _RelativeField = _RelativeField { relatives: &[(0, "yah minute")], future: ["", "{0} minute mein", "", "", "", "{0} minute mein"], past: ["", "{0} minute pahle", "", "", "", "{0} minute pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_758: _RelativeField = _RelativeField { relatives: &[(0, "yah minute")], future: ["", "{0} min. mein", "", "", "", "{0} min. mein"], past: ["", "{0} min. pahle", "", "", "", "{0} min. pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_759: _RelativeField = _RelativeField { relatives: &[(0, "yah ghanta")], future: ["", "{0} ghante mein", "", "", "", "{0} ghante mein"], past: ["", "{0} ghante pahle", "", "", "", "{0} ghante pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_760: _RelativeField = _RelativeField { relatives: &[(0, "yah ghanta")], future: ["", "{0} hr mein", "", "", "", "{0} hr mein"], past: ["", "{0} hr pahle", "", "", "", "{0} hr mein"] };
+
+#[rustfmt::skip]
+static _FIELD_761: _RelativeField = _RelativeField { relatives: &[(0, "yah ghanta")], future: ["", "{0}hr mein", "", "", "", "{0}hr mein"], past: ["", "{0}hr pahle", "", "", "", "{0}hr pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_762: _RelativeField = _RelativeField { relatives: &[(-2, "parson"), (-1, "kal"), (0, "aaj"), (1, "aane wala kal"), (2, "aane wala parson")], future: ["", "{0} din mein", "", "", "", "{0} din mein"], past: ["", "{0} din pahle", "", "", "", "{0} din pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_763: _RelativeField = _RelativeField { relatives: &[(-1, "pichhla hafta"), (0, "is hafte"), (1, "agla hafta")], future: ["", "{0} hafte mein", "", "", "", "{0} hafte mein"], past: ["", "{0} hafte pahle", "", "", "", "{0} hafte pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_764: _RelativeField = _RelativeField { relatives: &[(-1, "last wk."), (0, "is wk."), (1, "next wk.")], future: ["", "{0} wk. mein", "", "", "", "{0} wk. mein"], past: ["", "{0} wk. pahle", "", "", "", "{0} wk. pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_765: _RelativeField = _RelativeField { relatives: &[(-1, "last wk"), (0, "is wk"), (1, "next wk")], future: ["", "{0}w mein", "", "", "", "{0}w mein"], past: ["", "{0}w pahle", "", "", "", "{0}w pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_766: _RelativeField = _RelativeField { relatives: &[(-1, "pichhla maheena"), (0, "yah maheena"), (1, "agla maheena")], future: ["", "{0} maheene mein", "", "", "", "{0} maheene mein"], past: ["", "{0} maheene pahle", "", "", "", "{0} maheene pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_767: _RelativeField = _RelativeField { relatives: &[(-1, "last mo"), (0, "is mo"), (1, "next mo")], future: ["", "{0} mo. mein", "", "", "", "{0} mo. mein"], past: ["", "{0} mo. pahle", "", "", "", "{0} mo. pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_768: _RelativeField = _RelativeField { relatives: &[(-1, "last mo."), (0, "is mo."), (1, "next mo.")], future: ["", "{0}mo mein", "", "", "", "{0}mo mein"], past: ["", "{0}mo pahle", "", "", "", "{0}mo pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_769: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "is quarter"), (1, "next quarter")], future: ["", "{0} quarter mein", "", "", "", "{0} quarters mein"], past: ["", "{0} quarter pahle", "", "", "", "{0} quarters pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_770: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")], future: ["", "{0} qtr. mein", "", "", "", "{0} qtrs. mein"], past: ["", "{0} qtr. pahle", "", "", "", "{0} qtrs. pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_771: _RelativeField = _RelativeField { relatives: &[(-1, "last qtr."), (0, "this qtr."), (1, "next qtr.")], future: ["", "{0}q mein", "", "", "", "{0}q mein"], past: ["", "{0}q pahle", "", "", "", "{0}q pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_772: _RelativeField = _RelativeField { relatives: &[(-1, "pichhla saal"), (0, "is saal"), (1, "agla saal")], future: ["", "{0} saal mein", "", "", "", "{0} saal mein"], past: ["", "{0} saal pahle", "", "", "", "{0} saal pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_773: _RelativeField = _RelativeField { relatives: &[(-1, "last yr."), (0, "is yr."), (1, "next yr.")], future: ["", "{0} yr. mein", "", "", "", "{0} yr. mein"], past: ["", "{0} yr. pahle", "", "", "", "{0} yr. pahle"] };
+
+#[rustfmt::skip]
+static _FIELD_774: _RelativeField = _RelativeField { relatives: &[(-1, "last yr."), (0, "is yr."), (1, "next yr.")], future: ["", "{0}y mein", "", "", "", "{0}y mein"], past: ["", "{0}y pahle", "", "", "", "{0}y pahle"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_62: [&_RelativeField; 24] = [
+    &_FIELD_754,
+    &_FIELD_755,
+    &_FIELD_756,
+    &_FIELD_757,
+    &_FIELD_758,
+    &_FIELD_758,
+    &_FIELD_759,
+    &_FIELD_760,
+    &_FIELD_761,
+    &_FIELD_762,
+    &_FIELD_762,
+    &_FIELD_762,
+    &_FIELD_763,
+    &_FIELD_764,
+    &_FIELD_765,
+    &_FIELD_766,
+    &_FIELD_767,
+    &_FIELD_768,
+    &_FIELD_769,
+    &_FIELD_770,
+    &_FIELD_771,
+    &_FIELD_772,
+    &_FIELD_773,
+    &_FIELD_774
+];
+
+#[rustfmt::skip]
+static _FIELD_775: _RelativeField = _RelativeField { relatives: &[(0, "sad")], future: ["", "za {0} sekundu", "", "za {0} sekunde", "", "za {0} sekundi"], past: ["", "prije {0} sekundu", "", "prije {0} sekunde", "", "prije {0} sekundi"] };
+
+#[rustfmt::skip]
+static _FIELD_776: _RelativeField = _RelativeField { relatives: &[(0, "sad")], future: ["", "za {0} s", "", "za {0} s", "", "za {0} s"], past: ["", "prije {0} s", "", "prije {0} s", "", "prije {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_777: _RelativeField = _RelativeField { relatives: &[(0, "ova minuta")], future: ["", "za {0} min", "", "za {0} min", "", "za {0} min"], past: ["", "prije {0} min", "", "prije {0} min", "", "prije {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_778: _RelativeField = _RelativeField { relatives: &[(0, "ovaj sat")], future: ["", "za {0} h", "", "za {0} h", "", "za {0} h"], past: ["", "prije {0} h", "", "prije {0} h", "", "prije {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_779: _RelativeField = _RelativeField { relatives: &[(-2, "prekjučer"), (-1, "jučer"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} d", "", "za {0} d", "", "za {0} d"], past: ["", "prije {0} d", "", "prije {0} d", "", "prije {0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_780: _RelativeField = _RelativeField { relatives: &[(-1, "prošli tjedan"), (0, "ovaj tjedan"), (1, "sljedeći tjedan")], future: ["", "za {0} tjedan", "", "za {0} tjedna", "", "za {0} tjedana"], past: ["", "prije {0} tjedan", "", "prije {0} tjedna", "", "prije {0} tjedana"] };
+
+#[rustfmt::skip]
+static _FIELD_781: _RelativeField = _RelativeField { relatives: &[(-1, "prošli tj."), (0, "ovaj tj."), (1, "sljedeći tj.")], future: ["", "za {0} tj.", "", "za {0} tj.", "", "za {0} tj."], past: ["", "prije {0} tj.", "", "prije {0} tj.", "", "prije {0} tj."] };
+
+#[rustfmt::skip]
+static _FIELD_782: _RelativeField = _RelativeField { relatives: &[(-1, "prošli mj."), (0, "ovaj mj."), (1, "sljedeći mj.")], future: ["", "za {0} mj.", "", "za {0} mj.", "", "za {0} mj."], past: ["", "prije {0} mj.", "", "prije {0} mj.", "", "prije {0} mj."] };
+
+#[rustfmt::skip]
+static _FIELD_783: _RelativeField = _RelativeField { relatives: &[(-1, "prošli kvartal"), (0, "ovaj kvartal"), (1, "sljedeći kvartal")], future: ["", "za {0} kvartal", "", "za {0} kvartala", "", "za {0} kvartala"], past: ["", "prije {0} kvartal", "", "prije {0} kvartala", "", "prije {0} kvartala"] };
+
+#[rustfmt::skip]
+static _FIELD_784: _RelativeField = _RelativeField { relatives: &[(-1, "prošli kv."), (0, "ovaj kv."), (1, "sljedeći kv.")], future: ["", "za {0} kv.", "", "za {0} kv.", "", "za {0} kv."], past: ["", "prije {0} kv.", "", "prije {0} kv.", "", "prije {0} kv."] };
+
+#[rustfmt::skip]
+static _FIELD_785: _RelativeField = _RelativeField { relatives: &[(-1, "prošle god."), (0, "ove god."), (1, "sljedeće god.")], future: ["", "za {0} g.", "", "za {0} g.", "", "za {0} g."], past: ["", "prije {0} g.", "", "prije {0} g.", "", "prije {0} g."] };
+
+#[rustfmt::skip]
+static _FIELD_786: _RelativeField = _RelativeField { relatives: &[(-1, "prošle g."), (0, "ove g."), (1, "sljedeće g.")], future: ["", "za {0} g.", "", "za {0} g.", "", "za {0} g."], past: ["", "prije {0} g.", "", "prije {0} g.", "", "prije {0} g."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_63: [&_RelativeField; 24] = [
+    &_FIELD_775,
+    &_FIELD_776,
+    &_FIELD_776,
+    &_FIELD_206,
+    &_FIELD_777,
+    &_FIELD_777,
+    &_FIELD_208,
+    &_FIELD_778,
+    &_FIELD_778,
+    &_FIELD_209,
+    &_FIELD_209,
+    &_FIELD_779,
+    &_FIELD_780,
+    &_FIELD_781,
+    &_FIELD_781,
+    &_FIELD_213,
+    &_FIELD_782,
+    &_FIELD_782,
+    &_FIELD_783,
+    &_FIELD_784,
+    &_FIELD_784,
+    &_FIELD_217,
+    &_FIELD_785,
+    &_FIELD_786
+];
+
+#[rustfmt::skip]
+static _FIELD_787: _RelativeField = _RelativeField { relatives: &[(0, "nětko")], future: ["", "za {0} sekundu", "za {0} sekundźe", "za {0} sekundy", "", "za {0} sekundow"], past: ["", "před {0} sekundu", "před {0} sekundomaj", "před {0} sekundami", "", "před {0} sekundami"] };
+
+#[rustfmt::skip]
+static _FIELD_788: _RelativeField = _RelativeField { relatives: &[(0, "nětko")], future: ["", "za {0} sek.", "za {0} sek.", "za {0} sek.", "", "za {0} sek."], past: ["", "před {0} sek.", "před {0} sek.", "před {0} sek.", "", "před {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_789: _RelativeField = _RelativeField { relatives: &[(0, "nětko")], future: ["", "za {0} s", "za {0} s", "za {0} s", "", "za {0} s"], past: ["", "před {0} s", "před {0} s", "před {0} s", "", "před {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_790: _RelativeField = _RelativeField { relatives: &[(0, "w tutej mjeńšinje")], future: ["", "za {0} minutu", "za {0} minuće", "za {0} minuty", "", "za {0} minutow"], past: ["", "před {0} minutu", "před {0} minutomaj", "před {0} minutami", "", "před {0} minutami"] };
+
+#[rustfmt::skip]
+static _FIELD_791: _RelativeField = _RelativeField { relatives: &[(0, "w tutej mjeńšinje")], future: ["", "za {0} min.", "za {0} min.", "za {0} min.", "", "za {0} min."], past: ["", "před {0} min.", "před {0} min.", "před {0} min.", "", "před {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_792: _RelativeField = _RelativeField { relatives: &[(0, "w tutej mjeńšinje")], future: ["", "za {0} m", "za {0} m", "za {0} m", "", "za {0} m"], past: ["", "před {0} m", "před {0} m", "před {0} m", "", "před {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_793: _RelativeField = _RelativeField { relatives: &[(0, "w tutej hodźinje")], future: ["", "za {0} hodźinu", "za {0} hodźinje", "za {0} hodźiny", "", "za {0} hodźin"], past: ["", "před {0} hodźinu", "před {0} hodźinomaj", "před {0} hodźinami", "", "před {0} hodźinami"] };
+
+#[rustfmt::skip]
+static _FIELD_794: _RelativeField = _RelativeField { relatives: &[(0, "w tutej hodźinje")], future: ["", "za {0} hodź.", "za {0} hodź.", "za {0} hodź.", "", "za {0} hodź."], past: ["", "před {0} hodź.", "před {0} hodź.", "před {0} hodź.", "", "před {0} hodź."] };
+
+#[rustfmt::skip]
+static _FIELD_795: _RelativeField = _RelativeField { relatives: &[(0, "w tutej hodźinje")], future: ["", "za {0} h", "za {0} h", "za {0} h", "", "za {0} h"], past: ["", "před {0} h", "před {0} h", "před {0} h", "", "před {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_796: _RelativeField = _RelativeField { relatives: &[(-1, "wčera"), (0, "dźensa"), (1, "jutře")], future: ["", "za {0} dźeń", "za {0} dnjej", "za {0} dny", "", "za {0} dnjow"], past: ["", "před {0} dnjom", "před {0} dnjomaj", "před {0} dnjemi", "", "před {0} dnjemi"] };
+
+#[rustfmt::skip]
+static _FIELD_797: _RelativeField = _RelativeField { relatives: &[(-1, "wčer."), (0, "dźens."), (1, "jutř.")], future: ["", "za {0} dźeń", "za {0} dnj.", "za {0} dny", "", "za {0} dnj."], past: ["", "před {0} dnj.", "před {0} dnj.", "před {0} dnj.", "", "před {0} dnj."] };
+
+#[rustfmt::skip]
+static _FIELD_798: _RelativeField = _RelativeField { relatives: &[(-1, "wč."), (0, "dźe."), (1, "jut.")], future: ["", "za {0} d", "za {0} d", "za {0} d", "", "za {0} d"], past: ["", "před {0} d", "před {0} d", "před {0} d", "", "před {0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_799: _RelativeField = _RelativeField { relatives: &[(-1, "zašły tydźeń"), (0, "tutón tydźeń"), (1, "přichodny tydźeń")], future: ["", "za {0} tydźeń", "za {0} tydźenjej", "za {0} tydźenje", "", "za {0} tydźenjow"], past: ["", "před {0} tydźenjom", "před {0} tydźenjomaj", "před {0} tydźenjemi", "", "před {0} tydźenjemi"] };
+
+#[rustfmt::skip]
+static _FIELD_800: _RelativeField = _RelativeField { relatives: &[(-1, "zašł. tydźeń"), (0, "tut. tydźeń"), (1, "přichodn. tydźeń")], future: ["", "za {0} tydź.", "za {0} tydź.", "za {0} tydź.", "", "za {0} tydź."], past: ["", "před {0} tydź.", "před {0} tydź.", "před {0} tydź.", "", "před {0} tydź."] };
+
+#[rustfmt::skip]
+static _FIELD_801: _RelativeField = _RelativeField { relatives: &[(-1, "zaš. tydźeń"), (0, "tu. tydźeń"), (1, "přich. tydźeń")], future: ["", "za {0} tydź.", "za {0} tydź.", "za {0} tydź.", "", "za {0} tydź."], past: ["", "před {0} tydź.", "před {0} tydź.", "před {0} tydź.", "", "před {0} tydź."] };
+
+#[rustfmt::skip]
+static _FIELD_802: _RelativeField = _RelativeField { relatives: &[(-1, "zašły měsac"), (0, "tutón měsac"), (1, "přichodny měsac")], future: ["", "za {0} měsac", "za {0} měsacaj", "za {0} měsacy", "", "za {0} měsacow"], past: ["", "před {0} měsacom", "před {0} měsacomaj", "před {0} měsacami", "", "před {0} měsacami"] };
+
+#[rustfmt::skip]
+static _FIELD_803: _RelativeField = _RelativeField { relatives: &[(-1, "zašł. měsac"), (0, "tut. měsac"), (1, "přichodn. měsac")], future: ["", "za {0} měs.", "za {0} měs.", "za {0} měs.", "", "za {0} měs."], past: ["", "před {0} měs.", "před {0} měs.", "před {0} měs.", "", "před {0} měs."] };
+
+#[rustfmt::skip]
+static _FIELD_804: _RelativeField = _RelativeField { relatives: &[(-1, "zaš. měsac"), (0, "tu. měsac"), (1, "přich. měsac")], future: ["", "za {0} měs.", "za {0} měs.", "za {0} měs.", "", "za {0} měs."], past: ["", "před {0} měs.", "před {0} měs.", "před {0} měs.", "", "před {0} měs."] };
+
+#[rustfmt::skip]
+static _FIELD_805: _RelativeField = _RelativeField { relatives: &[(-1, "zašły kwartal"), (0, "tutón kwartal"), (1, "přichodny kwartal")], future: ["", "za {0} kwartal", "za {0} kwartalej", "za {0} kwartale", "", "za {0} kwartalow"], past: ["", "před {0} kwartalom", "před {0} kwartalomaj", "před {0} kwartalemi", "", "před {0} kwartalemi"] };
+
+#[rustfmt::skip]
+static _FIELD_806: _RelativeField = _RelativeField { relatives: &[(-1, "zašły kwartal"), (0, "tutón kwartal"), (1, "přichodny kwartal")], future: ["", "za {0} kwart.", "za {0} kwart.", "za {0} kwart.", "", "za {0} kwart."], past: ["", "před {0} kwart.", "před {0} kwart.", "před {0} kwart.", "", "před {0} kwart."] };
+
+#[rustfmt::skip]
+static _FIELD_807: _RelativeField = _RelativeField { relatives: &[(-1, "zašły kwartal"), (0, "tutón kwartal"), (1, "přichodny kwartal")], future: ["", "za {0} kw.", "za {0} kw.", "za {0} kw.", "", "za {0} kw."], past: ["", "před {0} kw.", "před {0} kw.", "před {0} kw.", "", "před {0} kw."] };
+
+#[rustfmt::skip]
+static _FIELD_808: _RelativeField = _RelativeField { relatives: &[(-1, "loni"), (0, "lětsa"), (1, "klětu")], future: ["", "za {0} lěto", "za {0} lěće", "za {0} lěta", "", "za {0} lět"], past: ["", "před {0} lětom", "před {0} lětomaj", "před {0} lětami", "", "před {0} lětami"] };
+
+#[rustfmt::skip]
+static _FIELD_809: _RelativeField = _RelativeField { relatives: &[(-1, "lon."), (0, "lěts."), (1, "klět.")], future: ["", "za {0} l.", "za {0} l.", "za {0} l.", "", "za {0} l."], past: ["", "před {0} l.", "před {0} l.", "před {0} l.", "", "před {0} l."] };
+
+#[rustfmt::skip]
+static _FIELD_810: _RelativeField = _RelativeField { relatives: &[(-1, "lo."), (0, "lět."), (1, "klě.")], future: ["", "za {0} l.", "za {0} l.", "za {0} l.", "", "za {0} l."], past: ["", "před {0} l.", "před {0} l.", "před {0} l.", "", "před {0} l."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_64: [&_RelativeField; 24] = [
+    &_FIELD_787,
+    &_FIELD_788,
+    &_FIELD_789,
+    &_FIELD_790,
+    &_FIELD_791,
+    &_FIELD_792,
+    &_FIELD_793,
+    &_FIELD_794,
+    &_FIELD_795,
+    &_FIELD_796,
+    &_FIELD_797,
+    &_FIELD_798,
+    &_FIELD_799,
+    &_FIELD_800,
+    &_FIELD_801,
+    &_FIELD_802,
+    &_FIELD_803,
+    &_FIELD_804,
+    &_FIELD_805,
+    &_FIELD_806,
+    &_FIELD_807,
+    &_FIELD_808,
+    &_FIELD_809,
+    &_FIELD_810
+];
+
+#[rustfmt::skip]
+static _FIELD_811: _RelativeField = _RelativeField { relatives: &[(0, "most")], future: ["", "{0} másodperc múlva", "", "", "", "{0} másodperc múlva"], past: ["", "{0} másodperccel ezelőtt", "", "", "", "{0} másodperccel ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_812: _RelativeField = _RelativeField { relatives: &[(0, "most")], future: ["", "{0} másodperc múlva", "", "", "", "{0} másodperc múlva"], past: ["", "{0} m.perce", "", "", "", "{0} m.perce"] };
+
+#[rustfmt::skip]
+static _FIELD_813: _RelativeField = _RelativeField { relatives: &[(0, "ebben a percben")], future: ["", "{0} perc múlva", "", "", "", "{0} perc múlva"], past: ["", "{0} perccel ezelőtt", "", "", "", "{0} perccel ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_814: _RelativeField = _RelativeField { relatives: &[(0, "ebben a percben")], future: ["", "{0} perc múlva", "", "", "", "{0} perc múlva"], past: ["", "{0} perce", "", "", "", "{0} perce"] };
+
+#[rustfmt::skip]
+static _FIELD_815: _RelativeField = _RelativeField { relatives: &[(0, "ebben az órában")], future: ["", "{0} óra múlva", "", "", "", "{0} óra múlva"], past: ["", "{0} órával ezelőtt", "", "", "", "{0} órával ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_816: _RelativeField = _RelativeField { relatives: &[(0, "ebben az órában")], future: ["", "{0} óra múlva", "", "", "", "{0} óra múlva"], past: ["", "{0} órája", "", "", "", "{0} órája"] };
+
+#[rustfmt::skip]
+static _FIELD_817: _RelativeField = _RelativeField { relatives: &[(-2, "tegnapelőtt"), (-1, "tegnap"), (0, "ma"), (1, "holnap"), (2, "holnapután")], future: ["", "{0} nap múlva", "", "", "", "{0} nap múlva"], past: ["", "{0} nappal ezelőtt", "", "", "", "{0} nappal ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_818: _RelativeField = _RelativeField { relatives: &[(-2, "tegnapelőtt"), (-1, "tegnap"), (0, "ma"), (1, "holnap"), (2, "holnapután")], future: ["", "{0} nap múlva", "", "", "", "{0} nap múlva"], past: ["", "{0} napja", "", "", "", "{0} napja"] };
+
+#[rustfmt::skip]
+static _FIELD_819: _RelativeField = _RelativeField { relatives: &[(-1, "előző hét"), (0, "ez a hét"), (1, "következő hét")], future: ["", "{0} hét múlva", "", "", "", "{0} hét múlva"], past: ["", "{0} héttel ezelőtt", "", "", "", "{0} héttel ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_820: _RelativeField = _RelativeField { relatives: &[(-1, "előző hét"), (0, "ez a hét"), (1, "következő hét")], future: ["", "{0} hét múlva", "", "", "", "{0} hét múlva"], past: ["", "{0} hete", "", "", "", "{0} hete"] };
+
+#[rustfmt::skip]
+static _FIELD_821: _RelativeField = _RelativeField { relatives: &[(-1, "előző hónap"), (0, "ez a hónap"), (1, "következő hónap")], future: ["", "{0} hónap múlva", "", "", "", "{0} hónap múlva"], past: ["", "{0} hónappal ezelőtt", "", "", "", "{0} hónappal ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_822: _RelativeField = _RelativeField { relatives: &[(-1, "előző hónap"), (0, "ez a hónap"), (1, "következő hónap")], future: ["", "{0} hónap múlva", "", "", "", "{0} hónap múlva"], past: ["", "{0} hónapja", "", "", "", "{0} hónapja"] };
+
+#[rustfmt::skip]
+static _FIELD_823: _RelativeField = _RelativeField { relatives: &[(-1, "előző negyedév"), (0, "ez a negyedév"), (1, "következő negyedév")], future: ["", "{0} negyedév múlva", "", "", "", "{0} negyedév múlva"], past: ["", "{0} negyedévvel ezelőtt", "", "", "", "{0} negyedévvel ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_824: _RelativeField = _RelativeField { relatives: &[(-1, "előző negyedév"), (0, "ez a negyedév"), (1, "következő negyedév")], future: ["", "{0} n.év múlva", "", "", "", "{0} n.év múlva"], past: ["", "{0} negyedévvel ezelőtt", "", "", "", "{0} negyedévvel ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_825: _RelativeField = _RelativeField { relatives: &[(-1, "előző év"), (0, "ez az év"), (1, "következő év")], future: ["", "{0} év múlva", "", "", "", "{0} év múlva"], past: ["", "{0} évvel ezelőtt", "", "", "", "{0} évvel ezelőtt"] };
+
+#[rustfmt::skip]
+static _FIELD_826: _RelativeField = _RelativeField { relatives: &[(-1, "előző év"), (0, "ez az év"), (1, "következő év")], future: ["", "{0} év múlva", "", "", "", "{0} év múlva"], past: ["", "{0} éve", "", "", "", "{0} éve"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_65: [&_RelativeField; 24] = [
+    &_FIELD_811,
+    &_FIELD_811,
+    &_FIELD_812,
+    &_FIELD_813,
+    &_FIELD_813,
+    &_FIELD_814,
+    &_FIELD_815,
+    &_FIELD_815,
+    &_FIELD_816,
+    &_FIELD_817,
+    &_FIELD_818,
+    &_FIELD_818,
+    &_FIELD_819,
+    &_FIELD_819,
+    &_FIELD_820,
+    &_FIELD_821,
+    &_FIELD_821,
+    &_FIELD_822,
+    &_FIELD_823,
+    &_FIELD_823,
+    &_FIELD_824,
+    &_FIELD_825,
+    &_FIELD_825,
+    &_FIELD_826
+];
+
+#[rustfmt::skip]
+static _FIELD_827: _RelativeField = _RelativeField { relatives: &[(0, "հիմա")], future: ["", "{0} վայրկյանից", "", "", "", "{0} վայրկյանից"], past: ["", "{0} վայրկյան առաջ", "", "", "", "{0} վայրկյան առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_828: _RelativeField = _RelativeField { relatives: &[(0, "հիմա")], future: ["", "{0} վրկ-ից", "", "", "", "{0} վրկ-ից"], past: ["", "{0} վրկ առաջ", "", "", "", "{0} վրկ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_829: _RelativeField = _RelativeField { relatives: &[(0, "հիմա")], future: ["", "{0} վ-ից", "", "", "", "{0} վ-ից"], past: ["", "{0} վ առաջ", "", "", "", "{0} վ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_830: _RelativeField = _RelativeField { relatives: &[(0, "այս րոպեին")], future: ["", "{0} րոպեից", "", "", "", "{0} րոպեից"], past: ["", "{0} րոպե առաջ", "", "", "", "{0} րոպե առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_831: _RelativeField = _RelativeField { relatives: &[(0, "այս րոպեին")], future: ["", "{0} ր-ից", "", "", "", "{0} ր-ից"], past: ["", "{0} ր առաջ", "", "", "", "{0} ր առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_832: _RelativeField = _RelativeField { relatives: &[(0, "այս ժամին")], future: ["", "{0} ժամից", "", "", "", "{0} ժամից"], past: ["", "{0} ժամ առաջ", "", "", "", "{0} ժամ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_833: _RelativeField = _RelativeField { relatives: &[(0, "այս ժամին")], future: ["", "{0} ժ-ից", "", "", "", "{0} ժ-ից"], past: ["", "{0} ժ առաջ", "", "", "", "{0} ժ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_834: _RelativeField = _RelativeField { relatives: &[(-2, "նախանցյալ օրը"), (-1, "երեկ"), (0, "այսօր"), (1, "վաղը"), (2, "վաղը չէ մյուս օրը")], future: ["", "{0} օրից", "", "", "", "{0} օրից"], past: ["", "{0} օր առաջ", "", "", "", "{0} օր առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_835: _RelativeField = _RelativeField { relatives: &[(-2, "երեկ չէ առաջի օրը"), (-1, "երեկ"), (0, "այսօր"), (1, "վաղը"), (2, "վաղը չէ մյուս օրը")], future: ["", "{0} օրից", "", "", "", "{0} օրից"], past: ["", "{0} օր առաջ", "", "", "", "{0} օր առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_836: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ շաբաթ"), (0, "այս շաբաթ"), (1, "հաջորդ շաբաթ")], future: ["", "{0} շաբաթից", "", "", "", "{0} շաբաթից"], past: ["", "{0} շաբաթ առաջ", "", "", "", "{0} շաբաթ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_837: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ շաբաթ"), (0, "այս շաբաթ"), (1, "հաջորդ շաբաթ")], future: ["", "{0} շաբ-ից", "", "", "", "{0} շաբ-ից"], past: ["", "{0} շաբ առաջ", "", "", "", "{0} շաբ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_838: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ շաբաթ"), (0, "այս շաբաթ"), (1, "հաջորդ շաբաթ")], future: ["", "{0} շաբ անց", "", "", "", "{0} շաբ անց"], past: ["", "{0} շաբ առաջ", "", "", "", "{0} շաբ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_839: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ ամիս"), (0, "այս ամիս"), (1, "հաջորդ ամիս")], future: ["", "{0} ամսից", "", "", "", "{0} ամսից"], past: ["", "{0} ամիս առաջ", "", "", "", "{0} ամիս առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_840: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ եռամսյակ"), (0, "այս եռամսյակ"), (1, "հաջորդ եռամսյակ")], future: ["", "{0} եռամսյակից", "", "", "", "{0} եռամսյակից"], past: ["", "{0} եռամսյակ առաջ", "", "", "", "{0} եռամսյակ առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_841: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ եռամսյակ"), (0, "այս եռամսյակ"), (1, "հաջորդ եռամսյակ")], future: ["", "{0} եռմս-ից", "", "", "", "{0} եռմս-ից"], past: ["", "{0} եռմս առաջ", "", "", "", "{0} եռմս առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_842: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ տարի"), (0, "այս տարի"), (1, "հաջորդ տարի")], future: ["", "{0} տարուց", "", "", "", "{0} տարուց"], past: ["", "{0} տարի առաջ", "", "", "", "{0} տարի առաջ"] };
+
+#[rustfmt::skip]
+static _FIELD_843: _RelativeField = _RelativeField { relatives: &[(-1, "նախորդ տարի"), (0, "այս տարի"), (1, "հաջորդ տարի")], future: ["", "{0} տարուց", "", "", "", "{0} տարուց"], past: ["", "{0} տ առաջ", "", "", "", "{0} տ առաջ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_66: [&_RelativeField; 24] = [
+    &_FIELD_827,
+    &_FIELD_828,
+    &_FIELD_829,
+    &_FIELD_830,
+    &_FIELD_831,
+    &_FIELD_831,
+    &_FIELD_832,
+    &_FIELD_833,
+    &_FIELD_833,
+    &_FIELD_834,
+    &_FIELD_835,
+    &_FIELD_835,
+    &_FIELD_836,
+    &_FIELD_837,
+    &_FIELD_838,
+    &_FIELD_839,
+    &_FIELD_839,
+    &_FIELD_839,
+    &_FIELD_840,
+    &_FIELD_841,
+    &_FIELD_841,
+    &_FIELD_842,
+    &_FIELD_843,
+    &_FIELD_843
+];
+
+#[rustfmt::skip]
+static _FIELD_844: _RelativeField = _RelativeField { relatives: &[(0, "ora")], future: ["", "in {0} secunda", "", "", "", "in {0} secundas"], past: ["", "{0} secunda retro", "", "", "", "{0} secundas retro"] };
+
+#[rustfmt::skip]
+static _FIELD_845: _RelativeField = _RelativeField { relatives: &[(0, "ora")], future: ["", "in {0} sec.", "", "", "", "in {0} sec."], past: ["", "{0} sec. retro", "", "", "", "{0} sec. retro"] };
+
+#[rustfmt::skip]
+static _FIELD_846: _RelativeField = _RelativeField { relatives: &[(0, "iste minuta")], future: ["", "in {0} minuta", "", "", "", "in {0} minutas"], past: ["", "{0} minuta retro", "", "", "", "{0} minutas retro"] };
+
+#[rustfmt::skip]
+static _FIELD_847: _RelativeField = _RelativeField { relatives: &[(0, "iste minuta")], future: ["", "in {0} min.", "", "", "", "in {0} min."], past: ["", "{0} min. retro", "", "", "", "{0} min. retro"] };
+
+#[rustfmt::skip]
+static _FIELD_848: _RelativeField = _RelativeField { relatives: &[(0, "iste hora")], future: ["", "in {0} hora", "", "", "", "in {0} horas"], past: ["", "{0} hora retro", "", "", "", "{0} horas retro"] };
+
+#[rustfmt::skip]
+static _FIELD_849: _RelativeField = _RelativeField { relatives: &[(0, "iste hora")], future: ["", "in {0} hr.", "", "", "", "in {0} hr."], past: ["", "{0} hr. retro", "", "", "", "{0} hr. retro"] };
+
+#[rustfmt::skip]
+static _FIELD_850: _RelativeField = _RelativeField { relatives: &[(-1, "heri"), (0, "hodie"), (1, "deman")], future: ["", "in {0} die", "", "", "", "in {0} dies"], past: ["", "{0} die retro", "", "", "", "{0} dies retro"] };
+
+#[rustfmt::skip]
+static _FIELD_851: _RelativeField = _RelativeField { relatives: &[(-1, "le septimana passate"), (0, "iste septimana"), (1, "le septimana proxime")], future: ["", "in {0} septimana", "", "", "", "in {0} septimanas"], past: ["", "{0} septimana retro", "", "", "", "{0} septimanas retro"] };
+
+#[rustfmt::skip]
+static _FIELD_852: _RelativeField = _RelativeField { relatives: &[(-1, "sept. pass."), (0, "iste sept."), (1, "sept. prox.")], future: ["", "in {0} sept.", "", "", "", "in {0} sept."], past: ["", "{0} sept. retro", "", "", "", "{0} sept. retro"] };
+
+#[rustfmt::skip]
+static _FIELD_853: _RelativeField = _RelativeField { relatives: &[(-1, "le mense passate"), (0, "iste mense"), (1, "le mense proxime")], future: ["", "in {0} mense", "", "", "", "in {0} menses"], past: ["", "{0} mense retro", "", "", "", "{0} menses retro"] };
+
+#[rustfmt::skip]
+static _FIELD_854: _RelativeField = _RelativeField { relatives: &[(-1, "mns. pass."), (0, "iste mns."), (1, "mns. prox.")], future: ["", "in {0} mns.", "", "", "", "in {0} mns."], past: ["", "{0} mns. retro", "", "", "", "{0} mns. retro"] };
+
+#[rustfmt::skip]
+static _FIELD_855: _RelativeField = _RelativeField { relatives: &[(-1, "le trimestre passate"), (0, "iste trimestre"), (1, "le trimestre proxime")], future: ["", "in {0} trimestre", "", "", "", "in {0} trimestres"], past: ["", "{0} trimestre retro", "", "", "", "{0} trimestres retro"] };
+
+#[rustfmt::skip]
+static _FIELD_856: _RelativeField = _RelativeField { relatives: &[(-1, "le trimestre passate"), (0, "iste trimestre"), (1, "le trimestre proxime")], future: ["", "in {0} trim.", "", "", "", "in {0} trim."], past: ["", "{0} trim. retro", "", "", "", "{0} trim. retro"] };
+
+#[rustfmt::skip]
+static _FIELD_857: _RelativeField = _RelativeField { relatives: &[(-1, "le anno passate"), (0, "iste anno"), (1, "le anno proxime")], future: ["", "in {0} anno", "", "", "", "in {0} annos"], past: ["", "{0} anno retro", "", "", "", "{0} annos retro"] };
+
+#[rustfmt::skip]
+static _FIELD_858: _RelativeField = _RelativeField { relatives: &[(-1, "an. pass."), (0, "iste an."), (1, "an. prox.")], future: ["", "in {0} an.", "", "", "", "in {0} an."], past: ["", "{0} an. retro", "", "", "", "{0} an. retro"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_67: [&_RelativeField; 24] = [
+    &_FIELD_844,
+    &_FIELD_845,
+    &_FIELD_845,
+    &_FIELD_846,
+    &_FIELD_847,
+    &_FIELD_847,
+    &_FIELD_848,
+    &_FIELD_849,
+    &_FIELD_849,
+    &_FIELD_850,
+    &_FIELD_850,
+    &_FIELD_850,
+    &_FIELD_851,
+    &_FIELD_852,
+    &_FIELD_852,
+    &_FIELD_853,
+    &_FIELD_854,
+    &_FIELD_854,
+    &_FIELD_855,
+    &_FIELD_856,
+    &_FIELD_856,
+    &_FIELD_857,
+    &_FIELD_858,
+    &_FIELD_858
+];
+
+#[rustfmt::skip]
+static _FIELD_859: _RelativeField = _RelativeField { relatives: &[(0, "sekarang")], future: ["", "", "", "", "", "dalam {0} detik"], past: ["", "", "", "", "", "{0} detik yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_860: _RelativeField = _RelativeField { relatives: &[(0, "sekarang")], future: ["", "", "", "", "", "dlm {0} dtk"], past: ["", "", "", "", "", "{0} dtk lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_861: _RelativeField = _RelativeField { relatives: &[(0, "menit ini")], future: ["", "", "", "", "", "dalam {0} menit"], past: ["", "", "", "", "", "{0} menit yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_862: _RelativeField = _RelativeField { relatives: &[(0, "mnt ini")], future: ["", "", "", "", "", "dlm {0} mnt"], past: ["", "", "", "", "", "{0} mnt lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_863: _RelativeField = _RelativeField { relatives: &[(0, "jam ini")], future: ["", "", "", "", "", "dalam {0} jam"], past: ["", "", "", "", "", "{0} jam yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_864: _RelativeField = _RelativeField { relatives: &[(0, "jam ini")], future: ["", "", "", "", "", "dalam {0} jam"], past: ["", "", "", "", "", "{0} jam lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_865: _RelativeField = _RelativeField { relatives: &[(0, "jam ini")], future: ["", "", "", "", "", "dlm {0} jam"], past: ["", "", "", "", "", "{0} jam lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_866: _RelativeField = _RelativeField { relatives: &[(-2, "kemarin dulu"), (-1, "kemarin"), (0, "hari ini"), (1, "besok"), (2, "lusa")], future: ["", "", "", "", "", "dalam {0} hari"], past: ["", "", "", "", "", "{0} hari yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_867: _RelativeField = _RelativeField { relatives: &[(-2, "selumbari"), (-1, "kemarin"), (0, "hari ini"), (1, "besok"), (2, "lusa")], future: ["", "", "", "", "", "dalam {0} h"], past: ["", "", "", "", "", "{0} h lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_868: _RelativeField = _RelativeField { relatives: &[(-1, "minggu lalu"), (0, "minggu ini"), (1, "minggu depan")], future: ["", "", "", "", "", "dalam {0} minggu"], past: ["", "", "", "", "", "{0} minggu yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_869: _RelativeField = _RelativeField { relatives: &[(-1, "mgg lalu"), (0, "mgg ini"), (1, "mgg depan")], future: ["", "", "", "", "", "dlm {0} mgg"], past: ["", "", "", "", "", "{0} mgg lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_870: _RelativeField = _RelativeField { relatives: &[(-1, "bulan lalu"), (0, "bulan ini"), (1, "bulan depan")], future: ["", "", "", "", "", "dalam {0} bulan"], past: ["", "", "", "", "", "{0} bulan yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_871: _RelativeField = _RelativeField { relatives: &[(-1, "bln lalu"), (0, "bln ini"), (1, "bln berikutnya")], future: ["", "", "", "", "", "dlm {0} bln"], past: ["", "", "", "", "", "{0} bln lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_872: _RelativeField = _RelativeField { relatives: &[(-1, "Kuartal lalu"), (0, "kuartal ini"), (1, "kuartal berikutnya")], future: ["", "", "", "", "", "dalam {0} kuartal"], past: ["", "", "", "", "", "{0} kuartal yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_873: _RelativeField = _RelativeField { relatives: &[(-1, "krtl lalu"), (0, "krtl ini"), (1, "krtl berikutnya")], future: ["", "", "", "", "", "dlm {0} krtl."], past: ["", "", "", "", "", "{0} krtl. lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_874: _RelativeField = _RelativeField { relatives: &[(-1, "tahun lalu"), (0, "tahun ini"), (1, "tahun depan")], future: ["", "", "", "", "", "dalam {0} tahun"], past: ["", "", "", "", "", "{0} tahun yang lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_875: _RelativeField = _RelativeField { relatives: &[(-1, "thn lalu"), (0, "thn ini"), (1, "thn depan")], future: ["", "", "", "", "", "dlm {0} thn"], past: ["", "", "", "", "", "{0} thn lalu"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_68: [&_RelativeField; 24] = [
+    &_FIELD_859,
+    &_FIELD_860,
+    &_FIELD_860,
+    &_FIELD_861,
+    &_FIELD_862,
+    &_FIELD_862,
+    &_FIELD_863,
+    &_FIELD_864,
+    &_FIELD_865,
+    &_FIELD_866,
+    &_FIELD_867,
+    &_FIELD_867,
+    &_FIELD_868,
+    &_FIELD_869,
+    &_FIELD_869,
+    &_FIELD_870,
+    &_FIELD_871,
+    &_FIELD_871,
+    &_FIELD_872,
+    &_FIELD_873,
+    &_FIELD_873,
+    &_FIELD_874,
+    &_FIELD_875,
+    &_FIELD_875
+];
+
+#[rustfmt::skip]
+static _FIELD_876: _RelativeField = _RelativeField { relatives: &[(-1, "yer"), (0, "hodie"), (1, "deman")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_877: _RelativeField = _RelativeField { relatives: &[(-1, "ultim annu"), (0, "ho-annu"), (1, "sequent annu")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_69: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_876,
+    &_FIELD_876,
+    &_FIELD_876,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_877,
+    &_FIELD_877,
+    &_FIELD_877
+];
+
+#[rustfmt::skip]
+static _FIELD_878: _RelativeField = _RelativeField { relatives: &[(0, "ụgbụa")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_879: _RelativeField = _RelativeField { relatives: &[(0, "nkejị a")], future: ["", "", "", "", "", "+{0} min"], past: ["", "", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_880: _RelativeField = _RelativeField { relatives: &[(0, "elekere a")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_881: _RelativeField = _RelativeField { relatives: &[(-1, "Ụnyaahụ"), (0, "Taa"), (1, "Echi")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_882: _RelativeField = _RelativeField { relatives: &[(-1, "Ụnyaahụ"), (0, "Taata"), (1, "Echi")], future: ["", "", "", "", "", "n’ụbọchị {0}"], past: ["", "", "", "", "", "n’ụbọchị {0} gara aga"] };
+
+#[rustfmt::skip]
+static _FIELD_883: _RelativeField = _RelativeField { relatives: &[(-1, "Izu gara aga"), (0, "Izu a"), (1, "Izu na-esote")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_884: _RelativeField = _RelativeField { relatives: &[(-1, "Ọnwa gara aga"), (0, "Ọnwa a"), (1, "Ọnwa na-abịa")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_885: _RelativeField = _RelativeField { relatives: &[(-1, "Nkejị keanọ gara aga"), (0, "nkejị keanọ a"), (1, "nkejị keanọ na abịa")], future: ["", "", "", "", "", "Nkejị keanọ {0}"], past: ["", "", "", "", "", "Nkejị keanọ {0} gara aga"] };
+
+#[rustfmt::skip]
+static _FIELD_886: _RelativeField = _RelativeField { relatives: &[(-1, "Afọ gara aga"), (0, "Afọ a"), (1, "Afọ na-abịa")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_887: _RelativeField = _RelativeField { relatives: &[(-1, "Afọ gara aga"), (0, "Afọ a"), (1, "afọ na-abịa")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_888: _RelativeField = _RelativeField { relatives: &[(-1, "Afọ gara aga"), (0, "Afọ a"), (1, "afọ na-abịa")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "Afọ {0} gara aga"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_70: [&_RelativeField; 24] = [
+    &_FIELD_878,
+    &_FIELD_878,
+    &_FIELD_878,
+    &_FIELD_879,
+    &_FIELD_879,
+    &_FIELD_879,
+    &_FIELD_880,
+    &_FIELD_880,
+    &_FIELD_880,
+    &_FIELD_881,
+    &_FIELD_882,
+    &_FIELD_882,
+    &_FIELD_883,
+    &_FIELD_883,
+    &_FIELD_883,
+    &_FIELD_884,
+    &_FIELD_884,
+    &_FIELD_884,
+    &_FIELD_885,
+    &_FIELD_885,
+    &_FIELD_885,
+    &_FIELD_886,
+    &_FIELD_887,
+    &_FIELD_888
+];
+
+#[rustfmt::skip]
+static _FIELD_889: _RelativeField = _RelativeField { relatives: &[(-2, "ꎴꂿꋍꑍ"), (-1, "ꀋꅔꉇ"), (0, "ꀃꑍ"), (1, "ꃆꏂꑍ"), (2, "ꌕꀿꑍ")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_890: _RelativeField = _RelativeField { relatives: &[(-1, "ꆏꊂꂴꂶꌠ"), (0, "ꀋꃋꋋꆏꊂ"), (1, "ꆏꊂꊁꂶꌠ")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_891: _RelativeField = _RelativeField { relatives: &[(-1, "ꂴꁮꆪ"), (0, "ꀋꃋꋋꁮꆪ"), (1, "ꊁꁮꆪ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_892: _RelativeField = _RelativeField { relatives: &[(-1, "ꀋꉊꄹꈎ"), (0, "ꀋꃋꎼꈎ"), (1, "ꑎꉐꄻꈎ")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_893: _RelativeField = _RelativeField { relatives: &[(-1, "ꀋꉊꄹꈎ"), (0, "ꎼꈎ"), (1, "ꑎꉐꄻꈎ")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_71: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_889,
+    &_FIELD_889,
+    &_FIELD_889,
+    &_FIELD_890,
+    &_FIELD_890,
+    &_FIELD_890,
+    &_FIELD_891,
+    &_FIELD_891,
+    &_FIELD_891,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_892,
+    &_FIELD_893,
+    &_FIELD_893
+];
+
+#[rustfmt::skip]
+static _FIELD_894: _RelativeField = _RelativeField { relatives: &[(0, "núna")], future: ["", "eftir {0} sekúndu", "", "", "", "eftir {0} sekúndur"], past: ["", "fyrir {0} sekúndu", "", "", "", "fyrir {0} sekúndum"] };
+
+#[rustfmt::skip]
+static _FIELD_895: _RelativeField = _RelativeField { relatives: &[(0, "núna")], future: ["", "eftir {0} sek.", "", "", "", "eftir {0} sek."], past: ["", "fyrir {0} sek.", "", "", "", "fyrir {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_896: _RelativeField = _RelativeField { relatives: &[(0, "núna")], future: ["", "+{0} sek.", "", "", "", "+{0} sek."], past: ["", "-{0} sek.", "", "", "", "-{0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_897: _RelativeField = _RelativeField { relatives: &[(0, "á þessari mínútu")], future: ["", "eftir {0} mínútu", "", "", "", "eftir {0} mínútur"], past: ["", "fyrir {0} mínútu", "", "", "", "fyrir {0} mínútum"] };
+
+#[rustfmt::skip]
+static _FIELD_898: _RelativeField = _RelativeField { relatives: &[(0, "á þessari mínútu")], future: ["", "eftir {0} mín.", "", "", "", "eftir {0} mín."], past: ["", "fyrir {0} mín.", "", "", "", "fyrir {0} mín."] };
+
+#[rustfmt::skip]
+static _FIELD_899: _RelativeField = _RelativeField { relatives: &[(0, "á þessari mínútu")], future: ["", "+{0} mín.", "", "", "", "+{0} mín."], past: ["", "-{0} mín.", "", "", "", "-{0} mín."] };
+
+#[rustfmt::skip]
+static _FIELD_900: _RelativeField = _RelativeField { relatives: &[(0, "þessa stundina")], future: ["", "eftir {0} klukkustund", "", "", "", "eftir {0} klukkustundir"], past: ["", "fyrir {0} klukkustund", "", "", "", "fyrir {0} klukkustundum"] };
+
+#[rustfmt::skip]
+static _FIELD_901: _RelativeField = _RelativeField { relatives: &[(0, "þessa stundina")], future: ["", "eftir {0} klst.", "", "", "", "eftir {0} klst."], past: ["", "fyrir {0} klst.", "", "", "", "fyrir {0} klst."] };
+
+#[rustfmt::skip]
+static _FIELD_902: _RelativeField = _RelativeField { relatives: &[(0, "þessa stundina")], future: ["", "+{0} klst.", "", "", "", "+{0} klst."], past: ["", "-{0} klst.", "", "", "", "-{0} klst."] };
+
+#[rustfmt::skip]
+static _FIELD_903: _RelativeField = _RelativeField { relatives: &[(-2, "í fyrradag"), (-1, "í gær"), (0, "í dag"), (1, "á morgun"), (2, "eftir tvo daga")], future: ["", "eftir {0} dag", "", "", "", "eftir {0} daga"], past: ["", "fyrir {0} degi", "", "", "", "fyrir {0} dögum"] };
+
+#[rustfmt::skip]
+static _FIELD_904: _RelativeField = _RelativeField { relatives: &[(-1, "í síðustu viku"), (0, "í þessari viku"), (1, "í næstu viku")], future: ["", "eftir {0} viku", "", "", "", "eftir {0} vikur"], past: ["", "fyrir {0} viku", "", "", "", "fyrir {0} vikum"] };
+
+#[rustfmt::skip]
+static _FIELD_905: _RelativeField = _RelativeField { relatives: &[(-1, "í síðasta mánuði"), (0, "í þessum mánuði"), (1, "í næsta mánuði")], future: ["", "eftir {0} mánuð", "", "", "", "eftir {0} mánuði"], past: ["", "fyrir {0} mánuði", "", "", "", "fyrir {0} mánuðum"] };
+
+#[rustfmt::skip]
+static _FIELD_906: _RelativeField = _RelativeField { relatives: &[(-1, "í síðasta mán."), (0, "í þessum mán."), (1, "í næsta mán.")], future: ["", "eftir {0} mán.", "", "", "", "eftir {0} mán."], past: ["", "fyrir {0} mán.", "", "", "", "fyrir {0} mán."] };
+
+#[rustfmt::skip]
+static _FIELD_907: _RelativeField = _RelativeField { relatives: &[(-1, "síðasti ársfjórðungur"), (0, "þessi ársfjórðungur"), (1, "næsti ársfjórðungur")], future: ["", "eftir {0} ársfjórðung", "", "", "", "eftir {0} ársfjórðunga"], past: ["", "fyrir {0} ársfjórðungi", "", "", "", "fyrir {0} ársfjórðungum"] };
+
+#[rustfmt::skip]
+static _FIELD_908: _RelativeField = _RelativeField { relatives: &[(-1, "síðasti ársfj."), (0, "þessi ársfj."), (1, "næsti ársfj.")], future: ["", "eftir {0} ársfj.", "", "", "", "eftir {0} ársfj."], past: ["", "fyrir {0} ársfj.", "", "", "", "fyrir {0} ársfj."] };
+
+#[rustfmt::skip]
+static _FIELD_909: _RelativeField = _RelativeField { relatives: &[(-1, "á síðasta ári"), (0, "á þessu ári"), (1, "á næsta ári")], future: ["", "eftir {0} ár", "", "", "", "eftir {0} ár"], past: ["", "fyrir {0} ári", "", "", "", "fyrir {0} árum"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_72: [&_RelativeField; 24] = [
+    &_FIELD_894,
+    &_FIELD_895,
+    &_FIELD_896,
+    &_FIELD_897,
+    &_FIELD_898,
+    &_FIELD_899,
+    &_FIELD_900,
+    &_FIELD_901,
+    &_FIELD_902,
+    &_FIELD_903,
+    &_FIELD_903,
+    &_FIELD_903,
+    &_FIELD_904,
+    &_FIELD_904,
+    &_FIELD_904,
+    &_FIELD_905,
+    &_FIELD_906,
+    &_FIELD_906,
+    &_FIELD_907,
+    &_FIELD_908,
+    &_FIELD_908,
+    &_FIELD_909,
+    &_FIELD_909,
+    &_FIELD_909
+];
+
+#[rustfmt::skip]
+static _FIELD_910: _RelativeField = _RelativeField { relatives: &[(0, "ora")], future: ["", "tra {0} secondo", "", "", "", "tra {0} secondi"], past: ["", "{0} secondo fa", "", "", "", "{0} secondi fa"] };
+
+#[rustfmt::skip]
+static _FIELD_911: _RelativeField = _RelativeField { relatives: &[(0, "ora")], future: ["", "tra {0} sec.", "", "", "", "tra {0} sec."], past: ["", "{0} sec. fa", "", "", "", "{0} sec. fa"] };
+
+#[rustfmt::skip]
+static _FIELD_912: _RelativeField = _RelativeField { relatives: &[(0, "ora")], future: ["", "tra {0} s", "", "", "", "tra {0} s"], past: ["", "{0} s fa", "", "", "", "{0} s fa"] };
+
+#[rustfmt::skip]
+static _FIELD_913: _RelativeField = _RelativeField { relatives: &[(0, "questo minuto")], future: ["", "tra {0} minuto", "", "", "", "tra {0} minuti"], past: ["", "{0} minuto fa", "", "", "", "{0} minuti fa"] };
+
+#[rustfmt::skip]
+static _FIELD_914: _RelativeField = _RelativeField { relatives: &[(0, "questo minuto")], future: ["", "tra {0} min", "", "", "", "tra {0} min"], past: ["", "{0} min fa", "", "", "", "{0} min fa"] };
+
+#[rustfmt::skip]
+static _FIELD_915: _RelativeField = _RelativeField { relatives: &[(0, "quest’ora")], future: ["", "tra {0} ora", "", "", "", "tra {0} ore"], past: ["", "{0} ora fa", "", "", "", "{0} ore fa"] };
+
+#[rustfmt::skip]
+static _FIELD_916: _RelativeField = _RelativeField { relatives: &[(0, "quest’ora")], future: ["", "tra {0} h", "", "", "", "tra {0} h"], past: ["", "{0} h fa", "", "", "", "{0} h fa"] };
+
+#[rustfmt::skip]
+static _FIELD_917: _RelativeField = _RelativeField { relatives: &[(-2, "l’altro ieri"), (-1, "ieri"), (0, "oggi"), (1, "domani"), (2, "dopodomani")], future: ["", "tra {0} giorno", "", "", "", "tra {0} giorni"], past: ["", "{0} giorno fa", "", "", "", "{0} giorni fa"] };
+
+#[rustfmt::skip]
+static _FIELD_918: _RelativeField = _RelativeField { relatives: &[(-2, "l’altro ieri"), (-1, "ieri"), (0, "oggi"), (1, "domani"), (2, "dopodomani")], future: ["", "tra {0} g", "", "", "", "tra {0} gg"], past: ["", "{0} g fa", "", "", "", "{0} gg fa"] };
+
+#[rustfmt::skip]
+static _FIELD_919: _RelativeField = _RelativeField { relatives: &[(-1, "settimana scorsa"), (0, "questa settimana"), (1, "settimana prossima")], future: ["", "tra {0} settimana", "", "", "", "tra {0} settimane"], past: ["", "{0} settimana fa", "", "", "", "{0} settimane fa"] };
+
+#[rustfmt::skip]
+static _FIELD_920: _RelativeField = _RelativeField { relatives: &[(-1, "sett. scorsa"), (0, "questa sett."), (1, "sett. prossima")], future: ["", "tra {0} sett.", "", "", "", "tra {0} sett."], past: ["", "{0} sett. fa", "", "", "", "{0} sett. fa"] };
+
+#[rustfmt::skip]
+static _FIELD_921: _RelativeField = _RelativeField { relatives: &[(-1, "mese scorso"), (0, "questo mese"), (1, "mese prossimo")], future: ["", "tra {0} mese", "", "", "", "tra {0} mesi"], past: ["", "{0} mese fa", "", "", "", "{0} mesi fa"] };
+
+#[rustfmt::skip]
+static _FIELD_922: _RelativeField = _RelativeField { relatives: &[(-1, "trimestre scorso"), (0, "questo trimestre"), (1, "trimestre prossimo")], future: ["", "tra {0} trimestre", "", "", "", "tra {0} trimestri"], past: ["", "{0} trimestre fa", "", "", "", "{0} trimestri fa"] };
+
+#[rustfmt::skip]
+static _FIELD_923: _RelativeField = _RelativeField { relatives: &[(-1, "trim. scorso"), (0, "questo trim."), (1, "trim. prossimo")], future: ["", "tra {0} trim.", "", "", "", "tra {0} trim."], past: ["", "{0} trim. fa", "", "", "", "{0} trim. fa"] };
+
+#[rustfmt::skip]
+static _FIELD_924: _RelativeField = _RelativeField { relatives: &[(-1, "anno scorso"), (0, "quest’anno"), (1, "anno prossimo")], future: ["", "tra {0} anno", "", "", "", "tra {0} anni"], past: ["", "{0} anno fa", "", "", "", "{0} anni fa"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_73: [&_RelativeField; 24] = [
+    &_FIELD_910,
+    &_FIELD_911,
+    &_FIELD_912,
+    &_FIELD_913,
+    &_FIELD_914,
+    &_FIELD_914,
+    &_FIELD_915,
+    &_FIELD_916,
+    &_FIELD_916,
+    &_FIELD_917,
+    &_FIELD_918,
+    &_FIELD_918,
+    &_FIELD_919,
+    &_FIELD_920,
+    &_FIELD_920,
+    &_FIELD_921,
+    &_FIELD_921,
+    &_FIELD_921,
+    &_FIELD_922,
+    &_FIELD_923,
+    &_FIELD_923,
+    &_FIELD_924,
+    &_FIELD_924,
+    &_FIELD_924
+];
+
+#[rustfmt::skip]
+static _FIELD_925: _RelativeField = _RelativeField { relatives: &[(0, "今")], future: ["", "", "", "", "", "{0} 秒後"], past: ["", "", "", "", "", "{0} 秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_926: _RelativeField = _RelativeField { relatives: &[(0, "今")], future: ["", "", "", "", "", "{0}秒後"], past: ["", "", "", "", "", "{0}秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_927: _RelativeField = _RelativeField { relatives: &[(0, "1 分以内")], future: ["", "", "", "", "", "{0} 分後"], past: ["", "", "", "", "", "{0} 分前"] };
+
+#[rustfmt::skip]
+static _FIELD_928: _RelativeField = _RelativeField { relatives: &[(0, "1 分以内")], future: ["", "", "", "", "", "{0}分後"], past: ["", "", "", "", "", "{0}分前"] };
+
+#[rustfmt::skip]
+static _FIELD_929: _RelativeField = _RelativeField { relatives: &[(0, "1 時間以内")], future: ["", "", "", "", "", "{0} 時間後"], past: ["", "", "", "", "", "{0} 時間前"] };
+
+#[rustfmt::skip]
+static _FIELD_930: _RelativeField = _RelativeField { relatives: &[(0, "1 時間以内")], future: ["", "", "", "", "", "{0}時間後"], past: ["", "", "", "", "", "{0}時間前"] };
+
+#[rustfmt::skip]
+static _FIELD_931: _RelativeField = _RelativeField { relatives: &[(-2, "一昨日"), (-1, "昨日"), (0, "今日"), (1, "明日"), (2, "明後日")], future: ["", "", "", "", "", "{0} 日後"], past: ["", "", "", "", "", "{0} 日前"] };
+
+#[rustfmt::skip]
+static _FIELD_932: _RelativeField = _RelativeField { relatives: &[(-2, "一昨日"), (-1, "昨日"), (0, "今日"), (1, "明日"), (2, "明後日")], future: ["", "", "", "", "", "{0}日後"], past: ["", "", "", "", "", "{0}日前"] };
+
+#[rustfmt::skip]
+static _FIELD_933: _RelativeField = _RelativeField { relatives: &[(-1, "先週"), (0, "今週"), (1, "来週")], future: ["", "", "", "", "", "{0} 週間後"], past: ["", "", "", "", "", "{0} 週間前"] };
+
+#[rustfmt::skip]
+static _FIELD_934: _RelativeField = _RelativeField { relatives: &[(-1, "先週"), (0, "今週"), (1, "来週")], future: ["", "", "", "", "", "{0}週間後"], past: ["", "", "", "", "", "{0}週間前"] };
+
+#[rustfmt::skip]
+static _FIELD_935: _RelativeField = _RelativeField { relatives: &[(-1, "先月"), (0, "今月"), (1, "来月")], future: ["", "", "", "", "", "{0} か月後"], past: ["", "", "", "", "", "{0} か月前"] };
+
+#[rustfmt::skip]
+static _FIELD_936: _RelativeField = _RelativeField { relatives: &[(-1, "先月"), (0, "今月"), (1, "来月")], future: ["", "", "", "", "", "{0}か月後"], past: ["", "", "", "", "", "{0}か月前"] };
+
+#[rustfmt::skip]
+static _FIELD_937: _RelativeField = _RelativeField { relatives: &[(-1, "前四半期"), (0, "今四半期"), (1, "翌四半期")], future: ["", "", "", "", "", "{0} 四半期後"], past: ["", "", "", "", "", "{0} 四半期前"] };
+
+#[rustfmt::skip]
+static _FIELD_938: _RelativeField = _RelativeField { relatives: &[(-1, "前四半期"), (0, "今四半期"), (1, "翌四半期")], future: ["", "", "", "", "", "{0}四半期後"], past: ["", "", "", "", "", "{0}四半期前"] };
+
+#[rustfmt::skip]
+static _FIELD_939: _RelativeField = _RelativeField { relatives: &[(-1, "昨年"), (0, "今年"), (1, "来年")], future: ["", "", "", "", "", "{0} 年後"], past: ["", "", "", "", "", "{0} 年前"] };
+
+#[rustfmt::skip]
+static _FIELD_940: _RelativeField = _RelativeField { relatives: &[(-1, "昨年"), (0, "今年"), (1, "来年")], future: ["", "", "", "", "", "{0}年後"], past: ["", "", "", "", "", "{0}年前"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_74: [&_RelativeField; 24] = [
+    &_FIELD_925,
+    &_FIELD_925,
+    &_FIELD_926,
+    &_FIELD_927,
+    &_FIELD_927,
+    &_FIELD_928,
+    &_FIELD_929,
+    &_FIELD_929,
+    &_FIELD_930,
+    &_FIELD_931,
+    &_FIELD_931,
+    &_FIELD_932,
+    &_FIELD_933,
+    &_FIELD_933,
+    &_FIELD_934,
+    &_FIELD_935,
+    &_FIELD_935,
+    &_FIELD_936,
+    &_FIELD_937,
+    &_FIELD_937,
+    &_FIELD_938,
+    &_FIELD_939,
+    &_FIELD_939,
+    &_FIELD_940
+];
+
+#[rustfmt::skip]
+static _FIELD_941: _RelativeField = _RelativeField { relatives: &[(0, "saiki")], future: ["", "", "", "", "", "ing {0} detik"], past: ["", "", "", "", "", "{0} detik kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_942: _RelativeField = _RelativeField { relatives: &[(0, "menit iki")], future: ["", "", "", "", "", "ing {0} menit"], past: ["", "", "", "", "", "{0} menit kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_943: _RelativeField = _RelativeField { relatives: &[(0, "jam iki")], future: ["", "", "", "", "", "ing {0} jam"], past: ["", "", "", "", "", "{0} jam kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_944: _RelativeField = _RelativeField { relatives: &[(-1, "wingi"), (0, "dino iki"), (1, "sesuk")], future: ["", "", "", "", "", "ing {0} dina"], past: ["", "", "", "", "", "{0} dina kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_945: _RelativeField = _RelativeField { relatives: &[(-1, "wingi"), (0, "dino iki"), (1, "sesuk")], future: ["", "", "", "", "", "ing {0} dina"], past: ["", "", "", "", "", "{0} dino kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_946: _RelativeField = _RelativeField { relatives: &[(-1, "pekan wingi"), (0, "pekan iki"), (1, "pekan ngarep")], future: ["", "", "", "", "", "ing {0} pekan"], past: ["", "", "", "", "", "{0} pekan kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_947: _RelativeField = _RelativeField { relatives: &[(-1, "pkn wingi"), (0, "pkn iki"), (1, "pkn ngarep")], future: ["", "", "", "", "", "ing {0} pekan"], past: ["", "", "", "", "", "{0} pekan kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_948: _RelativeField = _RelativeField { relatives: &[(-1, "sasi wingi"), (0, "sasi iki"), (1, "sasi ngarep")], future: ["", "", "", "", "", "ing {0} sasi"], past: ["", "", "", "", "", "{0} sasi kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_949: _RelativeField = _RelativeField { relatives: &[(-1, "triwulan wingi"), (0, "triwulan iki"), (1, "triwulan ngarep")], future: ["", "", "", "", "", "ing {0} triwulan"], past: ["", "", "", "", "", "{0} triwulan kepungkur"] };
+
+#[rustfmt::skip]
+static _FIELD_950: _RelativeField = _RelativeField { relatives: &[(-1, "taun wingi"), (0, "taun iki"), (1, "taun ngarep")], future: ["", "", "", "", "", "ing {0} taun"], past: ["", "", "", "", "", "{0} taun kepungkur"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_75: [&_RelativeField; 24] = [
+    &_FIELD_941,
+    &_FIELD_941,
+    &_FIELD_941,
+    &_FIELD_942,
+    &_FIELD_942,
+    &_FIELD_942,
+    &_FIELD_943,
+    &_FIELD_943,
+    &_FIELD_943,
+    &_FIELD_944,
+    &_FIELD_944,
+    &_FIELD_945,
+    &_FIELD_946,
+    &_FIELD_947,
+    &_FIELD_947,
+    &_FIELD_948,
+    &_FIELD_948,
+    &_FIELD_948,
+    &_FIELD_949,
+    &_FIELD_949,
+    &_FIELD_949,
+    &_FIELD_950,
+    &_FIELD_950,
+    &_FIELD_950
+];
+
+#[rustfmt::skip]
+static _FIELD_951: _RelativeField = _RelativeField { relatives: &[(0, "ახლა")], future: ["", "{0} წამში", "", "", "", "{0} წამში"], past: ["", "{0} წამის წინ", "", "", "", "{0} წამის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_952: _RelativeField = _RelativeField { relatives: &[(0, "ახლა")], future: ["", "{0} წამში", "", "", "", "{0} წამში"], past: ["", "{0} წმ წინ", "", "", "", "{0} წმ წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_953: _RelativeField = _RelativeField { relatives: &[(0, "ამ წუთში")], future: ["", "{0} წუთში", "", "", "", "{0} წუთში"], past: ["", "{0} წუთის წინ", "", "", "", "{0} წუთის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_954: _RelativeField = _RelativeField { relatives: &[(0, "ამ წუთში")], future: ["", "{0} წუთში", "", "", "", "{0} წუთში"], past: ["", "{0} წთ წინ", "", "", "", "{0} წთ წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_955: _RelativeField = _RelativeField { relatives: &[(0, "ამ საათში")], future: ["", "{0} საათში", "", "", "", "{0} საათში"], past: ["", "{0} საათის წინ", "", "", "", "{0} საათის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_956: _RelativeField = _RelativeField { relatives: &[(0, "ამ საათში")], future: ["", "{0} საათში", "", "", "", "{0} საათში"], past: ["", "{0} სთ წინ", "", "", "", "{0} სთ წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_957: _RelativeField = _RelativeField { relatives: &[(-2, "გუშინწინ"), (-1, "გუშინ"), (0, "დღეს"), (1, "ხვალ"), (2, "ზეგ")], future: ["", "{0} დღეში", "", "", "", "{0} დღეში"], past: ["", "{0} დღის წინ", "", "", "", "{0} დღის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_958: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ კვირაში"), (0, "ამ კვირაში"), (1, "მომავალ კვირაში")], future: ["", "{0} კვირაში", "", "", "", "{0} კვირაში"], past: ["", "{0} კვირის წინ", "", "", "", "{0} კვირის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_959: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ კვირაში"), (0, "ამ კვირაში"), (1, "მომავალ კვირაში")], future: ["", "{0} კვირაში", "", "", "", "{0} კვირაში"], past: ["", "{0} კვ. წინ", "", "", "", "{0} კვ. წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_960: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ თვეს"), (0, "ამ თვეში"), (1, "მომავალ თვეს")], future: ["", "{0} თვეში", "", "", "", "{0} თვეში"], past: ["", "{0} თვის წინ", "", "", "", "{0} თვის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_961: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ კვარტალში"), (0, "ამ კვარტალში"), (1, "შემდეგ კვარტალში")], future: ["", "{0} კვარტალში", "", "", "", "{0} კვარტალში"], past: ["", "{0} კვარტალის წინ", "", "", "", "{0} კვარტალის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_962: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ კვარტალში"), (0, "ამ კვარტალში"), (1, "შემდეგ კვარტალში")], future: ["", "{0} კვარტალში", "", "", "", "{0} კვარტალში"], past: ["", "{0} კვარტ. წინ", "", "", "", "{0} კვარტ. წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_963: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ წელს"), (0, "ამ წელს"), (1, "მომავალ წელს")], future: ["", "{0} წელიწადში", "", "", "", "{0} წელიწადში"], past: ["", "{0} წლის წინ", "", "", "", "{0} წლის წინ"] };
+
+#[rustfmt::skip]
+static _FIELD_964: _RelativeField = _RelativeField { relatives: &[(-1, "გასულ წელს"), (0, "ამ წელს"), (1, "მომავალ წელს")], future: ["", "{0} წელში", "", "", "", "{0} წელში"], past: ["", "{0} წლის წინ", "", "", "", "{0} წლის წინ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_76: [&_RelativeField; 24] = [
+    &_FIELD_951,
+    &_FIELD_952,
+    &_FIELD_952,
+    &_FIELD_953,
+    &_FIELD_954,
+    &_FIELD_954,
+    &_FIELD_955,
+    &_FIELD_956,
+    &_FIELD_956,
+    &_FIELD_957,
+    &_FIELD_957,
+    &_FIELD_957,
+    &_FIELD_958,
+    &_FIELD_959,
+    &_FIELD_958,
+    &_FIELD_960,
+    &_FIELD_960,
+    &_FIELD_960,
+    &_FIELD_961,
+    &_FIELD_962,
+    &_FIELD_962,
+    &_FIELD_963,
+    &_FIELD_964,
+    &_FIELD_964
+];
+
+#[rustfmt::skip]
+static _FIELD_965: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "", "", "", "", "di li {0} sigundu"], past: ["", "", "", "", "", "a ten {0} sigundu"] };
+
+#[rustfmt::skip]
+static _FIELD_966: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "", "", "", "", "di li {0} sig"], past: ["", "", "", "", "", "a ten {0} sig"] };
+
+#[rustfmt::skip]
+static _FIELD_967: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "", "", "", "", "di li {0} s"], past: ["", "", "", "", "", "a ten {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_968: _RelativeField = _RelativeField { relatives: &[(0, "es minutu li")], future: ["", "", "", "", "", "di li {0} minutu"], past: ["", "", "", "", "", "a ten {0} minutu"] };
+
+#[rustfmt::skip]
+static _FIELD_969: _RelativeField = _RelativeField { relatives: &[(0, "es minutu li")], future: ["", "", "", "", "", "di li {0} min"], past: ["", "", "", "", "", "a ten {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_970: _RelativeField = _RelativeField { relatives: &[(0, "es minutu li")], future: ["", "", "", "", "", "di li {0} m"], past: ["", "", "", "", "", "a ten {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_971: _RelativeField = _RelativeField { relatives: &[(0, "es ora li")], future: ["", "", "", "", "", "di li {0} ora"], past: ["", "", "", "", "", "a ten {0} ora"] };
+
+#[rustfmt::skip]
+static _FIELD_972: _RelativeField = _RelativeField { relatives: &[(-1, "onti"), (0, "oji"), (1, "manhan")], future: ["", "", "", "", "", "di li {0} dia"], past: ["", "", "", "", "", "a ten {0} dia"] };
+
+#[rustfmt::skip]
+static _FIELD_973: _RelativeField = _RelativeField { relatives: &[(-1, "simana pasadu"), (0, "es simana li"), (1, "prósimu simana")], future: ["", "", "", "", "", "di li {0} simana"], past: ["", "", "", "", "", "a ten {0} simana"] };
+
+#[rustfmt::skip]
+static _FIELD_974: _RelativeField = _RelativeField { relatives: &[(-1, "simana pasadu"), (0, "es simana li"), (1, "prósimu simana")], future: ["", "", "", "", "", "di li {0} sim."], past: ["", "", "", "", "", "a ten {0} sim."] };
+
+#[rustfmt::skip]
+static _FIELD_975: _RelativeField = _RelativeField { relatives: &[(-1, "mes pasadu"), (0, "es mes li"), (1, "prósimu mes")], future: ["", "", "", "", "", "di li {0} mes"], past: ["", "", "", "", "", "a ten {0} mes"] };
+
+#[rustfmt::skip]
+static _FIELD_976: _RelativeField = _RelativeField { relatives: &[(-1, "trimestri pasadu"), (0, "es trimestri li"), (1, "prósimu trimestri")], future: ["", "", "", "", "", "di li {0} trimestri"], past: ["", "", "", "", "", "a ten {0} trimestri"] };
+
+#[rustfmt::skip]
+static _FIELD_977: _RelativeField = _RelativeField { relatives: &[(-1, "trimestri pasadu"), (0, "es trimestri li"), (1, "prósimu trimestri")], future: ["", "", "", "", "", "di li {0} trim."], past: ["", "", "", "", "", "a ten {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_978: _RelativeField = _RelativeField { relatives: &[(-1, "anu pasadu"), (0, "es anu li"), (1, "prósimu anu")], future: ["", "", "", "", "", "di li {0} anu"], past: ["", "", "", "", "", "a ten {0} anu"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_77: [&_RelativeField; 24] = [
+    &_FIELD_965,
+    &_FIELD_966,
+    &_FIELD_967,
+    &_FIELD_968,
+    &_FIELD_969,
+    &_FIELD_970,
+    &_FIELD_971,
+    &_FIELD_971,
+    &_FIELD_971,
+    &_FIELD_972,
+    &_FIELD_972,
+    &_FIELD_972,
+    &_FIELD_973,
+    &_FIELD_974,
+    &_FIELD_974,
+    &_FIELD_975,
+    &_FIELD_975,
+    &_FIELD_975,
+    &_FIELD_976,
+    &_FIELD_977,
+    &_FIELD_977,
+    &_FIELD_978,
+    &_FIELD_978,
+    &_FIELD_978
+];
+
+#[rustfmt::skip]
+static _FIELD_979: _RelativeField = _RelativeField { relatives: &[(0, "ha")], future: ["", "", "", "", "", "segũno {0} kar kỹ"], past: ["", "", "", "", "", "segũno {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_980: _RelativeField = _RelativeField { relatives: &[(0, "ha")], future: ["", "seg. {0} kar kỹ", "", "", "", "seg. {0} kar kỹ"], past: ["", "seg. {0} si ser", "", "", "", "seg. {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_981: _RelativeField = _RelativeField { relatives: &[(0, "mĩnũtu tag")], future: ["", "", "", "", "", "mĩnũtu {0} kar kỹ"], past: ["", "", "", "", "", "mĩnũtu {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_982: _RelativeField = _RelativeField { relatives: &[(0, "mĩnũtu tag")], future: ["", "mĩn. {0} kar kỹ", "", "", "", "mĩn. {0} kar kỹ"], past: ["", "mĩn. {0} si ser", "", "", "", "mĩn. {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_983: _RelativeField = _RelativeField { relatives: &[(0, "óra tag kã")], future: ["", "", "", "", "", "óra {0} kar kỹ"], past: ["", "", "", "", "", "óra {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_984: _RelativeField = _RelativeField { relatives: &[(0, "óra tag kã")], future: ["", "{0} h kar kỹ", "", "", "", "{0} h kar kỹ"], past: ["", "{0} h si ser", "", "", "", "{0} h si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_985: _RelativeField = _RelativeField { relatives: &[(-2, "rãké ũn tá"), (-1, "rãketá"), (0, "uri"), (1, "vajkỹ"), (2, "vaj ũn ka")], future: ["", "", "", "", "", "kurã {0} kar kỹ"], past: ["", "", "", "", "", "kurã {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_986: _RelativeField = _RelativeField { relatives: &[(-1, "simỹnỹ tĩ mũn kã"), (0, "simỹnỹ tag kã"), (1, "simỹnỹ ũn kã")], future: ["", "", "", "", "", "simỹnỹ {0} kar kỹ"], past: ["", "", "", "", "", "simỹnỹ {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_987: _RelativeField = _RelativeField { relatives: &[(-1, "simỹnỹ tĩ mũn kã"), (0, "simỹnỹ tag kã"), (1, "simỹnỹ ũn kã")], future: ["", "sim. {0} kar kỹ", "", "", "", "sim. {0} kar kỹ"], past: ["", "sim. {0} si ser", "", "", "", "sim. {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_988: _RelativeField = _RelativeField { relatives: &[(-1, "kysã tĩ mũn kã"), (0, "kysã tag kã"), (1, "kysã ũn kã")], future: ["", "", "", "", "", "kysã {0} kar kỹ"], past: ["", "", "", "", "", "kysã {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_989: _RelativeField = _RelativeField { relatives: &[(-1, "kysã tẽgtũ ẽg no"), (0, "kysã tẽgtũ tag"), (1, "kysã tẽgtũ ũn kã")], future: ["", "", "", "", "", "kysã-tẽgtũ {0} kar kỹ"], past: ["", "", "", "", "", "kysã-tẽgtũ {0} si ser"] };
+
+#[rustfmt::skip]
+static _FIELD_990: _RelativeField = _RelativeField { relatives: &[(-1, "prỹg tĩ mũnh kã"), (0, "prỹg tag kã"), (1, "prỹg ũ kã")], future: ["", "", "", "", "", "prỹg {0} kar kỹ"], past: ["", "", "", "", "", "prỹg {0} si ser"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_78: [&_RelativeField; 24] = [
+    &_FIELD_979,
+    &_FIELD_980,
+    &_FIELD_980,
+    &_FIELD_981,
+    &_FIELD_982,
+    &_FIELD_982,
+    &_FIELD_983,
+    &_FIELD_984,
+    &_FIELD_984,
+    &_FIELD_985,
+    &_FIELD_985,
+    &_FIELD_985,
+    &_FIELD_986,
+    &_FIELD_987,
+    &_FIELD_987,
+    &_FIELD_988,
+    &_FIELD_988,
+    &_FIELD_988,
+    &_FIELD_989,
+    &_FIELD_989,
+    &_FIELD_989,
+    &_FIELD_990,
+    &_FIELD_990,
+    &_FIELD_990
+];
+
+#[rustfmt::skip]
+static _FIELD_991: _RelativeField = _RelativeField { relatives: &[(0, "қазір")], future: ["", "{0} секундтан кейін", "", "", "", "{0} секундтан кейін"], past: ["", "{0} секунд бұрын", "", "", "", "{0} секунд бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_992: _RelativeField = _RelativeField { relatives: &[(0, "қазір")], future: ["", "{0} сек. кейін", "", "", "", "{0} сек. кейін"], past: ["", "{0} сек. бұрын", "", "", "", "{0} сек. бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_993: _RelativeField = _RelativeField { relatives: &[(0, "осы минут")], future: ["", "{0} минуттан кейін", "", "", "", "{0} минуттан кейін"], past: ["", "{0} минут бұрын", "", "", "", "{0} минут бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_994: _RelativeField = _RelativeField { relatives: &[(0, "осы минут")], future: ["", "{0} мин. кейін", "", "", "", "{0} мин. кейін"], past: ["", "{0} мин. бұрын", "", "", "", "{0} мин. бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_995: _RelativeField = _RelativeField { relatives: &[(0, "осы сағат")], future: ["", "{0} сағаттан кейін", "", "", "", "{0} сағаттан кейін"], past: ["", "{0} сағат бұрын", "", "", "", "{0} сағат бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_996: _RelativeField = _RelativeField { relatives: &[(0, "осы сағат")], future: ["", "{0} сағ. кейін", "", "", "", "{0} сағ. кейін"], past: ["", "{0} сағ. бұрын", "", "", "", "{0} сағ. бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_997: _RelativeField = _RelativeField { relatives: &[(-2, "алдыңгүні"), (-1, "кеше"), (0, "бүгін"), (1, "ертең"), (2, "бүрсігүні")], future: ["", "{0} күннен кейін", "", "", "", "{0} күннен кейін"], past: ["", "{0} күн бұрын", "", "", "", "{0} күн бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_998: _RelativeField = _RelativeField { relatives: &[(-2, "алдыңғы күні"), (-1, "кеше"), (0, "бүгін"), (1, "ертең"), (2, "бүрсігүні")], future: ["", "{0} күннен кейін", "", "", "", "{0} күннен кейін"], past: ["", "{0} күн бұрын", "", "", "", "{0} күн бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_999: _RelativeField = _RelativeField { relatives: &[(-1, "өткен апта"), (0, "осы апта"), (1, "келесі апта")], future: ["", "{0} аптадан кейін", "", "", "", "{0} аптадан кейін"], past: ["", "{0} апта бұрын", "", "", "", "{0} апта бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_1000: _RelativeField = _RelativeField { relatives: &[(-1, "өткен апта"), (0, "осы апта"), (1, "келесі апта")], future: ["", "{0} ап. кейін", "", "", "", "{0} ап. кейін"], past: ["", "{0} ап. бұрын", "", "", "", "{0} ап. бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_1001: _RelativeField = _RelativeField { relatives: &[(-1, "өткен ай"), (0, "осы ай"), (1, "келесі ай")], future: ["", "{0} айдан кейін", "", "", "", "{0} айдан кейін"], past: ["", "{0} ай бұрын", "", "", "", "{0} ай бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_1002: _RelativeField = _RelativeField { relatives: &[(-1, "өткен тоқсан"), (0, "осы тоқсан"), (1, "келесі тоқсан")], future: ["", "{0} тоқсаннан кейін", "", "", "", "{0} тоқсаннан кейін"], past: ["", "{0} тоқсан бұрын", "", "", "", "{0} тоқсан бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_1003: _RelativeField = _RelativeField { relatives: &[(-1, "өткен тоқсан"), (0, "осы тоқсан"), (1, "келесі тоқсан")], future: ["", "{0} тқс. кейін", "", "", "", "{0} тқс. кейін"], past: ["", "{0} тқс. бұрын", "", "", "", "{0} тқс. бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_1004: _RelativeField = _RelativeField { relatives: &[(-1, "былтырғы жыл"), (0, "биылғы жыл"), (1, "келесі жыл")], future: ["", "{0} жылдан кейін", "", "", "", "{0} жылдан кейін"], past: ["", "{0} жыл бұрын", "", "", "", "{0} жыл бұрын"] };
+
+#[rustfmt::skip]
+static _FIELD_1005: _RelativeField = _RelativeField { relatives: &[(-1, "былтырғы жыл"), (0, "биылғы жыл"), (1, "келесі жыл")], future: ["", "{0} ж. кейін", "", "", "", "{0} ж. кейін"], past: ["", "{0} ж. бұрын", "", "", "", "{0} ж. бұрын"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_79: [&_RelativeField; 24] = [
+    &_FIELD_991,
+    &_FIELD_992,
+    &_FIELD_992,
+    &_FIELD_993,
+    &_FIELD_994,
+    &_FIELD_994,
+    &_FIELD_995,
+    &_FIELD_996,
+    &_FIELD_996,
+    &_FIELD_997,
+    &_FIELD_998,
+    &_FIELD_998,
+    &_FIELD_999,
+    &_FIELD_1000,
+    &_FIELD_1000,
+    &_FIELD_1001,
+    &_FIELD_1001,
+    &_FIELD_1001,
+    &_FIELD_1002,
+    &_FIELD_1003,
+    &_FIELD_1003,
+    &_FIELD_1004,
+    &_FIELD_1005,
+    &_FIELD_1005
+];
+
+#[rustfmt::skip]
+static _FIELD_1006: _RelativeField = _RelativeField { relatives: &[(0, "ឥឡ\u{17bc}វ")], future: ["", "", "", "", "", "{0} វ\u{17b7}នាទ\u{17b8}ទៀត"], past: ["", "", "", "", "", "{0} វ\u{17b7}នាទ\u{17b8}\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1007: _RelativeField = _RelativeField { relatives: &[(0, "នាទ\u{17b8}នេះ")], future: ["", "", "", "", "", "{0} នាទ\u{17b8}ទៀត"], past: ["", "", "", "", "", "{0} នាទ\u{17b8}\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1008: _RelativeField = _RelativeField { relatives: &[(0, "នាទ\u{17b8}នេះ")], future: ["", "", "", "", "", "{0} នាទ\u{17b8}ទៀត"], past: ["", "", "", "", "", "{0} នាទ\u{17b8}\u{200b}\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1009: _RelativeField = _RelativeField { relatives: &[(0, "ម\u{17c9}ោងនេះ")], future: ["", "", "", "", "", "ក\u{17d2}ន\u{17bb}ង\u{200b}រយៈ\u{200b}ពេល {0} ម\u{17c9}ោង"], past: ["", "", "", "", "", "{0} ម\u{17c9}ោង\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1010: _RelativeField = _RelativeField { relatives: &[(0, "ម\u{17c9}ោងនេះ")], future: ["", "", "", "", "", "{0} ម\u{17c9}ោងទៀត"], past: ["", "", "", "", "", "{0} ម\u{17c9}ោង\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1011: _RelativeField = _RelativeField { relatives: &[(-2, "ម\u{17d2}ស\u{17b7}ល\u{200b}ម\u{17c9}\u{17d2}ងៃ"), (-1, "ម\u{17d2}ស\u{17b7}លម\u{17b7}ញ"), (0, "ថ\u{17d2}ងៃ\u{200b}នេះ"), (1, "ថ\u{17d2}ងៃ\u{200b}ស\u{17d2}អែក"), (2, "\u{200b}ខាន\u{200b}ស\u{17d2}អែក")], future: ["", "", "", "", "", "{0} ថ\u{17d2}ងៃទៀត"], past: ["", "", "", "", "", "{0} ថ\u{17d2}ងៃ\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1012: _RelativeField = _RelativeField { relatives: &[(-2, "ម\u{17d2}ស\u{17b7}ល\u{200b}ម\u{17c9}\u{17d2}ងៃ"), (-1, "ម\u{17d2}ស\u{17b7}លម\u{17b7}ញ"), (0, "ថ\u{17d2}ងៃ\u{200b}នេះ"), (1, "ថ\u{17d2}ងៃស\u{17d2}អែក"), (2, "\u{200b}ខាន\u{200b}ស\u{17d2}អែក")], future: ["", "", "", "", "", "{0} ថ\u{17d2}ងៃទៀត"], past: ["", "", "", "", "", "{0} ថ\u{17d2}ងៃ\u{200b}\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1013: _RelativeField = _RelativeField { relatives: &[(-1, "សប\u{17d2}ដាហ\u{17cd}\u{200b}ម\u{17bb}ន"), (0, "សប\u{17d2}ដាហ\u{17cd}\u{200b}នេះ"), (1, "សប\u{17d2}ដាហ\u{17cd}\u{200b}ក\u{17d2}រោយ")], future: ["", "", "", "", "", "{0} សប\u{17d2}ដាហ\u{17cd}ទៀត"], past: ["", "", "", "", "", "{0} សប\u{17d2}ដាហ\u{17cd}\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1014: _RelativeField = _RelativeField { relatives: &[(-1, "ខែ\u{200b}ម\u{17bb}ន"), (0, "ខែ\u{200b}នេះ"), (1, "ខែ\u{200b}ក\u{17d2}រោយ")], future: ["", "", "", "", "", "{0} ខែទៀត"], past: ["", "", "", "", "", "{0} ខែម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1015: _RelativeField = _RelativeField { relatives: &[(-1, "ត\u{17d2}រ\u{17b8}មាស\u{200b}ម\u{17bb}ន"), (0, "ត\u{17d2}រ\u{17b8}មាស\u{200b}នេះ"), (1, "ត\u{17d2}រ\u{17b8}មាស\u{200b}ក\u{17d2}រោយ")], future: ["", "", "", "", "", "{0} ត\u{17d2}រ\u{17b8}មាសទៀត"], past: ["", "", "", "", "", "{0} ត\u{17d2}រ\u{17b8}មាស\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _FIELD_1016: _RelativeField = _RelativeField { relatives: &[(-1, "ឆ\u{17d2}នា\u{17c6}\u{200b}ម\u{17bb}ន"), (0, "ឆ\u{17d2}នា\u{17c6}\u{200b}នេះ"), (1, "ឆ\u{17d2}នា\u{17c6}\u{200b}ក\u{17d2}រោយ")], future: ["", "", "", "", "", "{0} ឆ\u{17d2}នា\u{17c6}ទៀត"], past: ["", "", "", "", "", "{0} ឆ\u{17d2}នា\u{17c6}\u{200b}ម\u{17bb}ន"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_80: [&_RelativeField; 24] = [
+    &_FIELD_1006,
+    &_FIELD_1006,
+    &_FIELD_1006,
+    &_FIELD_1007,
+    &_FIELD_1008,
+    &_FIELD_1008,
+    &_FIELD_1009,
+    &_FIELD_1010,
+    &_FIELD_1010,
+    &_FIELD_1011,
+    &_FIELD_1012,
+    &_FIELD_1012,
+    &_FIELD_1013,
+    &_FIELD_1013,
+    &_FIELD_1013,
+    &_FIELD_1014,
+    &_FIELD_1014,
+    &_FIELD_1014,
+    &_FIELD_1015,
+    &_FIELD_1015,
+    &_FIELD_1015,
+    &_FIELD_1016,
+    &_FIELD_1016,
+    &_FIELD_1016
+];
+
+#[rustfmt::skip]
+static _FIELD_1017: _RelativeField = _RelativeField { relatives: &[(0, "ಈಗ")], future: ["", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂಡ\u{ccd}\u{200c}ನಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂಡ\u{ccd}\u{200c}ಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂಡ\u{ccd} ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ಸ\u{cc6}ಕ\u{cc6}ಂಡುಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1018: _RelativeField = _RelativeField { relatives: &[(0, "ಈ ನ\u{cbf}ಮ\u{cbf}ಷ")], future: ["", "{0} ನ\u{cbf}ಮ\u{cbf}ಷದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ನ\u{cbf}ಮ\u{cbf}ಷಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ನ\u{cbf}ಮ\u{cbf}ಷದ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ನ\u{cbf}ಮ\u{cbf}ಷಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1019: _RelativeField = _RelativeField { relatives: &[(0, "ಈ ಗಂಟ\u{cc6}")], future: ["", "{0} ಗಂಟ\u{cc6}ಯಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ಗಂಟ\u{cc6}ಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ಗಂಟ\u{cc6} ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ಗಂಟ\u{cc6}ಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1020: _RelativeField = _RelativeField { relatives: &[(-2, "ಮ\u{cca}ನ\u{ccd}ನ\u{cc6}"), (-1, "ನ\u{cbf}ನ\u{ccd}ನ\u{cc6}"), (0, "ಇಂದು"), (1, "ನಾಳ\u{cc6}"), (2, "ನಾಡ\u{cbf}ದ\u{ccd}ದು")], future: ["", "{0} ದ\u{cbf}ನದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ದ\u{cbf}ನಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ದ\u{cbf}ನದ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ದ\u{cbf}ನಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1021: _RelativeField = _RelativeField { relatives: &[(-1, "ಕಳ\u{cc6}ದ ವಾರ"), (0, "ಈ ವಾರ"), (1, "ಮುಂದ\u{cbf}ನ ವಾರ")], future: ["", "{0} ವಾರದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ವಾರಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ವಾರದ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ವಾರಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1022: _RelativeField = _RelativeField { relatives: &[(-1, "ಕಳ\u{cc6}ದ ತ\u{cbf}ಂಗಳು"), (0, "ಈ ತ\u{cbf}ಂಗಳು"), (1, "ಮುಂದ\u{cbf}ನ ತ\u{cbf}ಂಗಳು")], future: ["", "{0} ತ\u{cbf}ಂಗಳಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ತ\u{cbf}ಂಗಳುಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ತ\u{cbf}ಂಗಳ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ತ\u{cbf}ಂಗಳುಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1023: _RelativeField = _RelativeField { relatives: &[(-1, "ಕಳ\u{cc6}ದ ತ\u{cbf}ಂಗಳು"), (0, "ಈ ತ\u{cbf}ಂಗಳು"), (1, "ಮುಂದ\u{cbf}ನ ತ\u{cbf}ಂಗಳು")], future: ["", "{0} ತ\u{cbf}ಂಗಳಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ತ\u{cbf}ಂಗಳುಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ತ\u{cbf}ಂಗಳು ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ತ\u{cbf}ಂಗಳುಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1024: _RelativeField = _RelativeField { relatives: &[(-1, "ಹ\u{cbf}ಂದ\u{cbf}ನ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), (0, "ಈ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), (1, "ಮುಂದ\u{cbf}ನ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ")], future: ["", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕದ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1025: _RelativeField = _RelativeField { relatives: &[(-1, "ಕಳ\u{cc6}ದ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), (0, "ಈ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), (1, "ಮುಂದ\u{cbf}ನ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ")], future: ["", "{0} ತ\u{ccd}ರ\u{cc8}.ಮಾ.ದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ತ\u{ccd}ರ\u{cc8}.ಮಾ. ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1026: _RelativeField = _RelativeField { relatives: &[(-1, "ಕಳ\u{cc6}ದ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), (0, "ಈ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), (1, "ಮುಂದ\u{cbf}ನ ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ")], future: ["", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ತ\u{ccd}ರ\u{cc8}.ಮಾ. ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1027: _RelativeField = _RelativeField { relatives: &[(-1, "ಹ\u{cbf}ಂದ\u{cbf}ನ ವರ\u{ccd}ಷ"), (0, "ಈ ವರ\u{ccd}ಷ"), (1, "ಮುಂದ\u{cbf}ನ ವರ\u{ccd}ಷ")], future: ["", "{0} ವರ\u{ccd}ಷದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ವರ\u{ccd}ಷಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ವರ\u{ccd}ಷದ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ವರ\u{ccd}ಷಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _FIELD_1028: _RelativeField = _RelativeField { relatives: &[(-1, "ಕಳ\u{cc6}ದ ವರ\u{ccd}ಷ"), (0, "ಈ ವರ\u{ccd}ಷ"), (1, "ಮುಂದ\u{cbf}ನ ವರ\u{ccd}ಷ")], future: ["", "{0} ವರ\u{ccd}ಷದಲ\u{ccd}ಲ\u{cbf}", "", "", "", "{0} ವರ\u{ccd}ಷಗಳಲ\u{ccd}ಲ\u{cbf}"], past: ["", "{0} ವರ\u{ccd}ಷದ ಹ\u{cbf}ಂದ\u{cc6}", "", "", "", "{0} ವರ\u{ccd}ಷಗಳ ಹ\u{cbf}ಂದ\u{cc6}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_81: [&_RelativeField; 24] = [
+    &_FIELD_1017,
+    &_FIELD_1017,
+    &_FIELD_1017,
+    &_FIELD_1018,
+    &_FIELD_1018,
+    &_FIELD_1018,
+    &_FIELD_1019,
+    &_FIELD_1019,
+    &_FIELD_1019,
+    &_FIELD_1020,
+    &_FIELD_1020,
+    &_FIELD_1020,
+    &_FIELD_1021,
+    &_FIELD_1021,
+    &_FIELD_1021,
+    &_FIELD_1022,
+    &_FIELD_1023,
+    &_FIELD_1022,
+    &_FIELD_1024,
+    &_FIELD_1025,
+    &_FIELD_1026,
+    &_FIELD_1027,
+    &_FIELD_1028,
+    &_FIELD_1028
+];
+
+#[rustfmt::skip]
+static _FIELD_1029: _RelativeField = _RelativeField { relatives: &[(0, "지금")], future: ["", "", "", "", "", "{0}초 후"], past: ["", "", "", "", "", "{0}초 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1030: _RelativeField = _RelativeField { relatives: &[(0, "현재 분")], future: ["", "", "", "", "", "{0}분 후"], past: ["", "", "", "", "", "{0}분 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1031: _RelativeField = _RelativeField { relatives: &[(0, "현재 시간")], future: ["", "", "", "", "", "{0}시간 후"], past: ["", "", "", "", "", "{0}시간 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1032: _RelativeField = _RelativeField { relatives: &[(-2, "그저께"), (-1, "어제"), (0, "오늘"), (1, "내일"), (2, "모레")], future: ["", "", "", "", "", "{0}일 후"], past: ["", "", "", "", "", "{0}일 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1033: _RelativeField = _RelativeField { relatives: &[(-1, "지난주"), (0, "이번 주"), (1, "다음 주")], future: ["", "", "", "", "", "{0}주 후"], past: ["", "", "", "", "", "{0}주 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1034: _RelativeField = _RelativeField { relatives: &[(-1, "지난달"), (0, "이번 달"), (1, "다음 달")], future: ["", "", "", "", "", "{0}개월 후"], past: ["", "", "", "", "", "{0}개월 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1035: _RelativeField = _RelativeField { relatives: &[(-1, "지난 분기"), (0, "이번 분기"), (1, "다음 분기")], future: ["", "", "", "", "", "{0}분기 후"], past: ["", "", "", "", "", "{0}분기 전"] };
+
+#[rustfmt::skip]
+static _FIELD_1036: _RelativeField = _RelativeField { relatives: &[(-1, "작년"), (0, "올해"), (1, "내년")], future: ["", "", "", "", "", "{0}년 후"], past: ["", "", "", "", "", "{0}년 전"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_82: [&_RelativeField; 24] = [
+    &_FIELD_1029,
+    &_FIELD_1029,
+    &_FIELD_1029,
+    &_FIELD_1030,
+    &_FIELD_1030,
+    &_FIELD_1030,
+    &_FIELD_1031,
+    &_FIELD_1031,
+    &_FIELD_1031,
+    &_FIELD_1032,
+    &_FIELD_1032,
+    &_FIELD_1032,
+    &_FIELD_1033,
+    &_FIELD_1033,
+    &_FIELD_1033,
+    &_FIELD_1034,
+    &_FIELD_1034,
+    &_FIELD_1034,
+    &_FIELD_1035,
+    &_FIELD_1035,
+    &_FIELD_1035,
+    &_FIELD_1036,
+    &_FIELD_1036,
+    &_FIELD_1036
+];
+
+#[rustfmt::skip]
+static _FIELD_1037: _RelativeField = _RelativeField { relatives: &[(0, "आता\u{902}")], future: ["", "", "", "", "", "{0} स\u{947}क\u{902}दानी\u{902}"], past: ["", "", "", "", "", "{0} स\u{947}क\u{902}द आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1038: _RelativeField = _RelativeField { relatives: &[(0, "आता\u{902}")], future: ["", "", "", "", "", "{0} स\u{947}क\u{902}दानी\u{902}"], past: ["", "", "", "", "", "{0} स\u{947}. आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1039: _RelativeField = _RelativeField { relatives: &[(0, "ह\u{947}\u{902} मिनीट")], future: ["", "", "", "", "", "{0} मिन\u{94d}टा\u{902}"], past: ["", "", "", "", "", "{0} मिन\u{94d}टा\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1040: _RelativeField = _RelativeField { relatives: &[(0, "हो मिन.")], future: ["", "", "", "", "", "{0} मिन\u{94d}टा\u{902}"], past: ["", "", "", "", "", "{0} मिन\u{94d}टा\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1041: _RelativeField = _RelativeField { relatives: &[(0, "हो मिन")], future: ["", "", "", "", "", "{0} मिन\u{94d}टा\u{902}"], past: ["", "", "", "", "", "{0} मिन\u{94d}टा\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1042: _RelativeField = _RelativeField { relatives: &[(0, "ह\u{947}\u{902} वर")], future: ["", "", "", "", "", "{0} वरा\u{902}नी\u{902}"], past: ["", "", "", "", "", "{0} वरा आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1043: _RelativeField = _RelativeField { relatives: &[(-1, "काल"), (0, "आयज"), (1, "फाल\u{94d}या\u{902}")], future: ["", "", "", "", "", "{0} दिसानी\u{902}"], past: ["", "", "", "", "", "{0} दीस आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1044: _RelativeField = _RelativeField { relatives: &[(-1, "निमाणो सप\u{94d}तक"), (0, "हो सप\u{94d}तक"), (1, "फ\u{941}डलो सप\u{94d}तक")], future: ["", "", "", "", "", "{0} सप\u{94d}तका\u{902}नी\u{902}"], past: ["", "", "", "", "", "{0} सप\u{94d}तका\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1045: _RelativeField = _RelativeField { relatives: &[(-1, "निमाणो सप\u{94d}तक"), (0, "हो सप\u{94d}तक"), (1, "फ\u{941}डलो सप\u{94d}तक")], future: ["", "", "", "", "", "{0} सप\u{94d}तका\u{902}नी\u{902}"], past: ["", "", "", "", "", "{0} सप\u{94d}त. आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1046: _RelativeField = _RelativeField { relatives: &[(-1, "फाटलो म\u{94d}हयनो"), (0, "हो म\u{94d}हयनो"), (1, "फ\u{941}डलो म\u{94d}हयनो")], future: ["", "", "", "", "", "{0} म\u{94d}हयन\u{94d}यानी\u{902}"], past: ["", "", "", "", "", "{0} म\u{94d}हयन\u{94d}या\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1047: _RelativeField = _RelativeField { relatives: &[(-1, "फाटलो त\u{94d}र\u{948}मासीक"), (0, "हो त\u{94d}र\u{948}मासीक"), (1, "फ\u{941}डलो त\u{94d}र\u{948}मासीक")], future: ["", "", "", "", "", "{0} त\u{94d}र\u{948}मासीका\u{902}त"], past: ["", "", "", "", "", "{0} त\u{94d}र\u{948}मासीका\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1048: _RelativeField = _RelativeField { relatives: &[(-1, "फाटल\u{947}\u{902} तिम\u{94d}ह."), (0, "ह\u{947}\u{902} तिम\u{94d}ह."), (1, "फ\u{941}डल\u{947}\u{902} तिम\u{94d}ह.")], future: ["", "", "", "", "", "{0} त\u{94d}र\u{948}मासीका\u{902}त"], past: ["", "", "", "", "", "{0} त\u{94d}र\u{948}मासीका\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1049: _RelativeField = _RelativeField { relatives: &[(-1, "फाटल\u{947}\u{902} तिम\u{94d}ह"), (0, "ह\u{947}\u{902} तिम\u{94d}ह"), (1, "फ\u{941}डल\u{947}\u{902} तिम\u{94d}ह")], future: ["", "", "", "", "", "{0} त\u{94d}र\u{948}मासीका\u{902}त"], past: ["", "", "", "", "", "{0} त\u{94d}र\u{948}मासीका\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1050: _RelativeField = _RelativeField { relatives: &[(-1, "फाटल\u{947}\u{902} वर\u{94d}स"), (0, "ह\u{947}\u{902} वर\u{94d}स"), (1, "फ\u{941}डल\u{947}\u{902} वर\u{94d}स")], future: ["", "", "", "", "", "{0} वर\u{94d}सा\u{902}नी\u{902}"], past: ["", "", "", "", "", "{0} वर\u{94d}सा\u{902} आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _FIELD_1051: _RelativeField = _RelativeField { relatives: &[(-1, "फाटल\u{947}\u{902} वर\u{94d}स"), (0, "ह\u{947}\u{902} वर\u{94d}स"), (1, "फ\u{941}डल\u{947}\u{902} वर\u{94d}स")], future: ["", "", "", "", "", "{0} वर\u{94d}सा\u{902}नी\u{902}"], past: ["", "", "", "", "", "{0} वर\u{94d}स आदी\u{902}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_83: [&_RelativeField; 24] = [
+    &_FIELD_1037,
+    &_FIELD_1038,
+    &_FIELD_1038,
+    &_FIELD_1039,
+    &_FIELD_1040,
+    &_FIELD_1041,
+    &_FIELD_1042,
+    &_FIELD_1042,
+    &_FIELD_1042,
+    &_FIELD_1043,
+    &_FIELD_1043,
+    &_FIELD_1043,
+    &_FIELD_1044,
+    &_FIELD_1044,
+    &_FIELD_1045,
+    &_FIELD_1046,
+    &_FIELD_1046,
+    &_FIELD_1046,
+    &_FIELD_1047,
+    &_FIELD_1048,
+    &_FIELD_1049,
+    &_FIELD_1050,
+    &_FIELD_1051,
+    &_FIELD_1051
+];
+
+#[rustfmt::skip]
+static _FIELD_1052: _RelativeField = _RelativeField { relatives: &[(0, "atam")], future: ["", "", "", "", "", "{0} sekondanim"], past: ["", "", "", "", "", "{0} sekond adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1053: _RelativeField = _RelativeField { relatives: &[(0, "atam")], future: ["", "", "", "", "", "{0} sekondanim"], past: ["", "", "", "", "", "{0} sek. adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1054: _RelativeField = _RelativeField { relatives: &[(0, "atam")], future: ["", "", "", "", "", "{0}sekondanim"], past: ["", "", "", "", "", "{0}sek adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1055: _RelativeField = _RelativeField { relatives: &[(0, "ho minut")], future: ["", "", "", "", "", "{0} mintanim"], past: ["", "", "", "", "", "{0} mintam adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1056: _RelativeField = _RelativeField { relatives: &[(0, "ho min.")], future: ["", "", "", "", "", "{0} min."], past: ["", "", "", "", "", "{0} min. adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1057: _RelativeField = _RelativeField { relatives: &[(0, "ho min")], future: ["", "", "", "", "", "{0}min"], past: ["", "", "", "", "", "{0}min adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1058: _RelativeField = _RelativeField { relatives: &[(0, "hem vor")], future: ["", "", "", "", "", "{0} voranim"], past: ["", "", "", "", "", "{0} voram adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1059: _RelativeField = _RelativeField { relatives: &[(0, "hem vor")], future: ["", "", "", "", "", "{0}voranim"], past: ["", "", "", "", "", "{0}voram adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1060: _RelativeField = _RelativeField { relatives: &[(-1, "kal"), (0, "aiz"), (1, "faleam")], future: ["", "", "", "", "", "{0} disanim"], past: ["", "", "", "", "", "{0} dis adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1061: _RelativeField = _RelativeField { relatives: &[(-1, "kal"), (0, "aiz"), (1, "fal")], future: ["", "", "", "", "", "{0}disanim"], past: ["", "", "", "", "", "{0}d adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1062: _RelativeField = _RelativeField { relatives: &[(-1, "fattlo suman"), (0, "ho suman"), (1, "fuddlo suman")], future: ["", "", "", "", "", "{0} sumananim"], past: ["", "", "", "", "", "{0} suman adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1063: _RelativeField = _RelativeField { relatives: &[(-1, "fattlo sum"), (0, "ho sum"), (1, "fuddlo sum")], future: ["", "", "", "", "", "{0}sumananim"], past: ["", "", "", "", "", "{0}sum adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1064: _RelativeField = _RelativeField { relatives: &[(-1, "fattlo mhoino"), (0, "ho mhoino"), (1, "fuddlo mhoino")], future: ["", "", "", "", "", "{0} mhoineanim"], past: ["", "", "", "", "", "{0} mhoine adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1065: _RelativeField = _RelativeField { relatives: &[(-1, "fattlo mho."), (0, "ho mho."), (1, "fuddlo mho.")], future: ["", "", "", "", "", "{0} mhoineanim"], past: ["", "", "", "", "", "{0} mho. adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1066: _RelativeField = _RelativeField { relatives: &[(-1, "fattlo mh"), (0, "ho mh"), (1, "fuddlo mh")], future: ["", "", "", "", "", "{0}mhoineanim"], past: ["", "", "", "", "", "{0}mh adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1067: _RelativeField = _RelativeField { relatives: &[(-1, "fattlem timhoinallem"), (0, "hem timhoinallem"), (1, "fuddlem timhoinallem")], future: ["", "", "", "", "", "{0} timhoinalleanim"], past: ["", "", "", "", "", "{0} timhoinalle adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1068: _RelativeField = _RelativeField { relatives: &[(-1, "fattlem timho."), (0, "hem timho."), (1, "fuddlem timho.")], future: ["", "", "", "", "", "{0} timhoinalleanim"], past: ["", "", "", "", "", "{0} timho. adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1069: _RelativeField = _RelativeField { relatives: &[(-1, "fattlem timh"), (0, "hem timh"), (1, "fuddlem timh")], future: ["", "", "", "", "", "{0}timhoinalleanim"], past: ["", "", "", "", "", "{0}timh adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1070: _RelativeField = _RelativeField { relatives: &[(-1, "fattlem voros"), (0, "hem voros"), (1, "fuddlem voros")], future: ["", "", "", "", "", "{0} vorsanim"], past: ["", "", "", "", "", "{0} vorsam adim"] };
+
+#[rustfmt::skip]
+static _FIELD_1071: _RelativeField = _RelativeField { relatives: &[(-1, "fattlem voros"), (0, "hem voros"), (1, "fuddlem voros")], future: ["", "", "", "", "", "{0}vorsanim"], past: ["", "", "", "", "", "{0}vorsam adim"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_84: [&_RelativeField; 24] = [
+    &_FIELD_1052,
+    &_FIELD_1053,
+    &_FIELD_1054,
+    &_FIELD_1055,
+    &_FIELD_1056,
+    &_FIELD_1057,
+    &_FIELD_1058,
+    &_FIELD_1058,
+    &_FIELD_1059,
+    &_FIELD_1060,
+    &_FIELD_1060,
+    &_FIELD_1061,
+    &_FIELD_1062,
+    &_FIELD_1062,
+    &_FIELD_1063,
+    &_FIELD_1064,
+    &_FIELD_1065,
+    &_FIELD_1066,
+    &_FIELD_1067,
+    &_FIELD_1068,
+    &_FIELD_1069,
+    &_FIELD_1070,
+    &_FIELD_1070,
+    &_FIELD_1071
+];
+
+#[rustfmt::skip]
+static _FIELD_1072: _RelativeField = _RelativeField { relatives: &[(-1, "راتھ"), (0, "ا\u{64e}ز"), (1, "پگاہ")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1073: _RelativeField = _RelativeField { relatives: &[(-1, "پ\u{654}ت\u{650}م ہفتہ"), (0, "ی\u{655}ہ ہفتہ"), (1, "نو ہفتہ")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_1074: _RelativeField = _RelativeField { relatives: &[(-1, "پ\u{654}ت\u{650}م ریتھؠ"), (0, "ی\u{655}ہ ریتھؠ"), (1, "نو ریتھؠ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_1075: _RelativeField = _RelativeField { relatives: &[(-1, "پ\u{654}ت\u{650}م ؤری"), (0, "ی\u{655}ہ ؤری"), (1, "نو ؤری")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_85: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1072,
+    &_FIELD_1072,
+    &_FIELD_1072,
+    &_FIELD_1073,
+    &_FIELD_1073,
+    &_FIELD_1073,
+    &_FIELD_1074,
+    &_FIELD_1074,
+    &_FIELD_1074,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1075,
+    &_FIELD_1075,
+    &_FIELD_1075
+];
+
+#[rustfmt::skip]
+static _FIELD_1076: _RelativeField = _RelativeField { relatives: &[(-1, "राथ"), (0, "अज\u{93c}"), (1, "पगाह")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_86: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1076,
+    &_FIELD_1076,
+    &_FIELD_1076,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1077: _RelativeField = _RelativeField { relatives: &[(0, "vêga")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1078: _RelativeField = _RelativeField { relatives: &[(0, "vê deqeyê")], future: ["", "di {0} deqeyê de", "", "", "", "di {0} deqeyan de"], past: ["", "berî {0} deqeyê", "", "", "", "berî {0} deqeyan"] };
+
+#[rustfmt::skip]
+static _FIELD_1079: _RelativeField = _RelativeField { relatives: &[(0, "vê deqeyê")], future: ["", "di {0} dq. de", "", "", "", "di {0} dq de"], past: ["", "berî {0} dq.", "", "", "", "berî {0} dq."] };
+
+#[rustfmt::skip]
+static _FIELD_1080: _RelativeField = _RelativeField { relatives: &[(0, "vê deqeyê")], future: ["", "di {0} dq de", "", "", "", "di {0}dq de"], past: ["", "berî {0} dq.", "", "", "", "berî {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1081: _RelativeField = _RelativeField { relatives: &[(-1, "duh"), (0, "îro"), (1, "sibê")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1082: _RelativeField = _RelativeField { relatives: &[(-1, "hefteya borî"), (0, "ev hefte"), (1, "hefteya bê")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_1083: _RelativeField = _RelativeField { relatives: &[(-1, "hft. borî"), (0, "ev hft."), (1, "hft. bê")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_1084: _RelativeField = _RelativeField { relatives: &[(-1, "meha borî"), (0, "ev meh"), (1, "meha bê")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_1085: _RelativeField = _RelativeField { relatives: &[(-1, "çaryeka dawî"), (0, "ev çaryek"), (1, "çaryeka were")], future: ["", "", "", "", "", "+{0} Q"], past: ["", "", "", "", "", "-{0} Q"] };
+
+#[rustfmt::skip]
+static _FIELD_1086: _RelativeField = _RelativeField { relatives: &[(-1, "sala borî"), (0, "îsal"), (1, "sala bê")], future: ["", "di salekê de", "", "", "", "di {0} salan de"], past: ["", "berî {0} salê", "", "", "", "berî {0} salan"] };
+
+#[rustfmt::skip]
+static _FIELD_1087: _RelativeField = _RelativeField { relatives: &[(-1, "sala borî"), (0, "îsal"), (1, "sala bê")], future: ["", "piştî {0} salan", "", "", "", "piştî {0} salan"], past: ["", "berî salekê", "", "", "", "berî {0} salan"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_87: [&_RelativeField; 24] = [
+    &_FIELD_1077,
+    &_FIELD_1077,
+    &_FIELD_1077,
+    &_FIELD_1078,
+    &_FIELD_1079,
+    &_FIELD_1080,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1081,
+    &_FIELD_1081,
+    &_FIELD_1081,
+    &_FIELD_1082,
+    &_FIELD_1083,
+    &_FIELD_1083,
+    &_FIELD_1084,
+    &_FIELD_1084,
+    &_FIELD_1084,
+    &_FIELD_1085,
+    &_FIELD_1085,
+    &_FIELD_1085,
+    &_FIELD_1086,
+    &_FIELD_1087,
+    &_FIELD_1087
+];
+
+#[rustfmt::skip]
+static _FIELD_1088: _RelativeField = _RelativeField { relatives: &[(-1, "raaala"), (0, "inju"), (1, "vie")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_88: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1088,
+    &_FIELD_1088,
+    &_FIELD_1088,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1089: _RelativeField = _RelativeField { relatives: &[(-1, "रा-आला"), (0, "इञ\u{94d}ज\u{941}"), (1, "वि-ए")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_89: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1089,
+    &_FIELD_1089,
+    &_FIELD_1089,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1090: _RelativeField = _RelativeField { relatives: &[(-1, "ର\u{b3e}ଆଲ\u{b3e}"), (0, "ଇଞ\u{b4d}ଜ\u{b41}"), (1, "ୱ\u{b3f} ଏ")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_90: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1090,
+    &_FIELD_1090,
+    &_FIELD_1090,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1091: _RelativeField = _RelativeField { relatives: &[(-1, "న\u{c3f}న\u{c4d}న"), (0, "ఈ ర\u{c4b}జు"), (1, "ర\u{c47}పు")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_91: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1091,
+    &_FIELD_1091,
+    &_FIELD_1091,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1092: _RelativeField = _RelativeField { relatives: &[(0, "азыр")], future: ["", "{0} секунддан кийин", "", "", "", "{0} секунддан кийин"], past: ["", "{0} секунд мурун", "", "", "", "{0} секунд мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1093: _RelativeField = _RelativeField { relatives: &[(0, "азыр")], future: ["", "{0} сек. кийин", "", "", "", "{0} сек. кийин"], past: ["", "{0} сек. мурун", "", "", "", "{0} сек. мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1094: _RelativeField = _RelativeField { relatives: &[(0, "азыр")], future: ["", "{0} сек. кийн", "", "", "", "{0} сек. кийн"], past: ["", "{0} сек. мурн", "", "", "", "{0} сек. мурн"] };
+
+#[rustfmt::skip]
+static _FIELD_1095: _RelativeField = _RelativeField { relatives: &[(0, "ушул мүнөттө")], future: ["", "{0} мүнөттөн кийин", "", "", "", "{0} мүнөттөн кийин"], past: ["", "{0} мүнөт мурун", "", "", "", "{0} мүнөт мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1096: _RelativeField = _RelativeField { relatives: &[(0, "ушул мүнөттө")], future: ["", "{0} мүн. кийин", "", "", "", "{0} мүн. кийин"], past: ["", "{0} мүн. мурун", "", "", "", "{0} мүн. мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1097: _RelativeField = _RelativeField { relatives: &[(0, "ушул мүнөттө")], future: ["", "{0} мүн. кийн", "", "", "", "{0} мүн. кийн"], past: ["", "{0} мүн. мурн", "", "", "", "{0} мүн. мурн"] };
+
+#[rustfmt::skip]
+static _FIELD_1098: _RelativeField = _RelativeField { relatives: &[(0, "ушул саатта")], future: ["", "{0} сааттан кийин", "", "", "", "{0} сааттан кийин"], past: ["", "{0} саат мурун", "", "", "", "{0} саат мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1099: _RelativeField = _RelativeField { relatives: &[(0, "ушул саатта")], future: ["", "{0} саат. кийин", "", "", "", "{0} саат. кийин"], past: ["", "{0} саат. мурун", "", "", "", "{0} саат. мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1100: _RelativeField = _RelativeField { relatives: &[(0, "ушул саатта")], future: ["", "{0} с. кийн", "", "", "", "{0} с. кийн"], past: ["", "{0} с. мурн", "", "", "", "{0} с. мурн"] };
+
+#[rustfmt::skip]
+static _FIELD_1101: _RelativeField = _RelativeField { relatives: &[(-2, "мурдагы күнү"), (-1, "кечээ"), (0, "бүгүн"), (1, "эртең"), (2, "бүрсүгүнү")], future: ["", "{0} күндөн кийин", "", "", "", "{0} күндөн кийин"], past: ["", "{0} күн мурун", "", "", "", "{0} күн мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1102: _RelativeField = _RelativeField { relatives: &[(-2, "мурдагы күнү"), (-1, "кечээ"), (0, "бүгүн"), (1, "эртең"), (2, "бүрсүгүнү")], future: ["", "{0} күн. кийин", "", "", "", "{0} күн. кийин"], past: ["", "{0} күн мурун", "", "", "", "{0} күн мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1103: _RelativeField = _RelativeField { relatives: &[(-1, "өткөн аптада"), (0, "ушул аптада"), (1, "келерки аптада")], future: ["", "{0} аптадан кийин", "", "", "", "{0} аптадан кийин"], past: ["", "{0} апта мурун", "", "", "", "{0} апта мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1104: _RelativeField = _RelativeField { relatives: &[(-1, "өткөн апт."), (0, "ушул апт."), (1, "келерки апт.")], future: ["", "{0} апт. кийин", "", "", "", "{0} апт. кийин"], past: ["", "{0} апт. мурун", "", "", "", "{0} апт. мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1105: _RelativeField = _RelativeField { relatives: &[(-1, "өткөн айда"), (0, "бул айда"), (1, "эмдиги айда")], future: ["", "{0} айдан кийин", "", "", "", "{0} айдан кийин"], past: ["", "{0} ай мурун", "", "", "", "{0} ай мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1106: _RelativeField = _RelativeField { relatives: &[(-1, "өткөн айда"), (0, "бул айда"), (1, "эмдиги айда")], future: ["", "{0} айд. кийин", "", "", "", "{0} айд. кийин"], past: ["", "{0} ай мурун", "", "", "", "{0} ай мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1107: _RelativeField = _RelativeField { relatives: &[(-1, "өткөн айда"), (0, "бул айда"), (1, "эмдиги айда")], future: ["", "{0} айд. кийн", "", "", "", "{0} айд. кийн"], past: ["", "{0} ай мурн", "", "", "", "{0} ай мурн"] };
+
+#[rustfmt::skip]
+static _FIELD_1108: _RelativeField = _RelativeField { relatives: &[(-1, "акыркы чейрек"), (0, "бул чейрек"), (1, "кийинки чейрек")], future: ["", "{0} чейректен кийин", "", "", "", "{0} чейректен кийин"], past: ["", "{0} чейрек мурун", "", "", "", "{0} чейрек мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1109: _RelativeField = _RelativeField { relatives: &[(-1, "акыркы чейр."), (0, "бул чейр."), (1, "кийинки чейр.")], future: ["", "{0} чейректен кийин", "", "", "", "{0} чейректен кийин"], past: ["", "{0} чейр. мурун", "", "", "", "{0} чейр. мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1110: _RelativeField = _RelativeField { relatives: &[(-1, "акыркы чейр."), (0, "бул чейр."), (1, "кийинки чейр.")], future: ["", "{0} чейр. кийин", "", "", "", "{0} чейр. кийин"], past: ["", "{0} чейр. мурун", "", "", "", "{0} чейр. мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1111: _RelativeField = _RelativeField { relatives: &[(-1, "былтыр"), (0, "быйыл"), (1, "эмдиги жылы")], future: ["", "{0} жылдан кийин", "", "", "", "{0} жылдан кийин"], past: ["", "{0} жыл мурун", "", "", "", "{0} жыл мурун"] };
+
+#[rustfmt::skip]
+static _FIELD_1112: _RelativeField = _RelativeField { relatives: &[(-1, "былтыр"), (0, "быйыл"), (1, "эмдиги жылы")], future: ["", "{0} жыл. кийин", "", "", "", "{0} жыл. кийин"], past: ["", "{0} жыл мурун", "", "", "", "{0} жыл мурун"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_92: [&_RelativeField; 24] = [
+    &_FIELD_1092,
+    &_FIELD_1093,
+    &_FIELD_1094,
+    &_FIELD_1095,
+    &_FIELD_1096,
+    &_FIELD_1097,
+    &_FIELD_1098,
+    &_FIELD_1099,
+    &_FIELD_1100,
+    &_FIELD_1101,
+    &_FIELD_1102,
+    &_FIELD_1102,
+    &_FIELD_1103,
+    &_FIELD_1104,
+    &_FIELD_1104,
+    &_FIELD_1105,
+    &_FIELD_1106,
+    &_FIELD_1107,
+    &_FIELD_1108,
+    &_FIELD_1109,
+    &_FIELD_1110,
+    &_FIELD_1111,
+    &_FIELD_1112,
+    &_FIELD_1112
+];
+
+#[rustfmt::skip]
+static _FIELD_1113: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "an {0} Sekonn", "", "", "", "a(n) {0} Sekonnen"], past: ["", "virun {0} Sekonn", "", "", "", "viru(n) {0} Sekonnen"] };
+
+#[rustfmt::skip]
+static _FIELD_1114: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "an {0} Sek.", "", "", "", "a(n) {0} Sek."], past: ["", "virun {0} Sek.", "", "", "", "viru(n) {0} Sek."] };
+
+#[rustfmt::skip]
+static _FIELD_1115: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "+{0} Sek.", "", "", "", "+{0} Sek."], past: ["", "-{0} Sek.", "", "", "", "-{0} Sek."] };
+
+#[rustfmt::skip]
+static _FIELD_1116: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "an {0} Minutt", "", "", "", "a(n) {0} Minutten"], past: ["", "virun {0} Minutt", "", "", "", "viru(n) {0} Minutten"] };
+
+#[rustfmt::skip]
+static _FIELD_1117: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "an {0} Min.", "", "", "", "a(n) {0} Min."], past: ["", "virun {0} Min.", "", "", "", "viru(n) {0} Min."] };
+
+#[rustfmt::skip]
+static _FIELD_1118: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "+{0} Min.", "", "", "", "+{0} Min."], past: ["", "-{0} Min.", "", "", "", "-{0} Min."] };
+
+#[rustfmt::skip]
+static _FIELD_1119: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "an {0} Stonn", "", "", "", "a(n) {0} Stonnen"], past: ["", "virun {0} Stonn", "", "", "", "viru(n) {0} Stonnen"] };
+
+#[rustfmt::skip]
+static _FIELD_1120: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "an {0} St.", "", "", "", "a(n) {0} St."], past: ["", "virun {0} St.", "", "", "", "viru(n) {0} St."] };
+
+#[rustfmt::skip]
+static _FIELD_1121: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "+{0} St.", "", "", "", "+{0} St."], past: ["", "-{0} St.", "", "", "", "-{0} St."] };
+
+#[rustfmt::skip]
+static _FIELD_1122: _RelativeField = _RelativeField { relatives: &[(-1, "gëschter"), (0, "haut"), (1, "muer")], future: ["", "an {0} Dag", "", "", "", "a(n) {0} Deeg"], past: ["", "virun {0} Dag", "", "", "", "viru(n) {0} Deeg"] };
+
+#[rustfmt::skip]
+static _FIELD_1123: _RelativeField = _RelativeField { relatives: &[(-1, "gëschter"), (0, "haut"), (1, "muer")], future: ["", "an {0} D.", "", "", "", "a(n) {0} D."], past: ["", "virun {0} D.", "", "", "", "viru(n) {0} D."] };
+
+#[rustfmt::skip]
+static _FIELD_1124: _RelativeField = _RelativeField { relatives: &[(-1, "gëschter"), (0, "haut"), (1, "muer")], future: ["", "+{0} D.", "", "", "", "+{0} D."], past: ["", "-{0} D.", "", "", "", "-{0} D."] };
+
+#[rustfmt::skip]
+static _FIELD_1125: _RelativeField = _RelativeField { relatives: &[(-1, "lescht Woch"), (0, "dës Woch"), (1, "nächst Woch")], future: ["", "an {0} Woch", "", "", "", "a(n) {0} Wochen"], past: ["", "virun {0} Woch", "", "", "", "viru(n) {0} Wochen"] };
+
+#[rustfmt::skip]
+static _FIELD_1126: _RelativeField = _RelativeField { relatives: &[(-1, "lescht Woch"), (0, "dës Woch"), (1, "nächst Woch")], future: ["", "an {0} W.", "", "", "", "a(n) {0} W."], past: ["", "virun {0} W.", "", "", "", "viru(n) {0} W."] };
+
+#[rustfmt::skip]
+static _FIELD_1127: _RelativeField = _RelativeField { relatives: &[(-1, "lescht Woch"), (0, "dës Woch"), (1, "nächst Woch")], future: ["", "+{0} W.", "", "", "", "+{0} W."], past: ["", "-{0} W.", "", "", "", "-{0} W."] };
+
+#[rustfmt::skip]
+static _FIELD_1128: _RelativeField = _RelativeField { relatives: &[(-1, "leschte Mount"), (0, "dëse Mount"), (1, "nächste Mount")], future: ["", "an {0} Mount", "", "", "", "a(n) {0} Méint"], past: ["", "virun {0} Mount", "", "", "", "viru(n) {0} Méint"] };
+
+#[rustfmt::skip]
+static _FIELD_1129: _RelativeField = _RelativeField { relatives: &[(-1, "leschte Mount"), (0, "dëse Mount"), (1, "nächste Mount")], future: ["", "an {0} M.", "", "", "", "a(n) {0} M."], past: ["", "virun {0} M.", "", "", "", "viru(n) {0} M."] };
+
+#[rustfmt::skip]
+static _FIELD_1130: _RelativeField = _RelativeField { relatives: &[(-1, "leschte Mount"), (0, "dëse Mount"), (1, "nächste Mount")], future: ["", "+{0} M.", "", "", "", "+{0} M."], past: ["", "-{0} M.", "", "", "", "-{0} M."] };
+
+#[rustfmt::skip]
+static _FIELD_1131: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "an {0} Quartal", "", "", "", "a(n) {0} Quartaler"], past: ["", "virun {0} Quartal", "", "", "", "viru(n) {0} Quartaler"] };
+
+#[rustfmt::skip]
+static _FIELD_1132: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "an {0} Q.", "", "", "", "a(n) {0} Q."], past: ["", "virun {0} Q.", "", "", "", "viru(n) {0} Q."] };
+
+#[rustfmt::skip]
+static _FIELD_1133: _RelativeField = _RelativeField { relatives: &[(-1, "last quarter"), (0, "this quarter"), (1, "next quarter")], future: ["", "+{0} Q.", "", "", "", "+{0} Q."], past: ["", "-{0} Q.", "", "", "", "-{0} Q."] };
+
+#[rustfmt::skip]
+static _FIELD_1134: _RelativeField = _RelativeField { relatives: &[(-1, "lescht Joer"), (0, "dëst Joer"), (1, "nächst Joer")], future: ["", "an {0} Joer", "", "", "", "a(n) {0} Joer"], past: ["", "virun {0} Joer", "", "", "", "viru(n) {0} Joer"] };
+
+#[rustfmt::skip]
+static _FIELD_1135: _RelativeField = _RelativeField { relatives: &[(-1, "lescht Joer"), (0, "dëst Joer"), (1, "nächst Joer")], future: ["", "an {0} J.", "", "", "", "a(n) {0} J."], past: ["", "virun {0} J.", "", "", "", "viru(n) {0} J."] };
+
+#[rustfmt::skip]
+static _FIELD_1136: _RelativeField = _RelativeField { relatives: &[(-1, "lescht Joer"), (0, "dëst Joer"), (1, "nächst Joer")], future: ["", "+{0} J.", "", "", "", "+{0} J."], past: ["", "-{0} J.", "", "", "", "-{0} J."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_93: [&_RelativeField; 24] = [
+    &_FIELD_1113,
+    &_FIELD_1114,
+    &_FIELD_1115,
+    &_FIELD_1116,
+    &_FIELD_1117,
+    &_FIELD_1118,
+    &_FIELD_1119,
+    &_FIELD_1120,
+    &_FIELD_1121,
+    &_FIELD_1122,
+    &_FIELD_1123,
+    &_FIELD_1124,
+    &_FIELD_1125,
+    &_FIELD_1126,
+    &_FIELD_1127,
+    &_FIELD_1128,
+    &_FIELD_1129,
+    &_FIELD_1130,
+    &_FIELD_1131,
+    &_FIELD_1132,
+    &_FIELD_1133,
+    &_FIELD_1134,
+    &_FIELD_1135,
+    &_FIELD_1136
+];
+
+#[rustfmt::skip]
+static _FIELD_1137: _RelativeField = _RelativeField { relatives: &[(0, "ຕອນນ\u{eb5}\u{ec9}")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ວ\u{eb4}ນາທ\u{eb5}"], past: ["", "", "", "", "", "{0} ວ\u{eb4}ນາທ\u{eb5}ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1138: _RelativeField = _RelativeField { relatives: &[(0, "ຕອນນ\u{eb5}\u{ec9}")], future: ["", "", "", "", "", "ໃນ {0} ວ\u{eb4}."], past: ["", "", "", "", "", "{0} ວ\u{eb4}. ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1139: _RelativeField = _RelativeField { relatives: &[(0, "ນາທ\u{eb5}ນ\u{eb5}\u{ec9}")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ນາທ\u{eb5}"], past: ["", "", "", "", "", "{0} ນາທ\u{eb5}ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1140: _RelativeField = _RelativeField { relatives: &[(0, "ນາທ\u{eb5}ນ\u{eb5}\u{ec9}")], future: ["", "", "", "", "", "ໃນ {0} ນທ."], past: ["", "", "", "", "", "{0} ນທ. ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1141: _RelativeField = _RelativeField { relatives: &[(0, "ຊ\u{ebb}\u{ec8}ວໂມງນ\u{eb5}\u{ec9}")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ຊ\u{ebb}\u{ec8}ວໂມງ"], past: ["", "", "", "", "", "{0} ຊ\u{ebb}\u{ec8}ວໂມງກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1142: _RelativeField = _RelativeField { relatives: &[(0, "ຊ\u{ebb}\u{ec8}ວໂມງນ\u{eb5}\u{ec9}")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ຊມ."], past: ["", "", "", "", "", "{0} ຊມ. ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1143: _RelativeField = _RelativeField { relatives: &[(-2, "ມ\u{eb7}\u{ec9}ກ\u{ec8}ອນ"), (-1, "ມ\u{eb7}\u{ec9}ວານ"), (0, "ມ\u{eb7}\u{ec9}ນ\u{eb5}\u{ec9}"), (1, "ມ\u{eb7}\u{ec9}ອ\u{eb7}\u{ec8}ນ"), (2, "ມ\u{eb7}\u{ec9}ຮ\u{eb7}")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ມ\u{eb7}\u{ec9}"], past: ["", "", "", "", "", "{0} ມ\u{eb7}\u{ec9}ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1144: _RelativeField = _RelativeField { relatives: &[(-1, "ອາທ\u{eb4}ດແລ\u{ec9}ວ"), (0, "ອາທ\u{eb4}ດນ\u{eb5}\u{ec9}"), (1, "ອາທ\u{eb4}ດໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ອາທ\u{eb4}ດ"], past: ["", "", "", "", "", "{0} ອາທ\u{eb4}ດກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1145: _RelativeField = _RelativeField { relatives: &[(-1, "ອາທ\u{eb4}ດແລ\u{ec9}ວ"), (0, "ອາທ\u{eb4}ດນ\u{eb5}\u{ec9}"), (1, "ອາທ\u{eb4}ດໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ອທ."], past: ["", "", "", "", "", "{0} ອທ. ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1146: _RelativeField = _RelativeField { relatives: &[(-1, "ເດ\u{eb7}ອນແລ\u{ec9}ວ"), (0, "ເດ\u{eb7}ອນນ\u{eb5}\u{ec9}"), (1, "ເດ\u{eb7}ອນໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ເດ\u{eb7}ອນ"], past: ["", "", "", "", "", "{0} ເດ\u{eb7}ອນກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1147: _RelativeField = _RelativeField { relatives: &[(-1, "ເດ\u{eb7}ອນແລ\u{ec9}ວ"), (0, "ເດ\u{eb7}ອນນ\u{eb5}\u{ec9}"), (1, "ເດ\u{eb7}ອນໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ດ."], past: ["", "", "", "", "", "{0} ດ. ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1148: _RelativeField = _RelativeField { relatives: &[(-1, "ໄຕຣມາດກ\u{ec8}ອນໜ\u{ec9}າ"), (0, "ໄຕຣມາດນ\u{eb5}\u{ec9}"), (1, "ໄຕຣມາດໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ໄຕຣມາດ"], past: ["", "", "", "", "", "{0} ໄຕຣມາດກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1149: _RelativeField = _RelativeField { relatives: &[(-1, "ໄຕຣມາດກ\u{ec8}ອນໜ\u{ec9}າ"), (0, "ໄຕຣມາດນ\u{eb5}\u{ec9}"), (1, "ໄຕຣມາດໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນ {0} ຕມ."], past: ["", "", "", "", "", "{0} ຕມ. ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _FIELD_1150: _RelativeField = _RelativeField { relatives: &[(-1, "ປ\u{eb5}ກາຍ"), (0, "ປ\u{eb5}ນ\u{eb5}\u{ec9}"), (1, "ປ\u{eb5}ໜ\u{ec9}າ")], future: ["", "", "", "", "", "ໃນອ\u{eb5}ກ {0} ປ\u{eb5}"], past: ["", "", "", "", "", "{0} ປ\u{eb5}ກ\u{ec8}ອນ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_94: [&_RelativeField; 24] = [
+    &_FIELD_1137,
+    &_FIELD_1138,
+    &_FIELD_1138,
+    &_FIELD_1139,
+    &_FIELD_1140,
+    &_FIELD_1140,
+    &_FIELD_1141,
+    &_FIELD_1142,
+    &_FIELD_1142,
+    &_FIELD_1143,
+    &_FIELD_1143,
+    &_FIELD_1143,
+    &_FIELD_1144,
+    &_FIELD_1145,
+    &_FIELD_1145,
+    &_FIELD_1146,
+    &_FIELD_1147,
+    &_FIELD_1147,
+    &_FIELD_1148,
+    &_FIELD_1149,
+    &_FIELD_1149,
+    &_FIELD_1150,
+    &_FIELD_1150,
+    &_FIELD_1150
+];
+
+#[rustfmt::skip]
+static _FIELD_1151: _RelativeField = _RelativeField { relatives: &[(0, "dabar")], future: ["", "po {0} sekundės", "", "po {0} sekundžių", "po {0} sekundės", "po {0} sekundžių"], past: ["", "prieš {0} sekundę", "", "prieš {0} sekundes", "prieš {0} sekundės", "prieš {0} sekundžių"] };
+
+#[rustfmt::skip]
+static _FIELD_1152: _RelativeField = _RelativeField { relatives: &[(0, "dabar")], future: ["", "po {0} sek.", "", "po {0} sek.", "po {0} sek.", "po {0} sek."], past: ["", "prieš {0} sek.", "", "prieš {0} sek.", "prieš {0} sek.", "prieš {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_1153: _RelativeField = _RelativeField { relatives: &[(0, "dabar")], future: ["", "po {0} s", "", "po {0} s", "po {0} s", "po {0} s"], past: ["", "prieš {0} s", "", "prieš {0} s", "prieš {0} s", "prieš {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1154: _RelativeField = _RelativeField { relatives: &[(0, "šią minutę")], future: ["", "po {0} minutės", "", "po {0} minučių", "po {0} minutės", "po {0} minučių"], past: ["", "prieš {0} minutę", "", "prieš {0} minutes", "prieš {0} minutės", "prieš {0} minučių"] };
+
+#[rustfmt::skip]
+static _FIELD_1155: _RelativeField = _RelativeField { relatives: &[(0, "šią minutę")], future: ["", "po {0} min.", "", "po {0} min.", "po {0} min.", "po {0} min."], past: ["", "prieš {0} min.", "", "prieš {0} min.", "prieš {0} min.", "prieš {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1156: _RelativeField = _RelativeField { relatives: &[(0, "šią valandą")], future: ["", "po {0} valandos", "", "po {0} valandų", "po {0} valandos", "po {0} valandų"], past: ["", "prieš {0} valandą", "", "prieš {0} valandas", "prieš {0} valandos", "prieš {0} valandų"] };
+
+#[rustfmt::skip]
+static _FIELD_1157: _RelativeField = _RelativeField { relatives: &[(0, "šią valandą")], future: ["", "po {0} val.", "", "po {0} val.", "po {0} val.", "po {0} val."], past: ["", "prieš {0} val.", "", "prieš {0} val.", "prieš {0} val.", "prieš {0} val."] };
+
+#[rustfmt::skip]
+static _FIELD_1158: _RelativeField = _RelativeField { relatives: &[(-2, "užvakar"), (-1, "vakar"), (0, "šiandien"), (1, "rytoj"), (2, "poryt")], future: ["", "po {0} dienos", "", "po {0} dienų", "po {0} dienos", "po {0} dienų"], past: ["", "prieš {0} dieną", "", "prieš {0} dienas", "prieš {0} dienos", "prieš {0} dienų"] };
+
+#[rustfmt::skip]
+static _FIELD_1159: _RelativeField = _RelativeField { relatives: &[(-2, "užvakar"), (-1, "vakar"), (0, "šiandien"), (1, "rytoj"), (2, "poryt")], future: ["", "po {0} d.", "", "po {0} d.", "po {0} d.", "po {0} d."], past: ["", "prieš {0} d.", "", "prieš {0} d.", "prieš {0} d.", "prieš {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1160: _RelativeField = _RelativeField { relatives: &[(-1, "praėjusią savaitę"), (0, "šią savaitę"), (1, "kitą savaitę")], future: ["", "po {0} savaitės", "", "po {0} savaičių", "po {0} savaitės", "po {0} savaičių"], past: ["", "prieš {0} savaitę", "", "prieš {0} savaites", "prieš {0} savaitės", "prieš {0} savaičių"] };
+
+#[rustfmt::skip]
+static _FIELD_1161: _RelativeField = _RelativeField { relatives: &[(-1, "praėjusią savaitę"), (0, "šią savaitę"), (1, "kitą savaitę")], future: ["", "po {0} sav.", "", "po {0} sav.", "po {0} sav.", "po {0} sav."], past: ["", "prieš {0} sav.", "", "prieš {0} sav.", "prieš {0} sav.", "prieš {0} sav."] };
+
+#[rustfmt::skip]
+static _FIELD_1162: _RelativeField = _RelativeField { relatives: &[(-1, "praėjusį mėnesį"), (0, "šį mėnesį"), (1, "kitą mėnesį")], future: ["", "po {0} mėnesio", "", "po {0} mėnesių", "po {0} mėnesio", "po {0} mėnesių"], past: ["", "prieš {0} mėnesį", "", "prieš {0} mėnesius", "prieš {0} mėnesio", "prieš {0} mėnesių"] };
+
+#[rustfmt::skip]
+static _FIELD_1163: _RelativeField = _RelativeField { relatives: &[(-1, "praėjusį mėnesį"), (0, "šį mėnesį"), (1, "kitą mėnesį")], future: ["", "po {0} mėn.", "", "po {0} mėn.", "po {0} mėn.", "po {0} mėn."], past: ["", "prieš {0} mėn.", "", "prieš {0} mėn.", "prieš {0} mėn.", "prieš {0} mėn."] };
+
+#[rustfmt::skip]
+static _FIELD_1164: _RelativeField = _RelativeField { relatives: &[(-1, "praėjęs ketvirtis"), (0, "šis ketvirtis"), (1, "kitas ketvirtis")], future: ["", "po {0} ketvirčio", "", "po {0} ketvirčių", "po {0} ketvirčio", "po {0} ketvirčių"], past: ["", "prieš {0} ketvirtį", "", "prieš {0} ketvirčius", "prieš {0} ketvirčio", "prieš {0} ketvirčių"] };
+
+#[rustfmt::skip]
+static _FIELD_1165: _RelativeField = _RelativeField { relatives: &[(-1, "praėjęs ketvirtis"), (0, "šis ketvirtis"), (1, "kitas ketvirtis")], future: ["", "po {0} ketv.", "", "po {0} ketv.", "po {0} ketv.", "po {0} ketv."], past: ["", "prieš {0} ketv.", "", "prieš {0} ketv.", "prieš {0} ketv.", "prieš {0} ketv."] };
+
+#[rustfmt::skip]
+static _FIELD_1166: _RelativeField = _RelativeField { relatives: &[(-1, "praėjusiais metais"), (0, "šiais metais"), (1, "kitais metais")], future: ["", "po {0} metų", "", "po {0} metų", "po {0} metų", "po {0} metų"], past: ["", "prieš {0} metus", "", "prieš {0} metus", "prieš {0} metų", "prieš {0} metų"] };
+
+#[rustfmt::skip]
+static _FIELD_1167: _RelativeField = _RelativeField { relatives: &[(-1, "praėjusiais metais"), (0, "šiais metais"), (1, "kitais metais")], future: ["", "po {0} m.", "", "po {0} m.", "po {0} m.", "po {0} m."], past: ["", "prieš {0} m.", "", "prieš {0} m.", "prieš {0} m.", "prieš {0} m."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_95: [&_RelativeField; 24] = [
+    &_FIELD_1151,
+    &_FIELD_1152,
+    &_FIELD_1153,
+    &_FIELD_1154,
+    &_FIELD_1155,
+    &_FIELD_1155,
+    &_FIELD_1156,
+    &_FIELD_1157,
+    &_FIELD_1157,
+    &_FIELD_1158,
+    &_FIELD_1159,
+    &_FIELD_1159,
+    &_FIELD_1160,
+    &_FIELD_1161,
+    &_FIELD_1161,
+    &_FIELD_1162,
+    &_FIELD_1163,
+    &_FIELD_1163,
+    &_FIELD_1164,
+    &_FIELD_1165,
+    &_FIELD_1165,
+    &_FIELD_1166,
+    &_FIELD_1167,
+    &_FIELD_1167
+];
+
+#[rustfmt::skip]
+static _FIELD_1168: _RelativeField = _RelativeField { relatives: &[(0, "tagad")], future: ["pēc {0} sekundēm", "pēc {0} sekundes", "", "", "", "pēc {0} sekundēm"], past: ["pirms {0} sekundēm", "pirms {0} sekundes", "", "", "", "pirms {0} sekundēm"] };
+
+#[rustfmt::skip]
+static _FIELD_1169: _RelativeField = _RelativeField { relatives: &[(0, "tagad")], future: ["pēc {0} sek.", "pēc {0} sek.", "", "", "", "pēc {0} sek."], past: ["pirms {0} sek.", "pirms {0} sek.", "", "", "", "pirms {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_1170: _RelativeField = _RelativeField { relatives: &[(0, "tagad")], future: ["pēc {0}\u{a0}s", "pēc {0}\u{a0}s", "", "", "", "pēc {0}\u{a0}s"], past: ["pirms {0}\u{a0}s", "pirms {0} s", "", "", "", "pirms {0}\u{a0}s"] };
+
+#[rustfmt::skip]
+static _FIELD_1171: _RelativeField = _RelativeField { relatives: &[(0, "šajā minūtē")], future: ["pēc {0} minūtēm", "pēc {0} minūtes", "", "", "", "pēc {0} minūtēm"], past: ["pirms {0} minūtēm", "pirms {0} minūtes", "", "", "", "pirms {0} minūtēm"] };
+
+#[rustfmt::skip]
+static _FIELD_1172: _RelativeField = _RelativeField { relatives: &[(0, "šajā minūtē")], future: ["pēc {0} min.", "pēc {0} min.", "", "", "", "pēc {0} min."], past: ["pirms {0} min.", "pirms {0} min.", "", "", "", "pirms {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1173: _RelativeField = _RelativeField { relatives: &[(0, "šajā minūtē")], future: ["pēc {0} min", "pēc {0}\u{a0}min", "", "", "", "pēc {0} min"], past: ["pirms {0}\u{a0}min", "pirms {0}\u{a0}min", "", "", "", "pirms {0}\u{a0}min"] };
+
+#[rustfmt::skip]
+static _FIELD_1174: _RelativeField = _RelativeField { relatives: &[(0, "šajā stundā")], future: ["pēc {0} stundām", "pēc {0} stundas", "", "", "", "pēc {0} stundām"], past: ["pirms {0} stundām", "pirms {0} stundas", "", "", "", "pirms {0} stundām"] };
+
+#[rustfmt::skip]
+static _FIELD_1175: _RelativeField = _RelativeField { relatives: &[(0, "šajā stundā")], future: ["pēc {0}\u{a0}st.", "pēc {0}\u{a0}st.", "", "", "", "pēc {0}\u{a0}st."], past: ["pirms {0}\u{a0}st.", "pirms {0}\u{a0}st.", "", "", "", "pirms {0}\u{a0}st."] };
+
+#[rustfmt::skip]
+static _FIELD_1176: _RelativeField = _RelativeField { relatives: &[(0, "šajā stundā")], future: ["pēc {0} h", "pēc {0} h", "", "", "", "pēc {0} h"], past: ["pirms {0} h", "pirms {0} h", "", "", "", "pirms {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1177: _RelativeField = _RelativeField { relatives: &[(-2, "aizvakar"), (-1, "vakar"), (0, "šodien"), (1, "rīt"), (2, "parīt")], future: ["pēc {0} dienām", "pēc {0} dienas", "", "", "", "pēc {0} dienām"], past: ["pirms {0} dienām", "pirms {0} dienas", "", "", "", "pirms {0} dienām"] };
+
+#[rustfmt::skip]
+static _FIELD_1178: _RelativeField = _RelativeField { relatives: &[(-2, "aizvakar"), (-1, "vakar"), (0, "šodien"), (1, "rīt"), (2, "parīt")], future: ["pēc {0} d.", "pēc {0}\u{a0}d.", "", "", "", "pēc {0} d."], past: ["pirms {0} d.", "pirms {0}\u{a0}d.", "", "", "", "pirms {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1179: _RelativeField = _RelativeField { relatives: &[(-1, "pagājušajā nedēļā"), (0, "šajā nedēļā"), (1, "nākamajā nedēļā")], future: ["pēc {0} nedēļām", "pēc {0} nedēļas", "", "", "", "pēc {0} nedēļām"], past: ["pirms {0} nedēļām", "pirms {0} nedēļas", "", "", "", "pirms {0} nedēļām"] };
+
+#[rustfmt::skip]
+static _FIELD_1180: _RelativeField = _RelativeField { relatives: &[(-1, "pag. ned."), (0, "šajā ned."), (1, "nāk. ned.")], future: ["pēc {0} ned.", "pēc {0} ned.", "", "", "", "pēc {0} ned."], past: ["pirms {0} ned.", "pirms {0} ned.", "", "", "", "pirms {0} ned."] };
+
+#[rustfmt::skip]
+static _FIELD_1181: _RelativeField = _RelativeField { relatives: &[(-1, "pagājušajā mēnesī"), (0, "šajā mēnesī"), (1, "nākamajā mēnesī")], future: ["pēc {0} mēnešiem", "pēc {0} mēneša", "", "", "", "pēc {0} mēnešiem"], past: ["pirms {0} mēnešiem", "pirms {0} mēneša", "", "", "", "pirms {0} mēnešiem"] };
+
+#[rustfmt::skip]
+static _FIELD_1182: _RelativeField = _RelativeField { relatives: &[(-1, "pag. mēn."), (0, "šajā mēn."), (1, "nāk. mēn.")], future: ["pēc {0} mēn.", "pēc {0} mēn.", "", "", "", "pēc {0} mēn."], past: ["pirms {0} mēn.", "pirms {0} mēn.", "", "", "", "pirms {0} mēn."] };
+
+#[rustfmt::skip]
+static _FIELD_1183: _RelativeField = _RelativeField { relatives: &[(-1, "pēdējais ceturksnis"), (0, "šis ceturksnis"), (1, "nākamais ceturksnis")], future: ["pēc {0}\u{a0}ceturkšņiem", "pēc {0}\u{a0}ceturkšņa", "", "", "", "pēc {0}\u{a0}ceturkšņiem"], past: ["pirms {0}\u{a0}ceturkšņiem", "pirms {0}\u{a0}ceturkšņa", "", "", "", "pirms {0}\u{a0}ceturkšņiem"] };
+
+#[rustfmt::skip]
+static _FIELD_1184: _RelativeField = _RelativeField { relatives: &[(-1, "pēdējais ceturksnis"), (0, "šis ceturksnis"), (1, "nākamais ceturksnis")], future: ["pēc {0}\u{a0}cet.", "pēc {0}\u{a0}cet.", "", "", "", "pēc {0}\u{a0}cet."], past: ["pirms {0}\u{a0}cet.", "pirms {0}\u{a0}cet.", "", "", "", "pirms {0}\u{a0}cet."] };
+
+#[rustfmt::skip]
+static _FIELD_1185: _RelativeField = _RelativeField { relatives: &[(-1, "pagājušajā gadā"), (0, "šajā gadā"), (1, "nākamajā gadā")], future: ["pēc {0} gadiem", "pēc {0} gada", "", "", "", "pēc {0} gadiem"], past: ["pirms {0} gadiem", "pirms {0} gada", "", "", "", "pirms {0} gadiem"] };
+
+#[rustfmt::skip]
+static _FIELD_1186: _RelativeField = _RelativeField { relatives: &[(-1, "pag. gadā"), (0, "šajā g."), (1, "nāk. gadā")], future: ["pēc {0} g.", "pēc {0} g.", "", "", "", "pēc {0} g."], past: ["pirms {0} g.", "pirms {0} g.", "", "", "", "pirms {0} g."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_96: [&_RelativeField; 24] = [
+    &_FIELD_1168,
+    &_FIELD_1169,
+    &_FIELD_1170,
+    &_FIELD_1171,
+    &_FIELD_1172,
+    &_FIELD_1173,
+    &_FIELD_1174,
+    &_FIELD_1175,
+    &_FIELD_1176,
+    &_FIELD_1177,
+    &_FIELD_1178,
+    &_FIELD_1178,
+    &_FIELD_1179,
+    &_FIELD_1180,
+    &_FIELD_1180,
+    &_FIELD_1181,
+    &_FIELD_1182,
+    &_FIELD_1182,
+    &_FIELD_1183,
+    &_FIELD_1184,
+    &_FIELD_1184,
+    &_FIELD_1185,
+    &_FIELD_1186,
+    &_FIELD_1186
+];
+
+#[rustfmt::skip]
+static _FIELD_1187: _RelativeField = _RelativeField { relatives: &[(0, "एहि समय")], future: ["", "", "", "", "", "{0} स\u{947}क\u{947}\u{902}ड म\u{947}"], past: ["", "", "", "", "", "{0} स\u{947}क\u{947}\u{902}ड पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1188: _RelativeField = _RelativeField { relatives: &[(0, "एहि मिनट")], future: ["", "", "", "", "", "{0} मिनट म\u{947}"], past: ["", "", "", "", "", "{0} मिनट पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1189: _RelativeField = _RelativeField { relatives: &[(0, "एहि घ\u{902}टा")], future: ["", "", "", "", "", "{0} घ\u{902}टा म\u{947}"], past: ["", "", "", "", "", "{0} घ\u{902}टा पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1190: _RelativeField = _RelativeField { relatives: &[(-1, "काल\u{94d}हिक दिन"), (0, "आज\u{941}क दिन"), (1, "काल\u{94d}हिक दिन")], future: ["", "", "", "", "", "{0} दिन म\u{947}"], past: ["", "", "", "", "", "{0} दिन म\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1191: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल काल\u{94d}हि"), (0, "आज\u{941}क दिन"), (1, "आबय वला काल\u{94d}हि")], future: ["", "", "", "", "", "{0} दिन म\u{947}"], past: ["", "", "", "", "", "{0}दिन पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1192: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल सप\u{94d}ताह"), (0, "एहि सप\u{94d}ताह"), (1, "अगिला सप\u{94d}ताह")], future: ["", "", "", "", "", "{0} सप\u{94d}ताह म\u{947}"], past: ["", "", "", "", "", "{0} सप\u{94d}ताह पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1193: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल माह"), (0, "एहि माह"), (1, "अगिला माह")], future: ["", "", "", "", "", "{0} माह म\u{947}"], past: ["", "", "", "", "", "{0} माह पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1194: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल माह"), (0, "एहि माह"), (1, "अगिला माह")], future: ["", "", "", "", "", "{0}माह म\u{947}"], past: ["", "", "", "", "", "{0}माह पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1195: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल तिमाही"), (0, "एहि तिमाही"), (1, "अगिला तिमाही")], future: ["", "", "", "", "", "{0} तिमाही म\u{947}"], past: ["", "", "", "", "", "{0} तिमाही पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1196: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल बरख"), (0, "एहि बरख"), (1, "अगिला बरख")], future: ["", "", "", "", "", "{0} बरख म\u{947}"], past: ["", "", "", "", "", "{0} बरख पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1197: _RelativeField = _RelativeField { relatives: &[(-1, "बीतल बरख"), (0, "एहि बरख"), (1, "अगिला बरख")], future: ["", "", "", "", "", "{0}बरख म\u{947}"], past: ["", "", "", "", "", "{0}बरख पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_97: [&_RelativeField; 24] = [
+    &_FIELD_1187,
+    &_FIELD_1187,
+    &_FIELD_1187,
+    &_FIELD_1188,
+    &_FIELD_1188,
+    &_FIELD_1188,
+    &_FIELD_1189,
+    &_FIELD_1189,
+    &_FIELD_1189,
+    &_FIELD_1190,
+    &_FIELD_1191,
+    &_FIELD_1191,
+    &_FIELD_1192,
+    &_FIELD_1192,
+    &_FIELD_1192,
+    &_FIELD_1193,
+    &_FIELD_1193,
+    &_FIELD_1194,
+    &_FIELD_1195,
+    &_FIELD_1195,
+    &_FIELD_1195,
+    &_FIELD_1196,
+    &_FIELD_1196,
+    &_FIELD_1197
+];
+
+#[rustfmt::skip]
+static _FIELD_1198: _RelativeField = _RelativeField { relatives: &[(0, "ināianei")], future: ["", "", "", "", "", "+{0} hēkona"], past: ["", "", "", "", "", "-{0} hēkona i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1199: _RelativeField = _RelativeField { relatives: &[(0, "ināianei")], future: ["", "", "", "", "", "+{0} h."], past: ["", "", "", "", "", "-{0} h."] };
+
+#[rustfmt::skip]
+static _FIELD_1200: _RelativeField = _RelativeField { relatives: &[(0, "ināianei")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1201: _RelativeField = _RelativeField { relatives: &[(0, "i tēnei meneti")], future: ["", "", "", "", "", "+{0} meneti"], past: ["", "", "", "", "", "-{0} meneti i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1202: _RelativeField = _RelativeField { relatives: &[(0, "i tēnei meneti")], future: ["", "", "", "", "", "+{0} men."], past: ["", "", "", "", "", "-{0} men."] };
+
+#[rustfmt::skip]
+static _FIELD_1203: _RelativeField = _RelativeField { relatives: &[(0, "i tēnei meneti")], future: ["", "", "", "", "", "+{0} men"], past: ["", "", "", "", "", "-{0} men"] };
+
+#[rustfmt::skip]
+static _FIELD_1204: _RelativeField = _RelativeField { relatives: &[(0, "i tēnei haora")], future: ["", "", "", "", "", "+{0} haora"], past: ["", "", "", "", "", "-{0} haora i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1205: _RelativeField = _RelativeField { relatives: &[(0, "i tēnei haora")], future: ["", "", "", "", "", "+{0} h."], past: ["", "", "", "", "", "-{0} h."] };
+
+#[rustfmt::skip]
+static _FIELD_1206: _RelativeField = _RelativeField { relatives: &[(0, "i tēnei haora")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1207: _RelativeField = _RelativeField { relatives: &[(-1, "inanahi"), (0, "i tēnei rā"), (1, "āpōpō")], future: ["", "", "", "", "", "+{0} rā"], past: ["", "", "", "", "", "-{0} rā i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1208: _RelativeField = _RelativeField { relatives: &[(-1, "inanahi"), (0, "i tēnei rā"), (1, "āpōpō")], future: ["", "", "", "", "", "+{0} rā"], past: ["", "", "", "", "", "-{0} rā"] };
+
+#[rustfmt::skip]
+static _FIELD_1209: _RelativeField = _RelativeField { relatives: &[(-1, "inanahi"), (0, "i tēnei rā"), (1, "āpōpō")], future: ["", "", "", "", "", "+{0} r"], past: ["", "", "", "", "", "-{0} r"] };
+
+#[rustfmt::skip]
+static _FIELD_1210: _RelativeField = _RelativeField { relatives: &[(-1, "i tērā wiki"), (0, "i tēnei wiki"), (1, "ā tērā wiki")], future: ["", "", "", "", "", "+{0} wiki"], past: ["", "", "", "", "", "-{0} wiki i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1211: _RelativeField = _RelativeField { relatives: &[(-1, "i tērā w."), (0, "i tēnei w."), (1, "ā tērā w.")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_1212: _RelativeField = _RelativeField { relatives: &[(-1, "i tērā marama"), (0, "i tēnei marama"), (1, "ā tērā marama")], future: ["", "", "", "", "", "+{0} marama"], past: ["", "", "", "", "", "-{0} marama i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1213: _RelativeField = _RelativeField { relatives: &[(-1, "i tērā m."), (0, "i tēnei m."), (1, "ā tērā m.")], future: ["", "", "", "", "", "+{0} m."], past: ["", "", "", "", "", "-{0} m."] };
+
+#[rustfmt::skip]
+static _FIELD_1214: _RelativeField = _RelativeField { relatives: &[(-1, "hauwhā whakamutunga"), (0, "tēnei hauwhā"), (1, "tērā hauwhā")], future: ["", "", "", "", "", "+{0} hauwhā"], past: ["", "", "", "", "", "-{0} hauwhā i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1215: _RelativeField = _RelativeField { relatives: &[(-1, "hauwhā whakamutunga"), (0, "tēnei hauwhā"), (1, "tērā hauwhā")], future: ["", "", "", "", "", "+{0} hwh"], past: ["", "", "", "", "", "-{0} hwh"] };
+
+#[rustfmt::skip]
+static _FIELD_1216: _RelativeField = _RelativeField { relatives: &[(-1, "hauwhā whakamutunga"), (0, "tēnei hauwhā"), (1, "tērā hauwhā")], future: ["", "", "", "", "", "+{0} Hwh"], past: ["", "", "", "", "", "-{0} hwh"] };
+
+#[rustfmt::skip]
+static _FIELD_1217: _RelativeField = _RelativeField { relatives: &[(-1, "i tērā tau"), (0, "i tēnei tau"), (1, "ā tērā tau")], future: ["", "", "", "", "", "ā te {0} tau"], past: ["", "", "", "", "", "i te {0} tau i mua"] };
+
+#[rustfmt::skip]
+static _FIELD_1218: _RelativeField = _RelativeField { relatives: &[(-1, "i tērā tau"), (0, "i tēnei tau"), (1, "ā tērā tau")], future: ["", "", "", "", "", "ā te {0} tau"], past: ["", "", "", "", "", "i te {0} tau"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_98: [&_RelativeField; 24] = [
+    &_FIELD_1198,
+    &_FIELD_1199,
+    &_FIELD_1200,
+    &_FIELD_1201,
+    &_FIELD_1202,
+    &_FIELD_1203,
+    &_FIELD_1204,
+    &_FIELD_1205,
+    &_FIELD_1206,
+    &_FIELD_1207,
+    &_FIELD_1208,
+    &_FIELD_1209,
+    &_FIELD_1210,
+    &_FIELD_1211,
+    &_FIELD_1211,
+    &_FIELD_1212,
+    &_FIELD_1213,
+    &_FIELD_1213,
+    &_FIELD_1214,
+    &_FIELD_1215,
+    &_FIELD_1216,
+    &_FIELD_1217,
+    &_FIELD_1218,
+    &_FIELD_1218
+];
+
+#[rustfmt::skip]
+static _FIELD_1219: _RelativeField = _RelativeField { relatives: &[(0, "сега")], future: ["", "за {0} секунда", "", "", "", "за {0} секунди"], past: ["", "пред {0} секунда", "", "", "", "пред {0} секунди"] };
+
+#[rustfmt::skip]
+static _FIELD_1220: _RelativeField = _RelativeField { relatives: &[(0, "сега")], future: ["", "за {0} сек.", "", "", "", "за {0} сек."], past: ["", "пред {0} сек.", "", "", "", "пред {0} сек."] };
+
+#[rustfmt::skip]
+static _FIELD_1221: _RelativeField = _RelativeField { relatives: &[(0, "оваа минута")], future: ["", "за {0} минута", "", "", "", "за {0} минути"], past: ["", "пред {0} минута", "", "", "", "пред {0} минути"] };
+
+#[rustfmt::skip]
+static _FIELD_1222: _RelativeField = _RelativeField { relatives: &[(0, "оваа минута")], future: ["", "за {0} мин.", "", "", "", "за {0} мин."], past: ["", "пред {0} мин.", "", "", "", "пред {0} мин."] };
+
+#[rustfmt::skip]
+static _FIELD_1223: _RelativeField = _RelativeField { relatives: &[(0, "часов")], future: ["", "за {0} час", "", "", "", "за {0} часа"], past: ["", "пред {0} час", "", "", "", "пред {0} часа"] };
+
+#[rustfmt::skip]
+static _FIELD_1224: _RelativeField = _RelativeField { relatives: &[(-2, "завчера"), (-1, "вчера"), (0, "денес"), (1, "утре"), (2, "задутре")], future: ["", "за {0} ден", "", "", "", "за {0} дена"], past: ["", "пред {0} ден", "", "", "", "пред {0} дена"] };
+
+#[rustfmt::skip]
+static _FIELD_1225: _RelativeField = _RelativeField { relatives: &[(-1, "минатата седмица"), (0, "оваа седмица"), (1, "следната седмица")], future: ["", "за {0} седмица", "", "", "", "за {0} седмици"], past: ["", "пред {0} седмица", "", "", "", "пред {0} седмици"] };
+
+#[rustfmt::skip]
+static _FIELD_1226: _RelativeField = _RelativeField { relatives: &[(-1, "минатиот месец"), (0, "овој месец"), (1, "следниот месец")], future: ["", "за {0} месец", "", "", "", "за {0} месеци"], past: ["", "пред {0} месец", "", "", "", "пред {0} месеци"] };
+
+#[rustfmt::skip]
+static _FIELD_1227: _RelativeField = _RelativeField { relatives: &[(-1, "последното тромесечје"), (0, "ова тромесечје"), (1, "следното тромесечје")], future: ["", "за {0} тромесечје", "", "", "", "за {0} тромесечја"], past: ["", "пред {0} тромесечје", "", "", "", "пред {0} тромесечја"] };
+
+#[rustfmt::skip]
+static _FIELD_1228: _RelativeField = _RelativeField { relatives: &[(-1, "последното тромесечје"), (0, "ова тромесечје"), (1, "следното тромесечје")], future: ["", "за {0} тромес.", "", "", "", "за {0} тромес."], past: ["", "пред {0} тромес.", "", "", "", "пред {0} тромес."] };
+
+#[rustfmt::skip]
+static _FIELD_1229: _RelativeField = _RelativeField { relatives: &[(-1, "лани"), (0, "оваа година"), (1, "догодина")], future: ["", "за {0} година", "", "", "", "за {0} години"], past: ["", "пред {0} година", "", "", "", "пред {0} години"] };
+
+#[rustfmt::skip]
+static _FIELD_1230: _RelativeField = _RelativeField { relatives: &[(-1, "минатата год."), (0, "оваа год."), (1, "следната год.")], future: ["", "за {0} год.", "", "", "", "за {0} год."], past: ["", "пред {0} год.", "", "", "", "пред {0} год."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_99: [&_RelativeField; 24] = [
+    &_FIELD_1219,
+    &_FIELD_1220,
+    &_FIELD_1220,
+    &_FIELD_1221,
+    &_FIELD_1222,
+    &_FIELD_1222,
+    &_FIELD_1223,
+    &_FIELD_1223,
+    &_FIELD_1223,
+    &_FIELD_1224,
+    &_FIELD_1224,
+    &_FIELD_1224,
+    &_FIELD_1225,
+    &_FIELD_1225,
+    &_FIELD_1225,
+    &_FIELD_1226,
+    &_FIELD_1226,
+    &_FIELD_1226,
+    &_FIELD_1227,
+    &_FIELD_1228,
+    &_FIELD_1228,
+    &_FIELD_1229,
+    &_FIELD_1230,
+    &_FIELD_1230
+];
+
+#[rustfmt::skip]
+static _FIELD_1231: _RelativeField = _RelativeField { relatives: &[(0, "ഇപ\u{d4d}പോൾ")], future: ["", "{0} സെക\u{d4d}കൻഡിൽ", "", "", "", "{0} സെക\u{d4d}കൻഡിൽ"], past: ["", "{0} സെക\u{d4d}കൻഡ\u{d4d} മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} സെക\u{d4d}കൻഡ\u{d4d} മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1232: _RelativeField = _RelativeField { relatives: &[(0, "ഈ മിനിറ\u{d4d}റിൽ")], future: ["", "{0} മിനിറ\u{d4d}റിൽ", "", "", "", "{0} മിനിറ\u{d4d}റിൽ"], past: ["", "{0} മിനിറ\u{d4d}റ\u{d4d} മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} മിനിറ\u{d4d}റ\u{d4d} മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1233: _RelativeField = _RelativeField { relatives: &[(0, "ഈ മണിക\u{d4d}ക\u{d42}റിൽ")], future: ["", "{0} മണിക\u{d4d}ക\u{d42}റിൽ", "", "", "", "{0} മണിക\u{d4d}ക\u{d42}റിൽ"], past: ["", "{0} മണിക\u{d4d}ക\u{d42}ർ മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} മണിക\u{d4d}ക\u{d42}ർ മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1234: _RelativeField = _RelativeField { relatives: &[(-2, "മിനിഞ\u{d4d}ഞ\u{d3e}ന\u{d4d}ന\u{d4d}"), (-1, "ഇന\u{d4d}നലെ"), (0, "ഇന\u{d4d}ന\u{d4d}"), (1, "ന\u{d3e}ളെ"), (2, "മറ\u{d4d}റന\u{d4d}ന\u{d3e}ൾ")], future: ["", "{0} ദിവസത\u{d4d}തിൽ", "", "", "", "{0} ദിവസത\u{d4d}തിൽ"], past: ["", "{0} ദിവസം മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} ദിവസം മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1235: _RelativeField = _RelativeField { relatives: &[(-1, "കഴിഞ\u{d4d}ഞ ആഴ\u{d4d}\u{200c}ച"), (0, "ഈ ആഴ\u{d4d}ച"), (1, "അട\u{d41}ത\u{d4d}ത ആഴ\u{d4d}ച")], future: ["", "{0} ആഴ\u{d4d}ചയിൽ", "", "", "", "{0} ആഴ\u{d4d}ചയിൽ"], past: ["", "{0} ആഴ\u{d4d}ച മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} ആഴ\u{d4d}ച മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1236: _RelativeField = _RelativeField { relatives: &[(-1, "കഴിഞ\u{d4d}ഞ മ\u{d3e}സം"), (0, "ഈ മ\u{d3e}സം"), (1, "അട\u{d41}ത\u{d4d}ത മ\u{d3e}സം")], future: ["", "{0} മ\u{d3e}സത\u{d4d}തിൽ", "", "", "", "{0} മ\u{d3e}സത\u{d4d}തിൽ"], past: ["", "{0} മ\u{d3e}സം മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} മ\u{d3e}സം മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1237: _RelativeField = _RelativeField { relatives: &[(-1, "കഴിഞ\u{d4d}ഞ പ\u{d3e}ദം"), (0, "ഈ പ\u{d3e}ദം"), (1, "അട\u{d41}ത\u{d4d}ത പ\u{d3e}ദം")], future: ["", "{0} പ\u{d3e}ദത\u{d4d}തിൽ", "", "", "", "{0} പ\u{d3e}ദത\u{d4d}തിൽ"], past: ["", "{0} പ\u{d3e}ദം മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} പ\u{d3e}ദം മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _FIELD_1238: _RelativeField = _RelativeField { relatives: &[(-1, "കഴിഞ\u{d4d}ഞ വർഷം"), (0, "ഈ വർ\u{200c}ഷം"), (1, "അട\u{d41}ത\u{d4d}തവർഷം")], future: ["", "{0} വർഷത\u{d4d}തിൽ", "", "", "", "{0} വർഷത\u{d4d}തിൽ"], past: ["", "{0} വർഷം മ\u{d41}മ\u{d4d}പ\u{d4d}", "", "", "", "{0} വർഷം മ\u{d41}മ\u{d4d}പ\u{d4d}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_100: [&_RelativeField; 24] = [
+    &_FIELD_1231,
+    &_FIELD_1231,
+    &_FIELD_1231,
+    &_FIELD_1232,
+    &_FIELD_1232,
+    &_FIELD_1232,
+    &_FIELD_1233,
+    &_FIELD_1233,
+    &_FIELD_1233,
+    &_FIELD_1234,
+    &_FIELD_1234,
+    &_FIELD_1234,
+    &_FIELD_1235,
+    &_FIELD_1235,
+    &_FIELD_1235,
+    &_FIELD_1236,
+    &_FIELD_1236,
+    &_FIELD_1236,
+    &_FIELD_1237,
+    &_FIELD_1237,
+    &_FIELD_1237,
+    &_FIELD_1238,
+    &_FIELD_1238,
+    &_FIELD_1238
+];
+
+#[rustfmt::skip]
+static _FIELD_1239: _RelativeField = _RelativeField { relatives: &[(0, "одоо")], future: ["", "{0} секундын дараа", "", "", "", "{0} секундын дараа"], past: ["", "{0} секундын өмнө", "", "", "", "{0} секундын өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1240: _RelativeField = _RelativeField { relatives: &[(0, "одоо")], future: ["", "{0} сек дараа", "", "", "", "{0} сек дараа"], past: ["", "{0} сек өмнө", "", "", "", "{0} сек өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1241: _RelativeField = _RelativeField { relatives: &[(0, "энэ минут")], future: ["", "{0} минутын дараа", "", "", "", "{0} минутын дараа"], past: ["", "{0} минутын өмнө", "", "", "", "{0} минутын өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1242: _RelativeField = _RelativeField { relatives: &[(0, "энэ минут")], future: ["", "{0} мин дараа", "", "", "", "{0} мин дараа"], past: ["", "{0} мин өмнө", "", "", "", "{0} мин өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1243: _RelativeField = _RelativeField { relatives: &[(0, "энэ цаг")], future: ["", "{0} цагийн дараа", "", "", "", "{0} цагийн дараа"], past: ["", "{0} цагийн өмнө", "", "", "", "{0} цагийн өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1244: _RelativeField = _RelativeField { relatives: &[(0, "энэ цаг")], future: ["", "{0} ц дараа", "", "", "", "{0} ц дараа"], past: ["", "{0} ц өмнө", "", "", "", "{0} ц өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1245: _RelativeField = _RelativeField { relatives: &[(-2, "уржигдар"), (-1, "өчигдөр"), (0, "өнөөдөр"), (1, "маргааш"), (2, "нөгөөдөр")], future: ["", "{0} өдрийн дараа", "", "", "", "{0} өдрийн дараа"], past: ["", "{0} өдрийн өмнө", "", "", "", "{0} өдрийн өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1246: _RelativeField = _RelativeField { relatives: &[(-1, "өнгөрсөн долоо хоног"), (0, "энэ долоо хоног"), (1, "ирэх долоо хоног")], future: ["", "{0} долоо хоногийн дараа", "", "", "", "{0} долоо хоногийн дараа"], past: ["", "{0} долоо хоногийн өмнө", "", "", "", "{0} долоо хоногийн өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1247: _RelativeField = _RelativeField { relatives: &[(-1, "өнгөрсөн 7 хоног"), (0, "энэ 7 хоног"), (1, "ирэх 7 хоног")], future: ["", "{0} 7 хоногийн дараа", "", "", "", "{0} 7 хоногийн дараа"], past: ["", "{0} 7 хоногийн өмнө", "", "", "", "{0} 7 хоногийн өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1248: _RelativeField = _RelativeField { relatives: &[(-1, "өнгөрсөн сар"), (0, "энэ сар"), (1, "ирэх сар")], future: ["", "{0} сарын дараа", "", "", "", "{0} сарын дараа"], past: ["", "{0} сарын өмнө", "", "", "", "{0} сарын өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1249: _RelativeField = _RelativeField { relatives: &[(-1, "өнгөрсөн улирал"), (0, "энэ улирал"), (1, "дараагийн улирал")], future: ["", "{0} улирлын дараа", "", "", "", "{0} улирлын дараа"], past: ["", "{0} улирлын өмнө", "", "", "", "{0} улирлын өмнө"] };
+
+#[rustfmt::skip]
+static _FIELD_1250: _RelativeField = _RelativeField { relatives: &[(-1, "өнгөрсөн жил"), (0, "энэ жил"), (1, "ирэх жил")], future: ["", "{0} жилийн дараа", "", "", "", "{0} жилийн дараа"], past: ["", "{0} жилийн өмнө", "", "", "", "{0} жилийн өмнө"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_101: [&_RelativeField; 24] = [
+    &_FIELD_1239,
+    &_FIELD_1240,
+    &_FIELD_1240,
+    &_FIELD_1241,
+    &_FIELD_1242,
+    &_FIELD_1242,
+    &_FIELD_1243,
+    &_FIELD_1244,
+    &_FIELD_1244,
+    &_FIELD_1245,
+    &_FIELD_1245,
+    &_FIELD_1245,
+    &_FIELD_1246,
+    &_FIELD_1247,
+    &_FIELD_1247,
+    &_FIELD_1248,
+    &_FIELD_1248,
+    &_FIELD_1248,
+    &_FIELD_1249,
+    &_FIELD_1249,
+    &_FIELD_1249,
+    &_FIELD_1250,
+    &_FIELD_1250,
+    &_FIELD_1250
+];
+
+#[rustfmt::skip]
+static _FIELD_1251: _RelativeField = _RelativeField { relatives: &[(-1, "ঙর\u{9be}ং"), (0, "ঙসি"), (1, "হয\u{9bc}েং")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1252: _RelativeField = _RelativeField { relatives: &[(-1, "হ\u{9be}ক\u{9c1}ম/ মম\u{9be}ংগী চহি"), (0, "ক\u{9c1}মশি"), (1, "মথং চহি")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_102: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1251,
+    &_FIELD_1251,
+    &_FIELD_1251,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1252,
+    &_FIELD_1252,
+    &_FIELD_1252
+];
+
+#[rustfmt::skip]
+static _FIELD_1253: _RelativeField = _RelativeField { relatives: &[(0, "आत\u{94d}ता")], future: ["", "{0} स\u{947}क\u{902}दामध\u{94d}य\u{947}", "", "", "", "{0} स\u{947}क\u{902}दा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} स\u{947}क\u{902}दाप\u{942}र\u{94d}वी", "", "", "", "{0} स\u{947}क\u{902}दा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1254: _RelativeField = _RelativeField { relatives: &[(0, "आत\u{94d}ता")], future: ["", "{0} स\u{947}. मध\u{94d}य\u{947}", "", "", "", "{0} स\u{947}. मध\u{94d}य\u{947}"], past: ["", "{0} स\u{947}. प\u{942}र\u{94d}वी", "", "", "", "{0} स\u{947}. प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1255: _RelativeField = _RelativeField { relatives: &[(0, "आत\u{94d}ता")], future: ["", "{0} स\u{947}. मध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} स\u{947}. मध\u{94d}य\u{947}"], past: ["", "{0} स\u{947}. प\u{942}र\u{94d}वी", "", "", "", "{0} स\u{947}. प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1256: _RelativeField = _RelativeField { relatives: &[(0, "या मिनिटात")], future: ["", "{0} मिनिटामध\u{94d}य\u{947}", "", "", "", "{0} मिनिटा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} मिनिटाप\u{942}र\u{94d}वी", "", "", "", "{0} मिनिटा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1257: _RelativeField = _RelativeField { relatives: &[(0, "या मिनिटात")], future: ["", "{0} मिनि. मध\u{94d}य\u{947}", "", "", "", "{0} मिनि. मध\u{94d}य\u{947}"], past: ["", "{0} मिनि. प\u{942}र\u{94d}वी", "", "", "", "{0} मिनि. प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1258: _RelativeField = _RelativeField { relatives: &[(0, "तासात")], future: ["", "{0} तासामध\u{94d}य\u{947}", "", "", "", "{0} तासा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} तासाप\u{942}र\u{94d}वी", "", "", "", "{0} तासा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1259: _RelativeField = _RelativeField { relatives: &[(0, "तासात")], future: ["", "य\u{947}त\u{94d}या {0} तासामध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} तासा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} तासाप\u{942}र\u{94d}वी", "", "", "", "{0} तासा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1260: _RelativeField = _RelativeField { relatives: &[(-1, "काल"), (0, "आज"), (1, "उद\u{94d}या")], future: ["", "य\u{947}त\u{94d}या {0} दिवसामध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} दिवसा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} दिवसाप\u{942}र\u{94d}वी", "", "", "", "{0} दिवसा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1261: _RelativeField = _RelativeField { relatives: &[(-1, "काल"), (0, "आज"), (1, "उद\u{94d}या")], future: ["", "{0} दिवसामध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} दिवसा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} दिवसाप\u{942}र\u{94d}वी", "", "", "", "{0} दिवसा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1262: _RelativeField = _RelativeField { relatives: &[(-1, "काल"), (0, "आज"), (1, "उद\u{94d}या")], future: ["", "{0} दिवसामध\u{94d}य\u{947}", "", "", "", "{0} दिवसा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} दिवसाप\u{942}र\u{94d}वी", "", "", "", "{0} दिवसा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1263: _RelativeField = _RelativeField { relatives: &[(-1, "मागील आठवडा"), (0, "हा आठवडा"), (1, "प\u{941}ढील आठवडा")], future: ["", "{0} आठवड\u{94d}यामध\u{94d}य\u{947}", "", "", "", "{0} आठवड\u{94d}या\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} आठवड\u{94d}याप\u{942}र\u{94d}वी", "", "", "", "{0} आठवड\u{94d}या\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1264: _RelativeField = _RelativeField { relatives: &[(-1, "मागील आठवडा"), (0, "हा आठवडा"), (1, "प\u{941}ढील आठवडा")], future: ["", "य\u{947}त\u{94d}या {0} आठवड\u{94d}यामध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} आठवड\u{94d}या\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} आठवड\u{94d}याप\u{942}र\u{94d}वी", "", "", "", "{0} आठवड\u{94d}या\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1265: _RelativeField = _RelativeField { relatives: &[(-1, "मागील महिना"), (0, "हा महिना"), (1, "प\u{941}ढील महिना")], future: ["", "य\u{947}त\u{94d}या {0} महिन\u{94d}यामध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} महिन\u{94d}या\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} महिन\u{94d}याप\u{942}र\u{94d}वी", "", "", "", "{0} महिन\u{94d}या\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1266: _RelativeField = _RelativeField { relatives: &[(-1, "मागील महिना"), (0, "हा महिना"), (1, "प\u{941}ढील महिना")], future: ["", "{0} महिन\u{94d}यामध\u{94d}य\u{947}", "", "", "", "{0} महिन\u{94d}यामध\u{94d}य\u{947}"], past: ["", "{0} महिन\u{94d}याप\u{942}र\u{94d}वी", "", "", "", "{0} महिन\u{94d}या\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1267: _RelativeField = _RelativeField { relatives: &[(-1, "मागील महिना"), (0, "हा महिना"), (1, "प\u{941}ढील महिना")], future: ["", "{0} महिन\u{94d}यामध\u{94d}य\u{947}", "", "", "", "{0} महिन\u{94d}या\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} महिन\u{94d}याप\u{942}र\u{94d}वी", "", "", "", "{0} महिन\u{94d}या\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1268: _RelativeField = _RelativeField { relatives: &[(-1, "मागील तिमाही"), (0, "ही तिमाही"), (1, "प\u{941}ढील तिमाही")], future: ["", "{0} तिमाहीमध\u{94d}य\u{947}", "", "", "", "{0} तिमाही\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} तिमाहीप\u{942}र\u{94d}वी", "", "", "", "{0} तिमाही\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1269: _RelativeField = _RelativeField { relatives: &[(-1, "मागील तिमाही"), (0, "ही तिमाही"), (1, "प\u{941}ढील तिमाही")], future: ["", "य\u{947}त\u{94d}या {0} तिमाहीमध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} तिमाही\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} तिमाहीप\u{942}र\u{94d}वी", "", "", "", "{0} तिमाही\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1270: _RelativeField = _RelativeField { relatives: &[(-1, "मागील वर\u{94d}ष"), (0, "ह\u{947} वर\u{94d}ष"), (1, "प\u{941}ढील वर\u{94d}ष")], future: ["", "य\u{947}त\u{94d}या {0} वर\u{94d}षामध\u{94d}य\u{947}", "", "", "", "य\u{947}त\u{94d}या {0} वर\u{94d}षा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} वर\u{94d}षाप\u{942}र\u{94d}वी", "", "", "", "{0} वर\u{94d}षा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _FIELD_1271: _RelativeField = _RelativeField { relatives: &[(-1, "मागील वर\u{94d}ष"), (0, "ह\u{947} वर\u{94d}ष"), (1, "प\u{941}ढील वर\u{94d}ष")], future: ["", "{0} वर\u{94d}षामध\u{94d}य\u{947}", "", "", "", "{0} वर\u{94d}षा\u{902}मध\u{94d}य\u{947}"], past: ["", "{0} वर\u{94d}षाप\u{942}र\u{94d}वी", "", "", "", "{0} वर\u{94d}षा\u{902}प\u{942}र\u{94d}वी"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_103: [&_RelativeField; 24] = [
+    &_FIELD_1253,
+    &_FIELD_1254,
+    &_FIELD_1255,
+    &_FIELD_1256,
+    &_FIELD_1257,
+    &_FIELD_1257,
+    &_FIELD_1258,
+    &_FIELD_1258,
+    &_FIELD_1259,
+    &_FIELD_1260,
+    &_FIELD_1261,
+    &_FIELD_1262,
+    &_FIELD_1263,
+    &_FIELD_1264,
+    &_FIELD_1264,
+    &_FIELD_1265,
+    &_FIELD_1266,
+    &_FIELD_1267,
+    &_FIELD_1268,
+    &_FIELD_1269,
+    &_FIELD_1268,
+    &_FIELD_1270,
+    &_FIELD_1271,
+    &_FIELD_1270
+];
+
+#[rustfmt::skip]
+static _FIELD_1272: _RelativeField = _RelativeField { relatives: &[(0, "sekarang")], future: ["", "", "", "", "", "dalam {0} saat"], past: ["", "", "", "", "", "{0} saat lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1273: _RelativeField = _RelativeField { relatives: &[(0, "sekarang")], future: ["", "", "", "", "", "dlm {0} saat"], past: ["", "", "", "", "", "{0} saat lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1274: _RelativeField = _RelativeField { relatives: &[(0, "pada minit ini")], future: ["", "", "", "", "", "dalam {0} minit"], past: ["", "", "", "", "", "{0} minit lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1275: _RelativeField = _RelativeField { relatives: &[(0, "pada minit ini")], future: ["", "", "", "", "", "dlm {0} min"], past: ["", "", "", "", "", "{0} min lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1276: _RelativeField = _RelativeField { relatives: &[(-2, "kelmarin"), (-1, "semalam"), (0, "hari ini"), (1, "esok"), (2, "lusa")], future: ["", "", "", "", "", "dalam {0} hari"], past: ["", "", "", "", "", "{0} hari lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1277: _RelativeField = _RelativeField { relatives: &[(-2, "kelmarin"), (-1, "semalam"), (0, "hari ini"), (1, "esok"), (2, "lusa")], future: ["", "", "", "", "", "dlm {0} hari"], past: ["", "", "", "", "", "{0} hari lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1278: _RelativeField = _RelativeField { relatives: &[(-2, "kelmarin"), (-1, "semlm"), (0, "hari ini"), (1, "esok"), (2, "lusa")], future: ["", "", "", "", "", "dlm {0} hari"], past: ["", "", "", "", "", "{0} hari lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1279: _RelativeField = _RelativeField { relatives: &[(-1, "minggu lalu"), (0, "minggu ini"), (1, "minggu depan")], future: ["", "", "", "", "", "dalam {0} minggu"], past: ["", "", "", "", "", "{0} minggu lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1280: _RelativeField = _RelativeField { relatives: &[(-1, "mgu lepas"), (0, "mgu ini"), (1, "mgu depan")], future: ["", "", "", "", "", "dlm {0} mgu"], past: ["", "", "", "", "", "{0} mgu lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1281: _RelativeField = _RelativeField { relatives: &[(-1, "bulan lalu"), (0, "bulan ini"), (1, "bulan depan")], future: ["", "", "", "", "", "dalam {0} bulan"], past: ["", "", "", "", "", "{0} bulan lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1282: _RelativeField = _RelativeField { relatives: &[(-1, "bln lalu"), (0, "bln ini"), (1, "bln depan")], future: ["", "", "", "", "", "dlm {0} bln"], past: ["", "", "", "", "", "{0} bln lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1283: _RelativeField = _RelativeField { relatives: &[(-1, "bln lalu"), (0, "bln ini"), (1, "bln depan")], future: ["", "", "", "", "", "dlm {0} bln"], past: ["", "", "", "", "", "{0} bulan lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1284: _RelativeField = _RelativeField { relatives: &[(-1, "suku tahun lalu"), (0, "suku tahun ini"), (1, "suku tahun seterusnya")], future: ["", "", "", "", "", "dalam {0} suku tahun"], past: ["", "", "", "", "", "{0} suku tahun lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1285: _RelativeField = _RelativeField { relatives: &[(-1, "suku lepas"), (0, "suku ini"), (1, "suku seterusnya")], future: ["", "", "", "", "", "dlm {0} suku thn"], past: ["", "", "", "", "", "{0} suku thn lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1286: _RelativeField = _RelativeField { relatives: &[(-1, "tahun lalu"), (0, "tahun ini"), (1, "tahun depan")], future: ["", "", "", "", "", "dalam {0} tahun"], past: ["", "", "", "", "", "{0} tahun lalu"] };
+
+#[rustfmt::skip]
+static _FIELD_1287: _RelativeField = _RelativeField { relatives: &[(-1, "thn lepas"), (0, "thn ini"), (1, "thn depan")], future: ["", "", "", "", "", "dalam {0} thn"], past: ["", "", "", "", "", "{0} thn lalu"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_104: [&_RelativeField; 24] = [
+    &_FIELD_1272,
+    &_FIELD_1273,
+    &_FIELD_1273,
+    &_FIELD_1274,
+    &_FIELD_1275,
+    &_FIELD_1275,
+    &_FIELD_864,
+    &_FIELD_865,
+    &_FIELD_865,
+    &_FIELD_1276,
+    &_FIELD_1277,
+    &_FIELD_1278,
+    &_FIELD_1279,
+    &_FIELD_1280,
+    &_FIELD_1280,
+    &_FIELD_1281,
+    &_FIELD_1282,
+    &_FIELD_1283,
+    &_FIELD_1284,
+    &_FIELD_1285,
+    &_FIELD_1285,
+    &_FIELD_1286,
+    &_FIELD_1287,
+    &_FIELD_1287
+];
+
+#[rustfmt::skip]
+static _FIELD_1288: _RelativeField = _RelativeField { relatives: &[(0, "issa")], future: ["", "sa sekonda oħra", "sa {0} sekondi oħra", "sa {0} sekondi oħra", "sa {0}-il sekonda oħra", "sa {0} sekonda oħra"], past: ["", "sekonda ilu", "{0} sekondi ilu", "{0} sekondi ilu", "{0}-il sekonda ilu", "{0} sekonda ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1289: _RelativeField = _RelativeField { relatives: &[(0, "issa")], future: ["", "sa sek. oħra", "sa {0} sek. oħra", "sa {0} sek. oħra", "sa {0}-il sek. oħra", "sa {0} sek. oħra"], past: ["", "sek. ilu", "{0} sek. ilu", "{0} sek. ilu", "{0}-il sek. ilu", "{0} sek. ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1290: _RelativeField = _RelativeField { relatives: &[(0, "din il-minuta")], future: ["", "sa minuta oħra", "sa {0} minuti oħra", "sa {0} minuti oħra", "sa {0}-il minuta oħra", "sa {0} minuta oħra"], past: ["", "minuta ilu", "{0} minuti ilu", "{0} minuti ilu", "{0}-il minuta ilu", "{0} minuta ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1291: _RelativeField = _RelativeField { relatives: &[(0, "din il-minuta")], future: ["", "sa min. oħra", "sa {0} min. oħra", "sa {0} min. oħra", "sa {0}-il min. oħra", "sa {0} min. oħra"], past: ["", "min. ilu", "{0} min. ilu", "{0} min. ilu", "{0}-il min. ilu", "{0} min. ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1292: _RelativeField = _RelativeField { relatives: &[(0, "din il-minuta")], future: ["", "sa min. oħra", "sa {0} min. oħra", "sa {0} min. oħra", "sa {0}-il min", "sa {0} min. oħra"], past: ["", "min. ilu", "{0} min. ilu", "{0} min. ilu", "{0}-il min. ilu", "{0} min. ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1293: _RelativeField = _RelativeField { relatives: &[(0, "din is-siegħa")], future: ["", "fi żmien siegħa oħra", "fi żmien sagħtejn", "fi żmien {0} sigħat", "fi żmien {0}-il siegħa", "fi żmien {0} siegħa"], past: ["", "siegħa ilu", "sagħtejn ilu", "{0} sigħat ilu", "{0}-il siegħa ilu", "{0} siegħa ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1294: _RelativeField = _RelativeField { relatives: &[(-1, "lbieraħ"), (0, "illum"), (1, "għada")], future: ["", "fi żmien ġurnata", "fi żmien jumejn oħra", "fi żmien {0} ġranet oħra", "fi żmien {0}-il ġurnata oħra", "fi żmien {0} ġurnata oħra"], past: ["", "ġurnata ilu", "jumejn ilu", "{0} ġranet ilu", "{0}-il ġurnata ilu", "{0} ġurnata ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1295: _RelativeField = _RelativeField { relatives: &[(-1, "lbieraħ"), (0, "illum"), (1, "għada")], future: ["", "fi żmien ġurnata", "fi żmien jumejn oħra", "fi żmien {0} ġurnata oħra", "fi żmien {0}-il ġurnata oħra", "fi żmien {0} ġurnata oħra"], past: ["", "ġurnata ilu", "jumejn ilu", "{0} ġranet ilu", "{0}-il ġurnata ilu", "{0} ġurnata ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1296: _RelativeField = _RelativeField { relatives: &[(-1, "il-ġimgħa li għaddiet"), (0, "din il-ġimgħa"), (1, "il-ġimgħa d-dieħla")], future: ["", "sa ġimgħa oħra", "sa ġimagħtejn oħra", "sa {0} ġimgħat oħra", "sa {0}-il ġimgħa oħra", "sa {0} ġimgħa oħra"], past: ["", "ġimgħa ilu", "ġimagħtejn ilu", "{0} ġimgħat ilu", "{0}-il ġimgħa ilu", "{0} ġimgħa ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1297: _RelativeField = _RelativeField { relatives: &[(-1, "ix-xahar li għadda"), (0, "dan ix-xahar"), (1, "ix-xahar id-dieħel")], future: ["", "sa xahar ieħor", "fi xahrejn oħra", "fi {0} xhur oħra", "fi {0}-xahar oħra", "fi {0} xahar oħra"], past: ["", "xahar ilu", "xahrejn ilu", "{0} xhur ilu", "{0}-il xahar ilu", "{0} xahar ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1298: _RelativeField = _RelativeField { relatives: &[(-1, "ix-xahar li għadda"), (0, "dan ix-xahar"), (1, "ix-xahar id-dieħel")], future: ["", "sa xahar ieħor", "sa xahrejn oħra", "sa {0} xhur oħra", "sa {0}-il xahar oħra", "sa {0} xahar oħra"], past: ["", "{0} xahar ilu", "xahrejn ilu", "{0} xhur ilu", "{0}-il xahar ilu", "{0} xahar ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1299: _RelativeField = _RelativeField { relatives: &[(-1, "ix-xahar li għadda"), (0, "dan ix-xahar"), (1, "ix-xahar id-dieħel")], future: ["", "sa xahar ieħor", "sa xahrejn oħra", "sa {0} xhur oħra", "sa {0}-il xahar oħra", "sa {0} xahar oħra"], past: ["", "xahar ilu", "xahrejn ilu", "{0} xhur ilu", "{0}-il xahar ilu", "{0} xahar ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1300: _RelativeField = _RelativeField { relatives: &[(-1, "il-kwart ta’ sena li għadda"), (0, "il-kwart ta’ sena li qegħdin fih"), (1, "il-kwart li jmiss tas-sena")], future: ["", "f’{0} kwart ta’ sena oħrajn", "f’{0} kwarti ta’ sena oħrajn", "f’{0} kwarti ta’ sena oħrajn", "f’{0}-il kwart ta’ sena oħrajn", "f’{0} kwart ta’ sena oħrajn"], past: ["", "il-kwart ta’ sena li għadda", "{0} kwarti ta’ sena li għaddew", "{0} kwarti ta’ sena li għaddew", "{0}-il kwart ta’ sena li għaddew", "{0} kwart ta’ sena li għaddew"] };
+
+#[rustfmt::skip]
+static _FIELD_1301: _RelativeField = _RelativeField { relatives: &[(-1, "il-kwart ta’ sena li għadda"), (0, "il-kwart ta’ sena li qegħdin fih"), (1, "il-kwart li jmiss tas-sena")], future: ["", "fil-kwart tas-sena li ġej", "f’{0} kwarti ta’ sena oħrajn", "f’{0} kwarti ta’ sena oħrajn", "f’{0}-il kwart ta’ sena oħrajn", "f’{0} kwart ta’ sena oħrajn"], past: ["", "fil-kwart tas-sena li għadda", "{0} kwarti ta’ sena ilu", "{0} kwarti ta’ sena ilu", "{0}-il kwart ta’ sena ilu", "{0} kwart ta’ sena ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1302: _RelativeField = _RelativeField { relatives: &[(-1, "il-kwart ta’ sena li għadda"), (0, "il-kwart ta’ sena li qegħdin fih"), (1, "il-kwart li jmiss tas-sena")], future: ["", "fi kwart ta’ sena ieħor", "f’{0} kwarti ta’ sena oħrajn", "f’{0} kwarti ta’ sena oħrajn", "f’{0}-il kwart ta’ sena oħrajn", "f’{0} kwart ta’ sena oħrajn"], past: ["", "fil-kwart tas-sena li għadda", "{0} kwarti ta’ sena ilu", "{0} kwarti ta’ sena ilu", "{0}-il kwart ta’ sena ilu", "{0} kwart ta’ sena ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1303: _RelativeField = _RelativeField { relatives: &[(-1, "is-sena l-oħra"), (0, "din is-sena"), (1, "is-sena d-dieħla")], future: ["", "fi żmien sena", "fi żmien sentejn oħra", "fi żmien {0} snin oħra", "fi żmien {0}-il sena oħra", "fi żmien {0} sena oħra"], past: ["", "sena ilu", "sentejn ilu", "{0} snin ilu", "{0}-il sena ilu", "{0} sena ilu"] };
+
+#[rustfmt::skip]
+static _FIELD_1304: _RelativeField = _RelativeField { relatives: &[(-1, "is-sena l-oħra"), (0, "din is-sena"), (1, "is-sena d-dieħla")], future: ["", "fi żmien sena", "fi żmien sentejn oħra", "fi żmien {0} snin oħra", "fi żmien {0}-il sena oħra", "fi żmien {0} sena oħra"], past: ["", "sa sena ilu", "sentejn ilu", "{0} snin ilu", "{0}-il sena ilu", "{0} sena ilu"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_105: [&_RelativeField; 24] = [
+    &_FIELD_1288,
+    &_FIELD_1289,
+    &_FIELD_1289,
+    &_FIELD_1290,
+    &_FIELD_1291,
+    &_FIELD_1292,
+    &_FIELD_1293,
+    &_FIELD_1293,
+    &_FIELD_1293,
+    &_FIELD_1294,
+    &_FIELD_1294,
+    &_FIELD_1295,
+    &_FIELD_1296,
+    &_FIELD_1296,
+    &_FIELD_1296,
+    &_FIELD_1297,
+    &_FIELD_1298,
+    &_FIELD_1299,
+    &_FIELD_1300,
+    &_FIELD_1301,
+    &_FIELD_1302,
+    &_FIELD_1303,
+    &_FIELD_1304,
+    &_FIELD_1303
+];
+
+#[rustfmt::skip]
+static _FIELD_1305: _RelativeField = _RelativeField { relatives: &[(0, "ယခ\u{102f}")], future: ["", "", "", "", "", "{0} စက\u{1039}ကန\u{1037}\u{103a}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} စက\u{1039}ကန\u{1037}\u{103a}"] };
+
+#[rustfmt::skip]
+static _FIELD_1306: _RelativeField = _RelativeField { relatives: &[(0, "ဤမ\u{102d}နစ\u{103a}")], future: ["", "", "", "", "", "{0} မ\u{102d}နစ\u{103a}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} မ\u{102d}နစ\u{103a}"] };
+
+#[rustfmt::skip]
+static _FIELD_1307: _RelativeField = _RelativeField { relatives: &[(0, "ဤအချ\u{102d}န\u{103a}")], future: ["", "", "", "", "", "{0} နာရ\u{102e}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} နာရ\u{102e}"] };
+
+#[rustfmt::skip]
+static _FIELD_1308: _RelativeField = _RelativeField { relatives: &[(-2, "တစ\u{103a}နေ\u{1037}က"), (-1, "မနေ\u{1037}က"), (0, "ယနေ\u{1037}"), (1, "မနက\u{103a}ဖြန\u{103a}"), (2, "သန\u{103a}ဘက\u{103a}ခါ")], future: ["", "", "", "", "", "{0} ရက\u{103a}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} ရက\u{103a}"] };
+
+#[rustfmt::skip]
+static _FIELD_1309: _RelativeField = _RelativeField { relatives: &[(-1, "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} သ\u{102e}တင\u{103a}းပတ\u{103a}"), (0, "ယခ\u{102f} သ\u{102e}တင\u{103a}းပတ\u{103a}"), (1, "လာမည\u{1037}\u{103a} သ\u{102e}တင\u{103a}းပတ\u{103a}")], future: ["", "", "", "", "", "{0} ပတ\u{103a}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} ပတ\u{103a}"] };
+
+#[rustfmt::skip]
+static _FIELD_1310: _RelativeField = _RelativeField { relatives: &[(-1, "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a}လ"), (0, "ယခ\u{102f}လ"), (1, "လာမည\u{1037}\u{103a}လ")], future: ["", "", "", "", "", "{0} လအတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} လ"] };
+
+#[rustfmt::skip]
+static _FIELD_1311: _RelativeField = _RelativeField { relatives: &[(-1, "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} သ\u{102f}\u{1036}းလပတ\u{103a}"), (0, "ယခ\u{102f} သ\u{102f}\u{1036}းလပတ\u{103a}"), (1, "လာမည\u{1037}\u{103a} သ\u{102f}\u{1036}းလပတ\u{103a}")], future: ["", "", "", "", "", "သ\u{102f}\u{1036}းလပတ\u{103a}ကာလ {0} အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} သ\u{102f}\u{1036}းလပတ\u{103a}ကာလ {0} ခ\u{102f}အတ\u{103d}င\u{103a}း"] };
+
+#[rustfmt::skip]
+static _FIELD_1312: _RelativeField = _RelativeField { relatives: &[(-1, "ပြ\u{102e}းခ\u{1032}\u{1037}သောသ\u{102f}\u{1036}းလပတ\u{103a}"), (0, "ယခ\u{102f}သ\u{102f}\u{1036}းလပတ\u{103a}"), (1, "နောက\u{103a}လာမည\u{1037}\u{103a}သ\u{102f}\u{1036}းလပတ\u{103a}")], future: ["", "", "", "", "", "သ\u{102f}\u{1036}းလပတ\u{103a}ကာလ {0} ခ\u{102f}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} သ\u{102f}\u{1036}းလပတ\u{103a}ကာလ {0} ခ\u{102f}အတ\u{103d}င\u{103a}း"] };
+
+#[rustfmt::skip]
+static _FIELD_1313: _RelativeField = _RelativeField { relatives: &[(-1, "ယမန\u{103a}န\u{103e}စ\u{103a}"), (0, "ယခ\u{102f}န\u{103e}စ\u{103a}"), (1, "လာမည\u{1037}\u{103a}န\u{103e}စ\u{103a}")], future: ["", "", "", "", "", "{0} န\u{103e}စ\u{103a}အတ\u{103d}င\u{103a}း"], past: ["", "", "", "", "", "ပြ\u{102e}းခ\u{1032}\u{1037}သည\u{1037}\u{103a} {0} န\u{103e}စ\u{103a}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_106: [&_RelativeField; 24] = [
+    &_FIELD_1305,
+    &_FIELD_1305,
+    &_FIELD_1305,
+    &_FIELD_1306,
+    &_FIELD_1306,
+    &_FIELD_1306,
+    &_FIELD_1307,
+    &_FIELD_1307,
+    &_FIELD_1307,
+    &_FIELD_1308,
+    &_FIELD_1308,
+    &_FIELD_1308,
+    &_FIELD_1309,
+    &_FIELD_1309,
+    &_FIELD_1309,
+    &_FIELD_1310,
+    &_FIELD_1310,
+    &_FIELD_1310,
+    &_FIELD_1311,
+    &_FIELD_1312,
+    &_FIELD_1312,
+    &_FIELD_1313,
+    &_FIELD_1313,
+    &_FIELD_1313
+];
+
+#[rustfmt::skip]
+static _FIELD_1314: _RelativeField = _RelativeField { relatives: &[(0, "nå")], future: ["", "om {0} sekund", "", "", "", "om {0} sekunder"], past: ["", "for {0} sekund siden", "", "", "", "for {0} sekunder siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1315: _RelativeField = _RelativeField { relatives: &[(0, "nå")], future: ["", "om {0} sek", "", "", "", "om {0} sek"], past: ["", "for {0} sek siden", "", "", "", "for {0} sek siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1316: _RelativeField = _RelativeField { relatives: &[(0, "nå")], future: ["", "+{0} s", "", "", "", "+{0} s"], past: ["", "-{0} s", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1317: _RelativeField = _RelativeField { relatives: &[(0, "dette minuttet")], future: ["", "om {0} minutt", "", "", "", "om {0} minutter"], past: ["", "for {0} minutt siden", "", "", "", "for {0} minutter siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1318: _RelativeField = _RelativeField { relatives: &[(0, "dette minuttet")], future: ["", "om {0} min", "", "", "", "om {0} min"], past: ["", "for {0} min siden", "", "", "", "for {0} min siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1319: _RelativeField = _RelativeField { relatives: &[(0, "dette minuttet")], future: ["", "+{0} min", "", "", "", "+{0} min"], past: ["", "-{0} min", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1320: _RelativeField = _RelativeField { relatives: &[(0, "denne timen")], future: ["", "om {0} time", "", "", "", "om {0} timer"], past: ["", "for {0} time siden", "", "", "", "for {0} timer siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1321: _RelativeField = _RelativeField { relatives: &[(0, "denne timen")], future: ["", "om {0} t", "", "", "", "om {0} t"], past: ["", "for {0} t siden", "", "", "", "for {0} t siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1322: _RelativeField = _RelativeField { relatives: &[(0, "denne timen")], future: ["", "+{0} t", "", "", "", "+{0} t"], past: ["", "-{0} t", "", "", "", "-{0} t"] };
+
+#[rustfmt::skip]
+static _FIELD_1323: _RelativeField = _RelativeField { relatives: &[(-2, "i forgårs"), (-1, "i går"), (0, "i dag"), (1, "i morgen"), (2, "i overmorgen")], future: ["", "om {0} døgn", "", "", "", "om {0} døgn"], past: ["", "for {0} døgn siden", "", "", "", "for {0} døgn siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1324: _RelativeField = _RelativeField { relatives: &[(-2, "i forgårs"), (-1, "i går"), (0, "i dag"), (1, "i morgen"), (2, "i overmorgen")], future: ["", "om {0} d.", "", "", "", "om {0} d."], past: ["", "for {0} d. siden", "", "", "", "for {0} d. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1325: _RelativeField = _RelativeField { relatives: &[(-2, "-2 d."), (-1, "i går"), (0, "i dag"), (1, "i morgen"), (2, "+2 d.")], future: ["", "+{0} d.", "", "", "", "+{0} d."], past: ["", "-{0} d.", "", "", "", "-{0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1326: _RelativeField = _RelativeField { relatives: &[(-1, "forrige uke"), (0, "denne uken"), (1, "neste uke")], future: ["", "om {0} uke", "", "", "", "om {0} uker"], past: ["", "for {0} uke siden", "", "", "", "for {0} uker siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1327: _RelativeField = _RelativeField { relatives: &[(-1, "forrige uke"), (0, "denne uken"), (1, "neste uke")], future: ["", "om {0} u.", "", "", "", "om {0} u."], past: ["", "for {0} u. siden", "", "", "", "for {0} u. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1328: _RelativeField = _RelativeField { relatives: &[(-1, "forrige måned"), (0, "denne måneden"), (1, "neste måned")], future: ["", "om {0} måned", "", "", "", "om {0} måneder"], past: ["", "for {0} måned siden", "", "", "", "for {0} måneder siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1329: _RelativeField = _RelativeField { relatives: &[(-1, "forrige md."), (0, "denne md."), (1, "neste md.")], future: ["", "om {0} md.", "", "", "", "om {0} md."], past: ["", "for {0} md. siden", "", "", "", "for {0} md. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1330: _RelativeField = _RelativeField { relatives: &[(-1, "forrige md."), (0, "denne md."), (1, "neste md.")], future: ["", "+{0} md.", "", "", "", "+{0} md."], past: ["", "-{0} md.", "", "", "", "-{0} md."] };
+
+#[rustfmt::skip]
+static _FIELD_1331: _RelativeField = _RelativeField { relatives: &[(-1, "forrige kvartal"), (0, "dette kvartalet"), (1, "neste kvartal")], future: ["", "om {0} kvartal", "", "", "", "om {0} kvartaler"], past: ["", "for {0} kvartal siden", "", "", "", "for {0} kvartaler siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1332: _RelativeField = _RelativeField { relatives: &[(-1, "forrige kv."), (0, "dette kv."), (1, "neste kv.")], future: ["", "om {0} kv.", "", "", "", "om {0} kv."], past: ["", "for {0} kv. siden", "", "", "", "for {0} kv. siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1333: _RelativeField = _RelativeField { relatives: &[(-1, "forrige kv."), (0, "dette kv."), (1, "neste kv.")], future: ["", "+{0} kv.", "", "", "", "+{0} kv."], past: ["", "-{0} kv.", "", "", "", "-{0} kv."] };
+
+#[rustfmt::skip]
+static _FIELD_1334: _RelativeField = _RelativeField { relatives: &[(-1, "i fjor"), (0, "i år"), (1, "neste år")], future: ["", "om {0} år", "", "", "", "om {0} år"], past: ["", "for {0} år siden", "", "", "", "for {0} år siden"] };
+
+#[rustfmt::skip]
+static _FIELD_1335: _RelativeField = _RelativeField { relatives: &[(-1, "i fjor"), (0, "i år"), (1, "neste år")], future: ["", "+{0} år", "", "", "", "+{0} år"], past: ["", "-{0} år", "", "", "", "-{0} år"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_107: [&_RelativeField; 24] = [
+    &_FIELD_1314,
+    &_FIELD_1315,
+    &_FIELD_1316,
+    &_FIELD_1317,
+    &_FIELD_1318,
+    &_FIELD_1319,
+    &_FIELD_1320,
+    &_FIELD_1321,
+    &_FIELD_1322,
+    &_FIELD_1323,
+    &_FIELD_1324,
+    &_FIELD_1325,
+    &_FIELD_1326,
+    &_FIELD_1327,
+    &_FIELD_1327,
+    &_FIELD_1328,
+    &_FIELD_1329,
+    &_FIELD_1330,
+    &_FIELD_1331,
+    &_FIELD_1332,
+    &_FIELD_1333,
+    &_FIELD_1334,
+    &_FIELD_1334,
+    &_FIELD_1335
+];
+
+#[rustfmt::skip]
+static _FIELD_1336: _RelativeField = _RelativeField { relatives: &[(0, "अहिल\u{947}")], future: ["", "{0} स\u{947}क\u{947}न\u{94d}डमा", "", "", "", "{0} स\u{947}क\u{947}न\u{94d}डमा"], past: ["", "{0} स\u{947}क\u{947}न\u{94d}ड पहिल\u{947}", "", "", "", "{0} स\u{947}क\u{947}न\u{94d}ड पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1337: _RelativeField = _RelativeField { relatives: &[(0, "यही मिन\u{947}टमा")], future: ["", "{0} मिन\u{947}टमा", "", "", "", "{0} मिन\u{947}टमा"], past: ["", "{0} मिन\u{947}ट पहिल\u{947}", "", "", "", "{0} मिन\u{947}ट पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1338: _RelativeField = _RelativeField { relatives: &[(0, "यस घडीमा")], future: ["", "{0} घण\u{94d}टामा", "", "", "", "{0} घण\u{94d}टामा"], past: ["", "{0} घण\u{94d}टा पहिल\u{947}", "", "", "", "{0} घण\u{94d}टा पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1339: _RelativeField = _RelativeField { relatives: &[(-2, "अस\u{94d}ति"), (-1, "हिजो"), (0, "आज"), (1, "भोलि"), (2, "पर\u{94d}सि")], future: ["", "{0} दिनमा", "", "", "", "{0} दिनमा"], past: ["", "{0} दिन पहिल\u{947}", "", "", "", "{0} दिन पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1340: _RelativeField = _RelativeField { relatives: &[(-1, "गत हप\u{94d}ता"), (0, "यो हप\u{94d}ता"), (1, "आगामी हप\u{94d}ता")], future: ["", "{0} हप\u{94d}तामा", "", "", "", "{0} हप\u{94d}तामा"], past: ["", "{0} हप\u{94d}ता पहिल\u{947}", "", "", "", "{0} हप\u{94d}ता पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1341: _RelativeField = _RelativeField { relatives: &[(-1, "गत महिना"), (0, "यो महिना"), (1, "अर\u{94d}को महिना")], future: ["", "{0} महिनामा", "", "", "", "{0} महिनामा"], past: ["", "{0} महिना पहिल\u{947}", "", "", "", "{0} महिना पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1342: _RelativeField = _RelativeField { relatives: &[(-1, "गत महिना"), (0, "यो महिना"), (1, "आगामी महिना")], future: ["", "{0} महिनामा", "", "", "", "{0} महिनामा"], past: ["", "{0} महिना पहिल\u{947}", "", "", "", "{0} महिना पहिल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_1343: _RelativeField = _RelativeField { relatives: &[(-1, "अघिल\u{94d}लो सत\u{94d}र"), (0, "यो सत\u{94d}र"), (1, "अर\u{94d}को सत\u{94d}र")], future: ["", "+{0} सत\u{94d}रमा", "", "", "", "{0}सत\u{94d}रमा"], past: ["", "{0}सत\u{94d}र अघि", "", "", "", "{0}सत\u{94d}र अघि"] };
+
+#[rustfmt::skip]
+static _FIELD_1344: _RelativeField = _RelativeField { relatives: &[(-1, "अघिल\u{94d}लो सत\u{94d}र"), (0, "यो सत\u{94d}र"), (1, "अर\u{94d}को सत\u{94d}र")], future: ["", "{0}सत\u{94d}रमा", "", "", "", "{0}सत\u{94d}रमा"], past: ["", "{0}सत\u{94d}र अघि", "", "", "", "{0}सत\u{94d}र अघि"] };
+
+#[rustfmt::skip]
+static _FIELD_1345: _RelativeField = _RelativeField { relatives: &[(-1, "गत वर\u{94d}ष"), (0, "यो वर\u{94d}ष"), (1, "आगामी वर\u{94d}ष")], future: ["", "{0} वर\u{94d}षमा", "", "", "", "{0} वर\u{94d}षमा"], past: ["", "{0} वर\u{94d}ष अघि", "", "", "", "{0} वर\u{94d}ष अघि"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_108: [&_RelativeField; 24] = [
+    &_FIELD_1336,
+    &_FIELD_1336,
+    &_FIELD_1336,
+    &_FIELD_1337,
+    &_FIELD_1337,
+    &_FIELD_1337,
+    &_FIELD_1338,
+    &_FIELD_1338,
+    &_FIELD_1338,
+    &_FIELD_1339,
+    &_FIELD_1339,
+    &_FIELD_1339,
+    &_FIELD_1340,
+    &_FIELD_1340,
+    &_FIELD_1340,
+    &_FIELD_1341,
+    &_FIELD_1342,
+    &_FIELD_1342,
+    &_FIELD_1343,
+    &_FIELD_1344,
+    &_FIELD_1344,
+    &_FIELD_1345,
+    &_FIELD_1345,
+    &_FIELD_1345
+];
+
+#[rustfmt::skip]
+static _FIELD_1346: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "over {0} seconde", "", "", "", "over {0} seconden"], past: ["", "{0} seconde geleden", "", "", "", "{0} seconden geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1347: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "over {0} sec.", "", "", "", "over {0} sec."], past: ["", "{0} sec. geleden", "", "", "", "{0} sec. geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1348: _RelativeField = _RelativeField { relatives: &[(0, "binnen een minuut")], future: ["", "over {0} minuut", "", "", "", "over {0} minuten"], past: ["", "{0} minuut geleden", "", "", "", "{0} minuten geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1349: _RelativeField = _RelativeField { relatives: &[(0, "binnen een minuut")], future: ["", "over {0} min.", "", "", "", "over {0} min."], past: ["", "{0} min. geleden", "", "", "", "{0} min. geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1350: _RelativeField = _RelativeField { relatives: &[(0, "binnen een uur")], future: ["", "over {0} uur", "", "", "", "over {0} uur"], past: ["", "{0} uur geleden", "", "", "", "{0} uur geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1351: _RelativeField = _RelativeField { relatives: &[(-2, "eergisteren"), (-1, "gisteren"), (0, "vandaag"), (1, "morgen"), (2, "overmorgen")], future: ["", "over {0} dag", "", "", "", "over {0} dagen"], past: ["", "{0} dag geleden", "", "", "", "{0} dagen geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1352: _RelativeField = _RelativeField { relatives: &[(-2, "eergisteren"), (-1, "gisteren"), (0, "vandaag"), (1, "morgen"), (2, "overmorgen")], future: ["", "over {0} dag", "", "", "", "over {0} dgn"], past: ["", "{0} dag geleden", "", "", "", "{0} dgn geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1353: _RelativeField = _RelativeField { relatives: &[(-1, "vorige week"), (0, "deze week"), (1, "volgende week")], future: ["", "over {0} week", "", "", "", "over {0} weken"], past: ["", "{0} week geleden", "", "", "", "{0} weken geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1354: _RelativeField = _RelativeField { relatives: &[(-1, "vorige maand"), (0, "deze maand"), (1, "volgende maand")], future: ["", "over {0} maand", "", "", "", "over {0} maanden"], past: ["", "{0} maand geleden", "", "", "", "{0} maanden geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1355: _RelativeField = _RelativeField { relatives: &[(-1, "vorig kwartaal"), (0, "dit kwartaal"), (1, "volgend kwartaal")], future: ["", "over {0} kwartaal", "", "", "", "over {0} kwartalen"], past: ["", "{0} kwartaal geleden", "", "", "", "{0} kwartalen geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1356: _RelativeField = _RelativeField { relatives: &[(-1, "vorig kwartaal"), (0, "dit kwartaal"), (1, "volgend kwartaal")], future: ["", "over {0} kwart.", "", "", "", "over {0} kwart."], past: ["", "{0} kwart. geleden", "", "", "", "{0} kwart. geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1357: _RelativeField = _RelativeField { relatives: &[(-1, "vorig kwartaal"), (0, "dit kwartaal"), (1, "volgend kwartaal")], future: ["", "over {0} kw.", "", "", "", "over {0} kw."], past: ["", "{0} kw. geleden", "", "", "", "{0} kw. geleden"] };
+
+#[rustfmt::skip]
+static _FIELD_1358: _RelativeField = _RelativeField { relatives: &[(-1, "vorig jaar"), (0, "dit jaar"), (1, "volgend jaar")], future: ["", "over {0} jaar", "", "", "", "over {0} jaar"], past: ["", "{0} jaar geleden", "", "", "", "{0} jaar geleden"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_109: [&_RelativeField; 24] = [
+    &_FIELD_1346,
+    &_FIELD_1347,
+    &_FIELD_1347,
+    &_FIELD_1348,
+    &_FIELD_1349,
+    &_FIELD_1349,
+    &_FIELD_1350,
+    &_FIELD_1350,
+    &_FIELD_1350,
+    &_FIELD_1351,
+    &_FIELD_1352,
+    &_FIELD_1352,
+    &_FIELD_1353,
+    &_FIELD_1353,
+    &_FIELD_1353,
+    &_FIELD_1354,
+    &_FIELD_1354,
+    &_FIELD_1354,
+    &_FIELD_1355,
+    &_FIELD_1356,
+    &_FIELD_1357,
+    &_FIELD_1358,
+    &_FIELD_1358,
+    &_FIELD_1358
+];
+
+#[rustfmt::skip]
+static _FIELD_1359: _RelativeField = _RelativeField { relatives: &[(0, "no")], future: ["", "om {0} sekund", "", "", "", "om {0}\u{a0}sekund"], past: ["", "for {0} sekund sidan", "", "", "", "for {0} sekund sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1360: _RelativeField = _RelativeField { relatives: &[(0, "no")], future: ["", "om {0}\u{a0}sek", "", "", "", "om {0}\u{a0}sek"], past: ["", "for {0} sek sidan", "", "", "", "for {0} sek sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1361: _RelativeField = _RelativeField { relatives: &[(0, "no")], future: ["", "+{0} s", "", "", "", "+{0} s"], past: ["", "–{0} s", "", "", "", "–{0}\u{a0}s"] };
+
+#[rustfmt::skip]
+static _FIELD_1362: _RelativeField = _RelativeField { relatives: &[(0, "dette minuttet")], future: ["", "om {0} minutt", "", "", "", "om {0} minutt"], past: ["", "for {0} minutt sidan", "", "", "", "for {0} minutt sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1363: _RelativeField = _RelativeField { relatives: &[(0, "dette minuttet")], future: ["", "om {0} min", "", "", "", "om {0} min"], past: ["", "for {0} min sidan", "", "", "", "for {0} min sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1364: _RelativeField = _RelativeField { relatives: &[(0, "dette minuttet")], future: ["", "+{0} min", "", "", "", "+{0} min"], past: ["", "–{0} min", "", "", "", "–{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1365: _RelativeField = _RelativeField { relatives: &[(0, "denne timen")], future: ["", "om {0} time", "", "", "", "om {0} timar"], past: ["", "for {0} time sidan", "", "", "", "for {0} timar sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1366: _RelativeField = _RelativeField { relatives: &[(0, "denne timen")], future: ["", "om {0} t", "", "", "", "om {0} t"], past: ["", "for {0} t sidan", "", "", "", "for {0} t sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1367: _RelativeField = _RelativeField { relatives: &[(0, "denne timen")], future: ["", "+{0} t", "", "", "", "+{0} t"], past: ["", "–{0} t", "", "", "", "–{0} t"] };
+
+#[rustfmt::skip]
+static _FIELD_1368: _RelativeField = _RelativeField { relatives: &[(-2, "i førgår"), (-1, "i går"), (0, "i dag"), (1, "i morgon"), (2, "i overmorgon")], future: ["", "om {0} døgn", "", "", "", "om {0} døgn"], past: ["", "for {0} døgn sidan", "", "", "", "for {0} døgn sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1369: _RelativeField = _RelativeField { relatives: &[(-2, "i forgårs"), (-1, "i går"), (0, "i dag"), (1, "i morgon"), (2, "i overmorgen")], future: ["", "om {0} d.", "", "", "", "om {0} d."], past: ["", "for {0} d. sidan", "", "", "", "for {0} d. sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1370: _RelativeField = _RelativeField { relatives: &[(-2, "-2 d."), (-1, "i går"), (0, "i dag"), (1, "i morgon"), (2, "+2 d.")], future: ["", "+{0} d.", "", "", "", "+{0} d."], past: ["", "–{0} d.", "", "", "", "–{0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1371: _RelativeField = _RelativeField { relatives: &[(-1, "førre veke"), (0, "denne veka"), (1, "neste veke")], future: ["", "om {0} veke", "", "", "", "om {0} veker"], past: ["", "for {0} veke sidan", "", "", "", "for {0} veker sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1372: _RelativeField = _RelativeField { relatives: &[(-1, "førre veke"), (0, "denne veka"), (1, "neste veke")], future: ["", "om {0} v.", "", "", "", "om {0} v."], past: ["", "for {0} v. sidan", "", "", "", "for {0} v. sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1373: _RelativeField = _RelativeField { relatives: &[(-1, "førre månad"), (0, "denne månaden"), (1, "neste månad")], future: ["", "om {0} månad", "", "", "", "om {0} månadar"], past: ["", "for {0} månad sidan", "", "", "", "for {0} månadar sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1374: _RelativeField = _RelativeField { relatives: &[(-1, "førre md."), (0, "denne md."), (1, "neste md.")], future: ["", "om {0} md.", "", "", "", "om {0} md."], past: ["", "for {0} md. sidan", "", "", "", "for {0} md. sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1375: _RelativeField = _RelativeField { relatives: &[(-1, "førre md."), (0, "denne md."), (1, "neste md.")], future: ["", "+{0} md.", "", "", "", "+{0} md."], past: ["", "–{0} md.", "", "", "", "–{0} md."] };
+
+#[rustfmt::skip]
+static _FIELD_1376: _RelativeField = _RelativeField { relatives: &[(-1, "førre kvartal"), (0, "dette kvartalet"), (1, "neste kvartal")], future: ["", "om {0} kvartal", "", "", "", "om {0} kvartal"], past: ["", "for {0} kvartal sidan", "", "", "", "for {0} kvartal sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1377: _RelativeField = _RelativeField { relatives: &[(-1, "forrige kv."), (0, "dette kv."), (1, "neste kv.")], future: ["", "om {0} kv.", "", "", "", "om {0} kv."], past: ["", "for {0} kv. sidan", "", "", "", "for {0} kv. sidan"] };
+
+#[rustfmt::skip]
+static _FIELD_1378: _RelativeField = _RelativeField { relatives: &[(-1, "forrige kv."), (0, "dette kv."), (1, "neste kv.")], future: ["", "+{0} kv.", "", "", "", "+{0} kv."], past: ["", "–{0} kv.", "", "", "", "–{0} kv."] };
+
+#[rustfmt::skip]
+static _FIELD_1379: _RelativeField = _RelativeField { relatives: &[(-1, "i fjor"), (0, "i år"), (1, "neste år")], future: ["", "om {0} år", "", "", "", "om {0} år"], past: ["", "for {0} år sidan", "", "", "", "for {0} år sidan"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_110: [&_RelativeField; 24] = [
+    &_FIELD_1359,
+    &_FIELD_1360,
+    &_FIELD_1361,
+    &_FIELD_1362,
+    &_FIELD_1363,
+    &_FIELD_1364,
+    &_FIELD_1365,
+    &_FIELD_1366,
+    &_FIELD_1367,
+    &_FIELD_1368,
+    &_FIELD_1369,
+    &_FIELD_1370,
+    &_FIELD_1371,
+    &_FIELD_1372,
+    &_FIELD_1372,
+    &_FIELD_1373,
+    &_FIELD_1374,
+    &_FIELD_1375,
+    &_FIELD_1376,
+    &_FIELD_1377,
+    &_FIELD_1378,
+    &_FIELD_1379,
+    &_FIELD_1379,
+    &_FIELD_1379
+];
+
+#[rustfmt::skip]
+static _FIELD_1380: _RelativeField = _RelativeField { relatives: &[(-1, "ߞߎߣߎ\u{7f2}\u{7ec}"), (0, "ߓߌ\u{7ec}"), (1, "ߛߌߣߌ\u{7f2}\u{7eb}")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1381: _RelativeField = _RelativeField { relatives: &[(-1, "ߞߎߣߎ\u{7f2}\u{7ec}"), (0, "ߓߌ\u{7ec}"), (1, "ߛߌߣߌ\u{7f2}\u{7eb}")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "ߊ\u{7ec} ߟߐ\u{7ef}ߕߍ\u{7eb} {0} ߟߋ\u{7eb} ߣߌ\u{7f2}\u{7ec}"] };
+
+#[rustfmt::skip]
+static _FIELD_1382: _RelativeField = _RelativeField { relatives: &[(-1, "ߟߐ\u{7ef}ߕߍ\u{7eb} ߕߊ\u{7ec}ߡߌ\u{7f2}\u{7ec}ߣߍ\u{7f2}"), (0, "ߟߐ\u{7ef}ߕߍ ߣߌ\u{7f2}\u{7ec}"), (1, "ߟߐ\u{7ef}ߕߍ\u{7eb} ߣߊ\u{7ec}ߕߐ")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_1383: _RelativeField = _RelativeField { relatives: &[(-1, "ߞߊߙߏ\u{7eb} ߕߊ\u{7ec}ߡߌ\u{7f2}\u{7ec}ߣߍ\u{7f2}"), (0, "ߞߊߙߏ ߣߌ\u{7f2}\u{7ec}"), (1, "ߞߊߙߏ\u{7eb} ߣߊ\u{7ec}ߕߐ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_1384: _RelativeField = _RelativeField { relatives: &[(-1, "ߞߊߙߏ\u{7eb} ߕߊߡߌ\u{7f2}\u{7ec}ߣߍ\u{7f2}"), (0, "ߞߊߙߏ ߣߌ\u{7f2}\u{7ec}"), (1, "ߞߊߙߏ\u{7eb} ߣߊ\u{7ec}ߕߐ")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_1385: _RelativeField = _RelativeField { relatives: &[(-1, "ߛߊߙߏ\u{7f2}\u{7ec}"), (0, "ߢߌ\u{7ec}ߣߊ\u{7f2}\u{7ec}"), (1, "ߛߊ\u{7f2}\u{7ec}ߘߐ\u{7eb}")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_1386: _RelativeField = _RelativeField { relatives: &[(-1, "ߛߊ\u{7f2}\u{7ec} ߕߊ\u{7ec}ߡߌ\u{7f2}\u{7ec}ߣߍ\u{7f2}"), (0, "ߢߌ\u{7ec}ߣߊ\u{7f2}\u{7ec}"), (1, "ߛߊ\u{7f2}\u{7ec}ߘߐ")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_1387: _RelativeField = _RelativeField { relatives: &[(-1, "ߛߊ\u{7f2}\u{7ec}ߘߐ\u{7eb}"), (0, "ߢߌ\u{7ec}ߣߊ\u{7f2}\u{7ec}"), (1, "ߛߊ\u{7f2}\u{7ec}ߘߐ\u{7eb}")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_111: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1380,
+    &_FIELD_1380,
+    &_FIELD_1381,
+    &_FIELD_1382,
+    &_FIELD_1382,
+    &_FIELD_1382,
+    &_FIELD_1383,
+    &_FIELD_1384,
+    &_FIELD_1383,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1385,
+    &_FIELD_1386,
+    &_FIELD_1387
+];
+
+#[rustfmt::skip]
+static _FIELD_1388: _RelativeField = _RelativeField { relatives: &[(0, "amma")], future: ["", "sekoondii {0} keessatti", "", "", "", "sekoondiiwwan {0} keessatti"], past: ["", "sekoondii {0} darbe", "", "", "", "sekoondiiwwan {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1389: _RelativeField = _RelativeField { relatives: &[(0, "amma")], future: ["", "sek. {0} keessatti", "", "", "", "sek. {0} keessatti"], past: ["", "sek. {0} darbe", "", "", "", "sek. {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1390: _RelativeField = _RelativeField { relatives: &[(0, "amma")], future: ["", "s {0} keessatti", "", "", "", "s {0} keessatti"], past: ["", "s {0} darbe", "", "", "", "s {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1391: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "daqiiqaa {0} keessatti", "", "", "", "daqiiqaawwan {0} keessatti"], past: ["", "daqiiqaa {0} darbe", "", "", "", "daqiiqaawwan {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1392: _RelativeField = _RelativeField { relatives: &[(0, "daqiiqaa kana")], future: ["", "daq. {0} keessatti", "", "", "", "daq. {0} keessatti"], past: ["", "daq. {0} darbe", "", "", "", "daq. {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1393: _RelativeField = _RelativeField { relatives: &[(0, "daqiiqaa kana")], future: ["", "d {0} keessatti", "", "", "", "d {0} keessatti"], past: ["", "d {0} darbe", "", "", "", "d {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1394: _RelativeField = _RelativeField { relatives: &[(0, "sa’aatii kana")], future: ["", "sa’aatii {0} keessatti", "", "", "", "sa’aatiiwwan {0} keessatti"], past: ["", "sa’aatii {0} darbe", "", "", "", "sa’aatiiwwan {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1395: _RelativeField = _RelativeField { relatives: &[(0, "sa’aatii kana")], future: ["", "sa. {0} keessatti", "", "", "", "sa. {0} keessatti"], past: ["", "sa. {0} darbe", "", "", "", "sa. {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1396: _RelativeField = _RelativeField { relatives: &[(0, "sa’aatii kana")], future: ["", "h {0} keessatti", "", "", "", "h {0} keessatti"], past: ["", "h {0} darbe", "", "", "", "h {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1397: _RelativeField = _RelativeField { relatives: &[(-1, "kaleessa"), (0, "har’a"), (1, "boru")], future: ["", "guyyaa {0} keessatti", "", "", "", "guyyoota {0} keessatti"], past: ["", "guyyaa {0} darbe", "", "", "", "guyyoota {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1398: _RelativeField = _RelativeField { relatives: &[(-1, "kaleessa"), (0, "har’a"), (1, "boru")], future: ["", "g {0} keessatti", "", "", "", "g {0} keessatti"], past: ["", "g {0} darbe", "", "", "", "g {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1399: _RelativeField = _RelativeField { relatives: &[(-1, "torbee darbe"), (0, "torbee kana"), (1, "torbee dhufu")], future: ["", "torbee {0} keessatti", "", "", "", "torbanoota {0} keessatti"], past: ["", "torbee {0} darbe", "", "", "", "torbanoota {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1400: _RelativeField = _RelativeField { relatives: &[(-1, "tr. darbe"), (0, "tr. kana"), (1, "tr. dhufu")], future: ["", "tr. {0} keessatti", "", "", "", "tr. {0} keessatti"], past: ["", "tr. {0} darbe", "", "", "", "tr. {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1401: _RelativeField = _RelativeField { relatives: &[(-1, "tr. darbe"), (0, "tr. kana"), (1, "tr. dhufu")], future: ["", "w {0} keessatti", "", "", "", "w {0} keessatti"], past: ["", "w {0} darbe", "", "", "", "w {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1402: _RelativeField = _RelativeField { relatives: &[(-1, "ji’a darbe"), (0, "ji’a kana"), (1, "ji’a dhufu")], future: ["", "ji’a {0} keessatti", "", "", "", "ji’oota {0} keessatti"], past: ["", "ji’a {0} darbe", "", "", "", "ji’oota {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1403: _RelativeField = _RelativeField { relatives: &[(-1, "ji. darbe"), (0, "ji. kana"), (1, "ji. dhufu")], future: ["", "ji. {0} keessatti", "", "", "", "ji. {0} keessatti"], past: ["", "ji. {0} darbe", "", "", "", "ji. {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1404: _RelativeField = _RelativeField { relatives: &[(-1, "ji. darbe"), (0, "ji. kana"), (1, "ji. dhufu")], future: ["", "ji {0} keessatti", "", "", "", "ji {0} keessatti"], past: ["", "ji {0} darbe", "", "", "", "ji {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1405: _RelativeField = _RelativeField { relatives: &[(-1, "kurmaana darbe"), (0, "kurmaana kana"), (1, "kurmaana dhufu")], future: ["", "kurmaana {0} keessatti", "", "", "", "kurmaanota {0} keessatti"], past: ["", "kurmaana {0} darbe", "", "", "", "kurmaanota {0} darban"] };
+
+#[rustfmt::skip]
+static _FIELD_1406: _RelativeField = _RelativeField { relatives: &[(-1, "kr. darbe"), (0, "kr. kana"), (1, "kr. dhufu")], future: ["", "kr. {0} keessatti", "", "", "", "kr. {0} keessatti"], past: ["", "kr. {0} darbe", "", "", "", "kr. {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1407: _RelativeField = _RelativeField { relatives: &[(-1, "q darbe"), (0, "q kana"), (1, "q dhufu")], future: ["", "q {0} keessatti", "", "", "", "q {0} keessatti"], past: ["", "q {0} darbe", "", "", "", "q {0} darbe"] };
+
+#[rustfmt::skip]
+static _FIELD_1408: _RelativeField = _RelativeField { relatives: &[(-1, "waggaa darbe"), (0, "waggaa kana"), (1, "waggaa dhufu")], future: ["", "waggaa {0} keessatti", "", "", "", "waggoota {0} keessatti"], past: ["", "waggaa {0} dura", "", "", "", "waggoota {0} dura"] };
+
+#[rustfmt::skip]
+static _FIELD_1409: _RelativeField = _RelativeField { relatives: &[(-1, "wg. darbe"), (0, "wg. kana"), (1, "wg. dhufu")], future: ["", "wg. {0} keessatti", "", "", "", "wg. {0} keessatti"], past: ["", "wg. {0} dura", "", "", "", "wg. {0} dura"] };
+
+#[rustfmt::skip]
+static _FIELD_1410: _RelativeField = _RelativeField { relatives: &[(-1, "wg. darbe"), (0, "wg. kana"), (1, "wg. dhufu")], future: ["", "w {0} keessatti", "", "", "", "w {0} keessatti"], past: ["", "w {0} dura", "", "", "", "w {0} dura"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_112: [&_RelativeField; 24] = [
+    &_FIELD_1388,
+    &_FIELD_1389,
+    &_FIELD_1390,
+    &_FIELD_1391,
+    &_FIELD_1392,
+    &_FIELD_1393,
+    &_FIELD_1394,
+    &_FIELD_1395,
+    &_FIELD_1396,
+    &_FIELD_1397,
+    &_FIELD_1397,
+    &_FIELD_1398,
+    &_FIELD_1399,
+    &_FIELD_1400,
+    &_FIELD_1401,
+    &_FIELD_1402,
+    &_FIELD_1403,
+    &_FIELD_1404,
+    &_FIELD_1405,
+    &_FIELD_1406,
+    &_FIELD_1407,
+    &_FIELD_1408,
+    &_FIELD_1409,
+    &_FIELD_1410
+];
+
+#[rustfmt::skip]
+static _FIELD_1411: _RelativeField = _RelativeField { relatives: &[(0, "ବର\u{b4d}ତ\u{b4d}ତମ\u{b3e}ନ")], future: ["", "{0} ସେକେଣ\u{b4d}ଡରେ", "", "", "", "{0} ସେକେଣ\u{b4d}ଡରେ"], past: ["", "{0} ସେକେଣ\u{b4d}ଡ ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ସେକେଣ\u{b4d}ଡ ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1412: _RelativeField = _RelativeField { relatives: &[(0, "ବର\u{b4d}ତ\u{b4d}ତମ\u{b3e}ନ")], future: ["", "{0} ସେ. ରେ", "", "", "", "{0} ସେ. ରେ"], past: ["", "{0} ସେ. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ସେ. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1413: _RelativeField = _RelativeField { relatives: &[(0, "ଏହ\u{b3f} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}")], future: ["", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}\u{200c}ରେ", "", "", "", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}\u{200c}\u{200c}ରେ"], past: ["", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d} ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d} ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1414: _RelativeField = _RelativeField { relatives: &[(0, "ଏହ\u{b3f} ମ\u{b3f}ନ\u{b3f}ଟ\u{b4d}")], future: ["", "{0} ମ\u{b3f}. ରେ", "", "", "", "{0} ମ\u{b3f}. ରେ"], past: ["", "{0} ମ\u{b3f}. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ମ\u{b3f}. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1415: _RelativeField = _RelativeField { relatives: &[(0, "ଏହ\u{b3f} ଘଣ\u{b4d}ଟ\u{b3e}")], future: ["", "{0} ଘଣ\u{b4d}ଟ\u{b3e}ରେ", "", "", "", "{0} ଘଣ\u{b4d}ଟ\u{b3e}ରେ"], past: ["", "{0} ଘଣ\u{b4d}ଟ\u{b3e} ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ଘଣ\u{b4d}ଟ\u{b3e} ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1416: _RelativeField = _RelativeField { relatives: &[(0, "ଏହ\u{b3f} ଘଣ\u{b4d}ଟ\u{b3e}")], future: ["", "{0} ଘ. ରେ", "", "", "", "{0} ଘ. ରେ"], past: ["", "{0} ଘ. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ଘ. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1417: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତକ\u{b3e}ଲ\u{b3f}"), (0, "ଆଜ\u{b3f}"), (1, "ଆସନ\u{b4d}ତ\u{b3e}କ\u{b3e}ଲ\u{b3f}")], future: ["", "{0} ଦ\u{b3f}ନରେ", "", "", "", "{0} ଦ\u{b3f}ନରେ"], past: ["", "{0} ଦ\u{b3f}ନ ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ଦ\u{b3f}ନ ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1418: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ସପ\u{b4d}ତ\u{b3e}ହ"), (0, "ଏହ\u{b3f} ସପ\u{b4d}ତ\u{b3e}ହ"), (1, "ଆଗ\u{b3e}ମୀ ସପ\u{b4d}ତ\u{b3e}ହ")], future: ["", "{0} ସପ\u{b4d}ତ\u{b3e}ହରେ", "", "", "", "{0} ସପ\u{b4d}ତ\u{b3e}ହରେ"], past: ["", "{0} ସପ\u{b4d}ତ\u{b3e}ହ ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ସପ\u{b4d}ତ\u{b3e}ହ ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1419: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ସପ\u{b4d}ତ\u{b3e}ହ"), (0, "ଏହ\u{b3f} ସପ\u{b4d}ତ\u{b3e}ହ"), (1, "ଆଗ\u{b3e}ମୀ ସପ\u{b4d}ତ\u{b3e}ହ")], future: ["", "{0} ସପ\u{b4d}ତ\u{b3e}. ରେ", "", "", "", "{0} ସପ\u{b4d}ତ\u{b3e}. ରେ"], past: ["", "{0} ସପ\u{b4d}ତ\u{b3e}. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ସପ\u{b4d}ତ\u{b3e}. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1420: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ମ\u{b3e}ସ"), (0, "ଏହ\u{b3f} ମ\u{b3e}ସ"), (1, "ଆଗ\u{b3e}ମୀ ମ\u{b3e}ସ")], future: ["", "{0} ମ\u{b3e}ସରେ", "", "", "", "{0} ମ\u{b3e}ସରେ"], past: ["", "{0} ମ\u{b3e}ସ ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ମ\u{b3e}ସ ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1421: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ମ\u{b3e}ସ"), (0, "ଏହ\u{b3f} ମ\u{b3e}ସ"), (1, "ଆଗ\u{b3e}ମୀ ମ\u{b3e}ସ")], future: ["", "{0} ମ\u{b3e}. ରେ", "", "", "", "{0} ମ\u{b3e}. ରେ"], past: ["", "{0} ମ\u{b3e}. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ମ\u{b3e}. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1422: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ତ\u{b4d}ରୟମ\u{b3e}ସ"), (0, "ଏହ\u{b3f} ତ\u{b4d}ରୟମ\u{b3e}ସ"), (1, "ଆଗ\u{b3e}ମୀ ତ\u{b4d}ରୟମ\u{b3e}ସ")], future: ["", "{0} ତ\u{b4d}ରୟମ\u{b3e}ସରେ", "", "", "", "{0} ତ\u{b4d}ରୟମ\u{b3e}ସରେ"], past: ["", "{0} ତ\u{b4d}ରୟମ\u{b3e}ସ ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ତ\u{b4d}ରୟମ\u{b3e}ସ ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1423: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ତ\u{b3f}ନ\u{b3f}ମ\u{b3e}ସ"), (0, "ଏହ\u{b3f} ତ\u{b4d}ରୟମ\u{b3e}ସ"), (1, "ଆଗ\u{b3e}ମୀ ତ\u{b4d}ରୟମ\u{b3e}ସ")], future: ["", "{0} ତ\u{b4d}ରୟ. ରେ", "", "", "", "{0} ତ\u{b4d}ରୟ. ରେ"], past: ["", "{0} ତ\u{b4d}ରୟ. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ତ\u{b4d}ରୟ. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1424: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ତ\u{b3f}ନ\u{b3f}ମ\u{b3e}ସ"), (0, "ଏହ\u{b3f} ତ\u{b3f}ନ\u{b3f}ମ\u{b3e}ସ"), (1, "ଆଗ\u{b3e}ମୀ ତ\u{b3f}ନ\u{b3f}ମ\u{b3e}ସ")], future: ["", "{0} ତ\u{b4d}ରୟ. ରେ", "", "", "", "{0} ତ\u{b4d}ରୟ. ରେ"], past: ["", "{0} ତ\u{b4d}ରୟ. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ତ\u{b4d}ରୟ. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1425: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ବର\u{b4d}ଷ"), (0, "ଏହ\u{b3f} ବର\u{b4d}ଷ"), (1, "ଆଗ\u{b3e}ମୀ ବର\u{b4d}ଷ")], future: ["", "{0} ବର\u{b4d}ଷରେ", "", "", "", "{0} ବର\u{b4d}ଷରେ"], past: ["", "{0} ବର\u{b4d}ଷ ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ବର\u{b4d}ଷ ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _FIELD_1426: _RelativeField = _RelativeField { relatives: &[(-1, "ଗତ ବର\u{b4d}ଷ"), (0, "ଏହ\u{b3f} ବର\u{b4d}ଷ"), (1, "ଆଗ\u{b3e}ମୀ ବର\u{b4d}ଷ")], future: ["", "{0} ବ. ରେ", "", "", "", "{0} ବ. ରେ"], past: ["", "{0} ବ. ପ\u{b42}ର\u{b4d}ବେ", "", "", "", "{0} ବ. ପ\u{b42}ର\u{b4d}ବେ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_113: [&_RelativeField; 24] = [
+    &_FIELD_1411,
+    &_FIELD_1412,
+    &_FIELD_1412,
+    &_FIELD_1413,
+    &_FIELD_1414,
+    &_FIELD_1414,
+    &_FIELD_1415,
+    &_FIELD_1416,
+    &_FIELD_1416,
+    &_FIELD_1417,
+    &_FIELD_1417,
+    &_FIELD_1417,
+    &_FIELD_1418,
+    &_FIELD_1419,
+    &_FIELD_1419,
+    &_FIELD_1420,
+    &_FIELD_1421,
+    &_FIELD_1421,
+    &_FIELD_1422,
+    &_FIELD_1423,
+    &_FIELD_1424,
+    &_FIELD_1425,
+    &_FIELD_1426,
+    &_FIELD_1426
+];
+
+#[rustfmt::skip]
+static _FIELD_1427: _RelativeField = _RelativeField { relatives: &[(0, "ਹ\u{a41}ਣ")], future: ["", "{0} ਸਕਿ\u{a70}ਟ ਵਿ\u{a71}ਚ", "", "", "", "{0} ਸਕਿ\u{a70}ਟਾ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਸਕਿ\u{a70}ਟ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਸਕਿ\u{a70}ਟ ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1428: _RelativeField = _RelativeField { relatives: &[(0, "ਇਸ ਮਿ\u{a70}ਟ")], future: ["", "{0} ਮਿ\u{a70}ਟ ਵਿ\u{a71}ਚ", "", "", "", "{0} ਮਿ\u{a70}ਟਾ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਮਿ\u{a70}ਟ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਮਿ\u{a70}ਟ ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1429: _RelativeField = _RelativeField { relatives: &[(0, "ਇਸ ਘ\u{a70}ਟ\u{a47}")], future: ["", "{0} ਘ\u{a70}ਟ\u{a47} ਵਿ\u{a71}ਚ", "", "", "", "{0} ਘ\u{a70}ਟਿਆ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਘ\u{a70}ਟਾ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਘ\u{a70}ਟ\u{a47} ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1430: _RelativeField = _RelativeField { relatives: &[(-1, "ਬੀਤਿਆ ਕ\u{a71}ਲ\u{a4d}ਹ"), (0, "ਅ\u{a71}ਜ"), (1, "ਭਲਕ\u{a47}")], future: ["", "{0} ਦਿਨ ਵਿ\u{a71}ਚ", "", "", "", "{0} ਦਿਨਾ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਦਿਨ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਦਿਨ ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1431: _RelativeField = _RelativeField { relatives: &[(-1, "ਪਿਛਲਾ ਹਫ\u{a3c}ਤਾ"), (0, "ਇਹ ਹਫ\u{a3c}ਤਾ"), (1, "ਅਗਲਾ ਹਫ\u{a3c}ਤਾ")], future: ["", "{0} ਹਫ\u{a3c}ਤ\u{a47} ਵਿ\u{a71}ਚ", "", "", "", "{0} ਹਫ\u{a3c}ਤਿਆ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਹਫ\u{a3c}ਤਾ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਹਫ\u{a3c}ਤ\u{a47} ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1432: _RelativeField = _RelativeField { relatives: &[(-1, "ਪਿਛਲਾ ਮਹੀਨਾ"), (0, "ਇਹ ਮਹੀਨਾ"), (1, "ਅਗਲਾ ਮਹੀਨਾ")], future: ["", "{0} ਮਹੀਨ\u{a47} ਵਿ\u{a71}ਚ", "", "", "", "{0} ਮਹੀਨਿਆ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਮਹੀਨਾ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਮਹੀਨ\u{a47} ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1433: _RelativeField = _RelativeField { relatives: &[(-1, "ਪਿਛਲੀ ਤਿਮਾਹੀ"), (0, "ਇਸ ਤਿਮਾਹੀ"), (1, "ਅਗਲੀ ਤਿਮਾਹੀ")], future: ["", "{0} ਤਿਮਾਹੀ ਵਿ\u{a71}ਚ", "", "", "", "{0} ਤਿਮਾਹੀਆ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਤਿਮਾਹੀ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਤਿਮਾਹੀਆ\u{a02} ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1434: _RelativeField = _RelativeField { relatives: &[(-1, "ਪਿਛਲੀ ਤਿਮਾਹੀ"), (0, "ਇਹ ਤਿਮਾਹੀ"), (1, "ਅਗਲੀ ਤਿਮਾਹੀ")], future: ["", "{0} ਤਿਮਾਹੀ ਵਿ\u{a71}ਚ", "", "", "", "{0} ਤਿਮਾਹੀਆ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਤਿਮਾਹੀ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਤਿਮਾਹੀਆ\u{a02} ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _FIELD_1435: _RelativeField = _RelativeField { relatives: &[(-1, "ਪਿਛਲਾ ਸਾਲ"), (0, "ਇਹ ਸਾਲ"), (1, "ਅਗਲਾ ਸਾਲ")], future: ["", "{0} ਸਾਲ ਵਿ\u{a71}ਚ", "", "", "", "{0} ਸਾਲਾ\u{a02} ਵਿ\u{a71}ਚ"], past: ["", "{0} ਸਾਲ ਪਹਿਲਾ\u{a02}", "", "", "", "{0} ਸਾਲ ਪਹਿਲਾ\u{a02}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_114: [&_RelativeField; 24] = [
+    &_FIELD_1427,
+    &_FIELD_1427,
+    &_FIELD_1427,
+    &_FIELD_1428,
+    &_FIELD_1428,
+    &_FIELD_1428,
+    &_FIELD_1429,
+    &_FIELD_1429,
+    &_FIELD_1429,
+    &_FIELD_1430,
+    &_FIELD_1430,
+    &_FIELD_1430,
+    &_FIELD_1431,
+    &_FIELD_1431,
+    &_FIELD_1431,
+    &_FIELD_1432,
+    &_FIELD_1432,
+    &_FIELD_1432,
+    &_FIELD_1433,
+    &_FIELD_1434,
+    &_FIELD_1434,
+    &_FIELD_1435,
+    &_FIELD_1435,
+    &_FIELD_1435
+];
+
+#[rustfmt::skip]
+static _FIELD_1436: _RelativeField = _RelativeField { relatives: &[(0, "nau")], future: ["", "Fọ {0} Sẹ\u{301}kọn", "", "", "", "Fọ {0} Sẹ\u{301}kọn"], past: ["", "{0} sẹ\u{301}kọn wé dọ\u{301}n pas", "", "", "", "{0} sẹ\u{301}kọn wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1437: _RelativeField = _RelativeField { relatives: &[(0, "Dís mínit")], future: ["", "Fọ {0} mínit wé de kọm", "", "", "", "Fọ {0} mínit wé de kọm"], past: ["", "{0} mínit wé dọ\u{301}n pas", "", "", "", "{0} mínit wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1438: _RelativeField = _RelativeField { relatives: &[(0, "Dís áwa")], future: ["", "Fọ {0} áwa wé de kọm", "", "", "", "Fọ {0} áwa wé de kọm"], past: ["", "{0} áwa wé dọ\u{301}n pas", "", "", "", "{0} áwa wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1439: _RelativeField = _RelativeField { relatives: &[(0, "Dís áwa")], future: ["", "Fọ {0} áwa wé de kọm", "", "", "", "Fọ {0} áwa wé de kọm"], past: ["", "Fọ {0} áwa wé de kọm", "", "", "", "Fọ {0} áwa wé de kọm"] };
+
+#[rustfmt::skip]
+static _FIELD_1440: _RelativeField = _RelativeField { relatives: &[(-1, "Yẹ\u{301}stadè"), (0, "Todè"), (1, "Tumọ\u{301}ro")], future: ["", "Fọ {0}dè wé de kọm", "", "", "", "Fọ {0}dè wé de kọm"], past: ["", "{0} dè wé dọ\u{301}n pas", "", "", "", "{0} dè wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1441: _RelativeField = _RelativeField { relatives: &[(-1, "Lást wik"), (0, "Dís wik"), (1, "Nẹ\u{301}st wik")], future: ["", "Fọ {0}wik wé de kọm", "", "", "", "Fọ {0}wik wé de kọm"], past: ["", "{0} wik wé dọ\u{301}n pas", "", "", "", "{0} wik wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1442: _RelativeField = _RelativeField { relatives: &[(-1, "Lást mọnt"), (0, "Dís mọnt"), (1, "Nẹ\u{301}st mọnt")], future: ["", "Fọ {0}mọnt wé de kọm", "", "", "", "Fọ {0}mọnt wé de kọm"], past: ["", "{0} mọnt wé dọ\u{301}n pas", "", "", "", "{0} mọnt wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1443: _RelativeField = _RelativeField { relatives: &[(-1, "Lást kwọ\u{301}ta"), (0, "Dís kwọ\u{301}ta"), (1, "Nẹ\u{301}st kwọ\u{301}ta")], future: ["", "fọ {0} kwọ\u{301}ta wé de kọm", "", "", "", "fọ {0} kwọ\u{301}ta wé de kọm"], past: ["", "{0} kwọ\u{301}ta wé dọ\u{301}n pas", "", "", "", "{0} kwọ\u{301}ta wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _FIELD_1444: _RelativeField = _RelativeField { relatives: &[(-1, "Lást yiẹ"), (0, "Dís yiẹ"), (1, "Nẹ\u{301}st yiẹ")], future: ["", "fọ {0} yiẹ wé de kọm", "", "", "", "fọ {0} yiẹ wé de kọm"], past: ["", "{0} yiẹ wé dọ\u{301}n pas", "", "", "", "{0} yiẹ wé dọ\u{301}n pas"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_115: [&_RelativeField; 24] = [
+    &_FIELD_1436,
+    &_FIELD_1436,
+    &_FIELD_1436,
+    &_FIELD_1437,
+    &_FIELD_1437,
+    &_FIELD_1437,
+    &_FIELD_1438,
+    &_FIELD_1438,
+    &_FIELD_1439,
+    &_FIELD_1440,
+    &_FIELD_1440,
+    &_FIELD_1440,
+    &_FIELD_1441,
+    &_FIELD_1441,
+    &_FIELD_1441,
+    &_FIELD_1442,
+    &_FIELD_1442,
+    &_FIELD_1442,
+    &_FIELD_1443,
+    &_FIELD_1443,
+    &_FIELD_1443,
+    &_FIELD_1444,
+    &_FIELD_1444,
+    &_FIELD_1444
+];
+
+#[rustfmt::skip]
+static _FIELD_1445: _RelativeField = _RelativeField { relatives: &[(0, "teraz")], future: ["", "za {0} sekundę", "", "za {0} sekundy", "za {0} sekund", "za {0} sekundy"], past: ["", "{0} sekundę temu", "", "{0} sekundy temu", "{0} sekund temu", "{0} sekundy temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1446: _RelativeField = _RelativeField { relatives: &[(0, "teraz")], future: ["", "za {0} sek.", "", "za {0} sek.", "za {0} sek.", "za {0} sek."], past: ["", "{0} sek. temu", "", "{0} sek. temu", "{0} sek. temu", "{0} sek. temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1447: _RelativeField = _RelativeField { relatives: &[(0, "teraz")], future: ["", "za {0} s", "", "za {0} s", "za {0} s", "za {0} s"], past: ["", "{0} s temu", "", "{0} s temu", "{0} s temu", "{0} s temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1448: _RelativeField = _RelativeField { relatives: &[(0, "ta minuta")], future: ["", "za {0} minutę", "", "za {0} minuty", "za {0} minut", "za {0} minuty"], past: ["", "{0} minutę temu", "", "{0} minuty temu", "{0} minut temu", "{0} minuty temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1449: _RelativeField = _RelativeField { relatives: &[(0, "ta minuta")], future: ["", "za {0} min", "", "za {0} min", "za {0} min", "za {0} min"], past: ["", "{0} min temu", "", "{0} min temu", "{0} min temu", "{0} min temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1450: _RelativeField = _RelativeField { relatives: &[(0, "ta godzina")], future: ["", "za {0} godzinę", "", "za {0} godziny", "za {0} godzin", "za {0} godziny"], past: ["", "{0} godzinę temu", "", "{0} godziny temu", "{0} godzin temu", "{0} godziny temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1451: _RelativeField = _RelativeField { relatives: &[(0, "ta godzina")], future: ["", "za {0} godz.", "", "za {0} godz.", "za {0} godz.", "za {0} godz."], past: ["", "{0} godz. temu", "", "{0} godz. temu", "{0} godz. temu", "{0} godz. temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1452: _RelativeField = _RelativeField { relatives: &[(0, "ta godzina")], future: ["", "za {0} g.", "", "za {0} g.", "za {0} g.", "za {0} g."], past: ["", "{0} g. temu", "", "{0} g. temu", "{0} g. temu", "{0} g. temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1453: _RelativeField = _RelativeField { relatives: &[(-2, "przedwczoraj"), (-1, "wczoraj"), (0, "dzisiaj"), (1, "jutro"), (2, "pojutrze")], future: ["", "za {0} dzień", "", "za {0} dni", "za {0} dni", "za {0} dnia"], past: ["", "{0} dzień temu", "", "{0} dni temu", "{0} dni temu", "{0} dnia temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1454: _RelativeField = _RelativeField { relatives: &[(-2, "przedwczoraj"), (-1, "wcz."), (0, "dziś"), (1, "jutro"), (2, "pojutrze")], future: ["", "za {0} dzień", "", "za {0} dni", "za {0} dni", "za {0} dnia"], past: ["", "{0} dzień temu", "", "{0} dni temu", "{0} dni temu", "{0} dnia temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1455: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym tygodniu"), (0, "w tym tygodniu"), (1, "w przyszłym tygodniu")], future: ["", "za {0} tydzień", "", "za {0} tygodnie", "za {0} tygodni", "za {0} tygodnia"], past: ["", "{0} tydzień temu", "", "{0} tygodnie temu", "{0} tygodni temu", "{0} tygodnia temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1456: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym tyg."), (0, "w tym tyg."), (1, "w przyszłym tyg.")], future: ["", "za {0} tydz.", "", "za {0} tyg.", "za {0} tyg.", "za {0} tyg."], past: ["", "{0} tydz. temu", "", "{0} tyg. temu", "{0} tyg. temu", "{0} tyg. temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1457: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym miesiącu"), (0, "w tym miesiącu"), (1, "w przyszłym miesiącu")], future: ["", "za {0} miesiąc", "", "za {0} miesiące", "za {0} miesięcy", "za {0} miesiąca"], past: ["", "{0} miesiąc temu", "", "{0} miesiące temu", "{0} miesięcy temu", "{0} miesiąca temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1458: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym mies."), (0, "w tym mies."), (1, "w przyszłym mies.")], future: ["", "za {0} mies.", "", "za {0} mies.", "za {0} mies.", "za {0} mies."], past: ["", "{0} mies. temu", "", "{0} mies. temu", "{0} mies. temu", "{0} mies. temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1459: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym kwartale"), (0, "w tym kwartale"), (1, "w przyszłym kwartale")], future: ["", "za {0} kwartał", "", "za {0} kwartały", "za {0} kwartałów", "za {0} kwartału"], past: ["", "{0} kwartał temu", "", "{0} kwartały temu", "{0} kwartałów temu", "{0} kwartału temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1460: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym kwartale"), (0, "w tym kwartale"), (1, "w przyszłym kwartale")], future: ["", "za {0} kw.", "", "za {0} kw.", "za {0} kw.", "za {0} kw."], past: ["", "{0} kw. temu", "", "{0} kw. temu", "{0} kw. temu", "{0} kw. temu"] };
+
+#[rustfmt::skip]
+static _FIELD_1461: _RelativeField = _RelativeField { relatives: &[(-1, "w zeszłym roku"), (0, "w tym roku"), (1, "w przyszłym roku")], future: ["", "za {0} rok", "", "za {0} lata", "za {0} lat", "za {0} roku"], past: ["", "{0} rok temu", "", "{0} lata temu", "{0} lat temu", "{0} roku temu"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_116: [&_RelativeField; 24] = [
+    &_FIELD_1445,
+    &_FIELD_1446,
+    &_FIELD_1447,
+    &_FIELD_1448,
+    &_FIELD_1449,
+    &_FIELD_1449,
+    &_FIELD_1450,
+    &_FIELD_1451,
+    &_FIELD_1452,
+    &_FIELD_1453,
+    &_FIELD_1453,
+    &_FIELD_1454,
+    &_FIELD_1455,
+    &_FIELD_1456,
+    &_FIELD_1456,
+    &_FIELD_1457,
+    &_FIELD_1458,
+    &_FIELD_1458,
+    &_FIELD_1459,
+    &_FIELD_1460,
+    &_FIELD_1460,
+    &_FIELD_1461,
+    &_FIELD_1461,
+    &_FIELD_1461
+];
+
+#[rustfmt::skip]
+static _FIELD_1462: _RelativeField = _RelativeField { relatives: &[(0, "اوس")], future: ["", "په {0} ثانيه کې", "", "", "", "په {0} ثانيو کې"], past: ["", "{0} ثانيه مخکې", "", "", "", "{0} ثانيې مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1463: _RelativeField = _RelativeField { relatives: &[(0, "اوس")], future: ["", "په {0} ثانيه کې", "", "", "", "په {0} ثانيه کې"], past: ["", "{0} ثانيه مخکې", "", "", "", "{0} ثانيه مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1464: _RelativeField = _RelativeField { relatives: &[(0, "اوس")], future: ["", "په {0} ثانيه کې", "", "", "", "په {0} ثانيه کې"], past: ["", "{0} ثانيه کې", "", "", "", "{0} ثانيه کې"] };
+
+#[rustfmt::skip]
+static _FIELD_1465: _RelativeField = _RelativeField { relatives: &[(0, "دا دقيقه")], future: ["", "په {0} دقيقه کې", "", "", "", "په {0} دقيقو کې"], past: ["", "{0} دقيقه مخکې", "", "", "", "{0} دقيقې مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1466: _RelativeField = _RelativeField { relatives: &[(0, "دا دقيقه")], future: ["", "په {0} دقيقه کې", "", "", "", "په {0} دقيقه کې"], past: ["", "{0} دقيقه مخکې", "", "", "", "{0} دقيقه مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1467: _RelativeField = _RelativeField { relatives: &[(0, "دا ساعت")], future: ["", "په {0} ساعت کې", "", "", "", "په {0} ساعتو کې"], past: ["", "{0} ساعت مخکې", "", "", "", "{0} ساعتونه مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1468: _RelativeField = _RelativeField { relatives: &[(0, "دا ساعت")], future: ["", "په {0} ساعت کې", "", "", "", "په {0} ساعت کې"], past: ["", "{0} ساعت مخکې", "", "", "", "{0} ساعت مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1469: _RelativeField = _RelativeField { relatives: &[(-1, "پرون"), (0, "نن"), (1, "سبا")], future: ["", "په {0} ورځ کې", "", "", "", "په {0} ورځو کې"], past: ["", "{0} ورځ مخکې", "", "", "", "{0} ورځې مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1470: _RelativeField = _RelativeField { relatives: &[(-1, "تيره اونۍ"), (0, "دا اونۍ"), (1, "راتلونکې اونۍ")], future: ["", "په {0} اونۍ کې", "", "", "", "په {0} اونيو کې"], past: ["", "{0} اونۍ مخکې", "", "", "", "{0} اونۍ مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1471: _RelativeField = _RelativeField { relatives: &[(-1, "تېره اونۍ"), (0, "دا اونۍ"), (1, "راتلونکې اونۍ")], future: ["", "په {0} اونۍ کې", "", "", "", "په {0} اونۍ کې"], past: ["", "{0} اونۍ مخکې", "", "", "", "{0} اونۍ مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1472: _RelativeField = _RelativeField { relatives: &[(-1, "تېره مياشت"), (0, "دا مياشت"), (1, "راتلونکې مياشت")], future: ["", "په {0} مياشت کې", "", "", "", "په {0} مياشتو کې"], past: ["", "{0} مياشت مخکې", "", "", "", "{0} مياشتې مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1473: _RelativeField = _RelativeField { relatives: &[(-1, "تېره مياشت"), (0, "دا مياشت"), (1, "راتلونکې مياشت")], future: ["", "په {0} مياشت کې", "", "", "", "په {0} مياشت کې"], past: ["", "{0} مياشت مخکې", "", "", "", "{0} مياشت مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1474: _RelativeField = _RelativeField { relatives: &[(-1, "ورستۍ ربع"), (0, "دا ربع"), (1, "راتلونکې ربع")], future: ["", "په {0} ربع کې", "", "", "", "په {0} ربعو کې"], past: ["", "{0} ربع مخکې", "", "", "", "{0} ربعې مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1475: _RelativeField = _RelativeField { relatives: &[(-1, "پروسږکال"), (0, "سږکال"), (1, "راتلونکی کال")], future: ["", "په {0} کال کې", "", "", "", "په {0} کالونو کې"], past: ["", "{0} کال مخکې", "", "", "", "{0} کاله مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1476: _RelativeField = _RelativeField { relatives: &[(-1, "تېر کال"), (0, "سږ کال"), (1, "روتلونکی کال")], future: ["", "په {0} کال کې", "", "", "", "په {0} کالونو کې"], past: ["", "{0} کال مخکې", "", "", "", "{0} کاله مخکې"] };
+
+#[rustfmt::skip]
+static _FIELD_1477: _RelativeField = _RelativeField { relatives: &[(-1, "تير کال"), (0, "سږ کال"), (1, "راتلونکی کال")], future: ["", "په {0} کال کې", "", "", "", "په {0} کالونو کې"], past: ["", "{0} کال مخکې", "", "", "", "{0} کاله مخکې"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_117: [&_RelativeField; 24] = [
+    &_FIELD_1462,
+    &_FIELD_1463,
+    &_FIELD_1464,
+    &_FIELD_1465,
+    &_FIELD_1466,
+    &_FIELD_1466,
+    &_FIELD_1467,
+    &_FIELD_1468,
+    &_FIELD_1468,
+    &_FIELD_1469,
+    &_FIELD_1469,
+    &_FIELD_1469,
+    &_FIELD_1470,
+    &_FIELD_1471,
+    &_FIELD_1471,
+    &_FIELD_1472,
+    &_FIELD_1473,
+    &_FIELD_1473,
+    &_FIELD_1474,
+    &_FIELD_1474,
+    &_FIELD_1474,
+    &_FIELD_1475,
+    &_FIELD_1476,
+    &_FIELD_1477
+];
+
+#[rustfmt::skip]
+static _FIELD_1478: _RelativeField = _RelativeField { relatives: &[(-1, "پروسږکال"), (0, "سږکال"), (1, "راتلونکی کال")], future: ["", "په {0} کال کے", "", "", "", "په {0} کالونو کے"], past: ["", "{0} کال مخکے", "", "", "", "{0} کاله مخکے"] };
+
+#[rustfmt::skip]
+static _FIELD_1479: _RelativeField = _RelativeField { relatives: &[(-1, "تېر کال"), (0, "سږ کال"), (1, "روتلونکی کال")], future: ["", "په {0} کال کے", "", "", "", "په {0} کالونو کے"], past: ["", "{0} کال مخکے", "", "", "", "{0} کاله مخکے"] };
+
+#[rustfmt::skip]
+static _FIELD_1480: _RelativeField = _RelativeField { relatives: &[(-1, "تير کال"), (0, "سږ کال"), (1, "راتلونکی کال")], future: ["", "په {0} کال کے", "", "", "", "په {0} کالونو کے"], past: ["", "{0} کال مخکے", "", "", "", "{0} کاله مخکے"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_118: [&_RelativeField; 24] = [
+    &_FIELD_1462,
+    &_FIELD_1463,
+    &_FIELD_1464,
+    &_FIELD_1465,
+    &_FIELD_1466,
+    &_FIELD_1466,
+    &_FIELD_1467,
+    &_FIELD_1468,
+    &_FIELD_1468,
+    &_FIELD_1469,
+    &_FIELD_1469,
+    &_FIELD_1469,
+    &_FIELD_1470,
+    &_FIELD_1471,
+    &_FIELD_1471,
+    &_FIELD_1472,
+    &_FIELD_1473,
+    &_FIELD_1473,
+    &_FIELD_1474,
+    &_FIELD_1474,
+    &_FIELD_1474,
+    &_FIELD_1478,
+    &_FIELD_1479,
+    &_FIELD_1480
+];
+
+#[rustfmt::skip]
+static _FIELD_1481: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "em {0} segundo", "", "", "", "em {0} segundos"], past: ["", "há {0} segundo", "", "", "", "há {0} segundos"] };
+
+#[rustfmt::skip]
+static _FIELD_1482: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "em {0} seg.", "", "", "", "em {0} seg."], past: ["", "há {0} seg.", "", "", "", "há {0} seg."] };
+
+#[rustfmt::skip]
+static _FIELD_1483: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "em {0} minuto", "", "", "", "em {0} minutos"], past: ["", "há {0} minuto", "", "", "", "há {0} minutos"] };
+
+#[rustfmt::skip]
+static _FIELD_1484: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "em {0} min.", "", "", "", "em {0} min."], past: ["", "há {0} min.", "", "", "", "há {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1485: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "em {0} hora", "", "", "", "em {0} horas"], past: ["", "há {0} hora", "", "", "", "há {0} horas"] };
+
+#[rustfmt::skip]
+static _FIELD_1486: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "em {0} h", "", "", "", "em {0} h"], past: ["", "há {0} h", "", "", "", "há {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1487: _RelativeField = _RelativeField { relatives: &[(-2, "anteontem"), (-1, "ontem"), (0, "hoje"), (1, "amanhã"), (2, "depois de amanhã")], future: ["", "em {0} dia", "", "", "", "em {0} dias"], past: ["", "há {0} dia", "", "", "", "há {0} dias"] };
+
+#[rustfmt::skip]
+static _FIELD_1488: _RelativeField = _RelativeField { relatives: &[(-1, "semana passada"), (0, "esta semana"), (1, "próxima semana")], future: ["", "em {0} semana", "", "", "", "em {0} semanas"], past: ["", "há {0} semana", "", "", "", "há {0} semanas"] };
+
+#[rustfmt::skip]
+static _FIELD_1489: _RelativeField = _RelativeField { relatives: &[(-1, "semana passada"), (0, "esta semana"), (1, "próxima semana")], future: ["", "em {0} sem.", "", "", "", "em {0} sem."], past: ["", "há {0} sem.", "", "", "", "há {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_1490: _RelativeField = _RelativeField { relatives: &[(-1, "mês passado"), (0, "este mês"), (1, "próximo mês")], future: ["", "em {0} mês", "", "", "", "em {0} meses"], past: ["", "há {0} mês", "", "", "", "há {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_1491: _RelativeField = _RelativeField { relatives: &[(-1, "último trimestre"), (0, "este trimestre"), (1, "próximo trimestre")], future: ["", "em {0} trimestre", "", "", "", "em {0} trimestres"], past: ["", "há {0} trimestre", "", "", "", "há {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_1492: _RelativeField = _RelativeField { relatives: &[(-1, "último trimestre"), (0, "este trimestre"), (1, "próximo trimestre")], future: ["", "em {0} trim.", "", "", "", "em {0} trim."], past: ["", "há {0} trim.", "", "", "", "há {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_1493: _RelativeField = _RelativeField { relatives: &[(-1, "ano passado"), (0, "este ano"), (1, "próximo ano")], future: ["", "em {0} ano", "", "", "", "em {0} anos"], past: ["", "há {0} ano", "", "", "", "há {0} anos"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_119: [&_RelativeField; 24] = [
+    &_FIELD_1481,
+    &_FIELD_1482,
+    &_FIELD_1482,
+    &_FIELD_1483,
+    &_FIELD_1484,
+    &_FIELD_1484,
+    &_FIELD_1485,
+    &_FIELD_1486,
+    &_FIELD_1486,
+    &_FIELD_1487,
+    &_FIELD_1487,
+    &_FIELD_1487,
+    &_FIELD_1488,
+    &_FIELD_1489,
+    &_FIELD_1489,
+    &_FIELD_1490,
+    &_FIELD_1490,
+    &_FIELD_1490,
+    &_FIELD_1491,
+    &_FIELD_1492,
+    &_FIELD_1492,
+    &_FIELD_1493,
+    &_FIELD_1493,
+    &_FIELD_1493
+];
+
+#[rustfmt::skip]
+static _FIELD_1494: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "dentro de {0} segundo", "", "", "", "dentro de {0} segundos"], past: ["", "há {0} segundo", "", "", "", "há {0} segundos"] };
+
+#[rustfmt::skip]
+static _FIELD_1495: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "dentro de {0} s", "", "", "", "dentro de {0} s"], past: ["", "há {0} s", "", "", "", "há {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1496: _RelativeField = _RelativeField { relatives: &[(0, "agora")], future: ["", "+{0} s", "", "", "", "+{0} s"], past: ["", "-{0} s", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1497: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "dentro de {0} minuto", "", "", "", "dentro de {0} minutos"], past: ["", "há {0} minuto", "", "", "", "há {0} minutos"] };
+
+#[rustfmt::skip]
+static _FIELD_1498: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "dentro de {0} min", "", "", "", "dentro de {0} min"], past: ["", "há {0} min", "", "", "", "há {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1499: _RelativeField = _RelativeField { relatives: &[(0, "este minuto")], future: ["", "+{0} min", "", "", "", "+{0} min"], past: ["", "-{0} min", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1500: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "dentro de {0} hora", "", "", "", "dentro de {0} horas"], past: ["", "há {0} hora", "", "", "", "há {0} horas"] };
+
+#[rustfmt::skip]
+static _FIELD_1501: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "dentro de {0} h", "", "", "", "dentro de {0} h"], past: ["", "há {0} h", "", "", "", "há {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1502: _RelativeField = _RelativeField { relatives: &[(0, "esta hora")], future: ["", "+{0} h", "", "", "", "+{0} h"], past: ["", "-{0} h", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1503: _RelativeField = _RelativeField { relatives: &[(-2, "anteontem"), (-1, "ontem"), (0, "hoje"), (1, "amanhã"), (2, "depois de amanhã")], future: ["", "dentro de {0} dia", "", "", "", "dentro de {0} dias"], past: ["", "há {0} dia", "", "", "", "há {0} dias"] };
+
+#[rustfmt::skip]
+static _FIELD_1504: _RelativeField = _RelativeField { relatives: &[(-2, "anteontem"), (-1, "ontem"), (0, "hoje"), (1, "amanhã"), (2, "depois de amanhã")], future: ["", "+{0} dia", "", "", "", "+{0} dias"], past: ["", "-{0} dia", "", "", "", "-{0} dias"] };
+
+#[rustfmt::skip]
+static _FIELD_1505: _RelativeField = _RelativeField { relatives: &[(-1, "semana passada"), (0, "esta semana"), (1, "próxima semana")], future: ["", "dentro de {0} semana", "", "", "", "dentro de {0} semanas"], past: ["", "há {0} semana", "", "", "", "há {0} semanas"] };
+
+#[rustfmt::skip]
+static _FIELD_1506: _RelativeField = _RelativeField { relatives: &[(-1, "semana passada"), (0, "esta semana"), (1, "próxima semana")], future: ["", "dentro de {0} sem.", "", "", "", "dentro de {0} sem."], past: ["", "há {0} sem.", "", "", "", "há {0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_1507: _RelativeField = _RelativeField { relatives: &[(-1, "semana passada"), (0, "esta semana"), (1, "próxima semana")], future: ["", "+{0} sem.", "", "", "", "+{0} sem."], past: ["", "-{0} sem.", "", "", "", "-{0} sem."] };
+
+#[rustfmt::skip]
+static _FIELD_1508: _RelativeField = _RelativeField { relatives: &[(-1, "mês passado"), (0, "este mês"), (1, "próximo mês")], future: ["", "dentro de {0} mês", "", "", "", "dentro de {0} meses"], past: ["", "há {0} mês", "", "", "", "há {0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_1509: _RelativeField = _RelativeField { relatives: &[(-1, "mês passado"), (0, "este mês"), (1, "próximo mês")], future: ["", "+{0} mês", "", "", "", "+{0} meses"], past: ["", "-{0} mês", "", "", "", "-{0} meses"] };
+
+#[rustfmt::skip]
+static _FIELD_1510: _RelativeField = _RelativeField { relatives: &[(-1, "trimestre passado"), (0, "este trimestre"), (1, "próximo trimestre")], future: ["", "dentro de {0} trimestre", "", "", "", "dentro de {0} trimestres"], past: ["", "há {0} trimestre", "", "", "", "há {0} trimestres"] };
+
+#[rustfmt::skip]
+static _FIELD_1511: _RelativeField = _RelativeField { relatives: &[(-1, "trim. passado"), (0, "este trim."), (1, "próximo trim.")], future: ["", "dentro de {0} trim.", "", "", "", "dentro de {0} trim."], past: ["", "há {0} trim.", "", "", "", "há {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_1512: _RelativeField = _RelativeField { relatives: &[(-1, "trim. passado"), (0, "este trim."), (1, "próximo trim.")], future: ["", "+{0} trim.", "", "", "", "+{0} trim."], past: ["", "-{0} trim.", "", "", "", "-{0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_1513: _RelativeField = _RelativeField { relatives: &[(-1, "ano passado"), (0, "este ano"), (1, "próximo ano")], future: ["", "dentro de {0} ano", "", "", "", "dentro de {0} anos"], past: ["", "há {0} ano", "", "", "", "há {0} anos"] };
+
+#[rustfmt::skip]
+static _FIELD_1514: _RelativeField = _RelativeField { relatives: &[(-1, "ano passado"), (0, "este ano"), (1, "próximo ano")], future: ["", "+{0} ano", "", "", "", "+{0} anos"], past: ["", "-{0} ano", "", "", "", "-{0} anos"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_120: [&_RelativeField; 24] = [
+    &_FIELD_1494,
+    &_FIELD_1495,
+    &_FIELD_1496,
+    &_FIELD_1497,
+    &_FIELD_1498,
+    &_FIELD_1499,
+    &_FIELD_1500,
+    &_FIELD_1501,
+    &_FIELD_1502,
+    &_FIELD_1503,
+    &_FIELD_1503,
+    &_FIELD_1504,
+    &_FIELD_1505,
+    &_FIELD_1506,
+    &_FIELD_1507,
+    &_FIELD_1508,
+    &_FIELD_1508,
+    &_FIELD_1509,
+    &_FIELD_1510,
+    &_FIELD_1511,
+    &_FIELD_1512,
+    &_FIELD_1513,
+    &_FIELD_1513,
+    &_FIELD_1514
+];
+
+#[rustfmt::skip]
+static _FIELD_1515: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "", "", "", "", "{0} segundupi"], past: ["", "", "", "", "", "{0} qipa segundu"] };
+
+#[rustfmt::skip]
+static _FIELD_1516: _RelativeField = _RelativeField { relatives: &[(0, "kay minuto")], future: ["", "", "", "", "", "{0} minutupi"], past: ["", "", "", "", "", "{0} qipa minutu"] };
+
+#[rustfmt::skip]
+static _FIELD_1517: _RelativeField = _RelativeField { relatives: &[(0, "kay hora")], future: ["", "", "", "", "", "{0} urapi"], past: ["", "", "", "", "", "{0} qipa ura"] };
+
+#[rustfmt::skip]
+static _FIELD_1518: _RelativeField = _RelativeField { relatives: &[(-1, "qayna punchaw"), (0, "kunan punchaw"), (1, "paqarin")], future: ["", "", "", "", "", "{0} punchawpi"], past: ["", "", "", "", "", "{0} qipa punchaw"] };
+
+#[rustfmt::skip]
+static _FIELD_1519: _RelativeField = _RelativeField { relatives: &[(-1, "qayna semana"), (0, "kunan semana"), (1, "hamuq semana")], future: ["", "", "", "", "", "{0} semanapi"], past: ["", "", "", "", "", "{0} qipa semana"] };
+
+#[rustfmt::skip]
+static _FIELD_1520: _RelativeField = _RelativeField { relatives: &[(-1, "qayna killa"), (0, "kunan killa"), (1, "hamuq killa")], future: ["", "", "", "", "", "{0} killapi"], past: ["", "", "", "", "", "{0} qipa killa"] };
+
+#[rustfmt::skip]
+static _FIELD_1521: _RelativeField = _RelativeField { relatives: &[(-1, "qayna kimsa killa"), (0, "kunan kimsa killa"), (1, "hamuq kimsa killa")], future: ["", "", "", "", "", "{0} kimsa killapi"], past: ["", "", "", "", "", "{0} qipa kimsa killa"] };
+
+#[rustfmt::skip]
+static _FIELD_1522: _RelativeField = _RelativeField { relatives: &[(-1, "qayna wata"), (0, "kunan wata"), (1, "hamuq wata")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_1523: _RelativeField = _RelativeField { relatives: &[(-1, "qayna wata"), (0, "kunan wata"), (1, "hamuq wata")], future: ["", "", "", "", "", "{0} watapi"], past: ["", "", "", "", "", "{0} qipa watapi"] };
+
+#[rustfmt::skip]
+static _FIELD_1524: _RelativeField = _RelativeField { relatives: &[(-1, "qayna wata"), (0, "kunan wata"), (1, "hamuq wata")], future: ["", "", "", "", "", "{0} hamuq watapi"], past: ["", "", "", "", "", "{0} ñawpaq watapi"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_121: [&_RelativeField; 24] = [
+    &_FIELD_1515,
+    &_FIELD_1515,
+    &_FIELD_1515,
+    &_FIELD_1516,
+    &_FIELD_1516,
+    &_FIELD_1516,
+    &_FIELD_1517,
+    &_FIELD_1517,
+    &_FIELD_1517,
+    &_FIELD_1518,
+    &_FIELD_1518,
+    &_FIELD_1518,
+    &_FIELD_1519,
+    &_FIELD_1519,
+    &_FIELD_1519,
+    &_FIELD_1520,
+    &_FIELD_1520,
+    &_FIELD_1520,
+    &_FIELD_1521,
+    &_FIELD_1521,
+    &_FIELD_1521,
+    &_FIELD_1522,
+    &_FIELD_1523,
+    &_FIELD_1524
+];
+
+#[rustfmt::skip]
+static _FIELD_1525: _RelativeField = _RelativeField { relatives: &[(-1, "काल"), (0, "आज"), (1, "काल")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1526: _RelativeField = _RelativeField { relatives: &[(-1, "पाछला\u{902} सप\u{94d}ताह"), (0, "ई सप\u{94d}ताह"), (1, "आग\u{94d}ल सप\u{94d}ताह")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_1527: _RelativeField = _RelativeField { relatives: &[(-1, "पाछलो मीनो"), (0, "यो\u{902} मीनो"), (1, "आग\u{94d}लो मीनो")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_1528: _RelativeField = _RelativeField { relatives: &[(-1, "गत बरस"), (0, "ई बरस"), (1, "आग\u{94d}ल बरस")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_122: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1525,
+    &_FIELD_1525,
+    &_FIELD_1525,
+    &_FIELD_1526,
+    &_FIELD_1526,
+    &_FIELD_1526,
+    &_FIELD_1527,
+    &_FIELD_1527,
+    &_FIELD_1527,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1528,
+    &_FIELD_1528,
+    &_FIELD_1528
+];
+
+#[rustfmt::skip]
+static _FIELD_1529: _RelativeField = _RelativeField { relatives: &[(-2, "stersas"), (-1, "ier"), (0, "oz"), (1, "damaun"), (2, "puschmaun")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1530: _RelativeField = _RelativeField { relatives: &[(-1, "l’onn passà"), (0, "quest onn"), (1, "l’onn proxim")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_123: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1529,
+    &_FIELD_1529,
+    &_FIELD_1529,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1530,
+    &_FIELD_1530,
+    &_FIELD_1530
+];
+
+#[rustfmt::skip]
+static _FIELD_1531: _RelativeField = _RelativeField { relatives: &[(0, "acum")], future: ["", "peste {0} secundă", "", "peste {0} secunde", "", "peste {0} de secunde"], past: ["", "acum {0} secundă", "", "acum {0} secunde", "", "acum {0} de secunde"] };
+
+#[rustfmt::skip]
+static _FIELD_1532: _RelativeField = _RelativeField { relatives: &[(0, "acum")], future: ["", "peste {0} sec.", "", "peste {0} sec.", "", "peste {0} sec."], past: ["", "acum {0} sec.", "", "acum {0} sec.", "", "acum {0} sec."] };
+
+#[rustfmt::skip]
+static _FIELD_1533: _RelativeField = _RelativeField { relatives: &[(0, "acum")], future: ["", "+{0} s", "", "+{0} s", "", "+{0} s"], past: ["", "-{0} s", "", "-{0} s", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1534: _RelativeField = _RelativeField { relatives: &[(0, "minutul acesta")], future: ["", "peste {0} minut", "", "peste {0} minute", "", "peste {0} de minute"], past: ["", "acum {0} minut", "", "acum {0} minute", "", "acum {0} de minute"] };
+
+#[rustfmt::skip]
+static _FIELD_1535: _RelativeField = _RelativeField { relatives: &[(0, "minutul acesta")], future: ["", "peste {0} min.", "", "peste {0} min.", "", "peste {0} min."], past: ["", "acum {0} min.", "", "acum {0} min.", "", "acum {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1536: _RelativeField = _RelativeField { relatives: &[(0, "minutul acesta")], future: ["", "+{0} m", "", "+{0} m", "", "+{0} m"], past: ["", "-{0} m", "", "-{0} m", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_1537: _RelativeField = _RelativeField { relatives: &[(0, "ora aceasta")], future: ["", "peste {0} oră", "", "peste {0} ore", "", "peste {0} de ore"], past: ["", "acum {0} oră", "", "acum {0} ore", "", "acum {0} de ore"] };
+
+#[rustfmt::skip]
+static _FIELD_1538: _RelativeField = _RelativeField { relatives: &[(0, "ora aceasta")], future: ["", "peste {0} h", "", "peste {0} h", "", "peste {0} h"], past: ["", "acum {0} h", "", "acum {0} h", "", "acum {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1539: _RelativeField = _RelativeField { relatives: &[(0, "ora aceasta")], future: ["", "+{0} h", "", "+{0} h", "", "+{0} h"], past: ["", "-{0} h", "", "-{0} h", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1540: _RelativeField = _RelativeField { relatives: &[(-2, "alaltăieri"), (-1, "ieri"), (0, "azi"), (1, "mâine"), (2, "poimâine")], future: ["", "peste {0} zi", "", "peste {0} zile", "", "peste {0} de zile"], past: ["", "acum {0} zi", "", "acum {0} zile", "", "acum {0} de zile"] };
+
+#[rustfmt::skip]
+static _FIELD_1541: _RelativeField = _RelativeField { relatives: &[(-2, "alaltăieri"), (-1, "ieri"), (0, "azi"), (1, "mâine"), (2, "poimâine")], future: ["", "+{0} zi", "", "+{0} zile", "", "+{0} zile"], past: ["", "-{0} zi", "", "-{0} zile", "", "-{0} zile"] };
+
+#[rustfmt::skip]
+static _FIELD_1542: _RelativeField = _RelativeField { relatives: &[(-1, "săptămâna trecută"), (0, "săptămâna aceasta"), (1, "săptămâna viitoare")], future: ["", "peste {0} săptămână", "", "peste {0} săptămâni", "", "peste {0} de săptămâni"], past: ["", "acum {0} săptămână", "", "acum {0} săptămâni", "", "acum {0} de săptămâni"] };
+
+#[rustfmt::skip]
+static _FIELD_1543: _RelativeField = _RelativeField { relatives: &[(-1, "săpt. trecută"), (0, "săpt. aceasta"), (1, "săpt. viitoare")], future: ["", "peste {0} săpt.", "", "peste {0} săpt.", "", "peste {0} săpt."], past: ["", "acum {0} săpt.", "", "acum {0} săpt.", "", "acum {0} săpt."] };
+
+#[rustfmt::skip]
+static _FIELD_1544: _RelativeField = _RelativeField { relatives: &[(-1, "săpt. trecută"), (0, "săpt. aceasta"), (1, "săpt. viitoare")], future: ["", "+{0} săpt.", "", "+{0} săpt.", "", "+{0} săpt."], past: ["", "-{0} săpt.", "", "-{0} săpt.", "", "-{0} săpt."] };
+
+#[rustfmt::skip]
+static _FIELD_1545: _RelativeField = _RelativeField { relatives: &[(-1, "luna trecută"), (0, "luna aceasta"), (1, "luna viitoare")], future: ["", "peste {0} lună", "", "peste {0} luni", "", "peste {0} de luni"], past: ["", "acum {0} lună", "", "acum {0} luni", "", "acum {0} de luni"] };
+
+#[rustfmt::skip]
+static _FIELD_1546: _RelativeField = _RelativeField { relatives: &[(-1, "luna trecută"), (0, "luna aceasta"), (1, "luna viitoare")], future: ["", "peste {0} lună", "", "peste {0} luni", "", "peste {0} luni"], past: ["", "acum {0} lună", "", "acum {0} luni", "", "acum {0} luni"] };
+
+#[rustfmt::skip]
+static _FIELD_1547: _RelativeField = _RelativeField { relatives: &[(-1, "luna trecută"), (0, "luna aceasta"), (1, "luna viitoare")], future: ["", "+{0} lună", "", "+{0} luni", "", "+{0} luni"], past: ["", "-{0} lună", "", "-{0} luni", "", "-{0} luni"] };
+
+#[rustfmt::skip]
+static _FIELD_1548: _RelativeField = _RelativeField { relatives: &[(-1, "trimestrul trecut"), (0, "trimestrul acesta"), (1, "trimestrul viitor")], future: ["", "peste {0} trimestru", "", "peste {0} trimestre", "", "peste {0} de trimestre"], past: ["", "acum {0} trimestru", "", "acum {0} trimestre", "", "acum {0} de trimestre"] };
+
+#[rustfmt::skip]
+static _FIELD_1549: _RelativeField = _RelativeField { relatives: &[(-1, "trim. trecut"), (0, "trim. acesta"), (1, "trim. viitor")], future: ["", "peste {0} trim.", "", "peste {0} trim.", "", "peste {0} trim."], past: ["", "acum {0} trim.", "", "acum {0} trim.", "", "acum {0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_1550: _RelativeField = _RelativeField { relatives: &[(-1, "trim. trecut"), (0, "trim. acesta"), (1, "trim. viitor")], future: ["", "+{0} trim.", "", "+{0} trim.", "", "+{0} trim."], past: ["", "-{0} trim.", "", "-{0} trim.", "", "-{0} trim."] };
+
+#[rustfmt::skip]
+static _FIELD_1551: _RelativeField = _RelativeField { relatives: &[(-1, "anul trecut"), (0, "anul acesta"), (1, "anul viitor")], future: ["", "peste {0} an", "", "peste {0} ani", "", "peste {0} de ani"], past: ["", "acum {0} an", "", "acum {0} ani", "", "acum {0} de ani"] };
+
+#[rustfmt::skip]
+static _FIELD_1552: _RelativeField = _RelativeField { relatives: &[(-1, "anul trecut"), (0, "anul acesta"), (1, "anul viitor")], future: ["", "+{0} an", "", "+{0} ani", "", "+{0} ani"], past: ["", "-{0} an", "", "-{0} ani", "", "-{0} ani"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_124: [&_RelativeField; 24] = [
+    &_FIELD_1531,
+    &_FIELD_1532,
+    &_FIELD_1533,
+    &_FIELD_1534,
+    &_FIELD_1535,
+    &_FIELD_1536,
+    &_FIELD_1537,
+    &_FIELD_1538,
+    &_FIELD_1539,
+    &_FIELD_1540,
+    &_FIELD_1540,
+    &_FIELD_1541,
+    &_FIELD_1542,
+    &_FIELD_1543,
+    &_FIELD_1544,
+    &_FIELD_1545,
+    &_FIELD_1546,
+    &_FIELD_1547,
+    &_FIELD_1548,
+    &_FIELD_1549,
+    &_FIELD_1550,
+    &_FIELD_1551,
+    &_FIELD_1551,
+    &_FIELD_1552
+];
+
+#[rustfmt::skip]
+static _FIELD_1553: _RelativeField = _RelativeField { relatives: &[(0, "сейчас")], future: ["", "через {0} секунду", "", "через {0} секунды", "через {0} секунд", "через {0} секунды"], past: ["", "{0} секунду назад", "", "{0} секунды назад", "{0} секунд назад", "{0} секунды назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1554: _RelativeField = _RelativeField { relatives: &[(0, "сейчас")], future: ["", "через {0}\u{a0}сек.", "", "через {0}\u{a0}сек.", "через {0}\u{a0}сек.", "через {0}\u{a0}сек."], past: ["", "{0} сек. назад", "", "{0} сек. назад", "{0} сек. назад", "{0} сек. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1555: _RelativeField = _RelativeField { relatives: &[(0, "сейчас")], future: ["", "+{0} с", "", "+{0} с", "+{0} с", "+{0} с"], past: ["", "-{0} с", "", "-{0} с", "-{0} с", "-{0} с"] };
+
+#[rustfmt::skip]
+static _FIELD_1556: _RelativeField = _RelativeField { relatives: &[(0, "в эту минуту")], future: ["", "через {0} минуту", "", "через {0} минуты", "через {0} минут", "через {0} минуты"], past: ["", "{0} минуту назад", "", "{0} минуты назад", "{0} минут назад", "{0} минуты назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1557: _RelativeField = _RelativeField { relatives: &[(0, "в эту минуту")], future: ["", "через {0} мин.", "", "через {0} мин.", "через {0} мин.", "через {0} мин."], past: ["", "{0} мин. назад", "", "{0} мин. назад", "{0} мин. назад", "{0} мин. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1558: _RelativeField = _RelativeField { relatives: &[(0, "в эту минуту")], future: ["", "+{0} мин", "", "+{0} мин", "+{0} мин", "+{0} мин"], past: ["", "-{0} мин", "", "-{0} мин", "-{0} мин", "-{0} мин"] };
+
+#[rustfmt::skip]
+static _FIELD_1559: _RelativeField = _RelativeField { relatives: &[(0, "в этот час")], future: ["", "через {0} час", "", "через {0} часа", "через {0} часов", "через {0} часа"], past: ["", "{0} час назад", "", "{0} часа назад", "{0} часов назад", "{0} часа назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1560: _RelativeField = _RelativeField { relatives: &[(0, "в этот час")], future: ["", "через {0} ч", "", "через {0} ч", "через {0} ч", "через {0} ч"], past: ["", "{0} ч назад", "", "{0} ч назад", "{0} ч назад", "{0} ч назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1561: _RelativeField = _RelativeField { relatives: &[(0, "в этот час")], future: ["", "+{0} ч", "", "+{0} ч", "+{0} ч", "+{0} ч"], past: ["", "-{0} ч", "", "-{0} ч", "-{0} ч", "-{0} ч"] };
+
+#[rustfmt::skip]
+static _FIELD_1562: _RelativeField = _RelativeField { relatives: &[(-2, "позавчера"), (-1, "вчера"), (0, "сегодня"), (1, "завтра"), (2, "послезавтра")], future: ["", "через {0} день", "", "через {0} дня", "через {0} дней", "через {0} дня"], past: ["", "{0} день назад", "", "{0} дня назад", "{0} дней назад", "{0} дня назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1563: _RelativeField = _RelativeField { relatives: &[(-2, "позавчера"), (-1, "вчера"), (0, "сегодня"), (1, "завтра"), (2, "послезавтра")], future: ["", "через {0} дн.", "", "через {0} дн.", "через {0} дн.", "через {0} дн."], past: ["", "{0} дн. назад", "", "{0} дн. назад", "{0} дн. назад", "{0} дн. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1564: _RelativeField = _RelativeField { relatives: &[(-2, "позавчера"), (-1, "вчера"), (0, "сегодня"), (1, "завтра"), (2, "послезавтра")], future: ["", "+{0} дн.", "", "+{0} дн.", "+{0} дн.", "+{0} дн."], past: ["", "-{0} дн.", "", "-{0} дн.", "-{0} дн.", "-{0} дн."] };
+
+#[rustfmt::skip]
+static _FIELD_1565: _RelativeField = _RelativeField { relatives: &[(-1, "на прошлой неделе"), (0, "на этой неделе"), (1, "на следующей неделе")], future: ["", "через {0} неделю", "", "через {0} недели", "через {0} недель", "через {0} недели"], past: ["", "{0} неделю назад", "", "{0} недели назад", "{0} недель назад", "{0} недели назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1566: _RelativeField = _RelativeField { relatives: &[(-1, "на прошлой нед."), (0, "на этой нед."), (1, "на следующей нед.")], future: ["", "через {0} нед.", "", "через {0} нед.", "через {0} нед.", "через {0} нед."], past: ["", "{0} нед. назад", "", "{0} нед. назад", "{0} нед. назад", "{0} нед. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1567: _RelativeField = _RelativeField { relatives: &[(-1, "на пр. нед."), (0, "на эт. нед."), (1, "на след. нед.")], future: ["", "+{0} нед.", "", "+{0} нед.", "+{0} нед.", "+{0} нед."], past: ["", "-{0} нед.", "", "-{0} нед.", "-{0} нед.", "-{0} нед."] };
+
+#[rustfmt::skip]
+static _FIELD_1568: _RelativeField = _RelativeField { relatives: &[(-1, "в прошлом месяце"), (0, "в этом месяце"), (1, "в следующем месяце")], future: ["", "через {0} месяц", "", "через {0} месяца", "через {0} месяцев", "через {0} месяца"], past: ["", "{0} месяц назад", "", "{0} месяца назад", "{0} месяцев назад", "{0} месяца назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1569: _RelativeField = _RelativeField { relatives: &[(-1, "в прошлом мес."), (0, "в этом мес."), (1, "в следующем мес.")], future: ["", "через {0} мес.", "", "через {0} мес.", "через {0} мес.", "через {0} мес."], past: ["", "{0} мес. назад", "", "{0} мес. назад", "{0} мес. назад", "{0} мес. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1570: _RelativeField = _RelativeField { relatives: &[(-1, "в пр. мес."), (0, "в эт. мес."), (1, "в след. мес.")], future: ["", "+{0} мес.", "", "+{0} мес.", "+{0} мес.", "+{0} мес."], past: ["", "-{0} мес.", "", "-{0} мес.", "-{0} мес.", "-{0} мес."] };
+
+#[rustfmt::skip]
+static _FIELD_1571: _RelativeField = _RelativeField { relatives: &[(-1, "в прошлом квартале"), (0, "в текущем квартале"), (1, "в следующем квартале")], future: ["", "через {0} квартал", "", "через {0} квартала", "через {0} кварталов", "через {0} квартала"], past: ["", "{0} квартал назад", "", "{0} квартала назад", "{0} кварталов назад", "{0} квартала назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1572: _RelativeField = _RelativeField { relatives: &[(-1, "последний кв."), (0, "текущий кв."), (1, "следующий кв.")], future: ["", "через {0} кв.", "", "через {0} кв.", "через {0} кв.", "через {0} кв."], past: ["", "{0} кв. назад", "", "{0} кв. назад", "{0} кв. назад", "{0} кв. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1573: _RelativeField = _RelativeField { relatives: &[(-1, "посл. кв."), (0, "тек. кв."), (1, "след. кв.")], future: ["", "+{0} кв.", "", "+{0} кв.", "+{0} кв.", "+{0} кв."], past: ["", "-{0} кв.", "", "-{0} кв.", "-{0} кв.", "-{0} кв."] };
+
+#[rustfmt::skip]
+static _FIELD_1574: _RelativeField = _RelativeField { relatives: &[(-1, "в прошлом году"), (0, "в этом году"), (1, "в следующем году")], future: ["", "через {0} год", "", "через {0} года", "через {0} лет", "через {0} года"], past: ["", "{0} год назад", "", "{0} года назад", "{0} лет назад", "{0} года назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1575: _RelativeField = _RelativeField { relatives: &[(-1, "в прошлом г."), (0, "в этом г."), (1, "в след. г.")], future: ["", "через {0} г.", "", "через {0} г.", "через {0} л.", "через {0} г."], past: ["", "{0} г. назад", "", "{0} г. назад", "{0} л. назад", "{0} г. назад"] };
+
+#[rustfmt::skip]
+static _FIELD_1576: _RelativeField = _RelativeField { relatives: &[(-1, "в пр. г."), (0, "в эт. г."), (1, "в сл. г.")], future: ["", "+{0} г.", "", "+{0} г.", "+{0} л.", "+{0} г."], past: ["", "-{0} г.", "", "-{0} г.", "-{0} л.", "-{0} г."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_125: [&_RelativeField; 24] = [
+    &_FIELD_1553,
+    &_FIELD_1554,
+    &_FIELD_1555,
+    &_FIELD_1556,
+    &_FIELD_1557,
+    &_FIELD_1558,
+    &_FIELD_1559,
+    &_FIELD_1560,
+    &_FIELD_1561,
+    &_FIELD_1562,
+    &_FIELD_1563,
+    &_FIELD_1564,
+    &_FIELD_1565,
+    &_FIELD_1566,
+    &_FIELD_1567,
+    &_FIELD_1568,
+    &_FIELD_1569,
+    &_FIELD_1570,
+    &_FIELD_1571,
+    &_FIELD_1572,
+    &_FIELD_1573,
+    &_FIELD_1574,
+    &_FIELD_1575,
+    &_FIELD_1576
+];
+
+#[rustfmt::skip]
+static _FIELD_1577: _RelativeField = _RelativeField { relatives: &[(-1, "गतदिनम\u{94d}"), (0, "अद\u{94d}य"), (1, "श\u{94d}वः")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1578: _RelativeField = _RelativeField { relatives: &[(-1, "ह\u{94d}यः"), (0, "अद\u{94d}य"), (1, "श\u{94d}वः")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_126: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1577,
+    &_FIELD_1578,
+    &_FIELD_1578,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1579: _RelativeField = _RelativeField { relatives: &[(0, "билигин")], future: ["", "", "", "", "", "{0} сөкүүндэннэн"], past: ["", "", "", "", "", "{0} сөкүүндэ ынараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1580: _RelativeField = _RelativeField { relatives: &[(0, "билигин")], future: ["", "", "", "", "", "{0} сөкүүндэннэн"], past: ["", "", "", "", "", "{0} сөк. анараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1581: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "", "", "", "", "{0} мүнүүтэннэн"], past: ["", "", "", "", "", "{0} мүнүүтэ ынараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1582: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "", "", "", "", "{0} чааһынан"], past: ["", "", "", "", "", "{0} чаас ынараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1583: _RelativeField = _RelativeField { relatives: &[(-2, "Иллэрээ күн"), (-1, "Бэҕэһээ"), (0, "Бүгүн"), (1, "Сарсын"), (2, "Өйүүн")], future: ["", "", "", "", "", "{0} күнүнэн"], past: ["", "", "", "", "", "{0} күн ынараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1584: _RelativeField = _RelativeField { relatives: &[(-1, "ааспыт нэдиэлэ"), (0, "бу нэдиэлэ"), (1, "кэлэр нэдиэлэ")], future: ["", "", "", "", "", "{0} нэдиэлэннэн"], past: ["", "", "", "", "", "{0} нэдиэлэ анараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1585: _RelativeField = _RelativeField { relatives: &[(-1, "ааспыт ый"), (0, "бу ый"), (1, "аныгыскы ый")], future: ["", "", "", "", "", "{0} ыйынан"], past: ["", "", "", "", "", "{0} ый ынараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1586: _RelativeField = _RelativeField { relatives: &[(-1, "ааспыт кыбаартал"), (0, "бу кыбаартал"), (1, "кэлэр кыбаартал")], future: ["", "", "", "", "", "{0} кыбаарталынан"], past: ["", "", "", "", "", "{0} кыбаартал анараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1587: _RelativeField = _RelativeField { relatives: &[(-1, "ааспыт кыбаартал"), (0, "бу кыбаартал"), (1, "кэлэр кыбаартал")], future: ["", "", "", "", "", "{0} кыбаарталынан"], past: ["", "", "", "", "", "{0} кыб. анараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _FIELD_1588: _RelativeField = _RelativeField { relatives: &[(-1, "Былырыын"), (0, "быйыл"), (1, "эһиил")], future: ["", "", "", "", "", "{0} сылынан"], past: ["", "", "", "", "", "{0} сыл ынараа өттүгэр"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_127: [&_RelativeField; 24] = [
+    &_FIELD_1579,
+    &_FIELD_1580,
+    &_FIELD_1580,
+    &_FIELD_1581,
+    &_FIELD_1581,
+    &_FIELD_1581,
+    &_FIELD_1582,
+    &_FIELD_1582,
+    &_FIELD_1582,
+    &_FIELD_1583,
+    &_FIELD_1583,
+    &_FIELD_1583,
+    &_FIELD_1584,
+    &_FIELD_1584,
+    &_FIELD_1584,
+    &_FIELD_1585,
+    &_FIELD_1585,
+    &_FIELD_1585,
+    &_FIELD_1586,
+    &_FIELD_1587,
+    &_FIELD_1587,
+    &_FIELD_1588,
+    &_FIELD_1588,
+    &_FIELD_1588
+];
+
+#[rustfmt::skip]
+static _FIELD_1589: _RelativeField = _RelativeField { relatives: &[(-1, "ᱦᱚᱞᱟ"), (0, "ᱛᱮᱦᱮᱧ"), (1, "ᱜᱟᱯᱟ")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_128: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1589,
+    &_FIELD_1589,
+    &_FIELD_1589,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1590: _RelativeField = _RelativeField { relatives: &[(0, "como")], future: ["", "intro de {0} segundu", "", "", "", "intro de {0} segundos"], past: ["", "{0} segundu a como", "", "", "", "{0} segundos a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1591: _RelativeField = _RelativeField { relatives: &[(0, "como")], future: ["", "intro de {0} seg.", "", "", "", "intro de {0} seg."], past: ["", "{0} seg. a como", "", "", "", "{0} seg. a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1592: _RelativeField = _RelativeField { relatives: &[(0, "como")], future: ["", "intro de {0} s", "", "", "", "intro de {0} s"], past: ["", "{0} s a como", "", "", "", "{0} s a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1593: _RelativeField = _RelativeField { relatives: &[(0, "custu minutu")], future: ["", "intro de {0} minutu", "", "", "", "intro de {0} minutos"], past: ["", "{0} minutu a como", "", "", "", "{0} minutos a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1594: _RelativeField = _RelativeField { relatives: &[(0, "custu minutu")], future: ["", "intro de {0} min.", "", "", "", "intro de {0} min."], past: ["", "{0} min. a como", "", "", "", "{0} min. a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1595: _RelativeField = _RelativeField { relatives: &[(0, "custu minutu")], future: ["", "intro de {0} m", "", "", "", "intro de {0} m"], past: ["", "{0} m a como", "", "", "", "{0} m a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1596: _RelativeField = _RelativeField { relatives: &[(0, "cust’ora")], future: ["", "intro de {0} ora", "", "", "", "intro de {0} oras"], past: ["", "{0} ora a como", "", "", "", "{0} oras a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1597: _RelativeField = _RelativeField { relatives: &[(0, "cust’ora")], future: ["", "intro de {0} o", "", "", "", "intro de {0} o"], past: ["", "{0} o a como", "", "", "", "{0} o a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1598: _RelativeField = _RelativeField { relatives: &[(-1, "eris"), (0, "oe"), (1, "cras")], future: ["", "intro de {0} die", "", "", "", "intro de {0} dies"], past: ["", "{0} die a como", "", "", "", "{0} dies a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1599: _RelativeField = _RelativeField { relatives: &[(-1, "sa chida colada"), (0, "custa chida"), (1, "sa chida chi intrat")], future: ["", "intro de {0} chida", "", "", "", "intro de {0} chidas"], past: ["", "{0} chida a como", "", "", "", "{0} chidas a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1600: _RelativeField = _RelativeField { relatives: &[(-1, "sa chida colada"), (0, "custa chida"), (1, "sa chida chi intrat")], future: ["", "intro de {0} ch.", "", "", "", "intro de {0} ch."], past: ["", "{0} ch. a como", "", "", "", "{0} ch. a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1601: _RelativeField = _RelativeField { relatives: &[(-1, "su mese coladu"), (0, "custu mese"), (1, "su mese chi intrat")], future: ["", "intro de {0} mese", "", "", "", "intro de {0} meses"], past: ["", "{0} mese a como", "", "", "", "{0} meses a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1602: _RelativeField = _RelativeField { relatives: &[(-1, "su trimestre coladu"), (0, "custu trimestre"), (1, "su trimestre chi intrat")], future: ["", "intro de {0} trimestre", "", "", "", "intro de {0} trimestres"], past: ["", "{0} trimestre a como", "", "", "", "{0} trimestres a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1603: _RelativeField = _RelativeField { relatives: &[(-1, "su trim. coladu"), (0, "custu trim."), (1, "su trim. chi intrat")], future: ["", "intro de {0} trim.", "", "", "", "intro de {0} trim."], past: ["", "{0} trim. a como", "", "", "", "{0} trim. a como"] };
+
+#[rustfmt::skip]
+static _FIELD_1604: _RelativeField = _RelativeField { relatives: &[(-1, "ocannu coladu"), (0, "ocannu"), (1, "ocannu chi benit")], future: ["", "intro de {0} annu", "", "", "", "intro de {0} annos"], past: ["", "{0} annu a como", "", "", "", "{0} annos a como"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_129: [&_RelativeField; 24] = [
+    &_FIELD_1590,
+    &_FIELD_1591,
+    &_FIELD_1592,
+    &_FIELD_1593,
+    &_FIELD_1594,
+    &_FIELD_1595,
+    &_FIELD_1596,
+    &_FIELD_1596,
+    &_FIELD_1597,
+    &_FIELD_1598,
+    &_FIELD_1598,
+    &_FIELD_1598,
+    &_FIELD_1599,
+    &_FIELD_1600,
+    &_FIELD_1600,
+    &_FIELD_1601,
+    &_FIELD_1601,
+    &_FIELD_1601,
+    &_FIELD_1602,
+    &_FIELD_1603,
+    &_FIELD_1603,
+    &_FIELD_1604,
+    &_FIELD_1604,
+    &_FIELD_1604
+];
+
+#[rustfmt::skip]
+static _FIELD_1605: _RelativeField = _RelativeField { relatives: &[(0, "هاڻي")], future: ["", "{0} سيڪنڊن ۾", "", "", "", "{0} سيڪنڊن ۾"], past: ["", "{0} سيڪنڊ پهرين", "", "", "", "{0} سيڪنڊ پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1606: _RelativeField = _RelativeField { relatives: &[(0, "هن منٽ")], future: ["", "{0} منٽن ۾", "", "", "", "+{0} min"], past: ["", "{0} منٽ پهرين", "", "", "", "{0} منٽ پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1607: _RelativeField = _RelativeField { relatives: &[(0, "هن منٽ")], future: ["", "{0} منٽن ۾", "", "", "", "{0} منٽن ۾"], past: ["", "{0} منٽ پهرين", "", "", "", "{0} منٽ پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1608: _RelativeField = _RelativeField { relatives: &[(0, "هن ڪلڪ")], future: ["", "{0} ڪلاڪ ۾", "", "", "", "{0} ڪلاڪ ۾"], past: ["", "{0} ڪلاڪ پهرين", "", "", "", "{0} ڪلاڪ پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1609: _RelativeField = _RelativeField { relatives: &[(-1, "ڪل"), (0, "اڄ"), (1, "سڀاڻي")], future: ["", "{0} ڏينهن ۾", "", "", "", "{0} ڏينهن ۾"], past: ["", "{0} ڏينهن پهرين", "", "", "", "{0} ڏينهن پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1610: _RelativeField = _RelativeField { relatives: &[(-1, "پوئين هفتي"), (0, "هن هفتي"), (1, "اڳين هفتي")], future: ["", "{0} هفتن ۾", "", "", "", "{0} هفتن ۾"], past: ["", "{0} هفتا پهرين", "", "", "", "{0} هفتا پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1611: _RelativeField = _RelativeField { relatives: &[(-1, "پوئين مهيني"), (0, "هن مهيني"), (1, "اڳين مهيني")], future: ["", "{0} مهينن ۾", "", "", "", "{0} مهينن ۾"], past: ["", "{0} مهينا پهرين", "", "", "", "{0} مهينا پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1612: _RelativeField = _RelativeField { relatives: &[(-1, "پوئين ٽي ماهي"), (0, "هن ٽي ماهي"), (1, "اڳين ٽي ماهي")], future: ["", "{0} ٽي ماهي ۾", "", "", "", "{0} ٽي ماهي ۾"], past: ["", "{0} ٽي ماهي پهرين", "", "", "", "{0} ٽي ماهي پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1613: _RelativeField = _RelativeField { relatives: &[(-1, "پويون سال"), (0, "پويون سال"), (1, "پويون سال")], future: ["", "{0} سالن ۾", "", "", "", "{0} سالن ۾"], past: ["", "{0} سال پهرين", "", "", "", "{0} سال پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1614: _RelativeField = _RelativeField { relatives: &[(-1, "پوئين سال"), (0, "هن سال"), (1, "اڳين سال")], future: ["", "{0} سالن ۾", "", "", "", "{0} سالن ۾"], past: ["", "{0} سال پهرين", "", "", "", "{0} سال پهرين"] };
+
+#[rustfmt::skip]
+static _FIELD_1615: _RelativeField = _RelativeField { relatives: &[(-1, "پوئين سال"), (0, "هن سال"), (1, "اڳيئن سال")], future: ["", "{0} سالن ۾", "", "", "", "{0} سالن ۾"], past: ["", "{0} سال پهرين", "", "", "", "{0} سال پهرين"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_130: [&_RelativeField; 24] = [
+    &_FIELD_1605,
+    &_FIELD_1605,
+    &_FIELD_1605,
+    &_FIELD_1606,
+    &_FIELD_1607,
+    &_FIELD_1607,
+    &_FIELD_1608,
+    &_FIELD_1608,
+    &_FIELD_1608,
+    &_FIELD_1609,
+    &_FIELD_1609,
+    &_FIELD_1609,
+    &_FIELD_1610,
+    &_FIELD_1610,
+    &_FIELD_1610,
+    &_FIELD_1611,
+    &_FIELD_1611,
+    &_FIELD_1611,
+    &_FIELD_1612,
+    &_FIELD_1612,
+    &_FIELD_1612,
+    &_FIELD_1613,
+    &_FIELD_1614,
+    &_FIELD_1615
+];
+
+#[rustfmt::skip]
+static _FIELD_1616: _RelativeField = _RelativeField { relatives: &[(-1, "कल"), (0, "अॼ\u{941}"), (1, "स\u{941}भाण\u{947}")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_131: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1616,
+    &_FIELD_1616,
+    &_FIELD_1616,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_7,
+    &_FIELD_7,
+    &_FIELD_7
+];
+
+#[rustfmt::skip]
+static _FIELD_1617: _RelativeField = _RelativeField { relatives: &[(0, "දැන\u{dca}")], future: ["", "තත\u{dca}පර {0}ක\u{dd2}න\u{dca}", "", "", "", "තත\u{dca}පර {0}ක\u{dd2}න\u{dca}"], past: ["", "තත\u{dca}පර {0}කට පෙර", "", "", "", "තත\u{dca}පර {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1618: _RelativeField = _RelativeField { relatives: &[(0, "මෙම ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4}ව")], future: ["", "ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4} {0}ක\u{dd2}න\u{dca}", "", "", "", "ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4} {0}ක\u{dd2}න\u{dca}"], past: ["", "ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4} {0}කට පෙර", "", "", "", "ම\u{dd2}න\u{dd2}ත\u{dca}ත\u{dd4} {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1619: _RelativeField = _RelativeField { relatives: &[(0, "මෙම පැය")], future: ["", "පැය {0}ක\u{dd2}න\u{dca}", "", "", "", "පැය {0}ක\u{dd2}න\u{dca}"], past: ["", "පැය {0}කට පෙර", "", "", "", "පැය {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1620: _RelativeField = _RelativeField { relatives: &[(-2, "පෙරේද\u{dcf}"), (-1, "ඊයේ"), (0, "අද"), (1, "හෙට"), (2, "අන\u{dd2}ද\u{dca}ද\u{dcf}")], future: ["", "ද\u{dd2}න {0}න\u{dca}", "", "", "", "ද\u{dd2}න {0}න\u{dca}"], past: ["", "ද\u{dd2}න {0}කට පෙර", "", "", "", "ද\u{dd2}න {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1621: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය සත\u{dd2}ය"), (0, "මෙම සත\u{dd2}ය"), (1, "ඊළඟ සත\u{dd2}ය")], future: ["", "සත\u{dd2} {0}ක\u{dd2}න\u{dca}", "", "", "", "සත\u{dd2} {0}ක\u{dd2}න\u{dca}"], past: ["", "සත\u{dd2} {0}කට පෙර", "", "", "", "සත\u{dd2} {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1622: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය සත\u{dd2}."), (0, "මෙම සත\u{dd2}."), (1, "ඊළඟ සත\u{dd2}.")], future: ["", "සත\u{dd2} {0}ක\u{dd2}න\u{dca}", "", "", "", "සත\u{dd2} {0}ක\u{dd2}න\u{dca}"], past: ["", "සත\u{dd2} {0}කට පෙර", "", "", "", "සත\u{dd2} {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1623: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය ම\u{dcf}සය"), (0, "මෙම ම\u{dcf}සය"), (1, "ඊළඟ ම\u{dcf}සය")], future: ["", "ම\u{dcf}ස {0}ක\u{dd2}න\u{dca}", "", "", "", "ම\u{dcf}ස {0}ක\u{dd2}න\u{dca}"], past: ["", "ම\u{dcf}ස {0}කට පෙර", "", "", "", "ම\u{dcf}ස {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1624: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය ම\u{dcf}ස."), (0, "මෙම ම\u{dcf}ස."), (1, "ඊළඟ ම\u{dcf}ස.")], future: ["", "ම\u{dcf}ස {0}ක\u{dd2}න\u{dca}", "", "", "", "ම\u{dcf}ස {0}ක\u{dd2}න\u{dca}"], past: ["", "ම\u{dcf}ස {0}කට පෙර", "", "", "", "ම\u{dcf}ස {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1625: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය ක\u{dcf}ර\u{dca}ත\u{dd4}ව"), (0, "මෙම ක\u{dcf}ර\u{dca}ත\u{dd4}ව"), (1, "ඊළඟ ක\u{dcf}ර\u{dca}ත\u{dd4}ව")], future: ["", "ක\u{dcf}ර\u{dca}ත\u{dd4} {0}ක\u{dd2}න\u{dca}", "", "", "", "ක\u{dcf}ර\u{dca}ත\u{dd4} {0}ක\u{dd2}න\u{dca}"], past: ["", "ක\u{dcf}ර\u{dca}ත\u{dd4} {0}කට පෙර", "", "", "", "ක\u{dcf}ර\u{dca}ත\u{dd4} {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1626: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය ක\u{dcf}ර\u{dca}."), (0, "මෙම ක\u{dcf}ර\u{dca}."), (1, "ඊළඟ ක\u{dcf}ර\u{dca}.")], future: ["", "ක\u{dcf}ර\u{dca}. {0}ක\u{dd2}න\u{dca}", "", "", "", "ක\u{dcf}ර\u{dca}. {0}ක\u{dd2}න\u{dca}"], past: ["", "ක\u{dcf}ර\u{dca}. {0}කට පෙර", "", "", "", "ක\u{dcf}ර\u{dca}. {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _FIELD_1627: _RelativeField = _RelativeField { relatives: &[(-1, "පස\u{dd4}ග\u{dd2}ය වසර"), (0, "මෙම වසර"), (1, "ඊළඟ වසර")], future: ["", "වසර {0}ක\u{dd2}න\u{dca}", "", "", "", "වසර {0}ක\u{dd2}න\u{dca}"], past: ["", "වසර {0}කට පෙර", "", "", "", "වසර {0}කට පෙර"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_132: [&_RelativeField; 24] = [
+    &_FIELD_1617,
+    &_FIELD_1617,
+    &_FIELD_1617,
+    &_FIELD_1618,
+    &_FIELD_1618,
+    &_FIELD_1618,
+    &_FIELD_1619,
+    &_FIELD_1619,
+    &_FIELD_1619,
+    &_FIELD_1620,
+    &_FIELD_1620,
+    &_FIELD_1620,
+    &_FIELD_1621,
+    &_FIELD_1622,
+    &_FIELD_1622,
+    &_FIELD_1623,
+    &_FIELD_1624,
+    &_FIELD_1624,
+    &_FIELD_1625,
+    &_FIELD_1626,
+    &_FIELD_1626,
+    &_FIELD_1627,
+    &_FIELD_1627,
+    &_FIELD_1627
+];
+
+#[rustfmt::skip]
+static _FIELD_1628: _RelativeField = _RelativeField { relatives: &[(0, "teraz")], future: ["", "o {0} sekundu", "", "o {0} sekundy", "o {0} sekundy", "o {0} sekúnd"], past: ["", "pred {0} sekundou", "", "pred {0} sekundami", "pred {0} sekundy", "pred {0} sekundami"] };
+
+#[rustfmt::skip]
+static _FIELD_1629: _RelativeField = _RelativeField { relatives: &[(0, "teraz")], future: ["", "o {0} s", "", "o {0} s", "o {0} s", "o {0} s"], past: ["", "pred {0} s", "", "pred {0} s", "pred {0} s", "pred {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1630: _RelativeField = _RelativeField { relatives: &[(0, "v tejto minúte")], future: ["", "o {0} minútu", "", "o {0} minúty", "o {0} minúty", "o {0} minút"], past: ["", "pred {0} minútou", "", "pred {0} minútami", "pred {0} minúty", "pred {0} minútami"] };
+
+#[rustfmt::skip]
+static _FIELD_1631: _RelativeField = _RelativeField { relatives: &[(0, "v tejto minúte")], future: ["", "o {0} min", "", "o {0} min", "o {0} min", "o {0} min"], past: ["", "pred {0} min", "", "pred {0} min", "pred {0} min", "pred {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1632: _RelativeField = _RelativeField { relatives: &[(0, "v tejto hodine")], future: ["", "o {0} hodinu", "", "o {0} hodiny", "o {0} hodiny", "o {0} hodín"], past: ["", "pred {0} hodinou", "", "pred {0} hodinami", "pred {0} hodinou", "pred {0} hodinami"] };
+
+#[rustfmt::skip]
+static _FIELD_1633: _RelativeField = _RelativeField { relatives: &[(0, "v tejto hodine")], future: ["", "o {0} h", "", "o {0} h", "o {0} h", "o {0} h"], past: ["", "pred {0} h", "", "pred {0} h", "pred {0} h", "pred {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1634: _RelativeField = _RelativeField { relatives: &[(-2, "predvčerom"), (-1, "včera"), (0, "dnes"), (1, "zajtra"), (2, "pozajtra")], future: ["", "o {0} deň", "", "o {0} dni", "o {0} dňa", "o {0} dní"], past: ["", "pred {0} dňom", "", "pred {0} dňami", "pred {0} dňa", "pred {0} dňami"] };
+
+#[rustfmt::skip]
+static _FIELD_1635: _RelativeField = _RelativeField { relatives: &[(-2, "predvčerom"), (-1, "včera"), (0, "dnes"), (1, "zajtra"), (2, "pozajtra")], future: ["", "o {0} d.", "", "o {0} d.", "o {0} d.", "o {0} d."], past: ["", "pred {0} d.", "", "pred {0} d.", "pred {0} d.", "pred {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1636: _RelativeField = _RelativeField { relatives: &[(-1, "minulý týždeň"), (0, "tento týždeň"), (1, "budúci týždeň")], future: ["", "o {0} týždeň", "", "o {0} týždne", "o {0} týždňa", "o {0} týždňov"], past: ["", "pred {0} týždňom", "", "pred {0} týždňami", "pred {0} týždňa", "pred {0} týždňami"] };
+
+#[rustfmt::skip]
+static _FIELD_1637: _RelativeField = _RelativeField { relatives: &[(-1, "minulý týž."), (0, "tento týž."), (1, "budúci týž.")], future: ["", "o {0} týž.", "", "o {0} týž.", "o {0} týž.", "o {0} týž."], past: ["", "pred {0} týž.", "", "pred {0} týž.", "pred {0} týž.", "pred {0} týž."] };
+
+#[rustfmt::skip]
+static _FIELD_1638: _RelativeField = _RelativeField { relatives: &[(-1, "minulý mesiac"), (0, "tento mesiac"), (1, "budúci mesiac")], future: ["", "o {0} mesiac", "", "o {0} mesiace", "o {0} mesiaca", "o {0} mesiacov"], past: ["", "pred {0} mesiacom", "", "pred {0} mesiacmi", "pred {0} mesiaca", "pred {0} mesiacmi"] };
+
+#[rustfmt::skip]
+static _FIELD_1639: _RelativeField = _RelativeField { relatives: &[(-1, "minulý mes."), (0, "tento mes."), (1, "budúci mes.")], future: ["", "o {0} mes.", "", "o {0} mes.", "o {0} mes.", "o {0} mes."], past: ["", "pred {0} mes.", "", "pred {0} mes.", "pred {0} mes.", "pred {0} mes."] };
+
+#[rustfmt::skip]
+static _FIELD_1640: _RelativeField = _RelativeField { relatives: &[(-1, "minulý štvrťrok"), (0, "tento štvrťrok"), (1, "budúci štvrťrok")], future: ["", "o {0} štvrťrok", "", "o {0} štvrťroky", "o {0} štvrťroka", "o {0} štvrťrokov"], past: ["", "pred {0} štvrťrokom", "", "pred {0} štvrťrokmi", "pred {0} štvrťroka", "pred {0} štvrťrokmi"] };
+
+#[rustfmt::skip]
+static _FIELD_1641: _RelativeField = _RelativeField { relatives: &[(-1, "minulý štvrťr."), (0, "tento štvrťr."), (1, "budúci štvrťr.")], future: ["", "o {0} štvrťr.", "", "o {0} štvrťr.", "o {0} štvrťr.", "o {0} štvrťr."], past: ["", "pred {0} štvrťr.", "", "pred {0} štvrťr.", "pred {0} štvrťr.", "pred {0} štvrťr."] };
+
+#[rustfmt::skip]
+static _FIELD_1642: _RelativeField = _RelativeField { relatives: &[(-1, "minulý rok"), (0, "tento rok"), (1, "budúci rok")], future: ["", "o {0} rok", "", "o {0} roky", "o {0} roka", "o {0} rokov"], past: ["", "pred {0} rokom", "", "pred {0} rokmi", "pred {0} roka", "pred {0} rokmi"] };
+
+#[rustfmt::skip]
+static _FIELD_1643: _RelativeField = _RelativeField { relatives: &[(-1, "minulý rok"), (0, "tento rok"), (1, "budúci rok")], future: ["", "o {0} r.", "", "o {0} r.", "o {0} r.", "o {0} r."], past: ["", "pred {0} r.", "", "pred {0} r.", "pred {0} r.", "pred {0} r."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_133: [&_RelativeField; 24] = [
+    &_FIELD_1628,
+    &_FIELD_1629,
+    &_FIELD_1629,
+    &_FIELD_1630,
+    &_FIELD_1631,
+    &_FIELD_1631,
+    &_FIELD_1632,
+    &_FIELD_1633,
+    &_FIELD_1633,
+    &_FIELD_1634,
+    &_FIELD_1635,
+    &_FIELD_1635,
+    &_FIELD_1636,
+    &_FIELD_1637,
+    &_FIELD_1637,
+    &_FIELD_1638,
+    &_FIELD_1639,
+    &_FIELD_1639,
+    &_FIELD_1640,
+    &_FIELD_1641,
+    &_FIELD_1641,
+    &_FIELD_1642,
+    &_FIELD_1643,
+    &_FIELD_1643
+];
+
+#[rustfmt::skip]
+static _FIELD_1644: _RelativeField = _RelativeField { relatives: &[(0, "zdaj")], future: ["", "čez {0} sekundo", "čez {0} sekundi", "čez {0} sekunde", "", "čez {0} sekund"], past: ["", "pred {0} sekundo", "pred {0} sekundama", "pred {0} sekundami", "", "pred {0} sekundami"] };
+
+#[rustfmt::skip]
+static _FIELD_1645: _RelativeField = _RelativeField { relatives: &[(0, "zdaj")], future: ["", "čez {0} s", "čez {0} s", "čez {0} s", "", "čez {0} s"], past: ["", "pred {0} s", "pred {0} s", "pred {0} s", "", "pred {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1646: _RelativeField = _RelativeField { relatives: &[(0, "to minuto")], future: ["", "čez {0} minuto", "čez {0} minuti", "čez {0} minute", "", "čez {0} minut"], past: ["", "pred {0} minuto", "pred {0} minutama", "pred {0} minutami", "", "pred {0} minutami"] };
+
+#[rustfmt::skip]
+static _FIELD_1647: _RelativeField = _RelativeField { relatives: &[(0, "to minuto")], future: ["", "čez {0} min.", "čez {0} min.", "čez {0} min.", "", "čez {0} min."], past: ["", "pred {0} min.", "pred {0} min.", "pred {0} min.", "", "pred {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1648: _RelativeField = _RelativeField { relatives: &[(0, "to minuto")], future: ["", "čez {0} min", "čez {0} min", "čez {0} min", "", "čez {0} min"], past: ["", "pred {0} min", "pred {0} min", "pred {0} min", "", "pred {0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1649: _RelativeField = _RelativeField { relatives: &[(0, "v tej uri")], future: ["", "čez {0} uro", "čez {0} uri", "čez {0} ure", "", "čez {0} ur"], past: ["", "pred {0} uro", "pred {0} urama", "pred {0} urami", "", "pred {0} urami"] };
+
+#[rustfmt::skip]
+static _FIELD_1650: _RelativeField = _RelativeField { relatives: &[(0, "v tej uri")], future: ["", "čez {0} h", "čez {0} h", "čez {0} h", "", "čez {0} h"], past: ["", "pred {0} h", "pred {0} h", "pred {0} h", "", "pred {0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1651: _RelativeField = _RelativeField { relatives: &[(-2, "predvčerajšnjim"), (-1, "včeraj"), (0, "danes"), (1, "jutri"), (2, "pojutrišnjem")], future: ["", "čez {0} dan", "čez {0} dneva", "čez {0} dni", "", "čez {0} dni"], past: ["", "pred {0} dnevom", "pred {0} dnevoma", "pred {0} dnevi", "", "pred {0} dnevi"] };
+
+#[rustfmt::skip]
+static _FIELD_1652: _RelativeField = _RelativeField { relatives: &[(-1, "prejšnji teden"), (0, "ta teden"), (1, "naslednji teden")], future: ["", "čez {0} teden", "čez {0} tedna", "čez {0} tedne", "", "čez {0} tednov"], past: ["", "pred {0} tednom", "pred {0} tednoma", "pred {0} tedni", "", "pred {0} tedni"] };
+
+#[rustfmt::skip]
+static _FIELD_1653: _RelativeField = _RelativeField { relatives: &[(-1, "prejšnji ted."), (0, "ta ted."), (1, "naslednji ted.")], future: ["", "čez {0} ted.", "čez {0} ted.", "čez {0} ted.", "", "čez {0} ted."], past: ["", "pred {0} ted.", "pred {0} ted.", "pred {0} ted.", "", "pred {0} ted."] };
+
+#[rustfmt::skip]
+static _FIELD_1654: _RelativeField = _RelativeField { relatives: &[(-1, "prej. ted."), (0, "ta ted."), (1, "nasl. ted.")], future: ["", "čez {0} ted.", "čez {0} ted.", "čez {0} ted.", "", "čez {0} ted."], past: ["", "pred {0} ted.", "pred {0} ted.", "pred {0} ted.", "", "pred {0} ted."] };
+
+#[rustfmt::skip]
+static _FIELD_1655: _RelativeField = _RelativeField { relatives: &[(-1, "prejšnji mesec"), (0, "ta mesec"), (1, "naslednji mesec")], future: ["", "čez {0} mesec", "čez {0} meseca", "čez {0} mesece", "", "čez {0} mesecev"], past: ["", "pred {0} mesecem", "pred {0} mesecema", "pred {0} meseci", "", "pred {0} meseci"] };
+
+#[rustfmt::skip]
+static _FIELD_1656: _RelativeField = _RelativeField { relatives: &[(-1, "prejšnji mes."), (0, "ta mes."), (1, "naslednji mes.")], future: ["", "čez {0} mes.", "čez {0} mes.", "čez {0} mes.", "", "čez {0} mes."], past: ["", "pred {0} mes.", "pred {0} mes.", "pred {0} mes.", "", "pred {0} mes."] };
+
+#[rustfmt::skip]
+static _FIELD_1657: _RelativeField = _RelativeField { relatives: &[(-1, "prej. mes."), (0, "ta mes."), (1, "nasl. mes.")], future: ["", "čez {0} mes.", "čez {0} mes.", "čez {0} mes.", "", "čez {0} mes."], past: ["", "pred {0} mes.", "pred {0} mes.", "pred {0} mes.", "", "pred {0} mes."] };
+
+#[rustfmt::skip]
+static _FIELD_1658: _RelativeField = _RelativeField { relatives: &[(-1, "zadnje četrtletje"), (0, "to četrtletje"), (1, "naslednje četrtletje")], future: ["", "čez {0} četrtletje", "čez {0} četrtletji", "čez {0} četrtletja", "", "čez {0} četrtletij"], past: ["", "pred {0} četrtletjem", "pred {0} četrtletjema", "pred {0} četrtletji", "", "pred {0} četrtletji"] };
+
+#[rustfmt::skip]
+static _FIELD_1659: _RelativeField = _RelativeField { relatives: &[(-1, "zadnje četrtletje"), (0, "to četrtletje"), (1, "naslednje četrtletje")], future: ["", "čez {0} četrtl.", "čez {0} četrtl.", "čez {0} četrtl.", "", "čez {0} četrtl."], past: ["", "pred {0} četrtl.", "pred {0} četrtl.", "pred {0} četrtl.", "", "pred {0} četrtl."] };
+
+#[rustfmt::skip]
+static _FIELD_1660: _RelativeField = _RelativeField { relatives: &[(-1, "zadnje četrtletje"), (0, "to četrtletje"), (1, "naslednje četrtletje")], future: ["", "čez {0} četr.", "čez {0} četr.", "čez {0} četr.", "", "čez {0} četr."], past: ["", "pred {0} četr.", "pred {0} četr.", "pred {0} četr.", "", "pred {0} četr."] };
+
+#[rustfmt::skip]
+static _FIELD_1661: _RelativeField = _RelativeField { relatives: &[(-1, "lani"), (0, "letos"), (1, "naslednje leto")], future: ["", "čez {0} leto", "čez {0} leti", "čez {0} leta", "", "čez {0} let"], past: ["", "pred {0} letom", "pred {0} letoma", "pred {0} leti", "", "pred {0} leti"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_134: [&_RelativeField; 24] = [
+    &_FIELD_1644,
+    &_FIELD_1645,
+    &_FIELD_1645,
+    &_FIELD_1646,
+    &_FIELD_1647,
+    &_FIELD_1648,
+    &_FIELD_1649,
+    &_FIELD_1649,
+    &_FIELD_1650,
+    &_FIELD_1651,
+    &_FIELD_1651,
+    &_FIELD_1651,
+    &_FIELD_1652,
+    &_FIELD_1653,
+    &_FIELD_1654,
+    &_FIELD_1655,
+    &_FIELD_1656,
+    &_FIELD_1657,
+    &_FIELD_1658,
+    &_FIELD_1659,
+    &_FIELD_1660,
+    &_FIELD_1661,
+    &_FIELD_1661,
+    &_FIELD_1661
+];
+
+#[rustfmt::skip]
+static _FIELD_1662: _RelativeField = _RelativeField { relatives: &[(0, "Imika")], future: ["", "{0} ilbiriqsi", "", "", "", "{0} ilbiriqsi"], past: ["", "{0} ilbiriqsi kahor", "", "", "", "{0} ilbiriqsi kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1663: _RelativeField = _RelativeField { relatives: &[(0, "Iminka")], future: ["", "{0} ilbrqsi", "", "", "", "{0} ilbrqsi"], past: ["", "{0} ilbrqsi khr", "", "", "", "{0} ilbrqsi khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1664: _RelativeField = _RelativeField { relatives: &[(0, "Daqiiqadan")], future: ["", "{0} daqiiqad", "", "", "", "{0} daqiidadood"], past: ["", "{0} daqiiqad kahor", "", "", "", "{0} daqiiqadood kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1665: _RelativeField = _RelativeField { relatives: &[(0, "Daqiiqadan")], future: ["", "{0} dqqd", "", "", "", "{0} dqqd"], past: ["", "{0} dqqd khr", "", "", "", "{0} daqiiqadood kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1666: _RelativeField = _RelativeField { relatives: &[(0, "Saacadan")], future: ["", "{0} saacad", "", "", "", "{0} saacadood"], past: ["", "{0} saacad kahor", "", "", "", "{0} saacadood kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1667: _RelativeField = _RelativeField { relatives: &[(0, "Saacadan")], future: ["", "{0} scd", "", "", "", "{0} scd"], past: ["", "{0} scd khr", "", "", "", "{0} scd khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1668: _RelativeField = _RelativeField { relatives: &[(-1, "Shalay"), (0, "Maanta"), (1, "Berri")], future: ["", "{0} maalin", "", "", "", "{0} maalmood"], past: ["", "{0} maalin kahor", "", "", "", "{0} maalmood kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1669: _RelativeField = _RelativeField { relatives: &[(-1, "Shalay"), (0, "Maanta"), (1, "Berri")], future: ["", "{0} mln", "", "", "", "{0} mlmd"], past: ["", "{0} mln khr", "", "", "", "{0} mlmd khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1670: _RelativeField = _RelativeField { relatives: &[(-1, "Toddobaadkii hore"), (0, "Usbuucan"), (1, "Toddobaadka danbe")], future: ["", "{0} toddobaad", "", "", "", "{0} toddobaad"], past: ["", "{0} toddobaad kahor", "", "", "", "{0} toddobaad kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1671: _RelativeField = _RelativeField { relatives: &[(-1, "Toddobaadkii hore"), (0, "Usbuucan"), (1, "Toddobaadka danbe")], future: ["", "{0} tdbd", "", "", "", "{0} tdbd"], past: ["", "{0} tdbd khr", "", "", "", "{0} tdbd khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1672: _RelativeField = _RelativeField { relatives: &[(-1, "Toddobaadkii hore"), (0, "Toddobaadkan"), (1, "Toddobaadka danbe")], future: ["", "{0} tdbd", "", "", "", "{0} tdbd"], past: ["", "{0} tdbd khr", "", "", "", "{0} tdbd khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1673: _RelativeField = _RelativeField { relatives: &[(-1, "Bishii hore"), (0, "Bishan"), (1, "Bisha danbe")], future: ["", "{0} bil", "", "", "", "{0} bilood"], past: ["", "{0} bil kahor", "", "", "", "{0} bilood kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1674: _RelativeField = _RelativeField { relatives: &[(-1, "Bishii hore"), (0, "Bishan"), (1, "Bisha danbe")], future: ["", "{0} bil", "", "", "", "{0} bil"], past: ["", "{0} bil khr", "", "", "", "{0} bil khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1675: _RelativeField = _RelativeField { relatives: &[(-1, "Rubucii hore"), (0, "Rubucan"), (1, "Rubuca danbe")], future: ["", "{0} rubuc", "", "", "", "{0} rubuc"], past: ["", "{0} rubuc kahor", "", "", "", "{0} rubuc kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1676: _RelativeField = _RelativeField { relatives: &[(-1, "Rubucii ugu dambeeyay"), (0, "Rubucan"), (1, "Rubuca xiga")], future: ["", "{0} rbc", "", "", "", "{0} rbc"], past: ["", "{0} rbc khr", "", "", "", "{0} rbc khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1677: _RelativeField = _RelativeField { relatives: &[(-1, "Sannadkii hore"), (0, "Sannadkan"), (1, "Sannadka danbe")], future: ["", "{0} sannad", "", "", "", "{0} sannadood"], past: ["", "{0} sannad kahor", "", "", "", "{0} sannadood kahor"] };
+
+#[rustfmt::skip]
+static _FIELD_1678: _RelativeField = _RelativeField { relatives: &[(-1, "Sannadkii hore"), (0, "Sannadkan"), (1, "Sannadka danbe")], future: ["", "{0} snd", "", "", "", "{0} snd"], past: ["", "{0} snd khr", "", "", "", "{0} Snd khr"] };
+
+#[rustfmt::skip]
+static _FIELD_1679: _RelativeField = _RelativeField { relatives: &[(-1, "Sannadkii la soo dhaafay"), (0, "Sannadkan"), (1, "Sannadka xiga")], future: ["", "{0} snd", "", "", "", "{0} snd"], past: ["", "{0} snd khr", "", "", "", "{0} Snd khr"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_135: [&_RelativeField; 24] = [
+    &_FIELD_1662,
+    &_FIELD_1663,
+    &_FIELD_1663,
+    &_FIELD_1664,
+    &_FIELD_1665,
+    &_FIELD_1665,
+    &_FIELD_1666,
+    &_FIELD_1667,
+    &_FIELD_1667,
+    &_FIELD_1668,
+    &_FIELD_1669,
+    &_FIELD_1669,
+    &_FIELD_1670,
+    &_FIELD_1671,
+    &_FIELD_1672,
+    &_FIELD_1673,
+    &_FIELD_1674,
+    &_FIELD_1674,
+    &_FIELD_1675,
+    &_FIELD_1676,
+    &_FIELD_1676,
+    &_FIELD_1677,
+    &_FIELD_1678,
+    &_FIELD_1679
+];
+
+#[rustfmt::skip]
+static _FIELD_1680: _RelativeField = _RelativeField { relatives: &[(0, "tani")], future: ["", "pas {0} sekonde", "", "", "", "pas {0} sekondash"], past: ["", "{0} sekondë më parë", "", "", "", "{0} sekonda më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1681: _RelativeField = _RelativeField { relatives: &[(0, "tani")], future: ["", "pas {0} sek", "", "", "", "pas {0} sek"], past: ["", "{0} sek më parë", "", "", "", "{0} sek më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1682: _RelativeField = _RelativeField { relatives: &[(0, "këtë minutë")], future: ["", "pas {0} minute", "", "", "", "pas {0} minutash"], past: ["", "{0} minutë më parë", "", "", "", "{0} minuta më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1683: _RelativeField = _RelativeField { relatives: &[(0, "këtë minutë")], future: ["", "pas {0} min", "", "", "", "pas {0} min"], past: ["", "{0} min më parë", "", "", "", "{0} min më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1684: _RelativeField = _RelativeField { relatives: &[(0, "këtë orë")], future: ["", "pas {0} ore", "", "", "", "pas {0} orësh"], past: ["", "{0} orë më parë", "", "", "", "{0} orë më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1685: _RelativeField = _RelativeField { relatives: &[(-1, "dje"), (0, "sot"), (1, "nesër")], future: ["", "pas {0} dite", "", "", "", "pas {0} ditësh"], past: ["", "{0} ditë më parë", "", "", "", "{0} ditë më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1686: _RelativeField = _RelativeField { relatives: &[(-1, "javën e kaluar"), (0, "këtë javë"), (1, "javën e ardhshme")], future: ["", "pas {0} jave", "", "", "", "pas {0} javësh"], past: ["", "{0} javë më parë", "", "", "", "{0} javë më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1687: _RelativeField = _RelativeField { relatives: &[(-1, "muajin e kaluar"), (0, "këtë muaj"), (1, "muajin e ardhshëm")], future: ["", "pas {0} muaji", "", "", "", "pas {0} muajsh"], past: ["", "{0} muaj më parë", "", "", "", "{0} muaj më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1688: _RelativeField = _RelativeField { relatives: &[(-1, "tremujorin e kaluar"), (0, "këtë tremujor"), (1, "tremujorin e ardhshëm")], future: ["", "pas {0} tremujori", "", "", "", "pas {0} tremujorësh"], past: ["", "{0} tremujor më parë", "", "", "", "{0} tremujorë më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1689: _RelativeField = _RelativeField { relatives: &[(-1, "vjet"), (0, "sivjet"), (1, "mot")], future: ["", "pas {0} viti", "", "", "", "pas {0} vjetësh"], past: ["", "{0} vit më parë", "", "", "", "{0} vjet më parë"] };
+
+#[rustfmt::skip]
+static _FIELD_1690: _RelativeField = _RelativeField { relatives: &[(-1, "vitin e kaluar"), (0, "këtë vit"), (1, "vitin e ardhshëm")], future: ["", "pas {0} viti", "", "", "", "pas {0} vjetësh"], past: ["", "{0} vit më parë", "", "", "", "{0} vjet më parë"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_136: [&_RelativeField; 24] = [
+    &_FIELD_1680,
+    &_FIELD_1681,
+    &_FIELD_1681,
+    &_FIELD_1682,
+    &_FIELD_1683,
+    &_FIELD_1683,
+    &_FIELD_1684,
+    &_FIELD_1684,
+    &_FIELD_1684,
+    &_FIELD_1685,
+    &_FIELD_1685,
+    &_FIELD_1685,
+    &_FIELD_1686,
+    &_FIELD_1686,
+    &_FIELD_1686,
+    &_FIELD_1687,
+    &_FIELD_1687,
+    &_FIELD_1687,
+    &_FIELD_1688,
+    &_FIELD_1688,
+    &_FIELD_1688,
+    &_FIELD_1689,
+    &_FIELD_1690,
+    &_FIELD_1690
+];
+
+#[rustfmt::skip]
+static _FIELD_1691: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} секунду", "", "за {0} секунде", "", "за {0} секунди"], past: ["", "пре {0} секунде", "", "пре {0} секунде", "", "пре {0} секунди"] };
+
+#[rustfmt::skip]
+static _FIELD_1692: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} сек.", "", "за {0} сек.", "", "за {0} сек."], past: ["", "пре {0} сек.", "", "пре {0} сек.", "", "пре {0} сек."] };
+
+#[rustfmt::skip]
+static _FIELD_1693: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} с.", "", "за {0} с.", "", "за {0} с."], past: ["", "пре {0} с.", "", "пре {0} с.", "", "пре {0} с."] };
+
+#[rustfmt::skip]
+static _FIELD_1694: _RelativeField = _RelativeField { relatives: &[(0, "овог минута")], future: ["", "за {0} минут", "", "за {0} минута", "", "за {0} минута"], past: ["", "пре {0} минута", "", "пре {0} минута", "", "пре {0} минута"] };
+
+#[rustfmt::skip]
+static _FIELD_1695: _RelativeField = _RelativeField { relatives: &[(0, "овог минута")], future: ["", "за {0} мин.", "", "за {0} мин.", "", "за {0} мин."], past: ["", "пре {0} мин.", "", "пре {0} мин.", "", "пре {0} мин."] };
+
+#[rustfmt::skip]
+static _FIELD_1696: _RelativeField = _RelativeField { relatives: &[(0, "овог сата")], future: ["", "за {0} сат", "", "за {0} сата", "", "за {0} сати"], past: ["", "пре {0} сата", "", "пре {0} сата", "", "пре {0} сати"] };
+
+#[rustfmt::skip]
+static _FIELD_1697: _RelativeField = _RelativeField { relatives: &[(0, "овог сата")], future: ["", "за {0} ч.", "", "за {0} ч.", "", "за {0} ч."], past: ["", "пре {0} ч.", "", "пре {0} ч.", "", "пре {0} ч."] };
+
+#[rustfmt::skip]
+static _FIELD_1698: _RelativeField = _RelativeField { relatives: &[(-2, "прекјуче"), (-1, "јуче"), (0, "данас"), (1, "сутра"), (2, "прекосутра")], future: ["", "за {0} дан", "", "за {0} дана", "", "за {0} дана"], past: ["", "пре {0} дана", "", "пре {0} дана", "", "пре {0} дана"] };
+
+#[rustfmt::skip]
+static _FIELD_1699: _RelativeField = _RelativeField { relatives: &[(-2, "прекјуче"), (-1, "јуче"), (0, "данас"), (1, "сутра"), (2, "прекосутра")], future: ["", "за {0} д.", "", "за {0} д.", "", "за {0} д."], past: ["", "пре {0} д.", "", "пре {0} д.", "", "пре {0} д."] };
+
+#[rustfmt::skip]
+static _FIELD_1700: _RelativeField = _RelativeField { relatives: &[(-1, "прошле недеље"), (0, "ове недеље"), (1, "следеће недеље")], future: ["", "за {0} недељу", "", "за {0} недеље", "", "за {0} недеља"], past: ["", "пре {0} недеље", "", "пре {0} недеље", "", "пре {0} недеља"] };
+
+#[rustfmt::skip]
+static _FIELD_1701: _RelativeField = _RelativeField { relatives: &[(-1, "прошле нед."), (0, "ове нед."), (1, "следеће нед.")], future: ["", "за {0} нед.", "", "за {0} нед.", "", "за {0} нед."], past: ["", "пре {0} нед.", "", "пре {0} нед.", "", "пре {0} нед."] };
+
+#[rustfmt::skip]
+static _FIELD_1702: _RelativeField = _RelativeField { relatives: &[(-1, "прошле н."), (0, "ове н."), (1, "следеће н.")], future: ["", "за {0} н.", "", "за {0} н.", "", "за {0} н."], past: ["", "пре {0} н.", "", "пре {0} н.", "", "пре {0} н."] };
+
+#[rustfmt::skip]
+static _FIELD_1703: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог месеца"), (0, "овог месеца"), (1, "следећег месеца")], future: ["", "за {0} месец", "", "за {0} месеца", "", "за {0} месеци"], past: ["", "пре {0} месеца", "", "пре {0} месеца", "", "пре {0} месеци"] };
+
+#[rustfmt::skip]
+static _FIELD_1704: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог мес."), (0, "овог мес."), (1, "следећег мес.")], future: ["", "за {0} мес.", "", "за {0} мес.", "", "за {0} мес."], past: ["", "пре {0} мес.", "", "пре {0} мес.", "", "пре {0} мес."] };
+
+#[rustfmt::skip]
+static _FIELD_1705: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог мес."), (0, "овог мес."), (1, "следећег мес.")], future: ["", "за {0} м.", "", "за {0} м.", "", "за {0} м."], past: ["", "пре {0} м.", "", "пре {0} м.", "", "пре {0} м."] };
+
+#[rustfmt::skip]
+static _FIELD_1706: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог квартала"), (0, "овог квартала"), (1, "следећег квартала")], future: ["", "за {0} квартал", "", "за {0} квартала", "", "за {0} квартала"], past: ["", "пре {0} квартала", "", "пре {0} квартала", "", "пре {0} квартала"] };
+
+#[rustfmt::skip]
+static _FIELD_1707: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог квартала"), (0, "овог квартала"), (1, "следећег квартала")], future: ["", "за {0} кв.", "", "за {0} кв.", "", "за {0} кв."], past: ["", "пре {0} кв.", "", "пре {0} кв.", "", "пре {0} кв."] };
+
+#[rustfmt::skip]
+static _FIELD_1708: _RelativeField = _RelativeField { relatives: &[(-1, "прошле године"), (0, "ове године"), (1, "следеће године")], future: ["", "за {0} годину", "", "за {0} године", "", "за {0} година"], past: ["", "пре {0} године", "", "пре {0} године", "", "пре {0} година"] };
+
+#[rustfmt::skip]
+static _FIELD_1709: _RelativeField = _RelativeField { relatives: &[(-1, "прошле год."), (0, "ове год."), (1, "следеће год.")], future: ["", "за {0} год.", "", "за {0} год.", "", "за {0} год."], past: ["", "пре {0} год.", "", "пре {0} год.", "", "пре {0} год."] };
+
+#[rustfmt::skip]
+static _FIELD_1710: _RelativeField = _RelativeField { relatives: &[(-1, "прошле г."), (0, "ове г."), (1, "следеће г.")], future: ["", "за {0} г.", "", "за {0} г.", "", "за {0} г."], past: ["", "пре {0} г.", "", "пре {0} г.", "", "пре {0} г."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_137: [&_RelativeField; 24] = [
+    &_FIELD_1691,
+    &_FIELD_1692,
+    &_FIELD_1693,
+    &_FIELD_1694,
+    &_FIELD_1695,
+    &_FIELD_1695,
+    &_FIELD_1696,
+    &_FIELD_1697,
+    &_FIELD_1697,
+    &_FIELD_1698,
+    &_FIELD_1699,
+    &_FIELD_1699,
+    &_FIELD_1700,
+    &_FIELD_1701,
+    &_FIELD_1702,
+    &_FIELD_1703,
+    &_FIELD_1704,
+    &_FIELD_1705,
+    &_FIELD_1706,
+    &_FIELD_1707,
+    &_FIELD_1707,
+    &_FIELD_1708,
+    &_FIELD_1709,
+    &_FIELD_1710
+];
+
+#[rustfmt::skip]
+static _FIELD_1711: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} секунду", "", "за {0} секунде", "", "за {0} секунди"], past: ["", "прије {0} секунде", "", "прије {0} секунде", "", "прије {0} секунди"] };
+
+#[rustfmt::skip]
+static _FIELD_1712: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} сек.", "", "за {0} сек.", "", "за {0} сек."], past: ["", "прије {0} сек.", "", "прије {0} сек.", "", "прије {0} сек."] };
+
+#[rustfmt::skip]
+static _FIELD_1713: _RelativeField = _RelativeField { relatives: &[(0, "сада")], future: ["", "за {0} с.", "", "за {0} с.", "", "за {0} с."], past: ["", "прије {0} с.", "", "прије {0} с.", "", "прије {0} с."] };
+
+#[rustfmt::skip]
+static _FIELD_1714: _RelativeField = _RelativeField { relatives: &[(0, "овог минута")], future: ["", "за {0} минут", "", "за {0} минута", "", "за {0} минута"], past: ["", "прије {0} минута", "", "прије {0} минута", "", "прије {0} минута"] };
+
+#[rustfmt::skip]
+static _FIELD_1715: _RelativeField = _RelativeField { relatives: &[(0, "овог минута")], future: ["", "за {0} мин.", "", "за {0} мин.", "", "за {0} мин."], past: ["", "прије {0} мин.", "", "прије {0} мин.", "", "прије {0} мин."] };
+
+#[rustfmt::skip]
+static _FIELD_1716: _RelativeField = _RelativeField { relatives: &[(0, "овог сата")], future: ["", "за {0} сат", "", "за {0} сата", "", "за {0} сати"], past: ["", "прије {0} сата", "", "прије {0} сата", "", "прије {0} сати"] };
+
+#[rustfmt::skip]
+static _FIELD_1717: _RelativeField = _RelativeField { relatives: &[(0, "овог сата")], future: ["", "за {0} ч.", "", "за {0} ч.", "", "за {0} ч."], past: ["", "прије {0} ч.", "", "прије {0} ч.", "", "прије {0} ч."] };
+
+#[rustfmt::skip]
+static _FIELD_1718: _RelativeField = _RelativeField { relatives: &[(-2, "прекјуче"), (-1, "јуче"), (0, "данас"), (1, "сутра"), (2, "прекосутра")], future: ["", "за {0} дан", "", "за {0} дана", "", "за {0} дана"], past: ["", "прије {0} дана", "", "прије {0} дана", "", "прије {0} дана"] };
+
+#[rustfmt::skip]
+static _FIELD_1719: _RelativeField = _RelativeField { relatives: &[(-2, "прекјуче"), (-1, "јуче"), (0, "данас"), (1, "сутра"), (2, "прекосутра")], future: ["", "за {0} д.", "", "за {0} д.", "", "за {0} д."], past: ["", "прије {0} д.", "", "прије {0} д.", "", "прије {0} д."] };
+
+#[rustfmt::skip]
+static _FIELD_1720: _RelativeField = _RelativeField { relatives: &[(-1, "прошле седмице"), (0, "ове седмице"), (1, "сљедеће седмице")], future: ["", "за {0} седмицу", "", "за {0} седмице", "", "за {0} седмица"], past: ["", "прије {0} седмице", "", "прије {0} седмице", "", "прије {0} седмица"] };
+
+#[rustfmt::skip]
+static _FIELD_1721: _RelativeField = _RelativeField { relatives: &[(-1, "прошле седм."), (0, "ове седм."), (1, "сљедеће седм.")], future: ["", "за {0} седм.", "", "за {0} седм.", "", "за {0} седм."], past: ["", "прије {0} седм.", "", "прије {0} седм.", "", "прије {0} седм."] };
+
+#[rustfmt::skip]
+static _FIELD_1722: _RelativeField = _RelativeField { relatives: &[(-1, "прошле с."), (0, "ове с."), (1, "сљедеће с.")], future: ["", "за {0} с.", "", "за {0} с.", "", "за {0} с."], past: ["", "прије {0} с.", "", "прије {0} с.", "", "прије {0} с."] };
+
+#[rustfmt::skip]
+static _FIELD_1723: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог мјесеца"), (0, "овог мјесеца"), (1, "сљедећег мјесеца")], future: ["", "за {0} мјесец", "", "за {0} мјесеца", "", "за {0} мјесеци"], past: ["", "прије {0} мјесеца", "", "прије {0} мјесеца", "", "прије {0} мјесеци"] };
+
+#[rustfmt::skip]
+static _FIELD_1724: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог мјес."), (0, "овог мјес."), (1, "сљедећег мјес.")], future: ["", "за {0} мјес.", "", "за {0} мјес.", "", "за {0} мјес."], past: ["", "прије {0} мјес.", "", "прије {0} мјес.", "", "прије {0} мјес."] };
+
+#[rustfmt::skip]
+static _FIELD_1725: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог мјес."), (0, "овог мјес."), (1, "сљедећег м.")], future: ["", "за {0} м.", "", "за {0} м.", "", "за {0} м."], past: ["", "прије {0} м.", "", "прије {0} м.", "", "прије {0} м."] };
+
+#[rustfmt::skip]
+static _FIELD_1726: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог квартала"), (0, "овог квартала"), (1, "сљедећег квартала")], future: ["", "за {0} квартал", "", "за {0} квартала", "", "за {0} квартала"], past: ["", "прије {0} квартала", "", "прије {0} квартала", "", "прије {0} квартала"] };
+
+#[rustfmt::skip]
+static _FIELD_1727: _RelativeField = _RelativeField { relatives: &[(-1, "прошлог квартала"), (0, "овог квартала"), (1, "сљедећег квартала")], future: ["", "за {0} кв.", "", "за {0} кв.", "", "за {0} кв."], past: ["", "прије {0} кв.", "", "прије {0} кв.", "", "прије {0} кв."] };
+
+#[rustfmt::skip]
+static _FIELD_1728: _RelativeField = _RelativeField { relatives: &[(-1, "прошле године"), (0, "ове године"), (1, "сљедеће године")], future: ["", "за {0} годину", "", "за {0} године", "", "за {0} година"], past: ["", "прије {0} године", "", "прије {0} године", "", "прије {0} година"] };
+
+#[rustfmt::skip]
+static _FIELD_1729: _RelativeField = _RelativeField { relatives: &[(-1, "прошле год."), (0, "ове год."), (1, "сљедеће год.")], future: ["", "за {0} год.", "", "за {0} год.", "", "за {0} год."], past: ["", "прије {0} год.", "", "прије {0} год.", "", "прије {0} год."] };
+
+#[rustfmt::skip]
+static _FIELD_1730: _RelativeField = _RelativeField { relatives: &[(-1, "прошле г."), (0, "ове г."), (1, "сљедеће г.")], future: ["", "за {0} г.", "", "за {0} г.", "", "за {0} г."], past: ["", "прије {0} г.", "", "прије {0} г.", "", "прије {0} г."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_138: [&_RelativeField; 24] = [
+    &_FIELD_1711,
+    &_FIELD_1712,
+    &_FIELD_1713,
+    &_FIELD_1714,
+    &_FIELD_1715,
+    &_FIELD_1715,
+    &_FIELD_1716,
+    &_FIELD_1717,
+    &_FIELD_1717,
+    &_FIELD_1718,
+    &_FIELD_1719,
+    &_FIELD_1719,
+    &_FIELD_1720,
+    &_FIELD_1721,
+    &_FIELD_1722,
+    &_FIELD_1723,
+    &_FIELD_1724,
+    &_FIELD_1725,
+    &_FIELD_1726,
+    &_FIELD_1727,
+    &_FIELD_1727,
+    &_FIELD_1728,
+    &_FIELD_1729,
+    &_FIELD_1730
+];
+
+#[rustfmt::skip]
+static _FIELD_1731: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} sekundu", "", "za {0} sekunde", "", "za {0} sekundi"], past: ["", "pre {0} sekunde", "", "pre {0} sekunde", "", "pre {0} sekundi"] };
+
+#[rustfmt::skip]
+static _FIELD_1732: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} sek.", "", "za {0} sek.", "", "za {0} sek."], past: ["", "pre {0} sek.", "", "pre {0} sek.", "", "pre {0} sek."] };
+
+#[rustfmt::skip]
+static _FIELD_1733: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} s.", "", "za {0} s.", "", "za {0} s."], past: ["", "pre {0} s.", "", "pre {0} s.", "", "pre {0} s."] };
+
+#[rustfmt::skip]
+static _FIELD_1734: _RelativeField = _RelativeField { relatives: &[(0, "ovog minuta")], future: ["", "za {0} minut", "", "za {0} minuta", "", "za {0} minuta"], past: ["", "pre {0} minuta", "", "pre {0} minuta", "", "pre {0} minuta"] };
+
+#[rustfmt::skip]
+static _FIELD_1735: _RelativeField = _RelativeField { relatives: &[(0, "ovog minuta")], future: ["", "za {0} min.", "", "za {0} min.", "", "za {0} min."], past: ["", "pre {0} min.", "", "pre {0} min.", "", "pre {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1736: _RelativeField = _RelativeField { relatives: &[(0, "ovog sata")], future: ["", "za {0} sat", "", "za {0} sata", "", "za {0} sati"], past: ["", "pre {0} sata", "", "pre {0} sata", "", "pre {0} sati"] };
+
+#[rustfmt::skip]
+static _FIELD_1737: _RelativeField = _RelativeField { relatives: &[(0, "ovog sata")], future: ["", "za {0} č.", "", "za {0} č.", "", "za {0} č."], past: ["", "pre {0} č.", "", "pre {0} č.", "", "pre {0} č."] };
+
+#[rustfmt::skip]
+static _FIELD_1738: _RelativeField = _RelativeField { relatives: &[(-2, "prekjuče"), (-1, "juče"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} dan", "", "za {0} dana", "", "za {0} dana"], past: ["", "pre {0} dana", "", "pre {0} dana", "", "pre {0} dana"] };
+
+#[rustfmt::skip]
+static _FIELD_1739: _RelativeField = _RelativeField { relatives: &[(-2, "prekjuče"), (-1, "juče"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} d.", "", "za {0} d.", "", "za {0} d."], past: ["", "pre {0} d.", "", "pre {0} d.", "", "pre {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1740: _RelativeField = _RelativeField { relatives: &[(-1, "prošle nedelje"), (0, "ove nedelje"), (1, "sledeće nedelje")], future: ["", "za {0} nedelju", "", "za {0} nedelje", "", "za {0} nedelja"], past: ["", "pre {0} nedelje", "", "pre {0} nedelje", "", "pre {0} nedelja"] };
+
+#[rustfmt::skip]
+static _FIELD_1741: _RelativeField = _RelativeField { relatives: &[(-1, "prošle ned."), (0, "ove ned."), (1, "sledeće ned.")], future: ["", "za {0} ned.", "", "za {0} ned.", "", "za {0} ned."], past: ["", "pre {0} ned.", "", "pre {0} ned.", "", "pre {0} ned."] };
+
+#[rustfmt::skip]
+static _FIELD_1742: _RelativeField = _RelativeField { relatives: &[(-1, "prošle n."), (0, "ove n."), (1, "sledeće n.")], future: ["", "za {0} n.", "", "za {0} n.", "", "za {0} n."], past: ["", "pre {0} n.", "", "pre {0} n.", "", "pre {0} n."] };
+
+#[rustfmt::skip]
+static _FIELD_1743: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog meseca"), (0, "ovog meseca"), (1, "sledećeg meseca")], future: ["", "za {0} mesec", "", "za {0} meseca", "", "za {0} meseci"], past: ["", "pre {0} meseca", "", "pre {0} meseca", "", "pre {0} meseci"] };
+
+#[rustfmt::skip]
+static _FIELD_1744: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog mes."), (0, "ovog mes."), (1, "sledećeg mes.")], future: ["", "za {0} mes.", "", "za {0} mes.", "", "za {0} mes."], past: ["", "pre {0} mes.", "", "pre {0} mes.", "", "pre {0} mes."] };
+
+#[rustfmt::skip]
+static _FIELD_1745: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog mes."), (0, "ovog mes."), (1, "sledećeg mes.")], future: ["", "za {0} m.", "", "za {0} m.", "", "za {0} m."], past: ["", "pre {0} m.", "", "pre {0} m.", "", "pre {0} m."] };
+
+#[rustfmt::skip]
+static _FIELD_1746: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog kvartala"), (0, "ovog kvartala"), (1, "sledećeg kvartala")], future: ["", "za {0} kvartal", "", "za {0} kvartala", "", "za {0} kvartala"], past: ["", "pre {0} kvartala", "", "pre {0} kvartala", "", "pre {0} kvartala"] };
+
+#[rustfmt::skip]
+static _FIELD_1747: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog kvartala"), (0, "ovog kvartala"), (1, "sledećeg kvartala")], future: ["", "za {0} kv.", "", "za {0} kv.", "", "za {0} kv."], past: ["", "pre {0} kv.", "", "pre {0} kv.", "", "pre {0} kv."] };
+
+#[rustfmt::skip]
+static _FIELD_1748: _RelativeField = _RelativeField { relatives: &[(-1, "prošle godine"), (0, "ove godine"), (1, "sledeće godine")], future: ["", "za {0} godinu", "", "za {0} godine", "", "za {0} godina"], past: ["", "pre {0} godine", "", "pre {0} godine", "", "pre {0} godina"] };
+
+#[rustfmt::skip]
+static _FIELD_1749: _RelativeField = _RelativeField { relatives: &[(-1, "prošle god."), (0, "ove god."), (1, "sledeće god.")], future: ["", "za {0} god.", "", "za {0} god.", "", "za {0} god."], past: ["", "pre {0} god.", "", "pre {0} god.", "", "pre {0} god."] };
+
+#[rustfmt::skip]
+static _FIELD_1750: _RelativeField = _RelativeField { relatives: &[(-1, "prošle g."), (0, "ove g."), (1, "sledeće g.")], future: ["", "za {0} g.", "", "za {0} g.", "", "za {0} g."], past: ["", "pre {0} g.", "", "pre {0} g.", "", "pre {0} g."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_139: [&_RelativeField; 24] = [
+    &_FIELD_1731,
+    &_FIELD_1732,
+    &_FIELD_1733,
+    &_FIELD_1734,
+    &_FIELD_1735,
+    &_FIELD_1735,
+    &_FIELD_1736,
+    &_FIELD_1737,
+    &_FIELD_1737,
+    &_FIELD_1738,
+    &_FIELD_1739,
+    &_FIELD_1739,
+    &_FIELD_1740,
+    &_FIELD_1741,
+    &_FIELD_1742,
+    &_FIELD_1743,
+    &_FIELD_1744,
+    &_FIELD_1745,
+    &_FIELD_1746,
+    &_FIELD_1747,
+    &_FIELD_1747,
+    &_FIELD_1748,
+    &_FIELD_1749,
+    &_FIELD_1750
+];
+
+#[rustfmt::skip]
+static _FIELD_1751: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} sekundu", "", "za {0} sekunde", "", "za {0} sekundi"], past: ["", "prije {0} sekunde", "", "prije {0} sekunde", "", "prije {0} sekundi"] };
+
+#[rustfmt::skip]
+static _FIELD_1752: _RelativeField = _RelativeField { relatives: &[(0, "sada")], future: ["", "za {0} s.", "", "za {0} s.", "", "za {0} s."], past: ["", "prije {0} s.", "", "prije {0} s.", "", "prije {0} s."] };
+
+#[rustfmt::skip]
+static _FIELD_1753: _RelativeField = _RelativeField { relatives: &[(0, "ovog minuta")], future: ["", "za {0} minut", "", "za {0} minuta", "", "za {0} minuta"], past: ["", "prije {0} minuta", "", "prije {0} minuta", "", "prije {0} minuta"] };
+
+#[rustfmt::skip]
+static _FIELD_1754: _RelativeField = _RelativeField { relatives: &[(0, "ovog minuta")], future: ["", "za {0} min.", "", "za {0} min.", "", "za {0} min."], past: ["", "prije {0} min.", "", "prije {0} min.", "", "prije {0} min."] };
+
+#[rustfmt::skip]
+static _FIELD_1755: _RelativeField = _RelativeField { relatives: &[(0, "ovog sata")], future: ["", "za {0} sat", "", "za {0} sata", "", "za {0} sati"], past: ["", "prije {0} sata", "", "prije {0} sata", "", "prije {0} sati"] };
+
+#[rustfmt::skip]
+static _FIELD_1756: _RelativeField = _RelativeField { relatives: &[(0, "ovog sata")], future: ["", "za {0} č.", "", "za {0} č.", "", "za {0} č."], past: ["", "prije {0} č.", "", "prije {0} č.", "", "prije {0} č."] };
+
+#[rustfmt::skip]
+static _FIELD_1757: _RelativeField = _RelativeField { relatives: &[(-2, "prekjuče"), (-1, "juče"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} dan", "", "za {0} dana", "", "za {0} dana"], past: ["", "prije {0} dana", "", "prije {0} dana", "", "prije {0} dana"] };
+
+#[rustfmt::skip]
+static _FIELD_1758: _RelativeField = _RelativeField { relatives: &[(-2, "prekjuče"), (-1, "juče"), (0, "danas"), (1, "sutra"), (2, "prekosutra")], future: ["", "za {0} d.", "", "za {0} d.", "", "za {0} d."], past: ["", "prije {0} d.", "", "prije {0} d.", "", "prije {0} d."] };
+
+#[rustfmt::skip]
+static _FIELD_1759: _RelativeField = _RelativeField { relatives: &[(-1, "prošle sedmice"), (0, "ove sedmice"), (1, "sljedeće sedmice")], future: ["", "za {0} sedmicu", "", "za {0} sedmice", "", "za {0} sedmica"], past: ["", "prije {0} sedmice", "", "prije {0} sedmice", "", "prije {0} sedmica"] };
+
+#[rustfmt::skip]
+static _FIELD_1760: _RelativeField = _RelativeField { relatives: &[(-1, "prošle sedm."), (0, "ove sedm."), (1, "sljedeće sedm.")], future: ["", "za {0} sedm.", "", "za {0} sedm.", "", "za {0} sedm."], past: ["", "prije {0} sedm.", "", "prije {0} sedm.", "", "prije {0} sedm."] };
+
+#[rustfmt::skip]
+static _FIELD_1761: _RelativeField = _RelativeField { relatives: &[(-1, "prošle s."), (0, "ove s."), (1, "sljedeće s.")], future: ["", "za {0} s.", "", "za {0} s.", "", "za {0} s."], past: ["", "prije {0} s.", "", "prije {0} s.", "", "prije {0} s."] };
+
+#[rustfmt::skip]
+static _FIELD_1762: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog mjeseca"), (0, "ovog mjeseca"), (1, "sljedećeg mjeseca")], future: ["", "za {0} mjesec", "", "za {0} mjeseca", "", "za {0} mjeseci"], past: ["", "prije {0} mjeseca", "", "prije {0} mjeseca", "", "prije {0} mjeseci"] };
+
+#[rustfmt::skip]
+static _FIELD_1763: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog mjes."), (0, "ovog mjes."), (1, "sljedećeg mjes.")], future: ["", "za {0} mjes.", "", "za {0} mjes.", "", "za {0} mjes."], past: ["", "prije {0} mjes.", "", "prije {0} mjes.", "", "prije {0} mjes."] };
+
+#[rustfmt::skip]
+static _FIELD_1764: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog mjes."), (0, "ovog mjes."), (1, "sljedećeg m.")], future: ["", "za {0} m.", "", "za {0} m.", "", "za {0} m."], past: ["", "prije {0} m.", "", "prije {0} m.", "", "prije {0} m."] };
+
+#[rustfmt::skip]
+static _FIELD_1765: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog kvartala"), (0, "ovog kvartala"), (1, "sljedećeg kvartala")], future: ["", "za {0} kvartal", "", "za {0} kvartala", "", "za {0} kvartala"], past: ["", "prije {0} kvartala", "", "prije {0} kvartala", "", "prije {0} kvartala"] };
+
+#[rustfmt::skip]
+static _FIELD_1766: _RelativeField = _RelativeField { relatives: &[(-1, "prošlog kvartala"), (0, "ovog kvartala"), (1, "sljedećeg kvartala")], future: ["", "za {0} kv.", "", "za {0} kv.", "", "za {0} kv."], past: ["", "prije {0} kv.", "", "prije {0} kv.", "", "prije {0} kv."] };
+
+#[rustfmt::skip]
+static _FIELD_1767: _RelativeField = _RelativeField { relatives: &[(-1, "prošle godine"), (0, "ove godine"), (1, "sljedeće godine")], future: ["", "za {0} godinu", "", "za {0} godine", "", "za {0} godina"], past: ["", "prije {0} godine", "", "prije {0} godine", "", "prije {0} godina"] };
+
+#[rustfmt::skip]
+static _FIELD_1768: _RelativeField = _RelativeField { relatives: &[(-1, "prošle god."), (0, "ove god."), (1, "sljedeće god.")], future: ["", "za {0} god.", "", "za {0} god.", "", "za {0} god."], past: ["", "prije {0} god.", "", "prije {0} god.", "", "prije {0} god."] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_140: [&_RelativeField; 24] = [
+    &_FIELD_1751,
+    &_FIELD_205,
+    &_FIELD_1752,
+    &_FIELD_1753,
+    &_FIELD_1754,
+    &_FIELD_1754,
+    &_FIELD_1755,
+    &_FIELD_1756,
+    &_FIELD_1756,
+    &_FIELD_1757,
+    &_FIELD_1758,
+    &_FIELD_1758,
+    &_FIELD_1759,
+    &_FIELD_1760,
+    &_FIELD_1761,
+    &_FIELD_1762,
+    &_FIELD_1763,
+    &_FIELD_1764,
+    &_FIELD_1765,
+    &_FIELD_1766,
+    &_FIELD_1766,
+    &_FIELD_1767,
+    &_FIELD_1768,
+    &_FIELD_786
+];
+
+#[rustfmt::skip]
+static _FIELD_1769: _RelativeField = _RelativeField { relatives: &[(-1, "kamari"), (0, "dinten ieu"), (1, "énjing")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1770: _RelativeField = _RelativeField { relatives: &[(-1, "taun kamari"), (0, "taun ieu"), (1, "taun payun")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_141: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_1769,
+    &_FIELD_1769,
+    &_FIELD_1769,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1770,
+    &_FIELD_1770,
+    &_FIELD_1770
+];
+
+#[rustfmt::skip]
+static _FIELD_1771: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "om {0} sekund", "", "", "", "om {0} sekunder"], past: ["", "för {0} sekund sedan", "", "", "", "för {0} sekunder sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1772: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "om\u{a0}{0} sek", "", "", "", "om {0} sek"], past: ["", "för {0} s sen", "", "", "", "för {0} s sen"] };
+
+#[rustfmt::skip]
+static _FIELD_1773: _RelativeField = _RelativeField { relatives: &[(0, "nu")], future: ["", "+{0} s", "", "", "", "+{0} s"], past: ["", "−{0} s", "", "", "", "−{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_1774: _RelativeField = _RelativeField { relatives: &[(0, "denna minut")], future: ["", "om {0} minut", "", "", "", "om {0} minuter"], past: ["", "för {0} minut sedan", "", "", "", "för {0} minuter sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1775: _RelativeField = _RelativeField { relatives: &[(0, "denna minut")], future: ["", "om\u{a0}{0} min", "", "", "", "om\u{a0}{0} min"], past: ["", "för {0} min sen", "", "", "", "för {0} min sen"] };
+
+#[rustfmt::skip]
+static _FIELD_1776: _RelativeField = _RelativeField { relatives: &[(0, "denna minut")], future: ["", "+{0} min", "", "", "", "+{0} min"], past: ["", "−{0} min", "", "", "", "−{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_1777: _RelativeField = _RelativeField { relatives: &[(0, "denna timme")], future: ["", "om {0} timme", "", "", "", "om {0} timmar"], past: ["", "för {0} timme sedan", "", "", "", "för {0} timmar sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1778: _RelativeField = _RelativeField { relatives: &[(0, "denna timme")], future: ["", "om {0} tim", "", "", "", "om {0} tim"], past: ["", "för {0} tim sedan", "", "", "", "för {0} tim sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1779: _RelativeField = _RelativeField { relatives: &[(0, "denna timme")], future: ["", "+{0} h", "", "", "", "+{0} h"], past: ["", "−{0} h", "", "", "", "−{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_1780: _RelativeField = _RelativeField { relatives: &[(-2, "i förrgår"), (-1, "i går"), (0, "i dag"), (1, "i morgon"), (2, "i övermorgon")], future: ["", "om {0} dag", "", "", "", "om {0} dagar"], past: ["", "för {0} dag sedan", "", "", "", "för {0} dagar sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1781: _RelativeField = _RelativeField { relatives: &[(-2, "i förrgår"), (-1, "i går"), (0, "i dag"), (1, "i morgon"), (2, "i övermorgon")], future: ["", "om {0} d", "", "", "", "om {0} d"], past: ["", "för {0} d sedan", "", "", "", "för\u{a0}{0}\u{a0}d sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1782: _RelativeField = _RelativeField { relatives: &[(-2, "i förrgår"), (-1, "igår"), (0, "idag"), (1, "imorgon"), (2, "i övermorgon")], future: ["", "+{0} d", "", "", "", "+{0} d"], past: ["", "−{0} d", "", "", "", "−{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_1783: _RelativeField = _RelativeField { relatives: &[(-1, "förra veckan"), (0, "denna vecka"), (1, "nästa vecka")], future: ["", "om {0} vecka", "", "", "", "om {0} veckor"], past: ["", "för {0} vecka sedan", "", "", "", "för {0} veckor sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1784: _RelativeField = _RelativeField { relatives: &[(-1, "förra v."), (0, "denna v."), (1, "nästa v.")], future: ["", "om {0} v.", "", "", "", "om {0} v."], past: ["", "för {0} v. sedan", "", "", "", "för {0} v. sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1785: _RelativeField = _RelativeField { relatives: &[(-1, "förra v."), (0, "denna v."), (1, "nästa v.")], future: ["", "+{0} v.", "", "", "", "+{0} v."], past: ["", "−{0} v", "", "", "", "−{0} v"] };
+
+#[rustfmt::skip]
+static _FIELD_1786: _RelativeField = _RelativeField { relatives: &[(-1, "förra månaden"), (0, "den här månaden"), (1, "nästa månad")], future: ["", "om {0} månad", "", "", "", "om {0} månader"], past: ["", "för {0} månad sedan", "", "", "", "för {0} månader sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1787: _RelativeField = _RelativeField { relatives: &[(-1, "förra mån."), (0, "denna mån."), (1, "nästa mån.")], future: ["", "om {0} mån.", "", "", "", "om {0} mån."], past: ["", "för {0} mån. sen", "", "", "", "för {0} mån. sen"] };
+
+#[rustfmt::skip]
+static _FIELD_1788: _RelativeField = _RelativeField { relatives: &[(-1, "förra mån."), (0, "denna mån."), (1, "nästa mån.")], future: ["", "+{0} mån.", "", "", "", "+{0} mån."], past: ["", "−{0} mån", "", "", "", "−{0} mån"] };
+
+#[rustfmt::skip]
+static _FIELD_1789: _RelativeField = _RelativeField { relatives: &[(-1, "förra kvartalet"), (0, "detta kvartal"), (1, "nästa kvartal")], future: ["", "om {0} kvartal", "", "", "", "om {0} kvartal"], past: ["", "för {0} kvartal sedan", "", "", "", "för {0} kvartal sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1790: _RelativeField = _RelativeField { relatives: &[(-1, "förra kv."), (0, "detta kv."), (1, "nästa kv.")], future: ["", "om {0} kv.", "", "", "", "om {0} kv."], past: ["", "för {0} kv. sen", "", "", "", "för {0} kv. sen"] };
+
+#[rustfmt::skip]
+static _FIELD_1791: _RelativeField = _RelativeField { relatives: &[(-1, "förra kv."), (0, "detta kv."), (1, "nästa kv.")], future: ["", "+{0} kv.", "", "", "", "+{0} kv."], past: ["", "−{0} kv", "", "", "", "−{0} kv"] };
+
+#[rustfmt::skip]
+static _FIELD_1792: _RelativeField = _RelativeField { relatives: &[(-1, "i fjol"), (0, "i år"), (1, "nästa år")], future: ["", "om {0} år", "", "", "", "om {0} år"], past: ["", "för {0} år sedan", "", "", "", "för {0} år sedan"] };
+
+#[rustfmt::skip]
+static _FIELD_1793: _RelativeField = _RelativeField { relatives: &[(-1, "i fjol"), (0, "i år"), (1, "nästa år")], future: ["", "om {0} år", "", "", "", "om {0} år"], past: ["", "för {0} år sen", "", "", "", "för {0} år sen"] };
+
+#[rustfmt::skip]
+static _FIELD_1794: _RelativeField = _RelativeField { relatives: &[(-1, "i fjol"), (0, "i år"), (1, "nästa år")], future: ["", "+{0} år", "", "", "", "+{0} år"], past: ["", "−{0} år", "", "", "", "−{0} år"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_142: [&_RelativeField; 24] = [
+    &_FIELD_1771,
+    &_FIELD_1772,
+    &_FIELD_1773,
+    &_FIELD_1774,
+    &_FIELD_1775,
+    &_FIELD_1776,
+    &_FIELD_1777,
+    &_FIELD_1778,
+    &_FIELD_1779,
+    &_FIELD_1780,
+    &_FIELD_1781,
+    &_FIELD_1782,
+    &_FIELD_1783,
+    &_FIELD_1784,
+    &_FIELD_1785,
+    &_FIELD_1786,
+    &_FIELD_1787,
+    &_FIELD_1788,
+    &_FIELD_1789,
+    &_FIELD_1790,
+    &_FIELD_1791,
+    &_FIELD_1792,
+    &_FIELD_1793,
+    &_FIELD_1794
+];
+
+#[rustfmt::skip]
+static _FIELD_1795: _RelativeField = _RelativeField { relatives: &[(0, "sasa hivi")], future: ["", "baada ya sekunde {0}", "", "", "", "baada ya sekunde {0}"], past: ["", "Sekunde {0} iliyopita", "", "", "", "Sekunde {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1796: _RelativeField = _RelativeField { relatives: &[(0, "sasa hivi")], future: ["", "baada ya sekunde {0}", "", "", "", "baada ya sekunde {0}"], past: ["", "sekunde {0} iliyopita", "", "", "", "sekunde {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1797: _RelativeField = _RelativeField { relatives: &[(0, "dakika hii")], future: ["", "baada ya dakika {0}", "", "", "", "baada ya dakika {0}"], past: ["", "dakika {0} iliyopita", "", "", "", "dakika {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1798: _RelativeField = _RelativeField { relatives: &[(0, "saa hii")], future: ["", "baada ya saa {0}", "", "", "", "baada ya saa {0}"], past: ["", "saa {0} iliyopita", "", "", "", "saa {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1799: _RelativeField = _RelativeField { relatives: &[(0, "saa hii")], future: ["", "baada ya saa {0}", "", "", "", "baada ya saa {0}"], past: ["", "Saa {0} iliyopita", "", "", "", "Saa {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1800: _RelativeField = _RelativeField { relatives: &[(-2, "juzi"), (-1, "jana"), (0, "leo"), (1, "kesho"), (2, "kesho kutwa")], future: ["", "baada ya siku {0}", "", "", "", "baada ya siku {0}"], past: ["", "siku {0} iliyopita", "", "", "", "siku {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1801: _RelativeField = _RelativeField { relatives: &[(-1, "wiki iliyopita"), (0, "wiki hii"), (1, "wiki ijayo")], future: ["", "baada ya wiki {0}", "", "", "", "baada ya wiki {0}"], past: ["", "wiki {0} iliyopita", "", "", "", "wiki {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1802: _RelativeField = _RelativeField { relatives: &[(-1, "mwezi uliopita"), (0, "mwezi huu"), (1, "mwezi ujao")], future: ["", "baada ya mwezi {0}", "", "", "", "baada ya miezi {0}"], past: ["", "mwezi {0} uliopita", "", "", "", "miezi {0} iliyopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1803: _RelativeField = _RelativeField { relatives: &[(-1, "robo ya mwaka iliyopita"), (0, "robo hii ya mwaka"), (1, "robo ya mwaka inayofuata")], future: ["", "baada ya robo {0}", "", "", "", "baada ya robo {0}"], past: ["", "robo {0} iliyopita", "", "", "", "robo {0} zilizopita"] };
+
+#[rustfmt::skip]
+static _FIELD_1804: _RelativeField = _RelativeField { relatives: &[(-1, "mwaka uliopita"), (0, "mwaka huu"), (1, "mwaka ujao")], future: ["", "baada ya mwaka {0}", "", "", "", "baada ya miaka {0}"], past: ["", "mwaka {0} uliopita", "", "", "", "miaka {0} iliyopita"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_143: [&_RelativeField; 24] = [
+    &_FIELD_1795,
+    &_FIELD_1796,
+    &_FIELD_1796,
+    &_FIELD_1797,
+    &_FIELD_1797,
+    &_FIELD_1797,
+    &_FIELD_1798,
+    &_FIELD_1798,
+    &_FIELD_1799,
+    &_FIELD_1800,
+    &_FIELD_1800,
+    &_FIELD_1800,
+    &_FIELD_1801,
+    &_FIELD_1801,
+    &_FIELD_1801,
+    &_FIELD_1802,
+    &_FIELD_1802,
+    &_FIELD_1802,
+    &_FIELD_1803,
+    &_FIELD_1803,
+    &_FIELD_1803,
+    &_FIELD_1804,
+    &_FIELD_1804,
+    &_FIELD_1804
+];
+
+#[rustfmt::skip]
+static _FIELD_1805: _RelativeField = _RelativeField { relatives: &[(0, "ܗܫܐ")], future: ["", "ܒܚܕ ܪܦܦܐ", "", "", "", "ܒ{0} ܪ\u{308}ܦܦܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕ ܪܦܦܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܖ\u{308}ܦܦܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1806: _RelativeField = _RelativeField { relatives: &[(0, "ܗܢܐ ܩܛܝܢܬܐ")], future: ["", "ܒܚܕܐ ܩܛܝܢܬܐ", "", "", "", "ܒ{0} ܩܛܝ\u{308}ܢܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕܐ ܩܛܝܢܬܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܩܛܝ\u{308}ܢܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1807: _RelativeField = _RelativeField { relatives: &[(0, "ܗܕܐ ܫܥܬܐ")], future: ["", "ܒܚܕܐ ܫܥܬܐ", "", "", "", "ܒ{0} ܫܥ\u{308}ܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕܐ ܫܥܬܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܫܥ\u{308}ܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1808: _RelativeField = _RelativeField { relatives: &[(-1, "ܐܬܡܠܝ"), (0, "ܐܕܝܘܡ"), (1, "ܝܘܡܐ ܕܐܬܐ")], future: ["", "ܒܚܕ ܝܘܡܐ", "", "", "", "ܒ{0} ܝܘܡܢ\u{308}ܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕ ܝܘܡܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܝܘܡܢ\u{308}ܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1809: _RelativeField = _RelativeField { relatives: &[(-1, "ܫܒܘܥܐ ܕܕܥܒܪ"), (0, "ܗܕܐ ܫܒܘܥܐ"), (1, "ܫܒܘܥܐ ܕܐܬܐ")], future: ["", "ܒܚܕ ܫܒܘܥܐ", "", "", "", "ܒ{0} ܫܒ\u{308}ܘܥܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕ ܫܒܘܥܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܫܒ\u{308}ܘܥܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1810: _RelativeField = _RelativeField { relatives: &[(-1, "ܝܪܚܐ ܕܕܥܒܪ"), (0, "ܗܢܐ ܝܪܚܐ"), (1, "ܝܪܚܐ ܕܐܬܐ")], future: ["", "ܒܚܕ ܝܪܚܐ", "", "", "", "ܒ{0} ܝܪ\u{308}ܚܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕ ܝܪܚܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܝܪ\u{308}ܚܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1811: _RelativeField = _RelativeField { relatives: &[(-1, "ܝܪܚܐ ܕܕܥܒܪ"), (0, "ܗܢܐ ܝܪܚܐ"), (1, "ܝܪܚܐ ܕܐܬܐ")], future: ["", "ܒܚܕ ܝܪܚܐ", "", "", "", "ܒ{0} ܝܪ\u{308}ܚܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕ ܝܪܚܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܝܖ\u{308}ܚܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1812: _RelativeField = _RelativeField { relatives: &[(-1, "ܪܘܒܥܐ ܕܥܒܪ"), (0, "ܗܢܐ ܪܘܒܥܐ"), (1, "ܪܘܒܥܐ ܕܐܬܐ")], future: ["", "ܒܚܕ ܪܘܒܥܐ", "", "", "", "ܒ{0} ܪ\u{308}ܘܒܥܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕ ܪܘܒܥܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܪ\u{308}ܘܒܥܐ"] };
+
+#[rustfmt::skip]
+static _FIELD_1813: _RelativeField = _RelativeField { relatives: &[(-1, "ܐܫܬܩܕܝ"), (0, "ܗܕܐ ܫܢܬܐ"), (1, "ܫܢܬܐ ܐܚܪܬܐ")], future: ["", "ܒܚܕܐ ܫܢܬܐ", "", "", "", "ܒ{0} ܫܢܝ\u{308}ܐ"], past: ["", "ܡ\u{323}ܢ ܩܕܡ ܚܕܐ ܫܢܬܐ", "", "", "", "ܡ\u{323}ܢ ܩܕܡ {0} ܫܢܝ\u{308}ܐ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_144: [&_RelativeField; 24] = [
+    &_FIELD_1805,
+    &_FIELD_1805,
+    &_FIELD_1805,
+    &_FIELD_1806,
+    &_FIELD_1806,
+    &_FIELD_1806,
+    &_FIELD_1807,
+    &_FIELD_1807,
+    &_FIELD_1807,
+    &_FIELD_1808,
+    &_FIELD_1808,
+    &_FIELD_1808,
+    &_FIELD_1809,
+    &_FIELD_1809,
+    &_FIELD_1809,
+    &_FIELD_1810,
+    &_FIELD_1811,
+    &_FIELD_1811,
+    &_FIELD_1812,
+    &_FIELD_1812,
+    &_FIELD_1812,
+    &_FIELD_1813,
+    &_FIELD_1813,
+    &_FIELD_1813
+];
+
+#[rustfmt::skip]
+static _FIELD_1814: _RelativeField = _RelativeField { relatives: &[(0, "இப\u{bcd}போது")], future: ["", "{0} விந\u{bbe}டியில\u{bcd}", "", "", "", "{0} விந\u{bbe}டிகளில\u{bcd}"], past: ["", "{0} விந\u{bbe}டிக\u{bcd}கு முன\u{bcd}", "", "", "", "{0} விந\u{bbe}டிகளுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1815: _RelativeField = _RelativeField { relatives: &[(0, "இப\u{bcd}போது")], future: ["", "{0} விந\u{bbe}.", "", "", "", "{0} விந\u{bbe}."], past: ["", "{0} விந\u{bbe}. முன\u{bcd}", "", "", "", "{0} விந\u{bbe}. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1816: _RelativeField = _RelativeField { relatives: &[(0, "இப\u{bcd}போது")], future: ["", "{0} வி.", "", "", "", "{0} வி."], past: ["", "{0} வி. முன\u{bcd}", "", "", "", "{0} வி. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1817: _RelativeField = _RelativeField { relatives: &[(0, "இந\u{bcd}த ஒரு நிமிடத\u{bcd}தில\u{bcd}")], future: ["", "{0} நிமிடத\u{bcd}தில\u{bcd}", "", "", "", "{0} நிமிடங\u{bcd}களில\u{bcd}"], past: ["", "{0} நிமிடத\u{bcd}திற\u{bcd}கு முன\u{bcd}", "", "", "", "{0} நிமிடங\u{bcd}களுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1818: _RelativeField = _RelativeField { relatives: &[(0, "இந\u{bcd}த ஒரு நிமிடத\u{bcd}தில\u{bcd}")], future: ["", "{0} நிமி.", "", "", "", "{0} நிமி."], past: ["", "{0} நிமி. முன\u{bcd}", "", "", "", "{0} நிமி. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1819: _RelativeField = _RelativeField { relatives: &[(0, "இந\u{bcd}த ஒரு நிமிடத\u{bcd}தில\u{bcd}")], future: ["", "{0} நி.", "", "", "", "{0} நி."], past: ["", "{0} நி. முன\u{bcd}", "", "", "", "{0} நி. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1820: _RelativeField = _RelativeField { relatives: &[(0, "இந\u{bcd}த ஒரு மணிநேரத\u{bcd}தில\u{bcd}")], future: ["", "{0} மணிநேரத\u{bcd}தில\u{bcd}", "", "", "", "{0} மணிநேரத\u{bcd}தில\u{bcd}"], past: ["", "{0} மணிநேரம\u{bcd} முன\u{bcd}", "", "", "", "{0} மணிநேரம\u{bcd} முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1821: _RelativeField = _RelativeField { relatives: &[(0, "இந\u{bcd}த ஒரு மணிநேரத\u{bcd}தில\u{bcd}")], future: ["", "{0} மணி.", "", "", "", "{0} மணி."], past: ["", "{0} மணி. முன\u{bcd}", "", "", "", "{0} மணி. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1822: _RelativeField = _RelativeField { relatives: &[(0, "இந\u{bcd}த ஒரு மணிநேரத\u{bcd}தில\u{bcd}")], future: ["", "{0} ம.", "", "", "", "{0} ம."], past: ["", "{0} ம. முன\u{bcd}", "", "", "", "{0} ம. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1823: _RelativeField = _RelativeField { relatives: &[(-2, "நேற\u{bcd}று முன\u{bcd} தினம\u{bcd}"), (-1, "நேற\u{bcd}று"), (0, "இன\u{bcd}று"), (1, "ந\u{bbe}ளை"), (2, "ந\u{bbe}ளை மறுந\u{bbe}ள\u{bcd}")], future: ["", "{0} ந\u{bbe}ளில\u{bcd}", "", "", "", "{0} ந\u{bbe}ட\u{bcd}களில\u{bcd}"], past: ["", "{0} ந\u{bbe}ளுக\u{bcd}கு முன\u{bcd}", "", "", "", "{0} ந\u{bbe}ட\u{bcd}களுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1824: _RelativeField = _RelativeField { relatives: &[(-2, "நேற\u{bcd}று முன\u{bcd} தினம\u{bcd}"), (-1, "நேற\u{bcd}று"), (0, "இன\u{bcd}று"), (1, "ந\u{bbe}ளை"), (2, "ந\u{bbe}ளை மறுந\u{bbe}ள\u{bcd}")], future: ["", "{0} ந\u{bbe}.", "", "", "", "{0} ந\u{bbe}."], past: ["", "{0} ந\u{bbe}. முன\u{bcd}", "", "", "", "{0} ந\u{bbe}. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1825: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த வ\u{bbe}ரம\u{bcd}"), (0, "இந\u{bcd}த வ\u{bbe}ரம\u{bcd}"), (1, "அடுத\u{bcd}த வ\u{bbe}ரம\u{bcd}")], future: ["", "{0} வ\u{bbe}ரத\u{bcd}தில\u{bcd}", "", "", "", "{0} வ\u{bbe}ரங\u{bcd}களில\u{bcd}"], past: ["", "{0} வ\u{bbe}ரத\u{bcd}திற\u{bcd}கு முன\u{bcd}", "", "", "", "{0} வ\u{bbe}ரங\u{bcd}களுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1826: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த வ\u{bbe}ரம\u{bcd}"), (0, "இந\u{bcd}த வ\u{bbe}ரம\u{bcd}"), (1, "அடுத\u{bcd}த வ\u{bbe}ரம\u{bcd}")], future: ["", "{0} வ\u{bbe}ர.", "", "", "", "{0} வ\u{bbe}ர."], past: ["", "{0} வ\u{bbe}ர. முன\u{bcd}", "", "", "", "{0} வ\u{bbe}ர. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1827: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த வ\u{bbe}ரம\u{bcd}"), (0, "இந\u{bcd}த வ\u{bbe}ரம\u{bcd}"), (1, "அடுத\u{bcd}த வ\u{bbe}ரம\u{bcd}")], future: ["", "{0} வ\u{bbe}.", "", "", "", "{0} வ\u{bbe}."], past: ["", "{0} வ\u{bbe}. முன\u{bcd}", "", "", "", "{0} வ\u{bbe}. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1828: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த ம\u{bbe}தம\u{bcd}"), (0, "இந\u{bcd}த ம\u{bbe}தம\u{bcd}"), (1, "அடுத\u{bcd}த ம\u{bbe}தம\u{bcd}")], future: ["", "{0} ம\u{bbe}தத\u{bcd}தில\u{bcd}", "", "", "", "{0} ம\u{bbe}தங\u{bcd}களில\u{bcd}"], past: ["", "{0} ம\u{bbe}தத\u{bcd}துக\u{bcd}கு முன\u{bcd}", "", "", "", "{0} ம\u{bbe}தங\u{bcd}களுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1829: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த ம\u{bbe}தம\u{bcd}"), (0, "இந\u{bcd}த ம\u{bbe}தம\u{bcd}"), (1, "அடுத\u{bcd}த ம\u{bbe}தம\u{bcd}")], future: ["", "{0} ம\u{bbe}த.", "", "", "", "{0} ம\u{bbe}த."], past: ["", "{0} ம\u{bbe}த. முன\u{bcd}", "", "", "", "{0} ம\u{bbe}த. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1830: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த ம\u{bbe}தம\u{bcd}"), (0, "இந\u{bcd}த ம\u{bbe}தம\u{bcd}"), (1, "அடுத\u{bcd}த ம\u{bbe}தம\u{bcd}")], future: ["", "{0} ம\u{bbe}.", "", "", "", "{0} ம\u{bbe}."], past: ["", "{0} ம\u{bbe}. முன\u{bcd}", "", "", "", "{0} ம\u{bbe}. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1831: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), (0, "இந\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), (1, "அடுத\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு")], future: ["", "+{0} க\u{bbe}ல\u{bbe}ண\u{bcd}டில\u{bcd}", "", "", "", "{0} க\u{bbe}ல\u{bbe}ண\u{bcd}டுகளில\u{bcd}"], past: ["", "{0} க\u{bbe}ல\u{bbe}ண\u{bcd}டுக\u{bcd}கு முன\u{bcd}", "", "", "", "{0} க\u{bbe}ல\u{bbe}ண\u{bcd}டுகளுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1832: _RelativeField = _RelativeField { relatives: &[(-1, "இறுதி க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), (0, "இந\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), (1, "அடுத\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு")], future: ["", "{0} க\u{bbe}ல\u{bbe}.", "", "", "", "{0} க\u{bbe}ல\u{bbe}."], past: ["", "{0} க\u{bbe}ல\u{bbe}. முன\u{bcd}", "", "", "", "{0} க\u{bbe}ல\u{bbe}. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1833: _RelativeField = _RelativeField { relatives: &[(-1, "இறுதி க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), (0, "இந\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), (1, "அடுத\u{bcd}த க\u{bbe}ல\u{bbe}ண\u{bcd}டு")], future: ["", "{0} க\u{bbe}.", "", "", "", "{0} க\u{bbe}."], past: ["", "{0} க\u{bbe}. முன\u{bcd}", "", "", "", "{0} க\u{bbe}. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1834: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த ஆண\u{bcd}டு"), (0, "இந\u{bcd}த ஆண\u{bcd}டு"), (1, "அடுத\u{bcd}த ஆண\u{bcd}டு")], future: ["", "{0} ஆண\u{bcd}டில\u{bcd}", "", "", "", "{0} ஆண\u{bcd}டுகளில\u{bcd}"], past: ["", "{0} ஆண\u{bcd}டிற\u{bcd}கு முன\u{bcd}", "", "", "", "{0} ஆண\u{bcd}டுகளுக\u{bcd}கு முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _FIELD_1835: _RelativeField = _RelativeField { relatives: &[(-1, "கடந\u{bcd}த ஆண\u{bcd}டு"), (0, "இந\u{bcd}த ஆண\u{bcd}டு"), (1, "அடுத\u{bcd}த ஆண\u{bcd}டு")], future: ["", "{0} ஆ.", "", "", "", "{0} ஆ."], past: ["", "{0} ஆ. முன\u{bcd}", "", "", "", "{0} ஆ. முன\u{bcd}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_145: [&_RelativeField; 24] = [
+    &_FIELD_1814,
+    &_FIELD_1815,
+    &_FIELD_1816,
+    &_FIELD_1817,
+    &_FIELD_1818,
+    &_FIELD_1819,
+    &_FIELD_1820,
+    &_FIELD_1821,
+    &_FIELD_1822,
+    &_FIELD_1823,
+    &_FIELD_1823,
+    &_FIELD_1824,
+    &_FIELD_1825,
+    &_FIELD_1826,
+    &_FIELD_1827,
+    &_FIELD_1828,
+    &_FIELD_1829,
+    &_FIELD_1830,
+    &_FIELD_1831,
+    &_FIELD_1832,
+    &_FIELD_1833,
+    &_FIELD_1834,
+    &_FIELD_1834,
+    &_FIELD_1835
+];
+
+#[rustfmt::skip]
+static _FIELD_1836: _RelativeField = _RelativeField { relatives: &[(0, "ప\u{c4d}రస\u{c4d}తుతం")], future: ["", "{0} స\u{c46}కనుల\u{c4b}", "", "", "", "{0} స\u{c46}కన\u{c4d}లల\u{c4b}"], past: ["", "{0} స\u{c46}కను క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} స\u{c46}కన\u{c4d}ల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1837: _RelativeField = _RelativeField { relatives: &[(0, "ప\u{c4d}రస\u{c4d}తుతం")], future: ["", "{0} స\u{c46}కనుల\u{c4b}", "", "", "", "{0} స\u{c46}కన\u{c4d}లల\u{c4b}"], past: ["", "{0} స\u{c46}క. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} స\u{c46}క. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1838: _RelativeField = _RelativeField { relatives: &[(0, "ప\u{c4d}రస\u{c4d}తుతం")], future: ["", "{0} స\u{c46}క.ల\u{c4b}", "", "", "", "{0} స\u{c46}క. ల\u{c4b}"], past: ["", "{0} స\u{c46}క. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} స\u{c46}క. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1839: _RelativeField = _RelativeField { relatives: &[(0, "ఈ న\u{c3f}మ\u{c3f}షం")], future: ["", "{0} న\u{c3f}మ\u{c3f}షంల\u{c4b}", "", "", "", "{0} న\u{c3f}మ\u{c3f}ష\u{c3e}ల\u{c4d}ల\u{c4b}"], past: ["", "{0} న\u{c3f}మ\u{c3f}షం క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} న\u{c3f}మ\u{c3f}ష\u{c3e}ల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1840: _RelativeField = _RelativeField { relatives: &[(0, "ఈ న\u{c3f}మ\u{c3f}షం")], future: ["", "{0} న\u{c3f}మ\u{c3f}.ల\u{c4b}", "", "", "", "{0} న\u{c3f}మ\u{c3f}.ల\u{c4b}"], past: ["", "{0} న\u{c3f}మ\u{c3f}. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} న\u{c3f}మ\u{c3f}. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1841: _RelativeField = _RelativeField { relatives: &[(0, "ఈ గంట")], future: ["", "{0} గంటల\u{c4b}", "", "", "", "{0} గంటల\u{c4d}ల\u{c4b}"], past: ["", "{0} గంట క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} గంటల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1842: _RelativeField = _RelativeField { relatives: &[(0, "ఈ గంట")], future: ["", "{0} గం.ల\u{c4b}", "", "", "", "{0} గం.ల\u{c4b}"], past: ["", "{0} గం. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} గం. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1843: _RelativeField = _RelativeField { relatives: &[(-2, "మ\u{c4a}న\u{c4d}న"), (-1, "న\u{c3f}న\u{c4d}న"), (0, "ఈ ర\u{c4b}జు"), (1, "ర\u{c47}పు"), (2, "ఎల\u{c4d}లుండ\u{c3f}")], future: ["", "{0} ర\u{c4b}జుల\u{c4b}", "", "", "", "{0} ర\u{c4b}జుల\u{c4d}ల\u{c4b}"], past: ["", "{0} ర\u{c4b}జు క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} ర\u{c4b}జుల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1844: _RelativeField = _RelativeField { relatives: &[(-1, "గత వ\u{c3e}రం"), (0, "ఈ వ\u{c3e}రం"), (1, "తదుపర\u{c3f} వ\u{c3e}రం")], future: ["", "{0} వ\u{c3e}రంల\u{c4b}", "", "", "", "{0} వ\u{c3e}ర\u{c3e}ల\u{c4d}ల\u{c4b}"], past: ["", "{0} వ\u{c3e}రం క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} వ\u{c3e}ర\u{c3e}ల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1845: _RelativeField = _RelativeField { relatives: &[(-1, "గత న\u{c46}ల"), (0, "ఈ న\u{c46}ల"), (1, "తదుపర\u{c3f} న\u{c46}ల")], future: ["", "{0} న\u{c46}లల\u{c4b}", "", "", "", "{0} న\u{c46}లల\u{c4d}ల\u{c4b}"], past: ["", "{0} న\u{c46}ల క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} న\u{c46}లల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1846: _RelativeField = _RelativeField { relatives: &[(-1, "గత త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), (0, "ఈ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), (1, "తదుపర\u{c3f} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం")], future: ["", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కంల\u{c4b}", "", "", "", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}క\u{c3e}ల\u{c4d}ల\u{c4b}"], past: ["", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}క\u{c3e}ల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1847: _RelativeField = _RelativeField { relatives: &[(-1, "గత త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), (0, "ఈ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), (1, "తదుపర\u{c3f} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం")], future: ["", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}.ల\u{c4b}", "", "", "", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}.ల\u{c4d}ల\u{c4b}"], past: ["", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1848: _RelativeField = _RelativeField { relatives: &[(-1, "గత త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), (0, "ఈ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), (1, "తదుపర\u{c3f} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం")], future: ["", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కంల\u{c4b}", "", "", "", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}క\u{c3e}ల\u{c4d}ల\u{c4b}"], past: ["", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} త\u{c4d}ర\u{c48}మ\u{c3e}. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1849: _RelativeField = _RelativeField { relatives: &[(-1, "గత సంవత\u{c4d}సరం"), (0, "ఈ సంవత\u{c4d}సరం"), (1, "తదుపర\u{c3f} సంవత\u{c4d}సరం")], future: ["", "{0} సంవత\u{c4d}సరంల\u{c4b}", "", "", "", "{0} సంవత\u{c4d}సర\u{c3e}ల\u{c4d}ల\u{c4b}"], past: ["", "{0} సంవత\u{c4d}సరం క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} సంవత\u{c4d}సర\u{c3e}ల క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1850: _RelativeField = _RelativeField { relatives: &[(-1, "గత సంవ."), (0, "ఈ సంవ."), (1, "తదుపర\u{c3f} సంవ.")], future: ["", "{0} సం.ల\u{c4b}", "", "", "", "{0} సం.ల\u{c4d}ల\u{c4b}"], past: ["", "{0} సం. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} సం. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _FIELD_1851: _RelativeField = _RelativeField { relatives: &[(-1, "గత సం."), (0, "ఈ సం."), (1, "తదుపర\u{c3f} సం.")], future: ["", "{0} సం.ల\u{c4b}", "", "", "", "{0} సం.ల\u{c4d}ల\u{c4b}"], past: ["", "{0} సం. క\u{c4d}ర\u{c3f}తం", "", "", "", "{0} సం. క\u{c4d}ర\u{c3f}తం"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_146: [&_RelativeField; 24] = [
+    &_FIELD_1836,
+    &_FIELD_1837,
+    &_FIELD_1838,
+    &_FIELD_1839,
+    &_FIELD_1840,
+    &_FIELD_1840,
+    &_FIELD_1841,
+    &_FIELD_1842,
+    &_FIELD_1842,
+    &_FIELD_1843,
+    &_FIELD_1843,
+    &_FIELD_1843,
+    &_FIELD_1844,
+    &_FIELD_1844,
+    &_FIELD_1844,
+    &_FIELD_1845,
+    &_FIELD_1845,
+    &_FIELD_1845,
+    &_FIELD_1846,
+    &_FIELD_1847,
+    &_FIELD_1848,
+    &_FIELD_1849,
+    &_FIELD_1850,
+    &_FIELD_1851
+];
+
+#[rustfmt::skip]
+static _FIELD_1852: _RelativeField = _RelativeField { relatives: &[(0, "ҳозир")], future: ["", "", "", "", "", "пас аз {0} сония"], past: ["", "", "", "", "", "{0} сония пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1853: _RelativeField = _RelativeField { relatives: &[(0, "ҳозир")], future: ["", "", "", "", "", "пас аз {0} сон."], past: ["", "", "", "", "", "{0} сон. пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1854: _RelativeField = _RelativeField { relatives: &[(0, "дақиқаи ҷорӣ")], future: ["", "", "", "", "", "пас аз {0} дақиқа"], past: ["", "", "", "", "", "{0} дақиқа пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1855: _RelativeField = _RelativeField { relatives: &[(0, "дақиқаи ҷорӣ")], future: ["", "", "", "", "", "пас аз {0} дақ."], past: ["", "", "", "", "", "{0} дақ. пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1856: _RelativeField = _RelativeField { relatives: &[(0, "соати ҷорӣ")], future: ["", "", "", "", "", "пас аз {0} соат"], past: ["", "", "", "", "", "{0} соат пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1857: _RelativeField = _RelativeField { relatives: &[(0, "соати ҷорӣ")], future: ["", "", "", "", "", "пас аз {0} ст."], past: ["", "", "", "", "", "{0} ст. пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1858: _RelativeField = _RelativeField { relatives: &[(-1, "дирӯз"), (0, "имрӯз"), (1, "фардо")], future: ["", "", "", "", "", "пас аз {0} рӯз"], past: ["", "", "", "", "", "{0} рӯз пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1859: _RelativeField = _RelativeField { relatives: &[(-1, "ҳафтаи гузашта"), (0, "ҳафтаи ҷорӣ"), (1, "ҳафтаи оянда")], future: ["", "", "", "", "", "пас аз {0} ҳафта"], past: ["", "", "", "", "", "{0} ҳафта пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1860: _RelativeField = _RelativeField { relatives: &[(-1, "ҳафтаи г."), (0, "ҳафтаи ҷ."), (1, "ҳафтаи о.")], future: ["", "", "", "", "", "пас аз {0} ҳ."], past: ["", "", "", "", "", "{0} ҳ. пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1861: _RelativeField = _RelativeField { relatives: &[(-1, "моҳи гузашта"), (0, "моҳи ҷорӣ"), (1, "моҳи оянда")], future: ["", "", "", "", "", "пас аз {0} моҳ"], past: ["", "", "", "", "", "{0} моҳ пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1862: _RelativeField = _RelativeField { relatives: &[(-1, "моҳи г."), (0, "моҳи ҷ."), (1, "моҳи о.")], future: ["", "", "", "", "", "пас аз {0} м."], past: ["", "", "", "", "", "{0} м. пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1863: _RelativeField = _RelativeField { relatives: &[(-1, "чоряки гузашта"), (0, "чоряки ҷорӣ"), (1, "чоряки оянда")], future: ["", "", "", "", "", "пас аз {0} чоряк"], past: ["", "", "", "", "", "{0} чоряк пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1864: _RelativeField = _RelativeField { relatives: &[(-1, "чоряки гузашта"), (0, "чоряки ҷорӣ"), (1, "чоряки оянда")], future: ["", "", "", "", "", "пас аз {0} чр."], past: ["", "", "", "", "", "{0} чр. пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1865: _RelativeField = _RelativeField { relatives: &[(-1, "соли гузашта"), (0, "соли ҷорӣ"), (1, "соли оянда")], future: ["", "", "", "", "", "пас аз {0} сол"], past: ["", "", "", "", "", "{0} сол пеш"] };
+
+#[rustfmt::skip]
+static _FIELD_1866: _RelativeField = _RelativeField { relatives: &[(-1, "соли г."), (0, "соли ҷ."), (1, "соли о.")], future: ["", "", "", "", "", "пас аз {0} с."], past: ["", "", "", "", "", "{0} с. пеш"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_147: [&_RelativeField; 24] = [
+    &_FIELD_1852,
+    &_FIELD_1853,
+    &_FIELD_1853,
+    &_FIELD_1854,
+    &_FIELD_1855,
+    &_FIELD_1855,
+    &_FIELD_1856,
+    &_FIELD_1857,
+    &_FIELD_1857,
+    &_FIELD_1858,
+    &_FIELD_1858,
+    &_FIELD_1858,
+    &_FIELD_1859,
+    &_FIELD_1860,
+    &_FIELD_1860,
+    &_FIELD_1861,
+    &_FIELD_1862,
+    &_FIELD_1862,
+    &_FIELD_1863,
+    &_FIELD_1864,
+    &_FIELD_1864,
+    &_FIELD_1865,
+    &_FIELD_1866,
+    &_FIELD_1866
+];
+
+#[rustfmt::skip]
+static _FIELD_1867: _RelativeField = _RelativeField { relatives: &[(0, "ขณะน\u{e35}\u{e49}")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} ว\u{e34}นาท\u{e35}"], past: ["", "", "", "", "", "{0} ว\u{e34}นาท\u{e35}ท\u{e35}\u{e48}ผ\u{e48}านมา"] };
+
+#[rustfmt::skip]
+static _FIELD_1868: _RelativeField = _RelativeField { relatives: &[(0, "ขณะน\u{e35}\u{e49}")], future: ["", "", "", "", "", "ใน {0} ว\u{e34}นาท\u{e35}"], past: ["", "", "", "", "", "{0} ว\u{e34}นาท\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1869: _RelativeField = _RelativeField { relatives: &[(0, "นาท\u{e35}น\u{e35}\u{e49}")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} นาท\u{e35}"], past: ["", "", "", "", "", "{0} นาท\u{e35}ท\u{e35}\u{e48}ผ\u{e48}านมา"] };
+
+#[rustfmt::skip]
+static _FIELD_1870: _RelativeField = _RelativeField { relatives: &[(0, "นาท\u{e35}น\u{e35}\u{e49}")], future: ["", "", "", "", "", "ใน {0} นาท\u{e35}"], past: ["", "", "", "", "", "{0} นาท\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1871: _RelativeField = _RelativeField { relatives: &[(0, "ช\u{e31}\u{e48}วโมงน\u{e35}\u{e49}")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} ช\u{e31}\u{e48}วโมง"], past: ["", "", "", "", "", "{0} ช\u{e31}\u{e48}วโมงท\u{e35}\u{e48}ผ\u{e48}านมา"] };
+
+#[rustfmt::skip]
+static _FIELD_1872: _RelativeField = _RelativeField { relatives: &[(0, "ช\u{e31}\u{e48}วโมงน\u{e35}\u{e49}")], future: ["", "", "", "", "", "ใน {0} ชม."], past: ["", "", "", "", "", "{0} ชม. ท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1873: _RelativeField = _RelativeField { relatives: &[(-2, "เม\u{e37}\u{e48}อวานซ\u{e37}น"), (-1, "เม\u{e37}\u{e48}อวาน"), (0, "ว\u{e31}นน\u{e35}\u{e49}"), (1, "พร\u{e38}\u{e48}งน\u{e35}\u{e49}"), (2, "มะร\u{e37}นน\u{e35}\u{e49}")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} ว\u{e31}น"], past: ["", "", "", "", "", "{0} ว\u{e31}นท\u{e35}\u{e48}ผ\u{e48}านมา"] };
+
+#[rustfmt::skip]
+static _FIELD_1874: _RelativeField = _RelativeField { relatives: &[(-2, "เม\u{e37}\u{e48}อวานซ\u{e37}น"), (-1, "เม\u{e37}\u{e48}อวาน"), (0, "ว\u{e31}นน\u{e35}\u{e49}"), (1, "พร\u{e38}\u{e48}งน\u{e35}\u{e49}"), (2, "มะร\u{e37}นน\u{e35}\u{e49}")], future: ["", "", "", "", "", "ใน {0} ว\u{e31}น"], past: ["", "", "", "", "", "{0} ว\u{e31}นท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1875: _RelativeField = _RelativeField { relatives: &[(-1, "ส\u{e31}ปดาห\u{e4c}ท\u{e35}\u{e48}แล\u{e49}ว"), (0, "ส\u{e31}ปดาห\u{e4c}น\u{e35}\u{e49}"), (1, "ส\u{e31}ปดาห\u{e4c}หน\u{e49}า")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} ส\u{e31}ปดาห\u{e4c}"], past: ["", "", "", "", "", "{0} ส\u{e31}ปดาห\u{e4c}ท\u{e35}\u{e48}ผ\u{e48}านมา"] };
+
+#[rustfmt::skip]
+static _FIELD_1876: _RelativeField = _RelativeField { relatives: &[(-1, "ส\u{e31}ปดาห\u{e4c}ท\u{e35}\u{e48}แล\u{e49}ว"), (0, "ส\u{e31}ปดาห\u{e4c}น\u{e35}\u{e49}"), (1, "ส\u{e31}ปดาห\u{e4c}หน\u{e49}า")], future: ["", "", "", "", "", "ใน {0} ส\u{e31}ปดาห\u{e4c}"], past: ["", "", "", "", "", "{0} ส\u{e31}ปดาห\u{e4c}ท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1877: _RelativeField = _RelativeField { relatives: &[(-1, "เด\u{e37}อนท\u{e35}\u{e48}แล\u{e49}ว"), (0, "เด\u{e37}อนน\u{e35}\u{e49}"), (1, "เด\u{e37}อนหน\u{e49}า")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} เด\u{e37}อน"], past: ["", "", "", "", "", "{0} เด\u{e37}อนท\u{e35}\u{e48}ผ\u{e48}านมา"] };
+
+#[rustfmt::skip]
+static _FIELD_1878: _RelativeField = _RelativeField { relatives: &[(-1, "เด\u{e37}อนท\u{e35}\u{e48}แล\u{e49}ว"), (0, "เด\u{e37}อนน\u{e35}\u{e49}"), (1, "เด\u{e37}อนหน\u{e49}า")], future: ["", "", "", "", "", "ใน {0} เด\u{e37}อน"], past: ["", "", "", "", "", "{0} เด\u{e37}อนท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1879: _RelativeField = _RelativeField { relatives: &[(-1, "ไตรมาสท\u{e35}\u{e48}แล\u{e49}ว"), (0, "ไตรมาสน\u{e35}\u{e49}"), (1, "ไตรมาสหน\u{e49}า")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} ไตรมาส"], past: ["", "", "", "", "", "{0} ไตรมาสท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1880: _RelativeField = _RelativeField { relatives: &[(-1, "ไตรมาสท\u{e35}\u{e48}แล\u{e49}ว"), (0, "ไตรมาสน\u{e35}\u{e49}"), (1, "ไตรมาสหน\u{e49}า")], future: ["", "", "", "", "", "ใน {0} ไตรมาส"], past: ["", "", "", "", "", "{0} ไตรมาสท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1881: _RelativeField = _RelativeField { relatives: &[(-1, "ป\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"), (0, "ป\u{e35}น\u{e35}\u{e49}"), (1, "ป\u{e35}หน\u{e49}า")], future: ["", "", "", "", "", "ในอ\u{e35}ก {0} ป\u{e35}"], past: ["", "", "", "", "", "{0} ป\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _FIELD_1882: _RelativeField = _RelativeField { relatives: &[(-1, "ป\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"), (0, "ป\u{e35}น\u{e35}\u{e49}"), (1, "ป\u{e35}หน\u{e49}า")], future: ["", "", "", "", "", "ใน {0} ป\u{e35}"], past: ["", "", "", "", "", "{0} ป\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_148: [&_RelativeField; 24] = [
+    &_FIELD_1867,
+    &_FIELD_1868,
+    &_FIELD_1868,
+    &_FIELD_1869,
+    &_FIELD_1870,
+    &_FIELD_1870,
+    &_FIELD_1871,
+    &_FIELD_1872,
+    &_FIELD_1872,
+    &_FIELD_1873,
+    &_FIELD_1874,
+    &_FIELD_1874,
+    &_FIELD_1875,
+    &_FIELD_1876,
+    &_FIELD_1876,
+    &_FIELD_1877,
+    &_FIELD_1878,
+    &_FIELD_1878,
+    &_FIELD_1879,
+    &_FIELD_1880,
+    &_FIELD_1880,
+    &_FIELD_1881,
+    &_FIELD_1882,
+    &_FIELD_1882
+];
+
+#[rustfmt::skip]
+static _FIELD_1883: _RelativeField = _RelativeField { relatives: &[(0, "ሕጂ")], future: ["", "ኣብ {0} ካልኢት", "", "", "", "ኣብ {0} ካልኢት"], past: ["", "ቅድሚ {0} ካልኢት", "", "", "", "ቅድሚ {0} ካልኢት"] };
+
+#[rustfmt::skip]
+static _FIELD_1884: _RelativeField = _RelativeField { relatives: &[(0, "ኣብዚ ደቒቕ")], future: ["", "ኣብ {0} ደቒቕ", "", "", "", "ኣብ {0} ደቒቕ"], past: ["", "ቅድሚ {0} ደቒቕ", "", "", "", "ቅድሚ {0} ደቒቕ"] };
+
+#[rustfmt::skip]
+static _FIELD_1885: _RelativeField = _RelativeField { relatives: &[(0, "ኣብዚ ሰዓት")], future: ["", "ኣብ {0} ሰዓት", "", "", "", "ኣብ {0} ሰዓት"], past: ["", "ቅድሚ {0} ሰዓት", "", "", "", "ቅድሚ {0} ሰዓት"] };
+
+#[rustfmt::skip]
+static _FIELD_1886: _RelativeField = _RelativeField { relatives: &[(-1, "ትማሊ"), (0, "ሎሚ"), (1, "ጽባሕ")], future: ["", "ኣብ {0} መዓልቲ", "", "", "", "ኣብ {0} መዓልቲ"], past: ["", "ቅድሚ {0} መዓልቲ", "", "", "", "ኣብ {0} መዓልቲ"] };
+
+#[rustfmt::skip]
+static _FIELD_1887: _RelativeField = _RelativeField { relatives: &[(-1, "ትማሊ"), (0, "ሎሚ"), (1, "ጽባሕ")], future: ["", "ኣብ {0} መዓልቲ", "", "", "", "ኣብ {0} መዓልቲ"], past: ["", "ቅድሚ {0} መዓልቲ", "", "", "", "ቅድሚ {0} መዓልቲ"] };
+
+#[rustfmt::skip]
+static _FIELD_1888: _RelativeField = _RelativeField { relatives: &[(-1, "ዝሓለፈ ሰሙን"), (0, "ህሉው ሰሙን"), (1, "ዝመጽእ ሰሙን")], future: ["", "ኣብ {0} ሰሙን", "", "", "", "ኣብ {0} ሰሙን"], past: ["", "ቅድሚ {0} ሰሙን", "", "", "", "ቅድሚ {0} ሰሙን"] };
+
+#[rustfmt::skip]
+static _FIELD_1889: _RelativeField = _RelativeField { relatives: &[(-1, "ዝሓለፈ ወርሒ"), (0, "ህሉው ወርሒ"), (1, "ዝመጽእ ወርሒ")], future: ["", "ኣብ {0} ወርሒ", "", "", "", "ኣብ {0} ወርሒ"], past: ["", "ቅድሚ {0} ወርሒ", "", "", "", "ቅድሚ {0} ወርሒ"] };
+
+#[rustfmt::skip]
+static _FIELD_1890: _RelativeField = _RelativeField { relatives: &[(-1, "ዝሓለፈ ርብዒ"), (0, "ህሉው ርብዒ"), (1, "ዝመጽእ ርብዒ")], future: ["", "ኣብ {0} ርብዒ", "", "", "", "ኣብ {0} ርብዒ"], past: ["", "ቅድሚ {0} ርብዒ", "", "", "", "ቅድሚ {0} ርብዒ"] };
+
+#[rustfmt::skip]
+static _FIELD_1891: _RelativeField = _RelativeField { relatives: &[(-1, "ዝሓለፈ ዓመት"), (0, "ሎሚ ዓመት"), (1, "ዝመጽእ ዓመት")], future: ["", "ኣብ {0} ዓ", "", "", "", "ኣብ {0} ዓ"], past: ["", "ቅድሚ {0} ዓ", "", "", "", "ቅድሚ {0} ዓ"] };
+
+#[rustfmt::skip]
+static _FIELD_1892: _RelativeField = _RelativeField { relatives: &[(-1, "ዝሓለፈ ዓመት"), (0, "ሎሚ ዓመት"), (1, "ዝመጽእ ዓመት")], future: ["", "ኣብ {0} ዓ", "", "", "", "ኣብ {0} ዓ"], past: ["", "ቅድሚ -{0} ዓ", "", "", "", "ቅድሚ {0} ዓ"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_149: [&_RelativeField; 24] = [
+    &_FIELD_1883,
+    &_FIELD_1883,
+    &_FIELD_1883,
+    &_FIELD_1884,
+    &_FIELD_1884,
+    &_FIELD_1884,
+    &_FIELD_1885,
+    &_FIELD_1885,
+    &_FIELD_1885,
+    &_FIELD_1886,
+    &_FIELD_1887,
+    &_FIELD_1887,
+    &_FIELD_1888,
+    &_FIELD_1888,
+    &_FIELD_1888,
+    &_FIELD_1889,
+    &_FIELD_1889,
+    &_FIELD_1889,
+    &_FIELD_1890,
+    &_FIELD_1890,
+    &_FIELD_1890,
+    &_FIELD_1891,
+    &_FIELD_1892,
+    &_FIELD_1891
+];
+
+#[rustfmt::skip]
+static _FIELD_1893: _RelativeField = _RelativeField { relatives: &[(0, "häzir")], future: ["", "{0} sekuntdan", "", "", "", "{0} sekuntdan"], past: ["", "{0} sekunt öň", "", "", "", "{0} sekunt öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1894: _RelativeField = _RelativeField { relatives: &[(0, "häzir")], future: ["", "{0} sek-dan", "", "", "", "{0} sek-dan"], past: ["", "{0} sek. öň", "", "", "", "{0} sek. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1895: _RelativeField = _RelativeField { relatives: &[(0, "şu minut")], future: ["", "{0} minutdan", "", "", "", "{0} minutdan"], past: ["", "{0} minut öň", "", "", "", "{0} minut öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1896: _RelativeField = _RelativeField { relatives: &[(0, "şu minut")], future: ["", "{0} min-dan", "", "", "", "{0} min-dan"], past: ["", "{0} min. öň", "", "", "", "{0} min. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1897: _RelativeField = _RelativeField { relatives: &[(0, "şu sagat")], future: ["", "{0} sagatdan", "", "", "", "{0} sagatdan"], past: ["", "{0} sagat öň", "", "", "", "{0} sagat öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1898: _RelativeField = _RelativeField { relatives: &[(0, "şu sagat")], future: ["", "{0} sag-dan", "", "", "", "{0} sag-dan"], past: ["", "{0} sag. öň", "", "", "", "{0} sag. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1899: _RelativeField = _RelativeField { relatives: &[(-1, "düýn"), (0, "şu gün"), (1, "ertir")], future: ["", "{0} günden", "", "", "", "{0} günden"], past: ["", "{0} gün öň", "", "", "", "{0} gün öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1900: _RelativeField = _RelativeField { relatives: &[(-1, "düýn"), (0, "şu gün"), (1, "ertir")], future: ["", "{0} g-den", "", "", "", "{0} g-den"], past: ["", "{0} g. öň", "", "", "", "{0} g. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1901: _RelativeField = _RelativeField { relatives: &[(-1, "geçen hepde"), (0, "şu hepde"), (1, "indiki hepde")], future: ["", "{0} hepdeden", "", "", "", "{0} hepdeden"], past: ["", "{0} hepde öň", "", "", "", "{0} hepde öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1902: _RelativeField = _RelativeField { relatives: &[(-1, "geçen hepde"), (0, "şu hepde"), (1, "indiki hepde")], future: ["", "{0} hep-den", "", "", "", "{0} hep-den"], past: ["", "{0} hep. öň", "", "", "", "{0} hep. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1903: _RelativeField = _RelativeField { relatives: &[(-1, "geçen hepde"), (0, "şu hepde"), (1, "indiki hepde")], future: ["", "{0} h-den", "", "", "", "{0} h-den"], past: ["", "{0} h. öň", "", "", "", "{0} h. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1904: _RelativeField = _RelativeField { relatives: &[(-1, "geçen aý"), (0, "şu aý"), (1, "indiki aý")], future: ["", "{0} aýdan", "", "", "", "{0} aýdan"], past: ["", "{0} aý öň", "", "", "", "{0} aý öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1905: _RelativeField = _RelativeField { relatives: &[(-1, "geçen çärýek"), (0, "şu çärýek"), (1, "indiki çärýek")], future: ["", "{0} çärýekden", "", "", "", "{0} çärýekden"], past: ["", "{0} çärýek öň", "", "", "", "{0} çärýek öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1906: _RelativeField = _RelativeField { relatives: &[(-1, "geçen çärýek"), (0, "şu çärýek"), (1, "indiki çärýek")], future: ["", "{0} çär-den", "", "", "", "{0} çär-den"], past: ["", "{0} çär. öň", "", "", "", "{0} çär. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1907: _RelativeField = _RelativeField { relatives: &[(-1, "geçen çärýek"), (0, "şu çärýek"), (1, "indiki çärýek")], future: ["", "{0} ç-den", "", "", "", "{0} ç-den"], past: ["", "{0} ç. öň", "", "", "", "{0} ç. öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1908: _RelativeField = _RelativeField { relatives: &[(-1, "geçen ýyl"), (0, "şu ýyl"), (1, "indiki ýyl")], future: ["", "{0} ýyldan", "", "", "", "{0} ýyldan"], past: ["", "{0} ýyl öň", "", "", "", "{0} ýyl öň"] };
+
+#[rustfmt::skip]
+static _FIELD_1909: _RelativeField = _RelativeField { relatives: &[(-1, "geçen ýyl"), (0, "şu ýyl"), (1, "indiki ýyl")], future: ["", "{0} ý-dan", "", "", "", "{0} ý-dan"], past: ["", "{0} ý. öň", "", "", "", "{0} ý. öň"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_150: [&_RelativeField; 24] = [
+    &_FIELD_1893,
+    &_FIELD_1894,
+    &_FIELD_1894,
+    &_FIELD_1895,
+    &_FIELD_1896,
+    &_FIELD_1896,
+    &_FIELD_1897,
+    &_FIELD_1898,
+    &_FIELD_1898,
+    &_FIELD_1899,
+    &_FIELD_1900,
+    &_FIELD_1900,
+    &_FIELD_1901,
+    &_FIELD_1902,
+    &_FIELD_1903,
+    &_FIELD_1904,
+    &_FIELD_1904,
+    &_FIELD_1904,
+    &_FIELD_1905,
+    &_FIELD_1906,
+    &_FIELD_1907,
+    &_FIELD_1908,
+    &_FIELD_1909,
+    &_FIELD_1909
+];
+
+#[rustfmt::skip]
+static _FIELD_1910: _RelativeField = _RelativeField { relatives: &[(-1, "ngogola"), (0, "monongwaga"), (1, "ngwaga o tlang")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_151: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_3,
+    &_FIELD_3,
+    &_FIELD_3,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_4,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_5,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1910,
+    &_FIELD_1910,
+    &_FIELD_1910
+];
+
+#[rustfmt::skip]
+static _FIELD_1911: _RelativeField = _RelativeField { relatives: &[(0, "taimí ni")], future: ["", "", "", "", "", "ʻi he sekoni ʻe {0}"], past: ["", "", "", "", "", "sekoni ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1912: _RelativeField = _RelativeField { relatives: &[(0, "ko e miniti ʻeni")], future: ["", "", "", "", "", "ʻi he miniti ʻe {0}"], past: ["", "", "", "", "", "miniti ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1913: _RelativeField = _RelativeField { relatives: &[(0, "ko e houa ʻeni")], future: ["", "", "", "", "", "ʻi he houa ʻe {0}"], past: ["", "", "", "", "", "houa ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1914: _RelativeField = _RelativeField { relatives: &[(-2, "ʻaneheafi"), (-1, "ʻaneafi"), (0, "ʻahó ni"), (1, "ʻapongipongi"), (2, "ʻahepongipongi")], future: ["", "", "", "", "", "ʻi he ʻaho ʻe {0}"], past: ["", "", "", "", "", "ʻaho ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1915: _RelativeField = _RelativeField { relatives: &[(-2, "ʻahn"), (-1, "ʻane"), (0, "ʻa.ni"), (1, "ʻapo"), (2, "ʻahepongipongi")], future: ["", "", "", "", "", "ʻi he ʻaho ʻe {0}"], past: ["", "", "", "", "", "ʻaho ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1916: _RelativeField = _RelativeField { relatives: &[(-1, "uike kuoʻosi"), (0, "uiké ni"), (1, "uike kahaʻu")], future: ["", "", "", "", "", "ʻi he uike ʻe {0}"], past: ["", "", "", "", "", "uike ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1917: _RelativeField = _RelativeField { relatives: &[(-1, "ui.ʻosi"), (0, "ui.ni"), (1, "ui.haʻu")], future: ["", "", "", "", "", "ʻi he uike ʻe {0}"], past: ["", "", "", "", "", "uike ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1918: _RelativeField = _RelativeField { relatives: &[(-1, "māhina kuoʻosi"), (0, "māhiná ni"), (1, "māhina kahaʻu")], future: ["", "", "", "", "", "ʻi he māhina ʻe {0}"], past: ["", "", "", "", "", "māhina ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1919: _RelativeField = _RelativeField { relatives: &[(-1, "mh.ʻosi"), (0, "mh.ni"), (1, "mh.haʻu")], future: ["", "", "", "", "", "ʻi he māhina ʻe {0}"], past: ["", "", "", "", "", "māhina ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1920: _RelativeField = _RelativeField { relatives: &[(-1, "kuata kuoʻosi"), (0, "kuata koʻeni"), (1, "kuata hoko")], future: ["", "", "", "", "", "ʻi he kuata ʻe {0}"], past: ["", "", "", "", "", "kuata ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1921: _RelativeField = _RelativeField { relatives: &[(-1, "taʻu kuoʻosi"), (0, "taʻú ni"), (1, "taʻu kahaʻu")], future: ["", "", "", "", "", "ʻi he taʻu ʻe {0}"], past: ["", "", "", "", "", "taʻu ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _FIELD_1922: _RelativeField = _RelativeField { relatives: &[(-1, "t.ʻosi"), (0, "t.ni"), (1, "t.haʻu")], future: ["", "", "", "", "", "ʻi he taʻu ʻe {0}"], past: ["", "", "", "", "", "taʻu ʻe {0} kuoʻosi"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_152: [&_RelativeField; 24] = [
+    &_FIELD_1911,
+    &_FIELD_1911,
+    &_FIELD_1911,
+    &_FIELD_1912,
+    &_FIELD_1912,
+    &_FIELD_1912,
+    &_FIELD_1913,
+    &_FIELD_1913,
+    &_FIELD_1913,
+    &_FIELD_1914,
+    &_FIELD_1915,
+    &_FIELD_1915,
+    &_FIELD_1916,
+    &_FIELD_1917,
+    &_FIELD_1917,
+    &_FIELD_1918,
+    &_FIELD_1919,
+    &_FIELD_1919,
+    &_FIELD_1920,
+    &_FIELD_1920,
+    &_FIELD_1920,
+    &_FIELD_1921,
+    &_FIELD_1922,
+    &_FIELD_1922
+];
+
+#[rustfmt::skip]
+static _FIELD_1923: _RelativeField = _RelativeField { relatives: &[(0, "şimdi")], future: ["", "{0} saniye sonra", "", "", "", "{0} saniye sonra"], past: ["", "{0} saniye önce", "", "", "", "{0} saniye önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1924: _RelativeField = _RelativeField { relatives: &[(0, "şimdi")], future: ["", "{0} sn. sonra", "", "", "", "{0} sn. sonra"], past: ["", "{0} sn. önce", "", "", "", "{0} sn. önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1925: _RelativeField = _RelativeField { relatives: &[(0, "bu dakika")], future: ["", "{0} dakika sonra", "", "", "", "{0} dakika sonra"], past: ["", "{0} dakika önce", "", "", "", "{0} dakika önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1926: _RelativeField = _RelativeField { relatives: &[(0, "bu dakika")], future: ["", "{0} dk. sonra", "", "", "", "{0} dk. sonra"], past: ["", "{0} dk. önce", "", "", "", "{0} dk. önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1927: _RelativeField = _RelativeField { relatives: &[(0, "bu saat")], future: ["", "{0} saat sonra", "", "", "", "{0} saat sonra"], past: ["", "{0} saat önce", "", "", "", "{0} saat önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1928: _RelativeField = _RelativeField { relatives: &[(0, "bu saat")], future: ["", "{0} sa. sonra", "", "", "", "{0} sa. sonra"], past: ["", "{0} sa. önce", "", "", "", "{0} sa. önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1929: _RelativeField = _RelativeField { relatives: &[(-2, "evvelsi gün"), (-1, "dün"), (0, "bugün"), (1, "yarın"), (2, "öbür gün")], future: ["", "{0} gün sonra", "", "", "", "{0} gün sonra"], past: ["", "{0} gün önce", "", "", "", "{0} gün önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1930: _RelativeField = _RelativeField { relatives: &[(-1, "geçen hafta"), (0, "bu hafta"), (1, "gelecek hafta")], future: ["", "{0} hafta sonra", "", "", "", "{0} hafta sonra"], past: ["", "{0} hafta önce", "", "", "", "{0} hafta önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1931: _RelativeField = _RelativeField { relatives: &[(-1, "geçen hf."), (0, "bu hf."), (1, "gelecek hf.")], future: ["", "{0} hf. sonra", "", "", "", "{0} hf. sonra"], past: ["", "{0} hf. önce", "", "", "", "{0} hf. önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1932: _RelativeField = _RelativeField { relatives: &[(-1, "geçen ay"), (0, "bu ay"), (1, "gelecek ay")], future: ["", "{0} ay sonra", "", "", "", "{0} ay sonra"], past: ["", "{0} ay önce", "", "", "", "{0} ay önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1933: _RelativeField = _RelativeField { relatives: &[(-1, "geçen çeyrek"), (0, "bu çeyrek"), (1, "gelecek çeyrek")], future: ["", "{0} çeyrek sonra", "", "", "", "{0} çeyrek sonra"], past: ["", "{0} çeyrek önce", "", "", "", "{0} çeyrek önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1934: _RelativeField = _RelativeField { relatives: &[(-1, "geçen çyr."), (0, "bu çyr."), (1, "gelecek çyr.")], future: ["", "{0} çyr. sonra", "", "", "", "{0} çyr. sonra"], past: ["", "{0} çyr. önce", "", "", "", "{0} çyr. önce"] };
+
+#[rustfmt::skip]
+static _FIELD_1935: _RelativeField = _RelativeField { relatives: &[(-1, "geçen yıl"), (0, "bu yıl"), (1, "gelecek yıl")], future: ["", "{0} yıl sonra", "", "", "", "{0} yıl sonra"], past: ["", "{0} yıl önce", "", "", "", "{0} yıl önce"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_153: [&_RelativeField; 24] = [
+    &_FIELD_1923,
+    &_FIELD_1924,
+    &_FIELD_1924,
+    &_FIELD_1925,
+    &_FIELD_1926,
+    &_FIELD_1926,
+    &_FIELD_1927,
+    &_FIELD_1928,
+    &_FIELD_1928,
+    &_FIELD_1929,
+    &_FIELD_1929,
+    &_FIELD_1929,
+    &_FIELD_1930,
+    &_FIELD_1931,
+    &_FIELD_1931,
+    &_FIELD_1932,
+    &_FIELD_1932,
+    &_FIELD_1932,
+    &_FIELD_1933,
+    &_FIELD_1934,
+    &_FIELD_1934,
+    &_FIELD_1935,
+    &_FIELD_1935,
+    &_FIELD_1935
+];
+
+#[rustfmt::skip]
+static _FIELD_1936: _RelativeField = _RelativeField { relatives: &[(0, "хәзер")], future: ["", "", "", "", "", "{0} секундтан"], past: ["", "", "", "", "", "{0} секунд элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1937: _RelativeField = _RelativeField { relatives: &[(0, "хәзер")], future: ["", "", "", "", "", "{0} с."], past: ["", "", "", "", "", "{0} с. элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1938: _RelativeField = _RelativeField { relatives: &[(0, "бу минутта")], future: ["", "", "", "", "", "{0} минуттан"], past: ["", "", "", "", "", "{0} минут элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1939: _RelativeField = _RelativeField { relatives: &[(0, "бу минутта")], future: ["", "", "", "", "", "{0} мин."], past: ["", "", "", "", "", "{0} мин. элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1940: _RelativeField = _RelativeField { relatives: &[(0, "бу сәгатьтә")], future: ["", "", "", "", "", "{0} сәгатьтән"], past: ["", "", "", "", "", "{0} сәгать элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1941: _RelativeField = _RelativeField { relatives: &[(0, "бу сәгатьтә")], future: ["", "", "", "", "", "{0} сәг."], past: ["", "", "", "", "", "{0} сәг. элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1942: _RelativeField = _RelativeField { relatives: &[(-1, "кичә"), (0, "бүген"), (1, "иртәгә")], future: ["", "", "", "", "", "{0} көннән"], past: ["", "", "", "", "", "{0} көн элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1943: _RelativeField = _RelativeField { relatives: &[(-1, "узган атнада"), (0, "бу атнада"), (1, "киләсе атнада")], future: ["", "", "", "", "", "{0} атнадан"], past: ["", "", "", "", "", "{0} атна элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1944: _RelativeField = _RelativeField { relatives: &[(-1, "узган айда"), (0, "бу айда"), (1, "киләсе айда")], future: ["", "", "", "", "", "{0} айдан"], past: ["", "", "", "", "", "{0} ай элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1945: _RelativeField = _RelativeField { relatives: &[(-1, "узган кварталда"), (0, "бу кварталда"), (1, "киләсе кварталда")], future: ["", "", "", "", "", "{0} кварталдан"], past: ["", "", "", "", "", "{0} квартал элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1946: _RelativeField = _RelativeField { relatives: &[(-1, "узган кварталда"), (0, "бу кварталда"), (1, "киләсе кварталда")], future: ["", "", "", "", "", "{0} кв."], past: ["", "", "", "", "", "{0} кв. элек"] };
+
+#[rustfmt::skip]
+static _FIELD_1947: _RelativeField = _RelativeField { relatives: &[(-1, "узган ел"), (0, "быел"), (1, "киләсе елда")], future: ["", "", "", "", "", "{0} елдан"], past: ["", "", "", "", "", "{0} ел элек"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_154: [&_RelativeField; 24] = [
+    &_FIELD_1936,
+    &_FIELD_1937,
+    &_FIELD_1937,
+    &_FIELD_1938,
+    &_FIELD_1939,
+    &_FIELD_1939,
+    &_FIELD_1940,
+    &_FIELD_1941,
+    &_FIELD_1941,
+    &_FIELD_1942,
+    &_FIELD_1942,
+    &_FIELD_1942,
+    &_FIELD_1943,
+    &_FIELD_1943,
+    &_FIELD_1943,
+    &_FIELD_1944,
+    &_FIELD_1944,
+    &_FIELD_1944,
+    &_FIELD_1945,
+    &_FIELD_1946,
+    &_FIELD_1946,
+    &_FIELD_1947,
+    &_FIELD_1947,
+    &_FIELD_1947
+];
+
+#[rustfmt::skip]
+static _FIELD_1948: _RelativeField = _RelativeField { relatives: &[(0, "now")], future: ["", "{0} سېكۇنتتىن كېيىن", "", "", "", "{0} سېكۇنتتىن كېيىن"], past: ["", "{0} سېكۇنت ئىلگىرى", "", "", "", "{0} سېكۇنت ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _FIELD_1949: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "{0} مىنۇتتىن كېيىن", "", "", "", "{0} مىنۇتتىن كېيىن"], past: ["", "{0} مىنۇت ئىلگىرى", "", "", "", "{0} مىنۇت ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _FIELD_1950: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "{0} سائەتتىن كېيىن", "", "", "", "{0} سائەتتىن كېيىن"], past: ["", "{0} سائەت ئىلگىرى", "", "", "", "{0} سائەت ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _FIELD_1951: _RelativeField = _RelativeField { relatives: &[(-1, "تۈنۈگۈن"), (0, "بۈگۈن"), (1, "ئەتە")], future: ["", "{0} كۈندىن كېيىن", "", "", "", "{0} كۈندىن كېيىن"], past: ["", "{0} كۈن ئىلگىرى", "", "", "", "{0} كۈن ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _FIELD_1952: _RelativeField = _RelativeField { relatives: &[(-1, "ئۆتكەن ھەپتە"), (0, "بۇ ھەپتە"), (1, "كېلەر ھەپتە")], future: ["", "{0} ھەپتىدىن كېيىن", "", "", "", "{0} ھەپتىدىن كېيىن"], past: ["", "{0} ھەپتە ئىلگىرى", "", "", "", "{0} ھەپتە ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _FIELD_1953: _RelativeField = _RelativeField { relatives: &[(-1, "ئۆتكەن ئاي"), (0, "بۇ ئاي"), (1, "كېلەر ئاي")], future: ["", "{0} ئايدىن كېيىن", "", "", "", "{0} ئايدىن كېيىن"], past: ["", "{0} ئاي ئىلگىرى", "", "", "", "{0} ئاي ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _FIELD_1954: _RelativeField = _RelativeField { relatives: &[(-1, "ئۆتكەن يىل"), (0, "بۇ يىل"), (1, "كېلەر يىل")], future: ["", "{0} يىلدىن كېيىن", "", "", "", "{0} يىلدىن كېيىن"], past: ["", "{0} يىل ئىلگىرى", "", "", "", "{0} يىل ئىلگىرى"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_155: [&_RelativeField; 24] = [
+    &_FIELD_1948,
+    &_FIELD_1948,
+    &_FIELD_1948,
+    &_FIELD_1949,
+    &_FIELD_1949,
+    &_FIELD_1949,
+    &_FIELD_1950,
+    &_FIELD_1950,
+    &_FIELD_1950,
+    &_FIELD_1951,
+    &_FIELD_1951,
+    &_FIELD_1951,
+    &_FIELD_1952,
+    &_FIELD_1952,
+    &_FIELD_1952,
+    &_FIELD_1953,
+    &_FIELD_1953,
+    &_FIELD_1953,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_1954,
+    &_FIELD_1954,
+    &_FIELD_1954
+];
+
+#[rustfmt::skip]
+static _FIELD_1955: _RelativeField = _RelativeField { relatives: &[(0, "зараз")], future: ["", "через {0} секунду", "", "через {0} секунди", "через {0} секунд", "через {0} секунди"], past: ["", "{0} секунду тому", "", "{0} секунди тому", "{0} секунд тому", "{0} секунди тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1956: _RelativeField = _RelativeField { relatives: &[(0, "зараз")], future: ["", "через {0} с", "", "через {0} с", "через {0} с", "через {0} с"], past: ["", "{0} с тому", "", "{0} с тому", "{0} с тому", "{0} с тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1957: _RelativeField = _RelativeField { relatives: &[(0, "зараз")], future: ["", "за {0} с", "", "за {0} с", "за {0} с", "за {0} с"], past: ["", "{0} с тому", "", "{0} с тому", "{0} с тому", "{0} с тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1958: _RelativeField = _RelativeField { relatives: &[(0, "цієї хвилини")], future: ["", "через {0} хвилину", "", "через {0} хвилини", "через {0} хвилин", "через {0} хвилини"], past: ["", "{0} хвилину тому", "", "{0} хвилини тому", "{0} хвилин тому", "{0} хвилини тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1959: _RelativeField = _RelativeField { relatives: &[(0, "цієї хвилини")], future: ["", "через {0} хв", "", "через {0} хв", "через {0} хв", "через {0} хв"], past: ["", "{0} хв тому", "", "{0} хв тому", "{0} хв тому", "{0} хв тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1960: _RelativeField = _RelativeField { relatives: &[(0, "цієї хвилини")], future: ["", "за {0} хв", "", "за {0} хв", "за {0} хв", "за {0} хв"], past: ["", "{0} хв тому", "", "{0} хв тому", "{0} хв тому", "{0} хв тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1961: _RelativeField = _RelativeField { relatives: &[(0, "цієї години")], future: ["", "через {0} годину", "", "через {0} години", "через {0} годин", "через {0} години"], past: ["", "{0} годину тому", "", "{0} години тому", "{0} годин тому", "{0} години тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1962: _RelativeField = _RelativeField { relatives: &[(0, "цієї години")], future: ["", "через {0} год", "", "через {0} год", "через {0} год", "через {0} год"], past: ["", "{0} год тому", "", "{0} год тому", "{0} год тому", "{0} год тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1963: _RelativeField = _RelativeField { relatives: &[(0, "цієї години")], future: ["", "за {0} год", "", "за {0} год", "за {0} год", "за {0} год"], past: ["", "{0} год тому", "", "{0} год тому", "{0} год тому", "{0} год тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1964: _RelativeField = _RelativeField { relatives: &[(-2, "позавчора"), (-1, "учора"), (0, "сьогодні"), (1, "завтра"), (2, "післязавтра")], future: ["", "через {0} день", "", "через {0} дні", "через {0} днів", "через {0} дня"], past: ["", "{0} день тому", "", "{0} дні тому", "{0} днів тому", "{0} дня тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1965: _RelativeField = _RelativeField { relatives: &[(-2, "позавчора"), (-1, "учора"), (0, "сьогодні"), (1, "завтра"), (2, "післязавтра")], future: ["", "через {0} дн.", "", "через {0} дн.", "через {0} дн.", "через {0} дн."], past: ["", "{0} дн. тому", "", "{0} дн. тому", "{0} дн. тому", "{0} дн. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1966: _RelativeField = _RelativeField { relatives: &[(-2, "позавчора"), (-1, "учора"), (0, "сьогодні"), (1, "завтра"), (2, "післязавтра")], future: ["", "за {0} дн.", "", "за {0} дн.", "за {0} дн.", "за {0} дн."], past: ["", "{0} дн. тому", "", "{0} дн. тому", "{0} дн. тому", "{0} дн. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1967: _RelativeField = _RelativeField { relatives: &[(-1, "минулого тижня"), (0, "цього тижня"), (1, "наступного тижня")], future: ["", "через {0} тиждень", "", "через {0} тижні", "через {0} тижнів", "через {0} тижня"], past: ["", "{0} тиждень тому", "", "{0} тижні тому", "{0} тижнів тому", "{0} тижня тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1968: _RelativeField = _RelativeField { relatives: &[(-1, "мин. тижня"), (0, "цього тижня"), (1, "наст. тижня")], future: ["", "через {0} тиж.", "", "через {0} тиж.", "через {0} тиж.", "через {0} тиж."], past: ["", "{0} тиж. тому", "", "{0} тиж. тому", "{0} тиж. тому", "{0} тиж. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1969: _RelativeField = _RelativeField { relatives: &[(-1, "минулого тижня"), (0, "цього тижня"), (1, "наступного тижня")], future: ["", "за {0} тиж.", "", "за {0} тиж.", "за {0} тиж.", "за {0} тиж."], past: ["", "{0} тиж. тому", "", "{0} тиж. тому", "{0} тиж. тому", "{0} тиж. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1970: _RelativeField = _RelativeField { relatives: &[(-1, "минулого місяця"), (0, "цього місяця"), (1, "наступного місяця")], future: ["", "через {0} місяць", "", "через {0} місяці", "через {0} місяців", "через {0} місяця"], past: ["", "{0} місяць тому", "", "{0} місяці тому", "{0} місяців тому", "{0} місяця тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1971: _RelativeField = _RelativeField { relatives: &[(-1, "минулого місяця"), (0, "цього місяця"), (1, "наступного місяця")], future: ["", "через {0} міс.", "", "через {0} міс.", "через {0} міс.", "через {0} міс."], past: ["", "{0} міс. тому", "", "{0} міс. тому", "{0} міс. тому", "{0} міс. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1972: _RelativeField = _RelativeField { relatives: &[(-1, "мин. міс."), (0, "цього міс."), (1, "наст. міс.")], future: ["", "за {0} міс.", "", "за {0} міс.", "за {0} міс.", "за {0} міс."], past: ["", "{0} міс. тому", "", "{0} міс. тому", "{0} міс. тому", "{0} міс. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1973: _RelativeField = _RelativeField { relatives: &[(-1, "минулого кварталу"), (0, "цього кварталу"), (1, "наступного кварталу")], future: ["", "через {0} квартал", "", "через {0} квартали", "через {0} кварталів", "через {0} кварталу"], past: ["", "{0} квартал тому", "", "{0} квартали тому", "{0} кварталів тому", "{0} кварталу тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1974: _RelativeField = _RelativeField { relatives: &[(-1, "минулого кв."), (0, "цього кв."), (1, "наступного кв.")], future: ["", "через {0} кв.", "", "через {0} кв.", "через {0} кв.", "через {0} кв."], past: ["", "{0} кв. тому", "", "{0} кв. тому", "{0} кв. тому", "{0} кв. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1975: _RelativeField = _RelativeField { relatives: &[(-1, "минулого кв."), (0, "цього кв."), (1, "наступного кв.")], future: ["", "за {0} кв.", "", "за {0} кв.", "за {0} кв.", "за {0} кв."], past: ["", "{0} кв. тому", "", "{0} кв. тому", "{0} кв. тому", "{0} кв. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1976: _RelativeField = _RelativeField { relatives: &[(-1, "минулого року"), (0, "цього року"), (1, "наступного року")], future: ["", "через {0} рік", "", "через {0} роки", "через {0} років", "через {0} року"], past: ["", "{0} рік тому", "", "{0} роки тому", "{0} років тому", "{0} року тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1977: _RelativeField = _RelativeField { relatives: &[(-1, "торік"), (0, "цьогоріч"), (1, "наст. року")], future: ["", "через {0} р.", "", "через {0} р.", "через {0} р.", "через {0} р."], past: ["", "{0} р. тому", "", "{0} р. тому", "{0} р. тому", "{0} р. тому"] };
+
+#[rustfmt::skip]
+static _FIELD_1978: _RelativeField = _RelativeField { relatives: &[(-1, "торік"), (0, "цього року"), (1, "наст. р.")], future: ["", "за {0} р.", "", "за {0} р.", "за {0} р.", "за {0} р."], past: ["", "{0} р. тому", "", "{0} р. тому", "{0} р. тому", "{0} р. тому"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_156: [&_RelativeField; 24] = [
+    &_FIELD_1955,
+    &_FIELD_1956,
+    &_FIELD_1957,
+    &_FIELD_1958,
+    &_FIELD_1959,
+    &_FIELD_1960,
+    &_FIELD_1961,
+    &_FIELD_1962,
+    &_FIELD_1963,
+    &_FIELD_1964,
+    &_FIELD_1965,
+    &_FIELD_1966,
+    &_FIELD_1967,
+    &_FIELD_1968,
+    &_FIELD_1969,
+    &_FIELD_1970,
+    &_FIELD_1971,
+    &_FIELD_1972,
+    &_FIELD_1973,
+    &_FIELD_1974,
+    &_FIELD_1975,
+    &_FIELD_1976,
+    &_FIELD_1977,
+    &_FIELD_1978
+];
+
+#[rustfmt::skip]
+static _FIELD_1979: _RelativeField = _RelativeField { relatives: &[(0, "اب")], future: ["", "{0} سیکنڈ میں", "", "", "", "{0} سیکنڈ میں"], past: ["", "{0} سیکنڈ پہلے", "", "", "", "{0} سیکنڈ پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1980: _RelativeField = _RelativeField { relatives: &[(0, "اس منٹ")], future: ["", "{0} منٹ میں", "", "", "", "{0} منٹ میں"], past: ["", "{0} منٹ پہلے", "", "", "", "{0} منٹ پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1981: _RelativeField = _RelativeField { relatives: &[(0, "اس گھنٹے")], future: ["", "{0} گھنٹے میں", "", "", "", "{0} گھنٹے میں"], past: ["", "{0} گھنٹہ پہلے", "", "", "", "{0} گھنٹے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1982: _RelativeField = _RelativeField { relatives: &[(0, "اس گھنٹے")], future: ["", "{0} گھنٹے میں", "", "", "", "{0} گھنٹے میں"], past: ["", "{0} گھنٹے پہلے", "", "", "", "{0} گھنٹے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1983: _RelativeField = _RelativeField { relatives: &[(0, "اس گھنٹے")], future: ["", "{0} گھنٹے میں", "", "", "", "{0} گھنٹوں میں"], past: ["", "{0} گھنٹہ پہلے", "", "", "", "{0} گھنٹے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1984: _RelativeField = _RelativeField { relatives: &[(-2, "گزشتہ پرسوں"), (-1, "گزشتہ کل"), (0, "آج"), (1, "آئندہ کل"), (2, "آنے والا پرسوں")], future: ["", "{0} دن میں", "", "", "", "{0} دنوں میں"], past: ["", "{0} دن پہلے", "", "", "", "{0} دنوں پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1985: _RelativeField = _RelativeField { relatives: &[(-2, "گزشتہ پرسوں"), (-1, "گزشتہ کل"), (0, "آج"), (1, "آئندہ کل"), (2, "آنے والا پرسوں")], future: ["", "{0} دن میں", "", "", "", "{0} دنوں میں"], past: ["", "{0} دن پہلے", "", "", "", "{0} دن پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1986: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ ہفتے"), (0, "اس ہفتہ"), (1, "اگلے ہفتے")], future: ["", "{0} ہفتہ میں", "", "", "", "{0} ہفتے میں"], past: ["", "{0} ہفتہ پہلے", "", "", "", "{0} ہفتے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1987: _RelativeField = _RelativeField { relatives: &[(-1, "پچھلے ہفتہ"), (0, "اس ہفتہ"), (1, "اگلے ہفتہ")], future: ["", "{0} ہفتے میں", "", "", "", "{0} ہفتے میں"], past: ["", "{0} ہفتے پہلے", "", "", "", "{0} ہفتے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1988: _RelativeField = _RelativeField { relatives: &[(-1, "پچھلے ہفتہ"), (0, "اس ہفتہ"), (1, "اگلے ہفتہ")], future: ["", "{0} ہفتہ میں", "", "", "", "{0} ہفتے میں"], past: ["", "{0} ہفتہ پہلے", "", "", "", "{0} ہفتے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1989: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ ماہ"), (0, "اس ماہ"), (1, "اگلا مہینہ")], future: ["", "{0} مہینہ میں", "", "", "", "{0} مہینے میں"], past: ["", "{0} مہینہ پہلے", "", "", "", "{0} مہینے پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1990: _RelativeField = _RelativeField { relatives: &[(-1, "پچھلے مہینہ"), (0, "اس مہینہ"), (1, "اگلے مہینہ")], future: ["", "{0} ماہ میں", "", "", "", "{0} ماہ میں"], past: ["", "{0} ماہ قبل", "", "", "", "{0} ماہ قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_1991: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ ماہ"), (0, "اس ماہ"), (1, "اگلے ماہ")], future: ["", "{0} ماہ میں", "", "", "", "{0} ماہ میں"], past: ["", "{0} ماہ پہلے", "", "", "", "{0} ماہ پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1992: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ سہ ماہی"), (0, "اس سہ ماہی"), (1, "اگلے سہ ماہی")], future: ["", "{0} سہ ماہی میں", "", "", "", "{0} سہ ماہی میں"], past: ["", "{0} سہ ماہی پہلے", "", "", "", "{0} سہ ماہی پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_1993: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ سہ ماہی"), (0, "اس سہ ماہی"), (1, "اگلے سہ ماہی")], future: ["", "{0} سہ ماہی میں", "", "", "", "{0} سہ ماہی میں"], past: ["", "{0} سہ ماہی قبل", "", "", "", "{0} سہ ماہی قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_1994: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ سال"), (0, "اس سال"), (1, "اگلے سال")], future: ["", "{0} سال میں", "", "", "", "{0} سال میں"], past: ["", "{0} سال پہلے", "", "", "", "{0} سال پہلے"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_157: [&_RelativeField; 24] = [
+    &_FIELD_1979,
+    &_FIELD_1979,
+    &_FIELD_1979,
+    &_FIELD_1980,
+    &_FIELD_1980,
+    &_FIELD_1980,
+    &_FIELD_1981,
+    &_FIELD_1982,
+    &_FIELD_1983,
+    &_FIELD_1984,
+    &_FIELD_1985,
+    &_FIELD_1985,
+    &_FIELD_1986,
+    &_FIELD_1987,
+    &_FIELD_1988,
+    &_FIELD_1989,
+    &_FIELD_1990,
+    &_FIELD_1991,
+    &_FIELD_1992,
+    &_FIELD_1993,
+    &_FIELD_1992,
+    &_FIELD_1994,
+    &_FIELD_1994,
+    &_FIELD_1994
+];
+
+#[rustfmt::skip]
+static _FIELD_1995: _RelativeField = _RelativeField { relatives: &[(0, "اب")], future: ["", "{0} سیکنڈ میں", "", "", "", "{0} سیکنڈ میں"], past: ["", "{0} سیکنڈ قبل", "", "", "", "{0} سیکنڈ قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_1996: _RelativeField = _RelativeField { relatives: &[(0, "اس منٹ")], future: ["", "{0} منٹ میں", "", "", "", "{0} منٹ میں"], past: ["", "{0} منٹ قبل", "", "", "", "{0} منٹ قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_1997: _RelativeField = _RelativeField { relatives: &[(0, "اس گھنٹے")], future: ["", "{0} گھنٹے میں", "", "", "", "{0} گھنٹے میں"], past: ["", "{0} گھنٹے قبل", "", "", "", "{0} گھنٹے قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_1998: _RelativeField = _RelativeField { relatives: &[(0, "اس گھنٹے")], future: ["", "{0} گھنٹے میں", "", "", "", "{0} گھنٹوں میں"], past: ["", "{0} گھنٹہ قبل", "", "", "", "{0} گھنٹے قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_1999: _RelativeField = _RelativeField { relatives: &[(-2, "گزشتہ پرسوں"), (-1, "گزشتہ کل"), (0, "آج"), (1, "آئندہ کل"), (2, "آنے والا پرسوں")], future: ["", "{0} دنوں میں", "", "", "", "{0} دنوں میں"], past: ["", "{0} دن پہلے", "", "", "", "{0} دن پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_2000: _RelativeField = _RelativeField { relatives: &[(-2, "گزشتہ پرسوں"), (-1, "گزشتہ کل"), (0, "آج"), (1, "آئندہ کل"), (2, "آنے والا پرسوں")], future: ["", "{0} دنوں میں", "", "", "", "{0} دنوں میں"], past: ["", "{0} دن قبل", "", "", "", "{0} دن قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_2001: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ ہفتہ"), (0, "اس ہفتہ"), (1, "اگلے ہفتہ")], future: ["", "{0} ہفتہ میں", "", "", "", "{0} ہفتوں میں"], past: ["", "{0} ہفتہ قبل", "", "", "", "{0} ہفتے قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_2002: _RelativeField = _RelativeField { relatives: &[(-1, "پچھلے ہفتہ"), (0, "اس ہفتہ"), (1, "اگلے ہفتہ")], future: ["", "{0} ہفتے میں", "", "", "", "{0} ہفتے میں"], past: ["", "{0} ہفتے قبل", "", "", "", "{0} ہفتے قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_2003: _RelativeField = _RelativeField { relatives: &[(-1, "پچھلے ہفتہ"), (0, "اس ہفتہ"), (1, "اگلے ہفتہ")], future: ["", "{0} ہفتہ میں", "", "", "", "{0} ہفتے میں"], past: ["", "{0} ہفتہ قبل", "", "", "", "{0} ہفتے قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_2004: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ ماہ"), (0, "اس ماہ"), (1, "اگلے ماہ")], future: ["", "{0} ماہ میں", "", "", "", "{0} ماہ میں"], past: ["", "{0} ماہ قبل", "", "", "", "{0} ماہ قبل"] };
+
+#[rustfmt::skip]
+static _FIELD_2005: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ سال"), (0, "اس سال"), (1, "اگلے سال")], future: ["", "{0} سال میں", "", "", "", "{0} سالوں میں"], past: ["", "{0} سال پہلے", "", "", "", "{0} سال پہلے"] };
+
+#[rustfmt::skip]
+static _FIELD_2006: _RelativeField = _RelativeField { relatives: &[(-1, "گزشتہ سال"), (0, "اس سال"), (1, "اگلے سال")], future: ["", "{0} سال میں", "", "", "", "{0} سالوں میں"], past: ["", "{0} سال پہلے", "", "", "", "{0} سالوں پہلے"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_158: [&_RelativeField; 24] = [
+    &_FIELD_1995,
+    &_FIELD_1995,
+    &_FIELD_1995,
+    &_FIELD_1996,
+    &_FIELD_1996,
+    &_FIELD_1996,
+    &_FIELD_1981,
+    &_FIELD_1997,
+    &_FIELD_1998,
+    &_FIELD_1984,
+    &_FIELD_1999,
+    &_FIELD_2000,
+    &_FIELD_2001,
+    &_FIELD_2002,
+    &_FIELD_2003,
+    &_FIELD_2004,
+    &_FIELD_1990,
+    &_FIELD_2004,
+    &_FIELD_1992,
+    &_FIELD_1993,
+    &_FIELD_1992,
+    &_FIELD_2005,
+    &_FIELD_2006,
+    &_FIELD_2006
+];
+
+#[rustfmt::skip]
+static _FIELD_2007: _RelativeField = _RelativeField { relatives: &[(0, "hozir")], future: ["", "{0} soniyadan keyin", "", "", "", "{0} soniyadan keyin"], past: ["", "{0} soniya oldin", "", "", "", "{0} soniya oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2008: _RelativeField = _RelativeField { relatives: &[(0, "shu daqiqada")], future: ["", "{0} daqiqadan keyin", "", "", "", "{0} daqiqadan keyin"], past: ["", "{0} daqiqa oldin", "", "", "", "{0} daqiqa oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2009: _RelativeField = _RelativeField { relatives: &[(0, "shu soatda")], future: ["", "{0} soatdan keyin", "", "", "", "{0} soatdan keyin"], past: ["", "{0} soat oldin", "", "", "", "{0} soat oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2010: _RelativeField = _RelativeField { relatives: &[(-1, "kecha"), (0, "bugun"), (1, "ertaga")], future: ["", "{0} kundan keyin", "", "", "", "{0} kundan keyin"], past: ["", "{0} kun oldin", "", "", "", "{0} kun oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2011: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan hafta"), (0, "shu hafta"), (1, "keyingi hafta")], future: ["", "{0} haftadan keyin", "", "", "", "{0} haftadan keyin"], past: ["", "{0} hafta oldin", "", "", "", "{0} hafta oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2012: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan hafta"), (0, "bu hafta"), (1, "keyingi hafta")], future: ["", "{0} haftadan keyin", "", "", "", "{0} haftadan keyin"], past: ["", "{0} hafta oldin", "", "", "", "{0} hafta oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2013: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan oy"), (0, "shu oy"), (1, "keyingi oy")], future: ["", "{0} oydan keyin", "", "", "", "{0} oydan keyin"], past: ["", "{0} oy oldin", "", "", "", "{0} oy oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2014: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan oy"), (0, "bu oy"), (1, "keyingi oy")], future: ["", "{0} oydan keyin", "", "", "", "{0} oydan keyin"], past: ["", "{0} oy oldin", "", "", "", "{0} oy oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2015: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan chorak"), (0, "shu chorak"), (1, "keyingi chorak")], future: ["", "{0} chorakdan keyin", "", "", "", "{0} chorakdan keyin"], past: ["", "{0} chorak oldin", "", "", "", "{0} chorak oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2016: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan yil"), (0, "shu yil"), (1, "keyingi yil")], future: ["", "{0} yildan keyin", "", "", "", "{0} yildan keyin"], past: ["", "{0} yil oldin", "", "", "", "{0} yil oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2017: _RelativeField = _RelativeField { relatives: &[(-1, "o‘ʻtgan yil"), (0, "bu yil"), (1, "keyingi yil")], future: ["", "{0} yildan keyin", "", "", "", "{0} yildan keyin"], past: ["", "{0} yil oldin", "", "", "", "{0} yil oldin"] };
+
+#[rustfmt::skip]
+static _FIELD_2018: _RelativeField = _RelativeField { relatives: &[(-1, "o‘tgan yil"), (0, "bu yil"), (1, "keyingi yil")], future: ["", "{0} yildan keyin", "", "", "", "{0} yildan keyin"], past: ["", "{0} yil oldin", "", "", "", "{0} yil oldin"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_159: [&_RelativeField; 24] = [
+    &_FIELD_2007,
+    &_FIELD_2007,
+    &_FIELD_2007,
+    &_FIELD_2008,
+    &_FIELD_2008,
+    &_FIELD_2008,
+    &_FIELD_2009,
+    &_FIELD_2009,
+    &_FIELD_2009,
+    &_FIELD_2010,
+    &_FIELD_2010,
+    &_FIELD_2010,
+    &_FIELD_2011,
+    &_FIELD_2012,
+    &_FIELD_2012,
+    &_FIELD_2013,
+    &_FIELD_2014,
+    &_FIELD_2014,
+    &_FIELD_2015,
+    &_FIELD_2015,
+    &_FIELD_2015,
+    &_FIELD_2016,
+    &_FIELD_2017,
+    &_FIELD_2018
+];
+
+#[rustfmt::skip]
+static _FIELD_2019: _RelativeField = _RelativeField { relatives: &[(0, "ҳозир")], future: ["", "{0} сониядан сўнг", "", "", "", "{0} сониядан сўнг"], past: ["", "{0} сония олдин", "", "", "", "{0} сония олдин"] };
+
+#[rustfmt::skip]
+static _FIELD_2020: _RelativeField = _RelativeField { relatives: &[(0, "this minute")], future: ["", "{0} дақиқадан сўнг", "", "", "", "{0} дақиқадан сўнг"], past: ["", "{0} дақиқа олдин", "", "", "", "{0} дақиқа олдин"] };
+
+#[rustfmt::skip]
+static _FIELD_2021: _RelativeField = _RelativeField { relatives: &[(0, "this hour")], future: ["", "{0} соатдан сўнг", "", "", "", "{0} соатдан сўнг"], past: ["", "{0} соат олдин", "", "", "", "{0} соат олдин"] };
+
+#[rustfmt::skip]
+static _FIELD_2022: _RelativeField = _RelativeField { relatives: &[(-1, "кеча"), (0, "бугун"), (1, "эртага")], future: ["", "{0} кундан сўнг", "", "", "", "{0} кундан сўнг"], past: ["", "{0} кун олдин", "", "", "", "{0} кун олдин"] };
+
+#[rustfmt::skip]
+static _FIELD_2023: _RelativeField = _RelativeField { relatives: &[(-1, "ўтган ҳафта"), (0, "бу ҳафта"), (1, "кейинги ҳафта")], future: ["", "{0} ҳафтадан сўнг", "", "", "", "{0} ҳафтадан сўнг"], past: ["", "{0} ҳафта олдин", "", "", "", "{0} ҳафта олдин"] };
+
+#[rustfmt::skip]
+static _FIELD_2024: _RelativeField = _RelativeField { relatives: &[(-1, "ўтган ой"), (0, "бу ой"), (1, "кейинги ой")], future: ["", "{0} ойдан сўнг", "", "", "", "{0} ойдан сўнг"], past: ["", "{0} ой аввал", "", "", "", "{0} ой аввал"] };
+
+#[rustfmt::skip]
+static _FIELD_2025: _RelativeField = _RelativeField { relatives: &[(-1, "ўтган йил"), (0, "бу йил"), (1, "кейинги йил")], future: ["", "{0} йилдан сўнг", "", "", "", "{0} йилдан сўнг"], past: ["", "{0} йил аввал", "", "", "", "{0} йил аввал"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_160: [&_RelativeField; 24] = [
+    &_FIELD_2019,
+    &_FIELD_2019,
+    &_FIELD_2019,
+    &_FIELD_2020,
+    &_FIELD_2020,
+    &_FIELD_2020,
+    &_FIELD_2021,
+    &_FIELD_2021,
+    &_FIELD_2021,
+    &_FIELD_2022,
+    &_FIELD_2022,
+    &_FIELD_2022,
+    &_FIELD_2023,
+    &_FIELD_2023,
+    &_FIELD_2023,
+    &_FIELD_2024,
+    &_FIELD_2024,
+    &_FIELD_2024,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_2025,
+    &_FIELD_2025,
+    &_FIELD_2025
+];
+
+#[rustfmt::skip]
+static _FIELD_2026: _RelativeField = _RelativeField { relatives: &[(0, "deso")], future: ["", "tenpo {0} segondo", "", "", "", "tenpo {0} segondi"], past: ["", "che’l ze {0} segondo", "", "", "", "che i ze {0} segondi"] };
+
+#[rustfmt::skip]
+static _FIELD_2027: _RelativeField = _RelativeField { relatives: &[(0, "deso")], future: ["", "tenpo {0} seg", "", "", "", "tenpo {0} seg"], past: ["", "che’l ze {0} seg", "", "", "", "che i ze {0} seg"] };
+
+#[rustfmt::skip]
+static _FIELD_2028: _RelativeField = _RelativeField { relatives: &[(0, "deso")], future: ["", "tenpo {0} s", "", "", "", "tenpo {0} s"], past: ["", "che’l ze {0} s", "", "", "", "che i ze {0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_2029: _RelativeField = _RelativeField { relatives: &[(0, "‘sto menuto")], future: ["", "tenpo {0} menuto", "", "", "", "tenpo {0} menuti"], past: ["", "che’l ze {0} menuto", "", "", "", "che i ze {0} menuti"] };
+
+#[rustfmt::skip]
+static _FIELD_2030: _RelativeField = _RelativeField { relatives: &[(0, "‘sto men")], future: ["", "tenpo {0} men", "", "", "", "tenpo {0} men"], past: ["", "che’l ze {0} men", "", "", "", "che i ze {0} men"] };
+
+#[rustfmt::skip]
+static _FIELD_2031: _RelativeField = _RelativeField { relatives: &[(0, "‘sto m")], future: ["", "tenpo {0} m", "", "", "", "tenpo {0} m"], past: ["", "che’l ze {0} m", "", "", "", "che i ze {0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_2032: _RelativeField = _RelativeField { relatives: &[(0, "‘sta ora")], future: ["", "tenpo {0} ora", "", "", "", "tenpo {0} ore"], past: ["", "che la ze {0} ora", "", "", "", "che le ze {0} ore"] };
+
+#[rustfmt::skip]
+static _FIELD_2033: _RelativeField = _RelativeField { relatives: &[(0, "‘sta o")], future: ["", "tenpo {0} o", "", "", "", "tenpo {0} o"], past: ["", "che la ze {0} o", "", "", "", "che le ze {0} o"] };
+
+#[rustfmt::skip]
+static _FIELD_2034: _RelativeField = _RelativeField { relatives: &[(-1, "jeri"), (0, "uncó"), (1, "doman")], future: ["", "tenpo {0} dì", "", "", "", "tenpo {0} dì"], past: ["", "che’l ze {0} dì", "", "", "", "che i ze {0} dì"] };
+
+#[rustfmt::skip]
+static _FIELD_2035: _RelativeField = _RelativeField { relatives: &[(-1, "la setemana pasada"), (0, "‘sta setemana"), (1, "la setemana che vien")], future: ["", "tenpo {0} setemana", "", "", "", "tenpo {0} setemane"], past: ["", "che la ze {0} setemana", "", "", "", "che le ze {0} setemane"] };
+
+#[rustfmt::skip]
+static _FIELD_2036: _RelativeField = _RelativeField { relatives: &[(-1, "la set pasada"), (0, "‘sta set"), (1, "la set che vien")], future: ["", "tenpo {0} set", "", "", "", "tenpo {0} set"], past: ["", "che la ze {0} set", "", "", "", "che le ze {0} set"] };
+
+#[rustfmt::skip]
+static _FIELD_2037: _RelativeField = _RelativeField { relatives: &[(-1, "set pasada"), (0, "‘sta set"), (1, "set che vien")], future: ["", "tenpo {0} set", "", "", "", "tenpo {0} set"], past: ["", "che la ze {0} set", "", "", "", "che le ze {0} set"] };
+
+#[rustfmt::skip]
+static _FIELD_2038: _RelativeField = _RelativeField { relatives: &[(-1, "el meze pasà"), (0, "‘sto meze"), (1, "el meze che vien")], future: ["", "tenpo {0} meze", "", "", "", "tenpo {0} mezi"], past: ["", "che’l ze {0} meze", "", "", "", "che i ze {0} mezi"] };
+
+#[rustfmt::skip]
+static _FIELD_2039: _RelativeField = _RelativeField { relatives: &[(-1, "meze pasà"), (0, "‘sto meze"), (1, "meze che vien")], future: ["", "tenpo {0} meze", "", "", "", "tenpo {0} mezi"], past: ["", "che’l ze {0} meze", "", "", "", "che i ze {0} mezi"] };
+
+#[rustfmt::skip]
+static _FIELD_2040: _RelativeField = _RelativeField { relatives: &[(-1, "el trimestre pasà"), (0, "‘sto trimestre"), (1, "el trimestre che vien")], future: ["", "tenpo {0} trimestre", "", "", "", "tenpo {0} trimestri"], past: ["", "che’l ze {0} trimestre", "", "", "", "che i ze {0} trimestri"] };
+
+#[rustfmt::skip]
+static _FIELD_2041: _RelativeField = _RelativeField { relatives: &[(-1, "el trim pasà"), (0, "‘sto trim"), (1, "el trim che vien")], future: ["", "tenpo {0} trim", "", "", "", "tenpo {0} trim"], past: ["", "che’l ze {0} trim", "", "", "", "che i ze {0} trim"] };
+
+#[rustfmt::skip]
+static _FIELD_2042: _RelativeField = _RelativeField { relatives: &[(-1, "trim pasà"), (0, "‘sto trim"), (1, "trim che vien")], future: ["", "tenpo {0} trim", "", "", "", "tenpo {0} trim"], past: ["", "che’l ze {0} trim", "", "", "", "che i ze {0} trim"] };
+
+#[rustfmt::skip]
+static _FIELD_2043: _RelativeField = _RelativeField { relatives: &[(-1, "l’ano pasà"), (0, "‘st’ano"), (1, "l’ano che vien")], future: ["", "tenpo {0} ano", "", "", "", "tenpo {0} ani"], past: ["", "che’l ze {0} ano", "", "", "", "che i ze {0} ani"] };
+
+#[rustfmt::skip]
+static _FIELD_2044: _RelativeField = _RelativeField { relatives: &[(-1, "ano pasà"), (0, "‘st’ano"), (1, "ano che vien")], future: ["", "tenpo {0} ano", "", "", "", "tenpo {0} ani"], past: ["", "che’l ze {0} ano", "", "", "", "che i ze {0} ani"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_161: [&_RelativeField; 24] = [
+    &_FIELD_2026,
+    &_FIELD_2027,
+    &_FIELD_2028,
+    &_FIELD_2029,
+    &_FIELD_2030,
+    &_FIELD_2031,
+    &_FIELD_2032,
+    &_FIELD_2032,
+    &_FIELD_2033,
+    &_FIELD_2034,
+    &_FIELD_2034,
+    &_FIELD_2034,
+    &_FIELD_2035,
+    &_FIELD_2036,
+    &_FIELD_2037,
+    &_FIELD_2038,
+    &_FIELD_2038,
+    &_FIELD_2039,
+    &_FIELD_2040,
+    &_FIELD_2041,
+    &_FIELD_2042,
+    &_FIELD_2043,
+    &_FIELD_2043,
+    &_FIELD_2044
+];
+
+#[rustfmt::skip]
+static _FIELD_2045: _RelativeField = _RelativeField { relatives: &[(0, "bây giờ")], future: ["", "", "", "", "", "sau {0} giây nữa"], past: ["", "", "", "", "", "{0} giây trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2046: _RelativeField = _RelativeField { relatives: &[(0, "phút này")], future: ["", "", "", "", "", "sau {0} phút nữa"], past: ["", "", "", "", "", "{0} phút trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2047: _RelativeField = _RelativeField { relatives: &[(0, "giờ này")], future: ["", "", "", "", "", "sau {0} giờ nữa"], past: ["", "", "", "", "", "{0} giờ trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2048: _RelativeField = _RelativeField { relatives: &[(-2, "Hôm kia"), (-1, "Hôm qua"), (0, "Hôm nay"), (1, "Ngày mai"), (2, "Ngày kia")], future: ["", "", "", "", "", "sau {0} ngày nữa"], past: ["", "", "", "", "", "{0} ngày trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2049: _RelativeField = _RelativeField { relatives: &[(-2, "Hôm kia"), (-1, "hôm qua"), (0, "hôm nay"), (1, "ngày mai"), (2, "Ngày kia")], future: ["", "", "", "", "", "sau {0} ngày nữa"], past: ["", "", "", "", "", "{0} ngày trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2050: _RelativeField = _RelativeField { relatives: &[(-1, "tuần trước"), (0, "tuần này"), (1, "tuần sau")], future: ["", "", "", "", "", "sau {0} tuần nữa"], past: ["", "", "", "", "", "{0} tuần trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2051: _RelativeField = _RelativeField { relatives: &[(-1, "tháng trước"), (0, "tháng này"), (1, "tháng sau")], future: ["", "", "", "", "", "sau {0} tháng nữa"], past: ["", "", "", "", "", "{0} tháng trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2052: _RelativeField = _RelativeField { relatives: &[(-1, "quý trước"), (0, "quý này"), (1, "quý sau")], future: ["", "", "", "", "", "sau {0} quý nữa"], past: ["", "", "", "", "", "{0} quý trước"] };
+
+#[rustfmt::skip]
+static _FIELD_2053: _RelativeField = _RelativeField { relatives: &[(-1, "năm ngoái"), (0, "năm nay"), (1, "năm sau")], future: ["", "", "", "", "", "sau {0} năm nữa"], past: ["", "", "", "", "", "{0} năm trước"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_162: [&_RelativeField; 24] = [
+    &_FIELD_2045,
+    &_FIELD_2045,
+    &_FIELD_2045,
+    &_FIELD_2046,
+    &_FIELD_2046,
+    &_FIELD_2046,
+    &_FIELD_2047,
+    &_FIELD_2047,
+    &_FIELD_2047,
+    &_FIELD_2048,
+    &_FIELD_2049,
+    &_FIELD_2049,
+    &_FIELD_2050,
+    &_FIELD_2050,
+    &_FIELD_2050,
+    &_FIELD_2051,
+    &_FIELD_2051,
+    &_FIELD_2051,
+    &_FIELD_2052,
+    &_FIELD_2052,
+    &_FIELD_2052,
+    &_FIELD_2053,
+    &_FIELD_2053,
+    &_FIELD_2053
+];
+
+#[rustfmt::skip]
+static _FIELD_2054: _RelativeField = _RelativeField { relatives: &[(0, "leegi")], future: ["", "", "", "", "", "fileek {0} saa"], past: ["", "", "", "", "", "{0} saa ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2055: _RelativeField = _RelativeField { relatives: &[(0, "ci simili bii")], future: ["", "", "", "", "", "fileek {0} simili"], past: ["", "", "", "", "", "{0} simili ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2056: _RelativeField = _RelativeField { relatives: &[(0, "ci simili bii")], future: ["", "", "", "", "", "fileek {0} sim."], past: ["", "", "", "", "", "{0} sim. ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2057: _RelativeField = _RelativeField { relatives: &[(0, "ci waxtu wii")], future: ["", "", "", "", "", "fileek {0} waxtu"], past: ["", "", "", "", "", "{0} waxtu ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2058: _RelativeField = _RelativeField { relatives: &[(0, "ci waxtu wii")], future: ["", "", "", "", "", "fileek {0} wax."], past: ["", "", "", "", "", "{0} wax. ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2059: _RelativeField = _RelativeField { relatives: &[(-1, "démb"), (0, "tay"), (1, "suba")], future: ["", "", "", "", "", "fileek {0} fan"], past: ["", "", "", "", "", "{0} fan ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2060: _RelativeField = _RelativeField { relatives: &[(-1, "ayu-bis bi weesu"), (0, "ayu-bis bii"), (1, "ayu-bis biy ñëw")], future: ["", "", "", "", "", "fileek {0} ayi-bis"], past: ["", "", "", "", "", "{0} ayi-bis ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2061: _RelativeField = _RelativeField { relatives: &[(-1, "ayu-b bi wees"), (0, "ayu-b bii"), (1, "ayu-b. ñëw")], future: ["", "", "", "", "", "fileek {0} ayi-b."], past: ["", "", "", "", "", "{0} ayi-b. ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2062: _RelativeField = _RelativeField { relatives: &[(-1, "weer wi weesu"), (0, "weer wii"), (1, "weer wiy ñëw")], future: ["", "", "", "", "", "fileek {0} weer"], past: ["", "", "", "", "", "{0} weer ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2063: _RelativeField = _RelativeField { relatives: &[(-1, "we. wi wees"), (0, "we. wii"), (1, "we. wiy ñëw")], future: ["", "", "", "", "", "fileek {0} we."], past: ["", "", "", "", "", "{0} we. ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2064: _RelativeField = _RelativeField { relatives: &[(-1, "trimestre bi weesu"), (0, "trimestre bii"), (1, "trimestre biy ñëw")], future: ["", "", "", "", "", "fileek {0} trimestre"], past: ["", "", "", "", "", "{0} trimestre ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2065: _RelativeField = _RelativeField { relatives: &[(-1, "trimestre bi weesu"), (0, "trimestre bii"), (1, "trimestre biy ñëw")], future: ["", "", "", "", "", "fileek {0} trim."], past: ["", "", "", "", "", "{0} trim. ci ginaaw"] };
+
+#[rustfmt::skip]
+static _FIELD_2066: _RelativeField = _RelativeField { relatives: &[(-1, "daaw"), (0, "ren"), (1, "dewen")], future: ["", "", "", "", "", "fileek {0} at"], past: ["", "", "", "", "", "{0} at ci ginaaw"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_163: [&_RelativeField; 24] = [
+    &_FIELD_2054,
+    &_FIELD_2054,
+    &_FIELD_2054,
+    &_FIELD_2055,
+    &_FIELD_2056,
+    &_FIELD_2056,
+    &_FIELD_2057,
+    &_FIELD_2058,
+    &_FIELD_2058,
+    &_FIELD_2059,
+    &_FIELD_2059,
+    &_FIELD_2059,
+    &_FIELD_2060,
+    &_FIELD_2061,
+    &_FIELD_2061,
+    &_FIELD_2062,
+    &_FIELD_2063,
+    &_FIELD_2063,
+    &_FIELD_2064,
+    &_FIELD_2065,
+    &_FIELD_2065,
+    &_FIELD_2066,
+    &_FIELD_2066,
+    &_FIELD_2066
+];
+
+#[rustfmt::skip]
+static _FIELD_2067: _RelativeField = _RelativeField { relatives: &[(-1, "izolo"), (0, "namhlanje"), (1, "ngomso")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_2068: _RelativeField = _RelativeField { relatives: &[(-1, "kwiveki ephelileyo"), (0, "ku;e veki"), (1, "kwiveki ezayo")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_2069: _RelativeField = _RelativeField { relatives: &[(-1, "vek ephel."), (0, "kule vek."), (1, "vek eza.")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_2070: _RelativeField = _RelativeField { relatives: &[(-1, "veki ephel."), (0, "kule vek."), (1, "veki eza.")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_2071: _RelativeField = _RelativeField { relatives: &[(-1, "inyanga ephelileyo"), (0, "kule nyanga"), (1, "kwinyanga ezayo")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_2072: _RelativeField = _RelativeField { relatives: &[(-1, "inyanga ephel."), (0, "kule nya."), (1, "kwinyanga eza.")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_2073: _RelativeField = _RelativeField { relatives: &[(-1, "inya ephel."), (0, "kule nya."), (1, "kwinya eza.")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_2074: _RelativeField = _RelativeField { relatives: &[(-1, "unyaka ophelileyo"), (0, "kulo nyaka"), (1, "kunyaka ozayo")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_2075: _RelativeField = _RelativeField { relatives: &[(-1, "unyaka ophel."), (0, "kulo nyak."), (1, "kunyak oza.")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _FIELD_2076: _RelativeField = _RelativeField { relatives: &[(-1, "unyaka ophel."), (0, "kulo nyak."), (1, "unyak oza.")], future: ["", "", "", "", "", "+{0} y"], past: ["", "", "", "", "", "-{0} y"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_164: [&_RelativeField; 24] = [
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_0,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_1,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2,
+    &_FIELD_2067,
+    &_FIELD_2067,
+    &_FIELD_2067,
+    &_FIELD_2068,
+    &_FIELD_2069,
+    &_FIELD_2070,
+    &_FIELD_2071,
+    &_FIELD_2072,
+    &_FIELD_2073,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_2074,
+    &_FIELD_2075,
+    &_FIELD_2076
+];
+
+#[rustfmt::skip]
+static _FIELD_2077: _RelativeField = _RelativeField { relatives: &[(0, "ह\u{941}ण")], future: ["", "", "", "", "", "{0} सकि\u{902}ट\u{947} च"], past: ["", "", "", "", "", "{0} सकि\u{902}ट प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2078: _RelativeField = _RelativeField { relatives: &[(0, "ह\u{941}ण")], future: ["", "", "", "", "", "{0} स॰ च"], past: ["", "", "", "", "", "{0} स॰ प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2079: _RelativeField = _RelativeField { relatives: &[(0, "एह मि\u{902}ट")], future: ["", "", "", "", "", "{0} मि\u{902}ट\u{947} च"], past: ["", "", "", "", "", "{0} मि\u{902}ट प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2080: _RelativeField = _RelativeField { relatives: &[(0, "एह मि\u{902}ट")], future: ["", "", "", "", "", "{0} मि॰ च"], past: ["", "", "", "", "", "{0} मि॰ प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2081: _RelativeField = _RelativeField { relatives: &[(0, "एह घ\u{902}टा")], future: ["", "", "", "", "", "{0} घ\u{902}ट\u{947} च"], past: ["", "", "", "", "", "{0} घ\u{902}ट\u{947} प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2082: _RelativeField = _RelativeField { relatives: &[(0, "एह घ\u{902}टा")], future: ["", "", "", "", "", "{0} घ\u{902}॰ च"], past: ["", "", "", "", "", "{0} घ\u{902}॰ प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2083: _RelativeField = _RelativeField { relatives: &[(-1, "पिछला कल"), (0, "अज\u{94d}ज"), (1, "अगल\u{947} कल")], future: ["", "", "", "", "", "{0} ध\u{94d}याड\u{93c}\u{947}या\u{902} च"], past: ["", "", "", "", "", "{0} ध\u{94d}याड\u{93c}\u{947} प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2084: _RelativeField = _RelativeField { relatives: &[(-1, "पिछल\u{947} हफ\u{94d}त\u{947}"), (0, "एह हफ\u{94d}ता"), (1, "अगल\u{947} हफ\u{94d}त\u{947}")], future: ["", "", "", "", "", "{0} हफ\u{94d}त\u{947}या\u{902} च"], past: ["", "", "", "", "", "{0} हफ\u{94d}त\u{947}या\u{902} प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2085: _RelativeField = _RelativeField { relatives: &[(-1, "पिछल\u{947} मीन\u{94d}न\u{947}"), (0, "एह मीन\u{94d}ना"), (1, "अगला मीन\u{94d}ना")], future: ["", "", "", "", "", "{0} मीन\u{94d}न\u{947}या\u{902} च"], past: ["", "", "", "", "", "{0} मीन\u{94d}न\u{947} प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _FIELD_2086: _RelativeField = _RelativeField { relatives: &[(-1, "आखरी तिन मीन\u{94d}न\u{947}"), (0, "एह तिन मीन\u{94d}न\u{947}"), (1, "अगल\u{947} तिन मीन\u{94d}न\u{947}")], future: ["", "", "", "", "", "{0} तिना\u{902} मीन\u{94d}न\u{947}या\u{902} च"], past: ["", "", "", "", "", "{0} तिना\u{902} मीन\u{94d}न\u{947}या\u{902} च"] };
+
+#[rustfmt::skip]
+static _FIELD_2087: _RelativeField = _RelativeField { relatives: &[(-1, "पिछल\u{947} साल"), (0, "एह साल"), (1, "अगला साल")], future: ["", "", "", "", "", "{0} साल\u{94d}ला\u{902} च"], past: ["", "", "", "", "", "{0} साल प\u{948}ल\u{947}"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_165: [&_RelativeField; 24] = [
+    &_FIELD_2077,
+    &_FIELD_2078,
+    &_FIELD_2078,
+    &_FIELD_2079,
+    &_FIELD_2080,
+    &_FIELD_2080,
+    &_FIELD_2081,
+    &_FIELD_2082,
+    &_FIELD_2082,
+    &_FIELD_2083,
+    &_FIELD_2083,
+    &_FIELD_2083,
+    &_FIELD_2084,
+    &_FIELD_2084,
+    &_FIELD_2084,
+    &_FIELD_2085,
+    &_FIELD_2085,
+    &_FIELD_2085,
+    &_FIELD_2086,
+    &_FIELD_2086,
+    &_FIELD_2086,
+    &_FIELD_2087,
+    &_FIELD_2087,
+    &_FIELD_2087
+];
+
+#[rustfmt::skip]
+static _FIELD_2088: _RelativeField = _RelativeField { relatives: &[(0, "nísinsìyí")], future: ["", "", "", "", "", "+{0} s"], past: ["", "", "", "", "", "-{0} s"] };
+
+#[rustfmt::skip]
+static _FIELD_2089: _RelativeField = _RelativeField { relatives: &[(0, "ìṣẹ\u{301}jú yìí")], future: ["", "", "", "", "", "+{0} min"], past: ["", "", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_2090: _RelativeField = _RelativeField { relatives: &[(0, "wákàtí yìí")], future: ["", "", "", "", "", "+{0} h"], past: ["", "", "", "", "", "-{0} h"] };
+
+#[rustfmt::skip]
+static _FIELD_2091: _RelativeField = _RelativeField { relatives: &[(-2, "íjẹta"), (-1, "Àná"), (0, "Òní"), (1, "Ọ\u{300}la"), (2, "òtúùnla")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_2092: _RelativeField = _RelativeField { relatives: &[(-1, "ọ\u{300}sẹ\u{300} tó kọjá"), (0, "ọ\u{300}sẹ\u{300} yìí"), (1, "ọ\u{301}sẹ\u{300} tó ń bọ\u{300}")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_2093: _RelativeField = _RelativeField { relatives: &[(-1, "óṣù tó kọjá"), (0, "oṣù yìí"), (1, "óṣù tó ń bọ\u{300},")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_2094: _RelativeField = _RelativeField { relatives: &[(-1, "Èṣí"), (0, "Ọdún yìí"), (1, "Àmọ\u{301}dún")], future: ["", "", "", "", "", "ní {0} Ọdún"], past: ["", "", "", "", "", "{0} Ọdún sẹ\u{301}yìn"] };
+
+#[rustfmt::skip]
+static _FIELD_2095: _RelativeField = _RelativeField { relatives: &[(-1, "Ọdún tó kọjá"), (0, "Ọdún yìí"), (1, "Ọdún tó ńbọ\u{300}")], future: ["", "", "", "", "", "ní {0} Ọdún"], past: ["", "", "", "", "", "Ọdún {0} sẹ\u{301}yìn"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_166: [&_RelativeField; 24] = [
+    &_FIELD_2088,
+    &_FIELD_2088,
+    &_FIELD_2088,
+    &_FIELD_2089,
+    &_FIELD_2089,
+    &_FIELD_2089,
+    &_FIELD_2090,
+    &_FIELD_2090,
+    &_FIELD_2090,
+    &_FIELD_2091,
+    &_FIELD_2091,
+    &_FIELD_2091,
+    &_FIELD_2092,
+    &_FIELD_2092,
+    &_FIELD_2092,
+    &_FIELD_2093,
+    &_FIELD_2093,
+    &_FIELD_2093,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_2094,
+    &_FIELD_2095,
+    &_FIELD_2095
+];
+
+#[rustfmt::skip]
+static _FIELD_2096: _RelativeField = _RelativeField { relatives: &[(0, "ìshɛ\u{301}jú yìí")], future: ["", "", "", "", "", "+{0} min"], past: ["", "", "", "", "", "-{0} min"] };
+
+#[rustfmt::skip]
+static _FIELD_2097: _RelativeField = _RelativeField { relatives: &[(-2, "íjɛta"), (-1, "Àná"), (0, "Òní"), (1, "Ɔ\u{300}la"), (2, "òtúùnla")], future: ["", "", "", "", "", "+{0} d"], past: ["", "", "", "", "", "-{0} d"] };
+
+#[rustfmt::skip]
+static _FIELD_2098: _RelativeField = _RelativeField { relatives: &[(-1, "ɔ\u{300}sɛ\u{300} tó kɔjá"), (0, "ɔ\u{300}sɛ\u{300} yìí"), (1, "ɔ\u{301}sɛ\u{300} tó ń bɔ\u{300}")], future: ["", "", "", "", "", "+{0} w"], past: ["", "", "", "", "", "-{0} w"] };
+
+#[rustfmt::skip]
+static _FIELD_2099: _RelativeField = _RelativeField { relatives: &[(-1, "óshù tó kɔjá"), (0, "oshù yìí"), (1, "óshù tó ń bɔ\u{300},")], future: ["", "", "", "", "", "+{0} m"], past: ["", "", "", "", "", "-{0} m"] };
+
+#[rustfmt::skip]
+static _FIELD_2100: _RelativeField = _RelativeField { relatives: &[(-1, "Èshí"), (0, "Ɔdún yìí"), (1, "Àmɔ\u{301}dún")], future: ["", "", "", "", "", "ní {0} Ɔdún"], past: ["", "", "", "", "", "{0} Ɔdún sɛ\u{301}yìn"] };
+
+#[rustfmt::skip]
+static _FIELD_2101: _RelativeField = _RelativeField { relatives: &[(-1, "Ɔdún tó kɔjá"), (0, "Ɔdún yìí"), (1, "Ɔdún tó ńbɔ\u{300}")], future: ["", "", "", "", "", "ní {0} Ɔdún"], past: ["", "", "", "", "", "Ɔdún {0} sɛ\u{301}yìn"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_167: [&_RelativeField; 24] = [
+    &_FIELD_2088,
+    &_FIELD_2088,
+    &_FIELD_2088,
+    &_FIELD_2096,
+    &_FIELD_2096,
+    &_FIELD_2096,
+    &_FIELD_2090,
+    &_FIELD_2090,
+    &_FIELD_2090,
+    &_FIELD_2097,
+    &_FIELD_2097,
+    &_FIELD_2097,
+    &_FIELD_2098,
+    &_FIELD_2098,
+    &_FIELD_2098,
+    &_FIELD_2099,
+    &_FIELD_2099,
+    &_FIELD_2099,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_6,
+    &_FIELD_2100,
+    &_FIELD_2101,
+    &_FIELD_2101
+];
+
+#[rustfmt::skip]
+static _FIELD_2102: _RelativeField = _RelativeField { relatives: &[(0, "kuíri")], future: ["", "{0} segũdu resê", "", "", "", "{0} segũdu resê itá"], past: ["", "aikué {0} segũdu", "", "", "", "aikué {0} segũdu itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2103: _RelativeField = _RelativeField { relatives: &[(0, "kuíri")], future: ["", "{0} seg. resê", "", "", "", "{0} seg. resê itá"], past: ["", "aikué {0} seg.", "", "", "", "aikué {0} seg. itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2104: _RelativeField = _RelativeField { relatives: &[(0, "kuá minutu")], future: ["", "{0} minutu resê", "", "", "", "{0} minutu resê itá"], past: ["", "aikué {0} minutu", "", "", "", "aikué {0} minutu itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2105: _RelativeField = _RelativeField { relatives: &[(0, "kuá minutu")], future: ["", "{0} min. resê", "", "", "", "{0} min. resê itá"], past: ["", "aikué {0} min.", "", "", "", "aikué {0} min. itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2106: _RelativeField = _RelativeField { relatives: &[(0, "kuá hura")], future: ["", "{0} hura resê", "", "", "", "{0} hura resê itá"], past: ["", "aikué {0} hura", "", "", "", "aikué {0} hura itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2107: _RelativeField = _RelativeField { relatives: &[(0, "kuá hura")], future: ["", "{0} h resê", "", "", "", "{0} h resê itá"], past: ["", "aikué {0} h", "", "", "", "aikué {0} h itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2108: _RelativeField = _RelativeField { relatives: &[(-2, "amũ kuesê"), (-1, "kuesê"), (0, "wií"), (1, "wirãdé"), (2, "wirãdé ariré")], future: ["", "{0} ara resê", "", "", "", "{0} ara itá resê"], past: ["", "aikué {0} ara", "", "", "", "aikué {0} ara itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2109: _RelativeField = _RelativeField { relatives: &[(-1, "sẽmãna kuera"), (0, "kuá sẽmãna"), (1, "amũ sẽmãna")], future: ["", "{0} sẽmãna resê", "", "", "", "{0} sẽmãna itá resê"], past: ["", "aikué {0} sẽmãna", "", "", "", "aikué {0} sẽmãna itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2110: _RelativeField = _RelativeField { relatives: &[(-1, "sẽmãna kuera"), (0, "kuá sẽmãna"), (1, "amũ sẽmãna")], future: ["", "{0} sẽm. resê", "", "", "", "{0} sẽm. itá resê"], past: ["", "aikué {0} sẽm.", "", "", "", "aikué {0} sẽm. itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2111: _RelativeField = _RelativeField { relatives: &[(-1, "yasí kuera"), (0, "kuá yasí"), (1, "amũ yasí")], future: ["", "{0} yasí resê", "", "", "", "{0} yasí itá resê"], past: ["", "aikué {0} yasí", "", "", "", "aikué {0} yasí itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2112: _RelativeField = _RelativeField { relatives: &[(-1, "kasakiriwara musapíri-yasí"), (0, "kuá musapíri-yasí"), (1, "amũ musapíri-yasí")], future: ["", "{0} musapíri-yasí resê", "", "", "", "{0} musapíri-yasí itá resê"], past: ["", "aikué {0} musapíri-yasí", "", "", "", "aikué {0} musapíri-yasí itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2113: _RelativeField = _RelativeField { relatives: &[(-1, "kasakiriwara musapíri-yasí"), (0, "kuá musapíri-yasí"), (1, "amũ musapíri-yasí")], future: ["", "{0} mu-y. resê", "", "", "", "{0} mu-y. itá resê"], past: ["", "aikué {0} mu-y.", "", "", "", "aikué {0} mu-y. itá"] };
+
+#[rustfmt::skip]
+static _FIELD_2114: _RelativeField = _RelativeField { relatives: &[(-1, "akayú kuera"), (0, "kuá akayú"), (1, "amũ akayú")], future: ["", "{0} akayú resê", "", "", "", "{0} akayú itá resê"], past: ["", "aikué {0} akayú", "", "", "", "aikué {0} akayú itá"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_168: [&_RelativeField; 24] = [
+    &_FIELD_2102,
+    &_FIELD_2103,
+    &_FIELD_2103,
+    &_FIELD_2104,
+    &_FIELD_2105,
+    &_FIELD_2105,
+    &_FIELD_2106,
+    &_FIELD_2107,
+    &_FIELD_2107,
+    &_FIELD_2108,
+    &_FIELD_2108,
+    &_FIELD_2108,
+    &_FIELD_2109,
+    &_FIELD_2110,
+    &_FIELD_2110,
+    &_FIELD_2111,
+    &_FIELD_2111,
+    &_FIELD_2111,
+    &_FIELD_2112,
+    &_FIELD_2113,
+    &_FIELD_2113,
+    &_FIELD_2114,
+    &_FIELD_2114,
+    &_FIELD_2114
+];
+
+#[rustfmt::skip]
+static _FIELD_2115: _RelativeField = _RelativeField { relatives: &[(0, "宜家")], future: ["", "", "", "", "", "{0} 秒後"], past: ["", "", "", "", "", "{0} 秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_2116: _RelativeField = _RelativeField { relatives: &[(0, "呢分鐘")], future: ["", "", "", "", "", "{0} 分鐘後"], past: ["", "", "", "", "", "{0} 分鐘前"] };
+
+#[rustfmt::skip]
+static _FIELD_2117: _RelativeField = _RelativeField { relatives: &[(0, "呢個小時")], future: ["", "", "", "", "", "{0} 小時後"], past: ["", "", "", "", "", "{0} 小時前"] };
+
+#[rustfmt::skip]
+static _FIELD_2118: _RelativeField = _RelativeField { relatives: &[(-2, "前天"), (-1, "尋日"), (0, "今日"), (1, "聽日"), (2, "後天")], future: ["", "", "", "", "", "{0} 日後"], past: ["", "", "", "", "", "{0} 日前"] };
+
+#[rustfmt::skip]
+static _FIELD_2119: _RelativeField = _RelativeField { relatives: &[(-1, "上星期"), (0, "今個星期"), (1, "下星期")], future: ["", "", "", "", "", "{0} 個星期後"], past: ["", "", "", "", "", "{0} 個星期前"] };
+
+#[rustfmt::skip]
+static _FIELD_2120: _RelativeField = _RelativeField { relatives: &[(-1, "上個月"), (0, "今個月"), (1, "下個月")], future: ["", "", "", "", "", "{0} 個月後"], past: ["", "", "", "", "", "{0} 個月前"] };
+
+#[rustfmt::skip]
+static _FIELD_2121: _RelativeField = _RelativeField { relatives: &[(-1, "上一季"), (0, "今季"), (1, "下一季")], future: ["", "", "", "", "", "{0} 季後"], past: ["", "", "", "", "", "{0} 季前"] };
+
+#[rustfmt::skip]
+static _FIELD_2122: _RelativeField = _RelativeField { relatives: &[(-1, "上季"), (0, "今季"), (1, "下季")], future: ["", "", "", "", "", "{0} 季後"], past: ["", "", "", "", "", "{0} 季前"] };
+
+#[rustfmt::skip]
+static _FIELD_2123: _RelativeField = _RelativeField { relatives: &[(-1, "舊年"), (0, "今年"), (1, "下年")], future: ["", "", "", "", "", "{0} 年後"], past: ["", "", "", "", "", "{0} 年前"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_169: [&_RelativeField; 24] = [
+    &_FIELD_2115,
+    &_FIELD_2115,
+    &_FIELD_2115,
+    &_FIELD_2116,
+    &_FIELD_2116,
+    &_FIELD_2116,
+    &_FIELD_2117,
+    &_FIELD_2117,
+    &_FIELD_2117,
+    &_FIELD_2118,
+    &_FIELD_2118,
+    &_FIELD_2118,
+    &_FIELD_2119,
+    &_FIELD_2119,
+    &_FIELD_2119,
+    &_FIELD_2120,
+    &_FIELD_2120,
+    &_FIELD_2120,
+    &_FIELD_2121,
+    &_FIELD_2122,
+    &_FIELD_2122,
+    &_FIELD_2123,
+    &_FIELD_2123,
+    &_FIELD_2123
+];
+
+#[rustfmt::skip]
+static _FIELD_2124: _RelativeField = _RelativeField { relatives: &[(0, "宜家")], future: ["", "", "", "", "", "{0} 秒后"], past: ["", "", "", "", "", "{0} 秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_2125: _RelativeField = _RelativeField { relatives: &[(0, "呢分钟")], future: ["", "", "", "", "", "{0} 分钟后"], past: ["", "", "", "", "", "{0} 分钟前"] };
+
+#[rustfmt::skip]
+static _FIELD_2126: _RelativeField = _RelativeField { relatives: &[(0, "呢个小时")], future: ["", "", "", "", "", "{0} 小时后"], past: ["", "", "", "", "", "{0} 小时前"] };
+
+#[rustfmt::skip]
+static _FIELD_2127: _RelativeField = _RelativeField { relatives: &[(-2, "前天"), (-1, "寻日"), (0, "今日"), (1, "听日"), (2, "后天")], future: ["", "", "", "", "", "{0} 日后"], past: ["", "", "", "", "", "{0} 日前"] };
+
+#[rustfmt::skip]
+static _FIELD_2128: _RelativeField = _RelativeField { relatives: &[(-1, "上星期"), (0, "今个星期"), (1, "下星期")], future: ["", "", "", "", "", "{0} 个星期后"], past: ["", "", "", "", "", "{0} 个星期前"] };
+
+#[rustfmt::skip]
+static _FIELD_2129: _RelativeField = _RelativeField { relatives: &[(-1, "上个月"), (0, "今个月"), (1, "下个月")], future: ["", "", "", "", "", "{0} 个月后"], past: ["", "", "", "", "", "{0} 个月前"] };
+
+#[rustfmt::skip]
+static _FIELD_2130: _RelativeField = _RelativeField { relatives: &[(-1, "上一季"), (0, "今季"), (1, "下一季")], future: ["", "", "", "", "", "{0} 季后"], past: ["", "", "", "", "", "{0} 季前"] };
+
+#[rustfmt::skip]
+static _FIELD_2131: _RelativeField = _RelativeField { relatives: &[(-1, "上季"), (0, "今季"), (1, "下季")], future: ["", "", "", "", "", "{0} 季后"], past: ["", "", "", "", "", "{0} 季前"] };
+
+#[rustfmt::skip]
+static _FIELD_2132: _RelativeField = _RelativeField { relatives: &[(-1, "旧年"), (0, "今年"), (1, "下年")], future: ["", "", "", "", "", "{0} 年后"], past: ["", "", "", "", "", "{0} 年前"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_170: [&_RelativeField; 24] = [
+    &_FIELD_2124,
+    &_FIELD_2124,
+    &_FIELD_2124,
+    &_FIELD_2125,
+    &_FIELD_2125,
+    &_FIELD_2125,
+    &_FIELD_2126,
+    &_FIELD_2126,
+    &_FIELD_2126,
+    &_FIELD_2127,
+    &_FIELD_2127,
+    &_FIELD_2127,
+    &_FIELD_2128,
+    &_FIELD_2128,
+    &_FIELD_2128,
+    &_FIELD_2129,
+    &_FIELD_2129,
+    &_FIELD_2129,
+    &_FIELD_2130,
+    &_FIELD_2131,
+    &_FIELD_2131,
+    &_FIELD_2132,
+    &_FIELD_2132,
+    &_FIELD_2132
+];
+
+#[rustfmt::skip]
+static _FIELD_2133: _RelativeField = _RelativeField { relatives: &[(0, "现在")], future: ["", "", "", "", "", "{0}秒钟后"], past: ["", "", "", "", "", "{0}秒钟前"] };
+
+#[rustfmt::skip]
+static _FIELD_2134: _RelativeField = _RelativeField { relatives: &[(0, "现在")], future: ["", "", "", "", "", "{0}秒后"], past: ["", "", "", "", "", "{0}秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_2135: _RelativeField = _RelativeField { relatives: &[(0, "此刻")], future: ["", "", "", "", "", "{0}分钟后"], past: ["", "", "", "", "", "{0}分钟前"] };
+
+#[rustfmt::skip]
+static _FIELD_2136: _RelativeField = _RelativeField { relatives: &[(0, "这一时间 / 此时")], future: ["", "", "", "", "", "{0}小时后"], past: ["", "", "", "", "", "{0}小时前"] };
+
+#[rustfmt::skip]
+static _FIELD_2137: _RelativeField = _RelativeField { relatives: &[(-2, "前天"), (-1, "昨天"), (0, "今天"), (1, "明天"), (2, "后天")], future: ["", "", "", "", "", "{0}天后"], past: ["", "", "", "", "", "{0}天前"] };
+
+#[rustfmt::skip]
+static _FIELD_2138: _RelativeField = _RelativeField { relatives: &[(-1, "上周"), (0, "本周"), (1, "下周")], future: ["", "", "", "", "", "{0}周后"], past: ["", "", "", "", "", "{0}周前"] };
+
+#[rustfmt::skip]
+static _FIELD_2139: _RelativeField = _RelativeField { relatives: &[(-1, "上个月"), (0, "本月"), (1, "下个月")], future: ["", "", "", "", "", "{0}个月后"], past: ["", "", "", "", "", "{0}个月前"] };
+
+#[rustfmt::skip]
+static _FIELD_2140: _RelativeField = _RelativeField { relatives: &[(-1, "上季度"), (0, "本季度"), (1, "下季度")], future: ["", "", "", "", "", "{0}个季度后"], past: ["", "", "", "", "", "{0}个季度前"] };
+
+#[rustfmt::skip]
+static _FIELD_2141: _RelativeField = _RelativeField { relatives: &[(-1, "去年"), (0, "今年"), (1, "明年")], future: ["", "", "", "", "", "{0}年后"], past: ["", "", "", "", "", "{0}年前"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_171: [&_RelativeField; 24] = [
+    &_FIELD_2133,
+    &_FIELD_2134,
+    &_FIELD_2134,
+    &_FIELD_2135,
+    &_FIELD_2135,
+    &_FIELD_2135,
+    &_FIELD_2136,
+    &_FIELD_2136,
+    &_FIELD_2136,
+    &_FIELD_2137,
+    &_FIELD_2137,
+    &_FIELD_2137,
+    &_FIELD_2138,
+    &_FIELD_2138,
+    &_FIELD_2138,
+    &_FIELD_2139,
+    &_FIELD_2139,
+    &_FIELD_2139,
+    &_FIELD_2140,
+    &_FIELD_2140,
+    &_FIELD_2140,
+    &_FIELD_2141,
+    &_FIELD_2141,
+    &_FIELD_2141
+];
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_172: [&_RelativeField; 24] = [
+    &_FIELD_2134,
+    &_FIELD_2134,
+    &_FIELD_2134,
+    &_FIELD_2135,
+    &_FIELD_2135,
+    &_FIELD_2135,
+    &_FIELD_2136,
+    &_FIELD_2136,
+    &_FIELD_2136,
+    &_FIELD_2137,
+    &_FIELD_2137,
+    &_FIELD_2137,
+    &_FIELD_2138,
+    &_FIELD_2138,
+    &_FIELD_2138,
+    &_FIELD_2139,
+    &_FIELD_2139,
+    &_FIELD_2139,
+    &_FIELD_2140,
+    &_FIELD_2140,
+    &_FIELD_2140,
+    &_FIELD_2141,
+    &_FIELD_2141,
+    &_FIELD_2141
+];
+
+#[rustfmt::skip]
+static _FIELD_2142: _RelativeField = _RelativeField { relatives: &[(0, "現在")], future: ["", "", "", "", "", "{0} 秒後"], past: ["", "", "", "", "", "{0} 秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_2143: _RelativeField = _RelativeField { relatives: &[(0, "這一分鐘")], future: ["", "", "", "", "", "{0} 分鐘後"], past: ["", "", "", "", "", "{0} 分鐘前"] };
+
+#[rustfmt::skip]
+static _FIELD_2144: _RelativeField = _RelativeField { relatives: &[(0, "這一小時")], future: ["", "", "", "", "", "{0} 小時後"], past: ["", "", "", "", "", "{0} 小時前"] };
+
+#[rustfmt::skip]
+static _FIELD_2145: _RelativeField = _RelativeField { relatives: &[(-2, "前天"), (-1, "昨天"), (0, "今天"), (1, "明天"), (2, "後天")], future: ["", "", "", "", "", "{0} 天後"], past: ["", "", "", "", "", "{0} 天前"] };
+
+#[rustfmt::skip]
+static _FIELD_2146: _RelativeField = _RelativeField { relatives: &[(-1, "上週"), (0, "本週"), (1, "下週")], future: ["", "", "", "", "", "{0} 週後"], past: ["", "", "", "", "", "{0} 週前"] };
+
+#[rustfmt::skip]
+static _FIELD_2147: _RelativeField = _RelativeField { relatives: &[(-1, "上個月"), (0, "本月"), (1, "下個月")], future: ["", "", "", "", "", "{0} 個月後"], past: ["", "", "", "", "", "{0} 個月前"] };
+
+#[rustfmt::skip]
+static _FIELD_2148: _RelativeField = _RelativeField { relatives: &[(-1, "上一季"), (0, "這一季"), (1, "下一季")], future: ["", "", "", "", "", "{0} 季後"], past: ["", "", "", "", "", "{0} 季前"] };
+
+#[rustfmt::skip]
+static _FIELD_2149: _RelativeField = _RelativeField { relatives: &[(-1, "去年"), (0, "今年"), (1, "明年")], future: ["", "", "", "", "", "{0} 年後"], past: ["", "", "", "", "", "{0} 年前"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_173: [&_RelativeField; 24] = [
+    &_FIELD_2142,
+    &_FIELD_2142,
+    &_FIELD_2142,
+    &_FIELD_2143,
+    &_FIELD_2143,
+    &_FIELD_2143,
+    &_FIELD_2144,
+    &_FIELD_2144,
+    &_FIELD_2144,
+    &_FIELD_2145,
+    &_FIELD_2145,
+    &_FIELD_2145,
+    &_FIELD_2146,
+    &_FIELD_2146,
+    &_FIELD_2146,
+    &_FIELD_2147,
+    &_FIELD_2147,
+    &_FIELD_2147,
+    &_FIELD_2148,
+    &_FIELD_2148,
+    &_FIELD_2148,
+    &_FIELD_2149,
+    &_FIELD_2149,
+    &_FIELD_2149
+];
+
+#[rustfmt::skip]
+static _FIELD_2150: _RelativeField = _RelativeField { relatives: &[(0, "現在")], future: ["", "", "", "", "", "{0}秒後"], past: ["", "", "", "", "", "{0}秒前"] };
+
+#[rustfmt::skip]
+static _FIELD_2151: _RelativeField = _RelativeField { relatives: &[(0, "這分鐘")], future: ["", "", "", "", "", "{0} 分鐘後"], past: ["", "", "", "", "", "{0} 分鐘前"] };
+
+#[rustfmt::skip]
+static _FIELD_2152: _RelativeField = _RelativeField { relatives: &[(0, "這分鐘")], future: ["", "", "", "", "", "{0}分後"], past: ["", "", "", "", "", "{0}分前"] };
+
+#[rustfmt::skip]
+static _FIELD_2153: _RelativeField = _RelativeField { relatives: &[(0, "這個小時")], future: ["", "", "", "", "", "{0} 小時後"], past: ["", "", "", "", "", "{0} 小時前"] };
+
+#[rustfmt::skip]
+static _FIELD_2154: _RelativeField = _RelativeField { relatives: &[(0, "這個小時")], future: ["", "", "", "", "", "{0}小時後"], past: ["", "", "", "", "", "{0}小時前"] };
+
+#[rustfmt::skip]
+static _FIELD_2155: _RelativeField = _RelativeField { relatives: &[(-2, "前日"), (-1, "昨日"), (0, "今日"), (1, "明日"), (2, "後日")], future: ["", "", "", "", "", "{0} 日後"], past: ["", "", "", "", "", "{0} 日前"] };
+
+#[rustfmt::skip]
+static _FIELD_2156: _RelativeField = _RelativeField { relatives: &[(-2, "前日"), (-1, "昨日"), (0, "今日"), (1, "明日"), (2, "後日")], future: ["", "", "", "", "", "{0}日後"], past: ["", "", "", "", "", "{0}日前"] };
+
+#[rustfmt::skip]
+static _FIELD_2157: _RelativeField = _RelativeField { relatives: &[(-1, "上星期"), (0, "本星期"), (1, "下星期")], future: ["", "", "", "", "", "{0} 星期後"], past: ["", "", "", "", "", "{0} 星期前"] };
+
+#[rustfmt::skip]
+static _FIELD_2158: _RelativeField = _RelativeField { relatives: &[(-1, "上星期"), (0, "本星期"), (1, "下星期")], future: ["", "", "", "", "", "{0}星期後"], past: ["", "", "", "", "", "{0}星期前"] };
+
+#[rustfmt::skip]
+static _FIELD_2159: _RelativeField = _RelativeField { relatives: &[(-1, "上個月"), (0, "本月"), (1, "下個月")], future: ["", "", "", "", "", "{0}個月後"], past: ["", "", "", "", "", "{0}個月前"] };
+
+#[rustfmt::skip]
+static _FIELD_2160: _RelativeField = _RelativeField { relatives: &[(-1, "上季"), (0, "今季"), (1, "下季")], future: ["", "", "", "", "", "+{0}Q"], past: ["", "", "", "", "", "-{0}Q"] };
+
+#[rustfmt::skip]
+static _FIELD_2161: _RelativeField = _RelativeField { relatives: &[(-1, "上年"), (0, "今年"), (1, "下年")], future: ["", "", "", "", "", "{0} 年後"], past: ["", "", "", "", "", "{0} 年前"] };
+
+#[rustfmt::skip]
+static _FIELD_2162: _RelativeField = _RelativeField { relatives: &[(-1, "上年"), (0, "今年"), (1, "下年")], future: ["", "", "", "", "", "{0}年後"], past: ["", "", "", "", "", "{0}年前"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_174: [&_RelativeField; 24] = [
+    &_FIELD_2142,
+    &_FIELD_2142,
+    &_FIELD_2150,
+    &_FIELD_2151,
+    &_FIELD_2151,
+    &_FIELD_2152,
+    &_FIELD_2153,
+    &_FIELD_2153,
+    &_FIELD_2154,
+    &_FIELD_2155,
+    &_FIELD_2155,
+    &_FIELD_2156,
+    &_FIELD_2157,
+    &_FIELD_2157,
+    &_FIELD_2158,
+    &_FIELD_2147,
+    &_FIELD_2147,
+    &_FIELD_2159,
+    &_FIELD_2121,
+    &_FIELD_2122,
+    &_FIELD_2160,
+    &_FIELD_2161,
+    &_FIELD_2161,
+    &_FIELD_2162
+];
+
+#[rustfmt::skip]
+static _FIELD_2163: _RelativeField = _RelativeField { relatives: &[(0, "manje")], future: ["", "kusekhondi elingu-{0} elizayo", "", "", "", "kumasekhondi angu-{0} ezayo"], past: ["", "{0} isekhondi eledlule", "", "", "", "{0} amasekhondi edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2164: _RelativeField = _RelativeField { relatives: &[(0, "leli minithi")], future: ["", "kuminithi elingu-{0} elizayo", "", "", "", "kumaminithi angu-{0} ezayo"], past: ["", "{0} iminithi eledlule", "", "", "", "{0} amaminithi edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2165: _RelativeField = _RelativeField { relatives: &[(0, "leli hora")], future: ["", "ehoreni elingu-{0} elizayo", "", "", "", "emahoreni angu-{0} ezayo"], past: ["", "{0} ihora eledlule", "", "", "", "emahoreni angu-{0} edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2166: _RelativeField = _RelativeField { relatives: &[(0, "leli hora")], future: ["", "ehoreni elingu-{0} elizayo", "", "", "", "emahoreni angu-{0} ezayo"], past: ["", "{0} ihora eledlule", "", "", "", "{0} amahora edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2167: _RelativeField = _RelativeField { relatives: &[(-2, "usuku olwandulela olwayizolo"), (-1, "izolo"), (0, "namhlanje"), (1, "kusasa"), (2, "usuku olulandela olwakusasa")], future: ["", "osukwini olungu-{0} oluzayo", "", "", "", "ezinsukwini ezingu-{0} ezizayo"], past: ["", "osukwini olungu-{0} olwedlule", "", "", "", "ezinsukwini ezingu-{0} ezedlule."] };
+
+#[rustfmt::skip]
+static _FIELD_2168: _RelativeField = _RelativeField { relatives: &[(-2, "usuku olwandulela olwayizolo"), (-1, "izolo"), (0, "namhlanje"), (1, "kusasa"), (2, "usuku olulandela olwakusasa")], future: ["", "osukwini olungu-{0} oluzayo", "", "", "", "ezinsukwini ezingu-{0} ezizayo"], past: ["", "{0} usuku olwedlule", "", "", "", "{0} izinsuku ezedlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2169: _RelativeField = _RelativeField { relatives: &[(-1, "iviki eledlule"), (0, "leli viki"), (1, "iviki elizayo")], future: ["", "evikini elingu-{0}", "", "", "", "emavikini angu-{0}"], past: ["", "evikini elingu-{0} eledlule", "", "", "", "amaviki angu-{0} edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2170: _RelativeField = _RelativeField { relatives: &[(-1, "iviki eledlule"), (0, "leli viki"), (1, "iviki elizayo")], future: ["", "evikini elingu-{0} elizayo", "", "", "", "emavikini angu-{0} ezayo"], past: ["", "amaviki angu-{0} edlule", "", "", "", "amaviki angu-{0} edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2171: _RelativeField = _RelativeField { relatives: &[(-1, "iviki eledlule"), (0, "leli viki"), (1, "iviki elizayo")], future: ["", "emavikini angu-{0} ezayo", "", "", "", "emavikini angu-{0} ezayo"], past: ["", "amaviki angu-{0} edlule", "", "", "", "amaviki angu-{0} edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2172: _RelativeField = _RelativeField { relatives: &[(-1, "inyanga edlule"), (0, "le nyanga"), (1, "inyanga ezayo")], future: ["", "enyangeni engu-{0}", "", "", "", "ezinyangeni ezingu-{0} ezizayo"], past: ["", "{0} inyanga edlule", "", "", "", "{0} izinyanga ezedlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2173: _RelativeField = _RelativeField { relatives: &[(-1, "inyanga edlule"), (0, "le nyanga"), (1, "inyanga ezayo")], future: ["", "ezinyangeni ezingu-{0} ezizayo", "", "", "", "ezinyangeni ezingu-{0} ezizayo"], past: ["", "{0} izinyanga ezedlule", "", "", "", "{0} izinyanga ezedlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2174: _RelativeField = _RelativeField { relatives: &[(-1, "inyanga edlule"), (0, "le nyanga"), (1, "inyanga ezayo")], future: ["", "enyangeni engu-{0} ezayo", "", "", "", "enyangeni engu-{0} ezayo"], past: ["", "{0} izinyanga ezedlule", "", "", "", "{0} izinyanga ezedlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2175: _RelativeField = _RelativeField { relatives: &[(-1, "ikota edlule"), (0, "le kota"), (1, "ikota ezayo")], future: ["", "kwikota engu-{0} ezayo", "", "", "", "kumakota angu-{0} ezayo"], past: ["", "{0} ikota edlule", "", "", "", "{0} amakota adlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2176: _RelativeField = _RelativeField { relatives: &[(-1, "ikota edlule"), (0, "le kota"), (1, "ikota ezayo")], future: ["", "kwikota engu-{0} ezayo", "", "", "", "kumakota angu-{0} ezayo"], past: ["", "{0} amakota adlule", "", "", "", "{0} amakota edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2177: _RelativeField = _RelativeField { relatives: &[(-1, "ikota edlule"), (0, "le kota"), (1, "ikota ezayo")], future: ["", "kumakota angu-{0}", "", "", "", "kumakota angu-{0}"], past: ["", "{0} amakota adlule", "", "", "", "{0} amakota edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2178: _RelativeField = _RelativeField { relatives: &[(-1, "onyakeni odlule"), (0, "kulo nyaka"), (1, "unyaka ozayo")], future: ["", "onyakeni ongu-{0} ozayo", "", "", "", "eminyakeni engu-{0} ezayo"], past: ["", "{0} unyaka odlule", "", "", "", "{0} iminyaka edlule"] };
+
+#[rustfmt::skip]
+static _FIELD_2179: _RelativeField = _RelativeField { relatives: &[(-1, "onyakeni odlule"), (0, "kulo nyaka"), (1, "unyaka ozayo")], future: ["", "onyakeni ongu-{0} ozayo", "", "", "", "eminyakeni engu-{0} ezayo"], past: ["", "{0} unyaka odlule", "", "", "", "{0} unyaka odlule"] };
+
+#[rustfmt::skip]
+static _RELATIVE_FIELDS_175: [&_RelativeField; 24] = [
+    &_FIELD_2163,
+    &_FIELD_2163,
+    &_FIELD_2163,
+    &_FIELD_2164,
+    &_FIELD_2164,
+    &_FIELD_2164,
+    &_FIELD_2165,
+    &_FIELD_2165,
+    &_FIELD_2166,
+    &_FIELD_2167,
+    &_FIELD_2168,
+    &_FIELD_2168,
+    &_FIELD_2169,
+    &_FIELD_2170,
+    &_FIELD_2171,
+    &_FIELD_2172,
+    &_FIELD_2173,
+    &_FIELD_2174,
+    &_FIELD_2175,
+    &_FIELD_2176,
+    &_FIELD_2177,
+    &_FIELD_2178,
+    &_FIELD_2179,
+    &_FIELD_2179
+];
@@ -1,6 +1,8 @@
 mod test_locales;
 #[cfg(feature = "nums")]
 mod test_num_formatting;
+#[cfg(feature = "nums")]
+mod test_rbnf;
 #[cfg(feature = "strum")]
 mod test_strum;
 
@@ -9,3 +11,18 @@ mod test_datetime_formatting;
 
 #[cfg(feature = "currency")]
 mod test_currency_formatting;
+
+#[cfg(feature = "datetime")]
+mod test_time_zones;
+
+#[cfg(feature = "datetime")]
+mod test_relative_time;
+
+#[cfg(feature = "datetime")]
+mod test_calendars;
+
+#[cfg(feature = "datetime")]
+mod test_durations;
+
+#[cfg(feature = "plurals")]
+mod test_plurals;
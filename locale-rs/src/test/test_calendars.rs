@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use crate::calendars::{Calendar, CalendarDate};
+use crate::datetime_formats::{DateStyle, DateTime};
+use crate::error::LocaleError;
+use crate::locale::Locale;
+
+fn date(year: i32, month: u32, day: u32, calendar: Calendar) -> CalendarDate {
+    DateTime::from_ymd(year, month, day)
+        .unwrap()
+        .to_calendar(calendar)
+}
+
+#[test]
+fn test_calendar_conversions() {
+    let nowruz = date(2026, 3, 21, Calendar::Persian);
+    assert_eq!((nowruz.year, nowruz.month, nowruz.day), (1405, 1, 1));
+    let last_of_esfand = date(2025, 3, 20, Calendar::Persian);
+    assert_eq!(
+        (
+            last_of_esfand.year,
+            last_of_esfand.month,
+            last_of_esfand.day
+        ),
+        (1403, 12, 30)
+    );
+
+    let umalqura = date(2026, 3, 21, Calendar::IslamicUmalqura);
+    assert_eq!((umalqura.year, umalqura.month, umalqura.day), (1447, 10, 2));
+    let civil = date(2024, 3, 11, Calendar::IslamicCivil);
+    assert_eq!((civil.year, civil.month, civil.day), (1445, 9, 1));
+
+    let ethiopic = date(2025, 9, 11, Calendar::Ethiopic);
+    assert_eq!(
+        (ethiopic.era, ethiopic.year, ethiopic.month, ethiopic.day),
+        (1, 2018, 1, 1)
+    );
+    let pagume = date(2023, 9, 11, Calendar::Ethiopic);
+    assert_eq!((pagume.year, pagume.month, pagume.day), (2015, 13, 6));
+
+    let buddhist = date(2026, 3, 21, Calendar::Buddhist);
+    assert_eq!((buddhist.era, buddhist.year), (0, 2569));
+}
+
+#[test]
+fn test_hebrew_leap_months() {
+    // 5784 is a leap year: Adar I and Adar II are months 6 and 7
+    let adar_two = date(2024, 3, 21, Calendar::Hebrew);
+    assert_eq!((adar_two.year, adar_two.month, adar_two.day), (5784, 7, 11));
+    let rosh_hashanah = date(2025, 9, 23, Calendar::Hebrew);
+    assert_eq!(
+        (rosh_hashanah.year, rosh_hashanah.month, rosh_hashanah.day),
+        (5786, 1, 1)
+    );
+    let nisan = date(2026, 3, 21, Calendar::Hebrew);
+    assert_eq!((nisan.year, nisan.month, nisan.day), (5786, 7, 3));
+
+    assert_eq!(
+        Locale::en.format_with_pattern_in(
+            &DateTime::from_ymd(2024, 3, 21).unwrap(),
+            "d MMMM y",
+            Calendar::Hebrew
+        ),
+        "11 Adar II 5784"
+    );
+    assert_eq!(
+        Locale::en.format_with_pattern_in(
+            &DateTime::from_ymd(2026, 3, 3).unwrap(),
+            "d MMMM y",
+            Calendar::Hebrew
+        ),
+        "14 Adar 5786"
+    );
+    // Cyclic years fall back to the calendar year, related years are Gregorian
+    assert_eq!(
+        Locale::en.format_with_pattern_in(
+            &DateTime::from_ymd(2026, 3, 3).unwrap(),
+            "U r",
+            Calendar::Hebrew
+        ),
+        "5786 2026"
+    );
+}
+
+#[test]
+fn test_hebrew_epoch_boundary() {
+    // 1 Tishri 1 AM is -3760-09-07 in the proleptic Gregorian calendar
+    let epoch = DateTime::from_ymd(-3760, 9, 7).unwrap();
+    let first = epoch.to_calendar(Calendar::Hebrew);
+    assert_eq!((first.year, first.month, first.day), (1, 1, 1));
+    // The day before is 29 Elul of leap year 0
+    let last = epoch
+        .checked_sub_days(1)
+        .unwrap()
+        .to_calendar(Calendar::Hebrew);
+    assert_eq!((last.year, last.month, last.day), (0, 13, 29));
+
+    // Consecutive days stay consecutive on both sides of the epoch
+    let mut previous = epoch
+        .checked_sub_days(800)
+        .unwrap()
+        .to_calendar(Calendar::Hebrew);
+    for offset in -799..=800 {
+        let next = epoch
+            .checked_add_days(offset)
+            .unwrap()
+            .to_calendar(Calendar::Hebrew);
+        let expected = [
+            (previous.year, previous.month, previous.day + 1),
+            (previous.year, previous.month + 1, 1),
+            (previous.year + 1, 1, 1),
+        ];
+        assert!(
+            expected.contains(&(next.year, next.month, next.day)),
+            "{offset}: {:?} after {:?}",
+            (next.year, next.month, next.day),
+            (previous.year, previous.month, previous.day)
+        );
+        previous = next;
+    }
+
+    let ancient = date(-5000, 1, 1, Calendar::Hebrew);
+    assert_eq!(ancient.year, -1240);
+    assert!((1..=13).contains(&ancient.month) && (1..=30).contains(&ancient.day));
+}
+
+#[test]
+fn test_japanese_eras() {
+    let heisei = date(2019, 4, 30, Calendar::Japanese);
+    assert_eq!((heisei.era, heisei.year), (5, 31));
+    let reiwa = date(2019, 5, 1, Calendar::Japanese);
+    assert_eq!((reiwa.era, reiwa.year), (6, 1));
+    // Before Meiji the Gregorian eras apply
+    let edo = date(1850, 1, 1, Calendar::Japanese);
+    assert_eq!((edo.era, edo.year), (1, 1850));
+
+    let dt = DateTime::from_ymd(2026, 3, 21).unwrap();
+    assert_eq!(
+        Locale::ja.format_date_in(&dt, DateStyle::Long, Calendar::Japanese),
+        "令和8年3月21日"
+    );
+    assert_eq!(
+        Locale::ja.format_date_in(&dt, DateStyle::Short, Calendar::Japanese),
+        "R8/3/21"
+    );
+}
+
+#[test]
+fn test_localized_calendar_dates() {
+    let dt = DateTime::from_ymd(2026, 3, 21).unwrap();
+    assert_eq!(
+        Locale::th.format_date_in(&dt, DateStyle::Long, Calendar::Buddhist),
+        "21 มีนาคม 2569"
+    );
+    assert_eq!(
+        Locale::en.format_date_in(&dt, DateStyle::Long, Calendar::IslamicCivil),
+        "Shawwal 2, 1447 AH"
+    );
+    assert_eq!(
+        Locale::en.format_date_in(&dt, DateStyle::Long, Calendar::Persian),
+        "Farvardin 1, 1405 AP"
+    );
+    assert_eq!(
+        Locale::en.format_date_in(&dt, DateStyle::Long, Calendar::Gregorian),
+        Locale::en.format_with_pattern(&dt, Locale::en.date_pattern(DateStyle::Long))
+    );
+    assert_eq!(
+        Locale::en.date_pattern_in(DateStyle::Medium, Calendar::Buddhist),
+        "MMM d, y G"
+    );
+}
+
+#[test]
+fn test_calendar_ids() {
+    assert_eq!(Calendar::from_str("islamicc"), Ok(Calendar::IslamicCivil));
+    assert_eq!(Calendar::Hebrew.to_string(), "hebrew");
+    assert_eq!(
+        Calendar::from_str("coptic"),
+        Err(LocaleError::UnknownCalendar("coptic".to_string()))
+    );
+    assert_eq!(
+        Locale::from_tag_with_calendar("he-u-ca-hebrew-nu-latn"),
+        Ok((Locale::he, Calendar::Hebrew))
+    );
+    assert_eq!(Calendar::from_locale_tag("en-u-nu-latn"), Ok(None));
+    assert!(Locale::from_tag_with_calendar("en-u-ca-mayan").is_err());
+}
+
+#[test]
+fn test_calendar_tags_with_regions() {
+    let cases = [
+        ("th-TH-u-ca-buddhist", Locale::th, Calendar::Buddhist),
+        ("fa-IR-u-ca-persian", Locale::fa, Calendar::Persian),
+        ("he-IL-u-ca-hebrew", Locale::he, Calendar::Hebrew),
+        ("ja-JP-u-ca-japanese", Locale::ja, Calendar::Japanese),
+        ("ja_JP", Locale::ja, Calendar::Gregorian),
+        // Regions with a locale of their own keep it
+        ("fa-AF-u-ca-persian", Locale::fa_AF, Calendar::Persian),
+        (
+            "ar-SA-u-ca-islamic-umalqura",
+            Locale::ar_SA,
+            Calendar::IslamicUmalqura,
+        ),
+    ];
+    for (tag, locale, calendar) in cases {
+        assert_eq!(
+            Locale::from_tag_with_calendar(tag),
+            Ok((locale, calendar)),
+            "{tag}"
+        );
+    }
+    assert_eq!(
+        Locale::from_tag_with_calendar("xx-TH-u-ca-buddhist"),
+        Err(LocaleError::UnknownLocale("xx-th".to_string()))
+    );
+}
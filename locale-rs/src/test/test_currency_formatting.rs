@@ -111,3 +111,370 @@ fn test_zero_values() {
     assert_eq!(0.0.to_currency(&locale), "$0,-");
     assert_eq!(0.to_currency(&locale), "$0,-");
 }
+
+#[test]
+fn test_currency_codes_and_fraction_digits() {
+    use crate::currency_formats::Currency;
+
+    assert_eq!(Currency::from_str("eur"), Ok(Currency::EUR));
+    assert!(Currency::from_str("EURO").is_err());
+    assert_eq!(Currency::USD.to_string(), "USD");
+
+    assert_eq!(Currency::EUR.fraction_digits(), 2);
+    assert_eq!(Currency::JPY.fraction_digits(), 0);
+    assert_eq!(Currency::KWD.fraction_digits(), 3);
+
+    assert_eq!(Locale::de.default_currency(), Currency::EUR);
+    assert_eq!(Locale::ja.default_currency(), Currency::JPY);
+    assert_eq!(Locale::en.currency_symbol(Currency::USD), "$");
+}
+
+#[test]
+fn test_currency_region_uses_the_region_subtag() {
+    use crate::currency_formats::{Currency, CurrencyDate};
+
+    let date = CurrencyDate::new(2026, 1, 1);
+    assert_eq!(Locale::aa_DJ.currency_region(), "DJ");
+    assert_eq!(Locale::aa_DJ.currency_on(date), Some(Currency::DJF));
+    assert_eq!(Locale::af_NA.currency_on(date), Some(Currency::NAD));
+    assert_eq!(Locale::ar_AE.currency_on(date), Some(Currency::AED));
+    // Without a region subtag the language's likely region decides
+    assert_eq!(Locale::ar.currency_region(), "EG");
+    assert_eq!(Locale::ar.currency_on(date), Some(Currency::EGP));
+
+    // The default currency is resolved as before
+    assert_eq!(Locale::ar_AE.default_currency_symbol(), "US$");
+    assert_eq!(Locale::ar.default_currency(), Currency::EGP);
+}
+
+#[test]
+fn test_money_formatting_uses_fraction_digits() {
+    use crate::currency_formats::{Currency, Money};
+
+    let eur = Money::from_minor(123456, Currency::EUR);
+    assert_eq!(eur.format(&Locale::en), "€1,234.56");
+    assert_eq!(eur.format(&Locale::de), "1.234,56\u{a0}€");
+    assert_eq!(eur.to_currency(&Locale::en), eur.format(&Locale::en));
+    assert_eq!(format!("{}", eur.display(&Locale::en)), "€1,234.56");
+
+    // Zero-decimal and three-decimal currencies
+    assert_eq!(
+        Money::from_minor(1235, Currency::JPY).format(&Locale::en),
+        "¥1,235"
+    );
+    assert!(
+        Money::from_minor(1234, Currency::KWD)
+            .format(&Locale::en)
+            .contains("1.234")
+    );
+
+    // Whole amounts keep their minor digits and negatives get the locale sign
+    assert_eq!(
+        Money::from_minor(10000, Currency::USD).format(&Locale::en),
+        "$100.00"
+    );
+    assert_eq!(
+        Money::from_minor(-199, Currency::USD).format(&Locale::en),
+        "-$1.99"
+    );
+}
+
+#[test]
+fn test_money_format_in_numbering_system() {
+    use crate::currency_formats::{Currency, Money};
+    use crate::num_formats::NumberingSystem;
+
+    let price = Money::from_minor(-123456, Currency::EGP);
+    assert_eq!(
+        price.format_in(&Locale::ar_EG, Locale::ar_EG.default_numbering_system()),
+        price.format(&Locale::ar_EG)
+    );
+    assert_eq!(
+        price.format_in(&Locale::ar_EG, NumberingSystem::Latn),
+        "\u{200e}-\u{200f}1,234.56\u{a0}ج.م.\u{200f}"
+    );
+    // Algorithmic systems fall back to the default digits
+    assert_eq!(
+        price.format_in(&Locale::en, NumberingSystem::Roman),
+        price.format(&Locale::en)
+    );
+}
+
+#[test]
+fn test_money_from_major_rounding() {
+    use crate::currency_formats::{Currency, Money};
+
+    assert_eq!(
+        Money::from_major(1.999, Currency::EUR)
+            .unwrap()
+            .minor_units(),
+        200
+    );
+    assert_eq!(
+        Money::from_major(-2.5, Currency::JPY)
+            .unwrap()
+            .minor_units(),
+        -3
+    );
+    assert_eq!(
+        Money::from_major(12.3456, Currency::BHD)
+            .unwrap()
+            .minor_units(),
+        12346
+    );
+    // Rounded as written, not as the slightly smaller binary value
+    assert_eq!(
+        Money::from_major(1.005, Currency::EUR)
+            .unwrap()
+            .minor_units(),
+        101
+    );
+    assert_eq!(
+        Money::from_major(-0.125, Currency::USD)
+            .unwrap()
+            .minor_units(),
+        -13
+    );
+    assert_eq!(Money::from_minor(250, Currency::EUR).to_major(), 2.5);
+    assert!(Money::from_major(f64::NAN, Currency::EUR).is_err());
+    assert!(Money::from_major(1e30, Currency::EUR).is_err());
+}
+
+#[test]
+fn test_money_checked_arithmetic() {
+    use crate::currency_formats::{Currency, Money};
+    use crate::error::LocaleError;
+
+    let a = Money::from_minor(150, Currency::EUR);
+    let b = Money::from_minor(75, Currency::EUR);
+    assert_eq!(a.checked_add(&b).unwrap().minor_units(), 225);
+    assert_eq!(b.checked_sub(&a).unwrap().minor_units(), -75);
+
+    let usd = Money::from_minor(100, Currency::USD);
+    assert_eq!(
+        a.checked_add(&usd),
+        Err(LocaleError::CurrencyMismatch {
+            expected: "EUR".to_string(),
+            found: "USD".to_string()
+        })
+    );
+
+    let max = Money::from_minor(i64::MAX, Currency::EUR);
+    assert_eq!(max.checked_add(&b), Err(LocaleError::Overflow));
+}
+
+#[test]
+fn test_money_split_and_allocate() {
+    use crate::currency_formats::{Currency, Money};
+
+    let total = Money::from_minor(100, Currency::EUR);
+    let parts: Vec<i64> = total
+        .split(3)
+        .unwrap()
+        .iter()
+        .map(|m| m.minor_units())
+        .collect();
+    assert_eq!(parts, vec![34, 33, 33]);
+
+    let parts: Vec<i64> = total
+        .allocate(&[0, 70, 30])
+        .unwrap()
+        .iter()
+        .map(|m| m.minor_units())
+        .collect();
+    assert_eq!(parts, vec![0, 70, 30]);
+
+    // Remainders of negative amounts are handed out with the same sign
+    let debt = Money::from_minor(-5, Currency::EUR);
+    let parts: Vec<i64> = debt
+        .split(2)
+        .unwrap()
+        .iter()
+        .map(|m| m.minor_units())
+        .collect();
+    assert_eq!(parts, vec![-3, -2]);
+
+    assert!(total.split(0).is_err());
+    assert!(total.allocate(&[0, 0]).is_err());
+}
+
+#[test]
+fn test_region_currency_history() {
+    use crate::currency_formats::{Currency, CurrencyDate, currencies_for_region};
+
+    let codes = |region: &str, date: CurrencyDate| -> Vec<Currency> {
+        currencies_for_region(region, date)
+            .iter()
+            .map(|p| p.currency)
+            .collect()
+    };
+
+    assert_eq!(
+        codes("DE", CurrencyDate::new(1998, 12, 31)),
+        vec![Currency::DEM]
+    );
+    assert_eq!(
+        codes("de", CurrencyDate::new(1999, 6, 1)),
+        vec![Currency::EUR, Currency::DEM]
+    );
+    assert_eq!(
+        codes("DE", CurrencyDate::new(2002, 3, 1)),
+        vec![Currency::EUR]
+    );
+
+    // Non-tender funds codes are listed after legal tender.
+    let us = currencies_for_region("US", CurrencyDate::new(2026, 1, 1));
+    assert_eq!(us[0].currency, Currency::USD);
+    assert!(us[0].tender);
+    assert!(us[1..].iter().all(|p| !p.tender));
+
+    assert!(currencies_for_region("ZZ", CurrencyDate::new(2026, 1, 1)).is_empty());
+}
+
+#[test]
+fn test_locale_currency_by_date() {
+    use crate::currency_formats::{Currency, CurrencyDate};
+
+    assert_eq!(Locale::de_AT.currency_region(), "AT");
+    assert_eq!(
+        Locale::de_AT.currency_on(CurrencyDate::new(2026, 5, 1)),
+        Some(Currency::EUR)
+    );
+    assert_eq!(
+        Locale::de_AT.currency_on(CurrencyDate::new(1998, 5, 1)),
+        Some(Currency::ATS)
+    );
+    assert_eq!(
+        Locale::de.currency_on(CurrencyDate::new(1998, 5, 1)),
+        Some(Currency::DEM)
+    );
+    assert_eq!(
+        Locale::de.currency_on(CurrencyDate::new(2026, 5, 1)),
+        Some(Currency::EUR)
+    );
+}
+
+#[test]
+fn test_convert_with_static_rates() {
+    use crate::currency_formats::{Currency, ExchangeRateProvider, Money, StaticRateProvider};
+    use crate::error::LocaleError;
+
+    let rates = StaticRateProvider::new()
+        .with_rate(Currency::EUR, Currency::USD, 1.25)
+        .unwrap()
+        .with_rate(Currency::USD, Currency::KWD, 0.3)
+        .unwrap();
+
+    assert_eq!(rates.rate(Currency::USD, Currency::EUR), Some(0.8));
+    assert_eq!(rates.rate(Currency::GBP, Currency::USD), None);
+
+    // Rounded to the target currency's fraction digits.
+    let price = Money::from_minor(1999, Currency::EUR);
+    assert_eq!(
+        price.convert(Currency::USD, &rates),
+        Ok(Money::from_minor(2499, Currency::USD))
+    );
+    assert_eq!(
+        price.convert(Currency::KWD, &rates),
+        Ok(Money::from_minor(7496, Currency::KWD))
+    );
+    assert_eq!(price.convert(Currency::EUR, &rates), Ok(price));
+
+    assert_eq!(
+        price.convert(Currency::GBP, &rates),
+        Err(LocaleError::ExchangeRateUnavailable {
+            from: "EUR".to_string(),
+            to: "GBP".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_static_rates_reject_invalid_values() {
+    use crate::currency_formats::{Currency, ExchangeRateProvider, StaticRateProvider};
+
+    let mut rates = StaticRateProvider::new();
+    for rate in [0.0, -1.25, f64::NAN, f64::INFINITY] {
+        assert!(rates.insert(Currency::EUR, Currency::USD, rate).is_err());
+    }
+    assert!(
+        StaticRateProvider::new()
+            .with_rate(Currency::EUR, Currency::USD, 0.0)
+            .is_err()
+    );
+    assert!(rates.is_empty());
+    assert_eq!(rates.rate(Currency::USD, Currency::EUR), None);
+}
+
+#[test]
+fn test_file_rate_providers() {
+    use crate::currency_formats::{Currency, ExchangeRateProvider, FileRateProvider, Money};
+
+    let csv = "from,to,rate\n# ECB reference rates\nEUR,USD,1.0812\n\nEUR, JPY, 162.30\n";
+    let rates = FileRateProvider::from_csv_str(csv).unwrap();
+    assert_eq!(rates.table().len(), 2);
+    assert_eq!(rates.rate(Currency::EUR, Currency::JPY), Some(162.30));
+    assert!(FileRateProvider::from_csv_str("EUR,USD,abc").is_err());
+    assert!(FileRateProvider::from_csv_str("EUR,XYZ1,1.0").is_err());
+    assert!(FileRateProvider::from_csv_str("EUR,USD,0").is_err());
+
+    // Only a header in place of the first rate is skipped.
+    assert!(FileRateProvider::from_csv_str("from,to,rate\nbase,quote,rate\nEUR,USD,1.1").is_err());
+    assert!(FileRateProvider::from_csv_str("EUR,USD,1.1\nfrom,to,rate").is_err());
+    assert!(FileRateProvider::from_csv_str("EURO,USD,abc\nEUR,USD,1.1").is_err());
+
+    let path = std::env::temp_dir().join(format!("locale-rs-rates-{}.csv", std::process::id()));
+    std::fs::write(&path, csv).unwrap();
+    let from_file = FileRateProvider::open(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file.unwrap().table().len(), 2);
+    assert!(FileRateProvider::open("rates.txt").is_err());
+}
+
+#[test]
+#[cfg(not(feature = "json"))]
+fn test_json_rates_need_the_json_feature() {
+    use crate::currency_formats::FileRateProvider;
+
+    let path = std::env::temp_dir().join(format!("locale-rs-rates-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{ "base": "EUR", "rates": { "USD": 1.1 } }"#).unwrap();
+    let result = FileRateProvider::open(&path);
+    std::fs::remove_file(&path).unwrap();
+    let message = result.unwrap_err().to_string();
+    assert!(
+        message.contains("requires the `json` feature"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_json_rate_provider() {
+    use crate::currency_formats::{Currency, ExchangeRateProvider, FileRateProvider, Money};
+
+    let json = r#"{ "base": "EUR", "rates": { "USD": 1.0812, "JPY": 162.30 } }"#;
+    let rates = FileRateProvider::from_json_str(json).unwrap();
+    assert_eq!(
+        Money::from_minor(10000, Currency::USD).convert(Currency::JPY, &rates),
+        Ok(Money::from_minor(15011, Currency::JPY))
+    );
+    assert!(FileRateProvider::from_json_str(r#"{ "rates": {} }"#).is_err());
+    assert!(
+        FileRateProvider::from_json_str(r#"{ "base": "EUR", "rates": { "USD": -1 } }"#).is_err()
+    );
+
+    // A typical rate API response with fields other than the rates
+    let api = r#"{
+        "success": true,
+        "timestamp": 1760000000,
+        "base": "EUR",
+        "date": "2026-10-19",
+        "note": "ECB \"reference\" rates",
+        "sources": ["ecb", null],
+        "rates": { "USD": 1.1, "GBP": 0.87 }
+    }"#;
+    let rates = FileRateProvider::from_json_str(api).unwrap();
+    assert_eq!(rates.table().len(), 2);
+    assert_eq!(rates.rate(Currency::EUR, Currency::USD), Some(1.1));
+}
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use crate::AVAILABLE_LOCALES;
-use crate::datetime_formats::DateTime;
+use crate::datetime_formats::{DateTime, WeekData};
 use crate::locale::Locale;
 
 fn base_dt() -> DateTime {
@@ -88,6 +88,32 @@ fn test_arabic_digit_translation() {
     assert!(formatted.contains('\u{0662}'));
 }
 
+#[test]
+#[cfg(feature = "nums")]
+fn test_numbering_system_digits() {
+    use crate::datetime_formats::{DateStyle, TimeStyle};
+    use crate::num_formats::NumberingSystem;
+
+    let dt = base_dt();
+    let latn = Locale::ar_EG.format_datetime_in_numbering_system(
+        &dt,
+        DateStyle::Short,
+        TimeStyle::Short,
+        NumberingSystem::Latn,
+    );
+    assert!(latn.chars().any(|c| c.is_ascii_digit()));
+    assert!(!latn.contains('\u{0662}'));
+    assert_eq!(
+        Locale::th.format_with_pattern_in_numbering_system(&dt, "y", NumberingSystem::Thai),
+        "๒๐๒๖"
+    );
+    // Algorithmic systems keep the locale's digits
+    assert_eq!(
+        Locale::en.format_with_pattern_in_numbering_system(&dt, "y", NumberingSystem::Roman),
+        Locale::en.format_with_pattern(&dt, "y")
+    );
+}
+
 #[test]
 fn test_exhaustive_branch_coverage() {
     let dt = base_dt();
@@ -112,3 +138,912 @@ fn test_datetime_struct_properties() {
     assert_eq!(dt.year, dt2.year);
     assert_eq!(dt.month, dt2.month);
 }
+
+#[test]
+fn test_skeleton_matching() {
+    let dt = base_dt();
+
+    assert_eq!(Locale::en.format_skeleton(&dt, "MMMd"), "Jan 3");
+    assert_eq!(Locale::de.format_skeleton(&dt, "MMMMd"), "3. Januar");
+    assert_eq!(
+        Locale::fr.format_skeleton(&dt, "yMMMMEEEEd"),
+        "samedi 3 janvier 2026"
+    );
+    assert_eq!(Locale::ja.format_skeleton(&dt, "yMMMd"), "2026年1月3日");
+
+    // `j` resolves to the locale's preferred hour symbol.
+    assert_eq!(Locale::en.pattern_for_skeleton("jm"), "h:mm\u{202f}a");
+    assert_eq!(Locale::de.pattern_for_skeleton("jm"), "HH:mm");
+}
+
+#[test]
+fn test_skeleton_width_adjustment() {
+    let dt = base_dt();
+
+    // No "MMMMyyyy" entry exists, so "yMMMM" is stretched to the request.
+    assert_eq!(Locale::en.pattern_for_skeleton("MMMMyyyy"), "MMMM yyyy");
+    assert_eq!(Locale::en.format_skeleton(&dt, "yyMMdd"), "01/03/26");
+
+    // Numeric months are not turned into names and vice versa.
+    assert_eq!(Locale::ja.pattern_for_skeleton("MMMMd"), "M月d日");
+}
+
+#[test]
+fn test_skeleton_date_time_combination() {
+    let dt = base_dt();
+
+    assert_eq!(
+        Locale::en.format_skeleton(&dt, "yMMMdjm"),
+        "Jan 3, 2026, 2:05\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::de.format_skeleton(&dt, "yMMMdHm"),
+        "3. Jan. 2026, 14:05"
+    );
+
+    for locale in AVAILABLE_LOCALES {
+        let loc = Locale::from_str(locale).unwrap();
+        assert!(!loc.format_skeleton(&dt, "yMMMdjms").is_empty());
+    }
+}
+
+#[test]
+fn test_date_and_time_styles() {
+    use crate::datetime_formats::{DateStyle, TimeStyle};
+    let dt = base_dt();
+
+    assert_eq!(
+        Locale::en.format_date_with_style(&dt, DateStyle::Short),
+        "1/3/26"
+    );
+    assert_eq!(
+        Locale::en.format_date_with_style(&dt, DateStyle::Full),
+        "Saturday, January 3, 2026"
+    );
+    assert_eq!(
+        Locale::de.format_date_with_style(&dt, DateStyle::Long),
+        "3. Januar 2026"
+    );
+    assert_eq!(
+        Locale::de.format_time_with_style(&dt, TimeStyle::Short),
+        "14:05"
+    );
+
+    // Medium is what format_date/format_time have always used.
+    assert_eq!(
+        Locale::fr.format_date_with_style(&dt, DateStyle::Medium),
+        Locale::fr.format_date(&dt)
+    );
+    assert_eq!(
+        Locale::fr.time_pattern(TimeStyle::Medium),
+        Locale::fr.time_format_pattern()
+    );
+}
+
+#[test]
+fn test_combined_datetime_format() {
+    use crate::datetime_formats::{DateStyle, TimeStyle};
+    let dt = base_dt();
+
+    assert_eq!(
+        Locale::en.format_datetime(&dt, DateStyle::Long, TimeStyle::Short),
+        "January 3, 2026 at 2:05\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::en.format_datetime(&dt, DateStyle::Short, TimeStyle::Short),
+        "1/3/26, 2:05\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::de.format_datetime(&dt, DateStyle::Full, TimeStyle::Medium),
+        "Samstag, 3. Januar 2026 um 14:05:09"
+    );
+    assert_eq!(
+        Locale::en.datetime_format_pattern(DateStyle::Full),
+        "{1}, {0}"
+    );
+
+    for locale in AVAILABLE_LOCALES {
+        let loc = Locale::from_str(locale).unwrap();
+        let out = loc.format_datetime(&dt, DateStyle::Medium, TimeStyle::Short);
+        assert!(
+            !out.contains("{0}") && !out.contains("{1}"),
+            "{}: {}",
+            locale,
+            out
+        );
+    }
+}
+
+#[test]
+fn test_long_and_full_times_without_a_zone() {
+    use crate::datetime_formats::{DateStyle, TimeStyle};
+    let dt = base_dt();
+
+    // A DateTime has no zone to name, so zone fields and their spacing go
+    assert_eq!(
+        Locale::en.format_time_with_style(&dt, TimeStyle::Full),
+        "2:05:09\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::en.format_datetime(&dt, DateStyle::Full, TimeStyle::Long),
+        "Saturday, January 3, 2026 at 2:05:09\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::es.format_time_with_style(&dt, TimeStyle::Full),
+        "14:05:09"
+    );
+    assert_eq!(
+        Locale::bg.format_time_with_style(&dt, TimeStyle::Long),
+        "14:05:09 ч."
+    );
+    assert_eq!(Locale::en.format_with_pattern(&dt, "zzzz, d MMM"), "3 Jan");
+
+    for locale in AVAILABLE_LOCALES {
+        let loc = Locale::from_str(locale).unwrap();
+        let out = loc.format_time_with_style(&dt, TimeStyle::Full);
+        assert!(
+            !out.contains("GMT") && !out.contains("UTC") && out.trim() == out,
+            "{}: {}",
+            locale,
+            out
+        );
+    }
+
+    // Zoned values keep the zone
+    let zoned = crate::time_zones::ZonedDateTime::with_offset(dt, 0);
+    assert_eq!(
+        Locale::en.format_zoned(&zoned, DateStyle::Medium, TimeStyle::Long),
+        "Jan 3, 2026, 2:05:09\u{202f}PM GMT"
+    );
+}
+
+#[test]
+fn test_standalone_and_width_fields() {
+    let dt = base_dt();
+
+    // Stand-alone (nominative) vs format (genitive) month names.
+    assert_eq!(Locale::pl.format_with_pattern(&dt, "LLLL"), "styczeń");
+    assert_eq!(Locale::pl.format_with_pattern(&dt, "d MMMM"), "3 stycznia");
+    assert_eq!(Locale::ru.format_with_pattern(&dt, "LLLL"), "январь");
+
+    assert_eq!(
+        Locale::en.format_with_pattern(&dt, "EEE EEEEE EEEEEE EEEE"),
+        "Sat S Sa Saturday"
+    );
+    assert_eq!(
+        Locale::de.format_with_pattern(&dt, "cccc ccc"),
+        "Samstag Sa"
+    );
+    assert_eq!(Locale::en.format_with_pattern(&dt, "MMMMM"), "J");
+}
+
+#[test]
+fn test_era_quarter_and_week_fields() {
+    let dt = base_dt();
+
+    assert_eq!(
+        Locale::en.format_with_pattern(&dt, "G GGGG GGGGG"),
+        "AD Anno Domini A"
+    );
+    assert_eq!(
+        Locale::en.format_with_pattern(&dt, "Q QQ QQQ QQQQ"),
+        "1 01 Q1 1st quarter"
+    );
+    assert_eq!(Locale::fr.format_with_pattern(&dt, "QQQQ"), "1er trimestre");
+    assert_eq!(Locale::en.format_with_pattern(&dt, "w D F Y"), "1 3 1 2026");
+
+    // 2026 has 53 ISO weeks, while US weeks put Dec 31 into week 1 of 2027;
+    // years before 1 use era years.
+    let dec = DateTime {
+        year: 2026,
+        month: 12,
+        day: 31,
+        ..base_dt()
+    };
+    assert_eq!(
+        Locale::en_GB.format_with_pattern(&dec, "Y w D"),
+        "2026 53 365"
+    );
+    assert_eq!(Locale::en.format_with_pattern(&dec, "Y w D"), "2027 1 365");
+    let bc = DateTime {
+        year: -43,
+        month: 3,
+        day: 15,
+        ..base_dt()
+    };
+    assert_eq!(Locale::en.format_with_pattern(&bc, "y G"), "44 BC");
+
+    // Extended, cyclic and related Gregorian years; modified Julian day
+    assert_eq!(
+        Locale::en.format_with_pattern(&dt, "u U r"),
+        "2026 2026 2026"
+    );
+    assert_eq!(Locale::en.format_with_pattern(&dt, "UU"), "26");
+    assert_eq!(Locale::en.format_with_pattern(&dt, "g"), "61043");
+    let mjd_epoch = DateTime {
+        year: 1858,
+        month: 11,
+        day: 17,
+        ..base_dt()
+    };
+    assert_eq!(Locale::en.format_with_pattern(&mjd_epoch, "g"), "0");
+    assert_eq!(Locale::en.format_with_pattern(&bc, "u r"), "-43 -43");
+}
+
+#[test]
+fn test_hour_second_and_zone_fields() {
+    let dt = base_dt();
+
+    assert_eq!(Locale::en.format_with_pattern(&dt, "k K h H"), "14 2 2 14");
+    let midnight = DateTime {
+        hour: 0,
+        ..base_dt()
+    };
+    assert_eq!(
+        Locale::en.format_with_pattern(&midnight, "k K h H"),
+        "24 0 12 0"
+    );
+    assert_eq!(Locale::en.format_with_pattern(&dt, "ss.SSS"), "09.000");
+    // Zone fields need a zone, which a DateTime does not have
+    assert_eq!(Locale::en.format_with_pattern(&dt, "HH:mm Z"), "14:05");
+    let utc = crate::time_zones::ZonedDateTime::with_offset(dt, 0);
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&utc, "Z X O"),
+        "+0000 Z GMT"
+    );
+    assert_eq!(
+        Locale::en.format_with_pattern(&dt, "h B"),
+        "2 in the afternoon"
+    );
+    assert_eq!(Locale::en.format_with_pattern(&dt, "aaaaa"), "p");
+
+    // Quoted text is copied verbatim.
+    assert_eq!(
+        Locale::en.format_with_pattern(&dt, "'Q' Q 'o''clock'"),
+        "Q 1 o'clock"
+    );
+}
+
+#[test]
+fn test_parse_date_round_trip() {
+    use crate::datetime_formats::DateStyle;
+    let dt = DateTime {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        ..base_dt()
+    };
+
+    for name in AVAILABLE_LOCALES {
+        let loc = Locale::from_str(name).unwrap();
+        for style in [
+            DateStyle::Short,
+            DateStyle::Medium,
+            DateStyle::Long,
+            DateStyle::Full,
+        ] {
+            let formatted = loc.format_date_with_style(&dt, style);
+            assert_eq!(
+                loc.parse_with_pattern(&formatted, loc.date_pattern(style)),
+                Ok(dt),
+                "{name} {formatted:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_date_spreadsheet_inputs() {
+    let expected = DateTime {
+        year: 2026,
+        month: 10,
+        day: 18,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+    assert_eq!(Locale::de.parse_date("18.10.2026"), Ok(expected));
+    assert_eq!(Locale::en.parse_date("10/18/26"), Ok(expected));
+    assert_eq!(Locale::fr.parse_date("18 oct. 2026"), Ok(expected));
+    // Case and the abbreviation's period don't matter
+    assert_eq!(Locale::fr.parse_date("18 OCT 2026"), Ok(expected));
+    assert_eq!(Locale::en.parse_date("  october 18, 2026 "), Ok(expected));
+    // Native digits
+    #[cfg(feature = "nums")]
+    assert_eq!(Locale::ar_EG.parse_date("١٨/١٠/٢٠٢٦"), Ok(expected));
+
+    // Adjacent numeric fields use their pattern width
+    assert_eq!(
+        Locale::en.parse_with_pattern("20261018", "yyyyMMdd"),
+        Ok(expected)
+    );
+}
+
+#[test]
+fn test_parse_time_and_markers() {
+    let parsed = Locale::en.parse_time("2:05 PM").unwrap();
+    assert_eq!((parsed.hour, parsed.minute), (14, 5));
+    let parsed = Locale::en.parse_time("12:30:15\u{202f}am").unwrap();
+    assert_eq!((parsed.hour, parsed.minute, parsed.second), (0, 30, 15));
+    let parsed = Locale::de.parse_time("23:59").unwrap();
+    assert_eq!((parsed.year, parsed.hour, parsed.minute), (1970, 23, 59));
+
+    let bc = Locale::en.parse_with_pattern("44 BC", "y G").unwrap();
+    assert_eq!(bc.year, -43);
+}
+
+#[test]
+fn test_parse_errors_are_positioned() {
+    use crate::error::LocaleError;
+    let error = |position: usize, reason: &str| {
+        Err(LocaleError::DateTimeParse {
+            position,
+            reason: reason.to_string(),
+        })
+    };
+
+    assert_eq!(
+        Locale::en.parse_with_pattern("2026-13-01", "y-MM-dd"),
+        error(5, "month out of range")
+    );
+    assert_eq!(
+        Locale::en.parse_with_pattern("2025-02-29", "y-MM-dd"),
+        error(8, "day out of range")
+    );
+    assert!(
+        Locale::en
+            .parse_with_pattern("2024-02-29", "y-MM-dd")
+            .is_ok()
+    );
+    assert_eq!(
+        Locale::en.parse_with_pattern("2026/10/18", "y-MM-dd"),
+        error(4, "expected '-'")
+    );
+    assert_eq!(
+        Locale::en.parse_with_pattern("18 Foo 2026", "d MMM y"),
+        error(3, "expected a name for 'MMM'")
+    );
+    assert_eq!(
+        Locale::en.parse_with_pattern("18 Oct 2026 extra", "d MMM y"),
+        error(12, "unexpected trailing text")
+    );
+    // parse_date reports the pattern that matched furthest
+    assert_eq!(
+        Locale::en.parse_date("October 18, 20x6"),
+        error(14, "unexpected trailing text")
+    );
+    // unless a pattern matched and only a field was out of range
+    assert_eq!(
+        Locale::en.parse_time("13:05 PM"),
+        error(0, "hour out of range")
+    );
+    assert_eq!(
+        Locale::en.parse_date("2/30/26"),
+        error(2, "day out of range")
+    );
+}
+
+#[test]
+fn test_parse_two_digit_years() {
+    use crate::error::LocaleError;
+
+    assert_eq!(Locale::en.parse_date("1/2/49").unwrap().year, 2049);
+    assert_eq!(Locale::en.parse_date("1/2/50").unwrap().year, 1950);
+    // Four digits are taken as written
+    assert_eq!(Locale::en.parse_date("1/2/1849").unwrap().year, 1849);
+
+    let parse = |input: &str, century_start: i32| {
+        Locale::en
+            .parse_with_pattern_and_century(input, "M/d/yy", century_start)
+            .map(|dt| dt.year)
+    };
+    assert_eq!(parse("1/2/49", 1900), Ok(1949));
+    assert_eq!(parse("1/2/99", 2000), Ok(2099));
+    assert_eq!(parse("1/2/05", 1906), Ok(2005));
+    assert_eq!(parse("1/2/06", 1906), Ok(1906));
+    assert_eq!(parse("1/2/26", -150), Ok(-74));
+    assert_eq!(
+        parse("1/2/99", i32::MAX),
+        Err(LocaleError::DateTimeParse {
+            position: 4,
+            reason: "year out of range".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_checked_construction() {
+    use crate::error::LocaleError;
+
+    assert_eq!(DateTime::new(2026, 1, 3, 14, 5, 9), Ok(base_dt()));
+    assert!(DateTime::from_ymd(2000, 2, 29).is_ok());
+    assert!(DateTime::from_ymd(1900, 2, 29).is_err());
+    assert!(DateTime::from_ymd(2026, 4, 31).is_err());
+    assert!(DateTime::new(2026, 1, 3, 24, 0, 0).is_err());
+    assert_eq!(
+        DateTime::from_ymd(2026, 13, 1),
+        Err(LocaleError::InvalidDateTime(
+            "month 13 is out of range".to_string()
+        ))
+    );
+
+    let dt = base_dt();
+    assert_eq!((dt.year(), dt.month(), dt.day()), (2026, 1, 3));
+    assert_eq!((dt.hour(), dt.minute(), dt.second()), (14, 5, 9));
+}
+
+#[test]
+fn test_calendar_accessors() {
+    let dt = base_dt();
+    assert_eq!(dt.weekday(), 6); // Saturday
+    assert_eq!(Locale::en.days_wide()[dt.weekday() as usize], "Saturday");
+    assert_eq!(dt.day_of_year(), 3);
+    assert!(!dt.is_leap_year());
+    assert_eq!(dt.days_in_month(), 31);
+
+    let leap_day = DateTime::from_ymd(2024, 12, 31).unwrap();
+    assert_eq!(leap_day.day_of_year(), 366);
+    assert_eq!(DateTime::from_ymd(2024, 2, 1).unwrap().days_in_month(), 29);
+    // Proleptic Gregorian dates before the common era
+    assert_eq!(DateTime::from_ymd(-43, 3, 15).unwrap().weekday(), 5);
+    assert_eq!(DateTime::from_ymd(1970, 1, 1).unwrap().weekday(), 4);
+}
+
+#[test]
+fn test_calendar_arithmetic() {
+    use crate::error::LocaleError;
+    use std::time::Duration;
+
+    let dt = base_dt();
+    assert_eq!(
+        dt.checked_add_days(365),
+        DateTime::new(2027, 1, 3, 14, 5, 9)
+    );
+    assert_eq!(
+        dt.checked_sub_days(3),
+        DateTime::new(2025, 12, 31, 14, 5, 9)
+    );
+    assert_eq!(
+        dt.checked_add_months(-13),
+        DateTime::new(2024, 12, 3, 14, 5, 9)
+    );
+    assert_eq!(
+        DateTime::from_ymd(2024, 2, 29)
+            .unwrap()
+            .checked_add_months(12),
+        DateTime::from_ymd(2025, 2, 28)
+    );
+    assert_eq!(
+        dt.checked_add(Duration::from_secs(10 * 3600)),
+        DateTime::new(2026, 1, 4, 0, 5, 9)
+    );
+    assert_eq!(
+        dt.checked_sub(Duration::from_millis(9_999)),
+        DateTime::new(2026, 1, 3, 14, 5, 0)
+    );
+
+    let max = DateTime::new(i32::MAX, 12, 31, 0, 0, 0).unwrap();
+    assert_eq!(max.checked_add_days(1), Err(LocaleError::Overflow));
+    assert_eq!(max.checked_add_months(1), Err(LocaleError::Overflow));
+    assert_eq!(
+        dt.checked_add(Duration::from_secs(u64::MAX)),
+        Err(LocaleError::Overflow)
+    );
+}
+
+#[test]
+fn test_datetime_ordering() {
+    let dt = base_dt();
+    let later = dt.checked_add(std::time::Duration::from_secs(1)).unwrap();
+    let next_year = DateTime::from_ymd(2027, 1, 1).unwrap();
+    assert!(dt < later);
+    assert!(later < next_year);
+
+    let mut dates = vec![next_year, later, dt];
+    dates.sort();
+    assert_eq!(dates, vec![dt, later, next_year]);
+}
+
+#[test]
+fn test_unix_timestamp_and_system_time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let dt = base_dt();
+    assert_eq!(DateTime::from_unix_timestamp(dt.unix_timestamp()), Ok(dt));
+    assert_eq!(
+        DateTime::from_unix_timestamp(-1),
+        DateTime::new(1969, 12, 31, 23, 59, 59)
+    );
+    assert!(DateTime::from_unix_timestamp(i64::MAX).is_err());
+
+    let system = UNIX_EPOCH + Duration::from_millis(1_767_449_109_500);
+    assert_eq!(DateTime::from(system), dt);
+    assert_eq!(
+        SystemTime::try_from(dt),
+        Ok(UNIX_EPOCH + Duration::from_secs(1_767_449_109))
+    );
+    // Times before the epoch round down to the whole second
+    let before = UNIX_EPOCH - Duration::from_millis(500);
+    assert_eq!(
+        DateTime::from(before),
+        DateTime::new(1969, 12, 31, 23, 59, 59).unwrap()
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_conversions() {
+    use crate::time_zones::ZonedDateTime;
+    use chrono::{FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+    let naive = NaiveDate::from_ymd_opt(2026, 1, 3)
+        .unwrap()
+        .and_hms_milli_opt(14, 5, 9, 750)
+        .unwrap();
+    assert_eq!(DateTime::from(naive), base_dt());
+    assert_eq!(
+        NaiveDateTime::try_from(base_dt()),
+        Ok(naive.with_nanosecond(0).unwrap())
+    );
+    assert_eq!(
+        DateTime::from(naive.date()),
+        DateTime::from_ymd(2026, 1, 3).unwrap()
+    );
+
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let aware = offset.from_local_datetime(&naive).unwrap();
+    assert_eq!(DateTime::from(aware), base_dt());
+    let zoned = ZonedDateTime::from(aware);
+    assert_eq!(zoned.offset.seconds, 7200);
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&zoned, "HH:mm O"),
+        "14:05 GMT+2"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_conversions() {
+    use crate::time_zones::ZonedDateTime;
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    let date = Date::from_calendar_date(2026, Month::January, 3).unwrap();
+    let primitive = PrimitiveDateTime::new(date, Time::from_hms_milli(14, 5, 9, 750).unwrap());
+    assert_eq!(DateTime::from(primitive), base_dt());
+    assert_eq!(
+        PrimitiveDateTime::try_from(base_dt()),
+        Ok(PrimitiveDateTime::new(
+            date,
+            Time::from_hms(14, 5, 9).unwrap()
+        ))
+    );
+    assert_eq!(
+        DateTime::from(date),
+        DateTime::from_ymd(2026, 1, 3).unwrap()
+    );
+
+    let offset = primitive.assume_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+    assert_eq!(DateTime::from(offset), base_dt());
+    let zoned = ZonedDateTime::from(offset);
+    assert_eq!(zoned.offset.seconds, -18000);
+    assert_eq!(zoned.to_utc(), DateTime::new(2026, 1, 3, 19, 5, 9).unwrap());
+}
+
+#[test]
+fn test_format_interval_collapses_shared_fields() {
+    let start = DateTime::from_ymd(2026, 1, 3).unwrap();
+    let cases = [
+        (Locale::en, (2026, 1, 7), "Jan 3\u{2009}–\u{2009}7, 2026"),
+        (
+            Locale::en,
+            (2026, 2, 7),
+            "Jan 3\u{2009}–\u{2009}Feb 7, 2026",
+        ),
+        (
+            Locale::en,
+            (2027, 2, 7),
+            "Jan 3, 2026\u{2009}–\u{2009}Feb 7, 2027",
+        ),
+        (Locale::en_GB, (2026, 1, 7), "3–7 Jan 2026"),
+        (Locale::de, (2026, 1, 7), "3.–7. Jan. 2026"),
+        (Locale::ja, (2026, 2, 7), "2026年1月3日～2月7日"),
+    ];
+    for (locale, (year, month, day), expected) in cases {
+        let end = DateTime::from_ymd(year, month, day).unwrap();
+        assert_eq!(
+            locale.format_interval(&start, &end, "yMMMd"),
+            expected,
+            "{locale:?}"
+        );
+    }
+
+    // Field widths follow the requested skeleton
+    let end = DateTime::from_ymd(2026, 1, 7).unwrap();
+    assert_eq!(
+        Locale::fr.format_interval(&start, &end, "yMMMMEEEEd"),
+        "samedi 3\u{2009}–\u{2009}mercredi 7 janvier 2026"
+    );
+    // Nothing the skeleton shows differs
+    let later = DateTime::new(2026, 1, 3, 18, 0, 0).unwrap();
+    assert_eq!(
+        Locale::en.format_interval(&start, &later, "yMMMd"),
+        "Jan 3, 2026"
+    );
+}
+
+#[test]
+fn test_format_interval_reversed_range() {
+    let start = DateTime::from_ymd(2026, 1, 3).unwrap();
+    let end = DateTime::from_ymd(2026, 2, 7).unwrap();
+    assert_eq!(
+        Locale::en.format_interval(&end, &start, "yMMMd"),
+        "Jan 3\u{2009}–\u{2009}Feb 7, 2026"
+    );
+    assert_eq!(
+        Locale::en.format_interval(&end, &start, "yMMMd"),
+        Locale::en.format_interval(&start, &end, "yMMMd")
+    );
+
+    let morning = DateTime::new(2026, 1, 3, 10, 0, 0).unwrap();
+    let afternoon = DateTime::new(2026, 1, 3, 14, 30, 0).unwrap();
+    assert_eq!(
+        Locale::en.format_interval(&afternoon, &morning, "yMMMdhm"),
+        "Jan 3, 2026, 10:00\u{202f}AM\u{2009}–\u{2009}2:30\u{202f}PM"
+    );
+}
+
+#[test]
+fn test_format_interval_times() {
+    let at = |day, hour, minute| DateTime::new(2026, 1, day, hour, minute, 0).unwrap();
+    assert_eq!(
+        Locale::en.format_interval(&at(3, 10, 0), &at(3, 11, 30), "jm"),
+        "10:00\u{2009}–\u{2009}11:30\u{202f}AM"
+    );
+    assert_eq!(
+        Locale::en.format_interval(&at(3, 10, 0), &at(3, 14, 0), "jm"),
+        "10:00\u{202f}AM\u{2009}–\u{2009}2:00\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::de.format_interval(&at(3, 10, 0), &at(3, 14, 0), "jm"),
+        "10:00–14:00 Uhr"
+    );
+
+    // A date with times on one day shows the date once
+    assert_eq!(
+        Locale::en.format_interval(&at(3, 10, 0), &at(3, 14, 30), "yMMMdhm"),
+        "Jan 3, 2026, 10:00\u{202f}AM\u{2009}–\u{2009}2:30\u{202f}PM"
+    );
+    assert_eq!(
+        Locale::en.format_interval(&at(3, 10, 0), &at(3, 11, 30), "yMMMdjm"),
+        "Jan 3, 2026, 10:00\u{2009}–\u{2009}11:30\u{202f}AM"
+    );
+    assert_eq!(
+        Locale::de.format_interval(&at(3, 10, 0), &at(3, 14, 0), "yMMMdHm"),
+        "3. Jan. 2026, 10:00–14:00 Uhr"
+    );
+    // Quoted text in the joining pattern stays literal
+    assert_eq!(
+        Locale::nn.format_interval(&at(3, 10, 0), &at(3, 14, 0), "yMMMMdHm"),
+        "3. januar 2026 kl. 10:00–14:00"
+    );
+    // Different days still repeat the date
+    assert_eq!(
+        Locale::en.format_interval(&at(3, 10, 0), &at(4, 14, 30), "yMMMdhm"),
+        "Jan 3, 2026, 10:00\u{202f}AM\u{2009}–\u{2009}Jan 4, 2026, 2:30\u{202f}PM"
+    );
+}
+
+#[test]
+fn test_format_interval_fallback() {
+    // Days differ on a time-only skeleton: both ends get a date
+    let start = DateTime::new(2026, 1, 3, 10, 0, 0).unwrap();
+    let end = DateTime::new(2026, 1, 4, 11, 30, 0).unwrap();
+    assert_eq!(
+        Locale::en_GB.format_interval(&start, &end, "jm"),
+        "03/01/2026, 10:00\u{2009}–\u{2009}04/01/2026, 11:30"
+    );
+    // Years differ on a skeleton without a year
+    let start = DateTime::from_ymd(2025, 12, 30).unwrap();
+    let end = DateTime::from_ymd(2026, 1, 2).unwrap();
+    assert_eq!(
+        Locale::de.format_interval(&start, &end, "MMMd"),
+        "30. Dez. 2025\u{2009}–\u{2009}2. Jan. 2026"
+    );
+    assert_eq!(Locale::ja.interval_format_fallback(), "{0}～{1}");
+}
+
+#[test]
+fn test_week_data() {
+    assert_eq!(Locale::de.first_day_of_week(), 1);
+    assert_eq!(Locale::en.first_day_of_week(), 0);
+    assert_eq!(Locale::ar_EG.first_day_of_week(), 6);
+    assert_eq!(Locale::de.week_data(), WeekData::ISO);
+    assert_eq!(Locale::en.week_data().min_days, 1);
+
+    // Friday is a weekend day in Egypt, Sunday is not
+    let friday = DateTime::from_ymd(2026, 1, 2).unwrap();
+    let sunday = DateTime::from_ymd(2026, 1, 4).unwrap();
+    assert!(Locale::ar_EG.is_weekend(&friday));
+    assert!(!Locale::ar_EG.is_weekend(&sunday));
+    assert!(Locale::en.is_weekend(&sunday));
+    assert!(!Locale::en.is_weekend(&friday));
+    assert_eq!(WeekData::for_region("zz"), WeekData::for_region("001"));
+
+    // Jan 1 2027 is a Friday: week 1 in the US, still week 53 of 2026 in ISO
+    let new_year = DateTime::from_ymd(2027, 1, 1).unwrap();
+    assert_eq!(new_year.iso_week(), (2026, 53));
+    assert_eq!(new_year.week_of_year(&Locale::en.week_data()), (2027, 1));
+
+    // The numeric weekday counts from the locale's first day
+    assert_eq!(Locale::de.format_with_pattern(&sunday, "e"), "7");
+    assert_eq!(Locale::en.format_with_pattern(&sunday, "e"), "1");
+    assert_eq!(Locale::en.format_with_pattern(&sunday, "W"), "2");
+}
+
+#[test]
+fn test_hour_cycles() {
+    use crate::datetime_formats::{HourCycle, TimeStyle};
+    use crate::error::LocaleError;
+
+    assert_eq!(Locale::en.hour_cycle(), HourCycle::H12);
+    assert_eq!(Locale::de.hour_cycle(), HourCycle::H23);
+    assert_eq!(Locale::en_GB.hour_cycle(), HourCycle::H23);
+    assert_eq!(
+        Locale::ja.allowed_hour_cycles(),
+        &[HourCycle::H23, HourCycle::H11, HourCycle::H12]
+    );
+    assert!(Locale::en.hour_cycle().is_12_hour());
+
+    let dt = DateTime::new(2026, 1, 3, 0, 5, 9).unwrap();
+    assert_eq!(
+        Locale::en.format_time_with_hour_cycle(&dt, TimeStyle::Short, HourCycle::H23),
+        "00:05"
+    );
+    assert_eq!(
+        Locale::en.format_time_with_hour_cycle(&dt, TimeStyle::Short, HourCycle::H11),
+        "0:05\u{202f}AM"
+    );
+    assert_eq!(
+        Locale::en.format_time_with_hour_cycle(&dt, TimeStyle::Short, HourCycle::H24),
+        "24:05"
+    );
+    assert_eq!(
+        Locale::ja.format_time_with_hour_cycle(&dt, TimeStyle::Short, HourCycle::H12),
+        "午前12:05"
+    );
+    // Quoted literals keep their letters
+    assert_eq!(
+        Locale::fr_CA.time_pattern_with_hour_cycle(TimeStyle::Short, HourCycle::H24),
+        "kk 'h' mm"
+    );
+
+    // Skeletons with `j` use the preferred hour cycle
+    assert_eq!(Locale::en.pattern_for_skeleton("jm"), "h:mm\u{202f}a");
+    assert_eq!(Locale::de.pattern_for_skeleton("jm"), "HH:mm");
+
+    assert_eq!(
+        HourCycle::from_locale_tag("en-u-ca-gregory-hc-h23"),
+        Ok(Some(HourCycle::H23))
+    );
+    assert_eq!(HourCycle::from_str("h24"), Ok(HourCycle::H24));
+    assert_eq!(
+        HourCycle::from_str("h13"),
+        Err(LocaleError::UnknownHourCycle("h13".to_string()))
+    );
+    assert_eq!(HourCycle::H11.to_string(), "h11");
+}
+
+#[test]
+fn test_day_periods() {
+    use crate::datetime_formats::{DayPeriod, NameWidth};
+
+    let at = |hour, minute| DateTime::new(2026, 1, 3, hour, minute, 0).unwrap();
+    assert_eq!(Locale::en.day_period(&at(9, 0)), DayPeriod::Morning1);
+    assert_eq!(Locale::en.day_period(&at(12, 0)), DayPeriod::Noon);
+    assert_eq!(Locale::en.day_period(&at(12, 1)), DayPeriod::Afternoon1);
+    // Night wraps past midnight
+    assert_eq!(Locale::en.day_period(&at(3, 0)), DayPeriod::Night1);
+    assert_eq!(Locale::en.day_period(&at(22, 0)), DayPeriod::Night1);
+
+    assert_eq!(
+        Locale::en.format_with_pattern(&at(19, 30), "h:mm B"),
+        "7:30 in the evening"
+    );
+    assert_eq!(
+        Locale::de.format_with_pattern(&at(13, 30), "h:mm B"),
+        "1:30 nachm."
+    );
+    assert_eq!(
+        Locale::de.format_with_pattern(&at(13, 30), "h:mm BBBB"),
+        "1:30 nachmittags"
+    );
+    assert_eq!(
+        Locale::zh.format_with_pattern(&at(3, 0), "Bh:mm"),
+        "凌晨3:00"
+    );
+    // `b` only adds noon and midnight to AM and PM
+    assert_eq!(
+        Locale::en.format_with_pattern(&at(12, 0), "h:mm b"),
+        "12:00 noon"
+    );
+    assert_eq!(
+        Locale::en.format_with_pattern(&at(9, 0), "h:mm b"),
+        "9:00 AM"
+    );
+
+    assert_eq!(Locale::en.am_pm(NameWidth::Wide), ("AM", "PM"));
+    assert_eq!(Locale::en.am_pm(NameWidth::Narrow), ("a", "p"));
+    assert_eq!(Locale::ja.am_pm(NameWidth::Abbreviated), ("午前", "午後"));
+    assert_eq!(
+        Locale::fr.day_period_name(DayPeriod::Evening1, NameWidth::Wide),
+        "du soir"
+    );
+    // Periods without a name use AM or PM
+    assert_eq!(
+        Locale::en.day_period_name(DayPeriod::Morning2, NameWidth::Wide),
+        "AM"
+    );
+
+    let parsed = Locale::en
+        .parse_with_pattern("7:30 in the evening", "h:mm B")
+        .unwrap();
+    assert_eq!((parsed.hour, parsed.minute), (19, 30));
+    let parsed = Locale::de
+        .parse_with_pattern("9:00 abends", "h:mm B")
+        .unwrap();
+    assert_eq!(parsed.hour, 21);
+}
+
+#[test]
+fn test_month_and_weekday_names() {
+    use crate::datetime_formats::{NameContext, NameWidth};
+
+    // Polish months are genitive inside dates and nominative on their own
+    assert_eq!(
+        Locale::pl.month_name(10, NameContext::Format, NameWidth::Wide),
+        Some("października")
+    );
+    assert_eq!(
+        Locale::pl.month_name(10, NameContext::StandAlone, NameWidth::Wide),
+        Some("październik")
+    );
+    assert_eq!(
+        Locale::lt.month_name(1, NameContext::StandAlone, NameWidth::Wide),
+        Some("sausis")
+    );
+    assert_eq!(
+        Locale::en.month_name(1, NameContext::Format, NameWidth::Narrow),
+        Some("J")
+    );
+    assert_eq!(
+        Locale::en.month_name(9, NameContext::Format, NameWidth::Short),
+        Some("Sep")
+    );
+    assert_eq!(
+        Locale::en.month_name(0, NameContext::Format, NameWidth::Wide),
+        None
+    );
+
+    assert_eq!(
+        Locale::en.weekday_name(6, NameContext::Format, NameWidth::Short),
+        Some("Sa")
+    );
+    assert_eq!(
+        Locale::ru.weekday_name(1, NameContext::StandAlone, NameWidth::Wide),
+        Some("понедельник")
+    );
+    assert_eq!(
+        Locale::en.weekday_name(7, NameContext::Format, NameWidth::Wide),
+        None
+    );
+
+    let dt = DateTime::new(2026, 10, 19, 0, 0, 0).unwrap();
+    assert_eq!(
+        Locale::pl.format_with_pattern(&dt, "LLLL y"),
+        "październik 2026"
+    );
+    assert_eq!(
+        Locale::pl.format_with_pattern(&dt, "d MMMM"),
+        "19 października"
+    );
+}
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use crate::durations::{DurationStyle, ToDurationString};
+use crate::locale::Locale;
+
+#[test]
+fn test_unit_styles() {
+    let runtime = Duration::from_secs(3900);
+    assert_eq!(
+        runtime.to_duration_string(&Locale::en, DurationStyle::Long),
+        "1 hour, 5 minutes"
+    );
+    assert_eq!(
+        runtime.to_duration_string(&Locale::en, DurationStyle::Short),
+        "1 hr, 5 min"
+    );
+    assert_eq!(
+        runtime.to_duration_string(&Locale::en, DurationStyle::Narrow),
+        "1h 5m"
+    );
+
+    // Three units use the start and end list patterns
+    let video = Duration::from_secs(2 * 3600 + 3 * 60 + 4);
+    assert_eq!(
+        video.to_duration_string(&Locale::en, DurationStyle::Long),
+        "2 hours, 3 minutes, 4 seconds"
+    );
+    assert_eq!(
+        video.to_duration_string(&Locale::de, DurationStyle::Long),
+        "2 Stunden, 3 Minuten und 4 Sekunden"
+    );
+    // Plural categories pick the unit form
+    assert_eq!(
+        Duration::from_secs(5 * 60).to_duration_string(&Locale::ru, DurationStyle::Long),
+        "5 минут"
+    );
+
+    // Zero durations and fractions of a second show seconds
+    assert_eq!(
+        Duration::from_millis(400).to_duration_string(&Locale::en, DurationStyle::Short),
+        "0 sec"
+    );
+}
+
+#[test]
+fn test_digital_style() {
+    let runtime = Duration::from_secs(3900);
+    assert_eq!(
+        runtime.to_duration_string(&Locale::en, DurationStyle::Digital),
+        "1:05:00"
+    );
+    assert_eq!(
+        Duration::from_secs(309).to_duration_string(&Locale::en, DurationStyle::Digital),
+        "5:09"
+    );
+    // Hours are not wrapped into days
+    assert_eq!(
+        Duration::from_secs(26 * 3600).to_duration_string(&Locale::en, DurationStyle::Digital),
+        "26:00:00"
+    );
+}
+
+#[test]
+fn test_narrow_style() {
+    let video = Duration::from_secs(2 * 3600 + 3 * 60 + 4);
+    assert_eq!(
+        video.to_duration_string(&Locale::en, DurationStyle::Narrow),
+        "2h 3m 4s"
+    );
+    assert_eq!(
+        video.to_duration_string(&Locale::de, DurationStyle::Narrow),
+        "2 Std., 3 Min. und 4 Sek."
+    );
+    assert_eq!(
+        video.to_duration_string(&Locale::fr, DurationStyle::Narrow),
+        "2h 3min 4s"
+    );
+    assert_eq!(
+        video.to_duration_string(&Locale::ja, DurationStyle::Narrow),
+        "2h3m4s"
+    );
+    assert_eq!(
+        Duration::from_secs(60).to_duration_string(&Locale::en, DurationStyle::Narrow),
+        "1m"
+    );
+}
+
+#[test]
+fn test_zero_units() {
+    // A zero duration shows zero seconds in every unit style
+    for (style, expected) in [
+        (DurationStyle::Long, "0 seconds"),
+        (DurationStyle::Short, "0 sec"),
+        (DurationStyle::Narrow, "0s"),
+        (DurationStyle::Digital, "0:00"),
+    ] {
+        assert_eq!(
+            Duration::ZERO.to_duration_string(&Locale::en, style),
+            expected,
+            "{style:?}"
+        );
+    }
+    assert_eq!(
+        Duration::ZERO.to_duration_string(&Locale::de, DurationStyle::Long),
+        "0 Sekunden"
+    );
+
+    // Zero units between others are left out
+    assert_eq!(
+        Duration::from_secs(3600).to_duration_string(&Locale::en, DurationStyle::Long),
+        "1 hour"
+    );
+    assert_eq!(
+        Duration::from_secs(2 * 3600 + 4).to_duration_string(&Locale::en, DurationStyle::Long),
+        "2 hours, 4 seconds"
+    );
+    assert_eq!(
+        Duration::from_secs(2 * 3600 + 4).to_duration_string(&Locale::de, DurationStyle::Short),
+        "2 Std., 4 Sek."
+    );
+    // Digital keeps zero fields
+    assert_eq!(
+        Duration::from_secs(3600).to_duration_string(&Locale::en, DurationStyle::Digital),
+        "1:00:00"
+    );
+}
+
+#[test]
+fn test_digital_style_past_a_day() {
+    for (seconds, expected) in [
+        (24 * 3600, "24:00:00"),
+        (100 * 3600 + 61, "100:01:01"),
+        (1_000_000, "277:46:40"),
+    ] {
+        assert_eq!(
+            Duration::from_secs(seconds).to_duration_string(&Locale::en, DurationStyle::Digital),
+            expected
+        );
+    }
+    assert_eq!(
+        Duration::from_secs(30 * 3600 + 5 * 60)
+            .to_duration_string(&Locale::de, DurationStyle::Digital),
+        "30:05:00"
+    );
+    // Unit styles switch to days
+    assert_eq!(
+        Duration::from_secs(30 * 3600).to_duration_string(&Locale::en, DurationStyle::Long),
+        "1 day, 6 hours"
+    );
+}
+
+#[test]
+fn test_days_and_weeks() {
+    let days = Duration::from_secs(3 * 86_400);
+    assert_eq!(
+        days.to_duration_string(&Locale::en, DurationStyle::Long),
+        "3 days"
+    );
+    assert_eq!(
+        days.to_duration_string(&Locale::en, DurationStyle::Narrow),
+        "3d"
+    );
+    assert_eq!(
+        days.to_duration_string(&Locale::ru, DurationStyle::Long),
+        "3 дня"
+    );
+
+    let trip = Duration::from_secs(9 * 86_400 + 3 * 3600);
+    assert_eq!(
+        trip.to_duration_string(&Locale::en, DurationStyle::Long),
+        "1 week, 2 days, 3 hours"
+    );
+    assert_eq!(
+        trip.to_duration_string(&Locale::en, DurationStyle::Short),
+        "1 wk, 2 days, 3 hr"
+    );
+    assert_eq!(
+        trip.to_duration_string(&Locale::de, DurationStyle::Long),
+        "1 Woche, 2 Tage und 3 Stunden"
+    );
+    assert_eq!(
+        Duration::from_secs(14 * 86_400).to_duration_string(&Locale::en, DurationStyle::Long),
+        "2 weeks"
+    );
+    // The digital style has no day field
+    assert_eq!(
+        trip.to_duration_string(&Locale::en, DurationStyle::Digital),
+        "219:00:00"
+    );
+}
+
+#[test]
+fn test_narrow_differs_from_short() {
+    let video = Duration::from_secs(2 * 3600 + 3 * 60);
+    assert_eq!(
+        video.to_duration_string(&Locale::uk, DurationStyle::Short),
+        "2 год і 3 хв"
+    );
+    assert_eq!(
+        video.to_duration_string(&Locale::uk, DurationStyle::Narrow),
+        "2 год. і 3х"
+    );
+
+    // CLDR has no shorter Russian hours and minutes, only days
+    assert_eq!(
+        video.to_duration_string(&Locale::ru, DurationStyle::Narrow),
+        video.to_duration_string(&Locale::ru, DurationStyle::Short)
+    );
+    let days = Duration::from_secs(3 * 86_400);
+    assert_eq!(
+        days.to_duration_string(&Locale::ru, DurationStyle::Short),
+        "3 дн."
+    );
+    assert_eq!(
+        days.to_duration_string(&Locale::ru, DurationStyle::Narrow),
+        "3 д."
+    );
+}
@@ -1,5 +1,9 @@
+use crate::error::LocaleError;
 use crate::locale::Locale;
-use crate::num_formats::ToFormattedString;
+use crate::num_formats::{
+    GroupingStrategy, NumberSymbols, NumberingSystem, OtherNumberingSystem, ToFormattedString,
+    ToOrdinalString,
+};
 
 #[test]
 fn test_diverse_numerical_symbols() {
@@ -73,3 +77,218 @@ fn test_grouping_exhaustion_detailed() {
     // 1,00,00,000 (The '2' is repeated indefinitely after the first '3')
     assert_eq!(10000000.to_formatted_string(hi_), "1,00,00,000");
 }
+
+#[test]
+fn test_ordinals() {
+    // English suffixes follow the ordinal plural categories
+    let english = &Locale::en;
+    assert_eq!(1.to_ordinal_string(english), "1st");
+    assert_eq!(2u8.to_ordinal_string(english), "2nd");
+    assert_eq!(3i64.to_ordinal_string(english), "3rd");
+    assert_eq!(11.to_ordinal_string(english), "11th");
+    assert_eq!(22usize.to_ordinal_string(english), "22nd");
+    assert_eq!(1000000.to_ordinal_string(english), "1,000,000th");
+    assert_eq!((-3).to_ordinal_string(english), "\u{2212}3rd");
+    assert_eq!(
+        i64::MIN.to_ordinal_string(english),
+        "\u{2212}9,223,372,036,854,775,808th"
+    );
+    // No absolute value to apply the rules to
+    assert_eq!(
+        i128::MIN.to_ordinal_string(english),
+        "-170,141,183,460,469,231,731,687,303,715,884,105,728"
+    );
+
+    assert_eq!(1.to_ordinal_string(&Locale::fr), "1er");
+    assert_eq!(2.to_ordinal_string(&Locale::fr), "2e");
+    assert_eq!(3.to_ordinal_string(&Locale::de), "3.");
+    assert_eq!(4.to_ordinal_string(&Locale::es), "4.º");
+    assert_eq!(2.to_ordinal_string(&Locale::sv), "2:a");
+    assert_eq!(3.to_ordinal_string(&Locale::sv), "3:e");
+
+    // Regional variants take the rules of their parent locale
+    assert_eq!(4.to_ordinal_string(&Locale::es_MX), "4º.");
+    assert_eq!(4.to_ordinal_string(&Locale::es_AR), "4º.");
+    assert_eq!(2.to_ordinal_string(&Locale::en_GB), "2nd");
+
+    // Digits and grouping come from the locale; grouping sizes from the rule
+    assert_eq!(1000000.to_ordinal_string(&Locale::hi), "10,00,000वाँ");
+    assert_eq!(1000000.to_ordinal_string(&Locale::ne), "१,०००,०००.");
+
+    // Locales without ordinal rules fall back to root
+    assert_eq!(5.to_ordinal_string(&Locale::ha), "5.");
+}
+
+#[test]
+fn test_algorithmic_numbering_systems() {
+    let english = &Locale::en;
+    let format = |n: i64, system: NumberingSystem| english.format_in_numbering_system(n, system);
+
+    assert_eq!(format(1984, NumberingSystem::Roman), "MCMLXXXIV");
+    assert_eq!(format(2024, NumberingSystem::Romanlow), "mmxxiv");
+    assert_eq!(format(5784, NumberingSystem::Hebr), "ה׳תשפ״ד");
+    assert_eq!(format(2024, NumberingSystem::Armn), "ՍԻԴ");
+    assert_eq!(format(2024, NumberingSystem::Geor), "ჩკდ");
+    // Chinese and Japanese systems use the rules of their language
+    assert_eq!(format(2024, NumberingSystem::Jpan), "二千二十四");
+    assert_eq!(format(12345, NumberingSystem::Hant), "一萬二千三百四十五");
+    assert!(NumberingSystem::Roman.is_algorithmic());
+    assert_eq!(NumberingSystem::Roman.digits(), None);
+}
+
+#[test]
+fn test_numeric_numbering_systems() {
+    // Digits come from the system, separators and grouping from the locale
+    assert_eq!(
+        Locale::en.format_in_numbering_system(2024, NumberingSystem::Hanidec),
+        "二,〇二四"
+    );
+    assert_eq!(
+        Locale::de.format_in_numbering_system(1234567, NumberingSystem::Deva),
+        "१.२३४.५६७"
+    );
+    assert_eq!(
+        Locale::hi.format_in_numbering_system(1234567, NumberingSystem::Latn),
+        "12,34,567"
+    );
+    assert_eq!(
+        Locale::en.format_in_numbering_system(-42, NumberingSystem::Fullwide),
+        "-４２"
+    );
+    assert!(!NumberingSystem::Thai.is_algorithmic());
+}
+
+#[test]
+fn test_numbering_system_names() {
+    assert_eq!("hebr".parse::<NumberingSystem>(), Ok(NumberingSystem::Hebr));
+    assert_eq!(
+        "ROMAN".parse::<NumberingSystem>(),
+        Ok(NumberingSystem::Roman)
+    );
+    assert_eq!(NumberingSystem::Arabext.as_str(), "arabext");
+    assert_eq!(NumberingSystem::Jpan.to_string(), "jpan");
+    assert_eq!(
+        "klingon".parse::<NumberingSystem>(),
+        Err(LocaleError::UnknownNumberingSystem("klingon".to_string()))
+    );
+}
+
+#[test]
+fn test_other_numbering_systems() {
+    assert_eq!(Locale::ar.default_numbering_system(), NumberingSystem::Latn);
+    assert_eq!(
+        Locale::ar_EG.default_numbering_system(),
+        NumberingSystem::Arab
+    );
+    assert_eq!(
+        Locale::ar.other_numbering_system(OtherNumberingSystem::Native),
+        NumberingSystem::Arab
+    );
+    assert_eq!(
+        Locale::zh.other_numbering_system(OtherNumberingSystem::Traditional),
+        NumberingSystem::Hans
+    );
+    // Traditional falls back to native, finance to the default system
+    assert_eq!(
+        Locale::hi.other_numbering_system(OtherNumberingSystem::Traditional),
+        NumberingSystem::Deva
+    );
+    assert_eq!(
+        Locale::hi.other_numbering_system(OtherNumberingSystem::Finance),
+        NumberingSystem::Latn
+    );
+}
+
+#[test]
+fn test_number_symbols_per_system() {
+    assert_eq!(
+        Locale::ar.number_symbols(NumberingSystem::Arab),
+        NumberSymbols {
+            decimal: "٫",
+            group: "٬",
+            minus_sign: "\u{61c}-",
+        }
+    );
+    assert_eq!(
+        Locale::ar_EG.number_symbols(NumberingSystem::Latn),
+        NumberSymbols {
+            decimal: ".",
+            group: ",",
+            minus_sign: "\u{200e}-",
+        }
+    );
+    // Systems without symbols of their own use the locale's latn symbols
+    assert_eq!(
+        Locale::ar_EG.number_symbols(NumberingSystem::Thai),
+        Locale::ar_EG.number_symbols(NumberingSystem::Latn)
+    );
+    assert_eq!(
+        Locale::de.number_symbols(NumberingSystem::Deva).decimal,
+        ","
+    );
+}
+
+#[test]
+fn test_to_formatted_string_in() {
+    assert_eq!(
+        (-1234567).to_formatted_string_in(&Locale::ar, NumberingSystem::Arab),
+        "\u{61c}-١٬٢٣٤٬٥٦٧"
+    );
+    assert_eq!(
+        1234567u64.to_formatted_string_in(&Locale::ar_EG, NumberingSystem::Latn),
+        "1,234,567"
+    );
+    assert_eq!(
+        1234.5.to_formatted_string_in(&Locale::hi, NumberingSystem::Deva),
+        "१,२३४.५"
+    );
+    assert_eq!(
+        (-7i8).to_formatted_string_in(&Locale::en, NumberingSystem::Roman),
+        "−VII"
+    );
+    // Fractions in an algorithmic system use the default one
+    assert_eq!(
+        2.5f32.to_formatted_string_in(&Locale::en, NumberingSystem::Roman),
+        "2.5"
+    );
+    assert_eq!(
+        Locale::ar.format_in_numbering_system(-5, NumberingSystem::Arab),
+        "\u{61c}-٥"
+    );
+}
+
+#[test]
+fn test_minimum_grouping_digits() {
+    assert_eq!(Locale::en.minimum_grouping_digits(), 1);
+    assert_eq!(Locale::es.minimum_grouping_digits(), 2);
+    assert_eq!(Locale::es_MX.minimum_grouping_digits(), 1);
+
+    // A four-digit number stays ungrouped when the minimum is two
+    assert_eq!(1234.to_formatted_string(&Locale::es), "1234");
+    assert_eq!(12345.to_formatted_string(&Locale::es), "12.345");
+    assert_eq!((-1234).to_formatted_string(&Locale::pl), "-1234");
+    assert_eq!(1234.5.to_formatted_string(&Locale::pl), "1234,5");
+    assert_eq!(1234.to_formatted_string(&Locale::es_MX), "1,234");
+    assert_eq!(1234.to_formatted_string(&Locale::en), "1,234");
+
+    assert_eq!(
+        1234.to_formatted_string_with_grouping(&Locale::es, GroupingStrategy::Always),
+        "1.234"
+    );
+    assert_eq!(
+        1234567u32.to_formatted_string_with_grouping(&Locale::en, GroupingStrategy::Never),
+        "1234567"
+    );
+    assert_eq!(
+        1234567.25.to_formatted_string_with_grouping(&Locale::hi, GroupingStrategy::Auto),
+        "12,34,567.25"
+    );
+    assert_eq!(
+        12345.to_formatted_string_with(
+            &Locale::es,
+            NumberingSystem::Fullwide,
+            GroupingStrategy::Never
+        ),
+        "１２３４５"
+    );
+}
@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use crate::error::LocaleError;
+use crate::locale::Locale;
+use crate::plurals::{PluralCategory, PluralOperands};
+
+#[test]
+fn test_cardinal_categories() {
+    assert_eq!(Locale::en.plural_category(&1.into()), PluralCategory::One);
+    assert_eq!(Locale::en.plural_category(&2.into()), PluralCategory::Other);
+    // Russian looks at the last digits
+    assert_eq!(Locale::ru.plural_category(&21.into()), PluralCategory::One);
+    assert_eq!(Locale::ru.plural_category(&22.into()), PluralCategory::Few);
+    assert_eq!(Locale::ru.plural_category(&25.into()), PluralCategory::Many);
+    assert_eq!(Locale::ru.plural_category(&11.into()), PluralCategory::Many);
+    assert_eq!(Locale::ar.plural_category(&0.into()), PluralCategory::Zero);
+    assert_eq!(Locale::ar.plural_category(&2.into()), PluralCategory::Two);
+    assert_eq!(Locale::ja.plural_category(&1.into()), PluralCategory::Other);
+    assert_eq!(
+        Locale::en.plural_category(&(-1).into()),
+        PluralCategory::One
+    );
+}
+
+#[test]
+fn test_visible_fraction_digits() {
+    let operands = |s: &str| PluralOperands::from_str(s).unwrap();
+    // "1.0" has a visible fraction digit, so it is not `one` in English
+    assert_eq!(
+        Locale::en.plural_category(&operands("1.0")),
+        PluralCategory::Other
+    );
+    assert_eq!(
+        Locale::en.plural_category(&operands("1")),
+        PluralCategory::One
+    );
+    // Russian decimals are `other`
+    assert_eq!(
+        Locale::ru.plural_category(&operands("1.5")),
+        PluralCategory::Other
+    );
+    // Latvian checks the fraction digits
+    assert_eq!(
+        Locale::lv.plural_category(&operands("0.1")),
+        PluralCategory::One
+    );
+
+    assert_eq!(operands("1.50"), operands("1.50"));
+    assert_ne!(operands("1.50"), operands("1.5"));
+    assert_eq!(PluralOperands::from_f64(1.5), operands("1.5"));
+    assert_eq!(PluralOperands::from_f64(2.0), operands("2"));
+
+    // Compact exponents: French uses `many` for a million
+    assert_eq!(
+        Locale::fr.plural_category(&operands("1c6")),
+        PluralCategory::Many
+    );
+    assert_eq!(
+        Locale::fr.plural_category(&operands("1.2c6")),
+        PluralCategory::Many
+    );
+    assert_eq!(
+        Locale::fr.plural_category(&operands("1.2c3")),
+        PluralCategory::Other
+    );
+    assert_eq!(
+        PluralOperands::from_str("1.2.3"),
+        Err(LocaleError::InvalidNumber("1.2.3".to_string()))
+    );
+    assert!(PluralOperands::from_str("").is_err());
+}
+
+#[test]
+fn test_ordinal_categories() {
+    assert_eq!(Locale::en.ordinal_category(&1.into()), PluralCategory::One);
+    assert_eq!(Locale::en.ordinal_category(&22.into()), PluralCategory::Two);
+    assert_eq!(Locale::en.ordinal_category(&23.into()), PluralCategory::Few);
+    assert_eq!(
+        Locale::en.ordinal_category(&11.into()),
+        PluralCategory::Other
+    );
+    assert_eq!(
+        Locale::en.ordinal_category(&111.into()),
+        PluralCategory::Other
+    );
+    assert_eq!(Locale::fr.ordinal_category(&1.into()), PluralCategory::One);
+    assert_eq!(
+        Locale::fr.ordinal_category(&2.into()),
+        PluralCategory::Other
+    );
+    assert_eq!(Locale::sv.ordinal_category(&2.into()), PluralCategory::One);
+    assert_eq!(
+        Locale::de.ordinal_category(&1.into()),
+        PluralCategory::Other
+    );
+}
+
+#[test]
+fn test_plural_ranges() {
+    // "1–2 days" is `other` even though 1 is `one`
+    assert_eq!(
+        Locale::en.plural_range_category(&1.into(), &2.into()),
+        PluralCategory::Other
+    );
+    assert_eq!(
+        Locale::fr.plural_range_category(&0.into(), &1.into()),
+        PluralCategory::One
+    );
+    // Latvian 0 is `zero`, and the range to 1 takes `one` from the end
+    assert_eq!(
+        Locale::lv.plural_range_category(&0.into(), &1.into()),
+        PluralCategory::One
+    );
+    assert_eq!(
+        Locale::ru.plural_range_category(&1.into(), &2.into()),
+        PluralCategory::Few
+    );
+    assert_eq!(
+        Locale::ru.plural_range_category(&1.into(), &5.into()),
+        PluralCategory::Many
+    );
+    // Without range rules the end decides
+    assert_eq!(
+        Locale::ja.plural_range_category(&1.into(), &2.into()),
+        PluralCategory::Other
+    );
+}
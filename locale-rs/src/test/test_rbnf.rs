@@ -0,0 +1,128 @@
+use crate::locale::Locale;
+use crate::rbnf::{Gender, RbnfRule};
+
+#[test]
+fn test_spell_out() {
+    let english = &Locale::en;
+    let spell = |locale: &Locale, n: i64, rule: RbnfRule| locale.spell_out(n, rule).unwrap();
+
+    assert_eq!(spell(english, 0, RbnfRule::SpelloutNumbering), "zero");
+    assert_eq!(
+        spell(english, 1234, RbnfRule::SpelloutNumbering),
+        "one thousand two hundred thirty-four"
+    );
+    assert_eq!(
+        spell(english, 1984, RbnfRule::SpelloutYear),
+        "nineteen eighty-four"
+    );
+    assert_eq!(
+        spell(english, -42, RbnfRule::SpelloutCardinal(None)),
+        "minus forty-two"
+    );
+    assert_eq!(
+        spell(english, 21, RbnfRule::SpelloutOrdinal(None)),
+        "twenty-first"
+    );
+    assert_eq!(
+        spell(english, i64::MIN, RbnfRule::SpelloutCardinal(None)),
+        "minus 9,223,372,036,854,775,808"
+    );
+    assert_eq!(
+        spell(english, i64::MIN, RbnfRule::SpelloutOrdinal(None)),
+        "minus 9,223,372,036,854,775,808th"
+    );
+
+    // German compounds carry soft hyphens as line break hints
+    assert_eq!(
+        spell(&Locale::de, 1234, RbnfRule::SpelloutNumbering).replace('\u{ad}', ""),
+        "eintausendzweihundertvierunddreißig"
+    );
+
+    // Regional vocabulary comes from the closest locale with rules
+    assert_eq!(
+        spell(
+            &Locale::fr,
+            71,
+            RbnfRule::SpelloutCardinal(Some(Gender::Masculine))
+        ),
+        "soixante-et-onze"
+    );
+    assert_eq!(
+        spell(
+            &Locale::fr_CH,
+            71,
+            RbnfRule::SpelloutCardinal(Some(Gender::Masculine))
+        ),
+        "septante-et-un"
+    );
+    assert_eq!(
+        spell(&Locale::es_MX, 1000000, RbnfRule::SpelloutNumbering),
+        "un millón"
+    );
+    assert_eq!(
+        spell(&Locale::ja, 12345, RbnfRule::SpelloutCardinal(None)),
+        "一万二千三百四十五"
+    );
+}
+
+#[test]
+fn test_spell_out_genders() {
+    let spanish = &Locale::es;
+    assert_eq!(
+        spanish
+            .spell_out(21, RbnfRule::SpelloutCardinal(Some(Gender::Feminine)))
+            .as_deref(),
+        Some("veintiuna")
+    );
+    assert_eq!(
+        spanish
+            .spell_out(21, RbnfRule::SpelloutCardinal(Some(Gender::Masculine)))
+            .as_deref(),
+        Some("veintiún")
+    );
+    // Without a gender, languages lacking a plain rule set use the masculine one
+    assert_eq!(
+        spanish
+            .spell_out(21, RbnfRule::SpelloutCardinal(None))
+            .as_deref(),
+        Some("veintiún")
+    );
+
+    assert_eq!(
+        Locale::ru
+            .spell_out(2, RbnfRule::SpelloutCardinal(Some(Gender::Feminine)))
+            .as_deref(),
+        Some("две")
+    );
+    // English has no gendered rule sets, so the plain one applies
+    assert_eq!(
+        Locale::en
+            .spell_out(2, RbnfRule::SpelloutCardinal(Some(Gender::Feminine)))
+            .as_deref(),
+        Some("two")
+    );
+}
+
+#[test]
+fn test_spell_out_roman_and_named() {
+    assert_eq!(
+        Locale::en.spell_out(1984, RbnfRule::RomanUpper).as_deref(),
+        Some("MCMLXXXIV")
+    );
+    assert_eq!(
+        Locale::de.spell_out(2024, RbnfRule::RomanLower).as_deref(),
+        Some("mmxxiv")
+    );
+    assert_eq!(
+        Locale::ru
+            .spell_out(3, RbnfRule::Named("%spellout-cardinal-feminine-genitive"))
+            .as_deref(),
+        Some("трех")
+    );
+    assert_eq!(
+        Locale::en.spell_out(1, RbnfRule::Named("%spellout-unknown")),
+        None
+    );
+    // Private rule sets are not reachable
+    assert_eq!(Locale::en.spell_out(1, RbnfRule::Named("%%and")), None);
+}
@@ -0,0 +1,126 @@
+use crate::datetime_formats::DateTime;
+use crate::locale::Locale;
+use crate::relative_time::{RelativeStyle, RelativeUnit};
+
+fn dt(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime {
+    DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second: 0,
+    }
+}
+
+#[test]
+fn test_relative_patterns() {
+    let locale = Locale::en;
+    assert_eq!(
+        locale.format_relative(-3, RelativeUnit::Day, RelativeStyle::Long),
+        "3 days ago"
+    );
+    assert_eq!(
+        locale.format_relative(2, RelativeUnit::Hour, RelativeStyle::Long),
+        "in 2 hours"
+    );
+    assert_eq!(
+        locale.format_relative(1, RelativeUnit::Minute, RelativeStyle::Long),
+        "in 1 minute"
+    );
+    assert_eq!(
+        locale.format_relative(-1, RelativeUnit::Day, RelativeStyle::Long),
+        "yesterday"
+    );
+    assert_eq!(
+        locale.format_relative(0, RelativeUnit::Month, RelativeStyle::Long),
+        "this month"
+    );
+    assert_eq!(
+        locale.format_relative_numeric(1, RelativeUnit::Day, RelativeStyle::Long),
+        "in 1 day"
+    );
+    assert_eq!(
+        Locale::fr.format_relative(-2, RelativeUnit::Day, RelativeStyle::Long),
+        "avant-hier"
+    );
+    assert_eq!(
+        Locale::fr.format_relative(-3, RelativeUnit::Day, RelativeStyle::Long),
+        "il y a 3 jours"
+    );
+}
+
+#[test]
+fn test_relative_plural_categories() {
+    // Arabic has a distinct form for each plural category
+    let locale = Locale::ar;
+    assert_eq!(
+        locale.format_relative(-1, RelativeUnit::Hour, RelativeStyle::Long),
+        "قبل ساعة واحدة"
+    );
+    assert_eq!(
+        locale.format_relative(-2, RelativeUnit::Hour, RelativeStyle::Long),
+        "قبل ساعتين"
+    );
+    // Polish: few for 2-4 except 12-14, many otherwise
+    let locale = Locale::pl;
+    assert_eq!(
+        locale.format_relative(3, RelativeUnit::Year, RelativeStyle::Long),
+        "za 3 lata"
+    );
+    assert_eq!(
+        locale.format_relative(5, RelativeUnit::Year, RelativeStyle::Long),
+        "za 5 lat"
+    );
+    assert_eq!(
+        locale.format_relative(22, RelativeUnit::Year, RelativeStyle::Long),
+        "za 22 lata"
+    );
+}
+
+#[test]
+fn test_relative_styles() {
+    let locale = Locale::en;
+    assert_eq!(
+        locale.format_relative(3, RelativeUnit::Month, RelativeStyle::Short),
+        "in 3 mo."
+    );
+    assert_eq!(
+        locale.format_relative(-5, RelativeUnit::Minute, RelativeStyle::Narrow),
+        "5m ago"
+    );
+    #[cfg(feature = "nums")]
+    assert_eq!(
+        locale.format_relative(-1500, RelativeUnit::Year, RelativeStyle::Long),
+        "1,500 years ago"
+    );
+}
+
+#[test]
+fn test_best_fit_unit() {
+    let now = dt(2026, 3, 15, 12, 0);
+    let cases = [
+        (dt(2026, 3, 15, 11, 59), (-1, RelativeUnit::Minute)),
+        (dt(2026, 3, 15, 11, 15), (-45, RelativeUnit::Minute)),
+        (dt(2026, 3, 15, 15, 30), (3, RelativeUnit::Hour)),
+        (dt(2026, 3, 14, 8, 0), (-1, RelativeUnit::Day)),
+        (dt(2026, 3, 1, 12, 0), (-2, RelativeUnit::Week)),
+        (dt(2026, 5, 20, 12, 0), (2, RelativeUnit::Month)),
+        (dt(2024, 1, 1, 12, 0), (-2, RelativeUnit::Year)),
+    ];
+    for (datetime, expected) in cases {
+        assert_eq!(
+            RelativeUnit::best_fit(&datetime, &now),
+            expected,
+            "{datetime:?}"
+        );
+    }
+    assert_eq!(
+        Locale::en.format_relative_datetime(&dt(2026, 3, 14, 8, 0), &now, RelativeStyle::Long),
+        "yesterday"
+    );
+    assert_eq!(
+        Locale::en.format_relative_datetime(&dt(2026, 3, 15, 9, 0), &now, RelativeStyle::Long),
+        "3 hours ago"
+    );
+}
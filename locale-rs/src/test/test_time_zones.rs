@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use crate::datetime_formats::{DateStyle, DateTime, TimeStyle};
+use crate::error::LocaleError;
+use crate::locale::Locale;
+use crate::time_zones::{TZDATA_VERSION, TimeZone, ZonedDateTime};
+
+fn dt(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime {
+    DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second: 0,
+    }
+}
+
+#[test]
+fn test_offsets_follow_dst_rules() {
+    let paris = TimeZone::Europe_Paris;
+    let winter = paris.offset_at(&dt(2026, 1, 3, 12, 0));
+    assert_eq!((winter.seconds, winter.is_dst), (3600, false));
+    let summer = paris.offset_at(&dt(2026, 7, 3, 12, 0));
+    assert_eq!((summer.seconds, summer.is_dst), (7200, true));
+
+    // Far future instants fall back to the zone's POSIX rule
+    assert_eq!(paris.offset_at(&dt(2100, 7, 1, 12, 0)).seconds, 7200);
+    // Before standard time Paris used local mean time
+    assert_eq!(paris.offset_at(&dt(1900, 1, 1, 0, 0)).seconds, 561);
+
+    // The US moved the DST start from April to March in 2007
+    let new_york = TimeZone::America_New_York;
+    assert_eq!(new_york.offset_at(&dt(2006, 3, 20, 12, 0)).seconds, -18000);
+    assert_eq!(new_york.offset_at(&dt(2007, 3, 20, 12, 0)).seconds, -14400);
+
+    // The offsets above are pinned to a known tz database release
+    assert_eq!(TZDATA_VERSION, "2025b");
+}
+
+#[test]
+fn test_local_time_gaps_and_overlaps() {
+    // 02:30 does not exist on the spring-forward day and moves to 03:30
+    let gap = ZonedDateTime::new(dt(2026, 3, 29, 2, 30), TimeZone::Europe_Paris);
+    assert_eq!((gap.datetime.hour, gap.datetime.minute), (3, 30));
+    assert_eq!(gap.offset.seconds, 7200);
+
+    // 02:30 happens twice on the fall-back day; the earlier offset wins
+    let overlap = ZonedDateTime::new(dt(2026, 10, 25, 2, 30), TimeZone::Europe_Paris);
+    assert_eq!(overlap.offset.seconds, 7200);
+    assert_eq!(overlap.to_utc(), dt(2026, 10, 25, 0, 30));
+
+    let from_utc = ZonedDateTime::from_utc(dt(2026, 10, 25, 1, 30), TimeZone::Europe_Paris);
+    assert_eq!(from_utc.datetime, dt(2026, 10, 25, 2, 30));
+    assert_eq!(from_utc.offset.seconds, 3600);
+}
+
+#[test]
+fn test_zone_ids_and_aliases() {
+    assert_eq!(
+        TimeZone::from_str("Europe/Paris"),
+        Ok(TimeZone::Europe_Paris)
+    );
+    assert_eq!(
+        TimeZone::from_str("asia/calcutta"),
+        Ok(TimeZone::Asia_Kolkata)
+    );
+    assert_eq!(TimeZone::Asia_Kolkata.iana_id(), "Asia/Kolkata");
+    assert_eq!(TimeZone::Europe_Paris.bcp47_id(), "frpar");
+    assert_eq!(TimeZone::Europe_Paris.to_string(), "Europe/Paris");
+    assert_eq!(
+        TimeZone::from_str("Mars/Olympus_Mons"),
+        Err(LocaleError::UnknownTimeZone(
+            "Mars/Olympus_Mons".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_localized_zone_names() {
+    let summer = ZonedDateTime::new(dt(2026, 7, 3, 14, 5), TimeZone::Europe_Berlin);
+    let winter = ZonedDateTime::new(dt(2026, 1, 3, 14, 5), TimeZone::Europe_Berlin);
+    assert_eq!(Locale::de.format_zoned_with_pattern(&summer, "z"), "MESZ");
+    assert_eq!(Locale::de.format_zoned_with_pattern(&winter, "z"), "MEZ");
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&summer, "zzzz"),
+        "Central European Summer Time"
+    );
+
+    let la = ZonedDateTime::new(dt(2026, 7, 3, 14, 5), TimeZone::America_Los_Angeles);
+    assert_eq!(Locale::en.format_zoned_with_pattern(&la, "z"), "PDT");
+    assert_eq!(Locale::en.format_zoned_with_pattern(&la, "v"), "PT");
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&la, "vvvv"),
+        "Pacific Time"
+    );
+
+    // Zone-specific names override the metazone ones
+    let dublin = ZonedDateTime::new(dt(2026, 7, 3, 14, 5), TimeZone::Europe_Dublin);
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&dublin, "zzzz"),
+        "Irish Standard Time"
+    );
+}
+
+#[test]
+fn test_location_and_offset_formats() {
+    let paris = ZonedDateTime::new(dt(2026, 7, 3, 14, 5), TimeZone::Europe_Paris);
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&paris, "VVVV"),
+        "Paris Time"
+    );
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&paris, "VV"),
+        "Europe/Paris"
+    );
+    assert_eq!(Locale::en.format_zoned_with_pattern(&paris, "O"), "GMT+2");
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&paris, "OOOO"),
+        "GMT+02:00"
+    );
+    assert_eq!(Locale::en.format_zoned_with_pattern(&paris, "X"), "+02");
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&paris, "XXX"),
+        "+02:00"
+    );
+    assert_eq!(Locale::en.format_zoned_with_pattern(&paris, "Z"), "+0200");
+
+    let utc = ZonedDateTime::with_offset(dt(2026, 7, 3, 14, 5), 0);
+    assert_eq!(Locale::en.format_zoned_with_pattern(&utc, "X"), "Z");
+    assert_eq!(Locale::fr.format_zoned_with_pattern(&utc, "O"), "UTC");
+    assert_eq!(Locale::en.format_gmt_offset(-34200, true), "GMT-09:30");
+    assert_eq!(Locale::fr.format_gmt_offset(3600, false), "UTC+1");
+
+    assert_eq!(
+        Locale::en.exemplar_city(TimeZone::America_New_York),
+        "New York"
+    );
+    assert_eq!(Locale::de.exemplar_city(TimeZone::Europe_Vienna), "Wien");
+}
+
+#[test]
+fn test_zoned_styles() {
+    let zdt = ZonedDateTime::new(dt(2026, 1, 3, 14, 5), TimeZone::America_Los_Angeles);
+    let formatted = Locale::en.format_zoned(&zdt, DateStyle::Medium, TimeStyle::Full);
+    assert!(formatted.ends_with("Pacific Standard Time"), "{formatted}");
+    assert_eq!(Locale::en.format_zoned_skeleton(&zdt, "Hmz"), "14:05 PST");
+}
+
+#[test]
+fn test_zone_names_need_a_zone() {
+    // A plain DateTime has no zone, so no generic or location name is made up
+    let naive = dt(2026, 1, 3, 14, 5);
+    assert_eq!(Locale::en.format_skeleton(&naive, "jmv"), "2:05\u{202f}PM");
+    assert_eq!(
+        Locale::en.format_with_pattern(&naive, "HH:mm VVVV, d MMM"),
+        "14:05 3 Jan"
+    );
+    assert_eq!(
+        Locale::fr.format_time_with_style(&naive, TimeStyle::Full),
+        "14:05:00"
+    );
+
+    let zdt = ZonedDateTime::new(naive, TimeZone::Europe_Paris);
+    assert_eq!(
+        Locale::en.format_zoned_skeleton(&zdt, "jmv"),
+        "2:05\u{202f}PM Paris Time"
+    );
+    assert_eq!(
+        Locale::en.format_zoned_with_pattern(&zdt, "HH:mm VVVV, d MMM"),
+        "14:05 Paris Time, 3 Jan"
+    );
+}